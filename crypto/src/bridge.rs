@@ -0,0 +1,471 @@
+use crate::errors::{BridgeError, Error, SigningError};
+use k256::ecdsa::{RecoveryId, Signature, VerifyingKey};
+use k256::elliptic_curve::sec1::ToEncodedPoint;
+use sha3::{Digest, Keccak256};
+use std::fmt;
+use std::str::FromStr;
+use vega_protobufs::{
+    datanode::api::v2::{
+        GetErc20ListAssetBundleResponse, GetErc20SetAssetLimitsBundleResponse,
+        GetErc20WithdrawalApprovalResponse,
+    },
+    vega::Node,
+};
+
+const WORD_LEN: usize = 32;
+const SIGNATURE_LEN: usize = 65;
+/// Default multisig control threshold, in thousandths of the signer count.
+pub const DEFAULT_THRESHOLD: u16 = 500;
+
+/// An ethereum address.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Address(pub [u8; 20]);
+
+impl Address {
+    /// The ethereum address of a secp256k1 public key.
+    pub fn from_public_key(pubkey: &k256::PublicKey) -> Address {
+        let point = pubkey.to_encoded_point(false);
+        let hash = Keccak256::digest(&point.as_bytes()[1..]);
+        let mut out = [0u8; 20];
+        out.copy_from_slice(&hash[12..]);
+        return Address(out);
+    }
+
+    fn word(&self) -> [u8; WORD_LEN] {
+        let mut out = [0u8; WORD_LEN];
+        out[12..].copy_from_slice(&self.0);
+        return out;
+    }
+}
+
+impl FromStr for Address {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let digits = s.strip_prefix("0x").unwrap_or(s);
+        if digits.len() != 40 {
+            return Err(BridgeError::InvalidEthereumAddress(s.to_string()).into());
+        }
+        let mut out = [0u8; 20];
+        hex::decode_to_slice(digits, &mut out)
+            .map_err(|_| BridgeError::InvalidEthereumAddress(s.to_string()))?;
+        return Ok(Address(out));
+    }
+}
+
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "0x{}", hex::encode(self.0))
+    }
+}
+
+/// The ethereum addresses of the validators, as registered on vega,
+/// to be used as the multisig signer set.
+pub fn signers_from_nodes(nodes: &[Node]) -> Result<Vec<Address>, Error> {
+    return nodes
+        .iter()
+        .filter(|n| !n.ethereum_address.is_empty())
+        .map(|n| n.ethereum_address.parse())
+        .collect();
+}
+
+/// A bundle of arguments signed by the validators,
+/// ready to be submitted to one of the bridge contracts.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Bundle {
+    function: &'static str,
+    signature: &'static str,
+    args: Vec<[u8; WORD_LEN]>,
+    signatures: Vec<u8>,
+}
+
+/// Build the bundle to call `withdraw_asset` on the ERC20 bridge
+/// from a withdrawal approval.
+pub fn withdraw_asset(resp: &GetErc20WithdrawalApprovalResponse) -> Result<Bundle, Error> {
+    if resp.creation < 0 {
+        return Err(BridgeError::InvalidUint256(resp.creation.to_string()).into());
+    }
+
+    return Ok(Bundle {
+        function: "withdraw_asset",
+        signature: "withdraw_asset(address,uint256,address,uint256,uint256,bytes)",
+        args: vec![
+            resp.asset_source.parse::<Address>()?.word(),
+            parse_uint256(&resp.amount)?,
+            resp.target_address.parse::<Address>()?.word(),
+            uint_word(resp.creation as u64),
+            parse_uint256(&resp.nonce)?,
+        ],
+        signatures: decode_signatures(&resp.signatures)?,
+    });
+}
+
+/// Build the bundle to call `list_asset` on the ERC20 bridge, the limits
+/// are the `lifetime_limit` and `withdraw_threshold` of the asset ERC20 details.
+pub fn list_asset(
+    resp: &GetErc20ListAssetBundleResponse,
+    lifetime_limit: &str,
+    withdraw_threshold: &str,
+) -> Result<Bundle, Error> {
+    let mut vega_asset_id = [0u8; WORD_LEN];
+    hex::decode_to_slice(
+        resp.vega_asset_id
+            .strip_prefix("0x")
+            .unwrap_or(&resp.vega_asset_id),
+        &mut vega_asset_id,
+    )?;
+
+    return Ok(Bundle {
+        function: "list_asset",
+        signature: "list_asset(address,bytes32,uint256,uint256,uint256,bytes)",
+        args: vec![
+            resp.asset_source.parse::<Address>()?.word(),
+            vega_asset_id,
+            parse_uint256(lifetime_limit)?,
+            parse_uint256(withdraw_threshold)?,
+            parse_uint256(&resp.nonce)?,
+        ],
+        signatures: decode_signatures(&resp.signatures)?,
+    });
+}
+
+/// Build the bundle to call `set_asset_limits` on the ERC20 bridge.
+pub fn set_asset_limits(resp: &GetErc20SetAssetLimitsBundleResponse) -> Result<Bundle, Error> {
+    return Ok(Bundle {
+        function: "set_asset_limits",
+        signature: "set_asset_limits(address,uint256,uint256,uint256,bytes)",
+        args: vec![
+            resp.asset_source.parse::<Address>()?.word(),
+            parse_uint256(&resp.lifetime_limit)?,
+            parse_uint256(&resp.threshold)?,
+            parse_uint256(&resp.nonce)?,
+        ],
+        signatures: decode_signatures(&resp.signatures)?,
+    });
+}
+
+impl Bundle {
+    /// The name of the bridge contract function.
+    pub fn function(&self) -> &str {
+        return self.function;
+    }
+
+    pub fn signatures(&self) -> &[u8] {
+        return &self.signatures;
+    }
+
+    /// The ABI encoded message signed by the validators,
+    /// all the arguments followed by the function name.
+    pub fn message(&self) -> Vec<u8> {
+        let mut tokens = self
+            .args
+            .iter()
+            .map(|w| Token::Word(*w))
+            .collect::<Vec<Token>>();
+        tokens.push(Token::Bytes(self.function.as_bytes()));
+        return encode(&tokens);
+    }
+
+    /// The hash actually signed by the validators, the message
+    /// is bound to the address of the bridge contract submitting it.
+    pub fn message_hash(&self, bridge: &Address) -> [u8; WORD_LEN] {
+        let message = self.message();
+        let packed = encode(&[Token::Bytes(&message), Token::Word(bridge.word())]);
+        return Keccak256::digest(packed).into();
+    }
+
+    /// The calldata of the bridge contract function call.
+    pub fn calldata(&self) -> Vec<u8> {
+        let mut tokens = self
+            .args
+            .iter()
+            .map(|w| Token::Word(*w))
+            .collect::<Vec<Token>>();
+        tokens.push(Token::Bytes(&self.signatures));
+
+        let mut out = selector(self.signature).to_vec();
+        out.extend_from_slice(&encode(&tokens));
+        return out;
+    }
+
+    /// Recover the addresses of all the signers of the bundle.
+    pub fn signers(&self, bridge: &Address) -> Result<Vec<Address>, Error> {
+        let hash = self.message_hash(bridge);
+        let mut out = vec![];
+        for sig in self.signatures.chunks(SIGNATURE_LEN) {
+            let signature = Signature::from_slice(&sig[..64])
+                .map_err(|_| BridgeError::InvalidSignatureBundle)?;
+            // the contract rejects malleable signatures
+            if signature.normalize_s().is_some() {
+                return Err(SigningError::InvalidSignature.into());
+            }
+            let v = match sig[64] {
+                v if v >= 27 => v - 27,
+                v => v,
+            };
+            let recovery_id =
+                RecoveryId::from_byte(v).ok_or(BridgeError::InvalidSignatureBundle)?;
+            // invalid signatures are ignored by the contract
+            if let Ok(key) = VerifyingKey::recover_from_prehash(&hash, &signature, recovery_id) {
+                let address = Address::from_public_key(&key.into());
+                if !out.contains(&address) {
+                    out.push(address);
+                }
+            }
+        }
+        return Ok(out);
+    }
+
+    /// Verify the bundle is signed by enough of the given signers,
+    /// the threshold is expressed in thousandths as in the multisig control contract.
+    pub fn verify(
+        &self,
+        bridge: &Address,
+        signers: &[Address],
+        threshold: u16,
+    ) -> Result<(), Error> {
+        let signed = self
+            .signers(bridge)?
+            .iter()
+            .filter(|a| signers.contains(a))
+            .count();
+
+        if signers.is_empty() || (signed * 1000) / signers.len() <= threshold as usize {
+            return Err(BridgeError::SignatureThresholdNotReached(signed, signers.len()).into());
+        }
+        return Ok(());
+    }
+}
+
+enum Token<'a> {
+    Word([u8; WORD_LEN]),
+    Bytes(&'a [u8]),
+}
+
+// ABI encoding of a tuple of static words and dynamic bytes / strings
+fn encode(tokens: &[Token]) -> Vec<u8> {
+    let head_len = WORD_LEN * tokens.len();
+    let mut head = vec![];
+    let mut tail = vec![];
+    for t in tokens.iter() {
+        match t {
+            Token::Word(w) => head.extend_from_slice(w),
+            Token::Bytes(b) => {
+                head.extend_from_slice(&uint_word((head_len + tail.len()) as u64));
+                tail.extend_from_slice(&uint_word(b.len() as u64));
+                tail.extend_from_slice(b);
+                let padding = (WORD_LEN - b.len() % WORD_LEN) % WORD_LEN;
+                tail.resize(tail.len() + padding, 0);
+            }
+        }
+    }
+    head.extend_from_slice(&tail);
+    return head;
+}
+
+fn selector(signature: &str) -> [u8; 4] {
+    let hash = Keccak256::digest(signature.as_bytes());
+    return [hash[0], hash[1], hash[2], hash[3]];
+}
+
+fn uint_word(v: u64) -> [u8; WORD_LEN] {
+    let mut out = [0u8; WORD_LEN];
+    out[24..].copy_from_slice(&v.to_be_bytes());
+    return out;
+}
+
+fn parse_uint256(s: &str) -> Result<[u8; WORD_LEN], Error> {
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(BridgeError::InvalidUint256(s.to_string()).into());
+    }
+
+    let mut out = [0u8; WORD_LEN];
+    for d in s.bytes() {
+        let mut carry = (d - b'0') as u32;
+        for b in out.iter_mut().rev() {
+            let v = (*b as u32) * 10 + carry;
+            *b = v as u8;
+            carry = v >> 8;
+        }
+        if carry != 0 {
+            return Err(BridgeError::InvalidUint256(s.to_string()).into());
+        }
+    }
+    return Ok(out);
+}
+
+fn decode_signatures(signatures: &str) -> Result<Vec<u8>, Error> {
+    let bytes = hex::decode(signatures.strip_prefix("0x").unwrap_or(signatures))?;
+    if bytes.is_empty() || bytes.len() % SIGNATURE_LEN != 0 {
+        return Err(BridgeError::InvalidSignatureBundle.into());
+    }
+    return Ok(bytes);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k256::ecdsa::SigningKey;
+
+    const BRIDGE: &str = "0x23872549cE10B40e31D6577e0A920088B0E0666a";
+
+    fn sign(keys: &[SigningKey], hash: &[u8]) -> String {
+        let mut out = vec![];
+        for k in keys.iter() {
+            let (sig, rec) = k.sign_prehash_recoverable(hash).unwrap();
+            out.extend_from_slice(&sig.to_bytes());
+            out.push(rec.to_byte() + 27);
+        }
+        return format!("0x{}", hex::encode(out));
+    }
+
+    fn key(i: u8) -> SigningKey {
+        return SigningKey::from_slice(&[i; 32]).unwrap();
+    }
+
+    fn address(k: &SigningKey) -> Address {
+        return Address::from_public_key(&k.verifying_key().into());
+    }
+
+    #[test]
+    fn test_address() {
+        let mut secret = [0u8; 32];
+        secret[31] = 1;
+        let k = SigningKey::from_slice(&secret).unwrap();
+        assert_eq!(
+            "0x7e5f4552091a69125d5dfcb7b8c2659029395bdf",
+            address(&k).to_string()
+        );
+        assert_eq!(
+            address(&k),
+            "0x7E5F4552091A69125d5DfCb7b8C2659029395Bdf"
+                .parse()
+                .unwrap()
+        );
+        assert!("0x7e5f".parse::<Address>().is_err());
+    }
+
+    #[test]
+    fn test_abi() {
+        assert_eq!(
+            [0xa9, 0x05, 0x9c, 0xbb],
+            selector("transfer(address,uint256)")
+        );
+
+        let mut expected = uint_word(0).to_vec();
+        expected[30] = 0x01;
+        expected[31] = 0x00;
+        assert_eq!(expected, parse_uint256("256").unwrap());
+        assert!(parse_uint256(
+            "115792089237316195423570985008687907853269984665640564039457584007913129639936"
+        )
+        .is_err());
+        assert!(parse_uint256("-1").is_err());
+
+        // abi.encode(uint256(1), bytes("abc"))
+        let out = encode(&[Token::Word(uint_word(1)), Token::Bytes(b"abc")]);
+        assert_eq!(out.len(), 4 * WORD_LEN);
+        assert_eq!(out[..32], uint_word(1));
+        assert_eq!(out[32..64], uint_word(64));
+        assert_eq!(out[64..96], uint_word(3));
+        assert_eq!(&out[96..99], b"abc");
+        assert!(out[99..].iter().all(|b| *b == 0));
+    }
+
+    #[test]
+    fn test_withdraw_asset() {
+        let bridge: Address = BRIDGE.parse().unwrap();
+        let validators = [key(1), key(2), key(3)];
+        let signers = validators.iter().map(address).collect::<Vec<Address>>();
+
+        let mut resp = GetErc20WithdrawalApprovalResponse {
+            asset_source: "0xb191d3d3b11f5a5c0e1ea2b0c6b7cc4af2b4cb96".into(),
+            amount: "1000000000000000000".into(),
+            nonce: "42".into(),
+            signatures: "".into(),
+            target_address: "0x7e5f4552091a69125d5dfcb7b8c2659029395bdf".into(),
+            creation: 1700000000,
+            source_chain_id: "1".into(),
+        };
+        assert!(matches!(
+            withdraw_asset(&resp),
+            Err(Error::Bridge(BridgeError::InvalidSignatureBundle))
+        ));
+
+        resp.signatures = format!("0x{}", "00".repeat(SIGNATURE_LEN));
+        let hash = withdraw_asset(&resp).unwrap().message_hash(&bridge);
+
+        // 2 out of 3 signers is above the 50% threshold
+        resp.signatures = sign(&validators[..2], &hash);
+        let bundle = withdraw_asset(&resp).unwrap();
+        assert_eq!(bundle.function(), "withdraw_asset");
+        assert_eq!(bundle.signers(&bridge).unwrap(), signers[..2].to_vec());
+        assert!(bundle.verify(&bridge, &signers, DEFAULT_THRESHOLD).is_ok());
+        assert!(matches!(
+            bundle.verify(&bridge, &signers, 700),
+            Err(Error::Bridge(BridgeError::SignatureThresholdNotReached(
+                2, 3
+            )))
+        ));
+
+        // signatures are bound to the bridge address
+        let other: Address = "0x7e5f4552091a69125d5dfcb7b8c2659029395bdf"
+            .parse()
+            .unwrap();
+        assert!(bundle.verify(&other, &signers, DEFAULT_THRESHOLD).is_err());
+
+        // duplicated signatures are only counted once
+        resp.signatures = sign(&[key(1), key(1)], &hash);
+        let bundle = withdraw_asset(&resp).unwrap();
+        assert!(bundle.verify(&bridge, &signers, DEFAULT_THRESHOLD).is_err());
+
+        let calldata = bundle.calldata();
+        assert_eq!(
+            calldata[..4],
+            selector("withdraw_asset(address,uint256,address,uint256,uint256,bytes)")
+        );
+        // 5 static args, the signatures offset, length and 2 signatures padded
+        assert_eq!(calldata.len(), 4 + 7 * WORD_LEN + 5 * WORD_LEN);
+        assert_eq!(calldata[4 + 5 * WORD_LEN..4 + 6 * WORD_LEN], uint_word(192));
+        assert_eq!(calldata[4 + 6 * WORD_LEN..4 + 7 * WORD_LEN], uint_word(130));
+    }
+
+    #[test]
+    fn test_asset_bundles() {
+        let bridge: Address = BRIDGE.parse().unwrap();
+        let validators = [key(1)];
+        let signers = validators.iter().map(address).collect::<Vec<Address>>();
+
+        let mut resp = GetErc20ListAssetBundleResponse {
+            asset_source: "0xb191d3d3b11f5a5c0e1ea2b0c6b7cc4af2b4cb96".into(),
+            vega_asset_id: "fc7fd956078fb1fc9db5c19b88f0874c4299b2a7639ad05a47a28c0aef291b55"
+                .into(),
+            nonce: "1".into(),
+            signatures: format!("0x{}", "00".repeat(SIGNATURE_LEN)),
+        };
+        let hash = list_asset(&resp, "100", "10")
+            .unwrap()
+            .message_hash(&bridge);
+        resp.signatures = sign(&validators, &hash);
+        let bundle = list_asset(&resp, "100", "10").unwrap();
+        assert!(bundle.verify(&bridge, &signers, DEFAULT_THRESHOLD).is_ok());
+        // the limits are part of the signed message
+        let bundle = list_asset(&resp, "200", "10").unwrap();
+        assert!(bundle.verify(&bridge, &signers, DEFAULT_THRESHOLD).is_err());
+
+        let mut resp = GetErc20SetAssetLimitsBundleResponse {
+            asset_source: "0xb191d3d3b11f5a5c0e1ea2b0c6b7cc4af2b4cb96".into(),
+            vega_asset_id: "fc7fd956078fb1fc9db5c19b88f0874c4299b2a7639ad05a47a28c0aef291b55"
+                .into(),
+            nonce: "2".into(),
+            lifetime_limit: "100".into(),
+            threshold: "10".into(),
+            signatures: format!("0x{}", "00".repeat(SIGNATURE_LEN)),
+        };
+        let hash = set_asset_limits(&resp).unwrap().message_hash(&bridge);
+        resp.signatures = sign(&validators, &hash);
+        let bundle = set_asset_limits(&resp).unwrap();
+        assert_eq!(bundle.function(), "set_asset_limits");
+        assert!(bundle.verify(&bridge, &signers, DEFAULT_THRESHOLD).is_ok());
+    }
+}
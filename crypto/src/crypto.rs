@@ -1,61 +1,201 @@
-use crate::errors::Error;
-use crate::slip10::{self, Node};
+use crate::derivation;
+use crate::errors::{Error, SigningError};
+use crate::slip10::{self, DerivationPath, Node};
 use bip39::{Language, Mnemonic};
-use ed25519_compact;
 use sha3::{Digest, Sha3_256};
+use std::fmt;
+use std::sync::Arc;
+use zeroize::Zeroizing;
 
-const MAGIC_NB: u32 = 1789;
-const ORGIN_INDEX: u32 = slip10::FIRST_HARDENED_INDEX + MAGIC_NB;
-
+/// An ed25519 signer, cloning a signer shares the secret key
+/// instead of copying it, the key is wiped from memory on drop.
 #[derive(Clone)]
 pub struct Signer {
-    secret: ed25519_compact::SecretKey,
+    secret: Arc<ed25519_compact::SecretKey>,
     pubkey: ed25519_compact::PublicKey,
+    path: Option<DerivationPath>,
 }
 
 impl Signer {
     pub fn from_secret_key(secret: &str) -> Result<Signer, Error> {
-        let bytes = hex::decode(secret)?;
-        let secret = ed25519_compact::SecretKey::from_slice(&*bytes)?;
+        let bytes = Zeroizing::new(hex::decode(secret)?);
+        let secret = ed25519_compact::SecretKey::from_slice(&bytes)?;
         return Ok(Signer {
             pubkey: secret.public_key(),
-            secret,
+            secret: Arc::new(secret),
+            path: None,
         });
     }
+
+    /// Build a signer from a raw 32 bytes ed25519 seed.
+    pub fn from_seed(seed: &[u8; 32]) -> Signer {
+        let kp = ed25519_compact::KeyPair::from_seed(ed25519_compact::Seed::new(*seed));
+        return Signer {
+            pubkey: kp.pk,
+            secret: Arc::new(kp.sk),
+            path: None,
+        };
+    }
+
     pub fn from_mnemonic(mnemonic: &str, derivations: usize) -> Result<Signer, Error> {
+        return Signer::from_mnemonic_with_passphrase(mnemonic, "", derivations);
+    }
+
+    /// Same as `from_mnemonic` but with a bip39 passphrase.
+    pub fn from_mnemonic_with_passphrase(
+        mnemonic: &str,
+        passphrase: &str,
+        derivations: usize,
+    ) -> Result<Signer, Error> {
+        let index = u32::try_from(derivations)
+            .map_err(|_| slip10::Error::IndexOutOfRange(derivations.to_string()))?;
+        return Signer::from_mnemonic_with_path(
+            mnemonic,
+            passphrase,
+            &derivation::key_path(index)?,
+        );
+    }
+
+    /// Derive the key of a mnemonic at an arbitrary hardened path, e.g: m/44'/0'/1'
+    pub fn from_mnemonic_with_path<P>(
+        mnemonic: &str,
+        passphrase: &str,
+        path: P,
+    ) -> Result<Signer, Error>
+    where
+        P: TryInto<DerivationPath>,
+        P::Error: Into<slip10::Error>,
+    {
+        let path = path.try_into().map_err(Into::into)?;
         let m = Mnemonic::parse_in(Language::English, mnemonic)?;
-        let node = Node::new_master_node(&m.to_seed(""))?
-            .derive(ORGIN_INDEX)?
-            .derive(slip10::FIRST_HARDENED_INDEX)?
-            .derive(slip10::FIRST_HARDENED_INDEX + derivations as u32)?;
-        let (p, s) = node.keypair();
+        return Signer::from_bip39_seed(&Zeroizing::new(m.to_seed(passphrase))[..], &path);
+    }
+
+    /// Derive the key at the given path from a bip39 seed.
+    pub(crate) fn from_bip39_seed(seed: &[u8], path: &DerivationPath) -> Result<Signer, Error> {
+        let (p, s) = Node::derive_for_path(path, seed)?.keypair();
         return Ok(Signer {
-            secret: s.bytes,
+            secret: Arc::new(s.bytes),
             pubkey: p.bytes,
+            path: Some(path.clone()),
         });
     }
 
+    /// Sign a message using the vega wallet scheme, the message is
+    /// first hashed with SHA3-256, then the hash is signed with ed25519.
+    /// This is the same scheme used by the wallet `sign_message` API.
     pub fn sign(&self, message: &[u8]) -> Vec<u8> {
-        let mut hasher = Sha3_256::new();
-        hasher.update(message);
-        let h = hasher.finalize().to_vec();
-        return self.secret.sign(h, None).to_vec();
+        return self.secret.sign(hash(message), None).to_vec();
+    }
+
+    /// Verify a signature produced by this signer.
+    pub fn verify(&self, message: &[u8], signature: &[u8]) -> Result<(), Error> {
+        return self.public_key().verify(message, signature);
     }
 
     pub fn pubkey(&self) -> &[u8] {
         return &*self.pubkey;
     }
 
-    pub fn secret(&self) -> &[u8] {
-        return &*self.secret;
+    /// The derivation path of the key, if it was derived from a mnemonic.
+    pub fn path(&self) -> Option<&DerivationPath> {
+        return self.path.as_ref();
+    }
+
+    pub fn public_key(&self) -> PublicKey {
+        return PublicKey { inner: self.pubkey };
+    }
+
+    pub(crate) fn secret(&self) -> &[u8] {
+        return &**self.secret;
+    }
+
+    /// The secret key hex encoded, only available with
+    /// the `secret-export` feature.
+    #[cfg(feature = "secret-export")]
+    pub fn secret_key(&self) -> Zeroizing<String> {
+        return Zeroizing::new(hex::encode(self.secret()));
     }
 }
 
+impl fmt::Debug for Signer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Signer")
+            .field("pubkey", &hex::encode(self.pubkey()))
+            .field("path", &self.path.as_ref().map(|p| p.to_string()))
+            .field("secret", &"<redacted>")
+            .finish()
+    }
+}
+
+/// A vega ed25519 public key, used to verify signatures
+/// produced by a `Signer` or a vega wallet.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct PublicKey {
+    inner: ed25519_compact::PublicKey,
+}
+
+impl PublicKey {
+    pub fn from_bytes(bytes: &[u8]) -> Result<PublicKey, Error> {
+        return Ok(PublicKey {
+            inner: ed25519_compact::PublicKey::from_slice(bytes)?,
+        });
+    }
+
+    /// Build a public key from its hex encoded form,
+    /// as returned by the vega wallet.
+    pub fn from_hex(pubkey: &str) -> Result<PublicKey, Error> {
+        return PublicKey::from_bytes(&hex::decode(pubkey)?);
+    }
+
+    /// Verify a signature over a message using the vega wallet scheme,
+    /// see `Signer::sign`. This is the same scheme used by the wallet
+    /// `verify_message` API.
+    pub fn verify(&self, message: &[u8], signature: &[u8]) -> Result<(), Error> {
+        let signature = ed25519_compact::Signature::from_slice(signature)?;
+        return self
+            .inner
+            .verify(hash(message), &signature)
+            .map_err(|_| SigningError::InvalidSignature.into());
+    }
+
+    /// Same as `verify` but with a hex encoded signature,
+    /// as found in vega transactions.
+    pub fn verify_hex(&self, message: &[u8], signature: &str) -> Result<(), Error> {
+        return self.verify(message, &hex::decode(signature)?);
+    }
+
+    pub fn bytes(&self) -> &[u8] {
+        return &*self.inner;
+    }
+
+    pub fn to_hex(&self) -> String {
+        return hex::encode(self.bytes());
+    }
+}
+
+impl fmt::Display for PublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_hex())
+    }
+}
+
+impl fmt::Debug for PublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "PublicKey({})", self.to_hex())
+    }
+}
+
+fn hash(message: &[u8]) -> Vec<u8> {
+    let mut hasher = Sha3_256::new();
+    hasher.update(message);
+    return hasher.finalize().to_vec();
+}
+
 #[cfg(test)]
 mod tests {
     // extern crate test;
     use super::*;
-    use hex;
 
     #[test]
     fn test_derive() {
@@ -69,10 +209,77 @@ mod tests {
         assert_eq!(pubkey, hex::encode(s.pubkey()));
         assert_eq!(secretkey, hex::encode(s.secret()));
 
-        let s2 = Signer::from_secret_key(&*hex::encode(s.secret()));
+        let s2 = Signer::from_secret_key(&hex::encode(s.secret()));
         assert!(s2.is_ok());
         let s2 = s2.unwrap();
         assert_eq!(pubkey, hex::encode(s2.pubkey()));
         assert_eq!(secretkey, hex::encode(s2.secret()));
     }
+
+    #[test]
+    fn test_derive_with_path() {
+        let mnemomnic = "another deal useless giraffe quarter glimpse blur civil reflect jelly quit endorse engage slender energy scare ask suggest toe spirit leaf seed unveil million";
+        let pubkey = "053a10c3e8aa92bcfae80b61845a23a4dfc88d94a31570e3c494da9f43b64ca0";
+        let secretkey = "e70da3716e54cfe4cbed58b584b85095bb4a8257a4b39ec91b491f29526430b6053a10c3e8aa92bcfae80b61845a23a4dfc88d94a31570e3c494da9f43b64ca0";
+
+        let s = Signer::from_mnemonic_with_path(mnemomnic, "", "m/1789'/0'/10'").unwrap();
+        assert_eq!(pubkey, hex::encode(s.pubkey()));
+        assert_eq!("m/1789'/0'/10'", s.path().unwrap().to_string());
+        assert_eq!(
+            s.path(),
+            Signer::from_mnemonic(mnemomnic, 10).unwrap().path()
+        );
+        assert!(Signer::from_mnemonic(mnemomnic, 1 << 31).is_err());
+
+        let s = Signer::from_mnemonic_with_path(mnemomnic, "", "m/44'/0'/10'").unwrap();
+        assert_ne!(pubkey, hex::encode(s.pubkey()));
+        assert!(Signer::from_mnemonic_with_path(mnemomnic, "", "m/44/0'").is_err());
+
+        let s = Signer::from_mnemonic_with_passphrase(mnemomnic, "passphrase", 10).unwrap();
+        assert_ne!(pubkey, hex::encode(s.pubkey()));
+
+        let mut seed = [0u8; 32];
+        seed.copy_from_slice(&hex::decode(&secretkey[..64]).unwrap());
+        let s = Signer::from_seed(&seed);
+        assert_eq!(pubkey, hex::encode(s.pubkey()));
+        assert_eq!(secretkey, hex::encode(s.secret()));
+        assert_eq!(None, s.path());
+    }
+
+    #[test]
+    fn test_sign_verify() {
+        let secretkey = "e70da3716e54cfe4cbed58b584b85095bb4a8257a4b39ec91b491f29526430b6053a10c3e8aa92bcfae80b61845a23a4dfc88d94a31570e3c494da9f43b64ca0";
+        let pubkey = "053a10c3e8aa92bcfae80b61845a23a4dfc88d94a31570e3c494da9f43b64ca0";
+        let message = b"proof of ownership challenge";
+
+        let s = Signer::from_secret_key(secretkey).unwrap();
+        let signature = s.sign(message);
+        assert!(s.verify(message, &signature).is_ok());
+
+        let pk = PublicKey::from_hex(pubkey).unwrap();
+        assert_eq!(pk, s.public_key());
+        assert_eq!(pubkey, pk.to_string());
+        assert!(pk.verify(message, &signature).is_ok());
+        assert!(pk.verify_hex(message, &hex::encode(&signature)).is_ok());
+
+        // the message is hashed with sha3-256 before being signed
+        let raw = ed25519_compact::PublicKey::from_slice(pk.bytes()).unwrap();
+        let sig = ed25519_compact::Signature::from_slice(&signature).unwrap();
+        assert!(raw.verify(hash(message), &sig).is_ok());
+        assert!(raw.verify(message, &sig).is_err());
+
+        assert!(matches!(
+            pk.verify(b"another message", &signature),
+            Err(Error::Signing(SigningError::InvalidSignature))
+        ));
+    }
+
+    #[test]
+    fn test_debug_redacted() {
+        let secretkey = "e70da3716e54cfe4cbed58b584b85095bb4a8257a4b39ec91b491f29526430b6053a10c3e8aa92bcfae80b61845a23a4dfc88d94a31570e3c494da9f43b64ca0";
+        let s = Signer::from_secret_key(secretkey).unwrap();
+        let dbg = format!("{:?}", s.clone());
+        assert!(dbg.contains("<redacted>"));
+        assert!(!dbg.contains(&secretkey[..64]));
+    }
 }
@@ -0,0 +1,166 @@
+use crate::{
+    build_signable_message,
+    crypto::PublicKey,
+    errors::{Error, SigningError},
+    SIGNATURE_ALGORITHM,
+};
+use prost::Message;
+use vega_protobufs::vega::commands::v1::{
+    input_data::Command, transaction::From as From_, DelayedTransactionsWrapper, InputData,
+    Transaction, TxVersion,
+};
+
+/// A transaction extracted from a `DelayedTransactionsWrapper`
+/// once its signature has been checked.
+#[derive(Clone, Debug)]
+pub struct DelayedTransaction {
+    /// The hex encoded public key of the sender
+    pub pubkey: String,
+    /// The decoded input data of the transaction
+    pub input_data: InputData,
+    /// The original transaction
+    pub transaction: Transaction,
+}
+
+/// Bundle already signed transactions into a `DelayedTransactionsWrapper`
+/// command to be executed at the given block height.
+pub fn wrap(txs: &[Transaction], height: u64) -> Command {
+    return Command::DelayedTransactionsWrapper(DelayedTransactionsWrapper {
+        transactions: txs.iter().map(|tx| tx.encode_to_vec()).collect(),
+        height,
+    });
+}
+
+/// Decode all the transactions bundled in a `DelayedTransactionsWrapper`,
+/// every transaction signature is verified against the given chain id.
+pub fn unwrap(
+    wrapper: &DelayedTransactionsWrapper,
+    chain_id: &str,
+) -> Result<Vec<DelayedTransaction>, Error> {
+    return wrapper
+        .transactions
+        .iter()
+        .enumerate()
+        .map(|(i, raw)| {
+            decode(raw, chain_id).map_err(|e| Error::InvalidDelayedTransaction(i, Box::new(e)))
+        })
+        .collect();
+}
+
+fn decode(raw: &[u8], chain_id: &str) -> Result<DelayedTransaction, Error> {
+    let transaction = Transaction::decode(raw)?;
+
+    if transaction.version != TxVersion::V3 as i32 {
+        return Err(SigningError::UnsupportedTxVersion(transaction.version).into());
+    }
+
+    let pubkey = match &transaction.from {
+        Some(From_::PubKey(pubkey)) => pubkey.clone(),
+        _ => return Err(SigningError::MissingPubKey.into()),
+    };
+
+    let signature = match &transaction.signature {
+        Some(signature) => signature,
+        None => return Err(SigningError::MissingSignature.into()),
+    };
+
+    if signature.algo != SIGNATURE_ALGORITHM {
+        return Err(SigningError::UnsupportedSignatureAlgorithm(signature.algo.clone()).into());
+    }
+
+    PublicKey::from_hex(&pubkey)?.verify_hex(
+        &build_signable_message(&transaction.input_data, chain_id),
+        &signature.value,
+    )?;
+
+    let input_data = InputData::decode(&*transaction.input_data)?;
+
+    return Ok(DelayedTransaction {
+        pubkey,
+        input_data,
+        transaction,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::Signer;
+    use vega_protobufs::vega::commands::v1::{Signature, VoteSubmission};
+
+    const CHAIN_ID: &str = "testnet";
+    const SECRET_KEY: &str = "e70da3716e54cfe4cbed58b584b85095bb4a8257a4b39ec91b491f29526430b6053a10c3e8aa92bcfae80b61845a23a4dfc88d94a31570e3c494da9f43b64ca0";
+
+    fn signed_vote(signer: &Signer, proposal_id: &str) -> Transaction {
+        let input_data = InputData {
+            nonce: 42,
+            block_height: 100,
+            command: Some(Command::VoteSubmission(VoteSubmission {
+                proposal_id: proposal_id.to_string(),
+                value: 1,
+            })),
+        }
+        .encode_to_vec();
+
+        let signature = signer.sign(&build_signable_message(&input_data, CHAIN_ID));
+
+        return Transaction {
+            from: Some(From_::PubKey(hex::encode(signer.pubkey()))),
+            version: TxVersion::V3.into(),
+            input_data,
+            signature: Some(Signature {
+                value: hex::encode(signature),
+                algo: SIGNATURE_ALGORITHM.into(),
+                version: 1,
+            }),
+            pow: None,
+        };
+    }
+
+    #[test]
+    fn test_wrap_unwrap() {
+        let signer = Signer::from_secret_key(SECRET_KEY).unwrap();
+        let txs = vec![signed_vote(&signer, "p1"), signed_vote(&signer, "p2")];
+
+        let wrapper = match wrap(&txs, 1000) {
+            Command::DelayedTransactionsWrapper(w) => w,
+            _ => panic!("expected a delayed transactions wrapper"),
+        };
+        assert_eq!(wrapper.height, 1000);
+        assert_eq!(wrapper.transactions.len(), 2);
+
+        let decoded = unwrap(&wrapper, CHAIN_ID).unwrap();
+        assert_eq!(decoded.len(), 2);
+        for (d, tx) in decoded.iter().zip(txs.iter()) {
+            assert_eq!(d.pubkey, hex::encode(signer.pubkey()));
+            assert_eq!(&d.transaction, tx);
+            assert_eq!(d.input_data.block_height, 100);
+        }
+    }
+
+    #[test]
+    fn test_unwrap_invalid_signature() {
+        let signer = Signer::from_secret_key(SECRET_KEY).unwrap();
+        let mut tampered = signed_vote(&signer, "p2");
+        tampered.input_data = signed_vote(&signer, "p3").input_data;
+        let txs = vec![signed_vote(&signer, "p1"), tampered];
+
+        let wrapper = match wrap(&txs, 1000) {
+            Command::DelayedTransactionsWrapper(w) => w,
+            _ => panic!("expected a delayed transactions wrapper"),
+        };
+
+        match unwrap(&wrapper, CHAIN_ID) {
+            Err(Error::InvalidDelayedTransaction(1, e)) => {
+                assert!(matches!(*e, Error::Signing(SigningError::InvalidSignature)))
+            }
+            _ => panic!("expected an invalid signature at index 1"),
+        }
+
+        // wrong chain id invalidates every transaction
+        assert!(matches!(
+            unwrap(&wrapper, "mainnet"),
+            Err(Error::InvalidDelayedTransaction(0, _))
+        ));
+    }
+}
@@ -0,0 +1,18 @@
+use crate::slip10::{self, DerivationPath, FIRST_HARDENED_INDEX};
+
+/// The vega purpose number, used as the first level of the derivation path.
+pub const MAGIC_NB: u32 = 1789;
+/// The hardened index of the vega purpose level, m/1789'.
+pub const ORIGIN_INDEX: u32 = FIRST_HARDENED_INDEX + MAGIC_NB;
+/// The hardened index of the second level of the vega wallet v2 path, m/1789'/0'.
+pub const ACCOUNT_INDEX: u32 = FIRST_HARDENED_INDEX;
+
+/// The derivation path of the key at the given index
+/// as used by the vega wallet, m/1789'/0'/index'
+pub fn key_path(index: u32) -> Result<DerivationPath, slip10::Error> {
+    return Ok(DerivationPath::new(vec![
+        ORIGIN_INDEX,
+        ACCOUNT_INDEX,
+        slip10::hardened_index(index)?,
+    ]));
+}
@@ -4,34 +4,352 @@ use std::error::Error as StdError;
 use std::fmt;
 use std::io;
 
+/// The error returned by all the vega_crypto APIs, new subsystems
+/// may be added over time so matching on it must include a wildcard.
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum Error {
+    Pow(PowError),
+    Signing(SigningError),
+    Transport(TransportError),
+    Derivation(DerivationError),
+    Keystore(KeystoreError),
+    Bridge(BridgeError),
+    /// One of the transactions of a delayed transactions wrapper is invalid
+    InvalidDelayedTransaction(usize, Box<Error>),
+    HexEncodingError(hex::FromHexError),
+    ProtobufDecodeError(prost::DecodeError),
+}
+
+/// Errors solving the spam proof of work.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PowError {
     InvalidDifficulty,
     EmptyTxId,
     InvalidBlockHash,
-    Bad,
-    PrivateKeyCredentialUnsupported,
+}
+
+/// Errors signing or verifying transactions and messages.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum SigningError {
+    InvalidSignature,
+    MissingSignature,
+    MissingPubKey,
+    UnsupportedSignatureAlgorithm(String),
+    UnsupportedTxVersion(i32),
+    Ed25519Error(ed25519_compact::Error),
+}
+
+/// Errors talking to a vega node.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum TransportError {
+    /// The connection to the node could not be established
+    Connect(tonic::transport::Error),
+    /// The node answered with an error status, boxed as it is far
+    /// larger than the other errors
+    Status(Box<tonic::Status>),
+}
+
+/// Errors deriving keys from a mnemonic.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum DerivationError {
     InvalidIndex,
-    InvalidSalt,
-    InvalidHexCypherText,
     Slip10Error(slip10::Error),
     Bip39Error(bip39::Error),
+}
+
+/// Errors reading or writing encrypted wallets.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum KeystoreError {
+    InvalidSalt,
+    InvalidCypherText,
+    InvalidPassphrase,
+    UnsupportedWalletVersion(u32),
+    WalletKeyMismatch(u32),
     IoError(io::Error),
-    GrpcTransportError(tonic::transport::Error),
-    GrpcError(tonic::Status),
-    HexEncodingError(hex::FromHexError),
-    Ed25519Error(ed25519_compact::Error),
+    JsonError(serde_json::Error),
+    Argon2Error(argon2::Error),
+}
+
+/// Errors building or verifying ethereum bridge bundles.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BridgeError {
+    InvalidEthereumAddress(String),
+    InvalidUint256(String),
+    InvalidSignatureBundle,
+    /// Number of valid signers, out of the total number of signers
+    SignatureThresholdNotReached(usize, usize),
+}
+
+impl Error {
+    pub fn desc(&self) -> String {
+        use Error::*;
+        match self {
+            Pow(e) => format!("proof of work error: {}", e.desc()),
+            Signing(e) => format!("signing error: {}", e.desc()),
+            Transport(e) => format!("transport error: {}", e.desc()),
+            Derivation(e) => format!("key derivation error: {}", e.desc()),
+            Keystore(e) => format!("keystore error: {}", e.desc()),
+            Bridge(e) => format!("bridge error: {}", e.desc()),
+            InvalidDelayedTransaction(i, e) => {
+                format!("invalid delayed transaction at index {}: {}", i, e.desc())
+            }
+            HexEncodingError(e) => format!("hex encoding error: {}", e),
+            ProtobufDecodeError(e) => format!("protobuf decode error: {}", e),
+        }
+    }
+
+    /// Whether the same call may succeed if retried later,
+    /// this is the case for connection errors, and for node statuses
+    /// reporting a transient failure.
+    pub fn is_retryable(&self) -> bool {
+        use tonic::Code::*;
+        return match self {
+            Error::Transport(TransportError::Connect(_)) => true,
+            Error::Transport(TransportError::Status(s)) => matches!(
+                s.code(),
+                Unavailable | DeadlineExceeded | ResourceExhausted | Aborted
+            ),
+            _ => false,
+        };
+    }
+
+    /// The status returned by the node, if any.
+    pub fn status(&self) -> Option<&tonic::Status> {
+        return match self {
+            Error::Transport(TransportError::Status(s)) => Some(s),
+            _ => None,
+        };
+    }
+}
+
+impl PowError {
+    pub fn desc(&self) -> String {
+        use PowError::*;
+        match self {
+            InvalidDifficulty => "invalid difficulty".into(),
+            EmptyTxId => "empty transaction id".into(),
+            InvalidBlockHash => "invalid block hash".into(),
+        }
+    }
+}
+
+impl SigningError {
+    pub fn desc(&self) -> String {
+        use SigningError::*;
+        match self {
+            InvalidSignature => "invalid signature".into(),
+            MissingSignature => "transaction is missing a signature".into(),
+            MissingPubKey => "transaction is missing a public key".into(),
+            UnsupportedSignatureAlgorithm(algo) => {
+                format!("unsupported signature algorithm: {}", algo)
+            }
+            UnsupportedTxVersion(v) => format!("unsupported transaction version: {}", v),
+            Ed25519Error(e) => format!("ed25519 error: {}", e),
+        }
+    }
+}
+
+impl TransportError {
+    pub fn desc(&self) -> String {
+        use TransportError::*;
+        match self {
+            Connect(e) => format!("GRPC transport error: {}", e),
+            Status(e) => format!("GRPC error: {}", e),
+        }
+    }
+}
+
+impl DerivationError {
+    pub fn desc(&self) -> String {
+        use DerivationError::*;
+        match self {
+            InvalidIndex => "index must be > 0".into(),
+            Slip10Error(e) => format!("slip10 error: {}", e.desc()),
+            Bip39Error(e) => format!("bip39 error: {}", e),
+        }
+    }
+}
+
+impl KeystoreError {
+    pub fn desc(&self) -> String {
+        use KeystoreError::*;
+        match self {
+            InvalidSalt => "encrypted data is too short to contain a salt".into(),
+            InvalidCypherText => "encrypted data is too short to contain a cypher text".into(),
+            InvalidPassphrase => "invalid passphrase or corrupted data".into(),
+            UnsupportedWalletVersion(v) => format!("unsupported wallet version: {}", v),
+            WalletKeyMismatch(i) => {
+                format!("key {} does not match the wallet recovery phrase", i)
+            }
+            IoError(e) => format!("IO error: {}", e),
+            JsonError(e) => format!("json error: {}", e),
+            Argon2Error(e) => format!("argon2 error: {}", e),
+        }
+    }
+}
+
+impl BridgeError {
+    pub fn desc(&self) -> String {
+        use BridgeError::*;
+        match self {
+            InvalidEthereumAddress(a) => format!("invalid ethereum address: {}", a),
+            InvalidUint256(v) => format!("invalid uint256: {}", v),
+            InvalidSignatureBundle => "signatures must be a list of 65 bytes signatures".into(),
+            SignatureThresholdNotReached(signed, total) => {
+                format!("only {} out of {} signers signed the bundle", signed, total)
+            }
+        }
+    }
 }
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "keystore error: {}", self.desc())
+        write!(f, "{}", self.desc())
+    }
+}
+
+impl fmt::Display for PowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.desc())
+    }
+}
+
+impl fmt::Display for SigningError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.desc())
+    }
+}
+
+impl fmt::Display for TransportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.desc())
+    }
+}
+
+impl fmt::Display for DerivationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.desc())
+    }
+}
+
+impl fmt::Display for KeystoreError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.desc())
+    }
+}
+
+impl fmt::Display for BridgeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.desc())
+    }
+}
+
+impl StdError for Error {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        use Error::*;
+        match self {
+            Pow(e) => Some(e),
+            Signing(e) => Some(e),
+            Transport(e) => Some(e),
+            Derivation(e) => Some(e),
+            Keystore(e) => Some(e),
+            Bridge(e) => Some(e),
+            InvalidDelayedTransaction(_, e) => Some(&**e),
+            HexEncodingError(e) => Some(e),
+            ProtobufDecodeError(e) => Some(e),
+        }
+    }
+}
+
+impl StdError for PowError {}
+
+impl StdError for SigningError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            SigningError::Ed25519Error(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl StdError for TransportError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            TransportError::Connect(e) => Some(e),
+            TransportError::Status(e) => Some(&**e),
+        }
+    }
+}
+
+impl StdError for DerivationError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            DerivationError::Slip10Error(e) => Some(e),
+            DerivationError::Bip39Error(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl StdError for KeystoreError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            KeystoreError::IoError(e) => Some(e),
+            KeystoreError::JsonError(e) => Some(e),
+            KeystoreError::Argon2Error(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl StdError for BridgeError {}
+
+impl From<PowError> for Error {
+    fn from(error: PowError) -> Self {
+        Error::Pow(error)
+    }
+}
+
+impl From<SigningError> for Error {
+    fn from(error: SigningError) -> Self {
+        Error::Signing(error)
+    }
+}
+
+impl From<TransportError> for Error {
+    fn from(error: TransportError) -> Self {
+        Error::Transport(error)
+    }
+}
+
+impl From<DerivationError> for Error {
+    fn from(error: DerivationError) -> Self {
+        Error::Derivation(error)
+    }
+}
+
+impl From<KeystoreError> for Error {
+    fn from(error: KeystoreError) -> Self {
+        Error::Keystore(error)
+    }
+}
+
+impl From<BridgeError> for Error {
+    fn from(error: BridgeError) -> Self {
+        Error::Bridge(error)
     }
 }
 
 impl From<ed25519_compact::Error> for Error {
     fn from(error: ed25519_compact::Error) -> Self {
-        Error::Ed25519Error(error)
+        Error::Signing(SigningError::Ed25519Error(error))
     }
 }
 
@@ -43,55 +361,91 @@ impl From<hex::FromHexError> for Error {
 
 impl From<io::Error> for Error {
     fn from(error: io::Error) -> Self {
-        Error::IoError(error)
+        Error::Keystore(KeystoreError::IoError(error))
     }
 }
 
 impl From<tonic::transport::Error> for Error {
     fn from(error: tonic::transport::Error) -> Self {
-        Error::GrpcTransportError(error)
+        Error::Transport(TransportError::Connect(error))
     }
 }
 
 impl From<tonic::Status> for Error {
     fn from(error: tonic::Status) -> Self {
-        Error::GrpcError(error)
+        Error::Transport(TransportError::Status(Box::new(error)))
+    }
+}
+
+impl From<prost::DecodeError> for Error {
+    fn from(error: prost::DecodeError) -> Self {
+        Error::ProtobufDecodeError(error)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(error: serde_json::Error) -> Self {
+        Error::Keystore(KeystoreError::JsonError(error))
+    }
+}
+
+impl From<argon2::Error> for Error {
+    fn from(error: argon2::Error) -> Self {
+        Error::Keystore(KeystoreError::Argon2Error(error))
     }
 }
 
 impl From<bip39::Error> for Error {
     fn from(error: bip39::Error) -> Self {
-        Error::Bip39Error(error)
+        Error::Derivation(DerivationError::Bip39Error(error))
     }
 }
 
 impl From<slip10::Error> for Error {
     fn from(error: slip10::Error) -> Self {
-        Error::Slip10Error(error)
+        Error::Derivation(DerivationError::Slip10Error(error))
     }
 }
 
-impl StdError for Error {}
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-impl Error {
-    pub fn desc(&self) -> String {
-        use Error::*;
-        match self {
-            PrivateKeyCredentialUnsupported => "raw private keys are not supported".into(),
-            Bad => "you're a bad boi!!!".into(),
-            InvalidIndex => "index must be > 0".into(),
-            InvalidSalt => "salt must be in valid hex format".into(),
-            InvalidHexCypherText => "cypher_test must be in valid hex format".into(),
-            Slip10Error(e) => format!("slip10 error: {}", e.desc()),
-            Bip39Error(e) => format!("bip39 error: {}", e),
-            IoError(e) => format!("IO error: {}", e),
-            GrpcTransportError(e) => format!("GRPC transport error: {}", e),
-            GrpcError(e) => format!("GRPC error: {}", e),
-            HexEncodingError(e) => format!("hex encoding error: {}", e),
-            Ed25519Error(e) => format!("ed25519 error: {}", e),
-            InvalidDifficulty => "invalid difficulty".into(),
-            EmptyTxId => "empty transaction id".into(),
-            InvalidBlockHash => "invalid block hash".into(),
-        }
+    #[test]
+    fn test_source_chain() {
+        let err = Error::from(slip10::Error::InvalidPath);
+        assert!(matches!(
+            err,
+            Error::Derivation(DerivationError::Slip10Error(slip10::Error::InvalidPath))
+        ));
+        let source = err.source().unwrap();
+        assert!(source.is::<DerivationError>());
+        assert!(source.source().unwrap().is::<slip10::Error>());
+        assert!(err
+            .to_string()
+            .starts_with("key derivation error: slip10 error:"));
+
+        let err = Error::InvalidDelayedTransaction(1, Box::new(SigningError::MissingPubKey.into()));
+        assert!(err.source().unwrap().is::<Error>());
+
+        let err = Error::from(argon2::Error::OutputTooShort);
+        let source = err.source().unwrap();
+        assert!(source.is::<KeystoreError>());
+        assert!(source.source().unwrap().is::<argon2::Error>());
+    }
+
+    #[test]
+    fn test_retryable() {
+        let err = Error::from(tonic::Status::unavailable("node is down"));
+        assert!(err.is_retryable());
+        assert_eq!(err.status().unwrap().code(), tonic::Code::Unavailable);
+        assert_eq!(err.status().unwrap().message(), "node is down");
+
+        let err = Error::from(tonic::Status::invalid_argument("bad tx"));
+        assert!(!err.is_retryable());
+        assert_eq!(err.status().unwrap().code(), tonic::Code::InvalidArgument);
+
+        assert!(!Error::from(PowError::InvalidBlockHash).is_retryable());
+        assert!(Error::from(PowError::InvalidBlockHash).status().is_none());
     }
 }
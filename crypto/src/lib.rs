@@ -1,8 +1,11 @@
-use crypto::Signer;
+#![allow(clippy::needless_return)]
+
+pub use crypto::{PublicKey, Signer};
 use errors::Error;
 use prost::Message;
 use rand::{thread_rng, Rng};
 use sha3::{Digest, Sha3_256};
+use slip10::DerivationPath;
 use vega_protobufs::vega::{
     api::v1::{
         core_service_client::CoreServiceClient, submit_raw_transaction_request,
@@ -14,10 +17,14 @@ use vega_protobufs::vega::{
     },
 };
 
+pub mod bridge;
 mod crypto;
+pub mod delayed;
+pub mod derivation;
 pub mod errors;
 pub mod pow;
 pub mod slip10;
+pub mod wallet;
 
 const CHAIN_ID_DELIMITER: char = 0 as char;
 const SIGNATURE_ALGORITHM: &str = "vega/ed25519";
@@ -28,7 +35,7 @@ pub struct Transact {
     client: CoreServiceClient<tonic::transport::Channel>,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub enum Credentials<'s> {
     /// An hex encoded private key
     PrivateKey(&'s str),
@@ -36,8 +43,46 @@ pub enum Credentials<'s> {
     /// this is to be compatible with the the vega wallet
     /// standard derivation
     Mnemonic(&'s str, usize),
+    /// Same as `Mnemonic` with a bip39 passphrase
+    MnemonicWithPassphrase(&'s str, &'s str, usize),
+    /// A mnemonic phrase, a bip39 passphrase (can be empty)
+    /// and an explicit hardened derivation path, e.g: m/1789'/0'/1'
+    MnemonicWithPath {
+        mnemonic: &'s str,
+        passphrase: &'s str,
+        path: &'s DerivationPath,
+    },
+    /// A raw 32 bytes ed25519 seed
+    Seed(&'s [u8; 32]),
+}
+
+impl<'s> std::fmt::Debug for Credentials<'s> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Credentials::PrivateKey(_) => f.write_str("PrivateKey(<redacted>)"),
+            Credentials::Mnemonic(_, derivations) => f
+                .debug_tuple("Mnemonic")
+                .field(&"<redacted>")
+                .field(derivations)
+                .finish(),
+            Credentials::MnemonicWithPassphrase(_, _, derivations) => f
+                .debug_tuple("MnemonicWithPassphrase")
+                .field(&"<redacted>")
+                .field(&"<redacted>")
+                .field(derivations)
+                .finish(),
+            Credentials::MnemonicWithPath { path, .. } => f
+                .debug_struct("MnemonicWithPath")
+                .field("mnemonic", &"<redacted>")
+                .field("passphrase", &"<redacted>")
+                .field("path", &path.to_string())
+                .finish(),
+            Credentials::Seed(_) => f.write_str("Seed(<redacted>)"),
+        }
+    }
 }
 
+#[allow(clippy::large_enum_variant)]
 #[derive(Clone, Debug)]
 pub enum Payload {
     Command(Command),
@@ -86,6 +131,15 @@ impl Transact {
             Credentials::Mnemonic(mnemonic, derivations) => {
                 crypto::Signer::from_mnemonic(mnemonic, derivations)?
             }
+            Credentials::MnemonicWithPassphrase(mnemonic, passphrase, derivations) => {
+                crypto::Signer::from_mnemonic_with_passphrase(mnemonic, passphrase, derivations)?
+            }
+            Credentials::MnemonicWithPath {
+                mnemonic,
+                passphrase,
+                path,
+            } => crypto::Signer::from_mnemonic_with_path(mnemonic, passphrase, path)?,
+            Credentials::Seed(seed) => crypto::Signer::from_seed(seed),
         };
 
         let client = CoreServiceClient::connect(node_address).await?;
@@ -198,19 +252,37 @@ impl Transact {
             gas_used: resp.get_ref().gas_used,
             gas_wanted: resp.get_ref().gas_wanted,
             error: err,
-            info: info,
-            log: log,
+            info,
+            log,
         });
     }
 
+    /// Sign an arbitrary message with the transact key,
+    /// using the same scheme as the vega wallet `sign_message` API.
+    pub fn sign_message(&self, message: &[u8]) -> Vec<u8> {
+        return self.signer.sign(message);
+    }
+
+    /// Verify a signature over an arbitrary message against the transact key.
+    pub fn verify_message(&self, message: &[u8], signature: &[u8]) -> Result<(), Error> {
+        return self.signer.verify(message, signature);
+    }
+
+    /// The derivation path of the key, if it was derived from a mnemonic
+    pub fn derivation_path(&self) -> Option<&DerivationPath> {
+        return self.signer.path();
+    }
+
     /// The public key hex encoded
     pub fn public_key(&self) -> String {
         return hex::encode(self.signer.pubkey());
     }
 
-    /// The secret key hex encoded
-    pub fn secret_key(&self) -> String {
-        return hex::encode(self.signer.secret());
+    /// The secret key hex encoded, only available with
+    /// the `secret-export` feature.
+    #[cfg(feature = "secret-export")]
+    pub fn secret_key(&self) -> zeroize::Zeroizing<String> {
+        return self.signer.secret_key();
     }
 }
 
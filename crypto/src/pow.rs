@@ -1,4 +1,4 @@
-use crate::Error;
+use crate::errors::PowError;
 use sha3::{Digest, Sha3_256};
 
 const BLOCK_HASH_LEN: usize = 64;
@@ -6,22 +6,22 @@ const MAX_DIFFICULTY: usize = 256;
 const MAX_NONCE: u64 = u64::MAX;
 const PREFIX: &str = "Vega_SPAM_PoW";
 
-pub fn solve(block_hash: &str, tx_id: &str, difficulty: usize) -> Result<(u64, Vec<u8>), Error> {
+pub fn solve(block_hash: &str, tx_id: &str, difficulty: usize) -> Result<(u64, Vec<u8>), PowError> {
     if difficulty > MAX_DIFFICULTY {
-        return Err(Error::InvalidDifficulty);
+        return Err(PowError::InvalidDifficulty);
     }
-    if tx_id.len() == 0 {
-        return Err(Error::EmptyTxId);
+    if tx_id.is_empty() {
+        return Err(PowError::EmptyTxId);
     }
     if block_hash.len() != BLOCK_HASH_LEN {
-        return Err(Error::InvalidBlockHash);
+        return Err(PowError::InvalidBlockHash);
     }
 
     let mut nonce: u64 = 0;
     let mut hash = vec![];
     let mut hasher = Sha3_256::new();
     while nonce < MAX_NONCE {
-        hasher.update(prepare_message(&block_hash, &tx_id, nonce));
+        hasher.update(prepare_message(block_hash, tx_id, nonce));
         hash = hasher.finalize_reset().to_vec();
         if count_leading_zeroes(&hash) >= difficulty {
             break;
@@ -1,17 +1,24 @@
 use ed25519_compact::{KeyPair, Seed};
 use hmac::{Hmac, Mac};
-use regex::Regex;
+use k256::elliptic_curve::{ff::PrimeField, sec1::ToEncodedPoint};
 use sha2::Sha512;
+use std::convert::Infallible;
 use std::error::Error as StdError;
 use std::fmt;
+use std::marker::PhantomData;
+use std::str::FromStr;
+use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
 
 pub const FIRST_HARDENED_INDEX: u32 = 0x80000000;
-const SEED_MODIFIER: &str = "ed25519 seed";
 
 #[derive(Debug)]
 pub enum Error {
     InvalidPath,
+    InvalidPathSegment(String),
+    IndexOutOfRange(String),
     NoPublicDerivation,
+    /// A node hash is not 64 bytes or does not hold a valid private key
+    InvalidNodeHash,
     HmacError(String),
 }
 
@@ -23,20 +30,238 @@ impl fmt::Display for Error {
 
 impl StdError for Error {}
 
+impl From<Infallible> for Error {
+    fn from(error: Infallible) -> Self {
+        match error {}
+    }
+}
+
+/// A BIP-0032 derivation path, e.g: m/1789'/0'/1'
+///
+/// Hardened segments can be written either with the `'`
+/// or the `h`/`H` suffix. The path is displayed with the `'`
+/// notation, or the `h` notation using the alternate flag (`{:#}`).
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct DerivationPath {
+    indices: Vec<u32>,
+}
+
+impl DerivationPath {
+    /// Build a path from raw child indices,
+    /// hardened indices must include `FIRST_HARDENED_INDEX`.
+    pub fn new(indices: Vec<u32>) -> DerivationPath {
+        return DerivationPath { indices };
+    }
+
+    /// Build a path from hardened indices only, e.g: [1789, 0, 1] for m/1789'/0'/1'
+    pub fn hardened(indices: &[u32]) -> Result<DerivationPath, Error> {
+        return Ok(DerivationPath {
+            indices: indices
+                .iter()
+                .map(|i| hardened_index(*i))
+                .collect::<Result<Vec<u32>, Error>>()?,
+        });
+    }
+
+    /// The raw child indices of the path.
+    pub fn indices(&self) -> &[u32] {
+        return &self.indices;
+    }
+
+    pub fn is_hardened(&self) -> bool {
+        return self.indices.iter().all(|i| *i >= FIRST_HARDENED_INDEX);
+    }
+}
+
+/// Convert an index to its hardened form, failing
+/// if the index is already in the hardened range.
+pub fn hardened_index(index: u32) -> Result<u32, Error> {
+    return match index.checked_add(FIRST_HARDENED_INDEX) {
+        Some(i) if index < FIRST_HARDENED_INDEX => Ok(i),
+        _ => Err(Error::IndexOutOfRange(index.to_string())),
+    };
+}
+
+impl FromStr for DerivationPath {
+    type Err = Error;
+
+    fn from_str(path: &str) -> Result<Self, Self::Err> {
+        let mut segments = path.split('/');
+        if segments.next() != Some("m") {
+            return Err(Error::InvalidPath);
+        }
+
+        let indices = segments
+            .map(|s| {
+                let (digits, hardened) = match s.strip_suffix(['\'', 'h', 'H']) {
+                    Some(digits) => (digits, true),
+                    None => (s, false),
+                };
+                if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+                    return Err(Error::InvalidPathSegment(s.to_string()));
+                }
+                let index = match digits.parse::<u32>() {
+                    Ok(index) if index < FIRST_HARDENED_INDEX => index,
+                    _ => return Err(Error::IndexOutOfRange(s.to_string())),
+                };
+                return match hardened {
+                    true => Ok(index + FIRST_HARDENED_INDEX),
+                    false => Ok(index),
+                };
+            })
+            .collect::<Result<Vec<u32>, Error>>()?;
+
+        return Ok(DerivationPath { indices });
+    }
+}
+
+impl TryFrom<&str> for DerivationPath {
+    type Error = Error;
+
+    fn try_from(path: &str) -> Result<Self, Self::Error> {
+        return path.parse();
+    }
+}
+
+impl TryFrom<&String> for DerivationPath {
+    type Error = Error;
+
+    fn try_from(path: &String) -> Result<Self, Self::Error> {
+        return path.parse();
+    }
+}
+
+impl From<&DerivationPath> for DerivationPath {
+    fn from(path: &DerivationPath) -> Self {
+        return path.clone();
+    }
+}
+
+impl fmt::Display for DerivationPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let suffix = if f.alternate() { "h" } else { "'" };
+        write!(f, "m")?;
+        for i in self.indices.iter() {
+            match *i >= FIRST_HARDENED_INDEX {
+                true => write!(f, "/{}{}", i - FIRST_HARDENED_INDEX, suffix)?,
+                false => write!(f, "/{}", i)?,
+            }
+        }
+        return Ok(());
+    }
+}
+
 impl Error {
     pub fn desc(&self) -> String {
         use Error::*;
         match self {
-            InvalidPath => "invalid derivation path".into(),
+            InvalidPath => "invalid derivation path, must start with m".into(),
+            InvalidPathSegment(s) => format!("invalid derivation path segment: {}", s),
+            IndexOutOfRange(s) => format!(
+                "derivation path index out of range, must be < {}: {}",
+                FIRST_HARDENED_INDEX, s
+            ),
             NoPublicDerivation => "no public derivation allowed with ed25519".into(),
+            InvalidNodeHash => {
+                "invalid node hash, must be a valid private key and chain code".into()
+            }
             HmacError(e) => format!("hmac error: {}", e),
         }
     }
 }
 
-pub struct Node {
-    pub chain_code: Vec<u8>,
-    pub key: Vec<u8>,
+/// An elliptic curve supported by the SLIP-0010 derivation scheme.
+pub trait Curve {
+    /// The HMAC key used to generate the master node from the seed.
+    const SEED_MODIFIER: &'static str;
+    /// Whether non-hardened derivation is supported by the curve.
+    const PUBLIC_DERIVATION: bool;
+
+    /// The serialized public key of a private key, as defined by SLIP-0010.
+    fn public_key(key: &[u8]) -> Vec<u8>;
+
+    /// Compute a child private key from the left half of the HMAC output
+    /// and the parent private key, or the master private key if there is no parent.
+    /// Returns None if the key is invalid and the derivation must be retried.
+    fn child_key(il: &[u8], parent: Option<&[u8]>) -> Option<Vec<u8>>;
+}
+
+/// The ed25519 curve, used by vega keys.
+pub struct Ed25519;
+
+impl Curve for Ed25519 {
+    const SEED_MODIFIER: &'static str = "ed25519 seed";
+    const PUBLIC_DERIVATION: bool = false;
+
+    // https://github.com/satoshilabs/slips/blob/master/slip-0010/testvectors.py#L64
+    fn public_key(key: &[u8]) -> Vec<u8> {
+        let seed = Seed::from_slice(key).unwrap();
+        let kp = KeyPair::from_seed(seed);
+        seed.wipe();
+        let mut out = vec![0x00; 1];
+        out.extend_from_slice(&*kp.pk);
+        return out;
+    }
+
+    fn child_key(il: &[u8], _: Option<&[u8]>) -> Option<Vec<u8>> {
+        return Some(il.to_vec());
+    }
+}
+
+/// The secp256k1 curve, used by ethereum keys.
+pub struct Secp256k1;
+
+impl Curve for Secp256k1 {
+    const SEED_MODIFIER: &'static str = "Bitcoin seed";
+    const PUBLIC_DERIVATION: bool = true;
+
+    fn public_key(key: &[u8]) -> Vec<u8> {
+        // the keys of the nodes are checked by `child_key` when built
+        let sk = k256::SecretKey::from_slice(key).unwrap();
+        return sk.public_key().to_encoded_point(true).as_bytes().to_vec();
+    }
+
+    fn child_key(il: &[u8], parent: Option<&[u8]>) -> Option<Vec<u8>> {
+        let il = Option::<k256::Scalar>::from(k256::Scalar::from_repr(
+            k256::FieldBytes::clone_from_slice(il),
+        ))?;
+        let key = match parent {
+            Some(parent) => {
+                il + Option::<k256::Scalar>::from(k256::Scalar::from_repr(
+                    k256::FieldBytes::clone_from_slice(parent),
+                ))?
+            }
+            None => il,
+        };
+        if bool::from(key.is_zero()) {
+            return None;
+        }
+        return Some(key.to_bytes().to_vec());
+    }
+}
+
+/// A node of the derivation tree, the key and chain code
+/// are wiped from memory once the node is dropped.
+#[derive(Zeroize, ZeroizeOnDrop)]
+pub struct CurveNode<C: Curve> {
+    chain_code: Vec<u8>,
+    key: Vec<u8>,
+    #[zeroize(skip)]
+    curve: PhantomData<C>,
+}
+
+/// A node of the ed25519 derivation tree.
+pub type Node = CurveNode<Ed25519>;
+/// A node of the secp256k1 derivation tree.
+pub type Secp256k1Node = CurveNode<Secp256k1>;
+
+impl<C: Curve> fmt::Debug for CurveNode<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Node")
+            .field("chain_code", &"<redacted>")
+            .field("key", &"<redacted>")
+            .finish()
+    }
 }
 
 pub struct PublicKey {
@@ -49,6 +274,8 @@ impl PublicKey {
     }
 }
 
+/// An ed25519 private key, ed25519_compact wipes
+/// the key bytes from memory on drop.
 pub struct PrivateKey {
     pub bytes: ed25519_compact::SecretKey,
 }
@@ -58,98 +285,120 @@ impl PrivateKey {
         &*self.bytes
     }
 
-    pub fn seed(&self) -> Vec<u8> {
-        return (*self.bytes.seed()).into();
+    pub fn seed(&self) -> Zeroizing<Vec<u8>> {
+        return Zeroizing::new((*self.bytes.seed()).into());
     }
 }
 
-impl Node {
-    pub fn derive_for_path(path: &str, seed: &[u8]) -> Result<Node, Error> {
-        if !is_valid_path(path) {
-            return Err(Error::InvalidPath);
-        }
+impl fmt::Debug for PrivateKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("PrivateKey(<redacted>)")
+    }
+}
 
-        let mut node = Node::new_master_node(seed)?;
-        for s in path
-            .split("/")
-            .collect::<Vec<&str>>()
-            .splice(1.., Vec::new())
-        {
-            // try to convert to uint + check overflow
-            match s.trim_end_matches("'").parse::<u32>() {
-                Ok(val) => {
-                    let i = val + FIRST_HARDENED_INDEX;
-                    node = node.derive(i)?;
-                }
-                Err(_) => return Err(Error::InvalidPath),
-            }
+impl<C: Curve> CurveNode<C> {
+    /// Derive the node at the given path, either a `DerivationPath`
+    /// or its string representation.
+    pub fn derive_for_path<P>(path: P, seed: &[u8]) -> Result<Self, Error>
+    where
+        P: TryInto<DerivationPath>,
+        P::Error: Into<Error>,
+    {
+        let path = path.try_into().map_err(Into::into)?;
+
+        let mut node = Self::new_master_node(seed)?;
+        for i in path.indices() {
+            node = node.derive(*i)?;
         }
 
         return Ok(node);
     }
 
     // NewMasterNode generates a new master key from seed.
-    pub fn new_master_node(seed: &[u8]) -> Result<Node, Error> {
-        let mut hasher = match Hmac::<Sha512>::new_from_slice(SEED_MODIFIER.as_bytes()) {
-            Ok(hasher) => hasher,
-            Err(e) => return Err(Error::HmacError(e.to_string())),
-        };
+    pub fn new_master_node(seed: &[u8]) -> Result<Self, Error> {
+        let mut data = Zeroizing::new(seed.to_vec());
+        loop {
+            let hash = hmac(C::SEED_MODIFIER.as_bytes(), &data)?;
+            if let Some(key) = C::child_key(&hash[..32], None) {
+                return Ok(Self::new(key, &hash[32..]));
+            }
+            // invalid key, retry with the hash as the seed
+            data = hash;
+        }
+    }
 
-        hasher.update(seed);
-        return Ok(Node::from_hash(&(hasher.finalize().into_bytes())));
+    /// Rebuild a node from its `hash`, the private key followed by the chain code.
+    pub fn from_hash(hash: &[u8]) -> Result<Self, Error> {
+        if hash.len() != 64 {
+            return Err(Error::InvalidNodeHash);
+        }
+        let key = C::child_key(&hash[..32], None).ok_or(Error::InvalidNodeHash)?;
+        return Ok(Self::new(key, &hash[32..]));
     }
 
-    pub fn from_hash(hash: &[u8]) -> Node {
-        let mut key = vec![0; 32];
-        key.clone_from_slice(&hash[..32]);
-        let mut chain_code = vec![0; 32];
-        chain_code.clone_from_slice(&hash[32..]);
-        return Node { chain_code, key };
+    fn new(key: Vec<u8>, chain_code: &[u8]) -> Self {
+        return CurveNode {
+            chain_code: chain_code.to_vec(),
+            key,
+            curve: PhantomData,
+        };
     }
 
-    pub fn hash(&self) -> Vec<u8> {
-        let mut out = vec![];
+    pub fn hash(&self) -> Zeroizing<Vec<u8>> {
+        let mut out = Zeroizing::new(vec![]);
         out.extend_from_slice(&self.key);
         out.extend_from_slice(&self.chain_code);
         return out;
     }
 
-    pub fn derive(&self, i: u32) -> Result<Node, Error> {
+    pub fn chain_code(&self) -> &[u8] {
+        return &self.chain_code;
+    }
+
+    pub fn derive(&self, i: u32) -> Result<Self, Error> {
         // no public derivation for ed25519
-        if i < FIRST_HARDENED_INDEX {
+        if i < FIRST_HARDENED_INDEX && !C::PUBLIC_DERIVATION {
             return Err(Error::NoPublicDerivation);
         }
 
-        let mut bytes = vec![0x0; 1];
-        bytes.extend_from_slice(&self.key);
+        let mut bytes = Zeroizing::new(vec![]);
+        if i >= FIRST_HARDENED_INDEX {
+            bytes.push(0x0);
+            bytes.extend_from_slice(&self.key);
+        } else {
+            bytes.extend_from_slice(&C::public_key(&self.key));
+        }
         bytes.extend_from_slice(&i.to_be_bytes());
 
-        let mut hasher = match Hmac::<Sha512>::new_from_slice(&self.chain_code) {
-            Ok(hasher) => hasher,
-            Err(e) => return Err(Error::HmacError(e.to_string())),
-        };
-
-        hasher.update(&bytes);
-        return Ok(Node::from_hash(&(hasher.finalize().into_bytes())));
-    }
-
-    pub fn keypair(&self) -> (PublicKey, PrivateKey) {
-        let seed = Seed::from_slice(&self.key).unwrap();
-        let kp = KeyPair::from_seed(seed);
-        return (PublicKey { bytes: kp.pk }, PrivateKey { bytes: kp.sk });
+        loop {
+            let hash = hmac(&self.chain_code, &bytes)?;
+            if let Some(key) = C::child_key(&hash[..32], Some(&self.key)) {
+                return Ok(Self::new(key, &hash[32..]));
+            }
+            // invalid key, retry with 0x01 || IR || i
+            bytes = Zeroizing::new(vec![0x1]);
+            bytes.extend_from_slice(&hash[32..]);
+            bytes.extend_from_slice(&i.to_be_bytes());
+        }
     }
 
     // https://github.com/satoshilabs/slips/blob/master/slip-0010/testvectors.py#L64
     pub fn public_key_with_prefix(&self) -> Vec<u8> {
-        let (pkey, _) = self.keypair();
-        let mut out = vec![0x00; 1];
-        out.extend_from_slice(&pkey.bytes());
-        return out;
+        return C::public_key(&self.key);
     }
 
     pub fn raw_seed(&self) -> &[u8] {
         return &self.key;
     }
+}
+
+impl CurveNode<Ed25519> {
+    pub fn keypair(&self) -> (PublicKey, PrivateKey) {
+        let seed = Seed::from_slice(&self.key).unwrap();
+        let kp = KeyPair::from_seed(seed);
+        seed.wipe();
+        return (PublicKey { bytes: kp.pk }, PrivateKey { bytes: kp.sk });
+    }
 
     // PrivateKey returns private key seed bytes
     pub fn private_key(&self) -> PrivateKey {
@@ -158,24 +407,28 @@ impl Node {
     }
 }
 
-fn is_valid_path(path: &str) -> bool {
-    let re = Regex::new(r"^m(/[0-9]+')*$").unwrap();
-    if !re.is_match(path) {
-        return false;
+impl CurveNode<Secp256k1> {
+    pub fn secret_key(&self) -> k256::SecretKey {
+        // the key is checked by `Secp256k1::child_key` when the node is built
+        return k256::SecretKey::from_slice(&self.key).unwrap();
     }
 
-    for s in path
-        .split("/")
-        .collect::<Vec<&str>>()
-        .splice(1.., Vec::new())
-    {
-        // try to convert to uint + check overflow
-        if s.trim_end_matches("'").parse::<u32>().is_err() {
-            return false;
-        }
+    pub fn public_key(&self) -> k256::PublicKey {
+        return self.secret_key().public_key();
     }
+}
 
-    return true;
+fn hmac(key: &[u8], data: &[u8]) -> Result<Zeroizing<Vec<u8>>, Error> {
+    let mut hasher = match Hmac::<Sha512>::new_from_slice(key) {
+        Ok(hasher) => hasher,
+        Err(e) => return Err(Error::HmacError(e.to_string())),
+    };
+
+    hasher.update(data);
+    let mut hash = hasher.finalize().into_bytes();
+    let out = Zeroizing::new(hash.to_vec());
+    hash.as_mut_slice().zeroize();
+    return Ok(out);
 }
 
 #[cfg(test)]
@@ -199,7 +452,7 @@ mod tests {
     fn test_derive_for_path() {
         let seed = "000102030405060708090a0b0c0d0e0f";
         let seed_bytes = hex::decode(seed).unwrap();
-        let tests = vec![
+        let tests = [
             Test {
                 args: Args {
                     path: "m",
@@ -284,9 +537,262 @@ mod tests {
         }
     }
 
+    const SEED_1: &str = "000102030405060708090a0b0c0d0e0f";
+    const SEED_2: &str = "fffcf9f6f3f0edeae7e4e1dedbd8d5d2cfccc9c6c3c0bdbab7b4b1aeaba8a5a29f9c999693908d8a8784817e7b7875726f6c696663605d5a5754514e4b484542";
+
+    // (path, chain code, private key, public key)
+    type Vector<'l> = (&'l str, &'l str, &'l str, &'l str);
+
+    fn check_vectors<C: Curve>(seed: &str, vectors: &[Vector]) {
+        let seed_bytes = hex::decode(seed).unwrap();
+        for (path, chain_code, private, public) in vectors.iter() {
+            let node = CurveNode::<C>::derive_for_path(*path, &seed_bytes).unwrap();
+            assert_eq!(*chain_code, hex::encode(node.chain_code()), "{}", path);
+            assert_eq!(*private, hex::encode(node.raw_seed()), "{}", path);
+            assert_eq!(
+                *public,
+                hex::encode(node.public_key_with_prefix()),
+                "{}",
+                path
+            );
+        }
+    }
+
+    // https://github.com/satoshilabs/slips/blob/master/slip-0010.md#test-vector-2-for-ed25519
+    #[test]
+    fn test_ed25519_vector_2() {
+        check_vectors::<Ed25519>(
+            SEED_2,
+            &[
+                (
+                    "m",
+                    "ef70a74db9c3a5af931b5fe73ed8e1a53464133654fd55e7a66f8570b8e33c3b",
+                    "171cb88b1b3c1db25add599712e36245d75bc65a1a5c9e18d76f9f2b1eab4012",
+                    "008fe9693f8fa62a4305a140b9764c5ee01e455963744fe18204b4fb948249308a",
+                ),
+                (
+                    "m/0'",
+                    "0b78a3226f915c082bf118f83618a618ab6dec793752624cbeb622acb562862d",
+                    "1559eb2bbec5790b0c65d8693e4d0875b1747f4970ae8b650486ed7470845635",
+                    "0086fab68dcb57aa196c77c5f264f215a112c22a912c10d123b0d03c3c28ef1037",
+                ),
+                (
+                    "m/0'/2147483647'",
+                    "138f0b2551bcafeca6ff2aa88ba8ed0ed8de070841f0c4ef0165df8181eaad7f",
+                    "ea4f5bfe8694d8bb74b7b59404632fd5968b774ed545e810de9c32a4fb4192f4",
+                    "005ba3b9ac6e90e83effcd25ac4e58a1365a9e35a3d3ae5eb07b9e4d90bcf7506d",
+                ),
+                (
+                    "m/0'/2147483647'/1'",
+                    "73bd9fff1cfbde33a1b846c27085f711c0fe2d66fd32e139d3ebc28e5a4a6b90",
+                    "3757c7577170179c7868353ada796c839135b3d30554bbb74a4b1e4a5a58505c",
+                    "002e66aa57069c86cc18249aecf5cb5a9cebbfd6fadeab056254763874a9352b45",
+                ),
+                (
+                    "m/0'/2147483647'/1'/2147483646'",
+                    "0902fe8a29f9140480a00ef244bd183e8a13288e4412d8389d140aac1794825a",
+                    "5837736c89570de861ebc173b1086da4f505d4adb387c6a1b1342d5e4ac9ec72",
+                    "00e33c0f7d81d843c572275f287498e8d408654fdf0d1e065b84e2e6f157aab09b",
+                ),
+                (
+                    "m/0'/2147483647'/1'/2147483646'/2'",
+                    "5d70af781f3a37b829f0d060924d5e960bdc02e85423494afc0b1a41bbe196d4",
+                    "551d333177df541ad876a60ea71f00447931c0a9da16f227c11ea080d7391b8d",
+                    "0047150c75db263559a70d5778bf36abbab30fb061ad69f69ece61a72b0cfa4fc0",
+                ),
+            ],
+        );
+    }
+
+    // https://github.com/satoshilabs/slips/blob/master/slip-0010.md#test-vector-1-for-secp256k1
+    #[test]
+    fn test_secp256k1_vector_1() {
+        check_vectors::<Secp256k1>(
+            SEED_1,
+            &[
+                (
+                    "m",
+                    "873dff81c02f525623fd1fe5167eac3a55a049de3d314bb42ee227ffed37d508",
+                    "e8f32e723decf4051aefac8e2c93c9c5b214313817cdb01a1494b917c8436b35",
+                    "0339a36013301597daef41fbe593a02cc513d0b55527ec2df1050e2e8ff49c85c2",
+                ),
+                (
+                    "m/0'",
+                    "47fdacbd0f1097043b78c63c20c34ef4ed9a111d980047ad16282c7ae6236141",
+                    "edb2e14f9ee77d26dd93b4ecede8d16ed408ce149b6cd80b0715a2d911a0afea",
+                    "035a784662a4a20a65bf6aab9ae98a6c068a81c52e4b032c0fb5400c706cfccc56",
+                ),
+                (
+                    "m/0'/1",
+                    "2a7857631386ba23dacac34180dd1983734e444fdbf774041578e9b6adb37c19",
+                    "3c6cb8d0f6a264c91ea8b5030fadaa8e538b020f0a387421a12de9319dc93368",
+                    "03501e454bf00751f24b1b489aa925215d66af2234e3891c3b21a52bedb3cd711c",
+                ),
+                (
+                    "m/0'/1/2'",
+                    "04466b9cc8e161e966409ca52986c584f07e9dc81f735db683c3ff6ec7b1503f",
+                    "cbce0d719ecf7431d88e6a89fa1483e02e35092af60c042b1df2ff59fa424dca",
+                    "0357bfe1e341d01c69fe5654309956cbea516822fba8a601743a012a7896ee8dc2",
+                ),
+                (
+                    "m/0'/1/2'/2",
+                    "cfb71883f01676f587d023cc53a35bc7f88f724b1f8c2892ac1275ac822a3edd",
+                    "0f479245fb19a38a1954c5c7c0ebab2f9bdfd96a17563ef28a6a4b1a2a764ef4",
+                    "02e8445082a72f29b75ca48748a914df60622a609cacfce8ed0e35804560741d29",
+                ),
+                (
+                    "m/0'/1/2'/2/1000000000",
+                    "c783e67b921d2beb8f6b389cc646d7263b4145701dadd2161548a8b078e65e9e",
+                    "471b76e389e528d6de6d816857e012c5455051cad6660850e58372a6c3e6e7c8",
+                    "022a471424da5e657499d1ff51cb43c47481a03b1e77f951fe64cec9f5a48f7011",
+                ),
+            ],
+        );
+    }
+
+    // https://github.com/satoshilabs/slips/blob/master/slip-0010.md#test-vector-2-for-secp256k1
+    #[test]
+    fn test_secp256k1_vector_2() {
+        check_vectors::<Secp256k1>(
+            SEED_2,
+            &[
+                (
+                    "m",
+                    "60499f801b896d83179a4374aeb7822aaeaceaa0db1f85ee3e904c4defbd9689",
+                    "4b03d6fc340455b363f51020ad3ecca4f0850280cf436c70c727923f6db46c3e",
+                    "03cbcaa9c98c877a26977d00825c956a238e8dddfbd322cce4f74b0b5bd6ace4a7",
+                ),
+                (
+                    "m/0",
+                    "f0909affaa7ee7abe5dd4e100598d4dc53cd709d5a5c2cac40e7412f232f7c9c",
+                    "abe74a98f6c7eabee0428f53798f0ab8aa1bd37873999041703c742f15ac7e1e",
+                    "02fc9e5af0ac8d9b3cecfe2a888e2117ba3d089d8585886c9c826b6b22a98d12ea",
+                ),
+                (
+                    "m/0/2147483647'",
+                    "be17a268474a6bb9c61e1d720cf6215e2a88c5406c4aee7b38547f585c9a37d9",
+                    "877c779ad9687164e9c2f4f0f4ff0340814392330693ce95a58fe18fd52e6e93",
+                    "03c01e7425647bdefa82b12d9bad5e3e6865bee0502694b94ca58b666abc0a5c3b",
+                ),
+                (
+                    "m/0/2147483647'/1",
+                    "f366f48f1ea9f2d1d3fe958c95ca84ea18e4c4ddb9366c336c927eb246fb38cb",
+                    "704addf544a06e5ee4bea37098463c23613da32020d604506da8c0518e1da4b7",
+                    "03a7d1d856deb74c508e05031f9895dab54626251b3806e16b4bd12e781a7df5b9",
+                ),
+                (
+                    "m/0/2147483647'/1/2147483646'",
+                    "637807030d55d01f9a0cb3a7839515d796bd07706386a6eddf06cc29a65a0e29",
+                    "f1c7c871a54a804afe328b4c83a1c33b8e5ff48f5087273f04efa83b247d6a2d",
+                    "02d2b36900396c9282fa14628566582f206a5dd0bcc8d5e892611806cafb0301f0",
+                ),
+                (
+                    "m/0/2147483647'/1/2147483646'/2",
+                    "9452b549be8cea3ecb7a84bec10dcfd94afe4d129ebfd3b3cb58eedf394ed271",
+                    "bb7d39bdb83ecf58f2fd82b6d918341cbef428661ef01ab97c28a4842125ac23",
+                    "024d902e1a2fc7a8755ab5b694c575fce742c48d9ff192e63df5193e4c7afe1f9c",
+                ),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_parse_path() {
+        let path = "m/0'/2147483647'/1'/2147483646'/2'"
+            .parse::<DerivationPath>()
+            .unwrap();
+        assert_eq!("m/0'/2147483647'/1'/2147483646'/2'", path.to_string());
+        assert_eq!("m/0h/2147483647h/1h/2147483646h/2h", format!("{:#}", path));
+        assert!(path.is_hardened());
+
+        let path = "m/1789h/0H/1'/2".parse::<DerivationPath>().unwrap();
+        assert_eq!("m/1789'/0'/1'/2", path.to_string());
+        assert_eq!(
+            path.indices(),
+            &[
+                FIRST_HARDENED_INDEX + 1789,
+                FIRST_HARDENED_INDEX,
+                FIRST_HARDENED_INDEX + 1,
+                2
+            ]
+        );
+        assert!(!path.is_hardened());
+
+        assert_eq!(
+            DerivationPath::hardened(&[1789, 0, 1]).unwrap(),
+            "m/1789'/0'/1'".parse().unwrap()
+        );
+        assert_eq!(DerivationPath::default(), "m".parse().unwrap());
+    }
+
+    #[test]
+    fn test_parse_path_errors() {
+        assert!(matches!(
+            "m/2147483648'".parse::<DerivationPath>(),
+            Err(Error::IndexOutOfRange(s)) if s == "2147483648'"
+        ));
+        assert!(matches!(
+            "m/4294967296".parse::<DerivationPath>(),
+            Err(Error::IndexOutOfRange(_))
+        ));
+        assert!(matches!(
+            "0'/1'".parse::<DerivationPath>(),
+            Err(Error::InvalidPath)
+        ));
+        assert!(matches!(
+            "".parse::<DerivationPath>(),
+            Err(Error::InvalidPath)
+        ));
+        for bad in ["m/", "m/1x", "m/'", "m/-1'", "m/+1", "m/1''", "m//1'"] {
+            assert!(
+                matches!(
+                    bad.parse::<DerivationPath>(),
+                    Err(Error::InvalidPathSegment(_))
+                ),
+                "{}",
+                bad
+            );
+        }
+        assert!(matches!(
+            DerivationPath::hardened(&[FIRST_HARDENED_INDEX]),
+            Err(Error::IndexOutOfRange(_))
+        ));
+        assert!(matches!(
+            hardened_index(u32::MAX),
+            Err(Error::IndexOutOfRange(_))
+        ));
+    }
+
     #[test]
-    fn test_is_valid_path() {
-        assert!(is_valid_path("m/0'/2147483647'/1'/2147483646'/2'"));
+    fn test_derive_for_path_notations() {
+        let seed_bytes = hex::decode("000102030405060708090a0b0c0d0e0f").unwrap();
+        let apostrophe = Node::derive_for_path("m/0'/1'/2'", &seed_bytes).unwrap();
+        let h = Node::derive_for_path("m/0h/1H/2h", &seed_bytes).unwrap();
+        let path = DerivationPath::hardened(&[0, 1, 2]).unwrap();
+        let typed = Node::derive_for_path(&path, &seed_bytes).unwrap();
+        assert_eq!(apostrophe.hash(), h.hash());
+        assert_eq!(apostrophe.hash(), typed.hash());
+    }
+
+    #[test]
+    fn test_from_hash() {
+        let seed_bytes = hex::decode(SEED_1).unwrap();
+        let node = Secp256k1Node::derive_for_path("m/0'/1", &seed_bytes).unwrap();
+        let copy = Secp256k1Node::from_hash(&node.hash()).unwrap();
+        assert_eq!(copy.public_key(), node.public_key());
+        assert!(Node::from_hash(&node.hash()).is_ok());
+
+        // a zero key, a key above the curve order and a truncated hash
+        for hash in [vec![0u8; 64], vec![0xffu8; 64], vec![1u8; 32]] {
+            assert!(matches!(
+                Secp256k1Node::from_hash(&hash),
+                Err(Error::InvalidNodeHash)
+            ));
+        }
+        assert!(matches!(
+            Node::from_hash(&[1u8; 32]),
+            Err(Error::InvalidNodeHash)
+        ));
     }
 
     // #[bench]
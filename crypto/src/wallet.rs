@@ -0,0 +1,437 @@
+use crate::{
+    crypto::Signer,
+    derivation,
+    errors::{DerivationError, Error, KeystoreError},
+    slip10::DerivationPath,
+    SIGNATURE_ALGORITHM,
+};
+use aes_gcm::{
+    aead::{Aead, KeyInit},
+    Aes256Gcm, Nonce,
+};
+use argon2::{Algorithm, Argon2, Params, Version};
+use bip39::{Language, Mnemonic};
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::{fmt, fs, path::Path};
+use zeroize::Zeroizing;
+
+/// Number of words of the mnemonics generated by the vega wallet.
+pub const MNEMONIC_WORD_COUNT: usize = 24;
+/// Version of the signature algorithm used by the wallet keys.
+pub const ALGORITHM_VERSION: u32 = 1;
+/// Version of the wallet file format, keys are derived on m/1789'/0'/index'.
+pub const WALLET_VERSION: u32 = 2;
+
+// argon2id parameters used by the vega wallet to derive
+// the encryption key from the passphrase
+const ARGON_TIME: u32 = 1;
+const ARGON_MEMORY: u32 = 64 * 1024;
+const ARGON_THREADS: u32 = 4;
+const ARGON_KEY_LEN: usize = 32;
+const SALT_LEN: usize = 32;
+const NONCE_LEN: usize = 12;
+const TAG_LEN: usize = 16;
+
+/// A vega HD wallet, the mnemonic is the only thing
+/// required to recover all the keys later on.
+#[derive(Clone)]
+pub struct Wallet {
+    pub mnemonic: Zeroizing<String>,
+    pub keys: Vec<Key>,
+    /// The fields of the wallet file not used here, e.g. the name, id and
+    /// permissions set by the vega wallet, written back as is
+    pub extra: Map<String, Value>,
+}
+
+impl fmt::Debug for Wallet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Wallet")
+            .field("mnemonic", &"<redacted>")
+            .field("keys", &self.keys)
+            .field("extra", &self.extra)
+            .finish()
+    }
+}
+
+/// The metadata of a key derived from a wallet mnemonic.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Key {
+    /// Index of the key in the wallet, the vega wallet starts at 1
+    pub index: u32,
+    /// Name of the key, following the vega wallet defaults
+    pub name: String,
+    /// The public key hex encoded
+    pub public_key: String,
+    /// The derivation path of the key
+    pub path: DerivationPath,
+    pub algorithm: String,
+    pub algorithm_version: u32,
+    /// Tainted keys should not be used for signing anymore
+    pub tainted: bool,
+    /// The fields of the key in the wallet file not used here
+    pub extra: Map<String, Value>,
+}
+
+impl Key {
+    fn new(signer: &Signer, index: u32, path: DerivationPath) -> Key {
+        return Key {
+            index,
+            name: format!("Key {}", index),
+            public_key: signer.public_key().to_hex(),
+            path,
+            algorithm: SIGNATURE_ALGORITHM.into(),
+            algorithm_version: ALGORITHM_VERSION,
+            tainted: false,
+            extra: Map::new(),
+        };
+    }
+}
+
+impl Wallet {
+    /// Create a new wallet from a freshly generated mnemonic,
+    /// deriving the first `key_count` keys.
+    pub fn create(key_count: u32) -> Result<Wallet, Error> {
+        return Wallet::from_mnemonic(&generate_mnemonic()?, key_count);
+    }
+
+    /// Derive the first `key_count` keys of an existing mnemonic.
+    pub fn from_mnemonic(mnemonic: &str, key_count: u32) -> Result<Wallet, Error> {
+        let m = Mnemonic::parse_in(Language::English, mnemonic)?;
+        let seed = Zeroizing::new(m.to_seed(""));
+        let keys = (1..=key_count)
+            .map(|index| {
+                let path = derivation::key_path(index)?;
+                let signer = Signer::from_bip39_seed(&seed[..], &path)?;
+                return Ok(Key::new(&signer, index, path));
+            })
+            .collect::<Result<Vec<Key>, Error>>()?;
+
+        return Ok(Wallet {
+            mnemonic: Zeroizing::new(m.to_string()),
+            keys,
+            extra: Map::new(),
+        });
+    }
+
+    /// Build the signer for the key at the given index.
+    pub fn signer(&self, index: u32) -> Result<Signer, Error> {
+        if index == 0 {
+            return Err(DerivationError::InvalidIndex.into());
+        }
+        return Signer::from_mnemonic(&self.mnemonic, index as usize);
+    }
+
+    /// Serialize and encrypt the wallet with the given passphrase,
+    /// using the vega wallet file format.
+    pub fn encrypt(&self, passphrase: &str) -> Result<Vec<u8>, Error> {
+        let keys = self
+            .keys
+            .iter()
+            .map(|k| {
+                let signer = self.signer(k.index)?;
+                return Ok(KeyPairFile {
+                    index: k.index,
+                    public_key: k.public_key.clone(),
+                    private_key: Zeroizing::new(hex::encode(signer.secret())),
+                    meta: vec![MetadataFile {
+                        key: "name".into(),
+                        value: k.name.clone(),
+                    }],
+                    tainted: k.tainted,
+                    algorithm: AlgorithmFile {
+                        name: k.algorithm.clone(),
+                        version: k.algorithm_version,
+                    },
+                    extra: k.extra.clone(),
+                });
+            })
+            .collect::<Result<Vec<KeyPairFile>, Error>>()?;
+
+        let file = WalletFile {
+            version: WALLET_VERSION,
+            recovery_phrase: self.mnemonic.clone(),
+            keys,
+            extra: self.extra.clone(),
+        };
+
+        return encrypt(&Zeroizing::new(serde_json::to_vec(&file)?), passphrase);
+    }
+
+    /// Decrypt and load a wallet from the vega wallet file format,
+    /// every key is checked against the recovery phrase.
+    pub fn decrypt(data: &[u8], passphrase: &str) -> Result<Wallet, Error> {
+        let file: WalletFile = serde_json::from_slice(&Zeroizing::new(decrypt(data, passphrase)?))?;
+        if file.version != WALLET_VERSION {
+            return Err(KeystoreError::UnsupportedWalletVersion(file.version).into());
+        }
+
+        let m = Mnemonic::parse_in(Language::English, file.recovery_phrase.as_str())?;
+        let seed = Zeroizing::new(m.to_seed(""));
+        let keys = file
+            .keys
+            .into_iter()
+            .map(|k| {
+                if k.index == 0 {
+                    return Err(DerivationError::InvalidIndex.into());
+                }
+                let path = derivation::key_path(k.index)?;
+                let signer = Signer::from_bip39_seed(&seed[..], &path)?;
+                if hex::encode(signer.pubkey()) != k.public_key
+                    || *Zeroizing::new(hex::encode(signer.secret())) != *k.private_key
+                {
+                    return Err(KeystoreError::WalletKeyMismatch(k.index).into());
+                }
+
+                let name = k
+                    .meta
+                    .iter()
+                    .find(|m| m.key == "name")
+                    .map(|m| m.value.clone())
+                    .unwrap_or_else(|| format!("Key {}", k.index));
+
+                return Ok(Key {
+                    index: k.index,
+                    name,
+                    public_key: k.public_key,
+                    path,
+                    algorithm: k.algorithm.name,
+                    algorithm_version: k.algorithm.version,
+                    tainted: k.tainted,
+                    extra: k.extra,
+                });
+            })
+            .collect::<Result<Vec<Key>, Error>>()?;
+
+        return Ok(Wallet {
+            mnemonic: Zeroizing::new(m.to_string()),
+            keys,
+            extra: file.extra,
+        });
+    }
+
+    /// Encrypt and write the wallet to a file.
+    pub fn save<P: AsRef<Path>>(&self, path: P, passphrase: &str) -> Result<(), Error> {
+        return Ok(fs::write(path, self.encrypt(passphrase)?)?);
+    }
+
+    /// Read and decrypt a wallet file.
+    pub fn load<P: AsRef<Path>>(path: P, passphrase: &str) -> Result<Wallet, Error> {
+        return Wallet::decrypt(&fs::read(path)?, passphrase);
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct WalletFile {
+    version: u32,
+    recovery_phrase: Zeroizing<String>,
+    keys: Vec<KeyPairFile>,
+    #[serde(flatten)]
+    extra: Map<String, Value>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct KeyPairFile {
+    index: u32,
+    public_key: String,
+    private_key: Zeroizing<String>,
+    #[serde(default)]
+    meta: Vec<MetadataFile>,
+    #[serde(default)]
+    tainted: bool,
+    algorithm: AlgorithmFile,
+    #[serde(flatten)]
+    extra: Map<String, Value>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct MetadataFile {
+    key: String,
+    value: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct AlgorithmFile {
+    name: String,
+    version: u32,
+}
+
+/// Encrypt data with a key derived from the passphrase using argon2id,
+/// the output is laid out as `nonce | cypher text | salt`.
+fn encrypt(data: &[u8], passphrase: &str) -> Result<Vec<u8>, Error> {
+    let mut salt = [0u8; SALT_LEN];
+    let mut nonce = [0u8; NONCE_LEN];
+    rand::thread_rng().fill_bytes(&mut salt);
+    rand::thread_rng().fill_bytes(&mut nonce);
+
+    let cipher = Aes256Gcm::new_from_slice(&derive_key(passphrase, &salt)?)
+        .map_err(|_| KeystoreError::InvalidPassphrase)?;
+    let cypher_text = cipher
+        .encrypt(Nonce::from_slice(&nonce), data)
+        .map_err(|_| KeystoreError::InvalidPassphrase)?;
+
+    let mut out = Vec::with_capacity(NONCE_LEN + cypher_text.len() + SALT_LEN);
+    out.extend_from_slice(&nonce);
+    out.extend_from_slice(&cypher_text);
+    out.extend_from_slice(&salt);
+    return Ok(out);
+}
+
+fn decrypt(data: &[u8], passphrase: &str) -> Result<Vec<u8>, Error> {
+    if data.len() < SALT_LEN {
+        return Err(KeystoreError::InvalidSalt.into());
+    }
+    let (data, salt) = data.split_at(data.len() - SALT_LEN);
+    if data.len() < NONCE_LEN + TAG_LEN {
+        return Err(KeystoreError::InvalidCypherText.into());
+    }
+    let (nonce, cypher_text) = data.split_at(NONCE_LEN);
+
+    let cipher = Aes256Gcm::new_from_slice(&derive_key(passphrase, salt)?)
+        .map_err(|_| KeystoreError::InvalidPassphrase)?;
+    return cipher
+        .decrypt(Nonce::from_slice(nonce), cypher_text)
+        .map_err(|_| KeystoreError::InvalidPassphrase.into());
+}
+
+fn derive_key(passphrase: &str, salt: &[u8]) -> Result<Zeroizing<Vec<u8>>, Error> {
+    let params = Params::new(ARGON_MEMORY, ARGON_TIME, ARGON_THREADS, Some(ARGON_KEY_LEN))?;
+    let mut key = Zeroizing::new(vec![0u8; ARGON_KEY_LEN]);
+    Argon2::new(Algorithm::Argon2id, Version::V0x13, params).hash_password_into(
+        passphrase.as_bytes(),
+        salt,
+        &mut key,
+    )?;
+    return Ok(key);
+}
+
+/// Generate a new 24 words english mnemonic.
+pub fn generate_mnemonic() -> Result<String, Error> {
+    let m = Mnemonic::generate_in(Language::English, MNEMONIC_WORD_COUNT)?;
+    return Ok(m.to_string());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create() {
+        let w = Wallet::create(3).unwrap();
+        assert_eq!(w.mnemonic.split_whitespace().count(), MNEMONIC_WORD_COUNT);
+        assert_eq!(w.keys.len(), 3);
+
+        for (i, k) in w.keys.iter().enumerate() {
+            let index = i as u32 + 1;
+            assert_eq!(k.index, index);
+            assert_eq!(k.name, format!("Key {}", index));
+            assert_eq!(k.path.to_string(), format!("m/1789'/0'/{}'", index));
+            let s = Signer::from_mnemonic(&w.mnemonic, index as usize).unwrap();
+            assert_eq!(k.public_key, hex::encode(s.pubkey()));
+            assert_eq!(w.signer(index).unwrap().pubkey(), s.pubkey());
+        }
+
+        assert!(matches!(
+            w.signer(0),
+            Err(Error::Derivation(DerivationError::InvalidIndex))
+        ));
+        assert_ne!(Wallet::create(1).unwrap().mnemonic, w.mnemonic);
+    }
+
+    #[test]
+    fn test_from_mnemonic() {
+        let mnemomnic = "another deal useless giraffe quarter glimpse blur civil reflect jelly quit endorse engage slender energy scare ask suggest toe spirit leaf seed unveil million";
+        let w = Wallet::from_mnemonic(mnemomnic, 10).unwrap();
+        assert_eq!(
+            w.keys[9].public_key,
+            "053a10c3e8aa92bcfae80b61845a23a4dfc88d94a31570e3c494da9f43b64ca0"
+        );
+    }
+
+    #[test]
+    fn test_encrypt_decrypt() {
+        let mut w = Wallet::create(2).unwrap();
+        w.keys[1].name = "trading".into();
+        w.keys[1].tainted = true;
+
+        let data = w.encrypt("passphrase").unwrap();
+        let w2 = Wallet::decrypt(&data, "passphrase").unwrap();
+        assert_eq!(w.mnemonic, w2.mnemonic);
+        assert_eq!(w.keys, w2.keys);
+
+        assert!(matches!(
+            Wallet::decrypt(&data, "wrong passphrase"),
+            Err(Error::Keystore(KeystoreError::InvalidPassphrase))
+        ));
+        assert!(matches!(
+            Wallet::decrypt(&data[..10], "passphrase"),
+            Err(Error::Keystore(KeystoreError::InvalidSalt))
+        ));
+        assert!(matches!(
+            Wallet::decrypt(&data[data.len() - 40..], "passphrase"),
+            Err(Error::Keystore(KeystoreError::InvalidCypherText))
+        ));
+    }
+
+    #[test]
+    fn test_decrypt_key_mismatch() {
+        let w = Wallet::create(1).unwrap();
+        let other = Wallet::create(1).unwrap();
+        let file = WalletFile {
+            version: WALLET_VERSION,
+            recovery_phrase: w.mnemonic.clone(),
+            keys: vec![KeyPairFile {
+                index: 1,
+                public_key: other.keys[0].public_key.clone(),
+                private_key: Zeroizing::new(hex::encode(other.signer(1).unwrap().secret())),
+                meta: vec![],
+                tainted: false,
+                algorithm: AlgorithmFile {
+                    name: SIGNATURE_ALGORITHM.into(),
+                    version: ALGORITHM_VERSION,
+                },
+                extra: Map::new(),
+            }],
+            extra: Map::new(),
+        };
+
+        let data = encrypt(&serde_json::to_vec(&file).unwrap(), "passphrase").unwrap();
+        assert!(matches!(
+            Wallet::decrypt(&data, "passphrase"),
+            Err(Error::Keystore(KeystoreError::WalletKeyMismatch(1)))
+        ));
+    }
+
+    #[test]
+    fn test_unknown_fields_are_kept() {
+        let mnemonic = "another deal useless giraffe quarter glimpse blur civil reflect jelly quit endorse engage slender energy scare ask suggest toe spirit leaf seed unveil million";
+        let signer = Signer::from_mnemonic(mnemonic, 1).unwrap();
+        // laid out as the vega wallet writes its HD wallets
+        let file = serde_json::json!({
+            "version": 2,
+            "name": "my-wallet",
+            "id": "a1b2c3",
+            "permissions": { "vega.xyz": { "publicKeys": { "access": "read" } } },
+            "recovery_phrase": mnemonic,
+            "keys": [{
+                "index": 1,
+                "public_key": hex::encode(signer.pubkey()),
+                "private_key": hex::encode(signer.secret()),
+                "meta": [{ "key": "name", "value": "trading" }],
+                "tainted": false,
+                "algorithm": { "name": "vega/ed25519", "version": 1 },
+                "created_at": "2024-01-01T00:00:00Z"
+            }]
+        });
+        let data = encrypt(&serde_json::to_vec(&file).unwrap(), "passphrase").unwrap();
+        let w = Wallet::decrypt(&data, "passphrase").unwrap();
+        assert_eq!(w.keys[0].name, "trading");
+        assert_eq!(w.extra["name"], "my-wallet");
+        assert_eq!(w.keys[0].extra["created_at"], "2024-01-01T00:00:00Z");
+
+        let saved = decrypt(&w.encrypt("passphrase").unwrap(), "passphrase").unwrap();
+        let saved: Value = serde_json::from_slice(&saved).unwrap();
+        assert_eq!(saved, file);
+    }
+}
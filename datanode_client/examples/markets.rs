@@ -0,0 +1,19 @@
+use vega_datanode_client::DataNodeClient;
+
+const NODE_ADDRESS: &str = "tcp://n07.testnet.vega.xyz:3007";
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let client = DataNodeClient::connect(NODE_ADDRESS).await?;
+
+    for mkt in client.markets().await? {
+        let name = mkt
+            .tradable_instrument
+            .and_then(|t| t.instrument)
+            .map(|i| i.name)
+            .unwrap_or_default();
+        println!("{} {}", mkt.id, name);
+    }
+
+    return Ok(());
+}
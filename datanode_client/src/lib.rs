@@ -0,0 +1,390 @@
+#![allow(clippy::needless_return)]
+// the handlers of the mock data node answer with the tonic statuses as they are
+#![cfg_attr(test, allow(clippy::result_large_err))]
+
+use futures::stream::{BoxStream, TryStreamExt};
+use std::error::Error as StdError;
+use std::fmt;
+use tonic::transport::{Channel, Endpoint};
+use vega_protobufs::datanode::api::v2::{
+    trading_data_service_client::TradingDataServiceClient, AccountBalance, AccountFilter,
+    GetMarketRequest, ListAccountsRequest, ListAllPositionsRequest, ListMarketsRequest,
+    ListOrdersRequest, PositionsFilter,
+};
+use vega_protobufs::vega::{Market, Order, Position};
+
+#[cfg(test)]
+mod mock;
+mod orderbook;
+mod orders;
+mod pagination;
+mod party;
+mod subscription;
+
+pub use orderbook::{Book, Level, Mid, OrderBook, Sequencing};
+pub use orders::{deterministic_order_id, OrderHandle, OrderManager, OrderState};
+pub use pagination::{ListRequest, Page, PageOptions, MAX_PAGE_SIZE};
+pub use party::{AccountKey, Change, PartySnapshot, PartyState, ACCOUNT_TYPES};
+pub use subscription::{Backfill, Backoff, Event, ObserveRequest, Subscription};
+pub use vega_protobufs::datanode::api::v2::OrderFilter;
+
+/// A client of the data node trading data API.
+#[derive(Clone)]
+pub struct DataNodeClient {
+    client: TradingDataServiceClient<Channel>,
+}
+
+#[derive(Debug)]
+pub enum Error {
+    /// The connection to the data node could not be established
+    Connect(tonic::transport::Error),
+    /// The data node answered with an error status, boxed as it is
+    /// far larger than the other errors
+    Status(Box<tonic::Status>),
+    /// The data node response is missing a required field
+    MissingField(&'static str),
+    /// No market with this id
+    MarketNotFound(String),
+    /// A price is not an unsigned integer
+    InvalidPrice(String),
+    /// A subscription stream kept ending right after being established
+    Disconnected,
+    /// A transaction could not be signed or sent
+    Transact(vega_crypto::errors::Error),
+    /// The network refused the transaction
+    TransactionRejected(String),
+    /// An order with this reference is already followed
+    DuplicateReference(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "data node client error: {}", self.desc())
+    }
+}
+
+impl From<tonic::transport::Error> for Error {
+    fn from(error: tonic::transport::Error) -> Self {
+        Error::Connect(error)
+    }
+}
+
+impl From<tonic::Status> for Error {
+    fn from(error: tonic::Status) -> Self {
+        Error::Status(Box::new(error))
+    }
+}
+
+impl From<vega_crypto::errors::Error> for Error {
+    fn from(error: vega_crypto::errors::Error) -> Self {
+        Error::Transact(error)
+    }
+}
+
+impl StdError for Error {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        use Error::*;
+        match self {
+            Connect(e) => Some(e),
+            Status(e) => Some(&**e),
+            Transact(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl Error {
+    pub fn desc(&self) -> String {
+        use Error::*;
+        match self {
+            Connect(e) => format!("GRPC transport error: {}", e),
+            Status(e) => format!("GRPC error: {}", e),
+            MissingField(field) => format!("response is missing the {} field", field),
+            MarketNotFound(id) => format!("no market with id {}", id),
+            InvalidPrice(p) => format!("invalid price: {}", p),
+            Disconnected => "the subscription stream was interrupted".into(),
+            Transact(e) => format!("transaction error: {}", e.desc()),
+            TransactionRejected(e) => format!("transaction rejected: {}", e),
+            DuplicateReference(r) => format!("an order with reference {} is already followed", r),
+        }
+    }
+
+    /// Whether the same call may succeed if retried later, this is the
+    /// case for connection errors and for statuses reporting a transient
+    /// failure. Tonic reports a stream broken by the loss of the
+    /// connection with the `Unknown` status.
+    pub fn is_retryable(&self) -> bool {
+        use tonic::Code::*;
+        return match self {
+            Error::Connect(_) | Error::Disconnected => true,
+            Error::Status(s) => matches!(
+                s.code(),
+                Unavailable | DeadlineExceeded | ResourceExhausted | Aborted | Cancelled | Unknown
+            ),
+            _ => false,
+        };
+    }
+}
+
+impl DataNodeClient {
+    pub async fn connect<D>(address: D) -> Result<DataNodeClient, Error>
+    where
+        D: TryInto<Endpoint>,
+        D::Error: Into<Box<dyn StdError + Send + Sync + 'static>>,
+    {
+        let client = TradingDataServiceClient::connect(address).await?;
+        return Ok(DataNodeClient { client });
+    }
+
+    /// Stream the nodes of all the pages of a `List*` RPC, the
+    /// pagination of the request is set for each page.
+    pub fn list<R>(
+        &self,
+        req: R,
+        options: PageOptions,
+    ) -> BoxStream<'static, Result<R::Node, Error>>
+    where
+        R: ListRequest,
+    {
+        return pagination::paginate(self.client.clone(), req, options);
+    }
+
+    /// Subscribe to an `Observe*` RPC, the request is sent again
+    /// whenever the stream is interrupted.
+    pub fn subscribe<R>(&self, req: R, backoff: Backoff) -> Subscription<R::Update>
+    where
+        R: ObserveRequest,
+    {
+        return subscription::subscribe(self.client.clone(), req, backoff);
+    }
+
+    /// All the markets, including the settled ones.
+    pub async fn markets(&self) -> Result<Vec<Market>, Error> {
+        let req = ListMarketsRequest {
+            pagination: None,
+            include_settled: Some(true),
+        };
+        return self.list(req, PageOptions::default()).try_collect().await;
+    }
+
+    pub async fn market(&self, id: &str) -> Result<Market, Error> {
+        let req = GetMarketRequest {
+            market_id: id.to_string(),
+        };
+        let market = match self.client.clone().get_market(req).await {
+            Ok(resp) => resp.into_inner().market,
+            Err(s) if s.code() == tonic::Code::NotFound => None,
+            Err(s) => return Err(s.into()),
+        };
+        return market.ok_or_else(|| Error::MarketNotFound(id.to_string()));
+    }
+
+    /// The orders matching the filter, the default filter matches all the orders.
+    pub async fn orders(&self, filter: OrderFilter) -> Result<Vec<Order>, Error> {
+        let req = ListOrdersRequest {
+            pagination: None,
+            filter: Some(filter),
+        };
+        return self.list(req, PageOptions::default()).try_collect().await;
+    }
+
+    /// The positions of the party in all the markets.
+    pub async fn positions(&self, party: &str) -> Result<Vec<Position>, Error> {
+        let req = ListAllPositionsRequest {
+            filter: Some(PositionsFilter {
+                party_ids: vec![party.to_string()],
+                ..Default::default()
+            }),
+            pagination: None,
+        };
+        return self.list(req, PageOptions::default()).try_collect().await;
+    }
+
+    /// The balances of all the accounts of the party.
+    pub async fn accounts(&self, party: &str) -> Result<Vec<AccountBalance>, Error> {
+        let req = ListAccountsRequest {
+            filter: Some(AccountFilter {
+                party_ids: vec![party.to_string()],
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        return self.list(req, PageOptions::default()).try_collect().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::MockDataNode;
+    use std::sync::{Arc, Mutex};
+    use vega_protobufs::datanode::api::v2::{
+        AccountEdge, AccountsConnection, GetMarketResponse, ListAccountsResponse,
+        ListMarketsResponse, ListOrdersResponse, MarketConnection, MarketEdge, OrderConnection,
+        OrderEdge, PageInfo,
+    };
+
+    fn market(id: &str) -> Market {
+        return Market {
+            id: id.to_string(),
+            ..Default::default()
+        };
+    }
+
+    #[tokio::test]
+    async fn markets_follow_the_cursors() {
+        let afters = Arc::new(Mutex::new(vec![]));
+        let a = afters.clone();
+        let url = MockDataNode::new()
+            .unary("ListMarkets", move |req: ListMarketsRequest| {
+                let p = req.pagination.unwrap();
+                assert_eq!(req.include_settled, Some(true));
+                a.lock().unwrap().push(p.after.clone());
+                let (ids, next) = match p.after.as_deref() {
+                    None => (vec!["m1", "m2"], true),
+                    Some("m2") => (vec!["m3"], false),
+                    Some(c) => panic!("unexpected cursor {}", c),
+                };
+                let edges = ids
+                    .iter()
+                    .map(|id| MarketEdge {
+                        node: Some(market(id)),
+                        cursor: id.to_string(),
+                    })
+                    .collect();
+                return Ok(ListMarketsResponse {
+                    markets: Some(MarketConnection {
+                        edges,
+                        page_info: Some(PageInfo {
+                            has_next_page: next,
+                            end_cursor: ids.last().unwrap().to_string(),
+                            ..Default::default()
+                        }),
+                    }),
+                });
+            })
+            .start()
+            .await;
+
+        let clt = DataNodeClient::connect(url).await.unwrap();
+        let ids = clt
+            .markets()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|m| m.id)
+            .collect::<Vec<_>>();
+        assert_eq!(ids, vec!["m1", "m2", "m3"]);
+        assert_eq!(*afters.lock().unwrap(), vec![None, Some("m2".to_string())]);
+    }
+
+    #[tokio::test]
+    async fn market_not_found() {
+        let url = MockDataNode::new()
+            .unary("GetMarket", |req: GetMarketRequest| {
+                return match req.market_id.as_str() {
+                    "m1" => Ok(GetMarketResponse {
+                        market: Some(market("m1")),
+                    }),
+                    _ => Err(tonic::Status::not_found("market not found")),
+                };
+            })
+            .start()
+            .await;
+
+        let clt = DataNodeClient::connect(url).await.unwrap();
+        assert_eq!(clt.market("m1").await.unwrap().id, "m1");
+        match clt.market("m2").await {
+            Err(Error::MarketNotFound(id)) => assert_eq!(id, "m2"),
+            r => panic!("unexpected result {:?}", r),
+        }
+    }
+
+    #[tokio::test]
+    async fn orders_are_filtered() {
+        let url = MockDataNode::new()
+            .unary("ListOrders", |req: ListOrdersRequest| {
+                let filter = req.filter.unwrap();
+                assert_eq!(filter.party_ids, vec!["p1"]);
+                assert_eq!(filter.live_only, Some(true));
+                return Ok(ListOrdersResponse {
+                    orders: Some(OrderConnection {
+                        edges: vec![OrderEdge {
+                            node: Some(Order {
+                                id: "o1".to_string(),
+                                party_id: "p1".to_string(),
+                                ..Default::default()
+                            }),
+                            cursor: "o1".to_string(),
+                        }],
+                        page_info: None,
+                    }),
+                });
+            })
+            .start()
+            .await;
+
+        let clt = DataNodeClient::connect(url).await.unwrap();
+        let filter = OrderFilter {
+            party_ids: vec!["p1".to_string()],
+            live_only: Some(true),
+            ..Default::default()
+        };
+        let orders = clt.orders(filter).await.unwrap();
+        assert_eq!(orders.len(), 1);
+        assert_eq!(orders[0].id, "o1");
+    }
+
+    #[tokio::test]
+    async fn accounts_of_party() {
+        let url = MockDataNode::new()
+            .unary("ListAccounts", |req: ListAccountsRequest| {
+                let owner = req.filter.unwrap().party_ids.remove(0);
+                return Ok(ListAccountsResponse {
+                    accounts: Some(AccountsConnection {
+                        edges: vec![AccountEdge {
+                            node: Some(AccountBalance {
+                                owner,
+                                balance: "100".to_string(),
+                                ..Default::default()
+                            }),
+                            cursor: "a1".to_string(),
+                        }],
+                        page_info: None,
+                    }),
+                });
+            })
+            .start()
+            .await;
+
+        let clt = DataNodeClient::connect(url).await.unwrap();
+        let accounts = clt.accounts("p1").await.unwrap();
+        assert_eq!(accounts.len(), 1);
+        assert_eq!(accounts[0].owner, "p1");
+        assert_eq!(accounts[0].balance, "100");
+    }
+
+    #[tokio::test]
+    async fn errors_are_typed() {
+        let url = MockDataNode::new()
+            .unary("ListMarkets", |_: ListMarketsRequest| {
+                return Ok(ListMarketsResponse { markets: None });
+            })
+            .start()
+            .await;
+
+        let clt = DataNodeClient::connect(url).await.unwrap();
+        assert!(matches!(
+            clt.markets().await,
+            Err(Error::MissingField("markets"))
+        ));
+        match clt.positions("p1").await {
+            Err(Error::Status(s)) => assert_eq!(s.code(), tonic::Code::Unimplemented),
+            r => panic!("unexpected result {:?}", r),
+        }
+        assert!(matches!(
+            DataNodeClient::connect("http://127.0.0.1:1").await,
+            Err(Error::Connect(_))
+        ));
+    }
+}
@@ -0,0 +1,221 @@
+//! A minimal gRPC server mocking the data node API, and the core
+//! API used to send transactions, for tests only.
+
+use futures::stream::{BoxStream, StreamExt};
+use prost::bytes::{Buf, BufMut};
+use prost::Message;
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::sync::Arc;
+use tokio::net::TcpListener;
+use tokio_stream::wrappers::TcpListenerStream;
+use tonic::body::BoxBody;
+use tonic::codec::{Codec, DecodeBuf, Decoder, EncodeBuf, Encoder};
+use tonic::codegen::{http, BoxFuture, Context, Poll, Service};
+use tonic::server::{Grpc, NamedService, ServerStreamingService, UnaryService};
+use tonic::transport::{Body, Server};
+use tonic::Status;
+
+const SERVICE: &str = "datanode.api.v2.TradingDataService";
+const CORE_SERVICE: &str = "vega.api.v1.CoreService";
+
+type Unary = Arc<dyn Fn(Vec<u8>) -> Result<Vec<u8>, Status> + Send + Sync>;
+type RawStream = BoxStream<'static, Result<Vec<u8>, Status>>;
+type Streaming = Arc<dyn Fn(Vec<u8>) -> Result<RawStream, Status> + Send + Sync>;
+
+/// A data node answering the registered RPCs, every other
+/// RPC fails with `Unimplemented`.
+#[derive(Default)]
+pub struct MockDataNode {
+    unary: HashMap<String, Unary>,
+    streaming: HashMap<String, Streaming>,
+}
+
+impl MockDataNode {
+    pub fn new() -> MockDataNode {
+        return MockDataNode::default();
+    }
+
+    /// Answer the unary RPC `method` with the handler, e.g. `ListMarkets`
+    /// or `vega.api.v1.CoreService/SubmitTransaction` for the other services.
+    pub fn unary<Req, Resp, F>(mut self, method: &str, handler: F) -> MockDataNode
+    where
+        Req: Message + Default,
+        Resp: Message,
+        F: Fn(Req) -> Result<Resp, Status> + Send + Sync + 'static,
+    {
+        let unary: Unary = Arc::new(move |bytes| {
+            let req = Req::decode(bytes.as_slice())
+                .map_err(|e| Status::invalid_argument(e.to_string()))?;
+            return handler(req).map(|resp| resp.encode_to_vec());
+        });
+        self.unary.insert(path(method), unary);
+        return self;
+    }
+
+    /// Answer the server streaming RPC `method`, e.g. `ObserveTrades`, with
+    /// the stream returned by the handler. The stream ending simulates
+    /// the connection being lost.
+    pub fn streaming<Req, Resp, F>(mut self, method: &str, handler: F) -> MockDataNode
+    where
+        Req: Message + Default,
+        Resp: Message + 'static,
+        F: Fn(Req) -> Result<BoxStream<'static, Result<Resp, Status>>, Status>
+            + Send
+            + Sync
+            + 'static,
+    {
+        let streaming: Streaming = Arc::new(move |bytes| {
+            let req = Req::decode(bytes.as_slice())
+                .map_err(|e| Status::invalid_argument(e.to_string()))?;
+            let updates = handler(req)?;
+            return Ok(updates.map(|u| u.map(|u| u.encode_to_vec())).boxed());
+        });
+        self.streaming.insert(path(method), streaming);
+        return self;
+    }
+
+    /// Serve on a random port, returns the URL of the server.
+    pub async fn start(self) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let svc = MockService {
+            unary: Arc::new(self.unary),
+            streaming: Arc::new(self.streaming),
+        };
+        tokio::spawn(
+            Server::builder()
+                .add_service(svc.clone())
+                .add_service(MockCoreService(svc))
+                .serve_with_incoming(TcpListenerStream::new(listener)),
+        );
+        return url;
+    }
+}
+
+fn path(method: &str) -> String {
+    return match method.contains('/') {
+        true => format!("/{}", method),
+        false => format!("/{}/{}", SERVICE, method),
+    };
+}
+
+#[derive(Clone)]
+struct MockService {
+    unary: Arc<HashMap<String, Unary>>,
+    streaming: Arc<HashMap<String, Streaming>>,
+}
+
+impl NamedService for MockService {
+    const NAME: &'static str = SERVICE;
+}
+
+impl Service<http::Request<Body>> for MockService {
+    type Response = http::Response<BoxBody>;
+    type Error = Infallible;
+    type Future = BoxFuture<Self::Response, Self::Error>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        return Poll::Ready(Ok(()));
+    }
+
+    fn call(&mut self, req: http::Request<Body>) -> Self::Future {
+        let unary = self.unary.get(req.uri().path()).cloned();
+        let streaming = self.streaming.get(req.uri().path()).cloned();
+        return Box::pin(async move {
+            let mut grpc = Grpc::new(RawCodec);
+            return Ok(match (unary, streaming) {
+                (Some(h), _) => grpc.unary(UnaryHandler(h), req).await,
+                (_, Some(h)) => grpc.server_streaming(StreamingHandler(h), req).await,
+                _ => Status::unimplemented(req.uri().path()).to_http(),
+            });
+        });
+    }
+}
+
+/// The same routes served under the core service name.
+#[derive(Clone)]
+struct MockCoreService(MockService);
+
+impl NamedService for MockCoreService {
+    const NAME: &'static str = CORE_SERVICE;
+}
+
+impl Service<http::Request<Body>> for MockCoreService {
+    type Response = http::Response<BoxBody>;
+    type Error = Infallible;
+    type Future = BoxFuture<Self::Response, Self::Error>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        return self.0.poll_ready(cx);
+    }
+
+    fn call(&mut self, req: http::Request<Body>) -> Self::Future {
+        return self.0.call(req);
+    }
+}
+
+struct UnaryHandler(Unary);
+
+impl UnaryService<Vec<u8>> for UnaryHandler {
+    type Response = Vec<u8>;
+    type Future = BoxFuture<tonic::Response<Vec<u8>>, Status>;
+
+    fn call(&mut self, req: tonic::Request<Vec<u8>>) -> Self::Future {
+        let resp = (self.0)(req.into_inner()).map(tonic::Response::new);
+        return Box::pin(async move { resp });
+    }
+}
+
+struct StreamingHandler(Streaming);
+
+impl ServerStreamingService<Vec<u8>> for StreamingHandler {
+    type Response = Vec<u8>;
+    type ResponseStream = RawStream;
+    type Future = BoxFuture<tonic::Response<RawStream>, Status>;
+
+    fn call(&mut self, req: tonic::Request<Vec<u8>>) -> Self::Future {
+        let resp = (self.0)(req.into_inner()).map(tonic::Response::new);
+        return Box::pin(async move { resp });
+    }
+}
+
+/// Pass the encoded messages through, the handlers do the decoding.
+#[derive(Default)]
+struct RawCodec;
+
+impl Codec for RawCodec {
+    type Encode = Vec<u8>;
+    type Decode = Vec<u8>;
+    type Encoder = RawCodec;
+    type Decoder = RawCodec;
+
+    fn encoder(&mut self) -> RawCodec {
+        return RawCodec;
+    }
+
+    fn decoder(&mut self) -> RawCodec {
+        return RawCodec;
+    }
+}
+
+impl Encoder for RawCodec {
+    type Item = Vec<u8>;
+    type Error = Status;
+
+    fn encode(&mut self, item: Vec<u8>, dst: &mut EncodeBuf<'_>) -> Result<(), Status> {
+        dst.put_slice(&item);
+        return Ok(());
+    }
+}
+
+impl Decoder for RawCodec {
+    type Item = Vec<u8>;
+    type Error = Status;
+
+    fn decode(&mut self, src: &mut DecodeBuf<'_>) -> Result<Option<Vec<u8>>, Status> {
+        let mut item = vec![0; src.remaining()];
+        src.copy_to_slice(&mut item);
+        return Ok(Some(item));
+    }
+}
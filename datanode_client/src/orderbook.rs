@@ -0,0 +1,575 @@
+//! A local copy of the price levels of a market, kept up to date
+//! with the market depth updates streamed by the data node.
+
+use crate::subscription::{self, Backoff, Event, Subscription};
+use crate::{DataNodeClient, Error};
+use futures::StreamExt;
+use std::collections::BTreeMap;
+use std::fmt;
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
+use vega_protobufs::datanode::api::v2::{
+    GetLatestMarketDepthRequest, GetLatestMarketDepthResponse, ObserveMarketsDepthUpdatesRequest,
+    ObserveMarketsDepthUpdatesResponse,
+};
+use vega_protobufs::vega::{MarketDepthUpdate, PriceLevel};
+
+/// A price level of the book, the price and the volume are
+/// integers in the market and position decimals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Level {
+    pub price: u128,
+    pub volume: u64,
+    pub number_of_orders: u64,
+}
+
+/// The price half way between two prices, exactly: `price`
+/// plus half a tick when `half` is set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Mid {
+    pub price: u128,
+    pub half: bool,
+}
+
+impl Mid {
+    pub fn new(a: u128, b: u128) -> Mid {
+        let (low, high) = (a.min(b), a.max(b));
+        return Mid {
+            price: low + (high - low) / 2,
+            half: (high - low) % 2 == 1,
+        };
+    }
+
+    /// The mid price as a float, which is lossy above 2^53.
+    pub fn to_f64(&self) -> f64 {
+        let half = if self.half { 0.5 } else { 0.0 };
+        return self.price as f64 + half;
+    }
+}
+
+impl fmt::Display for Mid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self.half {
+            true => write!(f, "{}.5", self.price),
+            false => write!(f, "{}", self.price),
+        };
+    }
+}
+
+/// What became of a depth update applied to a book.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Sequencing {
+    Applied,
+    /// The update is older than the book, it was ignored
+    Stale,
+    /// Updates are missing between the book and the update,
+    /// which was not applied, the book must be resynced
+    Gap,
+}
+
+/// The price levels of a market at a sequence number.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Book {
+    pub market_id: String,
+    pub sequence_number: u64,
+    bids: BTreeMap<u128, Level>,
+    asks: BTreeMap<u128, Level>,
+}
+
+impl Book {
+    pub fn from_depth(depth: &GetLatestMarketDepthResponse) -> Result<Book, Error> {
+        let mut book = Book {
+            market_id: depth.market_id.clone(),
+            sequence_number: depth.sequence_number,
+            ..Default::default()
+        };
+        update_levels(&mut book.bids, &depth.buy)?;
+        update_levels(&mut book.asks, &depth.sell)?;
+        return Ok(book);
+    }
+
+    /// Apply an update if it follows the sequence number of the book,
+    /// the levels with no volume left are removed.
+    pub fn apply(&mut self, update: &MarketDepthUpdate) -> Result<Sequencing, Error> {
+        if update.sequence_number <= self.sequence_number {
+            return Ok(Sequencing::Stale);
+        }
+        if update.previous_sequence_number != self.sequence_number {
+            return Ok(Sequencing::Gap);
+        }
+        // parse both sides before changing anything
+        let mut bids = self.bids.clone();
+        let mut asks = self.asks.clone();
+        update_levels(&mut bids, &update.buy)?;
+        update_levels(&mut asks, &update.sell)?;
+        self.bids = bids;
+        self.asks = asks;
+        self.sequence_number = update.sequence_number;
+        return Ok(Sequencing::Applied);
+    }
+
+    /// The bids, from the highest price.
+    pub fn bids(&self) -> impl Iterator<Item = &Level> {
+        return self.bids.values().rev();
+    }
+
+    /// The asks, from the lowest price.
+    pub fn asks(&self) -> impl Iterator<Item = &Level> {
+        return self.asks.values();
+    }
+
+    pub fn best_bid(&self) -> Option<Level> {
+        return self.bids().next().copied();
+    }
+
+    pub fn best_ask(&self) -> Option<Level> {
+        return self.asks().next().copied();
+    }
+
+    /// The price half way between the best bid and the best ask.
+    pub fn mid(&self) -> Option<Mid> {
+        let (bid, ask) = (self.best_bid()?, self.best_ask()?);
+        return Some(Mid::new(bid.price, ask.price));
+    }
+
+    /// The mid price as a float, which is lossy above 2^53.
+    pub fn mid_f64(&self) -> Option<f64> {
+        return self.mid().map(|m| m.to_f64());
+    }
+
+    pub fn spread(&self) -> Option<u128> {
+        let (bid, ask) = (self.best_bid()?, self.best_ask()?);
+        return ask.price.checked_sub(bid.price);
+    }
+
+    /// The best `levels` levels of each side, bids then asks.
+    pub fn depth(&self, levels: usize) -> (Vec<Level>, Vec<Level>) {
+        return (
+            self.bids().take(levels).copied().collect(),
+            self.asks().take(levels).copied().collect(),
+        );
+    }
+
+    /// The volume bid at `price` or higher.
+    pub fn bid_volume(&self, price: u128) -> u64 {
+        return self.bids.range(price..).map(|(_, l)| l.volume).sum();
+    }
+
+    /// The volume asked at `price` or lower.
+    pub fn ask_volume(&self, price: u128) -> u64 {
+        return self.asks.range(..=price).map(|(_, l)| l.volume).sum();
+    }
+}
+
+fn update_levels(side: &mut BTreeMap<u128, Level>, levels: &[PriceLevel]) -> Result<(), Error> {
+    for l in levels.iter() {
+        let price = l
+            .price
+            .parse::<u128>()
+            .map_err(|_| Error::InvalidPrice(l.price.clone()))?;
+        if l.volume == 0 {
+            side.remove(&price);
+        } else {
+            let level = Level {
+                price,
+                volume: l.volume,
+                number_of_orders: l.number_of_orders,
+            };
+            side.insert(price, level);
+        }
+    }
+    return Ok(());
+}
+
+/// The book of a market maintained by a background task, resynced
+/// from the latest market depth whenever an update is missed.
+pub struct OrderBook {
+    book: watch::Receiver<Book>,
+    task: JoinHandle<Error>,
+}
+
+impl OrderBook {
+    /// Subscribe to the updates of the market, then fetch its latest
+    /// depth and apply the updates following it.
+    pub async fn start(client: &DataNodeClient, market_id: &str) -> Result<OrderBook, Error> {
+        let req = ObserveMarketsDepthUpdatesRequest {
+            market_ids: vec![market_id.to_string()],
+        };
+        let updates =
+            subscription::subscribe_now(client.client.clone(), req, Backoff::default()).await?;
+        let book = latest_book(client, market_id).await?;
+        let (tx, rx) = watch::channel(book);
+        let task = tokio::spawn(follow(client.clone(), market_id.to_string(), updates, tx));
+        return Ok(OrderBook { book: rx, task });
+    }
+
+    /// A copy of the current book.
+    pub fn book(&self) -> Book {
+        return self.book.borrow().clone();
+    }
+
+    /// A receiver notified of each change of the book, it sees
+    /// the sender closed once the book stops being updated.
+    pub fn watch(&self) -> watch::Receiver<Book> {
+        return self.book.clone();
+    }
+
+    pub fn best_bid(&self) -> Option<Level> {
+        return self.book.borrow().best_bid();
+    }
+
+    pub fn best_ask(&self) -> Option<Level> {
+        return self.book.borrow().best_ask();
+    }
+
+    pub fn mid(&self) -> Option<Mid> {
+        return self.book.borrow().mid();
+    }
+
+    /// The mid price as a float, which is lossy above 2^53.
+    pub fn mid_f64(&self) -> Option<f64> {
+        return self.book.borrow().mid_f64();
+    }
+
+    /// Wait for the book to stop being updated, returns the error which stopped it.
+    pub async fn stopped(mut self) -> Error {
+        return match (&mut self.task).await {
+            Ok(e) => e,
+            Err(_) => Error::Disconnected,
+        };
+    }
+}
+
+impl Drop for OrderBook {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+async fn latest_book(client: &DataNodeClient, market_id: &str) -> Result<Book, Error> {
+    let req = GetLatestMarketDepthRequest {
+        market_id: market_id.to_string(),
+        max_depth: None,
+    };
+    let depth = client.client.clone().get_latest_market_depth(req).await?;
+    return Book::from_depth(depth.get_ref());
+}
+
+/// Apply the updates to the book, the ones already part of
+/// the depth the book was built from are ignored.
+async fn follow(
+    client: DataNodeClient,
+    market_id: String,
+    mut updates: Subscription<ObserveMarketsDepthUpdatesResponse>,
+    tx: watch::Sender<Book>,
+) -> Error {
+    loop {
+        let resync = match updates.next().await {
+            Some(Ok(Event::Update(resp))) => {
+                let mut outcome = Ok(Sequencing::Stale);
+                tx.send_if_modified(|book| {
+                    let mut modified = false;
+                    for u in resp.update.iter().filter(|u| u.market_id == market_id) {
+                        outcome = book.apply(u);
+                        match outcome {
+                            Ok(Sequencing::Applied) => modified = true,
+                            Ok(Sequencing::Stale) => {}
+                            _ => break,
+                        }
+                    }
+                    return modified;
+                });
+                match outcome {
+                    Ok(s) => s == Sequencing::Gap,
+                    Err(e) => return e,
+                }
+            }
+            Some(Ok(Event::Reconnected)) => false,
+            Some(Ok(Event::GapDetected)) => true,
+            Some(Err(e)) => return e,
+            None => return Error::Disconnected,
+        };
+        if resync {
+            let backoff = Backoff::default();
+            match backoff.retry(|| latest_book(&client, &market_id)).await {
+                Ok(book) => tx.send_replace(book),
+                Err(e) => return e,
+            };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::MockDataNode;
+    use futures::stream::{self, BoxStream};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+    use tonic::Status;
+
+    fn level(price: u128, volume: u64) -> PriceLevel {
+        return PriceLevel {
+            price: price.to_string(),
+            volume,
+            number_of_orders: 1,
+            ..Default::default()
+        };
+    }
+
+    fn depth(
+        seq: u64,
+        buy: Vec<PriceLevel>,
+        sell: Vec<PriceLevel>,
+    ) -> GetLatestMarketDepthResponse {
+        return GetLatestMarketDepthResponse {
+            market_id: "m1".to_string(),
+            buy,
+            sell,
+            sequence_number: seq,
+            ..Default::default()
+        };
+    }
+
+    fn update(
+        prev: u64,
+        seq: u64,
+        buy: Vec<PriceLevel>,
+        sell: Vec<PriceLevel>,
+    ) -> MarketDepthUpdate {
+        return MarketDepthUpdate {
+            market_id: "m1".to_string(),
+            buy,
+            sell,
+            sequence_number: seq,
+            previous_sequence_number: prev,
+        };
+    }
+
+    fn prices(levels: &[Level]) -> Vec<(u128, u64)> {
+        return levels.iter().map(|l| (l.price, l.volume)).collect();
+    }
+
+    #[test]
+    fn book_queries() {
+        let book = Book::from_depth(&depth(
+            1,
+            vec![level(98, 5), level(99, 2), level(97, 10)],
+            vec![level(102, 3), level(101, 1), level(105, 20)],
+        ))
+        .unwrap();
+
+        assert_eq!(book.best_bid().unwrap().price, 99);
+        assert_eq!(book.best_ask().unwrap().price, 101);
+        assert_eq!(book.mid().unwrap().to_string(), "100");
+        assert_eq!(book.mid_f64(), Some(100.0));
+        assert_eq!(book.spread(), Some(2));
+        let (bids, asks) = book.depth(2);
+        assert_eq!(prices(&bids), vec![(99, 2), (98, 5)]);
+        assert_eq!(prices(&asks), vec![(101, 1), (102, 3)]);
+        assert_eq!(book.bid_volume(98), 7);
+        assert_eq!(book.ask_volume(102), 4);
+        assert_eq!(book.ask_volume(100), 0);
+
+        assert_eq!(Book::default().mid(), None);
+    }
+
+    #[test]
+    fn mid_is_exact() {
+        let price = (1u128 << 60) + 1;
+        let book =
+            Book::from_depth(&depth(1, vec![level(price, 1)], vec![level(price + 1, 1)])).unwrap();
+        let mid = book.mid().unwrap();
+        assert_eq!(mid, Mid { price, half: true });
+        assert_eq!(mid.to_string(), "1152921504606846977.5");
+
+        // crossed books and prices close to the maximum
+        let mid = Mid::new(u128::MAX, u128::MAX - 2);
+        assert_eq!(mid.price, u128::MAX - 1);
+        assert!(!mid.half);
+    }
+
+    #[test]
+    fn book_updates_sequencing() {
+        let mut book =
+            Book::from_depth(&depth(10, vec![level(99, 2)], vec![level(101, 1)])).unwrap();
+
+        // already part of the snapshot
+        let stale = update(8, 10, vec![level(50, 1)], vec![]);
+        assert_eq!(book.apply(&stale).unwrap(), Sequencing::Stale);
+
+        let next = update(
+            10,
+            12,
+            vec![level(99, 0), level(98, 4)],
+            vec![level(101, 6)],
+        );
+        assert_eq!(book.apply(&next).unwrap(), Sequencing::Applied);
+        assert_eq!(book.sequence_number, 12);
+        assert_eq!(prices(&book.depth(5).0), vec![(98, 4)]);
+        assert_eq!(prices(&book.depth(5).1), vec![(101, 6)]);
+
+        let gap = update(13, 14, vec![level(97, 1)], vec![]);
+        assert_eq!(book.apply(&gap).unwrap(), Sequencing::Gap);
+        assert_eq!(book.sequence_number, 12);
+        assert_eq!(book.bids().count(), 1);
+
+        let invalid = update(
+            12,
+            15,
+            vec![level(97, 1)],
+            vec![PriceLevel {
+                price: "1.5".to_string(),
+                volume: 1,
+                ..Default::default()
+            }],
+        );
+        assert!(matches!(book.apply(&invalid), Err(Error::InvalidPrice(p)) if p == "1.5"));
+        assert_eq!(book.sequence_number, 12);
+        assert_eq!(book.bids().count(), 1);
+    }
+
+    #[tokio::test]
+    async fn order_book_resyncs_on_gaps() {
+        let snapshots = Arc::new(AtomicUsize::new(0));
+        let s = snapshots.clone();
+        let url = MockDataNode::new()
+            .unary(
+                "GetLatestMarketDepth",
+                move |req: GetLatestMarketDepthRequest| {
+                    assert_eq!(req.market_id, "m1");
+                    return Ok(match s.fetch_add(1, Ordering::SeqCst) {
+                        0 => depth(10, vec![level(99, 2)], vec![level(101, 1)]),
+                        _ => depth(20, vec![level(95, 1)], vec![level(105, 1)]),
+                    });
+                },
+            )
+            .streaming(
+                "ObserveMarketsDepthUpdates",
+                |req: ObserveMarketsDepthUpdatesRequest| {
+                    assert_eq!(req.market_ids, vec!["m1"]);
+                    let updates = vec![
+                        update(8, 10, vec![level(50, 1)], vec![]),
+                        update(10, 12, vec![level(100, 3)], vec![]),
+                        // 12 to 15 is missing
+                        update(15, 16, vec![level(10, 1)], vec![]),
+                        update(16, 20, vec![level(11, 1)], vec![]),
+                        update(20, 21, vec![], vec![level(104, 7)]),
+                    ];
+                    let updates = updates.into_iter().map(|u| {
+                        return Ok(ObserveMarketsDepthUpdatesResponse { update: vec![u] });
+                    });
+                    let updates: BoxStream<'static, Result<_, Status>> =
+                        Box::pin(stream::iter(updates).chain(stream::pending()));
+                    return Ok(updates);
+                },
+            )
+            .start()
+            .await;
+
+        let clt = DataNodeClient::connect(url).await.unwrap();
+        let ob = OrderBook::start(&clt, "m1").await.unwrap();
+        let mut watch = ob.watch();
+        let book = tokio::time::timeout(
+            Duration::from_secs(5),
+            watch.wait_for(|b| b.sequence_number == 21),
+        )
+        .await
+        .unwrap()
+        .unwrap()
+        .clone();
+
+        assert_eq!(snapshots.load(Ordering::SeqCst), 2);
+        assert_eq!(prices(&book.depth(5).0), vec![(95, 1)]);
+        assert_eq!(prices(&book.depth(5).1), vec![(104, 7), (105, 1)]);
+        assert_eq!(ob.best_ask().unwrap().price, 104);
+        assert_eq!(
+            ob.mid(),
+            Some(Mid {
+                price: 99,
+                half: true
+            })
+        );
+        assert_eq!(ob.mid_f64(), Some(99.5));
+    }
+
+    #[tokio::test]
+    async fn order_book_stops_on_errors() {
+        let url = MockDataNode::new()
+            .unary("GetLatestMarketDepth", |_: GetLatestMarketDepthRequest| {
+                return Ok(depth(1, vec![], vec![]));
+            })
+            .streaming(
+                "ObserveMarketsDepthUpdates",
+                |_: ObserveMarketsDepthUpdatesRequest| {
+                    let error = async {
+                        tokio::time::sleep(Duration::from_millis(10)).await;
+                        return Err(Status::invalid_argument("bad market"));
+                    };
+                    let updates: BoxStream<'static, Result<ObserveMarketsDepthUpdatesResponse, _>> =
+                        Box::pin(stream::once(error));
+                    return Ok(updates);
+                },
+            )
+            .start()
+            .await;
+
+        let clt = DataNodeClient::connect(url).await.unwrap();
+        let ob = OrderBook::start(&clt, "m1").await.unwrap();
+        let mut watch = ob.watch();
+        match ob.stopped().await {
+            Error::Status(s) => assert_eq!(s.code(), tonic::Code::InvalidArgument),
+            e => panic!("unexpected error {:?}", e),
+        }
+        assert!(watch.changed().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn order_book_subscribes_before_the_snapshot() {
+        let calls = Arc::new(Mutex::new(vec![]));
+        let (c1, c2) = (calls.clone(), calls.clone());
+        let url = MockDataNode::new()
+            .unary(
+                "GetLatestMarketDepth",
+                move |_: GetLatestMarketDepthRequest| {
+                    c1.lock().unwrap().push("depth");
+                    return Ok(depth(10, vec![level(99, 2)], vec![]));
+                },
+            )
+            .streaming(
+                "ObserveMarketsDepthUpdates",
+                move |_: ObserveMarketsDepthUpdatesRequest| {
+                    c2.lock().unwrap().push("subscribe");
+                    // the first update was made before the snapshot
+                    let updates = vec![
+                        update(9, 10, vec![level(99, 2)], vec![]),
+                        update(10, 11, vec![level(98, 1)], vec![]),
+                    ];
+                    let updates = updates.into_iter().map(|u| {
+                        return Ok(ObserveMarketsDepthUpdatesResponse { update: vec![u] });
+                    });
+                    let updates: BoxStream<'static, Result<_, Status>> =
+                        Box::pin(stream::iter(updates).chain(stream::pending()));
+                    return Ok(updates);
+                },
+            )
+            .start()
+            .await;
+
+        let clt = DataNodeClient::connect(url).await.unwrap();
+        let ob = OrderBook::start(&clt, "m1").await.unwrap();
+        let mut watch = ob.watch();
+        let book = tokio::time::timeout(
+            Duration::from_secs(5),
+            watch.wait_for(|b| b.sequence_number == 11),
+        )
+        .await
+        .unwrap()
+        .unwrap()
+        .clone();
+
+        assert_eq!(*calls.lock().unwrap(), vec!["subscribe", "depth"]);
+        assert_eq!(prices(&book.depth(5).0), vec![(99, 2), (98, 1)]);
+    }
+}
@@ -0,0 +1,820 @@
+//! Orders sent through `Transact`, followed with the order updates of
+//! the data node until they are filled, cancelled or rejected.
+
+use crate::subscription::{self, Backoff, Event, Subscription};
+use crate::{DataNodeClient, Error, OrderFilter};
+use futures::stream::StreamExt;
+use sha3::{Digest, Sha3_256};
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
+use vega_crypto::Transact;
+use vega_protobufs::datanode::api::v2::{
+    observe_orders_response, DateRange, GetVegaTimeRequest, ObserveOrdersRequest,
+    ObserveOrdersResponse,
+};
+use vega_protobufs::vega::commands::v1::{
+    input_data::Command, BatchMarketInstructions, OrderAmendment, OrderCancellation,
+    OrderSubmission, Transaction,
+};
+use vega_protobufs::vega::{order, Order, OrderError};
+
+/// The state of an order sent through the `OrderManager`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum OrderState {
+    /// Sent to the network, not seen by the data node yet
+    Pending,
+    /// On the book, or parked until the market leaves its auction
+    Active(Order),
+    Filled(Order),
+    /// Cancelled, stopped or expired, possibly after being partially filled
+    Cancelled(Order),
+    /// Refused by the network, the order is only known when it was
+    /// rejected by the market rather than with its transaction
+    Rejected {
+        reason: String,
+        order: Option<Order>,
+    },
+}
+
+impl OrderState {
+    fn of(order: Order) -> OrderState {
+        use order::Status;
+        return match Status::try_from(order.status) {
+            Ok(Status::Filled) => OrderState::Filled(order),
+            Ok(Status::Cancelled | Status::Stopped | Status::Expired | Status::PartiallyFilled) => {
+                OrderState::Cancelled(order)
+            }
+            Ok(Status::Rejected) => {
+                let reason = order
+                    .reason
+                    .and_then(|r| OrderError::try_from(r).ok())
+                    .unwrap_or(OrderError::Unspecified);
+                OrderState::Rejected {
+                    reason: reason.as_str_name().to_string(),
+                    order: Some(order),
+                }
+            }
+            _ => OrderState::Active(order),
+        };
+    }
+
+    /// Whether the order can't change anymore.
+    pub fn is_final(&self) -> bool {
+        return !matches!(self, OrderState::Pending | OrderState::Active(_));
+    }
+
+    /// The last version of the order seen by the data node.
+    pub fn order(&self) -> Option<&Order> {
+        return match self {
+            OrderState::Pending => None,
+            OrderState::Active(o) | OrderState::Filled(o) | OrderState::Cancelled(o) => Some(o),
+            OrderState::Rejected { order, .. } => order.as_ref(),
+        };
+    }
+}
+
+/// An order followed by the `OrderManager`.
+#[derive(Debug, Clone)]
+pub struct OrderHandle {
+    reference: String,
+    order_id: Option<String>,
+    state: watch::Receiver<OrderState>,
+}
+
+impl OrderHandle {
+    /// The reference of the order, given with the submission or generated.
+    pub fn reference(&self) -> &str {
+        return &self.reference;
+    }
+
+    /// The id of the order, known before the order is seen by the
+    /// data node for the orders not submitted in a batch.
+    pub fn order_id(&self) -> Option<String> {
+        return match self.state.borrow().order() {
+            Some(o) => Some(o.id.clone()),
+            None => self.order_id.clone(),
+        };
+    }
+
+    pub fn state(&self) -> OrderState {
+        return self.state.borrow().clone();
+    }
+
+    /// A receiver notified of each new state of the order.
+    pub fn watch(&self) -> watch::Receiver<OrderState> {
+        return self.state.clone();
+    }
+
+    /// Wait for the order to be filled, cancelled or rejected, fails with
+    /// `Disconnected` if the manager stops following the order before.
+    pub async fn done(&self) -> Result<OrderState, Error> {
+        let mut state = self.state.clone();
+        return match state.wait_for(OrderState::is_final).await {
+            Ok(s) => Ok(s.clone()),
+            Err(_) => Err(Error::Disconnected),
+        };
+    }
+}
+
+/// The id given by the network to the order submitted by the transaction,
+/// the hash of the transaction signature. It does not apply to the orders
+/// of a batch.
+pub fn deterministic_order_id(tx: &Transaction) -> Option<String> {
+    let signature = hex::decode(&tx.signature.as_ref()?.value).ok()?;
+    return Some(hex::encode(Sha3_256::digest(signature)));
+}
+
+/// The orders not final yet.
+#[derive(Default)]
+struct Tracked {
+    /// The state of the orders by reference
+    orders: HashMap<String, watch::Sender<OrderState>>,
+    /// The reference of the orders by id
+    references: HashMap<String, String>,
+    /// The references of the orders whose id is not known yet
+    unbound: HashSet<String>,
+    /// Set once the order updates are not followed anymore
+    stopped: bool,
+}
+
+impl Tracked {
+    fn track(&mut self, reference: &str, order_id: Option<&str>) -> watch::Receiver<OrderState> {
+        let (tx, rx) = watch::channel(OrderState::Pending);
+        // the sender is dropped right away once stopped, failing `OrderHandle::done`
+        if !self.stopped {
+            self.orders.insert(reference.to_string(), tx);
+            if let Some(id) = order_id {
+                self.references
+                    .insert(id.to_string(), reference.to_string());
+            } else {
+                self.unbound.insert(reference.to_string());
+            }
+        }
+        return rx;
+    }
+
+    /// Apply an order update, matched by id, or by reference for the
+    /// orders whose id is not known yet.
+    fn update(&mut self, order: Order) {
+        let reference = match self.references.get(&order.id) {
+            Some(r) => r.clone(),
+            None if self.unbound.remove(&order.reference) => order.reference.clone(),
+            None => return,
+        };
+        let Some(tx) = self.orders.get(&reference) else {
+            return;
+        };
+        // an older version of the order is ignored
+        if matches!(tx.borrow().order(), Some(o) if o.updated_at > order.updated_at) {
+            return;
+        }
+        self.references.insert(order.id.clone(), reference.clone());
+        let state = OrderState::of(order);
+        let is_final = state.is_final();
+        tx.send_replace(state);
+        if is_final {
+            self.remove(&reference);
+        }
+    }
+
+    fn reject(&mut self, reference: &str, reason: String) {
+        if let Some(tx) = self.orders.get(reference) {
+            tx.send_replace(OrderState::Rejected {
+                reason,
+                order: None,
+            });
+        }
+        self.remove(reference);
+    }
+
+    fn remove(&mut self, reference: &str) {
+        self.orders.remove(reference);
+        self.references.retain(|_, r| r != reference);
+        self.unbound.remove(reference);
+    }
+
+    fn stop(&mut self) {
+        self.stopped = true;
+        self.orders.clear();
+        self.references.clear();
+        self.unbound.clear();
+    }
+}
+
+/// Sends orders through `Transact` and follows their updates on the data
+/// node, the orders are matched to their updates by id or by reference.
+pub struct OrderManager {
+    transact: tokio::sync::Mutex<Transact>,
+    party_id: String,
+    tracked: Arc<Mutex<Tracked>>,
+    /// The prefix of the generated references
+    session: String,
+    count: AtomicU64,
+    task: JoinHandle<Error>,
+}
+
+impl OrderManager {
+    /// Start following the orders of the party of the transact key, the
+    /// orders sent from now on are tracked.
+    pub async fn start(client: &DataNodeClient, transact: Transact) -> Result<OrderManager, Error> {
+        let party_id = transact.public_key();
+        let req = ObserveOrdersRequest {
+            party_ids: vec![party_id.clone()],
+            ..Default::default()
+        };
+        // subscribed before the first order is sent, no update is missed
+        let events =
+            subscription::subscribe_now(client.client.clone(), req, Backoff::default()).await?;
+        // the orders tracked are all placed from now on
+        let mut data = client.client.clone();
+        let now = data.get_vega_time(GetVegaTimeRequest {}).await?;
+        let placed = OrderFilter {
+            party_ids: vec![party_id.clone()],
+            date_range: Some(DateRange {
+                start_timestamp: Some(now.get_ref().timestamp),
+                end_timestamp: None,
+            }),
+            ..Default::default()
+        };
+        let tracked = Arc::new(Mutex::new(Tracked::default()));
+        let task = tokio::spawn(follow(client.clone(), placed, events, tracked.clone()));
+        let started = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        return Ok(OrderManager {
+            transact: tokio::sync::Mutex::new(transact),
+            party_id,
+            tracked,
+            session: format!("{:x}", started.as_nanos()),
+            count: AtomicU64::new(0),
+            task,
+        });
+    }
+
+    /// The party of the orders.
+    pub fn party_id(&self) -> &str {
+        return &self.party_id;
+    }
+
+    /// Submit an order, a reference is generated if the submission has
+    /// none. The order is rejected right away if the network refuses its
+    /// transaction, and not tracked if the transaction could not be sent.
+    /// Fails with `DuplicateReference` if an order with the same reference
+    /// is still followed.
+    ///
+    /// The order is matched to its updates by id. The orders of a batch are
+    /// matched by reference until their id is known, a reference given by
+    /// the caller may then match an order the party placed some other way.
+    pub async fn submit(&self, mut submission: OrderSubmission) -> Result<OrderHandle, Error> {
+        if submission.reference.is_empty() {
+            submission.reference = self.next_reference();
+        }
+        let reference = submission.reference.clone();
+        return self
+            .send_submission(Command::OrderSubmission(submission), reference)
+            .await;
+    }
+
+    /// Amend an order, its handle sees the amended order.
+    pub async fn amend(&self, amendment: OrderAmendment) -> Result<(), Error> {
+        return self.send(Command::OrderAmendment(amendment)).await;
+    }
+
+    /// Cancel an order, its handle sees the cancelled order.
+    pub async fn cancel(&self, order_id: &str, market_id: &str) -> Result<(), Error> {
+        let cancellation = OrderCancellation {
+            order_id: order_id.to_string(),
+            market_id: market_id.to_string(),
+        };
+        return self.send(Command::OrderCancellation(cancellation)).await;
+    }
+
+    /// Cancel an order of the market and submit its replacement within a
+    /// single batch, so both happen in the same block. The replacement is
+    /// not conditional on the cancellation, both orders are live if the
+    /// cancellation fails. Returns the handle of the replacement.
+    pub async fn cancel_replace(
+        &self,
+        order_id: &str,
+        market_id: &str,
+        mut replacement: OrderSubmission,
+    ) -> Result<OrderHandle, Error> {
+        if replacement.reference.is_empty() {
+            replacement.reference = self.next_reference();
+        }
+        let reference = replacement.reference.clone();
+        let batch = BatchMarketInstructions {
+            cancellations: vec![OrderCancellation {
+                order_id: order_id.to_string(),
+                market_id: market_id.to_string(),
+            }],
+            submissions: vec![replacement],
+            ..Default::default()
+        };
+        return self
+            .send_submission(Command::BatchMarketInstructions(batch), reference)
+            .await;
+    }
+
+    /// Wait for the order updates to stop being followed, returns the
+    /// error which stopped them. The handles of the orders not final
+    /// yet fail once stopped.
+    pub async fn stopped(mut self) -> Error {
+        return match (&mut self.task).await {
+            Ok(e) => e,
+            Err(_) => Error::Disconnected,
+        };
+    }
+
+    fn next_reference(&self) -> String {
+        let n = self.count.fetch_add(1, Ordering::Relaxed);
+        return format!("{}-{}", self.session, n);
+    }
+
+    /// Send a command submitting a single order, tracked from before the
+    /// transaction is sent.
+    async fn send_submission(&self, cmd: Command, reference: String) -> Result<OrderHandle, Error> {
+        let mut transact = self.transact.lock().await;
+        // the submissions are sent one at a time, the reference is
+        // still free once the transaction is signed
+        if self.tracked.lock().unwrap().orders.contains_key(&reference) {
+            return Err(Error::DuplicateReference(reference));
+        }
+        let tx = transact.sign(&cmd).await?;
+        let order_id = match cmd {
+            Command::OrderSubmission(_) => deterministic_order_id(&tx),
+            _ => None,
+        };
+        let state = self
+            .tracked
+            .lock()
+            .unwrap()
+            .track(&reference, order_id.as_deref());
+        match transact.send(tx).await {
+            Ok(res) if res.success => {}
+            Ok(res) => {
+                let reason = res.error.unwrap_or_default();
+                self.tracked.lock().unwrap().reject(&reference, reason);
+            }
+            Err(e) => {
+                self.tracked.lock().unwrap().remove(&reference);
+                return Err(e.into());
+            }
+        }
+        return Ok(OrderHandle {
+            reference,
+            order_id,
+            state,
+        });
+    }
+
+    async fn send(&self, cmd: Command) -> Result<(), Error> {
+        let res = self.transact.lock().await.send(cmd).await?;
+        if !res.success {
+            return Err(Error::TransactionRejected(res.error.unwrap_or_default()));
+        }
+        return Ok(());
+    }
+}
+
+impl Drop for OrderManager {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Follow the updates of the tracked orders, `placed` selects the
+/// orders placed since the manager started.
+async fn follow(
+    client: DataNodeClient,
+    placed: OrderFilter,
+    events: Subscription<ObserveOrdersResponse>,
+    tracked: Arc<Mutex<Tracked>>,
+) -> Error {
+    let e = follow_updates(&client, &placed, events, &tracked).await;
+    tracked.lock().unwrap().stop();
+    return e;
+}
+
+async fn follow_updates(
+    client: &DataNodeClient,
+    placed: &OrderFilter,
+    mut events: Subscription<ObserveOrdersResponse>,
+    tracked: &Mutex<Tracked>,
+) -> Error {
+    loop {
+        let resync = match events.next().await {
+            Some(Ok(Event::Update(resp))) => {
+                use observe_orders_response::Response;
+                let orders = match resp.response {
+                    Some(Response::Snapshot(s)) => s.orders,
+                    Some(Response::Updates(u)) => u.orders,
+                    None => vec![],
+                };
+                let mut tracked = tracked.lock().unwrap();
+                for order in orders.into_iter() {
+                    tracked.update(order);
+                }
+                false
+            }
+            // the stream starts again with the live orders only, the
+            // tracked orders closed while it was interrupted are listed
+            Some(Ok(Event::Reconnected | Event::GapDetected)) => true,
+            Some(Err(e)) => return e,
+            None => return Error::Disconnected,
+        };
+        if resync {
+            let backoff = Backoff::default();
+            if let Err(e) = backoff
+                .retry(|| resync_orders(client, placed, tracked))
+                .await
+            {
+                return e;
+            }
+        }
+    }
+}
+
+/// Fetch the last version of the tracked orders, for the updates
+/// missed while the stream was interrupted. They are all listed at
+/// once, among the orders placed since the manager started.
+async fn resync_orders(
+    client: &DataNodeClient,
+    placed: &OrderFilter,
+    tracked: &Mutex<Tracked>,
+) -> Result<(), Error> {
+    if tracked.lock().unwrap().orders.is_empty() {
+        return Ok(());
+    }
+    let orders = client.orders(placed.clone()).await?;
+    let mut tracked = tracked.lock().unwrap();
+    for order in orders.into_iter() {
+        tracked.update(order);
+    }
+    return Ok(());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::MockDataNode;
+    use futures::channel::mpsc;
+    use prost::Message;
+    use std::collections::VecDeque;
+    use tonic::Status;
+    use vega_crypto::Credentials;
+    use vega_protobufs::datanode::api::v2::{
+        GetVegaTimeResponse, ListOrdersRequest, ListOrdersResponse, OrderConnection, OrderEdge,
+        OrderUpdates,
+    };
+    use vega_protobufs::vega::api::v1::{
+        LastBlockHeightRequest, LastBlockHeightResponse, SubmitTransactionRequest,
+        SubmitTransactionResponse,
+    };
+    use vega_protobufs::vega::commands::v1::InputData;
+
+    struct Network {
+        manager: OrderManager,
+        /// The transactions sent
+        txs: Arc<Mutex<Vec<Transaction>>>,
+        updates: mpsc::UnboundedSender<Order>,
+        /// The streams served when the orders are subscribed again
+        streams: Arc<Mutex<VecDeque<mpsc::UnboundedReceiver<Order>>>>,
+        /// The orders listed, and the requests listing them
+        listed: Arc<Mutex<Vec<Order>>>,
+        lists: Arc<Mutex<Vec<ListOrdersRequest>>>,
+    }
+
+    impl Network {
+        fn command(&self, n: usize) -> Command {
+            let txs = self.txs.lock().unwrap();
+            let input = InputData::decode(txs[n].input_data.as_slice()).unwrap();
+            return input.command.unwrap();
+        }
+
+        fn update(&self, order: Order) {
+            self.updates.unbounded_send(order).unwrap();
+        }
+
+        /// End the stream of updates, the next one is served to the
+        /// subscription once it reconnects.
+        fn reconnect(&mut self) {
+            let (updates, rx) = mpsc::unbounded();
+            self.streams.lock().unwrap().push_back(rx);
+            self.updates = updates;
+        }
+    }
+
+    /// A network accepting the transactions or refusing them with `spam`.
+    async fn network(accept: bool) -> Network {
+        let txs = Arc::new(Mutex::new(vec![]));
+        let (updates, rx) = mpsc::unbounded();
+        let streams = Arc::new(Mutex::new(VecDeque::from([rx])));
+        let listed = Arc::new(Mutex::new(vec![]));
+        let lists = Arc::new(Mutex::new(vec![]));
+        let (sent, s, l, ls) = (txs.clone(), streams.clone(), listed.clone(), lists.clone());
+        let url = MockDataNode::new()
+            .unary(
+                "vega.api.v1.CoreService/LastBlockHeight",
+                |_: LastBlockHeightRequest| {
+                    return Ok(LastBlockHeightResponse {
+                        height: 10,
+                        hash: "ab".repeat(32),
+                        chain_id: "test-chain".to_string(),
+                        ..Default::default()
+                    });
+                },
+            )
+            .unary(
+                "vega.api.v1.CoreService/SubmitTransaction",
+                move |req: SubmitTransactionRequest| {
+                    sent.lock().unwrap().push(req.tx.unwrap());
+                    return Ok(SubmitTransactionResponse {
+                        success: accept,
+                        tx_hash: "tx".to_string(),
+                        data: if accept { "" } else { "spam" }.to_string(),
+                        ..Default::default()
+                    });
+                },
+            )
+            .unary("GetVegaTime", |_: GetVegaTimeRequest| {
+                return Ok(GetVegaTimeResponse { timestamp: 1000 });
+            })
+            .unary("ListOrders", move |req: ListOrdersRequest| {
+                ls.lock().unwrap().push(req);
+                let edges = l
+                    .lock()
+                    .unwrap()
+                    .iter()
+                    .map(|o: &Order| OrderEdge {
+                        cursor: o.id.clone(),
+                        node: Some(o.clone()),
+                    })
+                    .collect();
+                return Ok(ListOrdersResponse {
+                    orders: Some(OrderConnection {
+                        edges,
+                        page_info: None,
+                    }),
+                });
+            })
+            .streaming("ObserveOrders", move |_: ObserveOrdersRequest| {
+                let Some(updates) = s.lock().unwrap().pop_front() else {
+                    return Err(Status::invalid_argument("subscribed again"));
+                };
+                return Ok(updates
+                    .map(|o| {
+                        Ok(ObserveOrdersResponse {
+                            response: Some(observe_orders_response::Response::Updates(
+                                OrderUpdates { orders: vec![o] },
+                            )),
+                        })
+                    })
+                    .boxed());
+            })
+            .start()
+            .await;
+
+        let transact = Transact::new(Credentials::Seed(&[7u8; 32]), url.clone())
+            .await
+            .unwrap();
+        let clt = DataNodeClient::connect(url).await.unwrap();
+        let manager = OrderManager::start(&clt, transact).await.unwrap();
+        return Network {
+            manager,
+            txs,
+            updates,
+            streams,
+            listed,
+            lists,
+        };
+    }
+
+    fn submission(reference: &str) -> OrderSubmission {
+        return OrderSubmission {
+            market_id: "m1".to_string(),
+            price: "100".to_string(),
+            size: 2,
+            reference: reference.to_string(),
+            ..Default::default()
+        };
+    }
+
+    fn order(id: &str, reference: &str, status: order::Status, updated_at: i64) -> Order {
+        return Order {
+            id: id.to_string(),
+            market_id: "m1".to_string(),
+            reference: reference.to_string(),
+            status: status as i32,
+            updated_at,
+            ..Default::default()
+        };
+    }
+
+    async fn active(handle: &OrderHandle) {
+        handle
+            .watch()
+            .wait_for(|s| matches!(s, OrderState::Active(_)))
+            .await
+            .unwrap();
+    }
+
+    #[test]
+    fn order_states() {
+        use order::Status::*;
+        let state = |status| OrderState::of(order("o1", "r1", status, 1));
+        assert!(matches!(state(Parked), OrderState::Active(_)));
+        assert!(matches!(state(Filled), OrderState::Filled(_)));
+        assert!(matches!(state(PartiallyFilled), OrderState::Cancelled(_)));
+        assert!(matches!(state(Expired), OrderState::Cancelled(_)));
+        let mut rejected = order("o1", "r1", Rejected, 1);
+        rejected.reason = Some(OrderError::MarginCheckFailed as i32);
+        match OrderState::of(rejected) {
+            OrderState::Rejected { reason, order } => {
+                assert_eq!(reason, "ORDER_ERROR_MARGIN_CHECK_FAILED");
+                assert_eq!(order.unwrap().id, "o1");
+            }
+            s => panic!("unexpected state {:?}", s),
+        }
+        assert!(!OrderState::Pending.is_final());
+        assert!(!state(Active).is_final());
+        assert!(state(Stopped).is_final());
+        assert_eq!(state(Active).order().unwrap().id, "o1");
+    }
+
+    #[tokio::test]
+    async fn orders_are_followed_until_filled() {
+        use order::Status::*;
+        let net = network(true).await;
+        let handle = net.manager.submit(submission("r1")).await.unwrap();
+        assert_eq!(handle.reference(), "r1");
+        assert_eq!(handle.state(), OrderState::Pending);
+        let id = handle.order_id().unwrap();
+        let tx = net.txs.lock().unwrap()[0].clone();
+        assert_eq!(deterministic_order_id(&tx), Some(id.clone()));
+        assert_eq!(id.len(), 64);
+
+        // orders of the party not sent by the manager are ignored
+        net.update(order("o9", "other", Active, 1));
+        net.update(order(&id, "r1", Active, 1));
+        active(&handle).await;
+
+        let amendment = OrderAmendment {
+            order_id: id.clone(),
+            market_id: "m1".to_string(),
+            price: Some("101".to_string()),
+            ..Default::default()
+        };
+        net.manager.amend(amendment.clone()).await.unwrap();
+        assert_eq!(net.command(1), Command::OrderAmendment(amendment));
+        let mut amended = order(&id, "r1", Active, 3);
+        amended.price = "101".to_string();
+        net.update(amended);
+        // an older version of the order is ignored
+        net.update(order(&id, "r1", Filled, 2));
+        // matched by id, the reference is not needed anymore
+        net.update(order(&id, "", Filled, 4));
+        match handle.done().await.unwrap() {
+            OrderState::Filled(o) => assert_eq!(o.updated_at, 4),
+            s => panic!("unexpected state {:?}", s),
+        }
+        assert!(net.manager.tracked.lock().unwrap().orders.is_empty());
+    }
+
+    #[tokio::test]
+    async fn rejected_transactions() {
+        let net = network(false).await;
+        let handle = net.manager.submit(submission("")).await.unwrap();
+        assert!(!handle.reference().is_empty());
+        assert_eq!(
+            handle.done().await.unwrap(),
+            OrderState::Rejected {
+                reason: "spam".to_string(),
+                order: None,
+            }
+        );
+        match net.manager.cancel("o1", "m1").await {
+            Err(Error::TransactionRejected(reason)) => assert_eq!(reason, "spam"),
+            r => panic!("unexpected result {:?}", r),
+        }
+        assert_eq!(
+            net.command(1),
+            Command::OrderCancellation(OrderCancellation {
+                order_id: "o1".to_string(),
+                market_id: "m1".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn orders_are_matched_by_reference_until_their_id_is_known() {
+        use order::Status::*;
+        let mut tracked = Tracked::default();
+        let submitted = tracked.track("r1", Some("o1"));
+        let batched = tracked.track("r2", None);
+
+        // an order placed outside the manager with the same reference
+        tracked.update(order("x1", "r1", Active, 1));
+        assert_eq!(*submitted.borrow(), OrderState::Pending);
+
+        tracked.update(order("o2", "r2", Active, 1));
+        assert_eq!(batched.borrow().order().unwrap().id, "o2");
+        tracked.update(order("x2", "r2", Filled, 2));
+        assert_eq!(batched.borrow().order().unwrap().id, "o2");
+        assert!(!batched.borrow().is_final());
+    }
+
+    #[tokio::test]
+    async fn tracked_orders_are_listed_at_once_after_a_reconnection() {
+        use order::Status::*;
+        let mut net = network(true).await;
+        let first = net.manager.submit(submission("r1")).await.unwrap();
+        let second = net.manager.submit(submission("r2")).await.unwrap();
+        let (id1, id2) = (first.order_id().unwrap(), second.order_id().unwrap());
+        *net.listed.lock().unwrap() = vec![
+            order(&id1, "r1", Filled, 2),
+            order(&id2, "r2", Active, 1),
+            order("o9", "other", Active, 1),
+        ];
+
+        net.reconnect();
+        assert!(matches!(first.done().await.unwrap(), OrderState::Filled(_)));
+        active(&second).await;
+
+        let lists = net.lists.lock().unwrap();
+        assert_eq!(lists.len(), 1);
+        let filter = lists[0].filter.as_ref().unwrap();
+        assert_eq!(filter.party_ids, vec![net.manager.party_id()]);
+        assert_eq!(filter.reference, None);
+        assert_eq!(
+            filter.date_range.as_ref().unwrap().start_timestamp,
+            Some(1000)
+        );
+    }
+
+    #[tokio::test]
+    async fn duplicate_references() {
+        use order::Status::*;
+        let net = network(true).await;
+        let first = net.manager.submit(submission("r1")).await.unwrap();
+        match net.manager.submit(submission("r1")).await {
+            Err(Error::DuplicateReference(r)) => assert_eq!(r, "r1"),
+            r => panic!("unexpected result {:?}", r.map(|h| h.state())),
+        }
+        // refused before the transaction is signed
+        assert_eq!(net.txs.lock().unwrap().len(), 1);
+
+        // the first order is still followed
+        let id = first.order_id().unwrap();
+        net.update(order(&id, "r1", Filled, 1));
+        assert!(matches!(first.done().await.unwrap(), OrderState::Filled(_)));
+
+        // the reference is free again once the order is final
+        let second = net.manager.submit(submission("r1")).await.unwrap();
+        assert_ne!(second.order_id(), Some(id));
+    }
+
+    #[tokio::test]
+    async fn cancel_replace() {
+        use order::Status::*;
+        let net = network(true).await;
+        let first = net.manager.submit(submission("r1")).await.unwrap();
+        let id = first.order_id().unwrap();
+        net.update(order(&id, "r1", Active, 1));
+        active(&first).await;
+
+        let mut other = submission("");
+        other.market_id = "m2".to_string();
+        let replacement = net.manager.cancel_replace(&id, "m1", other).await.unwrap();
+        // the orders of a batch are only matched by reference
+        assert_eq!(replacement.order_id(), None);
+        let Command::BatchMarketInstructions(batch) = net.command(1) else {
+            panic!("unexpected command {:?}", net.command(1));
+        };
+        assert_eq!(batch.cancellations[0].order_id, id);
+        assert_eq!(batch.cancellations[0].market_id, "m1");
+        assert_eq!(batch.submissions[0].market_id, "m2");
+        assert_eq!(batch.submissions[0].reference, replacement.reference());
+
+        net.update(order(&id, "r1", Cancelled, 2));
+        net.update(order("o2", replacement.reference(), Active, 2));
+        assert!(matches!(
+            first.done().await.unwrap(),
+            OrderState::Cancelled(_)
+        ));
+        active(&replacement).await;
+        assert_eq!(replacement.order_id().as_deref(), Some("o2"));
+
+        // the manager stopping fails the orders not final yet
+        drop(net.updates);
+        match net.manager.stopped().await {
+            Error::Status(s) => assert_eq!(s.code(), tonic::Code::InvalidArgument),
+            e => panic!("unexpected error {:?}", e),
+        }
+        assert!(matches!(replacement.done().await, Err(Error::Disconnected)));
+    }
+}
@@ -0,0 +1,396 @@
+//! Cursor pagination of the `List*` RPCs of the trading data API.
+
+use crate::Error;
+use futures::future::BoxFuture;
+use futures::stream::{self, BoxStream, StreamExt, TryStreamExt};
+use tonic::transport::Channel;
+use vega_protobufs::datanode::api::v2::{
+    self as v2, trading_data_service_client::TradingDataServiceClient, PageInfo, Pagination,
+};
+use vega_protobufs::vega::{self, commands::v1 as commands, events::v1 as events};
+
+/// The largest page the data node serves by default.
+pub const MAX_PAGE_SIZE: i32 = 1000;
+
+/// How to walk through the pages of a `List*` RPC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageOptions {
+    /// Number of nodes requested per page
+    pub page_size: i32,
+    /// Start from the last page and follow the cursors backward,
+    /// the nodes are then listed from the last to the first
+    pub backward: bool,
+}
+
+impl Default for PageOptions {
+    fn default() -> PageOptions {
+        return PageOptions {
+            page_size: MAX_PAGE_SIZE,
+            backward: false,
+        };
+    }
+}
+
+impl PageOptions {
+    pub fn page_size(mut self, page_size: i32) -> PageOptions {
+        self.page_size = page_size;
+        return self;
+    }
+
+    pub fn backward(mut self) -> PageOptions {
+        self.backward = true;
+        return self;
+    }
+
+    fn pagination(&self, cursor: Option<String>) -> Pagination {
+        return match self.backward {
+            false => Pagination {
+                first: Some(self.page_size),
+                after: cursor,
+                ..Default::default()
+            },
+            true => Pagination {
+                last: Some(self.page_size),
+                before: cursor,
+                ..Default::default()
+            },
+        };
+    }
+
+    /// The cursor of the page following this one, in the walk direction.
+    fn next_cursor(&self, info: PageInfo) -> Option<String> {
+        let (more, cursor) = match self.backward {
+            false => (info.has_next_page, info.end_cursor),
+            true => (info.has_previous_page, info.start_cursor),
+        };
+        return (more && !cursor.is_empty()).then_some(cursor);
+    }
+}
+
+/// A page of the nodes listed by a `List*` RPC.
+#[derive(Debug, Clone)]
+pub struct Page<N> {
+    pub nodes: Vec<N>,
+    pub info: Option<PageInfo>,
+}
+
+/// The request of a paginated `List*` RPC.
+pub trait ListRequest: Clone + Send + 'static {
+    /// The type of the listed items
+    type Node: Send + 'static;
+
+    /// Send the request for a single page.
+    fn list_page(
+        self,
+        client: TradingDataServiceClient<Channel>,
+        pagination: Pagination,
+    ) -> BoxFuture<'static, Result<Page<Self::Node>, Error>>;
+}
+
+/// Stream the nodes of all the pages, fetching a page when
+/// the nodes of the previous one have been consumed.
+pub(crate) fn paginate<R: ListRequest>(
+    client: TradingDataServiceClient<Channel>,
+    req: R,
+    options: PageOptions,
+) -> BoxStream<'static, Result<R::Node, Error>> {
+    // the state is the cursor of the next page, `None` once done
+    let pages = stream::try_unfold(Some(None), move |cursor: Option<Option<String>>| {
+        let client = client.clone();
+        let req = req.clone();
+        async move {
+            let Some(cursor) = cursor else {
+                return Ok::<_, Error>(None);
+            };
+            let Page { mut nodes, info } =
+                req.list_page(client, options.pagination(cursor)).await?;
+            if options.backward {
+                nodes.reverse();
+            }
+            // an empty page ends the walk even if the node claims there is more
+            let next = match nodes.is_empty() {
+                true => None,
+                false => info.and_then(|i| options.next_cursor(i)).map(Some),
+            };
+            return Ok(Some((
+                stream::iter(nodes.into_iter().map(Ok::<_, Error>)),
+                next,
+            )));
+        }
+    });
+    return pages.try_flatten().boxed();
+}
+
+macro_rules! impl_list_request {
+    ($($req:ident => $method:ident.$field:ident: $node:ty),* $(,)?) => {
+        $(
+            impl ListRequest for v2::$req {
+                type Node = $node;
+
+                #[allow(deprecated)]
+                fn list_page(
+                    mut self,
+                    mut client: TradingDataServiceClient<Channel>,
+                    pagination: Pagination,
+                ) -> BoxFuture<'static, Result<Page<$node>, Error>> {
+                    self.pagination = Some(pagination);
+                    return Box::pin(async move {
+                        let conn = client
+                            .$method(self)
+                            .await?
+                            .into_inner()
+                            .$field
+                            .ok_or(Error::MissingField(stringify!($field)))?;
+                        return Ok(Page {
+                            nodes: conn.edges.into_iter().filter_map(|e| e.node).collect(),
+                            info: conn.page_info,
+                        });
+                    });
+                }
+            }
+        )*
+    };
+}
+
+impl_list_request! {
+    ListAccountsRequest => list_accounts.accounts: v2::AccountBalance,
+    ListOrdersRequest => list_orders.orders: vega::Order,
+    ListOrderVersionsRequest => list_order_versions.orders: vega::Order,
+    ListStopOrdersRequest => list_stop_orders.orders: events::StopOrderEvent,
+    ListGameTeamScoresRequest => list_game_team_scores.team_scores: events::GameTeamScore,
+    ListGamePartyScoresRequest => list_game_party_scores.party_scores: events::GamePartyScore,
+    ListPositionsRequest => list_positions.positions: vega::Position,
+    ListAllPositionsRequest => list_all_positions.positions: vega::Position,
+    ListLedgerEntriesRequest => list_ledger_entries.ledger_entries: v2::AggregatedLedgerEntry,
+    ListBalanceChangesRequest => list_balance_changes.balances: v2::AggregatedBalance,
+    ListTransfersRequest => list_transfers.transfers: v2::TransferNode,
+    ListCandleDataRequest => list_candle_data.candles: v2::Candle,
+    ListVotesRequest => list_votes.votes: vega::Vote,
+    ListTradesRequest => list_trades.trades: vega::Trade,
+    ListOracleSpecsRequest => list_oracle_specs.oracle_specs: vega::OracleSpec,
+    ListOracleDataRequest => list_oracle_data.oracle_data: vega::OracleData,
+    ListMarketsRequest => list_markets.markets: vega::Market,
+    ListSuccessorMarketsRequest => list_successor_markets.successor_markets: v2::SuccessorMarket,
+    ListPartiesRequest => list_parties.parties: vega::Party,
+    ListPartiesProfilesRequest => list_parties_profiles.profiles: vega::PartyProfile,
+    ListMarginLevelsRequest => list_margin_levels.margin_levels: vega::MarginLevels,
+    ListRewardsRequest => list_rewards.rewards: vega::Reward,
+    ListEpochRewardSummariesRequest => list_epoch_reward_summaries.summaries: vega::EpochRewardSummary,
+    ListDepositsRequest => list_deposits.deposits: vega::Deposit,
+    ListWithdrawalsRequest => list_withdrawals.withdrawals: vega::Withdrawal,
+    ListAssetsRequest => list_assets.assets: vega::Asset,
+    ListLiquidityProvisionsRequest => list_liquidity_provisions.liquidity_provisions: vega::LiquidityProvision,
+    ListAllLiquidityProvisionsRequest => list_all_liquidity_provisions.liquidity_provisions: v2::LiquidityProvision,
+    ListLiquidityProvidersRequest => list_liquidity_providers.liquidity_providers: v2::LiquidityProvider,
+    ListPaidLiquidityFeesRequest => list_paid_liquidity_fees.paid_liquidity_fees: events::PaidLiquidityFeesStats,
+    ListGovernanceDataRequest => list_governance_data.connection: vega::GovernanceData,
+    ListDelegationsRequest => list_delegations.delegations: vega::Delegation,
+    ListNodesRequest => list_nodes.nodes: vega::Node,
+    ListNodeSignaturesRequest => list_node_signatures.signatures: commands::NodeSignature,
+    ListNetworkParametersRequest => list_network_parameters.network_parameters: vega::NetworkParameter,
+    ListCheckpointsRequest => list_checkpoints.checkpoints: v2::Checkpoint,
+    ListKeyRotationsRequest => list_key_rotations.rotations: events::KeyRotation,
+    ListEthereumKeyRotationsRequest => list_ethereum_key_rotations.key_rotations: events::EthereumKeyRotation,
+    ListProtocolUpgradeProposalsRequest => list_protocol_upgrade_proposals.protocol_upgrade_proposals: events::ProtocolUpgradeEvent,
+    ListCoreSnapshotsRequest => list_core_snapshots.core_snapshots: events::CoreSnapshotData,
+    ListFundingPeriodsRequest => list_funding_periods.funding_periods: events::FundingPeriod,
+    ListFundingPeriodDataPointsRequest => list_funding_period_data_points.funding_period_data_points: events::FundingPeriodDataPoint,
+    ListFundingPaymentsRequest => list_funding_payments.funding_payments: v2::FundingPayment,
+    ListReferralSetsRequest => list_referral_sets.referral_sets: v2::ReferralSet,
+    ListReferralSetRefereesRequest => list_referral_set_referees.referral_set_referees: v2::ReferralSetReferee,
+    ListTeamsRequest => list_teams.teams: v2::Team,
+    ListTeamsStatisticsRequest => list_teams_statistics.statistics: v2::TeamStatistics,
+    ListTeamMembersStatisticsRequest => list_team_members_statistics.statistics: v2::TeamMemberStatistics,
+    ListTeamRefereesRequest => list_team_referees.team_referees: v2::TeamReferee,
+    ListTeamRefereeHistoryRequest => list_team_referee_history.team_referee_history: v2::TeamRefereeHistory,
+    ListGamesRequest => list_games.games: v2::Game,
+    ListPartyMarginModesRequest => list_party_margin_modes.party_margin_modes: v2::PartyMarginMode,
+    ListAmMsRequest => list_am_ms.amms: events::Amm,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::MockDataNode;
+    use crate::DataNodeClient;
+    use std::sync::{Arc, Mutex};
+    use v2::{
+        AggregatedLedgerEntriesConnection, AggregatedLedgerEntriesEdge, AggregatedLedgerEntry,
+        ListLedgerEntriesRequest, ListLedgerEntriesResponse, ListTradesRequest, ListTradesResponse,
+        TradeConnection, TradeEdge,
+    };
+
+    /// The page of `len` items selected by the pagination,
+    /// the cursor of an item is its index.
+    fn page(len: usize, p: Pagination) -> (std::ops::Range<usize>, PageInfo) {
+        let cursor = |c: Option<String>| c.map(|c| c.parse::<usize>().unwrap());
+        let (start, end) = match p.last {
+            None => {
+                let start = cursor(p.after).map_or(0, |c| c + 1);
+                (start, len.min(start + p.first.unwrap() as usize))
+            }
+            Some(last) => {
+                let end = cursor(p.before).unwrap_or(len);
+                (end.saturating_sub(last as usize), end)
+            }
+        };
+        let info = PageInfo {
+            has_next_page: end < len,
+            has_previous_page: start > 0,
+            start_cursor: start.to_string(),
+            end_cursor: (end.max(1) - 1).to_string(),
+        };
+        return (start..end, info);
+    }
+
+    /// A data node listing `len` trades, failing to serve the page after `fail_after`.
+    fn trades_node(
+        len: usize,
+        fail_after: Option<&'static str>,
+        requests: Arc<Mutex<Vec<Pagination>>>,
+    ) -> MockDataNode {
+        return MockDataNode::new().unary("ListTrades", move |req: ListTradesRequest| {
+            let p = req.pagination.unwrap();
+            requests.lock().unwrap().push(p.clone());
+            if p.after.is_some() && p.after.as_deref() == fail_after {
+                return Err(tonic::Status::internal("boom"));
+            }
+            let (range, page_info) = page(len, p);
+            let edges = range
+                .map(|i| TradeEdge {
+                    node: Some(vega::Trade {
+                        id: i.to_string(),
+                        ..Default::default()
+                    }),
+                    cursor: i.to_string(),
+                })
+                .collect();
+            return Ok(ListTradesResponse {
+                trades: Some(TradeConnection {
+                    edges,
+                    page_info: Some(page_info),
+                }),
+            });
+        });
+    }
+
+    #[tokio::test]
+    async fn forward_pagination() {
+        let requests = Arc::new(Mutex::new(vec![]));
+        let url = trades_node(5, None, requests.clone()).start().await;
+        let clt = DataNodeClient::connect(url).await.unwrap();
+
+        let opts = PageOptions::default().page_size(2);
+        let ids = clt
+            .list(ListTradesRequest::default(), opts)
+            .map_ok(|t| t.id)
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+        assert_eq!(ids, vec!["0", "1", "2", "3", "4"]);
+
+        let afters = requests
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|p| (p.first, p.after.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            afters,
+            vec![
+                (Some(2), None),
+                (Some(2), Some("1".to_string())),
+                (Some(2), Some("3".to_string()))
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn backward_pagination() {
+        let url = MockDataNode::new()
+            .unary("ListLedgerEntries", |req: ListLedgerEntriesRequest| {
+                let p = req.pagination.unwrap();
+                assert!(p.first.is_none() && p.after.is_none());
+                let (range, page_info) = page(5, p);
+                let edges = range
+                    .map(|i| AggregatedLedgerEntriesEdge {
+                        node: Some(AggregatedLedgerEntry {
+                            timestamp: i as i64,
+                            ..Default::default()
+                        }),
+                        cursor: i.to_string(),
+                    })
+                    .collect();
+                return Ok(ListLedgerEntriesResponse {
+                    ledger_entries: Some(AggregatedLedgerEntriesConnection {
+                        edges,
+                        page_info: Some(page_info),
+                    }),
+                });
+            })
+            .start()
+            .await;
+        let clt = DataNodeClient::connect(url).await.unwrap();
+
+        let opts = PageOptions::default().page_size(2).backward();
+        let timestamps = clt
+            .list(ListLedgerEntriesRequest::default(), opts)
+            .map_ok(|e| e.timestamp)
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+        assert_eq!(timestamps, vec![4, 3, 2, 1, 0]);
+    }
+
+    #[tokio::test]
+    async fn pages_are_fetched_on_demand() {
+        let requests = Arc::new(Mutex::new(vec![]));
+        let url = trades_node(10, None, requests.clone()).start().await;
+        let clt = DataNodeClient::connect(url).await.unwrap();
+
+        let opts = PageOptions::default().page_size(3);
+        let first = clt
+            .list(ListTradesRequest::default(), opts)
+            .take(3)
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+        assert_eq!(first.len(), 3);
+        assert_eq!(requests.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn errors_end_the_stream() {
+        let requests = Arc::new(Mutex::new(vec![]));
+        let url = trades_node(5, Some("1"), requests).start().await;
+        let clt = DataNodeClient::connect(url).await.unwrap();
+
+        let opts = PageOptions::default().page_size(2);
+        let trades = clt
+            .list(ListTradesRequest::default(), opts)
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(trades.len(), 3);
+        assert!(trades[..2].iter().all(|t| t.is_ok()));
+        match &trades[2] {
+            Err(Error::Status(s)) => assert_eq!(s.code(), tonic::Code::Internal),
+            r => panic!("unexpected result {:?}", r),
+        }
+    }
+
+    #[tokio::test]
+    async fn empty_page_ends_the_walk() {
+        let requests = Arc::new(Mutex::new(vec![]));
+        let url = trades_node(5, None, requests.clone()).start().await;
+        let clt = DataNodeClient::connect(url).await.unwrap();
+
+        // the node claims there is a next page after an empty one
+        let opts = PageOptions::default().page_size(0);
+        let trades = clt
+            .list(ListTradesRequest::default(), opts)
+            .collect::<Vec<_>>()
+            .await;
+        assert!(trades.is_empty());
+        assert_eq!(requests.lock().unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,838 @@
+//! A live view of the accounts, orders, positions and margin levels of
+//! a party, bootstrapped from the list RPCs and kept up to date with
+//! the streams of the data node.
+
+use crate::pagination::PageOptions;
+use crate::subscription::{self, Backoff, Event, Subscription};
+use crate::{DataNodeClient, Error};
+use futures::stream::{self, StreamExt, TryStreamExt};
+use std::collections::BTreeMap;
+use tokio::sync::{broadcast, watch};
+use tokio::task::JoinHandle;
+use vega_protobufs::datanode::api::v2::{
+    observe_accounts_response, observe_orders_response, observe_positions_response, AccountBalance,
+    AccountFilter, DateRange, ListAccountsRequest, ListMarginLevelsRequest, ListOrdersRequest,
+    ObserveAccountsRequest, ObserveMarginLevelsRequest, ObserveOrdersRequest,
+    ObservePositionsRequest, OrderFilter,
+};
+use vega_protobufs::vega::{order, AccountType, MarginLevels, Order, Position};
+
+/// The account types followed, the data node streams a single account type per subscription.
+pub const ACCOUNT_TYPES: [AccountType; 4] = [
+    AccountType::General,
+    AccountType::Margin,
+    AccountType::OrderMargin,
+    AccountType::Bond,
+];
+
+/// The maximum number of changes kept for the slowest receiver.
+const CHANGES_CAPACITY: usize = 1024;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AccountKey {
+    pub r#type: AccountType,
+    pub asset: String,
+    /// Empty for the accounts not tied to a market
+    pub market_id: String,
+}
+
+/// The state of a party at a point in time.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PartySnapshot {
+    pub party_id: String,
+    pub accounts: BTreeMap<AccountKey, AccountBalance>,
+    /// The active and parked orders, by market then by order id
+    pub orders: BTreeMap<String, BTreeMap<String, Order>>,
+    /// The positions by market, including their PnL
+    pub positions: BTreeMap<String, Position>,
+    /// The margin levels by market
+    pub margin_levels: BTreeMap<String, MarginLevels>,
+}
+
+/// A change applied to the state of a party.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Change {
+    Account(AccountBalance),
+    Order(Order),
+    Position(Position),
+    MarginLevels(MarginLevels),
+    /// The state was rebuilt from the list RPCs after updates were missed
+    Resynced,
+}
+
+impl PartySnapshot {
+    /// The balances of the accounts of the type, in all the assets and markets.
+    pub fn balances(&self, r#type: AccountType) -> impl Iterator<Item = &AccountBalance> {
+        return self
+            .accounts
+            .iter()
+            .filter(move |(k, _)| k.r#type == r#type)
+            .map(|(_, a)| a);
+    }
+
+    pub fn open_orders(&self, market_id: &str) -> impl Iterator<Item = &Order> {
+        return self
+            .orders
+            .get(market_id)
+            .into_iter()
+            .flat_map(|o| o.values());
+    }
+
+    /// Apply a change, the orders, positions and margin levels older
+    /// than the ones known are ignored. Returns whether the state changed.
+    pub fn apply(&mut self, change: &Change) -> bool {
+        match change {
+            Change::Account(a) => {
+                let key = AccountKey {
+                    r#type: AccountType::try_from(a.r#type).unwrap_or_default(),
+                    asset: a.asset.clone(),
+                    market_id: a.market_id.clone(),
+                };
+                return self.accounts.insert(key, a.clone()).as_ref() != Some(a);
+            }
+            Change::Order(o) => {
+                let orders = self.orders.entry(o.market_id.clone()).or_default();
+                if orders
+                    .get(&o.id)
+                    .is_some_and(|known| known.updated_at > o.updated_at)
+                {
+                    return false;
+                }
+                let changed = match is_open(o) {
+                    true => orders.insert(o.id.clone(), o.clone()).as_ref() != Some(o),
+                    false => orders.remove(&o.id).is_some(),
+                };
+                if orders.is_empty() {
+                    self.orders.remove(&o.market_id);
+                }
+                return changed;
+            }
+            Change::Position(p) => {
+                let known = self.positions.get(&p.market_id);
+                if known.is_some_and(|k| k.updated_at > p.updated_at || k == p) {
+                    return false;
+                }
+                self.positions.insert(p.market_id.clone(), p.clone());
+                return true;
+            }
+            Change::MarginLevels(m) => {
+                let known = self.margin_levels.get(&m.market_id);
+                if known.is_some_and(|k| k.timestamp > m.timestamp || k == m) {
+                    return false;
+                }
+                self.margin_levels.insert(m.market_id.clone(), m.clone());
+                return true;
+            }
+            Change::Resynced => return false,
+        }
+    }
+}
+
+fn is_open(order: &Order) -> bool {
+    use order::Status::*;
+    return matches!(order::Status::try_from(order.status), Ok(Active | Parked));
+}
+
+/// The state of a party maintained by a background task.
+pub struct PartyState {
+    snapshot: watch::Receiver<PartySnapshot>,
+    changes: broadcast::Sender<Change>,
+    task: JoinHandle<Error>,
+}
+
+impl PartyState {
+    /// Subscribe to the updates of the party, then build its state
+    /// from the list RPCs and apply the updates on top of it.
+    pub async fn start(client: &DataNodeClient, party_id: &str) -> Result<PartyState, Error> {
+        let (events, snapshot) = resync(client, party_id).await?;
+        let (tx, rx) = watch::channel(snapshot);
+        let (changes, _) = broadcast::channel(CHANGES_CAPACITY);
+        let task = tokio::spawn(follow(
+            client.clone(),
+            party_id.to_string(),
+            events,
+            tx,
+            changes.clone(),
+        ));
+        return Ok(PartyState {
+            snapshot: rx,
+            changes,
+            task,
+        });
+    }
+
+    /// A copy of the current state.
+    pub fn snapshot(&self) -> PartySnapshot {
+        return self.snapshot.borrow().clone();
+    }
+
+    /// A receiver notified of each new state, it sees the
+    /// sender closed once the state stops being updated.
+    pub fn watch(&self) -> watch::Receiver<PartySnapshot> {
+        return self.snapshot.clone();
+    }
+
+    /// A receiver of the changes applied from now on.
+    pub fn changes(&self) -> broadcast::Receiver<Change> {
+        return self.changes.subscribe();
+    }
+
+    /// Wait for the state to stop being updated, returns the error which stopped it.
+    pub async fn stopped(mut self) -> Error {
+        return match (&mut self.task).await {
+            Ok(e) => e,
+            Err(_) => Error::Disconnected,
+        };
+    }
+}
+
+impl Drop for PartyState {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+async fn bootstrap(client: &DataNodeClient, party_id: &str) -> Result<PartySnapshot, Error> {
+    let accounts = client
+        .list(
+            ListAccountsRequest {
+                filter: Some(AccountFilter {
+                    party_ids: vec![party_id.to_string()],
+                    account_types: ACCOUNT_TYPES.iter().map(|t| *t as i32).collect(),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            PageOptions::default(),
+        )
+        .map_ok(Change::Account);
+    let orders = client
+        .list(
+            ListOrdersRequest {
+                pagination: None,
+                filter: Some(OrderFilter {
+                    party_ids: vec![party_id.to_string()],
+                    live_only: Some(true),
+                    ..Default::default()
+                }),
+            },
+            PageOptions::default(),
+        )
+        .map_ok(Change::Order);
+    let margin_levels = client
+        .list(
+            ListMarginLevelsRequest {
+                party_id: party_id.to_string(),
+                ..Default::default()
+            },
+            PageOptions::default(),
+        )
+        .map_ok(Change::MarginLevels);
+    let (accounts, orders, positions, margin_levels) = futures::try_join!(
+        accounts.try_collect::<Vec<_>>(),
+        orders.try_collect::<Vec<_>>(),
+        client.positions(party_id),
+        margin_levels.try_collect::<Vec<_>>(),
+    )?;
+
+    let mut snapshot = PartySnapshot {
+        party_id: party_id.to_string(),
+        ..Default::default()
+    };
+    let positions = positions.into_iter().map(Change::Position);
+    for change in accounts
+        .into_iter()
+        .chain(orders)
+        .chain(positions)
+        .chain(margin_levels)
+    {
+        snapshot.apply(&change);
+    }
+    return Ok(snapshot);
+}
+
+/// A message of the streams followed.
+enum StreamUpdate {
+    Changes(Vec<Change>),
+    /// The orders stream started again with the live orders only, the
+    /// open orders closed while it was interrupted must be listed
+    OrdersReconnected,
+}
+
+/// Subscribe to the streams followed, then build the state from the
+/// list RPCs. Not all the streams start with a snapshot, subscribing
+/// first keeps the updates made while the state is listed.
+async fn resync(
+    client: &DataNodeClient,
+    party_id: &str,
+) -> Result<(Subscription<StreamUpdate>, PartySnapshot), Error> {
+    let events = subscribe(client, party_id).await?;
+    let snapshot = bootstrap(client, party_id).await?;
+    return Ok((events, snapshot));
+}
+
+/// The changes carried by the messages of the streams followed.
+async fn subscribe(
+    client: &DataNodeClient,
+    party_id: &str,
+) -> Result<Subscription<StreamUpdate>, Error> {
+    let party = || party_id.to_string();
+    let mut streams = vec![];
+    for t in ACCOUNT_TYPES.iter() {
+        let req = ObserveAccountsRequest {
+            party_id: party(),
+            r#type: *t as i32,
+            ..Default::default()
+        };
+        let accounts = subscription::subscribe_now(client.client.clone(), req, Backoff::default())
+            .await?
+            .map_ok(|e| {
+                e.map(|resp| {
+                    use observe_accounts_response::Response;
+                    let accounts = match resp.response {
+                        Some(Response::Snapshot(s)) => s.accounts,
+                        Some(Response::Updates(u)) => u.accounts,
+                        None => vec![],
+                    };
+                    StreamUpdate::Changes(accounts.into_iter().map(Change::Account).collect())
+                })
+            });
+        streams.push(accounts.boxed());
+    }
+    let orders = subscription::subscribe_now(
+        client.client.clone(),
+        ObserveOrdersRequest {
+            party_ids: vec![party()],
+            ..Default::default()
+        },
+        Backoff::default(),
+    )
+    .await?
+    .map_ok(|e| match e {
+        Event::Reconnected => Event::Update(StreamUpdate::OrdersReconnected),
+        e => e.map(|resp| {
+            use observe_orders_response::Response;
+            let orders = match resp.response {
+                Some(Response::Snapshot(s)) => s.orders,
+                Some(Response::Updates(u)) => u.orders,
+                None => vec![],
+            };
+            StreamUpdate::Changes(orders.into_iter().map(Change::Order).collect())
+        }),
+    });
+    let positions = subscription::subscribe_now(
+        client.client.clone(),
+        ObservePositionsRequest {
+            party_id: Some(party()),
+            ..Default::default()
+        },
+        Backoff::default(),
+    )
+    .await?
+    .map_ok(|e| {
+        e.map(|resp| {
+            use observe_positions_response::Response;
+            let positions = match resp.response {
+                Some(Response::Snapshot(s)) => s.positions,
+                Some(Response::Updates(u)) => u.positions,
+                None => vec![],
+            };
+            StreamUpdate::Changes(positions.into_iter().map(Change::Position).collect())
+        })
+    });
+    let margin_levels = subscription::subscribe_now(
+        client.client.clone(),
+        ObserveMarginLevelsRequest {
+            party_id: party(),
+            market_id: None,
+        },
+        Backoff::default(),
+    )
+    .await?
+    .map_ok(|e| {
+        e.map(|resp| {
+            let margin_levels = resp.margin_levels.into_iter();
+            StreamUpdate::Changes(margin_levels.map(Change::MarginLevels).collect())
+        })
+    });
+
+    streams.push(orders.boxed());
+    streams.push(positions.boxed());
+    streams.push(margin_levels.boxed());
+    return Ok(stream::select_all(streams).boxed());
+}
+
+/// Apply the changes to the state, which is rebuilt when updates are
+/// missed, subscribing again first as when it was built.
+async fn follow(
+    client: DataNodeClient,
+    party_id: String,
+    mut events: Subscription<StreamUpdate>,
+    tx: watch::Sender<PartySnapshot>,
+    changes: broadcast::Sender<Change>,
+) -> Error {
+    loop {
+        let resync_needed = match events.next().await {
+            Some(Ok(Event::Update(StreamUpdate::Changes(update)))) => {
+                apply(&tx, &changes, update);
+                false
+            }
+            Some(Ok(Event::Update(StreamUpdate::OrdersReconnected))) => {
+                let since = tx
+                    .borrow()
+                    .orders
+                    .values()
+                    .flat_map(|o| o.values())
+                    .map(|o| o.created_at)
+                    .min();
+                let Some(since) = since else {
+                    continue;
+                };
+                let filter = OrderFilter {
+                    party_ids: vec![party_id.clone()],
+                    date_range: Some(DateRange {
+                        start_timestamp: Some(since),
+                        end_timestamp: None,
+                    }),
+                    ..Default::default()
+                };
+                let backoff = Backoff::default();
+                match backoff.retry(|| client.orders(filter.clone())).await {
+                    Ok(orders) => apply(
+                        &tx,
+                        &changes,
+                        orders.into_iter().map(Change::Order).collect(),
+                    ),
+                    Err(e) => return e,
+                }
+                false
+            }
+            Some(Ok(Event::Reconnected)) => false,
+            Some(Ok(Event::GapDetected)) => true,
+            Some(Err(e)) => return e,
+            None => return Error::Disconnected,
+        };
+        if resync_needed {
+            let backoff = Backoff::default();
+            match backoff.retry(|| resync(&client, &party_id)).await {
+                Ok((resubscribed, snapshot)) => {
+                    events = resubscribed;
+                    tx.send_replace(snapshot);
+                    let _ = changes.send(Change::Resynced);
+                }
+                Err(e) => return e,
+            }
+        }
+    }
+}
+
+/// Apply the changes to the state, broadcasting the ones changing it.
+fn apply(
+    tx: &watch::Sender<PartySnapshot>,
+    changes: &broadcast::Sender<Change>,
+    update: Vec<Change>,
+) {
+    tx.send_if_modified(|snapshot| {
+        let mut modified = false;
+        for change in update.into_iter() {
+            if snapshot.apply(&change) {
+                modified = true;
+                // no receiver is not an error
+                let _ = changes.send(change);
+            }
+        }
+        return modified;
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::MockDataNode;
+    use futures::stream::BoxStream;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+    use tonic::Status;
+    use vega_protobufs::datanode::api::v2::{
+        AccountEdge, AccountsConnection, ListAccountsResponse, ListAllPositionsRequest,
+        ListAllPositionsResponse, ListMarginLevelsResponse, ListOrdersResponse, MarginConnection,
+        ObserveAccountsResponse, ObserveMarginLevelsResponse, ObserveOrdersResponse,
+        ObservePositionsResponse, OrderConnection, OrderEdge, OrderSnapshotPage, OrderUpdates,
+        PositionConnection, PositionEdge, PositionUpdates,
+    };
+
+    fn account(r#type: AccountType, market_id: &str, balance: &str) -> AccountBalance {
+        return AccountBalance {
+            owner: "p1".to_string(),
+            balance: balance.to_string(),
+            asset: "usdt".to_string(),
+            market_id: market_id.to_string(),
+            r#type: r#type as i32,
+            ..Default::default()
+        };
+    }
+
+    fn order(id: &str, status: order::Status, updated_at: i64) -> Order {
+        return Order {
+            id: id.to_string(),
+            market_id: "m1".to_string(),
+            party_id: "p1".to_string(),
+            status: status as i32,
+            updated_at,
+            ..Default::default()
+        };
+    }
+
+    fn position(open_volume: i64, updated_at: i64) -> Position {
+        return Position {
+            market_id: "m1".to_string(),
+            party_id: "p1".to_string(),
+            open_volume,
+            unrealised_pnl: (open_volume * 10).to_string(),
+            updated_at,
+            ..Default::default()
+        };
+    }
+
+    fn open<T: Send + 'static>(updates: Vec<T>) -> BoxStream<'static, Result<T, Status>> {
+        return stream::iter(updates.into_iter().map(Ok))
+            .chain(stream::pending())
+            .boxed();
+    }
+
+    #[test]
+    fn snapshot_changes() {
+        let mut s = PartySnapshot::default();
+        assert!(s.apply(&Change::Account(account(AccountType::General, "", "100"))));
+        assert!(s.apply(&Change::Account(account(AccountType::Margin, "m1", "20"))));
+        assert!(!s.apply(&Change::Account(account(AccountType::General, "", "100"))));
+        assert!(s.apply(&Change::Account(account(AccountType::General, "", "90"))));
+        let general = s.balances(AccountType::General).collect::<Vec<_>>();
+        assert_eq!(general.len(), 1);
+        assert_eq!(general[0].balance, "90");
+
+        use order::Status::*;
+        assert!(s.apply(&Change::Order(order("o1", Active, 1))));
+        assert!(s.apply(&Change::Order(order("o2", Parked, 1))));
+        // an older version of the order is ignored
+        assert!(!s.apply(&Change::Order(order("o1", Cancelled, 0))));
+        assert_eq!(s.open_orders("m1").count(), 2);
+        assert!(s.apply(&Change::Order(order("o1", Filled, 2))));
+        assert!(s.apply(&Change::Order(order("o2", Cancelled, 2))));
+        assert_eq!(s.open_orders("m1").count(), 0);
+        assert!(s.orders.is_empty());
+        assert!(!s.apply(&Change::Order(order("o3", Rejected, 3))));
+
+        assert!(s.apply(&Change::Position(position(5, 2))));
+        assert!(!s.apply(&Change::Position(position(3, 1))));
+        assert!(!s.apply(&Change::Position(position(5, 2))));
+        assert_eq!(s.positions["m1"].open_volume, 5);
+    }
+
+    #[tokio::test]
+    async fn party_state_follows_the_streams() {
+        let order_lists = Arc::new(AtomicUsize::new(0));
+        let (l, c) = (order_lists.clone(), Arc::new(AtomicUsize::new(0)));
+        let (p, m) = (Arc::new(AtomicUsize::new(0)), Arc::new(AtomicUsize::new(0)));
+        let o = Arc::new(AtomicUsize::new(0));
+        let url = MockDataNode::new()
+            .unary("ListAccounts", |req: ListAccountsRequest| {
+                let filter = req.filter.unwrap();
+                assert_eq!(filter.party_ids, vec!["p1"]);
+                assert_eq!(filter.account_types.len(), ACCOUNT_TYPES.len());
+                return Ok(ListAccountsResponse {
+                    accounts: Some(AccountsConnection {
+                        edges: vec![AccountEdge {
+                            node: Some(account(AccountType::General, "", "100")),
+                            cursor: "a".to_string(),
+                        }],
+                        page_info: None,
+                    }),
+                });
+            })
+            .unary("ListOrders", move |req: ListOrdersRequest| {
+                assert_eq!(req.filter.unwrap().live_only, Some(true));
+                let edges = match l.fetch_add(1, Ordering::SeqCst) {
+                    0 => vec![order("o1", order::Status::Active, 1)],
+                    _ => vec![order("o3", order::Status::Active, 5)],
+                };
+                let edges = edges
+                    .into_iter()
+                    .map(|o| OrderEdge {
+                        cursor: o.id.clone(),
+                        node: Some(o),
+                    })
+                    .collect();
+                return Ok(ListOrdersResponse {
+                    orders: Some(OrderConnection {
+                        edges,
+                        page_info: None,
+                    }),
+                });
+            })
+            .unary("ListAllPositions", move |_: ListAllPositionsRequest| {
+                let position = match p.fetch_add(1, Ordering::SeqCst) {
+                    0 => position(1, 1),
+                    _ => position(4, 2),
+                };
+                return Ok(ListAllPositionsResponse {
+                    positions: Some(PositionConnection {
+                        edges: vec![PositionEdge {
+                            node: Some(position),
+                            cursor: "m1".to_string(),
+                        }],
+                        page_info: None,
+                    }),
+                });
+            })
+            .unary("ListMarginLevels", move |_: ListMarginLevelsRequest| {
+                // the margin levels missed by the first stream can't be backfilled
+                if m.fetch_add(1, Ordering::SeqCst) == 1 {
+                    return Err(Status::unavailable("down"));
+                }
+                return Ok(ListMarginLevelsResponse {
+                    margin_levels: Some(MarginConnection {
+                        edges: vec![],
+                        page_info: None,
+                    }),
+                });
+            })
+            .streaming("ObserveAccounts", |req: ObserveAccountsRequest| {
+                assert_eq!(req.party_id, "p1");
+                return Ok(open::<ObserveAccountsResponse>(vec![]));
+            })
+            .streaming("ObservePositions", |_: ObservePositionsRequest| {
+                use observe_positions_response::Response;
+                return Ok(open(vec![ObservePositionsResponse {
+                    response: Some(Response::Updates(PositionUpdates {
+                        positions: vec![position(4, 2)],
+                    })),
+                }]));
+            })
+            .streaming(
+                "ObserveMarginLevels",
+                move |_: ObserveMarginLevelsRequest| {
+                    return Ok(match c.fetch_add(1, Ordering::SeqCst) {
+                        // the first stream is interrupted
+                        0 => stream::empty().boxed(),
+                        _ => open::<ObserveMarginLevelsResponse>(vec![]),
+                    });
+                },
+            )
+            .streaming("ObserveOrders", move |_: ObserveOrdersRequest| {
+                use observe_orders_response::Response;
+                let updates = |orders| ObserveOrdersResponse {
+                    response: Some(Response::Updates(OrderUpdates { orders })),
+                };
+                return Ok(match o.fetch_add(1, Ordering::SeqCst) {
+                    0 => open(vec![updates(vec![
+                        order("o1", order::Status::Filled, 2),
+                        order("o2", order::Status::Active, 2),
+                    ])]),
+                    _ => open(vec![]),
+                });
+            })
+            .start()
+            .await;
+
+        let clt = DataNodeClient::connect(url).await.unwrap();
+        let state = PartyState::start(&clt, "p1").await.unwrap();
+        let initial = state.snapshot();
+        assert_eq!(initial.party_id, "p1");
+        assert_eq!(initial.balances(AccountType::General).count(), 1);
+        assert_eq!(initial.open_orders("m1").count(), 1);
+
+        let mut changes = state.changes();
+        let mut received = vec![];
+        while !received.contains(&Change::Resynced) {
+            let change = tokio::time::timeout(Duration::from_secs(5), changes.recv());
+            received.push(change.await.unwrap().unwrap());
+        }
+        assert!(received.contains(&Change::Position(position(4, 2))));
+        assert!(received.contains(&Change::Order(order("o2", order::Status::Active, 2))));
+
+        // resynced from the list RPCs
+        let snapshot = state.snapshot();
+        assert_eq!(order_lists.load(Ordering::SeqCst), 2);
+        let open_orders = snapshot
+            .open_orders("m1")
+            .map(|o| o.id.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(open_orders, vec!["o3"]);
+        assert_eq!(snapshot.positions["m1"].open_volume, 4);
+    }
+
+    #[tokio::test]
+    async fn party_state_subscribes_before_listing() {
+        let calls = Arc::new(Mutex::new(vec![]));
+        let log = |name: &'static str| {
+            let calls = calls.clone();
+            return move || calls.lock().unwrap().push(name);
+        };
+        let (accounts, orders, positions, margins) =
+            (log("list"), log("list"), log("list"), log("list"));
+        let (observe_orders, observe_positions, observe_margins) =
+            (log("observe"), log("observe"), log("observe"));
+        let margin_levels = MarginLevels {
+            market_id: "m1".to_string(),
+            party_id: "p1".to_string(),
+            maintenance_margin: "10".to_string(),
+            timestamp: 5,
+            ..Default::default()
+        };
+        let m = margin_levels.clone();
+        let url = MockDataNode::new()
+            .unary("ListAccounts", move |_: ListAccountsRequest| {
+                accounts();
+                return Ok(ListAccountsResponse {
+                    accounts: Some(AccountsConnection::default()),
+                });
+            })
+            .unary("ListOrders", move |_: ListOrdersRequest| {
+                orders();
+                return Ok(ListOrdersResponse {
+                    orders: Some(OrderConnection::default()),
+                });
+            })
+            .unary("ListAllPositions", move |_: ListAllPositionsRequest| {
+                positions();
+                return Ok(ListAllPositionsResponse {
+                    positions: Some(PositionConnection::default()),
+                });
+            })
+            .unary("ListMarginLevels", move |_: ListMarginLevelsRequest| {
+                margins();
+                return Ok(ListMarginLevelsResponse {
+                    margin_levels: Some(MarginConnection::default()),
+                });
+            })
+            .streaming("ObserveAccounts", |_: ObserveAccountsRequest| {
+                return Ok(open::<ObserveAccountsResponse>(vec![]));
+            })
+            .streaming("ObserveOrders", move |_: ObserveOrdersRequest| {
+                observe_orders();
+                return Ok(open::<ObserveOrdersResponse>(vec![]));
+            })
+            .streaming("ObservePositions", move |_: ObservePositionsRequest| {
+                observe_positions();
+                return Ok(open::<ObservePositionsResponse>(vec![]));
+            })
+            // no snapshot is sent, the update was made before the levels were listed
+            .streaming(
+                "ObserveMarginLevels",
+                move |_: ObserveMarginLevelsRequest| {
+                    observe_margins();
+                    return Ok(open(vec![ObserveMarginLevelsResponse {
+                        margin_levels: Some(m.clone()),
+                    }]));
+                },
+            )
+            .start()
+            .await;
+
+        let clt = DataNodeClient::connect(url).await.unwrap();
+        let state = PartyState::start(&clt, "p1").await.unwrap();
+        let mut watch = state.watch();
+        let snapshot = tokio::time::timeout(
+            Duration::from_secs(5),
+            watch.wait_for(|s| !s.margin_levels.is_empty()),
+        )
+        .await
+        .unwrap()
+        .unwrap()
+        .clone();
+        assert_eq!(snapshot.margin_levels["m1"], margin_levels);
+        assert_eq!(
+            *calls.lock().unwrap(),
+            vec!["observe", "observe", "observe", "list", "list", "list", "list"]
+        );
+    }
+
+    #[tokio::test]
+    async fn orders_closed_while_reconnecting_are_listed() {
+        let lists = Arc::new(Mutex::new(vec![]));
+        let (l, c) = (lists.clone(), Arc::new(AtomicUsize::new(0)));
+        let mut active = order("o1", order::Status::Active, 1);
+        active.created_at = 5;
+        let url = MockDataNode::new()
+            .unary("ListAccounts", |_: ListAccountsRequest| {
+                return Ok(ListAccountsResponse {
+                    accounts: Some(AccountsConnection::default()),
+                });
+            })
+            .unary("ListOrders", move |req: ListOrdersRequest| {
+                let filter = req.filter.unwrap();
+                let mut lists = l.lock().unwrap();
+                lists.push(filter.clone());
+                let o = match lists.len() {
+                    1 => active.clone(),
+                    _ => order("o1", order::Status::Filled, 2),
+                };
+                return Ok(ListOrdersResponse {
+                    orders: Some(OrderConnection {
+                        edges: vec![OrderEdge {
+                            cursor: o.id.clone(),
+                            node: Some(o),
+                        }],
+                        page_info: None,
+                    }),
+                });
+            })
+            .unary("ListAllPositions", |_: ListAllPositionsRequest| {
+                return Ok(ListAllPositionsResponse {
+                    positions: Some(PositionConnection::default()),
+                });
+            })
+            .unary("ListMarginLevels", |_: ListMarginLevelsRequest| {
+                return Ok(ListMarginLevelsResponse {
+                    margin_levels: Some(MarginConnection::default()),
+                });
+            })
+            .streaming("ObserveAccounts", |_: ObserveAccountsRequest| {
+                return Ok(open::<ObserveAccountsResponse>(vec![]));
+            })
+            .streaming("ObservePositions", |_: ObservePositionsRequest| {
+                return Ok(open::<ObservePositionsResponse>(vec![]));
+            })
+            .streaming("ObserveMarginLevels", |_: ObserveMarginLevelsRequest| {
+                return Ok(open::<ObserveMarginLevelsResponse>(vec![]));
+            })
+            .streaming("ObserveOrders", move |_: ObserveOrdersRequest| {
+                use observe_orders_response::Response;
+                return Ok(match c.fetch_add(1, Ordering::SeqCst) {
+                    0 => stream::empty().boxed(),
+                    // the order was filled in between, it is not live anymore
+                    _ => open(vec![ObserveOrdersResponse {
+                        response: Some(Response::Snapshot(OrderSnapshotPage {
+                            orders: vec![],
+                            last_page: true,
+                        })),
+                    }]),
+                });
+            })
+            .start()
+            .await;
+
+        let clt = DataNodeClient::connect(url).await.unwrap();
+        let state = PartyState::start(&clt, "p1").await.unwrap();
+        assert_eq!(state.snapshot().open_orders("m1").count(), 1);
+        let mut changes = state.changes();
+        let change = tokio::time::timeout(Duration::from_secs(5), changes.recv());
+        assert_eq!(
+            change.await.unwrap().unwrap(),
+            Change::Order(order("o1", order::Status::Filled, 2))
+        );
+        assert_eq!(state.snapshot().open_orders("m1").count(), 0);
+
+        // listed since the oldest open order was created, not relisted
+        let lists = lists.lock().unwrap();
+        assert_eq!(lists.len(), 2);
+        assert_eq!(lists[1].party_ids, vec!["p1"]);
+        assert_eq!(lists[1].live_only, None);
+        assert_eq!(
+            lists[1].date_range.as_ref().unwrap().start_timestamp,
+            Some(5)
+        );
+    }
+}
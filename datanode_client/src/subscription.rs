@@ -0,0 +1,754 @@
+//! Subscriptions to the `Observe*` RPCs of the trading data API
+//! surviving the loss of the connection to the data node.
+
+use crate::pagination::{paginate, PageOptions};
+use crate::Error;
+use futures::future::{self, BoxFuture};
+use futures::stream::{self, BoxStream, TryStreamExt};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::future::Future;
+use std::time::Duration;
+use tonic::transport::Channel;
+use tonic::Streaming;
+use vega_protobufs::datanode::api::v2::{
+    self as v2, trading_data_service_client::TradingDataServiceClient, DateRange,
+    ListMarginLevelsRequest, ListTradesRequest, ObserveAccountsRequest, ObserveMarginLevelsRequest,
+    ObserveMarginLevelsResponse, ObserveOrdersRequest, ObservePositionsRequest,
+    ObserveTradesRequest, ObserveTradesResponse,
+};
+
+/// An event of a subscription.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event<T> {
+    /// A message streamed by the data node, or recovered after a reconnection
+    Update(T),
+    /// The stream was interrupted and subscribed again, the updates
+    /// missed in between follow, unless a `GapDetected` follows instead
+    Reconnected,
+    /// Updates missed while the stream was interrupted could not be
+    /// recovered, the state built from the updates must be resynced
+    GapDetected,
+}
+
+impl<T> Event<T> {
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Event<U> {
+        return match self {
+            Event::Update(u) => Event::Update(f(u)),
+            Event::Reconnected => Event::Reconnected,
+            Event::GapDetected => Event::GapDetected,
+        };
+    }
+}
+
+/// The events of a subscription, the stream ends with an error once
+/// the subscription can't be re-established.
+pub type Subscription<T> = BoxStream<'static, Result<Event<T>, Error>>;
+
+/// The updates missed since the last one received.
+pub type Backfill<T> = BoxFuture<'static, Result<Vec<T>, Error>>;
+
+/// The delays between the attempts to subscribe again.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Backoff {
+    /// Delay before the first attempt
+    pub initial: Duration,
+    /// Upper bound of the delay, which doubles after each attempt
+    pub max: Duration,
+    /// Consecutive attempts not followed by any update before giving up,
+    /// `None` retries forever
+    pub max_attempts: Option<u32>,
+}
+
+impl Default for Backoff {
+    fn default() -> Backoff {
+        return Backoff {
+            initial: Duration::from_millis(100),
+            max: Duration::from_secs(30),
+            max_attempts: None,
+        };
+    }
+}
+
+impl Backoff {
+    fn delay(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        return self.initial.saturating_mul(factor).min(self.max);
+    }
+
+    /// Run `f` until it succeeds, retrying the retryable errors with
+    /// the same delays and limit as the subscriptions.
+    pub(crate) async fn retry<T, F, Fut>(&self, mut f: F) -> Result<T, Error>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, Error>>,
+    {
+        let mut attempts = 0;
+        loop {
+            attempts += 1;
+            match f().await {
+                Ok(v) => return Ok(v),
+                Err(e) if e.is_retryable() => {
+                    if self.max_attempts.is_some_and(|m| attempts >= m) {
+                        return Err(e);
+                    }
+                    tokio::time::sleep(self.delay(attempts)).await;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+/// The request of a server streaming `Observe*` RPC.
+pub trait ObserveRequest: Clone + Send + 'static {
+    /// The type of the streamed messages
+    type Update: Clone + Send + 'static;
+
+    fn observe(
+        self,
+        client: TradingDataServiceClient<Channel>,
+    ) -> BoxFuture<'static, Result<Streaming<Self::Update>, Error>>;
+
+    /// Fetch the updates missed since `last`, the last update received
+    /// before the stream was interrupted. `None` when they can't be recovered.
+    fn backfill(
+        &self,
+        _client: TradingDataServiceClient<Channel>,
+        _last: Option<&Self::Update>,
+    ) -> Option<Backfill<Self::Update>> {
+        return None;
+    }
+
+    /// Drop from an update streamed after a backfill what the backfill
+    /// already returned, `None` when nothing is left. `backfilled` is
+    /// cleared once the stream went past it.
+    fn dedup(
+        &self,
+        backfilled: &mut Vec<Self::Update>,
+        update: Self::Update,
+    ) -> Option<Self::Update> {
+        backfilled.clear();
+        return Some(update);
+    }
+}
+
+struct State<R: ObserveRequest> {
+    client: TradingDataServiceClient<Channel>,
+    req: R,
+    backoff: Backoff,
+    updates: Option<Streaming<R::Update>>,
+    last: Option<R::Update>,
+    /// The updates of the last backfill, which the new stream may send again
+    backfilled: Vec<R::Update>,
+    events: VecDeque<Event<R::Update>>,
+    /// Attempts to subscribe since the last update received
+    attempts: u32,
+    /// The error interrupting the stream, if any
+    error: Option<Error>,
+    interrupted: bool,
+    done: bool,
+}
+
+pub(crate) fn subscribe<R: ObserveRequest>(
+    client: TradingDataServiceClient<Channel>,
+    req: R,
+    backoff: Backoff,
+) -> Subscription<R::Update> {
+    let state = State::new(client, req, backoff);
+    return Box::pin(stream::unfold(state, next_event));
+}
+
+/// Same as `subscribe`, but the request is sent right away rather than
+/// when the stream is first polled, so no update following the call is missed.
+pub(crate) async fn subscribe_now<R: ObserveRequest>(
+    client: TradingDataServiceClient<Channel>,
+    req: R,
+    backoff: Backoff,
+) -> Result<Subscription<R::Update>, Error> {
+    let mut state = State::new(client, req, backoff);
+    state.subscribe().await?;
+    return Ok(Box::pin(stream::unfold(state, next_event)));
+}
+
+async fn next_event<R: ObserveRequest>(
+    mut state: State<R>,
+) -> Option<(Result<Event<R::Update>, Error>, State<R>)> {
+    loop {
+        if let Some(event) = state.events.pop_front() {
+            return Some((Ok(event), state));
+        }
+        if state.done {
+            return None;
+        }
+        let Some(updates) = state.updates.as_mut() else {
+            if let Err(e) = state.subscribe().await {
+                state.done = true;
+                return Some((Err(e), state));
+            }
+            continue;
+        };
+        match updates.message().await.map_err(Error::from) {
+            Ok(Some(update)) => {
+                state.attempts = 0;
+                let update = match state.backfilled.is_empty() {
+                    true => update,
+                    false => match state.req.dedup(&mut state.backfilled, update) {
+                        Some(update) => update,
+                        None => continue,
+                    },
+                };
+                state.last = Some(update.clone());
+                return Some((Ok(Event::Update(update)), state));
+            }
+            Ok(None) => state.error = None,
+            Err(e) if e.is_retryable() => state.error = Some(e),
+            Err(e) => {
+                state.done = true;
+                return Some((Err(e), state));
+            }
+        }
+        state.updates = None;
+        state.interrupted = true;
+    }
+}
+
+impl<R: ObserveRequest> State<R> {
+    fn new(client: TradingDataServiceClient<Channel>, req: R, backoff: Backoff) -> State<R> {
+        return State {
+            client,
+            req,
+            backoff,
+            updates: None,
+            last: None,
+            backfilled: vec![],
+            events: VecDeque::new(),
+            attempts: 0,
+            error: None,
+            interrupted: false,
+            done: false,
+        };
+    }
+
+    /// Subscribe, retrying with backoff, then queue the events
+    /// following a reconnection.
+    async fn subscribe(&mut self) -> Result<(), Error> {
+        let updates = loop {
+            if self.attempts > 0 || self.interrupted {
+                if self
+                    .backoff
+                    .max_attempts
+                    .is_some_and(|m| self.attempts >= m)
+                {
+                    return Err(self.error.take().unwrap_or(Error::Disconnected));
+                }
+                tokio::time::sleep(self.backoff.delay(self.attempts)).await;
+            }
+            self.attempts += 1;
+            match self.req.clone().observe(self.client.clone()).await {
+                Ok(updates) => break updates,
+                Err(e) if e.is_retryable() => self.error = Some(e),
+                Err(e) => return Err(e),
+            }
+        };
+        self.updates = Some(updates);
+        if !self.interrupted {
+            return Ok(());
+        }
+
+        self.interrupted = false;
+        self.events.push_back(Event::Reconnected);
+        let backfill = self.req.backfill(self.client.clone(), self.last.as_ref());
+        match backfill {
+            Some(missed) => match missed.await {
+                Ok(missed) => {
+                    if let Some(last) = missed.last() {
+                        self.last = Some(last.clone());
+                    }
+                    self.backfilled = missed.clone();
+                    self.events.extend(missed.into_iter().map(Event::Update));
+                }
+                Err(_) => self.events.push_back(Event::GapDetected),
+            },
+            None => self.events.push_back(Event::GapDetected),
+        }
+        return Ok(());
+    }
+}
+
+/// The trades made since the last trade received, except the ones
+/// already received, listed in a single update.
+fn backfill_trades(
+    req: &ObserveTradesRequest,
+    client: TradingDataServiceClient<Channel>,
+    last: Option<&ObserveTradesResponse>,
+) -> Option<Backfill<ObserveTradesResponse>> {
+    let since = last?.trades.iter().map(|t| t.timestamp).max()?;
+    let seen = last?
+        .trades
+        .iter()
+        .filter(|t| t.timestamp == since)
+        .map(|t| t.id.clone())
+        .collect::<HashSet<_>>();
+    let list = ListTradesRequest {
+        market_ids: req.market_ids.clone(),
+        party_ids: req.party_ids.clone(),
+        date_range: Some(DateRange {
+            start_timestamp: Some(since),
+            end_timestamp: None,
+        }),
+        ..Default::default()
+    };
+    return Some(Box::pin(async move {
+        let trades = paginate(client, list, PageOptions::default())
+            .try_filter(|t| future::ready(!seen.contains(&t.id)))
+            .try_collect::<Vec<_>>()
+            .await?;
+        return Ok(match trades.is_empty() {
+            true => vec![],
+            false => vec![ObserveTradesResponse { trades }],
+        });
+    }));
+}
+
+/// The trades of the new stream not listed by the backfill, the stream
+/// and the backfill overlap until the stream sends trades made after it.
+fn dedup_trades(
+    _req: &ObserveTradesRequest,
+    backfilled: &mut Vec<ObserveTradesResponse>,
+    mut update: ObserveTradesResponse,
+) -> Option<ObserveTradesResponse> {
+    let listed = backfilled
+        .iter()
+        .flat_map(|u| u.trades.iter())
+        .map(|t| (t.id.clone(), t.timestamp))
+        .collect::<HashMap<_, _>>();
+    let until = listed.values().max().copied();
+    update.trades.retain(|t| !listed.contains_key(&t.id));
+    if update.trades.iter().any(|t| Some(t.timestamp) > until) {
+        backfilled.clear();
+    }
+    return match update.trades.is_empty() {
+        true => None,
+        false => Some(update),
+    };
+}
+
+/// The current margin levels, the stream only sends the levels which change.
+fn backfill_margin_levels(
+    req: &ObserveMarginLevelsRequest,
+    client: TradingDataServiceClient<Channel>,
+    _last: Option<&ObserveMarginLevelsResponse>,
+) -> Option<Backfill<ObserveMarginLevelsResponse>> {
+    let list = ListMarginLevelsRequest {
+        party_id: req.party_id.clone(),
+        market_id: req.market_id.clone().unwrap_or_default(),
+        pagination: None,
+    };
+    return Some(Box::pin(async move {
+        return paginate(client, list, PageOptions::default())
+            .map_ok(|m| ObserveMarginLevelsResponse {
+                margin_levels: Some(m),
+            })
+            .try_collect()
+            .await;
+    }));
+}
+
+/// Nothing to backfill, the stream starts again with a snapshot of the current state.
+fn backfill_snapshot<R: ObserveRequest>(
+    _req: &R,
+    _client: TradingDataServiceClient<Channel>,
+    _last: Option<&R::Update>,
+) -> Option<Backfill<R::Update>> {
+    return Some(Box::pin(future::ready(Ok(vec![]))));
+}
+
+macro_rules! impl_observe_request {
+    ($($req:ident => $method:ident: $update:ident $(, $backfill:expr $(, $dedup:expr)?)?;)*) => {
+        $(
+            impl ObserveRequest for v2::$req {
+                type Update = v2::$update;
+
+                fn observe(
+                    self,
+                    mut client: TradingDataServiceClient<Channel>,
+                ) -> BoxFuture<'static, Result<Streaming<v2::$update>, Error>> {
+                    return Box::pin(async move {
+                        return Ok(client.$method(self).await?.into_inner());
+                    });
+                }
+
+                $(
+                    fn backfill(
+                        &self,
+                        client: TradingDataServiceClient<Channel>,
+                        last: Option<&v2::$update>,
+                    ) -> Option<Backfill<v2::$update>> {
+                        return $backfill(self, client, last);
+                    }
+
+                    $(
+                        fn dedup(
+                            &self,
+                            backfilled: &mut Vec<v2::$update>,
+                            update: v2::$update,
+                        ) -> Option<v2::$update> {
+                            return $dedup(self, backfilled, update);
+                        }
+                    )?
+                )?
+            }
+        )*
+    };
+}
+
+impl_observe_request! {
+    ObserveAccountsRequest => observe_accounts: ObserveAccountsResponse, backfill_snapshot::<ObserveAccountsRequest>;
+    ObserveOrdersRequest => observe_orders: ObserveOrdersResponse, backfill_snapshot::<ObserveOrdersRequest>;
+    ObservePositionsRequest => observe_positions: ObservePositionsResponse, backfill_snapshot::<ObservePositionsRequest>;
+    ObserveMarketsDepthRequest => observe_markets_depth: ObserveMarketsDepthResponse;
+    ObserveMarketsDepthUpdatesRequest => observe_markets_depth_updates: ObserveMarketsDepthUpdatesResponse;
+    ObserveMarketsDataRequest => observe_markets_data: ObserveMarketsDataResponse;
+    ObserveCandleDataRequest => observe_candle_data: ObserveCandleDataResponse;
+    ObserveVotesRequest => observe_votes: ObserveVotesResponse;
+    ObserveTradesRequest => observe_trades: ObserveTradesResponse, backfill_trades, dedup_trades;
+    ObserveMarginLevelsRequest => observe_margin_levels: ObserveMarginLevelsResponse, backfill_margin_levels;
+    ObserveLiquidityProvisionsRequest => observe_liquidity_provisions: ObserveLiquidityProvisionsResponse;
+    ObserveGovernanceRequest => observe_governance: ObserveGovernanceResponse;
+    ObserveLedgerMovementsRequest => observe_ledger_movements: ObserveLedgerMovementsResponse;
+    ObserveTransactionResultsRequest => observe_transaction_results: ObserveTransactionResultsResponse;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::MockDataNode;
+    use crate::DataNodeClient;
+    use futures::StreamExt;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+    use tonic::{Code, Status};
+    use v2::{
+        ListTradesResponse, ObserveMarketsDepthUpdatesRequest, ObserveMarketsDepthUpdatesResponse,
+        TradeConnection, TradeEdge,
+    };
+    use vega_protobufs::vega::{MarketDepthUpdate, Trade};
+
+    fn backoff() -> Backoff {
+        return Backoff {
+            initial: Duration::from_millis(1),
+            max: Duration::from_millis(10),
+            max_attempts: Some(3),
+        };
+    }
+
+    fn trade(id: &str, timestamp: i64) -> Trade {
+        return Trade {
+            id: id.to_string(),
+            timestamp,
+            ..Default::default()
+        };
+    }
+
+    fn trades(ids: &[&str]) -> ObserveTradesResponse {
+        return ObserveTradesResponse {
+            trades: ids.iter().map(|id| trade(id, 10)).collect(),
+        };
+    }
+
+    /// A stream sending the updates then staying open.
+    fn open<T: Send + 'static>(updates: Vec<T>) -> BoxStream<'static, Result<T, Status>> {
+        return stream::iter(updates.into_iter().map(Ok))
+            .chain(stream::pending())
+            .boxed();
+    }
+
+    #[test]
+    fn backoff_delays() {
+        let b = Backoff {
+            initial: Duration::from_millis(100),
+            max: Duration::from_millis(1000),
+            max_attempts: None,
+        };
+        let delays = (0..6).map(|a| b.delay(a).as_millis()).collect::<Vec<_>>();
+        assert_eq!(delays, vec![100, 100, 200, 400, 800, 1000]);
+    }
+
+    #[tokio::test]
+    async fn reconnects_and_backfills_trades() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let listed = Arc::new(Mutex::new(vec![]));
+        let (c, l) = (calls.clone(), listed.clone());
+        let url = MockDataNode::new()
+            .streaming("ObserveTrades", move |req: ObserveTradesRequest| {
+                assert_eq!(req.market_ids, vec!["m1"]);
+                return Ok(match c.fetch_add(1, Ordering::SeqCst) {
+                    // the first stream is interrupted after a single update
+                    0 => stream::iter(vec![Ok(trades(&["t1", "t2"]))]).boxed(),
+                    _ => open(vec![ObserveTradesResponse {
+                        trades: vec![trade("t5", 12)],
+                    }]),
+                });
+            })
+            .unary("ListTrades", move |req: ListTradesRequest| {
+                l.lock().unwrap().push(req.clone());
+                let edges = [trade("t2", 10), trade("t3", 10), trade("t4", 11)]
+                    .into_iter()
+                    .map(|t| TradeEdge {
+                        cursor: t.id.clone(),
+                        node: Some(t),
+                    })
+                    .collect();
+                return Ok(ListTradesResponse {
+                    trades: Some(TradeConnection {
+                        edges,
+                        page_info: None,
+                    }),
+                });
+            })
+            .start()
+            .await;
+
+        let clt = DataNodeClient::connect(url).await.unwrap();
+        let req = ObserveTradesRequest {
+            market_ids: vec!["m1".to_string()],
+            ..Default::default()
+        };
+        let events = clt
+            .subscribe(req, backoff())
+            .take(4)
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+        assert_eq!(
+            events,
+            vec![
+                Event::Update(trades(&["t1", "t2"])),
+                Event::Reconnected,
+                Event::Update(ObserveTradesResponse {
+                    trades: vec![trade("t3", 10), trade("t4", 11)]
+                }),
+                Event::Update(ObserveTradesResponse {
+                    trades: vec![trade("t5", 12)]
+                }),
+            ]
+        );
+
+        let listed = listed.lock().unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].market_ids, vec!["m1"]);
+        assert_eq!(
+            listed[0].date_range.as_ref().unwrap().start_timestamp,
+            Some(10)
+        );
+    }
+
+    #[tokio::test]
+    async fn drops_streamed_trades_already_backfilled() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let c = calls.clone();
+        let url = MockDataNode::new()
+            .streaming("ObserveTrades", move |_: ObserveTradesRequest| {
+                return Ok(match c.fetch_add(1, Ordering::SeqCst) {
+                    0 => stream::iter(vec![Ok(trades(&["t1"]))]).boxed(),
+                    // t3 was made while the backfill was listed
+                    _ => open(vec![
+                        ObserveTradesResponse {
+                            trades: vec![trade("t3", 11)],
+                        },
+                        ObserveTradesResponse {
+                            trades: vec![trade("t3", 11), trade("t4", 12)],
+                        },
+                        ObserveTradesResponse {
+                            trades: vec![trade("t5", 12)],
+                        },
+                    ]),
+                });
+            })
+            .unary("ListTrades", move |_: ListTradesRequest| {
+                let edges = [trade("t2", 10), trade("t3", 11)]
+                    .into_iter()
+                    .map(|t| TradeEdge {
+                        cursor: t.id.clone(),
+                        node: Some(t),
+                    })
+                    .collect();
+                return Ok(ListTradesResponse {
+                    trades: Some(TradeConnection {
+                        edges,
+                        page_info: None,
+                    }),
+                });
+            })
+            .start()
+            .await;
+
+        let clt = DataNodeClient::connect(url).await.unwrap();
+        let events = clt
+            .subscribe(ObserveTradesRequest::default(), backoff())
+            .take(5)
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+        assert_eq!(
+            events,
+            vec![
+                Event::Update(trades(&["t1"])),
+                Event::Reconnected,
+                Event::Update(ObserveTradesResponse {
+                    trades: vec![trade("t2", 10), trade("t3", 11)]
+                }),
+                Event::Update(ObserveTradesResponse {
+                    trades: vec![trade("t4", 12)]
+                }),
+                Event::Update(ObserveTradesResponse {
+                    trades: vec![trade("t5", 12)]
+                }),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn gap_detected_without_backfill() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let c = calls.clone();
+        let update = |seq| ObserveMarketsDepthUpdatesResponse {
+            update: vec![MarketDepthUpdate {
+                sequence_number: seq,
+                ..Default::default()
+            }],
+        };
+        let url = MockDataNode::new()
+            .streaming(
+                "ObserveMarketsDepthUpdates",
+                move |_: ObserveMarketsDepthUpdatesRequest| {
+                    return match c.fetch_add(1, Ordering::SeqCst) {
+                        0 => Ok(stream::iter(vec![Ok(update(1))]).boxed()),
+                        // the stream breaks with a transient error
+                        1 => Ok(stream::iter(vec![Err(Status::unavailable("blip"))]).boxed()),
+                        2 => Err(Status::unavailable("still down")),
+                        _ => Ok(open(vec![update(5)])),
+                    };
+                },
+            )
+            .start()
+            .await;
+
+        let clt = DataNodeClient::connect(url).await.unwrap();
+        let mut backoff = backoff();
+        backoff.max_attempts = None;
+        let events = clt
+            .subscribe(ObserveMarketsDepthUpdatesRequest::default(), backoff)
+            .take(5)
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+        assert_eq!(
+            events,
+            vec![
+                Event::Update(update(1)),
+                Event::Reconnected,
+                Event::GapDetected,
+                Event::Reconnected,
+                Event::GapDetected,
+            ]
+        );
+        assert_eq!(calls.load(Ordering::SeqCst), 4);
+    }
+
+    #[tokio::test]
+    async fn gives_up_after_max_attempts() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let c = calls.clone();
+        let url = MockDataNode::new()
+            .streaming("ObserveTrades", move |_: ObserveTradesRequest| {
+                c.fetch_add(1, Ordering::SeqCst);
+                return Err::<BoxStream<'static, Result<ObserveTradesResponse, Status>>, _>(
+                    Status::unavailable("down"),
+                );
+            })
+            .start()
+            .await;
+
+        let clt = DataNodeClient::connect(url).await.unwrap();
+        let events = clt
+            .subscribe(ObserveTradesRequest::default(), backoff())
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(events.len(), 1);
+        match &events[0] {
+            Err(Error::Status(s)) => assert_eq!(s.code(), Code::Unavailable),
+            r => panic!("unexpected event {:?}", r),
+        }
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn retries_with_backoff() {
+        let mut calls = 0;
+        let r = backoff()
+            .retry(|| {
+                calls += 1;
+                return future::ready(Err::<(), _>(Error::Disconnected));
+            })
+            .await;
+        assert!(matches!(r, Err(Error::Disconnected)));
+        assert_eq!(calls, 3);
+
+        let mut calls = 0;
+        let r = backoff()
+            .retry(|| {
+                calls += 1;
+                return future::ready(match calls {
+                    1 => Err(Error::Disconnected),
+                    _ => Ok(calls),
+                });
+            })
+            .await;
+        assert_eq!(r.unwrap(), 2);
+
+        let mut calls = 0;
+        let r = backoff()
+            .retry(|| {
+                calls += 1;
+                return future::ready(Err::<(), _>(Error::MissingField("market")));
+            })
+            .await;
+        assert!(matches!(r, Err(Error::MissingField(_))));
+        assert_eq!(calls, 1);
+    }
+
+    #[tokio::test]
+    async fn invalid_requests_are_not_retried() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let c = calls.clone();
+        let url = MockDataNode::new()
+            .streaming("ObserveTrades", move |_: ObserveTradesRequest| {
+                c.fetch_add(1, Ordering::SeqCst);
+                // the error is delayed, tonic drops the messages still
+                // buffered when the stream fails
+                let error = async {
+                    tokio::time::sleep(Duration::from_millis(10)).await;
+                    return Err(Status::invalid_argument("bad market"));
+                };
+                return Ok(stream::iter(vec![Ok(trades(&["t1"]))])
+                    .chain(stream::once(error))
+                    .boxed());
+            })
+            .start()
+            .await;
+
+        let clt = DataNodeClient::connect(url).await.unwrap();
+        let events = clt
+            .subscribe(ObserveTradesRequest::default(), backoff())
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(events.len(), 2);
+        assert!(matches!(&events[0], Ok(Event::Update(_))));
+        match &events[1] {
+            Err(Error::Status(s)) => assert_eq!(s.code(), Code::InvalidArgument),
+            r => panic!("unexpected event {:?}", r),
+        }
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+}
@@ -1,41 +1,41 @@
-use std::{ffi::OsStr, path::PathBuf};
-use walkdir::WalkDir;
+//! With the `compile-protos` feature, the protobuf sources are compiled
+//! with a protoc built from source instead of using the code committed
+//! under `src/generated`.
 
-fn main() {
-    std::env::set_var("PROTOC", protobuf_src::protoc());
+#[cfg(feature = "compile-protos")]
+#[path = "codegen/src/lib.rs"]
+mod codegen;
 
-    let protos_folder = "./sources";
-    let third_party_folder = "./third_party";
-    let mut files: Vec<PathBuf> = vec![];
+/// Proto folders only compiled when their cargo feature is enabled,
+/// everything else under `sources` is always compiled.
+#[cfg(feature = "compile-protos")]
+const OPTIONAL_PACKAGES: [(&str, bool); 6] = [
+    ("vega/events", cfg!(feature = "events")),
+    ("vega/checkpoint", cfg!(feature = "checkpoint")),
+    ("vega/snapshot", cfg!(feature = "snapshot")),
+    ("vega/api", cfg!(feature = "core-api")),
+    ("data-node", cfg!(feature = "data-node")),
+    ("blockexplorer", cfg!(feature = "block-explorer")),
+];
 
-    for entry in WalkDir::new(protos_folder) {
-        if let Ok(diren) = entry {
-            if diren.path().is_file()
-                && diren.path().extension().and_then(OsStr::to_str).unwrap() == "proto"
-            {
-                println!("{}", diren.path().display());
-                files.push(diren.path().to_path_buf())
-            }
-        }
-    }
+#[cfg(feature = "compile-protos")]
+fn main() {
+    use std::path::{Path, PathBuf};
 
-    for entry in WalkDir::new(third_party_folder) {
-        if let Ok(diren) = entry {
-            if diren.path().is_file()
-                && diren.path().extension().and_then(OsStr::to_str).unwrap() == "proto"
-            {
-                println!("{}", diren.path().display());
-                files.push(diren.path().to_path_buf())
-            }
-        }
-    }
+    let enabled = |path: &Path| {
+        OPTIONAL_PACKAGES
+            .iter()
+            .all(|(folder, enabled)| *enabled || !path.starts_with(folder))
+    };
+    let out_dir = PathBuf::from(std::env::var("OUT_DIR").unwrap());
+    codegen::generate(Path::new("."), &out_dir, &enabled);
 
-    tonic_build::configure()
-        .build_server(true)
-        .build_client(true)
-        // .out_dir("./src")
-        .compile(&files, &[protos_folder, third_party_folder])
-        .unwrap_or_else(|e| panic!("protobuf compile error: {}", e));
+    println!("cargo:rerun-if-changed=sources");
+    println!("cargo:rerun-if-changed=third_party");
+    println!("cargo:rerun-if-changed=codegen/src/lib.rs");
+}
 
-    println!("cargo:rerun-if-changed={}", protos_folder);
+#[cfg(not(feature = "compile-protos"))]
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
 }
@@ -0,0 +1,136 @@
+//! Generation of the Rust code of the Vega protobuf definitions.
+//!
+//! The code committed under `protobufs/src/generated` is refreshed with
+//! `cargo run -p vega_protobufs_codegen`, the build script of
+//! `vega_protobufs` uses the same code with the `compile-protos` feature.
+
+use std::{
+    ffi::OsStr,
+    path::{Path, PathBuf},
+};
+use walkdir::WalkDir;
+
+/// Compile the protos found under `root/sources` for which `enabled`
+/// returns true, given their path relative to `sources`, into `out_dir`.
+/// The encoded `FileDescriptorSet` is written to `file_descriptor_set.bin`.
+///
+/// Server stubs are only compiled with the `server` feature of
+/// `vega_protobufs`, the serde impls with its `serde` feature.
+pub fn generate(root: &Path, out_dir: &Path, enabled: &dyn Fn(&Path) -> bool) {
+    std::env::set_var("PROTOC", protobuf_src::protoc());
+
+    let protos_folder = root.join("sources");
+    let third_party_folder = root.join("third_party");
+    let mut files: Vec<PathBuf> = vec![];
+
+    for diren in WalkDir::new(&protos_folder)
+        .sort_by_file_name()
+        .into_iter()
+        .flatten()
+    {
+        if diren.path().is_file()
+            && diren.path().extension().and_then(OsStr::to_str) == Some("proto")
+            && enabled(diren.path().strip_prefix(&protos_folder).unwrap())
+        {
+            files.push(diren.path().to_path_buf())
+        }
+    }
+
+    for diren in WalkDir::new(&third_party_folder)
+        .sort_by_file_name()
+        .into_iter()
+        .flatten()
+    {
+        if diren.path().is_file()
+            && diren.path().extension().and_then(OsStr::to_str) == Some("proto")
+        {
+            files.push(diren.path().to_path_buf())
+        }
+    }
+
+    std::fs::create_dir_all(out_dir).unwrap();
+    let descriptor_path = out_dir.join("file_descriptor_set.bin");
+
+    // the well known types are resolved through `crate::google::protobuf`,
+    // which re-exports pbjson_types, with serde impls
+    tonic_build::configure()
+        .build_server(true)
+        .build_client(true)
+        .out_dir(out_dir)
+        .file_descriptor_set_path(&descriptor_path)
+        .compile_well_known_types(true)
+        .extern_path(".google.protobuf", "crate::google::protobuf")
+        .compile(&files, &[protos_folder, third_party_folder])
+        .unwrap_or_else(|e| panic!("protobuf compile error: {}", e));
+
+    let descriptors = std::fs::read(&descriptor_path).unwrap();
+    pbjson_build::Builder::new()
+        .register_descriptors(&descriptors)
+        .unwrap()
+        .out_dir(out_dir)
+        .extern_path(".google.protobuf", "crate::google::protobuf")
+        .build(&[".vega", ".datanode", ".blockexplorer"])
+        .unwrap_or_else(|e| panic!("serde impls generation error: {}", e));
+
+    for entry in std::fs::read_dir(out_dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.extension().and_then(OsStr::to_str) != Some("rs") {
+            continue;
+        }
+        let code = std::fs::read_to_string(&path).unwrap();
+        std::fs::write(&path, gate_servers(&code)).unwrap();
+    }
+}
+
+/// Put the server stubs generated by tonic behind the `server` feature.
+fn gate_servers(code: &str) -> String {
+    let mut out = String::with_capacity(code.len());
+    for line in code.lines() {
+        let item = line.trim_start();
+        if item.starts_with("pub mod ") && item.ends_with("_server {") {
+            out.push_str(&line[..line.len() - item.len()]);
+            out.push_str("#[cfg(feature = \"server\")]\n");
+        }
+        out.push_str(line);
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generated_code_is_up_to_date() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+        let committed = root.join("src/generated");
+        let fresh = std::env::temp_dir().join(format!("vega_protobufs_{}", std::process::id()));
+        generate(&root, &fresh, &|_| true);
+
+        let files = |dir: &Path| {
+            let mut names = std::fs::read_dir(dir)
+                .unwrap()
+                .map(|e| e.unwrap().file_name())
+                .collect::<Vec<_>>();
+            names.sort();
+            names
+        };
+        let names = files(&fresh);
+        assert_eq!(
+            names,
+            files(&committed),
+            "run `cargo run -p vega_protobufs_codegen`"
+        );
+        for name in names {
+            let a = std::fs::read(fresh.join(&name)).unwrap();
+            let b = std::fs::read(committed.join(&name)).unwrap();
+            assert!(
+                a == b,
+                "{:?} is out of date, run `cargo run -p vega_protobufs_codegen`",
+                name
+            );
+        }
+        std::fs::remove_dir_all(&fresh).unwrap();
+    }
+}
@@ -0,0 +1,12 @@
+use std::path::Path;
+
+/// Refresh the code committed under `protobufs/src/generated`.
+fn main() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let out_dir = root.join("src/generated");
+    if out_dir.exists() {
+        std::fs::remove_dir_all(&out_dir).unwrap();
+    }
+    vega_protobufs_codegen::generate(&root, &out_dir, &|_| true);
+    println!("generated {}", out_dir.display());
+}
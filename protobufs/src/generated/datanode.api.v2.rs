@@ -0,0 +1,17931 @@
+// This file is @generated by prost-build.
+/// All data returned from the API is ordered in a well-defined manner.
+/// The specific columns and the order in which the sorting is performed
+/// depend on the API endpoint being called. However, the primary sorting
+/// column is usually the timestamp of the block in which the data was last updated.
+///
+/// To prevent excessively large response messages and to avoid overloading
+/// database resources, the API employs a cursor-based pagination mechanism.
+///
+/// This Pagination message can be optionally provided as part of the request to specify:
+/// - The starting point within the total result set for beginning the page
+/// - The size of the returned page
+/// - The ordering of the data within that page
+///
+/// If no Pagination message is provided, the API will return the first page of
+/// data using the default page size. The default page size is 1000.
+///
+/// To retrieve subsequent pages, the caller must examine the PageInfo structure
+/// returned in the response to find a cursor string that uniquely identifies the
+/// last row of that page. This cursor should then be passed in the 'after' field
+/// of the Pagination message in a subsequent request.
+///
+/// For paging backward, take the cursor for the first row of the page from PageInfo
+/// and pass it in the 'before' field of the Pagination message.
+///
+/// Pagination message that uses both first/after and last/before is considered invalid.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Pagination {
+    /// Number of records to be returned that sort greater than row identified by cursor supplied in 'after'.
+    #[prost(int32, optional, tag = "1")]
+    pub first: ::core::option::Option<i32>,
+    /// If paging forwards, the cursor string for the last row of the previous page.
+    #[prost(string, optional, tag = "2")]
+    pub after: ::core::option::Option<::prost::alloc::string::String>,
+    /// Number of records to be returned that sort less than row identified by cursor supplied in 'before'.
+    #[prost(int32, optional, tag = "3")]
+    pub last: ::core::option::Option<i32>,
+    /// If paging forwards, the cursor string for the first row of the previous page.
+    #[prost(string, optional, tag = "4")]
+    pub before: ::core::option::Option<::prost::alloc::string::String>,
+    /// Whether to order the results with the newest records first. If not set, the default value is true.
+    #[prost(bool, optional, tag = "5")]
+    pub newest_first: ::core::option::Option<bool>,
+}
+/// Page information for cursor based pagination
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PageInfo {
+    /// Indicator if there is a next page.
+    #[prost(bool, tag = "1")]
+    pub has_next_page: bool,
+    /// Indicator if there is a previous page.
+    #[prost(bool, tag = "2")]
+    pub has_previous_page: bool,
+    /// Start cursor.
+    #[prost(string, tag = "3")]
+    pub start_cursor: ::prost::alloc::string::String,
+    /// End cursor.
+    #[prost(string, tag = "4")]
+    pub end_cursor: ::prost::alloc::string::String,
+}
+/// Request sent to get statistics about the vesting rewards for a party
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetPartyVestingStatsRequest {
+    /// Party ID to query the vesting stats for.
+    #[prost(string, tag = "1")]
+    pub party_id: ::prost::alloc::string::String,
+}
+/// Vesting reward statistics for a given party
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetPartyVestingStatsResponse {
+    /// Party ID.
+    #[prost(string, tag = "1")]
+    pub party_id: ::prost::alloc::string::String,
+    /// Reward bonus multiplier.
+    #[prost(string, tag = "2")]
+    pub reward_bonus_multiplier: ::prost::alloc::string::String,
+    /// Epoch for which this information is valid.
+    #[prost(uint64, tag = "3")]
+    pub epoch_seq: u64,
+    /// The balance of the party, in quantum.
+    #[prost(string, tag = "4")]
+    pub quantum_balance: ::prost::alloc::string::String,
+    /// Bonus multiplier applied on the reward, summed across all derived accounts.
+    #[prost(string, tag = "5")]
+    pub summed_reward_bonus_multiplier: ::prost::alloc::string::String,
+    /// The balance of the party and derived keys, in quantum.
+    #[prost(string, tag = "6")]
+    pub summed_quantum_balance: ::prost::alloc::string::String,
+}
+/// Request sent to list the vesting and locked balances for a party
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetVestingBalancesSummaryRequest {
+    /// Party ID to query vesting balances for.
+    #[prost(string, tag = "1")]
+    pub party_id: ::prost::alloc::string::String,
+    /// Optional asset ID, all asset balances returned if not set.
+    #[prost(string, optional, tag = "2")]
+    pub asset_id: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// List of vesting and locked balances for a party.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetVestingBalancesSummaryResponse {
+    /// Party ID.
+    #[prost(string, tag = "1")]
+    pub party_id: ::prost::alloc::string::String,
+    /// Epoch for which these balances are valid.
+    #[prost(uint64, optional, tag = "2")]
+    pub epoch_seq: ::core::option::Option<u64>,
+    /// List of locked balances for the party.
+    #[prost(message, repeated, tag = "3")]
+    pub locked_balances: ::prost::alloc::vec::Vec<
+        super::super::super::vega::events::v1::PartyLockedBalance,
+    >,
+    /// List of vesting balances for the party.
+    #[prost(message, repeated, tag = "4")]
+    pub vesting_balances: ::prost::alloc::vec::Vec<
+        super::super::super::vega::events::v1::PartyVestingBalance,
+    >,
+}
+/// Represents the current balance of an account for an asset on Vega, for a particular owner or party
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AccountBalance {
+    /// Party that owns the account.
+    /// Special values include `network` - represents the Vega network and is
+    /// most commonly seen during liquidation of a distressed trading position.
+    #[prost(string, tag = "2")]
+    pub owner: ::prost::alloc::string::String,
+    /// Asset balance represented as an integer, for example `123456` is a correctly
+    /// formatted price of `1.23456` assuming market configured to 5 decimal places.
+    /// Balances cannot be negative.
+    #[prost(string, tag = "3")]
+    pub balance: ::prost::alloc::string::String,
+    /// Asset ID for the account.
+    #[prost(string, tag = "4")]
+    pub asset: ::prost::alloc::string::String,
+    /// Market ID for the account, this field will be empty if the asset is in a general account.
+    #[prost(string, tag = "5")]
+    pub market_id: ::prost::alloc::string::String,
+    /// Account type of this account.
+    #[prost(enumeration = "super::super::super::vega::AccountType", tag = "6")]
+    pub r#type: i32,
+    /// Parent party ID of the account.
+    /// This field is optional and is used in cases where the account is derived from another party's account.
+    #[prost(string, optional, tag = "7")]
+    pub parent_party_id: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// Request that is sent when listing accounts.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListAccountsRequest {
+    /// Account filter contains all filter conditions and values that can be applied to the listing.
+    #[prost(message, optional, tag = "1")]
+    pub filter: ::core::option::Option<AccountFilter>,
+    /// Optional pagination control.
+    #[prost(message, optional, tag = "2")]
+    pub pagination: ::core::option::Option<Pagination>,
+    /// Whether to return all derived parties from AMMs for the given party.
+    /// If used, party ID is required.
+    #[prost(bool, optional, tag = "3")]
+    pub include_derived_parties: ::core::option::Option<bool>,
+}
+/// Response that is received from listing accounts query.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListAccountsResponse {
+    /// Page of accounts data and corresponding page information.
+    #[prost(message, optional, tag = "1")]
+    pub accounts: ::core::option::Option<AccountsConnection>,
+}
+/// Page of accounts data and corresponding page information.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AccountsConnection {
+    /// Page of accounts data and their corresponding cursors.
+    #[prost(message, repeated, tag = "1")]
+    pub edges: ::prost::alloc::vec::Vec<AccountEdge>,
+    /// Page information that is used for fetching further pages.
+    #[prost(message, optional, tag = "2")]
+    pub page_info: ::core::option::Option<PageInfo>,
+}
+/// Account data item with the corresponding cursor.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AccountEdge {
+    /// Account balance data.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<AccountBalance>,
+    /// Cursor that can be used to fetch further pages with reference to this account.
+    #[prost(string, tag = "2")]
+    pub cursor: ::prost::alloc::string::String,
+}
+/// Request that is sent when getting a subscription to a stream of accounts.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ObserveAccountsRequest {
+    /// Market ID to filter accounts by. If empty, no markets will be filtered.
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+    /// Party ID to filter accounts by. If empty, no parties will be filtered.
+    #[prost(string, tag = "2")]
+    pub party_id: ::prost::alloc::string::String,
+    /// Asset ID to filter accounts by. If empty, no assets will be filtered.
+    #[prost(string, tag = "3")]
+    pub asset: ::prost::alloc::string::String,
+    /// Account type to subscribe to, required field.
+    #[prost(enumeration = "super::super::super::vega::AccountType", tag = "4")]
+    pub r#type: i32,
+    /// Whether to return all derived parties from AMMs for the given party.
+    /// If used, party ID is required.
+    #[prost(bool, optional, tag = "5")]
+    pub include_derived_parties: ::core::option::Option<bool>,
+}
+/// Response that is received when subscribing to a stream of accounts.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ObserveAccountsResponse {
+    #[prost(oneof = "observe_accounts_response::Response", tags = "1, 2")]
+    pub response: ::core::option::Option<observe_accounts_response::Response>,
+}
+/// Nested message and enum types in `ObserveAccountsResponse`.
+pub mod observe_accounts_response {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Response {
+        /// 'Initial image' snapshot containing current account balances.
+        #[prost(message, tag = "1")]
+        Snapshot(super::AccountSnapshotPage),
+        /// List of account updates in the last block.
+        #[prost(message, tag = "2")]
+        Updates(super::AccountUpdates),
+    }
+}
+/// 'Initial image' snapshot containing current account balances - may be sent over several response messages
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AccountSnapshotPage {
+    /// List of account balances.
+    #[prost(message, repeated, tag = "1")]
+    pub accounts: ::prost::alloc::vec::Vec<AccountBalance>,
+    /// Indicator if the current page is the last one or not.
+    #[prost(bool, tag = "2")]
+    pub last_page: bool,
+}
+/// List of account updates in the last block
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AccountUpdates {
+    /// List of account balances.
+    #[prost(message, repeated, tag = "1")]
+    pub accounts: ::prost::alloc::vec::Vec<AccountBalance>,
+}
+/// Request that is sent when querying node information
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InfoRequest {}
+/// Response that is received from the node information query
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InfoResponse {
+    /// Semver formatted version of the data node.
+    #[prost(string, tag = "1")]
+    pub version: ::prost::alloc::string::String,
+    /// Commit hash from which the data node was built.
+    #[prost(string, tag = "2")]
+    pub commit_hash: ::prost::alloc::string::String,
+}
+/// Request that is sent when executing the query for getting a single order
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetOrderRequest {
+    /// Order ID to retrieve order information for.
+    #[prost(string, tag = "1")]
+    pub order_id: ::prost::alloc::string::String,
+    /// Historic version number of the order to return. If not set, the most current version will be returned.
+    #[prost(int32, optional, tag = "2")]
+    pub version: ::core::option::Option<i32>,
+}
+/// Response received from the query for getting a single order
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetOrderResponse {
+    /// Order details, if one was found.
+    #[prost(message, optional, tag = "1")]
+    pub order: ::core::option::Option<super::super::super::vega::Order>,
+}
+/// Order filter that contains all filtering conditions and values that are applied to the orders listing
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OrderFilter {
+    /// Restrict orders to those with the given statuses.
+    #[prost(
+        enumeration = "super::super::super::vega::order::Status",
+        repeated,
+        tag = "1"
+    )]
+    pub statuses: ::prost::alloc::vec::Vec<i32>,
+    /// Restrict orders to those with the given types.
+    #[prost(enumeration = "super::super::super::vega::order::Type", repeated, tag = "2")]
+    pub types: ::prost::alloc::vec::Vec<i32>,
+    /// Restrict orders to those with the given Time In Force.
+    #[prost(
+        enumeration = "super::super::super::vega::order::TimeInForce",
+        repeated,
+        tag = "3"
+    )]
+    pub time_in_forces: ::prost::alloc::vec::Vec<i32>,
+    /// Indicator if liquidity provisions should be included or not in the list.
+    #[prost(bool, tag = "4")]
+    pub exclude_liquidity: bool,
+    /// Restrict orders to those placed by the given party IDs.
+    #[prost(string, repeated, tag = "5")]
+    pub party_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Restrict orders to those placed on the given market IDs.
+    #[prost(string, repeated, tag = "6")]
+    pub market_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Restrict orders to those with the given reference.
+    #[prost(string, optional, tag = "7")]
+    pub reference: ::core::option::Option<::prost::alloc::string::String>,
+    /// Restrict orders to those placed during the given date range. If not set, all orders will be returned.
+    #[prost(message, optional, tag = "8")]
+    pub date_range: ::core::option::Option<DateRange>,
+    /// Restrict orders to those that are live. If not set, it is treated as being false.
+    #[prost(bool, optional, tag = "9")]
+    pub live_only: ::core::option::Option<bool>,
+}
+/// Request that is sent when executing a query for a list of orders
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListOrdersRequest {
+    /// Optional pagination control.
+    #[prost(message, optional, tag = "2")]
+    pub pagination: ::core::option::Option<Pagination>,
+    /// Order filter contains all filtering conditions and values that are applied to the orders listing.
+    #[prost(message, optional, tag = "5")]
+    pub filter: ::core::option::Option<OrderFilter>,
+}
+/// Response that is received from the query to list orders
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListOrdersResponse {
+    /// Page of orders data and corresponding page information.
+    #[prost(message, optional, tag = "1")]
+    pub orders: ::core::option::Option<OrderConnection>,
+}
+/// Request that is sent when listing possible order versions
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListOrderVersionsRequest {
+    /// Order ID to list versions for.
+    #[prost(string, tag = "1")]
+    pub order_id: ::prost::alloc::string::String,
+    /// Optional pagination control.
+    #[prost(message, optional, tag = "4")]
+    pub pagination: ::core::option::Option<Pagination>,
+}
+/// Response that is received when listing possible order versions
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListOrderVersionsResponse {
+    /// Page of order versions and corresponding page information.
+    #[prost(message, optional, tag = "1")]
+    pub orders: ::core::option::Option<OrderConnection>,
+}
+/// Request to subscribe to a stream of orders.
+///
+/// Request fields market ID and party ID are both optional filters:
+/// If omitted all orders, for all parties on all markets will be returned on the stream
+/// Both filters can be combined
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ObserveOrdersRequest {
+    /// Restrict orders to those placed on the given markets.
+    #[prost(string, repeated, tag = "1")]
+    pub market_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Restrict orders to those placed on the market by the given parties.
+    #[prost(string, repeated, tag = "2")]
+    pub party_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Whether liquidity orders should be excluded from the stream. If not set, liquidity orders will be included.
+    #[prost(bool, optional, tag = "3")]
+    pub exclude_liquidity: ::core::option::Option<bool>,
+}
+/// Response that is received from an orders subscription.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ObserveOrdersResponse {
+    #[prost(oneof = "observe_orders_response::Response", tags = "1, 2")]
+    pub response: ::core::option::Option<observe_orders_response::Response>,
+}
+/// Nested message and enum types in `ObserveOrdersResponse`.
+pub mod observe_orders_response {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Response {
+        /// An 'initial image' snapshot containing current live orders.
+        #[prost(message, tag = "1")]
+        Snapshot(super::OrderSnapshotPage),
+        /// List of order updates in the last block.
+        #[prost(message, tag = "2")]
+        Updates(super::OrderUpdates),
+    }
+}
+/// 'Initial image' of live orders, may be sent over multiple response messages.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OrderSnapshotPage {
+    /// List of order data parts.
+    #[prost(message, repeated, tag = "1")]
+    pub orders: ::prost::alloc::vec::Vec<super::super::super::vega::Order>,
+    /// Indicator if the last page is reached or not.
+    #[prost(bool, tag = "2")]
+    pub last_page: bool,
+}
+/// List of order updates in the last block.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OrderUpdates {
+    /// List of orders data.
+    #[prost(message, repeated, tag = "1")]
+    pub orders: ::prost::alloc::vec::Vec<super::super::super::vega::Order>,
+}
+/// Request that is sent when executing the query for getting a single stop order.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetStopOrderRequest {
+    /// Order ID to retrieve order information for.
+    #[prost(string, tag = "1")]
+    pub order_id: ::prost::alloc::string::String,
+}
+/// Response received from the query for getting a single stop order.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetStopOrderResponse {
+    /// Order details, if one was found.
+    #[prost(message, optional, tag = "1")]
+    pub order: ::core::option::Option<
+        super::super::super::vega::events::v1::StopOrderEvent,
+    >,
+}
+/// Request that is sent when executing a query for a list of team game scores.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListGameTeamScoresRequest {
+    /// Optional pagination control.
+    #[prost(message, optional, tag = "1")]
+    pub pagination: ::core::option::Option<Pagination>,
+    /// Team games filter contains all filtering conditions and values that are applied to the team games score listing.
+    #[prost(message, optional, tag = "2")]
+    pub filter: ::core::option::Option<GameTeamScoresFilter>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GameTeamScoresFilter {
+    /// Restrict game scores to the given ones.
+    #[prost(string, repeated, tag = "1")]
+    pub game_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Restrict game scores to those for the given teams.
+    #[prost(string, repeated, tag = "2")]
+    pub team_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Restrict game scores to those from a given epoch.
+    #[prost(uint64, optional, tag = "3")]
+    pub epoch_from: ::core::option::Option<u64>,
+    /// Restrict game scores to those up to a given epoch.
+    #[prost(uint64, optional, tag = "4")]
+    pub epoch_to: ::core::option::Option<u64>,
+}
+/// Response received from the query for getting a list of game scores for teams.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListGameTeamScoresResponse {
+    /// Page of team game scores data and corresponding page information.
+    #[prost(message, optional, tag = "1")]
+    pub team_scores: ::core::option::Option<GameTeamScoresConnection>,
+}
+/// Page of team game scores data and corresponding page information.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GameTeamScoresConnection {
+    /// Page of orders and their corresponding cursors.
+    #[prost(message, repeated, tag = "1")]
+    pub edges: ::prost::alloc::vec::Vec<GameTeamScoresEdge>,
+    /// Page information that is used for fetching further pages.
+    #[prost(message, optional, tag = "2")]
+    pub page_info: ::core::option::Option<PageInfo>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GameTeamScoresEdge {
+    /// Data associated with an order submitted to a Vega node.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<
+        super::super::super::vega::events::v1::GameTeamScore,
+    >,
+    /// Cursor that can be used to fetch further pages.
+    #[prost(string, tag = "2")]
+    pub cursor: ::prost::alloc::string::String,
+}
+/// Request that is sent when executing a query for a list of party game scores.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListGamePartyScoresRequest {
+    /// Optional pagination control.
+    #[prost(message, optional, tag = "1")]
+    pub pagination: ::core::option::Option<Pagination>,
+    /// Party games filter contains all filtering conditions and values that are applied to the party games score listing.
+    #[prost(message, optional, tag = "2")]
+    pub filter: ::core::option::Option<GamePartyScoresFilter>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GamePartyScoresFilter {
+    /// Restrict game scores to those in the given games.
+    #[prost(string, repeated, tag = "1")]
+    pub game_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Restrict game scores to the parties in the given teams.
+    #[prost(string, repeated, tag = "2")]
+    pub team_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Restrict game scores to those relating to the given parties.
+    #[prost(string, repeated, tag = "3")]
+    pub party_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Restrict game scores to those from a given epoch.
+    #[prost(uint64, optional, tag = "4")]
+    pub epoch_from: ::core::option::Option<u64>,
+    /// Restrict game scores to those up to a given epoch.
+    #[prost(uint64, optional, tag = "5")]
+    pub epoch_to: ::core::option::Option<u64>,
+}
+/// Response received from the query for getting a list of game scores for teams.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListGamePartyScoresResponse {
+    /// Page of party game scores data and corresponding page information.
+    #[prost(message, optional, tag = "1")]
+    pub party_scores: ::core::option::Option<GamePartyScoresConnection>,
+}
+/// Page of party game scores data and corresponding page information.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GamePartyScoresConnection {
+    /// Page of orders and their corresponding cursors.
+    #[prost(message, repeated, tag = "1")]
+    pub edges: ::prost::alloc::vec::Vec<GamePartyScoresEdge>,
+    /// Page information that is used for fetching further pages.
+    #[prost(message, optional, tag = "2")]
+    pub page_info: ::core::option::Option<PageInfo>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GamePartyScoresEdge {
+    /// Data associated with an order submitted to a Vega node.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<
+        super::super::super::vega::events::v1::GamePartyScore,
+    >,
+    /// Cursor that can be used to fetch further pages.
+    #[prost(string, tag = "2")]
+    pub cursor: ::prost::alloc::string::String,
+}
+/// Request that is sent when executing a query for a list of stop orders.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListStopOrdersRequest {
+    /// Optional pagination control.
+    #[prost(message, optional, tag = "2")]
+    pub pagination: ::core::option::Option<Pagination>,
+    /// Order filter contains all filtering conditions and values that are applied to the orders listing.
+    #[prost(message, optional, tag = "5")]
+    pub filter: ::core::option::Option<StopOrderFilter>,
+}
+/// Stop order filter
+///
+/// Stop order filter that contains all filtering conditions and values that are applied to the stop orders listing.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StopOrderFilter {
+    /// Restrict orders to those with the given statuses.
+    #[prost(
+        enumeration = "super::super::super::vega::stop_order::Status",
+        repeated,
+        tag = "1"
+    )]
+    pub statuses: ::prost::alloc::vec::Vec<i32>,
+    /// Restrict orders to those with the given expiry strategies.
+    #[prost(
+        enumeration = "super::super::super::vega::stop_order::ExpiryStrategy",
+        repeated,
+        tag = "2"
+    )]
+    pub expiry_strategies: ::prost::alloc::vec::Vec<i32>,
+    /// Restrict orders to those placed during the given date range. If not set, all orders will be returned.
+    #[prost(message, optional, tag = "3")]
+    pub date_range: ::core::option::Option<DateRange>,
+    /// Restrict orders to those placed by the given party IDs.
+    #[prost(string, repeated, tag = "4")]
+    pub party_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Restrict orders to those placed on the given market IDs.
+    #[prost(string, repeated, tag = "5")]
+    pub market_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Live stop orders only
+    #[prost(bool, optional, tag = "6")]
+    pub live_only: ::core::option::Option<bool>,
+}
+/// Order data with the corresponding cursor.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StopOrderEdge {
+    /// Data associated with an order submitted to a Vega node.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<
+        super::super::super::vega::events::v1::StopOrderEvent,
+    >,
+    /// Cursor that can be used to fetch further pages.
+    #[prost(string, tag = "2")]
+    pub cursor: ::prost::alloc::string::String,
+}
+/// Page of stop orders data and corresponding page information.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StopOrderConnection {
+    /// Page of stop orders and their corresponding cursors.
+    #[prost(message, repeated, tag = "1")]
+    pub edges: ::prost::alloc::vec::Vec<StopOrderEdge>,
+    /// Page information that is used for fetching further pages.
+    #[prost(message, optional, tag = "2")]
+    pub page_info: ::core::option::Option<PageInfo>,
+}
+/// Response that is received from the query to list stop orders.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListStopOrdersResponse {
+    /// Page of stop orders data and corresponding page information.
+    #[prost(message, optional, tag = "1")]
+    pub orders: ::core::option::Option<StopOrderConnection>,
+}
+/// Request used to list all positions for a party.
+/// Optionally, if a market ID is set, the results will be filtered for that market only.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListPositionsRequest {
+    /// Party ID to list positions for, required field.
+    #[prost(string, tag = "1")]
+    pub party_id: ::prost::alloc::string::String,
+    /// Market ID to filter for. If empty, no markets will be filtered.
+    #[prost(string, tag = "2")]
+    pub market_id: ::prost::alloc::string::String,
+    /// Pagination controls.
+    #[prost(message, optional, tag = "3")]
+    pub pagination: ::core::option::Option<Pagination>,
+}
+/// Response for a list of positions for a party
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListPositionsResponse {
+    /// Page of positions data and corresponding page information.
+    #[prost(message, optional, tag = "1")]
+    pub positions: ::core::option::Option<PositionConnection>,
+}
+/// Filter to apply to the ListAllPositionsRequest
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PositionsFilter {
+    /// Restrict positions to those related to the given parties.
+    #[prost(string, repeated, tag = "1")]
+    pub party_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Restrict positions to those on the given markets.
+    #[prost(string, repeated, tag = "2")]
+    pub market_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// Request to list positions, given the position filter is supplied.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListAllPositionsRequest {
+    /// Filter to apply to the positions.
+    #[prost(message, optional, tag = "1")]
+    pub filter: ::core::option::Option<PositionsFilter>,
+    /// Pagination controls.
+    #[prost(message, optional, tag = "2")]
+    pub pagination: ::core::option::Option<Pagination>,
+}
+/// Response to query for listing of positions, given the filter is supplied
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListAllPositionsResponse {
+    /// Page of positions data and corresponding page information.
+    #[prost(message, optional, tag = "1")]
+    pub positions: ::core::option::Option<PositionConnection>,
+}
+/// Position data with the corresponding cursor.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PositionEdge {
+    /// Position data for a party on a market.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<super::super::super::vega::Position>,
+    /// Cursor that can be used to fetch further pages.
+    #[prost(string, tag = "2")]
+    pub cursor: ::prost::alloc::string::String,
+}
+/// Page of positions and corresponding page information
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PositionConnection {
+    /// Page of positions data and their corresponding cursors.
+    #[prost(message, repeated, tag = "1")]
+    pub edges: ::prost::alloc::vec::Vec<PositionEdge>,
+    /// Page information that is used for fetching further pages.
+    #[prost(message, optional, tag = "2")]
+    pub page_info: ::core::option::Option<PageInfo>,
+}
+/// Request that is used to subscribe to a stream of positions
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ObservePositionsRequest {
+    /// Restrict position updates to those related to the given parties.
+    #[prost(string, optional, tag = "1")]
+    pub party_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Restrict position updates to those related to the given markets.
+    #[prost(string, optional, tag = "2")]
+    pub market_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Whether to return all derived parties from AMMs for the given party.
+    /// If used, party ID is required.
+    #[prost(bool, optional, tag = "3")]
+    pub include_derived_parties: ::core::option::Option<bool>,
+}
+/// Response received from a positions subscription request
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ObservePositionsResponse {
+    #[prost(oneof = "observe_positions_response::Response", tags = "1, 2")]
+    pub response: ::core::option::Option<observe_positions_response::Response>,
+}
+/// Nested message and enum types in `ObservePositionsResponse`.
+pub mod observe_positions_response {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Response {
+        /// An 'initial image' snapshot containing current positions.
+        #[prost(message, tag = "1")]
+        Snapshot(super::PositionSnapshotPage),
+        /// List of position updates in the last block.
+        #[prost(message, tag = "2")]
+        Updates(super::PositionUpdates),
+    }
+}
+/// 'Initial image' of current positions, may be sent over multiple response messages
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PositionSnapshotPage {
+    /// List of positions data.
+    #[prost(message, repeated, tag = "1")]
+    pub positions: ::prost::alloc::vec::Vec<super::super::super::vega::Position>,
+    /// Indicator if last page is reached or not.
+    #[prost(bool, tag = "2")]
+    pub last_page: bool,
+}
+/// List of position updates in the last block
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PositionUpdates {
+    /// List of positions data.
+    #[prost(message, repeated, tag = "1")]
+    pub positions: ::prost::alloc::vec::Vec<super::super::super::vega::Position>,
+}
+/// Ledger entry filter sets filters on returned set of ledger entries.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LedgerEntryFilter {
+    /// Determines whether an entry must have accounts matching both the account_from_filter
+    /// and the account_to_filter. If set to 'true', entries must have matches in both filters.
+    /// If set to `false`, entries matching only the account_from_filter or the account_to_filter will also be included.
+    #[prost(bool, tag = "1")]
+    pub close_on_account_filters: bool,
+    /// Used to set values for filtering sender accounts. Party must be provided in this filter or 'to' account filter, or both.
+    #[prost(message, optional, tag = "2")]
+    pub from_account_filter: ::core::option::Option<AccountFilter>,
+    /// Used to set values for filtering receiver accounts. Party must be provided in this filter or 'from' account filter, or both.
+    #[prost(message, optional, tag = "3")]
+    pub to_account_filter: ::core::option::Option<AccountFilter>,
+    /// List of transfer types that is used for filtering sender and receiver accounts.
+    #[prost(
+        enumeration = "super::super::super::vega::TransferType",
+        repeated,
+        tag = "5"
+    )]
+    pub transfer_types: ::prost::alloc::vec::Vec<i32>,
+    /// List ledger entries that are associated with a specific transfer ID. If provided, all other filters are ignored
+    #[prost(string, optional, tag = "6")]
+    pub transfer_id: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// Represents an aggregated view of ledger entry data, sender and receiver accounts,
+/// details and balances for both sides after the transfer has been made
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AggregatedLedgerEntry {
+    /// Timestamp in Unix nanoseconds of the block in which the result is referring to.
+    #[prost(int64, tag = "2")]
+    pub timestamp: i64,
+    /// Amount of ledger entries for the set of requested accounts at the time above.
+    #[prost(string, tag = "3")]
+    pub quantity: ::prost::alloc::string::String,
+    /// Transfer type.
+    #[prost(enumeration = "super::super::super::vega::TransferType", tag = "4")]
+    pub transfer_type: i32,
+    /// Asset ID for the asset associated with the entry.
+    #[prost(string, optional, tag = "5")]
+    pub asset_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Type of account sent from.
+    #[prost(enumeration = "super::super::super::vega::AccountType", tag = "6")]
+    pub from_account_type: i32,
+    /// Type of account received to.
+    #[prost(enumeration = "super::super::super::vega::AccountType", tag = "7")]
+    pub to_account_type: i32,
+    /// Sender's party ID.
+    #[prost(string, optional, tag = "8")]
+    pub from_account_party_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Receiver's party ID.
+    #[prost(string, optional, tag = "9")]
+    pub to_account_party_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Sender market ID.
+    #[prost(string, optional, tag = "10")]
+    pub from_account_market_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Receiver market ID.
+    #[prost(string, optional, tag = "11")]
+    pub to_account_market_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Sender account balance after the transfer.
+    #[prost(string, tag = "12")]
+    pub from_account_balance: ::prost::alloc::string::String,
+    /// Receiver account balance after the transfer.
+    #[prost(string, tag = "13")]
+    pub to_account_balance: ::prost::alloc::string::String,
+    /// Transfer ID associated with the ledger entry.
+    #[prost(string, tag = "14")]
+    pub transfer_id: ::prost::alloc::string::String,
+}
+/// Request that is sent when listing ledger entries.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListLedgerEntriesRequest {
+    /// Ledger entry filter that contains all values and conditions according to which
+    /// the listing of ledger entries is filtered.You must provide at least one party in
+    /// 'from' account filter, or 'to' account filter.
+    #[prost(message, optional, tag = "1")]
+    pub filter: ::core::option::Option<LedgerEntryFilter>,
+    /// Optional pagination control.
+    #[prost(message, optional, tag = "2")]
+    pub pagination: ::core::option::Option<Pagination>,
+    /// Date range for which to list ledger entries.
+    /// If not set, the date range is restricted to the last 5 days.
+    /// If a start and end date is provided, but the range is more than 5 days, the end date will be restricted to 5 days from the start.
+    /// If a start date is provided, but no end date, the end date will be set to 5 days from the start.
+    /// If an end date is provided, but no start date, the start date will be set to 5 days before the end.
+    #[prost(message, optional, tag = "3")]
+    pub date_range: ::core::option::Option<DateRange>,
+}
+/// Request that is sent when exporting ledger entries to csv
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExportLedgerEntriesRequest {
+    /// Restrict exported ledger entries to those relating to the given party ID.
+    #[prost(string, tag = "1")]
+    pub party_id: ::prost::alloc::string::String,
+    /// Restrict exported ledger entries to those relating to the given asset ID.
+    #[prost(string, optional, tag = "2")]
+    pub asset_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Date range to export ledger entries for.
+    #[prost(message, optional, tag = "4")]
+    pub date_range: ::core::option::Option<DateRange>,
+}
+/// Response that is received when listing ledger entries
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListLedgerEntriesResponse {
+    /// Page of aggregated ledger entries data and corresponding page information.
+    #[prost(message, optional, tag = "1")]
+    pub ledger_entries: ::core::option::Option<AggregatedLedgerEntriesConnection>,
+}
+/// Represents the aggregated ledger entry data with corresponding cursor for it
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AggregatedLedgerEntriesEdge {
+    /// 'AggregatedLedgerEntry' data.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<AggregatedLedgerEntry>,
+    /// Cursor that can be used to fetch further pages.
+    #[prost(string, tag = "2")]
+    pub cursor: ::prost::alloc::string::String,
+}
+/// Page of aggregated ledger entries data and corresponding page information.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AggregatedLedgerEntriesConnection {
+    /// Page of 'AggregatedLedgerEntry' data and their corresponding cursors.
+    #[prost(message, repeated, tag = "1")]
+    pub edges: ::prost::alloc::vec::Vec<AggregatedLedgerEntriesEdge>,
+    /// Page information that is used for fetching further pages.
+    #[prost(message, optional, tag = "2")]
+    pub page_info: ::core::option::Option<PageInfo>,
+}
+/// Request to use when querying balances changes.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListBalanceChangesRequest {
+    /// Limit the accounts considered according to the filter supplied.
+    #[prost(message, optional, tag = "1")]
+    pub filter: ::core::option::Option<AccountFilter>,
+    /// Pagination controls.
+    #[prost(message, optional, tag = "5")]
+    pub pagination: ::core::option::Option<Pagination>,
+    /// Date range over which to query. If a cursor is not provided in the pagination controls,
+    /// the date range must be provided with a start or end date, or both.
+    /// The date range is inclusive of the start date and exclusive of the end date.
+    /// The date range must be no more than 1 year in duration.
+    /// Dates before 2020-01-01 will not be accepted.
+    #[prost(message, optional, tag = "6")]
+    pub date_range: ::core::option::Option<DateRange>,
+}
+/// Response that is received from querying balances changes.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListBalanceChangesResponse {
+    /// Page of aggregated balances data and corresponding page information.
+    #[prost(message, optional, tag = "1")]
+    pub balances: ::core::option::Option<AggregatedBalanceConnection>,
+}
+/// Request that is used when querying balance history
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetBalanceHistoryRequest {
+    /// Account filter contains values that can be set in order to limit balance history data to them.
+    #[prost(message, optional, tag = "1")]
+    pub filter: ::core::option::Option<AccountFilter>,
+    /// By default the net balances of all the accounts specified by the filter are returned.
+    /// If a list of fields is given in group_by, split out those balances by the supplied criteria.
+    #[prost(enumeration = "AccountField", repeated, tag = "2")]
+    pub group_by: ::prost::alloc::vec::Vec<i32>,
+    #[prost(message, optional, tag = "3")]
+    pub pagination: ::core::option::Option<Pagination>,
+    #[prost(message, optional, tag = "4")]
+    pub date_range: ::core::option::Option<DateRange>,
+}
+/// Response that is received when querying for balance history
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetBalanceHistoryResponse {
+    /// Page of aggregated balances with the corresponding page information.
+    #[prost(message, optional, tag = "1")]
+    pub balances: ::core::option::Option<AggregatedBalanceConnection>,
+}
+/// Aggregated balance data with the corresponding cursor.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AggregatedBalanceEdge {
+    /// Balance of the set of requested accounts.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<AggregatedBalance>,
+    /// Cursor that can be used to fetch further pages.
+    #[prost(string, tag = "2")]
+    pub cursor: ::prost::alloc::string::String,
+}
+/// Page of aggregated balance objects and corresponding page information
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AggregatedBalanceConnection {
+    /// Page of aggregated balance data and their corresponding cursors.
+    #[prost(message, repeated, tag = "1")]
+    pub edges: ::prost::alloc::vec::Vec<AggregatedBalanceEdge>,
+    /// Page information that is used for fetching further pages.
+    #[prost(message, optional, tag = "2")]
+    pub page_info: ::core::option::Option<PageInfo>,
+}
+/// Account filter is used to filter account data.
+///
+/// An account is defined as a set of asset ID, type, party ID, and market ID.
+/// - Every account has an associated asset and type.
+/// - Certain account types such as the global reward pool for example, do not have an associated party.
+///    These are denoted by the special party ID 'network'.
+/// - Certain account types do not have an associated market such as the general party accounts, for example.
+///    These are denoted by the special market ID '' (an empty string).
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AccountFilter {
+    /// Restrict accounts to those holding balances in this asset ID.
+    #[prost(string, tag = "1")]
+    pub asset_id: ::prost::alloc::string::String,
+    /// Restrict accounts to those owned by the parties in this list. Pass an empty list for no filter.
+    #[prost(string, repeated, tag = "2")]
+    pub party_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Restrict accounts to those connected to the markets in this list. Pass an empty list for no filter.
+    #[prost(string, repeated, tag = "3")]
+    pub market_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Restrict accounts to those connected to any of the types in this list. Pass an empty list for no filter.
+    #[prost(enumeration = "super::super::super::vega::AccountType", repeated, tag = "4")]
+    pub account_types: ::prost::alloc::vec::Vec<i32>,
+}
+/// AggregatedBalance data contains the balance of the set of requested accounts combined with corresponding
+/// party ID, market ID and account type details
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AggregatedBalance {
+    /// Timestamp in Unix nanoseconds of the block in which the balance exists.
+    #[prost(int64, tag = "1")]
+    pub timestamp: i64,
+    /// Balance of the set of requested accounts at the time above.
+    #[prost(string, tag = "2")]
+    pub balance: ::prost::alloc::string::String,
+    /// If grouping by party, the party ID.
+    #[prost(string, optional, tag = "4")]
+    pub party_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// If grouping by asset, the asset ID.
+    #[prost(string, optional, tag = "5")]
+    pub asset_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// If grouping by market, the market ID
+    #[prost(string, optional, tag = "6")]
+    pub market_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// If grouping by account type, the account type.
+    #[prost(enumeration = "super::super::super::vega::AccountType", optional, tag = "7")]
+    pub account_type: ::core::option::Option<i32>,
+}
+/// Request that is sent to get market depth subscription
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ObserveMarketsDepthRequest {
+    /// Restrict market depth data by the given market IDs.
+    #[prost(string, repeated, tag = "1")]
+    pub market_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// Response that is received for MarketDepth subscription.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ObserveMarketsDepthResponse {
+    /// List of market depth data.
+    #[prost(message, repeated, tag = "1")]
+    pub market_depth: ::prost::alloc::vec::Vec<super::super::super::vega::MarketDepth>,
+}
+/// Request that is sent for market depth update subscription
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ObserveMarketsDepthUpdatesRequest {
+    /// Restrict updates to market depth by the given market IDs.
+    #[prost(string, repeated, tag = "1")]
+    pub market_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// Response that is received for market depth update subscription
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ObserveMarketsDepthUpdatesResponse {
+    /// List of market depth update data.
+    #[prost(message, repeated, tag = "1")]
+    pub update: ::prost::alloc::vec::Vec<super::super::super::vega::MarketDepthUpdate>,
+}
+/// Request that is sent for market data subscription
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ObserveMarketsDataRequest {
+    /// Restrict updates to market data by the given market IDs.
+    #[prost(string, repeated, tag = "1")]
+    pub market_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// Response that is received for market data subscription
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ObserveMarketsDataResponse {
+    /// List of market data.
+    #[prost(message, repeated, tag = "1")]
+    pub market_data: ::prost::alloc::vec::Vec<super::super::super::vega::MarketData>,
+}
+/// Request that is sent when requesting latest market depth data
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetLatestMarketDepthRequest {
+    /// Market ID to request market depth for, required field.
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+    /// Maximum market depth.
+    #[prost(uint64, optional, tag = "2")]
+    pub max_depth: ::core::option::Option<u64>,
+}
+/// Response that is received when latest market depth data is queried
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetLatestMarketDepthResponse {
+    /// Market ID of the depth levels returned.
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+    /// Zero or more price levels for the buy side of the market depth data.
+    #[prost(message, repeated, tag = "2")]
+    pub buy: ::prost::alloc::vec::Vec<super::super::super::vega::PriceLevel>,
+    /// Zero or more price levels for the sell side of the market depth data.
+    #[prost(message, repeated, tag = "3")]
+    pub sell: ::prost::alloc::vec::Vec<super::super::super::vega::PriceLevel>,
+    /// Last trade recorded on Vega.
+    #[prost(message, optional, tag = "4")]
+    pub last_trade: ::core::option::Option<super::super::super::vega::Trade>,
+    /// Sequence number incremented after each update.
+    #[prost(uint64, tag = "5")]
+    pub sequence_number: u64,
+}
+/// Request that is sent when listing the latest market data for every market
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListLatestMarketDataRequest {}
+/// Response that is received when listing the latest market data for every market
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListLatestMarketDataResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub markets_data: ::prost::alloc::vec::Vec<super::super::super::vega::MarketData>,
+}
+/// Request that is sent when listing the latest market data for a given market
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetLatestMarketDataRequest {
+    /// Market ID to retrieve market data for.
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+}
+/// Response that is received when listing the latest market data for a given market
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetLatestMarketDataResponse {
+    /// Market data that was requested.
+    #[prost(message, optional, tag = "1")]
+    pub market_data: ::core::option::Option<super::super::super::vega::MarketData>,
+}
+/// Request that is sent for getting Market Data History
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetMarketDataHistoryByIdRequest {
+    /// Market ID to request data history for.
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+    /// Timestamp in Unix nanoseconds indicating the start of the date range.
+    #[prost(int64, optional, tag = "2")]
+    pub start_timestamp: ::core::option::Option<i64>,
+    /// Timestamp in Unix nanoseconds indicating the end of the date range.
+    #[prost(int64, optional, tag = "3")]
+    pub end_timestamp: ::core::option::Option<i64>,
+    /// Pagination control.
+    #[prost(message, optional, tag = "4")]
+    pub pagination: ::core::option::Option<Pagination>,
+}
+/// Response that is received when querying Market Data History
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetMarketDataHistoryByIdResponse {
+    /// Page of market data history with the corresponding page information.
+    #[prost(message, optional, tag = "1")]
+    pub market_data: ::core::option::Option<MarketDataConnection>,
+}
+/// Market data with the corresponding cursor.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MarketDataEdge {
+    /// Data generated by a market when open.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<super::super::super::vega::MarketData>,
+    /// Cursor that can be used to fetch further pages.
+    #[prost(string, tag = "2")]
+    pub cursor: ::prost::alloc::string::String,
+}
+/// Page of market data items and corresponding page information
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MarketDataConnection {
+    /// Page of market data items and their corresponding cursors.
+    #[prost(message, repeated, tag = "1")]
+    pub edges: ::prost::alloc::vec::Vec<MarketDataEdge>,
+    /// Page information that is used for fetching further pages.
+    #[prost(message, optional, tag = "2")]
+    pub page_info: ::core::option::Option<PageInfo>,
+}
+/// Request that is sent to list transfers
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListTransfersRequest {
+    /// Restrict transfer to those where the given public key is a sender or receiver.
+    #[prost(string, optional, tag = "1")]
+    pub pubkey: ::core::option::Option<::prost::alloc::string::String>,
+    /// Restrict transfers to those in the given direction from the supplied public key.
+    /// When is_reward is true, and pubkey is set, then direction MUST be TRANSFER_DIRECTION_DIRECTION_FROM.
+    #[prost(enumeration = "TransferDirection", tag = "2")]
+    pub direction: i32,
+    /// Pagination controls.
+    #[prost(message, optional, tag = "3")]
+    pub pagination: ::core::option::Option<Pagination>,
+    /// Restrict transfers to those related to reward transfers.
+    #[prost(bool, optional, tag = "4")]
+    pub is_reward: ::core::option::Option<bool>,
+    /// Epoch to filter from (included). If omitted, the range goes from the oldest epoch to the `to epoch`.
+    #[prost(uint64, optional, tag = "5")]
+    pub from_epoch: ::core::option::Option<u64>,
+    /// Epoch to filter to (included). If omitted, the range goes from `from epoch` to the most recent epoch.
+    #[prost(uint64, optional, tag = "6")]
+    pub to_epoch: ::core::option::Option<u64>,
+    /// Status to filter on.
+    #[prost(
+        enumeration = "super::super::super::vega::events::v1::transfer::Status",
+        optional,
+        tag = "7"
+    )]
+    pub status: ::core::option::Option<i32>,
+    /// Dispatch strategy's scope to filter for.
+    #[prost(enumeration = "list_transfers_request::Scope", optional, tag = "8")]
+    pub scope: ::core::option::Option<i32>,
+    /// Game ID to filter on
+    #[prost(string, optional, tag = "9")]
+    pub game_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Account type to filter on for transfers from account
+    #[prost(
+        enumeration = "super::super::super::vega::AccountType",
+        optional,
+        tag = "10"
+    )]
+    pub from_account_type: ::core::option::Option<i32>,
+    /// Account type to filter on for transfers to account
+    #[prost(
+        enumeration = "super::super::super::vega::AccountType",
+        optional,
+        tag = "11"
+    )]
+    pub to_account_type: ::core::option::Option<i32>,
+}
+/// Nested message and enum types in `ListTransfersRequest`.
+pub mod list_transfers_request {
+    /// Defines the types of a dispatch strategy's scope the API can filter on.
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Scope {
+        Unspecified = 0,
+        /// Matches transfers that have dispatch strategy scope of individual set.
+        Individual = 1,
+        /// Matches transfers that have dispatch strategy scope of team set.
+        Team = 2,
+    }
+    impl Scope {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Scope::Unspecified => "SCOPE_UNSPECIFIED",
+                Scope::Individual => "SCOPE_INDIVIDUAL",
+                Scope::Team => "SCOPE_TEAM",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "SCOPE_UNSPECIFIED" => Some(Self::Unspecified),
+                "SCOPE_INDIVIDUAL" => Some(Self::Individual),
+                "SCOPE_TEAM" => Some(Self::Team),
+                _ => None,
+            }
+        }
+    }
+}
+/// Response that is received when listing  transfers
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListTransfersResponse {
+    /// Page of transfers data and corresponding page information.
+    #[prost(message, optional, tag = "1")]
+    pub transfers: ::core::option::Option<TransferConnection>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransferNode {
+    /// Data relating to a transfer that has been made.
+    #[prost(message, optional, tag = "1")]
+    pub transfer: ::core::option::Option<
+        super::super::super::vega::events::v1::Transfer,
+    >,
+    /// Fees paid for this transfer.
+    #[prost(message, repeated, tag = "2")]
+    pub fees: ::prost::alloc::vec::Vec<
+        super::super::super::vega::events::v1::TransferFees,
+    >,
+}
+/// Transfers data with the corresponding cursor.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransferEdge {
+    /// Data relating to a transfer that has been made.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<TransferNode>,
+    /// Cursor that can be used to fetch further pages.
+    #[prost(string, tag = "2")]
+    pub cursor: ::prost::alloc::string::String,
+}
+/// Page of transfers data items and corresponding page information
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransferConnection {
+    /// Page of transfers data and their corresponding cursors.
+    #[prost(message, repeated, tag = "1")]
+    pub edges: ::prost::alloc::vec::Vec<TransferEdge>,
+    /// Page information that is used for fetching further pages.
+    #[prost(message, optional, tag = "2")]
+    pub page_info: ::core::option::Option<PageInfo>,
+}
+/// Request that is sent for getting a transfer by ID
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetTransferRequest {
+    /// Transfer ID to request data for.
+    #[prost(string, tag = "1")]
+    pub transfer_id: ::prost::alloc::string::String,
+}
+/// Response that is received when querying transfers by ID
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetTransferResponse {
+    /// Transfer and its fees requested by ID.
+    #[prost(message, optional, tag = "1")]
+    pub transfer_node: ::core::option::Option<TransferNode>,
+}
+/// Request that is used to query current network limits
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetNetworkLimitsRequest {}
+/// Response received when querying the current network limits
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetNetworkLimitsResponse {
+    /// List of received network limits.
+    #[prost(message, optional, tag = "1")]
+    pub limits: ::core::option::Option<super::super::super::vega::NetworkLimits>,
+}
+/// Request that is used to fetch a list of supported intervals for the given market along with the corresponding candle ID
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListCandleIntervalsRequest {
+    /// Unique ID for the market to list candle intervals for.
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+}
+/// Maps an interval for a given market to its corresponding candle ID
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IntervalToCandleId {
+    /// Interval for the candle.
+    #[prost(string, tag = "1")]
+    pub interval: ::prost::alloc::string::String,
+    /// Unique ID of the candle.
+    #[prost(string, tag = "2")]
+    pub candle_id: ::prost::alloc::string::String,
+}
+/// List of mapped pairs `interval` -> `candle ID` for a given market
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListCandleIntervalsResponse {
+    /// List of the mappings.
+    #[prost(message, repeated, tag = "1")]
+    pub interval_to_candle_id: ::prost::alloc::vec::Vec<IntervalToCandleId>,
+}
+/// Represents the high, low, open, and closing prices for an interval of trading,
+/// referred to commonly as a candlestick or candle
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Candle {
+    /// Timestamp in Unix nanoseconds for the point in time when the candle was initially created/opened.
+    #[prost(int64, tag = "1")]
+    pub start: i64,
+    /// Timestamp in Unix nanoseconds for the point in time when the candle was last updated.
+    #[prost(int64, tag = "2")]
+    pub last_update: i64,
+    /// Highest price for trading during the candle interval.
+    #[prost(string, tag = "3")]
+    pub high: ::prost::alloc::string::String,
+    /// Lowest price for trading during the candle interval.
+    #[prost(string, tag = "4")]
+    pub low: ::prost::alloc::string::String,
+    /// Open trade price.
+    #[prost(string, tag = "5")]
+    pub open: ::prost::alloc::string::String,
+    /// Closing trade price.
+    #[prost(string, tag = "6")]
+    pub close: ::prost::alloc::string::String,
+    /// Total trading volume during the candle interval.
+    #[prost(uint64, tag = "7")]
+    pub volume: u64,
+    /// Total notional value traded during the candle interval. This value is determined by multiplying price, using market decimal places, by size, using position decimal places.
+    /// The number of decimal places needed to convert this value to a decimal is market decimal places plus position decimal places.
+    #[prost(uint64, tag = "8")]
+    pub notional: u64,
+}
+/// Request that is used to subscribe to a stream of candles
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ObserveCandleDataRequest {
+    /// Unique ID for the candle.
+    #[prost(string, tag = "1")]
+    pub candle_id: ::prost::alloc::string::String,
+}
+/// Response that is received when subscribing to a stream of candles
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ObserveCandleDataResponse {
+    /// Candle data.
+    #[prost(message, optional, tag = "1")]
+    pub candle: ::core::option::Option<Candle>,
+}
+/// Request that is used when listing candles for a market at an interval
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListCandleDataRequest {
+    /// Candle ID to retrieve candle data for.
+    #[prost(string, tag = "1")]
+    pub candle_id: ::prost::alloc::string::String,
+    /// Timestamp in Unix nanoseconds to retrieve candles from.
+    #[prost(int64, tag = "2")]
+    pub from_timestamp: i64,
+    /// Timestamp in Unix nanoseconds to retrieve candles to.
+    #[prost(int64, tag = "3")]
+    pub to_timestamp: i64,
+    /// Pagination controls.
+    #[prost(message, optional, tag = "5")]
+    pub pagination: ::core::option::Option<Pagination>,
+}
+/// Response for list of candles for a market at an interval
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListCandleDataResponse {
+    /// Page of candle data and corresponding page information.
+    #[prost(message, optional, tag = "1")]
+    pub candles: ::core::option::Option<CandleDataConnection>,
+}
+/// Candle data with the corresponding cursor.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CandleEdge {
+    /// Candlestick data, i.e. high, low, open, and closing prices for an interval of trading.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<Candle>,
+    /// Cursor that can be used to fetch further pages.
+    #[prost(string, tag = "2")]
+    pub cursor: ::prost::alloc::string::String,
+}
+/// Page of candles data and corresponding page information
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CandleDataConnection {
+    /// Page of candle data items and their corresponding cursors.
+    #[prost(message, repeated, tag = "1")]
+    pub edges: ::prost::alloc::vec::Vec<CandleEdge>,
+    /// Page information that is used for fetching further pages.
+    #[prost(message, optional, tag = "2")]
+    pub page_info: ::core::option::Option<PageInfo>,
+}
+/// Request that is used to list governance votes
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListVotesRequest {
+    /// Party for which the votes are requested.
+    #[prost(string, optional, tag = "1")]
+    pub party_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Proposal ID to list votes for.
+    #[prost(string, optional, tag = "2")]
+    pub proposal_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Optional pagination control.
+    #[prost(message, optional, tag = "3")]
+    pub pagination: ::core::option::Option<Pagination>,
+}
+/// Response that is received when listing Votes
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListVotesResponse {
+    /// Page of votes data received and corresponding page information.
+    #[prost(message, optional, tag = "1")]
+    pub votes: ::core::option::Option<VoteConnection>,
+}
+/// Votes data with the corresponding cursor.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VoteEdge {
+    /// Data associated with a governance vote.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<super::super::super::vega::Vote>,
+    /// Cursor that can be used to fetch further pages.
+    #[prost(string, tag = "2")]
+    pub cursor: ::prost::alloc::string::String,
+}
+/// Page of vote data items and corresponding page information
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VoteConnection {
+    /// Page of vote data and their corresponding cursors.
+    #[prost(message, repeated, tag = "1")]
+    pub edges: ::prost::alloc::vec::Vec<VoteEdge>,
+    /// Page information that is used for fetching further pages.
+    #[prost(message, optional, tag = "2")]
+    pub page_info: ::core::option::Option<PageInfo>,
+}
+/// Request that is sent to subscribe to votes
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ObserveVotesRequest {
+    /// Restrict vote updates to those made by the given party.
+    #[prost(string, optional, tag = "1")]
+    pub party_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Restrict vote updates to those made on the given proposal.
+    #[prost(string, optional, tag = "2")]
+    pub proposal_id: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// Response that is received from votes subscription
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ObserveVotesResponse {
+    /// Data associated with governance votes that are published to the stream.
+    #[prost(message, optional, tag = "1")]
+    pub vote: ::core::option::Option<super::super::super::vega::Vote>,
+}
+/// Request for adding a signature bundle to the signer list of a multisig contract for a particular validator
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListErc20MultiSigSignerAddedBundlesRequest {
+    /// Node ID of the validator for which a signature bundle is required.
+    #[prost(string, tag = "1")]
+    pub node_id: ::prost::alloc::string::String,
+    /// Ethereum address of the validator that will submit the bundle.
+    #[prost(string, tag = "2")]
+    pub submitter: ::prost::alloc::string::String,
+    /// Epoch in which the bundle was generated, i.e. the epoch in which the node was promoted to consensus validator.
+    #[prost(string, tag = "3")]
+    pub epoch_seq: ::prost::alloc::string::String,
+    /// Pagination controls.
+    #[prost(message, optional, tag = "4")]
+    pub pagination: ::core::option::Option<Pagination>,
+    /// Filter signature bundles to those related to the contract on the given chain ID.
+    #[prost(string, optional, tag = "5")]
+    pub chain_id: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// Response from adding a signature bundle to the signer list of a multisig contract for a particular validator
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListErc20MultiSigSignerAddedBundlesResponse {
+    /// Page of bundles for that validator - it may have been added multiple times if removed in between - and corresponding page information.
+    #[prost(message, optional, tag = "1")]
+    pub bundles: ::core::option::Option<Erc20MultiSigSignerAddedConnection>,
+}
+/// Signature data that is to be added with the corresponding cursor.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Erc20MultiSigSignerAddedEdge {
+    /// Signature data to be added.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<
+        super::super::super::vega::events::v1::Erc20MultiSigSignerAdded,
+    >,
+    /// Cursor that can be used to fetch further pages.
+    #[prost(string, tag = "2")]
+    pub cursor: ::prost::alloc::string::String,
+}
+/// Signature bundle data that is to be added with the corresponding cursor.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Erc20MultiSigSignerAddedBundleEdge {
+    /// Signature bundle data to be added.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<Erc20MultiSigSignerAddedBundle>,
+    /// Cursor that can be used to fetch further pages.
+    #[prost(string, tag = "2")]
+    pub cursor: ::prost::alloc::string::String,
+}
+/// Page of signature data items to be added and corresponding page information
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Erc20MultiSigSignerAddedConnection {
+    /// Page of signature bundle data and their corresponding cursors.
+    #[prost(message, repeated, tag = "1")]
+    pub edges: ::prost::alloc::vec::Vec<Erc20MultiSigSignerAddedBundleEdge>,
+    /// Page information that is used for fetching further pages.
+    #[prost(message, optional, tag = "2")]
+    pub page_info: ::core::option::Option<PageInfo>,
+}
+/// Signature bundle data to be added
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Erc20MultiSigSignerAddedBundle {
+    /// Ethereum address of the signer to be removed.
+    #[prost(string, tag = "1")]
+    pub new_signer: ::prost::alloc::string::String,
+    /// Ethereum address of the submitter.
+    #[prost(string, tag = "2")]
+    pub submitter: ::prost::alloc::string::String,
+    /// Nonce used in the signing operation.
+    #[prost(string, tag = "4")]
+    pub nonce: ::prost::alloc::string::String,
+    /// Unixnano timestamp for when the validator was added.
+    #[prost(int64, tag = "5")]
+    pub timestamp: i64,
+    /// Bundle of signatures from current validators to sign in the new signer.
+    #[prost(string, tag = "6")]
+    pub signatures: ::prost::alloc::string::String,
+    /// Epoch in which the validator was added.
+    #[prost(string, tag = "3")]
+    pub epoch_seq: ::prost::alloc::string::String,
+    /// ID of the EVM chain this signature bundle is valid for.
+    #[prost(string, tag = "7")]
+    pub chain_id: ::prost::alloc::string::String,
+}
+/// Request for removing a signature bundle from the signer list of the multisig contract for a particular validator
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListErc20MultiSigSignerRemovedBundlesRequest {
+    /// Node ID of the validator of which a signature bundle is required.
+    #[prost(string, tag = "1")]
+    pub node_id: ::prost::alloc::string::String,
+    /// Ethereum address of the validator that will submit the bundle.
+    #[prost(string, tag = "2")]
+    pub submitter: ::prost::alloc::string::String,
+    /// Epoch in which the bundle was generated, i.e. the epoch in which the node was demoted from a consensus validator.
+    #[prost(string, tag = "3")]
+    pub epoch_seq: ::prost::alloc::string::String,
+    /// Pagination controls.
+    #[prost(message, optional, tag = "4")]
+    pub pagination: ::core::option::Option<Pagination>,
+    /// Filter signature bundles to those related to the contract on the given chain ID.
+    #[prost(string, optional, tag = "5")]
+    pub chain_id: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// Response when removing a signature bundle from the signer list of the multisig contract for a particular validator
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListErc20MultiSigSignerRemovedBundlesResponse {
+    /// Page of signer bundle data items for that validator and corresponding page information.
+    #[prost(message, optional, tag = "1")]
+    pub bundles: ::core::option::Option<Erc20MultiSigSignerRemovedConnection>,
+}
+/// Signature data to be removed with the corresponding cursor.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Erc20MultiSigSignerRemovedEdge {
+    /// Signature data to be removed.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<
+        super::super::super::vega::events::v1::Erc20MultiSigSignerRemoved,
+    >,
+    /// Cursor that can be used to fetch further pages.
+    #[prost(string, tag = "2")]
+    pub cursor: ::prost::alloc::string::String,
+}
+/// Signature bundle data to be removed, with the corresponding cursor.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Erc20MultiSigSignerRemovedBundleEdge {
+    /// Signature bundle data to be added.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<Erc20MultiSigSignerRemovedBundle>,
+    /// Cursor that can be used to fetch further pages.
+    #[prost(string, tag = "2")]
+    pub cursor: ::prost::alloc::string::String,
+}
+/// Page of signature data items to be removed and corresponding page information.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Erc20MultiSigSignerRemovedConnection {
+    /// Page of signature bundle data and their corresponding cursors.
+    #[prost(message, repeated, tag = "1")]
+    pub edges: ::prost::alloc::vec::Vec<Erc20MultiSigSignerRemovedBundleEdge>,
+    /// Page information that is used for fetching further pages.
+    #[prost(message, optional, tag = "2")]
+    pub page_info: ::core::option::Option<PageInfo>,
+}
+/// Signature bundle data to be removed
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Erc20MultiSigSignerRemovedBundle {
+    /// Ethereum address of the signer to be removed.
+    #[prost(string, tag = "1")]
+    pub old_signer: ::prost::alloc::string::String,
+    /// Ethereum address of the submitter.
+    #[prost(string, tag = "2")]
+    pub submitter: ::prost::alloc::string::String,
+    /// Nonce used in the signing operation.
+    #[prost(string, tag = "4")]
+    pub nonce: ::prost::alloc::string::String,
+    /// Unixnano timestamp for when the validator was added.
+    #[prost(int64, tag = "5")]
+    pub timestamp: i64,
+    /// Bundle of signatures from current validators to sign in the new signer.
+    #[prost(string, tag = "6")]
+    pub signatures: ::prost::alloc::string::String,
+    /// Epoch in which the validator was removed.
+    #[prost(string, tag = "7")]
+    pub epoch_seq: ::prost::alloc::string::String,
+    /// ID of the EVM chain this signature bundle is valid for.
+    #[prost(string, tag = "8")]
+    pub chain_id: ::prost::alloc::string::String,
+}
+/// Request for listing the signature bundle to an ERC-20 token in the collateral bridge
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetErc20ListAssetBundleRequest {
+    /// Asset ID of the asset bundle requested.
+    #[prost(string, tag = "1")]
+    pub asset_id: ::prost::alloc::string::String,
+}
+/// Response from listing the signature bundle to an ERC-20 token in the collateral bridge
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetErc20ListAssetBundleResponse {
+    /// Address of the asset on Ethereum.
+    #[prost(string, tag = "1")]
+    pub asset_source: ::prost::alloc::string::String,
+    /// Asset ID for the underlying Vega asset.
+    #[prost(string, tag = "2")]
+    pub vega_asset_id: ::prost::alloc::string::String,
+    /// Nonce that uniquely identifies this signature bundle and prevents resubmission.
+    #[prost(string, tag = "3")]
+    pub nonce: ::prost::alloc::string::String,
+    /// Signatures bundle as hex encoded data, prefixed with `0x`
+    /// e.g: `0x + sig1 + sig2 + ... + sixN`.
+    #[prost(string, tag = "4")]
+    pub signatures: ::prost::alloc::string::String,
+}
+/// Request for the signature bundle to update the token limits i.e. maxLifetimeDeposit and withdrawThreshold for a given ERC20 token that is already allowlisted in the collateral bridge
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetErc20SetAssetLimitsBundleRequest {
+    /// Proposal ID of the asset update proposal.
+    #[prost(string, tag = "1")]
+    pub proposal_id: ::prost::alloc::string::String,
+}
+/// Response for the signature bundle to update the token limits i.e. maxLifetimeDeposit and withdrawThreshold for a given ERC20 token that is already allowlisted in the collateral bridge
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetErc20SetAssetLimitsBundleResponse {
+    /// Address of the asset on Ethereum.
+    #[prost(string, tag = "1")]
+    pub asset_source: ::prost::alloc::string::String,
+    /// Asset ID for the underlying Vega asset.
+    #[prost(string, tag = "2")]
+    pub vega_asset_id: ::prost::alloc::string::String,
+    /// Nonce that uniquely identifies this signature bundle and prevents resubmission.
+    #[prost(string, tag = "3")]
+    pub nonce: ::prost::alloc::string::String,
+    /// Lifetime limit deposit for this asset.
+    #[prost(string, tag = "4")]
+    pub lifetime_limit: ::prost::alloc::string::String,
+    /// Withdrawal threshold for this asset.
+    #[prost(string, tag = "5")]
+    pub threshold: ::prost::alloc::string::String,
+    /// Signatures bundle as hex encoded data, prefixed with `0x`
+    /// e.g: `0x + sig1 + sig2 + ... + sixN`.
+    #[prost(string, tag = "6")]
+    pub signatures: ::prost::alloc::string::String,
+}
+/// Request to get all information required to bundle the call to finalise the withdrawal on the erc20 bridge
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetErc20WithdrawalApprovalRequest {
+    /// Withdrawal ID to retrieve.
+    #[prost(string, tag = "1")]
+    pub withdrawal_id: ::prost::alloc::string::String,
+}
+/// Response with all information required to bundle the call to finalise the withdrawal on the erc20 bridge
+/// function withdraw_asset(address asset_source, uint256 asset_id, uint256 amount, uint256 expiry, uint256 nonce, bytes memory signatures)
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetErc20WithdrawalApprovalResponse {
+    /// Address of asset on the bridged EVM chain.
+    #[prost(string, tag = "1")]
+    pub asset_source: ::prost::alloc::string::String,
+    /// Amount to be withdrawn.
+    #[prost(string, tag = "2")]
+    pub amount: ::prost::alloc::string::String,
+    /// Nonce that uniquely identifies this signature bundle and prevents resubmission.
+    #[prost(string, tag = "4")]
+    pub nonce: ::prost::alloc::string::String,
+    /// Signatures bundle as hex encoded data, prefixed with `0x`
+    /// e.g: 0x + sig1 + sig2 + ... + sixN.
+    #[prost(string, tag = "5")]
+    pub signatures: ::prost::alloc::string::String,
+    /// Address, prefixed with `0x`, that will receive the withdrawn assets.
+    #[prost(string, tag = "6")]
+    pub target_address: ::prost::alloc::string::String,
+    /// Creation timestamps.
+    #[prost(int64, tag = "7")]
+    pub creation: i64,
+    /// Chain ID of the bridged EVM chain.
+    #[prost(string, tag = "8")]
+    pub source_chain_id: ::prost::alloc::string::String,
+}
+/// Request for the latest trade that occurred on Vega for a given market
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetLastTradeRequest {
+    /// Market ID to retrieve the last trade for.
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+}
+/// Response for the latest trade that occurred on Vega for a given market
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetLastTradeResponse {
+    /// Information about the trade, if one is found.
+    #[prost(message, optional, tag = "1")]
+    pub trade: ::core::option::Option<super::super::super::vega::Trade>,
+}
+/// Request to list trades
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListTradesRequest {
+    /// Restrict trades to those that occurred on the given markets.
+    #[prost(string, repeated, tag = "1")]
+    pub market_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Restrict trades to those that were caused by the given orders.
+    #[prost(string, repeated, tag = "2")]
+    pub order_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Restrict trades to those that were caused by orders placed by the given parties.
+    #[prost(string, repeated, tag = "3")]
+    pub party_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Pagination control.
+    #[prost(message, optional, tag = "4")]
+    pub pagination: ::core::option::Option<Pagination>,
+    /// Restrict trades to those made during the given date range. If not set, all trades will be returned.
+    #[prost(message, optional, tag = "5")]
+    pub date_range: ::core::option::Option<DateRange>,
+}
+/// Response from listing trades
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListTradesResponse {
+    /// Page of trades data and corresponding page information.
+    #[prost(message, optional, tag = "1")]
+    pub trades: ::core::option::Option<TradeConnection>,
+}
+/// Page of trades and corresponding page information
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TradeConnection {
+    /// Page of trades and their corresponding cursors.
+    #[prost(message, repeated, tag = "1")]
+    pub edges: ::prost::alloc::vec::Vec<TradeEdge>,
+    /// Page information that is used for fetching further pages.
+    #[prost(message, optional, tag = "2")]
+    pub page_info: ::core::option::Option<PageInfo>,
+}
+/// Trade data item with the corresponding cursor..
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TradeEdge {
+    /// Data associated with a trade that has been executed.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<super::super::super::vega::Trade>,
+    /// Cursor that can be used to fetch further pages.
+    #[prost(string, tag = "2")]
+    pub cursor: ::prost::alloc::string::String,
+}
+/// Request to subscribe to a stream of trades
+///
+/// Request fields market ID and party ID are both optional filters:
+/// If omitted all trades, for all parties on all markets will be returned on the stream
+/// If market ID is given, trades from that market will be returned on the stream
+/// If party ID is given, trades from that party will be returned on the stream
+/// Both filters can be combined.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ObserveTradesRequest {
+    /// Restrict the trades streamed to those made on the given markets.
+    #[prost(string, repeated, tag = "1")]
+    pub market_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Restrict the trades streamed to those made by the given parties.
+    #[prost(string, repeated, tag = "2")]
+    pub party_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// Stream of trades
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ObserveTradesResponse {
+    /// List of 0 or more trades.
+    #[prost(message, repeated, tag = "1")]
+    pub trades: ::prost::alloc::vec::Vec<super::super::super::vega::Trade>,
+}
+/// Request to get a specific oracle spec by its ID
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetOracleSpecRequest {
+    /// Oracle spec ID to request data for.
+    #[prost(string, tag = "1")]
+    pub oracle_spec_id: ::prost::alloc::string::String,
+}
+/// Response for a oracle spec
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetOracleSpecResponse {
+    /// External data spec matching the ID.
+    #[prost(message, optional, tag = "1")]
+    pub oracle_spec: ::core::option::Option<super::super::super::vega::OracleSpec>,
+}
+/// Request to get all active oracle specs
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListOracleSpecsRequest {
+    /// Pagination controls.
+    #[prost(message, optional, tag = "2")]
+    pub pagination: ::core::option::Option<Pagination>,
+}
+/// Response to get all active oracle specs
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListOracleSpecsResponse {
+    /// Page of active oracle specs and corresponding page information.
+    #[prost(message, optional, tag = "1")]
+    pub oracle_specs: ::core::option::Option<OracleSpecsConnection>,
+}
+/// Request to get all seen oracle data
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListOracleDataRequest {
+    /// Oracle spec ID to list data for.
+    #[prost(string, optional, tag = "1")]
+    pub oracle_spec_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Pagination controls.
+    #[prost(message, optional, tag = "2")]
+    pub pagination: ::core::option::Option<Pagination>,
+}
+/// Response to get all seen oracle data
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListOracleDataResponse {
+    /// Page of seen oracle data and corresponding page information.
+    #[prost(message, optional, tag = "1")]
+    pub oracle_data: ::core::option::Option<OracleDataConnection>,
+}
+/// Oracle specs data item with the corresponding cursor.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OracleSpecEdge {
+    /// External spec data that satisfies the list request.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<super::super::super::vega::OracleSpec>,
+    /// Cursor that can be used to fetch further pages.
+    #[prost(string, tag = "2")]
+    pub cursor: ::prost::alloc::string::String,
+}
+/// Page of oracle specs and corresponding page information
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OracleSpecsConnection {
+    /// Page of oracle specs data and their corresponding cursors.
+    #[prost(message, repeated, tag = "1")]
+    pub edges: ::prost::alloc::vec::Vec<OracleSpecEdge>,
+    /// Page information that is used for fetching further pages.
+    #[prost(message, optional, tag = "2")]
+    pub page_info: ::core::option::Option<PageInfo>,
+}
+/// Oracle data item with the corresponding cursor.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OracleDataEdge {
+    /// Data that was received from an external oracle.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<super::super::super::vega::OracleData>,
+    /// Cursor that can be used to fetch further pages.
+    #[prost(string, tag = "2")]
+    pub cursor: ::prost::alloc::string::String,
+}
+/// Page of oracle data and corresponding page information.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OracleDataConnection {
+    /// Page of oracle data and their corresponding cursors.
+    #[prost(message, repeated, tag = "1")]
+    pub edges: ::prost::alloc::vec::Vec<OracleDataEdge>,
+    /// Page information that is used for fetching further pages.
+    #[prost(message, optional, tag = "2")]
+    pub page_info: ::core::option::Option<PageInfo>,
+}
+/// Request for getting a market by ID
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetMarketRequest {
+    /// Market ID of the market to retrieve data for.
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+}
+/// Response from getting a market by ID
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetMarketResponse {
+    /// Information about the market requested.
+    #[prost(message, optional, tag = "1")]
+    pub market: ::core::option::Option<super::super::super::vega::Market>,
+}
+/// Request for listing markets
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListMarketsRequest {
+    /// Pagination control.
+    #[prost(message, optional, tag = "2")]
+    pub pagination: ::core::option::Option<Pagination>,
+    /// Whether to include settled markets. If not set, settled markets will be included.
+    #[prost(bool, optional, tag = "3")]
+    pub include_settled: ::core::option::Option<bool>,
+}
+/// Response from listing markets
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListMarketsResponse {
+    /// Page of markets and corresponding page information.
+    #[prost(message, optional, tag = "1")]
+    pub markets: ::core::option::Option<MarketConnection>,
+}
+/// Market information with the corresponding cursor.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MarketEdge {
+    /// Information about the market.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<super::super::super::vega::Market>,
+    /// Cursor that can be used to fetch further pages.
+    #[prost(string, tag = "2")]
+    pub cursor: ::prost::alloc::string::String,
+}
+/// Page of markets and corresponding page information.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MarketConnection {
+    /// Page of markets and their corresponding cursors.
+    #[prost(message, repeated, tag = "1")]
+    pub edges: ::prost::alloc::vec::Vec<MarketEdge>,
+    /// Page information that is used for fetching further pages.
+    #[prost(message, optional, tag = "2")]
+    pub page_info: ::core::option::Option<PageInfo>,
+}
+/// Request for listing successor markets.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListSuccessorMarketsRequest {
+    /// Market ID that is a member of the succession line. This can be the original market
+    /// or any subsequent child market that succeeded it.
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+    /// Flag to indicate whether or not to include the full succession line, or only list
+    /// the children of the given market ID. If true, the full succession line is included.
+    #[prost(bool, tag = "2")]
+    pub include_full_history: bool,
+    /// Pagination control.
+    #[prost(message, optional, tag = "3")]
+    pub pagination: ::core::option::Option<Pagination>,
+}
+/// Successor market information includes the market that is a member of the succession line
+/// and any governance data that is associated with proposals for child markets
+/// whether enacted or not.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SuccessorMarket {
+    /// The market that is a member of the succession line.
+    #[prost(message, optional, tag = "1")]
+    pub market: ::core::option::Option<super::super::super::vega::Market>,
+    /// All proposals for child markets that have the market as a parent.
+    #[prost(message, repeated, tag = "2")]
+    pub proposals: ::prost::alloc::vec::Vec<super::super::super::vega::GovernanceData>,
+}
+/// Successor market record with the corresponding cursor for paginated results
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SuccessorMarketEdge {
+    /// Successor market record.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<SuccessorMarket>,
+    /// Cursor identifying the record for pagination control.
+    #[prost(string, tag = "2")]
+    pub cursor: ::prost::alloc::string::String,
+}
+/// Page of successor market records and corresponding page information.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SuccessorMarketConnection {
+    /// Page of successor markets and their cursors.
+    #[prost(message, repeated, tag = "1")]
+    pub edges: ::prost::alloc::vec::Vec<SuccessorMarketEdge>,
+    /// Page information for pagination control.
+    #[prost(message, optional, tag = "2")]
+    pub page_info: ::core::option::Option<PageInfo>,
+}
+/// Response from a list successor markets request.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListSuccessorMarketsResponse {
+    /// List of markets in the succession line.
+    #[prost(message, optional, tag = "1")]
+    pub successor_markets: ::core::option::Option<SuccessorMarketConnection>,
+}
+/// Request to get party by ID
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetPartyRequest {
+    /// Party ID to retrieve party information for.
+    #[prost(string, tag = "1")]
+    pub party_id: ::prost::alloc::string::String,
+}
+/// Response from getting party by ID
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetPartyResponse {
+    /// Data for the party requested.
+    #[prost(message, optional, tag = "1")]
+    pub party: ::core::option::Option<super::super::super::vega::Party>,
+}
+/// Request to list parties by ID
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListPartiesRequest {
+    /// Restrict the returned party to only the given party ID. If not set, all parties will be returned.
+    #[prost(string, tag = "1")]
+    pub party_id: ::prost::alloc::string::String,
+    /// Pagination control.
+    #[prost(message, optional, tag = "2")]
+    pub pagination: ::core::option::Option<Pagination>,
+}
+/// Response from listing parties
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListPartiesResponse {
+    /// Page of parties data and corresponding page information.
+    #[prost(message, optional, tag = "1")]
+    pub parties: ::core::option::Option<PartyConnection>,
+}
+/// Party data with the corresponding cursor.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PartyEdge {
+    /// Data associated with a party.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<super::super::super::vega::Party>,
+    /// Cursor that can be used to fetch further pages.
+    #[prost(string, tag = "2")]
+    pub cursor: ::prost::alloc::string::String,
+}
+/// Page of parties data and corresponding page information.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PartyConnection {
+    /// Page of parties and their corresponding cursors.
+    #[prost(message, repeated, tag = "1")]
+    pub edges: ::prost::alloc::vec::Vec<PartyEdge>,
+    /// Page information that is used for fetching further pages.
+    #[prost(message, optional, tag = "2")]
+    pub page_info: ::core::option::Option<PageInfo>,
+}
+/// Request to list profiles by party ID.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListPartiesProfilesRequest {
+    /// Restrict the returned profiles to only the given party IDs. If not set,
+    /// all parties' profiles will be returned.
+    #[prost(string, repeated, tag = "1")]
+    pub parties: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Pagination control.
+    #[prost(message, optional, tag = "2")]
+    pub pagination: ::core::option::Option<Pagination>,
+}
+/// Response from listing parties' profiles.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListPartiesProfilesResponse {
+    /// Page of profile data and corresponding page information.
+    #[prost(message, optional, tag = "1")]
+    pub profiles: ::core::option::Option<PartiesProfilesConnection>,
+}
+/// Party's profile data with the corresponding cursor.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PartyProfileEdge {
+    /// Data associated with a party's profile.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<super::super::super::vega::PartyProfile>,
+    /// Cursor that can be used to fetch further pages.
+    #[prost(string, tag = "2")]
+    pub cursor: ::prost::alloc::string::String,
+}
+/// Page of profile data per party and corresponding page information.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PartiesProfilesConnection {
+    /// Page of profiles and their corresponding cursors.
+    #[prost(message, repeated, tag = "1")]
+    pub edges: ::prost::alloc::vec::Vec<PartyProfileEdge>,
+    /// Page information that is used for fetching further pages.
+    #[prost(message, optional, tag = "2")]
+    pub page_info: ::core::option::Option<PageInfo>,
+}
+/// Order data with the corresponding cursor.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OrderEdge {
+    /// Data associated with an order submitted to a Vega node.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<super::super::super::vega::Order>,
+    /// Cursor that can be used to fetch further pages.
+    #[prost(string, tag = "2")]
+    pub cursor: ::prost::alloc::string::String,
+}
+/// Request for listing margin levels
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListMarginLevelsRequest {
+    /// Party ID for which to list the margin levels
+    #[prost(string, tag = "1")]
+    pub party_id: ::prost::alloc::string::String,
+    /// Market ID for which to list the margin levels
+    #[prost(string, tag = "2")]
+    pub market_id: ::prost::alloc::string::String,
+    /// Pagination control
+    #[prost(message, optional, tag = "3")]
+    pub pagination: ::core::option::Option<Pagination>,
+}
+/// Response from listing margin levels
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListMarginLevelsResponse {
+    /// Page of margin levels data and corresponding page information.
+    #[prost(message, optional, tag = "1")]
+    pub margin_levels: ::core::option::Option<MarginConnection>,
+}
+/// Request to subscribe to a stream of MarginLevels data.
+/// If a party ID is provided, the stream will contain margin levels for that party only.
+/// Optionally, the list can be additionally filtered by market
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ObserveMarginLevelsRequest {
+    /// Restrict margin level updates to those relating to the given party.
+    #[prost(string, tag = "1")]
+    pub party_id: ::prost::alloc::string::String,
+    /// Restrict margin level updates to those relating to the given market.
+    #[prost(string, optional, tag = "2")]
+    pub market_id: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// Response from subscribing to margin levels data
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ObserveMarginLevelsResponse {
+    /// Margin levels data that match the subscription request filters.
+    #[prost(message, optional, tag = "1")]
+    pub margin_levels: ::core::option::Option<super::super::super::vega::MarginLevels>,
+}
+/// Page of orders data and corresponding page information.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OrderConnection {
+    /// Page of orders and their corresponding cursors.
+    #[prost(message, repeated, tag = "1")]
+    pub edges: ::prost::alloc::vec::Vec<OrderEdge>,
+    /// Page information that is used for fetching further pages.
+    #[prost(message, optional, tag = "2")]
+    pub page_info: ::core::option::Option<PageInfo>,
+}
+/// Margin data with the corresponding cursor.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MarginEdge {
+    /// Margin levels data that satisfy a list margin levels request.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<super::super::super::vega::MarginLevels>,
+    /// Cursor that can be used to fetch further pages.
+    #[prost(string, tag = "2")]
+    pub cursor: ::prost::alloc::string::String,
+}
+/// Page of margins data and corresponding page information.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MarginConnection {
+    /// Page of margins data and their corresponding cursors.
+    #[prost(message, repeated, tag = "1")]
+    pub edges: ::prost::alloc::vec::Vec<MarginEdge>,
+    /// Page information that is used for fetching further pages.
+    #[prost(message, optional, tag = "2")]
+    pub page_info: ::core::option::Option<PageInfo>,
+}
+/// Request to get reward details for a party
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListRewardsRequest {
+    /// Restrict rewards data to those that were received by the given party.
+    #[prost(string, tag = "1")]
+    pub party_id: ::prost::alloc::string::String,
+    /// Restrict rewards data to those that were paid with the given asset ID.
+    #[prost(string, optional, tag = "2")]
+    pub asset_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Pagination control.
+    #[prost(message, optional, tag = "3")]
+    pub pagination: ::core::option::Option<Pagination>,
+    /// Restrict rewards data to those that were paid after and including the given epoch ID.
+    #[prost(uint64, optional, tag = "4")]
+    pub from_epoch: ::core::option::Option<u64>,
+    /// Restrict rewards data to those that were paid up to and including the given epoch ID.
+    #[prost(uint64, optional, tag = "5")]
+    pub to_epoch: ::core::option::Option<u64>,
+    /// Filter for rewards paid if the party is a member of the given team
+    #[prost(string, optional, tag = "6")]
+    pub team_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Filter for rewards paid if the party participated in the given game
+    #[prost(string, optional, tag = "7")]
+    pub game_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Whether to return all derived parties from AMMs for the given party.
+    #[prost(bool, optional, tag = "8")]
+    pub include_derived_parties: ::core::option::Option<bool>,
+    /// Filter the rewards by market ID.
+    #[prost(string, optional, tag = "9")]
+    pub market_id: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// Response for listing reward details for a single party
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListRewardsResponse {
+    /// Page of rewards data and corresponding page information.
+    #[prost(message, optional, tag = "1")]
+    pub rewards: ::core::option::Option<RewardsConnection>,
+}
+/// Rewards data with the corresponding cursor.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RewardEdge {
+    /// Details for a single reward payment.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<super::super::super::vega::Reward>,
+    /// Cursor that can be used to fetch further pages.
+    #[prost(string, tag = "2")]
+    pub cursor: ::prost::alloc::string::String,
+}
+/// Page of rewards data and corresponding page information.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RewardsConnection {
+    /// Page of rewards data items and their corresponding cursors.
+    #[prost(message, repeated, tag = "1")]
+    pub edges: ::prost::alloc::vec::Vec<RewardEdge>,
+    /// Page information that is used for fetching further pages.
+    #[prost(message, optional, tag = "2")]
+    pub page_info: ::core::option::Option<PageInfo>,
+}
+/// Request to get reward details for a party
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListRewardSummariesRequest {
+    /// Restrict the reward summary to rewards paid to the given parties.
+    #[prost(string, optional, tag = "1")]
+    pub party_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Restrict the reward summary to rewards paid in the given assets.
+    #[prost(string, optional, tag = "2")]
+    pub asset_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Pagination controls.
+    #[prost(message, optional, tag = "3")]
+    pub pagination: ::core::option::Option<Pagination>,
+    /// Whether to return rewards for all derived parties from AMMs for the given party.
+    /// If used, party ID is required.
+    #[prost(bool, optional, tag = "8")]
+    pub include_derived_parties: ::core::option::Option<bool>,
+}
+/// Response from listing reward details in for a single party
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListRewardSummariesResponse {
+    /// Page of rewards details data and corresponding page information.
+    #[prost(message, repeated, tag = "1")]
+    pub summaries: ::prost::alloc::vec::Vec<super::super::super::vega::RewardSummary>,
+}
+/// Filter to restrict the results returned by the ListEpochRewardSummaries
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RewardSummaryFilter {
+    /// Restrict reward summaries to those connected to the assets in the given list.
+    #[prost(string, repeated, tag = "1")]
+    pub asset_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Restrict reward summaries to those connected to the markets in the given list.
+    #[prost(string, repeated, tag = "2")]
+    pub market_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Restrict rewards summaries to those that were paid after and including the given epoch ID.
+    #[prost(uint64, optional, tag = "3")]
+    pub from_epoch: ::core::option::Option<u64>,
+    /// Restrict rewards summaries to those that were paid up to and including the given epoch ID.
+    #[prost(uint64, optional, tag = "4")]
+    pub to_epoch: ::core::option::Option<u64>,
+}
+/// Request to get summary of reward per epoch for a given range of epochs
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListEpochRewardSummariesRequest {
+    /// Limit the results considered according to the filter supplied.
+    #[prost(message, optional, tag = "1")]
+    pub filter: ::core::option::Option<RewardSummaryFilter>,
+    /// Optional pagination information to limit the data that is returned.
+    #[prost(message, optional, tag = "4")]
+    pub pagination: ::core::option::Option<Pagination>,
+}
+/// Return message with reward details in for a single party
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListEpochRewardSummariesResponse {
+    /// Page of rewards details for a single party and corresponding page information.
+    #[prost(message, optional, tag = "1")]
+    pub summaries: ::core::option::Option<EpochRewardSummaryConnection>,
+}
+/// Page of rewards summary data for epoch and corresponding page information
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EpochRewardSummaryConnection {
+    /// Page of rewards summary data for epochs and their corresponding cursors.
+    #[prost(message, repeated, tag = "1")]
+    pub edges: ::prost::alloc::vec::Vec<EpochRewardSummaryEdge>,
+    /// Page information that is used for fetching further pages.
+    #[prost(message, optional, tag = "2")]
+    pub page_info: ::core::option::Option<PageInfo>,
+}
+/// Rewards summary data for epoch with the corresponding cursor.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EpochRewardSummaryEdge {
+    /// Rewards summary data for epoch.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<super::super::super::vega::EpochRewardSummary>,
+    /// Cursor that can be used to fetch further pages.
+    #[prost(string, tag = "2")]
+    pub cursor: ::prost::alloc::string::String,
+}
+/// Request sent for subscribing to rewards
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ObserveRewardsRequest {
+    /// Asset ID to get rewards data for, if provided.
+    #[prost(string, optional, tag = "1")]
+    pub asset_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Party ID to get rewards data for, if provided.
+    #[prost(string, optional, tag = "2")]
+    pub party_id: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// Response that is received from subscribing to rewards data
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ObserveRewardsResponse {
+    /// Rewards data received.
+    #[prost(message, optional, tag = "1")]
+    pub reward: ::core::option::Option<super::super::super::vega::Reward>,
+}
+/// Request to get data about a specific deposit by ID
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetDepositRequest {
+    /// Deposit ID to return data for.
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+}
+/// Response that is received from getting deposit data
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetDepositResponse {
+    /// Deposit matching the ID from the request.
+    #[prost(message, optional, tag = "1")]
+    pub deposit: ::core::option::Option<super::super::super::vega::Deposit>,
+}
+/// Request to list all deposits for a given party
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListDepositsRequest {
+    /// Restrict deposits to those made by the given party ID.
+    #[prost(string, tag = "1")]
+    pub party_id: ::prost::alloc::string::String,
+    /// Pagination controls.
+    #[prost(message, optional, tag = "2")]
+    pub pagination: ::core::option::Option<Pagination>,
+    /// Date range of the requested data, if provided.
+    #[prost(message, optional, tag = "3")]
+    pub date_range: ::core::option::Option<DateRange>,
+}
+/// Response from listing deposits
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListDepositsResponse {
+    /// Page of deposits data and corresponding page information.
+    #[prost(message, optional, tag = "1")]
+    pub deposits: ::core::option::Option<DepositsConnection>,
+}
+/// Deposits data with the corresponding cursor.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DepositEdge {
+    /// Data associated with a single deposit made on the Vega network.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<super::super::super::vega::Deposit>,
+    /// Cursor that can be used to fetch further pages.
+    #[prost(string, tag = "2")]
+    pub cursor: ::prost::alloc::string::String,
+}
+/// Page of deposits data received and corresponding page information
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DepositsConnection {
+    /// Page of deposits data and their corresponding cursors.
+    #[prost(message, repeated, tag = "1")]
+    pub edges: ::prost::alloc::vec::Vec<DepositEdge>,
+    /// Page information that is used for fetching further pages.
+    #[prost(message, optional, tag = "2")]
+    pub page_info: ::core::option::Option<PageInfo>,
+}
+/// Request to get a specific withdrawal by ID
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetWithdrawalRequest {
+    /// Withdrawal ID to retrieve data for.
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+}
+/// Response for a withdrawal request
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetWithdrawalResponse {
+    /// Withdrawal matching the ID from the request.
+    #[prost(message, optional, tag = "1")]
+    pub withdrawal: ::core::option::Option<super::super::super::vega::Withdrawal>,
+}
+/// List all withdrawals for a given party
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListWithdrawalsRequest {
+    /// Restrict withdrawals to those made by this party ID.
+    #[prost(string, tag = "1")]
+    pub party_id: ::prost::alloc::string::String,
+    /// Pagination controls.
+    #[prost(message, optional, tag = "2")]
+    pub pagination: ::core::option::Option<Pagination>,
+    /// Date range of the requested data, if provided.
+    #[prost(message, optional, tag = "3")]
+    pub date_range: ::core::option::Option<DateRange>,
+}
+/// Response from listing withdrawals data
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListWithdrawalsResponse {
+    /// Page of withdrawals data and corresponding page information.
+    #[prost(message, optional, tag = "1")]
+    pub withdrawals: ::core::option::Option<WithdrawalsConnection>,
+}
+/// Withdrawals data with the corresponding cursor.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WithdrawalEdge {
+    /// Data associated with a single withdrawal made from the Vega network.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<super::super::super::vega::Withdrawal>,
+    /// Cursor that can be used to fetch further pages.
+    #[prost(string, tag = "2")]
+    pub cursor: ::prost::alloc::string::String,
+}
+/// Page of withdrawals data and corresponding page information
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WithdrawalsConnection {
+    /// Page of withdrawals data and their corresponding cursors.
+    #[prost(message, repeated, tag = "1")]
+    pub edges: ::prost::alloc::vec::Vec<WithdrawalEdge>,
+    /// Page information that is used for fetching further pages.
+    #[prost(message, optional, tag = "2")]
+    pub page_info: ::core::option::Option<PageInfo>,
+}
+/// Request for obtaining asset
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetAssetRequest {
+    /// Asset ID to get data for.
+    #[prost(string, tag = "1")]
+    pub asset_id: ::prost::alloc::string::String,
+}
+/// Response from getting an asset
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetAssetResponse {
+    /// Asset information that is returned.
+    #[prost(message, optional, tag = "1")]
+    pub asset: ::core::option::Option<super::super::super::vega::Asset>,
+}
+/// Request for listing assets data
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListAssetsRequest {
+    /// Optional asset ID to list data for.
+    #[prost(string, optional, tag = "1")]
+    pub asset_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Optional pagination information to limit the data that is returned.
+    #[prost(message, optional, tag = "2")]
+    pub pagination: ::core::option::Option<Pagination>,
+}
+/// Response from listing assets
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListAssetsResponse {
+    /// Page of assets data and corresponding page information.
+    #[prost(message, optional, tag = "1")]
+    pub assets: ::core::option::Option<AssetsConnection>,
+}
+/// Assets data with the corresponding cursor.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AssetEdge {
+    /// Asset data returned.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<super::super::super::vega::Asset>,
+    /// Cursor that can be used to fetch further pages.
+    #[prost(string, tag = "2")]
+    pub cursor: ::prost::alloc::string::String,
+}
+/// Page of asset data and corresponding page information
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AssetsConnection {
+    /// Page of assets data and their corresponding cursors.
+    #[prost(message, repeated, tag = "1")]
+    pub edges: ::prost::alloc::vec::Vec<AssetEdge>,
+    /// Page information that is used for fetching further pages.
+    #[prost(message, optional, tag = "2")]
+    pub page_info: ::core::option::Option<PageInfo>,
+}
+/// Request for listing liquidity provisions
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListLiquidityProvisionsRequest {
+    /// Restrict liquidity provisions to those placed on the given market.
+    #[prost(string, optional, tag = "1")]
+    pub market_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Restrict liquidity provisions to those placed by the given party.
+    #[prost(string, optional, tag = "2")]
+    pub party_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Restrict liquidity provisions to those with the given order reference.
+    #[prost(string, optional, tag = "3")]
+    pub reference: ::core::option::Option<::prost::alloc::string::String>,
+    /// Whether to include live liquidity provisions. If not set, live orders will not be included.
+    #[prost(bool, optional, tag = "4")]
+    pub live: ::core::option::Option<bool>,
+    /// Pagination controls.
+    #[prost(message, optional, tag = "5")]
+    pub pagination: ::core::option::Option<Pagination>,
+}
+/// Request for listing liquidity provisions
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListAllLiquidityProvisionsRequest {
+    /// Restrict liquidity provisions to those placed on the given market.
+    #[prost(string, optional, tag = "1")]
+    pub market_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Restrict liquidity provisions to those placed by the given party.
+    #[prost(string, optional, tag = "2")]
+    pub party_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Restrict liquidity provisions to those with the given order reference.
+    #[prost(string, optional, tag = "3")]
+    pub reference: ::core::option::Option<::prost::alloc::string::String>,
+    /// Whether to include live liquidity provisions. If not set, live orders will not be included.
+    #[prost(bool, optional, tag = "4")]
+    pub live: ::core::option::Option<bool>,
+    /// Pagination controls.
+    #[prost(message, optional, tag = "5")]
+    pub pagination: ::core::option::Option<Pagination>,
+}
+/// Response from listing liquidity provisions
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListLiquidityProvisionsResponse {
+    /// Page of liquidity provisions data and corresponding page information.
+    #[prost(message, optional, tag = "1")]
+    pub liquidity_provisions: ::core::option::Option<LiquidityProvisionsConnection>,
+}
+/// Response from listing liquidity provisions
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListAllLiquidityProvisionsResponse {
+    /// Page of liquidity provisions data and corresponding page information.
+    #[prost(message, optional, tag = "1")]
+    pub liquidity_provisions: ::core::option::Option<
+        LiquidityProvisionsWithPendingConnection,
+    >,
+}
+/// Liquidity provider commitment. For API purposes this can report a pending liquidity provision,
+/// the current live provision or both in the event that an update has been accepted on the network, but has yet
+/// to go live and thus there is still a pending provision that will become active in the next epoch.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidityProvision {
+    /// Liquidity provision that is currently live.
+    #[prost(message, optional, tag = "1")]
+    pub current: ::core::option::Option<super::super::super::vega::LiquidityProvision>,
+    /// Liquidity provision that is currently pending and will go live in the next epoch.
+    #[prost(message, optional, tag = "2")]
+    pub pending: ::core::option::Option<super::super::super::vega::LiquidityProvision>,
+}
+/// Liquidity provision data with the corresponding cursor.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidityProvisionsEdge {
+    /// Data corresponding to a liquidity provider's commitment.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<super::super::super::vega::LiquidityProvision>,
+    /// Cursor that can be used to fetch further pages.
+    #[prost(string, tag = "2")]
+    pub cursor: ::prost::alloc::string::String,
+}
+/// Liquidity provision data with the corresponding cursor.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidityProvisionWithPendingEdge {
+    /// Data corresponding to a liquidity provider's commitment.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<LiquidityProvision>,
+    /// Cursor that can be used to fetch further pages.
+    #[prost(string, tag = "2")]
+    pub cursor: ::prost::alloc::string::String,
+}
+/// Page of liquidity provisions data and corresponding page information
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidityProvisionsConnection {
+    /// Page of liquidity provisions data and their corresponding cursors.
+    #[prost(message, repeated, tag = "1")]
+    pub edges: ::prost::alloc::vec::Vec<LiquidityProvisionsEdge>,
+    /// Page information that is used for fetching further pages.
+    #[prost(message, optional, tag = "2")]
+    pub page_info: ::core::option::Option<PageInfo>,
+}
+/// Page of liquidity provisions data and corresponding page information
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidityProvisionsWithPendingConnection {
+    /// Page of liquidity provisions data and their corresponding cursors.
+    #[prost(message, repeated, tag = "1")]
+    pub edges: ::prost::alloc::vec::Vec<LiquidityProvisionWithPendingEdge>,
+    /// Page information that is used to fetch further pages.
+    #[prost(message, optional, tag = "2")]
+    pub page_info: ::core::option::Option<PageInfo>,
+}
+/// Request sent to subscribe to liquidity provisions
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ObserveLiquidityProvisionsRequest {
+    /// Target market to observe for liquidity provisions.
+    #[prost(string, optional, tag = "1")]
+    pub market_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Target party to observe for submitted liquidity provisions.
+    #[prost(string, optional, tag = "2")]
+    pub party_id: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// Response from liquidity provisions subscription
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ObserveLiquidityProvisionsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub liquidity_provisions: ::prost::alloc::vec::Vec<
+        super::super::super::vega::LiquidityProvision,
+    >,
+}
+/// Request for listing active liquidity providers for a given market.
+/// Either market ID or party ID or both, must be provided.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListLiquidityProvidersRequest {
+    /// Market ID to retrieve liquidity providers for. If omitted, you must provide a party ID.
+    #[prost(string, optional, tag = "1")]
+    pub market_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Party ID to retrieve data for. If omitted, you must provide a market ID.
+    #[prost(string, optional, tag = "2")]
+    pub party_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Pagination controls.
+    #[prost(message, optional, tag = "3")]
+    pub pagination: ::core::option::Option<Pagination>,
+}
+/// Liquidity provider information.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidityProvider {
+    /// Party ID of the liquidity provider.
+    #[prost(string, tag = "1")]
+    pub party_id: ::prost::alloc::string::String,
+    /// ID of the market the liquidity provider is active in.
+    #[prost(string, tag = "2")]
+    pub market_id: ::prost::alloc::string::String,
+    /// Information used for calculating an LP's fee share, such as the equity like share,
+    /// average entry valuation and liquidity score for the liquidity provider for the specified market.
+    #[prost(message, optional, tag = "3")]
+    pub fee_share: ::core::option::Option<
+        super::super::super::vega::LiquidityProviderFeeShare,
+    >,
+    /// Information about LP's SLA performance.
+    #[prost(message, optional, tag = "4")]
+    pub sla: ::core::option::Option<super::super::super::vega::LiquidityProviderSla>,
+}
+/// Liquidity provider data with the corresponding cursor.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidityProviderEdge {
+    /// Liquidity provider information returned by the API.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<LiquidityProvider>,
+    /// Cursor that can be used to fetch further data.
+    #[prost(string, tag = "2")]
+    pub cursor: ::prost::alloc::string::String,
+}
+/// Page of liquidity provider data and corresponding page information.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidityProviderConnection {
+    /// Page of liquidity provider data.
+    #[prost(message, repeated, tag = "1")]
+    pub edges: ::prost::alloc::vec::Vec<LiquidityProviderEdge>,
+    /// Page information that is used for fetching further pages.
+    #[prost(message, optional, tag = "2")]
+    pub page_info: ::core::option::Option<PageInfo>,
+}
+/// Response for listing liquidity providers.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListLiquidityProvidersResponse {
+    /// Page of liquidity providers and corresponding page information.
+    #[prost(message, optional, tag = "1")]
+    pub liquidity_providers: ::core::option::Option<LiquidityProviderConnection>,
+}
+/// Paid liquidity fees request.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListPaidLiquidityFeesRequest {
+    /// Restrict fee data to those fees generated in the given market.
+    #[prost(string, optional, tag = "1")]
+    pub market_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Restrict fee statistics to those paid in the given asset.
+    #[prost(string, optional, tag = "2")]
+    pub asset_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Epoch to get paid liquidity fee statistics for. If omitted, the last complete epoch is used.
+    #[prost(uint64, optional, tag = "3")]
+    pub epoch_seq: ::core::option::Option<u64>,
+    /// Restrict fee data to those fees paid to the given parties.
+    #[prost(string, repeated, tag = "4")]
+    pub party_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Pagination controls.
+    #[prost(message, optional, tag = "5")]
+    pub pagination: ::core::option::Option<Pagination>,
+    /// Whether to return all derived parties from AMMs for the given party. If used, party ID is required.
+    #[prost(bool, optional, tag = "6")]
+    pub include_derived_parties: ::core::option::Option<bool>,
+    /// Restrict paid liquidity fees to those from a given epoch.
+    #[prost(uint64, optional, tag = "7")]
+    pub epoch_from: ::core::option::Option<u64>,
+    /// Restrict paid liquidity fees to those up to a given epoch.
+    #[prost(uint64, optional, tag = "8")]
+    pub epoch_to: ::core::option::Option<u64>,
+}
+/// Paid liquidity fees response.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListPaidLiquidityFeesResponse {
+    /// Page of paid liquidity fees and corresponding page information.
+    #[prost(message, optional, tag = "1")]
+    pub paid_liquidity_fees: ::core::option::Option<PaidLiquidityFeesConnection>,
+}
+/// Paid liquidity fees data with the corresponding cursor.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PaidLiquidityFeesEdge {
+    /// Paid liquidity fees data returned by the API.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<
+        super::super::super::vega::events::v1::PaidLiquidityFeesStats,
+    >,
+    /// Cursor that can be used to fetch further data.
+    #[prost(string, tag = "2")]
+    pub cursor: ::prost::alloc::string::String,
+}
+/// Paid liquidity fees data and corresponding page information.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PaidLiquidityFeesConnection {
+    /// Page of paid liquidity fees data.
+    #[prost(message, repeated, tag = "1")]
+    pub edges: ::prost::alloc::vec::Vec<PaidLiquidityFeesEdge>,
+    /// Page information that is used for fetching further pages.
+    #[prost(message, optional, tag = "2")]
+    pub page_info: ::core::option::Option<PageInfo>,
+}
+/// Request to get governance data
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetGovernanceDataRequest {
+    /// Proposal ID to get governance data for, if provided.
+    #[prost(string, optional, tag = "1")]
+    pub proposal_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Reference to get proposal data for, if provided.
+    #[prost(string, optional, tag = "2")]
+    pub reference: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// Response from getting governance data
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetGovernanceDataResponse {
+    /// Governance data content, i.e. proposal and votes for and against.
+    #[prost(message, optional, tag = "1")]
+    pub data: ::core::option::Option<super::super::super::vega::GovernanceData>,
+}
+/// Request to list governance data
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListGovernanceDataRequest {
+    /// Restrict proposals to those with the given state.
+    #[prost(
+        enumeration = "super::super::super::vega::proposal::State",
+        optional,
+        tag = "1"
+    )]
+    pub proposal_state: ::core::option::Option<i32>,
+    /// Restrict proposals to those with the given type.
+    #[prost(enumeration = "list_governance_data_request::Type", optional, tag = "2")]
+    pub proposal_type: ::core::option::Option<i32>,
+    /// Restrict proposals to those proposed by the given party ID.
+    #[prost(string, optional, tag = "3")]
+    pub proposer_party_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Restrict proposals to those with the given reference.
+    #[prost(string, optional, tag = "4")]
+    pub proposal_reference: ::core::option::Option<::prost::alloc::string::String>,
+    /// Pagination controls.
+    #[prost(message, optional, tag = "5")]
+    pub pagination: ::core::option::Option<Pagination>,
+}
+/// Nested message and enum types in `ListGovernanceDataRequest`.
+pub mod list_governance_data_request {
+    /// Filter for the types of governance proposals to view
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Type {
+        Unspecified = 0,
+        /// List all proposals
+        All = 1,
+        /// List new market proposals
+        NewMarket = 2,
+        /// List update market proposals
+        UpdateMarket = 3,
+        /// List change Vega network parameter proposals
+        NetworkParameters = 4,
+        /// New asset proposals
+        NewAsset = 5,
+        /// Proposals for creating a new free form proposal
+        NewFreeForm = 6,
+        /// Update asset proposals
+        UpdateAsset = 7,
+        /// Propose a new spot market
+        NewSpotMarket = 8,
+        /// Update an existing spot market
+        UpdateSpotMarket = 9,
+        /// Propose a new transfer
+        NewTransfer = 10,
+        /// Proposal to cancel a transfer
+        CancelTransfer = 11,
+        /// Proposal for updating the state of a market
+        UpdateMarketState = 12,
+        /// Proposal to update the referral program
+        UpdateReferralProgram = 13,
+        /// Proposal to update the volume discount program
+        UpdateVolumeDiscountProgram = 14,
+    }
+    impl Type {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Type::Unspecified => "TYPE_UNSPECIFIED",
+                Type::All => "TYPE_ALL",
+                Type::NewMarket => "TYPE_NEW_MARKET",
+                Type::UpdateMarket => "TYPE_UPDATE_MARKET",
+                Type::NetworkParameters => "TYPE_NETWORK_PARAMETERS",
+                Type::NewAsset => "TYPE_NEW_ASSET",
+                Type::NewFreeForm => "TYPE_NEW_FREE_FORM",
+                Type::UpdateAsset => "TYPE_UPDATE_ASSET",
+                Type::NewSpotMarket => "TYPE_NEW_SPOT_MARKET",
+                Type::UpdateSpotMarket => "TYPE_UPDATE_SPOT_MARKET",
+                Type::NewTransfer => "TYPE_NEW_TRANSFER",
+                Type::CancelTransfer => "TYPE_CANCEL_TRANSFER",
+                Type::UpdateMarketState => "TYPE_UPDATE_MARKET_STATE",
+                Type::UpdateReferralProgram => "TYPE_UPDATE_REFERRAL_PROGRAM",
+                Type::UpdateVolumeDiscountProgram => {
+                    "TYPE_UPDATE_VOLUME_DISCOUNT_PROGRAM"
+                }
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "TYPE_UNSPECIFIED" => Some(Self::Unspecified),
+                "TYPE_ALL" => Some(Self::All),
+                "TYPE_NEW_MARKET" => Some(Self::NewMarket),
+                "TYPE_UPDATE_MARKET" => Some(Self::UpdateMarket),
+                "TYPE_NETWORK_PARAMETERS" => Some(Self::NetworkParameters),
+                "TYPE_NEW_ASSET" => Some(Self::NewAsset),
+                "TYPE_NEW_FREE_FORM" => Some(Self::NewFreeForm),
+                "TYPE_UPDATE_ASSET" => Some(Self::UpdateAsset),
+                "TYPE_NEW_SPOT_MARKET" => Some(Self::NewSpotMarket),
+                "TYPE_UPDATE_SPOT_MARKET" => Some(Self::UpdateSpotMarket),
+                "TYPE_NEW_TRANSFER" => Some(Self::NewTransfer),
+                "TYPE_CANCEL_TRANSFER" => Some(Self::CancelTransfer),
+                "TYPE_UPDATE_MARKET_STATE" => Some(Self::UpdateMarketState),
+                "TYPE_UPDATE_REFERRAL_PROGRAM" => Some(Self::UpdateReferralProgram),
+                "TYPE_UPDATE_VOLUME_DISCOUNT_PROGRAM" => {
+                    Some(Self::UpdateVolumeDiscountProgram)
+                }
+                _ => None,
+            }
+        }
+    }
+}
+/// Response from listing governance data
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListGovernanceDataResponse {
+    /// Page of governance data and corresponding page information.
+    #[prost(message, optional, tag = "1")]
+    pub connection: ::core::option::Option<GovernanceDataConnection>,
+}
+/// Governance data with the corresponding cursor.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GovernanceDataEdge {
+    /// Governance data content, i.e. proposal and votes for and against.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<super::super::super::vega::GovernanceData>,
+    /// Cursor that can be used to fetch further pages.
+    #[prost(string, tag = "2")]
+    pub cursor: ::prost::alloc::string::String,
+}
+/// Page of governance data and corresponding page information
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GovernanceDataConnection {
+    /// Page of governance data and their corresponding cursors.
+    #[prost(message, repeated, tag = "1")]
+    pub edges: ::prost::alloc::vec::Vec<GovernanceDataEdge>,
+    /// Page information that is used for fetching further pages.
+    #[prost(message, optional, tag = "2")]
+    pub page_info: ::core::option::Option<PageInfo>,
+}
+/// Request for governance subscription
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ObserveGovernanceRequest {
+    /// Restrict proposal updates to those proposed by the given party ID.
+    #[prost(string, optional, tag = "1")]
+    pub party_id: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// Response from governance subscription
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ObserveGovernanceResponse {
+    /// Governance data, i.e. proposal and votes for and against.
+    #[prost(message, optional, tag = "1")]
+    pub data: ::core::option::Option<super::super::super::vega::GovernanceData>,
+}
+/// Request for listing delegations
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListDelegationsRequest {
+    /// Restrict delegations to those made by the given party ID.
+    #[prost(string, optional, tag = "1")]
+    pub party_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Restrict delegations to those made to the given node ID.
+    #[prost(string, optional, tag = "2")]
+    pub node_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Return delegations made in the given epoch. If not set, delegations for the current epoch will be returned.
+    #[prost(string, optional, tag = "3")]
+    pub epoch_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Pagination controls.
+    #[prost(message, optional, tag = "4")]
+    pub pagination: ::core::option::Option<Pagination>,
+}
+/// Response from listing delegations
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListDelegationsResponse {
+    /// Page of delegations data and corresponding page information.
+    #[prost(message, optional, tag = "1")]
+    pub delegations: ::core::option::Option<DelegationsConnection>,
+}
+/// Delegation data with the corresponding cursor.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DelegationEdge {
+    /// How much a party is delegating to a node and when.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<super::super::super::vega::Delegation>,
+    /// Cursor that can be used to fetch further pages.
+    #[prost(string, tag = "2")]
+    pub cursor: ::prost::alloc::string::String,
+}
+/// Page of delegations data and corresponding page information
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DelegationsConnection {
+    /// Page of delegations data and their corresponding cursors.
+    #[prost(message, repeated, tag = "1")]
+    pub edges: ::prost::alloc::vec::Vec<DelegationEdge>,
+    /// Page information that is used for fetching further pages.
+    #[prost(message, optional, tag = "2")]
+    pub page_info: ::core::option::Option<PageInfo>,
+}
+/// Request to subscribe to all event related to delegations, with the given filters
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ObserveDelegationsRequest {
+    /// Party ID to get delegations for, if provided.
+    #[prost(string, optional, tag = "1")]
+    pub party_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Node ID to get delegations for, if provided.
+    #[prost(string, optional, tag = "2")]
+    pub node_id: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// Response with all events related to delegations, with the given filters
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ObserveDelegationsResponse {
+    /// How much a party is delegating to a node and when.
+    #[prost(message, optional, tag = "1")]
+    pub delegation: ::core::option::Option<super::super::super::vega::Delegation>,
+}
+/// Represents a basic node without any epoch specific details like delegations, staking, rewards etc.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NodeBasic {
+    /// Unique ID identifying the node.
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Node operator's public key.
+    #[prost(string, tag = "2")]
+    pub pub_key: ::prost::alloc::string::String,
+    /// Tendermint public key of the node.
+    #[prost(string, tag = "3")]
+    pub tm_pub_key: ::prost::alloc::string::String,
+    /// Ethereum public key of the node.
+    #[prost(string, tag = "4")]
+    pub ethereum_address: ::prost::alloc::string::String,
+    /// URL that provides more information about the node.
+    #[prost(string, tag = "5")]
+    pub info_url: ::prost::alloc::string::String,
+    /// Country code for the location of the node.
+    #[prost(string, tag = "6")]
+    pub location: ::prost::alloc::string::String,
+    /// Node status.
+    #[prost(enumeration = "super::super::super::vega::NodeStatus", tag = "13")]
+    pub status: i32,
+    /// Node name.
+    #[prost(string, tag = "17")]
+    pub name: ::prost::alloc::string::String,
+    /// Avatar URL.
+    #[prost(string, tag = "18")]
+    pub avatar_url: ::prost::alloc::string::String,
+}
+/// Request to get network data
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetNetworkDataRequest {}
+/// Response from getting network data
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetNetworkDataResponse {
+    /// Summary of information with respect to nodes on the Vega network.
+    #[prost(message, optional, tag = "1")]
+    pub node_data: ::core::option::Option<super::super::super::vega::NodeData>,
+}
+/// Request to get node data
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetNodeRequest {
+    /// Node ID to get data for.
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+}
+/// Response from getting node
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetNodeResponse {
+    /// Data specific to a single node on the Vega network.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<super::super::super::vega::Node>,
+}
+/// Request to list nodes
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListNodesRequest {
+    /// Return the node list for the given epoch. If not set, the node list for the current epoch will be returned.
+    #[prost(uint64, optional, tag = "1")]
+    pub epoch_seq: ::core::option::Option<u64>,
+    /// Pagination controls.
+    #[prost(message, optional, tag = "2")]
+    pub pagination: ::core::option::Option<Pagination>,
+}
+/// Response from listing nodes
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListNodesResponse {
+    /// Page of node data and corresponding page information.
+    #[prost(message, optional, tag = "1")]
+    pub nodes: ::core::option::Option<NodesConnection>,
+}
+/// Node data with the corresponding cursor.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NodeEdge {
+    /// Data specific to a single node on the Vega network.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<super::super::super::vega::Node>,
+    /// Cursor that can be used to fetch further pages.
+    #[prost(string, tag = "2")]
+    pub cursor: ::prost::alloc::string::String,
+}
+/// Page of node data and corresponding page information
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NodesConnection {
+    /// Page of node data and their corresponding cursors.
+    #[prost(message, repeated, tag = "1")]
+    pub edges: ::prost::alloc::vec::Vec<NodeEdge>,
+    /// Page information that is used for fetching further pages.
+    #[prost(message, optional, tag = "2")]
+    pub page_info: ::core::option::Option<PageInfo>,
+}
+/// Request to specify the ID of the resource to retrieve aggregated signatures for
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListNodeSignaturesRequest {
+    /// Resource ID to list signatures for.
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Optional pagination information to limit the data that is returned.
+    #[prost(message, optional, tag = "2")]
+    pub pagination: ::core::option::Option<Pagination>,
+}
+/// Response to specify the ID of the resource to retrieve aggregated signatures for
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListNodeSignaturesResponse {
+    /// Page of nodes signatures and corresponding page information.
+    #[prost(message, optional, tag = "1")]
+    pub signatures: ::core::option::Option<NodeSignaturesConnection>,
+}
+/// Nodes signature edge with the corresponding cursor.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NodeSignatureEdge {
+    /// Node signature data.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<
+        super::super::super::vega::commands::v1::NodeSignature,
+    >,
+    /// Cursor that can be used to fetch further pages.
+    #[prost(string, tag = "2")]
+    pub cursor: ::prost::alloc::string::String,
+}
+/// Page of node signatures and corresponding page information.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NodeSignaturesConnection {
+    /// Page of node signatures and their corresponding cursors.
+    #[prost(message, repeated, tag = "1")]
+    pub edges: ::prost::alloc::vec::Vec<NodeSignatureEdge>,
+    /// Page information that is used for fetching further pages.
+    #[prost(message, optional, tag = "2")]
+    pub page_info: ::core::option::Option<PageInfo>,
+}
+/// Request to fetch epoch data
+/// Provide either the epoch ID or the block height to get the epoch for. If both are provided, the epoch ID will be used.
+/// If both are omitted, the current epoch will be returned.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetEpochRequest {
+    /// Epoch ID. If provided, returns the epoch with the given ID.
+    #[prost(uint64, optional, tag = "1")]
+    pub id: ::core::option::Option<u64>,
+    /// Block height. If provided, returns the epoch that the given block is in.
+    #[prost(uint64, optional, tag = "2")]
+    pub block: ::core::option::Option<u64>,
+}
+/// Response from getting epoch
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetEpochResponse {
+    /// Data specific to a single epoch on the Vega network. This includes the epoch number,
+    /// start and end times, and the nodes that participated in the epoch.
+    #[prost(message, optional, tag = "1")]
+    pub epoch: ::core::option::Option<super::super::super::vega::Epoch>,
+}
+/// Request to fetch the estimated fee if an order were to trade immediately
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EstimateFeeRequest {
+    /// Market ID, used to specify the fee factors.
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+    /// Price at which the potential order is expected to trade.
+    #[prost(string, tag = "2")]
+    pub price: ::prost::alloc::string::String,
+    /// Size at which the potential order is expected to trade.
+    #[prost(uint64, tag = "3")]
+    pub size: u64,
+}
+/// Response that is received from EstimateFeeRequest, contains the estimated fees for a given order
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EstimateFeeResponse {
+    /// Summary of the estimated fees for this order if it were to trade now.
+    #[prost(message, optional, tag = "2")]
+    pub fee: ::core::option::Option<super::super::super::vega::Fee>,
+}
+/// Request to fetch the estimated MarginLevels if an order were to trade immediately
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EstimateMarginRequest {
+    /// Market ID for the order.
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+    /// Party ID of the order.
+    #[prost(string, tag = "2")]
+    pub party_id: ::prost::alloc::string::String,
+    /// Order side - indicator for Seller or Buyer side.
+    #[prost(enumeration = "super::super::super::vega::Side", tag = "3")]
+    pub side: i32,
+    /// Type of the order.
+    #[prost(enumeration = "super::super::super::vega::order::Type", tag = "4")]
+    pub r#type: i32,
+    /// Size of order.
+    #[prost(uint64, tag = "5")]
+    pub size: u64,
+    /// Price of the asset.
+    #[prost(string, tag = "6")]
+    pub price: ::prost::alloc::string::String,
+}
+/// Response to an estimate margin request, containing the estimated margin levels for a given order
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EstimateMarginResponse {
+    /// Summary of the estimated margins for this order if it were to trade now.
+    #[prost(message, optional, tag = "2")]
+    pub margin_levels: ::core::option::Option<super::super::super::vega::MarginLevels>,
+}
+/// Message requesting for the list of all network parameters
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListNetworkParametersRequest {
+    /// Optional pagination information to limit the data that is returned.
+    #[prost(message, optional, tag = "1")]
+    pub pagination: ::core::option::Option<Pagination>,
+}
+/// Response containing all of the Vega network parameters
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListNetworkParametersResponse {
+    /// Page of network parameters and corresponding page information.
+    #[prost(message, optional, tag = "1")]
+    pub network_parameters: ::core::option::Option<NetworkParameterConnection>,
+}
+/// Request for a single network parameter
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetNetworkParameterRequest {
+    /// Key identifying the network parameter.
+    #[prost(string, tag = "1")]
+    pub key: ::prost::alloc::string::String,
+}
+/// Response that is received when getting a network parameter
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetNetworkParameterResponse {
+    /// Network parameter key and value.
+    #[prost(message, optional, tag = "1")]
+    pub network_parameter: ::core::option::Option<
+        super::super::super::vega::NetworkParameter,
+    >,
+}
+/// Network parameter with the corresponding cursor.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NetworkParameterEdge {
+    /// Name and associated value of a network parameter.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<super::super::super::vega::NetworkParameter>,
+    /// Cursor that can be used to fetch further pages.
+    #[prost(string, tag = "2")]
+    pub cursor: ::prost::alloc::string::String,
+}
+/// Page of network parameters and corresponding page information
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NetworkParameterConnection {
+    /// Page of network parameters data and their corresponding cursors.
+    #[prost(message, repeated, tag = "1")]
+    pub edges: ::prost::alloc::vec::Vec<NetworkParameterEdge>,
+    /// Page information that is used for fetching further pages.
+    #[prost(message, optional, tag = "2")]
+    pub page_info: ::core::option::Option<PageInfo>,
+}
+/// Actual data regarding a checkpoint
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Checkpoint {
+    /// Hash of the checkpoint.
+    #[prost(string, tag = "1")]
+    pub hash: ::prost::alloc::string::String,
+    /// Block hash at which the checkpoint is made.
+    #[prost(string, tag = "2")]
+    pub block_hash: ::prost::alloc::string::String,
+    /// Block number at which the checkpoint is made.
+    #[prost(uint64, tag = "3")]
+    pub at_block: u64,
+}
+/// Request to get all checkpoints. Currently no fields, probably will be expanded to allow filtering data
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListCheckpointsRequest {
+    /// Optional pagination information to limit the data that is returned.
+    #[prost(message, optional, tag = "1")]
+    pub pagination: ::core::option::Option<Pagination>,
+}
+/// Response message containing all checkpoints requested
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListCheckpointsResponse {
+    /// Page of checkpoints data and corresponding page information.
+    #[prost(message, optional, tag = "1")]
+    pub checkpoints: ::core::option::Option<CheckpointsConnection>,
+}
+/// Checkpoint data with the corresponding cursor.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CheckpointEdge {
+    /// Data relating to a single checkpoint generated by the Vega network.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<Checkpoint>,
+    /// Cursor that can be used to fetch further pages.
+    #[prost(string, tag = "2")]
+    pub cursor: ::prost::alloc::string::String,
+}
+/// Page of checkpoints data and corresponding page information
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CheckpointsConnection {
+    /// Page of checkpoints data and their corresponding cursors.
+    #[prost(message, repeated, tag = "1")]
+    pub edges: ::prost::alloc::vec::Vec<CheckpointEdge>,
+    /// Page information that is used for fetching further pages.
+    #[prost(message, optional, tag = "2")]
+    pub page_info: ::core::option::Option<PageInfo>,
+}
+/// Request to get stake
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetStakeRequest {
+    /// Party ID for which the stake information is requested.
+    #[prost(string, tag = "1")]
+    pub party_id: ::prost::alloc::string::String,
+    /// Optional pagination information to limit the data that is returned.
+    #[prost(message, optional, tag = "2")]
+    pub pagination: ::core::option::Option<Pagination>,
+}
+/// Response that is received from requesting stake information
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetStakeResponse {
+    /// Current stake available information.
+    #[prost(string, tag = "1")]
+    pub current_stake_available: ::prost::alloc::string::String,
+    /// Paged list of stake data with corresponding page information.
+    #[prost(message, optional, tag = "2")]
+    pub stake_linkings: ::core::option::Option<StakesConnection>,
+}
+/// Stake linking data with the corresponding cursor.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StakeLinkingEdge {
+    /// Stake linking representing the intent from a party to deposit.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<
+        super::super::super::vega::events::v1::StakeLinking,
+    >,
+    /// Cursor that can be used to fetch further pages.
+    #[prost(string, tag = "2")]
+    pub cursor: ::prost::alloc::string::String,
+}
+/// Page of stake data and corresponding page information.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StakesConnection {
+    /// Page of stake data and their corresponding cursors.
+    #[prost(message, repeated, tag = "1")]
+    pub edges: ::prost::alloc::vec::Vec<StakeLinkingEdge>,
+    /// Page information that is used for fetching further pages.
+    #[prost(message, optional, tag = "2")]
+    pub page_info: ::core::option::Option<PageInfo>,
+}
+/// Request to get risk factor
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetRiskFactorsRequest {
+    /// Market ID to get the risk factor for.
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+}
+/// Response that is received from getting a risk factor
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetRiskFactorsResponse {
+    /// Risk factor emitted by the risk model for a given market.
+    #[prost(message, optional, tag = "1")]
+    pub risk_factor: ::core::option::Option<super::super::super::vega::RiskFactor>,
+}
+/// Request to subscribe to a stream of one or more event types from the Vega event bus
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ObserveEventBusRequest {
+    /// One or more types of event, required field.
+    #[prost(
+        enumeration = "super::super::super::vega::events::v1::BusEventType",
+        repeated,
+        tag = "1"
+    )]
+    pub r#type: ::prost::alloc::vec::Vec<i32>,
+    /// Market ID to filter for, optional field. If empty, no markets will be excluded from the stream.
+    #[prost(string, tag = "2")]
+    pub market_id: ::prost::alloc::string::String,
+    /// Party ID to filter for, optional field. If empty, no parties will be excluded from the stream.
+    #[prost(string, tag = "3")]
+    pub party_id: ::prost::alloc::string::String,
+    /// Batch size,
+    /// If not specified, any events received will be sent immediately. If the client is not ready
+    /// for the next data-set, data may be dropped a number of times, and eventually the stream is closed.
+    /// if specified, the first batch will be sent when ready. To receive the next set of events, the client
+    /// must write an `ObserveEventBatch` message on the stream to flush the buffer.
+    /// If no message is received in 5 seconds, the stream is closed.
+    /// Default: 0, send any and all events when they are available.
+    #[prost(int64, tag = "4")]
+    pub batch_size: i64,
+}
+/// Response to a subscribed stream of events from the Vega event bus
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ObserveEventBusResponse {
+    /// List of events that occurred on the Vega event bus.
+    #[prost(message, repeated, tag = "1")]
+    pub events: ::prost::alloc::vec::Vec<
+        super::super::super::vega::events::v1::BusEvent,
+    >,
+}
+/// Request to subscribe to ledger movements
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ObserveLedgerMovementsRequest {}
+/// Response from ledger movements subscription
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ObserveLedgerMovementsResponse {
+    /// Ledger movements data with list of ledger entries and post-transfer balances.
+    #[prost(message, optional, tag = "1")]
+    pub ledger_movement: ::core::option::Option<
+        super::super::super::vega::LedgerMovement,
+    >,
+}
+/// Request to list all key rotations
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListKeyRotationsRequest {
+    /// Node ID to get key rotations for, if provided.
+    #[prost(string, optional, tag = "1")]
+    pub node_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Optional pagination information to limit the data that is returned.
+    #[prost(message, optional, tag = "2")]
+    pub pagination: ::core::option::Option<Pagination>,
+}
+/// Response message containing Vega key rotations
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListKeyRotationsResponse {
+    /// Page of key rotations data and corresponding page information.
+    #[prost(message, optional, tag = "1")]
+    pub rotations: ::core::option::Option<KeyRotationConnection>,
+}
+/// Key rotation data with the corresponding cursor.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct KeyRotationEdge {
+    /// Data relating to a key rotation that was performed by a node on the Vega network.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<super::super::super::vega::events::v1::KeyRotation>,
+    /// Cursor that can be used to fetch further pages.
+    #[prost(string, tag = "2")]
+    pub cursor: ::prost::alloc::string::String,
+}
+/// Page of key rotations data and corresponding page information
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct KeyRotationConnection {
+    /// Page of key rotation data and their corresponding cursors.
+    #[prost(message, repeated, tag = "1")]
+    pub edges: ::prost::alloc::vec::Vec<KeyRotationEdge>,
+    /// Page information that is used for fetching further pages.
+    #[prost(message, optional, tag = "2")]
+    pub page_info: ::core::option::Option<PageInfo>,
+}
+/// Request to list ethereum key rotations for nodes, optionally filtered by node
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListEthereumKeyRotationsRequest {
+    /// Node ID to get the rotation for, if provided.
+    #[prost(string, optional, tag = "1")]
+    pub node_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Optional pagination information to limit the data that is returned.
+    #[prost(message, optional, tag = "2")]
+    pub pagination: ::core::option::Option<Pagination>,
+}
+/// Response message containing Ethereum key rotations
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListEthereumKeyRotationsResponse {
+    /// Page of Ethereum key rotations data and corresponding page information.
+    #[prost(message, optional, tag = "1")]
+    pub key_rotations: ::core::option::Option<EthereumKeyRotationsConnection>,
+}
+/// Page of Ethereum key rotations data and corresponding page information
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EthereumKeyRotationsConnection {
+    /// Page of Ethereum key rotations data and their corresponding cursors.
+    #[prost(message, repeated, tag = "1")]
+    pub edges: ::prost::alloc::vec::Vec<EthereumKeyRotationEdge>,
+    /// Page information that is used for fetching further pages.
+    #[prost(message, optional, tag = "2")]
+    pub page_info: ::core::option::Option<PageInfo>,
+}
+/// Ethereum key rotation data with the corresponding cursor.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EthereumKeyRotationEdge {
+    /// Data relating to an Ethereum key rotation performed by a node on the Vega network.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<
+        super::super::super::vega::events::v1::EthereumKeyRotation,
+    >,
+    /// Cursor that can be used to fetch further pages.
+    #[prost(string, tag = "2")]
+    pub cursor: ::prost::alloc::string::String,
+}
+/// Request to get the current time of the Vega network
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetVegaTimeRequest {}
+/// Response for the current consensus coordinated time on the Vega network, referred to as "VegaTime"
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetVegaTimeResponse {
+    /// Timestamp representation of current VegaTime as represented in Unix nanoseconds, for example
+    /// `1580473859111222333` corresponds to `2020-01-31T12:30:59.111222333Z`.
+    #[prost(int64, tag = "1")]
+    pub timestamp: i64,
+}
+/// Date range for queries that can return historical data
+/// Timestamps should be provided as unix time in Unix nanoseconds and are inclusive.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DateRange {
+    /// Timestamp in Unix nanoseconds indicating the start of the date range.
+    #[prost(int64, optional, tag = "1")]
+    pub start_timestamp: ::core::option::Option<i64>,
+    /// Timestamp in Unix nanoseconds indicating the end of the date range.
+    #[prost(int64, optional, tag = "2")]
+    pub end_timestamp: ::core::option::Option<i64>,
+}
+/// Request to get protocol upgrade status
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetProtocolUpgradeStatusRequest {}
+/// Response from getting protocol upgrade status
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetProtocolUpgradeStatusResponse {
+    /// Indicator if the upgrade is ready or not.
+    #[prost(bool, tag = "1")]
+    pub ready: bool,
+}
+/// Request type for ListProtocolUpgradeProposals; fetches a paginated list of protocol upgrade proposals
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListProtocolUpgradeProposalsRequest {
+    /// Restrict protocol upgrade proposals to those with the given status.
+    #[prost(
+        enumeration = "super::super::super::vega::events::v1::ProtocolUpgradeProposalStatus",
+        optional,
+        tag = "1"
+    )]
+    pub status: ::core::option::Option<i32>,
+    /// Restrict protocol upgrade proposals to those approved by the given node ID.
+    #[prost(string, optional, tag = "2")]
+    pub approved_by: ::core::option::Option<::prost::alloc::string::String>,
+    /// Pagination controls.
+    #[prost(message, optional, tag = "3")]
+    pub pagination: ::core::option::Option<Pagination>,
+}
+/// Response type from a ListProtocolUpgradeProposals RPC call; a paginated list of protocol upgrade proposals
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListProtocolUpgradeProposalsResponse {
+    /// Page of protocol upgrade proposals and corresponding page information.
+    #[prost(message, optional, tag = "1")]
+    pub protocol_upgrade_proposals: ::core::option::Option<
+        ProtocolUpgradeProposalConnection,
+    >,
+}
+/// Page of protocol upgrade proposals and corresponding page information
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtocolUpgradeProposalConnection {
+    /// Page of protocol upgrade proposals data and their corresponding cursors.
+    #[prost(message, repeated, tag = "1")]
+    pub edges: ::prost::alloc::vec::Vec<ProtocolUpgradeProposalEdge>,
+    /// Page information that is used for fetching further pages.
+    #[prost(message, optional, tag = "2")]
+    pub page_info: ::core::option::Option<PageInfo>,
+}
+/// Protocol upgrade proposal data with the corresponding cursor.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtocolUpgradeProposalEdge {
+    /// Protocol upgrade proposal data.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<
+        super::super::super::vega::events::v1::ProtocolUpgradeEvent,
+    >,
+    /// Cursor that can be used to fetch further pages.
+    #[prost(string, tag = "2")]
+    pub cursor: ::prost::alloc::string::String,
+}
+/// Request type for ListCoreSnapshots; fetches a paginated list of the core snapshots.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListCoreSnapshotsRequest {
+    /// Optional pagination information to limit the data that is returned.
+    #[prost(message, optional, tag = "1")]
+    pub pagination: ::core::option::Option<Pagination>,
+}
+/// Response from a ListCoreSnapshots RPC call; a paginated list of the core snapshots.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListCoreSnapshotsResponse {
+    /// Page of core snapshot data and corresponding page information.
+    #[prost(message, optional, tag = "1")]
+    pub core_snapshots: ::core::option::Option<CoreSnapshotConnection>,
+}
+/// Page of core snapshot data and corresponding page information.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CoreSnapshotConnection {
+    /// Page of core snapshot data and their corresponding cursors.
+    #[prost(message, repeated, tag = "1")]
+    pub edges: ::prost::alloc::vec::Vec<CoreSnapshotEdge>,
+    /// Page information that is used for fetching further pages.
+    #[prost(message, optional, tag = "2")]
+    pub page_info: ::core::option::Option<PageInfo>,
+}
+/// Core snapshot data with the corresponding cursor.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CoreSnapshotEdge {
+    /// Core snapshot data.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<
+        super::super::super::vega::events::v1::CoreSnapshotData,
+    >,
+    /// Cursor that can be used to fetch further pages.
+    #[prost(string, tag = "2")]
+    pub cursor: ::prost::alloc::string::String,
+}
+/// Describes a network history segment
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct HistorySegment {
+    /// Starting height of the history segment.
+    #[prost(int64, tag = "1")]
+    pub from_height: i64,
+    /// Ending height of the history segment.
+    #[prost(int64, tag = "2")]
+    pub to_height: i64,
+    /// History segment ID.
+    #[prost(string, tag = "3")]
+    pub history_segment_id: ::prost::alloc::string::String,
+    /// Previous history segment ID.
+    #[prost(string, tag = "4")]
+    pub previous_history_segment_id: ::prost::alloc::string::String,
+    /// Database schema version of the history segment.
+    #[prost(int64, tag = "5")]
+    pub database_version: i64,
+    /// Chain ID of the history segment.
+    #[prost(string, tag = "6")]
+    pub chain_id: ::prost::alloc::string::String,
+}
+/// Request to get the most recent history segment
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetMostRecentNetworkHistorySegmentRequest {}
+/// Response from getting most recent history segment
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetMostRecentNetworkHistorySegmentResponse {
+    /// Data relating to a history segment created by a Vega data node.
+    #[prost(message, optional, tag = "1")]
+    pub segment: ::core::option::Option<HistorySegment>,
+    /// Key seed for the swarm section.
+    #[prost(string, tag = "2")]
+    pub swarm_key_seed: ::prost::alloc::string::String,
+}
+/// Request to list all the nodes history segments
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListAllNetworkHistorySegmentsRequest {}
+/// Response with a list of all the nodes history segments
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListAllNetworkHistorySegmentsResponse {
+    /// Page of history segments data and corresponding page information.
+    #[prost(message, repeated, tag = "1")]
+    pub segments: ::prost::alloc::vec::Vec<HistorySegment>,
+}
+/// Request to get the addresses of active network history peers
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetActiveNetworkHistoryPeerAddressesRequest {}
+/// Response containing the addresses of active network history peers
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetActiveNetworkHistoryPeerAddressesResponse {
+    /// List of IP addresses for the active peers.
+    #[prost(string, repeated, tag = "1")]
+    pub ip_addresses: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// Request to get the status of network history
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetNetworkHistoryStatusRequest {}
+/// Response containing the status of network history
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetNetworkHistoryStatusResponse {
+    /// IPFS address of the data node currently connected to.
+    #[prost(string, tag = "1")]
+    pub ipfs_address: ::prost::alloc::string::String,
+    /// Swarm key used by the IPFS swarm.
+    #[prost(string, tag = "2")]
+    pub swarm_key: ::prost::alloc::string::String,
+    /// Swarm key seed used by the IPFS swarm.
+    #[prost(string, tag = "3")]
+    pub swarm_key_seed: ::prost::alloc::string::String,
+    /// List of peers connected to the IPFS swarm.
+    #[prost(string, repeated, tag = "5")]
+    pub connected_peers: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// Request to get the nodes network history bootstrap peers
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetNetworkHistoryBootstrapPeersRequest {}
+/// Response containing the nodes network history bootstrap peers
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetNetworkHistoryBootstrapPeersResponse {
+    /// List of peers that can be used to bootstrap a Vega data node.
+    #[prost(string, repeated, tag = "1")]
+    pub bootstrap_peers: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// Request to export network history data in CSV format
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExportNetworkHistoryRequest {
+    /// Block to begin exporting from. Must be the first block of a history segment,
+    /// which by default are 1000 blocks each; in that case - 1, 1001, 2001 etc. are valid values.
+    /// This can be checked by first calling the API to list all network history segments.
+    #[prost(int64, tag = "1")]
+    pub from_block: i64,
+    /// Last block to export up to and including. Must be the last block of a history segment
+    /// which by default are 1000 blocks each; in that case - 1000, 2000, 3000 etc. are valid values.
+    /// This can be checked by first calling the API to list all network history segments.
+    #[prost(int64, tag = "2")]
+    pub to_block: i64,
+    /// Table to export data from.
+    #[prost(enumeration = "Table", tag = "3")]
+    pub table: i32,
+}
+/// Request to list all entities that were created by the given transaction hash
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListEntitiesRequest {
+    /// Transaction hash to match against
+    #[prost(string, tag = "1")]
+    pub transaction_hash: ::prost::alloc::string::String,
+}
+/// Response from listing entities that were created for a given transaction hash
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListEntitiesResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub accounts: ::prost::alloc::vec::Vec<super::super::super::vega::Account>,
+    #[prost(message, repeated, tag = "2")]
+    pub orders: ::prost::alloc::vec::Vec<super::super::super::vega::Order>,
+    #[prost(message, repeated, tag = "3")]
+    pub positions: ::prost::alloc::vec::Vec<super::super::super::vega::Position>,
+    #[prost(message, repeated, tag = "4")]
+    pub ledger_entries: ::prost::alloc::vec::Vec<super::super::super::vega::LedgerEntry>,
+    #[prost(message, repeated, tag = "5")]
+    pub balance_changes: ::prost::alloc::vec::Vec<AccountBalance>,
+    #[prost(message, repeated, tag = "6")]
+    pub transfers: ::prost::alloc::vec::Vec<
+        super::super::super::vega::events::v1::Transfer,
+    >,
+    #[prost(message, repeated, tag = "7")]
+    pub votes: ::prost::alloc::vec::Vec<super::super::super::vega::Vote>,
+    #[prost(message, repeated, tag = "8")]
+    pub erc20_multi_sig_signer_added_bundles: ::prost::alloc::vec::Vec<
+        Erc20MultiSigSignerAddedBundle,
+    >,
+    #[prost(message, repeated, tag = "9")]
+    pub erc20_multi_sig_signer_removed_bundles: ::prost::alloc::vec::Vec<
+        Erc20MultiSigSignerRemovedBundle,
+    >,
+    #[prost(message, repeated, tag = "10")]
+    pub trades: ::prost::alloc::vec::Vec<super::super::super::vega::Trade>,
+    #[prost(message, repeated, tag = "11")]
+    pub oracle_specs: ::prost::alloc::vec::Vec<super::super::super::vega::OracleSpec>,
+    #[prost(message, repeated, tag = "12")]
+    pub oracle_data: ::prost::alloc::vec::Vec<super::super::super::vega::OracleData>,
+    #[prost(message, repeated, tag = "13")]
+    pub markets: ::prost::alloc::vec::Vec<super::super::super::vega::Market>,
+    #[prost(message, repeated, tag = "14")]
+    pub parties: ::prost::alloc::vec::Vec<super::super::super::vega::Party>,
+    #[prost(message, repeated, tag = "15")]
+    pub margin_levels: ::prost::alloc::vec::Vec<super::super::super::vega::MarginLevels>,
+    #[prost(message, repeated, tag = "16")]
+    pub rewards: ::prost::alloc::vec::Vec<super::super::super::vega::Reward>,
+    #[prost(message, repeated, tag = "17")]
+    pub deposits: ::prost::alloc::vec::Vec<super::super::super::vega::Deposit>,
+    #[prost(message, repeated, tag = "18")]
+    pub withdrawals: ::prost::alloc::vec::Vec<super::super::super::vega::Withdrawal>,
+    #[prost(message, repeated, tag = "19")]
+    pub assets: ::prost::alloc::vec::Vec<super::super::super::vega::Asset>,
+    #[prost(message, repeated, tag = "20")]
+    pub liquidity_provisions: ::prost::alloc::vec::Vec<
+        super::super::super::vega::LiquidityProvision,
+    >,
+    #[prost(message, repeated, tag = "21")]
+    pub proposals: ::prost::alloc::vec::Vec<super::super::super::vega::Proposal>,
+    #[prost(message, repeated, tag = "22")]
+    pub delegations: ::prost::alloc::vec::Vec<super::super::super::vega::Delegation>,
+    #[prost(message, repeated, tag = "23")]
+    pub nodes: ::prost::alloc::vec::Vec<NodeBasic>,
+    #[prost(message, repeated, tag = "24")]
+    pub node_signatures: ::prost::alloc::vec::Vec<
+        super::super::super::vega::commands::v1::NodeSignature,
+    >,
+    #[prost(message, repeated, tag = "25")]
+    pub network_parameters: ::prost::alloc::vec::Vec<
+        super::super::super::vega::NetworkParameter,
+    >,
+    #[prost(message, repeated, tag = "26")]
+    pub key_rotations: ::prost::alloc::vec::Vec<
+        super::super::super::vega::events::v1::KeyRotation,
+    >,
+    #[prost(message, repeated, tag = "27")]
+    pub ethereum_key_rotations: ::prost::alloc::vec::Vec<
+        super::super::super::vega::events::v1::EthereumKeyRotation,
+    >,
+    #[prost(message, repeated, tag = "28")]
+    pub protocol_upgrade_proposals: ::prost::alloc::vec::Vec<
+        super::super::super::vega::events::v1::ProtocolUpgradeEvent,
+    >,
+}
+/// Request to get a party's activity streaks across epochs
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetPartyActivityStreakRequest {
+    /// Party ID to get the activity streak for.
+    #[prost(string, tag = "1")]
+    pub party_id: ::prost::alloc::string::String,
+    /// Epoch to get the activity streak for. If not set, the last complete epoch is used.
+    #[prost(uint64, optional, tag = "2")]
+    pub epoch: ::core::option::Option<u64>,
+}
+/// Response when getting a party's activity streaks across epochs
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetPartyActivityStreakResponse {
+    /// Party's activity streak in a given epoch.
+    #[prost(message, optional, tag = "1")]
+    pub activity_streak: ::core::option::Option<
+        super::super::super::vega::events::v1::PartyActivityStreak,
+    >,
+}
+/// A funding payment for a party on a perpetual market
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FundingPayment {
+    /// Party that received the funding payment.
+    #[prost(string, tag = "1")]
+    pub party_id: ::prost::alloc::string::String,
+    /// Market that produced the funding payment.
+    #[prost(string, tag = "2")]
+    pub market_id: ::prost::alloc::string::String,
+    /// Funding period sequence this payment was calculated from.
+    #[prost(uint64, tag = "3")]
+    pub funding_period_seq: u64,
+    /// Timestamp, in Unix nanoseconds, at which this funding payment occurred.
+    #[prost(int64, tag = "4")]
+    pub timestamp: i64,
+    /// Funding payment amount, where a positive value indicates an earned payment received and a negative value a loss.
+    #[prost(string, tag = "5")]
+    pub amount: ::prost::alloc::string::String,
+}
+/// Request to list a a party's funding payments
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListFundingPaymentsRequest {
+    /// Party ID to get funding payment for.
+    #[prost(string, tag = "1")]
+    pub party_id: ::prost::alloc::string::String,
+    /// Restrict funding payments returned to those generated by the given market.
+    #[prost(string, optional, tag = "2")]
+    pub market_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Pagination controls.
+    #[prost(message, optional, tag = "3")]
+    pub pagination: ::core::option::Option<Pagination>,
+}
+/// Funding payment data with the corresponding cursor.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FundingPaymentEdge {
+    /// Funding payment data.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<FundingPayment>,
+    /// Cursor that can be used to fetch further pages.
+    #[prost(string, tag = "2")]
+    pub cursor: ::prost::alloc::string::String,
+}
+/// Page of funding payment data and corresponding page information.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FundingPaymentConnection {
+    /// Page of funding payment data and their corresponding cursors.
+    #[prost(message, repeated, tag = "1")]
+    pub edges: ::prost::alloc::vec::Vec<FundingPaymentEdge>,
+    /// Page information that is used for fetching further pages.
+    #[prost(message, optional, tag = "2")]
+    pub page_info: ::core::option::Option<PageInfo>,
+}
+/// Response from listing funding payments
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListFundingPaymentsResponse {
+    /// Page of funding period data and corresponding page information.
+    #[prost(message, optional, tag = "1")]
+    pub funding_payments: ::core::option::Option<FundingPaymentConnection>,
+}
+/// Request to list a perpetual market's funding periods
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListFundingPeriodsRequest {
+    /// Market ID to get funding periods for.
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+    /// Restrict the funding periods to those within the given date range.
+    #[prost(message, optional, tag = "2")]
+    pub date_range: ::core::option::Option<DateRange>,
+    /// Pagination controls.
+    #[prost(message, optional, tag = "3")]
+    pub pagination: ::core::option::Option<Pagination>,
+}
+/// Funding period data with the corresponding cursor.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FundingPeriodEdge {
+    /// Funding period data.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<
+        super::super::super::vega::events::v1::FundingPeriod,
+    >,
+    /// Cursor that can be used to fetch further pages.
+    #[prost(string, tag = "2")]
+    pub cursor: ::prost::alloc::string::String,
+}
+/// Page of funding period data and corresponding page information.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FundingPeriodConnection {
+    /// Page of funding period data and their corresponding cursors.
+    #[prost(message, repeated, tag = "1")]
+    pub edges: ::prost::alloc::vec::Vec<FundingPeriodEdge>,
+    /// Page information that is used for fetching further pages.
+    #[prost(message, optional, tag = "2")]
+    pub page_info: ::core::option::Option<PageInfo>,
+}
+/// Response from listing funding periods
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListFundingPeriodsResponse {
+    /// Page of funding period data and corresponding page information.
+    #[prost(message, optional, tag = "1")]
+    pub funding_periods: ::core::option::Option<FundingPeriodConnection>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListFundingPeriodDataPointsRequest {
+    /// Market ID to get funding period data points for.
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+    /// Restrict the data points to those within the given date range.
+    #[prost(message, optional, tag = "2")]
+    pub date_range: ::core::option::Option<DateRange>,
+    /// Restrict the data points to those with the given source type.
+    #[prost(
+        enumeration = "super::super::super::vega::events::v1::funding_period_data_point::Source",
+        optional,
+        tag = "3"
+    )]
+    pub source: ::core::option::Option<i32>,
+    /// Restrict the data points to those that contributed to the given funding period sequence.
+    #[prost(uint64, optional, tag = "4")]
+    pub seq: ::core::option::Option<u64>,
+    /// Pagination controls.
+    #[prost(message, optional, tag = "5")]
+    pub pagination: ::core::option::Option<Pagination>,
+}
+/// Funding period data point with the corresponding cursor.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FundingPeriodDataPointEdge {
+    /// Funding period data point.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<
+        super::super::super::vega::events::v1::FundingPeriodDataPoint,
+    >,
+    /// Cursor that can be used to fetch further pages.
+    #[prost(string, tag = "2")]
+    pub cursor: ::prost::alloc::string::String,
+}
+/// Page of funding period data points and corresponding page information.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FundingPeriodDataPointConnection {
+    /// Page of funding period data points and their corresponding cursors.
+    #[prost(message, repeated, tag = "1")]
+    pub edges: ::prost::alloc::vec::Vec<FundingPeriodDataPointEdge>,
+    /// Page information that is used for fetching further pages.
+    #[prost(message, optional, tag = "2")]
+    pub page_info: ::core::option::Option<PageInfo>,
+}
+/// Response from listing funding period data points.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListFundingPeriodDataPointsResponse {
+    /// Page of funding period data points and corresponding page information.
+    #[prost(message, optional, tag = "1")]
+    pub funding_period_data_points: ::core::option::Option<
+        FundingPeriodDataPointConnection,
+    >,
+}
+/// Request to ping the data node
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PingRequest {}
+/// Ping response from the data node
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PingResponse {}
+/// Basic description of an order.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OrderInfo {
+    /// Side for the order, e.g. buy or sell.
+    #[prost(enumeration = "super::super::super::vega::Side", tag = "1")]
+    pub side: i32,
+    /// Price for the order. The price is an unsigned integer. For example `123456` is a correctly
+    /// formatted price of `1.23456` assuming market configured to 5 decimal places.
+    #[prost(string, tag = "2")]
+    pub price: ::prost::alloc::string::String,
+    /// Size remaining.
+    #[prost(uint64, tag = "3")]
+    pub remaining: u64,
+    /// Boolean that indicates if it is a market order.
+    #[prost(bool, tag = "4")]
+    pub is_market_order: bool,
+}
+/// Request for the estimated margin level, margin account balance change, and liquidation price for the specified position.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EstimatePositionRequest {
+    /// Market ID to estimate position for.
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+    /// Open volume. This field is a signed integer scaled to the market's position decimal places.
+    /// A negative number denotes a short position.
+    #[prost(int64, tag = "2")]
+    pub open_volume: i64,
+    /// Average entry price corresponding to the open volume. The price is an unsigned integer. For example `123456` is a correctly
+    /// formatted price of `1.23456` assuming market configured to 5 decimal places.
+    #[prost(string, tag = "3")]
+    pub average_entry_price: ::prost::alloc::string::String,
+    /// Open and/or hypothetical orders.
+    #[prost(message, repeated, tag = "4")]
+    pub orders: ::prost::alloc::vec::Vec<OrderInfo>,
+    /// Margin account balance. Needs to scaled by asset decimal places.
+    #[prost(string, tag = "5")]
+    pub margin_account_balance: ::prost::alloc::string::String,
+    /// General account balance. Needs to scaled by asset decimal places.
+    #[prost(string, tag = "6")]
+    pub general_account_balance: ::prost::alloc::string::String,
+    /// Order margin account balance. Needs to be scaled by asset decimal places.
+    #[prost(string, tag = "7")]
+    pub order_margin_account_balance: ::prost::alloc::string::String,
+    /// Margin mode for the party, cross margin or isolated margin.
+    #[prost(enumeration = "super::super::super::vega::MarginMode", tag = "8")]
+    pub margin_mode: i32,
+    /// Margin factor to be used along with isolated margin mode
+    #[prost(string, optional, tag = "9")]
+    pub margin_factor: ::core::option::Option<::prost::alloc::string::String>,
+    /// Whether the estimated position margin increase should be included in available collateral for liquidation price calculation in isolated margin mode.
+    #[prost(bool, optional, tag = "10")]
+    pub include_required_position_margin_in_available_collateral: ::core::option::Option<
+        bool,
+    >,
+    /// Whether the liquidation price estimates should be scaled to market decimal places or by asset decimal places. If not set, asset decimal places are used.
+    #[prost(bool, optional, tag = "11")]
+    pub scale_liquidation_price_to_market_decimals: ::core::option::Option<bool>,
+}
+/// Response for the estimated margin level, margin account balance change, and liquidation price for the specified position.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EstimatePositionResponse {
+    /// Margin level range estimate for the specified position.
+    #[prost(message, optional, tag = "1")]
+    pub margin: ::core::option::Option<MarginEstimate>,
+    /// Estimated margin account balance increase.
+    #[prost(message, optional, tag = "2")]
+    pub collateral_increase_estimate: ::core::option::Option<CollateralIncreaseEstimate>,
+    /// Liquidation price range estimate for the specified position. Only populated if available collateral was specified in the request.
+    #[prost(message, optional, tag = "3")]
+    pub liquidation: ::core::option::Option<LiquidationEstimate>,
+}
+/// Estimates the additional funds needed to support the specified position
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CollateralIncreaseEstimate {
+    /// Estimate assuming slippage cap is applied
+    #[prost(string, tag = "1")]
+    pub worst_case: ::prost::alloc::string::String,
+    /// Estimate assuming no slippage
+    #[prost(string, tag = "2")]
+    pub best_case: ::prost::alloc::string::String,
+}
+/// Margin level estimate for both worst and best case possible.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MarginEstimate {
+    /// Margin level estimate assuming slippage cap is applied.
+    #[prost(message, optional, tag = "1")]
+    pub worst_case: ::core::option::Option<super::super::super::vega::MarginLevels>,
+    /// Margin level estimate assuming no slippage.
+    #[prost(message, optional, tag = "2")]
+    pub best_case: ::core::option::Option<super::super::super::vega::MarginLevels>,
+}
+/// Liquidation estimate for both worst and best case possible.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidationEstimate {
+    /// Liquidation price estimate assuming slippage cap is applied.
+    #[prost(message, optional, tag = "1")]
+    pub worst_case: ::core::option::Option<LiquidationPrice>,
+    /// Liquidation price estimate assuming no slippage.
+    #[prost(message, optional, tag = "2")]
+    pub best_case: ::core::option::Option<LiquidationPrice>,
+}
+/// Liquidation price estimate for either only the current open volume and position given some or all buy orders get filled, or position given some or all sell orders get filled.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidationPrice {
+    /// Liquidation price for current open volume ignoring any active orders.
+    #[prost(string, tag = "1")]
+    pub open_volume_only: ::prost::alloc::string::String,
+    /// Liquidation price assuming buy orders start getting filled.
+    #[prost(string, tag = "2")]
+    pub including_buy_orders: ::prost::alloc::string::String,
+    /// Liquidation price assuming sell orders start getting filled.
+    #[prost(string, tag = "3")]
+    pub including_sell_orders: ::prost::alloc::string::String,
+}
+/// Request to get the current referral program
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetCurrentReferralProgramRequest {}
+/// Response containing the current referral program
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetCurrentReferralProgramResponse {
+    /// Referral program currently activated on the network.
+    #[prost(message, optional, tag = "1")]
+    pub current_referral_program: ::core::option::Option<ReferralProgram>,
+}
+/// Referral program details.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReferralProgram {
+    /// Incremental version of the program. It is incremented after each program
+    /// update.
+    #[prost(uint64, tag = "1")]
+    pub version: u64,
+    /// Unique ID generated from the proposal that created this program.
+    #[prost(string, tag = "2")]
+    pub id: ::prost::alloc::string::String,
+    /// Defined tiers in increasing order. First element will give Tier 1, second
+    /// element will give Tier 2, and so on.
+    #[prost(message, repeated, tag = "3")]
+    pub benefit_tiers: ::prost::alloc::vec::Vec<super::super::super::vega::BenefitTier>,
+    /// Timestamp in Unix nanoseconds, after which when the current epoch ends, the
+    /// program will end and benefits will be disabled.
+    #[prost(int64, tag = "4")]
+    pub end_of_program_timestamp: i64,
+    /// Number of epochs over which the referral set's running volume is evaluated.
+    #[prost(uint64, tag = "5")]
+    pub window_length: u64,
+    /// Defined benefit tiers ordered by increasing reward multiplier. Determines the level of
+    /// benefit a party can expect based on their staking.
+    #[prost(message, repeated, tag = "6")]
+    pub staking_tiers: ::prost::alloc::vec::Vec<super::super::super::vega::StakingTier>,
+    /// Timestamp, in Unix nanoseconds, when the program ended.
+    #[prost(int64, optional, tag = "7")]
+    pub ended_at: ::core::option::Option<i64>,
+}
+/// Data relating to a referral set.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReferralSet {
+    /// Unique ID of the created set.
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Party that created the set.
+    #[prost(string, tag = "2")]
+    pub referrer: ::prost::alloc::string::String,
+    /// Timestamp, in Unix nanoseconds, when the set was created.
+    #[prost(int64, tag = "3")]
+    pub created_at: i64,
+    /// Timestamp, in Unix nanoseconds, when the set was updated.
+    #[prost(int64, tag = "4")]
+    pub updated_at: i64,
+    /// Current number of members in the referral set.
+    #[prost(uint64, tag = "5")]
+    pub total_members: u64,
+}
+/// Referral set data with the corresponding cursor.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReferralSetEdge {
+    /// Referral set data.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<ReferralSet>,
+    /// Cursor that can be used to fetch further pages.
+    #[prost(string, tag = "2")]
+    pub cursor: ::prost::alloc::string::String,
+}
+/// Page of referral set data and corresponding page information.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReferralSetConnection {
+    /// Page of referral set data and their corresponding cursors.
+    #[prost(message, repeated, tag = "1")]
+    pub edges: ::prost::alloc::vec::Vec<ReferralSetEdge>,
+    /// Page information that is used for fetching further pages.
+    #[prost(message, optional, tag = "2")]
+    pub page_info: ::core::option::Option<PageInfo>,
+}
+/// Request to retrieve information about a referral set or sets
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListReferralSetsRequest {
+    /// Referral set ID to retrieve information for
+    #[prost(string, optional, tag = "1")]
+    pub referral_set_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Pagination controls.
+    #[prost(message, optional, tag = "2")]
+    pub pagination: ::core::option::Option<Pagination>,
+    /// Referrer to filter by. If referrer set ID is provided, this field is ignored.
+    #[prost(string, optional, tag = "3")]
+    pub referrer: ::core::option::Option<::prost::alloc::string::String>,
+    /// Referee to filter by. If referrer set ID or referrer is provided, this field is ignored.
+    #[prost(string, optional, tag = "4")]
+    pub referee: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// Response containing information about a referral set or sets
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListReferralSetsResponse {
+    /// Page of referral set data and corresponding page information.
+    #[prost(message, optional, tag = "1")]
+    pub referral_sets: ::core::option::Option<ReferralSetConnection>,
+}
+/// Data relating to referees that have joined a referral set
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReferralSetReferee {
+    /// Unique ID of the referral set the referee joined.
+    #[prost(string, tag = "1")]
+    pub referral_set_id: ::prost::alloc::string::String,
+    /// Party that joined the set.
+    #[prost(string, tag = "2")]
+    pub referee: ::prost::alloc::string::String,
+    /// Timestamp, in Unix nanoseconds, when the party joined the set.
+    #[prost(int64, tag = "3")]
+    pub joined_at: i64,
+    /// Epoch at which the party joined the set.
+    #[prost(uint64, tag = "4")]
+    pub at_epoch: u64,
+    /// Total notional volume of the referee's aggressive trades over the aggregation period.
+    #[prost(string, tag = "5")]
+    pub total_referee_notional_taker_volume: ::prost::alloc::string::String,
+    /// Total rewards generated by the referee over the aggregation period.
+    #[prost(string, tag = "6")]
+    pub total_referee_generated_rewards: ::prost::alloc::string::String,
+}
+/// Data about the Referral set's referees with the corresponding cursor.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReferralSetRefereeEdge {
+    /// Referral set referee data.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<ReferralSetReferee>,
+    /// Cursor that can be used to fetch further pages.
+    #[prost(string, tag = "2")]
+    pub cursor: ::prost::alloc::string::String,
+}
+/// Page of data about the referral set's referees and corresponding page information.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReferralSetRefereeConnection {
+    /// Page of referral set referee data and their corresponding cursors.
+    #[prost(message, repeated, tag = "1")]
+    pub edges: ::prost::alloc::vec::Vec<ReferralSetRefereeEdge>,
+    /// Page information that is used for fetching further pages.
+    #[prost(message, optional, tag = "2")]
+    pub page_info: ::core::option::Option<PageInfo>,
+}
+/// Request to retrieve information about a referral set's referees
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListReferralSetRefereesRequest {
+    /// Referral set ID to retrieve information for
+    #[prost(string, optional, tag = "1")]
+    pub referral_set_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Pagination controls.
+    #[prost(message, optional, tag = "2")]
+    pub pagination: ::core::option::Option<Pagination>,
+    /// Referrer to filter by. If referrer set ID is provided, this field is ignored.
+    #[prost(string, optional, tag = "3")]
+    pub referrer: ::core::option::Option<::prost::alloc::string::String>,
+    /// Referee to filter by. If referrer set ID or referrer is provided, this field is ignored.
+    #[prost(string, optional, tag = "4")]
+    pub referee: ::core::option::Option<::prost::alloc::string::String>,
+    /// Epochs to aggregate party volume and rewards over. If omitted, 30 epochs will be used.
+    #[prost(uint32, optional, tag = "5")]
+    pub aggregation_epochs: ::core::option::Option<u32>,
+}
+/// Response containing information about a referral set's referees
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListReferralSetRefereesResponse {
+    /// Page of referral set referee data and corresponding page information.
+    #[prost(message, optional, tag = "1")]
+    pub referral_set_referees: ::core::option::Option<ReferralSetRefereeConnection>,
+}
+/// Request to get a referral set's stats
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetReferralSetStatsRequest {
+    /// Restrict referral set statistics to those for the given referral set.
+    #[prost(string, optional, tag = "1")]
+    pub referral_set_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Epoch to get referral set statistics for. If not set, the last complete epoch is used.
+    #[prost(uint64, optional, tag = "2")]
+    pub at_epoch: ::core::option::Option<u64>,
+    /// Restrict referral set statistics to those for the given referee.
+    #[prost(string, optional, tag = "3")]
+    pub referee: ::core::option::Option<::prost::alloc::string::String>,
+    /// Pagination controls.
+    #[prost(message, optional, tag = "4")]
+    pub pagination: ::core::option::Option<Pagination>,
+}
+/// Response containing the volume discount statistics for the given epoch.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetReferralSetStatsResponse {
+    /// Referral set statistics for the epoch.
+    #[prost(message, optional, tag = "1")]
+    pub stats: ::core::option::Option<ReferralSetStatsConnection>,
+}
+/// Page of volume discount stats data and corresponding page information.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReferralSetStatsConnection {
+    /// Page of volume discount statistics data and their corresponding cursors.
+    #[prost(message, repeated, tag = "1")]
+    pub edges: ::prost::alloc::vec::Vec<ReferralSetStatsEdge>,
+    /// Page information that is used for fetching further pages.
+    #[prost(message, optional, tag = "2")]
+    pub page_info: ::core::option::Option<PageInfo>,
+}
+/// Referral set stats data with the corresponding cursor.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReferralSetStatsEdge {
+    /// Referral set stats data.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<ReferralSetStats>,
+    /// Cursor that can be used to fetch further pages.
+    #[prost(string, tag = "2")]
+    pub cursor: ::prost::alloc::string::String,
+}
+/// Referral set statistics for a given epoch for a party.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReferralSetStats {
+    /// Epoch at which the set's statistics were updated.
+    #[prost(uint64, tag = "1")]
+    pub at_epoch: u64,
+    /// Running volume for the set based on the window length of the current
+    /// referral program.
+    #[prost(string, tag = "2")]
+    pub referral_set_running_notional_taker_volume: ::prost::alloc::string::String,
+    /// Party ID.
+    #[prost(string, tag = "3")]
+    pub party_id: ::prost::alloc::string::String,
+    /// Discount factor applied to the party.
+    #[prost(string, tag = "4")]
+    pub discount_factor: ::prost::alloc::string::String,
+    /// Reward factor applied to the party.
+    #[prost(string, tag = "5")]
+    pub reward_factor: ::prost::alloc::string::String,
+    /// Current referee notional taker volume.
+    #[prost(string, tag = "6")]
+    pub epoch_notional_taker_volume: ::prost::alloc::string::String,
+    /// Multiplier applied to the referral reward factor when calculating referral rewards due to the referrer.
+    #[prost(string, tag = "7")]
+    pub rewards_multiplier: ::prost::alloc::string::String,
+    /// Proportion of the referee's taker fees to be rewarded to the referrer.
+    #[prost(string, tag = "8")]
+    pub rewards_factor_multiplier: ::prost::alloc::string::String,
+    /// Indicates if the referral set was eligible to be part of the referral program.
+    #[prost(bool, tag = "9")]
+    pub was_eligible: bool,
+    /// Referrer's taker volume
+    #[prost(string, tag = "10")]
+    pub referrer_taker_volume: ::prost::alloc::string::String,
+}
+/// Team record containing the team information.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Team {
+    /// ID of the created team.
+    #[prost(string, tag = "1")]
+    pub team_id: ::prost::alloc::string::String,
+    /// Party ID that created the team.
+    #[prost(string, tag = "2")]
+    pub referrer: ::prost::alloc::string::String,
+    /// Name of the team.
+    #[prost(string, tag = "3")]
+    pub name: ::prost::alloc::string::String,
+    /// Link to the team's homepage.
+    #[prost(string, optional, tag = "4")]
+    pub team_url: ::core::option::Option<::prost::alloc::string::String>,
+    /// Link to an image of the team's avatar.
+    #[prost(string, optional, tag = "5")]
+    pub avatar_url: ::core::option::Option<::prost::alloc::string::String>,
+    /// Timestamp in Unix nanoseconds when the team was created.
+    #[prost(int64, tag = "6")]
+    pub created_at: i64,
+    /// Whether or not the team is closed to new party members. When closed, only parties specified in the allow list can
+    /// join the team.
+    #[prost(bool, tag = "7")]
+    pub closed: bool,
+    /// Epoch at which the team was created.
+    #[prost(uint64, tag = "8")]
+    pub created_at_epoch: u64,
+    /// List of public keys that are allowed to join the team.
+    /// Only applicable to closed teams.
+    #[prost(string, repeated, tag = "9")]
+    pub allow_list: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Current number of members in the team.
+    #[prost(uint64, tag = "10")]
+    pub total_members: u64,
+}
+/// Team data item with the corresponding cursor.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TeamEdge {
+    /// Team data.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<Team>,
+    /// Cursor that can be used to fetch further pages.
+    #[prost(string, tag = "2")]
+    pub cursor: ::prost::alloc::string::String,
+}
+/// Page of team data and corresponding page information.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TeamConnection {
+    /// Page of team data and their corresponding cursors.
+    #[prost(message, repeated, tag = "1")]
+    pub edges: ::prost::alloc::vec::Vec<TeamEdge>,
+    /// Page information that is used for fetching further pages.
+    #[prost(message, optional, tag = "2")]
+    pub page_info: ::core::option::Option<PageInfo>,
+}
+/// Request to list all teams
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListTeamsRequest {
+    /// Restrict teams to those with the given team ID.
+    #[prost(string, optional, tag = "1")]
+    pub team_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Restrict teams to those where the given party is a referrer or a referee.
+    #[prost(string, optional, tag = "2")]
+    pub party_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Pagination controls.
+    #[prost(message, optional, tag = "3")]
+    pub pagination: ::core::option::Option<Pagination>,
+}
+/// Response for the list teams request containing the team information.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListTeamsResponse {
+    /// Page of team data and corresponding page information.
+    #[prost(message, optional, tag = "1")]
+    pub teams: ::core::option::Option<TeamConnection>,
+}
+/// Request to list all teams' statistics.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListTeamsStatisticsRequest {
+    /// Restrict team statistics to those with the given team ID.
+    #[prost(string, optional, tag = "1")]
+    pub team_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Defines the number of past epochs to aggregate data from. By default, it takes
+    /// the last 10 epochs.
+    #[prost(uint64, optional, tag = "2")]
+    pub aggregation_epochs: ::core::option::Option<u64>,
+    /// Pagination controls.
+    #[prost(message, optional, tag = "3")]
+    pub pagination: ::core::option::Option<Pagination>,
+}
+/// Response for the list teams' statistics request containing the statistics.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListTeamsStatisticsResponse {
+    /// Page of teams' statistics data and corresponding page information.
+    #[prost(message, optional, tag = "1")]
+    pub statistics: ::core::option::Option<TeamsStatisticsConnection>,
+}
+/// Page of teams' statistics and corresponding page information.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TeamsStatisticsConnection {
+    /// Page of team data and their corresponding cursors.
+    #[prost(message, repeated, tag = "1")]
+    pub edges: ::prost::alloc::vec::Vec<TeamStatisticsEdge>,
+    /// Page information that is used for fetching further pages.
+    #[prost(message, optional, tag = "2")]
+    pub page_info: ::core::option::Option<PageInfo>,
+}
+/// Team data item with the corresponding cursor.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TeamStatisticsEdge {
+    /// Team's statistics data.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<TeamStatistics>,
+    /// Cursor that can be used to fetch further pages.
+    #[prost(string, tag = "2")]
+    pub cursor: ::prost::alloc::string::String,
+}
+/// Team's statistics record containing the team information.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TeamStatistics {
+    /// Team ID the statistics are related to.
+    #[prost(string, tag = "1")]
+    pub team_id: ::prost::alloc::string::String,
+    /// Total of volume accumulated over the requested epoch period, expressed in
+    /// quantum value.
+    #[prost(string, tag = "2")]
+    pub total_quantum_volume: ::prost::alloc::string::String,
+    /// Total of rewards accumulated over the requested epoch period, expressed in
+    /// quantum value.
+    #[prost(string, tag = "3")]
+    pub total_quantum_rewards: ::prost::alloc::string::String,
+    /// List of rewards over the requested epoch period, expressed in quantum
+    /// value for each epoch.
+    #[prost(message, repeated, tag = "4")]
+    pub quantum_rewards: ::prost::alloc::vec::Vec<QuantumRewardsPerEpoch>,
+    /// Total of games played.
+    #[prost(uint64, tag = "5")]
+    pub total_games_played: u64,
+    /// List of games played over the requested epoch period.
+    #[prost(string, repeated, tag = "6")]
+    pub games_played: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// List of volumes over the requested epoch period, expressed in quantum
+    /// value for each epoch.
+    #[prost(message, repeated, tag = "7")]
+    pub quantum_volumes: ::prost::alloc::vec::Vec<QuantumVolumesPerEpoch>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QuantumRewardsPerEpoch {
+    /// Epoch for which this information is valid.
+    #[prost(uint64, tag = "1")]
+    pub epoch: u64,
+    /// Total rewards accumulated over the epoch period, expressed in quantum value.
+    #[prost(string, tag = "2")]
+    pub total_quantum_rewards: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QuantumVolumesPerEpoch {
+    /// Epoch for which this information is valid.
+    #[prost(uint64, tag = "1")]
+    pub epoch: u64,
+    /// Total volumes accumulated over the epoch period, expressed in quantum value.
+    #[prost(string, tag = "2")]
+    pub total_quantum_volumes: ::prost::alloc::string::String,
+}
+/// Request to list all team members' statistics.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListTeamMembersStatisticsRequest {
+    /// Restrict team statistics to those with the given team ID.
+    #[prost(string, tag = "1")]
+    pub team_id: ::prost::alloc::string::String,
+    /// Restrict team members' statistics to those with the given party ID.
+    #[prost(string, optional, tag = "2")]
+    pub party_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Defines the number of past epochs to aggregate data from. By default, it takes
+    /// the last 10 epochs.
+    #[prost(uint64, optional, tag = "3")]
+    pub aggregation_epochs: ::core::option::Option<u64>,
+    /// Pagination controls.
+    #[prost(message, optional, tag = "4")]
+    pub pagination: ::core::option::Option<Pagination>,
+}
+/// Response for the list team members' statistics request containing the statistics.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListTeamMembersStatisticsResponse {
+    /// Page of team members' statistics data and corresponding page information.
+    #[prost(message, optional, tag = "1")]
+    pub statistics: ::core::option::Option<TeamMembersStatisticsConnection>,
+}
+/// Page of team members' statistics and corresponding page information.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TeamMembersStatisticsConnection {
+    /// Page of team member data and their corresponding cursors.
+    #[prost(message, repeated, tag = "1")]
+    pub edges: ::prost::alloc::vec::Vec<TeamMemberStatisticsEdge>,
+    /// Page information that is used for fetching further pages.
+    #[prost(message, optional, tag = "2")]
+    pub page_info: ::core::option::Option<PageInfo>,
+}
+/// Team member data item with the corresponding cursor.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TeamMemberStatisticsEdge {
+    /// Team member's statistics data.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<TeamMemberStatistics>,
+    /// Cursor that can be used to fetch further pages.
+    #[prost(string, tag = "2")]
+    pub cursor: ::prost::alloc::string::String,
+}
+/// Team member's statistics record containing the team member's information.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TeamMemberStatistics {
+    /// Party ID the statistics are related to.
+    #[prost(string, tag = "1")]
+    pub party_id: ::prost::alloc::string::String,
+    /// Total of volume accumulated over the requested epoch period, expressed in
+    /// quantum value.
+    #[prost(string, tag = "2")]
+    pub total_quantum_volume: ::prost::alloc::string::String,
+    /// Total of rewards accumulated over the requested epoch period, expressed in
+    /// quantum value.
+    #[prost(string, tag = "3")]
+    pub total_quantum_rewards: ::prost::alloc::string::String,
+    /// List of rewards over the requested epoch period, expressed in quantum
+    /// value for each epoch.
+    #[prost(message, repeated, tag = "4")]
+    pub quantum_rewards: ::prost::alloc::vec::Vec<QuantumRewardsPerEpoch>,
+    /// Total number of games played.
+    #[prost(uint64, tag = "5")]
+    pub total_games_played: u64,
+    /// List of games played over the requested epoch period.
+    #[prost(string, repeated, tag = "6")]
+    pub games_played: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// List of volumes over the requested epoch period, expressed in quantum
+    /// value for each epoch.
+    #[prost(message, repeated, tag = "7")]
+    pub quantum_volumes: ::prost::alloc::vec::Vec<QuantumVolumesPerEpoch>,
+}
+/// Request that is sent when listing the referees for a given team.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListTeamRefereesRequest {
+    /// Team ID to list referees for.
+    #[prost(string, tag = "1")]
+    pub team_id: ::prost::alloc::string::String,
+    /// Pagination controls.
+    #[prost(message, optional, tag = "2")]
+    pub pagination: ::core::option::Option<Pagination>,
+}
+/// A team's referee info
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TeamReferee {
+    /// ID of the team the referee joined.
+    #[prost(string, tag = "1")]
+    pub team_id: ::prost::alloc::string::String,
+    /// Party that joined the team.
+    #[prost(string, tag = "2")]
+    pub referee: ::prost::alloc::string::String,
+    /// Timestamp in Unix nanoseconds when the party joined the team.
+    #[prost(int64, tag = "3")]
+    pub joined_at: i64,
+    /// Epoch at which the party joined the team.
+    #[prost(uint64, tag = "4")]
+    pub joined_at_epoch: u64,
+}
+/// Team referee data item with the corresponding cursor.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TeamRefereeEdge {
+    /// Team referee data.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<TeamReferee>,
+    /// Cursor that can be used to fetch further pages.
+    #[prost(string, tag = "2")]
+    pub cursor: ::prost::alloc::string::String,
+}
+/// Page of team referee data and corresponding page information.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TeamRefereeConnection {
+    /// Page of team referee data and their corresponding cursors.
+    #[prost(message, repeated, tag = "1")]
+    pub edges: ::prost::alloc::vec::Vec<TeamRefereeEdge>,
+    /// Page information that is used for fetching further pages.
+    #[prost(message, optional, tag = "2")]
+    pub page_info: ::core::option::Option<PageInfo>,
+}
+/// Response that is sent when listing the referees for a given team.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListTeamRefereesResponse {
+    /// Page of team referee data and corresponding page information.
+    #[prost(message, optional, tag = "1")]
+    pub team_referees: ::core::option::Option<TeamRefereeConnection>,
+}
+/// A referee's team change information
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TeamRefereeHistory {
+    /// ID of the team the referee joined.
+    #[prost(string, tag = "1")]
+    pub team_id: ::prost::alloc::string::String,
+    /// Timestamp in Unix nanoseconds when the party joined the team.
+    #[prost(int64, tag = "2")]
+    pub joined_at: i64,
+    /// Epoch at which the party joined the team.
+    #[prost(uint64, tag = "3")]
+    pub joined_at_epoch: u64,
+}
+/// Team referee history data item with the corresponding cursor.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TeamRefereeHistoryEdge {
+    /// Team referee data.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<TeamRefereeHistory>,
+    /// Cursor that can be used to fetch further pages.
+    #[prost(string, tag = "2")]
+    pub cursor: ::prost::alloc::string::String,
+}
+/// Page of history data about a referee's team membership and corresponding page information.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TeamRefereeHistoryConnection {
+    /// Page of team referee history data and their corresponding cursors.
+    #[prost(message, repeated, tag = "1")]
+    pub edges: ::prost::alloc::vec::Vec<TeamRefereeHistoryEdge>,
+    /// Page information that is used for fetching further pages.
+    #[prost(message, optional, tag = "2")]
+    pub page_info: ::core::option::Option<PageInfo>,
+}
+/// Request that is sent when listing the referee history for a given team.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListTeamRefereeHistoryRequest {
+    /// Party ID to list referee history for.
+    #[prost(string, tag = "1")]
+    pub referee: ::prost::alloc::string::String,
+    /// Pagination controls.
+    #[prost(message, optional, tag = "2")]
+    pub pagination: ::core::option::Option<Pagination>,
+}
+/// Response that is sent when listing the referee history for a given team.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListTeamRefereeHistoryResponse {
+    /// Page of team referee history data and corresponding page information.
+    #[prost(message, optional, tag = "1")]
+    pub team_referee_history: ::core::option::Option<TeamRefereeHistoryConnection>,
+}
+/// Request that is sent to retrieve fees statistics.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetFeesStatsRequest {
+    /// Restrict fee statistics to those related to the given market.
+    #[prost(string, optional, tag = "1")]
+    pub market_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Restrict fee statistics to those related to the given asset.
+    #[prost(string, optional, tag = "2")]
+    pub asset_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Epoch to get referral fee statistics for. If not set, the last complete epoch is used.
+    #[prost(uint64, optional, tag = "3")]
+    pub epoch_seq: ::core::option::Option<u64>,
+    /// Restrict fee statistics to those for the given party.
+    #[prost(string, optional, tag = "4")]
+    pub party_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Restrict fee statistics to epoch range starting with epoch from.
+    #[prost(uint64, optional, tag = "5")]
+    pub epoch_from: ::core::option::Option<u64>,
+    /// Restrict fee statistics to epoch range ending with epoch to.
+    #[prost(uint64, optional, tag = "6")]
+    pub epoch_to: ::core::option::Option<u64>,
+}
+/// Response that is sent when requesting fees statistics.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetFeesStatsResponse {
+    /// Fees statistics for the given request.
+    #[prost(message, optional, tag = "1")]
+    pub fees_stats: ::core::option::Option<
+        super::super::super::vega::events::v1::FeesStats,
+    >,
+}
+/// Request that is sent to retrieve fees statistics for a given party.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetFeesStatsForPartyRequest {
+    /// Restrict fees statistics to those for the given party.
+    #[prost(string, tag = "1")]
+    pub party_id: ::prost::alloc::string::String,
+    /// Restrict fees statistics to those related to the given asset.
+    #[prost(string, optional, tag = "2")]
+    pub asset_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Epoch to filter from (included). If omitted, the range goes from the oldest epoch to the `to epoch`.
+    #[prost(uint64, optional, tag = "3")]
+    pub from_epoch: ::core::option::Option<u64>,
+    /// Epoch to filter to (included). If omitted, the range goes from `from epoch` to the most recent epoch.
+    #[prost(uint64, optional, tag = "4")]
+    pub to_epoch: ::core::option::Option<u64>,
+}
+/// Response that is sent when requesting fees statistics for a given party.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetFeesStatsForPartyResponse {
+    /// Fees statistics for the given request.
+    #[prost(message, repeated, tag = "1")]
+    pub fees_stats_for_party: ::prost::alloc::vec::Vec<FeesStatsForParty>,
+}
+/// Request to get the current referral program
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetCurrentVolumeDiscountProgramRequest {}
+/// Response containing the current referral program
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetCurrentVolumeDiscountProgramResponse {
+    /// Volume discount program currently activated on the network.
+    #[prost(message, optional, tag = "1")]
+    pub current_volume_discount_program: ::core::option::Option<VolumeDiscountProgram>,
+}
+/// Request to get the volume discount statistics for a given epoch.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetVolumeDiscountStatsRequest {
+    /// Epoch to get volume discount statistics for. If not set, last epoch is used.
+    #[prost(uint64, optional, tag = "1")]
+    pub at_epoch: ::core::option::Option<u64>,
+    /// Restrict volume discount statistics to those for the given party.
+    #[prost(string, optional, tag = "2")]
+    pub party_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Pagination controls.
+    #[prost(message, optional, tag = "3")]
+    pub pagination: ::core::option::Option<Pagination>,
+}
+/// Response containing the volume discount statistics for the given epoch.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetVolumeDiscountStatsResponse {
+    /// Volume discount statistics for an epoch.
+    #[prost(message, optional, tag = "1")]
+    pub stats: ::core::option::Option<VolumeDiscountStatsConnection>,
+}
+/// Page of volume discount stats data and corresponding page information.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VolumeDiscountStatsConnection {
+    /// Page of volume discount statistics data and their corresponding cursors.
+    #[prost(message, repeated, tag = "1")]
+    pub edges: ::prost::alloc::vec::Vec<VolumeDiscountStatsEdge>,
+    /// Page information that is used for fetching further pages.
+    #[prost(message, optional, tag = "2")]
+    pub page_info: ::core::option::Option<PageInfo>,
+}
+/// Volume discount stats data with the corresponding cursor.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VolumeDiscountStatsEdge {
+    /// Volume discount stats data.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<VolumeDiscountStats>,
+    /// Cursor that can be used to fetch further pages.
+    #[prost(string, tag = "2")]
+    pub cursor: ::prost::alloc::string::String,
+}
+/// Volume discount statistics for a given epoch for all parties.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VolumeDiscountStats {
+    /// Epoch at which the statistics apply.
+    #[prost(uint64, tag = "1")]
+    pub at_epoch: u64,
+    /// Party ID for which the statistics apply.
+    #[prost(string, tag = "2")]
+    pub party_id: ::prost::alloc::string::String,
+    /// Discount factor applied given the party's running volume.
+    #[prost(string, tag = "3")]
+    pub discount_factor: ::prost::alloc::string::String,
+    /// The party's running volume.
+    #[prost(string, tag = "4")]
+    pub running_volume: ::prost::alloc::string::String,
+}
+/// Volume discount program details.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VolumeDiscountProgram {
+    /// Incremental version of the program. It is incremented after each program
+    /// update.
+    #[prost(uint64, tag = "1")]
+    pub version: u64,
+    /// Unique ID generated from the proposal that created this program.
+    #[prost(string, tag = "2")]
+    pub id: ::prost::alloc::string::String,
+    /// Defined benefit tiers ordered by increasing discounts. Determines the level of
+    /// benefit a party can expect based on performance criteria.
+    #[prost(message, repeated, tag = "3")]
+    pub benefit_tiers: ::prost::alloc::vec::Vec<
+        super::super::super::vega::VolumeBenefitTier,
+    >,
+    /// Timestamp in Unix nanoseconds, after which when the current epoch
+    /// ends, the program will end and benefits will be disabled.
+    #[prost(int64, tag = "4")]
+    pub end_of_program_timestamp: i64,
+    /// Number of epochs over which a volume discount statistics' running volume is evaluated.
+    #[prost(uint64, tag = "5")]
+    pub window_length: u64,
+    /// Timestamp in Unix nanoseconds, at which the program ended.
+    #[prost(int64, optional, tag = "6")]
+    pub ended_at: ::core::option::Option<i64>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FeesStatsForParty {
+    /// Settlement asset of the market.
+    #[prost(string, tag = "1")]
+    pub asset_id: ::prost::alloc::string::String,
+    /// Total referral rewards received by referrer of the referral set.
+    #[prost(string, tag = "2")]
+    pub total_rewards_received: ::prost::alloc::string::String,
+    /// Total referral discounts applied to referee fees.
+    #[prost(string, tag = "3")]
+    pub referees_discount_applied: ::prost::alloc::string::String,
+    /// Total volume discounts applied to referee fees.
+    #[prost(string, tag = "4")]
+    pub volume_discount_applied: ::prost::alloc::string::String,
+    /// Total maker fees received by the maker side.
+    #[prost(string, tag = "5")]
+    pub total_maker_fees_received: ::prost::alloc::string::String,
+}
+/// Request to subscribe to a stream of users' transaction results
+///
+/// Request fields party ID, hash and status are all optional filters:
+/// If omitted all transaction results, for all parties with all hashes and states will be returned on the stream
+/// If party ID is given, transaction results from that party will be returned on the stream
+/// If hash is given, transaction result with that hash will be returned on the stream
+/// If status is given, transaction result with that status (true/false - success failure) will be returned on the stream
+/// All filters can be combined.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ObserveTransactionResultsRequest {
+    /// Restrict the transaction results streamed to those made by the given parties.
+    #[prost(string, repeated, tag = "1")]
+    pub party_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Restrict the transaction results streamed to those with given hashes.
+    #[prost(string, repeated, tag = "2")]
+    pub hashes: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Restrict the transaction results streamed to those with given status true/false (success/failure).
+    #[prost(bool, optional, tag = "3")]
+    pub status: ::core::option::Option<bool>,
+}
+/// Stream of transaction results
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ObserveTransactionResultsResponse {
+    /// List of 0 or more transaction results.
+    #[prost(message, repeated, tag = "1")]
+    pub transaction_results: ::prost::alloc::vec::Vec<
+        super::super::super::vega::events::v1::TransactionResult,
+    >,
+}
+/// Request an estimation of transfer fee and discount
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EstimateTransferFeeRequest {
+    /// Sender's ID.
+    #[prost(string, tag = "1")]
+    pub from_account: ::prost::alloc::string::String,
+    /// Type of account sent from.
+    #[prost(enumeration = "super::super::super::vega::AccountType", tag = "2")]
+    pub from_account_type: i32,
+    /// Receiver's ID.
+    #[prost(string, tag = "3")]
+    pub to_account: ::prost::alloc::string::String,
+    /// Amount to be transferred.
+    #[prost(string, tag = "4")]
+    pub amount: ::prost::alloc::string::String,
+    /// Asset ID for the asset associated with the transaction.
+    #[prost(string, tag = "5")]
+    pub asset_id: ::prost::alloc::string::String,
+    /// AMM public key, if assets are being transferred from an AMM key to the AMM owner's public key.
+    /// The `from_account` must be the owner of this AMM key.
+    #[prost(string, optional, tag = "6")]
+    pub from_amm_key: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// Results of estimation of transfer fee and the fee discount
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EstimateTransferFeeResponse {
+    /// Estimated fee for the transfer.
+    #[prost(string, tag = "1")]
+    pub fee: ::prost::alloc::string::String,
+    /// Discount applied to the fee.
+    #[prost(string, tag = "2")]
+    pub discount: ::prost::alloc::string::String,
+}
+/// Get total transfer fee discount available
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetTotalTransferFeeDiscountRequest {
+    /// ID of party eligible for the discount.
+    #[prost(string, tag = "1")]
+    pub party_id: ::prost::alloc::string::String,
+    /// ID of asset associated with the discount.
+    #[prost(string, tag = "5")]
+    pub asset_id: ::prost::alloc::string::String,
+}
+/// Returns total transfer fee discount available
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetTotalTransferFeeDiscountResponse {
+    /// Total per party per asset discount available.
+    #[prost(string, tag = "2")]
+    pub total_discount: ::prost::alloc::string::String,
+}
+/// Request to retrieve games data.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListGamesRequest {
+    /// Game ID to filter for.
+    #[prost(string, optional, tag = "1")]
+    pub game_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Oldest epoch to retrieve game data from, inclusive. The maximum range of epochs that can be fetched at once is 30.
+    /// If not provided, defaults to the 'to' epoch minus 29, or the first epoch available if not enough epochs have passed.
+    /// If the 'to' epoch is not specified, it will be set to the chosen 'from' epoch + 29.
+    #[prost(uint64, optional, tag = "2")]
+    pub epoch_from: ::core::option::Option<u64>,
+    /// Most recent epoch to retrieve game data to, inclusive. If not provided, defaults to the latest epoch. The maximum range of epochs that can be fetched at once is 30.
+    /// If no 'from' epoch is provided, or the 'from' epoch is out of range, i.e., the 'to' epoch - 'from' epoch > 29,
+    /// the 'from' epoch will be set to the 'to' epoch - 29.
+    #[prost(uint64, optional, tag = "3")]
+    pub epoch_to: ::core::option::Option<u64>,
+    /// Entity scope to filter games for, i.e. team games or individual games only
+    #[prost(enumeration = "super::super::super::vega::EntityScope", optional, tag = "4")]
+    pub entity_scope: ::core::option::Option<i32>,
+    /// Pagination controls.
+    #[prost(message, optional, tag = "5")]
+    pub pagination: ::core::option::Option<Pagination>,
+    /// Team ID to filter for. This filter will only be applied if entity scope is not specified
+    /// in the request, or the entity scope is set to teams.
+    #[prost(string, optional, tag = "6")]
+    pub team_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Party ID to filter for. This filter will apply regardless of the entity scope.
+    /// If the entity scope filter is teams, then the party ID filter will apply to team members.
+    #[prost(string, optional, tag = "7")]
+    pub party_id: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// Response containing games data.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListGamesResponse {
+    /// Page of games data and corresponding page information.
+    #[prost(message, optional, tag = "1")]
+    pub games: ::core::option::Option<GamesConnection>,
+}
+/// Page of games data and corresponding page information.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GamesConnection {
+    /// Page of games data and their corresponding cursors.
+    #[prost(message, repeated, tag = "1")]
+    pub edges: ::prost::alloc::vec::Vec<GameEdge>,
+    /// Page information that is used for fetching further pages.
+    #[prost(message, optional, tag = "2")]
+    pub page_info: ::core::option::Option<PageInfo>,
+}
+/// Game information and corresponding cursor.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GameEdge {
+    /// Game data.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<Game>,
+    /// Cursor that can be used to fetch further pages.
+    #[prost(string, tag = "2")]
+    pub cursor: ::prost::alloc::string::String,
+}
+/// Data relating to a game.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Game {
+    /// Unique ID of the game
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Epoch at which the last game metrics were captured.
+    #[prost(uint64, tag = "2")]
+    pub epoch: u64,
+    /// The number of participants in the game.
+    #[prost(uint64, tag = "3")]
+    pub participants: u64,
+    /// ID of the asset rewarded for involvement in the game.
+    #[prost(string, tag = "6")]
+    pub reward_asset_id: ::prost::alloc::string::String,
+    /// Entities participating in a game and their corresponding metrics.
+    #[prost(oneof = "game::Entities", tags = "4, 5")]
+    pub entities: ::core::option::Option<game::Entities>,
+}
+/// Nested message and enum types in `Game`.
+pub mod game {
+    /// Entities participating in a game and their corresponding metrics.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Entities {
+        /// Team entities that are participating in the game
+        #[prost(message, tag = "4")]
+        Team(super::TeamGameEntities),
+        /// Individual entities that are participating in the game
+        #[prost(message, tag = "5")]
+        Individual(super::IndividualGameEntities),
+    }
+}
+/// List of teams that are participating in a game
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TeamGameEntities {
+    /// List of teams, the participant members of a team, and the metrics associated with each participant.
+    #[prost(message, repeated, tag = "1")]
+    pub team: ::prost::alloc::vec::Vec<TeamGameEntity>,
+}
+/// List of individuals that are participating in a game
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IndividualGameEntities {
+    /// List of individual party IDs, and the metrics associated with each participant.
+    #[prost(message, repeated, tag = "1")]
+    pub individual: ::prost::alloc::vec::Vec<IndividualGameEntity>,
+}
+/// Data relating to a team participating in a game.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TeamGameEntity {
+    /// Team participation breakdown
+    #[prost(message, optional, tag = "1")]
+    pub team: ::core::option::Option<TeamGameParticipation>,
+    /// Rank of the team in the game
+    #[prost(uint64, tag = "2")]
+    pub rank: u64,
+    /// Volume traded by the team
+    #[prost(string, tag = "3")]
+    pub volume: ::prost::alloc::string::String,
+    /// Reward metric applied to the team
+    #[prost(enumeration = "super::super::super::vega::DispatchMetric", tag = "4")]
+    pub reward_metric: i32,
+    /// Reward earned by the team
+    #[prost(string, tag = "5")]
+    pub reward_earned: ::prost::alloc::string::String,
+    /// Total rewards earned by the individual for the team
+    #[prost(string, tag = "6")]
+    pub total_rewards_earned: ::prost::alloc::string::String,
+    /// Rewards earned by the team in quantum value
+    #[prost(string, tag = "7")]
+    pub reward_earned_quantum: ::prost::alloc::string::String,
+    /// Total rewards earned by the team in quantum value
+    #[prost(string, tag = "8")]
+    pub total_rewards_earned_quantum: ::prost::alloc::string::String,
+}
+/// Breakdown of a team's participation in a game.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TeamGameParticipation {
+    /// ID of the team participating
+    #[prost(string, tag = "1")]
+    pub team_id: ::prost::alloc::string::String,
+    /// Individual team member metrics for their participation in the game
+    #[prost(message, repeated, tag = "2")]
+    pub members_participating: ::prost::alloc::vec::Vec<IndividualGameEntity>,
+}
+/// Data relating to an individual participating in a game.
+/// This can be used for both team members who are participating in a team game,
+/// or individuals participating in an individual entity game.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IndividualGameEntity {
+    /// Party ID of the individual participating
+    #[prost(string, tag = "1")]
+    pub individual: ::prost::alloc::string::String,
+    /// Rank of the individual either in the game or within their team.
+    #[prost(uint64, tag = "2")]
+    pub rank: u64,
+    /// Volume traded by the individual
+    #[prost(string, tag = "3")]
+    pub volume: ::prost::alloc::string::String,
+    /// Reward metric applied to the individual
+    #[prost(enumeration = "super::super::super::vega::DispatchMetric", tag = "4")]
+    pub reward_metric: i32,
+    /// Reward earned by the individual during the epoch
+    #[prost(string, tag = "5")]
+    pub reward_earned: ::prost::alloc::string::String,
+    /// Total rewards earned by the individual for the game
+    #[prost(string, tag = "6")]
+    pub total_rewards_earned: ::prost::alloc::string::String,
+    /// Rewards earned by the individual in quantum value
+    #[prost(string, tag = "7")]
+    pub reward_earned_quantum: ::prost::alloc::string::String,
+    /// Total rewards earned by the individual in quantum value
+    #[prost(string, tag = "8")]
+    pub total_rewards_earned_quantum: ::prost::alloc::string::String,
+}
+/// Request to retrieve party margin modes.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListPartyMarginModesRequest {
+    /// Market ID to filter for.
+    #[prost(string, optional, tag = "1")]
+    pub market_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Party ID to filter for.
+    #[prost(string, optional, tag = "2")]
+    pub party_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Pagination controls.
+    #[prost(message, optional, tag = "5")]
+    pub pagination: ::core::option::Option<Pagination>,
+}
+/// Response containing party margin modes.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListPartyMarginModesResponse {
+    /// Page of party margin modes data and corresponding page information.
+    #[prost(message, optional, tag = "1")]
+    pub party_margin_modes: ::core::option::Option<PartyMarginModesConnection>,
+}
+/// Page of party margin modes data and corresponding page information.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PartyMarginModesConnection {
+    /// Page of party margin modes data and their corresponding cursors.
+    #[prost(message, repeated, tag = "1")]
+    pub edges: ::prost::alloc::vec::Vec<PartyMarginModeEdge>,
+    /// Page information that is used for fetching further pages.
+    #[prost(message, optional, tag = "2")]
+    pub page_info: ::core::option::Option<PageInfo>,
+}
+/// Party margin mode information and corresponding cursor.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PartyMarginModeEdge {
+    /// Party margin mode data.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<PartyMarginMode>,
+    /// Cursor that can be used to fetch further pages.
+    #[prost(string, tag = "2")]
+    pub cursor: ::prost::alloc::string::String,
+}
+/// Margin mode selected for the given party and market.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PartyMarginMode {
+    /// Unique ID of the market.
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+    /// Unique ID of the party.
+    #[prost(string, tag = "2")]
+    pub party_id: ::prost::alloc::string::String,
+    /// Selected margin mode.
+    #[prost(enumeration = "super::super::super::vega::MarginMode", tag = "3")]
+    pub margin_mode: i32,
+    /// Margin factor for the market. Isolated mode only.
+    #[prost(string, optional, tag = "4")]
+    pub margin_factor: ::core::option::Option<::prost::alloc::string::String>,
+    /// Minimum theoretical margin factor for the market. Isolated mode only.
+    #[prost(string, optional, tag = "5")]
+    pub min_theoretical_margin_factor: ::core::option::Option<
+        ::prost::alloc::string::String,
+    >,
+    /// Maximum theoretical leverage for the market. Isolated mode only.
+    #[prost(string, optional, tag = "6")]
+    pub max_theoretical_leverage: ::core::option::Option<::prost::alloc::string::String>,
+    /// Epoch at which the update happened.
+    #[prost(uint64, tag = "7")]
+    pub at_epoch: u64,
+}
+/// Time weighted notional position for a party and asset at a given epoch. It is used as a metric to calculate whether
+/// the party is eligible for receiving a reward.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TimeWeightedNotionalPosition {
+    /// Asset ID for the settlement asset associated with the position.
+    #[prost(string, tag = "1")]
+    pub asset_id: ::prost::alloc::string::String,
+    /// Party ID for the party associated with the position.
+    #[prost(string, tag = "2")]
+    pub party_id: ::prost::alloc::string::String,
+    /// Game ID for the game associated with the position.
+    #[prost(string, tag = "3")]
+    pub game_id: ::prost::alloc::string::String,
+    /// Epoch at which the time weighted notional position is calculated.
+    #[prost(uint64, tag = "4")]
+    pub at_epoch: u64,
+    /// Time weighted notional position for the party and asset.
+    #[prost(string, tag = "5")]
+    pub time_weighted_notional_position: ::prost::alloc::string::String,
+    /// Vega time of the block in which the last position update occurred.
+    #[prost(int64, tag = "6")]
+    pub last_updated: i64,
+}
+/// Request to retrieve the time weighted notional position for a party and asset.
+/// If no epoch is given, the time weighted notional position at the end of the most recently completed epoch will be returned.
+/// If an epoch is given, the time weighted notional position achieved by the end of the given epoch will be returned.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetTimeWeightedNotionalPositionRequest {
+    /// Asset ID to filter for.
+    #[prost(string, tag = "1")]
+    pub asset_id: ::prost::alloc::string::String,
+    /// Party ID to filter for.
+    #[prost(string, tag = "2")]
+    pub party_id: ::prost::alloc::string::String,
+    /// Game ID to filter for.
+    #[prost(string, tag = "3")]
+    pub game_id: ::prost::alloc::string::String,
+    /// Epoch to filter for.
+    #[prost(uint64, optional, tag = "4")]
+    pub at_epoch: ::core::option::Option<u64>,
+}
+/// Response containing the time weighted notional position for a party and asset.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetTimeWeightedNotionalPositionResponse {
+    /// Time weighted notional position for the party and asset.
+    #[prost(message, optional, tag = "1")]
+    pub time_weighted_notional_position: ::core::option::Option<
+        TimeWeightedNotionalPosition,
+    >,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListAmMsRequest {
+    /// AMM ID to filter for. If party ID or market ID is provided, the ID filter is ignored.
+    #[prost(string, optional, tag = "1")]
+    pub id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Party ID to filter for.
+    #[prost(string, optional, tag = "2")]
+    pub party_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Market ID to filter for. If party ID is provided, the market filter is ignored
+    #[prost(string, optional, tag = "3")]
+    pub market_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Party's underlying AMM sub-account to filter for. A party's AMM sub-account will remain the
+    /// same regardless of the AMM.
+    #[prost(string, optional, tag = "4")]
+    pub amm_party_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Filter for AMMs with the given status. This filter will be ignored if any of the other
+    /// filters have been set.
+    #[prost(
+        enumeration = "super::super::super::vega::events::v1::amm::Status",
+        optional,
+        tag = "5"
+    )]
+    pub status: ::core::option::Option<i32>,
+    /// Pagination controls.
+    #[prost(message, optional, tag = "6")]
+    pub pagination: ::core::option::Option<Pagination>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListAmMsResponse {
+    /// Page of AMM data and corresponding page information.
+    #[prost(message, optional, tag = "1")]
+    pub amms: ::core::option::Option<AmmConnection>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AmmConnection {
+    /// Page of AMM data and the corresponding cursors.
+    #[prost(message, repeated, tag = "1")]
+    pub edges: ::prost::alloc::vec::Vec<AmmEdge>,
+    /// Page information that is used for fetching further pages.
+    #[prost(message, optional, tag = "2")]
+    pub page_info: ::core::option::Option<PageInfo>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AmmEdge {
+    /// AMM pool data.
+    #[prost(message, optional, tag = "1")]
+    pub node: ::core::option::Option<super::super::super::vega::events::v1::Amm>,
+    /// Cursor that can be used to fetch further pages.
+    #[prost(string, tag = "2")]
+    pub cursor: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EstimateAmmBoundsRequest {
+    /// Base price of the AMM pool, the price is an integer, for example `123456` is a correctly
+    /// formatted price of `1.23456` assuming market configured to 5 decimal places.
+    #[prost(string, tag = "1")]
+    pub base_price: ::prost::alloc::string::String,
+    /// Upper price of the AMM pool, the price is an integer, for example `123456` is a correctly
+    /// formatted price of `1.23456` assuming market configured to 5 decimal places.
+    #[prost(string, optional, tag = "2")]
+    pub upper_price: ::core::option::Option<::prost::alloc::string::String>,
+    /// Lower price of the AMM pool, the price is an integer, for example `123456` is a correctly
+    /// formatted price of `1.23456` assuming market configured to 5 decimal places.
+    #[prost(string, optional, tag = "3")]
+    pub lower_price: ::core::option::Option<::prost::alloc::string::String>,
+    /// Leverage at the upper price of the AMM pool.
+    #[prost(string, optional, tag = "4")]
+    pub leverage_at_upper_price: ::core::option::Option<::prost::alloc::string::String>,
+    /// Leverage at the lower price of the AMM pool.
+    #[prost(string, optional, tag = "5")]
+    pub leverage_at_lower_price: ::core::option::Option<::prost::alloc::string::String>,
+    /// Amount of the asset that the party is willing to commit to the AMM pool.
+    #[prost(string, tag = "6")]
+    pub commitment_amount: ::prost::alloc::string::String,
+    /// Market ID to estimate the AMM for.
+    #[prost(string, tag = "7")]
+    pub market_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EstimateAmmBoundsResponse {
+    /// Theoretical volume at the top of the upper bound.
+    #[prost(string, tag = "1")]
+    pub position_size_at_upper: ::prost::alloc::string::String,
+    /// Theoretical volume at the top of the lower bound.
+    #[prost(string, tag = "2")]
+    pub position_size_at_lower: ::prost::alloc::string::String,
+    /// Loss of commitment at the upper bound.
+    #[prost(string, tag = "3")]
+    pub loss_on_commitment_at_upper: ::prost::alloc::string::String,
+    /// Loss of commitment at the lower bound.
+    #[prost(string, tag = "4")]
+    pub loss_on_commitment_at_lower: ::prost::alloc::string::String,
+    /// Estimated price above upper bound at which the commitment will be lost.
+    #[prost(string, tag = "5")]
+    pub liquidation_price_at_upper: ::prost::alloc::string::String,
+    /// Estimated price below the lower bound at which the commitment will be lost.
+    #[prost(string, tag = "6")]
+    pub liquidation_price_at_lower: ::prost::alloc::string::String,
+}
+/// List of fields for a ledger entry
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum LedgerEntryField {
+    Unspecified = 0,
+    AccountFromId = 1,
+    AccountToId = 2,
+    TransferType = 3,
+}
+impl LedgerEntryField {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            LedgerEntryField::Unspecified => "LEDGER_ENTRY_FIELD_UNSPECIFIED",
+            LedgerEntryField::AccountFromId => "LEDGER_ENTRY_FIELD_ACCOUNT_FROM_ID",
+            LedgerEntryField::AccountToId => "LEDGER_ENTRY_FIELD_ACCOUNT_TO_ID",
+            LedgerEntryField::TransferType => "LEDGER_ENTRY_FIELD_TRANSFER_TYPE",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "LEDGER_ENTRY_FIELD_UNSPECIFIED" => Some(Self::Unspecified),
+            "LEDGER_ENTRY_FIELD_ACCOUNT_FROM_ID" => Some(Self::AccountFromId),
+            "LEDGER_ENTRY_FIELD_ACCOUNT_TO_ID" => Some(Self::AccountToId),
+            "LEDGER_ENTRY_FIELD_TRANSFER_TYPE" => Some(Self::TransferType),
+            _ => None,
+        }
+    }
+}
+/// List of the properties of an account, used for grouping
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum AccountField {
+    Unspecified = 0,
+    Id = 1,
+    PartyId = 2,
+    AssetId = 3,
+    MarketId = 4,
+    Type = 5,
+}
+impl AccountField {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            AccountField::Unspecified => "ACCOUNT_FIELD_UNSPECIFIED",
+            AccountField::Id => "ACCOUNT_FIELD_ID",
+            AccountField::PartyId => "ACCOUNT_FIELD_PARTY_ID",
+            AccountField::AssetId => "ACCOUNT_FIELD_ASSET_ID",
+            AccountField::MarketId => "ACCOUNT_FIELD_MARKET_ID",
+            AccountField::Type => "ACCOUNT_FIELD_TYPE",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "ACCOUNT_FIELD_UNSPECIFIED" => Some(Self::Unspecified),
+            "ACCOUNT_FIELD_ID" => Some(Self::Id),
+            "ACCOUNT_FIELD_PARTY_ID" => Some(Self::PartyId),
+            "ACCOUNT_FIELD_ASSET_ID" => Some(Self::AssetId),
+            "ACCOUNT_FIELD_MARKET_ID" => Some(Self::MarketId),
+            "ACCOUNT_FIELD_TYPE" => Some(Self::Type),
+            _ => None,
+        }
+    }
+}
+/// Direction of a transfer
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum TransferDirection {
+    Unspecified = 0,
+    TransferFrom = 1,
+    TransferTo = 2,
+    TransferToOrFrom = 3,
+}
+impl TransferDirection {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            TransferDirection::Unspecified => "TRANSFER_DIRECTION_UNSPECIFIED",
+            TransferDirection::TransferFrom => "TRANSFER_DIRECTION_TRANSFER_FROM",
+            TransferDirection::TransferTo => "TRANSFER_DIRECTION_TRANSFER_TO",
+            TransferDirection::TransferToOrFrom => {
+                "TRANSFER_DIRECTION_TRANSFER_TO_OR_FROM"
+            }
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "TRANSFER_DIRECTION_UNSPECIFIED" => Some(Self::Unspecified),
+            "TRANSFER_DIRECTION_TRANSFER_FROM" => Some(Self::TransferFrom),
+            "TRANSFER_DIRECTION_TRANSFER_TO" => Some(Self::TransferTo),
+            "TRANSFER_DIRECTION_TRANSFER_TO_OR_FROM" => Some(Self::TransferToOrFrom),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum Table {
+    Unspecified = 0,
+    Balances = 1,
+    Checkpoints = 2,
+    Delegations = 3,
+    Ledger = 4,
+    Orders = 5,
+    Trades = 6,
+    MarketData = 7,
+    MarginLevels = 8,
+    Positions = 9,
+    LiquidityProvisions = 10,
+    Markets = 11,
+    Deposits = 12,
+    Withdrawals = 13,
+    Blocks = 14,
+    Rewards = 15,
+}
+impl Table {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Table::Unspecified => "TABLE_UNSPECIFIED",
+            Table::Balances => "TABLE_BALANCES",
+            Table::Checkpoints => "TABLE_CHECKPOINTS",
+            Table::Delegations => "TABLE_DELEGATIONS",
+            Table::Ledger => "TABLE_LEDGER",
+            Table::Orders => "TABLE_ORDERS",
+            Table::Trades => "TABLE_TRADES",
+            Table::MarketData => "TABLE_MARKET_DATA",
+            Table::MarginLevels => "TABLE_MARGIN_LEVELS",
+            Table::Positions => "TABLE_POSITIONS",
+            Table::LiquidityProvisions => "TABLE_LIQUIDITY_PROVISIONS",
+            Table::Markets => "TABLE_MARKETS",
+            Table::Deposits => "TABLE_DEPOSITS",
+            Table::Withdrawals => "TABLE_WITHDRAWALS",
+            Table::Blocks => "TABLE_BLOCKS",
+            Table::Rewards => "TABLE_REWARDS",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "TABLE_UNSPECIFIED" => Some(Self::Unspecified),
+            "TABLE_BALANCES" => Some(Self::Balances),
+            "TABLE_CHECKPOINTS" => Some(Self::Checkpoints),
+            "TABLE_DELEGATIONS" => Some(Self::Delegations),
+            "TABLE_LEDGER" => Some(Self::Ledger),
+            "TABLE_ORDERS" => Some(Self::Orders),
+            "TABLE_TRADES" => Some(Self::Trades),
+            "TABLE_MARKET_DATA" => Some(Self::MarketData),
+            "TABLE_MARGIN_LEVELS" => Some(Self::MarginLevels),
+            "TABLE_POSITIONS" => Some(Self::Positions),
+            "TABLE_LIQUIDITY_PROVISIONS" => Some(Self::LiquidityProvisions),
+            "TABLE_MARKETS" => Some(Self::Markets),
+            "TABLE_DEPOSITS" => Some(Self::Deposits),
+            "TABLE_WITHDRAWALS" => Some(Self::Withdrawals),
+            "TABLE_BLOCKS" => Some(Self::Blocks),
+            "TABLE_REWARDS" => Some(Self::Rewards),
+            _ => None,
+        }
+    }
+}
+/// Generated client implementations.
+pub mod trading_data_service_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    #[derive(Debug, Clone)]
+    pub struct TradingDataServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl TradingDataServiceClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> TradingDataServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> TradingDataServiceClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+            >>::Error: Into<StdError> + Send + Sync,
+        {
+            TradingDataServiceClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        /// List accounts
+        ///
+        /// Get a list of accounts matching the supplied filter, including their current balances.
+        /// If a given account has never had a balance, it will be absent from the list.
+        pub async fn list_accounts(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListAccountsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListAccountsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListAccounts",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("datanode.api.v2.TradingDataService", "ListAccounts"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Accounts subscription
+        ///
+        /// Subscribe to a stream of accounts
+        pub async fn observe_accounts(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ObserveAccountsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::ObserveAccountsResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ObserveAccounts",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ObserveAccounts",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// Data node information
+        ///
+        /// Get information about the data node.
+        /// Response contains a semver formatted version of the data node and the commit hash, from which the data node was built
+        pub async fn info(
+            &mut self,
+            request: impl tonic::IntoRequest<super::InfoRequest>,
+        ) -> std::result::Result<tonic::Response<super::InfoResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/Info",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("datanode.api.v2.TradingDataService", "Info"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get order
+        ///
+        /// Get an order by its ID. An order's ID will be the SHA3-256 hash of the signature that the order was submitted with
+        pub async fn get_order(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetOrderRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetOrderResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/GetOrder",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("datanode.api.v2.TradingDataService", "GetOrder"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List orders
+        ///
+        /// Get a list of orders that match the given filters
+        pub async fn list_orders(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListOrdersRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListOrdersResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListOrders",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("datanode.api.v2.TradingDataService", "ListOrders"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List order versions
+        ///
+        /// Get a list of all versions of an order in the order history
+        pub async fn list_order_versions(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListOrderVersionsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListOrderVersionsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListOrderVersions",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ListOrderVersions",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Observe orders
+        ///
+        /// Subscribe to a stream of orders
+        pub async fn observe_orders(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ObserveOrdersRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::ObserveOrdersResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ObserveOrders",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ObserveOrders",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// Get stop order
+        ///
+        /// Get a stop order by its ID. A stop order's ID will be the SHA3-256 hash of the signature that the order was submitted with.
+        /// A stop order's ID is likely to be different from the ID of the order that will be submitted when the stop is triggered.
+        pub async fn get_stop_order(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetStopOrderRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetStopOrderResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/GetStopOrder",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("datanode.api.v2.TradingDataService", "GetStopOrder"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List stop orders
+        ///
+        /// Get a list of stop orders that match the given filters
+        pub async fn list_stop_orders(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListStopOrdersRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListStopOrdersResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListStopOrders",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ListStopOrders",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List game team scores
+        ///
+        /// Get a list of team scores for the given filters
+        pub async fn list_game_team_scores(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListGameTeamScoresRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListGameTeamScoresResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListGameTeamScores",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ListGameTeamScores",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List game party scores
+        ///
+        /// Get a list of party scores for the given filters
+        pub async fn list_game_party_scores(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListGamePartyScoresRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListGamePartyScoresResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListGamePartyScores",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ListGamePartyScores",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Deprecated: List positions
+        ///
+        /// Get a list of positions by party's public key using cursor based pagination
+        /// Deprecated: use ListAllPositions instead
+        pub async fn list_positions(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListPositionsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListPositionsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListPositions",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ListPositions",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List positions
+        ///
+        /// Get a list of all of a party's positions
+        pub async fn list_all_positions(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListAllPositionsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListAllPositionsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListAllPositions",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ListAllPositions",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Observe positions
+        ///
+        /// Subscribe to a stream of position updates. The first messages sent through the stream will contain
+        /// information about current positions, followed by updates to those positions.
+        pub async fn observe_positions(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ObservePositionsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::ObservePositionsResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ObservePositions",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ObservePositions",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// List ledger entries
+        ///
+        /// Get a list of ledger entries within the given date range. The date range is restricted to a maximum of 5 days.
+        /// This query requests and sums the number of ledger entries from a given subset of accounts, specified via the 'filter' argument.
+        /// It returns a time series - implemented as a list of AggregateLedgerEntry structs - with a row for every time
+        /// the summed ledger entries of the set of specified accounts changes.
+        /// Each account filter must contain no more than one party ID.
+        /// At least one party ID must be specified in the from or to account filter.
+        ///
+        /// Entries can be filtered by:
+        ///   - the sending account (market ID, asset ID, account type)
+        ///   - receiving account (market ID, asset ID, account type)
+        ///   - sending AND receiving account
+        ///   - transfer type either in addition to the above filters or as a standalone option
+        /// Note: The date range is restricted to any 5 days.
+        ///   If no start or end date is provided, only ledger entries from the last 5 days will be returned.
+        ///   If a start and end date are provided, but the end date is more than 5 days after the start date, only data up to 5 days after the start date will be returned.
+        ///   If a start date is provided but no end date, the end date will be set to 5 days after the start date.
+        ///   If no start date is provided, but the end date is, the start date will be set to 5 days before the end date.
+        pub async fn list_ledger_entries(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListLedgerEntriesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListLedgerEntriesResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListLedgerEntries",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ListLedgerEntries",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Export ledger entries
+        ///
+        /// Export ledger entries records ledger entries to a csv file.
+        /// May or may not contain a date range - if no date range is provided, list all records for all times.
+        ///
+        /// Ledger entries can be exported by:
+        ///   - export ledger entries for a single party for a given asset within a given time range
+        ///   - export ledger entries for a single party for a given asset for all times
+        /// buf:lint:ignore RPC_RESPONSE_STANDARD_NAME
+        /// buf:lint:ignore RPC_REQUEST_RESPONSE_UNIQUE
+        pub async fn export_ledger_entries(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ExportLedgerEntriesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<
+                tonic::codec::Streaming<
+                    super::super::super::super::google::api::HttpBody,
+                >,
+            >,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ExportLedgerEntries",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ExportLedgerEntries",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// List balance changes
+        ///
+        /// Get a list of the changes in account balances over a period of time.
+        pub async fn list_balance_changes(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListBalanceChangesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListBalanceChangesResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListBalanceChanges",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ListBalanceChanges",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get latest market data
+        ///
+        /// Get the latest market data for a given market
+        pub async fn get_latest_market_data(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetLatestMarketDataRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetLatestMarketDataResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/GetLatestMarketData",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "GetLatestMarketData",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List latest market data
+        ///
+        /// Get a list of the latest market data for every market
+        pub async fn list_latest_market_data(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListLatestMarketDataRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListLatestMarketDataResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListLatestMarketData",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ListLatestMarketData",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get latest market depth
+        ///
+        /// Get the latest market depth for a given market
+        pub async fn get_latest_market_depth(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetLatestMarketDepthRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetLatestMarketDepthResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/GetLatestMarketDepth",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "GetLatestMarketDepth",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Observe markets depth
+        ///
+        /// Subscribe to a stream of the latest market depth for a given market
+        pub async fn observe_markets_depth(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ObserveMarketsDepthRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::ObserveMarketsDepthResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ObserveMarketsDepth",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ObserveMarketsDepth",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// Observe markets depth updates
+        ///
+        /// Subscribe to a stream of updates on market depth for a given market
+        pub async fn observe_markets_depth_updates(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ObserveMarketsDepthUpdatesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<
+                tonic::codec::Streaming<super::ObserveMarketsDepthUpdatesResponse>,
+            >,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ObserveMarketsDepthUpdates",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ObserveMarketsDepthUpdates",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// Observe markets data
+        ///
+        /// Subscribe to a stream of data about a given market
+        pub async fn observe_markets_data(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ObserveMarketsDataRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::ObserveMarketsDataResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ObserveMarketsData",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ObserveMarketsData",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// Get market data history
+        ///
+        /// Get market data history for a market ID from between a given date range
+        pub async fn get_market_data_history_by_id(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetMarketDataHistoryByIdRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetMarketDataHistoryByIdResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/GetMarketDataHistoryByID",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "GetMarketDataHistoryByID",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List transfers
+        ///
+        /// Get a list of transfers between public keys. A valid value for public key can be one of:
+        /// - a party ID
+        /// - "network"
+        /// - "0000000000000000000000000000000000000000000000000000000000000000", the public key for the global rewards account
+        pub async fn list_transfers(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListTransfersRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListTransfersResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListTransfers",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ListTransfers",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get transfer by ID
+        ///
+        /// Get a specific transfer by ID
+        pub async fn get_transfer(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetTransferRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetTransferResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/GetTransfer",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("datanode.api.v2.TradingDataService", "GetTransfer"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get network limits
+        ///
+        /// Get the network limits relating to asset and market creation
+        pub async fn get_network_limits(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetNetworkLimitsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetNetworkLimitsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/GetNetworkLimits",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "GetNetworkLimits",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List candle data
+        ///
+        /// Get a list of candle data for a given candle ID.
+        /// A candle ID encapsulates a market ID and candle interval. A list of available candle IDs, and therefore candle intervals can be found using the list-candle-intervals API.
+        pub async fn list_candle_data(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListCandleDataRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListCandleDataResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListCandleData",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ListCandleData",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Observe candle data
+        ///
+        /// Subscribe to a stream of candle updates given a candle ID.
+        /// A candle ID encapsulates a market ID and candle interval. A list of available candle IDs, and therefore candle intervals can be found using the list-candle-intervals API.
+        pub async fn observe_candle_data(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ObserveCandleDataRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::ObserveCandleDataResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ObserveCandleData",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ObserveCandleData",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// List candle intervals
+        ///
+        /// Get a list of all available candle intervals for a given market along with the corresponding candle ID.
+        pub async fn list_candle_intervals(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListCandleIntervalsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListCandleIntervalsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListCandleIntervals",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ListCandleIntervals",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List votes
+        ///
+        /// Get a list of votes. A party ID or a proposal ID must be provided.
+        pub async fn list_votes(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListVotesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListVotesResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListVotes",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("datanode.api.v2.TradingDataService", "ListVotes"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Observe votes
+        ///
+        /// Subscribe to a stream of votes cast on a given proposal, or by all votes made by a given party
+        pub async fn observe_votes(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ObserveVotesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::ObserveVotesResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ObserveVotes",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("datanode.api.v2.TradingDataService", "ObserveVotes"),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// List ERC20 multisig signer added bundles
+        ///
+        /// Get a list of the signature bundles to add a particular validator to the signer list of the multisig contract
+        pub async fn list_erc20_multi_sig_signer_added_bundles(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::ListErc20MultiSigSignerAddedBundlesRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::ListErc20MultiSigSignerAddedBundlesResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListERC20MultiSigSignerAddedBundles",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ListERC20MultiSigSignerAddedBundles",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List ERC20 multisig signer removed bundles
+        ///
+        /// Get a list of the signature bundles to remove a particular validator from signer list of the multisig contract
+        pub async fn list_erc20_multi_sig_signer_removed_bundles(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::ListErc20MultiSigSignerRemovedBundlesRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::ListErc20MultiSigSignerRemovedBundlesResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListERC20MultiSigSignerRemovedBundles",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ListERC20MultiSigSignerRemovedBundles",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get ERC20 list asset bundle
+        ///
+        /// Get the signatures bundle to allowlist an ERC20 token in the collateral bridge
+        pub async fn get_erc20_list_asset_bundle(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetErc20ListAssetBundleRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetErc20ListAssetBundleResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/GetERC20ListAssetBundle",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "GetERC20ListAssetBundle",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get ERC20 set asset limits bundle
+        ///
+        /// Get the signature bundle to update the token limits. These are `maxLifetimeDeposit` and `withdrawThreshold` for a given ERC20 token that is
+        /// already allowlisted in the collateral bridge.
+        pub async fn get_erc20_set_asset_limits_bundle(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetErc20SetAssetLimitsBundleRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetErc20SetAssetLimitsBundleResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/GetERC20SetAssetLimitsBundle",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "GetERC20SetAssetLimitsBundle",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get ERC20 withdrawal approval
+        ///
+        /// Get the signature bundle to finalise a withdrawal on Ethereum
+        pub async fn get_erc20_withdrawal_approval(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetErc20WithdrawalApprovalRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetErc20WithdrawalApprovalResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/GetERC20WithdrawalApproval",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "GetERC20WithdrawalApproval",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get last trade
+        ///
+        /// Get the last trade made for a given market.
+        pub async fn get_last_trade(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetLastTradeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetLastTradeResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/GetLastTrade",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("datanode.api.v2.TradingDataService", "GetLastTrade"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List trades
+        ///
+        /// Get a list of all trades, optionally filtered by party/market/order
+        pub async fn list_trades(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListTradesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListTradesResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListTrades",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("datanode.api.v2.TradingDataService", "ListTrades"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Observe trades
+        ///
+        /// Subscribe to a stream of trades, optionally filtered by party/market
+        pub async fn observe_trades(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ObserveTradesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::ObserveTradesResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ObserveTrades",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ObserveTrades",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// Get oracle spec
+        ///
+        /// Get an oracle spec by ID. Oracle spec IDs can be found by querying markets that use them as a data source
+        pub async fn get_oracle_spec(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetOracleSpecRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetOracleSpecResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/GetOracleSpec",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "GetOracleSpec",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List oracle specs
+        ///
+        /// Get a list of all oracles specs that are defined against all markets
+        pub async fn list_oracle_specs(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListOracleSpecsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListOracleSpecsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListOracleSpecs",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ListOracleSpecs",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List oracle data
+        ///
+        /// Get a list of all oracle data that have been broadcast to any market
+        pub async fn list_oracle_data(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListOracleDataRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListOracleDataResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListOracleData",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ListOracleData",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get market
+        ///
+        /// Get information about a specific market using its ID. A market's ID will be the same as the ID of the proposal that
+        /// generated it
+        pub async fn get_market(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetMarketRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetMarketResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/GetMarket",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("datanode.api.v2.TradingDataService", "GetMarket"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List markets
+        ///
+        /// Get a list of markets
+        pub async fn list_markets(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListMarketsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListMarketsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListMarkets",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("datanode.api.v2.TradingDataService", "ListMarkets"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List successor markets
+        ///
+        /// Given a market ID, return the full lineage of markets since inception, or all successor markets since and including
+        /// the given market ID. The markets will be returned in succession order, i.e. the market at position i will be the parent
+        /// of the market at position i+1.
+        pub async fn list_successor_markets(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListSuccessorMarketsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListSuccessorMarketsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListSuccessorMarkets",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ListSuccessorMarkets",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get party
+        ///
+        /// Get a single party
+        pub async fn get_party(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetPartyRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetPartyResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/GetParty",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("datanode.api.v2.TradingDataService", "GetParty"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List parties
+        ///
+        /// Get a list of parties
+        pub async fn list_parties(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListPartiesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListPartiesResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListParties",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("datanode.api.v2.TradingDataService", "ListParties"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List parties' profiles
+        ///
+        /// Get a list of profiles for multiple parties
+        pub async fn list_parties_profiles(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListPartiesProfilesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListPartiesProfilesResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListPartiesProfiles",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ListPartiesProfiles",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List margin levels
+        ///
+        /// Get a list of margin levels that match the provided criteria. If no filter is provided, all margin levels will be returned.
+        pub async fn list_margin_levels(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListMarginLevelsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListMarginLevelsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListMarginLevels",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ListMarginLevels",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Observe margin levels
+        ///
+        /// Subscribe to a stream of margin levels updates
+        pub async fn observe_margin_levels(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ObserveMarginLevelsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::ObserveMarginLevelsResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ObserveMarginLevels",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ObserveMarginLevels",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// List rewards
+        ///
+        /// Get a list of rewards that match the provided criteria. If no filter is provided, all rewards will be returned.
+        pub async fn list_rewards(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListRewardsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListRewardsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListRewards",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("datanode.api.v2.TradingDataService", "ListRewards"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List reward summaries
+        ///
+        /// Get a list of reward summaries where the reward amount is the total rewards received over all epochs
+        /// per party ID and asset ID.
+        /// Request parameters are optional party ID and asset ID.
+        /// If no data is provided, all reward summaries will be returned grouped by party and asset ID.
+        pub async fn list_reward_summaries(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListRewardSummariesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListRewardSummariesResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListRewardSummaries",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ListRewardSummaries",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List epoch reward summaries
+        ///
+        /// Get a list of reward summaries by epoch for a given range of epochs.
+        /// The result is filtered by a list of asset IDs, market IDs and starting and ending epochs, for which to return rewards.
+        /// If no data is provided, all reward summaries will be returned, grouped by epochs, market IDs, asset IDs and reward type.
+        pub async fn list_epoch_reward_summaries(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListEpochRewardSummariesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListEpochRewardSummariesResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListEpochRewardSummaries",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ListEpochRewardSummaries",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get deposit
+        ///
+        /// Get a deposit by its ID
+        pub async fn get_deposit(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetDepositRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetDepositResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/GetDeposit",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("datanode.api.v2.TradingDataService", "GetDeposit"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List deposits
+        ///
+        /// Get a list of deposits for a given party.
+        /// If a date range is provided, filtering will be based on the last time the deposit
+        /// has been updated in Vega time.
+        pub async fn list_deposits(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListDepositsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListDepositsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListDeposits",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("datanode.api.v2.TradingDataService", "ListDeposits"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get withdrawal
+        ///
+        /// Get a withdrawal by its ID. A withdrawal's ID will be the SHA3-256 hash of the signature that the withdrawal was submitted with
+        pub async fn get_withdrawal(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetWithdrawalRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetWithdrawalResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/GetWithdrawal",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "GetWithdrawal",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List withdrawals
+        ///
+        /// Get a list of withdrawals for a given party
+        pub async fn list_withdrawals(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListWithdrawalsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListWithdrawalsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListWithdrawals",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ListWithdrawals",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get asset
+        ///
+        /// Get a single asset using its ID. Use the assets list query to get an asset's ID
+        pub async fn get_asset(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetAssetRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetAssetResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/GetAsset",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("datanode.api.v2.TradingDataService", "GetAsset"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List assets
+        ///
+        /// Get a list of assets available on the Vega network
+        pub async fn list_assets(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListAssetsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListAssetsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListAssets",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("datanode.api.v2.TradingDataService", "ListAssets"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List liquidity provisions
+        ///
+        /// DEPRECATED: When a liquidity provider amends a provision, and it's accepted by the network, the pending
+        /// provision is returned by the API instead of the provision that is currently active.
+        /// Use ListAllLiquidityProvisions instead.
+        pub async fn list_liquidity_provisions(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListLiquidityProvisionsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListLiquidityProvisionsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListLiquidityProvisions",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ListLiquidityProvisions",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List liquidity provisions
+        ///
+        /// Get a list of liquidity provisions for a given market. This API returns a current and pending liquidity provision
+        /// in the event that a provision has been updated by the provider but the updated provision will not be active until the next epoch.
+        pub async fn list_all_liquidity_provisions(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListAllLiquidityProvisionsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListAllLiquidityProvisionsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListAllLiquidityProvisions",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ListAllLiquidityProvisions",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Observe liquidity provisions
+        ///
+        /// Subscribe to a stream of liquidity provision events for a given market and party
+        pub async fn observe_liquidity_provisions(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ObserveLiquidityProvisionsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<
+                tonic::codec::Streaming<super::ObserveLiquidityProvisionsResponse>,
+            >,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ObserveLiquidityProvisions",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ObserveLiquidityProvisions",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// List liquidity providers data
+        ///
+        /// List information about active liquidity provider(s) for a given market, or liquidity provider's party ID.
+        pub async fn list_liquidity_providers(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListLiquidityProvidersRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListLiquidityProvidersResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListLiquidityProviders",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ListLiquidityProviders",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List paid liquidity fees data
+        ///
+        /// List information about paid liquidity fees for a given market, or asset ID.
+        pub async fn list_paid_liquidity_fees(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListPaidLiquidityFeesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListPaidLiquidityFeesResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListPaidLiquidityFees",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ListPaidLiquidityFees",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get governance data
+        ///
+        /// Get a single proposal's details either by proposal ID or by reference
+        pub async fn get_governance_data(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetGovernanceDataRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetGovernanceDataResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/GetGovernanceData",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "GetGovernanceData",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List governance data
+        ///
+        /// Get a list of proposals
+        pub async fn list_governance_data(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListGovernanceDataRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListGovernanceDataResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListGovernanceData",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ListGovernanceData",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Observe governance
+        ///
+        /// Subscribe to a stream of updates to governance proposals
+        pub async fn observe_governance(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ObserveGovernanceRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::ObserveGovernanceResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ObserveGovernance",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ObserveGovernance",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// List delegations
+        ///
+        /// Get a list of the token delegations on the network
+        pub async fn list_delegations(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListDelegationsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListDelegationsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListDelegations",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ListDelegations",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get network data
+        ///
+        /// Get data regarding the nodes of the network
+        pub async fn get_network_data(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetNetworkDataRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetNetworkDataResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/GetNetworkData",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "GetNetworkData",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get node
+        ///
+        /// Get information about a given node
+        pub async fn get_node(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetNodeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetNodeResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/GetNode",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("datanode.api.v2.TradingDataService", "GetNode"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List nodes
+        ///
+        /// Get a list of the nodes on the network along with the related information.
+        pub async fn list_nodes(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListNodesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListNodesResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListNodes",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("datanode.api.v2.TradingDataService", "ListNodes"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List node signatures
+        ///
+        /// Get a list of aggregate signatures from all the nodes of the network
+        pub async fn list_node_signatures(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListNodeSignaturesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListNodeSignaturesResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListNodeSignatures",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ListNodeSignatures",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get epoch
+        ///
+        /// Get data for a specific epoch, if ID is omitted, it retrieves the current epoch
+        pub async fn get_epoch(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetEpochRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetEpochResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/GetEpoch",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("datanode.api.v2.TradingDataService", "GetEpoch"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Estimate fee
+        ///
+        /// Estimate the fee that would be incurred for submitting an order
+        /// with the specified price and size on the market.
+        pub async fn estimate_fee(
+            &mut self,
+            request: impl tonic::IntoRequest<super::EstimateFeeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::EstimateFeeResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/EstimateFee",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("datanode.api.v2.TradingDataService", "EstimateFee"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Estimate margin
+        ///
+        /// Estimate the margin that would be required for submitting this order
+        pub async fn estimate_margin(
+            &mut self,
+            request: impl tonic::IntoRequest<super::EstimateMarginRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::EstimateMarginResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/EstimateMargin",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "EstimateMargin",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Estimate position
+        ///
+        /// Estimate the margin that would be required for maintaining the specified position, collateral increase needed to open the specified position and the liquidation price estimate.
+        /// Margin estimates are scaled to asset decimal places.
+        /// Liquidation price estimates are scaled to asset decimal places by default, unless an argument to scale to market decimal places is specified in the request.
+        pub async fn estimate_position(
+            &mut self,
+            request: impl tonic::IntoRequest<super::EstimatePositionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::EstimatePositionResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/EstimatePosition",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "EstimatePosition",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List network parameters
+        ///
+        /// Get a list of the network parameter keys and their values
+        pub async fn list_network_parameters(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListNetworkParametersRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListNetworkParametersResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListNetworkParameters",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ListNetworkParameters",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get network parameter
+        ///
+        /// Get a network parameter's value by its key
+        pub async fn get_network_parameter(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetNetworkParameterRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetNetworkParameterResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/GetNetworkParameter",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "GetNetworkParameter",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List checkpoints
+        ///
+        /// Get a list of information about checkpoints generated by the network
+        pub async fn list_checkpoints(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListCheckpointsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListCheckpointsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListCheckpoints",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ListCheckpoints",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get stake
+        ///
+        /// Get staking information for a given party
+        pub async fn get_stake(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetStakeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetStakeResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/GetStake",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("datanode.api.v2.TradingDataService", "GetStake"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get risk factors
+        ///
+        /// Get risk factor data for a given market
+        pub async fn get_risk_factors(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetRiskFactorsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetRiskFactorsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/GetRiskFactors",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "GetRiskFactors",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Observe event bus
+        ///
+        /// Subscribe to a stream of events from the core
+        pub async fn observe_event_bus(
+            &mut self,
+            request: impl tonic::IntoStreamingRequest<
+                Message = super::ObserveEventBusRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::ObserveEventBusResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ObserveEventBus",
+            );
+            let mut req = request.into_streaming_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ObserveEventBus",
+                    ),
+                );
+            self.inner.streaming(req, path, codec).await
+        }
+        /// Observe ledger movements
+        ///
+        /// Subscribe to a stream of transfer responses
+        pub async fn observe_ledger_movements(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ObserveLedgerMovementsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<
+                tonic::codec::Streaming<super::ObserveLedgerMovementsResponse>,
+            >,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ObserveLedgerMovements",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ObserveLedgerMovements",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// List key rotations
+        ///
+        /// Get a list of information about Vega key rotations that have been performed by validator nodes
+        pub async fn list_key_rotations(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListKeyRotationsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListKeyRotationsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListKeyRotations",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ListKeyRotations",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List Ethereum key rotations
+        ///
+        /// Get a list of information about Ethereum key rotations that have been performed by validator nodes
+        pub async fn list_ethereum_key_rotations(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListEthereumKeyRotationsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListEthereumKeyRotationsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListEthereumKeyRotations",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ListEthereumKeyRotations",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get Vega time
+        ///
+        /// Get the current time of the network in Unix nanoseconds
+        pub async fn get_vega_time(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetVegaTimeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetVegaTimeResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/GetVegaTime",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("datanode.api.v2.TradingDataService", "GetVegaTime"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get protocol upgrade status
+        ///
+        /// Get status of a protocol upgrade
+        pub async fn get_protocol_upgrade_status(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetProtocolUpgradeStatusRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetProtocolUpgradeStatusResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/GetProtocolUpgradeStatus",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "GetProtocolUpgradeStatus",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List protocol upgrade proposals
+        ///
+        /// Get a list of protocol upgrade proposals, optionally filtering on status or approver.
+        pub async fn list_protocol_upgrade_proposals(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListProtocolUpgradeProposalsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListProtocolUpgradeProposalsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListProtocolUpgradeProposals",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ListProtocolUpgradeProposals",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List snapshots
+        ///
+        /// Get a list of core snapshots taken
+        pub async fn list_core_snapshots(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListCoreSnapshotsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListCoreSnapshotsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListCoreSnapshots",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ListCoreSnapshots",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get most recent network history segment
+        ///
+        /// Get the network's most recently history segment
+        pub async fn get_most_recent_network_history_segment(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::GetMostRecentNetworkHistorySegmentRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::GetMostRecentNetworkHistorySegmentResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/GetMostRecentNetworkHistorySegment",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "GetMostRecentNetworkHistorySegment",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List all network history segments
+        ///
+        /// Get a list of all history segments stored by the data node currently connected to
+        pub async fn list_all_network_history_segments(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListAllNetworkHistorySegmentsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListAllNetworkHistorySegmentsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListAllNetworkHistorySegments",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ListAllNetworkHistorySegments",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get active network history peer addresses
+        ///
+        /// Get a list of the addresses of all active network history peers
+        pub async fn get_active_network_history_peer_addresses(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::GetActiveNetworkHistoryPeerAddressesRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::GetActiveNetworkHistoryPeerAddressesResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/GetActiveNetworkHistoryPeerAddresses",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "GetActiveNetworkHistoryPeerAddresses",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Network history status
+        ///
+        /// Get information about the current state of network history's IPFS swarm
+        pub async fn get_network_history_status(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetNetworkHistoryStatusRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetNetworkHistoryStatusResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/GetNetworkHistoryStatus",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "GetNetworkHistoryStatus",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Network history bootstrap peers
+        ///
+        /// Get a list of IPFS peers that can be used to initialise a new data node with network history
+        pub async fn get_network_history_bootstrap_peers(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::GetNetworkHistoryBootstrapPeersRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::GetNetworkHistoryBootstrapPeersResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/GetNetworkHistoryBootstrapPeers",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "GetNetworkHistoryBootstrapPeers",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List entities
+        ///
+        /// Get a list of all entities created by transaction hash
+        pub async fn list_entities(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListEntitiesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListEntitiesResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListEntities",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("datanode.api.v2.TradingDataService", "ListEntities"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List funding periods
+        ///
+        /// Get a list of funding periods for a perpetual market.
+        pub async fn list_funding_periods(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListFundingPeriodsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListFundingPeriodsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListFundingPeriods",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ListFundingPeriods",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List funding period data points
+        ///
+        /// Get a list of data points for a perpetual market's funding periods.
+        pub async fn list_funding_period_data_points(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListFundingPeriodDataPointsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListFundingPeriodDataPointsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListFundingPeriodDataPoints",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ListFundingPeriodDataPoints",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List funding payments
+        ///
+        /// Get a list of funding payment gains and losses for a party as a result of their position on a perpetual market when a funding
+        /// period ends.
+        pub async fn list_funding_payments(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListFundingPaymentsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListFundingPaymentsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListFundingPayments",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ListFundingPayments",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List party activity streak
+        ///
+        /// Get a party's activity across epochs.
+        pub async fn get_party_activity_streak(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetPartyActivityStreakRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetPartyActivityStreakResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/GetPartyActivityStreak",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "GetPartyActivityStreak",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get current referral program
+        ///
+        /// Get the current referral program for the network. This program may not be active if it has ended
+        /// and has not been replaced by another.
+        pub async fn get_current_referral_program(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetCurrentReferralProgramRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetCurrentReferralProgramResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/GetCurrentReferralProgram",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "GetCurrentReferralProgram",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List referral sets
+        ///
+        /// List all referral sets, or a specific referral set if you know its ID.
+        pub async fn list_referral_sets(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListReferralSetsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListReferralSetsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListReferralSets",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ListReferralSets",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List referral set referees
+        ///
+        /// List all referees that belong to a referral set.
+        pub async fn list_referral_set_referees(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListReferralSetRefereesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListReferralSetRefereesResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListReferralSetReferees",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ListReferralSetReferees",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get referral set statistics
+        ///
+        /// Get the total taker volume, and each referee's taker volume and, reward and discount factors for a referral set
+        /// at the latest or a specific epoch. You can also optionally filter for a specific referee's statistics.
+        pub async fn get_referral_set_stats(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetReferralSetStatsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetReferralSetStatsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/GetReferralSetStats",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "GetReferralSetStats",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List teams
+        ///
+        /// Get a list of all teams, or for a specific team by using team ID, or party ID of a referrer or referee
+        pub async fn list_teams(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListTeamsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListTeamsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListTeams",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("datanode.api.v2.TradingDataService", "ListTeams"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List teams statistics
+        ///
+        /// Get the statistics of all teams, or for a specific team by using team ID, over a number of epochs.
+        /// If a team does not have at least the number of epochs' worth of data, it is ignored.
+        pub async fn list_teams_statistics(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListTeamsStatisticsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListTeamsStatisticsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListTeamsStatistics",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ListTeamsStatistics",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List team members' statistics
+        ///
+        /// Get the statistics for all members of a given team, or for a specific member by using party ID, over a number of epochs.
+        /// If a team does not have at least the number of epochs' worth of data, it is ignored.
+        pub async fn list_team_members_statistics(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListTeamMembersStatisticsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListTeamMembersStatisticsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListTeamMembersStatistics",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ListTeamMembersStatistics",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List team referees
+        ///
+        /// Get a list of all referees for a given team ID
+        pub async fn list_team_referees(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListTeamRefereesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListTeamRefereesResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListTeamReferees",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ListTeamReferees",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List referee team history
+        ///
+        /// Get a list of a referee's team history, i.e. the teams that a referee has been a member of and transferred from/to.
+        pub async fn list_team_referee_history(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListTeamRefereeHistoryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListTeamRefereeHistoryResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListTeamRefereeHistory",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ListTeamRefereeHistory",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get fees statistics
+        ///
+        /// Get accumulated fees, rewards, and applied discount information. Either a market or an asset must be supplied as a filter.
+        pub async fn get_fees_stats(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetFeesStatsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetFeesStatsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/GetFeesStats",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("datanode.api.v2.TradingDataService", "GetFeesStats"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get fees statistics for a party
+        ///
+        /// Get accumulated fees, rewards, and applied discount information. A party ID must be supplied as a filter.
+        pub async fn get_fees_stats_for_party(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetFeesStatsForPartyRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetFeesStatsForPartyResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/GetFeesStatsForParty",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "GetFeesStatsForParty",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get current volume discount program
+        ///
+        /// Get the current volume discount program for the network. This program may not be active if it has ended
+        /// and has not been replaced by another.
+        pub async fn get_current_volume_discount_program(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::GetCurrentVolumeDiscountProgramRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::GetCurrentVolumeDiscountProgramResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/GetCurrentVolumeDiscountProgram",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "GetCurrentVolumeDiscountProgram",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get volume discount statistics
+        ///
+        /// Get the information about a party's running traded volume, and the discount factor it earns them.
+        pub async fn get_volume_discount_stats(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetVolumeDiscountStatsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetVolumeDiscountStatsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/GetVolumeDiscountStats",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "GetVolumeDiscountStats",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get vesting balances summary
+        ///
+        /// Get information about a party's vesting and locked balances
+        pub async fn get_vesting_balances_summary(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetVestingBalancesSummaryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetVestingBalancesSummaryResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/GetVestingBalancesSummary",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "GetVestingBalancesSummary",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get vesting balance statistics
+        ///
+        /// Get information about a party's vesting rewards
+        pub async fn get_party_vesting_stats(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetPartyVestingStatsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetPartyVestingStatsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/GetPartyVestingStats",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "GetPartyVestingStats",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Observe transaction results
+        ///
+        /// Subscribe to a stream of transaction results, optionally filtered by party/hash/status
+        pub async fn observe_transaction_results(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ObserveTransactionResultsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<
+                tonic::codec::Streaming<super::ObserveTransactionResultsResponse>,
+            >,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ObserveTransactionResults",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ObserveTransactionResults",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// Estimate transfer fee costs
+        ///
+        /// Estimate transfer fee costs with potential discount applied
+        pub async fn estimate_transfer_fee(
+            &mut self,
+            request: impl tonic::IntoRequest<super::EstimateTransferFeeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::EstimateTransferFeeResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/EstimateTransferFee",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "EstimateTransferFee",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Available transfer fee discount
+        ///
+        /// Returns available per party per asset transfer discount
+        pub async fn get_total_transfer_fee_discount(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetTotalTransferFeeDiscountRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetTotalTransferFeeDiscountResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/GetTotalTransferFeeDiscount",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "GetTotalTransferFeeDiscount",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List games
+        ///
+        /// Get a list of games and corresponding game data, given the provided filters
+        pub async fn list_games(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListGamesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListGamesResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListGames",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("datanode.api.v2.TradingDataService", "ListGames"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List margin modes per party per market
+        ///
+        /// Get a list of all margin modes, or for a specific market ID, or party ID.
+        pub async fn list_party_margin_modes(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListPartyMarginModesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListPartyMarginModesResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListPartyMarginModes",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ListPartyMarginModes",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get time weighted notional position
+        ///
+        /// Get the time weighted notional position for a given party and asset. The time weighted notional position
+        /// is used to check if a party qualifies for a reward.
+        /// If no epoch is specified, the final time weighted notional position from the end of the most recently completed epoch is returned.
+        /// If an epoch is specified, the final time weighted notional position at that epoch is returned.
+        pub async fn get_time_weighted_notional_position(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::GetTimeWeightedNotionalPositionRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::GetTimeWeightedNotionalPositionResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/GetTimeWeightedNotionalPosition",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "GetTimeWeightedNotionalPosition",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List AMMs
+        ///
+        /// Get a list of AMM or filter by market ID, party ID or AMM ID
+        pub async fn list_am_ms(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListAmMsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListAmMsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ListAMMs",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("datanode.api.v2.TradingDataService", "ListAMMs"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Estimate AMM bounds
+        ///
+        /// Get a list of AMMs or filter by market ID, party ID or AMM ID
+        pub async fn estimate_amm_bounds(
+            &mut self,
+            request: impl tonic::IntoRequest<super::EstimateAmmBoundsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::EstimateAmmBoundsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/EstimateAMMBounds",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "EstimateAMMBounds",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Export network history as CSV
+        ///
+        /// Export CSV table data from network history between two block heights.
+        ///
+        /// The requested block heights must fall on network history segment boundaries, which can
+        /// be discovered by calling the API to list all network history segments. By default
+        /// segments contain 1000 blocks. In that case ranges such as (1, 1000), (1001, 2000), (1, 3000)
+        /// would all fall on segment boundaries and be valid.
+        ///
+        /// The generated CSV file is compressed into a ZIP file and returned, with the file name
+        /// in the following format: `[chain id]-[table name]-[start block]-[end block].zip`
+        ///
+        /// In gRPC, results are returned in a chunked stream of base64 encoded data.
+        ///
+        /// Through the REST gateway, the base64 data chunks are decoded and streamed as a
+        /// `content-type: application/zip` HTTP response.
+        ///
+        /// The CSV exported data uses a comma as a DELIMITER between fields, and " for QUOTE-ing fields.
+        ///
+        /// If a value contains any of: DELIMITER, QUOTE, carriage return, or line feed then the whole
+        /// value is prefixed and suffixed by the QUOTE character and any occurrence within
+        /// the value of a QUOTE character preceded by another QUOTE.
+        ///
+        /// A NULL is output as the NULL parameter string and is not quoted, while a non-NULL value
+        /// matching the NULL parameter string is quoted.
+        ///
+        /// For example, with the default settings, a NULL is written as an unquoted empty string,
+        /// while an empty string data value is written with double quotes.
+        ///
+        /// Note that CSV files produced may contain quoted values containing embedded carriage returns and line feeds.
+        /// Thus the files are not strictly one line per table row like text-format files.
+        ///
+        /// The first row of the CSV file is a header that describes the contents of each column
+        /// in subsequent rows.
+        ///
+        /// Usually the ZIP file will contain only a single CSV file. However it is possible that
+        /// the (from_block, to_block) request spans over a range of blocks in which the underlying
+        /// schema of the database changes. For example, a column may have been added, removed, or renamed.
+        ///
+        /// If this happens, the CSV file will be split at the point of the schema change and the zip
+        /// file will contain multiple CSV files, with a potentially different set of headers. The
+        /// 'version' number of the database schema is part of the in the CSV filename:
+        ///
+        ///  `[chain id]-[table name]-[schema version]-[start block]-[end block].zip`
+        ///
+        /// For example, a zip file might be called mainnet-sometable-000001-003000.zip
+        ///
+        /// And contain two CSV files: `mainnet-sometable-1-000001-002000.csv`:
+        ///
+        /// timestamp, value
+        /// 1, foo
+        /// 2, bar
+        ///
+        /// And `mainnet-sometable-2-002001-003000.csv`:
+        ///
+        /// timestamp, value, extra_value
+        /// 3, baz, apple
+        ///
+        /// It is worth noting that the schema will not change within a single network history segment.
+        /// buf:lint:ignore RPC_RESPONSE_STANDARD_NAME
+        /// buf:lint:ignore RPC_REQUEST_RESPONSE_UNIQUE
+        pub async fn export_network_history(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ExportNetworkHistoryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<
+                tonic::codec::Streaming<
+                    super::super::super::super::google::api::HttpBody,
+                >,
+            >,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/ExportNetworkHistory",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "datanode.api.v2.TradingDataService",
+                        "ExportNetworkHistory",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// Ping
+        ///
+        /// Ping the data node
+        pub async fn ping(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PingRequest>,
+        ) -> std::result::Result<tonic::Response<super::PingResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/datanode.api.v2.TradingDataService/Ping",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("datanode.api.v2.TradingDataService", "Ping"));
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+#[cfg(feature = "server")]
+pub mod trading_data_service_server {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with TradingDataServiceServer.
+    #[async_trait]
+    pub trait TradingDataService: Send + Sync + 'static {
+        /// List accounts
+        ///
+        /// Get a list of accounts matching the supplied filter, including their current balances.
+        /// If a given account has never had a balance, it will be absent from the list.
+        async fn list_accounts(
+            &self,
+            request: tonic::Request<super::ListAccountsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListAccountsResponse>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the ObserveAccounts method.
+        type ObserveAccountsStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::ObserveAccountsResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// Accounts subscription
+        ///
+        /// Subscribe to a stream of accounts
+        async fn observe_accounts(
+            &self,
+            request: tonic::Request<super::ObserveAccountsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::ObserveAccountsStream>,
+            tonic::Status,
+        >;
+        /// Data node information
+        ///
+        /// Get information about the data node.
+        /// Response contains a semver formatted version of the data node and the commit hash, from which the data node was built
+        async fn info(
+            &self,
+            request: tonic::Request<super::InfoRequest>,
+        ) -> std::result::Result<tonic::Response<super::InfoResponse>, tonic::Status>;
+        /// Get order
+        ///
+        /// Get an order by its ID. An order's ID will be the SHA3-256 hash of the signature that the order was submitted with
+        async fn get_order(
+            &self,
+            request: tonic::Request<super::GetOrderRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetOrderResponse>,
+            tonic::Status,
+        >;
+        /// List orders
+        ///
+        /// Get a list of orders that match the given filters
+        async fn list_orders(
+            &self,
+            request: tonic::Request<super::ListOrdersRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListOrdersResponse>,
+            tonic::Status,
+        >;
+        /// List order versions
+        ///
+        /// Get a list of all versions of an order in the order history
+        async fn list_order_versions(
+            &self,
+            request: tonic::Request<super::ListOrderVersionsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListOrderVersionsResponse>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the ObserveOrders method.
+        type ObserveOrdersStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::ObserveOrdersResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// Observe orders
+        ///
+        /// Subscribe to a stream of orders
+        async fn observe_orders(
+            &self,
+            request: tonic::Request<super::ObserveOrdersRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::ObserveOrdersStream>,
+            tonic::Status,
+        >;
+        /// Get stop order
+        ///
+        /// Get a stop order by its ID. A stop order's ID will be the SHA3-256 hash of the signature that the order was submitted with.
+        /// A stop order's ID is likely to be different from the ID of the order that will be submitted when the stop is triggered.
+        async fn get_stop_order(
+            &self,
+            request: tonic::Request<super::GetStopOrderRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetStopOrderResponse>,
+            tonic::Status,
+        >;
+        /// List stop orders
+        ///
+        /// Get a list of stop orders that match the given filters
+        async fn list_stop_orders(
+            &self,
+            request: tonic::Request<super::ListStopOrdersRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListStopOrdersResponse>,
+            tonic::Status,
+        >;
+        /// List game team scores
+        ///
+        /// Get a list of team scores for the given filters
+        async fn list_game_team_scores(
+            &self,
+            request: tonic::Request<super::ListGameTeamScoresRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListGameTeamScoresResponse>,
+            tonic::Status,
+        >;
+        /// List game party scores
+        ///
+        /// Get a list of party scores for the given filters
+        async fn list_game_party_scores(
+            &self,
+            request: tonic::Request<super::ListGamePartyScoresRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListGamePartyScoresResponse>,
+            tonic::Status,
+        >;
+        /// Deprecated: List positions
+        ///
+        /// Get a list of positions by party's public key using cursor based pagination
+        /// Deprecated: use ListAllPositions instead
+        async fn list_positions(
+            &self,
+            request: tonic::Request<super::ListPositionsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListPositionsResponse>,
+            tonic::Status,
+        >;
+        /// List positions
+        ///
+        /// Get a list of all of a party's positions
+        async fn list_all_positions(
+            &self,
+            request: tonic::Request<super::ListAllPositionsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListAllPositionsResponse>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the ObservePositions method.
+        type ObservePositionsStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::ObservePositionsResponse,
+                    tonic::Status,
+                >,
+            >
+            + Send
+            + 'static;
+        /// Observe positions
+        ///
+        /// Subscribe to a stream of position updates. The first messages sent through the stream will contain
+        /// information about current positions, followed by updates to those positions.
+        async fn observe_positions(
+            &self,
+            request: tonic::Request<super::ObservePositionsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::ObservePositionsStream>,
+            tonic::Status,
+        >;
+        /// List ledger entries
+        ///
+        /// Get a list of ledger entries within the given date range. The date range is restricted to a maximum of 5 days.
+        /// This query requests and sums the number of ledger entries from a given subset of accounts, specified via the 'filter' argument.
+        /// It returns a time series - implemented as a list of AggregateLedgerEntry structs - with a row for every time
+        /// the summed ledger entries of the set of specified accounts changes.
+        /// Each account filter must contain no more than one party ID.
+        /// At least one party ID must be specified in the from or to account filter.
+        ///
+        /// Entries can be filtered by:
+        ///   - the sending account (market ID, asset ID, account type)
+        ///   - receiving account (market ID, asset ID, account type)
+        ///   - sending AND receiving account
+        ///   - transfer type either in addition to the above filters or as a standalone option
+        /// Note: The date range is restricted to any 5 days.
+        ///   If no start or end date is provided, only ledger entries from the last 5 days will be returned.
+        ///   If a start and end date are provided, but the end date is more than 5 days after the start date, only data up to 5 days after the start date will be returned.
+        ///   If a start date is provided but no end date, the end date will be set to 5 days after the start date.
+        ///   If no start date is provided, but the end date is, the start date will be set to 5 days before the end date.
+        async fn list_ledger_entries(
+            &self,
+            request: tonic::Request<super::ListLedgerEntriesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListLedgerEntriesResponse>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the ExportLedgerEntries method.
+        type ExportLedgerEntriesStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::super::super::super::google::api::HttpBody,
+                    tonic::Status,
+                >,
+            >
+            + Send
+            + 'static;
+        /// Export ledger entries
+        ///
+        /// Export ledger entries records ledger entries to a csv file.
+        /// May or may not contain a date range - if no date range is provided, list all records for all times.
+        ///
+        /// Ledger entries can be exported by:
+        ///   - export ledger entries for a single party for a given asset within a given time range
+        ///   - export ledger entries for a single party for a given asset for all times
+        /// buf:lint:ignore RPC_RESPONSE_STANDARD_NAME
+        /// buf:lint:ignore RPC_REQUEST_RESPONSE_UNIQUE
+        async fn export_ledger_entries(
+            &self,
+            request: tonic::Request<super::ExportLedgerEntriesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::ExportLedgerEntriesStream>,
+            tonic::Status,
+        >;
+        /// List balance changes
+        ///
+        /// Get a list of the changes in account balances over a period of time.
+        async fn list_balance_changes(
+            &self,
+            request: tonic::Request<super::ListBalanceChangesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListBalanceChangesResponse>,
+            tonic::Status,
+        >;
+        /// Get latest market data
+        ///
+        /// Get the latest market data for a given market
+        async fn get_latest_market_data(
+            &self,
+            request: tonic::Request<super::GetLatestMarketDataRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetLatestMarketDataResponse>,
+            tonic::Status,
+        >;
+        /// List latest market data
+        ///
+        /// Get a list of the latest market data for every market
+        async fn list_latest_market_data(
+            &self,
+            request: tonic::Request<super::ListLatestMarketDataRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListLatestMarketDataResponse>,
+            tonic::Status,
+        >;
+        /// Get latest market depth
+        ///
+        /// Get the latest market depth for a given market
+        async fn get_latest_market_depth(
+            &self,
+            request: tonic::Request<super::GetLatestMarketDepthRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetLatestMarketDepthResponse>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the ObserveMarketsDepth method.
+        type ObserveMarketsDepthStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::ObserveMarketsDepthResponse,
+                    tonic::Status,
+                >,
+            >
+            + Send
+            + 'static;
+        /// Observe markets depth
+        ///
+        /// Subscribe to a stream of the latest market depth for a given market
+        async fn observe_markets_depth(
+            &self,
+            request: tonic::Request<super::ObserveMarketsDepthRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::ObserveMarketsDepthStream>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the ObserveMarketsDepthUpdates method.
+        type ObserveMarketsDepthUpdatesStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::ObserveMarketsDepthUpdatesResponse,
+                    tonic::Status,
+                >,
+            >
+            + Send
+            + 'static;
+        /// Observe markets depth updates
+        ///
+        /// Subscribe to a stream of updates on market depth for a given market
+        async fn observe_markets_depth_updates(
+            &self,
+            request: tonic::Request<super::ObserveMarketsDepthUpdatesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::ObserveMarketsDepthUpdatesStream>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the ObserveMarketsData method.
+        type ObserveMarketsDataStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::ObserveMarketsDataResponse,
+                    tonic::Status,
+                >,
+            >
+            + Send
+            + 'static;
+        /// Observe markets data
+        ///
+        /// Subscribe to a stream of data about a given market
+        async fn observe_markets_data(
+            &self,
+            request: tonic::Request<super::ObserveMarketsDataRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::ObserveMarketsDataStream>,
+            tonic::Status,
+        >;
+        /// Get market data history
+        ///
+        /// Get market data history for a market ID from between a given date range
+        async fn get_market_data_history_by_id(
+            &self,
+            request: tonic::Request<super::GetMarketDataHistoryByIdRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetMarketDataHistoryByIdResponse>,
+            tonic::Status,
+        >;
+        /// List transfers
+        ///
+        /// Get a list of transfers between public keys. A valid value for public key can be one of:
+        /// - a party ID
+        /// - "network"
+        /// - "0000000000000000000000000000000000000000000000000000000000000000", the public key for the global rewards account
+        async fn list_transfers(
+            &self,
+            request: tonic::Request<super::ListTransfersRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListTransfersResponse>,
+            tonic::Status,
+        >;
+        /// Get transfer by ID
+        ///
+        /// Get a specific transfer by ID
+        async fn get_transfer(
+            &self,
+            request: tonic::Request<super::GetTransferRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetTransferResponse>,
+            tonic::Status,
+        >;
+        /// Get network limits
+        ///
+        /// Get the network limits relating to asset and market creation
+        async fn get_network_limits(
+            &self,
+            request: tonic::Request<super::GetNetworkLimitsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetNetworkLimitsResponse>,
+            tonic::Status,
+        >;
+        /// List candle data
+        ///
+        /// Get a list of candle data for a given candle ID.
+        /// A candle ID encapsulates a market ID and candle interval. A list of available candle IDs, and therefore candle intervals can be found using the list-candle-intervals API.
+        async fn list_candle_data(
+            &self,
+            request: tonic::Request<super::ListCandleDataRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListCandleDataResponse>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the ObserveCandleData method.
+        type ObserveCandleDataStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::ObserveCandleDataResponse,
+                    tonic::Status,
+                >,
+            >
+            + Send
+            + 'static;
+        /// Observe candle data
+        ///
+        /// Subscribe to a stream of candle updates given a candle ID.
+        /// A candle ID encapsulates a market ID and candle interval. A list of available candle IDs, and therefore candle intervals can be found using the list-candle-intervals API.
+        async fn observe_candle_data(
+            &self,
+            request: tonic::Request<super::ObserveCandleDataRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::ObserveCandleDataStream>,
+            tonic::Status,
+        >;
+        /// List candle intervals
+        ///
+        /// Get a list of all available candle intervals for a given market along with the corresponding candle ID.
+        async fn list_candle_intervals(
+            &self,
+            request: tonic::Request<super::ListCandleIntervalsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListCandleIntervalsResponse>,
+            tonic::Status,
+        >;
+        /// List votes
+        ///
+        /// Get a list of votes. A party ID or a proposal ID must be provided.
+        async fn list_votes(
+            &self,
+            request: tonic::Request<super::ListVotesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListVotesResponse>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the ObserveVotes method.
+        type ObserveVotesStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::ObserveVotesResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// Observe votes
+        ///
+        /// Subscribe to a stream of votes cast on a given proposal, or by all votes made by a given party
+        async fn observe_votes(
+            &self,
+            request: tonic::Request<super::ObserveVotesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::ObserveVotesStream>,
+            tonic::Status,
+        >;
+        /// List ERC20 multisig signer added bundles
+        ///
+        /// Get a list of the signature bundles to add a particular validator to the signer list of the multisig contract
+        async fn list_erc20_multi_sig_signer_added_bundles(
+            &self,
+            request: tonic::Request<super::ListErc20MultiSigSignerAddedBundlesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListErc20MultiSigSignerAddedBundlesResponse>,
+            tonic::Status,
+        >;
+        /// List ERC20 multisig signer removed bundles
+        ///
+        /// Get a list of the signature bundles to remove a particular validator from signer list of the multisig contract
+        async fn list_erc20_multi_sig_signer_removed_bundles(
+            &self,
+            request: tonic::Request<super::ListErc20MultiSigSignerRemovedBundlesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListErc20MultiSigSignerRemovedBundlesResponse>,
+            tonic::Status,
+        >;
+        /// Get ERC20 list asset bundle
+        ///
+        /// Get the signatures bundle to allowlist an ERC20 token in the collateral bridge
+        async fn get_erc20_list_asset_bundle(
+            &self,
+            request: tonic::Request<super::GetErc20ListAssetBundleRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetErc20ListAssetBundleResponse>,
+            tonic::Status,
+        >;
+        /// Get ERC20 set asset limits bundle
+        ///
+        /// Get the signature bundle to update the token limits. These are `maxLifetimeDeposit` and `withdrawThreshold` for a given ERC20 token that is
+        /// already allowlisted in the collateral bridge.
+        async fn get_erc20_set_asset_limits_bundle(
+            &self,
+            request: tonic::Request<super::GetErc20SetAssetLimitsBundleRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetErc20SetAssetLimitsBundleResponse>,
+            tonic::Status,
+        >;
+        /// Get ERC20 withdrawal approval
+        ///
+        /// Get the signature bundle to finalise a withdrawal on Ethereum
+        async fn get_erc20_withdrawal_approval(
+            &self,
+            request: tonic::Request<super::GetErc20WithdrawalApprovalRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetErc20WithdrawalApprovalResponse>,
+            tonic::Status,
+        >;
+        /// Get last trade
+        ///
+        /// Get the last trade made for a given market.
+        async fn get_last_trade(
+            &self,
+            request: tonic::Request<super::GetLastTradeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetLastTradeResponse>,
+            tonic::Status,
+        >;
+        /// List trades
+        ///
+        /// Get a list of all trades, optionally filtered by party/market/order
+        async fn list_trades(
+            &self,
+            request: tonic::Request<super::ListTradesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListTradesResponse>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the ObserveTrades method.
+        type ObserveTradesStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::ObserveTradesResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// Observe trades
+        ///
+        /// Subscribe to a stream of trades, optionally filtered by party/market
+        async fn observe_trades(
+            &self,
+            request: tonic::Request<super::ObserveTradesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::ObserveTradesStream>,
+            tonic::Status,
+        >;
+        /// Get oracle spec
+        ///
+        /// Get an oracle spec by ID. Oracle spec IDs can be found by querying markets that use them as a data source
+        async fn get_oracle_spec(
+            &self,
+            request: tonic::Request<super::GetOracleSpecRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetOracleSpecResponse>,
+            tonic::Status,
+        >;
+        /// List oracle specs
+        ///
+        /// Get a list of all oracles specs that are defined against all markets
+        async fn list_oracle_specs(
+            &self,
+            request: tonic::Request<super::ListOracleSpecsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListOracleSpecsResponse>,
+            tonic::Status,
+        >;
+        /// List oracle data
+        ///
+        /// Get a list of all oracle data that have been broadcast to any market
+        async fn list_oracle_data(
+            &self,
+            request: tonic::Request<super::ListOracleDataRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListOracleDataResponse>,
+            tonic::Status,
+        >;
+        /// Get market
+        ///
+        /// Get information about a specific market using its ID. A market's ID will be the same as the ID of the proposal that
+        /// generated it
+        async fn get_market(
+            &self,
+            request: tonic::Request<super::GetMarketRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetMarketResponse>,
+            tonic::Status,
+        >;
+        /// List markets
+        ///
+        /// Get a list of markets
+        async fn list_markets(
+            &self,
+            request: tonic::Request<super::ListMarketsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListMarketsResponse>,
+            tonic::Status,
+        >;
+        /// List successor markets
+        ///
+        /// Given a market ID, return the full lineage of markets since inception, or all successor markets since and including
+        /// the given market ID. The markets will be returned in succession order, i.e. the market at position i will be the parent
+        /// of the market at position i+1.
+        async fn list_successor_markets(
+            &self,
+            request: tonic::Request<super::ListSuccessorMarketsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListSuccessorMarketsResponse>,
+            tonic::Status,
+        >;
+        /// Get party
+        ///
+        /// Get a single party
+        async fn get_party(
+            &self,
+            request: tonic::Request<super::GetPartyRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetPartyResponse>,
+            tonic::Status,
+        >;
+        /// List parties
+        ///
+        /// Get a list of parties
+        async fn list_parties(
+            &self,
+            request: tonic::Request<super::ListPartiesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListPartiesResponse>,
+            tonic::Status,
+        >;
+        /// List parties' profiles
+        ///
+        /// Get a list of profiles for multiple parties
+        async fn list_parties_profiles(
+            &self,
+            request: tonic::Request<super::ListPartiesProfilesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListPartiesProfilesResponse>,
+            tonic::Status,
+        >;
+        /// List margin levels
+        ///
+        /// Get a list of margin levels that match the provided criteria. If no filter is provided, all margin levels will be returned.
+        async fn list_margin_levels(
+            &self,
+            request: tonic::Request<super::ListMarginLevelsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListMarginLevelsResponse>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the ObserveMarginLevels method.
+        type ObserveMarginLevelsStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::ObserveMarginLevelsResponse,
+                    tonic::Status,
+                >,
+            >
+            + Send
+            + 'static;
+        /// Observe margin levels
+        ///
+        /// Subscribe to a stream of margin levels updates
+        async fn observe_margin_levels(
+            &self,
+            request: tonic::Request<super::ObserveMarginLevelsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::ObserveMarginLevelsStream>,
+            tonic::Status,
+        >;
+        /// List rewards
+        ///
+        /// Get a list of rewards that match the provided criteria. If no filter is provided, all rewards will be returned.
+        async fn list_rewards(
+            &self,
+            request: tonic::Request<super::ListRewardsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListRewardsResponse>,
+            tonic::Status,
+        >;
+        /// List reward summaries
+        ///
+        /// Get a list of reward summaries where the reward amount is the total rewards received over all epochs
+        /// per party ID and asset ID.
+        /// Request parameters are optional party ID and asset ID.
+        /// If no data is provided, all reward summaries will be returned grouped by party and asset ID.
+        async fn list_reward_summaries(
+            &self,
+            request: tonic::Request<super::ListRewardSummariesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListRewardSummariesResponse>,
+            tonic::Status,
+        >;
+        /// List epoch reward summaries
+        ///
+        /// Get a list of reward summaries by epoch for a given range of epochs.
+        /// The result is filtered by a list of asset IDs, market IDs and starting and ending epochs, for which to return rewards.
+        /// If no data is provided, all reward summaries will be returned, grouped by epochs, market IDs, asset IDs and reward type.
+        async fn list_epoch_reward_summaries(
+            &self,
+            request: tonic::Request<super::ListEpochRewardSummariesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListEpochRewardSummariesResponse>,
+            tonic::Status,
+        >;
+        /// Get deposit
+        ///
+        /// Get a deposit by its ID
+        async fn get_deposit(
+            &self,
+            request: tonic::Request<super::GetDepositRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetDepositResponse>,
+            tonic::Status,
+        >;
+        /// List deposits
+        ///
+        /// Get a list of deposits for a given party.
+        /// If a date range is provided, filtering will be based on the last time the deposit
+        /// has been updated in Vega time.
+        async fn list_deposits(
+            &self,
+            request: tonic::Request<super::ListDepositsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListDepositsResponse>,
+            tonic::Status,
+        >;
+        /// Get withdrawal
+        ///
+        /// Get a withdrawal by its ID. A withdrawal's ID will be the SHA3-256 hash of the signature that the withdrawal was submitted with
+        async fn get_withdrawal(
+            &self,
+            request: tonic::Request<super::GetWithdrawalRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetWithdrawalResponse>,
+            tonic::Status,
+        >;
+        /// List withdrawals
+        ///
+        /// Get a list of withdrawals for a given party
+        async fn list_withdrawals(
+            &self,
+            request: tonic::Request<super::ListWithdrawalsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListWithdrawalsResponse>,
+            tonic::Status,
+        >;
+        /// Get asset
+        ///
+        /// Get a single asset using its ID. Use the assets list query to get an asset's ID
+        async fn get_asset(
+            &self,
+            request: tonic::Request<super::GetAssetRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetAssetResponse>,
+            tonic::Status,
+        >;
+        /// List assets
+        ///
+        /// Get a list of assets available on the Vega network
+        async fn list_assets(
+            &self,
+            request: tonic::Request<super::ListAssetsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListAssetsResponse>,
+            tonic::Status,
+        >;
+        /// List liquidity provisions
+        ///
+        /// DEPRECATED: When a liquidity provider amends a provision, and it's accepted by the network, the pending
+        /// provision is returned by the API instead of the provision that is currently active.
+        /// Use ListAllLiquidityProvisions instead.
+        async fn list_liquidity_provisions(
+            &self,
+            request: tonic::Request<super::ListLiquidityProvisionsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListLiquidityProvisionsResponse>,
+            tonic::Status,
+        >;
+        /// List liquidity provisions
+        ///
+        /// Get a list of liquidity provisions for a given market. This API returns a current and pending liquidity provision
+        /// in the event that a provision has been updated by the provider but the updated provision will not be active until the next epoch.
+        async fn list_all_liquidity_provisions(
+            &self,
+            request: tonic::Request<super::ListAllLiquidityProvisionsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListAllLiquidityProvisionsResponse>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the ObserveLiquidityProvisions method.
+        type ObserveLiquidityProvisionsStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::ObserveLiquidityProvisionsResponse,
+                    tonic::Status,
+                >,
+            >
+            + Send
+            + 'static;
+        /// Observe liquidity provisions
+        ///
+        /// Subscribe to a stream of liquidity provision events for a given market and party
+        async fn observe_liquidity_provisions(
+            &self,
+            request: tonic::Request<super::ObserveLiquidityProvisionsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::ObserveLiquidityProvisionsStream>,
+            tonic::Status,
+        >;
+        /// List liquidity providers data
+        ///
+        /// List information about active liquidity provider(s) for a given market, or liquidity provider's party ID.
+        async fn list_liquidity_providers(
+            &self,
+            request: tonic::Request<super::ListLiquidityProvidersRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListLiquidityProvidersResponse>,
+            tonic::Status,
+        >;
+        /// List paid liquidity fees data
+        ///
+        /// List information about paid liquidity fees for a given market, or asset ID.
+        async fn list_paid_liquidity_fees(
+            &self,
+            request: tonic::Request<super::ListPaidLiquidityFeesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListPaidLiquidityFeesResponse>,
+            tonic::Status,
+        >;
+        /// Get governance data
+        ///
+        /// Get a single proposal's details either by proposal ID or by reference
+        async fn get_governance_data(
+            &self,
+            request: tonic::Request<super::GetGovernanceDataRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetGovernanceDataResponse>,
+            tonic::Status,
+        >;
+        /// List governance data
+        ///
+        /// Get a list of proposals
+        async fn list_governance_data(
+            &self,
+            request: tonic::Request<super::ListGovernanceDataRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListGovernanceDataResponse>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the ObserveGovernance method.
+        type ObserveGovernanceStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::ObserveGovernanceResponse,
+                    tonic::Status,
+                >,
+            >
+            + Send
+            + 'static;
+        /// Observe governance
+        ///
+        /// Subscribe to a stream of updates to governance proposals
+        async fn observe_governance(
+            &self,
+            request: tonic::Request<super::ObserveGovernanceRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::ObserveGovernanceStream>,
+            tonic::Status,
+        >;
+        /// List delegations
+        ///
+        /// Get a list of the token delegations on the network
+        async fn list_delegations(
+            &self,
+            request: tonic::Request<super::ListDelegationsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListDelegationsResponse>,
+            tonic::Status,
+        >;
+        /// Get network data
+        ///
+        /// Get data regarding the nodes of the network
+        async fn get_network_data(
+            &self,
+            request: tonic::Request<super::GetNetworkDataRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetNetworkDataResponse>,
+            tonic::Status,
+        >;
+        /// Get node
+        ///
+        /// Get information about a given node
+        async fn get_node(
+            &self,
+            request: tonic::Request<super::GetNodeRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetNodeResponse>, tonic::Status>;
+        /// List nodes
+        ///
+        /// Get a list of the nodes on the network along with the related information.
+        async fn list_nodes(
+            &self,
+            request: tonic::Request<super::ListNodesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListNodesResponse>,
+            tonic::Status,
+        >;
+        /// List node signatures
+        ///
+        /// Get a list of aggregate signatures from all the nodes of the network
+        async fn list_node_signatures(
+            &self,
+            request: tonic::Request<super::ListNodeSignaturesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListNodeSignaturesResponse>,
+            tonic::Status,
+        >;
+        /// Get epoch
+        ///
+        /// Get data for a specific epoch, if ID is omitted, it retrieves the current epoch
+        async fn get_epoch(
+            &self,
+            request: tonic::Request<super::GetEpochRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetEpochResponse>,
+            tonic::Status,
+        >;
+        /// Estimate fee
+        ///
+        /// Estimate the fee that would be incurred for submitting an order
+        /// with the specified price and size on the market.
+        async fn estimate_fee(
+            &self,
+            request: tonic::Request<super::EstimateFeeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::EstimateFeeResponse>,
+            tonic::Status,
+        >;
+        /// Estimate margin
+        ///
+        /// Estimate the margin that would be required for submitting this order
+        async fn estimate_margin(
+            &self,
+            request: tonic::Request<super::EstimateMarginRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::EstimateMarginResponse>,
+            tonic::Status,
+        >;
+        /// Estimate position
+        ///
+        /// Estimate the margin that would be required for maintaining the specified position, collateral increase needed to open the specified position and the liquidation price estimate.
+        /// Margin estimates are scaled to asset decimal places.
+        /// Liquidation price estimates are scaled to asset decimal places by default, unless an argument to scale to market decimal places is specified in the request.
+        async fn estimate_position(
+            &self,
+            request: tonic::Request<super::EstimatePositionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::EstimatePositionResponse>,
+            tonic::Status,
+        >;
+        /// List network parameters
+        ///
+        /// Get a list of the network parameter keys and their values
+        async fn list_network_parameters(
+            &self,
+            request: tonic::Request<super::ListNetworkParametersRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListNetworkParametersResponse>,
+            tonic::Status,
+        >;
+        /// Get network parameter
+        ///
+        /// Get a network parameter's value by its key
+        async fn get_network_parameter(
+            &self,
+            request: tonic::Request<super::GetNetworkParameterRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetNetworkParameterResponse>,
+            tonic::Status,
+        >;
+        /// List checkpoints
+        ///
+        /// Get a list of information about checkpoints generated by the network
+        async fn list_checkpoints(
+            &self,
+            request: tonic::Request<super::ListCheckpointsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListCheckpointsResponse>,
+            tonic::Status,
+        >;
+        /// Get stake
+        ///
+        /// Get staking information for a given party
+        async fn get_stake(
+            &self,
+            request: tonic::Request<super::GetStakeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetStakeResponse>,
+            tonic::Status,
+        >;
+        /// Get risk factors
+        ///
+        /// Get risk factor data for a given market
+        async fn get_risk_factors(
+            &self,
+            request: tonic::Request<super::GetRiskFactorsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetRiskFactorsResponse>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the ObserveEventBus method.
+        type ObserveEventBusStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::ObserveEventBusResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// Observe event bus
+        ///
+        /// Subscribe to a stream of events from the core
+        async fn observe_event_bus(
+            &self,
+            request: tonic::Request<tonic::Streaming<super::ObserveEventBusRequest>>,
+        ) -> std::result::Result<
+            tonic::Response<Self::ObserveEventBusStream>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the ObserveLedgerMovements method.
+        type ObserveLedgerMovementsStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::ObserveLedgerMovementsResponse,
+                    tonic::Status,
+                >,
+            >
+            + Send
+            + 'static;
+        /// Observe ledger movements
+        ///
+        /// Subscribe to a stream of transfer responses
+        async fn observe_ledger_movements(
+            &self,
+            request: tonic::Request<super::ObserveLedgerMovementsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::ObserveLedgerMovementsStream>,
+            tonic::Status,
+        >;
+        /// List key rotations
+        ///
+        /// Get a list of information about Vega key rotations that have been performed by validator nodes
+        async fn list_key_rotations(
+            &self,
+            request: tonic::Request<super::ListKeyRotationsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListKeyRotationsResponse>,
+            tonic::Status,
+        >;
+        /// List Ethereum key rotations
+        ///
+        /// Get a list of information about Ethereum key rotations that have been performed by validator nodes
+        async fn list_ethereum_key_rotations(
+            &self,
+            request: tonic::Request<super::ListEthereumKeyRotationsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListEthereumKeyRotationsResponse>,
+            tonic::Status,
+        >;
+        /// Get Vega time
+        ///
+        /// Get the current time of the network in Unix nanoseconds
+        async fn get_vega_time(
+            &self,
+            request: tonic::Request<super::GetVegaTimeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetVegaTimeResponse>,
+            tonic::Status,
+        >;
+        /// Get protocol upgrade status
+        ///
+        /// Get status of a protocol upgrade
+        async fn get_protocol_upgrade_status(
+            &self,
+            request: tonic::Request<super::GetProtocolUpgradeStatusRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetProtocolUpgradeStatusResponse>,
+            tonic::Status,
+        >;
+        /// List protocol upgrade proposals
+        ///
+        /// Get a list of protocol upgrade proposals, optionally filtering on status or approver.
+        async fn list_protocol_upgrade_proposals(
+            &self,
+            request: tonic::Request<super::ListProtocolUpgradeProposalsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListProtocolUpgradeProposalsResponse>,
+            tonic::Status,
+        >;
+        /// List snapshots
+        ///
+        /// Get a list of core snapshots taken
+        async fn list_core_snapshots(
+            &self,
+            request: tonic::Request<super::ListCoreSnapshotsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListCoreSnapshotsResponse>,
+            tonic::Status,
+        >;
+        /// Get most recent network history segment
+        ///
+        /// Get the network's most recently history segment
+        async fn get_most_recent_network_history_segment(
+            &self,
+            request: tonic::Request<super::GetMostRecentNetworkHistorySegmentRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetMostRecentNetworkHistorySegmentResponse>,
+            tonic::Status,
+        >;
+        /// List all network history segments
+        ///
+        /// Get a list of all history segments stored by the data node currently connected to
+        async fn list_all_network_history_segments(
+            &self,
+            request: tonic::Request<super::ListAllNetworkHistorySegmentsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListAllNetworkHistorySegmentsResponse>,
+            tonic::Status,
+        >;
+        /// Get active network history peer addresses
+        ///
+        /// Get a list of the addresses of all active network history peers
+        async fn get_active_network_history_peer_addresses(
+            &self,
+            request: tonic::Request<super::GetActiveNetworkHistoryPeerAddressesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetActiveNetworkHistoryPeerAddressesResponse>,
+            tonic::Status,
+        >;
+        /// Network history status
+        ///
+        /// Get information about the current state of network history's IPFS swarm
+        async fn get_network_history_status(
+            &self,
+            request: tonic::Request<super::GetNetworkHistoryStatusRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetNetworkHistoryStatusResponse>,
+            tonic::Status,
+        >;
+        /// Network history bootstrap peers
+        ///
+        /// Get a list of IPFS peers that can be used to initialise a new data node with network history
+        async fn get_network_history_bootstrap_peers(
+            &self,
+            request: tonic::Request<super::GetNetworkHistoryBootstrapPeersRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetNetworkHistoryBootstrapPeersResponse>,
+            tonic::Status,
+        >;
+        /// List entities
+        ///
+        /// Get a list of all entities created by transaction hash
+        async fn list_entities(
+            &self,
+            request: tonic::Request<super::ListEntitiesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListEntitiesResponse>,
+            tonic::Status,
+        >;
+        /// List funding periods
+        ///
+        /// Get a list of funding periods for a perpetual market.
+        async fn list_funding_periods(
+            &self,
+            request: tonic::Request<super::ListFundingPeriodsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListFundingPeriodsResponse>,
+            tonic::Status,
+        >;
+        /// List funding period data points
+        ///
+        /// Get a list of data points for a perpetual market's funding periods.
+        async fn list_funding_period_data_points(
+            &self,
+            request: tonic::Request<super::ListFundingPeriodDataPointsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListFundingPeriodDataPointsResponse>,
+            tonic::Status,
+        >;
+        /// List funding payments
+        ///
+        /// Get a list of funding payment gains and losses for a party as a result of their position on a perpetual market when a funding
+        /// period ends.
+        async fn list_funding_payments(
+            &self,
+            request: tonic::Request<super::ListFundingPaymentsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListFundingPaymentsResponse>,
+            tonic::Status,
+        >;
+        /// List party activity streak
+        ///
+        /// Get a party's activity across epochs.
+        async fn get_party_activity_streak(
+            &self,
+            request: tonic::Request<super::GetPartyActivityStreakRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetPartyActivityStreakResponse>,
+            tonic::Status,
+        >;
+        /// Get current referral program
+        ///
+        /// Get the current referral program for the network. This program may not be active if it has ended
+        /// and has not been replaced by another.
+        async fn get_current_referral_program(
+            &self,
+            request: tonic::Request<super::GetCurrentReferralProgramRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetCurrentReferralProgramResponse>,
+            tonic::Status,
+        >;
+        /// List referral sets
+        ///
+        /// List all referral sets, or a specific referral set if you know its ID.
+        async fn list_referral_sets(
+            &self,
+            request: tonic::Request<super::ListReferralSetsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListReferralSetsResponse>,
+            tonic::Status,
+        >;
+        /// List referral set referees
+        ///
+        /// List all referees that belong to a referral set.
+        async fn list_referral_set_referees(
+            &self,
+            request: tonic::Request<super::ListReferralSetRefereesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListReferralSetRefereesResponse>,
+            tonic::Status,
+        >;
+        /// Get referral set statistics
+        ///
+        /// Get the total taker volume, and each referee's taker volume and, reward and discount factors for a referral set
+        /// at the latest or a specific epoch. You can also optionally filter for a specific referee's statistics.
+        async fn get_referral_set_stats(
+            &self,
+            request: tonic::Request<super::GetReferralSetStatsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetReferralSetStatsResponse>,
+            tonic::Status,
+        >;
+        /// List teams
+        ///
+        /// Get a list of all teams, or for a specific team by using team ID, or party ID of a referrer or referee
+        async fn list_teams(
+            &self,
+            request: tonic::Request<super::ListTeamsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListTeamsResponse>,
+            tonic::Status,
+        >;
+        /// List teams statistics
+        ///
+        /// Get the statistics of all teams, or for a specific team by using team ID, over a number of epochs.
+        /// If a team does not have at least the number of epochs' worth of data, it is ignored.
+        async fn list_teams_statistics(
+            &self,
+            request: tonic::Request<super::ListTeamsStatisticsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListTeamsStatisticsResponse>,
+            tonic::Status,
+        >;
+        /// List team members' statistics
+        ///
+        /// Get the statistics for all members of a given team, or for a specific member by using party ID, over a number of epochs.
+        /// If a team does not have at least the number of epochs' worth of data, it is ignored.
+        async fn list_team_members_statistics(
+            &self,
+            request: tonic::Request<super::ListTeamMembersStatisticsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListTeamMembersStatisticsResponse>,
+            tonic::Status,
+        >;
+        /// List team referees
+        ///
+        /// Get a list of all referees for a given team ID
+        async fn list_team_referees(
+            &self,
+            request: tonic::Request<super::ListTeamRefereesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListTeamRefereesResponse>,
+            tonic::Status,
+        >;
+        /// List referee team history
+        ///
+        /// Get a list of a referee's team history, i.e. the teams that a referee has been a member of and transferred from/to.
+        async fn list_team_referee_history(
+            &self,
+            request: tonic::Request<super::ListTeamRefereeHistoryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListTeamRefereeHistoryResponse>,
+            tonic::Status,
+        >;
+        /// Get fees statistics
+        ///
+        /// Get accumulated fees, rewards, and applied discount information. Either a market or an asset must be supplied as a filter.
+        async fn get_fees_stats(
+            &self,
+            request: tonic::Request<super::GetFeesStatsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetFeesStatsResponse>,
+            tonic::Status,
+        >;
+        /// Get fees statistics for a party
+        ///
+        /// Get accumulated fees, rewards, and applied discount information. A party ID must be supplied as a filter.
+        async fn get_fees_stats_for_party(
+            &self,
+            request: tonic::Request<super::GetFeesStatsForPartyRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetFeesStatsForPartyResponse>,
+            tonic::Status,
+        >;
+        /// Get current volume discount program
+        ///
+        /// Get the current volume discount program for the network. This program may not be active if it has ended
+        /// and has not been replaced by another.
+        async fn get_current_volume_discount_program(
+            &self,
+            request: tonic::Request<super::GetCurrentVolumeDiscountProgramRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetCurrentVolumeDiscountProgramResponse>,
+            tonic::Status,
+        >;
+        /// Get volume discount statistics
+        ///
+        /// Get the information about a party's running traded volume, and the discount factor it earns them.
+        async fn get_volume_discount_stats(
+            &self,
+            request: tonic::Request<super::GetVolumeDiscountStatsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetVolumeDiscountStatsResponse>,
+            tonic::Status,
+        >;
+        /// Get vesting balances summary
+        ///
+        /// Get information about a party's vesting and locked balances
+        async fn get_vesting_balances_summary(
+            &self,
+            request: tonic::Request<super::GetVestingBalancesSummaryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetVestingBalancesSummaryResponse>,
+            tonic::Status,
+        >;
+        /// Get vesting balance statistics
+        ///
+        /// Get information about a party's vesting rewards
+        async fn get_party_vesting_stats(
+            &self,
+            request: tonic::Request<super::GetPartyVestingStatsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetPartyVestingStatsResponse>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the ObserveTransactionResults method.
+        type ObserveTransactionResultsStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::ObserveTransactionResultsResponse,
+                    tonic::Status,
+                >,
+            >
+            + Send
+            + 'static;
+        /// Observe transaction results
+        ///
+        /// Subscribe to a stream of transaction results, optionally filtered by party/hash/status
+        async fn observe_transaction_results(
+            &self,
+            request: tonic::Request<super::ObserveTransactionResultsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::ObserveTransactionResultsStream>,
+            tonic::Status,
+        >;
+        /// Estimate transfer fee costs
+        ///
+        /// Estimate transfer fee costs with potential discount applied
+        async fn estimate_transfer_fee(
+            &self,
+            request: tonic::Request<super::EstimateTransferFeeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::EstimateTransferFeeResponse>,
+            tonic::Status,
+        >;
+        /// Available transfer fee discount
+        ///
+        /// Returns available per party per asset transfer discount
+        async fn get_total_transfer_fee_discount(
+            &self,
+            request: tonic::Request<super::GetTotalTransferFeeDiscountRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetTotalTransferFeeDiscountResponse>,
+            tonic::Status,
+        >;
+        /// List games
+        ///
+        /// Get a list of games and corresponding game data, given the provided filters
+        async fn list_games(
+            &self,
+            request: tonic::Request<super::ListGamesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListGamesResponse>,
+            tonic::Status,
+        >;
+        /// List margin modes per party per market
+        ///
+        /// Get a list of all margin modes, or for a specific market ID, or party ID.
+        async fn list_party_margin_modes(
+            &self,
+            request: tonic::Request<super::ListPartyMarginModesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListPartyMarginModesResponse>,
+            tonic::Status,
+        >;
+        /// Get time weighted notional position
+        ///
+        /// Get the time weighted notional position for a given party and asset. The time weighted notional position
+        /// is used to check if a party qualifies for a reward.
+        /// If no epoch is specified, the final time weighted notional position from the end of the most recently completed epoch is returned.
+        /// If an epoch is specified, the final time weighted notional position at that epoch is returned.
+        async fn get_time_weighted_notional_position(
+            &self,
+            request: tonic::Request<super::GetTimeWeightedNotionalPositionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetTimeWeightedNotionalPositionResponse>,
+            tonic::Status,
+        >;
+        /// List AMMs
+        ///
+        /// Get a list of AMM or filter by market ID, party ID or AMM ID
+        async fn list_am_ms(
+            &self,
+            request: tonic::Request<super::ListAmMsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListAmMsResponse>,
+            tonic::Status,
+        >;
+        /// Estimate AMM bounds
+        ///
+        /// Get a list of AMMs or filter by market ID, party ID or AMM ID
+        async fn estimate_amm_bounds(
+            &self,
+            request: tonic::Request<super::EstimateAmmBoundsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::EstimateAmmBoundsResponse>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the ExportNetworkHistory method.
+        type ExportNetworkHistoryStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::super::super::super::google::api::HttpBody,
+                    tonic::Status,
+                >,
+            >
+            + Send
+            + 'static;
+        /// Export network history as CSV
+        ///
+        /// Export CSV table data from network history between two block heights.
+        ///
+        /// The requested block heights must fall on network history segment boundaries, which can
+        /// be discovered by calling the API to list all network history segments. By default
+        /// segments contain 1000 blocks. In that case ranges such as (1, 1000), (1001, 2000), (1, 3000)
+        /// would all fall on segment boundaries and be valid.
+        ///
+        /// The generated CSV file is compressed into a ZIP file and returned, with the file name
+        /// in the following format: `[chain id]-[table name]-[start block]-[end block].zip`
+        ///
+        /// In gRPC, results are returned in a chunked stream of base64 encoded data.
+        ///
+        /// Through the REST gateway, the base64 data chunks are decoded and streamed as a
+        /// `content-type: application/zip` HTTP response.
+        ///
+        /// The CSV exported data uses a comma as a DELIMITER between fields, and " for QUOTE-ing fields.
+        ///
+        /// If a value contains any of: DELIMITER, QUOTE, carriage return, or line feed then the whole
+        /// value is prefixed and suffixed by the QUOTE character and any occurrence within
+        /// the value of a QUOTE character preceded by another QUOTE.
+        ///
+        /// A NULL is output as the NULL parameter string and is not quoted, while a non-NULL value
+        /// matching the NULL parameter string is quoted.
+        ///
+        /// For example, with the default settings, a NULL is written as an unquoted empty string,
+        /// while an empty string data value is written with double quotes.
+        ///
+        /// Note that CSV files produced may contain quoted values containing embedded carriage returns and line feeds.
+        /// Thus the files are not strictly one line per table row like text-format files.
+        ///
+        /// The first row of the CSV file is a header that describes the contents of each column
+        /// in subsequent rows.
+        ///
+        /// Usually the ZIP file will contain only a single CSV file. However it is possible that
+        /// the (from_block, to_block) request spans over a range of blocks in which the underlying
+        /// schema of the database changes. For example, a column may have been added, removed, or renamed.
+        ///
+        /// If this happens, the CSV file will be split at the point of the schema change and the zip
+        /// file will contain multiple CSV files, with a potentially different set of headers. The
+        /// 'version' number of the database schema is part of the in the CSV filename:
+        ///
+        ///  `[chain id]-[table name]-[schema version]-[start block]-[end block].zip`
+        ///
+        /// For example, a zip file might be called mainnet-sometable-000001-003000.zip
+        ///
+        /// And contain two CSV files: `mainnet-sometable-1-000001-002000.csv`:
+        ///
+        /// timestamp, value
+        /// 1, foo
+        /// 2, bar
+        ///
+        /// And `mainnet-sometable-2-002001-003000.csv`:
+        ///
+        /// timestamp, value, extra_value
+        /// 3, baz, apple
+        ///
+        /// It is worth noting that the schema will not change within a single network history segment.
+        /// buf:lint:ignore RPC_RESPONSE_STANDARD_NAME
+        /// buf:lint:ignore RPC_REQUEST_RESPONSE_UNIQUE
+        async fn export_network_history(
+            &self,
+            request: tonic::Request<super::ExportNetworkHistoryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::ExportNetworkHistoryStream>,
+            tonic::Status,
+        >;
+        /// Ping
+        ///
+        /// Ping the data node
+        async fn ping(
+            &self,
+            request: tonic::Request<super::PingRequest>,
+        ) -> std::result::Result<tonic::Response<super::PingResponse>, tonic::Status>;
+    }
+    #[derive(Debug)]
+    pub struct TradingDataServiceServer<T: TradingDataService> {
+        inner: _Inner<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    struct _Inner<T>(Arc<T>);
+    impl<T: TradingDataService> TradingDataServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            let inner = _Inner(inner);
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for TradingDataServiceServer<T>
+    where
+        T: TradingDataService,
+        B: Body + Send + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            let inner = self.inner.clone();
+            match req.uri().path() {
+                "/datanode.api.v2.TradingDataService/ListAccounts" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListAccountsSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::ListAccountsRequest>
+                    for ListAccountsSvc<T> {
+                        type Response = super::ListAccountsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListAccountsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_accounts(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListAccountsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ObserveAccounts" => {
+                    #[allow(non_camel_case_types)]
+                    struct ObserveAccountsSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::ServerStreamingService<
+                        super::ObserveAccountsRequest,
+                    > for ObserveAccountsSvc<T> {
+                        type Response = super::ObserveAccountsResponse;
+                        type ResponseStream = T::ObserveAccountsStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ObserveAccountsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::observe_accounts(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ObserveAccountsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/Info" => {
+                    #[allow(non_camel_case_types)]
+                    struct InfoSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::InfoRequest> for InfoSvc<T> {
+                        type Response = super::InfoResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::InfoRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::info(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = InfoSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/GetOrder" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetOrderSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::GetOrderRequest>
+                    for GetOrderSvc<T> {
+                        type Response = super::GetOrderResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetOrderRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::get_order(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetOrderSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ListOrders" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListOrdersSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::ListOrdersRequest>
+                    for ListOrdersSvc<T> {
+                        type Response = super::ListOrdersResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListOrdersRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_orders(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListOrdersSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ListOrderVersions" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListOrderVersionsSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::ListOrderVersionsRequest>
+                    for ListOrderVersionsSvc<T> {
+                        type Response = super::ListOrderVersionsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListOrderVersionsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_order_versions(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListOrderVersionsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ObserveOrders" => {
+                    #[allow(non_camel_case_types)]
+                    struct ObserveOrdersSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::ServerStreamingService<super::ObserveOrdersRequest>
+                    for ObserveOrdersSvc<T> {
+                        type Response = super::ObserveOrdersResponse;
+                        type ResponseStream = T::ObserveOrdersStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ObserveOrdersRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::observe_orders(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ObserveOrdersSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/GetStopOrder" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetStopOrderSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::GetStopOrderRequest>
+                    for GetStopOrderSvc<T> {
+                        type Response = super::GetStopOrderResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetStopOrderRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::get_stop_order(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetStopOrderSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ListStopOrders" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListStopOrdersSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::ListStopOrdersRequest>
+                    for ListStopOrdersSvc<T> {
+                        type Response = super::ListStopOrdersResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListStopOrdersRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_stop_orders(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListStopOrdersSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ListGameTeamScores" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListGameTeamScoresSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::ListGameTeamScoresRequest>
+                    for ListGameTeamScoresSvc<T> {
+                        type Response = super::ListGameTeamScoresResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListGameTeamScoresRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_game_team_scores(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListGameTeamScoresSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ListGamePartyScores" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListGamePartyScoresSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::ListGamePartyScoresRequest>
+                    for ListGamePartyScoresSvc<T> {
+                        type Response = super::ListGamePartyScoresResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListGamePartyScoresRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_game_party_scores(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListGamePartyScoresSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ListPositions" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListPositionsSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::ListPositionsRequest>
+                    for ListPositionsSvc<T> {
+                        type Response = super::ListPositionsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListPositionsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_positions(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListPositionsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ListAllPositions" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListAllPositionsSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::ListAllPositionsRequest>
+                    for ListAllPositionsSvc<T> {
+                        type Response = super::ListAllPositionsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListAllPositionsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_all_positions(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListAllPositionsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ObservePositions" => {
+                    #[allow(non_camel_case_types)]
+                    struct ObservePositionsSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::ServerStreamingService<
+                        super::ObservePositionsRequest,
+                    > for ObservePositionsSvc<T> {
+                        type Response = super::ObservePositionsResponse;
+                        type ResponseStream = T::ObservePositionsStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ObservePositionsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::observe_positions(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ObservePositionsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ListLedgerEntries" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListLedgerEntriesSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::ListLedgerEntriesRequest>
+                    for ListLedgerEntriesSvc<T> {
+                        type Response = super::ListLedgerEntriesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListLedgerEntriesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_ledger_entries(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListLedgerEntriesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ExportLedgerEntries" => {
+                    #[allow(non_camel_case_types)]
+                    struct ExportLedgerEntriesSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::ServerStreamingService<
+                        super::ExportLedgerEntriesRequest,
+                    > for ExportLedgerEntriesSvc<T> {
+                        type Response = super::super::super::super::google::api::HttpBody;
+                        type ResponseStream = T::ExportLedgerEntriesStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ExportLedgerEntriesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::export_ledger_entries(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ExportLedgerEntriesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ListBalanceChanges" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListBalanceChangesSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::ListBalanceChangesRequest>
+                    for ListBalanceChangesSvc<T> {
+                        type Response = super::ListBalanceChangesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListBalanceChangesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_balance_changes(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListBalanceChangesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/GetLatestMarketData" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetLatestMarketDataSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::GetLatestMarketDataRequest>
+                    for GetLatestMarketDataSvc<T> {
+                        type Response = super::GetLatestMarketDataResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetLatestMarketDataRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::get_latest_market_data(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetLatestMarketDataSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ListLatestMarketData" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListLatestMarketDataSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::ListLatestMarketDataRequest>
+                    for ListLatestMarketDataSvc<T> {
+                        type Response = super::ListLatestMarketDataResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListLatestMarketDataRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_latest_market_data(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListLatestMarketDataSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/GetLatestMarketDepth" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetLatestMarketDepthSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::GetLatestMarketDepthRequest>
+                    for GetLatestMarketDepthSvc<T> {
+                        type Response = super::GetLatestMarketDepthResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetLatestMarketDepthRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::get_latest_market_depth(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetLatestMarketDepthSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ObserveMarketsDepth" => {
+                    #[allow(non_camel_case_types)]
+                    struct ObserveMarketsDepthSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::ServerStreamingService<
+                        super::ObserveMarketsDepthRequest,
+                    > for ObserveMarketsDepthSvc<T> {
+                        type Response = super::ObserveMarketsDepthResponse;
+                        type ResponseStream = T::ObserveMarketsDepthStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ObserveMarketsDepthRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::observe_markets_depth(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ObserveMarketsDepthSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ObserveMarketsDepthUpdates" => {
+                    #[allow(non_camel_case_types)]
+                    struct ObserveMarketsDepthUpdatesSvc<T: TradingDataService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::ServerStreamingService<
+                        super::ObserveMarketsDepthUpdatesRequest,
+                    > for ObserveMarketsDepthUpdatesSvc<T> {
+                        type Response = super::ObserveMarketsDepthUpdatesResponse;
+                        type ResponseStream = T::ObserveMarketsDepthUpdatesStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::ObserveMarketsDepthUpdatesRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::observe_markets_depth_updates(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ObserveMarketsDepthUpdatesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ObserveMarketsData" => {
+                    #[allow(non_camel_case_types)]
+                    struct ObserveMarketsDataSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::ServerStreamingService<
+                        super::ObserveMarketsDataRequest,
+                    > for ObserveMarketsDataSvc<T> {
+                        type Response = super::ObserveMarketsDataResponse;
+                        type ResponseStream = T::ObserveMarketsDataStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ObserveMarketsDataRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::observe_markets_data(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ObserveMarketsDataSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/GetMarketDataHistoryByID" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetMarketDataHistoryByIDSvc<T: TradingDataService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::GetMarketDataHistoryByIdRequest>
+                    for GetMarketDataHistoryByIDSvc<T> {
+                        type Response = super::GetMarketDataHistoryByIdResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::GetMarketDataHistoryByIdRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::get_market_data_history_by_id(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetMarketDataHistoryByIDSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ListTransfers" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListTransfersSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::ListTransfersRequest>
+                    for ListTransfersSvc<T> {
+                        type Response = super::ListTransfersResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListTransfersRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_transfers(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListTransfersSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/GetTransfer" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetTransferSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::GetTransferRequest>
+                    for GetTransferSvc<T> {
+                        type Response = super::GetTransferResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetTransferRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::get_transfer(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetTransferSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/GetNetworkLimits" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetNetworkLimitsSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::GetNetworkLimitsRequest>
+                    for GetNetworkLimitsSvc<T> {
+                        type Response = super::GetNetworkLimitsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetNetworkLimitsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::get_network_limits(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetNetworkLimitsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ListCandleData" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListCandleDataSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::ListCandleDataRequest>
+                    for ListCandleDataSvc<T> {
+                        type Response = super::ListCandleDataResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListCandleDataRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_candle_data(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListCandleDataSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ObserveCandleData" => {
+                    #[allow(non_camel_case_types)]
+                    struct ObserveCandleDataSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::ServerStreamingService<
+                        super::ObserveCandleDataRequest,
+                    > for ObserveCandleDataSvc<T> {
+                        type Response = super::ObserveCandleDataResponse;
+                        type ResponseStream = T::ObserveCandleDataStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ObserveCandleDataRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::observe_candle_data(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ObserveCandleDataSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ListCandleIntervals" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListCandleIntervalsSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::ListCandleIntervalsRequest>
+                    for ListCandleIntervalsSvc<T> {
+                        type Response = super::ListCandleIntervalsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListCandleIntervalsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_candle_intervals(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListCandleIntervalsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ListVotes" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListVotesSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::ListVotesRequest>
+                    for ListVotesSvc<T> {
+                        type Response = super::ListVotesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListVotesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_votes(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListVotesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ObserveVotes" => {
+                    #[allow(non_camel_case_types)]
+                    struct ObserveVotesSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::ServerStreamingService<super::ObserveVotesRequest>
+                    for ObserveVotesSvc<T> {
+                        type Response = super::ObserveVotesResponse;
+                        type ResponseStream = T::ObserveVotesStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ObserveVotesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::observe_votes(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ObserveVotesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ListERC20MultiSigSignerAddedBundles" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListERC20MultiSigSignerAddedBundlesSvc<T: TradingDataService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<
+                        super::ListErc20MultiSigSignerAddedBundlesRequest,
+                    > for ListERC20MultiSigSignerAddedBundlesSvc<T> {
+                        type Response = super::ListErc20MultiSigSignerAddedBundlesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::ListErc20MultiSigSignerAddedBundlesRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_erc20_multi_sig_signer_added_bundles(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListERC20MultiSigSignerAddedBundlesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ListERC20MultiSigSignerRemovedBundles" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListERC20MultiSigSignerRemovedBundlesSvc<
+                        T: TradingDataService,
+                    >(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<
+                        super::ListErc20MultiSigSignerRemovedBundlesRequest,
+                    > for ListERC20MultiSigSignerRemovedBundlesSvc<T> {
+                        type Response = super::ListErc20MultiSigSignerRemovedBundlesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::ListErc20MultiSigSignerRemovedBundlesRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_erc20_multi_sig_signer_removed_bundles(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListERC20MultiSigSignerRemovedBundlesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/GetERC20ListAssetBundle" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetERC20ListAssetBundleSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::GetErc20ListAssetBundleRequest>
+                    for GetERC20ListAssetBundleSvc<T> {
+                        type Response = super::GetErc20ListAssetBundleResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::GetErc20ListAssetBundleRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::get_erc20_list_asset_bundle(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetERC20ListAssetBundleSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/GetERC20SetAssetLimitsBundle" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetERC20SetAssetLimitsBundleSvc<T: TradingDataService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<
+                        super::GetErc20SetAssetLimitsBundleRequest,
+                    > for GetERC20SetAssetLimitsBundleSvc<T> {
+                        type Response = super::GetErc20SetAssetLimitsBundleResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::GetErc20SetAssetLimitsBundleRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::get_erc20_set_asset_limits_bundle(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetERC20SetAssetLimitsBundleSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/GetERC20WithdrawalApproval" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetERC20WithdrawalApprovalSvc<T: TradingDataService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<
+                        super::GetErc20WithdrawalApprovalRequest,
+                    > for GetERC20WithdrawalApprovalSvc<T> {
+                        type Response = super::GetErc20WithdrawalApprovalResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::GetErc20WithdrawalApprovalRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::get_erc20_withdrawal_approval(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetERC20WithdrawalApprovalSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/GetLastTrade" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetLastTradeSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::GetLastTradeRequest>
+                    for GetLastTradeSvc<T> {
+                        type Response = super::GetLastTradeResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetLastTradeRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::get_last_trade(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetLastTradeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ListTrades" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListTradesSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::ListTradesRequest>
+                    for ListTradesSvc<T> {
+                        type Response = super::ListTradesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListTradesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_trades(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListTradesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ObserveTrades" => {
+                    #[allow(non_camel_case_types)]
+                    struct ObserveTradesSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::ServerStreamingService<super::ObserveTradesRequest>
+                    for ObserveTradesSvc<T> {
+                        type Response = super::ObserveTradesResponse;
+                        type ResponseStream = T::ObserveTradesStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ObserveTradesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::observe_trades(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ObserveTradesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/GetOracleSpec" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetOracleSpecSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::GetOracleSpecRequest>
+                    for GetOracleSpecSvc<T> {
+                        type Response = super::GetOracleSpecResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetOracleSpecRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::get_oracle_spec(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetOracleSpecSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ListOracleSpecs" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListOracleSpecsSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::ListOracleSpecsRequest>
+                    for ListOracleSpecsSvc<T> {
+                        type Response = super::ListOracleSpecsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListOracleSpecsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_oracle_specs(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListOracleSpecsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ListOracleData" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListOracleDataSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::ListOracleDataRequest>
+                    for ListOracleDataSvc<T> {
+                        type Response = super::ListOracleDataResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListOracleDataRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_oracle_data(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListOracleDataSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/GetMarket" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetMarketSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::GetMarketRequest>
+                    for GetMarketSvc<T> {
+                        type Response = super::GetMarketResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetMarketRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::get_market(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetMarketSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ListMarkets" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListMarketsSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::ListMarketsRequest>
+                    for ListMarketsSvc<T> {
+                        type Response = super::ListMarketsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListMarketsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_markets(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListMarketsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ListSuccessorMarkets" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListSuccessorMarketsSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::ListSuccessorMarketsRequest>
+                    for ListSuccessorMarketsSvc<T> {
+                        type Response = super::ListSuccessorMarketsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListSuccessorMarketsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_successor_markets(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListSuccessorMarketsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/GetParty" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetPartySvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::GetPartyRequest>
+                    for GetPartySvc<T> {
+                        type Response = super::GetPartyResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetPartyRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::get_party(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetPartySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ListParties" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListPartiesSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::ListPartiesRequest>
+                    for ListPartiesSvc<T> {
+                        type Response = super::ListPartiesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListPartiesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_parties(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListPartiesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ListPartiesProfiles" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListPartiesProfilesSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::ListPartiesProfilesRequest>
+                    for ListPartiesProfilesSvc<T> {
+                        type Response = super::ListPartiesProfilesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListPartiesProfilesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_parties_profiles(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListPartiesProfilesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ListMarginLevels" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListMarginLevelsSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::ListMarginLevelsRequest>
+                    for ListMarginLevelsSvc<T> {
+                        type Response = super::ListMarginLevelsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListMarginLevelsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_margin_levels(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListMarginLevelsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ObserveMarginLevels" => {
+                    #[allow(non_camel_case_types)]
+                    struct ObserveMarginLevelsSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::ServerStreamingService<
+                        super::ObserveMarginLevelsRequest,
+                    > for ObserveMarginLevelsSvc<T> {
+                        type Response = super::ObserveMarginLevelsResponse;
+                        type ResponseStream = T::ObserveMarginLevelsStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ObserveMarginLevelsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::observe_margin_levels(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ObserveMarginLevelsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ListRewards" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListRewardsSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::ListRewardsRequest>
+                    for ListRewardsSvc<T> {
+                        type Response = super::ListRewardsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListRewardsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_rewards(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListRewardsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ListRewardSummaries" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListRewardSummariesSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::ListRewardSummariesRequest>
+                    for ListRewardSummariesSvc<T> {
+                        type Response = super::ListRewardSummariesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListRewardSummariesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_reward_summaries(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListRewardSummariesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ListEpochRewardSummaries" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListEpochRewardSummariesSvc<T: TradingDataService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::ListEpochRewardSummariesRequest>
+                    for ListEpochRewardSummariesSvc<T> {
+                        type Response = super::ListEpochRewardSummariesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::ListEpochRewardSummariesRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_epoch_reward_summaries(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListEpochRewardSummariesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/GetDeposit" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetDepositSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::GetDepositRequest>
+                    for GetDepositSvc<T> {
+                        type Response = super::GetDepositResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetDepositRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::get_deposit(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetDepositSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ListDeposits" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListDepositsSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::ListDepositsRequest>
+                    for ListDepositsSvc<T> {
+                        type Response = super::ListDepositsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListDepositsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_deposits(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListDepositsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/GetWithdrawal" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetWithdrawalSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::GetWithdrawalRequest>
+                    for GetWithdrawalSvc<T> {
+                        type Response = super::GetWithdrawalResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetWithdrawalRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::get_withdrawal(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetWithdrawalSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ListWithdrawals" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListWithdrawalsSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::ListWithdrawalsRequest>
+                    for ListWithdrawalsSvc<T> {
+                        type Response = super::ListWithdrawalsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListWithdrawalsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_withdrawals(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListWithdrawalsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/GetAsset" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetAssetSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::GetAssetRequest>
+                    for GetAssetSvc<T> {
+                        type Response = super::GetAssetResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetAssetRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::get_asset(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetAssetSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ListAssets" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListAssetsSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::ListAssetsRequest>
+                    for ListAssetsSvc<T> {
+                        type Response = super::ListAssetsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListAssetsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_assets(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListAssetsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ListLiquidityProvisions" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListLiquidityProvisionsSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::ListLiquidityProvisionsRequest>
+                    for ListLiquidityProvisionsSvc<T> {
+                        type Response = super::ListLiquidityProvisionsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::ListLiquidityProvisionsRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_liquidity_provisions(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListLiquidityProvisionsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ListAllLiquidityProvisions" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListAllLiquidityProvisionsSvc<T: TradingDataService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<
+                        super::ListAllLiquidityProvisionsRequest,
+                    > for ListAllLiquidityProvisionsSvc<T> {
+                        type Response = super::ListAllLiquidityProvisionsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::ListAllLiquidityProvisionsRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_all_liquidity_provisions(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListAllLiquidityProvisionsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ObserveLiquidityProvisions" => {
+                    #[allow(non_camel_case_types)]
+                    struct ObserveLiquidityProvisionsSvc<T: TradingDataService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::ServerStreamingService<
+                        super::ObserveLiquidityProvisionsRequest,
+                    > for ObserveLiquidityProvisionsSvc<T> {
+                        type Response = super::ObserveLiquidityProvisionsResponse;
+                        type ResponseStream = T::ObserveLiquidityProvisionsStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::ObserveLiquidityProvisionsRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::observe_liquidity_provisions(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ObserveLiquidityProvisionsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ListLiquidityProviders" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListLiquidityProvidersSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::ListLiquidityProvidersRequest>
+                    for ListLiquidityProvidersSvc<T> {
+                        type Response = super::ListLiquidityProvidersResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListLiquidityProvidersRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_liquidity_providers(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListLiquidityProvidersSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ListPaidLiquidityFees" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListPaidLiquidityFeesSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::ListPaidLiquidityFeesRequest>
+                    for ListPaidLiquidityFeesSvc<T> {
+                        type Response = super::ListPaidLiquidityFeesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListPaidLiquidityFeesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_paid_liquidity_fees(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListPaidLiquidityFeesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/GetGovernanceData" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetGovernanceDataSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::GetGovernanceDataRequest>
+                    for GetGovernanceDataSvc<T> {
+                        type Response = super::GetGovernanceDataResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetGovernanceDataRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::get_governance_data(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetGovernanceDataSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ListGovernanceData" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListGovernanceDataSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::ListGovernanceDataRequest>
+                    for ListGovernanceDataSvc<T> {
+                        type Response = super::ListGovernanceDataResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListGovernanceDataRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_governance_data(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListGovernanceDataSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ObserveGovernance" => {
+                    #[allow(non_camel_case_types)]
+                    struct ObserveGovernanceSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::ServerStreamingService<
+                        super::ObserveGovernanceRequest,
+                    > for ObserveGovernanceSvc<T> {
+                        type Response = super::ObserveGovernanceResponse;
+                        type ResponseStream = T::ObserveGovernanceStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ObserveGovernanceRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::observe_governance(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ObserveGovernanceSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ListDelegations" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListDelegationsSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::ListDelegationsRequest>
+                    for ListDelegationsSvc<T> {
+                        type Response = super::ListDelegationsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListDelegationsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_delegations(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListDelegationsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/GetNetworkData" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetNetworkDataSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::GetNetworkDataRequest>
+                    for GetNetworkDataSvc<T> {
+                        type Response = super::GetNetworkDataResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetNetworkDataRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::get_network_data(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetNetworkDataSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/GetNode" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetNodeSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::GetNodeRequest>
+                    for GetNodeSvc<T> {
+                        type Response = super::GetNodeResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetNodeRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::get_node(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetNodeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ListNodes" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListNodesSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::ListNodesRequest>
+                    for ListNodesSvc<T> {
+                        type Response = super::ListNodesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListNodesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_nodes(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListNodesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ListNodeSignatures" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListNodeSignaturesSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::ListNodeSignaturesRequest>
+                    for ListNodeSignaturesSvc<T> {
+                        type Response = super::ListNodeSignaturesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListNodeSignaturesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_node_signatures(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListNodeSignaturesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/GetEpoch" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetEpochSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::GetEpochRequest>
+                    for GetEpochSvc<T> {
+                        type Response = super::GetEpochResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetEpochRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::get_epoch(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetEpochSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/EstimateFee" => {
+                    #[allow(non_camel_case_types)]
+                    struct EstimateFeeSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::EstimateFeeRequest>
+                    for EstimateFeeSvc<T> {
+                        type Response = super::EstimateFeeResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::EstimateFeeRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::estimate_fee(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = EstimateFeeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/EstimateMargin" => {
+                    #[allow(non_camel_case_types)]
+                    struct EstimateMarginSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::EstimateMarginRequest>
+                    for EstimateMarginSvc<T> {
+                        type Response = super::EstimateMarginResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::EstimateMarginRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::estimate_margin(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = EstimateMarginSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/EstimatePosition" => {
+                    #[allow(non_camel_case_types)]
+                    struct EstimatePositionSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::EstimatePositionRequest>
+                    for EstimatePositionSvc<T> {
+                        type Response = super::EstimatePositionResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::EstimatePositionRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::estimate_position(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = EstimatePositionSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ListNetworkParameters" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListNetworkParametersSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::ListNetworkParametersRequest>
+                    for ListNetworkParametersSvc<T> {
+                        type Response = super::ListNetworkParametersResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListNetworkParametersRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_network_parameters(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListNetworkParametersSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/GetNetworkParameter" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetNetworkParameterSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::GetNetworkParameterRequest>
+                    for GetNetworkParameterSvc<T> {
+                        type Response = super::GetNetworkParameterResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetNetworkParameterRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::get_network_parameter(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetNetworkParameterSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ListCheckpoints" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListCheckpointsSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::ListCheckpointsRequest>
+                    for ListCheckpointsSvc<T> {
+                        type Response = super::ListCheckpointsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListCheckpointsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_checkpoints(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListCheckpointsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/GetStake" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetStakeSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::GetStakeRequest>
+                    for GetStakeSvc<T> {
+                        type Response = super::GetStakeResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetStakeRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::get_stake(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetStakeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/GetRiskFactors" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetRiskFactorsSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::GetRiskFactorsRequest>
+                    for GetRiskFactorsSvc<T> {
+                        type Response = super::GetRiskFactorsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetRiskFactorsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::get_risk_factors(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetRiskFactorsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ObserveEventBus" => {
+                    #[allow(non_camel_case_types)]
+                    struct ObserveEventBusSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::StreamingService<super::ObserveEventBusRequest>
+                    for ObserveEventBusSvc<T> {
+                        type Response = super::ObserveEventBusResponse;
+                        type ResponseStream = T::ObserveEventBusStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                tonic::Streaming<super::ObserveEventBusRequest>,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::observe_event_bus(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ObserveEventBusSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ObserveLedgerMovements" => {
+                    #[allow(non_camel_case_types)]
+                    struct ObserveLedgerMovementsSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::ServerStreamingService<
+                        super::ObserveLedgerMovementsRequest,
+                    > for ObserveLedgerMovementsSvc<T> {
+                        type Response = super::ObserveLedgerMovementsResponse;
+                        type ResponseStream = T::ObserveLedgerMovementsStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ObserveLedgerMovementsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::observe_ledger_movements(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ObserveLedgerMovementsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ListKeyRotations" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListKeyRotationsSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::ListKeyRotationsRequest>
+                    for ListKeyRotationsSvc<T> {
+                        type Response = super::ListKeyRotationsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListKeyRotationsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_key_rotations(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListKeyRotationsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ListEthereumKeyRotations" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListEthereumKeyRotationsSvc<T: TradingDataService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::ListEthereumKeyRotationsRequest>
+                    for ListEthereumKeyRotationsSvc<T> {
+                        type Response = super::ListEthereumKeyRotationsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::ListEthereumKeyRotationsRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_ethereum_key_rotations(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListEthereumKeyRotationsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/GetVegaTime" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetVegaTimeSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::GetVegaTimeRequest>
+                    for GetVegaTimeSvc<T> {
+                        type Response = super::GetVegaTimeResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetVegaTimeRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::get_vega_time(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetVegaTimeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/GetProtocolUpgradeStatus" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetProtocolUpgradeStatusSvc<T: TradingDataService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::GetProtocolUpgradeStatusRequest>
+                    for GetProtocolUpgradeStatusSvc<T> {
+                        type Response = super::GetProtocolUpgradeStatusResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::GetProtocolUpgradeStatusRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::get_protocol_upgrade_status(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetProtocolUpgradeStatusSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ListProtocolUpgradeProposals" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListProtocolUpgradeProposalsSvc<T: TradingDataService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<
+                        super::ListProtocolUpgradeProposalsRequest,
+                    > for ListProtocolUpgradeProposalsSvc<T> {
+                        type Response = super::ListProtocolUpgradeProposalsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::ListProtocolUpgradeProposalsRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_protocol_upgrade_proposals(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListProtocolUpgradeProposalsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ListCoreSnapshots" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListCoreSnapshotsSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::ListCoreSnapshotsRequest>
+                    for ListCoreSnapshotsSvc<T> {
+                        type Response = super::ListCoreSnapshotsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListCoreSnapshotsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_core_snapshots(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListCoreSnapshotsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/GetMostRecentNetworkHistorySegment" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetMostRecentNetworkHistorySegmentSvc<T: TradingDataService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<
+                        super::GetMostRecentNetworkHistorySegmentRequest,
+                    > for GetMostRecentNetworkHistorySegmentSvc<T> {
+                        type Response = super::GetMostRecentNetworkHistorySegmentResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::GetMostRecentNetworkHistorySegmentRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::get_most_recent_network_history_segment(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetMostRecentNetworkHistorySegmentSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ListAllNetworkHistorySegments" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListAllNetworkHistorySegmentsSvc<T: TradingDataService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<
+                        super::ListAllNetworkHistorySegmentsRequest,
+                    > for ListAllNetworkHistorySegmentsSvc<T> {
+                        type Response = super::ListAllNetworkHistorySegmentsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::ListAllNetworkHistorySegmentsRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_all_network_history_segments(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListAllNetworkHistorySegmentsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/GetActiveNetworkHistoryPeerAddresses" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetActiveNetworkHistoryPeerAddressesSvc<
+                        T: TradingDataService,
+                    >(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<
+                        super::GetActiveNetworkHistoryPeerAddressesRequest,
+                    > for GetActiveNetworkHistoryPeerAddressesSvc<T> {
+                        type Response = super::GetActiveNetworkHistoryPeerAddressesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::GetActiveNetworkHistoryPeerAddressesRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::get_active_network_history_peer_addresses(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetActiveNetworkHistoryPeerAddressesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/GetNetworkHistoryStatus" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetNetworkHistoryStatusSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::GetNetworkHistoryStatusRequest>
+                    for GetNetworkHistoryStatusSvc<T> {
+                        type Response = super::GetNetworkHistoryStatusResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::GetNetworkHistoryStatusRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::get_network_history_status(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetNetworkHistoryStatusSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/GetNetworkHistoryBootstrapPeers" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetNetworkHistoryBootstrapPeersSvc<T: TradingDataService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<
+                        super::GetNetworkHistoryBootstrapPeersRequest,
+                    > for GetNetworkHistoryBootstrapPeersSvc<T> {
+                        type Response = super::GetNetworkHistoryBootstrapPeersResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::GetNetworkHistoryBootstrapPeersRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::get_network_history_bootstrap_peers(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetNetworkHistoryBootstrapPeersSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ListEntities" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListEntitiesSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::ListEntitiesRequest>
+                    for ListEntitiesSvc<T> {
+                        type Response = super::ListEntitiesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListEntitiesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_entities(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListEntitiesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ListFundingPeriods" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListFundingPeriodsSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::ListFundingPeriodsRequest>
+                    for ListFundingPeriodsSvc<T> {
+                        type Response = super::ListFundingPeriodsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListFundingPeriodsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_funding_periods(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListFundingPeriodsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ListFundingPeriodDataPoints" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListFundingPeriodDataPointsSvc<T: TradingDataService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<
+                        super::ListFundingPeriodDataPointsRequest,
+                    > for ListFundingPeriodDataPointsSvc<T> {
+                        type Response = super::ListFundingPeriodDataPointsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::ListFundingPeriodDataPointsRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_funding_period_data_points(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListFundingPeriodDataPointsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ListFundingPayments" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListFundingPaymentsSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::ListFundingPaymentsRequest>
+                    for ListFundingPaymentsSvc<T> {
+                        type Response = super::ListFundingPaymentsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListFundingPaymentsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_funding_payments(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListFundingPaymentsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/GetPartyActivityStreak" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetPartyActivityStreakSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::GetPartyActivityStreakRequest>
+                    for GetPartyActivityStreakSvc<T> {
+                        type Response = super::GetPartyActivityStreakResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetPartyActivityStreakRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::get_party_activity_streak(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetPartyActivityStreakSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/GetCurrentReferralProgram" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetCurrentReferralProgramSvc<T: TradingDataService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<
+                        super::GetCurrentReferralProgramRequest,
+                    > for GetCurrentReferralProgramSvc<T> {
+                        type Response = super::GetCurrentReferralProgramResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::GetCurrentReferralProgramRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::get_current_referral_program(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetCurrentReferralProgramSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ListReferralSets" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListReferralSetsSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::ListReferralSetsRequest>
+                    for ListReferralSetsSvc<T> {
+                        type Response = super::ListReferralSetsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListReferralSetsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_referral_sets(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListReferralSetsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ListReferralSetReferees" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListReferralSetRefereesSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::ListReferralSetRefereesRequest>
+                    for ListReferralSetRefereesSvc<T> {
+                        type Response = super::ListReferralSetRefereesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::ListReferralSetRefereesRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_referral_set_referees(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListReferralSetRefereesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/GetReferralSetStats" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetReferralSetStatsSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::GetReferralSetStatsRequest>
+                    for GetReferralSetStatsSvc<T> {
+                        type Response = super::GetReferralSetStatsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetReferralSetStatsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::get_referral_set_stats(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetReferralSetStatsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ListTeams" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListTeamsSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::ListTeamsRequest>
+                    for ListTeamsSvc<T> {
+                        type Response = super::ListTeamsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListTeamsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_teams(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListTeamsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ListTeamsStatistics" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListTeamsStatisticsSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::ListTeamsStatisticsRequest>
+                    for ListTeamsStatisticsSvc<T> {
+                        type Response = super::ListTeamsStatisticsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListTeamsStatisticsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_teams_statistics(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListTeamsStatisticsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ListTeamMembersStatistics" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListTeamMembersStatisticsSvc<T: TradingDataService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<
+                        super::ListTeamMembersStatisticsRequest,
+                    > for ListTeamMembersStatisticsSvc<T> {
+                        type Response = super::ListTeamMembersStatisticsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::ListTeamMembersStatisticsRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_team_members_statistics(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListTeamMembersStatisticsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ListTeamReferees" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListTeamRefereesSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::ListTeamRefereesRequest>
+                    for ListTeamRefereesSvc<T> {
+                        type Response = super::ListTeamRefereesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListTeamRefereesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_team_referees(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListTeamRefereesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ListTeamRefereeHistory" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListTeamRefereeHistorySvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::ListTeamRefereeHistoryRequest>
+                    for ListTeamRefereeHistorySvc<T> {
+                        type Response = super::ListTeamRefereeHistoryResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListTeamRefereeHistoryRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_team_referee_history(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListTeamRefereeHistorySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/GetFeesStats" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetFeesStatsSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::GetFeesStatsRequest>
+                    for GetFeesStatsSvc<T> {
+                        type Response = super::GetFeesStatsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetFeesStatsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::get_fees_stats(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetFeesStatsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/GetFeesStatsForParty" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetFeesStatsForPartySvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::GetFeesStatsForPartyRequest>
+                    for GetFeesStatsForPartySvc<T> {
+                        type Response = super::GetFeesStatsForPartyResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetFeesStatsForPartyRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::get_fees_stats_for_party(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetFeesStatsForPartySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/GetCurrentVolumeDiscountProgram" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetCurrentVolumeDiscountProgramSvc<T: TradingDataService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<
+                        super::GetCurrentVolumeDiscountProgramRequest,
+                    > for GetCurrentVolumeDiscountProgramSvc<T> {
+                        type Response = super::GetCurrentVolumeDiscountProgramResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::GetCurrentVolumeDiscountProgramRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::get_current_volume_discount_program(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetCurrentVolumeDiscountProgramSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/GetVolumeDiscountStats" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetVolumeDiscountStatsSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::GetVolumeDiscountStatsRequest>
+                    for GetVolumeDiscountStatsSvc<T> {
+                        type Response = super::GetVolumeDiscountStatsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetVolumeDiscountStatsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::get_volume_discount_stats(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetVolumeDiscountStatsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/GetVestingBalancesSummary" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetVestingBalancesSummarySvc<T: TradingDataService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<
+                        super::GetVestingBalancesSummaryRequest,
+                    > for GetVestingBalancesSummarySvc<T> {
+                        type Response = super::GetVestingBalancesSummaryResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::GetVestingBalancesSummaryRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::get_vesting_balances_summary(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetVestingBalancesSummarySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/GetPartyVestingStats" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetPartyVestingStatsSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::GetPartyVestingStatsRequest>
+                    for GetPartyVestingStatsSvc<T> {
+                        type Response = super::GetPartyVestingStatsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetPartyVestingStatsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::get_party_vesting_stats(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetPartyVestingStatsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ObserveTransactionResults" => {
+                    #[allow(non_camel_case_types)]
+                    struct ObserveTransactionResultsSvc<T: TradingDataService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::ServerStreamingService<
+                        super::ObserveTransactionResultsRequest,
+                    > for ObserveTransactionResultsSvc<T> {
+                        type Response = super::ObserveTransactionResultsResponse;
+                        type ResponseStream = T::ObserveTransactionResultsStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::ObserveTransactionResultsRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::observe_transaction_results(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ObserveTransactionResultsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/EstimateTransferFee" => {
+                    #[allow(non_camel_case_types)]
+                    struct EstimateTransferFeeSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::EstimateTransferFeeRequest>
+                    for EstimateTransferFeeSvc<T> {
+                        type Response = super::EstimateTransferFeeResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::EstimateTransferFeeRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::estimate_transfer_fee(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = EstimateTransferFeeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/GetTotalTransferFeeDiscount" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetTotalTransferFeeDiscountSvc<T: TradingDataService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<
+                        super::GetTotalTransferFeeDiscountRequest,
+                    > for GetTotalTransferFeeDiscountSvc<T> {
+                        type Response = super::GetTotalTransferFeeDiscountResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::GetTotalTransferFeeDiscountRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::get_total_transfer_fee_discount(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetTotalTransferFeeDiscountSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ListGames" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListGamesSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::ListGamesRequest>
+                    for ListGamesSvc<T> {
+                        type Response = super::ListGamesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListGamesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_games(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListGamesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ListPartyMarginModes" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListPartyMarginModesSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::ListPartyMarginModesRequest>
+                    for ListPartyMarginModesSvc<T> {
+                        type Response = super::ListPartyMarginModesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListPartyMarginModesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_party_margin_modes(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListPartyMarginModesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/GetTimeWeightedNotionalPosition" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetTimeWeightedNotionalPositionSvc<T: TradingDataService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<
+                        super::GetTimeWeightedNotionalPositionRequest,
+                    > for GetTimeWeightedNotionalPositionSvc<T> {
+                        type Response = super::GetTimeWeightedNotionalPositionResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::GetTimeWeightedNotionalPositionRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::get_time_weighted_notional_position(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetTimeWeightedNotionalPositionSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ListAMMs" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListAMMsSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::ListAmMsRequest>
+                    for ListAMMsSvc<T> {
+                        type Response = super::ListAmMsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListAmMsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::list_am_ms(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListAMMsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/EstimateAMMBounds" => {
+                    #[allow(non_camel_case_types)]
+                    struct EstimateAMMBoundsSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::EstimateAmmBoundsRequest>
+                    for EstimateAMMBoundsSvc<T> {
+                        type Response = super::EstimateAmmBoundsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::EstimateAmmBoundsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::estimate_amm_bounds(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = EstimateAMMBoundsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/ExportNetworkHistory" => {
+                    #[allow(non_camel_case_types)]
+                    struct ExportNetworkHistorySvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::ServerStreamingService<
+                        super::ExportNetworkHistoryRequest,
+                    > for ExportNetworkHistorySvc<T> {
+                        type Response = super::super::super::super::google::api::HttpBody;
+                        type ResponseStream = T::ExportNetworkHistoryStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ExportNetworkHistoryRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::export_network_history(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ExportNetworkHistorySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/datanode.api.v2.TradingDataService/Ping" => {
+                    #[allow(non_camel_case_types)]
+                    struct PingSvc<T: TradingDataService>(pub Arc<T>);
+                    impl<
+                        T: TradingDataService,
+                    > tonic::server::UnaryService<super::PingRequest> for PingSvc<T> {
+                        type Response = super::PingResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PingRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TradingDataService>::ping(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = PingSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        Ok(
+                            http::Response::builder()
+                                .status(200)
+                                .header("grpc-status", "12")
+                                .header("content-type", "application/grpc")
+                                .body(empty_body())
+                                .unwrap(),
+                        )
+                    })
+                }
+            }
+        }
+    }
+    impl<T: TradingDataService> Clone for TradingDataServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    impl<T: TradingDataService> Clone for _Inner<T> {
+        fn clone(&self) -> Self {
+            Self(Arc::clone(&self.0))
+        }
+    }
+    impl<T: std::fmt::Debug> std::fmt::Debug for _Inner<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{:?}", self.0)
+        }
+    }
+    impl<T: TradingDataService> tonic::server::NamedService
+    for TradingDataServiceServer<T> {
+        const NAME: &'static str = "datanode.api.v2.TradingDataService";
+    }
+}
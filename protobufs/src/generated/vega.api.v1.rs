@@ -0,0 +1,3135 @@
+// This file is @generated by prost-build.
+/// Request for a new event sent by the blockchain queue to be propagated on Vega
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PropagateChainEventRequest {
+    /// Chain event to propagate.
+    #[prost(bytes = "vec", tag = "1")]
+    pub event: ::prost::alloc::vec::Vec<u8>,
+    /// Public key of the key pair used to sign messages.
+    #[prost(string, tag = "2")]
+    pub pub_key: ::prost::alloc::string::String,
+    /// Signature generated by the private key associated with the public key.
+    #[prost(bytes = "vec", tag = "3")]
+    pub signature: ::prost::alloc::vec::Vec<u8>,
+}
+/// Response for a new event sent by the blockchain queue to be propagated on Vega
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PropagateChainEventResponse {
+    /// Success will be true if the event was accepted by the node,
+    /// **Important** - success does not mean that the event is confirmed by consensus
+    #[prost(bool, tag = "1")]
+    pub success: bool,
+}
+/// Request for submitting a transaction v2 on Vega
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SubmitTransactionRequest {
+    /// Transaction containing a command to execute on the network, and a signature to provide authentication.
+    #[prost(message, optional, tag = "1")]
+    pub tx: ::core::option::Option<super::super::commands::v1::Transaction>,
+    /// Method of submission.
+    #[prost(enumeration = "submit_transaction_request::Type", tag = "2")]
+    pub r#type: i32,
+}
+/// Nested message and enum types in `SubmitTransactionRequest`.
+pub mod submit_transaction_request {
+    /// Blockchain transaction type.
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Type {
+        Unspecified = 0,
+        /// Transaction will be submitted without waiting for a response.
+        Async = 1,
+        /// Transaction will be submitted, and blocking until the mempool returns a response.
+        Sync = 2,
+        /// Transaction will be submitted, and blocking until the network has committed it into a block.
+        /// Used only for debugging local network, not for submitting transactions.
+        Commit = 3,
+    }
+    impl Type {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Type::Unspecified => "TYPE_UNSPECIFIED",
+                Type::Async => "TYPE_ASYNC",
+                Type::Sync => "TYPE_SYNC",
+                Type::Commit => "TYPE_COMMIT",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "TYPE_UNSPECIFIED" => Some(Self::Unspecified),
+                "TYPE_ASYNC" => Some(Self::Async),
+                "TYPE_SYNC" => Some(Self::Sync),
+                "TYPE_COMMIT" => Some(Self::Commit),
+                _ => None,
+            }
+        }
+    }
+}
+/// Response for submitting a transaction on the network.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SubmitTransactionResponse {
+    /// Whether or not the transaction was validated and submitted to the chain's mempool.
+    #[prost(bool, tag = "1")]
+    pub success: bool,
+    /// Hash of the transaction, which can be used to identify the transaction in a node's event stream.
+    #[prost(string, tag = "2")]
+    pub tx_hash: ::prost::alloc::string::String,
+    /// Error code to indicate the category of failure if the transaction was not successfully submitted.
+    #[prost(uint32, tag = "3")]
+    pub code: u32,
+    /// Further details for why the transaction was not successfully submitted.
+    #[prost(string, tag = "4")]
+    pub data: ::prost::alloc::string::String,
+    /// Further details for the underlying consensus layer of the result of the transaction.
+    #[prost(string, tag = "5")]
+    pub log: ::prost::alloc::string::String,
+    /// Unused.
+    #[prost(int64, tag = "6")]
+    pub height: i64,
+}
+/// Request for checking a transaction v2 on Vega
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CheckTransactionRequest {
+    /// Transaction containing a command to be checked on the network, and not added to the chain's mempool.
+    #[prost(message, optional, tag = "1")]
+    pub tx: ::core::option::Option<super::super::commands::v1::Transaction>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CheckTransactionResponse {
+    /// Whether or not the transaction passed the submission checks.
+    #[prost(bool, tag = "1")]
+    pub success: bool,
+    /// Error code to indicate the category of failure if the transaction was not successfully checked.
+    #[prost(uint32, tag = "2")]
+    pub code: u32,
+    /// Amount of space in a block that the transaction will fill. This does not relate to any monetary cost for submitting the transaction.
+    #[prost(int64, tag = "3")]
+    pub gas_wanted: i64,
+    /// Unused.
+    #[prost(int64, tag = "4")]
+    pub gas_used: i64,
+    /// Further details for why the transaction was not successfully submitted.
+    #[prost(string, tag = "5")]
+    pub data: ::prost::alloc::string::String,
+    /// Further details for the underlying consensus layer of the result of the transaction.
+    #[prost(string, tag = "6")]
+    pub log: ::prost::alloc::string::String,
+    /// Unused.
+    #[prost(string, tag = "7")]
+    pub info: ::prost::alloc::string::String,
+}
+/// Request for submitting a version agnostic transaction on Vega
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SubmitRawTransactionRequest {
+    /// Bundle of signed payload and signature marshalled into a byte array, to form a transaction that will be submitted to the Vega blockchain
+    #[prost(bytes = "vec", tag = "1")]
+    pub tx: ::prost::alloc::vec::Vec<u8>,
+    /// Type of transaction request, for example ASYNC, meaning the transaction will be submitted and not block on a response
+    #[prost(enumeration = "submit_raw_transaction_request::Type", tag = "2")]
+    pub r#type: i32,
+}
+/// Nested message and enum types in `SubmitRawTransactionRequest`.
+pub mod submit_raw_transaction_request {
+    /// Blockchain transaction type
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Type {
+        Unspecified = 0,
+        /// Transaction will be submitted without waiting for response
+        Async = 1,
+        /// Transaction will be submitted, and blocking until the
+        /// tendermint mempool returns a response
+        Sync = 2,
+        /// Transaction will be submitted, and blocking until the tendermint
+        /// network has committed it into a block. Used only for debugging,
+        /// not for submitting transactions
+        Commit = 3,
+    }
+    impl Type {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Type::Unspecified => "TYPE_UNSPECIFIED",
+                Type::Async => "TYPE_ASYNC",
+                Type::Sync => "TYPE_SYNC",
+                Type::Commit => "TYPE_COMMIT",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "TYPE_UNSPECIFIED" => Some(Self::Unspecified),
+                "TYPE_ASYNC" => Some(Self::Async),
+                "TYPE_SYNC" => Some(Self::Sync),
+                "TYPE_COMMIT" => Some(Self::Commit),
+                _ => None,
+            }
+        }
+    }
+}
+/// Response for submitting a version agnostic transaction on Vega
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SubmitRawTransactionResponse {
+    /// Whether or not the transaction was validated and submitted to the chain's mempool.
+    #[prost(bool, tag = "1")]
+    pub success: bool,
+    /// Hash of the transaction, which can be used to identify the transaction in a node's event stream.
+    #[prost(string, tag = "2")]
+    pub tx_hash: ::prost::alloc::string::String,
+    /// Error code to indicate the category of failure if the transaction was not successfully submitted.
+    #[prost(uint32, tag = "3")]
+    pub code: u32,
+    /// Further details for why the transaction was not successfully submitted.
+    #[prost(string, tag = "4")]
+    pub data: ::prost::alloc::string::String,
+    /// Further details for the underlying consensus layer of the result of the transaction.
+    #[prost(string, tag = "5")]
+    pub log: ::prost::alloc::string::String,
+    /// Unused.
+    #[prost(int64, tag = "6")]
+    pub height: i64,
+}
+/// Request for checking a version agnostic transaction on Vega
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CheckRawTransactionRequest {
+    /// Bundle of signed payload and signature marshalled into a byte array, to form a transaction that would be submitted to the Vega blockchain
+    #[prost(bytes = "vec", tag = "1")]
+    pub tx: ::prost::alloc::vec::Vec<u8>,
+}
+/// Response for checking a version agnostic transaction on Vega
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CheckRawTransactionResponse {
+    /// Whether or not the transaction passed the submission checks.
+    #[prost(bool, tag = "1")]
+    pub success: bool,
+    /// Error code to indicate the category of failure if the transaction was not successfully submitted.
+    #[prost(uint32, tag = "2")]
+    pub code: u32,
+    /// Amount of space in a block that the transaction will fill. This does not relate to any monetary cost for submitting the transaction.
+    #[prost(int64, tag = "3")]
+    pub gas_wanted: i64,
+    /// Unused.
+    #[prost(int64, tag = "4")]
+    pub gas_used: i64,
+    /// Further details for why the transaction was not successfully checked.
+    #[prost(string, tag = "5")]
+    pub data: ::prost::alloc::string::String,
+    /// Further details for the underlying consensus layer of the result of the transaction.
+    #[prost(string, tag = "6")]
+    pub log: ::prost::alloc::string::String,
+    /// Unused
+    #[prost(string, tag = "7")]
+    pub info: ::prost::alloc::string::String,
+}
+/// Request for the current time of the Vega network
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetVegaTimeRequest {}
+/// Response for the current consensus coordinated time on the Vega network, referred to as "VegaTime"
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetVegaTimeResponse {
+    /// Timestamp representation of current VegaTime as represented in
+    /// Unix nanoseconds, for example `1580473859111222333` corresponds to `2020-01-31T12:30:59.111222333Z`
+    #[prost(int64, tag = "1")]
+    pub timestamp: i64,
+}
+/// Request to subscribe to a stream of one or more event types from the Vega event bus
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ObserveEventBusRequest {
+    /// One or more types of event, required field
+    #[prost(enumeration = "super::super::events::v1::BusEventType", repeated, tag = "1")]
+    pub r#type: ::prost::alloc::vec::Vec<i32>,
+    /// Market ID to filter events for, optional field. If omitted, no markets will be filtered out.
+    #[prost(string, tag = "2")]
+    pub market_id: ::prost::alloc::string::String,
+    /// Party ID to filter events for, optional field. If omitted, no parties will be filtered out.
+    #[prost(string, tag = "3")]
+    pub party_id: ::prost::alloc::string::String,
+    /// Batch size, optional field -
+    /// If not specified, any events received will be sent immediately. If the client is not ready
+    /// for the next data-set, data may be dropped a number of times, and eventually the stream is closed.
+    /// if specified, the first batch will be sent when ready. To receive the next set of events, the client
+    /// must write an `ObserveEventBatch` message on the stream to flush the buffer.
+    /// If no message is received in 5 seconds, the stream is closed.
+    /// Default: 0, send any and all events when they are available.
+    #[prost(int64, tag = "4")]
+    pub batch_size: i64,
+}
+/// Response to a subscribed stream of events from the Vega event bus
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ObserveEventBusResponse {
+    /// One or more events that match the subscription request criteria.
+    #[prost(message, repeated, tag = "1")]
+    pub events: ::prost::alloc::vec::Vec<super::super::events::v1::BusEvent>,
+}
+/// Request for statistics about the Vega network
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StatisticsRequest {}
+/// Response containing statistics about the Vega network
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StatisticsResponse {
+    #[prost(message, optional, tag = "1")]
+    pub statistics: ::core::option::Option<Statistics>,
+}
+/// Vega domain specific statistics as reported by the node the caller is connected to
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Statistics {
+    /// Current block height as reported by the Vega blockchain
+    #[prost(uint64, tag = "1")]
+    pub block_height: u64,
+    /// Current backlog length i.e., number of transactions, that are waiting to be included in a block
+    #[prost(uint64, tag = "2")]
+    pub backlog_length: u64,
+    /// Total number of connected peers to this node
+    #[prost(uint64, tag = "3")]
+    pub total_peers: u64,
+    /// Genesis block date and time formatted in ISO-8601 datetime format with nanosecond precision
+    #[prost(string, tag = "4")]
+    pub genesis_time: ::prost::alloc::string::String,
+    /// Current system date and time formatted in ISO-8601 datetime format with nanosecond precision
+    #[prost(string, tag = "5")]
+    pub current_time: ::prost::alloc::string::String,
+    /// Current Vega date and time formatted in ISO-8601 datetime format with nanosecond precision
+    #[prost(string, tag = "6")]
+    pub vega_time: ::prost::alloc::string::String,
+    /// Status of the connection to the Vega blockchain
+    #[prost(enumeration = "super::super::ChainStatus", tag = "7")]
+    pub status: i32,
+    /// Transactions per block
+    #[prost(uint64, tag = "8")]
+    pub tx_per_block: u64,
+    /// Average transaction size in bytes
+    #[prost(uint64, tag = "9")]
+    pub average_tx_bytes: u64,
+    /// Average orders per block
+    #[prost(uint64, tag = "10")]
+    pub average_orders_per_block: u64,
+    /// Trades emitted per second
+    #[prost(uint64, tag = "11")]
+    pub trades_per_second: u64,
+    /// Orders processed per second
+    #[prost(uint64, tag = "12")]
+    pub orders_per_second: u64,
+    /// Total markets on this Vega network
+    #[prost(uint64, tag = "13")]
+    pub total_markets: u64,
+    /// Total number of order amendments since genesis across all markets
+    #[prost(uint64, tag = "16")]
+    pub total_amend_order: u64,
+    /// Total number of order cancellations since genesis across all markets
+    #[prost(uint64, tag = "17")]
+    pub total_cancel_order: u64,
+    /// Total number of order submissions since genesis across all markets
+    #[prost(uint64, tag = "18")]
+    pub total_create_order: u64,
+    /// Total number of orders processed since genesis across all markets
+    #[prost(uint64, tag = "19")]
+    pub total_orders: u64,
+    /// Total number of trades emitted since genesis across all markets
+    #[prost(uint64, tag = "20")]
+    pub total_trades: u64,
+    /// Current number of stream subscribers to order data
+    #[prost(uint32, tag = "21")]
+    pub order_subscriptions: u32,
+    /// Current number of stream subscribers to trade data
+    #[prost(uint32, tag = "22")]
+    pub trade_subscriptions: u32,
+    /// Current number of stream subscribers to candlestick data
+    #[prost(uint32, tag = "23")]
+    pub candle_subscriptions: u32,
+    /// Current number of stream subscribers to market depth data
+    #[prost(uint32, tag = "24")]
+    pub market_depth_subscriptions: u32,
+    /// Current number of stream subscribers to positions data
+    #[prost(uint32, tag = "25")]
+    pub positions_subscriptions: u32,
+    /// Current number of stream subscribers to account data
+    #[prost(uint32, tag = "26")]
+    pub account_subscriptions: u32,
+    /// Current number of stream subscribers to market data
+    #[prost(uint32, tag = "27")]
+    pub market_data_subscriptions: u32,
+    /// Version hash of the Vega node software
+    #[prost(string, tag = "28")]
+    pub app_version_hash: ::prost::alloc::string::String,
+    /// Version of the Vega node software
+    #[prost(string, tag = "29")]
+    pub app_version: ::prost::alloc::string::String,
+    /// Version of the underlying Vega blockchain
+    #[prost(string, tag = "30")]
+    pub chain_version: ::prost::alloc::string::String,
+    /// Current block duration, in nanoseconds
+    #[prost(uint64, tag = "31")]
+    pub block_duration: u64,
+    /// Total uptime for this node formatted in ISO-8601 datetime format with nanosecond precision
+    #[prost(string, tag = "32")]
+    pub uptime: ::prost::alloc::string::String,
+    /// Unique ID for the underlying Vega blockchain
+    #[prost(string, tag = "33")]
+    pub chain_id: ::prost::alloc::string::String,
+    /// Current number of stream subscribers to market depth update data
+    #[prost(uint32, tag = "34")]
+    pub market_depth_updates_subscriptions: u32,
+    /// Current block hash
+    #[prost(string, tag = "35")]
+    pub block_hash: ::prost::alloc::string::String,
+    /// Current epoch
+    #[prost(uint64, tag = "36")]
+    pub epoch_seq: u64,
+    /// Epoch start time
+    #[prost(string, tag = "37")]
+    pub epoch_start_time: ::prost::alloc::string::String,
+    /// Epoch expected end time
+    #[prost(string, tag = "38")]
+    pub epoch_expiry_time: ::prost::alloc::string::String,
+    /// Number of events in the last block
+    #[prost(uint64, tag = "39")]
+    pub event_count: u64,
+    /// Rate of events per second in the last block
+    #[prost(uint64, tag = "40")]
+    pub events_per_second: u64,
+}
+/// Request to get the height of the very last block processed
+/// by tendermint
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LastBlockHeightRequest {}
+/// Response with the height of the last block processed by
+/// tendermint
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LastBlockHeightResponse {
+    /// Last block height
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    /// Last block hash
+    #[prost(string, tag = "2")]
+    pub hash: ::prost::alloc::string::String,
+    /// Supported proof of work hash function
+    #[prost(string, tag = "3")]
+    pub spam_pow_hash_function: ::prost::alloc::string::String,
+    /// Difficulty of the proof of work, i.e. the target number of zeros
+    #[prost(uint32, tag = "4")]
+    pub spam_pow_difficulty: u32,
+    /// Supported proof of work number of blocks behind current height allowed
+    #[prost(uint32, tag = "5")]
+    pub spam_pow_number_of_past_blocks: u32,
+    /// Allowed number of transactions per block
+    #[prost(uint32, tag = "6")]
+    pub spam_pow_number_of_tx_per_block: u32,
+    /// Boolean indicating whether increasing difficulty is allowed for using the
+    /// same height for more than `spam_pow_number_of_past_blocks` transactions
+    #[prost(bool, tag = "7")]
+    pub spam_pow_increasing_difficulty: bool,
+    /// Network chain id from which the block comes from
+    #[prost(string, tag = "8")]
+    pub chain_id: ::prost::alloc::string::String,
+}
+/// Request to retrieve the spam statistics of a party for the given epoch
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetSpamStatisticsRequest {
+    /// Party ID whose statistics are requested
+    #[prost(string, tag = "1")]
+    pub party_id: ::prost::alloc::string::String,
+}
+/// Statistics for a given spam policy
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SpamStatistic {
+    /// Current transaction count received from the party during this epoch for this policy
+    #[prost(uint64, tag = "1")]
+    pub count_for_epoch: u64,
+    /// Maximum number of transactions allowed for this policy in an epoch
+    #[prost(uint64, tag = "2")]
+    pub max_for_epoch: u64,
+    /// If blocked the timestamp when the party will be unblocked as RFC3339Nano
+    #[prost(string, optional, tag = "4")]
+    pub banned_until: ::core::option::Option<::prost::alloc::string::String>,
+    /// Effective minimum number of tokens required to submit a transaction of this type
+    #[prost(string, tag = "5")]
+    pub min_tokens_required: ::prost::alloc::string::String,
+}
+/// Voting statistics by proposal for a given party for the current epoch
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VoteSpamStatistics {
+    /// List of statistics for proposals voted on by the party
+    #[prost(message, repeated, tag = "1")]
+    pub statistics: ::prost::alloc::vec::Vec<VoteSpamStatistic>,
+    /// Maximum number of votes per proposal allowed in an epoch
+    #[prost(uint64, tag = "2")]
+    pub max_for_epoch: u64,
+    /// If blocked the timestamp when the party will be unblocked as RFC3339Nano
+    #[prost(string, optional, tag = "3")]
+    pub banned_until: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// Vote statistics for the voting spam policies
+/// which are calculated as a ratio of the total votes
+/// that have been rejected.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VoteSpamStatistic {
+    /// Unique ID of the proposal being voted on by the party.
+    #[prost(string, tag = "1")]
+    pub proposal: ::prost::alloc::string::String,
+    /// Current vote count received from the party for the given proposal during this epoch
+    #[prost(uint64, tag = "2")]
+    pub count_for_epoch: u64,
+    /// Effective minimum number of tokens required to vote on the proposal
+    #[prost(string, tag = "3")]
+    pub min_tokens_required: ::prost::alloc::string::String,
+}
+/// Proof of Work state for a given block
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PoWBlockState {
+    /// Block height for the current Proof of Work state statistics
+    #[prost(uint64, tag = "1")]
+    pub block_height: u64,
+    /// Hash of the current block
+    #[prost(string, tag = "2")]
+    pub block_hash: ::prost::alloc::string::String,
+    /// Total number of transactions seen in the block
+    #[prost(uint64, tag = "3")]
+    pub transactions_seen: u64,
+    /// This is the minimum required difficulty for the next transaction submitted on this block
+    /// if it is possible to submit more transactions on this block, otherwise nil.
+    #[prost(uint64, optional, tag = "4")]
+    pub expected_difficulty: ::core::option::Option<u64>,
+    /// Hashing function used to calculate the block hash
+    #[prost(string, tag = "5")]
+    pub hash_function: ::prost::alloc::string::String,
+    /// Base difficulty for this block for when transactions seen < tx_per_block
+    #[prost(uint64, tag = "6")]
+    pub difficulty: u64,
+    /// Number of transactions that can have their proof-of-work calculated with this block hash before
+    /// either the difficulty increases, or no more transactions can use this block hash
+    #[prost(uint64, tag = "7")]
+    pub tx_per_block: u64,
+    /// Whether or not this block allows for increasing proof-of-work difficulty if the
+    /// tx-per-block-hash limit has been reached
+    #[prost(bool, tag = "8")]
+    pub increasing_difficulty: bool,
+}
+/// Proof of work statistics for a party
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PoWStatistic {
+    /// Block state for each block in scope for PoW calculation
+    #[prost(message, repeated, tag = "1")]
+    pub block_states: ::prost::alloc::vec::Vec<PoWBlockState>,
+    /// PoW banned until timestamp as RFC3339Nano
+    #[prost(string, optional, tag = "2")]
+    pub banned_until: ::core::option::Option<::prost::alloc::string::String>,
+    /// Number of block behind the current block whose hash can be used for proof-of-work calculations
+    #[prost(uint64, tag = "3")]
+    pub number_of_past_blocks: u64,
+}
+/// Complete spam statistics captured for a given party
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SpamStatistics {
+    /// Statistics for proposal transactions made by the party.
+    #[prost(message, optional, tag = "1")]
+    pub proposals: ::core::option::Option<SpamStatistic>,
+    /// Statistics for delegation transactions made by the party.
+    #[prost(message, optional, tag = "2")]
+    pub delegations: ::core::option::Option<SpamStatistic>,
+    /// Statistics for transfer transactions made by the party.
+    #[prost(message, optional, tag = "3")]
+    pub transfers: ::core::option::Option<SpamStatistic>,
+    /// Statistics for node announcement transactions made by the party.
+    #[prost(message, optional, tag = "4")]
+    pub node_announcements: ::core::option::Option<SpamStatistic>,
+    /// Statistics for proposal votes made by the party.
+    #[prost(message, optional, tag = "5")]
+    pub votes: ::core::option::Option<VoteSpamStatistics>,
+    /// Statistics for proof of work difficulty observed per block for the party.
+    #[prost(message, optional, tag = "6")]
+    pub pow: ::core::option::Option<PoWStatistic>,
+    /// Statistics for multisig signatures issued for the party.
+    #[prost(message, optional, tag = "7")]
+    pub issue_signatures: ::core::option::Option<SpamStatistic>,
+    /// Epoch in which these statistics apply to.
+    #[prost(uint64, tag = "8")]
+    pub epoch_seq: u64,
+    /// Statistics for transactions made by the party to create referral sets.
+    #[prost(message, optional, tag = "9")]
+    pub create_referral_set: ::core::option::Option<SpamStatistic>,
+    /// Statistics for transactions made by the party to update referral sets.
+    #[prost(message, optional, tag = "10")]
+    pub update_referral_set: ::core::option::Option<SpamStatistic>,
+    /// Statistics for transactions made by the party to apply referral codes.
+    #[prost(message, optional, tag = "11")]
+    pub apply_referral_code: ::core::option::Option<SpamStatistic>,
+}
+/// Response containing all the spam statistics of a party for the current epoch
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetSpamStatisticsResponse {
+    /// Chain ID for which the statistics are captured.
+    #[prost(string, tag = "1")]
+    pub chain_id: ::prost::alloc::string::String,
+    /// Spam statistics for the party
+    #[prost(message, optional, tag = "2")]
+    pub statistics: ::core::option::Option<SpamStatistics>,
+}
+/// Generated client implementations.
+pub mod core_service_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    #[derive(Debug, Clone)]
+    pub struct CoreServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl CoreServiceClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> CoreServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> CoreServiceClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+            >>::Error: Into<StdError> + Send + Sync,
+        {
+            CoreServiceClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        /// Submit transaction
+        ///
+        /// Submit a signed transaction to the network containing a command to be executed such that if the submission is successful then it will be included in the chain's mempool.
+        /// The network will then attempt to execute the transaction in the next available block, where the results of its execution can be seen on the EventBus.
+        pub async fn submit_transaction(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SubmitTransactionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SubmitTransactionResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/vega.api.v1.CoreService/SubmitTransaction",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("vega.api.v1.CoreService", "SubmitTransaction"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Chain event
+        ///
+        /// Propagate a chain event
+        pub async fn propagate_chain_event(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PropagateChainEventRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PropagateChainEventResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/vega.api.v1.CoreService/PropagateChainEvent",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("vega.api.v1.CoreService", "PropagateChainEvent"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Statistics
+        ///
+        /// Get statistics on Vega
+        pub async fn statistics(
+            &mut self,
+            request: impl tonic::IntoRequest<super::StatisticsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::StatisticsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/vega.api.v1.CoreService/Statistics",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("vega.api.v1.CoreService", "Statistics"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Blockchain height
+        ///
+        /// Get the height of the last tendermint block
+        pub async fn last_block_height(
+            &mut self,
+            request: impl tonic::IntoRequest<super::LastBlockHeightRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::LastBlockHeightResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/vega.api.v1.CoreService/LastBlockHeight",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("vega.api.v1.CoreService", "LastBlockHeight"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Vega time
+        ///
+        /// Get current Vega time
+        pub async fn get_vega_time(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetVegaTimeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetVegaTimeResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/vega.api.v1.CoreService/GetVegaTime",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("vega.api.v1.CoreService", "GetVegaTime"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Events subscription
+        ///
+        /// Subscribe to a stream of events from the core
+        pub async fn observe_event_bus(
+            &mut self,
+            request: impl tonic::IntoStreamingRequest<
+                Message = super::ObserveEventBusRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::ObserveEventBusResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/vega.api.v1.CoreService/ObserveEventBus",
+            );
+            let mut req = request.into_streaming_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("vega.api.v1.CoreService", "ObserveEventBus"));
+            self.inner.streaming(req, path, codec).await
+        }
+        /// Submit raw transaction
+        ///
+        /// Submit a pre-serialised signed transaction containing a command to the network to be executed, such that if the submission is successful then it will be included in the chain's mempool.
+        /// The network will then attempt to execute the transaction in the next available block, where the results of its execution can be seen on the EventBus.
+        pub async fn submit_raw_transaction(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SubmitRawTransactionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SubmitRawTransactionResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/vega.api.v1.CoreService/SubmitRawTransaction",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("vega.api.v1.CoreService", "SubmitRawTransaction"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Check transaction
+        ///
+        /// Send a signed transaction containing a command to the network to be checked, but not added to the chain's mempool.
+        /// This is useful for checking the validity of a potential transaction before submitting it.
+        pub async fn check_transaction(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CheckTransactionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CheckTransactionResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/vega.api.v1.CoreService/CheckTransaction",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("vega.api.v1.CoreService", "CheckTransaction"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Check raw transaction
+        ///
+        /// Send a pre-serialised transaction containing a command to the network to be checked, but then not added to the chain's mempool.
+        /// This is useful for checking the validity of a potential transaction before submitting it.
+        pub async fn check_raw_transaction(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CheckRawTransactionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CheckRawTransactionResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/vega.api.v1.CoreService/CheckRawTransaction",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("vega.api.v1.CoreService", "CheckRawTransaction"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get Spam statistics
+        ///
+        /// Get the spam statistics for a given party.
+        pub async fn get_spam_statistics(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetSpamStatisticsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetSpamStatisticsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/vega.api.v1.CoreService/GetSpamStatistics",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("vega.api.v1.CoreService", "GetSpamStatistics"));
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+#[cfg(feature = "server")]
+pub mod core_service_server {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with CoreServiceServer.
+    #[async_trait]
+    pub trait CoreService: Send + Sync + 'static {
+        /// Submit transaction
+        ///
+        /// Submit a signed transaction to the network containing a command to be executed such that if the submission is successful then it will be included in the chain's mempool.
+        /// The network will then attempt to execute the transaction in the next available block, where the results of its execution can be seen on the EventBus.
+        async fn submit_transaction(
+            &self,
+            request: tonic::Request<super::SubmitTransactionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SubmitTransactionResponse>,
+            tonic::Status,
+        >;
+        /// Chain event
+        ///
+        /// Propagate a chain event
+        async fn propagate_chain_event(
+            &self,
+            request: tonic::Request<super::PropagateChainEventRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PropagateChainEventResponse>,
+            tonic::Status,
+        >;
+        /// Statistics
+        ///
+        /// Get statistics on Vega
+        async fn statistics(
+            &self,
+            request: tonic::Request<super::StatisticsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::StatisticsResponse>,
+            tonic::Status,
+        >;
+        /// Blockchain height
+        ///
+        /// Get the height of the last tendermint block
+        async fn last_block_height(
+            &self,
+            request: tonic::Request<super::LastBlockHeightRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::LastBlockHeightResponse>,
+            tonic::Status,
+        >;
+        /// Vega time
+        ///
+        /// Get current Vega time
+        async fn get_vega_time(
+            &self,
+            request: tonic::Request<super::GetVegaTimeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetVegaTimeResponse>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the ObserveEventBus method.
+        type ObserveEventBusStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::ObserveEventBusResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// Events subscription
+        ///
+        /// Subscribe to a stream of events from the core
+        async fn observe_event_bus(
+            &self,
+            request: tonic::Request<tonic::Streaming<super::ObserveEventBusRequest>>,
+        ) -> std::result::Result<
+            tonic::Response<Self::ObserveEventBusStream>,
+            tonic::Status,
+        >;
+        /// Submit raw transaction
+        ///
+        /// Submit a pre-serialised signed transaction containing a command to the network to be executed, such that if the submission is successful then it will be included in the chain's mempool.
+        /// The network will then attempt to execute the transaction in the next available block, where the results of its execution can be seen on the EventBus.
+        async fn submit_raw_transaction(
+            &self,
+            request: tonic::Request<super::SubmitRawTransactionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SubmitRawTransactionResponse>,
+            tonic::Status,
+        >;
+        /// Check transaction
+        ///
+        /// Send a signed transaction containing a command to the network to be checked, but not added to the chain's mempool.
+        /// This is useful for checking the validity of a potential transaction before submitting it.
+        async fn check_transaction(
+            &self,
+            request: tonic::Request<super::CheckTransactionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CheckTransactionResponse>,
+            tonic::Status,
+        >;
+        /// Check raw transaction
+        ///
+        /// Send a pre-serialised transaction containing a command to the network to be checked, but then not added to the chain's mempool.
+        /// This is useful for checking the validity of a potential transaction before submitting it.
+        async fn check_raw_transaction(
+            &self,
+            request: tonic::Request<super::CheckRawTransactionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CheckRawTransactionResponse>,
+            tonic::Status,
+        >;
+        /// Get Spam statistics
+        ///
+        /// Get the spam statistics for a given party.
+        async fn get_spam_statistics(
+            &self,
+            request: tonic::Request<super::GetSpamStatisticsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetSpamStatisticsResponse>,
+            tonic::Status,
+        >;
+    }
+    #[derive(Debug)]
+    pub struct CoreServiceServer<T: CoreService> {
+        inner: _Inner<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    struct _Inner<T>(Arc<T>);
+    impl<T: CoreService> CoreServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            let inner = _Inner(inner);
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for CoreServiceServer<T>
+    where
+        T: CoreService,
+        B: Body + Send + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            let inner = self.inner.clone();
+            match req.uri().path() {
+                "/vega.api.v1.CoreService/SubmitTransaction" => {
+                    #[allow(non_camel_case_types)]
+                    struct SubmitTransactionSvc<T: CoreService>(pub Arc<T>);
+                    impl<
+                        T: CoreService,
+                    > tonic::server::UnaryService<super::SubmitTransactionRequest>
+                    for SubmitTransactionSvc<T> {
+                        type Response = super::SubmitTransactionResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SubmitTransactionRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CoreService>::submit_transaction(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = SubmitTransactionSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/vega.api.v1.CoreService/PropagateChainEvent" => {
+                    #[allow(non_camel_case_types)]
+                    struct PropagateChainEventSvc<T: CoreService>(pub Arc<T>);
+                    impl<
+                        T: CoreService,
+                    > tonic::server::UnaryService<super::PropagateChainEventRequest>
+                    for PropagateChainEventSvc<T> {
+                        type Response = super::PropagateChainEventResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PropagateChainEventRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CoreService>::propagate_chain_event(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = PropagateChainEventSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/vega.api.v1.CoreService/Statistics" => {
+                    #[allow(non_camel_case_types)]
+                    struct StatisticsSvc<T: CoreService>(pub Arc<T>);
+                    impl<
+                        T: CoreService,
+                    > tonic::server::UnaryService<super::StatisticsRequest>
+                    for StatisticsSvc<T> {
+                        type Response = super::StatisticsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::StatisticsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CoreService>::statistics(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = StatisticsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/vega.api.v1.CoreService/LastBlockHeight" => {
+                    #[allow(non_camel_case_types)]
+                    struct LastBlockHeightSvc<T: CoreService>(pub Arc<T>);
+                    impl<
+                        T: CoreService,
+                    > tonic::server::UnaryService<super::LastBlockHeightRequest>
+                    for LastBlockHeightSvc<T> {
+                        type Response = super::LastBlockHeightResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::LastBlockHeightRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CoreService>::last_block_height(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = LastBlockHeightSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/vega.api.v1.CoreService/GetVegaTime" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetVegaTimeSvc<T: CoreService>(pub Arc<T>);
+                    impl<
+                        T: CoreService,
+                    > tonic::server::UnaryService<super::GetVegaTimeRequest>
+                    for GetVegaTimeSvc<T> {
+                        type Response = super::GetVegaTimeResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetVegaTimeRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CoreService>::get_vega_time(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetVegaTimeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/vega.api.v1.CoreService/ObserveEventBus" => {
+                    #[allow(non_camel_case_types)]
+                    struct ObserveEventBusSvc<T: CoreService>(pub Arc<T>);
+                    impl<
+                        T: CoreService,
+                    > tonic::server::StreamingService<super::ObserveEventBusRequest>
+                    for ObserveEventBusSvc<T> {
+                        type Response = super::ObserveEventBusResponse;
+                        type ResponseStream = T::ObserveEventBusStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                tonic::Streaming<super::ObserveEventBusRequest>,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CoreService>::observe_event_bus(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ObserveEventBusSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/vega.api.v1.CoreService/SubmitRawTransaction" => {
+                    #[allow(non_camel_case_types)]
+                    struct SubmitRawTransactionSvc<T: CoreService>(pub Arc<T>);
+                    impl<
+                        T: CoreService,
+                    > tonic::server::UnaryService<super::SubmitRawTransactionRequest>
+                    for SubmitRawTransactionSvc<T> {
+                        type Response = super::SubmitRawTransactionResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SubmitRawTransactionRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CoreService>::submit_raw_transaction(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = SubmitRawTransactionSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/vega.api.v1.CoreService/CheckTransaction" => {
+                    #[allow(non_camel_case_types)]
+                    struct CheckTransactionSvc<T: CoreService>(pub Arc<T>);
+                    impl<
+                        T: CoreService,
+                    > tonic::server::UnaryService<super::CheckTransactionRequest>
+                    for CheckTransactionSvc<T> {
+                        type Response = super::CheckTransactionResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CheckTransactionRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CoreService>::check_transaction(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = CheckTransactionSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/vega.api.v1.CoreService/CheckRawTransaction" => {
+                    #[allow(non_camel_case_types)]
+                    struct CheckRawTransactionSvc<T: CoreService>(pub Arc<T>);
+                    impl<
+                        T: CoreService,
+                    > tonic::server::UnaryService<super::CheckRawTransactionRequest>
+                    for CheckRawTransactionSvc<T> {
+                        type Response = super::CheckRawTransactionResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CheckRawTransactionRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CoreService>::check_raw_transaction(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = CheckRawTransactionSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/vega.api.v1.CoreService/GetSpamStatistics" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetSpamStatisticsSvc<T: CoreService>(pub Arc<T>);
+                    impl<
+                        T: CoreService,
+                    > tonic::server::UnaryService<super::GetSpamStatisticsRequest>
+                    for GetSpamStatisticsSvc<T> {
+                        type Response = super::GetSpamStatisticsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetSpamStatisticsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CoreService>::get_spam_statistics(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetSpamStatisticsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        Ok(
+                            http::Response::builder()
+                                .status(200)
+                                .header("grpc-status", "12")
+                                .header("content-type", "application/grpc")
+                                .body(empty_body())
+                                .unwrap(),
+                        )
+                    })
+                }
+            }
+        }
+    }
+    impl<T: CoreService> Clone for CoreServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    impl<T: CoreService> Clone for _Inner<T> {
+        fn clone(&self) -> Self {
+            Self(Arc::clone(&self.0))
+        }
+    }
+    impl<T: std::fmt::Debug> std::fmt::Debug for _Inner<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{:?}", self.0)
+        }
+    }
+    impl<T: CoreService> tonic::server::NamedService for CoreServiceServer<T> {
+        const NAME: &'static str = "vega.api.v1.CoreService";
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Account {
+    #[prost(string, tag = "1")]
+    pub party: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub market: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub balance: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub asset: ::prost::alloc::string::String,
+    #[prost(string, tag = "6")]
+    pub r#type: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListAccountsRequest {
+    #[prost(string, tag = "1")]
+    pub party: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub market: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListAccountsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub accounts: ::prost::alloc::vec::Vec<Account>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListAssetsRequest {
+    /// optional ID
+    #[prost(string, tag = "1")]
+    pub asset: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListAssetsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub assets: ::prost::alloc::vec::Vec<super::super::Asset>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListNetworkParametersRequest {
+    /// optional parameter key
+    #[prost(string, tag = "1")]
+    pub network_parameter_key: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListNetworkParametersResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub network_parameters: ::prost::alloc::vec::Vec<super::super::NetworkParameter>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListNetworkLimitsRequest {}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListNetworkLimitsResponse {
+    #[prost(message, optional, tag = "1")]
+    pub network_limits: ::core::option::Option<super::super::NetworkLimits>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListPartiesRequest {}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListPartiesResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub parties: ::prost::alloc::vec::Vec<super::super::Party>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListValidatorsRequest {}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListValidatorsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub validators: ::prost::alloc::vec::Vec<super::super::events::v1::ValidatorUpdate>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListMarketsRequest {
+    #[prost(string, tag = "1")]
+    pub market: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListMarketsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub markets: ::prost::alloc::vec::Vec<super::super::Market>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListProposalsRequest {
+    /// optional ID
+    #[prost(string, tag = "1")]
+    pub proposal: ::prost::alloc::string::String,
+    /// optional party
+    #[prost(string, tag = "2")]
+    pub proposer: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListProposalsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub proposals: ::prost::alloc::vec::Vec<super::super::Proposal>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListMarketsDataRequest {
+    #[prost(string, tag = "1")]
+    pub market: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListMarketsDataResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub markets_data: ::prost::alloc::vec::Vec<super::super::MarketData>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListVotesRequest {
+    #[prost(string, tag = "1")]
+    pub proposal: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub party: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListVotesResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub votes: ::prost::alloc::vec::Vec<super::super::Vote>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PartyStake {
+    #[prost(string, tag = "1")]
+    pub party: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub current_stake_available: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "3")]
+    pub stake_linkings: ::prost::alloc::vec::Vec<super::super::events::v1::StakeLinking>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListPartiesStakeRequest {
+    #[prost(string, tag = "1")]
+    pub party: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListPartiesStakeResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub parties_stake: ::prost::alloc::vec::Vec<PartyStake>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListDelegationsRequest {
+    #[prost(string, tag = "1")]
+    pub party: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub node: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub epoch_seq: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListDelegationsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub delegations: ::prost::alloc::vec::Vec<super::super::Delegation>,
+}
+/// Generated client implementations.
+pub mod core_state_service_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    #[derive(Debug, Clone)]
+    pub struct CoreStateServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl CoreStateServiceClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> CoreStateServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> CoreStateServiceClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+            >>::Error: Into<StdError> + Send + Sync,
+        {
+            CoreStateServiceClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        /// Accounts list
+        ///
+        /// Get a list of accounts. This endpoint may not be exposed by mainnet nodes.
+        pub async fn list_accounts(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListAccountsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListAccountsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/vega.api.v1.CoreStateService/ListAccounts",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("vega.api.v1.CoreStateService", "ListAccounts"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Assets list
+        ///
+        /// Get a list of assets. This endpoint may not be exposed by mainnet nodes.
+        pub async fn list_assets(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListAssetsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListAssetsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/vega.api.v1.CoreStateService/ListAssets",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("vega.api.v1.CoreStateService", "ListAssets"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Network parameters list
+        ///
+        /// Get a list of network parameters. This endpoint may not be exposed by mainnet nodes.
+        pub async fn list_network_parameters(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListNetworkParametersRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListNetworkParametersResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/vega.api.v1.CoreStateService/ListNetworkParameters",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "vega.api.v1.CoreStateService",
+                        "ListNetworkParameters",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Network limits list
+        ///
+        /// Get a list of network limits. This endpoint may not be exposed by mainnet nodes.
+        pub async fn list_network_limits(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListNetworkLimitsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListNetworkLimitsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/vega.api.v1.CoreStateService/ListNetworkLimits",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("vega.api.v1.CoreStateService", "ListNetworkLimits"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Parties list
+        ///
+        /// Get a list of parties. This endpoint may not be exposed by mainnet nodes.
+        pub async fn list_parties(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListPartiesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListPartiesResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/vega.api.v1.CoreStateService/ListParties",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("vega.api.v1.CoreStateService", "ListParties"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Validators list
+        ///
+        /// Get a list of validators. This endpoint may not be exposed by mainnet nodes.
+        pub async fn list_validators(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListValidatorsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListValidatorsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/vega.api.v1.CoreStateService/ListValidators",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("vega.api.v1.CoreStateService", "ListValidators"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Markets list
+        ///
+        /// Get a list of markets. This endpoint may not be exposed by mainnet nodes.
+        pub async fn list_markets(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListMarketsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListMarketsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/vega.api.v1.CoreStateService/ListMarkets",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("vega.api.v1.CoreStateService", "ListMarkets"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Proposals list
+        ///
+        /// Get a list of proposals. This endpoint may not be exposed by mainnet nodes.
+        pub async fn list_proposals(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListProposalsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListProposalsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/vega.api.v1.CoreStateService/ListProposals",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("vega.api.v1.CoreStateService", "ListProposals"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Markets data list
+        ///
+        /// Get a list of markets' data. This endpoint may not be exposed by mainnet nodes.
+        pub async fn list_markets_data(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListMarketsDataRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListMarketsDataResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/vega.api.v1.CoreStateService/ListMarketsData",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("vega.api.v1.CoreStateService", "ListMarketsData"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Votes list
+        ///
+        /// Get a list of votes. This endpoint may not be exposed by mainnet nodes.
+        pub async fn list_votes(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListVotesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListVotesResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/vega.api.v1.CoreStateService/ListVotes",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("vega.api.v1.CoreStateService", "ListVotes"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Parties stake list
+        ///
+        /// Get a list of parties' stake. This endpoint may not be exposed by mainnet nodes.
+        pub async fn list_parties_stake(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListPartiesStakeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListPartiesStakeResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/vega.api.v1.CoreStateService/ListPartiesStake",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("vega.api.v1.CoreStateService", "ListPartiesStake"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Delegations list
+        ///
+        /// Get a list of delegations. This endpoint may not be exposed by mainnet nodes.
+        pub async fn list_delegations(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListDelegationsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListDelegationsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/vega.api.v1.CoreStateService/ListDelegations",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("vega.api.v1.CoreStateService", "ListDelegations"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+#[cfg(feature = "server")]
+pub mod core_state_service_server {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with CoreStateServiceServer.
+    #[async_trait]
+    pub trait CoreStateService: Send + Sync + 'static {
+        /// Accounts list
+        ///
+        /// Get a list of accounts. This endpoint may not be exposed by mainnet nodes.
+        async fn list_accounts(
+            &self,
+            request: tonic::Request<super::ListAccountsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListAccountsResponse>,
+            tonic::Status,
+        >;
+        /// Assets list
+        ///
+        /// Get a list of assets. This endpoint may not be exposed by mainnet nodes.
+        async fn list_assets(
+            &self,
+            request: tonic::Request<super::ListAssetsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListAssetsResponse>,
+            tonic::Status,
+        >;
+        /// Network parameters list
+        ///
+        /// Get a list of network parameters. This endpoint may not be exposed by mainnet nodes.
+        async fn list_network_parameters(
+            &self,
+            request: tonic::Request<super::ListNetworkParametersRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListNetworkParametersResponse>,
+            tonic::Status,
+        >;
+        /// Network limits list
+        ///
+        /// Get a list of network limits. This endpoint may not be exposed by mainnet nodes.
+        async fn list_network_limits(
+            &self,
+            request: tonic::Request<super::ListNetworkLimitsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListNetworkLimitsResponse>,
+            tonic::Status,
+        >;
+        /// Parties list
+        ///
+        /// Get a list of parties. This endpoint may not be exposed by mainnet nodes.
+        async fn list_parties(
+            &self,
+            request: tonic::Request<super::ListPartiesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListPartiesResponse>,
+            tonic::Status,
+        >;
+        /// Validators list
+        ///
+        /// Get a list of validators. This endpoint may not be exposed by mainnet nodes.
+        async fn list_validators(
+            &self,
+            request: tonic::Request<super::ListValidatorsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListValidatorsResponse>,
+            tonic::Status,
+        >;
+        /// Markets list
+        ///
+        /// Get a list of markets. This endpoint may not be exposed by mainnet nodes.
+        async fn list_markets(
+            &self,
+            request: tonic::Request<super::ListMarketsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListMarketsResponse>,
+            tonic::Status,
+        >;
+        /// Proposals list
+        ///
+        /// Get a list of proposals. This endpoint may not be exposed by mainnet nodes.
+        async fn list_proposals(
+            &self,
+            request: tonic::Request<super::ListProposalsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListProposalsResponse>,
+            tonic::Status,
+        >;
+        /// Markets data list
+        ///
+        /// Get a list of markets' data. This endpoint may not be exposed by mainnet nodes.
+        async fn list_markets_data(
+            &self,
+            request: tonic::Request<super::ListMarketsDataRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListMarketsDataResponse>,
+            tonic::Status,
+        >;
+        /// Votes list
+        ///
+        /// Get a list of votes. This endpoint may not be exposed by mainnet nodes.
+        async fn list_votes(
+            &self,
+            request: tonic::Request<super::ListVotesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListVotesResponse>,
+            tonic::Status,
+        >;
+        /// Parties stake list
+        ///
+        /// Get a list of parties' stake. This endpoint may not be exposed by mainnet nodes.
+        async fn list_parties_stake(
+            &self,
+            request: tonic::Request<super::ListPartiesStakeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListPartiesStakeResponse>,
+            tonic::Status,
+        >;
+        /// Delegations list
+        ///
+        /// Get a list of delegations. This endpoint may not be exposed by mainnet nodes.
+        async fn list_delegations(
+            &self,
+            request: tonic::Request<super::ListDelegationsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListDelegationsResponse>,
+            tonic::Status,
+        >;
+    }
+    #[derive(Debug)]
+    pub struct CoreStateServiceServer<T: CoreStateService> {
+        inner: _Inner<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    struct _Inner<T>(Arc<T>);
+    impl<T: CoreStateService> CoreStateServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            let inner = _Inner(inner);
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for CoreStateServiceServer<T>
+    where
+        T: CoreStateService,
+        B: Body + Send + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            let inner = self.inner.clone();
+            match req.uri().path() {
+                "/vega.api.v1.CoreStateService/ListAccounts" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListAccountsSvc<T: CoreStateService>(pub Arc<T>);
+                    impl<
+                        T: CoreStateService,
+                    > tonic::server::UnaryService<super::ListAccountsRequest>
+                    for ListAccountsSvc<T> {
+                        type Response = super::ListAccountsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListAccountsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CoreStateService>::list_accounts(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListAccountsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/vega.api.v1.CoreStateService/ListAssets" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListAssetsSvc<T: CoreStateService>(pub Arc<T>);
+                    impl<
+                        T: CoreStateService,
+                    > tonic::server::UnaryService<super::ListAssetsRequest>
+                    for ListAssetsSvc<T> {
+                        type Response = super::ListAssetsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListAssetsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CoreStateService>::list_assets(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListAssetsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/vega.api.v1.CoreStateService/ListNetworkParameters" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListNetworkParametersSvc<T: CoreStateService>(pub Arc<T>);
+                    impl<
+                        T: CoreStateService,
+                    > tonic::server::UnaryService<super::ListNetworkParametersRequest>
+                    for ListNetworkParametersSvc<T> {
+                        type Response = super::ListNetworkParametersResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListNetworkParametersRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CoreStateService>::list_network_parameters(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListNetworkParametersSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/vega.api.v1.CoreStateService/ListNetworkLimits" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListNetworkLimitsSvc<T: CoreStateService>(pub Arc<T>);
+                    impl<
+                        T: CoreStateService,
+                    > tonic::server::UnaryService<super::ListNetworkLimitsRequest>
+                    for ListNetworkLimitsSvc<T> {
+                        type Response = super::ListNetworkLimitsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListNetworkLimitsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CoreStateService>::list_network_limits(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListNetworkLimitsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/vega.api.v1.CoreStateService/ListParties" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListPartiesSvc<T: CoreStateService>(pub Arc<T>);
+                    impl<
+                        T: CoreStateService,
+                    > tonic::server::UnaryService<super::ListPartiesRequest>
+                    for ListPartiesSvc<T> {
+                        type Response = super::ListPartiesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListPartiesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CoreStateService>::list_parties(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListPartiesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/vega.api.v1.CoreStateService/ListValidators" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListValidatorsSvc<T: CoreStateService>(pub Arc<T>);
+                    impl<
+                        T: CoreStateService,
+                    > tonic::server::UnaryService<super::ListValidatorsRequest>
+                    for ListValidatorsSvc<T> {
+                        type Response = super::ListValidatorsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListValidatorsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CoreStateService>::list_validators(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListValidatorsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/vega.api.v1.CoreStateService/ListMarkets" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListMarketsSvc<T: CoreStateService>(pub Arc<T>);
+                    impl<
+                        T: CoreStateService,
+                    > tonic::server::UnaryService<super::ListMarketsRequest>
+                    for ListMarketsSvc<T> {
+                        type Response = super::ListMarketsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListMarketsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CoreStateService>::list_markets(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListMarketsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/vega.api.v1.CoreStateService/ListProposals" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListProposalsSvc<T: CoreStateService>(pub Arc<T>);
+                    impl<
+                        T: CoreStateService,
+                    > tonic::server::UnaryService<super::ListProposalsRequest>
+                    for ListProposalsSvc<T> {
+                        type Response = super::ListProposalsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListProposalsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CoreStateService>::list_proposals(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListProposalsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/vega.api.v1.CoreStateService/ListMarketsData" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListMarketsDataSvc<T: CoreStateService>(pub Arc<T>);
+                    impl<
+                        T: CoreStateService,
+                    > tonic::server::UnaryService<super::ListMarketsDataRequest>
+                    for ListMarketsDataSvc<T> {
+                        type Response = super::ListMarketsDataResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListMarketsDataRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CoreStateService>::list_markets_data(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListMarketsDataSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/vega.api.v1.CoreStateService/ListVotes" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListVotesSvc<T: CoreStateService>(pub Arc<T>);
+                    impl<
+                        T: CoreStateService,
+                    > tonic::server::UnaryService<super::ListVotesRequest>
+                    for ListVotesSvc<T> {
+                        type Response = super::ListVotesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListVotesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CoreStateService>::list_votes(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListVotesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/vega.api.v1.CoreStateService/ListPartiesStake" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListPartiesStakeSvc<T: CoreStateService>(pub Arc<T>);
+                    impl<
+                        T: CoreStateService,
+                    > tonic::server::UnaryService<super::ListPartiesStakeRequest>
+                    for ListPartiesStakeSvc<T> {
+                        type Response = super::ListPartiesStakeResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListPartiesStakeRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CoreStateService>::list_parties_stake(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListPartiesStakeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/vega.api.v1.CoreStateService/ListDelegations" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListDelegationsSvc<T: CoreStateService>(pub Arc<T>);
+                    impl<
+                        T: CoreStateService,
+                    > tonic::server::UnaryService<super::ListDelegationsRequest>
+                    for ListDelegationsSvc<T> {
+                        type Response = super::ListDelegationsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListDelegationsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CoreStateService>::list_delegations(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListDelegationsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        Ok(
+                            http::Response::builder()
+                                .status(200)
+                                .header("grpc-status", "12")
+                                .header("content-type", "application/grpc")
+                                .body(empty_body())
+                                .unwrap(),
+                        )
+                    })
+                }
+            }
+        }
+    }
+    impl<T: CoreStateService> Clone for CoreStateServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    impl<T: CoreStateService> Clone for _Inner<T> {
+        fn clone(&self) -> Self {
+            Self(Arc::clone(&self.0))
+        }
+    }
+    impl<T: std::fmt::Debug> std::fmt::Debug for _Inner<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{:?}", self.0)
+        }
+    }
+    impl<T: CoreStateService> tonic::server::NamedService for CoreStateServiceServer<T> {
+        const NAME: &'static str = "vega.api.v1.CoreStateService";
+    }
+}
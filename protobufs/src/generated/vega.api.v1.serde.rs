@@ -0,0 +1,6996 @@
+impl serde::Serialize for Account {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.party.is_empty() {
+            len += 1;
+        }
+        if !self.market.is_empty() {
+            len += 1;
+        }
+        if !self.balance.is_empty() {
+            len += 1;
+        }
+        if !self.asset.is_empty() {
+            len += 1;
+        }
+        if !self.r#type.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.api.v1.Account", len)?;
+        if !self.party.is_empty() {
+            struct_ser.serialize_field("party", &self.party)?;
+        }
+        if !self.market.is_empty() {
+            struct_ser.serialize_field("market", &self.market)?;
+        }
+        if !self.balance.is_empty() {
+            struct_ser.serialize_field("balance", &self.balance)?;
+        }
+        if !self.asset.is_empty() {
+            struct_ser.serialize_field("asset", &self.asset)?;
+        }
+        if !self.r#type.is_empty() {
+            struct_ser.serialize_field("type", &self.r#type)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Account {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "party",
+            "market",
+            "balance",
+            "asset",
+            "type",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Party,
+            Market,
+            Balance,
+            Asset,
+            Type,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "party" => Ok(GeneratedField::Party),
+                            "market" => Ok(GeneratedField::Market),
+                            "balance" => Ok(GeneratedField::Balance),
+                            "asset" => Ok(GeneratedField::Asset),
+                            "type" => Ok(GeneratedField::Type),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Account;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.api.v1.Account")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Account, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut party__ = None;
+                let mut market__ = None;
+                let mut balance__ = None;
+                let mut asset__ = None;
+                let mut r#type__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Party => {
+                            if party__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("party"));
+                            }
+                            party__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Market => {
+                            if market__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("market"));
+                            }
+                            market__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Balance => {
+                            if balance__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("balance"));
+                            }
+                            balance__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Asset => {
+                            if asset__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("asset"));
+                            }
+                            asset__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Type => {
+                            if r#type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("type"));
+                            }
+                            r#type__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(Account {
+                    party: party__.unwrap_or_default(),
+                    market: market__.unwrap_or_default(),
+                    balance: balance__.unwrap_or_default(),
+                    asset: asset__.unwrap_or_default(),
+                    r#type: r#type__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.api.v1.Account", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for CheckRawTransactionRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.tx.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.api.v1.CheckRawTransactionRequest", len)?;
+        if !self.tx.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("tx", pbjson::private::base64::encode(&self.tx).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for CheckRawTransactionRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "tx",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Tx,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "tx" => Ok(GeneratedField::Tx),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CheckRawTransactionRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.api.v1.CheckRawTransactionRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CheckRawTransactionRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut tx__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Tx => {
+                            if tx__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tx"));
+                            }
+                            tx__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(CheckRawTransactionRequest {
+                    tx: tx__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.api.v1.CheckRawTransactionRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for CheckRawTransactionResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.success {
+            len += 1;
+        }
+        if self.code != 0 {
+            len += 1;
+        }
+        if self.gas_wanted != 0 {
+            len += 1;
+        }
+        if self.gas_used != 0 {
+            len += 1;
+        }
+        if !self.data.is_empty() {
+            len += 1;
+        }
+        if !self.log.is_empty() {
+            len += 1;
+        }
+        if !self.info.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.api.v1.CheckRawTransactionResponse", len)?;
+        if self.success {
+            struct_ser.serialize_field("success", &self.success)?;
+        }
+        if self.code != 0 {
+            struct_ser.serialize_field("code", &self.code)?;
+        }
+        if self.gas_wanted != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("gasWanted", ToString::to_string(&self.gas_wanted).as_str())?;
+        }
+        if self.gas_used != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("gasUsed", ToString::to_string(&self.gas_used).as_str())?;
+        }
+        if !self.data.is_empty() {
+            struct_ser.serialize_field("data", &self.data)?;
+        }
+        if !self.log.is_empty() {
+            struct_ser.serialize_field("log", &self.log)?;
+        }
+        if !self.info.is_empty() {
+            struct_ser.serialize_field("info", &self.info)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for CheckRawTransactionResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "success",
+            "code",
+            "gas_wanted",
+            "gasWanted",
+            "gas_used",
+            "gasUsed",
+            "data",
+            "log",
+            "info",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Success,
+            Code,
+            GasWanted,
+            GasUsed,
+            Data,
+            Log,
+            Info,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "success" => Ok(GeneratedField::Success),
+                            "code" => Ok(GeneratedField::Code),
+                            "gasWanted" | "gas_wanted" => Ok(GeneratedField::GasWanted),
+                            "gasUsed" | "gas_used" => Ok(GeneratedField::GasUsed),
+                            "data" => Ok(GeneratedField::Data),
+                            "log" => Ok(GeneratedField::Log),
+                            "info" => Ok(GeneratedField::Info),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CheckRawTransactionResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.api.v1.CheckRawTransactionResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CheckRawTransactionResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut success__ = None;
+                let mut code__ = None;
+                let mut gas_wanted__ = None;
+                let mut gas_used__ = None;
+                let mut data__ = None;
+                let mut log__ = None;
+                let mut info__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Success => {
+                            if success__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("success"));
+                            }
+                            success__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Code => {
+                            if code__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("code"));
+                            }
+                            code__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::GasWanted => {
+                            if gas_wanted__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("gasWanted"));
+                            }
+                            gas_wanted__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::GasUsed => {
+                            if gas_used__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("gasUsed"));
+                            }
+                            gas_used__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Data => {
+                            if data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("data"));
+                            }
+                            data__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Log => {
+                            if log__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("log"));
+                            }
+                            log__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Info => {
+                            if info__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("info"));
+                            }
+                            info__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(CheckRawTransactionResponse {
+                    success: success__.unwrap_or_default(),
+                    code: code__.unwrap_or_default(),
+                    gas_wanted: gas_wanted__.unwrap_or_default(),
+                    gas_used: gas_used__.unwrap_or_default(),
+                    data: data__.unwrap_or_default(),
+                    log: log__.unwrap_or_default(),
+                    info: info__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.api.v1.CheckRawTransactionResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for CheckTransactionRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.tx.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.api.v1.CheckTransactionRequest", len)?;
+        if let Some(v) = self.tx.as_ref() {
+            struct_ser.serialize_field("tx", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for CheckTransactionRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "tx",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Tx,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "tx" => Ok(GeneratedField::Tx),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CheckTransactionRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.api.v1.CheckTransactionRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CheckTransactionRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut tx__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Tx => {
+                            if tx__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tx"));
+                            }
+                            tx__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(CheckTransactionRequest {
+                    tx: tx__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.api.v1.CheckTransactionRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for CheckTransactionResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.success {
+            len += 1;
+        }
+        if self.code != 0 {
+            len += 1;
+        }
+        if self.gas_wanted != 0 {
+            len += 1;
+        }
+        if self.gas_used != 0 {
+            len += 1;
+        }
+        if !self.data.is_empty() {
+            len += 1;
+        }
+        if !self.log.is_empty() {
+            len += 1;
+        }
+        if !self.info.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.api.v1.CheckTransactionResponse", len)?;
+        if self.success {
+            struct_ser.serialize_field("success", &self.success)?;
+        }
+        if self.code != 0 {
+            struct_ser.serialize_field("code", &self.code)?;
+        }
+        if self.gas_wanted != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("gasWanted", ToString::to_string(&self.gas_wanted).as_str())?;
+        }
+        if self.gas_used != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("gasUsed", ToString::to_string(&self.gas_used).as_str())?;
+        }
+        if !self.data.is_empty() {
+            struct_ser.serialize_field("data", &self.data)?;
+        }
+        if !self.log.is_empty() {
+            struct_ser.serialize_field("log", &self.log)?;
+        }
+        if !self.info.is_empty() {
+            struct_ser.serialize_field("info", &self.info)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for CheckTransactionResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "success",
+            "code",
+            "gas_wanted",
+            "gasWanted",
+            "gas_used",
+            "gasUsed",
+            "data",
+            "log",
+            "info",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Success,
+            Code,
+            GasWanted,
+            GasUsed,
+            Data,
+            Log,
+            Info,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "success" => Ok(GeneratedField::Success),
+                            "code" => Ok(GeneratedField::Code),
+                            "gasWanted" | "gas_wanted" => Ok(GeneratedField::GasWanted),
+                            "gasUsed" | "gas_used" => Ok(GeneratedField::GasUsed),
+                            "data" => Ok(GeneratedField::Data),
+                            "log" => Ok(GeneratedField::Log),
+                            "info" => Ok(GeneratedField::Info),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CheckTransactionResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.api.v1.CheckTransactionResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CheckTransactionResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut success__ = None;
+                let mut code__ = None;
+                let mut gas_wanted__ = None;
+                let mut gas_used__ = None;
+                let mut data__ = None;
+                let mut log__ = None;
+                let mut info__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Success => {
+                            if success__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("success"));
+                            }
+                            success__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Code => {
+                            if code__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("code"));
+                            }
+                            code__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::GasWanted => {
+                            if gas_wanted__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("gasWanted"));
+                            }
+                            gas_wanted__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::GasUsed => {
+                            if gas_used__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("gasUsed"));
+                            }
+                            gas_used__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Data => {
+                            if data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("data"));
+                            }
+                            data__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Log => {
+                            if log__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("log"));
+                            }
+                            log__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Info => {
+                            if info__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("info"));
+                            }
+                            info__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(CheckTransactionResponse {
+                    success: success__.unwrap_or_default(),
+                    code: code__.unwrap_or_default(),
+                    gas_wanted: gas_wanted__.unwrap_or_default(),
+                    gas_used: gas_used__.unwrap_or_default(),
+                    data: data__.unwrap_or_default(),
+                    log: log__.unwrap_or_default(),
+                    info: info__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.api.v1.CheckTransactionResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for GetSpamStatisticsRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.party_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.api.v1.GetSpamStatisticsRequest", len)?;
+        if !self.party_id.is_empty() {
+            struct_ser.serialize_field("partyId", &self.party_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GetSpamStatisticsRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "party_id",
+            "partyId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PartyId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "partyId" | "party_id" => Ok(GeneratedField::PartyId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GetSpamStatisticsRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.api.v1.GetSpamStatisticsRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GetSpamStatisticsRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut party_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PartyId => {
+                            if party_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("partyId"));
+                            }
+                            party_id__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(GetSpamStatisticsRequest {
+                    party_id: party_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.api.v1.GetSpamStatisticsRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for GetSpamStatisticsResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.chain_id.is_empty() {
+            len += 1;
+        }
+        if self.statistics.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.api.v1.GetSpamStatisticsResponse", len)?;
+        if !self.chain_id.is_empty() {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
+        }
+        if let Some(v) = self.statistics.as_ref() {
+            struct_ser.serialize_field("statistics", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GetSpamStatisticsResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "chain_id",
+            "chainId",
+            "statistics",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ChainId,
+            Statistics,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            "statistics" => Ok(GeneratedField::Statistics),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GetSpamStatisticsResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.api.v1.GetSpamStatisticsResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GetSpamStatisticsResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut chain_id__ = None;
+                let mut statistics__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
+                            }
+                            chain_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Statistics => {
+                            if statistics__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("statistics"));
+                            }
+                            statistics__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(GetSpamStatisticsResponse {
+                    chain_id: chain_id__.unwrap_or_default(),
+                    statistics: statistics__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.api.v1.GetSpamStatisticsResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for GetVegaTimeRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("vega.api.v1.GetVegaTimeRequest", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GetVegaTimeRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                            Err(serde::de::Error::unknown_field(value, FIELDS))
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GetVegaTimeRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.api.v1.GetVegaTimeRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GetVegaTimeRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(GetVegaTimeRequest {
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.api.v1.GetVegaTimeRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for GetVegaTimeResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.timestamp != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.api.v1.GetVegaTimeResponse", len)?;
+        if self.timestamp != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("timestamp", ToString::to_string(&self.timestamp).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GetVegaTimeResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "timestamp",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Timestamp,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "timestamp" => Ok(GeneratedField::Timestamp),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GetVegaTimeResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.api.v1.GetVegaTimeResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GetVegaTimeResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut timestamp__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Timestamp => {
+                            if timestamp__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timestamp"));
+                            }
+                            timestamp__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(GetVegaTimeResponse {
+                    timestamp: timestamp__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.api.v1.GetVegaTimeResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for LastBlockHeightRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("vega.api.v1.LastBlockHeightRequest", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for LastBlockHeightRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                            Err(serde::de::Error::unknown_field(value, FIELDS))
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = LastBlockHeightRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.api.v1.LastBlockHeightRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LastBlockHeightRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(LastBlockHeightRequest {
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.api.v1.LastBlockHeightRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for LastBlockHeightResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.height != 0 {
+            len += 1;
+        }
+        if !self.hash.is_empty() {
+            len += 1;
+        }
+        if !self.spam_pow_hash_function.is_empty() {
+            len += 1;
+        }
+        if self.spam_pow_difficulty != 0 {
+            len += 1;
+        }
+        if self.spam_pow_number_of_past_blocks != 0 {
+            len += 1;
+        }
+        if self.spam_pow_number_of_tx_per_block != 0 {
+            len += 1;
+        }
+        if self.spam_pow_increasing_difficulty {
+            len += 1;
+        }
+        if !self.chain_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.api.v1.LastBlockHeightResponse", len)?;
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        if !self.hash.is_empty() {
+            struct_ser.serialize_field("hash", &self.hash)?;
+        }
+        if !self.spam_pow_hash_function.is_empty() {
+            struct_ser.serialize_field("spamPowHashFunction", &self.spam_pow_hash_function)?;
+        }
+        if self.spam_pow_difficulty != 0 {
+            struct_ser.serialize_field("spamPowDifficulty", &self.spam_pow_difficulty)?;
+        }
+        if self.spam_pow_number_of_past_blocks != 0 {
+            struct_ser.serialize_field("spamPowNumberOfPastBlocks", &self.spam_pow_number_of_past_blocks)?;
+        }
+        if self.spam_pow_number_of_tx_per_block != 0 {
+            struct_ser.serialize_field("spamPowNumberOfTxPerBlock", &self.spam_pow_number_of_tx_per_block)?;
+        }
+        if self.spam_pow_increasing_difficulty {
+            struct_ser.serialize_field("spamPowIncreasingDifficulty", &self.spam_pow_increasing_difficulty)?;
+        }
+        if !self.chain_id.is_empty() {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for LastBlockHeightResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "height",
+            "hash",
+            "spam_pow_hash_function",
+            "spamPowHashFunction",
+            "spam_pow_difficulty",
+            "spamPowDifficulty",
+            "spam_pow_number_of_past_blocks",
+            "spamPowNumberOfPastBlocks",
+            "spam_pow_number_of_tx_per_block",
+            "spamPowNumberOfTxPerBlock",
+            "spam_pow_increasing_difficulty",
+            "spamPowIncreasingDifficulty",
+            "chain_id",
+            "chainId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Height,
+            Hash,
+            SpamPowHashFunction,
+            SpamPowDifficulty,
+            SpamPowNumberOfPastBlocks,
+            SpamPowNumberOfTxPerBlock,
+            SpamPowIncreasingDifficulty,
+            ChainId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "height" => Ok(GeneratedField::Height),
+                            "hash" => Ok(GeneratedField::Hash),
+                            "spamPowHashFunction" | "spam_pow_hash_function" => Ok(GeneratedField::SpamPowHashFunction),
+                            "spamPowDifficulty" | "spam_pow_difficulty" => Ok(GeneratedField::SpamPowDifficulty),
+                            "spamPowNumberOfPastBlocks" | "spam_pow_number_of_past_blocks" => Ok(GeneratedField::SpamPowNumberOfPastBlocks),
+                            "spamPowNumberOfTxPerBlock" | "spam_pow_number_of_tx_per_block" => Ok(GeneratedField::SpamPowNumberOfTxPerBlock),
+                            "spamPowIncreasingDifficulty" | "spam_pow_increasing_difficulty" => Ok(GeneratedField::SpamPowIncreasingDifficulty),
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = LastBlockHeightResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.api.v1.LastBlockHeightResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LastBlockHeightResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut height__ = None;
+                let mut hash__ = None;
+                let mut spam_pow_hash_function__ = None;
+                let mut spam_pow_difficulty__ = None;
+                let mut spam_pow_number_of_past_blocks__ = None;
+                let mut spam_pow_number_of_tx_per_block__ = None;
+                let mut spam_pow_increasing_difficulty__ = None;
+                let mut chain_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Hash => {
+                            if hash__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("hash"));
+                            }
+                            hash__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::SpamPowHashFunction => {
+                            if spam_pow_hash_function__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("spamPowHashFunction"));
+                            }
+                            spam_pow_hash_function__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::SpamPowDifficulty => {
+                            if spam_pow_difficulty__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("spamPowDifficulty"));
+                            }
+                            spam_pow_difficulty__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::SpamPowNumberOfPastBlocks => {
+                            if spam_pow_number_of_past_blocks__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("spamPowNumberOfPastBlocks"));
+                            }
+                            spam_pow_number_of_past_blocks__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::SpamPowNumberOfTxPerBlock => {
+                            if spam_pow_number_of_tx_per_block__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("spamPowNumberOfTxPerBlock"));
+                            }
+                            spam_pow_number_of_tx_per_block__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::SpamPowIncreasingDifficulty => {
+                            if spam_pow_increasing_difficulty__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("spamPowIncreasingDifficulty"));
+                            }
+                            spam_pow_increasing_difficulty__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
+                            }
+                            chain_id__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(LastBlockHeightResponse {
+                    height: height__.unwrap_or_default(),
+                    hash: hash__.unwrap_or_default(),
+                    spam_pow_hash_function: spam_pow_hash_function__.unwrap_or_default(),
+                    spam_pow_difficulty: spam_pow_difficulty__.unwrap_or_default(),
+                    spam_pow_number_of_past_blocks: spam_pow_number_of_past_blocks__.unwrap_or_default(),
+                    spam_pow_number_of_tx_per_block: spam_pow_number_of_tx_per_block__.unwrap_or_default(),
+                    spam_pow_increasing_difficulty: spam_pow_increasing_difficulty__.unwrap_or_default(),
+                    chain_id: chain_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.api.v1.LastBlockHeightResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ListAccountsRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.party.is_empty() {
+            len += 1;
+        }
+        if !self.market.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.api.v1.ListAccountsRequest", len)?;
+        if !self.party.is_empty() {
+            struct_ser.serialize_field("party", &self.party)?;
+        }
+        if !self.market.is_empty() {
+            struct_ser.serialize_field("market", &self.market)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ListAccountsRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "party",
+            "market",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Party,
+            Market,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "party" => Ok(GeneratedField::Party),
+                            "market" => Ok(GeneratedField::Market),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ListAccountsRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.api.v1.ListAccountsRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ListAccountsRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut party__ = None;
+                let mut market__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Party => {
+                            if party__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("party"));
+                            }
+                            party__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Market => {
+                            if market__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("market"));
+                            }
+                            market__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(ListAccountsRequest {
+                    party: party__.unwrap_or_default(),
+                    market: market__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.api.v1.ListAccountsRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ListAccountsResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.accounts.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.api.v1.ListAccountsResponse", len)?;
+        if !self.accounts.is_empty() {
+            struct_ser.serialize_field("accounts", &self.accounts)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ListAccountsResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "accounts",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Accounts,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "accounts" => Ok(GeneratedField::Accounts),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ListAccountsResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.api.v1.ListAccountsResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ListAccountsResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut accounts__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Accounts => {
+                            if accounts__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("accounts"));
+                            }
+                            accounts__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(ListAccountsResponse {
+                    accounts: accounts__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.api.v1.ListAccountsResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ListAssetsRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.asset.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.api.v1.ListAssetsRequest", len)?;
+        if !self.asset.is_empty() {
+            struct_ser.serialize_field("asset", &self.asset)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ListAssetsRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "asset",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Asset,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "asset" => Ok(GeneratedField::Asset),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ListAssetsRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.api.v1.ListAssetsRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ListAssetsRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut asset__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Asset => {
+                            if asset__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("asset"));
+                            }
+                            asset__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(ListAssetsRequest {
+                    asset: asset__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.api.v1.ListAssetsRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ListAssetsResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.assets.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.api.v1.ListAssetsResponse", len)?;
+        if !self.assets.is_empty() {
+            struct_ser.serialize_field("assets", &self.assets)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ListAssetsResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "assets",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Assets,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "assets" => Ok(GeneratedField::Assets),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ListAssetsResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.api.v1.ListAssetsResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ListAssetsResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut assets__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Assets => {
+                            if assets__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("assets"));
+                            }
+                            assets__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(ListAssetsResponse {
+                    assets: assets__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.api.v1.ListAssetsResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ListDelegationsRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.party.is_empty() {
+            len += 1;
+        }
+        if !self.node.is_empty() {
+            len += 1;
+        }
+        if !self.epoch_seq.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.api.v1.ListDelegationsRequest", len)?;
+        if !self.party.is_empty() {
+            struct_ser.serialize_field("party", &self.party)?;
+        }
+        if !self.node.is_empty() {
+            struct_ser.serialize_field("node", &self.node)?;
+        }
+        if !self.epoch_seq.is_empty() {
+            struct_ser.serialize_field("epochSeq", &self.epoch_seq)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ListDelegationsRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "party",
+            "node",
+            "epoch_seq",
+            "epochSeq",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Party,
+            Node,
+            EpochSeq,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "party" => Ok(GeneratedField::Party),
+                            "node" => Ok(GeneratedField::Node),
+                            "epochSeq" | "epoch_seq" => Ok(GeneratedField::EpochSeq),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ListDelegationsRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.api.v1.ListDelegationsRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ListDelegationsRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut party__ = None;
+                let mut node__ = None;
+                let mut epoch_seq__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Party => {
+                            if party__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("party"));
+                            }
+                            party__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Node => {
+                            if node__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("node"));
+                            }
+                            node__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::EpochSeq => {
+                            if epoch_seq__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epochSeq"));
+                            }
+                            epoch_seq__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(ListDelegationsRequest {
+                    party: party__.unwrap_or_default(),
+                    node: node__.unwrap_or_default(),
+                    epoch_seq: epoch_seq__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.api.v1.ListDelegationsRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ListDelegationsResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.delegations.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.api.v1.ListDelegationsResponse", len)?;
+        if !self.delegations.is_empty() {
+            struct_ser.serialize_field("delegations", &self.delegations)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ListDelegationsResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "delegations",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Delegations,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "delegations" => Ok(GeneratedField::Delegations),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ListDelegationsResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.api.v1.ListDelegationsResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ListDelegationsResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut delegations__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Delegations => {
+                            if delegations__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("delegations"));
+                            }
+                            delegations__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(ListDelegationsResponse {
+                    delegations: delegations__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.api.v1.ListDelegationsResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ListMarketsDataRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.market.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.api.v1.ListMarketsDataRequest", len)?;
+        if !self.market.is_empty() {
+            struct_ser.serialize_field("market", &self.market)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ListMarketsDataRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "market",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Market,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "market" => Ok(GeneratedField::Market),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ListMarketsDataRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.api.v1.ListMarketsDataRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ListMarketsDataRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut market__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Market => {
+                            if market__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("market"));
+                            }
+                            market__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(ListMarketsDataRequest {
+                    market: market__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.api.v1.ListMarketsDataRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ListMarketsDataResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.markets_data.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.api.v1.ListMarketsDataResponse", len)?;
+        if !self.markets_data.is_empty() {
+            struct_ser.serialize_field("marketsData", &self.markets_data)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ListMarketsDataResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "markets_data",
+            "marketsData",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            MarketsData,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "marketsData" | "markets_data" => Ok(GeneratedField::MarketsData),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ListMarketsDataResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.api.v1.ListMarketsDataResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ListMarketsDataResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut markets_data__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::MarketsData => {
+                            if markets_data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("marketsData"));
+                            }
+                            markets_data__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(ListMarketsDataResponse {
+                    markets_data: markets_data__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.api.v1.ListMarketsDataResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ListMarketsRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.market.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.api.v1.ListMarketsRequest", len)?;
+        if !self.market.is_empty() {
+            struct_ser.serialize_field("market", &self.market)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ListMarketsRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "market",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Market,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "market" => Ok(GeneratedField::Market),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ListMarketsRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.api.v1.ListMarketsRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ListMarketsRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut market__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Market => {
+                            if market__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("market"));
+                            }
+                            market__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(ListMarketsRequest {
+                    market: market__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.api.v1.ListMarketsRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ListMarketsResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.markets.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.api.v1.ListMarketsResponse", len)?;
+        if !self.markets.is_empty() {
+            struct_ser.serialize_field("markets", &self.markets)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ListMarketsResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "markets",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Markets,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "markets" => Ok(GeneratedField::Markets),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ListMarketsResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.api.v1.ListMarketsResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ListMarketsResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut markets__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Markets => {
+                            if markets__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("markets"));
+                            }
+                            markets__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(ListMarketsResponse {
+                    markets: markets__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.api.v1.ListMarketsResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ListNetworkLimitsRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("vega.api.v1.ListNetworkLimitsRequest", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ListNetworkLimitsRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                            Err(serde::de::Error::unknown_field(value, FIELDS))
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ListNetworkLimitsRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.api.v1.ListNetworkLimitsRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ListNetworkLimitsRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(ListNetworkLimitsRequest {
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.api.v1.ListNetworkLimitsRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ListNetworkLimitsResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.network_limits.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.api.v1.ListNetworkLimitsResponse", len)?;
+        if let Some(v) = self.network_limits.as_ref() {
+            struct_ser.serialize_field("networkLimits", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ListNetworkLimitsResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "network_limits",
+            "networkLimits",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            NetworkLimits,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "networkLimits" | "network_limits" => Ok(GeneratedField::NetworkLimits),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ListNetworkLimitsResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.api.v1.ListNetworkLimitsResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ListNetworkLimitsResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut network_limits__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::NetworkLimits => {
+                            if network_limits__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("networkLimits"));
+                            }
+                            network_limits__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(ListNetworkLimitsResponse {
+                    network_limits: network_limits__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.api.v1.ListNetworkLimitsResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ListNetworkParametersRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.network_parameter_key.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.api.v1.ListNetworkParametersRequest", len)?;
+        if !self.network_parameter_key.is_empty() {
+            struct_ser.serialize_field("networkParameterKey", &self.network_parameter_key)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ListNetworkParametersRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "network_parameter_key",
+            "networkParameterKey",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            NetworkParameterKey,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "networkParameterKey" | "network_parameter_key" => Ok(GeneratedField::NetworkParameterKey),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ListNetworkParametersRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.api.v1.ListNetworkParametersRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ListNetworkParametersRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut network_parameter_key__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::NetworkParameterKey => {
+                            if network_parameter_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("networkParameterKey"));
+                            }
+                            network_parameter_key__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(ListNetworkParametersRequest {
+                    network_parameter_key: network_parameter_key__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.api.v1.ListNetworkParametersRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ListNetworkParametersResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.network_parameters.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.api.v1.ListNetworkParametersResponse", len)?;
+        if !self.network_parameters.is_empty() {
+            struct_ser.serialize_field("networkParameters", &self.network_parameters)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ListNetworkParametersResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "network_parameters",
+            "networkParameters",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            NetworkParameters,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "networkParameters" | "network_parameters" => Ok(GeneratedField::NetworkParameters),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ListNetworkParametersResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.api.v1.ListNetworkParametersResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ListNetworkParametersResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut network_parameters__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::NetworkParameters => {
+                            if network_parameters__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("networkParameters"));
+                            }
+                            network_parameters__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(ListNetworkParametersResponse {
+                    network_parameters: network_parameters__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.api.v1.ListNetworkParametersResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ListPartiesRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("vega.api.v1.ListPartiesRequest", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ListPartiesRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                            Err(serde::de::Error::unknown_field(value, FIELDS))
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ListPartiesRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.api.v1.ListPartiesRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ListPartiesRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(ListPartiesRequest {
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.api.v1.ListPartiesRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ListPartiesResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.parties.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.api.v1.ListPartiesResponse", len)?;
+        if !self.parties.is_empty() {
+            struct_ser.serialize_field("parties", &self.parties)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ListPartiesResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "parties",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Parties,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "parties" => Ok(GeneratedField::Parties),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ListPartiesResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.api.v1.ListPartiesResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ListPartiesResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut parties__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Parties => {
+                            if parties__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("parties"));
+                            }
+                            parties__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(ListPartiesResponse {
+                    parties: parties__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.api.v1.ListPartiesResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ListPartiesStakeRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.party.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.api.v1.ListPartiesStakeRequest", len)?;
+        if !self.party.is_empty() {
+            struct_ser.serialize_field("party", &self.party)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ListPartiesStakeRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "party",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Party,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "party" => Ok(GeneratedField::Party),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ListPartiesStakeRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.api.v1.ListPartiesStakeRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ListPartiesStakeRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut party__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Party => {
+                            if party__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("party"));
+                            }
+                            party__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(ListPartiesStakeRequest {
+                    party: party__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.api.v1.ListPartiesStakeRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ListPartiesStakeResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.parties_stake.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.api.v1.ListPartiesStakeResponse", len)?;
+        if !self.parties_stake.is_empty() {
+            struct_ser.serialize_field("partiesStake", &self.parties_stake)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ListPartiesStakeResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "parties_stake",
+            "partiesStake",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PartiesStake,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "partiesStake" | "parties_stake" => Ok(GeneratedField::PartiesStake),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ListPartiesStakeResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.api.v1.ListPartiesStakeResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ListPartiesStakeResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut parties_stake__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PartiesStake => {
+                            if parties_stake__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("partiesStake"));
+                            }
+                            parties_stake__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(ListPartiesStakeResponse {
+                    parties_stake: parties_stake__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.api.v1.ListPartiesStakeResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ListProposalsRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.proposal.is_empty() {
+            len += 1;
+        }
+        if !self.proposer.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.api.v1.ListProposalsRequest", len)?;
+        if !self.proposal.is_empty() {
+            struct_ser.serialize_field("proposal", &self.proposal)?;
+        }
+        if !self.proposer.is_empty() {
+            struct_ser.serialize_field("proposer", &self.proposer)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ListProposalsRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "proposal",
+            "proposer",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Proposal,
+            Proposer,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "proposal" => Ok(GeneratedField::Proposal),
+                            "proposer" => Ok(GeneratedField::Proposer),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ListProposalsRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.api.v1.ListProposalsRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ListProposalsRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut proposal__ = None;
+                let mut proposer__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Proposal => {
+                            if proposal__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposal"));
+                            }
+                            proposal__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Proposer => {
+                            if proposer__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposer"));
+                            }
+                            proposer__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(ListProposalsRequest {
+                    proposal: proposal__.unwrap_or_default(),
+                    proposer: proposer__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.api.v1.ListProposalsRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ListProposalsResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.proposals.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.api.v1.ListProposalsResponse", len)?;
+        if !self.proposals.is_empty() {
+            struct_ser.serialize_field("proposals", &self.proposals)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ListProposalsResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "proposals",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Proposals,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "proposals" => Ok(GeneratedField::Proposals),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ListProposalsResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.api.v1.ListProposalsResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ListProposalsResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut proposals__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Proposals => {
+                            if proposals__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposals"));
+                            }
+                            proposals__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(ListProposalsResponse {
+                    proposals: proposals__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.api.v1.ListProposalsResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ListValidatorsRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("vega.api.v1.ListValidatorsRequest", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ListValidatorsRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                            Err(serde::de::Error::unknown_field(value, FIELDS))
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ListValidatorsRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.api.v1.ListValidatorsRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ListValidatorsRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(ListValidatorsRequest {
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.api.v1.ListValidatorsRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ListValidatorsResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.validators.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.api.v1.ListValidatorsResponse", len)?;
+        if !self.validators.is_empty() {
+            struct_ser.serialize_field("validators", &self.validators)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ListValidatorsResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "validators",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Validators,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "validators" => Ok(GeneratedField::Validators),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ListValidatorsResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.api.v1.ListValidatorsResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ListValidatorsResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut validators__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Validators => {
+                            if validators__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("validators"));
+                            }
+                            validators__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(ListValidatorsResponse {
+                    validators: validators__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.api.v1.ListValidatorsResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ListVotesRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.proposal.is_empty() {
+            len += 1;
+        }
+        if !self.party.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.api.v1.ListVotesRequest", len)?;
+        if !self.proposal.is_empty() {
+            struct_ser.serialize_field("proposal", &self.proposal)?;
+        }
+        if !self.party.is_empty() {
+            struct_ser.serialize_field("party", &self.party)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ListVotesRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "proposal",
+            "party",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Proposal,
+            Party,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "proposal" => Ok(GeneratedField::Proposal),
+                            "party" => Ok(GeneratedField::Party),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ListVotesRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.api.v1.ListVotesRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ListVotesRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut proposal__ = None;
+                let mut party__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Proposal => {
+                            if proposal__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposal"));
+                            }
+                            proposal__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Party => {
+                            if party__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("party"));
+                            }
+                            party__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(ListVotesRequest {
+                    proposal: proposal__.unwrap_or_default(),
+                    party: party__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.api.v1.ListVotesRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ListVotesResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.votes.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.api.v1.ListVotesResponse", len)?;
+        if !self.votes.is_empty() {
+            struct_ser.serialize_field("votes", &self.votes)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ListVotesResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "votes",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Votes,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "votes" => Ok(GeneratedField::Votes),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ListVotesResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.api.v1.ListVotesResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ListVotesResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut votes__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Votes => {
+                            if votes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("votes"));
+                            }
+                            votes__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(ListVotesResponse {
+                    votes: votes__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.api.v1.ListVotesResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ObserveEventBusRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.r#type.is_empty() {
+            len += 1;
+        }
+        if !self.market_id.is_empty() {
+            len += 1;
+        }
+        if !self.party_id.is_empty() {
+            len += 1;
+        }
+        if self.batch_size != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.api.v1.ObserveEventBusRequest", len)?;
+        if !self.r#type.is_empty() {
+            let v = self.r#type.iter().cloned().map(|v| {
+                super::super::events::v1::BusEventType::try_from(v)
+                    .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", v)))
+                }).collect::<Result<Vec<_>, _>>()?;
+            struct_ser.serialize_field("type", &v)?;
+        }
+        if !self.market_id.is_empty() {
+            struct_ser.serialize_field("marketId", &self.market_id)?;
+        }
+        if !self.party_id.is_empty() {
+            struct_ser.serialize_field("partyId", &self.party_id)?;
+        }
+        if self.batch_size != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("batchSize", ToString::to_string(&self.batch_size).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ObserveEventBusRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "type",
+            "market_id",
+            "marketId",
+            "party_id",
+            "partyId",
+            "batch_size",
+            "batchSize",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Type,
+            MarketId,
+            PartyId,
+            BatchSize,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "type" => Ok(GeneratedField::Type),
+                            "marketId" | "market_id" => Ok(GeneratedField::MarketId),
+                            "partyId" | "party_id" => Ok(GeneratedField::PartyId),
+                            "batchSize" | "batch_size" => Ok(GeneratedField::BatchSize),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ObserveEventBusRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.api.v1.ObserveEventBusRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ObserveEventBusRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut r#type__ = None;
+                let mut market_id__ = None;
+                let mut party_id__ = None;
+                let mut batch_size__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Type => {
+                            if r#type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("type"));
+                            }
+                            r#type__ = Some(map_.next_value::<Vec<super::super::events::v1::BusEventType>>()?.into_iter().map(|x| x as i32).collect());
+                        }
+                        GeneratedField::MarketId => {
+                            if market_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("marketId"));
+                            }
+                            market_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::PartyId => {
+                            if party_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("partyId"));
+                            }
+                            party_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::BatchSize => {
+                            if batch_size__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("batchSize"));
+                            }
+                            batch_size__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(ObserveEventBusRequest {
+                    r#type: r#type__.unwrap_or_default(),
+                    market_id: market_id__.unwrap_or_default(),
+                    party_id: party_id__.unwrap_or_default(),
+                    batch_size: batch_size__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.api.v1.ObserveEventBusRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ObserveEventBusResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.events.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.api.v1.ObserveEventBusResponse", len)?;
+        if !self.events.is_empty() {
+            struct_ser.serialize_field("events", &self.events)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ObserveEventBusResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "events",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Events,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "events" => Ok(GeneratedField::Events),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ObserveEventBusResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.api.v1.ObserveEventBusResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ObserveEventBusResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut events__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Events => {
+                            if events__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("events"));
+                            }
+                            events__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(ObserveEventBusResponse {
+                    events: events__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.api.v1.ObserveEventBusResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PartyStake {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.party.is_empty() {
+            len += 1;
+        }
+        if !self.current_stake_available.is_empty() {
+            len += 1;
+        }
+        if !self.stake_linkings.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.api.v1.PartyStake", len)?;
+        if !self.party.is_empty() {
+            struct_ser.serialize_field("party", &self.party)?;
+        }
+        if !self.current_stake_available.is_empty() {
+            struct_ser.serialize_field("currentStakeAvailable", &self.current_stake_available)?;
+        }
+        if !self.stake_linkings.is_empty() {
+            struct_ser.serialize_field("stakeLinkings", &self.stake_linkings)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PartyStake {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "party",
+            "current_stake_available",
+            "currentStakeAvailable",
+            "stake_linkings",
+            "stakeLinkings",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Party,
+            CurrentStakeAvailable,
+            StakeLinkings,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "party" => Ok(GeneratedField::Party),
+                            "currentStakeAvailable" | "current_stake_available" => Ok(GeneratedField::CurrentStakeAvailable),
+                            "stakeLinkings" | "stake_linkings" => Ok(GeneratedField::StakeLinkings),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PartyStake;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.api.v1.PartyStake")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PartyStake, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut party__ = None;
+                let mut current_stake_available__ = None;
+                let mut stake_linkings__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Party => {
+                            if party__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("party"));
+                            }
+                            party__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::CurrentStakeAvailable => {
+                            if current_stake_available__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("currentStakeAvailable"));
+                            }
+                            current_stake_available__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::StakeLinkings => {
+                            if stake_linkings__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stakeLinkings"));
+                            }
+                            stake_linkings__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(PartyStake {
+                    party: party__.unwrap_or_default(),
+                    current_stake_available: current_stake_available__.unwrap_or_default(),
+                    stake_linkings: stake_linkings__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.api.v1.PartyStake", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PoWBlockState {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.block_height != 0 {
+            len += 1;
+        }
+        if !self.block_hash.is_empty() {
+            len += 1;
+        }
+        if self.transactions_seen != 0 {
+            len += 1;
+        }
+        if self.expected_difficulty.is_some() {
+            len += 1;
+        }
+        if !self.hash_function.is_empty() {
+            len += 1;
+        }
+        if self.difficulty != 0 {
+            len += 1;
+        }
+        if self.tx_per_block != 0 {
+            len += 1;
+        }
+        if self.increasing_difficulty {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.api.v1.PoWBlockState", len)?;
+        if self.block_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("blockHeight", ToString::to_string(&self.block_height).as_str())?;
+        }
+        if !self.block_hash.is_empty() {
+            struct_ser.serialize_field("blockHash", &self.block_hash)?;
+        }
+        if self.transactions_seen != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("transactionsSeen", ToString::to_string(&self.transactions_seen).as_str())?;
+        }
+        if let Some(v) = self.expected_difficulty.as_ref() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("expectedDifficulty", ToString::to_string(&v).as_str())?;
+        }
+        if !self.hash_function.is_empty() {
+            struct_ser.serialize_field("hashFunction", &self.hash_function)?;
+        }
+        if self.difficulty != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("difficulty", ToString::to_string(&self.difficulty).as_str())?;
+        }
+        if self.tx_per_block != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("txPerBlock", ToString::to_string(&self.tx_per_block).as_str())?;
+        }
+        if self.increasing_difficulty {
+            struct_ser.serialize_field("increasingDifficulty", &self.increasing_difficulty)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PoWBlockState {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "block_height",
+            "blockHeight",
+            "block_hash",
+            "blockHash",
+            "transactions_seen",
+            "transactionsSeen",
+            "expected_difficulty",
+            "expectedDifficulty",
+            "hash_function",
+            "hashFunction",
+            "difficulty",
+            "tx_per_block",
+            "txPerBlock",
+            "increasing_difficulty",
+            "increasingDifficulty",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            BlockHeight,
+            BlockHash,
+            TransactionsSeen,
+            ExpectedDifficulty,
+            HashFunction,
+            Difficulty,
+            TxPerBlock,
+            IncreasingDifficulty,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "blockHeight" | "block_height" => Ok(GeneratedField::BlockHeight),
+                            "blockHash" | "block_hash" => Ok(GeneratedField::BlockHash),
+                            "transactionsSeen" | "transactions_seen" => Ok(GeneratedField::TransactionsSeen),
+                            "expectedDifficulty" | "expected_difficulty" => Ok(GeneratedField::ExpectedDifficulty),
+                            "hashFunction" | "hash_function" => Ok(GeneratedField::HashFunction),
+                            "difficulty" => Ok(GeneratedField::Difficulty),
+                            "txPerBlock" | "tx_per_block" => Ok(GeneratedField::TxPerBlock),
+                            "increasingDifficulty" | "increasing_difficulty" => Ok(GeneratedField::IncreasingDifficulty),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PoWBlockState;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.api.v1.PoWBlockState")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PoWBlockState, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut block_height__ = None;
+                let mut block_hash__ = None;
+                let mut transactions_seen__ = None;
+                let mut expected_difficulty__ = None;
+                let mut hash_function__ = None;
+                let mut difficulty__ = None;
+                let mut tx_per_block__ = None;
+                let mut increasing_difficulty__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::BlockHeight => {
+                            if block_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("blockHeight"));
+                            }
+                            block_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::BlockHash => {
+                            if block_hash__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("blockHash"));
+                            }
+                            block_hash__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::TransactionsSeen => {
+                            if transactions_seen__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("transactionsSeen"));
+                            }
+                            transactions_seen__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ExpectedDifficulty => {
+                            if expected_difficulty__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("expectedDifficulty"));
+                            }
+                            expected_difficulty__ = 
+                                map_.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                        GeneratedField::HashFunction => {
+                            if hash_function__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("hashFunction"));
+                            }
+                            hash_function__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Difficulty => {
+                            if difficulty__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("difficulty"));
+                            }
+                            difficulty__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::TxPerBlock => {
+                            if tx_per_block__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("txPerBlock"));
+                            }
+                            tx_per_block__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::IncreasingDifficulty => {
+                            if increasing_difficulty__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("increasingDifficulty"));
+                            }
+                            increasing_difficulty__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(PoWBlockState {
+                    block_height: block_height__.unwrap_or_default(),
+                    block_hash: block_hash__.unwrap_or_default(),
+                    transactions_seen: transactions_seen__.unwrap_or_default(),
+                    expected_difficulty: expected_difficulty__,
+                    hash_function: hash_function__.unwrap_or_default(),
+                    difficulty: difficulty__.unwrap_or_default(),
+                    tx_per_block: tx_per_block__.unwrap_or_default(),
+                    increasing_difficulty: increasing_difficulty__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.api.v1.PoWBlockState", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PoWStatistic {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.block_states.is_empty() {
+            len += 1;
+        }
+        if self.banned_until.is_some() {
+            len += 1;
+        }
+        if self.number_of_past_blocks != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.api.v1.PoWStatistic", len)?;
+        if !self.block_states.is_empty() {
+            struct_ser.serialize_field("blockStates", &self.block_states)?;
+        }
+        if let Some(v) = self.banned_until.as_ref() {
+            struct_ser.serialize_field("bannedUntil", v)?;
+        }
+        if self.number_of_past_blocks != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("numberOfPastBlocks", ToString::to_string(&self.number_of_past_blocks).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PoWStatistic {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "block_states",
+            "blockStates",
+            "banned_until",
+            "bannedUntil",
+            "number_of_past_blocks",
+            "numberOfPastBlocks",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            BlockStates,
+            BannedUntil,
+            NumberOfPastBlocks,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "blockStates" | "block_states" => Ok(GeneratedField::BlockStates),
+                            "bannedUntil" | "banned_until" => Ok(GeneratedField::BannedUntil),
+                            "numberOfPastBlocks" | "number_of_past_blocks" => Ok(GeneratedField::NumberOfPastBlocks),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PoWStatistic;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.api.v1.PoWStatistic")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PoWStatistic, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut block_states__ = None;
+                let mut banned_until__ = None;
+                let mut number_of_past_blocks__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::BlockStates => {
+                            if block_states__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("blockStates"));
+                            }
+                            block_states__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::BannedUntil => {
+                            if banned_until__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("bannedUntil"));
+                            }
+                            banned_until__ = map_.next_value()?;
+                        }
+                        GeneratedField::NumberOfPastBlocks => {
+                            if number_of_past_blocks__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("numberOfPastBlocks"));
+                            }
+                            number_of_past_blocks__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(PoWStatistic {
+                    block_states: block_states__.unwrap_or_default(),
+                    banned_until: banned_until__,
+                    number_of_past_blocks: number_of_past_blocks__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.api.v1.PoWStatistic", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PropagateChainEventRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.event.is_empty() {
+            len += 1;
+        }
+        if !self.pub_key.is_empty() {
+            len += 1;
+        }
+        if !self.signature.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.api.v1.PropagateChainEventRequest", len)?;
+        if !self.event.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("event", pbjson::private::base64::encode(&self.event).as_str())?;
+        }
+        if !self.pub_key.is_empty() {
+            struct_ser.serialize_field("pubKey", &self.pub_key)?;
+        }
+        if !self.signature.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("signature", pbjson::private::base64::encode(&self.signature).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PropagateChainEventRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "event",
+            "pub_key",
+            "pubKey",
+            "signature",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Event,
+            PubKey,
+            Signature,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "event" => Ok(GeneratedField::Event),
+                            "pubKey" | "pub_key" => Ok(GeneratedField::PubKey),
+                            "signature" => Ok(GeneratedField::Signature),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PropagateChainEventRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.api.v1.PropagateChainEventRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PropagateChainEventRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut event__ = None;
+                let mut pub_key__ = None;
+                let mut signature__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Event => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("event"));
+                            }
+                            event__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::PubKey => {
+                            if pub_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pubKey"));
+                            }
+                            pub_key__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Signature => {
+                            if signature__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("signature"));
+                            }
+                            signature__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(PropagateChainEventRequest {
+                    event: event__.unwrap_or_default(),
+                    pub_key: pub_key__.unwrap_or_default(),
+                    signature: signature__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.api.v1.PropagateChainEventRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PropagateChainEventResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.success {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.api.v1.PropagateChainEventResponse", len)?;
+        if self.success {
+            struct_ser.serialize_field("success", &self.success)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PropagateChainEventResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "success",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Success,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "success" => Ok(GeneratedField::Success),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PropagateChainEventResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.api.v1.PropagateChainEventResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PropagateChainEventResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut success__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Success => {
+                            if success__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("success"));
+                            }
+                            success__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(PropagateChainEventResponse {
+                    success: success__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.api.v1.PropagateChainEventResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SpamStatistic {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.count_for_epoch != 0 {
+            len += 1;
+        }
+        if self.max_for_epoch != 0 {
+            len += 1;
+        }
+        if self.banned_until.is_some() {
+            len += 1;
+        }
+        if !self.min_tokens_required.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.api.v1.SpamStatistic", len)?;
+        if self.count_for_epoch != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("countForEpoch", ToString::to_string(&self.count_for_epoch).as_str())?;
+        }
+        if self.max_for_epoch != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("maxForEpoch", ToString::to_string(&self.max_for_epoch).as_str())?;
+        }
+        if let Some(v) = self.banned_until.as_ref() {
+            struct_ser.serialize_field("bannedUntil", v)?;
+        }
+        if !self.min_tokens_required.is_empty() {
+            struct_ser.serialize_field("minTokensRequired", &self.min_tokens_required)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SpamStatistic {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "count_for_epoch",
+            "countForEpoch",
+            "max_for_epoch",
+            "maxForEpoch",
+            "banned_until",
+            "bannedUntil",
+            "min_tokens_required",
+            "minTokensRequired",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            CountForEpoch,
+            MaxForEpoch,
+            BannedUntil,
+            MinTokensRequired,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "countForEpoch" | "count_for_epoch" => Ok(GeneratedField::CountForEpoch),
+                            "maxForEpoch" | "max_for_epoch" => Ok(GeneratedField::MaxForEpoch),
+                            "bannedUntil" | "banned_until" => Ok(GeneratedField::BannedUntil),
+                            "minTokensRequired" | "min_tokens_required" => Ok(GeneratedField::MinTokensRequired),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SpamStatistic;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.api.v1.SpamStatistic")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SpamStatistic, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut count_for_epoch__ = None;
+                let mut max_for_epoch__ = None;
+                let mut banned_until__ = None;
+                let mut min_tokens_required__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::CountForEpoch => {
+                            if count_for_epoch__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("countForEpoch"));
+                            }
+                            count_for_epoch__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::MaxForEpoch => {
+                            if max_for_epoch__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxForEpoch"));
+                            }
+                            max_for_epoch__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::BannedUntil => {
+                            if banned_until__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("bannedUntil"));
+                            }
+                            banned_until__ = map_.next_value()?;
+                        }
+                        GeneratedField::MinTokensRequired => {
+                            if min_tokens_required__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("minTokensRequired"));
+                            }
+                            min_tokens_required__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(SpamStatistic {
+                    count_for_epoch: count_for_epoch__.unwrap_or_default(),
+                    max_for_epoch: max_for_epoch__.unwrap_or_default(),
+                    banned_until: banned_until__,
+                    min_tokens_required: min_tokens_required__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.api.v1.SpamStatistic", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SpamStatistics {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.proposals.is_some() {
+            len += 1;
+        }
+        if self.delegations.is_some() {
+            len += 1;
+        }
+        if self.transfers.is_some() {
+            len += 1;
+        }
+        if self.node_announcements.is_some() {
+            len += 1;
+        }
+        if self.votes.is_some() {
+            len += 1;
+        }
+        if self.pow.is_some() {
+            len += 1;
+        }
+        if self.issue_signatures.is_some() {
+            len += 1;
+        }
+        if self.epoch_seq != 0 {
+            len += 1;
+        }
+        if self.create_referral_set.is_some() {
+            len += 1;
+        }
+        if self.update_referral_set.is_some() {
+            len += 1;
+        }
+        if self.apply_referral_code.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.api.v1.SpamStatistics", len)?;
+        if let Some(v) = self.proposals.as_ref() {
+            struct_ser.serialize_field("proposals", v)?;
+        }
+        if let Some(v) = self.delegations.as_ref() {
+            struct_ser.serialize_field("delegations", v)?;
+        }
+        if let Some(v) = self.transfers.as_ref() {
+            struct_ser.serialize_field("transfers", v)?;
+        }
+        if let Some(v) = self.node_announcements.as_ref() {
+            struct_ser.serialize_field("nodeAnnouncements", v)?;
+        }
+        if let Some(v) = self.votes.as_ref() {
+            struct_ser.serialize_field("votes", v)?;
+        }
+        if let Some(v) = self.pow.as_ref() {
+            struct_ser.serialize_field("pow", v)?;
+        }
+        if let Some(v) = self.issue_signatures.as_ref() {
+            struct_ser.serialize_field("issueSignatures", v)?;
+        }
+        if self.epoch_seq != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("epochSeq", ToString::to_string(&self.epoch_seq).as_str())?;
+        }
+        if let Some(v) = self.create_referral_set.as_ref() {
+            struct_ser.serialize_field("createReferralSet", v)?;
+        }
+        if let Some(v) = self.update_referral_set.as_ref() {
+            struct_ser.serialize_field("updateReferralSet", v)?;
+        }
+        if let Some(v) = self.apply_referral_code.as_ref() {
+            struct_ser.serialize_field("applyReferralCode", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SpamStatistics {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "proposals",
+            "delegations",
+            "transfers",
+            "node_announcements",
+            "nodeAnnouncements",
+            "votes",
+            "pow",
+            "issue_signatures",
+            "issueSignatures",
+            "epoch_seq",
+            "epochSeq",
+            "create_referral_set",
+            "createReferralSet",
+            "update_referral_set",
+            "updateReferralSet",
+            "apply_referral_code",
+            "applyReferralCode",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Proposals,
+            Delegations,
+            Transfers,
+            NodeAnnouncements,
+            Votes,
+            Pow,
+            IssueSignatures,
+            EpochSeq,
+            CreateReferralSet,
+            UpdateReferralSet,
+            ApplyReferralCode,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "proposals" => Ok(GeneratedField::Proposals),
+                            "delegations" => Ok(GeneratedField::Delegations),
+                            "transfers" => Ok(GeneratedField::Transfers),
+                            "nodeAnnouncements" | "node_announcements" => Ok(GeneratedField::NodeAnnouncements),
+                            "votes" => Ok(GeneratedField::Votes),
+                            "pow" => Ok(GeneratedField::Pow),
+                            "issueSignatures" | "issue_signatures" => Ok(GeneratedField::IssueSignatures),
+                            "epochSeq" | "epoch_seq" => Ok(GeneratedField::EpochSeq),
+                            "createReferralSet" | "create_referral_set" => Ok(GeneratedField::CreateReferralSet),
+                            "updateReferralSet" | "update_referral_set" => Ok(GeneratedField::UpdateReferralSet),
+                            "applyReferralCode" | "apply_referral_code" => Ok(GeneratedField::ApplyReferralCode),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SpamStatistics;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.api.v1.SpamStatistics")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SpamStatistics, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut proposals__ = None;
+                let mut delegations__ = None;
+                let mut transfers__ = None;
+                let mut node_announcements__ = None;
+                let mut votes__ = None;
+                let mut pow__ = None;
+                let mut issue_signatures__ = None;
+                let mut epoch_seq__ = None;
+                let mut create_referral_set__ = None;
+                let mut update_referral_set__ = None;
+                let mut apply_referral_code__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Proposals => {
+                            if proposals__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposals"));
+                            }
+                            proposals__ = map_.next_value()?;
+                        }
+                        GeneratedField::Delegations => {
+                            if delegations__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("delegations"));
+                            }
+                            delegations__ = map_.next_value()?;
+                        }
+                        GeneratedField::Transfers => {
+                            if transfers__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("transfers"));
+                            }
+                            transfers__ = map_.next_value()?;
+                        }
+                        GeneratedField::NodeAnnouncements => {
+                            if node_announcements__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nodeAnnouncements"));
+                            }
+                            node_announcements__ = map_.next_value()?;
+                        }
+                        GeneratedField::Votes => {
+                            if votes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("votes"));
+                            }
+                            votes__ = map_.next_value()?;
+                        }
+                        GeneratedField::Pow => {
+                            if pow__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pow"));
+                            }
+                            pow__ = map_.next_value()?;
+                        }
+                        GeneratedField::IssueSignatures => {
+                            if issue_signatures__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("issueSignatures"));
+                            }
+                            issue_signatures__ = map_.next_value()?;
+                        }
+                        GeneratedField::EpochSeq => {
+                            if epoch_seq__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epochSeq"));
+                            }
+                            epoch_seq__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::CreateReferralSet => {
+                            if create_referral_set__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("createReferralSet"));
+                            }
+                            create_referral_set__ = map_.next_value()?;
+                        }
+                        GeneratedField::UpdateReferralSet => {
+                            if update_referral_set__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("updateReferralSet"));
+                            }
+                            update_referral_set__ = map_.next_value()?;
+                        }
+                        GeneratedField::ApplyReferralCode => {
+                            if apply_referral_code__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("applyReferralCode"));
+                            }
+                            apply_referral_code__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(SpamStatistics {
+                    proposals: proposals__,
+                    delegations: delegations__,
+                    transfers: transfers__,
+                    node_announcements: node_announcements__,
+                    votes: votes__,
+                    pow: pow__,
+                    issue_signatures: issue_signatures__,
+                    epoch_seq: epoch_seq__.unwrap_or_default(),
+                    create_referral_set: create_referral_set__,
+                    update_referral_set: update_referral_set__,
+                    apply_referral_code: apply_referral_code__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.api.v1.SpamStatistics", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Statistics {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.block_height != 0 {
+            len += 1;
+        }
+        if self.backlog_length != 0 {
+            len += 1;
+        }
+        if self.total_peers != 0 {
+            len += 1;
+        }
+        if !self.genesis_time.is_empty() {
+            len += 1;
+        }
+        if !self.current_time.is_empty() {
+            len += 1;
+        }
+        if !self.vega_time.is_empty() {
+            len += 1;
+        }
+        if self.status != 0 {
+            len += 1;
+        }
+        if self.tx_per_block != 0 {
+            len += 1;
+        }
+        if self.average_tx_bytes != 0 {
+            len += 1;
+        }
+        if self.average_orders_per_block != 0 {
+            len += 1;
+        }
+        if self.trades_per_second != 0 {
+            len += 1;
+        }
+        if self.orders_per_second != 0 {
+            len += 1;
+        }
+        if self.total_markets != 0 {
+            len += 1;
+        }
+        if self.total_amend_order != 0 {
+            len += 1;
+        }
+        if self.total_cancel_order != 0 {
+            len += 1;
+        }
+        if self.total_create_order != 0 {
+            len += 1;
+        }
+        if self.total_orders != 0 {
+            len += 1;
+        }
+        if self.total_trades != 0 {
+            len += 1;
+        }
+        if self.order_subscriptions != 0 {
+            len += 1;
+        }
+        if self.trade_subscriptions != 0 {
+            len += 1;
+        }
+        if self.candle_subscriptions != 0 {
+            len += 1;
+        }
+        if self.market_depth_subscriptions != 0 {
+            len += 1;
+        }
+        if self.positions_subscriptions != 0 {
+            len += 1;
+        }
+        if self.account_subscriptions != 0 {
+            len += 1;
+        }
+        if self.market_data_subscriptions != 0 {
+            len += 1;
+        }
+        if !self.app_version_hash.is_empty() {
+            len += 1;
+        }
+        if !self.app_version.is_empty() {
+            len += 1;
+        }
+        if !self.chain_version.is_empty() {
+            len += 1;
+        }
+        if self.block_duration != 0 {
+            len += 1;
+        }
+        if !self.uptime.is_empty() {
+            len += 1;
+        }
+        if !self.chain_id.is_empty() {
+            len += 1;
+        }
+        if self.market_depth_updates_subscriptions != 0 {
+            len += 1;
+        }
+        if !self.block_hash.is_empty() {
+            len += 1;
+        }
+        if self.epoch_seq != 0 {
+            len += 1;
+        }
+        if !self.epoch_start_time.is_empty() {
+            len += 1;
+        }
+        if !self.epoch_expiry_time.is_empty() {
+            len += 1;
+        }
+        if self.event_count != 0 {
+            len += 1;
+        }
+        if self.events_per_second != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.api.v1.Statistics", len)?;
+        if self.block_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("blockHeight", ToString::to_string(&self.block_height).as_str())?;
+        }
+        if self.backlog_length != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("backlogLength", ToString::to_string(&self.backlog_length).as_str())?;
+        }
+        if self.total_peers != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("totalPeers", ToString::to_string(&self.total_peers).as_str())?;
+        }
+        if !self.genesis_time.is_empty() {
+            struct_ser.serialize_field("genesisTime", &self.genesis_time)?;
+        }
+        if !self.current_time.is_empty() {
+            struct_ser.serialize_field("currentTime", &self.current_time)?;
+        }
+        if !self.vega_time.is_empty() {
+            struct_ser.serialize_field("vegaTime", &self.vega_time)?;
+        }
+        if self.status != 0 {
+            let v = super::super::ChainStatus::try_from(self.status)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.status)))?;
+            struct_ser.serialize_field("status", &v)?;
+        }
+        if self.tx_per_block != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("txPerBlock", ToString::to_string(&self.tx_per_block).as_str())?;
+        }
+        if self.average_tx_bytes != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("averageTxBytes", ToString::to_string(&self.average_tx_bytes).as_str())?;
+        }
+        if self.average_orders_per_block != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("averageOrdersPerBlock", ToString::to_string(&self.average_orders_per_block).as_str())?;
+        }
+        if self.trades_per_second != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("tradesPerSecond", ToString::to_string(&self.trades_per_second).as_str())?;
+        }
+        if self.orders_per_second != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("ordersPerSecond", ToString::to_string(&self.orders_per_second).as_str())?;
+        }
+        if self.total_markets != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("totalMarkets", ToString::to_string(&self.total_markets).as_str())?;
+        }
+        if self.total_amend_order != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("totalAmendOrder", ToString::to_string(&self.total_amend_order).as_str())?;
+        }
+        if self.total_cancel_order != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("totalCancelOrder", ToString::to_string(&self.total_cancel_order).as_str())?;
+        }
+        if self.total_create_order != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("totalCreateOrder", ToString::to_string(&self.total_create_order).as_str())?;
+        }
+        if self.total_orders != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("totalOrders", ToString::to_string(&self.total_orders).as_str())?;
+        }
+        if self.total_trades != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("totalTrades", ToString::to_string(&self.total_trades).as_str())?;
+        }
+        if self.order_subscriptions != 0 {
+            struct_ser.serialize_field("orderSubscriptions", &self.order_subscriptions)?;
+        }
+        if self.trade_subscriptions != 0 {
+            struct_ser.serialize_field("tradeSubscriptions", &self.trade_subscriptions)?;
+        }
+        if self.candle_subscriptions != 0 {
+            struct_ser.serialize_field("candleSubscriptions", &self.candle_subscriptions)?;
+        }
+        if self.market_depth_subscriptions != 0 {
+            struct_ser.serialize_field("marketDepthSubscriptions", &self.market_depth_subscriptions)?;
+        }
+        if self.positions_subscriptions != 0 {
+            struct_ser.serialize_field("positionsSubscriptions", &self.positions_subscriptions)?;
+        }
+        if self.account_subscriptions != 0 {
+            struct_ser.serialize_field("accountSubscriptions", &self.account_subscriptions)?;
+        }
+        if self.market_data_subscriptions != 0 {
+            struct_ser.serialize_field("marketDataSubscriptions", &self.market_data_subscriptions)?;
+        }
+        if !self.app_version_hash.is_empty() {
+            struct_ser.serialize_field("appVersionHash", &self.app_version_hash)?;
+        }
+        if !self.app_version.is_empty() {
+            struct_ser.serialize_field("appVersion", &self.app_version)?;
+        }
+        if !self.chain_version.is_empty() {
+            struct_ser.serialize_field("chainVersion", &self.chain_version)?;
+        }
+        if self.block_duration != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("blockDuration", ToString::to_string(&self.block_duration).as_str())?;
+        }
+        if !self.uptime.is_empty() {
+            struct_ser.serialize_field("uptime", &self.uptime)?;
+        }
+        if !self.chain_id.is_empty() {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
+        }
+        if self.market_depth_updates_subscriptions != 0 {
+            struct_ser.serialize_field("marketDepthUpdatesSubscriptions", &self.market_depth_updates_subscriptions)?;
+        }
+        if !self.block_hash.is_empty() {
+            struct_ser.serialize_field("blockHash", &self.block_hash)?;
+        }
+        if self.epoch_seq != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("epochSeq", ToString::to_string(&self.epoch_seq).as_str())?;
+        }
+        if !self.epoch_start_time.is_empty() {
+            struct_ser.serialize_field("epochStartTime", &self.epoch_start_time)?;
+        }
+        if !self.epoch_expiry_time.is_empty() {
+            struct_ser.serialize_field("epochExpiryTime", &self.epoch_expiry_time)?;
+        }
+        if self.event_count != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("eventCount", ToString::to_string(&self.event_count).as_str())?;
+        }
+        if self.events_per_second != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("eventsPerSecond", ToString::to_string(&self.events_per_second).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Statistics {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "block_height",
+            "blockHeight",
+            "backlog_length",
+            "backlogLength",
+            "total_peers",
+            "totalPeers",
+            "genesis_time",
+            "genesisTime",
+            "current_time",
+            "currentTime",
+            "vega_time",
+            "vegaTime",
+            "status",
+            "tx_per_block",
+            "txPerBlock",
+            "average_tx_bytes",
+            "averageTxBytes",
+            "average_orders_per_block",
+            "averageOrdersPerBlock",
+            "trades_per_second",
+            "tradesPerSecond",
+            "orders_per_second",
+            "ordersPerSecond",
+            "total_markets",
+            "totalMarkets",
+            "total_amend_order",
+            "totalAmendOrder",
+            "total_cancel_order",
+            "totalCancelOrder",
+            "total_create_order",
+            "totalCreateOrder",
+            "total_orders",
+            "totalOrders",
+            "total_trades",
+            "totalTrades",
+            "order_subscriptions",
+            "orderSubscriptions",
+            "trade_subscriptions",
+            "tradeSubscriptions",
+            "candle_subscriptions",
+            "candleSubscriptions",
+            "market_depth_subscriptions",
+            "marketDepthSubscriptions",
+            "positions_subscriptions",
+            "positionsSubscriptions",
+            "account_subscriptions",
+            "accountSubscriptions",
+            "market_data_subscriptions",
+            "marketDataSubscriptions",
+            "app_version_hash",
+            "appVersionHash",
+            "app_version",
+            "appVersion",
+            "chain_version",
+            "chainVersion",
+            "block_duration",
+            "blockDuration",
+            "uptime",
+            "chain_id",
+            "chainId",
+            "market_depth_updates_subscriptions",
+            "marketDepthUpdatesSubscriptions",
+            "block_hash",
+            "blockHash",
+            "epoch_seq",
+            "epochSeq",
+            "epoch_start_time",
+            "epochStartTime",
+            "epoch_expiry_time",
+            "epochExpiryTime",
+            "event_count",
+            "eventCount",
+            "events_per_second",
+            "eventsPerSecond",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            BlockHeight,
+            BacklogLength,
+            TotalPeers,
+            GenesisTime,
+            CurrentTime,
+            VegaTime,
+            Status,
+            TxPerBlock,
+            AverageTxBytes,
+            AverageOrdersPerBlock,
+            TradesPerSecond,
+            OrdersPerSecond,
+            TotalMarkets,
+            TotalAmendOrder,
+            TotalCancelOrder,
+            TotalCreateOrder,
+            TotalOrders,
+            TotalTrades,
+            OrderSubscriptions,
+            TradeSubscriptions,
+            CandleSubscriptions,
+            MarketDepthSubscriptions,
+            PositionsSubscriptions,
+            AccountSubscriptions,
+            MarketDataSubscriptions,
+            AppVersionHash,
+            AppVersion,
+            ChainVersion,
+            BlockDuration,
+            Uptime,
+            ChainId,
+            MarketDepthUpdatesSubscriptions,
+            BlockHash,
+            EpochSeq,
+            EpochStartTime,
+            EpochExpiryTime,
+            EventCount,
+            EventsPerSecond,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "blockHeight" | "block_height" => Ok(GeneratedField::BlockHeight),
+                            "backlogLength" | "backlog_length" => Ok(GeneratedField::BacklogLength),
+                            "totalPeers" | "total_peers" => Ok(GeneratedField::TotalPeers),
+                            "genesisTime" | "genesis_time" => Ok(GeneratedField::GenesisTime),
+                            "currentTime" | "current_time" => Ok(GeneratedField::CurrentTime),
+                            "vegaTime" | "vega_time" => Ok(GeneratedField::VegaTime),
+                            "status" => Ok(GeneratedField::Status),
+                            "txPerBlock" | "tx_per_block" => Ok(GeneratedField::TxPerBlock),
+                            "averageTxBytes" | "average_tx_bytes" => Ok(GeneratedField::AverageTxBytes),
+                            "averageOrdersPerBlock" | "average_orders_per_block" => Ok(GeneratedField::AverageOrdersPerBlock),
+                            "tradesPerSecond" | "trades_per_second" => Ok(GeneratedField::TradesPerSecond),
+                            "ordersPerSecond" | "orders_per_second" => Ok(GeneratedField::OrdersPerSecond),
+                            "totalMarkets" | "total_markets" => Ok(GeneratedField::TotalMarkets),
+                            "totalAmendOrder" | "total_amend_order" => Ok(GeneratedField::TotalAmendOrder),
+                            "totalCancelOrder" | "total_cancel_order" => Ok(GeneratedField::TotalCancelOrder),
+                            "totalCreateOrder" | "total_create_order" => Ok(GeneratedField::TotalCreateOrder),
+                            "totalOrders" | "total_orders" => Ok(GeneratedField::TotalOrders),
+                            "totalTrades" | "total_trades" => Ok(GeneratedField::TotalTrades),
+                            "orderSubscriptions" | "order_subscriptions" => Ok(GeneratedField::OrderSubscriptions),
+                            "tradeSubscriptions" | "trade_subscriptions" => Ok(GeneratedField::TradeSubscriptions),
+                            "candleSubscriptions" | "candle_subscriptions" => Ok(GeneratedField::CandleSubscriptions),
+                            "marketDepthSubscriptions" | "market_depth_subscriptions" => Ok(GeneratedField::MarketDepthSubscriptions),
+                            "positionsSubscriptions" | "positions_subscriptions" => Ok(GeneratedField::PositionsSubscriptions),
+                            "accountSubscriptions" | "account_subscriptions" => Ok(GeneratedField::AccountSubscriptions),
+                            "marketDataSubscriptions" | "market_data_subscriptions" => Ok(GeneratedField::MarketDataSubscriptions),
+                            "appVersionHash" | "app_version_hash" => Ok(GeneratedField::AppVersionHash),
+                            "appVersion" | "app_version" => Ok(GeneratedField::AppVersion),
+                            "chainVersion" | "chain_version" => Ok(GeneratedField::ChainVersion),
+                            "blockDuration" | "block_duration" => Ok(GeneratedField::BlockDuration),
+                            "uptime" => Ok(GeneratedField::Uptime),
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            "marketDepthUpdatesSubscriptions" | "market_depth_updates_subscriptions" => Ok(GeneratedField::MarketDepthUpdatesSubscriptions),
+                            "blockHash" | "block_hash" => Ok(GeneratedField::BlockHash),
+                            "epochSeq" | "epoch_seq" => Ok(GeneratedField::EpochSeq),
+                            "epochStartTime" | "epoch_start_time" => Ok(GeneratedField::EpochStartTime),
+                            "epochExpiryTime" | "epoch_expiry_time" => Ok(GeneratedField::EpochExpiryTime),
+                            "eventCount" | "event_count" => Ok(GeneratedField::EventCount),
+                            "eventsPerSecond" | "events_per_second" => Ok(GeneratedField::EventsPerSecond),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Statistics;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.api.v1.Statistics")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Statistics, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut block_height__ = None;
+                let mut backlog_length__ = None;
+                let mut total_peers__ = None;
+                let mut genesis_time__ = None;
+                let mut current_time__ = None;
+                let mut vega_time__ = None;
+                let mut status__ = None;
+                let mut tx_per_block__ = None;
+                let mut average_tx_bytes__ = None;
+                let mut average_orders_per_block__ = None;
+                let mut trades_per_second__ = None;
+                let mut orders_per_second__ = None;
+                let mut total_markets__ = None;
+                let mut total_amend_order__ = None;
+                let mut total_cancel_order__ = None;
+                let mut total_create_order__ = None;
+                let mut total_orders__ = None;
+                let mut total_trades__ = None;
+                let mut order_subscriptions__ = None;
+                let mut trade_subscriptions__ = None;
+                let mut candle_subscriptions__ = None;
+                let mut market_depth_subscriptions__ = None;
+                let mut positions_subscriptions__ = None;
+                let mut account_subscriptions__ = None;
+                let mut market_data_subscriptions__ = None;
+                let mut app_version_hash__ = None;
+                let mut app_version__ = None;
+                let mut chain_version__ = None;
+                let mut block_duration__ = None;
+                let mut uptime__ = None;
+                let mut chain_id__ = None;
+                let mut market_depth_updates_subscriptions__ = None;
+                let mut block_hash__ = None;
+                let mut epoch_seq__ = None;
+                let mut epoch_start_time__ = None;
+                let mut epoch_expiry_time__ = None;
+                let mut event_count__ = None;
+                let mut events_per_second__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::BlockHeight => {
+                            if block_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("blockHeight"));
+                            }
+                            block_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::BacklogLength => {
+                            if backlog_length__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("backlogLength"));
+                            }
+                            backlog_length__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::TotalPeers => {
+                            if total_peers__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("totalPeers"));
+                            }
+                            total_peers__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::GenesisTime => {
+                            if genesis_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("genesisTime"));
+                            }
+                            genesis_time__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::CurrentTime => {
+                            if current_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("currentTime"));
+                            }
+                            current_time__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::VegaTime => {
+                            if vega_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("vegaTime"));
+                            }
+                            vega_time__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Status => {
+                            if status__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("status"));
+                            }
+                            status__ = Some(map_.next_value::<super::super::ChainStatus>()? as i32);
+                        }
+                        GeneratedField::TxPerBlock => {
+                            if tx_per_block__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("txPerBlock"));
+                            }
+                            tx_per_block__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::AverageTxBytes => {
+                            if average_tx_bytes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("averageTxBytes"));
+                            }
+                            average_tx_bytes__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::AverageOrdersPerBlock => {
+                            if average_orders_per_block__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("averageOrdersPerBlock"));
+                            }
+                            average_orders_per_block__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::TradesPerSecond => {
+                            if trades_per_second__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradesPerSecond"));
+                            }
+                            trades_per_second__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::OrdersPerSecond => {
+                            if orders_per_second__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ordersPerSecond"));
+                            }
+                            orders_per_second__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::TotalMarkets => {
+                            if total_markets__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("totalMarkets"));
+                            }
+                            total_markets__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::TotalAmendOrder => {
+                            if total_amend_order__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("totalAmendOrder"));
+                            }
+                            total_amend_order__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::TotalCancelOrder => {
+                            if total_cancel_order__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("totalCancelOrder"));
+                            }
+                            total_cancel_order__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::TotalCreateOrder => {
+                            if total_create_order__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("totalCreateOrder"));
+                            }
+                            total_create_order__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::TotalOrders => {
+                            if total_orders__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("totalOrders"));
+                            }
+                            total_orders__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::TotalTrades => {
+                            if total_trades__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("totalTrades"));
+                            }
+                            total_trades__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::OrderSubscriptions => {
+                            if order_subscriptions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("orderSubscriptions"));
+                            }
+                            order_subscriptions__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::TradeSubscriptions => {
+                            if trade_subscriptions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradeSubscriptions"));
+                            }
+                            trade_subscriptions__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::CandleSubscriptions => {
+                            if candle_subscriptions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("candleSubscriptions"));
+                            }
+                            candle_subscriptions__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::MarketDepthSubscriptions => {
+                            if market_depth_subscriptions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("marketDepthSubscriptions"));
+                            }
+                            market_depth_subscriptions__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::PositionsSubscriptions => {
+                            if positions_subscriptions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionsSubscriptions"));
+                            }
+                            positions_subscriptions__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::AccountSubscriptions => {
+                            if account_subscriptions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("accountSubscriptions"));
+                            }
+                            account_subscriptions__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::MarketDataSubscriptions => {
+                            if market_data_subscriptions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("marketDataSubscriptions"));
+                            }
+                            market_data_subscriptions__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::AppVersionHash => {
+                            if app_version_hash__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("appVersionHash"));
+                            }
+                            app_version_hash__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::AppVersion => {
+                            if app_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("appVersion"));
+                            }
+                            app_version__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ChainVersion => {
+                            if chain_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainVersion"));
+                            }
+                            chain_version__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::BlockDuration => {
+                            if block_duration__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("blockDuration"));
+                            }
+                            block_duration__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Uptime => {
+                            if uptime__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("uptime"));
+                            }
+                            uptime__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
+                            }
+                            chain_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::MarketDepthUpdatesSubscriptions => {
+                            if market_depth_updates_subscriptions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("marketDepthUpdatesSubscriptions"));
+                            }
+                            market_depth_updates_subscriptions__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::BlockHash => {
+                            if block_hash__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("blockHash"));
+                            }
+                            block_hash__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::EpochSeq => {
+                            if epoch_seq__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epochSeq"));
+                            }
+                            epoch_seq__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::EpochStartTime => {
+                            if epoch_start_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epochStartTime"));
+                            }
+                            epoch_start_time__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::EpochExpiryTime => {
+                            if epoch_expiry_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epochExpiryTime"));
+                            }
+                            epoch_expiry_time__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::EventCount => {
+                            if event_count__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("eventCount"));
+                            }
+                            event_count__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::EventsPerSecond => {
+                            if events_per_second__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("eventsPerSecond"));
+                            }
+                            events_per_second__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(Statistics {
+                    block_height: block_height__.unwrap_or_default(),
+                    backlog_length: backlog_length__.unwrap_or_default(),
+                    total_peers: total_peers__.unwrap_or_default(),
+                    genesis_time: genesis_time__.unwrap_or_default(),
+                    current_time: current_time__.unwrap_or_default(),
+                    vega_time: vega_time__.unwrap_or_default(),
+                    status: status__.unwrap_or_default(),
+                    tx_per_block: tx_per_block__.unwrap_or_default(),
+                    average_tx_bytes: average_tx_bytes__.unwrap_or_default(),
+                    average_orders_per_block: average_orders_per_block__.unwrap_or_default(),
+                    trades_per_second: trades_per_second__.unwrap_or_default(),
+                    orders_per_second: orders_per_second__.unwrap_or_default(),
+                    total_markets: total_markets__.unwrap_or_default(),
+                    total_amend_order: total_amend_order__.unwrap_or_default(),
+                    total_cancel_order: total_cancel_order__.unwrap_or_default(),
+                    total_create_order: total_create_order__.unwrap_or_default(),
+                    total_orders: total_orders__.unwrap_or_default(),
+                    total_trades: total_trades__.unwrap_or_default(),
+                    order_subscriptions: order_subscriptions__.unwrap_or_default(),
+                    trade_subscriptions: trade_subscriptions__.unwrap_or_default(),
+                    candle_subscriptions: candle_subscriptions__.unwrap_or_default(),
+                    market_depth_subscriptions: market_depth_subscriptions__.unwrap_or_default(),
+                    positions_subscriptions: positions_subscriptions__.unwrap_or_default(),
+                    account_subscriptions: account_subscriptions__.unwrap_or_default(),
+                    market_data_subscriptions: market_data_subscriptions__.unwrap_or_default(),
+                    app_version_hash: app_version_hash__.unwrap_or_default(),
+                    app_version: app_version__.unwrap_or_default(),
+                    chain_version: chain_version__.unwrap_or_default(),
+                    block_duration: block_duration__.unwrap_or_default(),
+                    uptime: uptime__.unwrap_or_default(),
+                    chain_id: chain_id__.unwrap_or_default(),
+                    market_depth_updates_subscriptions: market_depth_updates_subscriptions__.unwrap_or_default(),
+                    block_hash: block_hash__.unwrap_or_default(),
+                    epoch_seq: epoch_seq__.unwrap_or_default(),
+                    epoch_start_time: epoch_start_time__.unwrap_or_default(),
+                    epoch_expiry_time: epoch_expiry_time__.unwrap_or_default(),
+                    event_count: event_count__.unwrap_or_default(),
+                    events_per_second: events_per_second__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.api.v1.Statistics", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for StatisticsRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("vega.api.v1.StatisticsRequest", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for StatisticsRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                            Err(serde::de::Error::unknown_field(value, FIELDS))
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = StatisticsRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.api.v1.StatisticsRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<StatisticsRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(StatisticsRequest {
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.api.v1.StatisticsRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for StatisticsResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.statistics.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.api.v1.StatisticsResponse", len)?;
+        if let Some(v) = self.statistics.as_ref() {
+            struct_ser.serialize_field("statistics", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for StatisticsResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "statistics",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Statistics,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "statistics" => Ok(GeneratedField::Statistics),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = StatisticsResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.api.v1.StatisticsResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<StatisticsResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut statistics__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Statistics => {
+                            if statistics__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("statistics"));
+                            }
+                            statistics__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(StatisticsResponse {
+                    statistics: statistics__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.api.v1.StatisticsResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SubmitRawTransactionRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.tx.is_empty() {
+            len += 1;
+        }
+        if self.r#type != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.api.v1.SubmitRawTransactionRequest", len)?;
+        if !self.tx.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("tx", pbjson::private::base64::encode(&self.tx).as_str())?;
+        }
+        if self.r#type != 0 {
+            let v = submit_raw_transaction_request::Type::try_from(self.r#type)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.r#type)))?;
+            struct_ser.serialize_field("type", &v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SubmitRawTransactionRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "tx",
+            "type",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Tx,
+            Type,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "tx" => Ok(GeneratedField::Tx),
+                            "type" => Ok(GeneratedField::Type),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SubmitRawTransactionRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.api.v1.SubmitRawTransactionRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SubmitRawTransactionRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut tx__ = None;
+                let mut r#type__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Tx => {
+                            if tx__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tx"));
+                            }
+                            tx__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Type => {
+                            if r#type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("type"));
+                            }
+                            r#type__ = Some(map_.next_value::<submit_raw_transaction_request::Type>()? as i32);
+                        }
+                    }
+                }
+                Ok(SubmitRawTransactionRequest {
+                    tx: tx__.unwrap_or_default(),
+                    r#type: r#type__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.api.v1.SubmitRawTransactionRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for submit_raw_transaction_request::Type {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "TYPE_UNSPECIFIED",
+            Self::Async => "TYPE_ASYNC",
+            Self::Sync => "TYPE_SYNC",
+            Self::Commit => "TYPE_COMMIT",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for submit_raw_transaction_request::Type {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "TYPE_UNSPECIFIED",
+            "TYPE_ASYNC",
+            "TYPE_SYNC",
+            "TYPE_COMMIT",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = submit_raw_transaction_request::Type;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "TYPE_UNSPECIFIED" => Ok(submit_raw_transaction_request::Type::Unspecified),
+                    "TYPE_ASYNC" => Ok(submit_raw_transaction_request::Type::Async),
+                    "TYPE_SYNC" => Ok(submit_raw_transaction_request::Type::Sync),
+                    "TYPE_COMMIT" => Ok(submit_raw_transaction_request::Type::Commit),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SubmitRawTransactionResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.success {
+            len += 1;
+        }
+        if !self.tx_hash.is_empty() {
+            len += 1;
+        }
+        if self.code != 0 {
+            len += 1;
+        }
+        if !self.data.is_empty() {
+            len += 1;
+        }
+        if !self.log.is_empty() {
+            len += 1;
+        }
+        if self.height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.api.v1.SubmitRawTransactionResponse", len)?;
+        if self.success {
+            struct_ser.serialize_field("success", &self.success)?;
+        }
+        if !self.tx_hash.is_empty() {
+            struct_ser.serialize_field("txHash", &self.tx_hash)?;
+        }
+        if self.code != 0 {
+            struct_ser.serialize_field("code", &self.code)?;
+        }
+        if !self.data.is_empty() {
+            struct_ser.serialize_field("data", &self.data)?;
+        }
+        if !self.log.is_empty() {
+            struct_ser.serialize_field("log", &self.log)?;
+        }
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SubmitRawTransactionResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "success",
+            "tx_hash",
+            "txHash",
+            "code",
+            "data",
+            "log",
+            "height",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Success,
+            TxHash,
+            Code,
+            Data,
+            Log,
+            Height,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "success" => Ok(GeneratedField::Success),
+                            "txHash" | "tx_hash" => Ok(GeneratedField::TxHash),
+                            "code" => Ok(GeneratedField::Code),
+                            "data" => Ok(GeneratedField::Data),
+                            "log" => Ok(GeneratedField::Log),
+                            "height" => Ok(GeneratedField::Height),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SubmitRawTransactionResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.api.v1.SubmitRawTransactionResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SubmitRawTransactionResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut success__ = None;
+                let mut tx_hash__ = None;
+                let mut code__ = None;
+                let mut data__ = None;
+                let mut log__ = None;
+                let mut height__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Success => {
+                            if success__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("success"));
+                            }
+                            success__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::TxHash => {
+                            if tx_hash__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("txHash"));
+                            }
+                            tx_hash__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Code => {
+                            if code__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("code"));
+                            }
+                            code__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Data => {
+                            if data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("data"));
+                            }
+                            data__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Log => {
+                            if log__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("log"));
+                            }
+                            log__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(SubmitRawTransactionResponse {
+                    success: success__.unwrap_or_default(),
+                    tx_hash: tx_hash__.unwrap_or_default(),
+                    code: code__.unwrap_or_default(),
+                    data: data__.unwrap_or_default(),
+                    log: log__.unwrap_or_default(),
+                    height: height__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.api.v1.SubmitRawTransactionResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SubmitTransactionRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.tx.is_some() {
+            len += 1;
+        }
+        if self.r#type != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.api.v1.SubmitTransactionRequest", len)?;
+        if let Some(v) = self.tx.as_ref() {
+            struct_ser.serialize_field("tx", v)?;
+        }
+        if self.r#type != 0 {
+            let v = submit_transaction_request::Type::try_from(self.r#type)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.r#type)))?;
+            struct_ser.serialize_field("type", &v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SubmitTransactionRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "tx",
+            "type",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Tx,
+            Type,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "tx" => Ok(GeneratedField::Tx),
+                            "type" => Ok(GeneratedField::Type),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SubmitTransactionRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.api.v1.SubmitTransactionRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SubmitTransactionRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut tx__ = None;
+                let mut r#type__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Tx => {
+                            if tx__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tx"));
+                            }
+                            tx__ = map_.next_value()?;
+                        }
+                        GeneratedField::Type => {
+                            if r#type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("type"));
+                            }
+                            r#type__ = Some(map_.next_value::<submit_transaction_request::Type>()? as i32);
+                        }
+                    }
+                }
+                Ok(SubmitTransactionRequest {
+                    tx: tx__,
+                    r#type: r#type__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.api.v1.SubmitTransactionRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for submit_transaction_request::Type {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "TYPE_UNSPECIFIED",
+            Self::Async => "TYPE_ASYNC",
+            Self::Sync => "TYPE_SYNC",
+            Self::Commit => "TYPE_COMMIT",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for submit_transaction_request::Type {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "TYPE_UNSPECIFIED",
+            "TYPE_ASYNC",
+            "TYPE_SYNC",
+            "TYPE_COMMIT",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = submit_transaction_request::Type;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "TYPE_UNSPECIFIED" => Ok(submit_transaction_request::Type::Unspecified),
+                    "TYPE_ASYNC" => Ok(submit_transaction_request::Type::Async),
+                    "TYPE_SYNC" => Ok(submit_transaction_request::Type::Sync),
+                    "TYPE_COMMIT" => Ok(submit_transaction_request::Type::Commit),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SubmitTransactionResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.success {
+            len += 1;
+        }
+        if !self.tx_hash.is_empty() {
+            len += 1;
+        }
+        if self.code != 0 {
+            len += 1;
+        }
+        if !self.data.is_empty() {
+            len += 1;
+        }
+        if !self.log.is_empty() {
+            len += 1;
+        }
+        if self.height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.api.v1.SubmitTransactionResponse", len)?;
+        if self.success {
+            struct_ser.serialize_field("success", &self.success)?;
+        }
+        if !self.tx_hash.is_empty() {
+            struct_ser.serialize_field("txHash", &self.tx_hash)?;
+        }
+        if self.code != 0 {
+            struct_ser.serialize_field("code", &self.code)?;
+        }
+        if !self.data.is_empty() {
+            struct_ser.serialize_field("data", &self.data)?;
+        }
+        if !self.log.is_empty() {
+            struct_ser.serialize_field("log", &self.log)?;
+        }
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SubmitTransactionResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "success",
+            "tx_hash",
+            "txHash",
+            "code",
+            "data",
+            "log",
+            "height",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Success,
+            TxHash,
+            Code,
+            Data,
+            Log,
+            Height,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "success" => Ok(GeneratedField::Success),
+                            "txHash" | "tx_hash" => Ok(GeneratedField::TxHash),
+                            "code" => Ok(GeneratedField::Code),
+                            "data" => Ok(GeneratedField::Data),
+                            "log" => Ok(GeneratedField::Log),
+                            "height" => Ok(GeneratedField::Height),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SubmitTransactionResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.api.v1.SubmitTransactionResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SubmitTransactionResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut success__ = None;
+                let mut tx_hash__ = None;
+                let mut code__ = None;
+                let mut data__ = None;
+                let mut log__ = None;
+                let mut height__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Success => {
+                            if success__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("success"));
+                            }
+                            success__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::TxHash => {
+                            if tx_hash__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("txHash"));
+                            }
+                            tx_hash__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Code => {
+                            if code__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("code"));
+                            }
+                            code__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Data => {
+                            if data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("data"));
+                            }
+                            data__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Log => {
+                            if log__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("log"));
+                            }
+                            log__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(SubmitTransactionResponse {
+                    success: success__.unwrap_or_default(),
+                    tx_hash: tx_hash__.unwrap_or_default(),
+                    code: code__.unwrap_or_default(),
+                    data: data__.unwrap_or_default(),
+                    log: log__.unwrap_or_default(),
+                    height: height__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.api.v1.SubmitTransactionResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for VoteSpamStatistic {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.proposal.is_empty() {
+            len += 1;
+        }
+        if self.count_for_epoch != 0 {
+            len += 1;
+        }
+        if !self.min_tokens_required.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.api.v1.VoteSpamStatistic", len)?;
+        if !self.proposal.is_empty() {
+            struct_ser.serialize_field("proposal", &self.proposal)?;
+        }
+        if self.count_for_epoch != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("countForEpoch", ToString::to_string(&self.count_for_epoch).as_str())?;
+        }
+        if !self.min_tokens_required.is_empty() {
+            struct_ser.serialize_field("minTokensRequired", &self.min_tokens_required)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for VoteSpamStatistic {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "proposal",
+            "count_for_epoch",
+            "countForEpoch",
+            "min_tokens_required",
+            "minTokensRequired",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Proposal,
+            CountForEpoch,
+            MinTokensRequired,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "proposal" => Ok(GeneratedField::Proposal),
+                            "countForEpoch" | "count_for_epoch" => Ok(GeneratedField::CountForEpoch),
+                            "minTokensRequired" | "min_tokens_required" => Ok(GeneratedField::MinTokensRequired),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = VoteSpamStatistic;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.api.v1.VoteSpamStatistic")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<VoteSpamStatistic, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut proposal__ = None;
+                let mut count_for_epoch__ = None;
+                let mut min_tokens_required__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Proposal => {
+                            if proposal__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposal"));
+                            }
+                            proposal__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::CountForEpoch => {
+                            if count_for_epoch__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("countForEpoch"));
+                            }
+                            count_for_epoch__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::MinTokensRequired => {
+                            if min_tokens_required__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("minTokensRequired"));
+                            }
+                            min_tokens_required__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(VoteSpamStatistic {
+                    proposal: proposal__.unwrap_or_default(),
+                    count_for_epoch: count_for_epoch__.unwrap_or_default(),
+                    min_tokens_required: min_tokens_required__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.api.v1.VoteSpamStatistic", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for VoteSpamStatistics {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.statistics.is_empty() {
+            len += 1;
+        }
+        if self.max_for_epoch != 0 {
+            len += 1;
+        }
+        if self.banned_until.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.api.v1.VoteSpamStatistics", len)?;
+        if !self.statistics.is_empty() {
+            struct_ser.serialize_field("statistics", &self.statistics)?;
+        }
+        if self.max_for_epoch != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("maxForEpoch", ToString::to_string(&self.max_for_epoch).as_str())?;
+        }
+        if let Some(v) = self.banned_until.as_ref() {
+            struct_ser.serialize_field("bannedUntil", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for VoteSpamStatistics {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "statistics",
+            "max_for_epoch",
+            "maxForEpoch",
+            "banned_until",
+            "bannedUntil",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Statistics,
+            MaxForEpoch,
+            BannedUntil,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "statistics" => Ok(GeneratedField::Statistics),
+                            "maxForEpoch" | "max_for_epoch" => Ok(GeneratedField::MaxForEpoch),
+                            "bannedUntil" | "banned_until" => Ok(GeneratedField::BannedUntil),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = VoteSpamStatistics;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.api.v1.VoteSpamStatistics")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<VoteSpamStatistics, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut statistics__ = None;
+                let mut max_for_epoch__ = None;
+                let mut banned_until__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Statistics => {
+                            if statistics__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("statistics"));
+                            }
+                            statistics__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::MaxForEpoch => {
+                            if max_for_epoch__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxForEpoch"));
+                            }
+                            max_for_epoch__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::BannedUntil => {
+                            if banned_until__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("bannedUntil"));
+                            }
+                            banned_until__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(VoteSpamStatistics {
+                    statistics: statistics__.unwrap_or_default(),
+                    max_for_epoch: max_for_epoch__.unwrap_or_default(),
+                    banned_until: banned_until__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.api.v1.VoteSpamStatistics", FIELDS, GeneratedVisitor)
+    }
+}
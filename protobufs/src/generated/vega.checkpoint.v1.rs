@@ -0,0 +1,643 @@
+// This file is @generated by prost-build.
+/// CheckpointState is the entire checkpoint serialised. Basically it is the serialisation of the Checkpoint message + hash.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CheckpointState {
+    #[prost(bytes = "vec", tag = "1")]
+    pub hash: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub state: ::prost::alloc::vec::Vec<u8>,
+}
+/// Checkpoint aggregates the various engine snapshots
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Checkpoint {
+    #[prost(bytes = "vec", tag = "1")]
+    pub governance: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub assets: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "3")]
+    pub collateral: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "4")]
+    pub network_parameters: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "5")]
+    pub delegation: ::prost::alloc::vec::Vec<u8>,
+    /// will just be an epoch event
+    #[prost(bytes = "vec", tag = "6")]
+    pub epoch: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "7")]
+    pub block: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "8")]
+    pub rewards: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "9")]
+    pub banking: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "10")]
+    pub validators: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "11")]
+    pub staking: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "12")]
+    pub multisig_control: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "13")]
+    pub market_tracker: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "14")]
+    pub execution: ::prost::alloc::vec::Vec<u8>,
+}
+/// AssetEntry is a single (enabled) asset
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AssetEntry {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub asset_details: ::core::option::Option<super::super::AssetDetails>,
+}
+/// Assets contains all the enabled assets as AssetEntries
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Assets {
+    #[prost(message, repeated, tag = "1")]
+    pub assets: ::prost::alloc::vec::Vec<AssetEntry>,
+    #[prost(message, repeated, tag = "2")]
+    pub pending_listing_assets: ::prost::alloc::vec::Vec<AssetEntry>,
+}
+/// AssetBalance represents the total balance of a given asset for a party
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AssetBalance {
+    #[prost(string, tag = "1")]
+    pub party: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub asset: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub balance: ::prost::alloc::string::String,
+}
+/// Collateral contains the balances per party
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Collateral {
+    #[prost(message, repeated, tag = "1")]
+    pub balances: ::prost::alloc::vec::Vec<AssetBalance>,
+}
+/// NetParams contains all network parameters
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NetParams {
+    #[prost(message, repeated, tag = "1")]
+    pub params: ::prost::alloc::vec::Vec<super::super::NetworkParameter>,
+}
+/// Proposals will contain all accepted proposals
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Proposals {
+    #[prost(message, repeated, tag = "1")]
+    pub proposals: ::prost::alloc::vec::Vec<super::super::Proposal>,
+}
+/// Delegated amounts for party/node
+/// `undelegate` and `epoch_seq` are only relevant for pending entries
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DelegateEntry {
+    #[prost(string, tag = "1")]
+    pub party: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub node: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub amount: ::prost::alloc::string::String,
+    #[prost(bool, tag = "4")]
+    pub undelegate: bool,
+    #[prost(uint64, tag = "5")]
+    pub epoch_seq: u64,
+}
+/// Delegate contains all entries for a checkpoint
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Delegate {
+    #[prost(message, repeated, tag = "1")]
+    pub active: ::prost::alloc::vec::Vec<DelegateEntry>,
+    #[prost(message, repeated, tag = "2")]
+    pub pending: ::prost::alloc::vec::Vec<DelegateEntry>,
+    #[prost(string, repeated, tag = "3")]
+    pub auto_delegation: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// Block message contains data related to block at which the checkpoint
+/// was created i.e. the block height
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Block {
+    #[prost(int64, tag = "1")]
+    pub height: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Rewards {
+    #[prost(message, repeated, tag = "1")]
+    pub rewards: ::prost::alloc::vec::Vec<RewardPayout>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RewardPayout {
+    #[prost(int64, tag = "1")]
+    pub payout_time: i64,
+    #[prost(message, repeated, tag = "2")]
+    pub rewards_payout: ::prost::alloc::vec::Vec<PendingRewardPayout>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PendingRewardPayout {
+    #[prost(string, tag = "1")]
+    pub from_account: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub asset: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "3")]
+    pub party_amount: ::prost::alloc::vec::Vec<PartyAmount>,
+    #[prost(string, tag = "4")]
+    pub total_reward: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub epoch_seq: ::prost::alloc::string::String,
+    #[prost(int64, tag = "6")]
+    pub timestamp: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PartyAmount {
+    #[prost(string, tag = "1")]
+    pub party: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub amount: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PendingKeyRotation {
+    /// Relative target block height is: target block height - current block height.
+    /// Useful for cross blockchain compatibility.
+    #[prost(uint64, tag = "1")]
+    pub relative_target_block_height: u64,
+    #[prost(string, tag = "2")]
+    pub node_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub new_pub_key: ::prost::alloc::string::String,
+    #[prost(uint32, tag = "4")]
+    pub new_pub_key_index: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PendingEthereumKeyRotation {
+    /// Relative target block height is: target block height - current block height.
+    /// Useful for cross blockchain compatibility.
+    #[prost(uint64, tag = "1")]
+    pub relative_target_block_height: u64,
+    #[prost(string, tag = "2")]
+    pub node_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub new_address: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ScheduledTransfer {
+    #[prost(message, optional, tag = "1")]
+    pub transfer: ::core::option::Option<super::super::Transfer>,
+    #[prost(enumeration = "super::super::AccountType", tag = "2")]
+    pub account_type: i32,
+    #[prost(string, tag = "3")]
+    pub reference: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "4")]
+    pub oneoff_transfer: ::core::option::Option<super::super::events::v1::Transfer>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ScheduledTransferAtTime {
+    #[prost(int64, tag = "1")]
+    pub deliver_on: i64,
+    #[prost(message, repeated, tag = "2")]
+    pub transfers: ::prost::alloc::vec::Vec<ScheduledTransfer>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RecurringTransfers {
+    #[prost(message, repeated, tag = "1")]
+    pub recurring_transfers: ::prost::alloc::vec::Vec<
+        super::super::events::v1::Transfer,
+    >,
+    #[prost(int64, tag = "2")]
+    pub next_metric_update: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GovernanceTransfer {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub reference: ::prost::alloc::string::String,
+    #[prost(enumeration = "super::super::events::v1::transfer::Status", tag = "3")]
+    pub status: i32,
+    #[prost(int64, tag = "4")]
+    pub timestamp: i64,
+    #[prost(message, optional, tag = "5")]
+    pub config: ::core::option::Option<super::super::NewTransferConfiguration>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ScheduledGovernanceTransferAtTime {
+    #[prost(int64, tag = "1")]
+    pub deliver_on: i64,
+    #[prost(message, repeated, tag = "2")]
+    pub transfers: ::prost::alloc::vec::Vec<GovernanceTransfer>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Banking {
+    #[prost(message, repeated, tag = "1")]
+    pub transfers_at_time: ::prost::alloc::vec::Vec<ScheduledTransferAtTime>,
+    #[prost(message, optional, tag = "2")]
+    pub recurring_transfers: ::core::option::Option<RecurringTransfers>,
+    #[prost(message, optional, tag = "3")]
+    pub primary_bridge_state: ::core::option::Option<BridgeState>,
+    #[prost(message, repeated, tag = "4")]
+    pub asset_actions: ::prost::alloc::vec::Vec<AssetAction>,
+    #[prost(uint64, tag = "5")]
+    pub last_seen_primary_eth_block: u64,
+    #[prost(string, repeated, tag = "6")]
+    pub seen_refs: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(message, repeated, tag = "7")]
+    pub governance_transfers_at_time: ::prost::alloc::vec::Vec<
+        ScheduledGovernanceTransferAtTime,
+    >,
+    #[prost(message, repeated, tag = "8")]
+    pub recurring_governance_transfers: ::prost::alloc::vec::Vec<GovernanceTransfer>,
+    #[prost(message, optional, tag = "9")]
+    pub secondary_bridge_state: ::core::option::Option<BridgeState>,
+    #[prost(uint64, tag = "10")]
+    pub last_seen_secondary_eth_block: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BridgeState {
+    #[prost(bool, tag = "1")]
+    pub active: bool,
+    #[prost(uint64, tag = "2")]
+    pub block_height: u64,
+    #[prost(uint64, tag = "3")]
+    pub log_index: u64,
+    #[prost(string, tag = "4")]
+    pub chain_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Validators {
+    #[prost(message, repeated, tag = "1")]
+    pub validator_state: ::prost::alloc::vec::Vec<ValidatorState>,
+    #[prost(message, repeated, tag = "2")]
+    pub pending_key_rotations: ::prost::alloc::vec::Vec<PendingKeyRotation>,
+    #[prost(message, repeated, tag = "3")]
+    pub pending_ethereum_key_rotations: ::prost::alloc::vec::Vec<
+        PendingEthereumKeyRotation,
+    >,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidatorState {
+    #[prost(message, optional, tag = "1")]
+    pub validator_update: ::core::option::Option<
+        super::super::events::v1::ValidatorUpdate,
+    >,
+    #[prost(int32, tag = "2")]
+    pub status: i32,
+    #[prost(uint64, tag = "3")]
+    pub eth_events_forwarded: u64,
+    #[prost(int64, tag = "4")]
+    pub validator_power: i64,
+    #[prost(message, optional, tag = "5")]
+    pub ranking_score: ::core::option::Option<super::super::RankingScore>,
+    #[prost(int32, tag = "6")]
+    pub heartbeat_block_index: i32,
+    #[prost(bool, repeated, tag = "7")]
+    pub heartbeat_block_sigs: ::prost::alloc::vec::Vec<bool>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Staking {
+    #[prost(message, repeated, tag = "1")]
+    pub accepted: ::prost::alloc::vec::Vec<super::super::events::v1::StakeLinking>,
+    #[prost(uint64, tag = "2")]
+    pub last_block_seen: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MultisigControl {
+    #[prost(message, repeated, tag = "1")]
+    pub signers: ::prost::alloc::vec::Vec<
+        super::super::events::v1::Erc20MultiSigSignerEvent,
+    >,
+    #[prost(message, optional, tag = "2")]
+    pub threshold_set: ::core::option::Option<
+        super::super::events::v1::Erc20MultiSigThresholdSetEvent,
+    >,
+    #[prost(uint64, tag = "3")]
+    pub last_block_seen: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MarketTracker {
+    #[prost(message, repeated, tag = "1")]
+    pub market_activity: ::prost::alloc::vec::Vec<MarketActivityTracker>,
+    #[prost(message, repeated, tag = "2")]
+    pub taker_notional_volume: ::prost::alloc::vec::Vec<TakerNotionalVolume>,
+    #[prost(message, repeated, tag = "3")]
+    pub market_to_party_taker_notional_volume: ::prost::alloc::vec::Vec<
+        MarketToPartyTakerNotionalVolume,
+    >,
+    #[prost(message, repeated, tag = "4")]
+    pub epoch_taker_fees: ::prost::alloc::vec::Vec<EpochPartyTakerFees>,
+    #[prost(message, repeated, tag = "5")]
+    pub game_eligibility_tracker: ::prost::alloc::vec::Vec<GameEligibilityTracker>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MarketActivityTracker {
+    #[prost(string, tag = "1")]
+    pub market: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub asset: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "3")]
+    pub maker_fees_received: ::prost::alloc::vec::Vec<PartyFees>,
+    #[prost(message, repeated, tag = "4")]
+    pub maker_fees_paid: ::prost::alloc::vec::Vec<PartyFees>,
+    #[prost(message, repeated, tag = "5")]
+    pub lp_fees: ::prost::alloc::vec::Vec<PartyFees>,
+    #[prost(string, tag = "6")]
+    pub proposer: ::prost::alloc::string::String,
+    #[prost(string, repeated, tag = "7")]
+    pub bonus_paid: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(string, tag = "8")]
+    pub value_traded: ::prost::alloc::string::String,
+    #[prost(bool, tag = "9")]
+    pub ready_to_delete: bool,
+    #[prost(message, repeated, tag = "10")]
+    pub time_weighted_position: ::prost::alloc::vec::Vec<TwPositionData>,
+    #[prost(message, repeated, tag = "11")]
+    pub time_weighted_notional: ::prost::alloc::vec::Vec<TwNotionalData>,
+    #[prost(message, repeated, tag = "12")]
+    pub returns_data: ::prost::alloc::vec::Vec<ReturnsData>,
+    #[prost(message, repeated, tag = "13")]
+    pub maker_fees_received_history: ::prost::alloc::vec::Vec<EpochPartyFees>,
+    #[prost(message, repeated, tag = "14")]
+    pub maker_fees_paid_history: ::prost::alloc::vec::Vec<EpochPartyFees>,
+    #[prost(message, repeated, tag = "15")]
+    pub lp_fees_history: ::prost::alloc::vec::Vec<EpochPartyFees>,
+    #[prost(message, repeated, tag = "16")]
+    pub time_weighted_position_data_history: ::prost::alloc::vec::Vec<
+        EpochTimeWeightPositionData,
+    >,
+    #[prost(message, repeated, tag = "17")]
+    pub time_weighted_notional_data_history: ::prost::alloc::vec::Vec<
+        EpochTimeWeightedNotionalData,
+    >,
+    #[prost(message, repeated, tag = "18")]
+    pub returns_data_history: ::prost::alloc::vec::Vec<EpochReturnsData>,
+    #[prost(message, repeated, tag = "19")]
+    pub infra_fees: ::prost::alloc::vec::Vec<PartyFees>,
+    #[prost(message, repeated, tag = "20")]
+    pub lp_paid_fees: ::prost::alloc::vec::Vec<PartyFees>,
+    #[prost(message, repeated, tag = "21")]
+    pub realised_returns: ::prost::alloc::vec::Vec<ReturnsData>,
+    #[prost(message, repeated, tag = "22")]
+    pub realised_returns_history: ::prost::alloc::vec::Vec<EpochReturnsData>,
+    #[prost(string, repeated, tag = "23")]
+    pub amm_parties: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(message, repeated, tag = "24")]
+    pub buy_back_fees: ::prost::alloc::vec::Vec<PartyFees>,
+    #[prost(message, repeated, tag = "25")]
+    pub treasury_fees: ::prost::alloc::vec::Vec<PartyFees>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GameEligibilityTracker {
+    #[prost(string, tag = "1")]
+    pub game_id: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "2")]
+    pub epoch_eligibility: ::prost::alloc::vec::Vec<EpochEligibility>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EpochEligibility {
+    #[prost(string, repeated, tag = "1")]
+    pub eligible_parties: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EpochPartyTakerFees {
+    #[prost(message, repeated, tag = "1")]
+    pub epoch_party_taker_fees_paid: ::prost::alloc::vec::Vec<AssetMarketPartyTakerFees>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EpochTimeWeightPositionData {
+    #[prost(message, repeated, tag = "1")]
+    pub party_time_weighted_positions: ::prost::alloc::vec::Vec<
+        PartyTimeWeightedPosition,
+    >,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EpochTimeWeightedNotionalData {
+    #[prost(message, repeated, tag = "1")]
+    pub party_time_weighted_notionals: ::prost::alloc::vec::Vec<
+        PartyTimeWeightedNotional,
+    >,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PartyTimeWeightedNotional {
+    #[prost(string, tag = "1")]
+    pub party: ::prost::alloc::string::String,
+    #[prost(bytes = "vec", tag = "2")]
+    pub tw_notional: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PartyTimeWeightedPosition {
+    #[prost(string, tag = "1")]
+    pub party: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "2")]
+    pub tw_position: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AssetMarketPartyTakerFees {
+    #[prost(string, tag = "1")]
+    pub asset: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub market: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "3")]
+    pub taker_fees: ::prost::alloc::vec::Vec<PartyTakerFees>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PartyTakerFees {
+    #[prost(string, tag = "1")]
+    pub party: ::prost::alloc::string::String,
+    #[prost(bytes = "vec", tag = "2")]
+    pub taker_fees: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EpochPartyFees {
+    #[prost(message, repeated, tag = "1")]
+    pub party_fees: ::prost::alloc::vec::Vec<PartyFeesHistory>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TakerNotionalVolume {
+    #[prost(string, tag = "1")]
+    pub party: ::prost::alloc::string::String,
+    #[prost(bytes = "vec", tag = "2")]
+    pub volume: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MarketToPartyTakerNotionalVolume {
+    #[prost(string, tag = "1")]
+    pub market: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "2")]
+    pub taker_notional_volume: ::prost::alloc::vec::Vec<TakerNotionalVolume>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EpochReturnsData {
+    #[prost(message, repeated, tag = "1")]
+    pub returns: ::prost::alloc::vec::Vec<ReturnsData>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReturnsData {
+    #[prost(string, tag = "1")]
+    pub party: ::prost::alloc::string::String,
+    #[prost(bytes = "vec", tag = "2")]
+    pub r#return: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TwPositionData {
+    #[prost(string, tag = "1")]
+    pub party: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "2")]
+    pub position: u64,
+    #[prost(int64, tag = "3")]
+    pub time: i64,
+    #[prost(uint64, tag = "4")]
+    pub tw_position: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TwNotionalData {
+    #[prost(string, tag = "1")]
+    pub party: ::prost::alloc::string::String,
+    #[prost(bytes = "vec", tag = "2")]
+    pub notional: ::prost::alloc::vec::Vec<u8>,
+    #[prost(int64, tag = "3")]
+    pub time: i64,
+    #[prost(bytes = "vec", tag = "4")]
+    pub tw_notional: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "5")]
+    pub price: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PartyFees {
+    #[prost(string, tag = "1")]
+    pub party: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub fee: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PartyFeesHistory {
+    #[prost(string, tag = "1")]
+    pub party: ::prost::alloc::string::String,
+    #[prost(bytes = "vec", tag = "2")]
+    pub fee: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AssetAction {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(uint32, tag = "2")]
+    pub state: u32,
+    #[prost(string, tag = "3")]
+    pub asset: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "4")]
+    pub block_number: u64,
+    #[prost(uint64, tag = "5")]
+    pub tx_index: u64,
+    #[prost(string, tag = "6")]
+    pub hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "7")]
+    pub builtin_deposit: ::core::option::Option<super::super::BuiltinAssetDeposit>,
+    #[prost(message, optional, tag = "8")]
+    pub erc20_deposit: ::core::option::Option<super::super::Erc20Deposit>,
+    #[prost(message, optional, tag = "9")]
+    pub asset_list: ::core::option::Option<super::super::Erc20AssetList>,
+    #[prost(message, optional, tag = "10")]
+    pub erc20_asset_limits_updated: ::core::option::Option<
+        super::super::Erc20AssetLimitsUpdated,
+    >,
+    #[prost(bool, tag = "11")]
+    pub erc20_bridge_stopped: bool,
+    #[prost(bool, tag = "12")]
+    pub erc20_bridge_resumed: bool,
+    #[prost(string, tag = "13")]
+    pub chain_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ElsShare {
+    /// Party ID of the liquidity provider.
+    #[prost(string, tag = "1")]
+    pub party_id: ::prost::alloc::string::String,
+    /// The equity-like share of the liquidity provider, representing their share in the total provided liquidity expressed as a value between 0 and 1.
+    #[prost(string, tag = "2")]
+    pub share: ::prost::alloc::string::String,
+    /// Amount committed. This is the total commitment made. It does not necessarily match the LP balance.
+    #[prost(string, tag = "3")]
+    pub supplied_stake: ::prost::alloc::string::String,
+    /// LP stake, weighted according to the time the stake was committed and market growth.
+    #[prost(string, tag = "4")]
+    pub virtual_stake: ::prost::alloc::string::String,
+    /// Average entry valuation. As time increases the weight of stake, this is the overall valuation of the current stake.
+    #[prost(string, tag = "5")]
+    pub avg: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MarketState {
+    /// Unique ID for the market
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// The current equity-like share data for a market.
+    #[prost(message, repeated, tag = "2")]
+    pub shares: ::prost::alloc::vec::Vec<ElsShare>,
+    /// Current balance of the insurance pool.
+    #[prost(string, tag = "3")]
+    pub insurance_balance: ::prost::alloc::string::String,
+    /// Total trade value which last determined the growth rate of the market.
+    #[prost(string, tag = "4")]
+    pub last_trade_value: ::prost::alloc::string::String,
+    /// Total trade volume which last determined the growth rate of the market.
+    #[prost(string, tag = "5")]
+    pub last_trade_volume: ::prost::alloc::string::String,
+    /// Succession window in nanoseconds: the timestamp when this market cannot be succeeded anymore.
+    #[prost(int64, tag = "6")]
+    pub succession_window: i64,
+    /// The underlying market should be present in case the successor market is enacted during the succession window.
+    /// This field can be omitted if the market is not yet settled.
+    #[prost(message, optional, tag = "7")]
+    pub market: ::core::option::Option<super::super::Market>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExecutionState {
+    /// Market data that should be preserved, like equity-like shares, and settled markets that have pending successor markets.
+    #[prost(message, repeated, tag = "1")]
+    pub data: ::prost::alloc::vec::Vec<MarketState>,
+}
@@ -0,0 +1,7331 @@
+impl serde::Serialize for AssetAction {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.id.is_empty() {
+            len += 1;
+        }
+        if self.state != 0 {
+            len += 1;
+        }
+        if !self.asset.is_empty() {
+            len += 1;
+        }
+        if self.block_number != 0 {
+            len += 1;
+        }
+        if self.tx_index != 0 {
+            len += 1;
+        }
+        if !self.hash.is_empty() {
+            len += 1;
+        }
+        if self.builtin_deposit.is_some() {
+            len += 1;
+        }
+        if self.erc20_deposit.is_some() {
+            len += 1;
+        }
+        if self.asset_list.is_some() {
+            len += 1;
+        }
+        if self.erc20_asset_limits_updated.is_some() {
+            len += 1;
+        }
+        if self.erc20_bridge_stopped {
+            len += 1;
+        }
+        if self.erc20_bridge_resumed {
+            len += 1;
+        }
+        if !self.chain_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.checkpoint.v1.AssetAction", len)?;
+        if !self.id.is_empty() {
+            struct_ser.serialize_field("id", &self.id)?;
+        }
+        if self.state != 0 {
+            struct_ser.serialize_field("state", &self.state)?;
+        }
+        if !self.asset.is_empty() {
+            struct_ser.serialize_field("asset", &self.asset)?;
+        }
+        if self.block_number != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("blockNumber", ToString::to_string(&self.block_number).as_str())?;
+        }
+        if self.tx_index != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("txIndex", ToString::to_string(&self.tx_index).as_str())?;
+        }
+        if !self.hash.is_empty() {
+            struct_ser.serialize_field("hash", &self.hash)?;
+        }
+        if let Some(v) = self.builtin_deposit.as_ref() {
+            struct_ser.serialize_field("builtinDeposit", v)?;
+        }
+        if let Some(v) = self.erc20_deposit.as_ref() {
+            struct_ser.serialize_field("erc20Deposit", v)?;
+        }
+        if let Some(v) = self.asset_list.as_ref() {
+            struct_ser.serialize_field("assetList", v)?;
+        }
+        if let Some(v) = self.erc20_asset_limits_updated.as_ref() {
+            struct_ser.serialize_field("erc20AssetLimitsUpdated", v)?;
+        }
+        if self.erc20_bridge_stopped {
+            struct_ser.serialize_field("erc20BridgeStopped", &self.erc20_bridge_stopped)?;
+        }
+        if self.erc20_bridge_resumed {
+            struct_ser.serialize_field("erc20BridgeResumed", &self.erc20_bridge_resumed)?;
+        }
+        if !self.chain_id.is_empty() {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for AssetAction {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "id",
+            "state",
+            "asset",
+            "block_number",
+            "blockNumber",
+            "tx_index",
+            "txIndex",
+            "hash",
+            "builtin_deposit",
+            "builtinDeposit",
+            "erc20_deposit",
+            "erc20Deposit",
+            "asset_list",
+            "assetList",
+            "erc20_asset_limits_updated",
+            "erc20AssetLimitsUpdated",
+            "erc20_bridge_stopped",
+            "erc20BridgeStopped",
+            "erc20_bridge_resumed",
+            "erc20BridgeResumed",
+            "chain_id",
+            "chainId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Id,
+            State,
+            Asset,
+            BlockNumber,
+            TxIndex,
+            Hash,
+            BuiltinDeposit,
+            Erc20Deposit,
+            AssetList,
+            Erc20AssetLimitsUpdated,
+            Erc20BridgeStopped,
+            Erc20BridgeResumed,
+            ChainId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "id" => Ok(GeneratedField::Id),
+                            "state" => Ok(GeneratedField::State),
+                            "asset" => Ok(GeneratedField::Asset),
+                            "blockNumber" | "block_number" => Ok(GeneratedField::BlockNumber),
+                            "txIndex" | "tx_index" => Ok(GeneratedField::TxIndex),
+                            "hash" => Ok(GeneratedField::Hash),
+                            "builtinDeposit" | "builtin_deposit" => Ok(GeneratedField::BuiltinDeposit),
+                            "erc20Deposit" | "erc20_deposit" => Ok(GeneratedField::Erc20Deposit),
+                            "assetList" | "asset_list" => Ok(GeneratedField::AssetList),
+                            "erc20AssetLimitsUpdated" | "erc20_asset_limits_updated" => Ok(GeneratedField::Erc20AssetLimitsUpdated),
+                            "erc20BridgeStopped" | "erc20_bridge_stopped" => Ok(GeneratedField::Erc20BridgeStopped),
+                            "erc20BridgeResumed" | "erc20_bridge_resumed" => Ok(GeneratedField::Erc20BridgeResumed),
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = AssetAction;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.checkpoint.v1.AssetAction")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<AssetAction, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut id__ = None;
+                let mut state__ = None;
+                let mut asset__ = None;
+                let mut block_number__ = None;
+                let mut tx_index__ = None;
+                let mut hash__ = None;
+                let mut builtin_deposit__ = None;
+                let mut erc20_deposit__ = None;
+                let mut asset_list__ = None;
+                let mut erc20_asset_limits_updated__ = None;
+                let mut erc20_bridge_stopped__ = None;
+                let mut erc20_bridge_resumed__ = None;
+                let mut chain_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::State => {
+                            if state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("state"));
+                            }
+                            state__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Asset => {
+                            if asset__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("asset"));
+                            }
+                            asset__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::BlockNumber => {
+                            if block_number__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("blockNumber"));
+                            }
+                            block_number__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::TxIndex => {
+                            if tx_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("txIndex"));
+                            }
+                            tx_index__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Hash => {
+                            if hash__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("hash"));
+                            }
+                            hash__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::BuiltinDeposit => {
+                            if builtin_deposit__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("builtinDeposit"));
+                            }
+                            builtin_deposit__ = map_.next_value()?;
+                        }
+                        GeneratedField::Erc20Deposit => {
+                            if erc20_deposit__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("erc20Deposit"));
+                            }
+                            erc20_deposit__ = map_.next_value()?;
+                        }
+                        GeneratedField::AssetList => {
+                            if asset_list__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("assetList"));
+                            }
+                            asset_list__ = map_.next_value()?;
+                        }
+                        GeneratedField::Erc20AssetLimitsUpdated => {
+                            if erc20_asset_limits_updated__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("erc20AssetLimitsUpdated"));
+                            }
+                            erc20_asset_limits_updated__ = map_.next_value()?;
+                        }
+                        GeneratedField::Erc20BridgeStopped => {
+                            if erc20_bridge_stopped__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("erc20BridgeStopped"));
+                            }
+                            erc20_bridge_stopped__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Erc20BridgeResumed => {
+                            if erc20_bridge_resumed__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("erc20BridgeResumed"));
+                            }
+                            erc20_bridge_resumed__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
+                            }
+                            chain_id__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(AssetAction {
+                    id: id__.unwrap_or_default(),
+                    state: state__.unwrap_or_default(),
+                    asset: asset__.unwrap_or_default(),
+                    block_number: block_number__.unwrap_or_default(),
+                    tx_index: tx_index__.unwrap_or_default(),
+                    hash: hash__.unwrap_or_default(),
+                    builtin_deposit: builtin_deposit__,
+                    erc20_deposit: erc20_deposit__,
+                    asset_list: asset_list__,
+                    erc20_asset_limits_updated: erc20_asset_limits_updated__,
+                    erc20_bridge_stopped: erc20_bridge_stopped__.unwrap_or_default(),
+                    erc20_bridge_resumed: erc20_bridge_resumed__.unwrap_or_default(),
+                    chain_id: chain_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.checkpoint.v1.AssetAction", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for AssetBalance {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.party.is_empty() {
+            len += 1;
+        }
+        if !self.asset.is_empty() {
+            len += 1;
+        }
+        if !self.balance.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.checkpoint.v1.AssetBalance", len)?;
+        if !self.party.is_empty() {
+            struct_ser.serialize_field("party", &self.party)?;
+        }
+        if !self.asset.is_empty() {
+            struct_ser.serialize_field("asset", &self.asset)?;
+        }
+        if !self.balance.is_empty() {
+            struct_ser.serialize_field("balance", &self.balance)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for AssetBalance {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "party",
+            "asset",
+            "balance",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Party,
+            Asset,
+            Balance,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "party" => Ok(GeneratedField::Party),
+                            "asset" => Ok(GeneratedField::Asset),
+                            "balance" => Ok(GeneratedField::Balance),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = AssetBalance;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.checkpoint.v1.AssetBalance")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<AssetBalance, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut party__ = None;
+                let mut asset__ = None;
+                let mut balance__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Party => {
+                            if party__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("party"));
+                            }
+                            party__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Asset => {
+                            if asset__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("asset"));
+                            }
+                            asset__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Balance => {
+                            if balance__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("balance"));
+                            }
+                            balance__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(AssetBalance {
+                    party: party__.unwrap_or_default(),
+                    asset: asset__.unwrap_or_default(),
+                    balance: balance__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.checkpoint.v1.AssetBalance", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for AssetEntry {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.id.is_empty() {
+            len += 1;
+        }
+        if self.asset_details.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.checkpoint.v1.AssetEntry", len)?;
+        if !self.id.is_empty() {
+            struct_ser.serialize_field("id", &self.id)?;
+        }
+        if let Some(v) = self.asset_details.as_ref() {
+            struct_ser.serialize_field("assetDetails", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for AssetEntry {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "id",
+            "asset_details",
+            "assetDetails",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Id,
+            AssetDetails,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "id" => Ok(GeneratedField::Id),
+                            "assetDetails" | "asset_details" => Ok(GeneratedField::AssetDetails),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = AssetEntry;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.checkpoint.v1.AssetEntry")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<AssetEntry, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut id__ = None;
+                let mut asset_details__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::AssetDetails => {
+                            if asset_details__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("assetDetails"));
+                            }
+                            asset_details__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(AssetEntry {
+                    id: id__.unwrap_or_default(),
+                    asset_details: asset_details__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.checkpoint.v1.AssetEntry", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for AssetMarketPartyTakerFees {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.asset.is_empty() {
+            len += 1;
+        }
+        if !self.market.is_empty() {
+            len += 1;
+        }
+        if !self.taker_fees.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.checkpoint.v1.AssetMarketPartyTakerFees", len)?;
+        if !self.asset.is_empty() {
+            struct_ser.serialize_field("asset", &self.asset)?;
+        }
+        if !self.market.is_empty() {
+            struct_ser.serialize_field("market", &self.market)?;
+        }
+        if !self.taker_fees.is_empty() {
+            struct_ser.serialize_field("takerFees", &self.taker_fees)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for AssetMarketPartyTakerFees {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "asset",
+            "market",
+            "taker_fees",
+            "takerFees",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Asset,
+            Market,
+            TakerFees,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "asset" => Ok(GeneratedField::Asset),
+                            "market" => Ok(GeneratedField::Market),
+                            "takerFees" | "taker_fees" => Ok(GeneratedField::TakerFees),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = AssetMarketPartyTakerFees;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.checkpoint.v1.AssetMarketPartyTakerFees")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<AssetMarketPartyTakerFees, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut asset__ = None;
+                let mut market__ = None;
+                let mut taker_fees__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Asset => {
+                            if asset__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("asset"));
+                            }
+                            asset__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Market => {
+                            if market__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("market"));
+                            }
+                            market__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::TakerFees => {
+                            if taker_fees__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("takerFees"));
+                            }
+                            taker_fees__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(AssetMarketPartyTakerFees {
+                    asset: asset__.unwrap_or_default(),
+                    market: market__.unwrap_or_default(),
+                    taker_fees: taker_fees__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.checkpoint.v1.AssetMarketPartyTakerFees", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Assets {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.assets.is_empty() {
+            len += 1;
+        }
+        if !self.pending_listing_assets.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.checkpoint.v1.Assets", len)?;
+        if !self.assets.is_empty() {
+            struct_ser.serialize_field("assets", &self.assets)?;
+        }
+        if !self.pending_listing_assets.is_empty() {
+            struct_ser.serialize_field("pendingListingAssets", &self.pending_listing_assets)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Assets {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "assets",
+            "pending_listing_assets",
+            "pendingListingAssets",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Assets,
+            PendingListingAssets,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "assets" => Ok(GeneratedField::Assets),
+                            "pendingListingAssets" | "pending_listing_assets" => Ok(GeneratedField::PendingListingAssets),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Assets;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.checkpoint.v1.Assets")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Assets, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut assets__ = None;
+                let mut pending_listing_assets__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Assets => {
+                            if assets__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("assets"));
+                            }
+                            assets__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::PendingListingAssets => {
+                            if pending_listing_assets__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pendingListingAssets"));
+                            }
+                            pending_listing_assets__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(Assets {
+                    assets: assets__.unwrap_or_default(),
+                    pending_listing_assets: pending_listing_assets__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.checkpoint.v1.Assets", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Banking {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.transfers_at_time.is_empty() {
+            len += 1;
+        }
+        if self.recurring_transfers.is_some() {
+            len += 1;
+        }
+        if self.primary_bridge_state.is_some() {
+            len += 1;
+        }
+        if !self.asset_actions.is_empty() {
+            len += 1;
+        }
+        if self.last_seen_primary_eth_block != 0 {
+            len += 1;
+        }
+        if !self.seen_refs.is_empty() {
+            len += 1;
+        }
+        if !self.governance_transfers_at_time.is_empty() {
+            len += 1;
+        }
+        if !self.recurring_governance_transfers.is_empty() {
+            len += 1;
+        }
+        if self.secondary_bridge_state.is_some() {
+            len += 1;
+        }
+        if self.last_seen_secondary_eth_block != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.checkpoint.v1.Banking", len)?;
+        if !self.transfers_at_time.is_empty() {
+            struct_ser.serialize_field("transfersAtTime", &self.transfers_at_time)?;
+        }
+        if let Some(v) = self.recurring_transfers.as_ref() {
+            struct_ser.serialize_field("recurringTransfers", v)?;
+        }
+        if let Some(v) = self.primary_bridge_state.as_ref() {
+            struct_ser.serialize_field("primaryBridgeState", v)?;
+        }
+        if !self.asset_actions.is_empty() {
+            struct_ser.serialize_field("assetActions", &self.asset_actions)?;
+        }
+        if self.last_seen_primary_eth_block != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("lastSeenPrimaryEthBlock", ToString::to_string(&self.last_seen_primary_eth_block).as_str())?;
+        }
+        if !self.seen_refs.is_empty() {
+            struct_ser.serialize_field("seenRefs", &self.seen_refs)?;
+        }
+        if !self.governance_transfers_at_time.is_empty() {
+            struct_ser.serialize_field("governanceTransfersAtTime", &self.governance_transfers_at_time)?;
+        }
+        if !self.recurring_governance_transfers.is_empty() {
+            struct_ser.serialize_field("recurringGovernanceTransfers", &self.recurring_governance_transfers)?;
+        }
+        if let Some(v) = self.secondary_bridge_state.as_ref() {
+            struct_ser.serialize_field("secondaryBridgeState", v)?;
+        }
+        if self.last_seen_secondary_eth_block != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("lastSeenSecondaryEthBlock", ToString::to_string(&self.last_seen_secondary_eth_block).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Banking {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "transfers_at_time",
+            "transfersAtTime",
+            "recurring_transfers",
+            "recurringTransfers",
+            "primary_bridge_state",
+            "primaryBridgeState",
+            "asset_actions",
+            "assetActions",
+            "last_seen_primary_eth_block",
+            "lastSeenPrimaryEthBlock",
+            "seen_refs",
+            "seenRefs",
+            "governance_transfers_at_time",
+            "governanceTransfersAtTime",
+            "recurring_governance_transfers",
+            "recurringGovernanceTransfers",
+            "secondary_bridge_state",
+            "secondaryBridgeState",
+            "last_seen_secondary_eth_block",
+            "lastSeenSecondaryEthBlock",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TransfersAtTime,
+            RecurringTransfers,
+            PrimaryBridgeState,
+            AssetActions,
+            LastSeenPrimaryEthBlock,
+            SeenRefs,
+            GovernanceTransfersAtTime,
+            RecurringGovernanceTransfers,
+            SecondaryBridgeState,
+            LastSeenSecondaryEthBlock,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "transfersAtTime" | "transfers_at_time" => Ok(GeneratedField::TransfersAtTime),
+                            "recurringTransfers" | "recurring_transfers" => Ok(GeneratedField::RecurringTransfers),
+                            "primaryBridgeState" | "primary_bridge_state" => Ok(GeneratedField::PrimaryBridgeState),
+                            "assetActions" | "asset_actions" => Ok(GeneratedField::AssetActions),
+                            "lastSeenPrimaryEthBlock" | "last_seen_primary_eth_block" => Ok(GeneratedField::LastSeenPrimaryEthBlock),
+                            "seenRefs" | "seen_refs" => Ok(GeneratedField::SeenRefs),
+                            "governanceTransfersAtTime" | "governance_transfers_at_time" => Ok(GeneratedField::GovernanceTransfersAtTime),
+                            "recurringGovernanceTransfers" | "recurring_governance_transfers" => Ok(GeneratedField::RecurringGovernanceTransfers),
+                            "secondaryBridgeState" | "secondary_bridge_state" => Ok(GeneratedField::SecondaryBridgeState),
+                            "lastSeenSecondaryEthBlock" | "last_seen_secondary_eth_block" => Ok(GeneratedField::LastSeenSecondaryEthBlock),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Banking;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.checkpoint.v1.Banking")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Banking, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut transfers_at_time__ = None;
+                let mut recurring_transfers__ = None;
+                let mut primary_bridge_state__ = None;
+                let mut asset_actions__ = None;
+                let mut last_seen_primary_eth_block__ = None;
+                let mut seen_refs__ = None;
+                let mut governance_transfers_at_time__ = None;
+                let mut recurring_governance_transfers__ = None;
+                let mut secondary_bridge_state__ = None;
+                let mut last_seen_secondary_eth_block__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TransfersAtTime => {
+                            if transfers_at_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("transfersAtTime"));
+                            }
+                            transfers_at_time__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::RecurringTransfers => {
+                            if recurring_transfers__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("recurringTransfers"));
+                            }
+                            recurring_transfers__ = map_.next_value()?;
+                        }
+                        GeneratedField::PrimaryBridgeState => {
+                            if primary_bridge_state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("primaryBridgeState"));
+                            }
+                            primary_bridge_state__ = map_.next_value()?;
+                        }
+                        GeneratedField::AssetActions => {
+                            if asset_actions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("assetActions"));
+                            }
+                            asset_actions__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::LastSeenPrimaryEthBlock => {
+                            if last_seen_primary_eth_block__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastSeenPrimaryEthBlock"));
+                            }
+                            last_seen_primary_eth_block__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::SeenRefs => {
+                            if seen_refs__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("seenRefs"));
+                            }
+                            seen_refs__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::GovernanceTransfersAtTime => {
+                            if governance_transfers_at_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("governanceTransfersAtTime"));
+                            }
+                            governance_transfers_at_time__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::RecurringGovernanceTransfers => {
+                            if recurring_governance_transfers__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("recurringGovernanceTransfers"));
+                            }
+                            recurring_governance_transfers__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::SecondaryBridgeState => {
+                            if secondary_bridge_state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("secondaryBridgeState"));
+                            }
+                            secondary_bridge_state__ = map_.next_value()?;
+                        }
+                        GeneratedField::LastSeenSecondaryEthBlock => {
+                            if last_seen_secondary_eth_block__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastSeenSecondaryEthBlock"));
+                            }
+                            last_seen_secondary_eth_block__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(Banking {
+                    transfers_at_time: transfers_at_time__.unwrap_or_default(),
+                    recurring_transfers: recurring_transfers__,
+                    primary_bridge_state: primary_bridge_state__,
+                    asset_actions: asset_actions__.unwrap_or_default(),
+                    last_seen_primary_eth_block: last_seen_primary_eth_block__.unwrap_or_default(),
+                    seen_refs: seen_refs__.unwrap_or_default(),
+                    governance_transfers_at_time: governance_transfers_at_time__.unwrap_or_default(),
+                    recurring_governance_transfers: recurring_governance_transfers__.unwrap_or_default(),
+                    secondary_bridge_state: secondary_bridge_state__,
+                    last_seen_secondary_eth_block: last_seen_secondary_eth_block__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.checkpoint.v1.Banking", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Block {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.checkpoint.v1.Block", len)?;
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Block {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "height",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Height,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "height" => Ok(GeneratedField::Height),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Block;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.checkpoint.v1.Block")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Block, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut height__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(Block {
+                    height: height__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.checkpoint.v1.Block", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for BridgeState {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.active {
+            len += 1;
+        }
+        if self.block_height != 0 {
+            len += 1;
+        }
+        if self.log_index != 0 {
+            len += 1;
+        }
+        if !self.chain_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.checkpoint.v1.BridgeState", len)?;
+        if self.active {
+            struct_ser.serialize_field("active", &self.active)?;
+        }
+        if self.block_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("blockHeight", ToString::to_string(&self.block_height).as_str())?;
+        }
+        if self.log_index != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("logIndex", ToString::to_string(&self.log_index).as_str())?;
+        }
+        if !self.chain_id.is_empty() {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BridgeState {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "active",
+            "block_height",
+            "blockHeight",
+            "log_index",
+            "logIndex",
+            "chain_id",
+            "chainId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Active,
+            BlockHeight,
+            LogIndex,
+            ChainId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "active" => Ok(GeneratedField::Active),
+                            "blockHeight" | "block_height" => Ok(GeneratedField::BlockHeight),
+                            "logIndex" | "log_index" => Ok(GeneratedField::LogIndex),
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BridgeState;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.checkpoint.v1.BridgeState")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BridgeState, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut active__ = None;
+                let mut block_height__ = None;
+                let mut log_index__ = None;
+                let mut chain_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Active => {
+                            if active__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("active"));
+                            }
+                            active__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::BlockHeight => {
+                            if block_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("blockHeight"));
+                            }
+                            block_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::LogIndex => {
+                            if log_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("logIndex"));
+                            }
+                            log_index__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
+                            }
+                            chain_id__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(BridgeState {
+                    active: active__.unwrap_or_default(),
+                    block_height: block_height__.unwrap_or_default(),
+                    log_index: log_index__.unwrap_or_default(),
+                    chain_id: chain_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.checkpoint.v1.BridgeState", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Checkpoint {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.governance.is_empty() {
+            len += 1;
+        }
+        if !self.assets.is_empty() {
+            len += 1;
+        }
+        if !self.collateral.is_empty() {
+            len += 1;
+        }
+        if !self.network_parameters.is_empty() {
+            len += 1;
+        }
+        if !self.delegation.is_empty() {
+            len += 1;
+        }
+        if !self.epoch.is_empty() {
+            len += 1;
+        }
+        if !self.block.is_empty() {
+            len += 1;
+        }
+        if !self.rewards.is_empty() {
+            len += 1;
+        }
+        if !self.banking.is_empty() {
+            len += 1;
+        }
+        if !self.validators.is_empty() {
+            len += 1;
+        }
+        if !self.staking.is_empty() {
+            len += 1;
+        }
+        if !self.multisig_control.is_empty() {
+            len += 1;
+        }
+        if !self.market_tracker.is_empty() {
+            len += 1;
+        }
+        if !self.execution.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.checkpoint.v1.Checkpoint", len)?;
+        if !self.governance.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("governance", pbjson::private::base64::encode(&self.governance).as_str())?;
+        }
+        if !self.assets.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("assets", pbjson::private::base64::encode(&self.assets).as_str())?;
+        }
+        if !self.collateral.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("collateral", pbjson::private::base64::encode(&self.collateral).as_str())?;
+        }
+        if !self.network_parameters.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("networkParameters", pbjson::private::base64::encode(&self.network_parameters).as_str())?;
+        }
+        if !self.delegation.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("delegation", pbjson::private::base64::encode(&self.delegation).as_str())?;
+        }
+        if !self.epoch.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("epoch", pbjson::private::base64::encode(&self.epoch).as_str())?;
+        }
+        if !self.block.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("block", pbjson::private::base64::encode(&self.block).as_str())?;
+        }
+        if !self.rewards.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("rewards", pbjson::private::base64::encode(&self.rewards).as_str())?;
+        }
+        if !self.banking.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("banking", pbjson::private::base64::encode(&self.banking).as_str())?;
+        }
+        if !self.validators.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("validators", pbjson::private::base64::encode(&self.validators).as_str())?;
+        }
+        if !self.staking.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("staking", pbjson::private::base64::encode(&self.staking).as_str())?;
+        }
+        if !self.multisig_control.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("multisigControl", pbjson::private::base64::encode(&self.multisig_control).as_str())?;
+        }
+        if !self.market_tracker.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("marketTracker", pbjson::private::base64::encode(&self.market_tracker).as_str())?;
+        }
+        if !self.execution.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("execution", pbjson::private::base64::encode(&self.execution).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Checkpoint {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "governance",
+            "assets",
+            "collateral",
+            "network_parameters",
+            "networkParameters",
+            "delegation",
+            "epoch",
+            "block",
+            "rewards",
+            "banking",
+            "validators",
+            "staking",
+            "multisig_control",
+            "multisigControl",
+            "market_tracker",
+            "marketTracker",
+            "execution",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Governance,
+            Assets,
+            Collateral,
+            NetworkParameters,
+            Delegation,
+            Epoch,
+            Block,
+            Rewards,
+            Banking,
+            Validators,
+            Staking,
+            MultisigControl,
+            MarketTracker,
+            Execution,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "governance" => Ok(GeneratedField::Governance),
+                            "assets" => Ok(GeneratedField::Assets),
+                            "collateral" => Ok(GeneratedField::Collateral),
+                            "networkParameters" | "network_parameters" => Ok(GeneratedField::NetworkParameters),
+                            "delegation" => Ok(GeneratedField::Delegation),
+                            "epoch" => Ok(GeneratedField::Epoch),
+                            "block" => Ok(GeneratedField::Block),
+                            "rewards" => Ok(GeneratedField::Rewards),
+                            "banking" => Ok(GeneratedField::Banking),
+                            "validators" => Ok(GeneratedField::Validators),
+                            "staking" => Ok(GeneratedField::Staking),
+                            "multisigControl" | "multisig_control" => Ok(GeneratedField::MultisigControl),
+                            "marketTracker" | "market_tracker" => Ok(GeneratedField::MarketTracker),
+                            "execution" => Ok(GeneratedField::Execution),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Checkpoint;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.checkpoint.v1.Checkpoint")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Checkpoint, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut governance__ = None;
+                let mut assets__ = None;
+                let mut collateral__ = None;
+                let mut network_parameters__ = None;
+                let mut delegation__ = None;
+                let mut epoch__ = None;
+                let mut block__ = None;
+                let mut rewards__ = None;
+                let mut banking__ = None;
+                let mut validators__ = None;
+                let mut staking__ = None;
+                let mut multisig_control__ = None;
+                let mut market_tracker__ = None;
+                let mut execution__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Governance => {
+                            if governance__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("governance"));
+                            }
+                            governance__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Assets => {
+                            if assets__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("assets"));
+                            }
+                            assets__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Collateral => {
+                            if collateral__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("collateral"));
+                            }
+                            collateral__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::NetworkParameters => {
+                            if network_parameters__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("networkParameters"));
+                            }
+                            network_parameters__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Delegation => {
+                            if delegation__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("delegation"));
+                            }
+                            delegation__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Epoch => {
+                            if epoch__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epoch"));
+                            }
+                            epoch__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Block => {
+                            if block__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("block"));
+                            }
+                            block__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Rewards => {
+                            if rewards__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rewards"));
+                            }
+                            rewards__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Banking => {
+                            if banking__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("banking"));
+                            }
+                            banking__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Validators => {
+                            if validators__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("validators"));
+                            }
+                            validators__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Staking => {
+                            if staking__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("staking"));
+                            }
+                            staking__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::MultisigControl => {
+                            if multisig_control__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("multisigControl"));
+                            }
+                            multisig_control__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::MarketTracker => {
+                            if market_tracker__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("marketTracker"));
+                            }
+                            market_tracker__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Execution => {
+                            if execution__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("execution"));
+                            }
+                            execution__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(Checkpoint {
+                    governance: governance__.unwrap_or_default(),
+                    assets: assets__.unwrap_or_default(),
+                    collateral: collateral__.unwrap_or_default(),
+                    network_parameters: network_parameters__.unwrap_or_default(),
+                    delegation: delegation__.unwrap_or_default(),
+                    epoch: epoch__.unwrap_or_default(),
+                    block: block__.unwrap_or_default(),
+                    rewards: rewards__.unwrap_or_default(),
+                    banking: banking__.unwrap_or_default(),
+                    validators: validators__.unwrap_or_default(),
+                    staking: staking__.unwrap_or_default(),
+                    multisig_control: multisig_control__.unwrap_or_default(),
+                    market_tracker: market_tracker__.unwrap_or_default(),
+                    execution: execution__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.checkpoint.v1.Checkpoint", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for CheckpointState {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.hash.is_empty() {
+            len += 1;
+        }
+        if !self.state.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.checkpoint.v1.CheckpointState", len)?;
+        if !self.hash.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("hash", pbjson::private::base64::encode(&self.hash).as_str())?;
+        }
+        if !self.state.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("state", pbjson::private::base64::encode(&self.state).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for CheckpointState {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "hash",
+            "state",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Hash,
+            State,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "hash" => Ok(GeneratedField::Hash),
+                            "state" => Ok(GeneratedField::State),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CheckpointState;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.checkpoint.v1.CheckpointState")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CheckpointState, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut hash__ = None;
+                let mut state__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Hash => {
+                            if hash__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("hash"));
+                            }
+                            hash__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::State => {
+                            if state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("state"));
+                            }
+                            state__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(CheckpointState {
+                    hash: hash__.unwrap_or_default(),
+                    state: state__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.checkpoint.v1.CheckpointState", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Collateral {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.balances.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.checkpoint.v1.Collateral", len)?;
+        if !self.balances.is_empty() {
+            struct_ser.serialize_field("balances", &self.balances)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Collateral {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "balances",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Balances,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "balances" => Ok(GeneratedField::Balances),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Collateral;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.checkpoint.v1.Collateral")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Collateral, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut balances__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Balances => {
+                            if balances__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("balances"));
+                            }
+                            balances__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(Collateral {
+                    balances: balances__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.checkpoint.v1.Collateral", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Delegate {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.active.is_empty() {
+            len += 1;
+        }
+        if !self.pending.is_empty() {
+            len += 1;
+        }
+        if !self.auto_delegation.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.checkpoint.v1.Delegate", len)?;
+        if !self.active.is_empty() {
+            struct_ser.serialize_field("active", &self.active)?;
+        }
+        if !self.pending.is_empty() {
+            struct_ser.serialize_field("pending", &self.pending)?;
+        }
+        if !self.auto_delegation.is_empty() {
+            struct_ser.serialize_field("autoDelegation", &self.auto_delegation)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Delegate {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "active",
+            "pending",
+            "auto_delegation",
+            "autoDelegation",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Active,
+            Pending,
+            AutoDelegation,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "active" => Ok(GeneratedField::Active),
+                            "pending" => Ok(GeneratedField::Pending),
+                            "autoDelegation" | "auto_delegation" => Ok(GeneratedField::AutoDelegation),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Delegate;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.checkpoint.v1.Delegate")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Delegate, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut active__ = None;
+                let mut pending__ = None;
+                let mut auto_delegation__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Active => {
+                            if active__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("active"));
+                            }
+                            active__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Pending => {
+                            if pending__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pending"));
+                            }
+                            pending__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::AutoDelegation => {
+                            if auto_delegation__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("autoDelegation"));
+                            }
+                            auto_delegation__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(Delegate {
+                    active: active__.unwrap_or_default(),
+                    pending: pending__.unwrap_or_default(),
+                    auto_delegation: auto_delegation__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.checkpoint.v1.Delegate", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for DelegateEntry {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.party.is_empty() {
+            len += 1;
+        }
+        if !self.node.is_empty() {
+            len += 1;
+        }
+        if !self.amount.is_empty() {
+            len += 1;
+        }
+        if self.undelegate {
+            len += 1;
+        }
+        if self.epoch_seq != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.checkpoint.v1.DelegateEntry", len)?;
+        if !self.party.is_empty() {
+            struct_ser.serialize_field("party", &self.party)?;
+        }
+        if !self.node.is_empty() {
+            struct_ser.serialize_field("node", &self.node)?;
+        }
+        if !self.amount.is_empty() {
+            struct_ser.serialize_field("amount", &self.amount)?;
+        }
+        if self.undelegate {
+            struct_ser.serialize_field("undelegate", &self.undelegate)?;
+        }
+        if self.epoch_seq != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("epochSeq", ToString::to_string(&self.epoch_seq).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for DelegateEntry {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "party",
+            "node",
+            "amount",
+            "undelegate",
+            "epoch_seq",
+            "epochSeq",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Party,
+            Node,
+            Amount,
+            Undelegate,
+            EpochSeq,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "party" => Ok(GeneratedField::Party),
+                            "node" => Ok(GeneratedField::Node),
+                            "amount" => Ok(GeneratedField::Amount),
+                            "undelegate" => Ok(GeneratedField::Undelegate),
+                            "epochSeq" | "epoch_seq" => Ok(GeneratedField::EpochSeq),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = DelegateEntry;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.checkpoint.v1.DelegateEntry")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DelegateEntry, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut party__ = None;
+                let mut node__ = None;
+                let mut amount__ = None;
+                let mut undelegate__ = None;
+                let mut epoch_seq__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Party => {
+                            if party__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("party"));
+                            }
+                            party__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Node => {
+                            if node__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("node"));
+                            }
+                            node__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Amount => {
+                            if amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amount"));
+                            }
+                            amount__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Undelegate => {
+                            if undelegate__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("undelegate"));
+                            }
+                            undelegate__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::EpochSeq => {
+                            if epoch_seq__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epochSeq"));
+                            }
+                            epoch_seq__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(DelegateEntry {
+                    party: party__.unwrap_or_default(),
+                    node: node__.unwrap_or_default(),
+                    amount: amount__.unwrap_or_default(),
+                    undelegate: undelegate__.unwrap_or_default(),
+                    epoch_seq: epoch_seq__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.checkpoint.v1.DelegateEntry", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ElsShare {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.party_id.is_empty() {
+            len += 1;
+        }
+        if !self.share.is_empty() {
+            len += 1;
+        }
+        if !self.supplied_stake.is_empty() {
+            len += 1;
+        }
+        if !self.virtual_stake.is_empty() {
+            len += 1;
+        }
+        if !self.avg.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.checkpoint.v1.ELSShare", len)?;
+        if !self.party_id.is_empty() {
+            struct_ser.serialize_field("partyId", &self.party_id)?;
+        }
+        if !self.share.is_empty() {
+            struct_ser.serialize_field("share", &self.share)?;
+        }
+        if !self.supplied_stake.is_empty() {
+            struct_ser.serialize_field("suppliedStake", &self.supplied_stake)?;
+        }
+        if !self.virtual_stake.is_empty() {
+            struct_ser.serialize_field("virtualStake", &self.virtual_stake)?;
+        }
+        if !self.avg.is_empty() {
+            struct_ser.serialize_field("avg", &self.avg)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ElsShare {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "party_id",
+            "partyId",
+            "share",
+            "supplied_stake",
+            "suppliedStake",
+            "virtual_stake",
+            "virtualStake",
+            "avg",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PartyId,
+            Share,
+            SuppliedStake,
+            VirtualStake,
+            Avg,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "partyId" | "party_id" => Ok(GeneratedField::PartyId),
+                            "share" => Ok(GeneratedField::Share),
+                            "suppliedStake" | "supplied_stake" => Ok(GeneratedField::SuppliedStake),
+                            "virtualStake" | "virtual_stake" => Ok(GeneratedField::VirtualStake),
+                            "avg" => Ok(GeneratedField::Avg),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ElsShare;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.checkpoint.v1.ELSShare")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ElsShare, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut party_id__ = None;
+                let mut share__ = None;
+                let mut supplied_stake__ = None;
+                let mut virtual_stake__ = None;
+                let mut avg__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PartyId => {
+                            if party_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("partyId"));
+                            }
+                            party_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Share => {
+                            if share__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("share"));
+                            }
+                            share__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::SuppliedStake => {
+                            if supplied_stake__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("suppliedStake"));
+                            }
+                            supplied_stake__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::VirtualStake => {
+                            if virtual_stake__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("virtualStake"));
+                            }
+                            virtual_stake__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Avg => {
+                            if avg__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("avg"));
+                            }
+                            avg__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(ElsShare {
+                    party_id: party_id__.unwrap_or_default(),
+                    share: share__.unwrap_or_default(),
+                    supplied_stake: supplied_stake__.unwrap_or_default(),
+                    virtual_stake: virtual_stake__.unwrap_or_default(),
+                    avg: avg__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.checkpoint.v1.ELSShare", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EpochEligibility {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.eligible_parties.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.checkpoint.v1.EpochEligibility", len)?;
+        if !self.eligible_parties.is_empty() {
+            struct_ser.serialize_field("eligibleParties", &self.eligible_parties)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EpochEligibility {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "eligible_parties",
+            "eligibleParties",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            EligibleParties,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "eligibleParties" | "eligible_parties" => Ok(GeneratedField::EligibleParties),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EpochEligibility;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.checkpoint.v1.EpochEligibility")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EpochEligibility, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut eligible_parties__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::EligibleParties => {
+                            if eligible_parties__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("eligibleParties"));
+                            }
+                            eligible_parties__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(EpochEligibility {
+                    eligible_parties: eligible_parties__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.checkpoint.v1.EpochEligibility", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EpochPartyFees {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.party_fees.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.checkpoint.v1.EpochPartyFees", len)?;
+        if !self.party_fees.is_empty() {
+            struct_ser.serialize_field("partyFees", &self.party_fees)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EpochPartyFees {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "party_fees",
+            "partyFees",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PartyFees,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "partyFees" | "party_fees" => Ok(GeneratedField::PartyFees),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EpochPartyFees;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.checkpoint.v1.EpochPartyFees")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EpochPartyFees, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut party_fees__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PartyFees => {
+                            if party_fees__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("partyFees"));
+                            }
+                            party_fees__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(EpochPartyFees {
+                    party_fees: party_fees__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.checkpoint.v1.EpochPartyFees", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EpochPartyTakerFees {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.epoch_party_taker_fees_paid.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.checkpoint.v1.EpochPartyTakerFees", len)?;
+        if !self.epoch_party_taker_fees_paid.is_empty() {
+            struct_ser.serialize_field("epochPartyTakerFeesPaid", &self.epoch_party_taker_fees_paid)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EpochPartyTakerFees {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "epoch_party_taker_fees_paid",
+            "epochPartyTakerFeesPaid",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            EpochPartyTakerFeesPaid,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "epochPartyTakerFeesPaid" | "epoch_party_taker_fees_paid" => Ok(GeneratedField::EpochPartyTakerFeesPaid),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EpochPartyTakerFees;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.checkpoint.v1.EpochPartyTakerFees")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EpochPartyTakerFees, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut epoch_party_taker_fees_paid__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::EpochPartyTakerFeesPaid => {
+                            if epoch_party_taker_fees_paid__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epochPartyTakerFeesPaid"));
+                            }
+                            epoch_party_taker_fees_paid__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(EpochPartyTakerFees {
+                    epoch_party_taker_fees_paid: epoch_party_taker_fees_paid__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.checkpoint.v1.EpochPartyTakerFees", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EpochReturnsData {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.returns.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.checkpoint.v1.EpochReturnsData", len)?;
+        if !self.returns.is_empty() {
+            struct_ser.serialize_field("returns", &self.returns)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EpochReturnsData {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "returns",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Returns,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "returns" => Ok(GeneratedField::Returns),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EpochReturnsData;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.checkpoint.v1.EpochReturnsData")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EpochReturnsData, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut returns__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Returns => {
+                            if returns__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("returns"));
+                            }
+                            returns__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(EpochReturnsData {
+                    returns: returns__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.checkpoint.v1.EpochReturnsData", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EpochTimeWeightPositionData {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.party_time_weighted_positions.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.checkpoint.v1.EpochTimeWeightPositionData", len)?;
+        if !self.party_time_weighted_positions.is_empty() {
+            struct_ser.serialize_field("partyTimeWeightedPositions", &self.party_time_weighted_positions)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EpochTimeWeightPositionData {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "party_time_weighted_positions",
+            "partyTimeWeightedPositions",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PartyTimeWeightedPositions,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "partyTimeWeightedPositions" | "party_time_weighted_positions" => Ok(GeneratedField::PartyTimeWeightedPositions),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EpochTimeWeightPositionData;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.checkpoint.v1.EpochTimeWeightPositionData")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EpochTimeWeightPositionData, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut party_time_weighted_positions__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PartyTimeWeightedPositions => {
+                            if party_time_weighted_positions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("partyTimeWeightedPositions"));
+                            }
+                            party_time_weighted_positions__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(EpochTimeWeightPositionData {
+                    party_time_weighted_positions: party_time_weighted_positions__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.checkpoint.v1.EpochTimeWeightPositionData", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EpochTimeWeightedNotionalData {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.party_time_weighted_notionals.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.checkpoint.v1.EpochTimeWeightedNotionalData", len)?;
+        if !self.party_time_weighted_notionals.is_empty() {
+            struct_ser.serialize_field("partyTimeWeightedNotionals", &self.party_time_weighted_notionals)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EpochTimeWeightedNotionalData {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "party_time_weighted_notionals",
+            "partyTimeWeightedNotionals",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PartyTimeWeightedNotionals,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "partyTimeWeightedNotionals" | "party_time_weighted_notionals" => Ok(GeneratedField::PartyTimeWeightedNotionals),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EpochTimeWeightedNotionalData;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.checkpoint.v1.EpochTimeWeightedNotionalData")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EpochTimeWeightedNotionalData, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut party_time_weighted_notionals__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PartyTimeWeightedNotionals => {
+                            if party_time_weighted_notionals__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("partyTimeWeightedNotionals"));
+                            }
+                            party_time_weighted_notionals__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(EpochTimeWeightedNotionalData {
+                    party_time_weighted_notionals: party_time_weighted_notionals__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.checkpoint.v1.EpochTimeWeightedNotionalData", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ExecutionState {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.data.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.checkpoint.v1.ExecutionState", len)?;
+        if !self.data.is_empty() {
+            struct_ser.serialize_field("data", &self.data)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ExecutionState {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "data",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Data,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "data" => Ok(GeneratedField::Data),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ExecutionState;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.checkpoint.v1.ExecutionState")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ExecutionState, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut data__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Data => {
+                            if data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("data"));
+                            }
+                            data__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(ExecutionState {
+                    data: data__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.checkpoint.v1.ExecutionState", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for GameEligibilityTracker {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.game_id.is_empty() {
+            len += 1;
+        }
+        if !self.epoch_eligibility.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.checkpoint.v1.GameEligibilityTracker", len)?;
+        if !self.game_id.is_empty() {
+            struct_ser.serialize_field("gameId", &self.game_id)?;
+        }
+        if !self.epoch_eligibility.is_empty() {
+            struct_ser.serialize_field("epochEligibility", &self.epoch_eligibility)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GameEligibilityTracker {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "game_id",
+            "gameId",
+            "epoch_eligibility",
+            "epochEligibility",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            GameId,
+            EpochEligibility,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "gameId" | "game_id" => Ok(GeneratedField::GameId),
+                            "epochEligibility" | "epoch_eligibility" => Ok(GeneratedField::EpochEligibility),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GameEligibilityTracker;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.checkpoint.v1.GameEligibilityTracker")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GameEligibilityTracker, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut game_id__ = None;
+                let mut epoch_eligibility__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::GameId => {
+                            if game_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("gameId"));
+                            }
+                            game_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::EpochEligibility => {
+                            if epoch_eligibility__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epochEligibility"));
+                            }
+                            epoch_eligibility__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(GameEligibilityTracker {
+                    game_id: game_id__.unwrap_or_default(),
+                    epoch_eligibility: epoch_eligibility__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.checkpoint.v1.GameEligibilityTracker", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for GovernanceTransfer {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.id.is_empty() {
+            len += 1;
+        }
+        if !self.reference.is_empty() {
+            len += 1;
+        }
+        if self.status != 0 {
+            len += 1;
+        }
+        if self.timestamp != 0 {
+            len += 1;
+        }
+        if self.config.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.checkpoint.v1.GovernanceTransfer", len)?;
+        if !self.id.is_empty() {
+            struct_ser.serialize_field("id", &self.id)?;
+        }
+        if !self.reference.is_empty() {
+            struct_ser.serialize_field("reference", &self.reference)?;
+        }
+        if self.status != 0 {
+            let v = super::super::events::v1::transfer::Status::try_from(self.status)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.status)))?;
+            struct_ser.serialize_field("status", &v)?;
+        }
+        if self.timestamp != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("timestamp", ToString::to_string(&self.timestamp).as_str())?;
+        }
+        if let Some(v) = self.config.as_ref() {
+            struct_ser.serialize_field("config", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GovernanceTransfer {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "id",
+            "reference",
+            "status",
+            "timestamp",
+            "config",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Id,
+            Reference,
+            Status,
+            Timestamp,
+            Config,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "id" => Ok(GeneratedField::Id),
+                            "reference" => Ok(GeneratedField::Reference),
+                            "status" => Ok(GeneratedField::Status),
+                            "timestamp" => Ok(GeneratedField::Timestamp),
+                            "config" => Ok(GeneratedField::Config),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GovernanceTransfer;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.checkpoint.v1.GovernanceTransfer")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GovernanceTransfer, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut id__ = None;
+                let mut reference__ = None;
+                let mut status__ = None;
+                let mut timestamp__ = None;
+                let mut config__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Reference => {
+                            if reference__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reference"));
+                            }
+                            reference__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Status => {
+                            if status__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("status"));
+                            }
+                            status__ = Some(map_.next_value::<super::super::events::v1::transfer::Status>()? as i32);
+                        }
+                        GeneratedField::Timestamp => {
+                            if timestamp__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timestamp"));
+                            }
+                            timestamp__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Config => {
+                            if config__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("config"));
+                            }
+                            config__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(GovernanceTransfer {
+                    id: id__.unwrap_or_default(),
+                    reference: reference__.unwrap_or_default(),
+                    status: status__.unwrap_or_default(),
+                    timestamp: timestamp__.unwrap_or_default(),
+                    config: config__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.checkpoint.v1.GovernanceTransfer", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for MarketActivityTracker {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.market.is_empty() {
+            len += 1;
+        }
+        if !self.asset.is_empty() {
+            len += 1;
+        }
+        if !self.maker_fees_received.is_empty() {
+            len += 1;
+        }
+        if !self.maker_fees_paid.is_empty() {
+            len += 1;
+        }
+        if !self.lp_fees.is_empty() {
+            len += 1;
+        }
+        if !self.proposer.is_empty() {
+            len += 1;
+        }
+        if !self.bonus_paid.is_empty() {
+            len += 1;
+        }
+        if !self.value_traded.is_empty() {
+            len += 1;
+        }
+        if self.ready_to_delete {
+            len += 1;
+        }
+        if !self.time_weighted_position.is_empty() {
+            len += 1;
+        }
+        if !self.time_weighted_notional.is_empty() {
+            len += 1;
+        }
+        if !self.returns_data.is_empty() {
+            len += 1;
+        }
+        if !self.maker_fees_received_history.is_empty() {
+            len += 1;
+        }
+        if !self.maker_fees_paid_history.is_empty() {
+            len += 1;
+        }
+        if !self.lp_fees_history.is_empty() {
+            len += 1;
+        }
+        if !self.time_weighted_position_data_history.is_empty() {
+            len += 1;
+        }
+        if !self.time_weighted_notional_data_history.is_empty() {
+            len += 1;
+        }
+        if !self.returns_data_history.is_empty() {
+            len += 1;
+        }
+        if !self.infra_fees.is_empty() {
+            len += 1;
+        }
+        if !self.lp_paid_fees.is_empty() {
+            len += 1;
+        }
+        if !self.realised_returns.is_empty() {
+            len += 1;
+        }
+        if !self.realised_returns_history.is_empty() {
+            len += 1;
+        }
+        if !self.amm_parties.is_empty() {
+            len += 1;
+        }
+        if !self.buy_back_fees.is_empty() {
+            len += 1;
+        }
+        if !self.treasury_fees.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.checkpoint.v1.MarketActivityTracker", len)?;
+        if !self.market.is_empty() {
+            struct_ser.serialize_field("market", &self.market)?;
+        }
+        if !self.asset.is_empty() {
+            struct_ser.serialize_field("asset", &self.asset)?;
+        }
+        if !self.maker_fees_received.is_empty() {
+            struct_ser.serialize_field("makerFeesReceived", &self.maker_fees_received)?;
+        }
+        if !self.maker_fees_paid.is_empty() {
+            struct_ser.serialize_field("makerFeesPaid", &self.maker_fees_paid)?;
+        }
+        if !self.lp_fees.is_empty() {
+            struct_ser.serialize_field("lpFees", &self.lp_fees)?;
+        }
+        if !self.proposer.is_empty() {
+            struct_ser.serialize_field("proposer", &self.proposer)?;
+        }
+        if !self.bonus_paid.is_empty() {
+            struct_ser.serialize_field("bonusPaid", &self.bonus_paid)?;
+        }
+        if !self.value_traded.is_empty() {
+            struct_ser.serialize_field("valueTraded", &self.value_traded)?;
+        }
+        if self.ready_to_delete {
+            struct_ser.serialize_field("readyToDelete", &self.ready_to_delete)?;
+        }
+        if !self.time_weighted_position.is_empty() {
+            struct_ser.serialize_field("timeWeightedPosition", &self.time_weighted_position)?;
+        }
+        if !self.time_weighted_notional.is_empty() {
+            struct_ser.serialize_field("timeWeightedNotional", &self.time_weighted_notional)?;
+        }
+        if !self.returns_data.is_empty() {
+            struct_ser.serialize_field("returnsData", &self.returns_data)?;
+        }
+        if !self.maker_fees_received_history.is_empty() {
+            struct_ser.serialize_field("makerFeesReceivedHistory", &self.maker_fees_received_history)?;
+        }
+        if !self.maker_fees_paid_history.is_empty() {
+            struct_ser.serialize_field("makerFeesPaidHistory", &self.maker_fees_paid_history)?;
+        }
+        if !self.lp_fees_history.is_empty() {
+            struct_ser.serialize_field("lpFeesHistory", &self.lp_fees_history)?;
+        }
+        if !self.time_weighted_position_data_history.is_empty() {
+            struct_ser.serialize_field("timeWeightedPositionDataHistory", &self.time_weighted_position_data_history)?;
+        }
+        if !self.time_weighted_notional_data_history.is_empty() {
+            struct_ser.serialize_field("timeWeightedNotionalDataHistory", &self.time_weighted_notional_data_history)?;
+        }
+        if !self.returns_data_history.is_empty() {
+            struct_ser.serialize_field("returnsDataHistory", &self.returns_data_history)?;
+        }
+        if !self.infra_fees.is_empty() {
+            struct_ser.serialize_field("infraFees", &self.infra_fees)?;
+        }
+        if !self.lp_paid_fees.is_empty() {
+            struct_ser.serialize_field("lpPaidFees", &self.lp_paid_fees)?;
+        }
+        if !self.realised_returns.is_empty() {
+            struct_ser.serialize_field("realisedReturns", &self.realised_returns)?;
+        }
+        if !self.realised_returns_history.is_empty() {
+            struct_ser.serialize_field("realisedReturnsHistory", &self.realised_returns_history)?;
+        }
+        if !self.amm_parties.is_empty() {
+            struct_ser.serialize_field("ammParties", &self.amm_parties)?;
+        }
+        if !self.buy_back_fees.is_empty() {
+            struct_ser.serialize_field("buyBackFees", &self.buy_back_fees)?;
+        }
+        if !self.treasury_fees.is_empty() {
+            struct_ser.serialize_field("treasuryFees", &self.treasury_fees)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for MarketActivityTracker {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "market",
+            "asset",
+            "maker_fees_received",
+            "makerFeesReceived",
+            "maker_fees_paid",
+            "makerFeesPaid",
+            "lp_fees",
+            "lpFees",
+            "proposer",
+            "bonus_paid",
+            "bonusPaid",
+            "value_traded",
+            "valueTraded",
+            "ready_to_delete",
+            "readyToDelete",
+            "time_weighted_position",
+            "timeWeightedPosition",
+            "time_weighted_notional",
+            "timeWeightedNotional",
+            "returns_data",
+            "returnsData",
+            "maker_fees_received_history",
+            "makerFeesReceivedHistory",
+            "maker_fees_paid_history",
+            "makerFeesPaidHistory",
+            "lp_fees_history",
+            "lpFeesHistory",
+            "time_weighted_position_data_history",
+            "timeWeightedPositionDataHistory",
+            "time_weighted_notional_data_history",
+            "timeWeightedNotionalDataHistory",
+            "returns_data_history",
+            "returnsDataHistory",
+            "infra_fees",
+            "infraFees",
+            "lp_paid_fees",
+            "lpPaidFees",
+            "realised_returns",
+            "realisedReturns",
+            "realised_returns_history",
+            "realisedReturnsHistory",
+            "amm_parties",
+            "ammParties",
+            "buy_back_fees",
+            "buyBackFees",
+            "treasury_fees",
+            "treasuryFees",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Market,
+            Asset,
+            MakerFeesReceived,
+            MakerFeesPaid,
+            LpFees,
+            Proposer,
+            BonusPaid,
+            ValueTraded,
+            ReadyToDelete,
+            TimeWeightedPosition,
+            TimeWeightedNotional,
+            ReturnsData,
+            MakerFeesReceivedHistory,
+            MakerFeesPaidHistory,
+            LpFeesHistory,
+            TimeWeightedPositionDataHistory,
+            TimeWeightedNotionalDataHistory,
+            ReturnsDataHistory,
+            InfraFees,
+            LpPaidFees,
+            RealisedReturns,
+            RealisedReturnsHistory,
+            AmmParties,
+            BuyBackFees,
+            TreasuryFees,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "market" => Ok(GeneratedField::Market),
+                            "asset" => Ok(GeneratedField::Asset),
+                            "makerFeesReceived" | "maker_fees_received" => Ok(GeneratedField::MakerFeesReceived),
+                            "makerFeesPaid" | "maker_fees_paid" => Ok(GeneratedField::MakerFeesPaid),
+                            "lpFees" | "lp_fees" => Ok(GeneratedField::LpFees),
+                            "proposer" => Ok(GeneratedField::Proposer),
+                            "bonusPaid" | "bonus_paid" => Ok(GeneratedField::BonusPaid),
+                            "valueTraded" | "value_traded" => Ok(GeneratedField::ValueTraded),
+                            "readyToDelete" | "ready_to_delete" => Ok(GeneratedField::ReadyToDelete),
+                            "timeWeightedPosition" | "time_weighted_position" => Ok(GeneratedField::TimeWeightedPosition),
+                            "timeWeightedNotional" | "time_weighted_notional" => Ok(GeneratedField::TimeWeightedNotional),
+                            "returnsData" | "returns_data" => Ok(GeneratedField::ReturnsData),
+                            "makerFeesReceivedHistory" | "maker_fees_received_history" => Ok(GeneratedField::MakerFeesReceivedHistory),
+                            "makerFeesPaidHistory" | "maker_fees_paid_history" => Ok(GeneratedField::MakerFeesPaidHistory),
+                            "lpFeesHistory" | "lp_fees_history" => Ok(GeneratedField::LpFeesHistory),
+                            "timeWeightedPositionDataHistory" | "time_weighted_position_data_history" => Ok(GeneratedField::TimeWeightedPositionDataHistory),
+                            "timeWeightedNotionalDataHistory" | "time_weighted_notional_data_history" => Ok(GeneratedField::TimeWeightedNotionalDataHistory),
+                            "returnsDataHistory" | "returns_data_history" => Ok(GeneratedField::ReturnsDataHistory),
+                            "infraFees" | "infra_fees" => Ok(GeneratedField::InfraFees),
+                            "lpPaidFees" | "lp_paid_fees" => Ok(GeneratedField::LpPaidFees),
+                            "realisedReturns" | "realised_returns" => Ok(GeneratedField::RealisedReturns),
+                            "realisedReturnsHistory" | "realised_returns_history" => Ok(GeneratedField::RealisedReturnsHistory),
+                            "ammParties" | "amm_parties" => Ok(GeneratedField::AmmParties),
+                            "buyBackFees" | "buy_back_fees" => Ok(GeneratedField::BuyBackFees),
+                            "treasuryFees" | "treasury_fees" => Ok(GeneratedField::TreasuryFees),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = MarketActivityTracker;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.checkpoint.v1.MarketActivityTracker")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<MarketActivityTracker, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut market__ = None;
+                let mut asset__ = None;
+                let mut maker_fees_received__ = None;
+                let mut maker_fees_paid__ = None;
+                let mut lp_fees__ = None;
+                let mut proposer__ = None;
+                let mut bonus_paid__ = None;
+                let mut value_traded__ = None;
+                let mut ready_to_delete__ = None;
+                let mut time_weighted_position__ = None;
+                let mut time_weighted_notional__ = None;
+                let mut returns_data__ = None;
+                let mut maker_fees_received_history__ = None;
+                let mut maker_fees_paid_history__ = None;
+                let mut lp_fees_history__ = None;
+                let mut time_weighted_position_data_history__ = None;
+                let mut time_weighted_notional_data_history__ = None;
+                let mut returns_data_history__ = None;
+                let mut infra_fees__ = None;
+                let mut lp_paid_fees__ = None;
+                let mut realised_returns__ = None;
+                let mut realised_returns_history__ = None;
+                let mut amm_parties__ = None;
+                let mut buy_back_fees__ = None;
+                let mut treasury_fees__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Market => {
+                            if market__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("market"));
+                            }
+                            market__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Asset => {
+                            if asset__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("asset"));
+                            }
+                            asset__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::MakerFeesReceived => {
+                            if maker_fees_received__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("makerFeesReceived"));
+                            }
+                            maker_fees_received__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::MakerFeesPaid => {
+                            if maker_fees_paid__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("makerFeesPaid"));
+                            }
+                            maker_fees_paid__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::LpFees => {
+                            if lp_fees__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lpFees"));
+                            }
+                            lp_fees__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Proposer => {
+                            if proposer__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposer"));
+                            }
+                            proposer__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::BonusPaid => {
+                            if bonus_paid__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("bonusPaid"));
+                            }
+                            bonus_paid__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ValueTraded => {
+                            if value_traded__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("valueTraded"));
+                            }
+                            value_traded__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ReadyToDelete => {
+                            if ready_to_delete__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("readyToDelete"));
+                            }
+                            ready_to_delete__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::TimeWeightedPosition => {
+                            if time_weighted_position__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timeWeightedPosition"));
+                            }
+                            time_weighted_position__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::TimeWeightedNotional => {
+                            if time_weighted_notional__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timeWeightedNotional"));
+                            }
+                            time_weighted_notional__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ReturnsData => {
+                            if returns_data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("returnsData"));
+                            }
+                            returns_data__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::MakerFeesReceivedHistory => {
+                            if maker_fees_received_history__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("makerFeesReceivedHistory"));
+                            }
+                            maker_fees_received_history__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::MakerFeesPaidHistory => {
+                            if maker_fees_paid_history__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("makerFeesPaidHistory"));
+                            }
+                            maker_fees_paid_history__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::LpFeesHistory => {
+                            if lp_fees_history__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lpFeesHistory"));
+                            }
+                            lp_fees_history__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::TimeWeightedPositionDataHistory => {
+                            if time_weighted_position_data_history__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timeWeightedPositionDataHistory"));
+                            }
+                            time_weighted_position_data_history__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::TimeWeightedNotionalDataHistory => {
+                            if time_weighted_notional_data_history__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timeWeightedNotionalDataHistory"));
+                            }
+                            time_weighted_notional_data_history__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ReturnsDataHistory => {
+                            if returns_data_history__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("returnsDataHistory"));
+                            }
+                            returns_data_history__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::InfraFees => {
+                            if infra_fees__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("infraFees"));
+                            }
+                            infra_fees__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::LpPaidFees => {
+                            if lp_paid_fees__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lpPaidFees"));
+                            }
+                            lp_paid_fees__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::RealisedReturns => {
+                            if realised_returns__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("realisedReturns"));
+                            }
+                            realised_returns__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::RealisedReturnsHistory => {
+                            if realised_returns_history__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("realisedReturnsHistory"));
+                            }
+                            realised_returns_history__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::AmmParties => {
+                            if amm_parties__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ammParties"));
+                            }
+                            amm_parties__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::BuyBackFees => {
+                            if buy_back_fees__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("buyBackFees"));
+                            }
+                            buy_back_fees__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::TreasuryFees => {
+                            if treasury_fees__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("treasuryFees"));
+                            }
+                            treasury_fees__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(MarketActivityTracker {
+                    market: market__.unwrap_or_default(),
+                    asset: asset__.unwrap_or_default(),
+                    maker_fees_received: maker_fees_received__.unwrap_or_default(),
+                    maker_fees_paid: maker_fees_paid__.unwrap_or_default(),
+                    lp_fees: lp_fees__.unwrap_or_default(),
+                    proposer: proposer__.unwrap_or_default(),
+                    bonus_paid: bonus_paid__.unwrap_or_default(),
+                    value_traded: value_traded__.unwrap_or_default(),
+                    ready_to_delete: ready_to_delete__.unwrap_or_default(),
+                    time_weighted_position: time_weighted_position__.unwrap_or_default(),
+                    time_weighted_notional: time_weighted_notional__.unwrap_or_default(),
+                    returns_data: returns_data__.unwrap_or_default(),
+                    maker_fees_received_history: maker_fees_received_history__.unwrap_or_default(),
+                    maker_fees_paid_history: maker_fees_paid_history__.unwrap_or_default(),
+                    lp_fees_history: lp_fees_history__.unwrap_or_default(),
+                    time_weighted_position_data_history: time_weighted_position_data_history__.unwrap_or_default(),
+                    time_weighted_notional_data_history: time_weighted_notional_data_history__.unwrap_or_default(),
+                    returns_data_history: returns_data_history__.unwrap_or_default(),
+                    infra_fees: infra_fees__.unwrap_or_default(),
+                    lp_paid_fees: lp_paid_fees__.unwrap_or_default(),
+                    realised_returns: realised_returns__.unwrap_or_default(),
+                    realised_returns_history: realised_returns_history__.unwrap_or_default(),
+                    amm_parties: amm_parties__.unwrap_or_default(),
+                    buy_back_fees: buy_back_fees__.unwrap_or_default(),
+                    treasury_fees: treasury_fees__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.checkpoint.v1.MarketActivityTracker", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for MarketState {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.id.is_empty() {
+            len += 1;
+        }
+        if !self.shares.is_empty() {
+            len += 1;
+        }
+        if !self.insurance_balance.is_empty() {
+            len += 1;
+        }
+        if !self.last_trade_value.is_empty() {
+            len += 1;
+        }
+        if !self.last_trade_volume.is_empty() {
+            len += 1;
+        }
+        if self.succession_window != 0 {
+            len += 1;
+        }
+        if self.market.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.checkpoint.v1.MarketState", len)?;
+        if !self.id.is_empty() {
+            struct_ser.serialize_field("id", &self.id)?;
+        }
+        if !self.shares.is_empty() {
+            struct_ser.serialize_field("shares", &self.shares)?;
+        }
+        if !self.insurance_balance.is_empty() {
+            struct_ser.serialize_field("insuranceBalance", &self.insurance_balance)?;
+        }
+        if !self.last_trade_value.is_empty() {
+            struct_ser.serialize_field("lastTradeValue", &self.last_trade_value)?;
+        }
+        if !self.last_trade_volume.is_empty() {
+            struct_ser.serialize_field("lastTradeVolume", &self.last_trade_volume)?;
+        }
+        if self.succession_window != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("successionWindow", ToString::to_string(&self.succession_window).as_str())?;
+        }
+        if let Some(v) = self.market.as_ref() {
+            struct_ser.serialize_field("market", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for MarketState {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "id",
+            "shares",
+            "insurance_balance",
+            "insuranceBalance",
+            "last_trade_value",
+            "lastTradeValue",
+            "last_trade_volume",
+            "lastTradeVolume",
+            "succession_window",
+            "successionWindow",
+            "market",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Id,
+            Shares,
+            InsuranceBalance,
+            LastTradeValue,
+            LastTradeVolume,
+            SuccessionWindow,
+            Market,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "id" => Ok(GeneratedField::Id),
+                            "shares" => Ok(GeneratedField::Shares),
+                            "insuranceBalance" | "insurance_balance" => Ok(GeneratedField::InsuranceBalance),
+                            "lastTradeValue" | "last_trade_value" => Ok(GeneratedField::LastTradeValue),
+                            "lastTradeVolume" | "last_trade_volume" => Ok(GeneratedField::LastTradeVolume),
+                            "successionWindow" | "succession_window" => Ok(GeneratedField::SuccessionWindow),
+                            "market" => Ok(GeneratedField::Market),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = MarketState;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.checkpoint.v1.MarketState")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<MarketState, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut id__ = None;
+                let mut shares__ = None;
+                let mut insurance_balance__ = None;
+                let mut last_trade_value__ = None;
+                let mut last_trade_volume__ = None;
+                let mut succession_window__ = None;
+                let mut market__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Shares => {
+                            if shares__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("shares"));
+                            }
+                            shares__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::InsuranceBalance => {
+                            if insurance_balance__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("insuranceBalance"));
+                            }
+                            insurance_balance__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::LastTradeValue => {
+                            if last_trade_value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastTradeValue"));
+                            }
+                            last_trade_value__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::LastTradeVolume => {
+                            if last_trade_volume__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastTradeVolume"));
+                            }
+                            last_trade_volume__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::SuccessionWindow => {
+                            if succession_window__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("successionWindow"));
+                            }
+                            succession_window__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Market => {
+                            if market__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("market"));
+                            }
+                            market__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(MarketState {
+                    id: id__.unwrap_or_default(),
+                    shares: shares__.unwrap_or_default(),
+                    insurance_balance: insurance_balance__.unwrap_or_default(),
+                    last_trade_value: last_trade_value__.unwrap_or_default(),
+                    last_trade_volume: last_trade_volume__.unwrap_or_default(),
+                    succession_window: succession_window__.unwrap_or_default(),
+                    market: market__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.checkpoint.v1.MarketState", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for MarketToPartyTakerNotionalVolume {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.market.is_empty() {
+            len += 1;
+        }
+        if !self.taker_notional_volume.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.checkpoint.v1.MarketToPartyTakerNotionalVolume", len)?;
+        if !self.market.is_empty() {
+            struct_ser.serialize_field("market", &self.market)?;
+        }
+        if !self.taker_notional_volume.is_empty() {
+            struct_ser.serialize_field("takerNotionalVolume", &self.taker_notional_volume)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for MarketToPartyTakerNotionalVolume {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "market",
+            "taker_notional_volume",
+            "takerNotionalVolume",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Market,
+            TakerNotionalVolume,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "market" => Ok(GeneratedField::Market),
+                            "takerNotionalVolume" | "taker_notional_volume" => Ok(GeneratedField::TakerNotionalVolume),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = MarketToPartyTakerNotionalVolume;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.checkpoint.v1.MarketToPartyTakerNotionalVolume")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<MarketToPartyTakerNotionalVolume, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut market__ = None;
+                let mut taker_notional_volume__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Market => {
+                            if market__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("market"));
+                            }
+                            market__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::TakerNotionalVolume => {
+                            if taker_notional_volume__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("takerNotionalVolume"));
+                            }
+                            taker_notional_volume__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(MarketToPartyTakerNotionalVolume {
+                    market: market__.unwrap_or_default(),
+                    taker_notional_volume: taker_notional_volume__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.checkpoint.v1.MarketToPartyTakerNotionalVolume", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for MarketTracker {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.market_activity.is_empty() {
+            len += 1;
+        }
+        if !self.taker_notional_volume.is_empty() {
+            len += 1;
+        }
+        if !self.market_to_party_taker_notional_volume.is_empty() {
+            len += 1;
+        }
+        if !self.epoch_taker_fees.is_empty() {
+            len += 1;
+        }
+        if !self.game_eligibility_tracker.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.checkpoint.v1.MarketTracker", len)?;
+        if !self.market_activity.is_empty() {
+            struct_ser.serialize_field("marketActivity", &self.market_activity)?;
+        }
+        if !self.taker_notional_volume.is_empty() {
+            struct_ser.serialize_field("takerNotionalVolume", &self.taker_notional_volume)?;
+        }
+        if !self.market_to_party_taker_notional_volume.is_empty() {
+            struct_ser.serialize_field("marketToPartyTakerNotionalVolume", &self.market_to_party_taker_notional_volume)?;
+        }
+        if !self.epoch_taker_fees.is_empty() {
+            struct_ser.serialize_field("epochTakerFees", &self.epoch_taker_fees)?;
+        }
+        if !self.game_eligibility_tracker.is_empty() {
+            struct_ser.serialize_field("gameEligibilityTracker", &self.game_eligibility_tracker)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for MarketTracker {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "market_activity",
+            "marketActivity",
+            "taker_notional_volume",
+            "takerNotionalVolume",
+            "market_to_party_taker_notional_volume",
+            "marketToPartyTakerNotionalVolume",
+            "epoch_taker_fees",
+            "epochTakerFees",
+            "game_eligibility_tracker",
+            "gameEligibilityTracker",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            MarketActivity,
+            TakerNotionalVolume,
+            MarketToPartyTakerNotionalVolume,
+            EpochTakerFees,
+            GameEligibilityTracker,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "marketActivity" | "market_activity" => Ok(GeneratedField::MarketActivity),
+                            "takerNotionalVolume" | "taker_notional_volume" => Ok(GeneratedField::TakerNotionalVolume),
+                            "marketToPartyTakerNotionalVolume" | "market_to_party_taker_notional_volume" => Ok(GeneratedField::MarketToPartyTakerNotionalVolume),
+                            "epochTakerFees" | "epoch_taker_fees" => Ok(GeneratedField::EpochTakerFees),
+                            "gameEligibilityTracker" | "game_eligibility_tracker" => Ok(GeneratedField::GameEligibilityTracker),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = MarketTracker;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.checkpoint.v1.MarketTracker")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<MarketTracker, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut market_activity__ = None;
+                let mut taker_notional_volume__ = None;
+                let mut market_to_party_taker_notional_volume__ = None;
+                let mut epoch_taker_fees__ = None;
+                let mut game_eligibility_tracker__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::MarketActivity => {
+                            if market_activity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("marketActivity"));
+                            }
+                            market_activity__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::TakerNotionalVolume => {
+                            if taker_notional_volume__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("takerNotionalVolume"));
+                            }
+                            taker_notional_volume__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::MarketToPartyTakerNotionalVolume => {
+                            if market_to_party_taker_notional_volume__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("marketToPartyTakerNotionalVolume"));
+                            }
+                            market_to_party_taker_notional_volume__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::EpochTakerFees => {
+                            if epoch_taker_fees__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epochTakerFees"));
+                            }
+                            epoch_taker_fees__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::GameEligibilityTracker => {
+                            if game_eligibility_tracker__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("gameEligibilityTracker"));
+                            }
+                            game_eligibility_tracker__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(MarketTracker {
+                    market_activity: market_activity__.unwrap_or_default(),
+                    taker_notional_volume: taker_notional_volume__.unwrap_or_default(),
+                    market_to_party_taker_notional_volume: market_to_party_taker_notional_volume__.unwrap_or_default(),
+                    epoch_taker_fees: epoch_taker_fees__.unwrap_or_default(),
+                    game_eligibility_tracker: game_eligibility_tracker__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.checkpoint.v1.MarketTracker", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for MultisigControl {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.signers.is_empty() {
+            len += 1;
+        }
+        if self.threshold_set.is_some() {
+            len += 1;
+        }
+        if self.last_block_seen != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.checkpoint.v1.MultisigControl", len)?;
+        if !self.signers.is_empty() {
+            struct_ser.serialize_field("signers", &self.signers)?;
+        }
+        if let Some(v) = self.threshold_set.as_ref() {
+            struct_ser.serialize_field("thresholdSet", v)?;
+        }
+        if self.last_block_seen != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("lastBlockSeen", ToString::to_string(&self.last_block_seen).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for MultisigControl {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "signers",
+            "threshold_set",
+            "thresholdSet",
+            "last_block_seen",
+            "lastBlockSeen",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Signers,
+            ThresholdSet,
+            LastBlockSeen,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "signers" => Ok(GeneratedField::Signers),
+                            "thresholdSet" | "threshold_set" => Ok(GeneratedField::ThresholdSet),
+                            "lastBlockSeen" | "last_block_seen" => Ok(GeneratedField::LastBlockSeen),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = MultisigControl;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.checkpoint.v1.MultisigControl")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<MultisigControl, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut signers__ = None;
+                let mut threshold_set__ = None;
+                let mut last_block_seen__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Signers => {
+                            if signers__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("signers"));
+                            }
+                            signers__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ThresholdSet => {
+                            if threshold_set__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("thresholdSet"));
+                            }
+                            threshold_set__ = map_.next_value()?;
+                        }
+                        GeneratedField::LastBlockSeen => {
+                            if last_block_seen__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastBlockSeen"));
+                            }
+                            last_block_seen__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(MultisigControl {
+                    signers: signers__.unwrap_or_default(),
+                    threshold_set: threshold_set__,
+                    last_block_seen: last_block_seen__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.checkpoint.v1.MultisigControl", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for NetParams {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.params.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.checkpoint.v1.NetParams", len)?;
+        if !self.params.is_empty() {
+            struct_ser.serialize_field("params", &self.params)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for NetParams {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "params",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Params,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "params" => Ok(GeneratedField::Params),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = NetParams;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.checkpoint.v1.NetParams")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NetParams, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut params__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Params => {
+                            if params__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("params"));
+                            }
+                            params__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(NetParams {
+                    params: params__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.checkpoint.v1.NetParams", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PartyAmount {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.party.is_empty() {
+            len += 1;
+        }
+        if !self.amount.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.checkpoint.v1.PartyAmount", len)?;
+        if !self.party.is_empty() {
+            struct_ser.serialize_field("party", &self.party)?;
+        }
+        if !self.amount.is_empty() {
+            struct_ser.serialize_field("amount", &self.amount)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PartyAmount {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "party",
+            "amount",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Party,
+            Amount,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "party" => Ok(GeneratedField::Party),
+                            "amount" => Ok(GeneratedField::Amount),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PartyAmount;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.checkpoint.v1.PartyAmount")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PartyAmount, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut party__ = None;
+                let mut amount__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Party => {
+                            if party__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("party"));
+                            }
+                            party__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Amount => {
+                            if amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amount"));
+                            }
+                            amount__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(PartyAmount {
+                    party: party__.unwrap_or_default(),
+                    amount: amount__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.checkpoint.v1.PartyAmount", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PartyFees {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.party.is_empty() {
+            len += 1;
+        }
+        if !self.fee.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.checkpoint.v1.PartyFees", len)?;
+        if !self.party.is_empty() {
+            struct_ser.serialize_field("party", &self.party)?;
+        }
+        if !self.fee.is_empty() {
+            struct_ser.serialize_field("fee", &self.fee)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PartyFees {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "party",
+            "fee",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Party,
+            Fee,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "party" => Ok(GeneratedField::Party),
+                            "fee" => Ok(GeneratedField::Fee),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PartyFees;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.checkpoint.v1.PartyFees")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PartyFees, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut party__ = None;
+                let mut fee__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Party => {
+                            if party__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("party"));
+                            }
+                            party__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Fee => {
+                            if fee__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fee"));
+                            }
+                            fee__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(PartyFees {
+                    party: party__.unwrap_or_default(),
+                    fee: fee__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.checkpoint.v1.PartyFees", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PartyFeesHistory {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.party.is_empty() {
+            len += 1;
+        }
+        if !self.fee.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.checkpoint.v1.PartyFeesHistory", len)?;
+        if !self.party.is_empty() {
+            struct_ser.serialize_field("party", &self.party)?;
+        }
+        if !self.fee.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("fee", pbjson::private::base64::encode(&self.fee).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PartyFeesHistory {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "party",
+            "fee",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Party,
+            Fee,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "party" => Ok(GeneratedField::Party),
+                            "fee" => Ok(GeneratedField::Fee),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PartyFeesHistory;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.checkpoint.v1.PartyFeesHistory")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PartyFeesHistory, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut party__ = None;
+                let mut fee__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Party => {
+                            if party__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("party"));
+                            }
+                            party__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Fee => {
+                            if fee__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fee"));
+                            }
+                            fee__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(PartyFeesHistory {
+                    party: party__.unwrap_or_default(),
+                    fee: fee__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.checkpoint.v1.PartyFeesHistory", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PartyTakerFees {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.party.is_empty() {
+            len += 1;
+        }
+        if !self.taker_fees.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.checkpoint.v1.PartyTakerFees", len)?;
+        if !self.party.is_empty() {
+            struct_ser.serialize_field("party", &self.party)?;
+        }
+        if !self.taker_fees.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("takerFees", pbjson::private::base64::encode(&self.taker_fees).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PartyTakerFees {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "party",
+            "taker_fees",
+            "takerFees",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Party,
+            TakerFees,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "party" => Ok(GeneratedField::Party),
+                            "takerFees" | "taker_fees" => Ok(GeneratedField::TakerFees),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PartyTakerFees;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.checkpoint.v1.PartyTakerFees")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PartyTakerFees, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut party__ = None;
+                let mut taker_fees__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Party => {
+                            if party__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("party"));
+                            }
+                            party__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::TakerFees => {
+                            if taker_fees__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("takerFees"));
+                            }
+                            taker_fees__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(PartyTakerFees {
+                    party: party__.unwrap_or_default(),
+                    taker_fees: taker_fees__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.checkpoint.v1.PartyTakerFees", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PartyTimeWeightedNotional {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.party.is_empty() {
+            len += 1;
+        }
+        if !self.tw_notional.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.checkpoint.v1.PartyTimeWeightedNotional", len)?;
+        if !self.party.is_empty() {
+            struct_ser.serialize_field("party", &self.party)?;
+        }
+        if !self.tw_notional.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("twNotional", pbjson::private::base64::encode(&self.tw_notional).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PartyTimeWeightedNotional {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "party",
+            "tw_notional",
+            "twNotional",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Party,
+            TwNotional,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "party" => Ok(GeneratedField::Party),
+                            "twNotional" | "tw_notional" => Ok(GeneratedField::TwNotional),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PartyTimeWeightedNotional;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.checkpoint.v1.PartyTimeWeightedNotional")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PartyTimeWeightedNotional, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut party__ = None;
+                let mut tw_notional__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Party => {
+                            if party__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("party"));
+                            }
+                            party__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::TwNotional => {
+                            if tw_notional__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("twNotional"));
+                            }
+                            tw_notional__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(PartyTimeWeightedNotional {
+                    party: party__.unwrap_or_default(),
+                    tw_notional: tw_notional__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.checkpoint.v1.PartyTimeWeightedNotional", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PartyTimeWeightedPosition {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.party.is_empty() {
+            len += 1;
+        }
+        if self.tw_position != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.checkpoint.v1.PartyTimeWeightedPosition", len)?;
+        if !self.party.is_empty() {
+            struct_ser.serialize_field("party", &self.party)?;
+        }
+        if self.tw_position != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("twPosition", ToString::to_string(&self.tw_position).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PartyTimeWeightedPosition {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "party",
+            "tw_position",
+            "twPosition",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Party,
+            TwPosition,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "party" => Ok(GeneratedField::Party),
+                            "twPosition" | "tw_position" => Ok(GeneratedField::TwPosition),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PartyTimeWeightedPosition;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.checkpoint.v1.PartyTimeWeightedPosition")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PartyTimeWeightedPosition, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut party__ = None;
+                let mut tw_position__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Party => {
+                            if party__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("party"));
+                            }
+                            party__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::TwPosition => {
+                            if tw_position__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("twPosition"));
+                            }
+                            tw_position__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(PartyTimeWeightedPosition {
+                    party: party__.unwrap_or_default(),
+                    tw_position: tw_position__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.checkpoint.v1.PartyTimeWeightedPosition", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PendingEthereumKeyRotation {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.relative_target_block_height != 0 {
+            len += 1;
+        }
+        if !self.node_id.is_empty() {
+            len += 1;
+        }
+        if !self.new_address.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.checkpoint.v1.PendingEthereumKeyRotation", len)?;
+        if self.relative_target_block_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("relativeTargetBlockHeight", ToString::to_string(&self.relative_target_block_height).as_str())?;
+        }
+        if !self.node_id.is_empty() {
+            struct_ser.serialize_field("nodeId", &self.node_id)?;
+        }
+        if !self.new_address.is_empty() {
+            struct_ser.serialize_field("newAddress", &self.new_address)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PendingEthereumKeyRotation {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "relative_target_block_height",
+            "relativeTargetBlockHeight",
+            "node_id",
+            "nodeId",
+            "new_address",
+            "newAddress",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            RelativeTargetBlockHeight,
+            NodeId,
+            NewAddress,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "relativeTargetBlockHeight" | "relative_target_block_height" => Ok(GeneratedField::RelativeTargetBlockHeight),
+                            "nodeId" | "node_id" => Ok(GeneratedField::NodeId),
+                            "newAddress" | "new_address" => Ok(GeneratedField::NewAddress),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PendingEthereumKeyRotation;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.checkpoint.v1.PendingEthereumKeyRotation")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PendingEthereumKeyRotation, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut relative_target_block_height__ = None;
+                let mut node_id__ = None;
+                let mut new_address__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::RelativeTargetBlockHeight => {
+                            if relative_target_block_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("relativeTargetBlockHeight"));
+                            }
+                            relative_target_block_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::NodeId => {
+                            if node_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nodeId"));
+                            }
+                            node_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::NewAddress => {
+                            if new_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newAddress"));
+                            }
+                            new_address__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(PendingEthereumKeyRotation {
+                    relative_target_block_height: relative_target_block_height__.unwrap_or_default(),
+                    node_id: node_id__.unwrap_or_default(),
+                    new_address: new_address__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.checkpoint.v1.PendingEthereumKeyRotation", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PendingKeyRotation {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.relative_target_block_height != 0 {
+            len += 1;
+        }
+        if !self.node_id.is_empty() {
+            len += 1;
+        }
+        if !self.new_pub_key.is_empty() {
+            len += 1;
+        }
+        if self.new_pub_key_index != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.checkpoint.v1.PendingKeyRotation", len)?;
+        if self.relative_target_block_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("relativeTargetBlockHeight", ToString::to_string(&self.relative_target_block_height).as_str())?;
+        }
+        if !self.node_id.is_empty() {
+            struct_ser.serialize_field("nodeId", &self.node_id)?;
+        }
+        if !self.new_pub_key.is_empty() {
+            struct_ser.serialize_field("newPubKey", &self.new_pub_key)?;
+        }
+        if self.new_pub_key_index != 0 {
+            struct_ser.serialize_field("newPubKeyIndex", &self.new_pub_key_index)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PendingKeyRotation {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "relative_target_block_height",
+            "relativeTargetBlockHeight",
+            "node_id",
+            "nodeId",
+            "new_pub_key",
+            "newPubKey",
+            "new_pub_key_index",
+            "newPubKeyIndex",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            RelativeTargetBlockHeight,
+            NodeId,
+            NewPubKey,
+            NewPubKeyIndex,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "relativeTargetBlockHeight" | "relative_target_block_height" => Ok(GeneratedField::RelativeTargetBlockHeight),
+                            "nodeId" | "node_id" => Ok(GeneratedField::NodeId),
+                            "newPubKey" | "new_pub_key" => Ok(GeneratedField::NewPubKey),
+                            "newPubKeyIndex" | "new_pub_key_index" => Ok(GeneratedField::NewPubKeyIndex),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PendingKeyRotation;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.checkpoint.v1.PendingKeyRotation")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PendingKeyRotation, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut relative_target_block_height__ = None;
+                let mut node_id__ = None;
+                let mut new_pub_key__ = None;
+                let mut new_pub_key_index__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::RelativeTargetBlockHeight => {
+                            if relative_target_block_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("relativeTargetBlockHeight"));
+                            }
+                            relative_target_block_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::NodeId => {
+                            if node_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nodeId"));
+                            }
+                            node_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::NewPubKey => {
+                            if new_pub_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newPubKey"));
+                            }
+                            new_pub_key__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::NewPubKeyIndex => {
+                            if new_pub_key_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newPubKeyIndex"));
+                            }
+                            new_pub_key_index__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(PendingKeyRotation {
+                    relative_target_block_height: relative_target_block_height__.unwrap_or_default(),
+                    node_id: node_id__.unwrap_or_default(),
+                    new_pub_key: new_pub_key__.unwrap_or_default(),
+                    new_pub_key_index: new_pub_key_index__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.checkpoint.v1.PendingKeyRotation", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PendingRewardPayout {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.from_account.is_empty() {
+            len += 1;
+        }
+        if !self.asset.is_empty() {
+            len += 1;
+        }
+        if !self.party_amount.is_empty() {
+            len += 1;
+        }
+        if !self.total_reward.is_empty() {
+            len += 1;
+        }
+        if !self.epoch_seq.is_empty() {
+            len += 1;
+        }
+        if self.timestamp != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.checkpoint.v1.PendingRewardPayout", len)?;
+        if !self.from_account.is_empty() {
+            struct_ser.serialize_field("fromAccount", &self.from_account)?;
+        }
+        if !self.asset.is_empty() {
+            struct_ser.serialize_field("asset", &self.asset)?;
+        }
+        if !self.party_amount.is_empty() {
+            struct_ser.serialize_field("partyAmount", &self.party_amount)?;
+        }
+        if !self.total_reward.is_empty() {
+            struct_ser.serialize_field("totalReward", &self.total_reward)?;
+        }
+        if !self.epoch_seq.is_empty() {
+            struct_ser.serialize_field("epochSeq", &self.epoch_seq)?;
+        }
+        if self.timestamp != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("timestamp", ToString::to_string(&self.timestamp).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PendingRewardPayout {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "from_account",
+            "fromAccount",
+            "asset",
+            "party_amount",
+            "partyAmount",
+            "total_reward",
+            "totalReward",
+            "epoch_seq",
+            "epochSeq",
+            "timestamp",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            FromAccount,
+            Asset,
+            PartyAmount,
+            TotalReward,
+            EpochSeq,
+            Timestamp,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "fromAccount" | "from_account" => Ok(GeneratedField::FromAccount),
+                            "asset" => Ok(GeneratedField::Asset),
+                            "partyAmount" | "party_amount" => Ok(GeneratedField::PartyAmount),
+                            "totalReward" | "total_reward" => Ok(GeneratedField::TotalReward),
+                            "epochSeq" | "epoch_seq" => Ok(GeneratedField::EpochSeq),
+                            "timestamp" => Ok(GeneratedField::Timestamp),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PendingRewardPayout;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.checkpoint.v1.PendingRewardPayout")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PendingRewardPayout, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut from_account__ = None;
+                let mut asset__ = None;
+                let mut party_amount__ = None;
+                let mut total_reward__ = None;
+                let mut epoch_seq__ = None;
+                let mut timestamp__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::FromAccount => {
+                            if from_account__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fromAccount"));
+                            }
+                            from_account__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Asset => {
+                            if asset__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("asset"));
+                            }
+                            asset__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::PartyAmount => {
+                            if party_amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("partyAmount"));
+                            }
+                            party_amount__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::TotalReward => {
+                            if total_reward__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("totalReward"));
+                            }
+                            total_reward__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::EpochSeq => {
+                            if epoch_seq__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epochSeq"));
+                            }
+                            epoch_seq__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Timestamp => {
+                            if timestamp__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timestamp"));
+                            }
+                            timestamp__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(PendingRewardPayout {
+                    from_account: from_account__.unwrap_or_default(),
+                    asset: asset__.unwrap_or_default(),
+                    party_amount: party_amount__.unwrap_or_default(),
+                    total_reward: total_reward__.unwrap_or_default(),
+                    epoch_seq: epoch_seq__.unwrap_or_default(),
+                    timestamp: timestamp__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.checkpoint.v1.PendingRewardPayout", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Proposals {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.proposals.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.checkpoint.v1.Proposals", len)?;
+        if !self.proposals.is_empty() {
+            struct_ser.serialize_field("proposals", &self.proposals)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Proposals {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "proposals",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Proposals,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "proposals" => Ok(GeneratedField::Proposals),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Proposals;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.checkpoint.v1.Proposals")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Proposals, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut proposals__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Proposals => {
+                            if proposals__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposals"));
+                            }
+                            proposals__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(Proposals {
+                    proposals: proposals__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.checkpoint.v1.Proposals", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for RecurringTransfers {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.recurring_transfers.is_empty() {
+            len += 1;
+        }
+        if self.next_metric_update != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.checkpoint.v1.RecurringTransfers", len)?;
+        if !self.recurring_transfers.is_empty() {
+            struct_ser.serialize_field("recurringTransfers", &self.recurring_transfers)?;
+        }
+        if self.next_metric_update != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("nextMetricUpdate", ToString::to_string(&self.next_metric_update).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RecurringTransfers {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "recurring_transfers",
+            "recurringTransfers",
+            "next_metric_update",
+            "nextMetricUpdate",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            RecurringTransfers,
+            NextMetricUpdate,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "recurringTransfers" | "recurring_transfers" => Ok(GeneratedField::RecurringTransfers),
+                            "nextMetricUpdate" | "next_metric_update" => Ok(GeneratedField::NextMetricUpdate),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = RecurringTransfers;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.checkpoint.v1.RecurringTransfers")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RecurringTransfers, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut recurring_transfers__ = None;
+                let mut next_metric_update__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::RecurringTransfers => {
+                            if recurring_transfers__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("recurringTransfers"));
+                            }
+                            recurring_transfers__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::NextMetricUpdate => {
+                            if next_metric_update__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nextMetricUpdate"));
+                            }
+                            next_metric_update__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(RecurringTransfers {
+                    recurring_transfers: recurring_transfers__.unwrap_or_default(),
+                    next_metric_update: next_metric_update__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.checkpoint.v1.RecurringTransfers", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ReturnsData {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.party.is_empty() {
+            len += 1;
+        }
+        if !self.r#return.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.checkpoint.v1.ReturnsData", len)?;
+        if !self.party.is_empty() {
+            struct_ser.serialize_field("party", &self.party)?;
+        }
+        if !self.r#return.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("return", pbjson::private::base64::encode(&self.r#return).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ReturnsData {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "party",
+            "return",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Party,
+            Return,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "party" => Ok(GeneratedField::Party),
+                            "return" => Ok(GeneratedField::Return),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ReturnsData;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.checkpoint.v1.ReturnsData")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ReturnsData, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut party__ = None;
+                let mut r#return__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Party => {
+                            if party__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("party"));
+                            }
+                            party__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Return => {
+                            if r#return__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("return"));
+                            }
+                            r#return__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(ReturnsData {
+                    party: party__.unwrap_or_default(),
+                    r#return: r#return__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.checkpoint.v1.ReturnsData", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for RewardPayout {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.payout_time != 0 {
+            len += 1;
+        }
+        if !self.rewards_payout.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.checkpoint.v1.RewardPayout", len)?;
+        if self.payout_time != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("payoutTime", ToString::to_string(&self.payout_time).as_str())?;
+        }
+        if !self.rewards_payout.is_empty() {
+            struct_ser.serialize_field("rewardsPayout", &self.rewards_payout)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RewardPayout {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "payout_time",
+            "payoutTime",
+            "rewards_payout",
+            "rewardsPayout",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PayoutTime,
+            RewardsPayout,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "payoutTime" | "payout_time" => Ok(GeneratedField::PayoutTime),
+                            "rewardsPayout" | "rewards_payout" => Ok(GeneratedField::RewardsPayout),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = RewardPayout;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.checkpoint.v1.RewardPayout")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RewardPayout, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut payout_time__ = None;
+                let mut rewards_payout__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PayoutTime => {
+                            if payout_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("payoutTime"));
+                            }
+                            payout_time__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::RewardsPayout => {
+                            if rewards_payout__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rewardsPayout"));
+                            }
+                            rewards_payout__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(RewardPayout {
+                    payout_time: payout_time__.unwrap_or_default(),
+                    rewards_payout: rewards_payout__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.checkpoint.v1.RewardPayout", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Rewards {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.rewards.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.checkpoint.v1.Rewards", len)?;
+        if !self.rewards.is_empty() {
+            struct_ser.serialize_field("rewards", &self.rewards)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Rewards {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "rewards",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Rewards,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "rewards" => Ok(GeneratedField::Rewards),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Rewards;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.checkpoint.v1.Rewards")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Rewards, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut rewards__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Rewards => {
+                            if rewards__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rewards"));
+                            }
+                            rewards__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(Rewards {
+                    rewards: rewards__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.checkpoint.v1.Rewards", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ScheduledGovernanceTransferAtTime {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.deliver_on != 0 {
+            len += 1;
+        }
+        if !self.transfers.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.checkpoint.v1.ScheduledGovernanceTransferAtTime", len)?;
+        if self.deliver_on != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("deliverOn", ToString::to_string(&self.deliver_on).as_str())?;
+        }
+        if !self.transfers.is_empty() {
+            struct_ser.serialize_field("transfers", &self.transfers)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ScheduledGovernanceTransferAtTime {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "deliver_on",
+            "deliverOn",
+            "transfers",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            DeliverOn,
+            Transfers,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "deliverOn" | "deliver_on" => Ok(GeneratedField::DeliverOn),
+                            "transfers" => Ok(GeneratedField::Transfers),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ScheduledGovernanceTransferAtTime;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.checkpoint.v1.ScheduledGovernanceTransferAtTime")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ScheduledGovernanceTransferAtTime, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut deliver_on__ = None;
+                let mut transfers__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::DeliverOn => {
+                            if deliver_on__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("deliverOn"));
+                            }
+                            deliver_on__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Transfers => {
+                            if transfers__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("transfers"));
+                            }
+                            transfers__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(ScheduledGovernanceTransferAtTime {
+                    deliver_on: deliver_on__.unwrap_or_default(),
+                    transfers: transfers__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.checkpoint.v1.ScheduledGovernanceTransferAtTime", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ScheduledTransfer {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.transfer.is_some() {
+            len += 1;
+        }
+        if self.account_type != 0 {
+            len += 1;
+        }
+        if !self.reference.is_empty() {
+            len += 1;
+        }
+        if self.oneoff_transfer.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.checkpoint.v1.ScheduledTransfer", len)?;
+        if let Some(v) = self.transfer.as_ref() {
+            struct_ser.serialize_field("transfer", v)?;
+        }
+        if self.account_type != 0 {
+            let v = super::super::AccountType::try_from(self.account_type)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.account_type)))?;
+            struct_ser.serialize_field("accountType", &v)?;
+        }
+        if !self.reference.is_empty() {
+            struct_ser.serialize_field("reference", &self.reference)?;
+        }
+        if let Some(v) = self.oneoff_transfer.as_ref() {
+            struct_ser.serialize_field("oneoffTransfer", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ScheduledTransfer {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "transfer",
+            "account_type",
+            "accountType",
+            "reference",
+            "oneoff_transfer",
+            "oneoffTransfer",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Transfer,
+            AccountType,
+            Reference,
+            OneoffTransfer,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "transfer" => Ok(GeneratedField::Transfer),
+                            "accountType" | "account_type" => Ok(GeneratedField::AccountType),
+                            "reference" => Ok(GeneratedField::Reference),
+                            "oneoffTransfer" | "oneoff_transfer" => Ok(GeneratedField::OneoffTransfer),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ScheduledTransfer;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.checkpoint.v1.ScheduledTransfer")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ScheduledTransfer, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut transfer__ = None;
+                let mut account_type__ = None;
+                let mut reference__ = None;
+                let mut oneoff_transfer__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Transfer => {
+                            if transfer__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("transfer"));
+                            }
+                            transfer__ = map_.next_value()?;
+                        }
+                        GeneratedField::AccountType => {
+                            if account_type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("accountType"));
+                            }
+                            account_type__ = Some(map_.next_value::<super::super::AccountType>()? as i32);
+                        }
+                        GeneratedField::Reference => {
+                            if reference__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reference"));
+                            }
+                            reference__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::OneoffTransfer => {
+                            if oneoff_transfer__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("oneoffTransfer"));
+                            }
+                            oneoff_transfer__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(ScheduledTransfer {
+                    transfer: transfer__,
+                    account_type: account_type__.unwrap_or_default(),
+                    reference: reference__.unwrap_or_default(),
+                    oneoff_transfer: oneoff_transfer__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.checkpoint.v1.ScheduledTransfer", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ScheduledTransferAtTime {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.deliver_on != 0 {
+            len += 1;
+        }
+        if !self.transfers.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.checkpoint.v1.ScheduledTransferAtTime", len)?;
+        if self.deliver_on != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("deliverOn", ToString::to_string(&self.deliver_on).as_str())?;
+        }
+        if !self.transfers.is_empty() {
+            struct_ser.serialize_field("transfers", &self.transfers)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ScheduledTransferAtTime {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "deliver_on",
+            "deliverOn",
+            "transfers",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            DeliverOn,
+            Transfers,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "deliverOn" | "deliver_on" => Ok(GeneratedField::DeliverOn),
+                            "transfers" => Ok(GeneratedField::Transfers),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ScheduledTransferAtTime;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.checkpoint.v1.ScheduledTransferAtTime")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ScheduledTransferAtTime, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut deliver_on__ = None;
+                let mut transfers__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::DeliverOn => {
+                            if deliver_on__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("deliverOn"));
+                            }
+                            deliver_on__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Transfers => {
+                            if transfers__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("transfers"));
+                            }
+                            transfers__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(ScheduledTransferAtTime {
+                    deliver_on: deliver_on__.unwrap_or_default(),
+                    transfers: transfers__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.checkpoint.v1.ScheduledTransferAtTime", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Staking {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.accepted.is_empty() {
+            len += 1;
+        }
+        if self.last_block_seen != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.checkpoint.v1.Staking", len)?;
+        if !self.accepted.is_empty() {
+            struct_ser.serialize_field("accepted", &self.accepted)?;
+        }
+        if self.last_block_seen != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("lastBlockSeen", ToString::to_string(&self.last_block_seen).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Staking {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "accepted",
+            "last_block_seen",
+            "lastBlockSeen",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Accepted,
+            LastBlockSeen,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "accepted" => Ok(GeneratedField::Accepted),
+                            "lastBlockSeen" | "last_block_seen" => Ok(GeneratedField::LastBlockSeen),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Staking;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.checkpoint.v1.Staking")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Staking, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut accepted__ = None;
+                let mut last_block_seen__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Accepted => {
+                            if accepted__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("accepted"));
+                            }
+                            accepted__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::LastBlockSeen => {
+                            if last_block_seen__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastBlockSeen"));
+                            }
+                            last_block_seen__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(Staking {
+                    accepted: accepted__.unwrap_or_default(),
+                    last_block_seen: last_block_seen__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.checkpoint.v1.Staking", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TwNotionalData {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.party.is_empty() {
+            len += 1;
+        }
+        if !self.notional.is_empty() {
+            len += 1;
+        }
+        if self.time != 0 {
+            len += 1;
+        }
+        if !self.tw_notional.is_empty() {
+            len += 1;
+        }
+        if !self.price.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.checkpoint.v1.TWNotionalData", len)?;
+        if !self.party.is_empty() {
+            struct_ser.serialize_field("party", &self.party)?;
+        }
+        if !self.notional.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("notional", pbjson::private::base64::encode(&self.notional).as_str())?;
+        }
+        if self.time != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("time", ToString::to_string(&self.time).as_str())?;
+        }
+        if !self.tw_notional.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("twNotional", pbjson::private::base64::encode(&self.tw_notional).as_str())?;
+        }
+        if !self.price.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("price", pbjson::private::base64::encode(&self.price).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TwNotionalData {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "party",
+            "notional",
+            "time",
+            "tw_notional",
+            "twNotional",
+            "price",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Party,
+            Notional,
+            Time,
+            TwNotional,
+            Price,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "party" => Ok(GeneratedField::Party),
+                            "notional" => Ok(GeneratedField::Notional),
+                            "time" => Ok(GeneratedField::Time),
+                            "twNotional" | "tw_notional" => Ok(GeneratedField::TwNotional),
+                            "price" => Ok(GeneratedField::Price),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TwNotionalData;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.checkpoint.v1.TWNotionalData")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TwNotionalData, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut party__ = None;
+                let mut notional__ = None;
+                let mut time__ = None;
+                let mut tw_notional__ = None;
+                let mut price__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Party => {
+                            if party__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("party"));
+                            }
+                            party__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Notional => {
+                            if notional__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("notional"));
+                            }
+                            notional__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Time => {
+                            if time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("time"));
+                            }
+                            time__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::TwNotional => {
+                            if tw_notional__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("twNotional"));
+                            }
+                            tw_notional__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Price => {
+                            if price__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("price"));
+                            }
+                            price__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(TwNotionalData {
+                    party: party__.unwrap_or_default(),
+                    notional: notional__.unwrap_or_default(),
+                    time: time__.unwrap_or_default(),
+                    tw_notional: tw_notional__.unwrap_or_default(),
+                    price: price__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.checkpoint.v1.TWNotionalData", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TwPositionData {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.party.is_empty() {
+            len += 1;
+        }
+        if self.position != 0 {
+            len += 1;
+        }
+        if self.time != 0 {
+            len += 1;
+        }
+        if self.tw_position != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.checkpoint.v1.TWPositionData", len)?;
+        if !self.party.is_empty() {
+            struct_ser.serialize_field("party", &self.party)?;
+        }
+        if self.position != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("position", ToString::to_string(&self.position).as_str())?;
+        }
+        if self.time != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("time", ToString::to_string(&self.time).as_str())?;
+        }
+        if self.tw_position != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("twPosition", ToString::to_string(&self.tw_position).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TwPositionData {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "party",
+            "position",
+            "time",
+            "tw_position",
+            "twPosition",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Party,
+            Position,
+            Time,
+            TwPosition,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "party" => Ok(GeneratedField::Party),
+                            "position" => Ok(GeneratedField::Position),
+                            "time" => Ok(GeneratedField::Time),
+                            "twPosition" | "tw_position" => Ok(GeneratedField::TwPosition),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TwPositionData;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.checkpoint.v1.TWPositionData")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TwPositionData, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut party__ = None;
+                let mut position__ = None;
+                let mut time__ = None;
+                let mut tw_position__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Party => {
+                            if party__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("party"));
+                            }
+                            party__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Position => {
+                            if position__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("position"));
+                            }
+                            position__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Time => {
+                            if time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("time"));
+                            }
+                            time__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::TwPosition => {
+                            if tw_position__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("twPosition"));
+                            }
+                            tw_position__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(TwPositionData {
+                    party: party__.unwrap_or_default(),
+                    position: position__.unwrap_or_default(),
+                    time: time__.unwrap_or_default(),
+                    tw_position: tw_position__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.checkpoint.v1.TWPositionData", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TakerNotionalVolume {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.party.is_empty() {
+            len += 1;
+        }
+        if !self.volume.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.checkpoint.v1.TakerNotionalVolume", len)?;
+        if !self.party.is_empty() {
+            struct_ser.serialize_field("party", &self.party)?;
+        }
+        if !self.volume.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("volume", pbjson::private::base64::encode(&self.volume).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TakerNotionalVolume {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "party",
+            "volume",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Party,
+            Volume,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "party" => Ok(GeneratedField::Party),
+                            "volume" => Ok(GeneratedField::Volume),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TakerNotionalVolume;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.checkpoint.v1.TakerNotionalVolume")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TakerNotionalVolume, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut party__ = None;
+                let mut volume__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Party => {
+                            if party__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("party"));
+                            }
+                            party__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Volume => {
+                            if volume__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("volume"));
+                            }
+                            volume__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(TakerNotionalVolume {
+                    party: party__.unwrap_or_default(),
+                    volume: volume__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.checkpoint.v1.TakerNotionalVolume", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ValidatorState {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.validator_update.is_some() {
+            len += 1;
+        }
+        if self.status != 0 {
+            len += 1;
+        }
+        if self.eth_events_forwarded != 0 {
+            len += 1;
+        }
+        if self.validator_power != 0 {
+            len += 1;
+        }
+        if self.ranking_score.is_some() {
+            len += 1;
+        }
+        if self.heartbeat_block_index != 0 {
+            len += 1;
+        }
+        if !self.heartbeat_block_sigs.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.checkpoint.v1.ValidatorState", len)?;
+        if let Some(v) = self.validator_update.as_ref() {
+            struct_ser.serialize_field("validatorUpdate", v)?;
+        }
+        if self.status != 0 {
+            struct_ser.serialize_field("status", &self.status)?;
+        }
+        if self.eth_events_forwarded != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("ethEventsForwarded", ToString::to_string(&self.eth_events_forwarded).as_str())?;
+        }
+        if self.validator_power != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("validatorPower", ToString::to_string(&self.validator_power).as_str())?;
+        }
+        if let Some(v) = self.ranking_score.as_ref() {
+            struct_ser.serialize_field("rankingScore", v)?;
+        }
+        if self.heartbeat_block_index != 0 {
+            struct_ser.serialize_field("heartbeatBlockIndex", &self.heartbeat_block_index)?;
+        }
+        if !self.heartbeat_block_sigs.is_empty() {
+            struct_ser.serialize_field("heartbeatBlockSigs", &self.heartbeat_block_sigs)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValidatorState {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "validator_update",
+            "validatorUpdate",
+            "status",
+            "eth_events_forwarded",
+            "ethEventsForwarded",
+            "validator_power",
+            "validatorPower",
+            "ranking_score",
+            "rankingScore",
+            "heartbeat_block_index",
+            "heartbeatBlockIndex",
+            "heartbeat_block_sigs",
+            "heartbeatBlockSigs",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ValidatorUpdate,
+            Status,
+            EthEventsForwarded,
+            ValidatorPower,
+            RankingScore,
+            HeartbeatBlockIndex,
+            HeartbeatBlockSigs,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "validatorUpdate" | "validator_update" => Ok(GeneratedField::ValidatorUpdate),
+                            "status" => Ok(GeneratedField::Status),
+                            "ethEventsForwarded" | "eth_events_forwarded" => Ok(GeneratedField::EthEventsForwarded),
+                            "validatorPower" | "validator_power" => Ok(GeneratedField::ValidatorPower),
+                            "rankingScore" | "ranking_score" => Ok(GeneratedField::RankingScore),
+                            "heartbeatBlockIndex" | "heartbeat_block_index" => Ok(GeneratedField::HeartbeatBlockIndex),
+                            "heartbeatBlockSigs" | "heartbeat_block_sigs" => Ok(GeneratedField::HeartbeatBlockSigs),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ValidatorState;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.checkpoint.v1.ValidatorState")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorState, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut validator_update__ = None;
+                let mut status__ = None;
+                let mut eth_events_forwarded__ = None;
+                let mut validator_power__ = None;
+                let mut ranking_score__ = None;
+                let mut heartbeat_block_index__ = None;
+                let mut heartbeat_block_sigs__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ValidatorUpdate => {
+                            if validator_update__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("validatorUpdate"));
+                            }
+                            validator_update__ = map_.next_value()?;
+                        }
+                        GeneratedField::Status => {
+                            if status__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("status"));
+                            }
+                            status__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::EthEventsForwarded => {
+                            if eth_events_forwarded__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ethEventsForwarded"));
+                            }
+                            eth_events_forwarded__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ValidatorPower => {
+                            if validator_power__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("validatorPower"));
+                            }
+                            validator_power__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::RankingScore => {
+                            if ranking_score__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rankingScore"));
+                            }
+                            ranking_score__ = map_.next_value()?;
+                        }
+                        GeneratedField::HeartbeatBlockIndex => {
+                            if heartbeat_block_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("heartbeatBlockIndex"));
+                            }
+                            heartbeat_block_index__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::HeartbeatBlockSigs => {
+                            if heartbeat_block_sigs__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("heartbeatBlockSigs"));
+                            }
+                            heartbeat_block_sigs__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(ValidatorState {
+                    validator_update: validator_update__,
+                    status: status__.unwrap_or_default(),
+                    eth_events_forwarded: eth_events_forwarded__.unwrap_or_default(),
+                    validator_power: validator_power__.unwrap_or_default(),
+                    ranking_score: ranking_score__,
+                    heartbeat_block_index: heartbeat_block_index__.unwrap_or_default(),
+                    heartbeat_block_sigs: heartbeat_block_sigs__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.checkpoint.v1.ValidatorState", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Validators {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.validator_state.is_empty() {
+            len += 1;
+        }
+        if !self.pending_key_rotations.is_empty() {
+            len += 1;
+        }
+        if !self.pending_ethereum_key_rotations.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.checkpoint.v1.Validators", len)?;
+        if !self.validator_state.is_empty() {
+            struct_ser.serialize_field("validatorState", &self.validator_state)?;
+        }
+        if !self.pending_key_rotations.is_empty() {
+            struct_ser.serialize_field("pendingKeyRotations", &self.pending_key_rotations)?;
+        }
+        if !self.pending_ethereum_key_rotations.is_empty() {
+            struct_ser.serialize_field("pendingEthereumKeyRotations", &self.pending_ethereum_key_rotations)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Validators {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "validator_state",
+            "validatorState",
+            "pending_key_rotations",
+            "pendingKeyRotations",
+            "pending_ethereum_key_rotations",
+            "pendingEthereumKeyRotations",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ValidatorState,
+            PendingKeyRotations,
+            PendingEthereumKeyRotations,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "validatorState" | "validator_state" => Ok(GeneratedField::ValidatorState),
+                            "pendingKeyRotations" | "pending_key_rotations" => Ok(GeneratedField::PendingKeyRotations),
+                            "pendingEthereumKeyRotations" | "pending_ethereum_key_rotations" => Ok(GeneratedField::PendingEthereumKeyRotations),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Validators;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.checkpoint.v1.Validators")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Validators, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut validator_state__ = None;
+                let mut pending_key_rotations__ = None;
+                let mut pending_ethereum_key_rotations__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ValidatorState => {
+                            if validator_state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("validatorState"));
+                            }
+                            validator_state__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::PendingKeyRotations => {
+                            if pending_key_rotations__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pendingKeyRotations"));
+                            }
+                            pending_key_rotations__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::PendingEthereumKeyRotations => {
+                            if pending_ethereum_key_rotations__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pendingEthereumKeyRotations"));
+                            }
+                            pending_ethereum_key_rotations__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(Validators {
+                    validator_state: validator_state__.unwrap_or_default(),
+                    pending_key_rotations: pending_key_rotations__.unwrap_or_default(),
+                    pending_ethereum_key_rotations: pending_ethereum_key_rotations__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.checkpoint.v1.Validators", FIELDS, GeneratedVisitor)
+    }
+}
@@ -0,0 +1,1464 @@
+// This file is @generated by prost-build.
+/// Signature definition that allows the network to authenticate external data.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Signature {
+    /// Hex encoded bytes of the signature.
+    #[prost(string, tag = "1")]
+    pub value: ::prost::alloc::string::String,
+    /// Algorithm used to create the signature.
+    #[prost(string, tag = "2")]
+    pub algo: ::prost::alloc::string::String,
+    /// Version of the algorithm used to create the signature.
+    #[prost(uint32, tag = "3")]
+    pub version: u32,
+}
+/// A validator command which is sent automatically at regular intervals by any validator participating in the network.
+/// It is used to allow the network to know whether a validator is active, or if they have shut down.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidatorHeartbeat {
+    /// Node ID of the validator emitting the heartbeat.
+    #[prost(string, tag = "1")]
+    pub node_id: ::prost::alloc::string::String,
+    /// Signature from the validator made using their Ethereum wallet.
+    #[prost(message, optional, tag = "2")]
+    pub ethereum_signature: ::core::option::Option<Signature>,
+    /// Signature from the validator made using their Vega wallet.
+    #[prost(message, optional, tag = "3")]
+    pub vega_signature: ::core::option::Option<Signature>,
+    /// Message which has been signed.
+    #[prost(string, tag = "4")]
+    pub message: ::prost::alloc::string::String,
+}
+/// A command that allows a new node operator to announce themselves to the network as a new validator.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AnnounceNode {
+    /// Vega public key of the node being announced.
+    #[prost(string, tag = "1")]
+    pub vega_pub_key: ::prost::alloc::string::String,
+    /// Ethereum public key of the node being announced.
+    #[prost(string, tag = "2")]
+    pub ethereum_address: ::prost::alloc::string::String,
+    /// Public key for the blockchain, currently the node's CometBFT key.
+    #[prost(string, tag = "3")]
+    pub chain_pub_key: ::prost::alloc::string::String,
+    /// URL to the node operators homepage allowing stake holders to make an informed decision when delegating.
+    #[prost(string, tag = "4")]
+    pub info_url: ::prost::alloc::string::String,
+    /// Country code (ISO 3166-1 alpha-2) for the location of the node.
+    #[prost(string, tag = "5")]
+    pub country: ::prost::alloc::string::String,
+    /// Node ID of the validator, which is the node's public master key.
+    #[prost(string, tag = "6")]
+    pub id: ::prost::alloc::string::String,
+    /// Human-readable name of the node.
+    #[prost(string, tag = "7")]
+    pub name: ::prost::alloc::string::String,
+    /// URL to the node operator's avatar.
+    #[prost(string, tag = "8")]
+    pub avatar_url: ::prost::alloc::string::String,
+    /// Vega public key derivation index.
+    #[prost(uint32, tag = "9")]
+    pub vega_pub_key_index: u32,
+    /// Epoch from which the node is expected to be ready to validate blocks.
+    #[prost(uint64, tag = "10")]
+    pub from_epoch: u64,
+    /// Signature from the node made using the ethereum wallet.
+    #[prost(message, optional, tag = "11")]
+    pub ethereum_signature: ::core::option::Option<Signature>,
+    /// Signature from the node made using the Vega wallet.
+    #[prost(message, optional, tag = "12")]
+    pub vega_signature: ::core::option::Option<Signature>,
+    /// Ethereum public key to use as a submitter to allow automatic signature generation.
+    #[prost(string, tag = "13")]
+    pub submitter_address: ::prost::alloc::string::String,
+}
+/// A validator command which is sent automatically by a node when it has verified a resource external to the network.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NodeVote {
+    /// Reference identifying the resource that has been verified.
+    #[prost(string, tag = "2")]
+    pub reference: ::prost::alloc::string::String,
+    /// Type of external event that has been verified.
+    #[prost(enumeration = "node_vote::Type", tag = "3")]
+    pub r#type: i32,
+}
+/// Nested message and enum types in `NodeVote`.
+pub mod node_vote {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Type {
+        /// Represents an unspecified or missing value from the input
+        Unspecified = 0,
+        /// Node vote for a new stake deposit
+        StakeDeposited = 1,
+        /// Node vote for a new stake removed event
+        StakeRemoved = 2,
+        /// Node vote for a new collateral deposit
+        FundsDeposited = 3,
+        /// Node vote for a new signer added to the erc20 bridge
+        SignerAdded = 4,
+        /// Node vote for a signer removed from the erc20 bridge
+        SignerRemoved = 5,
+        /// Node vote for a bridge stopped event
+        BridgeStopped = 6,
+        /// Node vote for a bridge resumed event
+        BridgeResumed = 7,
+        /// Node vote for a newly listed asset
+        AssetListed = 8,
+        /// Node vote for an asset limits update
+        LimitsUpdated = 9,
+        /// Node vote to share the total supply of the staking token
+        StakeTotalSupply = 10,
+        /// Node vote to update the threshold of the signer set for the multisig contract
+        SignerThresholdSet = 11,
+        /// Node vote to validate a new assert governance proposal
+        GovernanceValidateAsset = 12,
+        /// Node vote for an Ethereum contract call result
+        EthereumContractCallResult = 13,
+        /// Node vote for an Ethereum chain event heartbeat.
+        EthereumHeartbeat = 14,
+    }
+    impl Type {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Type::Unspecified => "TYPE_UNSPECIFIED",
+                Type::StakeDeposited => "TYPE_STAKE_DEPOSITED",
+                Type::StakeRemoved => "TYPE_STAKE_REMOVED",
+                Type::FundsDeposited => "TYPE_FUNDS_DEPOSITED",
+                Type::SignerAdded => "TYPE_SIGNER_ADDED",
+                Type::SignerRemoved => "TYPE_SIGNER_REMOVED",
+                Type::BridgeStopped => "TYPE_BRIDGE_STOPPED",
+                Type::BridgeResumed => "TYPE_BRIDGE_RESUMED",
+                Type::AssetListed => "TYPE_ASSET_LISTED",
+                Type::LimitsUpdated => "TYPE_LIMITS_UPDATED",
+                Type::StakeTotalSupply => "TYPE_STAKE_TOTAL_SUPPLY",
+                Type::SignerThresholdSet => "TYPE_SIGNER_THRESHOLD_SET",
+                Type::GovernanceValidateAsset => "TYPE_GOVERNANCE_VALIDATE_ASSET",
+                Type::EthereumContractCallResult => "TYPE_ETHEREUM_CONTRACT_CALL_RESULT",
+                Type::EthereumHeartbeat => "TYPE_ETHEREUM_HEARTBEAT",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "TYPE_UNSPECIFIED" => Some(Self::Unspecified),
+                "TYPE_STAKE_DEPOSITED" => Some(Self::StakeDeposited),
+                "TYPE_STAKE_REMOVED" => Some(Self::StakeRemoved),
+                "TYPE_FUNDS_DEPOSITED" => Some(Self::FundsDeposited),
+                "TYPE_SIGNER_ADDED" => Some(Self::SignerAdded),
+                "TYPE_SIGNER_REMOVED" => Some(Self::SignerRemoved),
+                "TYPE_BRIDGE_STOPPED" => Some(Self::BridgeStopped),
+                "TYPE_BRIDGE_RESUMED" => Some(Self::BridgeResumed),
+                "TYPE_ASSET_LISTED" => Some(Self::AssetListed),
+                "TYPE_LIMITS_UPDATED" => Some(Self::LimitsUpdated),
+                "TYPE_STAKE_TOTAL_SUPPLY" => Some(Self::StakeTotalSupply),
+                "TYPE_SIGNER_THRESHOLD_SET" => Some(Self::SignerThresholdSet),
+                "TYPE_GOVERNANCE_VALIDATE_ASSET" => Some(Self::GovernanceValidateAsset),
+                "TYPE_ETHEREUM_CONTRACT_CALL_RESULT" => {
+                    Some(Self::EthereumContractCallResult)
+                }
+                "TYPE_ETHEREUM_HEARTBEAT" => Some(Self::EthereumHeartbeat),
+                _ => None,
+            }
+        }
+    }
+}
+/// A validator command sent automatically containing a signature that can be used on a foreign chain to process an action.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NodeSignature {
+    /// ID of the resource that the signature relates to.
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Signature generated by the node.
+    #[prost(bytes = "vec", tag = "2")]
+    pub sig: ::prost::alloc::vec::Vec<u8>,
+    /// Kind of resource being signed.
+    #[prost(enumeration = "NodeSignatureKind", tag = "3")]
+    pub kind: i32,
+}
+/// A validator command sent automatically that provides information of events that have happened on foreign chains.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ChainEvent {
+    /// ID of the transaction on the foreign chain that caused the event.
+    #[prost(string, tag = "1")]
+    pub tx_id: ::prost::alloc::string::String,
+    /// Arbitrary one-time integer used to prevent replay attacks.
+    #[prost(uint64, tag = "2")]
+    pub nonce: u64,
+    /// Event being forwarded.
+    #[prost(oneof = "chain_event::Event", tags = "1001, 1002, 1005, 1006, 1007, 1008")]
+    pub event: ::core::option::Option<chain_event::Event>,
+}
+/// Nested message and enum types in `ChainEvent`.
+pub mod chain_event {
+    /// Event being forwarded.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Event {
+        /// Built-in asset event.
+        #[prost(message, tag = "1001")]
+        Builtin(super::super::super::BuiltinAssetEvent),
+        /// Ethereum ERC20 event.
+        #[prost(message, tag = "1002")]
+        Erc20(super::super::super::Erc20Event),
+        /// Ethereum Staking event.
+        #[prost(message, tag = "1005")]
+        StakingEvent(super::super::super::StakingEvent),
+        /// Ethereum ERC20 multisig event.
+        #[prost(message, tag = "1006")]
+        Erc20Multisig(super::super::super::Erc20MultiSigEvent),
+        /// Ethereum contract call event.
+        #[prost(message, tag = "1007")]
+        ContractCall(super::super::super::EthContractCallEvent),
+        /// Heartbeat showing evidence that a contract is being listened to.
+        #[prost(message, tag = "1008")]
+        Heartbeat(super::super::super::Erc20Heartbeat),
+    }
+}
+/// A validator command sent manually that allows a node operator to indicate to the network that their node's Vega key will be rotated.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct KeyRotateSubmission {
+    /// New Vega public key derivation index.
+    #[prost(uint32, tag = "1")]
+    pub new_pub_key_index: u32,
+    /// Block height at which the key rotation will take effect.
+    #[prost(uint64, tag = "2")]
+    pub target_block: u64,
+    /// Vega public key that would be rotated to.
+    #[prost(string, tag = "3")]
+    pub new_pub_key: ::prost::alloc::string::String,
+    /// Hash of the node's current Vega public key.
+    #[prost(string, tag = "4")]
+    pub current_pub_key_hash: ::prost::alloc::string::String,
+}
+/// A validator command sent manually that allows a node operator to indicate to the network that their node's Ethereum key will be rotated.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EthereumKeyRotateSubmission {
+    /// Block height at which the key rotation will take effect.
+    #[prost(uint64, tag = "1")]
+    pub target_block: u64,
+    /// Ethereum address that is being rotated to.
+    #[prost(string, tag = "2")]
+    pub new_address: ::prost::alloc::string::String,
+    /// Ethereum address of the node's current Ethereum keys.
+    #[prost(string, tag = "3")]
+    pub current_address: ::prost::alloc::string::String,
+    /// Ethereum public key to use as a submitter to allow automatic signature generation.
+    #[prost(string, tag = "4")]
+    pub submitter_address: ::prost::alloc::string::String,
+    /// Signature signed by the new Ethereum key that can be verified to prove ownership.
+    #[prost(message, optional, tag = "5")]
+    pub ethereum_signature: ::core::option::Option<Signature>,
+}
+/// A validator command sent automatically to reach consensus on floating point values.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StateVariableProposal {
+    /// Details of the state variable being proposed.
+    #[prost(message, optional, tag = "1")]
+    pub proposal: ::core::option::Option<super::super::StateValueProposal>,
+}
+/// A validator command sent manually by a node operator to propose a protocol upgrade.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtocolUpgradeProposal {
+    /// Block height at which to perform the upgrade.
+    #[prost(uint64, tag = "1")]
+    pub upgrade_block_height: u64,
+    /// Release tag for the Vega binary.
+    #[prost(string, tag = "2")]
+    pub vega_release_tag: ::prost::alloc::string::String,
+}
+/// Kind of signature created by a node, for example, allow-listing a new asset, withdrawal etc
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum NodeSignatureKind {
+    /// Represents an unspecified or missing value from the input.
+    Unspecified = 0,
+    /// Represents a signature for a new asset allow-listing.
+    AssetNew = 1,
+    /// Represents a signature for an asset withdrawal.
+    AssetWithdrawal = 2,
+    /// Represents a signature for a new signer added to the erc20 multisig contract.
+    Erc20MultisigSignerAdded = 3,
+    /// Represents a signature for a signer removed from the erc20 multisig contract.
+    Erc20MultisigSignerRemoved = 4,
+    /// Represents a signature for an asset update allow-listing.
+    AssetUpdate = 5,
+}
+impl NodeSignatureKind {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            NodeSignatureKind::Unspecified => "NODE_SIGNATURE_KIND_UNSPECIFIED",
+            NodeSignatureKind::AssetNew => "NODE_SIGNATURE_KIND_ASSET_NEW",
+            NodeSignatureKind::AssetWithdrawal => "NODE_SIGNATURE_KIND_ASSET_WITHDRAWAL",
+            NodeSignatureKind::Erc20MultisigSignerAdded => {
+                "NODE_SIGNATURE_KIND_ERC20_MULTISIG_SIGNER_ADDED"
+            }
+            NodeSignatureKind::Erc20MultisigSignerRemoved => {
+                "NODE_SIGNATURE_KIND_ERC20_MULTISIG_SIGNER_REMOVED"
+            }
+            NodeSignatureKind::AssetUpdate => "NODE_SIGNATURE_KIND_ASSET_UPDATE",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "NODE_SIGNATURE_KIND_UNSPECIFIED" => Some(Self::Unspecified),
+            "NODE_SIGNATURE_KIND_ASSET_NEW" => Some(Self::AssetNew),
+            "NODE_SIGNATURE_KIND_ASSET_WITHDRAWAL" => Some(Self::AssetWithdrawal),
+            "NODE_SIGNATURE_KIND_ERC20_MULTISIG_SIGNER_ADDED" => {
+                Some(Self::Erc20MultisigSignerAdded)
+            }
+            "NODE_SIGNATURE_KIND_ERC20_MULTISIG_SIGNER_REMOVED" => {
+                Some(Self::Erc20MultisigSignerRemoved)
+            }
+            "NODE_SIGNATURE_KIND_ASSET_UPDATE" => Some(Self::AssetUpdate),
+            _ => None,
+        }
+    }
+}
+/// A command that allows the submission of a batch market instruction which wraps up multiple market instructions into a single transaction.
+/// These instructions are then processed sequentially in the following order:
+/// - OrderCancellation
+/// - OrderAmendment
+/// - OrderSubmission
+/// - StopOrderSubmission
+/// where the maximum allow of instructions in a batch is controlled by the network parameter "spam.protection.max.batchSize".
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BatchMarketInstructions {
+    /// List of order cancellations to be processed sequentially.
+    #[prost(message, repeated, tag = "1")]
+    pub cancellations: ::prost::alloc::vec::Vec<OrderCancellation>,
+    /// List of order amendments to be processed sequentially.
+    #[prost(message, repeated, tag = "2")]
+    pub amendments: ::prost::alloc::vec::Vec<OrderAmendment>,
+    /// List of order submissions to be processed sequentially.
+    #[prost(message, repeated, tag = "3")]
+    pub submissions: ::prost::alloc::vec::Vec<OrderSubmission>,
+    /// List of stop order cancellations to be processed sequentially.
+    #[prost(message, repeated, tag = "4")]
+    pub stop_orders_cancellation: ::prost::alloc::vec::Vec<StopOrdersCancellation>,
+    /// List of stop order submissions to be processed sequentially.
+    #[prost(message, repeated, tag = "5")]
+    pub stop_orders_submission: ::prost::alloc::vec::Vec<StopOrdersSubmission>,
+    /// Update margin mode instruction
+    #[prost(message, repeated, tag = "6")]
+    pub update_margin_mode: ::prost::alloc::vec::Vec<UpdateMarginMode>,
+}
+/// A command that allows a party to submit a stop order for a given market.
+/// A stop order is a normal order that remains off the order book and is only submitted if a given trigger is breached from a particular direction.
+/// If both rises-above and falls-below are configured, then if one is triggered the other will be cancelled (OCO).
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StopOrdersSubmission {
+    /// Stop order that will be triggered if the price rises above a given trigger price.
+    #[prost(message, optional, tag = "1")]
+    pub rises_above: ::core::option::Option<StopOrderSetup>,
+    /// Stop order that will be triggered if the price falls below a given trigger price.
+    #[prost(message, optional, tag = "2")]
+    pub falls_below: ::core::option::Option<StopOrderSetup>,
+}
+/// Price and expiry configuration for a stop order.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StopOrderSetup {
+    /// Order to be submitted once the trigger is breached.
+    #[prost(message, optional, tag = "1")]
+    pub order_submission: ::core::option::Option<OrderSubmission>,
+    /// Timestamp, in Unix nanoseconds, for when the stop order should expire. If not set the stop order will not expire.
+    #[prost(int64, optional, tag = "2")]
+    pub expires_at: ::core::option::Option<i64>,
+    /// Strategy to adopt if the expiry time is reached.
+    #[prost(
+        enumeration = "super::super::stop_order::ExpiryStrategy",
+        optional,
+        tag = "3"
+    )]
+    pub expiry_strategy: ::core::option::Option<i32>,
+    /// Indicates if this order is linked to an order or position to derive the order size
+    #[prost(
+        enumeration = "super::super::stop_order::SizeOverrideSetting",
+        optional,
+        tag = "4"
+    )]
+    pub size_override_setting: ::core::option::Option<i32>,
+    /// If this order is linked to a position, provide an optional scaling factor
+    #[prost(message, optional, tag = "5")]
+    pub size_override_value: ::core::option::Option<
+        super::super::stop_order::SizeOverrideValue,
+    >,
+    /// Trigger that will need to be breached for the order to be submitted to the book.
+    #[prost(oneof = "stop_order_setup::Trigger", tags = "100, 101")]
+    pub trigger: ::core::option::Option<stop_order_setup::Trigger>,
+}
+/// Nested message and enum types in `StopOrderSetup`.
+pub mod stop_order_setup {
+    /// Trigger that will need to be breached for the order to be submitted to the book.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Trigger {
+        /// Order will be submitted if the last traded price on the market breaches the given price.
+        #[prost(string, tag = "100")]
+        Price(::prost::alloc::string::String),
+        /// Order will be submitted if the last traded price has moved the given percent from the highest/lowest mark price since the stop order was submitted.
+        #[prost(string, tag = "101")]
+        TrailingPercentOffset(::prost::alloc::string::String),
+    }
+}
+/// A command that instructs the network to cancel untriggered stop orders that were submitted by the sender of this transaction.
+/// If any cancelled stop order is part of an OCO, both stop orders will be cancelled.
+/// It is not possible to cancel another party's stop orders with this command.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StopOrdersCancellation {
+    /// Restrict cancellations to those submitted to the given market. If not set, all stop orders across all markets will be cancelled.
+    #[prost(string, optional, tag = "1")]
+    pub market_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Restrict cancellations to a stop order with the given ID. If set, then a market ID must also be provided.
+    #[prost(string, optional, tag = "2")]
+    pub stop_order_id: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// A command that submits an order to the Vega network for a given market.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OrderSubmission {
+    /// Market ID to submit the order to.
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+    /// Price for the order, the price is an integer, for example `123456` is a correctly
+    /// formatted price of `1.23456` assuming market configured to 5 decimal places,
+    /// required field for limit orders, however it is not required for market orders.
+    /// This field is an unsigned integer scaled to the market's decimal places.
+    #[prost(string, tag = "2")]
+    pub price: ::prost::alloc::string::String,
+    /// Size for the order, for example, in a futures market the size equals the number of units.
+    #[prost(uint64, tag = "3")]
+    pub size: u64,
+    /// Which side of the order book the order is for, e.g. buy or sell.
+    #[prost(enumeration = "super::super::Side", tag = "4")]
+    pub side: i32,
+    /// Time in force indicates how long an order will remain active before it is executed or expires..
+    #[prost(enumeration = "super::super::order::TimeInForce", tag = "5")]
+    pub time_in_force: i32,
+    /// Timestamp, in Unix nanoseconds, for when the order will expire. Can only be set when the order's time-in-force is GTT.
+    #[prost(int64, tag = "6")]
+    pub expires_at: i64,
+    /// Type of the order.
+    #[prost(enumeration = "super::super::order::Type", tag = "7")]
+    pub r#type: i32,
+    /// Arbitrary optional reference for the order, to be used as a human-readable non-unique identifier for the order.
+    #[prost(string, tag = "8")]
+    pub reference: ::prost::alloc::string::String,
+    /// Pegged order details. If set, the order's price will be offset from a particular reference price of the order book at all times.
+    #[prost(message, optional, tag = "9")]
+    pub pegged_order: ::core::option::Option<super::super::PeggedOrder>,
+    /// If set, the order will only be executed if it would not trade on entry to the order book. Only valid for limit orders.
+    #[prost(bool, tag = "10")]
+    pub post_only: bool,
+    /// If set, the order will only be executed if the outcome of the trade moves the trader's position closer to 0.
+    /// Only valid for non-persistent orders.
+    #[prost(bool, tag = "11")]
+    pub reduce_only: bool,
+    /// Iceberg order details. If set, the order will exist on the order book in chunks.
+    #[prost(message, optional, tag = "12")]
+    pub iceberg_opts: ::core::option::Option<IcebergOpts>,
+}
+/// Iceberg order options
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IcebergOpts {
+    /// Size of the order that is made visible and can be traded with during the execution of a single order.
+    #[prost(uint64, tag = "1")]
+    pub peak_size: u64,
+    /// Minimum allowed remaining size of the order before it is replenished back to its peak size.
+    #[prost(uint64, tag = "2")]
+    pub minimum_visible_size: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateMarginMode {
+    /// Market to change margin mode for.
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+    /// Margin mode to use.
+    #[prost(enumeration = "update_margin_mode::Mode", tag = "2")]
+    pub mode: i32,
+    /// Margin factor to use for margin in isolated mode. It is a multiplier that defines how much margin needs to be set aside
+    #[prost(string, optional, tag = "3")]
+    pub margin_factor: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// Nested message and enum types in `UpdateMarginMode`.
+pub mod update_margin_mode {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Mode {
+        /// Never valid.
+        Unspecified = 0,
+        /// Cross margin mode - margin is dynamically acquired and released as a position is marked to market
+        CrossMargin = 1,
+        /// Isolated margin mode - margin for any newly opened position volume is transferred to the margin account when the trade is executed
+        IsolatedMargin = 2,
+    }
+    impl Mode {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Mode::Unspecified => "MODE_UNSPECIFIED",
+                Mode::CrossMargin => "MODE_CROSS_MARGIN",
+                Mode::IsolatedMargin => "MODE_ISOLATED_MARGIN",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "MODE_UNSPECIFIED" => Some(Self::Unspecified),
+                "MODE_CROSS_MARGIN" => Some(Self::CrossMargin),
+                "MODE_ISOLATED_MARGIN" => Some(Self::IsolatedMargin),
+                _ => None,
+            }
+        }
+    }
+}
+/// A command that instructs the network to cancel orders, active or partially filled, that were previously submitted by the sender of this transaction.
+/// It is not possible to cancel another party's order with this command.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OrderCancellation {
+    /// Restrict cancellations to an order with the given ID. If set, then a market ID must also be provided.
+    #[prost(string, tag = "1")]
+    pub order_id: ::prost::alloc::string::String,
+    /// Restrict cancellations to those submitted to the given market. If not set, all stop orders across all markets will be cancelled.
+    #[prost(string, tag = "2")]
+    pub market_id: ::prost::alloc::string::String,
+}
+/// A command that allows a party to update the details of an existing order.
+/// Any field that is left unset or as a default value indicates that this field on the original order will be left unchanged.
+/// It is not possible to change an order's type through this command.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OrderAmendment {
+    /// ID of the order to amend.
+    #[prost(string, tag = "1")]
+    pub order_id: ::prost::alloc::string::String,
+    /// Market ID that the order was originally submitted to.
+    #[prost(string, tag = "2")]
+    pub market_id: ::prost::alloc::string::String,
+    /// New price for the order. This field is an unsigned integer scaled to the market's decimal places.
+    #[prost(string, optional, tag = "3")]
+    pub price: ::core::option::Option<::prost::alloc::string::String>,
+    /// Amend the size for the order by the delta specified:
+    /// - To reduce the size from the current value set a negative integer value
+    /// - To increase the size from the current value, set a positive integer value
+    /// - To leave the size unchanged set a value of zero
+    /// This field needs to be scaled using the market's position decimal places.
+    /// If specified, size must not be set.
+    #[prost(int64, tag = "4")]
+    pub size_delta: i64,
+    /// Timestamp, in Unix nanoseconds, for the new expiry time for the order.
+    #[prost(int64, optional, tag = "5")]
+    pub expires_at: ::core::option::Option<i64>,
+    /// New time in force for the order.
+    #[prost(enumeration = "super::super::order::TimeInForce", tag = "6")]
+    pub time_in_force: i32,
+    /// New pegged offset for the order.
+    /// This field is an unsigned integer scaled to the market's decimal places.
+    #[prost(string, tag = "7")]
+    pub pegged_offset: ::prost::alloc::string::String,
+    /// New pegged reference for the order.
+    #[prost(enumeration = "super::super::PeggedReference", tag = "8")]
+    pub pegged_reference: i32,
+    /// New size for the order.
+    /// Amending the size causes the size and remaining part of the order to be changed by the difference between the original and amended size.
+    /// - Specifying a size smaller than the current size leaves the order at its current order book position.
+    /// - Specifying a size larger than the current size removes and reinserts the order at the back of the price level.
+    /// - Specifying a size that results in the remaining part of the order being reduced to zero cancels the order.
+    /// This field is an unsigned integer scaled to the market's decimal places.
+    /// If specified, size_delta must be set to 0.
+    #[prost(uint64, optional, tag = "9")]
+    pub size: ::core::option::Option<u64>,
+}
+/// A command that indicates to the network the party's intention to supply liquidity to the given market and become a liquidity provider.
+/// An active liquidity provider for a market will earn fees based on the trades that occur in the market.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidityProvisionSubmission {
+    /// Market that the submitter wishes to provide liquidity for.
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+    /// Amount that the submitter will commit as liquidity to the market, specified as a unitless number in the settlement asset of the market.
+    /// This field is an unsigned integer scaled using the asset's decimal places.
+    #[prost(string, tag = "2")]
+    pub commitment_amount: ::prost::alloc::string::String,
+    /// Nominated liquidity fee factor, which is an input to the calculation of taker fees on the market, as per setting fees and rewarding liquidity providers.
+    #[prost(string, tag = "3")]
+    pub fee: ::prost::alloc::string::String,
+    /// Arbitrary reference to be added to every order created out of this liquidity provision submission.
+    #[prost(string, tag = "6")]
+    pub reference: ::prost::alloc::string::String,
+}
+/// Command that allows a liquidity provider to inform the network that they will stop providing liquidity for a market.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidityProvisionCancellation {
+    /// Market that the submitter will stop providing liquidity for.
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+}
+/// Command that allows a liquidity provider to update the details of their existing liquidity commitment.
+/// Any field that is left unset or as a default value indicates that this field on the original submission will be left unchanged.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidityProvisionAmendment {
+    /// Market that the submitter wants to amend the liquidity commitment for.
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+    /// New commitment amount.
+    #[prost(string, tag = "2")]
+    pub commitment_amount: ::prost::alloc::string::String,
+    /// New nominated liquidity fee factor.
+    #[prost(string, tag = "3")]
+    pub fee: ::prost::alloc::string::String,
+    /// New arbitrary reference to be added to every order created out of this liquidity provision submission.
+    #[prost(string, tag = "6")]
+    pub reference: ::prost::alloc::string::String,
+}
+/// Command to instruct the network to process an asset withdrawal from the Vega network.
+/// The process is specific to the destination foreign chain, for example, a withdrawal to Ethereum will generate signatures
+/// that allow funds to be taken across the bridge.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WithdrawSubmission {
+    /// Amount to be withdrawn, as an unsigned integer scaled to the asset's decimal places.
+    #[prost(string, tag = "1")]
+    pub amount: ::prost::alloc::string::String,
+    /// Asset to be withdrawn.
+    #[prost(string, tag = "2")]
+    pub asset: ::prost::alloc::string::String,
+    /// Details specific to the foreign chain, such as the receiver address.
+    #[prost(message, optional, tag = "3")]
+    pub ext: ::core::option::Option<super::super::WithdrawExt>,
+}
+/// Command that allows a token holder to submit a governance proposal that can be voted on by any other token holders, and eventually enacted on the Vega network.
+/// For example this command can be used to propose a new market.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProposalSubmission {
+    /// Arbitrary human-readable reference identifying the proposal.
+    #[prost(string, tag = "1")]
+    pub reference: ::prost::alloc::string::String,
+    /// Proposal terms containing the type and details of the proposal, as well as time spans for voting and enactment.
+    #[prost(message, optional, tag = "2")]
+    pub terms: ::core::option::Option<super::super::ProposalTerms>,
+    /// Rationale behind a proposal.
+    #[prost(message, optional, tag = "3")]
+    pub rationale: ::core::option::Option<super::super::ProposalRationale>,
+}
+/// Terms for a batch governance proposal submission
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BatchProposalSubmissionTerms {
+    /// Closing timestamp in Unix time; adheres to `minClose` and `maxClose` limits.
+    #[prost(int64, tag = "1")]
+    pub closing_timestamp: i64,
+    /// List of individual changes included in the batch proposal.
+    #[prost(message, repeated, tag = "2")]
+    pub changes: ::prost::alloc::vec::Vec<super::super::BatchProposalTermsChange>,
+}
+/// Command that allows a token holder to submit a batch governance proposal that can be voted on by any other token holders, and eventually enacted on the Vega network.
+/// For example this command can be used to propose a new market and a network parameter change with it.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BatchProposalSubmission {
+    /// Arbitrary human-readable reference identifying the proposal.
+    #[prost(string, tag = "1")]
+    pub reference: ::prost::alloc::string::String,
+    /// Proposal terms containing the type and details of the proposal, as well as time spans for voting and enactment.
+    #[prost(message, optional, tag = "2")]
+    pub terms: ::core::option::Option<BatchProposalSubmissionTerms>,
+    /// Rationale behind a proposal.
+    #[prost(message, optional, tag = "3")]
+    pub rationale: ::core::option::Option<super::super::ProposalRationale>,
+}
+/// Command that allows a token holder to vote for or against an active governance proposal.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VoteSubmission {
+    /// Submit vote for the specified proposal ID.
+    #[prost(string, tag = "1")]
+    pub proposal_id: ::prost::alloc::string::String,
+    /// Actual value of the vote.
+    #[prost(enumeration = "super::super::vote::Value", tag = "2")]
+    pub value: i32,
+}
+/// Command to allow a token holder to delegate their tokens to a validator to help secure the network.
+/// A token holder delegating to a validator will earn rewards based on the amount they have delegated, and the performance of the chosen validator.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DelegateSubmission {
+    /// Node ID to delegate stake to.
+    #[prost(string, tag = "1")]
+    pub node_id: ::prost::alloc::string::String,
+    /// Amount of stake to delegate, as an unsigned integer scaled to the governance asset's decimal places.
+    #[prost(string, tag = "2")]
+    pub amount: ::prost::alloc::string::String,
+}
+/// Command to allow a token holder to instruct the network to remove their delegated stake from a given validator node.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UndelegateSubmission {
+    /// Node ID to undelegate stake from.
+    #[prost(string, tag = "1")]
+    pub node_id: ::prost::alloc::string::String,
+    /// Amount to undelegate, as an unsigned integer scaled to the governance asset's decimal places.
+    /// If not set, then all delegations to the given validator node will be removed.
+    #[prost(string, tag = "2")]
+    pub amount: ::prost::alloc::string::String,
+    /// Method of delegation.
+    #[prost(enumeration = "undelegate_submission::Method", tag = "3")]
+    pub method: i32,
+}
+/// Nested message and enum types in `UndelegateSubmission`.
+pub mod undelegate_submission {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Method {
+        Unspecified = 0,
+        /// Undelegate straight away, losing all rewards for the current epoch.
+        Now = 1,
+        /// Undelegate at the end of an epoch, retaining all rewards for the current epoch.
+        AtEndOfEpoch = 2,
+    }
+    impl Method {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Method::Unspecified => "METHOD_UNSPECIFIED",
+                Method::Now => "METHOD_NOW",
+                Method::AtEndOfEpoch => "METHOD_AT_END_OF_EPOCH",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "METHOD_UNSPECIFIED" => Some(Self::Unspecified),
+                "METHOD_NOW" => Some(Self::Now),
+                "METHOD_AT_END_OF_EPOCH" => Some(Self::AtEndOfEpoch),
+                _ => None,
+            }
+        }
+    }
+}
+/// Command that allows a party to move assets from one account to another.
+/// A transfer can be set up as a single one-off transfer, or a recurring transfer that occurs once at the start of each epoch.
+/// Each transfer incurs a fee as specified by the network parameter `transfer.fee.factor`
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Transfer {
+    /// Account type from which the funds of the party should be taken.
+    #[prost(enumeration = "super::super::AccountType", tag = "1")]
+    pub from_account_type: i32,
+    /// Public key of the destination account.
+    #[prost(string, tag = "2")]
+    pub to: ::prost::alloc::string::String,
+    /// Type of the destination account.
+    #[prost(enumeration = "super::super::AccountType", tag = "3")]
+    pub to_account_type: i32,
+    /// Asset ID of the asset to be transferred.
+    #[prost(string, tag = "4")]
+    pub asset: ::prost::alloc::string::String,
+    /// Amount to be taken from the source account, as an unsigned integer scaled to the asset's decimal places.
+    #[prost(string, tag = "5")]
+    pub amount: ::prost::alloc::string::String,
+    /// Reference to be attached to the transfer.
+    #[prost(string, tag = "6")]
+    pub reference: ::prost::alloc::string::String,
+    /// AMM key from which assets are to be transferred, if applicable.
+    /// The submitter of the transaction must be the owner of this AMM key.
+    /// If provided, the 'from_account_type' must be REWARDS_VESTED, and the asset in this account
+    /// must match the asset specified in the transfer.
+    #[prost(string, optional, tag = "7")]
+    pub from: ::core::option::Option<::prost::alloc::string::String>,
+    /// Specific details of the transfer.
+    #[prost(oneof = "transfer::Kind", tags = "101, 102")]
+    pub kind: ::core::option::Option<transfer::Kind>,
+}
+/// Nested message and enum types in `Transfer`.
+pub mod transfer {
+    /// Specific details of the transfer.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Kind {
+        /// Details of a one-off transfer that is executed once at a specified time.
+        #[prost(message, tag = "101")]
+        OneOff(super::OneOffTransfer),
+        /// Details of a transfer that is executed once every epoch until stopped.
+        #[prost(message, tag = "102")]
+        Recurring(super::RecurringTransfer),
+    }
+}
+/// Details for a one-off transfer.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OneOffTransfer {
+    /// Timestamp, in Unix nanoseconds, for when the transfer should be executed, i.e., assets transferred into the receiver's account.
+    #[prost(int64, tag = "1")]
+    pub deliver_on: i64,
+}
+/// Details for a recurring transfer
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RecurringTransfer {
+    /// First epoch from which this transfer shall be executed.
+    #[prost(uint64, tag = "1")]
+    pub start_epoch: u64,
+    /// Last epoch at which this transfer shall be executed.
+    #[prost(uint64, optional, tag = "2")]
+    pub end_epoch: ::core::option::Option<u64>,
+    /// Factor that the initial transfer amount is multiplied by for each epoch that it is executed.
+    /// For example if the initial transfer amount is 1000 and the factor is 0.5, then the amounts transferred per epoch will be 1000, 500, 250, 125, etc.
+    #[prost(string, tag = "3")]
+    pub factor: ::prost::alloc::string::String,
+    /// Optional parameter defining how a transfer is dispatched.
+    #[prost(message, optional, tag = "4")]
+    pub dispatch_strategy: ::core::option::Option<super::super::DispatchStrategy>,
+}
+/// Command that can be used by the party that initiated a transfer to instruct the network to stop an active recurring transaction.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CancelTransfer {
+    /// Transfer ID of the transfer to cancel.
+    #[prost(string, tag = "1")]
+    pub transfer_id: ::prost::alloc::string::String,
+}
+/// Command that can be used by a validator to instruct the network to generate signatures to add or remove validators from the multisig-control contract.
+/// Signatures can only be generated for validator nodes that have been promoted or demoted from the consensus validator set, and any attempt to generate signatures for another node will be rejected.
+/// The generated signatures can only be submitted to the contract by the Ethereum addresses included in the command.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IssueSignatures {
+    /// Ethereum address which will submit the signatures to the smart contract.
+    #[prost(string, tag = "1")]
+    pub submitter: ::prost::alloc::string::String,
+    /// What kind of signatures to generate, namely for whether a signer is being added or removed.
+    #[prost(enumeration = "NodeSignatureKind", tag = "2")]
+    pub kind: i32,
+    /// Node ID of the validator node that will be signed in or out of the smart contract.
+    #[prost(string, tag = "3")]
+    pub validator_node_id: ::prost::alloc::string::String,
+    /// Chain ID of the bridge to generate signatures for.
+    #[prost(string, tag = "4")]
+    pub chain_id: ::prost::alloc::string::String,
+}
+/// Command that a party can use to instruct the network to create a new referral set on the network.
+/// The submitter of this command will become the referrer of the new set and cannot be the referrer or a referee of another set.
+/// A referrer can use the referral set ID as a referral code to attract others to the Vega network and have fees reduced for the referral set.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateReferralSet {
+    /// Whether or not the referral set should be considered a team that can participate in team games on the network.
+    #[prost(bool, tag = "1")]
+    pub is_team: bool,
+    /// Team details, if the referral set is to be considered a team.
+    #[prost(message, optional, tag = "2")]
+    pub team: ::core::option::Option<create_referral_set::Team>,
+    /// Should a referral set be created as well or only a team.
+    /// the default is false so the existing behaviour is kept if older versions of the protobufs are used.
+    #[prost(bool, tag = "3")]
+    pub do_not_create_referral_set: bool,
+}
+/// Nested message and enum types in `CreateReferralSet`.
+pub mod create_referral_set {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Team {
+        /// Name of the team.
+        #[prost(string, tag = "10")]
+        pub name: ::prost::alloc::string::String,
+        /// External link to the team's homepage.
+        #[prost(string, optional, tag = "11")]
+        pub team_url: ::core::option::Option<::prost::alloc::string::String>,
+        /// External link to an avatar for the team.
+        #[prost(string, optional, tag = "12")]
+        pub avatar_url: ::core::option::Option<::prost::alloc::string::String>,
+        /// Whether or not the team is closed to new party members.
+        #[prost(bool, tag = "13")]
+        pub closed: bool,
+        /// List of public keys that are allowed to join the team.
+        /// Only applicable to closed teams. Removing a party from the allow list does not remove
+        /// the party from the team.
+        #[prost(string, repeated, tag = "14")]
+        pub allow_list: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    }
+}
+/// A command that allows the referrer of a referral set to update team details for a referral set.
+/// Any field that is left unset or has a default value indicates that this field on the original referral set will be left unchanged.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateReferralSet {
+    /// ID of the referral set to update.
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Whether or not the referral set should be considered a team that can participate in team games on the network.
+    #[prost(bool, tag = "2")]
+    pub is_team: bool,
+    /// Team details, if the referral set is to be considered a team.
+    #[prost(message, optional, tag = "3")]
+    pub team: ::core::option::Option<update_referral_set::Team>,
+}
+/// Nested message and enum types in `UpdateReferralSet`.
+pub mod update_referral_set {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Team {
+        /// New name of the team.
+        #[prost(string, optional, tag = "10")]
+        pub name: ::core::option::Option<::prost::alloc::string::String>,
+        /// New link to the team's homepage.
+        #[prost(string, optional, tag = "11")]
+        pub team_url: ::core::option::Option<::prost::alloc::string::String>,
+        /// New link to an avatar for the team.
+        #[prost(string, optional, tag = "12")]
+        pub avatar_url: ::core::option::Option<::prost::alloc::string::String>,
+        /// Whether or not the team is closed to new party members. When closed, only parties specified in the allow list can
+        /// join the team.
+        #[prost(bool, optional, tag = "13")]
+        pub closed: ::core::option::Option<bool>,
+        /// List of public keys that are allowed to join the team.
+        /// Only applicable to closed teams. Removing a party from the allow list does not remove
+        /// the party from the team.
+        #[prost(string, repeated, tag = "14")]
+        pub allow_list: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    }
+}
+/// Command that allows the submitter to join a referral set and earn a collective reduction in fees based on the activity of all members of that set.
+/// A party that joins a referral set is called a referee. A referee can only be a member of one referral set and cannot themselves be or become a referrer.
+/// To switch to another referral set, a subsequent command can be sent and the switch will take effect at the end of the epoch.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ApplyReferralCode {
+    /// Referral code, normally the referral set ID, for the party to join.
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Should the key applying the referral code also join the team.
+    /// the default is false so the existing behaviour is kept if older versions of the protobufs are used.
+    #[prost(bool, tag = "2")]
+    pub do_not_join_team: bool,
+}
+/// Command that allows the submitter to join a team or change teams if they are already a member of a team.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct JoinTeam {
+    /// ID of the team to join, this is the same as the referral code used to generate the team.
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+}
+/// Command to associate metadata to a public key, known as a party ID.
+/// Partial update is not supported, meaning previous values must be included in
+/// the update, otherwise they are removed.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdatePartyProfile {
+    /// Alias given to the party. It must be unique network-wide.
+    #[prost(string, tag = "1")]
+    pub alias: ::prost::alloc::string::String,
+    /// Freeform data to associate to the party.
+    /// Support a maximum of 10 entries.
+    #[prost(message, repeated, tag = "2")]
+    pub metadata: ::prost::alloc::vec::Vec<super::super::Metadata>,
+}
+/// Command to create an automated market maker for a given market.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SubmitAmm {
+    /// Market ID for which to create an AMM.
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+    /// Amount to be committed to the AMM.
+    #[prost(string, tag = "2")]
+    pub commitment_amount: ::prost::alloc::string::String,
+    /// Slippage tolerance used for rebasing the AMM if its base price crosses with existing order
+    #[prost(string, tag = "3")]
+    pub slippage_tolerance: ::prost::alloc::string::String,
+    /// Concentrated liquidity parameters defining the shape of the AMM's volume curves.
+    #[prost(message, optional, tag = "4")]
+    pub concentrated_liquidity_parameters: ::core::option::Option<
+        submit_amm::ConcentratedLiquidityParameters,
+    >,
+    /// Nominated liquidity fee factor, which is an input to the calculation of taker fees on the market.
+    #[prost(string, tag = "5")]
+    pub proposed_fee: ::prost::alloc::string::String,
+}
+/// Nested message and enum types in `SubmitAMM`.
+pub mod submit_amm {
+    /// Liquidity parameters that define the size and range of the AMM's tradeable volume.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ConcentratedLiquidityParameters {
+        /// Price at which the AMM will stop quoting sell volume. If not supplied the AMM will never hold a short position.
+        #[prost(string, optional, tag = "1")]
+        pub upper_bound: ::core::option::Option<::prost::alloc::string::String>,
+        /// Price at which the AMM will stop quoting buy volume. If not supplied the AMM will never hold a long position.
+        #[prost(string, optional, tag = "2")]
+        pub lower_bound: ::core::option::Option<::prost::alloc::string::String>,
+        /// Price that the AMM will quote as its "fair price" when its position is zero.
+        #[prost(string, tag = "3")]
+        pub base: ::prost::alloc::string::String,
+        /// Leverage at upper bound. If not set the markets risk-factors will be used to calculate leverage.
+        #[prost(string, optional, tag = "4")]
+        pub leverage_at_upper_bound: ::core::option::Option<
+            ::prost::alloc::string::String,
+        >,
+        /// Leverage at lower bound. If not set the markets risk-factors will be used to calculate leverage.
+        #[prost(string, optional, tag = "5")]
+        pub leverage_at_lower_bound: ::core::option::Option<
+            ::prost::alloc::string::String,
+        >,
+    }
+}
+/// Command to amend an existing automated market maker on a market.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AmendAmm {
+    /// Market ID for the AMM to be amended.
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+    /// Amount to be committed to the AMM. If not supplied the commitment will remain unchanged.
+    #[prost(string, optional, tag = "2")]
+    pub commitment_amount: ::core::option::Option<::prost::alloc::string::String>,
+    /// Slippage tolerance for rebasing position when updating the AMM.
+    #[prost(string, tag = "3")]
+    pub slippage_tolerance: ::prost::alloc::string::String,
+    /// Concentrated liquidity parameters defining the shape of the AMM's volume curves. If not supplied the parameters will remain unchanged.
+    #[prost(message, optional, tag = "4")]
+    pub concentrated_liquidity_parameters: ::core::option::Option<
+        amend_amm::ConcentratedLiquidityParameters,
+    >,
+    /// Nominated liquidity fee factor, which is an input to the calculation of taker fees on the market. If not supplied the proposed fee will remain unchanged.
+    #[prost(string, optional, tag = "5")]
+    pub proposed_fee: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// Nested message and enum types in `AmendAMM`.
+pub mod amend_amm {
+    /// Liquidity parameters that define the size and range of the AMM's tradeable volume.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ConcentratedLiquidityParameters {
+        /// Price at which the AMM will stop quoting sell volume. If not supplied the AMM will never hold a short position.
+        #[prost(string, optional, tag = "1")]
+        pub upper_bound: ::core::option::Option<::prost::alloc::string::String>,
+        /// Price at which the AMM will stop quoting buy volume. If not supplied the AMM will never hold a long position.
+        #[prost(string, optional, tag = "2")]
+        pub lower_bound: ::core::option::Option<::prost::alloc::string::String>,
+        /// Price that the AMM will quote as its "fair price" when its position is zero.
+        #[prost(string, tag = "3")]
+        pub base: ::prost::alloc::string::String,
+        /// Leverage at upper bound. If not set the markets risk-factors will be used to calculate leverage.
+        #[prost(string, optional, tag = "4")]
+        pub leverage_at_upper_bound: ::core::option::Option<
+            ::prost::alloc::string::String,
+        >,
+        /// Leverage at lower bound. If not set the markets risk-factors will be used to calculate leverage.
+        #[prost(string, optional, tag = "5")]
+        pub leverage_at_lower_bound: ::core::option::Option<
+            ::prost::alloc::string::String,
+        >,
+    }
+}
+/// Command to cancel an automated market maker for a given market.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CancelAmm {
+    /// Market ID to cancel an AMM for.
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+    /// Method to use to cancel the AMM.
+    #[prost(enumeration = "cancel_amm::Method", tag = "2")]
+    pub method: i32,
+}
+/// Nested message and enum types in `CancelAMM`.
+pub mod cancel_amm {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Method {
+        Unspecified = 0,
+        /// Cancellation will be immediate and any open positions will be transferred to the network for liquidation.
+        Immediate = 1,
+        /// AMM will only trade to reduce its position, and will be cancelled once its position reaches zero.
+        ReduceOnly = 2,
+    }
+    impl Method {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Method::Unspecified => "METHOD_UNSPECIFIED",
+                Method::Immediate => "METHOD_IMMEDIATE",
+                Method::ReduceOnly => "METHOD_REDUCE_ONLY",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "METHOD_UNSPECIFIED" => Some(Self::Unspecified),
+                "METHOD_IMMEDIATE" => Some(Self::Immediate),
+                "METHOD_REDUCE_ONLY" => Some(Self::ReduceOnly),
+                _ => None,
+            }
+        }
+    }
+}
+/// Internal transactions used to convey delayed transactions to be included in the next block.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DelayedTransactionsWrapper {
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    pub transactions: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(uint64, tag = "2")]
+    pub height: u64,
+}
+/// Command to submit new oracle data from third party providers
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OracleDataSubmission {
+    /// Source from which the data is coming from.
+    #[prost(enumeration = "oracle_data_submission::OracleSource", tag = "1")]
+    pub source: i32,
+    /// Data provided by the data source.
+    #[prost(bytes = "vec", tag = "2")]
+    pub payload: ::prost::alloc::vec::Vec<u8>,
+}
+/// Nested message and enum types in `OracleDataSubmission`.
+pub mod oracle_data_submission {
+    /// Supported oracle sources
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum OracleSource {
+        /// Default value
+        Unspecified = 0,
+        /// Specifies that the payload will be base64 encoded JSON conforming to the Open Oracle standard.
+        OpenOracle = 1,
+        /// Specifies that the payload will be base64 encoded JSON, but does not specify the shape of the data.
+        Json = 2,
+        /// Specifies that the payload will be base64 encoded JSON conforming to the ETH standard.
+        Ethereum = 3,
+    }
+    impl OracleSource {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                OracleSource::Unspecified => "ORACLE_SOURCE_UNSPECIFIED",
+                OracleSource::OpenOracle => "ORACLE_SOURCE_OPEN_ORACLE",
+                OracleSource::Json => "ORACLE_SOURCE_JSON",
+                OracleSource::Ethereum => "ORACLE_SOURCE_ETHEREUM",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "ORACLE_SOURCE_UNSPECIFIED" => Some(Self::Unspecified),
+                "ORACLE_SOURCE_OPEN_ORACLE" => Some(Self::OpenOracle),
+                "ORACLE_SOURCE_JSON" => Some(Self::Json),
+                "ORACLE_SOURCE_ETHEREUM" => Some(Self::Ethereum),
+                _ => None,
+            }
+        }
+    }
+}
+/// Input data for a transaction containing a network command for the Vega network to execute.
+/// Once populated the protobuf message should be marshalled into a byte array and included in a transaction message.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InputData {
+    /// Arbitrary number used to provide uniqueness to the signature of two otherwise identical input data, preventing replay attacks.
+    /// Must be set to a different value for all new transactions sent by a party. It is advised to generate this number randomly.
+    #[prost(uint64, tag = "1")]
+    pub nonce: u64,
+    /// Block height which has been used to calculate the transaction proof-of-work.
+    #[prost(uint64, tag = "2")]
+    pub block_height: u64,
+    #[prost(
+        oneof = "input_data::Command",
+        tags = "1001, 1002, 1003, 1004, 1005, 1006, 1007, 1008, 1009, 1010, 1011, 1012, 1013, 1014, 1015, 1016, 1017, 1018, 1019, 1020, 1021, 1022, 1023, 1024, 1025, 1026, 1027, 2002, 2003, 2004, 2005, 2006, 2007, 2008, 2009, 2010, 3001, 4000"
+    )]
+    pub command: ::core::option::Option<input_data::Command>,
+}
+/// Nested message and enum types in `InputData`.
+pub mod input_data {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Command {
+        /// Command to submit an order.
+        #[prost(message, tag = "1001")]
+        OrderSubmission(super::OrderSubmission),
+        /// Command to cancel an order.
+        #[prost(message, tag = "1002")]
+        OrderCancellation(super::OrderCancellation),
+        /// Command to amend an order.
+        #[prost(message, tag = "1003")]
+        OrderAmendment(super::OrderAmendment),
+        /// Command to submit a withdrawal.
+        #[prost(message, tag = "1004")]
+        WithdrawSubmission(super::WithdrawSubmission),
+        /// Command to submit a governance proposal.
+        #[prost(message, tag = "1005")]
+        ProposalSubmission(super::ProposalSubmission),
+        /// Command to submit a vote on a governance proposal.
+        #[prost(message, tag = "1006")]
+        VoteSubmission(super::VoteSubmission),
+        /// Command to submit a liquidity commitment.
+        #[prost(message, tag = "1007")]
+        LiquidityProvisionSubmission(super::LiquidityProvisionSubmission),
+        /// Command to delegate tokens to a validator.
+        #[prost(message, tag = "1008")]
+        DelegateSubmission(super::DelegateSubmission),
+        /// Command to remove tokens delegated to a validator.
+        #[prost(message, tag = "1009")]
+        UndelegateSubmission(super::UndelegateSubmission),
+        /// Command to cancel a liquidity commitment.
+        #[prost(message, tag = "1010")]
+        LiquidityProvisionCancellation(super::LiquidityProvisionCancellation),
+        /// Command to amend a liquidity commitment.
+        #[prost(message, tag = "1011")]
+        LiquidityProvisionAmendment(super::LiquidityProvisionAmendment),
+        /// Command to submit a transfer.
+        #[prost(message, tag = "1012")]
+        Transfer(super::Transfer),
+        /// Command to cancel a recurring transfer.
+        #[prost(message, tag = "1013")]
+        CancelTransfer(super::CancelTransfer),
+        /// Command used by a node operator to announce its node as a pending validator.
+        #[prost(message, tag = "1014")]
+        AnnounceNode(super::AnnounceNode),
+        /// Command to submit a batch of order instructions.
+        #[prost(message, tag = "1015")]
+        BatchMarketInstructions(super::BatchMarketInstructions),
+        /// Command to submit a pair of stop orders.
+        #[prost(message, tag = "1016")]
+        StopOrdersSubmission(super::StopOrdersSubmission),
+        /// Command to cancel stop orders.
+        #[prost(message, tag = "1017")]
+        StopOrdersCancellation(super::StopOrdersCancellation),
+        /// Command to create a referral set.
+        #[prost(message, tag = "1018")]
+        CreateReferralSet(super::CreateReferralSet),
+        /// Command to update a referral set.
+        #[prost(message, tag = "1019")]
+        UpdateReferralSet(super::UpdateReferralSet),
+        /// Command to apply a referral code.
+        #[prost(message, tag = "1020")]
+        ApplyReferralCode(super::ApplyReferralCode),
+        /// Command to update the margin mode of a party in a market.
+        #[prost(message, tag = "1021")]
+        UpdateMarginMode(super::UpdateMarginMode),
+        /// Command to join a team.
+        #[prost(message, tag = "1022")]
+        JoinTeam(super::JoinTeam),
+        /// Command to submit a batch governance proposal.
+        #[prost(message, tag = "1023")]
+        BatchProposalSubmission(super::BatchProposalSubmission),
+        /// Command to update a party's profile.
+        #[prost(message, tag = "1024")]
+        UpdatePartyProfile(super::UpdatePartyProfile),
+        /// Command to submit an AMM pool to a market
+        #[prost(message, tag = "1025")]
+        SubmitAmm(super::SubmitAmm),
+        /// Command to amend an AMM pool on a market
+        #[prost(message, tag = "1026")]
+        AmendAmm(super::AmendAmm),
+        /// Command to cancel an AMM pool on a market
+        #[prost(message, tag = "1027")]
+        CancelAmm(super::CancelAmm),
+        /// Validator command sent automatically to vote on that validity of an external resource.
+        #[prost(message, tag = "2002")]
+        NodeVote(super::NodeVote),
+        /// Validator command sent automatically to provide signatures for the Ethereum bridge.
+        #[prost(message, tag = "2003")]
+        NodeSignature(super::NodeSignature),
+        /// Validator command sent automatically to notify the Vega chain of an off-chain event.
+        #[prost(message, tag = "2004")]
+        ChainEvent(super::ChainEvent),
+        /// Validator command sent manually by a node operator to rotate their node's Vega keys.
+        #[prost(message, tag = "2005")]
+        KeyRotateSubmission(super::KeyRotateSubmission),
+        /// Validator command sent automatically to reach consensus on floating point values.
+        #[prost(message, tag = "2006")]
+        StateVariableProposal(super::StateVariableProposal),
+        /// Validator command sent automatically to signal regular participation in the network.
+        #[prost(message, tag = "2007")]
+        ValidatorHeartbeat(super::ValidatorHeartbeat),
+        /// Validator command sent manually by a node operator to rotate their node's Ethereum keys.
+        #[prost(message, tag = "2008")]
+        EthereumKeyRotateSubmission(super::EthereumKeyRotateSubmission),
+        /// Validator command sent manually to propose a protocol upgrade.
+        #[prost(message, tag = "2009")]
+        ProtocolUpgradeProposal(super::ProtocolUpgradeProposal),
+        /// Command to request signatures to amend the multisig-control contract.
+        #[prost(message, tag = "2010")]
+        IssueSignatures(super::IssueSignatures),
+        /// Command to submit external oracle data.
+        #[prost(message, tag = "3001")]
+        OracleDataSubmission(super::OracleDataSubmission),
+        /// Internal transactions used to convey delayed transactions to be included in the next block.
+        #[prost(message, tag = "4000")]
+        DelayedTransactionsWrapper(super::DelayedTransactionsWrapper),
+    }
+}
+/// Transaction containing a command that can be sent to instruct the network to execute an action.
+/// A transaction contains a byte string representation of the input data which must then be signed, with the signature added to the transaction.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Transaction {
+    /// Protobuf message of type `InputData` marshalled into bytes. If the transaction version is V3 or higher then this bytes
+    /// string must be prepended with `chain_id_as_byes + \0`.
+    #[prost(bytes = "vec", tag = "1")]
+    pub input_data: ::prost::alloc::vec::Vec<u8>,
+    /// Signature of the input data field, signed by the sender of this transaction.
+    #[prost(message, optional, tag = "2")]
+    pub signature: ::core::option::Option<Signature>,
+    /// Version of the transaction.
+    #[prost(enumeration = "TxVersion", tag = "2000")]
+    pub version: i32,
+    /// Proof-of-work containing the random transaction ID used by the client and the nonce.
+    #[prost(message, optional, tag = "3000")]
+    pub pow: ::core::option::Option<ProofOfWork>,
+    /// Sender of the transaction.
+    #[prost(oneof = "transaction::From", tags = "1001, 1002")]
+    pub from: ::core::option::Option<transaction::From>,
+}
+/// Nested message and enum types in `Transaction`.
+pub mod transaction {
+    /// Sender of the transaction.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum From {
+        /// Hex-encoded address of the sender. Not supported yet.
+        #[prost(string, tag = "1001")]
+        Address(::prost::alloc::string::String),
+        /// Hex-encoded public key of the sender.
+        #[prost(string, tag = "1002")]
+        PubKey(::prost::alloc::string::String),
+    }
+}
+/// Components needed for the network to verify proof-of-work.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProofOfWork {
+    /// Unique transaction identifier used to seed the proof-of-work hash.
+    #[prost(string, tag = "1")]
+    pub tid: ::prost::alloc::string::String,
+    /// Number which, combined with the transaction identifier, will produce a hash with the required number of leading zeros to be accepted by the network.
+    #[prost(uint64, tag = "2")]
+    pub nonce: u64,
+}
+/// Transaction versions to maintain backwards compatibility of transaction formats.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum TxVersion {
+    /// Transaction version is unspecified.
+    Unspecified = 0,
+    /// Transaction requires the addition of a proof-of-work calculation.
+    V2 = 2,
+    /// Transaction input data contains a prepended chain ID to prevent use of a single transaction across multiple networks.
+    V3 = 3,
+}
+impl TxVersion {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            TxVersion::Unspecified => "TX_VERSION_UNSPECIFIED",
+            TxVersion::V2 => "TX_VERSION_V2",
+            TxVersion::V3 => "TX_VERSION_V3",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "TX_VERSION_UNSPECIFIED" => Some(Self::Unspecified),
+            "TX_VERSION_V2" => Some(Self::V2),
+            "TX_VERSION_V3" => Some(Self::V3),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,8374 @@
+impl serde::Serialize for AmendAmm {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.market_id.is_empty() {
+            len += 1;
+        }
+        if self.commitment_amount.is_some() {
+            len += 1;
+        }
+        if !self.slippage_tolerance.is_empty() {
+            len += 1;
+        }
+        if self.concentrated_liquidity_parameters.is_some() {
+            len += 1;
+        }
+        if self.proposed_fee.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.commands.v1.AmendAMM", len)?;
+        if !self.market_id.is_empty() {
+            struct_ser.serialize_field("marketId", &self.market_id)?;
+        }
+        if let Some(v) = self.commitment_amount.as_ref() {
+            struct_ser.serialize_field("commitmentAmount", v)?;
+        }
+        if !self.slippage_tolerance.is_empty() {
+            struct_ser.serialize_field("slippageTolerance", &self.slippage_tolerance)?;
+        }
+        if let Some(v) = self.concentrated_liquidity_parameters.as_ref() {
+            struct_ser.serialize_field("concentratedLiquidityParameters", v)?;
+        }
+        if let Some(v) = self.proposed_fee.as_ref() {
+            struct_ser.serialize_field("proposedFee", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for AmendAmm {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "market_id",
+            "marketId",
+            "commitment_amount",
+            "commitmentAmount",
+            "slippage_tolerance",
+            "slippageTolerance",
+            "concentrated_liquidity_parameters",
+            "concentratedLiquidityParameters",
+            "proposed_fee",
+            "proposedFee",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            MarketId,
+            CommitmentAmount,
+            SlippageTolerance,
+            ConcentratedLiquidityParameters,
+            ProposedFee,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "marketId" | "market_id" => Ok(GeneratedField::MarketId),
+                            "commitmentAmount" | "commitment_amount" => Ok(GeneratedField::CommitmentAmount),
+                            "slippageTolerance" | "slippage_tolerance" => Ok(GeneratedField::SlippageTolerance),
+                            "concentratedLiquidityParameters" | "concentrated_liquidity_parameters" => Ok(GeneratedField::ConcentratedLiquidityParameters),
+                            "proposedFee" | "proposed_fee" => Ok(GeneratedField::ProposedFee),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = AmendAmm;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.commands.v1.AmendAMM")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<AmendAmm, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut market_id__ = None;
+                let mut commitment_amount__ = None;
+                let mut slippage_tolerance__ = None;
+                let mut concentrated_liquidity_parameters__ = None;
+                let mut proposed_fee__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::MarketId => {
+                            if market_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("marketId"));
+                            }
+                            market_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::CommitmentAmount => {
+                            if commitment_amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("commitmentAmount"));
+                            }
+                            commitment_amount__ = map_.next_value()?;
+                        }
+                        GeneratedField::SlippageTolerance => {
+                            if slippage_tolerance__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("slippageTolerance"));
+                            }
+                            slippage_tolerance__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ConcentratedLiquidityParameters => {
+                            if concentrated_liquidity_parameters__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("concentratedLiquidityParameters"));
+                            }
+                            concentrated_liquidity_parameters__ = map_.next_value()?;
+                        }
+                        GeneratedField::ProposedFee => {
+                            if proposed_fee__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposedFee"));
+                            }
+                            proposed_fee__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(AmendAmm {
+                    market_id: market_id__.unwrap_or_default(),
+                    commitment_amount: commitment_amount__,
+                    slippage_tolerance: slippage_tolerance__.unwrap_or_default(),
+                    concentrated_liquidity_parameters: concentrated_liquidity_parameters__,
+                    proposed_fee: proposed_fee__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.commands.v1.AmendAMM", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for amend_amm::ConcentratedLiquidityParameters {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.upper_bound.is_some() {
+            len += 1;
+        }
+        if self.lower_bound.is_some() {
+            len += 1;
+        }
+        if !self.base.is_empty() {
+            len += 1;
+        }
+        if self.leverage_at_upper_bound.is_some() {
+            len += 1;
+        }
+        if self.leverage_at_lower_bound.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.commands.v1.AmendAMM.ConcentratedLiquidityParameters", len)?;
+        if let Some(v) = self.upper_bound.as_ref() {
+            struct_ser.serialize_field("upperBound", v)?;
+        }
+        if let Some(v) = self.lower_bound.as_ref() {
+            struct_ser.serialize_field("lowerBound", v)?;
+        }
+        if !self.base.is_empty() {
+            struct_ser.serialize_field("base", &self.base)?;
+        }
+        if let Some(v) = self.leverage_at_upper_bound.as_ref() {
+            struct_ser.serialize_field("leverageAtUpperBound", v)?;
+        }
+        if let Some(v) = self.leverage_at_lower_bound.as_ref() {
+            struct_ser.serialize_field("leverageAtLowerBound", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for amend_amm::ConcentratedLiquidityParameters {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "upper_bound",
+            "upperBound",
+            "lower_bound",
+            "lowerBound",
+            "base",
+            "leverage_at_upper_bound",
+            "leverageAtUpperBound",
+            "leverage_at_lower_bound",
+            "leverageAtLowerBound",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            UpperBound,
+            LowerBound,
+            Base,
+            LeverageAtUpperBound,
+            LeverageAtLowerBound,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "upperBound" | "upper_bound" => Ok(GeneratedField::UpperBound),
+                            "lowerBound" | "lower_bound" => Ok(GeneratedField::LowerBound),
+                            "base" => Ok(GeneratedField::Base),
+                            "leverageAtUpperBound" | "leverage_at_upper_bound" => Ok(GeneratedField::LeverageAtUpperBound),
+                            "leverageAtLowerBound" | "leverage_at_lower_bound" => Ok(GeneratedField::LeverageAtLowerBound),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = amend_amm::ConcentratedLiquidityParameters;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.commands.v1.AmendAMM.ConcentratedLiquidityParameters")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<amend_amm::ConcentratedLiquidityParameters, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut upper_bound__ = None;
+                let mut lower_bound__ = None;
+                let mut base__ = None;
+                let mut leverage_at_upper_bound__ = None;
+                let mut leverage_at_lower_bound__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::UpperBound => {
+                            if upper_bound__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("upperBound"));
+                            }
+                            upper_bound__ = map_.next_value()?;
+                        }
+                        GeneratedField::LowerBound => {
+                            if lower_bound__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lowerBound"));
+                            }
+                            lower_bound__ = map_.next_value()?;
+                        }
+                        GeneratedField::Base => {
+                            if base__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("base"));
+                            }
+                            base__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::LeverageAtUpperBound => {
+                            if leverage_at_upper_bound__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("leverageAtUpperBound"));
+                            }
+                            leverage_at_upper_bound__ = map_.next_value()?;
+                        }
+                        GeneratedField::LeverageAtLowerBound => {
+                            if leverage_at_lower_bound__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("leverageAtLowerBound"));
+                            }
+                            leverage_at_lower_bound__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(amend_amm::ConcentratedLiquidityParameters {
+                    upper_bound: upper_bound__,
+                    lower_bound: lower_bound__,
+                    base: base__.unwrap_or_default(),
+                    leverage_at_upper_bound: leverage_at_upper_bound__,
+                    leverage_at_lower_bound: leverage_at_lower_bound__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.commands.v1.AmendAMM.ConcentratedLiquidityParameters", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for AnnounceNode {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.vega_pub_key.is_empty() {
+            len += 1;
+        }
+        if !self.ethereum_address.is_empty() {
+            len += 1;
+        }
+        if !self.chain_pub_key.is_empty() {
+            len += 1;
+        }
+        if !self.info_url.is_empty() {
+            len += 1;
+        }
+        if !self.country.is_empty() {
+            len += 1;
+        }
+        if !self.id.is_empty() {
+            len += 1;
+        }
+        if !self.name.is_empty() {
+            len += 1;
+        }
+        if !self.avatar_url.is_empty() {
+            len += 1;
+        }
+        if self.vega_pub_key_index != 0 {
+            len += 1;
+        }
+        if self.from_epoch != 0 {
+            len += 1;
+        }
+        if self.ethereum_signature.is_some() {
+            len += 1;
+        }
+        if self.vega_signature.is_some() {
+            len += 1;
+        }
+        if !self.submitter_address.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.commands.v1.AnnounceNode", len)?;
+        if !self.vega_pub_key.is_empty() {
+            struct_ser.serialize_field("vegaPubKey", &self.vega_pub_key)?;
+        }
+        if !self.ethereum_address.is_empty() {
+            struct_ser.serialize_field("ethereumAddress", &self.ethereum_address)?;
+        }
+        if !self.chain_pub_key.is_empty() {
+            struct_ser.serialize_field("chainPubKey", &self.chain_pub_key)?;
+        }
+        if !self.info_url.is_empty() {
+            struct_ser.serialize_field("infoUrl", &self.info_url)?;
+        }
+        if !self.country.is_empty() {
+            struct_ser.serialize_field("country", &self.country)?;
+        }
+        if !self.id.is_empty() {
+            struct_ser.serialize_field("id", &self.id)?;
+        }
+        if !self.name.is_empty() {
+            struct_ser.serialize_field("name", &self.name)?;
+        }
+        if !self.avatar_url.is_empty() {
+            struct_ser.serialize_field("avatarUrl", &self.avatar_url)?;
+        }
+        if self.vega_pub_key_index != 0 {
+            struct_ser.serialize_field("vegaPubKeyIndex", &self.vega_pub_key_index)?;
+        }
+        if self.from_epoch != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("fromEpoch", ToString::to_string(&self.from_epoch).as_str())?;
+        }
+        if let Some(v) = self.ethereum_signature.as_ref() {
+            struct_ser.serialize_field("ethereumSignature", v)?;
+        }
+        if let Some(v) = self.vega_signature.as_ref() {
+            struct_ser.serialize_field("vegaSignature", v)?;
+        }
+        if !self.submitter_address.is_empty() {
+            struct_ser.serialize_field("submitterAddress", &self.submitter_address)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for AnnounceNode {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "vega_pub_key",
+            "vegaPubKey",
+            "ethereum_address",
+            "ethereumAddress",
+            "chain_pub_key",
+            "chainPubKey",
+            "info_url",
+            "infoUrl",
+            "country",
+            "id",
+            "name",
+            "avatar_url",
+            "avatarUrl",
+            "vega_pub_key_index",
+            "vegaPubKeyIndex",
+            "from_epoch",
+            "fromEpoch",
+            "ethereum_signature",
+            "ethereumSignature",
+            "vega_signature",
+            "vegaSignature",
+            "submitter_address",
+            "submitterAddress",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            VegaPubKey,
+            EthereumAddress,
+            ChainPubKey,
+            InfoUrl,
+            Country,
+            Id,
+            Name,
+            AvatarUrl,
+            VegaPubKeyIndex,
+            FromEpoch,
+            EthereumSignature,
+            VegaSignature,
+            SubmitterAddress,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "vegaPubKey" | "vega_pub_key" => Ok(GeneratedField::VegaPubKey),
+                            "ethereumAddress" | "ethereum_address" => Ok(GeneratedField::EthereumAddress),
+                            "chainPubKey" | "chain_pub_key" => Ok(GeneratedField::ChainPubKey),
+                            "infoUrl" | "info_url" => Ok(GeneratedField::InfoUrl),
+                            "country" => Ok(GeneratedField::Country),
+                            "id" => Ok(GeneratedField::Id),
+                            "name" => Ok(GeneratedField::Name),
+                            "avatarUrl" | "avatar_url" => Ok(GeneratedField::AvatarUrl),
+                            "vegaPubKeyIndex" | "vega_pub_key_index" => Ok(GeneratedField::VegaPubKeyIndex),
+                            "fromEpoch" | "from_epoch" => Ok(GeneratedField::FromEpoch),
+                            "ethereumSignature" | "ethereum_signature" => Ok(GeneratedField::EthereumSignature),
+                            "vegaSignature" | "vega_signature" => Ok(GeneratedField::VegaSignature),
+                            "submitterAddress" | "submitter_address" => Ok(GeneratedField::SubmitterAddress),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = AnnounceNode;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.commands.v1.AnnounceNode")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<AnnounceNode, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut vega_pub_key__ = None;
+                let mut ethereum_address__ = None;
+                let mut chain_pub_key__ = None;
+                let mut info_url__ = None;
+                let mut country__ = None;
+                let mut id__ = None;
+                let mut name__ = None;
+                let mut avatar_url__ = None;
+                let mut vega_pub_key_index__ = None;
+                let mut from_epoch__ = None;
+                let mut ethereum_signature__ = None;
+                let mut vega_signature__ = None;
+                let mut submitter_address__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::VegaPubKey => {
+                            if vega_pub_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("vegaPubKey"));
+                            }
+                            vega_pub_key__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::EthereumAddress => {
+                            if ethereum_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ethereumAddress"));
+                            }
+                            ethereum_address__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ChainPubKey => {
+                            if chain_pub_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainPubKey"));
+                            }
+                            chain_pub_key__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::InfoUrl => {
+                            if info_url__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("infoUrl"));
+                            }
+                            info_url__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Country => {
+                            if country__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("country"));
+                            }
+                            country__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Name => {
+                            if name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("name"));
+                            }
+                            name__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::AvatarUrl => {
+                            if avatar_url__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("avatarUrl"));
+                            }
+                            avatar_url__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::VegaPubKeyIndex => {
+                            if vega_pub_key_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("vegaPubKeyIndex"));
+                            }
+                            vega_pub_key_index__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::FromEpoch => {
+                            if from_epoch__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fromEpoch"));
+                            }
+                            from_epoch__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::EthereumSignature => {
+                            if ethereum_signature__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ethereumSignature"));
+                            }
+                            ethereum_signature__ = map_.next_value()?;
+                        }
+                        GeneratedField::VegaSignature => {
+                            if vega_signature__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("vegaSignature"));
+                            }
+                            vega_signature__ = map_.next_value()?;
+                        }
+                        GeneratedField::SubmitterAddress => {
+                            if submitter_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("submitterAddress"));
+                            }
+                            submitter_address__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(AnnounceNode {
+                    vega_pub_key: vega_pub_key__.unwrap_or_default(),
+                    ethereum_address: ethereum_address__.unwrap_or_default(),
+                    chain_pub_key: chain_pub_key__.unwrap_or_default(),
+                    info_url: info_url__.unwrap_or_default(),
+                    country: country__.unwrap_or_default(),
+                    id: id__.unwrap_or_default(),
+                    name: name__.unwrap_or_default(),
+                    avatar_url: avatar_url__.unwrap_or_default(),
+                    vega_pub_key_index: vega_pub_key_index__.unwrap_or_default(),
+                    from_epoch: from_epoch__.unwrap_or_default(),
+                    ethereum_signature: ethereum_signature__,
+                    vega_signature: vega_signature__,
+                    submitter_address: submitter_address__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.commands.v1.AnnounceNode", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ApplyReferralCode {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.id.is_empty() {
+            len += 1;
+        }
+        if self.do_not_join_team {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.commands.v1.ApplyReferralCode", len)?;
+        if !self.id.is_empty() {
+            struct_ser.serialize_field("id", &self.id)?;
+        }
+        if self.do_not_join_team {
+            struct_ser.serialize_field("doNotJoinTeam", &self.do_not_join_team)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ApplyReferralCode {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "id",
+            "do_not_join_team",
+            "doNotJoinTeam",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Id,
+            DoNotJoinTeam,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "id" => Ok(GeneratedField::Id),
+                            "doNotJoinTeam" | "do_not_join_team" => Ok(GeneratedField::DoNotJoinTeam),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ApplyReferralCode;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.commands.v1.ApplyReferralCode")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ApplyReferralCode, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut id__ = None;
+                let mut do_not_join_team__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::DoNotJoinTeam => {
+                            if do_not_join_team__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("doNotJoinTeam"));
+                            }
+                            do_not_join_team__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(ApplyReferralCode {
+                    id: id__.unwrap_or_default(),
+                    do_not_join_team: do_not_join_team__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.commands.v1.ApplyReferralCode", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for BatchMarketInstructions {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.cancellations.is_empty() {
+            len += 1;
+        }
+        if !self.amendments.is_empty() {
+            len += 1;
+        }
+        if !self.submissions.is_empty() {
+            len += 1;
+        }
+        if !self.stop_orders_cancellation.is_empty() {
+            len += 1;
+        }
+        if !self.stop_orders_submission.is_empty() {
+            len += 1;
+        }
+        if !self.update_margin_mode.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.commands.v1.BatchMarketInstructions", len)?;
+        if !self.cancellations.is_empty() {
+            struct_ser.serialize_field("cancellations", &self.cancellations)?;
+        }
+        if !self.amendments.is_empty() {
+            struct_ser.serialize_field("amendments", &self.amendments)?;
+        }
+        if !self.submissions.is_empty() {
+            struct_ser.serialize_field("submissions", &self.submissions)?;
+        }
+        if !self.stop_orders_cancellation.is_empty() {
+            struct_ser.serialize_field("stopOrdersCancellation", &self.stop_orders_cancellation)?;
+        }
+        if !self.stop_orders_submission.is_empty() {
+            struct_ser.serialize_field("stopOrdersSubmission", &self.stop_orders_submission)?;
+        }
+        if !self.update_margin_mode.is_empty() {
+            struct_ser.serialize_field("updateMarginMode", &self.update_margin_mode)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BatchMarketInstructions {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "cancellations",
+            "amendments",
+            "submissions",
+            "stop_orders_cancellation",
+            "stopOrdersCancellation",
+            "stop_orders_submission",
+            "stopOrdersSubmission",
+            "update_margin_mode",
+            "updateMarginMode",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Cancellations,
+            Amendments,
+            Submissions,
+            StopOrdersCancellation,
+            StopOrdersSubmission,
+            UpdateMarginMode,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "cancellations" => Ok(GeneratedField::Cancellations),
+                            "amendments" => Ok(GeneratedField::Amendments),
+                            "submissions" => Ok(GeneratedField::Submissions),
+                            "stopOrdersCancellation" | "stop_orders_cancellation" => Ok(GeneratedField::StopOrdersCancellation),
+                            "stopOrdersSubmission" | "stop_orders_submission" => Ok(GeneratedField::StopOrdersSubmission),
+                            "updateMarginMode" | "update_margin_mode" => Ok(GeneratedField::UpdateMarginMode),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BatchMarketInstructions;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.commands.v1.BatchMarketInstructions")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BatchMarketInstructions, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut cancellations__ = None;
+                let mut amendments__ = None;
+                let mut submissions__ = None;
+                let mut stop_orders_cancellation__ = None;
+                let mut stop_orders_submission__ = None;
+                let mut update_margin_mode__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Cancellations => {
+                            if cancellations__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("cancellations"));
+                            }
+                            cancellations__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Amendments => {
+                            if amendments__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amendments"));
+                            }
+                            amendments__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Submissions => {
+                            if submissions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("submissions"));
+                            }
+                            submissions__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::StopOrdersCancellation => {
+                            if stop_orders_cancellation__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stopOrdersCancellation"));
+                            }
+                            stop_orders_cancellation__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::StopOrdersSubmission => {
+                            if stop_orders_submission__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stopOrdersSubmission"));
+                            }
+                            stop_orders_submission__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::UpdateMarginMode => {
+                            if update_margin_mode__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("updateMarginMode"));
+                            }
+                            update_margin_mode__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(BatchMarketInstructions {
+                    cancellations: cancellations__.unwrap_or_default(),
+                    amendments: amendments__.unwrap_or_default(),
+                    submissions: submissions__.unwrap_or_default(),
+                    stop_orders_cancellation: stop_orders_cancellation__.unwrap_or_default(),
+                    stop_orders_submission: stop_orders_submission__.unwrap_or_default(),
+                    update_margin_mode: update_margin_mode__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.commands.v1.BatchMarketInstructions", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for BatchProposalSubmission {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.reference.is_empty() {
+            len += 1;
+        }
+        if self.terms.is_some() {
+            len += 1;
+        }
+        if self.rationale.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.commands.v1.BatchProposalSubmission", len)?;
+        if !self.reference.is_empty() {
+            struct_ser.serialize_field("reference", &self.reference)?;
+        }
+        if let Some(v) = self.terms.as_ref() {
+            struct_ser.serialize_field("terms", v)?;
+        }
+        if let Some(v) = self.rationale.as_ref() {
+            struct_ser.serialize_field("rationale", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BatchProposalSubmission {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "reference",
+            "terms",
+            "rationale",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Reference,
+            Terms,
+            Rationale,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "reference" => Ok(GeneratedField::Reference),
+                            "terms" => Ok(GeneratedField::Terms),
+                            "rationale" => Ok(GeneratedField::Rationale),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BatchProposalSubmission;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.commands.v1.BatchProposalSubmission")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BatchProposalSubmission, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut reference__ = None;
+                let mut terms__ = None;
+                let mut rationale__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Reference => {
+                            if reference__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reference"));
+                            }
+                            reference__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Terms => {
+                            if terms__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("terms"));
+                            }
+                            terms__ = map_.next_value()?;
+                        }
+                        GeneratedField::Rationale => {
+                            if rationale__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rationale"));
+                            }
+                            rationale__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(BatchProposalSubmission {
+                    reference: reference__.unwrap_or_default(),
+                    terms: terms__,
+                    rationale: rationale__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.commands.v1.BatchProposalSubmission", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for BatchProposalSubmissionTerms {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.closing_timestamp != 0 {
+            len += 1;
+        }
+        if !self.changes.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.commands.v1.BatchProposalSubmissionTerms", len)?;
+        if self.closing_timestamp != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("closingTimestamp", ToString::to_string(&self.closing_timestamp).as_str())?;
+        }
+        if !self.changes.is_empty() {
+            struct_ser.serialize_field("changes", &self.changes)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BatchProposalSubmissionTerms {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "closing_timestamp",
+            "closingTimestamp",
+            "changes",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ClosingTimestamp,
+            Changes,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "closingTimestamp" | "closing_timestamp" => Ok(GeneratedField::ClosingTimestamp),
+                            "changes" => Ok(GeneratedField::Changes),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BatchProposalSubmissionTerms;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.commands.v1.BatchProposalSubmissionTerms")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BatchProposalSubmissionTerms, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut closing_timestamp__ = None;
+                let mut changes__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ClosingTimestamp => {
+                            if closing_timestamp__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("closingTimestamp"));
+                            }
+                            closing_timestamp__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Changes => {
+                            if changes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("changes"));
+                            }
+                            changes__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(BatchProposalSubmissionTerms {
+                    closing_timestamp: closing_timestamp__.unwrap_or_default(),
+                    changes: changes__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.commands.v1.BatchProposalSubmissionTerms", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for CancelAmm {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.market_id.is_empty() {
+            len += 1;
+        }
+        if self.method != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.commands.v1.CancelAMM", len)?;
+        if !self.market_id.is_empty() {
+            struct_ser.serialize_field("marketId", &self.market_id)?;
+        }
+        if self.method != 0 {
+            let v = cancel_amm::Method::try_from(self.method)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.method)))?;
+            struct_ser.serialize_field("method", &v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for CancelAmm {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "market_id",
+            "marketId",
+            "method",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            MarketId,
+            Method,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "marketId" | "market_id" => Ok(GeneratedField::MarketId),
+                            "method" => Ok(GeneratedField::Method),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CancelAmm;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.commands.v1.CancelAMM")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CancelAmm, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut market_id__ = None;
+                let mut method__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::MarketId => {
+                            if market_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("marketId"));
+                            }
+                            market_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Method => {
+                            if method__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("method"));
+                            }
+                            method__ = Some(map_.next_value::<cancel_amm::Method>()? as i32);
+                        }
+                    }
+                }
+                Ok(CancelAmm {
+                    market_id: market_id__.unwrap_or_default(),
+                    method: method__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.commands.v1.CancelAMM", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for cancel_amm::Method {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "METHOD_UNSPECIFIED",
+            Self::Immediate => "METHOD_IMMEDIATE",
+            Self::ReduceOnly => "METHOD_REDUCE_ONLY",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for cancel_amm::Method {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "METHOD_UNSPECIFIED",
+            "METHOD_IMMEDIATE",
+            "METHOD_REDUCE_ONLY",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = cancel_amm::Method;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "METHOD_UNSPECIFIED" => Ok(cancel_amm::Method::Unspecified),
+                    "METHOD_IMMEDIATE" => Ok(cancel_amm::Method::Immediate),
+                    "METHOD_REDUCE_ONLY" => Ok(cancel_amm::Method::ReduceOnly),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for CancelTransfer {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.transfer_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.commands.v1.CancelTransfer", len)?;
+        if !self.transfer_id.is_empty() {
+            struct_ser.serialize_field("transferId", &self.transfer_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for CancelTransfer {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "transfer_id",
+            "transferId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TransferId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "transferId" | "transfer_id" => Ok(GeneratedField::TransferId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CancelTransfer;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.commands.v1.CancelTransfer")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CancelTransfer, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut transfer_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TransferId => {
+                            if transfer_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("transferId"));
+                            }
+                            transfer_id__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(CancelTransfer {
+                    transfer_id: transfer_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.commands.v1.CancelTransfer", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ChainEvent {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.tx_id.is_empty() {
+            len += 1;
+        }
+        if self.nonce != 0 {
+            len += 1;
+        }
+        if self.event.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.commands.v1.ChainEvent", len)?;
+        if !self.tx_id.is_empty() {
+            struct_ser.serialize_field("txId", &self.tx_id)?;
+        }
+        if self.nonce != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("nonce", ToString::to_string(&self.nonce).as_str())?;
+        }
+        if let Some(v) = self.event.as_ref() {
+            match v {
+                chain_event::Event::Builtin(v) => {
+                    struct_ser.serialize_field("builtin", v)?;
+                }
+                chain_event::Event::Erc20(v) => {
+                    struct_ser.serialize_field("erc20", v)?;
+                }
+                chain_event::Event::StakingEvent(v) => {
+                    struct_ser.serialize_field("stakingEvent", v)?;
+                }
+                chain_event::Event::Erc20Multisig(v) => {
+                    struct_ser.serialize_field("erc20Multisig", v)?;
+                }
+                chain_event::Event::ContractCall(v) => {
+                    struct_ser.serialize_field("contractCall", v)?;
+                }
+                chain_event::Event::Heartbeat(v) => {
+                    struct_ser.serialize_field("heartbeat", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ChainEvent {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "tx_id",
+            "txId",
+            "nonce",
+            "builtin",
+            "erc20",
+            "staking_event",
+            "stakingEvent",
+            "erc20_multisig",
+            "erc20Multisig",
+            "contract_call",
+            "contractCall",
+            "heartbeat",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TxId,
+            Nonce,
+            Builtin,
+            Erc20,
+            StakingEvent,
+            Erc20Multisig,
+            ContractCall,
+            Heartbeat,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "txId" | "tx_id" => Ok(GeneratedField::TxId),
+                            "nonce" => Ok(GeneratedField::Nonce),
+                            "builtin" => Ok(GeneratedField::Builtin),
+                            "erc20" => Ok(GeneratedField::Erc20),
+                            "stakingEvent" | "staking_event" => Ok(GeneratedField::StakingEvent),
+                            "erc20Multisig" | "erc20_multisig" => Ok(GeneratedField::Erc20Multisig),
+                            "contractCall" | "contract_call" => Ok(GeneratedField::ContractCall),
+                            "heartbeat" => Ok(GeneratedField::Heartbeat),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ChainEvent;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.commands.v1.ChainEvent")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ChainEvent, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut tx_id__ = None;
+                let mut nonce__ = None;
+                let mut event__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TxId => {
+                            if tx_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("txId"));
+                            }
+                            tx_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Nonce => {
+                            if nonce__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nonce"));
+                            }
+                            nonce__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Builtin => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("builtin"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(chain_event::Event::Builtin)
+;
+                        }
+                        GeneratedField::Erc20 => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("erc20"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(chain_event::Event::Erc20)
+;
+                        }
+                        GeneratedField::StakingEvent => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stakingEvent"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(chain_event::Event::StakingEvent)
+;
+                        }
+                        GeneratedField::Erc20Multisig => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("erc20Multisig"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(chain_event::Event::Erc20Multisig)
+;
+                        }
+                        GeneratedField::ContractCall => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("contractCall"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(chain_event::Event::ContractCall)
+;
+                        }
+                        GeneratedField::Heartbeat => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("heartbeat"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(chain_event::Event::Heartbeat)
+;
+                        }
+                    }
+                }
+                Ok(ChainEvent {
+                    tx_id: tx_id__.unwrap_or_default(),
+                    nonce: nonce__.unwrap_or_default(),
+                    event: event__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.commands.v1.ChainEvent", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for CreateReferralSet {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.is_team {
+            len += 1;
+        }
+        if self.team.is_some() {
+            len += 1;
+        }
+        if self.do_not_create_referral_set {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.commands.v1.CreateReferralSet", len)?;
+        if self.is_team {
+            struct_ser.serialize_field("isTeam", &self.is_team)?;
+        }
+        if let Some(v) = self.team.as_ref() {
+            struct_ser.serialize_field("team", v)?;
+        }
+        if self.do_not_create_referral_set {
+            struct_ser.serialize_field("doNotCreateReferralSet", &self.do_not_create_referral_set)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for CreateReferralSet {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "is_team",
+            "isTeam",
+            "team",
+            "do_not_create_referral_set",
+            "doNotCreateReferralSet",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            IsTeam,
+            Team,
+            DoNotCreateReferralSet,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "isTeam" | "is_team" => Ok(GeneratedField::IsTeam),
+                            "team" => Ok(GeneratedField::Team),
+                            "doNotCreateReferralSet" | "do_not_create_referral_set" => Ok(GeneratedField::DoNotCreateReferralSet),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CreateReferralSet;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.commands.v1.CreateReferralSet")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CreateReferralSet, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut is_team__ = None;
+                let mut team__ = None;
+                let mut do_not_create_referral_set__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::IsTeam => {
+                            if is_team__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("isTeam"));
+                            }
+                            is_team__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Team => {
+                            if team__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("team"));
+                            }
+                            team__ = map_.next_value()?;
+                        }
+                        GeneratedField::DoNotCreateReferralSet => {
+                            if do_not_create_referral_set__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("doNotCreateReferralSet"));
+                            }
+                            do_not_create_referral_set__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(CreateReferralSet {
+                    is_team: is_team__.unwrap_or_default(),
+                    team: team__,
+                    do_not_create_referral_set: do_not_create_referral_set__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.commands.v1.CreateReferralSet", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for create_referral_set::Team {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.name.is_empty() {
+            len += 1;
+        }
+        if self.team_url.is_some() {
+            len += 1;
+        }
+        if self.avatar_url.is_some() {
+            len += 1;
+        }
+        if self.closed {
+            len += 1;
+        }
+        if !self.allow_list.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.commands.v1.CreateReferralSet.Team", len)?;
+        if !self.name.is_empty() {
+            struct_ser.serialize_field("name", &self.name)?;
+        }
+        if let Some(v) = self.team_url.as_ref() {
+            struct_ser.serialize_field("teamUrl", v)?;
+        }
+        if let Some(v) = self.avatar_url.as_ref() {
+            struct_ser.serialize_field("avatarUrl", v)?;
+        }
+        if self.closed {
+            struct_ser.serialize_field("closed", &self.closed)?;
+        }
+        if !self.allow_list.is_empty() {
+            struct_ser.serialize_field("allowList", &self.allow_list)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for create_referral_set::Team {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "name",
+            "team_url",
+            "teamUrl",
+            "avatar_url",
+            "avatarUrl",
+            "closed",
+            "allow_list",
+            "allowList",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Name,
+            TeamUrl,
+            AvatarUrl,
+            Closed,
+            AllowList,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "name" => Ok(GeneratedField::Name),
+                            "teamUrl" | "team_url" => Ok(GeneratedField::TeamUrl),
+                            "avatarUrl" | "avatar_url" => Ok(GeneratedField::AvatarUrl),
+                            "closed" => Ok(GeneratedField::Closed),
+                            "allowList" | "allow_list" => Ok(GeneratedField::AllowList),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = create_referral_set::Team;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.commands.v1.CreateReferralSet.Team")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<create_referral_set::Team, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut name__ = None;
+                let mut team_url__ = None;
+                let mut avatar_url__ = None;
+                let mut closed__ = None;
+                let mut allow_list__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Name => {
+                            if name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("name"));
+                            }
+                            name__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::TeamUrl => {
+                            if team_url__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("teamUrl"));
+                            }
+                            team_url__ = map_.next_value()?;
+                        }
+                        GeneratedField::AvatarUrl => {
+                            if avatar_url__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("avatarUrl"));
+                            }
+                            avatar_url__ = map_.next_value()?;
+                        }
+                        GeneratedField::Closed => {
+                            if closed__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("closed"));
+                            }
+                            closed__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::AllowList => {
+                            if allow_list__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("allowList"));
+                            }
+                            allow_list__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(create_referral_set::Team {
+                    name: name__.unwrap_or_default(),
+                    team_url: team_url__,
+                    avatar_url: avatar_url__,
+                    closed: closed__.unwrap_or_default(),
+                    allow_list: allow_list__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.commands.v1.CreateReferralSet.Team", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for DelayedTransactionsWrapper {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.transactions.is_empty() {
+            len += 1;
+        }
+        if self.height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.commands.v1.DelayedTransactionsWrapper", len)?;
+        if !self.transactions.is_empty() {
+            struct_ser.serialize_field("transactions", &self.transactions.iter().map(pbjson::private::base64::encode).collect::<Vec<_>>())?;
+        }
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for DelayedTransactionsWrapper {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "transactions",
+            "height",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Transactions,
+            Height,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "transactions" => Ok(GeneratedField::Transactions),
+                            "height" => Ok(GeneratedField::Height),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = DelayedTransactionsWrapper;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.commands.v1.DelayedTransactionsWrapper")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DelayedTransactionsWrapper, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut transactions__ = None;
+                let mut height__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Transactions => {
+                            if transactions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("transactions"));
+                            }
+                            transactions__ = 
+                                Some(map_.next_value::<Vec<::pbjson::private::BytesDeserialize<_>>>()?
+                                    .into_iter().map(|x| x.0).collect())
+                            ;
+                        }
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(DelayedTransactionsWrapper {
+                    transactions: transactions__.unwrap_or_default(),
+                    height: height__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.commands.v1.DelayedTransactionsWrapper", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for DelegateSubmission {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.node_id.is_empty() {
+            len += 1;
+        }
+        if !self.amount.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.commands.v1.DelegateSubmission", len)?;
+        if !self.node_id.is_empty() {
+            struct_ser.serialize_field("nodeId", &self.node_id)?;
+        }
+        if !self.amount.is_empty() {
+            struct_ser.serialize_field("amount", &self.amount)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for DelegateSubmission {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "node_id",
+            "nodeId",
+            "amount",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            NodeId,
+            Amount,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "nodeId" | "node_id" => Ok(GeneratedField::NodeId),
+                            "amount" => Ok(GeneratedField::Amount),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = DelegateSubmission;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.commands.v1.DelegateSubmission")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DelegateSubmission, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut node_id__ = None;
+                let mut amount__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::NodeId => {
+                            if node_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nodeId"));
+                            }
+                            node_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Amount => {
+                            if amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amount"));
+                            }
+                            amount__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(DelegateSubmission {
+                    node_id: node_id__.unwrap_or_default(),
+                    amount: amount__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.commands.v1.DelegateSubmission", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EthereumKeyRotateSubmission {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.target_block != 0 {
+            len += 1;
+        }
+        if !self.new_address.is_empty() {
+            len += 1;
+        }
+        if !self.current_address.is_empty() {
+            len += 1;
+        }
+        if !self.submitter_address.is_empty() {
+            len += 1;
+        }
+        if self.ethereum_signature.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.commands.v1.EthereumKeyRotateSubmission", len)?;
+        if self.target_block != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("targetBlock", ToString::to_string(&self.target_block).as_str())?;
+        }
+        if !self.new_address.is_empty() {
+            struct_ser.serialize_field("newAddress", &self.new_address)?;
+        }
+        if !self.current_address.is_empty() {
+            struct_ser.serialize_field("currentAddress", &self.current_address)?;
+        }
+        if !self.submitter_address.is_empty() {
+            struct_ser.serialize_field("submitterAddress", &self.submitter_address)?;
+        }
+        if let Some(v) = self.ethereum_signature.as_ref() {
+            struct_ser.serialize_field("ethereumSignature", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EthereumKeyRotateSubmission {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "target_block",
+            "targetBlock",
+            "new_address",
+            "newAddress",
+            "current_address",
+            "currentAddress",
+            "submitter_address",
+            "submitterAddress",
+            "ethereum_signature",
+            "ethereumSignature",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TargetBlock,
+            NewAddress,
+            CurrentAddress,
+            SubmitterAddress,
+            EthereumSignature,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "targetBlock" | "target_block" => Ok(GeneratedField::TargetBlock),
+                            "newAddress" | "new_address" => Ok(GeneratedField::NewAddress),
+                            "currentAddress" | "current_address" => Ok(GeneratedField::CurrentAddress),
+                            "submitterAddress" | "submitter_address" => Ok(GeneratedField::SubmitterAddress),
+                            "ethereumSignature" | "ethereum_signature" => Ok(GeneratedField::EthereumSignature),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EthereumKeyRotateSubmission;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.commands.v1.EthereumKeyRotateSubmission")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EthereumKeyRotateSubmission, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut target_block__ = None;
+                let mut new_address__ = None;
+                let mut current_address__ = None;
+                let mut submitter_address__ = None;
+                let mut ethereum_signature__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TargetBlock => {
+                            if target_block__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("targetBlock"));
+                            }
+                            target_block__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::NewAddress => {
+                            if new_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newAddress"));
+                            }
+                            new_address__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::CurrentAddress => {
+                            if current_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("currentAddress"));
+                            }
+                            current_address__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::SubmitterAddress => {
+                            if submitter_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("submitterAddress"));
+                            }
+                            submitter_address__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::EthereumSignature => {
+                            if ethereum_signature__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ethereumSignature"));
+                            }
+                            ethereum_signature__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(EthereumKeyRotateSubmission {
+                    target_block: target_block__.unwrap_or_default(),
+                    new_address: new_address__.unwrap_or_default(),
+                    current_address: current_address__.unwrap_or_default(),
+                    submitter_address: submitter_address__.unwrap_or_default(),
+                    ethereum_signature: ethereum_signature__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.commands.v1.EthereumKeyRotateSubmission", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for IcebergOpts {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.peak_size != 0 {
+            len += 1;
+        }
+        if self.minimum_visible_size != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.commands.v1.IcebergOpts", len)?;
+        if self.peak_size != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("peakSize", ToString::to_string(&self.peak_size).as_str())?;
+        }
+        if self.minimum_visible_size != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("minimumVisibleSize", ToString::to_string(&self.minimum_visible_size).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for IcebergOpts {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "peak_size",
+            "peakSize",
+            "minimum_visible_size",
+            "minimumVisibleSize",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PeakSize,
+            MinimumVisibleSize,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "peakSize" | "peak_size" => Ok(GeneratedField::PeakSize),
+                            "minimumVisibleSize" | "minimum_visible_size" => Ok(GeneratedField::MinimumVisibleSize),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = IcebergOpts;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.commands.v1.IcebergOpts")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<IcebergOpts, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut peak_size__ = None;
+                let mut minimum_visible_size__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PeakSize => {
+                            if peak_size__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("peakSize"));
+                            }
+                            peak_size__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::MinimumVisibleSize => {
+                            if minimum_visible_size__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("minimumVisibleSize"));
+                            }
+                            minimum_visible_size__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(IcebergOpts {
+                    peak_size: peak_size__.unwrap_or_default(),
+                    minimum_visible_size: minimum_visible_size__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.commands.v1.IcebergOpts", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for InputData {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.nonce != 0 {
+            len += 1;
+        }
+        if self.block_height != 0 {
+            len += 1;
+        }
+        if self.command.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.commands.v1.InputData", len)?;
+        if self.nonce != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("nonce", ToString::to_string(&self.nonce).as_str())?;
+        }
+        if self.block_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("blockHeight", ToString::to_string(&self.block_height).as_str())?;
+        }
+        if let Some(v) = self.command.as_ref() {
+            match v {
+                input_data::Command::OrderSubmission(v) => {
+                    struct_ser.serialize_field("orderSubmission", v)?;
+                }
+                input_data::Command::OrderCancellation(v) => {
+                    struct_ser.serialize_field("orderCancellation", v)?;
+                }
+                input_data::Command::OrderAmendment(v) => {
+                    struct_ser.serialize_field("orderAmendment", v)?;
+                }
+                input_data::Command::WithdrawSubmission(v) => {
+                    struct_ser.serialize_field("withdrawSubmission", v)?;
+                }
+                input_data::Command::ProposalSubmission(v) => {
+                    struct_ser.serialize_field("proposalSubmission", v)?;
+                }
+                input_data::Command::VoteSubmission(v) => {
+                    struct_ser.serialize_field("voteSubmission", v)?;
+                }
+                input_data::Command::LiquidityProvisionSubmission(v) => {
+                    struct_ser.serialize_field("liquidityProvisionSubmission", v)?;
+                }
+                input_data::Command::DelegateSubmission(v) => {
+                    struct_ser.serialize_field("delegateSubmission", v)?;
+                }
+                input_data::Command::UndelegateSubmission(v) => {
+                    struct_ser.serialize_field("undelegateSubmission", v)?;
+                }
+                input_data::Command::LiquidityProvisionCancellation(v) => {
+                    struct_ser.serialize_field("liquidityProvisionCancellation", v)?;
+                }
+                input_data::Command::LiquidityProvisionAmendment(v) => {
+                    struct_ser.serialize_field("liquidityProvisionAmendment", v)?;
+                }
+                input_data::Command::Transfer(v) => {
+                    struct_ser.serialize_field("transfer", v)?;
+                }
+                input_data::Command::CancelTransfer(v) => {
+                    struct_ser.serialize_field("cancelTransfer", v)?;
+                }
+                input_data::Command::AnnounceNode(v) => {
+                    struct_ser.serialize_field("announceNode", v)?;
+                }
+                input_data::Command::BatchMarketInstructions(v) => {
+                    struct_ser.serialize_field("batchMarketInstructions", v)?;
+                }
+                input_data::Command::StopOrdersSubmission(v) => {
+                    struct_ser.serialize_field("stopOrdersSubmission", v)?;
+                }
+                input_data::Command::StopOrdersCancellation(v) => {
+                    struct_ser.serialize_field("stopOrdersCancellation", v)?;
+                }
+                input_data::Command::CreateReferralSet(v) => {
+                    struct_ser.serialize_field("createReferralSet", v)?;
+                }
+                input_data::Command::UpdateReferralSet(v) => {
+                    struct_ser.serialize_field("updateReferralSet", v)?;
+                }
+                input_data::Command::ApplyReferralCode(v) => {
+                    struct_ser.serialize_field("applyReferralCode", v)?;
+                }
+                input_data::Command::UpdateMarginMode(v) => {
+                    struct_ser.serialize_field("updateMarginMode", v)?;
+                }
+                input_data::Command::JoinTeam(v) => {
+                    struct_ser.serialize_field("joinTeam", v)?;
+                }
+                input_data::Command::BatchProposalSubmission(v) => {
+                    struct_ser.serialize_field("batchProposalSubmission", v)?;
+                }
+                input_data::Command::UpdatePartyProfile(v) => {
+                    struct_ser.serialize_field("updatePartyProfile", v)?;
+                }
+                input_data::Command::SubmitAmm(v) => {
+                    struct_ser.serialize_field("submitAmm", v)?;
+                }
+                input_data::Command::AmendAmm(v) => {
+                    struct_ser.serialize_field("amendAmm", v)?;
+                }
+                input_data::Command::CancelAmm(v) => {
+                    struct_ser.serialize_field("cancelAmm", v)?;
+                }
+                input_data::Command::NodeVote(v) => {
+                    struct_ser.serialize_field("nodeVote", v)?;
+                }
+                input_data::Command::NodeSignature(v) => {
+                    struct_ser.serialize_field("nodeSignature", v)?;
+                }
+                input_data::Command::ChainEvent(v) => {
+                    struct_ser.serialize_field("chainEvent", v)?;
+                }
+                input_data::Command::KeyRotateSubmission(v) => {
+                    struct_ser.serialize_field("keyRotateSubmission", v)?;
+                }
+                input_data::Command::StateVariableProposal(v) => {
+                    struct_ser.serialize_field("stateVariableProposal", v)?;
+                }
+                input_data::Command::ValidatorHeartbeat(v) => {
+                    struct_ser.serialize_field("validatorHeartbeat", v)?;
+                }
+                input_data::Command::EthereumKeyRotateSubmission(v) => {
+                    struct_ser.serialize_field("ethereumKeyRotateSubmission", v)?;
+                }
+                input_data::Command::ProtocolUpgradeProposal(v) => {
+                    struct_ser.serialize_field("protocolUpgradeProposal", v)?;
+                }
+                input_data::Command::IssueSignatures(v) => {
+                    struct_ser.serialize_field("issueSignatures", v)?;
+                }
+                input_data::Command::OracleDataSubmission(v) => {
+                    struct_ser.serialize_field("oracleDataSubmission", v)?;
+                }
+                input_data::Command::DelayedTransactionsWrapper(v) => {
+                    struct_ser.serialize_field("delayedTransactionsWrapper", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for InputData {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "nonce",
+            "block_height",
+            "blockHeight",
+            "order_submission",
+            "orderSubmission",
+            "order_cancellation",
+            "orderCancellation",
+            "order_amendment",
+            "orderAmendment",
+            "withdraw_submission",
+            "withdrawSubmission",
+            "proposal_submission",
+            "proposalSubmission",
+            "vote_submission",
+            "voteSubmission",
+            "liquidity_provision_submission",
+            "liquidityProvisionSubmission",
+            "delegate_submission",
+            "delegateSubmission",
+            "undelegate_submission",
+            "undelegateSubmission",
+            "liquidity_provision_cancellation",
+            "liquidityProvisionCancellation",
+            "liquidity_provision_amendment",
+            "liquidityProvisionAmendment",
+            "transfer",
+            "cancel_transfer",
+            "cancelTransfer",
+            "announce_node",
+            "announceNode",
+            "batch_market_instructions",
+            "batchMarketInstructions",
+            "stop_orders_submission",
+            "stopOrdersSubmission",
+            "stop_orders_cancellation",
+            "stopOrdersCancellation",
+            "create_referral_set",
+            "createReferralSet",
+            "update_referral_set",
+            "updateReferralSet",
+            "apply_referral_code",
+            "applyReferralCode",
+            "update_margin_mode",
+            "updateMarginMode",
+            "join_team",
+            "joinTeam",
+            "batch_proposal_submission",
+            "batchProposalSubmission",
+            "update_party_profile",
+            "updatePartyProfile",
+            "submit_amm",
+            "submitAmm",
+            "amend_amm",
+            "amendAmm",
+            "cancel_amm",
+            "cancelAmm",
+            "node_vote",
+            "nodeVote",
+            "node_signature",
+            "nodeSignature",
+            "chain_event",
+            "chainEvent",
+            "key_rotate_submission",
+            "keyRotateSubmission",
+            "state_variable_proposal",
+            "stateVariableProposal",
+            "validator_heartbeat",
+            "validatorHeartbeat",
+            "ethereum_key_rotate_submission",
+            "ethereumKeyRotateSubmission",
+            "protocol_upgrade_proposal",
+            "protocolUpgradeProposal",
+            "issue_signatures",
+            "issueSignatures",
+            "oracle_data_submission",
+            "oracleDataSubmission",
+            "delayed_transactions_wrapper",
+            "delayedTransactionsWrapper",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Nonce,
+            BlockHeight,
+            OrderSubmission,
+            OrderCancellation,
+            OrderAmendment,
+            WithdrawSubmission,
+            ProposalSubmission,
+            VoteSubmission,
+            LiquidityProvisionSubmission,
+            DelegateSubmission,
+            UndelegateSubmission,
+            LiquidityProvisionCancellation,
+            LiquidityProvisionAmendment,
+            Transfer,
+            CancelTransfer,
+            AnnounceNode,
+            BatchMarketInstructions,
+            StopOrdersSubmission,
+            StopOrdersCancellation,
+            CreateReferralSet,
+            UpdateReferralSet,
+            ApplyReferralCode,
+            UpdateMarginMode,
+            JoinTeam,
+            BatchProposalSubmission,
+            UpdatePartyProfile,
+            SubmitAmm,
+            AmendAmm,
+            CancelAmm,
+            NodeVote,
+            NodeSignature,
+            ChainEvent,
+            KeyRotateSubmission,
+            StateVariableProposal,
+            ValidatorHeartbeat,
+            EthereumKeyRotateSubmission,
+            ProtocolUpgradeProposal,
+            IssueSignatures,
+            OracleDataSubmission,
+            DelayedTransactionsWrapper,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "nonce" => Ok(GeneratedField::Nonce),
+                            "blockHeight" | "block_height" => Ok(GeneratedField::BlockHeight),
+                            "orderSubmission" | "order_submission" => Ok(GeneratedField::OrderSubmission),
+                            "orderCancellation" | "order_cancellation" => Ok(GeneratedField::OrderCancellation),
+                            "orderAmendment" | "order_amendment" => Ok(GeneratedField::OrderAmendment),
+                            "withdrawSubmission" | "withdraw_submission" => Ok(GeneratedField::WithdrawSubmission),
+                            "proposalSubmission" | "proposal_submission" => Ok(GeneratedField::ProposalSubmission),
+                            "voteSubmission" | "vote_submission" => Ok(GeneratedField::VoteSubmission),
+                            "liquidityProvisionSubmission" | "liquidity_provision_submission" => Ok(GeneratedField::LiquidityProvisionSubmission),
+                            "delegateSubmission" | "delegate_submission" => Ok(GeneratedField::DelegateSubmission),
+                            "undelegateSubmission" | "undelegate_submission" => Ok(GeneratedField::UndelegateSubmission),
+                            "liquidityProvisionCancellation" | "liquidity_provision_cancellation" => Ok(GeneratedField::LiquidityProvisionCancellation),
+                            "liquidityProvisionAmendment" | "liquidity_provision_amendment" => Ok(GeneratedField::LiquidityProvisionAmendment),
+                            "transfer" => Ok(GeneratedField::Transfer),
+                            "cancelTransfer" | "cancel_transfer" => Ok(GeneratedField::CancelTransfer),
+                            "announceNode" | "announce_node" => Ok(GeneratedField::AnnounceNode),
+                            "batchMarketInstructions" | "batch_market_instructions" => Ok(GeneratedField::BatchMarketInstructions),
+                            "stopOrdersSubmission" | "stop_orders_submission" => Ok(GeneratedField::StopOrdersSubmission),
+                            "stopOrdersCancellation" | "stop_orders_cancellation" => Ok(GeneratedField::StopOrdersCancellation),
+                            "createReferralSet" | "create_referral_set" => Ok(GeneratedField::CreateReferralSet),
+                            "updateReferralSet" | "update_referral_set" => Ok(GeneratedField::UpdateReferralSet),
+                            "applyReferralCode" | "apply_referral_code" => Ok(GeneratedField::ApplyReferralCode),
+                            "updateMarginMode" | "update_margin_mode" => Ok(GeneratedField::UpdateMarginMode),
+                            "joinTeam" | "join_team" => Ok(GeneratedField::JoinTeam),
+                            "batchProposalSubmission" | "batch_proposal_submission" => Ok(GeneratedField::BatchProposalSubmission),
+                            "updatePartyProfile" | "update_party_profile" => Ok(GeneratedField::UpdatePartyProfile),
+                            "submitAmm" | "submit_amm" => Ok(GeneratedField::SubmitAmm),
+                            "amendAmm" | "amend_amm" => Ok(GeneratedField::AmendAmm),
+                            "cancelAmm" | "cancel_amm" => Ok(GeneratedField::CancelAmm),
+                            "nodeVote" | "node_vote" => Ok(GeneratedField::NodeVote),
+                            "nodeSignature" | "node_signature" => Ok(GeneratedField::NodeSignature),
+                            "chainEvent" | "chain_event" => Ok(GeneratedField::ChainEvent),
+                            "keyRotateSubmission" | "key_rotate_submission" => Ok(GeneratedField::KeyRotateSubmission),
+                            "stateVariableProposal" | "state_variable_proposal" => Ok(GeneratedField::StateVariableProposal),
+                            "validatorHeartbeat" | "validator_heartbeat" => Ok(GeneratedField::ValidatorHeartbeat),
+                            "ethereumKeyRotateSubmission" | "ethereum_key_rotate_submission" => Ok(GeneratedField::EthereumKeyRotateSubmission),
+                            "protocolUpgradeProposal" | "protocol_upgrade_proposal" => Ok(GeneratedField::ProtocolUpgradeProposal),
+                            "issueSignatures" | "issue_signatures" => Ok(GeneratedField::IssueSignatures),
+                            "oracleDataSubmission" | "oracle_data_submission" => Ok(GeneratedField::OracleDataSubmission),
+                            "delayedTransactionsWrapper" | "delayed_transactions_wrapper" => Ok(GeneratedField::DelayedTransactionsWrapper),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = InputData;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.commands.v1.InputData")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<InputData, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut nonce__ = None;
+                let mut block_height__ = None;
+                let mut command__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Nonce => {
+                            if nonce__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nonce"));
+                            }
+                            nonce__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::BlockHeight => {
+                            if block_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("blockHeight"));
+                            }
+                            block_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::OrderSubmission => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("orderSubmission"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(input_data::Command::OrderSubmission)
+;
+                        }
+                        GeneratedField::OrderCancellation => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("orderCancellation"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(input_data::Command::OrderCancellation)
+;
+                        }
+                        GeneratedField::OrderAmendment => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("orderAmendment"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(input_data::Command::OrderAmendment)
+;
+                        }
+                        GeneratedField::WithdrawSubmission => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("withdrawSubmission"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(input_data::Command::WithdrawSubmission)
+;
+                        }
+                        GeneratedField::ProposalSubmission => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposalSubmission"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(input_data::Command::ProposalSubmission)
+;
+                        }
+                        GeneratedField::VoteSubmission => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("voteSubmission"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(input_data::Command::VoteSubmission)
+;
+                        }
+                        GeneratedField::LiquidityProvisionSubmission => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("liquidityProvisionSubmission"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(input_data::Command::LiquidityProvisionSubmission)
+;
+                        }
+                        GeneratedField::DelegateSubmission => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("delegateSubmission"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(input_data::Command::DelegateSubmission)
+;
+                        }
+                        GeneratedField::UndelegateSubmission => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("undelegateSubmission"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(input_data::Command::UndelegateSubmission)
+;
+                        }
+                        GeneratedField::LiquidityProvisionCancellation => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("liquidityProvisionCancellation"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(input_data::Command::LiquidityProvisionCancellation)
+;
+                        }
+                        GeneratedField::LiquidityProvisionAmendment => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("liquidityProvisionAmendment"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(input_data::Command::LiquidityProvisionAmendment)
+;
+                        }
+                        GeneratedField::Transfer => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("transfer"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(input_data::Command::Transfer)
+;
+                        }
+                        GeneratedField::CancelTransfer => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("cancelTransfer"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(input_data::Command::CancelTransfer)
+;
+                        }
+                        GeneratedField::AnnounceNode => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("announceNode"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(input_data::Command::AnnounceNode)
+;
+                        }
+                        GeneratedField::BatchMarketInstructions => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("batchMarketInstructions"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(input_data::Command::BatchMarketInstructions)
+;
+                        }
+                        GeneratedField::StopOrdersSubmission => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stopOrdersSubmission"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(input_data::Command::StopOrdersSubmission)
+;
+                        }
+                        GeneratedField::StopOrdersCancellation => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stopOrdersCancellation"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(input_data::Command::StopOrdersCancellation)
+;
+                        }
+                        GeneratedField::CreateReferralSet => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("createReferralSet"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(input_data::Command::CreateReferralSet)
+;
+                        }
+                        GeneratedField::UpdateReferralSet => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("updateReferralSet"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(input_data::Command::UpdateReferralSet)
+;
+                        }
+                        GeneratedField::ApplyReferralCode => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("applyReferralCode"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(input_data::Command::ApplyReferralCode)
+;
+                        }
+                        GeneratedField::UpdateMarginMode => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("updateMarginMode"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(input_data::Command::UpdateMarginMode)
+;
+                        }
+                        GeneratedField::JoinTeam => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("joinTeam"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(input_data::Command::JoinTeam)
+;
+                        }
+                        GeneratedField::BatchProposalSubmission => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("batchProposalSubmission"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(input_data::Command::BatchProposalSubmission)
+;
+                        }
+                        GeneratedField::UpdatePartyProfile => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("updatePartyProfile"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(input_data::Command::UpdatePartyProfile)
+;
+                        }
+                        GeneratedField::SubmitAmm => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("submitAmm"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(input_data::Command::SubmitAmm)
+;
+                        }
+                        GeneratedField::AmendAmm => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amendAmm"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(input_data::Command::AmendAmm)
+;
+                        }
+                        GeneratedField::CancelAmm => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("cancelAmm"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(input_data::Command::CancelAmm)
+;
+                        }
+                        GeneratedField::NodeVote => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nodeVote"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(input_data::Command::NodeVote)
+;
+                        }
+                        GeneratedField::NodeSignature => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nodeSignature"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(input_data::Command::NodeSignature)
+;
+                        }
+                        GeneratedField::ChainEvent => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainEvent"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(input_data::Command::ChainEvent)
+;
+                        }
+                        GeneratedField::KeyRotateSubmission => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("keyRotateSubmission"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(input_data::Command::KeyRotateSubmission)
+;
+                        }
+                        GeneratedField::StateVariableProposal => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stateVariableProposal"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(input_data::Command::StateVariableProposal)
+;
+                        }
+                        GeneratedField::ValidatorHeartbeat => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("validatorHeartbeat"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(input_data::Command::ValidatorHeartbeat)
+;
+                        }
+                        GeneratedField::EthereumKeyRotateSubmission => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ethereumKeyRotateSubmission"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(input_data::Command::EthereumKeyRotateSubmission)
+;
+                        }
+                        GeneratedField::ProtocolUpgradeProposal => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("protocolUpgradeProposal"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(input_data::Command::ProtocolUpgradeProposal)
+;
+                        }
+                        GeneratedField::IssueSignatures => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("issueSignatures"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(input_data::Command::IssueSignatures)
+;
+                        }
+                        GeneratedField::OracleDataSubmission => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("oracleDataSubmission"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(input_data::Command::OracleDataSubmission)
+;
+                        }
+                        GeneratedField::DelayedTransactionsWrapper => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("delayedTransactionsWrapper"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(input_data::Command::DelayedTransactionsWrapper)
+;
+                        }
+                    }
+                }
+                Ok(InputData {
+                    nonce: nonce__.unwrap_or_default(),
+                    block_height: block_height__.unwrap_or_default(),
+                    command: command__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.commands.v1.InputData", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for IssueSignatures {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.submitter.is_empty() {
+            len += 1;
+        }
+        if self.kind != 0 {
+            len += 1;
+        }
+        if !self.validator_node_id.is_empty() {
+            len += 1;
+        }
+        if !self.chain_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.commands.v1.IssueSignatures", len)?;
+        if !self.submitter.is_empty() {
+            struct_ser.serialize_field("submitter", &self.submitter)?;
+        }
+        if self.kind != 0 {
+            let v = NodeSignatureKind::try_from(self.kind)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.kind)))?;
+            struct_ser.serialize_field("kind", &v)?;
+        }
+        if !self.validator_node_id.is_empty() {
+            struct_ser.serialize_field("validatorNodeId", &self.validator_node_id)?;
+        }
+        if !self.chain_id.is_empty() {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for IssueSignatures {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "submitter",
+            "kind",
+            "validator_node_id",
+            "validatorNodeId",
+            "chain_id",
+            "chainId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Submitter,
+            Kind,
+            ValidatorNodeId,
+            ChainId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "submitter" => Ok(GeneratedField::Submitter),
+                            "kind" => Ok(GeneratedField::Kind),
+                            "validatorNodeId" | "validator_node_id" => Ok(GeneratedField::ValidatorNodeId),
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = IssueSignatures;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.commands.v1.IssueSignatures")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<IssueSignatures, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut submitter__ = None;
+                let mut kind__ = None;
+                let mut validator_node_id__ = None;
+                let mut chain_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Submitter => {
+                            if submitter__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("submitter"));
+                            }
+                            submitter__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Kind => {
+                            if kind__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("kind"));
+                            }
+                            kind__ = Some(map_.next_value::<NodeSignatureKind>()? as i32);
+                        }
+                        GeneratedField::ValidatorNodeId => {
+                            if validator_node_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("validatorNodeId"));
+                            }
+                            validator_node_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
+                            }
+                            chain_id__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(IssueSignatures {
+                    submitter: submitter__.unwrap_or_default(),
+                    kind: kind__.unwrap_or_default(),
+                    validator_node_id: validator_node_id__.unwrap_or_default(),
+                    chain_id: chain_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.commands.v1.IssueSignatures", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for JoinTeam {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.commands.v1.JoinTeam", len)?;
+        if !self.id.is_empty() {
+            struct_ser.serialize_field("id", &self.id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for JoinTeam {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "id",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Id,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "id" => Ok(GeneratedField::Id),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = JoinTeam;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.commands.v1.JoinTeam")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<JoinTeam, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            id__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(JoinTeam {
+                    id: id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.commands.v1.JoinTeam", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for KeyRotateSubmission {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.new_pub_key_index != 0 {
+            len += 1;
+        }
+        if self.target_block != 0 {
+            len += 1;
+        }
+        if !self.new_pub_key.is_empty() {
+            len += 1;
+        }
+        if !self.current_pub_key_hash.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.commands.v1.KeyRotateSubmission", len)?;
+        if self.new_pub_key_index != 0 {
+            struct_ser.serialize_field("newPubKeyIndex", &self.new_pub_key_index)?;
+        }
+        if self.target_block != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("targetBlock", ToString::to_string(&self.target_block).as_str())?;
+        }
+        if !self.new_pub_key.is_empty() {
+            struct_ser.serialize_field("newPubKey", &self.new_pub_key)?;
+        }
+        if !self.current_pub_key_hash.is_empty() {
+            struct_ser.serialize_field("currentPubKeyHash", &self.current_pub_key_hash)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for KeyRotateSubmission {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "new_pub_key_index",
+            "newPubKeyIndex",
+            "target_block",
+            "targetBlock",
+            "new_pub_key",
+            "newPubKey",
+            "current_pub_key_hash",
+            "currentPubKeyHash",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            NewPubKeyIndex,
+            TargetBlock,
+            NewPubKey,
+            CurrentPubKeyHash,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "newPubKeyIndex" | "new_pub_key_index" => Ok(GeneratedField::NewPubKeyIndex),
+                            "targetBlock" | "target_block" => Ok(GeneratedField::TargetBlock),
+                            "newPubKey" | "new_pub_key" => Ok(GeneratedField::NewPubKey),
+                            "currentPubKeyHash" | "current_pub_key_hash" => Ok(GeneratedField::CurrentPubKeyHash),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = KeyRotateSubmission;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.commands.v1.KeyRotateSubmission")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<KeyRotateSubmission, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut new_pub_key_index__ = None;
+                let mut target_block__ = None;
+                let mut new_pub_key__ = None;
+                let mut current_pub_key_hash__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::NewPubKeyIndex => {
+                            if new_pub_key_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newPubKeyIndex"));
+                            }
+                            new_pub_key_index__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::TargetBlock => {
+                            if target_block__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("targetBlock"));
+                            }
+                            target_block__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::NewPubKey => {
+                            if new_pub_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newPubKey"));
+                            }
+                            new_pub_key__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::CurrentPubKeyHash => {
+                            if current_pub_key_hash__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("currentPubKeyHash"));
+                            }
+                            current_pub_key_hash__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(KeyRotateSubmission {
+                    new_pub_key_index: new_pub_key_index__.unwrap_or_default(),
+                    target_block: target_block__.unwrap_or_default(),
+                    new_pub_key: new_pub_key__.unwrap_or_default(),
+                    current_pub_key_hash: current_pub_key_hash__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.commands.v1.KeyRotateSubmission", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for LiquidityProvisionAmendment {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.market_id.is_empty() {
+            len += 1;
+        }
+        if !self.commitment_amount.is_empty() {
+            len += 1;
+        }
+        if !self.fee.is_empty() {
+            len += 1;
+        }
+        if !self.reference.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.commands.v1.LiquidityProvisionAmendment", len)?;
+        if !self.market_id.is_empty() {
+            struct_ser.serialize_field("marketId", &self.market_id)?;
+        }
+        if !self.commitment_amount.is_empty() {
+            struct_ser.serialize_field("commitmentAmount", &self.commitment_amount)?;
+        }
+        if !self.fee.is_empty() {
+            struct_ser.serialize_field("fee", &self.fee)?;
+        }
+        if !self.reference.is_empty() {
+            struct_ser.serialize_field("reference", &self.reference)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for LiquidityProvisionAmendment {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "market_id",
+            "marketId",
+            "commitment_amount",
+            "commitmentAmount",
+            "fee",
+            "reference",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            MarketId,
+            CommitmentAmount,
+            Fee,
+            Reference,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "marketId" | "market_id" => Ok(GeneratedField::MarketId),
+                            "commitmentAmount" | "commitment_amount" => Ok(GeneratedField::CommitmentAmount),
+                            "fee" => Ok(GeneratedField::Fee),
+                            "reference" => Ok(GeneratedField::Reference),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = LiquidityProvisionAmendment;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.commands.v1.LiquidityProvisionAmendment")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityProvisionAmendment, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut market_id__ = None;
+                let mut commitment_amount__ = None;
+                let mut fee__ = None;
+                let mut reference__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::MarketId => {
+                            if market_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("marketId"));
+                            }
+                            market_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::CommitmentAmount => {
+                            if commitment_amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("commitmentAmount"));
+                            }
+                            commitment_amount__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Fee => {
+                            if fee__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fee"));
+                            }
+                            fee__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Reference => {
+                            if reference__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reference"));
+                            }
+                            reference__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(LiquidityProvisionAmendment {
+                    market_id: market_id__.unwrap_or_default(),
+                    commitment_amount: commitment_amount__.unwrap_or_default(),
+                    fee: fee__.unwrap_or_default(),
+                    reference: reference__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.commands.v1.LiquidityProvisionAmendment", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for LiquidityProvisionCancellation {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.market_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.commands.v1.LiquidityProvisionCancellation", len)?;
+        if !self.market_id.is_empty() {
+            struct_ser.serialize_field("marketId", &self.market_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for LiquidityProvisionCancellation {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "market_id",
+            "marketId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            MarketId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "marketId" | "market_id" => Ok(GeneratedField::MarketId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = LiquidityProvisionCancellation;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.commands.v1.LiquidityProvisionCancellation")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityProvisionCancellation, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut market_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::MarketId => {
+                            if market_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("marketId"));
+                            }
+                            market_id__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(LiquidityProvisionCancellation {
+                    market_id: market_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.commands.v1.LiquidityProvisionCancellation", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for LiquidityProvisionSubmission {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.market_id.is_empty() {
+            len += 1;
+        }
+        if !self.commitment_amount.is_empty() {
+            len += 1;
+        }
+        if !self.fee.is_empty() {
+            len += 1;
+        }
+        if !self.reference.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.commands.v1.LiquidityProvisionSubmission", len)?;
+        if !self.market_id.is_empty() {
+            struct_ser.serialize_field("marketId", &self.market_id)?;
+        }
+        if !self.commitment_amount.is_empty() {
+            struct_ser.serialize_field("commitmentAmount", &self.commitment_amount)?;
+        }
+        if !self.fee.is_empty() {
+            struct_ser.serialize_field("fee", &self.fee)?;
+        }
+        if !self.reference.is_empty() {
+            struct_ser.serialize_field("reference", &self.reference)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for LiquidityProvisionSubmission {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "market_id",
+            "marketId",
+            "commitment_amount",
+            "commitmentAmount",
+            "fee",
+            "reference",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            MarketId,
+            CommitmentAmount,
+            Fee,
+            Reference,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "marketId" | "market_id" => Ok(GeneratedField::MarketId),
+                            "commitmentAmount" | "commitment_amount" => Ok(GeneratedField::CommitmentAmount),
+                            "fee" => Ok(GeneratedField::Fee),
+                            "reference" => Ok(GeneratedField::Reference),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = LiquidityProvisionSubmission;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.commands.v1.LiquidityProvisionSubmission")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityProvisionSubmission, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut market_id__ = None;
+                let mut commitment_amount__ = None;
+                let mut fee__ = None;
+                let mut reference__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::MarketId => {
+                            if market_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("marketId"));
+                            }
+                            market_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::CommitmentAmount => {
+                            if commitment_amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("commitmentAmount"));
+                            }
+                            commitment_amount__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Fee => {
+                            if fee__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fee"));
+                            }
+                            fee__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Reference => {
+                            if reference__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reference"));
+                            }
+                            reference__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(LiquidityProvisionSubmission {
+                    market_id: market_id__.unwrap_or_default(),
+                    commitment_amount: commitment_amount__.unwrap_or_default(),
+                    fee: fee__.unwrap_or_default(),
+                    reference: reference__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.commands.v1.LiquidityProvisionSubmission", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for NodeSignature {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.id.is_empty() {
+            len += 1;
+        }
+        if !self.sig.is_empty() {
+            len += 1;
+        }
+        if self.kind != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.commands.v1.NodeSignature", len)?;
+        if !self.id.is_empty() {
+            struct_ser.serialize_field("id", &self.id)?;
+        }
+        if !self.sig.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("sig", pbjson::private::base64::encode(&self.sig).as_str())?;
+        }
+        if self.kind != 0 {
+            let v = NodeSignatureKind::try_from(self.kind)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.kind)))?;
+            struct_ser.serialize_field("kind", &v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for NodeSignature {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "id",
+            "sig",
+            "kind",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Id,
+            Sig,
+            Kind,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "id" => Ok(GeneratedField::Id),
+                            "sig" => Ok(GeneratedField::Sig),
+                            "kind" => Ok(GeneratedField::Kind),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = NodeSignature;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.commands.v1.NodeSignature")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NodeSignature, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut id__ = None;
+                let mut sig__ = None;
+                let mut kind__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Sig => {
+                            if sig__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sig"));
+                            }
+                            sig__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Kind => {
+                            if kind__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("kind"));
+                            }
+                            kind__ = Some(map_.next_value::<NodeSignatureKind>()? as i32);
+                        }
+                    }
+                }
+                Ok(NodeSignature {
+                    id: id__.unwrap_or_default(),
+                    sig: sig__.unwrap_or_default(),
+                    kind: kind__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.commands.v1.NodeSignature", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for NodeSignatureKind {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "NODE_SIGNATURE_KIND_UNSPECIFIED",
+            Self::AssetNew => "NODE_SIGNATURE_KIND_ASSET_NEW",
+            Self::AssetWithdrawal => "NODE_SIGNATURE_KIND_ASSET_WITHDRAWAL",
+            Self::Erc20MultisigSignerAdded => "NODE_SIGNATURE_KIND_ERC20_MULTISIG_SIGNER_ADDED",
+            Self::Erc20MultisigSignerRemoved => "NODE_SIGNATURE_KIND_ERC20_MULTISIG_SIGNER_REMOVED",
+            Self::AssetUpdate => "NODE_SIGNATURE_KIND_ASSET_UPDATE",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for NodeSignatureKind {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "NODE_SIGNATURE_KIND_UNSPECIFIED",
+            "NODE_SIGNATURE_KIND_ASSET_NEW",
+            "NODE_SIGNATURE_KIND_ASSET_WITHDRAWAL",
+            "NODE_SIGNATURE_KIND_ERC20_MULTISIG_SIGNER_ADDED",
+            "NODE_SIGNATURE_KIND_ERC20_MULTISIG_SIGNER_REMOVED",
+            "NODE_SIGNATURE_KIND_ASSET_UPDATE",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = NodeSignatureKind;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "NODE_SIGNATURE_KIND_UNSPECIFIED" => Ok(NodeSignatureKind::Unspecified),
+                    "NODE_SIGNATURE_KIND_ASSET_NEW" => Ok(NodeSignatureKind::AssetNew),
+                    "NODE_SIGNATURE_KIND_ASSET_WITHDRAWAL" => Ok(NodeSignatureKind::AssetWithdrawal),
+                    "NODE_SIGNATURE_KIND_ERC20_MULTISIG_SIGNER_ADDED" => Ok(NodeSignatureKind::Erc20MultisigSignerAdded),
+                    "NODE_SIGNATURE_KIND_ERC20_MULTISIG_SIGNER_REMOVED" => Ok(NodeSignatureKind::Erc20MultisigSignerRemoved),
+                    "NODE_SIGNATURE_KIND_ASSET_UPDATE" => Ok(NodeSignatureKind::AssetUpdate),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for NodeVote {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.reference.is_empty() {
+            len += 1;
+        }
+        if self.r#type != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.commands.v1.NodeVote", len)?;
+        if !self.reference.is_empty() {
+            struct_ser.serialize_field("reference", &self.reference)?;
+        }
+        if self.r#type != 0 {
+            let v = node_vote::Type::try_from(self.r#type)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.r#type)))?;
+            struct_ser.serialize_field("type", &v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for NodeVote {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "reference",
+            "type",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Reference,
+            Type,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "reference" => Ok(GeneratedField::Reference),
+                            "type" => Ok(GeneratedField::Type),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = NodeVote;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.commands.v1.NodeVote")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NodeVote, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut reference__ = None;
+                let mut r#type__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Reference => {
+                            if reference__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reference"));
+                            }
+                            reference__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Type => {
+                            if r#type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("type"));
+                            }
+                            r#type__ = Some(map_.next_value::<node_vote::Type>()? as i32);
+                        }
+                    }
+                }
+                Ok(NodeVote {
+                    reference: reference__.unwrap_or_default(),
+                    r#type: r#type__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.commands.v1.NodeVote", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for node_vote::Type {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "TYPE_UNSPECIFIED",
+            Self::StakeDeposited => "TYPE_STAKE_DEPOSITED",
+            Self::StakeRemoved => "TYPE_STAKE_REMOVED",
+            Self::FundsDeposited => "TYPE_FUNDS_DEPOSITED",
+            Self::SignerAdded => "TYPE_SIGNER_ADDED",
+            Self::SignerRemoved => "TYPE_SIGNER_REMOVED",
+            Self::BridgeStopped => "TYPE_BRIDGE_STOPPED",
+            Self::BridgeResumed => "TYPE_BRIDGE_RESUMED",
+            Self::AssetListed => "TYPE_ASSET_LISTED",
+            Self::LimitsUpdated => "TYPE_LIMITS_UPDATED",
+            Self::StakeTotalSupply => "TYPE_STAKE_TOTAL_SUPPLY",
+            Self::SignerThresholdSet => "TYPE_SIGNER_THRESHOLD_SET",
+            Self::GovernanceValidateAsset => "TYPE_GOVERNANCE_VALIDATE_ASSET",
+            Self::EthereumContractCallResult => "TYPE_ETHEREUM_CONTRACT_CALL_RESULT",
+            Self::EthereumHeartbeat => "TYPE_ETHEREUM_HEARTBEAT",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for node_vote::Type {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "TYPE_UNSPECIFIED",
+            "TYPE_STAKE_DEPOSITED",
+            "TYPE_STAKE_REMOVED",
+            "TYPE_FUNDS_DEPOSITED",
+            "TYPE_SIGNER_ADDED",
+            "TYPE_SIGNER_REMOVED",
+            "TYPE_BRIDGE_STOPPED",
+            "TYPE_BRIDGE_RESUMED",
+            "TYPE_ASSET_LISTED",
+            "TYPE_LIMITS_UPDATED",
+            "TYPE_STAKE_TOTAL_SUPPLY",
+            "TYPE_SIGNER_THRESHOLD_SET",
+            "TYPE_GOVERNANCE_VALIDATE_ASSET",
+            "TYPE_ETHEREUM_CONTRACT_CALL_RESULT",
+            "TYPE_ETHEREUM_HEARTBEAT",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = node_vote::Type;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "TYPE_UNSPECIFIED" => Ok(node_vote::Type::Unspecified),
+                    "TYPE_STAKE_DEPOSITED" => Ok(node_vote::Type::StakeDeposited),
+                    "TYPE_STAKE_REMOVED" => Ok(node_vote::Type::StakeRemoved),
+                    "TYPE_FUNDS_DEPOSITED" => Ok(node_vote::Type::FundsDeposited),
+                    "TYPE_SIGNER_ADDED" => Ok(node_vote::Type::SignerAdded),
+                    "TYPE_SIGNER_REMOVED" => Ok(node_vote::Type::SignerRemoved),
+                    "TYPE_BRIDGE_STOPPED" => Ok(node_vote::Type::BridgeStopped),
+                    "TYPE_BRIDGE_RESUMED" => Ok(node_vote::Type::BridgeResumed),
+                    "TYPE_ASSET_LISTED" => Ok(node_vote::Type::AssetListed),
+                    "TYPE_LIMITS_UPDATED" => Ok(node_vote::Type::LimitsUpdated),
+                    "TYPE_STAKE_TOTAL_SUPPLY" => Ok(node_vote::Type::StakeTotalSupply),
+                    "TYPE_SIGNER_THRESHOLD_SET" => Ok(node_vote::Type::SignerThresholdSet),
+                    "TYPE_GOVERNANCE_VALIDATE_ASSET" => Ok(node_vote::Type::GovernanceValidateAsset),
+                    "TYPE_ETHEREUM_CONTRACT_CALL_RESULT" => Ok(node_vote::Type::EthereumContractCallResult),
+                    "TYPE_ETHEREUM_HEARTBEAT" => Ok(node_vote::Type::EthereumHeartbeat),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for OneOffTransfer {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.deliver_on != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.commands.v1.OneOffTransfer", len)?;
+        if self.deliver_on != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("deliverOn", ToString::to_string(&self.deliver_on).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for OneOffTransfer {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "deliver_on",
+            "deliverOn",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            DeliverOn,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "deliverOn" | "deliver_on" => Ok(GeneratedField::DeliverOn),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = OneOffTransfer;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.commands.v1.OneOffTransfer")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<OneOffTransfer, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut deliver_on__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::DeliverOn => {
+                            if deliver_on__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("deliverOn"));
+                            }
+                            deliver_on__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(OneOffTransfer {
+                    deliver_on: deliver_on__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.commands.v1.OneOffTransfer", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for OracleDataSubmission {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.source != 0 {
+            len += 1;
+        }
+        if !self.payload.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.commands.v1.OracleDataSubmission", len)?;
+        if self.source != 0 {
+            let v = oracle_data_submission::OracleSource::try_from(self.source)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.source)))?;
+            struct_ser.serialize_field("source", &v)?;
+        }
+        if !self.payload.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("payload", pbjson::private::base64::encode(&self.payload).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for OracleDataSubmission {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "source",
+            "payload",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Source,
+            Payload,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "source" => Ok(GeneratedField::Source),
+                            "payload" => Ok(GeneratedField::Payload),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = OracleDataSubmission;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.commands.v1.OracleDataSubmission")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<OracleDataSubmission, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut source__ = None;
+                let mut payload__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Source => {
+                            if source__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("source"));
+                            }
+                            source__ = Some(map_.next_value::<oracle_data_submission::OracleSource>()? as i32);
+                        }
+                        GeneratedField::Payload => {
+                            if payload__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("payload"));
+                            }
+                            payload__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(OracleDataSubmission {
+                    source: source__.unwrap_or_default(),
+                    payload: payload__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.commands.v1.OracleDataSubmission", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for oracle_data_submission::OracleSource {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "ORACLE_SOURCE_UNSPECIFIED",
+            Self::OpenOracle => "ORACLE_SOURCE_OPEN_ORACLE",
+            Self::Json => "ORACLE_SOURCE_JSON",
+            Self::Ethereum => "ORACLE_SOURCE_ETHEREUM",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for oracle_data_submission::OracleSource {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "ORACLE_SOURCE_UNSPECIFIED",
+            "ORACLE_SOURCE_OPEN_ORACLE",
+            "ORACLE_SOURCE_JSON",
+            "ORACLE_SOURCE_ETHEREUM",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = oracle_data_submission::OracleSource;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "ORACLE_SOURCE_UNSPECIFIED" => Ok(oracle_data_submission::OracleSource::Unspecified),
+                    "ORACLE_SOURCE_OPEN_ORACLE" => Ok(oracle_data_submission::OracleSource::OpenOracle),
+                    "ORACLE_SOURCE_JSON" => Ok(oracle_data_submission::OracleSource::Json),
+                    "ORACLE_SOURCE_ETHEREUM" => Ok(oracle_data_submission::OracleSource::Ethereum),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for OrderAmendment {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.order_id.is_empty() {
+            len += 1;
+        }
+        if !self.market_id.is_empty() {
+            len += 1;
+        }
+        if self.price.is_some() {
+            len += 1;
+        }
+        if self.size_delta != 0 {
+            len += 1;
+        }
+        if self.expires_at.is_some() {
+            len += 1;
+        }
+        if self.time_in_force != 0 {
+            len += 1;
+        }
+        if !self.pegged_offset.is_empty() {
+            len += 1;
+        }
+        if self.pegged_reference != 0 {
+            len += 1;
+        }
+        if self.size.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.commands.v1.OrderAmendment", len)?;
+        if !self.order_id.is_empty() {
+            struct_ser.serialize_field("orderId", &self.order_id)?;
+        }
+        if !self.market_id.is_empty() {
+            struct_ser.serialize_field("marketId", &self.market_id)?;
+        }
+        if let Some(v) = self.price.as_ref() {
+            struct_ser.serialize_field("price", v)?;
+        }
+        if self.size_delta != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("sizeDelta", ToString::to_string(&self.size_delta).as_str())?;
+        }
+        if let Some(v) = self.expires_at.as_ref() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("expiresAt", ToString::to_string(&v).as_str())?;
+        }
+        if self.time_in_force != 0 {
+            let v = super::super::order::TimeInForce::try_from(self.time_in_force)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.time_in_force)))?;
+            struct_ser.serialize_field("timeInForce", &v)?;
+        }
+        if !self.pegged_offset.is_empty() {
+            struct_ser.serialize_field("peggedOffset", &self.pegged_offset)?;
+        }
+        if self.pegged_reference != 0 {
+            let v = super::super::PeggedReference::try_from(self.pegged_reference)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.pegged_reference)))?;
+            struct_ser.serialize_field("peggedReference", &v)?;
+        }
+        if let Some(v) = self.size.as_ref() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("size", ToString::to_string(&v).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for OrderAmendment {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "order_id",
+            "orderId",
+            "market_id",
+            "marketId",
+            "price",
+            "size_delta",
+            "sizeDelta",
+            "expires_at",
+            "expiresAt",
+            "time_in_force",
+            "timeInForce",
+            "pegged_offset",
+            "peggedOffset",
+            "pegged_reference",
+            "peggedReference",
+            "size",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            OrderId,
+            MarketId,
+            Price,
+            SizeDelta,
+            ExpiresAt,
+            TimeInForce,
+            PeggedOffset,
+            PeggedReference,
+            Size,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "orderId" | "order_id" => Ok(GeneratedField::OrderId),
+                            "marketId" | "market_id" => Ok(GeneratedField::MarketId),
+                            "price" => Ok(GeneratedField::Price),
+                            "sizeDelta" | "size_delta" => Ok(GeneratedField::SizeDelta),
+                            "expiresAt" | "expires_at" => Ok(GeneratedField::ExpiresAt),
+                            "timeInForce" | "time_in_force" => Ok(GeneratedField::TimeInForce),
+                            "peggedOffset" | "pegged_offset" => Ok(GeneratedField::PeggedOffset),
+                            "peggedReference" | "pegged_reference" => Ok(GeneratedField::PeggedReference),
+                            "size" => Ok(GeneratedField::Size),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = OrderAmendment;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.commands.v1.OrderAmendment")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<OrderAmendment, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut order_id__ = None;
+                let mut market_id__ = None;
+                let mut price__ = None;
+                let mut size_delta__ = None;
+                let mut expires_at__ = None;
+                let mut time_in_force__ = None;
+                let mut pegged_offset__ = None;
+                let mut pegged_reference__ = None;
+                let mut size__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::OrderId => {
+                            if order_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("orderId"));
+                            }
+                            order_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::MarketId => {
+                            if market_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("marketId"));
+                            }
+                            market_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Price => {
+                            if price__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("price"));
+                            }
+                            price__ = map_.next_value()?;
+                        }
+                        GeneratedField::SizeDelta => {
+                            if size_delta__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sizeDelta"));
+                            }
+                            size_delta__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ExpiresAt => {
+                            if expires_at__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("expiresAt"));
+                            }
+                            expires_at__ = 
+                                map_.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                        GeneratedField::TimeInForce => {
+                            if time_in_force__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timeInForce"));
+                            }
+                            time_in_force__ = Some(map_.next_value::<super::super::order::TimeInForce>()? as i32);
+                        }
+                        GeneratedField::PeggedOffset => {
+                            if pegged_offset__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("peggedOffset"));
+                            }
+                            pegged_offset__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::PeggedReference => {
+                            if pegged_reference__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("peggedReference"));
+                            }
+                            pegged_reference__ = Some(map_.next_value::<super::super::PeggedReference>()? as i32);
+                        }
+                        GeneratedField::Size => {
+                            if size__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("size"));
+                            }
+                            size__ = 
+                                map_.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(OrderAmendment {
+                    order_id: order_id__.unwrap_or_default(),
+                    market_id: market_id__.unwrap_or_default(),
+                    price: price__,
+                    size_delta: size_delta__.unwrap_or_default(),
+                    expires_at: expires_at__,
+                    time_in_force: time_in_force__.unwrap_or_default(),
+                    pegged_offset: pegged_offset__.unwrap_or_default(),
+                    pegged_reference: pegged_reference__.unwrap_or_default(),
+                    size: size__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.commands.v1.OrderAmendment", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for OrderCancellation {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.order_id.is_empty() {
+            len += 1;
+        }
+        if !self.market_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.commands.v1.OrderCancellation", len)?;
+        if !self.order_id.is_empty() {
+            struct_ser.serialize_field("orderId", &self.order_id)?;
+        }
+        if !self.market_id.is_empty() {
+            struct_ser.serialize_field("marketId", &self.market_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for OrderCancellation {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "order_id",
+            "orderId",
+            "market_id",
+            "marketId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            OrderId,
+            MarketId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "orderId" | "order_id" => Ok(GeneratedField::OrderId),
+                            "marketId" | "market_id" => Ok(GeneratedField::MarketId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = OrderCancellation;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.commands.v1.OrderCancellation")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<OrderCancellation, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut order_id__ = None;
+                let mut market_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::OrderId => {
+                            if order_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("orderId"));
+                            }
+                            order_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::MarketId => {
+                            if market_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("marketId"));
+                            }
+                            market_id__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(OrderCancellation {
+                    order_id: order_id__.unwrap_or_default(),
+                    market_id: market_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.commands.v1.OrderCancellation", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for OrderSubmission {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.market_id.is_empty() {
+            len += 1;
+        }
+        if !self.price.is_empty() {
+            len += 1;
+        }
+        if self.size != 0 {
+            len += 1;
+        }
+        if self.side != 0 {
+            len += 1;
+        }
+        if self.time_in_force != 0 {
+            len += 1;
+        }
+        if self.expires_at != 0 {
+            len += 1;
+        }
+        if self.r#type != 0 {
+            len += 1;
+        }
+        if !self.reference.is_empty() {
+            len += 1;
+        }
+        if self.pegged_order.is_some() {
+            len += 1;
+        }
+        if self.post_only {
+            len += 1;
+        }
+        if self.reduce_only {
+            len += 1;
+        }
+        if self.iceberg_opts.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.commands.v1.OrderSubmission", len)?;
+        if !self.market_id.is_empty() {
+            struct_ser.serialize_field("marketId", &self.market_id)?;
+        }
+        if !self.price.is_empty() {
+            struct_ser.serialize_field("price", &self.price)?;
+        }
+        if self.size != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("size", ToString::to_string(&self.size).as_str())?;
+        }
+        if self.side != 0 {
+            let v = super::super::Side::try_from(self.side)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.side)))?;
+            struct_ser.serialize_field("side", &v)?;
+        }
+        if self.time_in_force != 0 {
+            let v = super::super::order::TimeInForce::try_from(self.time_in_force)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.time_in_force)))?;
+            struct_ser.serialize_field("timeInForce", &v)?;
+        }
+        if self.expires_at != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("expiresAt", ToString::to_string(&self.expires_at).as_str())?;
+        }
+        if self.r#type != 0 {
+            let v = super::super::order::Type::try_from(self.r#type)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.r#type)))?;
+            struct_ser.serialize_field("type", &v)?;
+        }
+        if !self.reference.is_empty() {
+            struct_ser.serialize_field("reference", &self.reference)?;
+        }
+        if let Some(v) = self.pegged_order.as_ref() {
+            struct_ser.serialize_field("peggedOrder", v)?;
+        }
+        if self.post_only {
+            struct_ser.serialize_field("postOnly", &self.post_only)?;
+        }
+        if self.reduce_only {
+            struct_ser.serialize_field("reduceOnly", &self.reduce_only)?;
+        }
+        if let Some(v) = self.iceberg_opts.as_ref() {
+            struct_ser.serialize_field("icebergOpts", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for OrderSubmission {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "market_id",
+            "marketId",
+            "price",
+            "size",
+            "side",
+            "time_in_force",
+            "timeInForce",
+            "expires_at",
+            "expiresAt",
+            "type",
+            "reference",
+            "pegged_order",
+            "peggedOrder",
+            "post_only",
+            "postOnly",
+            "reduce_only",
+            "reduceOnly",
+            "iceberg_opts",
+            "icebergOpts",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            MarketId,
+            Price,
+            Size,
+            Side,
+            TimeInForce,
+            ExpiresAt,
+            Type,
+            Reference,
+            PeggedOrder,
+            PostOnly,
+            ReduceOnly,
+            IcebergOpts,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "marketId" | "market_id" => Ok(GeneratedField::MarketId),
+                            "price" => Ok(GeneratedField::Price),
+                            "size" => Ok(GeneratedField::Size),
+                            "side" => Ok(GeneratedField::Side),
+                            "timeInForce" | "time_in_force" => Ok(GeneratedField::TimeInForce),
+                            "expiresAt" | "expires_at" => Ok(GeneratedField::ExpiresAt),
+                            "type" => Ok(GeneratedField::Type),
+                            "reference" => Ok(GeneratedField::Reference),
+                            "peggedOrder" | "pegged_order" => Ok(GeneratedField::PeggedOrder),
+                            "postOnly" | "post_only" => Ok(GeneratedField::PostOnly),
+                            "reduceOnly" | "reduce_only" => Ok(GeneratedField::ReduceOnly),
+                            "icebergOpts" | "iceberg_opts" => Ok(GeneratedField::IcebergOpts),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = OrderSubmission;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.commands.v1.OrderSubmission")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<OrderSubmission, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut market_id__ = None;
+                let mut price__ = None;
+                let mut size__ = None;
+                let mut side__ = None;
+                let mut time_in_force__ = None;
+                let mut expires_at__ = None;
+                let mut r#type__ = None;
+                let mut reference__ = None;
+                let mut pegged_order__ = None;
+                let mut post_only__ = None;
+                let mut reduce_only__ = None;
+                let mut iceberg_opts__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::MarketId => {
+                            if market_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("marketId"));
+                            }
+                            market_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Price => {
+                            if price__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("price"));
+                            }
+                            price__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Size => {
+                            if size__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("size"));
+                            }
+                            size__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Side => {
+                            if side__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("side"));
+                            }
+                            side__ = Some(map_.next_value::<super::super::Side>()? as i32);
+                        }
+                        GeneratedField::TimeInForce => {
+                            if time_in_force__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timeInForce"));
+                            }
+                            time_in_force__ = Some(map_.next_value::<super::super::order::TimeInForce>()? as i32);
+                        }
+                        GeneratedField::ExpiresAt => {
+                            if expires_at__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("expiresAt"));
+                            }
+                            expires_at__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Type => {
+                            if r#type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("type"));
+                            }
+                            r#type__ = Some(map_.next_value::<super::super::order::Type>()? as i32);
+                        }
+                        GeneratedField::Reference => {
+                            if reference__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reference"));
+                            }
+                            reference__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::PeggedOrder => {
+                            if pegged_order__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("peggedOrder"));
+                            }
+                            pegged_order__ = map_.next_value()?;
+                        }
+                        GeneratedField::PostOnly => {
+                            if post_only__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("postOnly"));
+                            }
+                            post_only__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ReduceOnly => {
+                            if reduce_only__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reduceOnly"));
+                            }
+                            reduce_only__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::IcebergOpts => {
+                            if iceberg_opts__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("icebergOpts"));
+                            }
+                            iceberg_opts__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(OrderSubmission {
+                    market_id: market_id__.unwrap_or_default(),
+                    price: price__.unwrap_or_default(),
+                    size: size__.unwrap_or_default(),
+                    side: side__.unwrap_or_default(),
+                    time_in_force: time_in_force__.unwrap_or_default(),
+                    expires_at: expires_at__.unwrap_or_default(),
+                    r#type: r#type__.unwrap_or_default(),
+                    reference: reference__.unwrap_or_default(),
+                    pegged_order: pegged_order__,
+                    post_only: post_only__.unwrap_or_default(),
+                    reduce_only: reduce_only__.unwrap_or_default(),
+                    iceberg_opts: iceberg_opts__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.commands.v1.OrderSubmission", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ProofOfWork {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.tid.is_empty() {
+            len += 1;
+        }
+        if self.nonce != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.commands.v1.ProofOfWork", len)?;
+        if !self.tid.is_empty() {
+            struct_ser.serialize_field("tid", &self.tid)?;
+        }
+        if self.nonce != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("nonce", ToString::to_string(&self.nonce).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ProofOfWork {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "tid",
+            "nonce",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Tid,
+            Nonce,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "tid" => Ok(GeneratedField::Tid),
+                            "nonce" => Ok(GeneratedField::Nonce),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ProofOfWork;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.commands.v1.ProofOfWork")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ProofOfWork, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut tid__ = None;
+                let mut nonce__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Tid => {
+                            if tid__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tid"));
+                            }
+                            tid__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Nonce => {
+                            if nonce__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nonce"));
+                            }
+                            nonce__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(ProofOfWork {
+                    tid: tid__.unwrap_or_default(),
+                    nonce: nonce__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.commands.v1.ProofOfWork", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ProposalSubmission {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.reference.is_empty() {
+            len += 1;
+        }
+        if self.terms.is_some() {
+            len += 1;
+        }
+        if self.rationale.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.commands.v1.ProposalSubmission", len)?;
+        if !self.reference.is_empty() {
+            struct_ser.serialize_field("reference", &self.reference)?;
+        }
+        if let Some(v) = self.terms.as_ref() {
+            struct_ser.serialize_field("terms", v)?;
+        }
+        if let Some(v) = self.rationale.as_ref() {
+            struct_ser.serialize_field("rationale", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ProposalSubmission {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "reference",
+            "terms",
+            "rationale",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Reference,
+            Terms,
+            Rationale,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "reference" => Ok(GeneratedField::Reference),
+                            "terms" => Ok(GeneratedField::Terms),
+                            "rationale" => Ok(GeneratedField::Rationale),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ProposalSubmission;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.commands.v1.ProposalSubmission")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ProposalSubmission, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut reference__ = None;
+                let mut terms__ = None;
+                let mut rationale__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Reference => {
+                            if reference__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reference"));
+                            }
+                            reference__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Terms => {
+                            if terms__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("terms"));
+                            }
+                            terms__ = map_.next_value()?;
+                        }
+                        GeneratedField::Rationale => {
+                            if rationale__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rationale"));
+                            }
+                            rationale__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(ProposalSubmission {
+                    reference: reference__.unwrap_or_default(),
+                    terms: terms__,
+                    rationale: rationale__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.commands.v1.ProposalSubmission", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ProtocolUpgradeProposal {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.upgrade_block_height != 0 {
+            len += 1;
+        }
+        if !self.vega_release_tag.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.commands.v1.ProtocolUpgradeProposal", len)?;
+        if self.upgrade_block_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("upgradeBlockHeight", ToString::to_string(&self.upgrade_block_height).as_str())?;
+        }
+        if !self.vega_release_tag.is_empty() {
+            struct_ser.serialize_field("vegaReleaseTag", &self.vega_release_tag)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ProtocolUpgradeProposal {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "upgrade_block_height",
+            "upgradeBlockHeight",
+            "vega_release_tag",
+            "vegaReleaseTag",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            UpgradeBlockHeight,
+            VegaReleaseTag,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "upgradeBlockHeight" | "upgrade_block_height" => Ok(GeneratedField::UpgradeBlockHeight),
+                            "vegaReleaseTag" | "vega_release_tag" => Ok(GeneratedField::VegaReleaseTag),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ProtocolUpgradeProposal;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.commands.v1.ProtocolUpgradeProposal")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ProtocolUpgradeProposal, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut upgrade_block_height__ = None;
+                let mut vega_release_tag__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::UpgradeBlockHeight => {
+                            if upgrade_block_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("upgradeBlockHeight"));
+                            }
+                            upgrade_block_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::VegaReleaseTag => {
+                            if vega_release_tag__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("vegaReleaseTag"));
+                            }
+                            vega_release_tag__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(ProtocolUpgradeProposal {
+                    upgrade_block_height: upgrade_block_height__.unwrap_or_default(),
+                    vega_release_tag: vega_release_tag__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.commands.v1.ProtocolUpgradeProposal", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for RecurringTransfer {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.start_epoch != 0 {
+            len += 1;
+        }
+        if self.end_epoch.is_some() {
+            len += 1;
+        }
+        if !self.factor.is_empty() {
+            len += 1;
+        }
+        if self.dispatch_strategy.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.commands.v1.RecurringTransfer", len)?;
+        if self.start_epoch != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("startEpoch", ToString::to_string(&self.start_epoch).as_str())?;
+        }
+        if let Some(v) = self.end_epoch.as_ref() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("endEpoch", ToString::to_string(&v).as_str())?;
+        }
+        if !self.factor.is_empty() {
+            struct_ser.serialize_field("factor", &self.factor)?;
+        }
+        if let Some(v) = self.dispatch_strategy.as_ref() {
+            struct_ser.serialize_field("dispatchStrategy", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RecurringTransfer {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "start_epoch",
+            "startEpoch",
+            "end_epoch",
+            "endEpoch",
+            "factor",
+            "dispatch_strategy",
+            "dispatchStrategy",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            StartEpoch,
+            EndEpoch,
+            Factor,
+            DispatchStrategy,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "startEpoch" | "start_epoch" => Ok(GeneratedField::StartEpoch),
+                            "endEpoch" | "end_epoch" => Ok(GeneratedField::EndEpoch),
+                            "factor" => Ok(GeneratedField::Factor),
+                            "dispatchStrategy" | "dispatch_strategy" => Ok(GeneratedField::DispatchStrategy),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = RecurringTransfer;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.commands.v1.RecurringTransfer")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RecurringTransfer, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut start_epoch__ = None;
+                let mut end_epoch__ = None;
+                let mut factor__ = None;
+                let mut dispatch_strategy__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::StartEpoch => {
+                            if start_epoch__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startEpoch"));
+                            }
+                            start_epoch__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::EndEpoch => {
+                            if end_epoch__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endEpoch"));
+                            }
+                            end_epoch__ = 
+                                map_.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                        GeneratedField::Factor => {
+                            if factor__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("factor"));
+                            }
+                            factor__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::DispatchStrategy => {
+                            if dispatch_strategy__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("dispatchStrategy"));
+                            }
+                            dispatch_strategy__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(RecurringTransfer {
+                    start_epoch: start_epoch__.unwrap_or_default(),
+                    end_epoch: end_epoch__,
+                    factor: factor__.unwrap_or_default(),
+                    dispatch_strategy: dispatch_strategy__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.commands.v1.RecurringTransfer", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Signature {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.value.is_empty() {
+            len += 1;
+        }
+        if !self.algo.is_empty() {
+            len += 1;
+        }
+        if self.version != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.commands.v1.Signature", len)?;
+        if !self.value.is_empty() {
+            struct_ser.serialize_field("value", &self.value)?;
+        }
+        if !self.algo.is_empty() {
+            struct_ser.serialize_field("algo", &self.algo)?;
+        }
+        if self.version != 0 {
+            struct_ser.serialize_field("version", &self.version)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Signature {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "value",
+            "algo",
+            "version",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Value,
+            Algo,
+            Version,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "value" => Ok(GeneratedField::Value),
+                            "algo" => Ok(GeneratedField::Algo),
+                            "version" => Ok(GeneratedField::Version),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Signature;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.commands.v1.Signature")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Signature, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut value__ = None;
+                let mut algo__ = None;
+                let mut version__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Value => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("value"));
+                            }
+                            value__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Algo => {
+                            if algo__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("algo"));
+                            }
+                            algo__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Version => {
+                            if version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("version"));
+                            }
+                            version__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(Signature {
+                    value: value__.unwrap_or_default(),
+                    algo: algo__.unwrap_or_default(),
+                    version: version__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.commands.v1.Signature", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for StateVariableProposal {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.proposal.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.commands.v1.StateVariableProposal", len)?;
+        if let Some(v) = self.proposal.as_ref() {
+            struct_ser.serialize_field("proposal", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for StateVariableProposal {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "proposal",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Proposal,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "proposal" => Ok(GeneratedField::Proposal),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = StateVariableProposal;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.commands.v1.StateVariableProposal")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<StateVariableProposal, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut proposal__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Proposal => {
+                            if proposal__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposal"));
+                            }
+                            proposal__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(StateVariableProposal {
+                    proposal: proposal__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.commands.v1.StateVariableProposal", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for StopOrderSetup {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.order_submission.is_some() {
+            len += 1;
+        }
+        if self.expires_at.is_some() {
+            len += 1;
+        }
+        if self.expiry_strategy.is_some() {
+            len += 1;
+        }
+        if self.size_override_setting.is_some() {
+            len += 1;
+        }
+        if self.size_override_value.is_some() {
+            len += 1;
+        }
+        if self.trigger.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.commands.v1.StopOrderSetup", len)?;
+        if let Some(v) = self.order_submission.as_ref() {
+            struct_ser.serialize_field("orderSubmission", v)?;
+        }
+        if let Some(v) = self.expires_at.as_ref() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("expiresAt", ToString::to_string(&v).as_str())?;
+        }
+        if let Some(v) = self.expiry_strategy.as_ref() {
+            let v = super::super::stop_order::ExpiryStrategy::try_from(*v)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", *v)))?;
+            struct_ser.serialize_field("expiryStrategy", &v)?;
+        }
+        if let Some(v) = self.size_override_setting.as_ref() {
+            let v = super::super::stop_order::SizeOverrideSetting::try_from(*v)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", *v)))?;
+            struct_ser.serialize_field("sizeOverrideSetting", &v)?;
+        }
+        if let Some(v) = self.size_override_value.as_ref() {
+            struct_ser.serialize_field("sizeOverrideValue", v)?;
+        }
+        if let Some(v) = self.trigger.as_ref() {
+            match v {
+                stop_order_setup::Trigger::Price(v) => {
+                    struct_ser.serialize_field("price", v)?;
+                }
+                stop_order_setup::Trigger::TrailingPercentOffset(v) => {
+                    struct_ser.serialize_field("trailingPercentOffset", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for StopOrderSetup {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "order_submission",
+            "orderSubmission",
+            "expires_at",
+            "expiresAt",
+            "expiry_strategy",
+            "expiryStrategy",
+            "size_override_setting",
+            "sizeOverrideSetting",
+            "size_override_value",
+            "sizeOverrideValue",
+            "price",
+            "trailing_percent_offset",
+            "trailingPercentOffset",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            OrderSubmission,
+            ExpiresAt,
+            ExpiryStrategy,
+            SizeOverrideSetting,
+            SizeOverrideValue,
+            Price,
+            TrailingPercentOffset,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "orderSubmission" | "order_submission" => Ok(GeneratedField::OrderSubmission),
+                            "expiresAt" | "expires_at" => Ok(GeneratedField::ExpiresAt),
+                            "expiryStrategy" | "expiry_strategy" => Ok(GeneratedField::ExpiryStrategy),
+                            "sizeOverrideSetting" | "size_override_setting" => Ok(GeneratedField::SizeOverrideSetting),
+                            "sizeOverrideValue" | "size_override_value" => Ok(GeneratedField::SizeOverrideValue),
+                            "price" => Ok(GeneratedField::Price),
+                            "trailingPercentOffset" | "trailing_percent_offset" => Ok(GeneratedField::TrailingPercentOffset),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = StopOrderSetup;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.commands.v1.StopOrderSetup")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<StopOrderSetup, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut order_submission__ = None;
+                let mut expires_at__ = None;
+                let mut expiry_strategy__ = None;
+                let mut size_override_setting__ = None;
+                let mut size_override_value__ = None;
+                let mut trigger__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::OrderSubmission => {
+                            if order_submission__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("orderSubmission"));
+                            }
+                            order_submission__ = map_.next_value()?;
+                        }
+                        GeneratedField::ExpiresAt => {
+                            if expires_at__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("expiresAt"));
+                            }
+                            expires_at__ = 
+                                map_.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                        GeneratedField::ExpiryStrategy => {
+                            if expiry_strategy__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("expiryStrategy"));
+                            }
+                            expiry_strategy__ = map_.next_value::<::std::option::Option<super::super::stop_order::ExpiryStrategy>>()?.map(|x| x as i32);
+                        }
+                        GeneratedField::SizeOverrideSetting => {
+                            if size_override_setting__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sizeOverrideSetting"));
+                            }
+                            size_override_setting__ = map_.next_value::<::std::option::Option<super::super::stop_order::SizeOverrideSetting>>()?.map(|x| x as i32);
+                        }
+                        GeneratedField::SizeOverrideValue => {
+                            if size_override_value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sizeOverrideValue"));
+                            }
+                            size_override_value__ = map_.next_value()?;
+                        }
+                        GeneratedField::Price => {
+                            if trigger__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("price"));
+                            }
+                            trigger__ = map_.next_value::<::std::option::Option<_>>()?.map(stop_order_setup::Trigger::Price);
+                        }
+                        GeneratedField::TrailingPercentOffset => {
+                            if trigger__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("trailingPercentOffset"));
+                            }
+                            trigger__ = map_.next_value::<::std::option::Option<_>>()?.map(stop_order_setup::Trigger::TrailingPercentOffset);
+                        }
+                    }
+                }
+                Ok(StopOrderSetup {
+                    order_submission: order_submission__,
+                    expires_at: expires_at__,
+                    expiry_strategy: expiry_strategy__,
+                    size_override_setting: size_override_setting__,
+                    size_override_value: size_override_value__,
+                    trigger: trigger__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.commands.v1.StopOrderSetup", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for StopOrdersCancellation {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.market_id.is_some() {
+            len += 1;
+        }
+        if self.stop_order_id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.commands.v1.StopOrdersCancellation", len)?;
+        if let Some(v) = self.market_id.as_ref() {
+            struct_ser.serialize_field("marketId", v)?;
+        }
+        if let Some(v) = self.stop_order_id.as_ref() {
+            struct_ser.serialize_field("stopOrderId", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for StopOrdersCancellation {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "market_id",
+            "marketId",
+            "stop_order_id",
+            "stopOrderId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            MarketId,
+            StopOrderId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "marketId" | "market_id" => Ok(GeneratedField::MarketId),
+                            "stopOrderId" | "stop_order_id" => Ok(GeneratedField::StopOrderId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = StopOrdersCancellation;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.commands.v1.StopOrdersCancellation")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<StopOrdersCancellation, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut market_id__ = None;
+                let mut stop_order_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::MarketId => {
+                            if market_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("marketId"));
+                            }
+                            market_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::StopOrderId => {
+                            if stop_order_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stopOrderId"));
+                            }
+                            stop_order_id__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(StopOrdersCancellation {
+                    market_id: market_id__,
+                    stop_order_id: stop_order_id__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.commands.v1.StopOrdersCancellation", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for StopOrdersSubmission {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.rises_above.is_some() {
+            len += 1;
+        }
+        if self.falls_below.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.commands.v1.StopOrdersSubmission", len)?;
+        if let Some(v) = self.rises_above.as_ref() {
+            struct_ser.serialize_field("risesAbove", v)?;
+        }
+        if let Some(v) = self.falls_below.as_ref() {
+            struct_ser.serialize_field("fallsBelow", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for StopOrdersSubmission {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "rises_above",
+            "risesAbove",
+            "falls_below",
+            "fallsBelow",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            RisesAbove,
+            FallsBelow,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "risesAbove" | "rises_above" => Ok(GeneratedField::RisesAbove),
+                            "fallsBelow" | "falls_below" => Ok(GeneratedField::FallsBelow),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = StopOrdersSubmission;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.commands.v1.StopOrdersSubmission")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<StopOrdersSubmission, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut rises_above__ = None;
+                let mut falls_below__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::RisesAbove => {
+                            if rises_above__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("risesAbove"));
+                            }
+                            rises_above__ = map_.next_value()?;
+                        }
+                        GeneratedField::FallsBelow => {
+                            if falls_below__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fallsBelow"));
+                            }
+                            falls_below__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(StopOrdersSubmission {
+                    rises_above: rises_above__,
+                    falls_below: falls_below__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.commands.v1.StopOrdersSubmission", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SubmitAmm {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.market_id.is_empty() {
+            len += 1;
+        }
+        if !self.commitment_amount.is_empty() {
+            len += 1;
+        }
+        if !self.slippage_tolerance.is_empty() {
+            len += 1;
+        }
+        if self.concentrated_liquidity_parameters.is_some() {
+            len += 1;
+        }
+        if !self.proposed_fee.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.commands.v1.SubmitAMM", len)?;
+        if !self.market_id.is_empty() {
+            struct_ser.serialize_field("marketId", &self.market_id)?;
+        }
+        if !self.commitment_amount.is_empty() {
+            struct_ser.serialize_field("commitmentAmount", &self.commitment_amount)?;
+        }
+        if !self.slippage_tolerance.is_empty() {
+            struct_ser.serialize_field("slippageTolerance", &self.slippage_tolerance)?;
+        }
+        if let Some(v) = self.concentrated_liquidity_parameters.as_ref() {
+            struct_ser.serialize_field("concentratedLiquidityParameters", v)?;
+        }
+        if !self.proposed_fee.is_empty() {
+            struct_ser.serialize_field("proposedFee", &self.proposed_fee)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SubmitAmm {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "market_id",
+            "marketId",
+            "commitment_amount",
+            "commitmentAmount",
+            "slippage_tolerance",
+            "slippageTolerance",
+            "concentrated_liquidity_parameters",
+            "concentratedLiquidityParameters",
+            "proposed_fee",
+            "proposedFee",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            MarketId,
+            CommitmentAmount,
+            SlippageTolerance,
+            ConcentratedLiquidityParameters,
+            ProposedFee,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "marketId" | "market_id" => Ok(GeneratedField::MarketId),
+                            "commitmentAmount" | "commitment_amount" => Ok(GeneratedField::CommitmentAmount),
+                            "slippageTolerance" | "slippage_tolerance" => Ok(GeneratedField::SlippageTolerance),
+                            "concentratedLiquidityParameters" | "concentrated_liquidity_parameters" => Ok(GeneratedField::ConcentratedLiquidityParameters),
+                            "proposedFee" | "proposed_fee" => Ok(GeneratedField::ProposedFee),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SubmitAmm;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.commands.v1.SubmitAMM")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SubmitAmm, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut market_id__ = None;
+                let mut commitment_amount__ = None;
+                let mut slippage_tolerance__ = None;
+                let mut concentrated_liquidity_parameters__ = None;
+                let mut proposed_fee__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::MarketId => {
+                            if market_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("marketId"));
+                            }
+                            market_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::CommitmentAmount => {
+                            if commitment_amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("commitmentAmount"));
+                            }
+                            commitment_amount__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::SlippageTolerance => {
+                            if slippage_tolerance__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("slippageTolerance"));
+                            }
+                            slippage_tolerance__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ConcentratedLiquidityParameters => {
+                            if concentrated_liquidity_parameters__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("concentratedLiquidityParameters"));
+                            }
+                            concentrated_liquidity_parameters__ = map_.next_value()?;
+                        }
+                        GeneratedField::ProposedFee => {
+                            if proposed_fee__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposedFee"));
+                            }
+                            proposed_fee__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(SubmitAmm {
+                    market_id: market_id__.unwrap_or_default(),
+                    commitment_amount: commitment_amount__.unwrap_or_default(),
+                    slippage_tolerance: slippage_tolerance__.unwrap_or_default(),
+                    concentrated_liquidity_parameters: concentrated_liquidity_parameters__,
+                    proposed_fee: proposed_fee__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.commands.v1.SubmitAMM", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for submit_amm::ConcentratedLiquidityParameters {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.upper_bound.is_some() {
+            len += 1;
+        }
+        if self.lower_bound.is_some() {
+            len += 1;
+        }
+        if !self.base.is_empty() {
+            len += 1;
+        }
+        if self.leverage_at_upper_bound.is_some() {
+            len += 1;
+        }
+        if self.leverage_at_lower_bound.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.commands.v1.SubmitAMM.ConcentratedLiquidityParameters", len)?;
+        if let Some(v) = self.upper_bound.as_ref() {
+            struct_ser.serialize_field("upperBound", v)?;
+        }
+        if let Some(v) = self.lower_bound.as_ref() {
+            struct_ser.serialize_field("lowerBound", v)?;
+        }
+        if !self.base.is_empty() {
+            struct_ser.serialize_field("base", &self.base)?;
+        }
+        if let Some(v) = self.leverage_at_upper_bound.as_ref() {
+            struct_ser.serialize_field("leverageAtUpperBound", v)?;
+        }
+        if let Some(v) = self.leverage_at_lower_bound.as_ref() {
+            struct_ser.serialize_field("leverageAtLowerBound", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for submit_amm::ConcentratedLiquidityParameters {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "upper_bound",
+            "upperBound",
+            "lower_bound",
+            "lowerBound",
+            "base",
+            "leverage_at_upper_bound",
+            "leverageAtUpperBound",
+            "leverage_at_lower_bound",
+            "leverageAtLowerBound",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            UpperBound,
+            LowerBound,
+            Base,
+            LeverageAtUpperBound,
+            LeverageAtLowerBound,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "upperBound" | "upper_bound" => Ok(GeneratedField::UpperBound),
+                            "lowerBound" | "lower_bound" => Ok(GeneratedField::LowerBound),
+                            "base" => Ok(GeneratedField::Base),
+                            "leverageAtUpperBound" | "leverage_at_upper_bound" => Ok(GeneratedField::LeverageAtUpperBound),
+                            "leverageAtLowerBound" | "leverage_at_lower_bound" => Ok(GeneratedField::LeverageAtLowerBound),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = submit_amm::ConcentratedLiquidityParameters;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.commands.v1.SubmitAMM.ConcentratedLiquidityParameters")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<submit_amm::ConcentratedLiquidityParameters, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut upper_bound__ = None;
+                let mut lower_bound__ = None;
+                let mut base__ = None;
+                let mut leverage_at_upper_bound__ = None;
+                let mut leverage_at_lower_bound__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::UpperBound => {
+                            if upper_bound__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("upperBound"));
+                            }
+                            upper_bound__ = map_.next_value()?;
+                        }
+                        GeneratedField::LowerBound => {
+                            if lower_bound__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lowerBound"));
+                            }
+                            lower_bound__ = map_.next_value()?;
+                        }
+                        GeneratedField::Base => {
+                            if base__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("base"));
+                            }
+                            base__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::LeverageAtUpperBound => {
+                            if leverage_at_upper_bound__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("leverageAtUpperBound"));
+                            }
+                            leverage_at_upper_bound__ = map_.next_value()?;
+                        }
+                        GeneratedField::LeverageAtLowerBound => {
+                            if leverage_at_lower_bound__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("leverageAtLowerBound"));
+                            }
+                            leverage_at_lower_bound__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(submit_amm::ConcentratedLiquidityParameters {
+                    upper_bound: upper_bound__,
+                    lower_bound: lower_bound__,
+                    base: base__.unwrap_or_default(),
+                    leverage_at_upper_bound: leverage_at_upper_bound__,
+                    leverage_at_lower_bound: leverage_at_lower_bound__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.commands.v1.SubmitAMM.ConcentratedLiquidityParameters", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Transaction {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.input_data.is_empty() {
+            len += 1;
+        }
+        if self.signature.is_some() {
+            len += 1;
+        }
+        if self.version != 0 {
+            len += 1;
+        }
+        if self.pow.is_some() {
+            len += 1;
+        }
+        if self.from.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.commands.v1.Transaction", len)?;
+        if !self.input_data.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("inputData", pbjson::private::base64::encode(&self.input_data).as_str())?;
+        }
+        if let Some(v) = self.signature.as_ref() {
+            struct_ser.serialize_field("signature", v)?;
+        }
+        if self.version != 0 {
+            let v = TxVersion::try_from(self.version)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.version)))?;
+            struct_ser.serialize_field("version", &v)?;
+        }
+        if let Some(v) = self.pow.as_ref() {
+            struct_ser.serialize_field("pow", v)?;
+        }
+        if let Some(v) = self.from.as_ref() {
+            match v {
+                transaction::From::Address(v) => {
+                    struct_ser.serialize_field("address", v)?;
+                }
+                transaction::From::PubKey(v) => {
+                    struct_ser.serialize_field("pubKey", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Transaction {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "input_data",
+            "inputData",
+            "signature",
+            "version",
+            "pow",
+            "address",
+            "pub_key",
+            "pubKey",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            InputData,
+            Signature,
+            Version,
+            Pow,
+            Address,
+            PubKey,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "inputData" | "input_data" => Ok(GeneratedField::InputData),
+                            "signature" => Ok(GeneratedField::Signature),
+                            "version" => Ok(GeneratedField::Version),
+                            "pow" => Ok(GeneratedField::Pow),
+                            "address" => Ok(GeneratedField::Address),
+                            "pubKey" | "pub_key" => Ok(GeneratedField::PubKey),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Transaction;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.commands.v1.Transaction")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Transaction, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut input_data__ = None;
+                let mut signature__ = None;
+                let mut version__ = None;
+                let mut pow__ = None;
+                let mut from__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::InputData => {
+                            if input_data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("inputData"));
+                            }
+                            input_data__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Signature => {
+                            if signature__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("signature"));
+                            }
+                            signature__ = map_.next_value()?;
+                        }
+                        GeneratedField::Version => {
+                            if version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("version"));
+                            }
+                            version__ = Some(map_.next_value::<TxVersion>()? as i32);
+                        }
+                        GeneratedField::Pow => {
+                            if pow__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pow"));
+                            }
+                            pow__ = map_.next_value()?;
+                        }
+                        GeneratedField::Address => {
+                            if from__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("address"));
+                            }
+                            from__ = map_.next_value::<::std::option::Option<_>>()?.map(transaction::From::Address);
+                        }
+                        GeneratedField::PubKey => {
+                            if from__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pubKey"));
+                            }
+                            from__ = map_.next_value::<::std::option::Option<_>>()?.map(transaction::From::PubKey);
+                        }
+                    }
+                }
+                Ok(Transaction {
+                    input_data: input_data__.unwrap_or_default(),
+                    signature: signature__,
+                    version: version__.unwrap_or_default(),
+                    pow: pow__,
+                    from: from__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.commands.v1.Transaction", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Transfer {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.from_account_type != 0 {
+            len += 1;
+        }
+        if !self.to.is_empty() {
+            len += 1;
+        }
+        if self.to_account_type != 0 {
+            len += 1;
+        }
+        if !self.asset.is_empty() {
+            len += 1;
+        }
+        if !self.amount.is_empty() {
+            len += 1;
+        }
+        if !self.reference.is_empty() {
+            len += 1;
+        }
+        if self.from.is_some() {
+            len += 1;
+        }
+        if self.kind.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.commands.v1.Transfer", len)?;
+        if self.from_account_type != 0 {
+            let v = super::super::AccountType::try_from(self.from_account_type)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.from_account_type)))?;
+            struct_ser.serialize_field("fromAccountType", &v)?;
+        }
+        if !self.to.is_empty() {
+            struct_ser.serialize_field("to", &self.to)?;
+        }
+        if self.to_account_type != 0 {
+            let v = super::super::AccountType::try_from(self.to_account_type)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.to_account_type)))?;
+            struct_ser.serialize_field("toAccountType", &v)?;
+        }
+        if !self.asset.is_empty() {
+            struct_ser.serialize_field("asset", &self.asset)?;
+        }
+        if !self.amount.is_empty() {
+            struct_ser.serialize_field("amount", &self.amount)?;
+        }
+        if !self.reference.is_empty() {
+            struct_ser.serialize_field("reference", &self.reference)?;
+        }
+        if let Some(v) = self.from.as_ref() {
+            struct_ser.serialize_field("from", v)?;
+        }
+        if let Some(v) = self.kind.as_ref() {
+            match v {
+                transfer::Kind::OneOff(v) => {
+                    struct_ser.serialize_field("oneOff", v)?;
+                }
+                transfer::Kind::Recurring(v) => {
+                    struct_ser.serialize_field("recurring", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Transfer {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "from_account_type",
+            "fromAccountType",
+            "to",
+            "to_account_type",
+            "toAccountType",
+            "asset",
+            "amount",
+            "reference",
+            "from",
+            "one_off",
+            "oneOff",
+            "recurring",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            FromAccountType,
+            To,
+            ToAccountType,
+            Asset,
+            Amount,
+            Reference,
+            From,
+            OneOff,
+            Recurring,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "fromAccountType" | "from_account_type" => Ok(GeneratedField::FromAccountType),
+                            "to" => Ok(GeneratedField::To),
+                            "toAccountType" | "to_account_type" => Ok(GeneratedField::ToAccountType),
+                            "asset" => Ok(GeneratedField::Asset),
+                            "amount" => Ok(GeneratedField::Amount),
+                            "reference" => Ok(GeneratedField::Reference),
+                            "from" => Ok(GeneratedField::From),
+                            "oneOff" | "one_off" => Ok(GeneratedField::OneOff),
+                            "recurring" => Ok(GeneratedField::Recurring),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Transfer;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.commands.v1.Transfer")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Transfer, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut from_account_type__ = None;
+                let mut to__ = None;
+                let mut to_account_type__ = None;
+                let mut asset__ = None;
+                let mut amount__ = None;
+                let mut reference__ = None;
+                let mut from__ = None;
+                let mut kind__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::FromAccountType => {
+                            if from_account_type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fromAccountType"));
+                            }
+                            from_account_type__ = Some(map_.next_value::<super::super::AccountType>()? as i32);
+                        }
+                        GeneratedField::To => {
+                            if to__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("to"));
+                            }
+                            to__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ToAccountType => {
+                            if to_account_type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("toAccountType"));
+                            }
+                            to_account_type__ = Some(map_.next_value::<super::super::AccountType>()? as i32);
+                        }
+                        GeneratedField::Asset => {
+                            if asset__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("asset"));
+                            }
+                            asset__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Amount => {
+                            if amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amount"));
+                            }
+                            amount__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Reference => {
+                            if reference__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reference"));
+                            }
+                            reference__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::From => {
+                            if from__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("from"));
+                            }
+                            from__ = map_.next_value()?;
+                        }
+                        GeneratedField::OneOff => {
+                            if kind__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("oneOff"));
+                            }
+                            kind__ = map_.next_value::<::std::option::Option<_>>()?.map(transfer::Kind::OneOff)
+;
+                        }
+                        GeneratedField::Recurring => {
+                            if kind__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("recurring"));
+                            }
+                            kind__ = map_.next_value::<::std::option::Option<_>>()?.map(transfer::Kind::Recurring)
+;
+                        }
+                    }
+                }
+                Ok(Transfer {
+                    from_account_type: from_account_type__.unwrap_or_default(),
+                    to: to__.unwrap_or_default(),
+                    to_account_type: to_account_type__.unwrap_or_default(),
+                    asset: asset__.unwrap_or_default(),
+                    amount: amount__.unwrap_or_default(),
+                    reference: reference__.unwrap_or_default(),
+                    from: from__,
+                    kind: kind__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.commands.v1.Transfer", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TxVersion {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "TX_VERSION_UNSPECIFIED",
+            Self::V2 => "TX_VERSION_V2",
+            Self::V3 => "TX_VERSION_V3",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for TxVersion {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "TX_VERSION_UNSPECIFIED",
+            "TX_VERSION_V2",
+            "TX_VERSION_V3",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TxVersion;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "TX_VERSION_UNSPECIFIED" => Ok(TxVersion::Unspecified),
+                    "TX_VERSION_V2" => Ok(TxVersion::V2),
+                    "TX_VERSION_V3" => Ok(TxVersion::V3),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for UndelegateSubmission {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.node_id.is_empty() {
+            len += 1;
+        }
+        if !self.amount.is_empty() {
+            len += 1;
+        }
+        if self.method != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.commands.v1.UndelegateSubmission", len)?;
+        if !self.node_id.is_empty() {
+            struct_ser.serialize_field("nodeId", &self.node_id)?;
+        }
+        if !self.amount.is_empty() {
+            struct_ser.serialize_field("amount", &self.amount)?;
+        }
+        if self.method != 0 {
+            let v = undelegate_submission::Method::try_from(self.method)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.method)))?;
+            struct_ser.serialize_field("method", &v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for UndelegateSubmission {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "node_id",
+            "nodeId",
+            "amount",
+            "method",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            NodeId,
+            Amount,
+            Method,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "nodeId" | "node_id" => Ok(GeneratedField::NodeId),
+                            "amount" => Ok(GeneratedField::Amount),
+                            "method" => Ok(GeneratedField::Method),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = UndelegateSubmission;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.commands.v1.UndelegateSubmission")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<UndelegateSubmission, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut node_id__ = None;
+                let mut amount__ = None;
+                let mut method__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::NodeId => {
+                            if node_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nodeId"));
+                            }
+                            node_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Amount => {
+                            if amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amount"));
+                            }
+                            amount__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Method => {
+                            if method__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("method"));
+                            }
+                            method__ = Some(map_.next_value::<undelegate_submission::Method>()? as i32);
+                        }
+                    }
+                }
+                Ok(UndelegateSubmission {
+                    node_id: node_id__.unwrap_or_default(),
+                    amount: amount__.unwrap_or_default(),
+                    method: method__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.commands.v1.UndelegateSubmission", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for undelegate_submission::Method {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "METHOD_UNSPECIFIED",
+            Self::Now => "METHOD_NOW",
+            Self::AtEndOfEpoch => "METHOD_AT_END_OF_EPOCH",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for undelegate_submission::Method {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "METHOD_UNSPECIFIED",
+            "METHOD_NOW",
+            "METHOD_AT_END_OF_EPOCH",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = undelegate_submission::Method;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "METHOD_UNSPECIFIED" => Ok(undelegate_submission::Method::Unspecified),
+                    "METHOD_NOW" => Ok(undelegate_submission::Method::Now),
+                    "METHOD_AT_END_OF_EPOCH" => Ok(undelegate_submission::Method::AtEndOfEpoch),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for UpdateMarginMode {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.market_id.is_empty() {
+            len += 1;
+        }
+        if self.mode != 0 {
+            len += 1;
+        }
+        if self.margin_factor.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.commands.v1.UpdateMarginMode", len)?;
+        if !self.market_id.is_empty() {
+            struct_ser.serialize_field("marketId", &self.market_id)?;
+        }
+        if self.mode != 0 {
+            let v = update_margin_mode::Mode::try_from(self.mode)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.mode)))?;
+            struct_ser.serialize_field("mode", &v)?;
+        }
+        if let Some(v) = self.margin_factor.as_ref() {
+            struct_ser.serialize_field("marginFactor", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for UpdateMarginMode {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "market_id",
+            "marketId",
+            "mode",
+            "margin_factor",
+            "marginFactor",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            MarketId,
+            Mode,
+            MarginFactor,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "marketId" | "market_id" => Ok(GeneratedField::MarketId),
+                            "mode" => Ok(GeneratedField::Mode),
+                            "marginFactor" | "margin_factor" => Ok(GeneratedField::MarginFactor),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = UpdateMarginMode;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.commands.v1.UpdateMarginMode")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<UpdateMarginMode, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut market_id__ = None;
+                let mut mode__ = None;
+                let mut margin_factor__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::MarketId => {
+                            if market_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("marketId"));
+                            }
+                            market_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Mode => {
+                            if mode__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("mode"));
+                            }
+                            mode__ = Some(map_.next_value::<update_margin_mode::Mode>()? as i32);
+                        }
+                        GeneratedField::MarginFactor => {
+                            if margin_factor__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("marginFactor"));
+                            }
+                            margin_factor__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(UpdateMarginMode {
+                    market_id: market_id__.unwrap_or_default(),
+                    mode: mode__.unwrap_or_default(),
+                    margin_factor: margin_factor__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.commands.v1.UpdateMarginMode", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for update_margin_mode::Mode {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "MODE_UNSPECIFIED",
+            Self::CrossMargin => "MODE_CROSS_MARGIN",
+            Self::IsolatedMargin => "MODE_ISOLATED_MARGIN",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for update_margin_mode::Mode {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "MODE_UNSPECIFIED",
+            "MODE_CROSS_MARGIN",
+            "MODE_ISOLATED_MARGIN",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = update_margin_mode::Mode;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "MODE_UNSPECIFIED" => Ok(update_margin_mode::Mode::Unspecified),
+                    "MODE_CROSS_MARGIN" => Ok(update_margin_mode::Mode::CrossMargin),
+                    "MODE_ISOLATED_MARGIN" => Ok(update_margin_mode::Mode::IsolatedMargin),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for UpdatePartyProfile {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.alias.is_empty() {
+            len += 1;
+        }
+        if !self.metadata.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.commands.v1.UpdatePartyProfile", len)?;
+        if !self.alias.is_empty() {
+            struct_ser.serialize_field("alias", &self.alias)?;
+        }
+        if !self.metadata.is_empty() {
+            struct_ser.serialize_field("metadata", &self.metadata)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for UpdatePartyProfile {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "alias",
+            "metadata",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Alias,
+            Metadata,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "alias" => Ok(GeneratedField::Alias),
+                            "metadata" => Ok(GeneratedField::Metadata),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = UpdatePartyProfile;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.commands.v1.UpdatePartyProfile")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<UpdatePartyProfile, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut alias__ = None;
+                let mut metadata__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Alias => {
+                            if alias__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("alias"));
+                            }
+                            alias__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Metadata => {
+                            if metadata__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("metadata"));
+                            }
+                            metadata__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(UpdatePartyProfile {
+                    alias: alias__.unwrap_or_default(),
+                    metadata: metadata__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.commands.v1.UpdatePartyProfile", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for UpdateReferralSet {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.id.is_empty() {
+            len += 1;
+        }
+        if self.is_team {
+            len += 1;
+        }
+        if self.team.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.commands.v1.UpdateReferralSet", len)?;
+        if !self.id.is_empty() {
+            struct_ser.serialize_field("id", &self.id)?;
+        }
+        if self.is_team {
+            struct_ser.serialize_field("isTeam", &self.is_team)?;
+        }
+        if let Some(v) = self.team.as_ref() {
+            struct_ser.serialize_field("team", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for UpdateReferralSet {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "id",
+            "is_team",
+            "isTeam",
+            "team",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Id,
+            IsTeam,
+            Team,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "id" => Ok(GeneratedField::Id),
+                            "isTeam" | "is_team" => Ok(GeneratedField::IsTeam),
+                            "team" => Ok(GeneratedField::Team),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = UpdateReferralSet;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.commands.v1.UpdateReferralSet")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<UpdateReferralSet, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut id__ = None;
+                let mut is_team__ = None;
+                let mut team__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::IsTeam => {
+                            if is_team__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("isTeam"));
+                            }
+                            is_team__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Team => {
+                            if team__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("team"));
+                            }
+                            team__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(UpdateReferralSet {
+                    id: id__.unwrap_or_default(),
+                    is_team: is_team__.unwrap_or_default(),
+                    team: team__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.commands.v1.UpdateReferralSet", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for update_referral_set::Team {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.name.is_some() {
+            len += 1;
+        }
+        if self.team_url.is_some() {
+            len += 1;
+        }
+        if self.avatar_url.is_some() {
+            len += 1;
+        }
+        if self.closed.is_some() {
+            len += 1;
+        }
+        if !self.allow_list.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.commands.v1.UpdateReferralSet.Team", len)?;
+        if let Some(v) = self.name.as_ref() {
+            struct_ser.serialize_field("name", v)?;
+        }
+        if let Some(v) = self.team_url.as_ref() {
+            struct_ser.serialize_field("teamUrl", v)?;
+        }
+        if let Some(v) = self.avatar_url.as_ref() {
+            struct_ser.serialize_field("avatarUrl", v)?;
+        }
+        if let Some(v) = self.closed.as_ref() {
+            struct_ser.serialize_field("closed", v)?;
+        }
+        if !self.allow_list.is_empty() {
+            struct_ser.serialize_field("allowList", &self.allow_list)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for update_referral_set::Team {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "name",
+            "team_url",
+            "teamUrl",
+            "avatar_url",
+            "avatarUrl",
+            "closed",
+            "allow_list",
+            "allowList",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Name,
+            TeamUrl,
+            AvatarUrl,
+            Closed,
+            AllowList,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "name" => Ok(GeneratedField::Name),
+                            "teamUrl" | "team_url" => Ok(GeneratedField::TeamUrl),
+                            "avatarUrl" | "avatar_url" => Ok(GeneratedField::AvatarUrl),
+                            "closed" => Ok(GeneratedField::Closed),
+                            "allowList" | "allow_list" => Ok(GeneratedField::AllowList),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = update_referral_set::Team;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.commands.v1.UpdateReferralSet.Team")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<update_referral_set::Team, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut name__ = None;
+                let mut team_url__ = None;
+                let mut avatar_url__ = None;
+                let mut closed__ = None;
+                let mut allow_list__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Name => {
+                            if name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("name"));
+                            }
+                            name__ = map_.next_value()?;
+                        }
+                        GeneratedField::TeamUrl => {
+                            if team_url__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("teamUrl"));
+                            }
+                            team_url__ = map_.next_value()?;
+                        }
+                        GeneratedField::AvatarUrl => {
+                            if avatar_url__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("avatarUrl"));
+                            }
+                            avatar_url__ = map_.next_value()?;
+                        }
+                        GeneratedField::Closed => {
+                            if closed__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("closed"));
+                            }
+                            closed__ = map_.next_value()?;
+                        }
+                        GeneratedField::AllowList => {
+                            if allow_list__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("allowList"));
+                            }
+                            allow_list__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(update_referral_set::Team {
+                    name: name__,
+                    team_url: team_url__,
+                    avatar_url: avatar_url__,
+                    closed: closed__,
+                    allow_list: allow_list__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.commands.v1.UpdateReferralSet.Team", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ValidatorHeartbeat {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.node_id.is_empty() {
+            len += 1;
+        }
+        if self.ethereum_signature.is_some() {
+            len += 1;
+        }
+        if self.vega_signature.is_some() {
+            len += 1;
+        }
+        if !self.message.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.commands.v1.ValidatorHeartbeat", len)?;
+        if !self.node_id.is_empty() {
+            struct_ser.serialize_field("nodeId", &self.node_id)?;
+        }
+        if let Some(v) = self.ethereum_signature.as_ref() {
+            struct_ser.serialize_field("ethereumSignature", v)?;
+        }
+        if let Some(v) = self.vega_signature.as_ref() {
+            struct_ser.serialize_field("vegaSignature", v)?;
+        }
+        if !self.message.is_empty() {
+            struct_ser.serialize_field("message", &self.message)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValidatorHeartbeat {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "node_id",
+            "nodeId",
+            "ethereum_signature",
+            "ethereumSignature",
+            "vega_signature",
+            "vegaSignature",
+            "message",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            NodeId,
+            EthereumSignature,
+            VegaSignature,
+            Message,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "nodeId" | "node_id" => Ok(GeneratedField::NodeId),
+                            "ethereumSignature" | "ethereum_signature" => Ok(GeneratedField::EthereumSignature),
+                            "vegaSignature" | "vega_signature" => Ok(GeneratedField::VegaSignature),
+                            "message" => Ok(GeneratedField::Message),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ValidatorHeartbeat;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.commands.v1.ValidatorHeartbeat")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorHeartbeat, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut node_id__ = None;
+                let mut ethereum_signature__ = None;
+                let mut vega_signature__ = None;
+                let mut message__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::NodeId => {
+                            if node_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nodeId"));
+                            }
+                            node_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::EthereumSignature => {
+                            if ethereum_signature__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ethereumSignature"));
+                            }
+                            ethereum_signature__ = map_.next_value()?;
+                        }
+                        GeneratedField::VegaSignature => {
+                            if vega_signature__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("vegaSignature"));
+                            }
+                            vega_signature__ = map_.next_value()?;
+                        }
+                        GeneratedField::Message => {
+                            if message__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("message"));
+                            }
+                            message__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(ValidatorHeartbeat {
+                    node_id: node_id__.unwrap_or_default(),
+                    ethereum_signature: ethereum_signature__,
+                    vega_signature: vega_signature__,
+                    message: message__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.commands.v1.ValidatorHeartbeat", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for VoteSubmission {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.proposal_id.is_empty() {
+            len += 1;
+        }
+        if self.value != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.commands.v1.VoteSubmission", len)?;
+        if !self.proposal_id.is_empty() {
+            struct_ser.serialize_field("proposalId", &self.proposal_id)?;
+        }
+        if self.value != 0 {
+            let v = super::super::vote::Value::try_from(self.value)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.value)))?;
+            struct_ser.serialize_field("value", &v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for VoteSubmission {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "proposal_id",
+            "proposalId",
+            "value",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ProposalId,
+            Value,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "proposalId" | "proposal_id" => Ok(GeneratedField::ProposalId),
+                            "value" => Ok(GeneratedField::Value),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = VoteSubmission;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.commands.v1.VoteSubmission")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<VoteSubmission, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut proposal_id__ = None;
+                let mut value__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ProposalId => {
+                            if proposal_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposalId"));
+                            }
+                            proposal_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Value => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("value"));
+                            }
+                            value__ = Some(map_.next_value::<super::super::vote::Value>()? as i32);
+                        }
+                    }
+                }
+                Ok(VoteSubmission {
+                    proposal_id: proposal_id__.unwrap_or_default(),
+                    value: value__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.commands.v1.VoteSubmission", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for WithdrawSubmission {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.amount.is_empty() {
+            len += 1;
+        }
+        if !self.asset.is_empty() {
+            len += 1;
+        }
+        if self.ext.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.commands.v1.WithdrawSubmission", len)?;
+        if !self.amount.is_empty() {
+            struct_ser.serialize_field("amount", &self.amount)?;
+        }
+        if !self.asset.is_empty() {
+            struct_ser.serialize_field("asset", &self.asset)?;
+        }
+        if let Some(v) = self.ext.as_ref() {
+            struct_ser.serialize_field("ext", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WithdrawSubmission {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "amount",
+            "asset",
+            "ext",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Amount,
+            Asset,
+            Ext,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "amount" => Ok(GeneratedField::Amount),
+                            "asset" => Ok(GeneratedField::Asset),
+                            "ext" => Ok(GeneratedField::Ext),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WithdrawSubmission;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.commands.v1.WithdrawSubmission")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WithdrawSubmission, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut amount__ = None;
+                let mut asset__ = None;
+                let mut ext__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Amount => {
+                            if amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amount"));
+                            }
+                            amount__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Asset => {
+                            if asset__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("asset"));
+                            }
+                            asset__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Ext => {
+                            if ext__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ext"));
+                            }
+                            ext__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(WithdrawSubmission {
+                    amount: amount__.unwrap_or_default(),
+                    asset: asset__.unwrap_or_default(),
+                    ext: ext__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.commands.v1.WithdrawSubmission", FIELDS, GeneratedVisitor)
+    }
+}
@@ -0,0 +1,252 @@
+// This file is @generated by prost-build.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EthAddress {
+    #[prost(string, tag = "1")]
+    pub address: ::prost::alloc::string::String,
+}
+/// PubKey is the public key that signed this data.
+/// Different public keys coming from different sources will be further separated.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PubKey {
+    #[prost(string, tag = "1")]
+    pub key: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Signer {
+    #[prost(oneof = "signer::Signer", tags = "1, 2")]
+    pub signer: ::core::option::Option<signer::Signer>,
+}
+/// Nested message and enum types in `Signer`.
+pub mod signer {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Signer {
+        /// List of authorized public keys that signed the data for this
+        /// source. All the public keys in the data should be contained in these
+        /// public keys.
+        #[prost(message, tag = "1")]
+        PubKey(super::PubKey),
+        /// In case of an open oracle - Ethereum address will be submitted.
+        #[prost(message, tag = "2")]
+        EthAddress(super::EthAddress),
+    }
+}
+/// Property describes one property of data spec with a key with its value.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Property {
+    /// Name of the property.
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// Value of the property.
+    #[prost(string, tag = "2")]
+    pub value: ::prost::alloc::string::String,
+}
+/// Data describes valid source data that has been received by the node.
+/// It represents both matched and unmatched data.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Data {
+    #[prost(message, repeated, tag = "1")]
+    pub signers: ::prost::alloc::vec::Vec<Signer>,
+    /// Data holds all the properties of the data
+    #[prost(message, repeated, tag = "2")]
+    pub data: ::prost::alloc::vec::Vec<Property>,
+    /// `matched_specs_ids` lists all the specs that matched this data.
+    /// When the array is empty, it means no spec matched this data.
+    #[prost(string, repeated, tag = "3")]
+    pub matched_spec_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Timestamp in Unix nanoseconds for when the data was broadcast to the markets
+    /// with a matching spec. It has no value when the data did not match any spec.
+    #[prost(int64, tag = "4")]
+    pub broadcast_at: i64,
+    /// Holds all metadata properties
+    #[prost(message, repeated, tag = "5")]
+    pub meta_data: ::prost::alloc::vec::Vec<Property>,
+    /// Error message if the data could not be sourced.
+    #[prost(string, optional, tag = "6")]
+    pub error: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExternalData {
+    #[prost(message, optional, tag = "1")]
+    pub data: ::core::option::Option<Data>,
+}
+/// Filter describes the conditions under which a data source data is considered of
+/// interest or not.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Filter {
+    /// Data source's data property key targeted by the filter.
+    #[prost(message, optional, tag = "1")]
+    pub key: ::core::option::Option<PropertyKey>,
+    /// Conditions that should be matched by the data to be
+    /// considered of interest.
+    #[prost(message, repeated, tag = "2")]
+    pub conditions: ::prost::alloc::vec::Vec<Condition>,
+}
+/// PropertyKey describes the property key contained in data source data.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PropertyKey {
+    /// Name of the property.
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// Data type of the property.
+    #[prost(enumeration = "property_key::Type", tag = "2")]
+    pub r#type: i32,
+    /// Optional decimal place to be be applied on the provided value
+    /// valid only for PropertyType of type DECIMAL and INTEGER
+    #[prost(uint64, optional, tag = "3")]
+    pub number_decimal_places: ::core::option::Option<u64>,
+}
+/// Nested message and enum types in `PropertyKey`.
+pub mod property_key {
+    /// Type describes the data type of properties that are supported by the data source
+    /// engine.
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Type {
+        /// The default value.
+        Unspecified = 0,
+        /// Any type.
+        Empty = 1,
+        /// Integer type.
+        Integer = 2,
+        /// String type.
+        String = 3,
+        /// Boolean type.
+        Boolean = 4,
+        /// Any floating point decimal type.
+        Decimal = 5,
+        /// Timestamp date type.
+        Timestamp = 6,
+    }
+    impl Type {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Type::Unspecified => "TYPE_UNSPECIFIED",
+                Type::Empty => "TYPE_EMPTY",
+                Type::Integer => "TYPE_INTEGER",
+                Type::String => "TYPE_STRING",
+                Type::Boolean => "TYPE_BOOLEAN",
+                Type::Decimal => "TYPE_DECIMAL",
+                Type::Timestamp => "TYPE_TIMESTAMP",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "TYPE_UNSPECIFIED" => Some(Self::Unspecified),
+                "TYPE_EMPTY" => Some(Self::Empty),
+                "TYPE_INTEGER" => Some(Self::Integer),
+                "TYPE_STRING" => Some(Self::String),
+                "TYPE_BOOLEAN" => Some(Self::Boolean),
+                "TYPE_DECIMAL" => Some(Self::Decimal),
+                "TYPE_TIMESTAMP" => Some(Self::Timestamp),
+                _ => None,
+            }
+        }
+    }
+}
+/// Condition describes the condition that must be validated by the network
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Condition {
+    /// Type of comparison to make on the value.
+    #[prost(enumeration = "condition::Operator", tag = "1")]
+    pub operator: i32,
+    /// Value to be compared with by the operator.
+    #[prost(string, tag = "2")]
+    pub value: ::prost::alloc::string::String,
+}
+/// Nested message and enum types in `Condition`.
+pub mod condition {
+    /// Operator describes the type of comparison.
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Operator {
+        /// The default value
+        Unspecified = 0,
+        /// Verify if the property values are strictly equal or not.
+        Equals = 1,
+        /// Verify if the data source data value is greater than the Condition value.
+        GreaterThan = 2,
+        /// Verify if the data source data value is greater than or equal to the Condition
+        /// value.
+        GreaterThanOrEqual = 3,
+        /// Verify if the data source data value is less than the Condition value.
+        LessThan = 4,
+        /// Verify if the data source data value is less or equal to than the Condition
+        /// value.
+        LessThanOrEqual = 5,
+    }
+    impl Operator {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Operator::Unspecified => "OPERATOR_UNSPECIFIED",
+                Operator::Equals => "OPERATOR_EQUALS",
+                Operator::GreaterThan => "OPERATOR_GREATER_THAN",
+                Operator::GreaterThanOrEqual => "OPERATOR_GREATER_THAN_OR_EQUAL",
+                Operator::LessThan => "OPERATOR_LESS_THAN",
+                Operator::LessThanOrEqual => "OPERATOR_LESS_THAN_OR_EQUAL",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "OPERATOR_UNSPECIFIED" => Some(Self::Unspecified),
+                "OPERATOR_EQUALS" => Some(Self::Equals),
+                "OPERATOR_GREATER_THAN" => Some(Self::GreaterThan),
+                "OPERATOR_GREATER_THAN_OR_EQUAL" => Some(Self::GreaterThanOrEqual),
+                "OPERATOR_LESS_THAN" => Some(Self::LessThan),
+                "OPERATOR_LESS_THAN_OR_EQUAL" => Some(Self::LessThanOrEqual),
+                _ => None,
+            }
+        }
+    }
+}
+/// Trigger for an internal time data source.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InternalTimeTrigger {
+    /// Trigger when the vega time is greater or equal to this time, in Unix seconds.
+    #[prost(int64, optional, tag = "1")]
+    pub initial: ::core::option::Option<i64>,
+    /// Repeat the trigger every n seconds after the initial. If no time for
+    /// initial was specified, begin repeating immediately.
+    #[prost(int64, tag = "2")]
+    pub every: i64,
+}
@@ -0,0 +1,3154 @@
+// This file is @generated by prost-build.
+/// Time weighted notional position update for the current epoch.
+/// The time weighted notional position is used to determine whether
+/// a party is eligible for receiving rewards at the end of an epoch.
+/// These updates are provided to facilitate knowing whether or not a party
+/// is on track to qualify.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TimeWeightedNotionalPositionUpdated {
+    /// Epoch the time weighted notional position is relevant for
+    #[prost(uint64, tag = "1")]
+    pub epoch_seq: u64,
+    /// Asset ID for the position's settlement asset.
+    #[prost(string, tag = "2")]
+    pub asset: ::prost::alloc::string::String,
+    /// Party holding the position
+    #[prost(string, tag = "3")]
+    pub party: ::prost::alloc::string::String,
+    /// Game ID the time weighted notional position is calculated for.
+    #[prost(string, tag = "4")]
+    pub game_id: ::prost::alloc::string::String,
+    /// Time weighted notional position from the end of the most recently completed epoch.
+    #[prost(string, tag = "5")]
+    pub time_weighted_notional_position: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Amm {
+    /// ID of the AMM.
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Party ID of the owner of the AMM.
+    #[prost(string, tag = "2")]
+    pub party_id: ::prost::alloc::string::String,
+    /// Market ID that the AMM provides liquidity for.
+    #[prost(string, tag = "3")]
+    pub market_id: ::prost::alloc::string::String,
+    /// Party ID that the AMM operates as.
+    #[prost(string, tag = "4")]
+    pub amm_party_id: ::prost::alloc::string::String,
+    /// Amount committed to the AMM.
+    #[prost(string, tag = "5")]
+    pub commitment: ::prost::alloc::string::String,
+    /// Liquidity parameters that define the size and range of the AMM's tradeable volume.
+    #[prost(message, optional, tag = "6")]
+    pub parameters: ::core::option::Option<amm::ConcentratedLiquidityParameters>,
+    /// Current status of the AMM.
+    #[prost(enumeration = "amm::Status", tag = "7")]
+    pub status: i32,
+    /// Reason for the AMM's current status.
+    #[prost(enumeration = "amm::StatusReason", tag = "8")]
+    pub status_reason: i32,
+    /// Nominated liquidity fee factor, which is an input to the calculation of taker fees on the market.
+    #[prost(string, tag = "9")]
+    pub proposed_fee: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "10")]
+    pub lower_curve: ::core::option::Option<amm::Curve>,
+    #[prost(message, optional, tag = "11")]
+    pub upper_curve: ::core::option::Option<amm::Curve>,
+}
+/// Nested message and enum types in `AMM`.
+pub mod amm {
+    /// Liquidity parameters that define the range and shape of the AMM's curve.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ConcentratedLiquidityParameters {
+        /// Base price bound configuration for the AMM.
+        #[prost(string, tag = "1")]
+        pub base: ::prost::alloc::string::String,
+        /// Upper price bound configuration for the AMM. If unset, the AMM will never hold a short position.
+        #[prost(string, optional, tag = "2")]
+        pub lower_bound: ::core::option::Option<::prost::alloc::string::String>,
+        /// Lower price bound configuration for the AMM. If unset, the AMM will never hold a long position.
+        #[prost(string, optional, tag = "3")]
+        pub upper_bound: ::core::option::Option<::prost::alloc::string::String>,
+        /// Leverage at upper bounds.
+        #[prost(string, optional, tag = "4")]
+        pub leverage_at_upper_bound: ::core::option::Option<
+            ::prost::alloc::string::String,
+        >,
+        /// Leverage at lower bounds.
+        #[prost(string, optional, tag = "5")]
+        pub leverage_at_lower_bound: ::core::option::Option<
+            ::prost::alloc::string::String,
+        >,
+    }
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Curve {
+        /// Virtual liquidity for the given AMM curve.
+        #[prost(string, tag = "1")]
+        pub virtual_liquidity: ::prost::alloc::string::String,
+        /// Maximum tradable volume on the AMM curve.
+        #[prost(string, tag = "2")]
+        pub theoretical_position: ::prost::alloc::string::String,
+    }
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Status {
+        Unspecified = 0,
+        /// AMM is active on the market and is posting tradable volume.
+        Active = 1,
+        /// AMM submission was rejected.
+        Rejected = 2,
+        /// AMM has been cancelled by the owner and is no longer trading.
+        Cancelled = 3,
+        /// AMM has been stopped by the network and is no longer trading.
+        Stopped = 4,
+        /// AMM will only trade such that it will reduce its position.
+        ReduceOnly = 5,
+    }
+    impl Status {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Status::Unspecified => "STATUS_UNSPECIFIED",
+                Status::Active => "STATUS_ACTIVE",
+                Status::Rejected => "STATUS_REJECTED",
+                Status::Cancelled => "STATUS_CANCELLED",
+                Status::Stopped => "STATUS_STOPPED",
+                Status::ReduceOnly => "STATUS_REDUCE_ONLY",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "STATUS_UNSPECIFIED" => Some(Self::Unspecified),
+                "STATUS_ACTIVE" => Some(Self::Active),
+                "STATUS_REJECTED" => Some(Self::Rejected),
+                "STATUS_CANCELLED" => Some(Self::Cancelled),
+                "STATUS_STOPPED" => Some(Self::Stopped),
+                "STATUS_REDUCE_ONLY" => Some(Self::ReduceOnly),
+                _ => None,
+            }
+        }
+    }
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum StatusReason {
+        Unspecified = 0,
+        /// AMM was cancelled by its owner.
+        CancelledByParty = 1,
+        /// Party does not have enough funds in their general account to meet the AMM's commitment.
+        CannotFillCommitment = 2,
+        /// Party already has an AMM operating on this market and cannot create another one.
+        PartyAlreadyOwnsAmmForMarket = 3,
+        /// AMM was liquidated and stopped by the network.
+        PartyClosedOut = 4,
+        /// AMM was stopped by the network because the market it operated in was closed.
+        MarketClosed = 5,
+        /// Commitment amount was below the network wide minimum, or its price bounds are too wide, such that the volume is spread thinly creating zero-volume price-levels.
+        CommitmentTooLow = 6,
+        /// AMM was unable to rebase its fair-price such that it does not cross with existing orders.
+        CannotRebase = 7,
+    }
+    impl StatusReason {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                StatusReason::Unspecified => "STATUS_REASON_UNSPECIFIED",
+                StatusReason::CancelledByParty => "STATUS_REASON_CANCELLED_BY_PARTY",
+                StatusReason::CannotFillCommitment => {
+                    "STATUS_REASON_CANNOT_FILL_COMMITMENT"
+                }
+                StatusReason::PartyAlreadyOwnsAmmForMarket => {
+                    "STATUS_REASON_PARTY_ALREADY_OWNS_AMM_FOR_MARKET"
+                }
+                StatusReason::PartyClosedOut => "STATUS_REASON_PARTY_CLOSED_OUT",
+                StatusReason::MarketClosed => "STATUS_REASON_MARKET_CLOSED",
+                StatusReason::CommitmentTooLow => "STATUS_REASON_COMMITMENT_TOO_LOW",
+                StatusReason::CannotRebase => "STATUS_REASON_CANNOT_REBASE",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "STATUS_REASON_UNSPECIFIED" => Some(Self::Unspecified),
+                "STATUS_REASON_CANCELLED_BY_PARTY" => Some(Self::CancelledByParty),
+                "STATUS_REASON_CANNOT_FILL_COMMITMENT" => {
+                    Some(Self::CannotFillCommitment)
+                }
+                "STATUS_REASON_PARTY_ALREADY_OWNS_AMM_FOR_MARKET" => {
+                    Some(Self::PartyAlreadyOwnsAmmForMarket)
+                }
+                "STATUS_REASON_PARTY_CLOSED_OUT" => Some(Self::PartyClosedOut),
+                "STATUS_REASON_MARKET_CLOSED" => Some(Self::MarketClosed),
+                "STATUS_REASON_COMMITMENT_TOO_LOW" => Some(Self::CommitmentTooLow),
+                "STATUS_REASON_CANNOT_REBASE" => Some(Self::CannotRebase),
+                _ => None,
+            }
+        }
+    }
+}
+/// Summary of the vesting and locked balances for an epoch
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VestingBalancesSummary {
+    /// Epoch for which these balances are valid.
+    #[prost(uint64, tag = "1")]
+    pub epoch_seq: u64,
+    /// Parties' summaries.
+    #[prost(message, repeated, tag = "2")]
+    pub parties_vesting_summary: ::prost::alloc::vec::Vec<PartyVestingSummary>,
+}
+/// Summary of a party's vesting balances
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PartyVestingSummary {
+    /// Party ID.
+    #[prost(string, tag = "1")]
+    pub party: ::prost::alloc::string::String,
+    /// List of locked balances.
+    #[prost(message, repeated, tag = "2")]
+    pub party_locked_balances: ::prost::alloc::vec::Vec<PartyLockedBalance>,
+    /// List of vesting balances.
+    #[prost(message, repeated, tag = "3")]
+    pub party_vesting_balances: ::prost::alloc::vec::Vec<PartyVestingBalance>,
+}
+/// A party's locked balance for a given asset.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PartyLockedBalance {
+    /// Asset ID.
+    #[prost(string, tag = "1")]
+    pub asset: ::prost::alloc::string::String,
+    /// Epoch in which the balance will be released.
+    #[prost(uint64, tag = "2")]
+    pub until_epoch: u64,
+    /// Locked balance.
+    #[prost(string, tag = "3")]
+    pub balance: ::prost::alloc::string::String,
+}
+/// Balance that is being vested for the party.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PartyVestingBalance {
+    /// Asset ID.
+    #[prost(string, tag = "1")]
+    pub asset: ::prost::alloc::string::String,
+    /// Balance that is vested.
+    #[prost(string, tag = "2")]
+    pub balance: ::prost::alloc::string::String,
+}
+/// Stats of all parties eligible for volume discount.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VolumeDiscountStatsUpdated {
+    /// Epoch at which the volume discount statistics are updated.
+    #[prost(uint64, tag = "1")]
+    pub at_epoch: u64,
+    /// All parties' stats.
+    #[prost(message, repeated, tag = "2")]
+    pub stats: ::prost::alloc::vec::Vec<PartyVolumeDiscountStats>,
+}
+/// Volume discount stats for a given party.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PartyVolumeDiscountStats {
+    /// Party ID.
+    #[prost(string, tag = "1")]
+    pub party_id: ::prost::alloc::string::String,
+    /// deprecated.
+    #[prost(string, tag = "2")]
+    pub discount_factor: ::prost::alloc::string::String,
+    /// The party's running volume.
+    #[prost(string, tag = "3")]
+    pub running_volume: ::prost::alloc::string::String,
+    /// Discount factors applied to fees.
+    #[prost(message, optional, tag = "4")]
+    pub discount_factors: ::core::option::Option<super::super::DiscountFactors>,
+}
+/// Stats of all parties invested in the vesting program.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VestingStatsUpdated {
+    /// Epoch at which the vesting statistics are updated.
+    #[prost(uint64, tag = "1")]
+    pub at_epoch: u64,
+    /// All parties stats.
+    #[prost(message, repeated, tag = "2")]
+    pub stats: ::prost::alloc::vec::Vec<PartyVestingStats>,
+}
+/// The vesting stats for a given party.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PartyVestingStats {
+    /// The party.
+    #[prost(string, tag = "1")]
+    pub party_id: ::prost::alloc::string::String,
+    /// The bonus multiplier applied on the reward.
+    #[prost(string, tag = "2")]
+    pub reward_bonus_multiplier: ::prost::alloc::string::String,
+    /// The balance of the party, in quantum.
+    #[prost(string, tag = "3")]
+    pub quantum_balance: ::prost::alloc::string::String,
+    /// Bonus multiplier applied on the reward, summed across all derived accounts.
+    #[prost(string, tag = "4")]
+    pub summed_reward_bonus_multiplier: ::prost::alloc::string::String,
+    /// The balance of the party and derived keys, in quantum.
+    #[prost(string, tag = "5")]
+    pub summed_quantum_balance: ::prost::alloc::string::String,
+}
+/// Fees rewards and discounts paid / received per parties during an epoch
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FeesStats {
+    /// Market the fees were paid in
+    #[prost(string, tag = "1")]
+    pub market: ::prost::alloc::string::String,
+    /// Settlement asset of the market.
+    #[prost(string, tag = "2")]
+    pub asset: ::prost::alloc::string::String,
+    /// Epoch for which these stats where valid.
+    #[prost(uint64, tag = "3")]
+    pub epoch_seq: u64,
+    /// Total referral rewards received by the referrer of the referral set.
+    #[prost(message, repeated, tag = "4")]
+    pub total_rewards_received: ::prost::alloc::vec::Vec<PartyAmount>,
+    /// Referral rewards generated by all referee taker fees.
+    #[prost(message, repeated, tag = "5")]
+    pub referrer_rewards_generated: ::prost::alloc::vec::Vec<ReferrerRewardsGenerated>,
+    /// Total referral discounts applied to all referee taker fees.
+    #[prost(message, repeated, tag = "6")]
+    pub referees_discount_applied: ::prost::alloc::vec::Vec<PartyAmount>,
+    /// Total volume discounts applied to all referee taker fees.
+    #[prost(message, repeated, tag = "7")]
+    pub volume_discount_applied: ::prost::alloc::vec::Vec<PartyAmount>,
+    /// Total maker fees received by the maker side.
+    #[prost(message, repeated, tag = "8")]
+    pub total_maker_fees_received: ::prost::alloc::vec::Vec<PartyAmount>,
+    /// Maker fees paid by all trade aggressors, and which makers the fees were paid to.
+    #[prost(message, repeated, tag = "9")]
+    pub maker_fees_generated: ::prost::alloc::vec::Vec<MakerFeesGenerated>,
+    /// Total trading fees received and paid by the party.
+    #[prost(message, repeated, tag = "11")]
+    pub total_fees_paid_and_received: ::prost::alloc::vec::Vec<PartyAmount>,
+}
+/// Rewards generated for referrers by each of their referees
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReferrerRewardsGenerated {
+    /// Referrer party.
+    #[prost(string, tag = "1")]
+    pub referrer: ::prost::alloc::string::String,
+    /// Amount of rewards generated per party.
+    #[prost(message, repeated, tag = "2")]
+    pub generated_reward: ::prost::alloc::vec::Vec<PartyAmount>,
+}
+/// Maker fees generated by the trade aggressor
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MakerFeesGenerated {
+    /// Party that paid the fees.
+    #[prost(string, tag = "1")]
+    pub taker: ::prost::alloc::string::String,
+    /// Amount of maker fees paid by the taker to the maker.
+    #[prost(message, repeated, tag = "2")]
+    pub maker_fees_paid: ::prost::alloc::vec::Vec<PartyAmount>,
+}
+/// A pair of a party and amount
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PartyAmount {
+    /// Receiving party ID.
+    #[prost(string, tag = "1")]
+    pub party: ::prost::alloc::string::String,
+    /// Amount received.
+    #[prost(string, tag = "2")]
+    pub amount: ::prost::alloc::string::String,
+    /// Amount value in quantum.
+    #[prost(string, tag = "3")]
+    pub quantum_amount: ::prost::alloc::string::String,
+}
+/// The updated activity streak of a party at end of epoch
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PartyActivityStreak {
+    /// The party.
+    #[prost(string, tag = "1")]
+    pub party: ::prost::alloc::string::String,
+    /// How many epoch this party has been active for.
+    #[prost(uint64, tag = "2")]
+    pub active_for: u64,
+    /// how many epoch this party has been inactive for.
+    #[prost(uint64, tag = "3")]
+    pub inactive_for: u64,
+    /// Is this party considered active or not.
+    #[prost(bool, tag = "4")]
+    pub is_active: bool,
+    /// The current rewards distribution multiplier for this party.
+    #[prost(string, tag = "5")]
+    pub reward_distribution_activity_multiplier: ::prost::alloc::string::String,
+    /// The vesting multiplier for this party.
+    #[prost(string, tag = "6")]
+    pub reward_vesting_activity_multiplier: ::prost::alloc::string::String,
+    /// The epoch this information is relevant to.
+    #[prost(uint64, tag = "7")]
+    pub epoch: u64,
+    /// Party's traded volume at the end of the given epoch
+    #[prost(string, tag = "8")]
+    pub traded_volume: ::prost::alloc::string::String,
+    /// Party's open interest volume at the end of the given epoch
+    #[prost(string, tag = "9")]
+    pub open_volume: ::prost::alloc::string::String,
+}
+/// Event notifying on the details of a funding interval for a perpetuals market.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FundingPeriod {
+    /// ID of the market for which this funding period relates to.
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+    /// Sequence number of the funding period.
+    #[prost(uint64, tag = "2")]
+    pub seq: u64,
+    /// Time in Unix nanoseconds when the funding period started.
+    #[prost(int64, tag = "3")]
+    pub start: i64,
+    /// Time in Unix nanoseconds when the funding period ended.
+    #[prost(int64, optional, tag = "4")]
+    pub end: ::core::option::Option<i64>,
+    /// Funding payment for this period as the difference between the time-weighted average price of the external and internal data point.
+    #[prost(string, optional, tag = "5")]
+    pub funding_payment: ::core::option::Option<::prost::alloc::string::String>,
+    /// Percentage difference between the time-weighted average price of the external and internal data point.
+    #[prost(string, optional, tag = "6")]
+    pub funding_rate: ::core::option::Option<::prost::alloc::string::String>,
+    /// TWAP for this period based on the internal data-points.
+    #[prost(string, optional, tag = "7")]
+    pub internal_twap: ::core::option::Option<::prost::alloc::string::String>,
+    /// TWAP for this period based on the external data-points.
+    #[prost(string, optional, tag = "8")]
+    pub external_twap: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// The amount gained or lost by a party as a result of a funding payment.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FundingPayment {
+    /// ID of the party.
+    #[prost(string, tag = "1")]
+    pub party_id: ::prost::alloc::string::String,
+    /// The amount paid, this can be negative for parties who lost at the end of the funding period.
+    #[prost(string, tag = "2")]
+    pub amount: ::prost::alloc::string::String,
+}
+/// Event notifying of funding payments at the end of a funding period.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FundingPayments {
+    /// ID of the market.
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+    /// Sequence number of the funding period associated with these payments.
+    #[prost(uint64, tag = "2")]
+    pub seq: u64,
+    /// List of parties and the payment amounts, this can be negative for parties who lost at the end of the funding period.
+    #[prost(message, repeated, tag = "3")]
+    pub payments: ::prost::alloc::vec::Vec<FundingPayment>,
+}
+/// Event notifying a data point for a funding period.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FundingPeriodDataPoint {
+    /// Market ID which the data point relates to.
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+    /// Sequence number of the funding period this data point belongs to.
+    #[prost(uint64, tag = "2")]
+    pub seq: u64,
+    /// Origin of the data point.
+    #[prost(enumeration = "funding_period_data_point::Source", tag = "3")]
+    pub data_point_type: i32,
+    /// Price of the asset as seen by this data point.
+    #[prost(string, tag = "4")]
+    pub price: ::prost::alloc::string::String,
+    /// Timestamp in Unix nanoseconds of when the data point was received.
+    #[prost(int64, tag = "6")]
+    pub timestamp: i64,
+    /// The TWAP for this source with this data-point added.
+    #[prost(string, tag = "7")]
+    pub twap: ::prost::alloc::string::String,
+}
+/// Nested message and enum types in `FundingPeriodDataPoint`.
+pub mod funding_period_data_point {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Source {
+        /// Default value
+        Unspecified = 0,
+        /// Data point is from an external data source, such as an oracle, and represents the spot price of the underlying asset.
+        External = 1,
+        /// Data point from within Vega such as the mark price after performing mark-to-market.
+        Internal = 2,
+    }
+    impl Source {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Source::Unspecified => "SOURCE_UNSPECIFIED",
+                Source::External => "SOURCE_EXTERNAL",
+                Source::Internal => "SOURCE_INTERNAL",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "SOURCE_UNSPECIFIED" => Some(Self::Unspecified),
+                "SOURCE_EXTERNAL" => Some(Self::External),
+                "SOURCE_INTERNAL" => Some(Self::Internal),
+                _ => None,
+            }
+        }
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StopOrderEvent {
+    #[prost(message, optional, tag = "1")]
+    pub submission: ::core::option::Option<super::super::commands::v1::OrderSubmission>,
+    #[prost(message, optional, tag = "2")]
+    pub stop_order: ::core::option::Option<super::super::StopOrder>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Erc20MultiSigSignerAdded {
+    /// ID of the signature bundle
+    #[prost(string, tag = "1")]
+    pub signature_id: ::prost::alloc::string::String,
+    /// Node ID of the Vega node to be added
+    #[prost(string, tag = "2")]
+    pub validator_id: ::prost::alloc::string::String,
+    /// Time at which this happened
+    #[prost(int64, tag = "3")]
+    pub timestamp: i64,
+    /// Ethereum address of the new signer
+    #[prost(string, tag = "4")]
+    pub new_signer: ::prost::alloc::string::String,
+    /// Address of the submitter of the transaction
+    #[prost(string, tag = "5")]
+    pub submitter: ::prost::alloc::string::String,
+    /// Nonce used.
+    #[prost(string, tag = "6")]
+    pub nonce: ::prost::alloc::string::String,
+    /// Epoch that the node was added for
+    #[prost(string, tag = "7")]
+    pub epoch_seq: ::prost::alloc::string::String,
+    /// EVM chain ID that the multisig control contract lives on.
+    #[prost(string, tag = "8")]
+    pub chain_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Erc20MultiSigSignerRemovedSubmitter {
+    /// Signature ID of the signer removed.
+    #[prost(string, tag = "1")]
+    pub signature_id: ::prost::alloc::string::String,
+    /// Address of the submitter of the transaction
+    #[prost(string, tag = "2")]
+    pub submitter: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Erc20MultiSigSignerRemoved {
+    /// List containing all the IDs of bundle generated
+    /// There should be one bundle generated for every validators
+    /// in the validator set
+    #[prost(message, repeated, tag = "1")]
+    pub signature_submitters: ::prost::alloc::vec::Vec<
+        Erc20MultiSigSignerRemovedSubmitter,
+    >,
+    /// Node ID of the Vega node which is to be removed
+    #[prost(string, tag = "2")]
+    pub validator_id: ::prost::alloc::string::String,
+    /// Time at which this happened
+    #[prost(int64, tag = "3")]
+    pub timestamp: i64,
+    /// Ethereum address of the signer to be removed
+    #[prost(string, tag = "4")]
+    pub old_signer: ::prost::alloc::string::String,
+    /// Nonce used.
+    #[prost(string, tag = "5")]
+    pub nonce: ::prost::alloc::string::String,
+    /// Epoch that the node was removed for
+    #[prost(string, tag = "6")]
+    pub epoch_seq: ::prost::alloc::string::String,
+    /// EVM chain ID that the multisig control contract lives on.
+    #[prost(string, tag = "8")]
+    pub chain_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Transfer {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub from: ::prost::alloc::string::String,
+    #[prost(enumeration = "super::super::AccountType", tag = "3")]
+    pub from_account_type: i32,
+    #[prost(string, tag = "4")]
+    pub to: ::prost::alloc::string::String,
+    #[prost(enumeration = "super::super::AccountType", tag = "5")]
+    pub to_account_type: i32,
+    #[prost(string, tag = "6")]
+    pub asset: ::prost::alloc::string::String,
+    #[prost(string, tag = "7")]
+    pub amount: ::prost::alloc::string::String,
+    #[prost(string, tag = "8")]
+    pub reference: ::prost::alloc::string::String,
+    #[prost(enumeration = "transfer::Status", tag = "9")]
+    pub status: i32,
+    #[prost(int64, tag = "10")]
+    pub timestamp: i64,
+    #[prost(string, optional, tag = "11")]
+    pub reason: ::core::option::Option<::prost::alloc::string::String>,
+    /// ID of the game this transfer was made in relation to. A transfer is made to members of a team
+    /// or participants who take part in a game and are rewarded for their participation
+    #[prost(string, optional, tag = "12")]
+    pub game_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(oneof = "transfer::Kind", tags = "101, 102, 103, 104")]
+    pub kind: ::core::option::Option<transfer::Kind>,
+}
+/// Nested message and enum types in `Transfer`.
+pub mod transfer {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Status {
+        /// Default value
+        Unspecified = 0,
+        /// Indicates a transfer still being processed
+        Pending = 1,
+        /// Indicates a transfer accepted by the Vega network
+        Done = 2,
+        /// Indicates a transfer rejected by the Vega network
+        Rejected = 3,
+        /// Indicates a transfer stopped by the Vega network
+        /// e.g: no funds left to cover the transfer
+        Stopped = 4,
+        /// Indicates a transfer cancelled by the user
+        Cancelled = 5,
+    }
+    impl Status {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Status::Unspecified => "STATUS_UNSPECIFIED",
+                Status::Pending => "STATUS_PENDING",
+                Status::Done => "STATUS_DONE",
+                Status::Rejected => "STATUS_REJECTED",
+                Status::Stopped => "STATUS_STOPPED",
+                Status::Cancelled => "STATUS_CANCELLED",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "STATUS_UNSPECIFIED" => Some(Self::Unspecified),
+                "STATUS_PENDING" => Some(Self::Pending),
+                "STATUS_DONE" => Some(Self::Done),
+                "STATUS_REJECTED" => Some(Self::Rejected),
+                "STATUS_STOPPED" => Some(Self::Stopped),
+                "STATUS_CANCELLED" => Some(Self::Cancelled),
+                _ => None,
+            }
+        }
+    }
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Kind {
+        #[prost(message, tag = "101")]
+        OneOff(super::OneOffTransfer),
+        #[prost(message, tag = "102")]
+        Recurring(super::RecurringTransfer),
+        #[prost(message, tag = "103")]
+        OneOffGovernance(super::OneOffGovernanceTransfer),
+        #[prost(message, tag = "104")]
+        RecurringGovernance(super::RecurringGovernanceTransfer),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OneOffGovernanceTransfer {
+    #[prost(int64, tag = "1")]
+    pub deliver_on: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OneOffTransfer {
+    #[prost(int64, tag = "1")]
+    pub deliver_on: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RecurringTransfer {
+    #[prost(uint64, tag = "1")]
+    pub start_epoch: u64,
+    #[prost(uint64, optional, tag = "2")]
+    pub end_epoch: ::core::option::Option<u64>,
+    #[prost(string, tag = "3")]
+    pub factor: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "4")]
+    pub dispatch_strategy: ::core::option::Option<super::super::DispatchStrategy>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RecurringGovernanceTransfer {
+    #[prost(uint64, tag = "1")]
+    pub start_epoch: u64,
+    #[prost(uint64, optional, tag = "2")]
+    pub end_epoch: ::core::option::Option<u64>,
+    #[prost(message, optional, tag = "3")]
+    pub dispatch_strategy: ::core::option::Option<super::super::DispatchStrategy>,
+    #[prost(string, tag = "4")]
+    pub factor: ::prost::alloc::string::String,
+}
+/// StakeLinking - an event notifying of stake being deposited or removed for a given party
+/// These events are emitted for every Staking deposit or removed accepted by the network
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StakeLinking {
+    /// Internal ID for this staking event
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Stake linking event type.
+    #[prost(enumeration = "stake_linking::Type", tag = "2")]
+    pub r#type: i32,
+    /// Timestamp in Unix nanoseconds of when the event was emitted by Ethereum.
+    #[prost(int64, tag = "3")]
+    pub ts: i64,
+    /// Party to whom the event is directed at.
+    #[prost(string, tag = "4")]
+    pub party: ::prost::alloc::string::String,
+    /// Amount of stake deposited or removed.
+    #[prost(string, tag = "5")]
+    pub amount: ::prost::alloc::string::String,
+    /// Status of the event.
+    #[prost(enumeration = "stake_linking::Status", tag = "6")]
+    pub status: i32,
+    /// Time at which the Vega network finalised the state of the event.
+    #[prost(int64, tag = "7")]
+    pub finalized_at: i64,
+    /// Hash of the transaction in which the event happened.
+    #[prost(string, tag = "8")]
+    pub tx_hash: ::prost::alloc::string::String,
+    /// Block when the event happened.
+    #[prost(uint64, tag = "9")]
+    pub block_height: u64,
+    /// Block time.
+    #[prost(int64, tag = "10")]
+    pub block_time: i64,
+    /// Log index.
+    #[prost(uint64, tag = "11")]
+    pub log_index: u64,
+    /// Ethereum address from which the stake link was initiated.
+    #[prost(string, tag = "12")]
+    pub ethereum_address: ::prost::alloc::string::String,
+}
+/// Nested message and enum types in `StakeLinking`.
+pub mod stake_linking {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Type {
+        /// Default value
+        Unspecified = 0,
+        /// Indicate of a stake deposit instruction
+        Link = 1,
+        /// Indicate of a stake remove instruction
+        Unlink = 2,
+    }
+    impl Type {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Type::Unspecified => "TYPE_UNSPECIFIED",
+                Type::Link => "TYPE_LINK",
+                Type::Unlink => "TYPE_UNLINK",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "TYPE_UNSPECIFIED" => Some(Self::Unspecified),
+                "TYPE_LINK" => Some(Self::Link),
+                "TYPE_UNLINK" => Some(Self::Unlink),
+                _ => None,
+            }
+        }
+    }
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Status {
+        /// Default value
+        Unspecified = 0,
+        /// Indicates an event waiting for confirmation from the Vega network
+        Pending = 1,
+        /// Indicates an event accepted by the Vega network
+        Accepted = 2,
+        /// Indicates an event rejected by the Vega network
+        Rejected = 3,
+    }
+    impl Status {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Status::Unspecified => "STATUS_UNSPECIFIED",
+                Status::Pending => "STATUS_PENDING",
+                Status::Accepted => "STATUS_ACCEPTED",
+                Status::Rejected => "STATUS_REJECTED",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "STATUS_UNSPECIFIED" => Some(Self::Unspecified),
+                "STATUS_PENDING" => Some(Self::Pending),
+                "STATUS_ACCEPTED" => Some(Self::Accepted),
+                "STATUS_REJECTED" => Some(Self::Rejected),
+                _ => None,
+            }
+        }
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Erc20MultiSigSignerEvent {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(enumeration = "erc20_multi_sig_signer_event::Type", tag = "2")]
+    pub r#type: i32,
+    #[prost(string, tag = "3")]
+    pub signer: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub nonce: ::prost::alloc::string::String,
+    #[prost(int64, tag = "5")]
+    pub block_time: i64,
+    #[prost(string, tag = "6")]
+    pub tx_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "7")]
+    pub log_index: u64,
+    #[prost(uint64, tag = "8")]
+    pub block_number: u64,
+    #[prost(string, tag = "9")]
+    pub chain_id: ::prost::alloc::string::String,
+}
+/// Nested message and enum types in `ERC20MultiSigSignerEvent`.
+pub mod erc20_multi_sig_signer_event {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Type {
+        Unspecified = 0,
+        Added = 1,
+        Removed = 2,
+    }
+    impl Type {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Type::Unspecified => "TYPE_UNSPECIFIED",
+                Type::Added => "TYPE_ADDED",
+                Type::Removed => "TYPE_REMOVED",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "TYPE_UNSPECIFIED" => Some(Self::Unspecified),
+                "TYPE_ADDED" => Some(Self::Added),
+                "TYPE_REMOVED" => Some(Self::Removed),
+                _ => None,
+            }
+        }
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Erc20MultiSigThresholdSetEvent {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(uint32, tag = "2")]
+    pub new_threshold: u32,
+    #[prost(string, tag = "3")]
+    pub nonce: ::prost::alloc::string::String,
+    #[prost(int64, tag = "4")]
+    pub block_time: i64,
+    #[prost(string, tag = "5")]
+    pub tx_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "6")]
+    pub log_index: u64,
+    #[prost(uint64, tag = "7")]
+    pub block_number: u64,
+    #[prost(string, tag = "8")]
+    pub chain_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CheckpointEvent {
+    #[prost(string, tag = "1")]
+    pub hash: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub block_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "3")]
+    pub block_height: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StreamStartEvent {
+    #[prost(string, tag = "1")]
+    pub chain_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RewardPayoutEvent {
+    #[prost(string, tag = "1")]
+    pub party: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub epoch_seq: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub asset: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub amount: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub percent_of_total_reward: ::prost::alloc::string::String,
+    #[prost(int64, tag = "6")]
+    pub timestamp: i64,
+    #[prost(string, tag = "7")]
+    pub reward_type: ::prost::alloc::string::String,
+    #[prost(string, tag = "9")]
+    pub locked_until_epoch: ::prost::alloc::string::String,
+    #[prost(string, tag = "10")]
+    pub quantum_amount: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "11")]
+    pub game_id: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// ValidatorScoreEvent is the score a validator gets for a given epoch
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidatorScoreEvent {
+    #[prost(string, tag = "1")]
+    pub node_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub epoch_seq: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub validator_score: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub normalised_score: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub validator_performance: ::prost::alloc::string::String,
+    #[prost(string, tag = "6")]
+    pub raw_validator_score: ::prost::alloc::string::String,
+    #[prost(string, tag = "7")]
+    pub validator_status: ::prost::alloc::string::String,
+    #[prost(string, tag = "8")]
+    pub multisig_score: ::prost::alloc::string::String,
+}
+/// DelegationBalanceEvent - updates on the delegation balance of a party to a node in the current epoch in effect
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DelegationBalanceEvent {
+    #[prost(string, tag = "1")]
+    pub party: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub node_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub amount: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub epoch_seq: ::prost::alloc::string::String,
+}
+/// MarketEvent - the common denominator for all market events
+/// interface has a method to return a string for logging
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MarketEvent {
+    /// Market ID for the event
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+    /// Payload is a unique information string
+    #[prost(string, tag = "2")]
+    pub payload: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransferFees {
+    /// Transfer that triggered the collection of fees.
+    #[prost(string, tag = "1")]
+    pub transfer_id: ::prost::alloc::string::String,
+    /// Amount of fees paid.
+    #[prost(string, tag = "2")]
+    pub amount: ::prost::alloc::string::String,
+    /// Epoch when the transfer was dispatched, and fees were paid.
+    #[prost(uint64, tag = "3")]
+    pub epoch: u64,
+    /// Amount that was subtracted from the transfer fee based on available discounts.
+    #[prost(string, tag = "4")]
+    pub discount_applied: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransferFeesDiscount {
+    /// Party that the transfer fee discount applies to.
+    #[prost(string, tag = "1")]
+    pub party: ::prost::alloc::string::String,
+    /// Asset that the transfer fee discount is relevant to.
+    #[prost(string, tag = "2")]
+    pub asset: ::prost::alloc::string::String,
+    /// Amount that the transfer fee was discounted by.
+    #[prost(string, tag = "3")]
+    pub amount: ::prost::alloc::string::String,
+    /// Epoch in which the discount was first available.
+    #[prost(uint64, tag = "4")]
+    pub epoch: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransactionResult {
+    /// Unique party ID for the related party
+    #[prost(string, tag = "1")]
+    pub party_id: ::prost::alloc::string::String,
+    /// Status of the transaction, if it succeeded or an error was raised.
+    #[prost(bool, tag = "2")]
+    pub status: bool,
+    /// Hash of the transaction
+    #[prost(string, tag = "3")]
+    pub hash: ::prost::alloc::string::String,
+    /// Status of the transaction.
+    /// Backward compatible with previous events,
+    /// as this field will default to UNSPECIFIED.
+    #[prost(enumeration = "transaction_result::Status", tag = "4")]
+    pub status_detail: i32,
+    /// Transaction itself as received by the network
+    #[prost(
+        oneof = "transaction_result::Transaction",
+        tags = "101, 102, 103, 104, 105, 106, 107, 108, 109, 111, 112, 113, 114, 115, 116, 117, 118, 119, 120, 121, 122, 123, 124, 125, 126, 127, 128, 129, 130, 131, 132, 133"
+    )]
+    pub transaction: ::core::option::Option<transaction_result::Transaction>,
+    /// extra details about the transaction processing
+    #[prost(oneof = "transaction_result::Extra", tags = "1001, 1002")]
+    pub extra: ::core::option::Option<transaction_result::Extra>,
+}
+/// Nested message and enum types in `TransactionResult`.
+pub mod transaction_result {
+    /// A mapping of a key to a list of errors.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct KeyErrors {
+        /// The key.
+        #[prost(string, tag = "1")]
+        pub key: ::prost::alloc::string::String,
+        /// The errors mapped to this key.
+        #[prost(string, repeated, tag = "2")]
+        pub errors: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    }
+    /// TBD
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct SuccessDetails {}
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct FailureDetails {
+        /// Error message explaining the reason for the transaction failing processing
+        #[prost(string, tag = "1")]
+        pub error: ::prost::alloc::string::String,
+        /// Map of the detailed errors, if any.
+        #[prost(message, repeated, tag = "2")]
+        pub errors: ::prost::alloc::vec::Vec<KeyErrors>,
+    }
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Status {
+        /// Default value, always invalid
+        Unspecified = 0,
+        /// The transaction succeeded.
+        Success = 1,
+        /// The transaction partially succeeded.
+        PartialSuccess = 2,
+        /// The transaction's final state is failure.
+        Failure = 3,
+    }
+    impl Status {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Status::Unspecified => "STATUS_UNSPECIFIED",
+                Status::Success => "STATUS_SUCCESS",
+                Status::PartialSuccess => "STATUS_PARTIAL_SUCCESS",
+                Status::Failure => "STATUS_FAILURE",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "STATUS_UNSPECIFIED" => Some(Self::Unspecified),
+                "STATUS_SUCCESS" => Some(Self::Success),
+                "STATUS_PARTIAL_SUCCESS" => Some(Self::PartialSuccess),
+                "STATUS_FAILURE" => Some(Self::Failure),
+                _ => None,
+            }
+        }
+    }
+    /// Transaction itself as received by the network
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Transaction {
+        #[prost(message, tag = "101")]
+        OrderSubmission(super::super::super::commands::v1::OrderSubmission),
+        #[prost(message, tag = "102")]
+        OrderAmendment(super::super::super::commands::v1::OrderAmendment),
+        #[prost(message, tag = "103")]
+        OrderCancellation(super::super::super::commands::v1::OrderCancellation),
+        #[prost(message, tag = "104")]
+        Proposal(super::super::super::commands::v1::ProposalSubmission),
+        #[prost(message, tag = "105")]
+        VoteSubmission(super::super::super::commands::v1::VoteSubmission),
+        #[prost(message, tag = "106")]
+        LiquidityProvisionSubmission(
+            super::super::super::commands::v1::LiquidityProvisionSubmission,
+        ),
+        #[prost(message, tag = "107")]
+        WithdrawSubmission(super::super::super::commands::v1::WithdrawSubmission),
+        #[prost(message, tag = "108")]
+        DelegateSubmission(super::super::super::commands::v1::DelegateSubmission),
+        #[prost(message, tag = "109")]
+        UndelegateSubmission(super::super::super::commands::v1::UndelegateSubmission),
+        #[prost(message, tag = "111")]
+        LiquidityProvisionCancellation(
+            super::super::super::commands::v1::LiquidityProvisionCancellation,
+        ),
+        #[prost(message, tag = "112")]
+        LiquidityProvisionAmendment(
+            super::super::super::commands::v1::LiquidityProvisionAmendment,
+        ),
+        #[prost(message, tag = "113")]
+        Transfer(super::super::super::commands::v1::Transfer),
+        #[prost(message, tag = "114")]
+        CancelTransfer(super::super::super::commands::v1::CancelTransfer),
+        #[prost(message, tag = "115")]
+        AnnounceNode(super::super::super::commands::v1::AnnounceNode),
+        #[prost(message, tag = "116")]
+        OracleDataSubmission(super::super::super::commands::v1::OracleDataSubmission),
+        #[prost(message, tag = "117")]
+        ProtocolUpgradeProposal(
+            super::super::super::commands::v1::ProtocolUpgradeProposal,
+        ),
+        #[prost(message, tag = "118")]
+        IssueSignatures(super::super::super::commands::v1::IssueSignatures),
+        #[prost(message, tag = "119")]
+        BatchMarketInstructions(
+            super::super::super::commands::v1::BatchMarketInstructions,
+        ),
+        #[prost(message, tag = "120")]
+        KeyRotateSubmission(super::super::super::commands::v1::KeyRotateSubmission),
+        #[prost(message, tag = "121")]
+        EthereumKeyRotateSubmission(
+            super::super::super::commands::v1::EthereumKeyRotateSubmission,
+        ),
+        #[prost(message, tag = "122")]
+        StopOrdersSubmission(super::super::super::commands::v1::StopOrdersSubmission),
+        #[prost(message, tag = "123")]
+        StopOrdersCancellation(
+            super::super::super::commands::v1::StopOrdersCancellation,
+        ),
+        #[prost(message, tag = "124")]
+        CreateReferralSet(super::super::super::commands::v1::CreateReferralSet),
+        #[prost(message, tag = "125")]
+        UpdateReferralSet(super::super::super::commands::v1::UpdateReferralSet),
+        #[prost(message, tag = "126")]
+        ApplyReferralCode(super::super::super::commands::v1::ApplyReferralCode),
+        #[prost(message, tag = "127")]
+        UpdateMarginMode(super::super::super::commands::v1::UpdateMarginMode),
+        #[prost(message, tag = "128")]
+        JoinTeam(super::super::super::commands::v1::JoinTeam),
+        #[prost(message, tag = "129")]
+        BatchProposal(super::super::super::commands::v1::BatchProposalSubmission),
+        #[prost(message, tag = "130")]
+        UpdatePartyProfile(super::super::super::commands::v1::UpdatePartyProfile),
+        #[prost(message, tag = "131")]
+        SubmitAmm(super::super::super::commands::v1::SubmitAmm),
+        #[prost(message, tag = "132")]
+        AmendAmm(super::super::super::commands::v1::AmendAmm),
+        #[prost(message, tag = "133")]
+        CancelAmm(super::super::super::commands::v1::CancelAmm),
+    }
+    /// extra details about the transaction processing
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Extra {
+        #[prost(message, tag = "1001")]
+        Success(SuccessDetails),
+        #[prost(message, tag = "1002")]
+        Failure(FailureDetails),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TxErrorEvent {
+    /// Unique party ID for the related party
+    #[prost(string, tag = "1")]
+    pub party_id: ::prost::alloc::string::String,
+    /// Error message describing what went wrong
+    #[prost(string, tag = "2")]
+    pub err_msg: ::prost::alloc::string::String,
+    /// Transaction that failed
+    #[prost(
+        oneof = "tx_error_event::Transaction",
+        tags = "101, 102, 103, 104, 105, 106, 107, 108, 109, 111, 112, 113, 114, 115, 116, 117, 118, 119"
+    )]
+    pub transaction: ::core::option::Option<tx_error_event::Transaction>,
+}
+/// Nested message and enum types in `TxErrorEvent`.
+pub mod tx_error_event {
+    /// Transaction that failed
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Transaction {
+        #[prost(message, tag = "101")]
+        OrderSubmission(super::super::super::commands::v1::OrderSubmission),
+        #[prost(message, tag = "102")]
+        OrderAmendment(super::super::super::commands::v1::OrderAmendment),
+        #[prost(message, tag = "103")]
+        OrderCancellation(super::super::super::commands::v1::OrderCancellation),
+        #[prost(message, tag = "104")]
+        Proposal(super::super::super::commands::v1::ProposalSubmission),
+        #[prost(message, tag = "105")]
+        VoteSubmission(super::super::super::commands::v1::VoteSubmission),
+        #[prost(message, tag = "106")]
+        LiquidityProvisionSubmission(
+            super::super::super::commands::v1::LiquidityProvisionSubmission,
+        ),
+        #[prost(message, tag = "107")]
+        WithdrawSubmission(super::super::super::commands::v1::WithdrawSubmission),
+        #[prost(message, tag = "108")]
+        DelegateSubmission(super::super::super::commands::v1::DelegateSubmission),
+        #[prost(message, tag = "109")]
+        UndelegateSubmission(super::super::super::commands::v1::UndelegateSubmission),
+        #[prost(message, tag = "111")]
+        LiquidityProvisionCancellation(
+            super::super::super::commands::v1::LiquidityProvisionCancellation,
+        ),
+        #[prost(message, tag = "112")]
+        LiquidityProvisionAmendment(
+            super::super::super::commands::v1::LiquidityProvisionAmendment,
+        ),
+        #[prost(message, tag = "113")]
+        Transfer(super::super::super::commands::v1::Transfer),
+        #[prost(message, tag = "114")]
+        CancelTransfer(super::super::super::commands::v1::CancelTransfer),
+        #[prost(message, tag = "115")]
+        AnnounceNode(super::super::super::commands::v1::AnnounceNode),
+        #[prost(message, tag = "116")]
+        OracleDataSubmission(super::super::super::commands::v1::OracleDataSubmission),
+        #[prost(message, tag = "117")]
+        ProtocolUpgradeProposal(
+            super::super::super::commands::v1::ProtocolUpgradeProposal,
+        ),
+        #[prost(message, tag = "118")]
+        IssueSignatures(super::super::super::commands::v1::IssueSignatures),
+        #[prost(message, tag = "119")]
+        BatchMarketInstructions(
+            super::super::super::commands::v1::BatchMarketInstructions,
+        ),
+    }
+}
+/// Time update event contains the latest time update from Vega blockchain and indicates the start of a new block
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TimeUpdate {
+    /// Timestamp containing latest update from Vega blockchain aka Vega-time
+    #[prost(int64, tag = "1")]
+    pub timestamp: i64,
+}
+/// Epoch details
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EpochEvent {
+    /// Sequence number that increases by one each epoch
+    #[prost(uint64, tag = "1")]
+    pub seq: u64,
+    /// Action tells us what action is taking place
+    #[prost(enumeration = "super::super::EpochAction", tag = "2")]
+    pub action: i32,
+    /// Vega time at which this epoch started
+    #[prost(int64, tag = "3")]
+    pub start_time: i64,
+    /// Vega time at which this epoch should end
+    #[prost(int64, tag = "4")]
+    pub expire_time: i64,
+    /// Vega time at which this epoch actually ended
+    #[prost(int64, tag = "5")]
+    pub end_time: i64,
+}
+/// Transfer responses event contains a collection of transfer information
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LedgerMovements {
+    /// One or more entries containing internal transfer information
+    #[prost(message, repeated, tag = "1")]
+    pub ledger_movements: ::prost::alloc::vec::Vec<super::super::LedgerMovement>,
+}
+/// Position resolution event contains information on distressed trades
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PositionResolution {
+    /// Market ID for the event
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+    /// Number of distressed traders
+    #[prost(int64, tag = "2")]
+    pub distressed: i64,
+    /// Number of close outs
+    #[prost(int64, tag = "3")]
+    pub closed: i64,
+    /// Mark price as a string representing a scaled price
+    #[prost(string, tag = "4")]
+    pub mark_price: ::prost::alloc::string::String,
+}
+/// Loss socialization event contains details on the amount of wins unable to be distributed
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LossSocialization {
+    /// Market ID for the event
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+    /// Party ID (public key) for the event
+    #[prost(string, tag = "2")]
+    pub party_id: ::prost::alloc::string::String,
+    /// Amount distributed
+    #[prost(string, tag = "3")]
+    pub amount: ::prost::alloc::string::String,
+}
+/// Trade settlement is part of the settle position event
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TradeSettlement {
+    /// Size of trade settlement
+    #[prost(int64, tag = "1")]
+    pub size: i64,
+    /// Price of settlement as string (in asset decimals)
+    #[prost(string, tag = "2")]
+    pub price: ::prost::alloc::string::String,
+    /// Price of settlement as a string (in market decimals)
+    #[prost(string, tag = "3")]
+    pub market_price: ::prost::alloc::string::String,
+}
+/// Settle position event contains position settlement information for a party
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SettlePosition {
+    /// Market ID for the event
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+    /// Party ID (public key) for the event
+    #[prost(string, tag = "2")]
+    pub party_id: ::prost::alloc::string::String,
+    /// Price of settlement as a string
+    #[prost(string, tag = "3")]
+    pub price: ::prost::alloc::string::String,
+    /// A collection of 1 or more trade settlements
+    #[prost(message, repeated, tag = "4")]
+    pub trade_settlements: ::prost::alloc::vec::Vec<TradeSettlement>,
+    /// Position factor - 10 ^ number of position decimal places
+    #[prost(string, tag = "5")]
+    pub position_factor: ::prost::alloc::string::String,
+}
+/// Settle market event to notify data node that a market has been settled
+/// so positions and PL can be updated accordingly
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SettleMarket {
+    /// Market ID for the event
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+    /// Price of settlement as a string
+    #[prost(string, tag = "2")]
+    pub price: ::prost::alloc::string::String,
+    /// Position factor - 10 ^ number of position decimal places
+    #[prost(string, tag = "3")]
+    pub position_factor: ::prost::alloc::string::String,
+}
+/// Position state event contains the current position state for a single party in a single market
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PositionStateEvent {
+    /// Party ID for this position update
+    #[prost(string, tag = "1")]
+    pub party_id: ::prost::alloc::string::String,
+    /// Market ID for this position update
+    #[prost(string, tag = "2")]
+    pub market_id: ::prost::alloc::string::String,
+    /// Current position
+    #[prost(int64, tag = "3")]
+    pub size: i64,
+    /// Potential orders
+    #[prost(int64, tag = "4")]
+    pub potential_buys: i64,
+    #[prost(int64, tag = "5")]
+    pub potential_sells: i64,
+    /// Volume weighted prices
+    #[prost(string, tag = "6")]
+    pub vw_buy_price: ::prost::alloc::string::String,
+    #[prost(string, tag = "7")]
+    pub vw_sell_price: ::prost::alloc::string::String,
+}
+/// Settle distressed event contains information on distressed trading parties who are closed out
+///
+/// Note: Any PositionResolution event (market level) will most likely be followed by a number of these events
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SettleDistressed {
+    /// Market ID for the event
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+    /// Party ID i.e. a party's public key for the event
+    #[prost(string, tag = "2")]
+    pub party_id: ::prost::alloc::string::String,
+    /// Margin value as an integer, for example `123456` is a correctly
+    /// formatted price of `1.23456` assuming market configured to 5 decimal places
+    #[prost(string, tag = "3")]
+    pub margin: ::prost::alloc::string::String,
+    /// Price as an integer, for example `123456` is a correctly
+    /// formatted price of `1.23456` assuming market configured to 5 decimal places
+    #[prost(string, tag = "4")]
+    pub price: ::prost::alloc::string::String,
+}
+/// Distressed order contains the party IDs for all parties that were distressed and had their orders closed,
+/// but did not need to be closed out after cancelling their orders
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DistressedOrders {
+    /// Market ID for the event
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+    /// Slice of Party IDs i.e. each party's public key for the event
+    #[prost(string, repeated, tag = "2")]
+    pub parties: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// Distressed positions event contains the party IDs for all parties that were distressed, had their orders closed but because of insufficient volume on the book
+/// could not be fully closed out. These parties are distressed, but still hold an active position on the book as a result. Once enough volume is on the book to close
+/// them out, a SettleDistressed event will be sent. In case they manage to reduce their position, or meet the margin requirements, this status will be updated.
+/// Parties that are no longer distressed but active will be listed in the safe_parties field.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DistressedPositions {
+    /// Market ID for the event
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+    /// Slice of party IDs i.e. each party's public key, that are distressed but still have open volume
+    #[prost(string, repeated, tag = "2")]
+    pub distressed_parties: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Slice of party IDs i.e. each party's public key, who were distressed but now can safely maintain their position
+    #[prost(string, repeated, tag = "3")]
+    pub safe_parties: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// Market tick event contains the time value for when a particular market was last processed on Vega
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MarketTick {
+    /// Market ID for the event
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Timestamp containing latest update from Vega blockchain aka Vega-time
+    #[prost(int64, tag = "2")]
+    pub time: i64,
+}
+/// Auction event indicating a change in auction state, for example starting or ending an auction
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AuctionEvent {
+    /// Market ID for the event
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+    /// True if the event indicates an auction opening and False otherwise
+    #[prost(bool, tag = "2")]
+    pub opening_auction: bool,
+    /// True if the event indicates leaving auction mode and False otherwise
+    #[prost(bool, tag = "3")]
+    pub leave: bool,
+    /// Timestamp containing the start time for an auction
+    #[prost(int64, tag = "4")]
+    pub start: i64,
+    /// Timestamp containing the end time for an auction
+    #[prost(int64, tag = "5")]
+    pub end: i64,
+    /// Reason this market is/was in auction
+    #[prost(enumeration = "super::super::AuctionTrigger", tag = "6")]
+    pub trigger: i32,
+    /// If an auction was ongoing, but was extended for whatever reason, this field will
+    /// be set to the trigger type indicating which component extended the auction
+    #[prost(enumeration = "super::super::AuctionTrigger", tag = "7")]
+    pub extension_trigger: i32,
+}
+/// Validator update event contains information about validator node
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidatorUpdate {
+    /// Node ID of the validator node
+    #[prost(string, tag = "1")]
+    pub node_id: ::prost::alloc::string::String,
+    /// Vega public key of validator node
+    #[prost(string, tag = "2")]
+    pub vega_pub_key: ::prost::alloc::string::String,
+    /// Ethereum public key of validator node
+    #[prost(string, tag = "3")]
+    pub ethereum_address: ::prost::alloc::string::String,
+    /// Public key of Tendermint
+    #[prost(string, tag = "4")]
+    pub tm_pub_key: ::prost::alloc::string::String,
+    /// URL with more info on the node
+    #[prost(string, tag = "5")]
+    pub info_url: ::prost::alloc::string::String,
+    /// Country code (ISO 3166-1 alpha-2) for the location of the node
+    #[prost(string, tag = "6")]
+    pub country: ::prost::alloc::string::String,
+    /// Name of the validator
+    #[prost(string, tag = "7")]
+    pub name: ::prost::alloc::string::String,
+    /// AvatarURL of the validator
+    #[prost(string, tag = "8")]
+    pub avatar_url: ::prost::alloc::string::String,
+    /// Vega public key derivation index
+    #[prost(uint32, tag = "9")]
+    pub vega_pub_key_index: u32,
+    /// Flag indicating if the validator has been added to or removed from vega
+    #[prost(bool, tag = "10")]
+    pub added: bool,
+    /// Epoch in which the announced pending node will start participating in the network
+    #[prost(uint64, tag = "11")]
+    pub from_epoch: u64,
+    /// Ethereum public key being used as the submitter to allow automatic signature generation
+    #[prost(string, tag = "12")]
+    pub submitter_address: ::prost::alloc::string::String,
+    /// Epoch in which the node was announced or removed from the network
+    #[prost(uint64, tag = "13")]
+    pub epoch_seq: u64,
+}
+/// Event that explains the status of the validator for the coming epoch
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidatorRankingEvent {
+    #[prost(string, tag = "1")]
+    pub node_id: ::prost::alloc::string::String,
+    /// Stake based score - no anti-whaling
+    #[prost(string, tag = "2")]
+    pub stake_score: ::prost::alloc::string::String,
+    /// Performance base score
+    #[prost(string, tag = "3")]
+    pub performance_score: ::prost::alloc::string::String,
+    /// Final score
+    #[prost(string, tag = "4")]
+    pub ranking_score: ::prost::alloc::string::String,
+    /// Status of the validator in the previous epoch
+    #[prost(string, tag = "5")]
+    pub previous_status: ::prost::alloc::string::String,
+    /// Status of the validator in the next epoch
+    #[prost(string, tag = "6")]
+    pub next_status: ::prost::alloc::string::String,
+    /// Epoch seq for which the status is valid
+    #[prost(string, tag = "7")]
+    pub epoch_seq: ::prost::alloc::string::String,
+    /// Tendermint voting power of the validator
+    #[prost(uint32, tag = "8")]
+    pub tm_voting_power: u32,
+}
+/// Event that contains information about a Vega key rotation
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct KeyRotation {
+    /// Node ID of the node that rotated their Vega key
+    #[prost(string, tag = "1")]
+    pub node_id: ::prost::alloc::string::String,
+    /// Vega public key that was previously associated with the node
+    #[prost(string, tag = "2")]
+    pub old_pub_key: ::prost::alloc::string::String,
+    /// Vega public key that is newly associated with the node
+    #[prost(string, tag = "3")]
+    pub new_pub_key: ::prost::alloc::string::String,
+    /// Block height when the key rotation took effect
+    #[prost(uint64, tag = "4")]
+    pub block_height: u64,
+}
+/// Event that contains information about an Ethereum key rotation
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EthereumKeyRotation {
+    /// Node ID of the node that rotated their Ethereum key
+    #[prost(string, tag = "1")]
+    pub node_id: ::prost::alloc::string::String,
+    /// Ethereum address that was previously associated with the node
+    #[prost(string, tag = "2")]
+    pub old_address: ::prost::alloc::string::String,
+    /// Ethereum address that is newly associated with the node
+    #[prost(string, tag = "3")]
+    pub new_address: ::prost::alloc::string::String,
+    /// Block height when the key rotation took effect
+    #[prost(uint64, tag = "4")]
+    pub block_height: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtocolUpgradeEvent {
+    /// Block height at which to perform the upgrade
+    #[prost(uint64, tag = "1")]
+    pub upgrade_block_height: u64,
+    /// Release tag for the vega binary
+    #[prost(string, tag = "2")]
+    pub vega_release_tag: ::prost::alloc::string::String,
+    /// Tendermint validators that have agreed to the upgrade
+    #[prost(string, repeated, tag = "3")]
+    pub approvers: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Status of the proposal
+    #[prost(enumeration = "ProtocolUpgradeProposalStatus", tag = "4")]
+    pub status: i32,
+}
+/// StateVar event updates on state changes in state variable consensus
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StateVar {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub event_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub state: ::prost::alloc::string::String,
+}
+/// BeginBlock
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BeginBlock {
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    #[prost(int64, tag = "2")]
+    pub timestamp: i64,
+    #[prost(string, tag = "3")]
+    pub hash: ::prost::alloc::string::String,
+}
+/// EndBlock
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EndBlock {
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+}
+/// Event indicating the core is starting a protocol upgrade
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtocolUpgradeStarted {
+    #[prost(uint64, tag = "1")]
+    pub last_block_height: u64,
+}
+/// Event indicating the data node is ready for protocol upgrade
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtocolUpgradeDataNodeReady {
+    #[prost(uint64, tag = "1")]
+    pub last_block_height: u64,
+}
+/// CoreSnapshotData represents the core snapshot data.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CoreSnapshotData {
+    /// Block height at which snapshot was taken
+    #[prost(uint64, tag = "1")]
+    pub block_height: u64,
+    /// Hash of the snapshot block
+    #[prost(string, tag = "2")]
+    pub block_hash: ::prost::alloc::string::String,
+    /// Semver version number of the core.
+    #[prost(string, tag = "3")]
+    pub core_version: ::prost::alloc::string::String,
+    /// Indicates if the snapshot is taken as part of protocol upgrade
+    #[prost(bool, tag = "4")]
+    pub protocol_upgrade_block: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExpiredOrders {
+    /// Market ID for the event
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+    /// Slice of expired order IDs
+    #[prost(string, repeated, tag = "2")]
+    pub order_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CancelledOrders {
+    /// Market ID for the event.
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+    /// The ID of the party which cancelled the orders.
+    #[prost(string, tag = "2")]
+    pub party_id: ::prost::alloc::string::String,
+    /// The order IDs that were cancelled.
+    #[prost(string, repeated, tag = "3")]
+    pub order_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TeamCreated {
+    /// The unique identifier of the created team.
+    #[prost(string, tag = "1")]
+    pub team_id: ::prost::alloc::string::String,
+    /// The party that created the team.
+    #[prost(string, tag = "2")]
+    pub referrer: ::prost::alloc::string::String,
+    /// Name of the team.
+    #[prost(string, tag = "3")]
+    pub name: ::prost::alloc::string::String,
+    /// Link to the team's homepage.
+    #[prost(string, optional, tag = "4")]
+    pub team_url: ::core::option::Option<::prost::alloc::string::String>,
+    /// Link to an image of the team's avatar.
+    #[prost(string, optional, tag = "5")]
+    pub avatar_url: ::core::option::Option<::prost::alloc::string::String>,
+    /// Time in Unix nanoseconds when the team is created.
+    #[prost(int64, tag = "6")]
+    pub created_at: i64,
+    /// Tells if a party can join the team or not.
+    #[prost(bool, tag = "7")]
+    pub closed: bool,
+    /// Epoch at which the team was created.
+    #[prost(uint64, tag = "8")]
+    pub at_epoch: u64,
+    /// List of public keys that are allowed to join the team.
+    #[prost(string, repeated, tag = "9")]
+    pub allow_list: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TeamUpdated {
+    /// The unique identifier for the updated team.
+    #[prost(string, tag = "1")]
+    pub team_id: ::prost::alloc::string::String,
+    /// Name of the team.
+    #[prost(string, tag = "2")]
+    pub name: ::prost::alloc::string::String,
+    /// Link to the team's homepage.
+    #[prost(string, optional, tag = "3")]
+    pub team_url: ::core::option::Option<::prost::alloc::string::String>,
+    /// Link to an image of the team's avatar.
+    #[prost(string, optional, tag = "4")]
+    pub avatar_url: ::core::option::Option<::prost::alloc::string::String>,
+    /// Tells if a party can join the team or not.
+    #[prost(bool, tag = "5")]
+    pub closed: bool,
+    /// List of public keys that are allowed to join the team.
+    #[prost(string, repeated, tag = "6")]
+    pub allow_list: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RefereeSwitchedTeam {
+    /// The unique identifier of the team the referee left.
+    #[prost(string, tag = "1")]
+    pub from_team_id: ::prost::alloc::string::String,
+    /// The unique identifier of the team joined.
+    #[prost(string, tag = "2")]
+    pub to_team_id: ::prost::alloc::string::String,
+    /// The party that switched team.
+    #[prost(string, tag = "3")]
+    pub referee: ::prost::alloc::string::String,
+    /// Time in Unix nanoseconds when the party switched team. This time acts as
+    /// the joining time.
+    #[prost(int64, tag = "4")]
+    pub switched_at: i64,
+    /// Epoch at which the party switched the team.
+    #[prost(uint64, tag = "5")]
+    pub at_epoch: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RefereeJoinedTeam {
+    /// The unique identifier of the team the referee joined.
+    #[prost(string, tag = "1")]
+    pub team_id: ::prost::alloc::string::String,
+    /// The party that joined the team.
+    #[prost(string, tag = "2")]
+    pub referee: ::prost::alloc::string::String,
+    /// Time in Unix nanoseconds when the party joined a team.
+    #[prost(int64, tag = "3")]
+    pub joined_at: i64,
+    /// Epoch at which the party joined the team.
+    #[prost(uint64, tag = "4")]
+    pub at_epoch: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReferralSetCreated {
+    /// Unique ID of the created set.
+    #[prost(string, tag = "1")]
+    pub set_id: ::prost::alloc::string::String,
+    /// Party that created the set.
+    #[prost(string, tag = "2")]
+    pub referrer: ::prost::alloc::string::String,
+    /// Time in Unix nanoseconds when the set was created.
+    #[prost(int64, tag = "3")]
+    pub created_at: i64,
+    /// Time in Unix nanoseconds when the set was updated.
+    #[prost(int64, tag = "4")]
+    pub updated_at: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReferralSetStatsUpdated {
+    /// Unique ID of the set.
+    #[prost(string, tag = "1")]
+    pub set_id: ::prost::alloc::string::String,
+    /// Epoch at which the set's statistics are updated.
+    #[prost(uint64, tag = "2")]
+    pub at_epoch: u64,
+    /// Running volume for the set based on the window length of the current
+    /// referral program.
+    #[prost(string, tag = "3")]
+    pub referral_set_running_notional_taker_volume: ::prost::alloc::string::String,
+    /// Referees' statistics for that epoch.
+    #[prost(message, repeated, tag = "4")]
+    pub referees_stats: ::prost::alloc::vec::Vec<RefereeStats>,
+    /// deprecated
+    #[prost(string, tag = "5")]
+    pub reward_factor: ::prost::alloc::string::String,
+    /// Rewards multiplier applied to the trades.
+    #[prost(string, tag = "6")]
+    pub rewards_multiplier: ::prost::alloc::string::String,
+    /// deprecated
+    #[prost(string, tag = "7")]
+    pub rewards_factor_multiplier: ::prost::alloc::string::String,
+    /// Indicates if the referral set was eligible to be part of the referral program.
+    #[prost(bool, tag = "8")]
+    pub was_eligible: bool,
+    /// Taker volume of the referrer
+    #[prost(string, tag = "9")]
+    pub referrer_taker_volume: ::prost::alloc::string::String,
+    /// Reward factor applied to the trades.
+    #[prost(message, optional, tag = "10")]
+    pub reward_factors: ::core::option::Option<super::super::RewardFactors>,
+    /// Reward factors multiplier for the trades.
+    #[prost(message, optional, tag = "11")]
+    pub reward_factors_multiplier: ::core::option::Option<super::super::RewardFactors>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RefereeStats {
+    /// Unique ID of the party.
+    #[prost(string, tag = "1")]
+    pub party_id: ::prost::alloc::string::String,
+    /// deprecated.
+    #[prost(string, tag = "2")]
+    pub discount_factor: ::prost::alloc::string::String,
+    /// Current referee notional taker volume
+    #[prost(string, tag = "4")]
+    pub epoch_notional_taker_volume: ::prost::alloc::string::String,
+    /// Discount factors applied to the party.
+    #[prost(message, optional, tag = "5")]
+    pub discount_factors: ::core::option::Option<super::super::DiscountFactors>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RefereeJoinedReferralSet {
+    /// Unique ID of the referral set the referee joined.
+    #[prost(string, tag = "1")]
+    pub set_id: ::prost::alloc::string::String,
+    /// Party that joined the set.
+    #[prost(string, tag = "2")]
+    pub referee: ::prost::alloc::string::String,
+    /// Time in Unix nanoseconds when the party joined the set.
+    #[prost(int64, tag = "3")]
+    pub joined_at: i64,
+    /// Epoch at which the party joined the set.
+    #[prost(uint64, tag = "4")]
+    pub at_epoch: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReferralProgramStarted {
+    /// Referral program that has started.
+    #[prost(message, optional, tag = "1")]
+    pub program: ::core::option::Option<super::super::ReferralProgram>,
+    /// Time in Unix nanoseconds when the referral program started.
+    #[prost(int64, tag = "2")]
+    pub started_at: i64,
+    /// Epoch at which the referral program started.
+    #[prost(uint64, tag = "3")]
+    pub at_epoch: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReferralProgramUpdated {
+    /// The updated referral program.
+    #[prost(message, optional, tag = "1")]
+    pub program: ::core::option::Option<super::super::ReferralProgram>,
+    /// Time in Unix nanoseconds when the referral program was updated.
+    #[prost(int64, tag = "2")]
+    pub updated_at: i64,
+    /// Epoch at which the referral program was updated.
+    #[prost(uint64, tag = "3")]
+    pub at_epoch: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReferralProgramEnded {
+    /// Program update version.
+    #[prost(uint64, tag = "1")]
+    pub version: u64,
+    /// Unique ID of the referral program.
+    #[prost(string, tag = "2")]
+    pub id: ::prost::alloc::string::String,
+    /// Time in Unix nanoseconds when the referral program ended.
+    #[prost(int64, tag = "3")]
+    pub ended_at: i64,
+    /// Epoch at which the referral program ended.
+    #[prost(uint64, tag = "4")]
+    pub at_epoch: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VolumeDiscountProgramStarted {
+    /// Volume discount program that has started.
+    #[prost(message, optional, tag = "1")]
+    pub program: ::core::option::Option<super::super::VolumeDiscountProgram>,
+    /// Time in Unix nanoseconds when the volume discount program started.
+    #[prost(int64, tag = "2")]
+    pub started_at: i64,
+    /// Epoch at which the volume discount program started.
+    #[prost(uint64, tag = "3")]
+    pub at_epoch: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VolumeDiscountProgramUpdated {
+    /// The updated volume discount program.
+    #[prost(message, optional, tag = "1")]
+    pub program: ::core::option::Option<super::super::VolumeDiscountProgram>,
+    /// Time in Unix nanoseconds when the volume discount program was updated.
+    #[prost(int64, tag = "2")]
+    pub updated_at: i64,
+    /// Epoch at which the volume discount program was updated.
+    #[prost(uint64, tag = "3")]
+    pub at_epoch: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VolumeDiscountProgramEnded {
+    /// Program update version.
+    #[prost(uint64, tag = "1")]
+    pub version: u64,
+    /// Unique ID of the volume discount program.
+    #[prost(string, tag = "2")]
+    pub id: ::prost::alloc::string::String,
+    /// Time in Unix nanoseconds when the referral program ended.
+    #[prost(int64, tag = "3")]
+    pub ended_at: i64,
+    /// Epoch at which the referral program ended.
+    #[prost(uint64, tag = "4")]
+    pub at_epoch: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PaidLiquidityFeesStats {
+    /// Market the fees were paid in.
+    #[prost(string, tag = "1")]
+    pub market: ::prost::alloc::string::String,
+    /// Settlement asset of the market.
+    #[prost(string, tag = "2")]
+    pub asset: ::prost::alloc::string::String,
+    /// Epoch for which these stats where valid.
+    #[prost(uint64, tag = "3")]
+    pub epoch_seq: u64,
+    /// Total fees paid across all parties.
+    #[prost(string, tag = "4")]
+    pub total_fees_paid: ::prost::alloc::string::String,
+    /// Fees paid per party.
+    #[prost(message, repeated, tag = "5")]
+    pub fees_paid_per_party: ::prost::alloc::vec::Vec<PartyAmount>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PartyMarginModeUpdated {
+    /// Unique ID of the market in which the update happened.
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+    /// Unique ID of the party that updated their margin mode.
+    #[prost(string, tag = "2")]
+    pub party_id: ::prost::alloc::string::String,
+    /// Updated margin mode.
+    #[prost(enumeration = "super::super::MarginMode", tag = "3")]
+    pub margin_mode: i32,
+    /// Margin factor for the market. Isolated mode only.
+    #[prost(string, optional, tag = "4")]
+    pub margin_factor: ::core::option::Option<::prost::alloc::string::String>,
+    /// Minimum theoretical margin factor for the market. Isolated mode only.
+    #[prost(string, optional, tag = "5")]
+    pub min_theoretical_margin_factor: ::core::option::Option<
+        ::prost::alloc::string::String,
+    >,
+    /// Maximum theoretical leverage for the market. Isolated mode only.
+    #[prost(string, optional, tag = "6")]
+    pub max_theoretical_leverage: ::core::option::Option<::prost::alloc::string::String>,
+    /// Epoch at which the update happened.
+    #[prost(uint64, tag = "7")]
+    pub at_epoch: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PartyProfileUpdated {
+    /// Party's profile updated.
+    #[prost(message, optional, tag = "1")]
+    pub updated_profile: ::core::option::Option<super::super::PartyProfile>,
+}
+/// Stats for all teams.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TeamsStatsUpdated {
+    /// Epoch at which the statistics are updated.
+    #[prost(uint64, tag = "1")]
+    pub at_epoch: u64,
+    /// All teams' stats.
+    #[prost(message, repeated, tag = "2")]
+    pub stats: ::prost::alloc::vec::Vec<TeamStats>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TeamStats {
+    /// The unique identifier of the team.
+    #[prost(string, tag = "1")]
+    pub team_id: ::prost::alloc::string::String,
+    /// Statistics for each team member.
+    #[prost(message, repeated, tag = "2")]
+    pub members_stats: ::prost::alloc::vec::Vec<TeamMemberStats>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TeamMemberStats {
+    /// The party ID of the team member.
+    #[prost(string, tag = "1")]
+    pub party_id: ::prost::alloc::string::String,
+    /// Team members' notional volume for the epoch.
+    #[prost(string, tag = "2")]
+    pub notional_volume: ::prost::alloc::string::String,
+}
+/// Scores per party per game with eligibility context.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GamePartyScore {
+    /// Game ID.
+    #[prost(string, tag = "1")]
+    pub game_id: ::prost::alloc::string::String,
+    /// The party ID.
+    #[prost(string, tag = "2")]
+    pub party: ::prost::alloc::string::String,
+    /// Team the party belongs to. Unset if the party is not part of a team.
+    #[prost(string, optional, tag = "3")]
+    pub team_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Epoch when these scores were generated.
+    #[prost(int64, tag = "4")]
+    pub epoch: i64,
+    /// Timestamp in Unix nanoseconds when these scores were generated.
+    #[prost(int64, tag = "5")]
+    pub time: i64,
+    /// Party's current score in the game.
+    #[prost(string, tag = "6")]
+    pub score: ::prost::alloc::string::String,
+    /// Party's current staking balance. Only populated if the game has a requirement for it.
+    #[prost(string, tag = "7")]
+    pub staking_balance: ::prost::alloc::string::String,
+    /// Party's current open volume. Only populated if the game has a requirement for it.
+    #[prost(string, tag = "8")]
+    pub open_volume: ::prost::alloc::string::String,
+    /// Total fees paid by the party during the relevant period.
+    #[prost(string, tag = "9")]
+    pub total_fees_paid: ::prost::alloc::string::String,
+    /// If the party is eligible for a reward for this game based on the current information.
+    #[prost(bool, tag = "10")]
+    pub is_eligible: bool,
+    /// If the party is a member of a team, this is their relative position in the sorting order of the team.
+    /// Empty if not a team, or if the party is not eligible.
+    #[prost(uint64, optional, tag = "11")]
+    pub rank: ::core::option::Option<u64>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GameTeamScore {
+    /// Game ID.
+    #[prost(string, tag = "1")]
+    pub game_id: ::prost::alloc::string::String,
+    /// Team ID
+    #[prost(string, tag = "2")]
+    pub team_id: ::prost::alloc::string::String,
+    /// Epoch when these scores were generated.
+    #[prost(int64, tag = "3")]
+    pub epoch: i64,
+    /// Timestamp in Unix nanoseconds when these scores were generated.
+    #[prost(int64, tag = "4")]
+    pub time: i64,
+    /// Team's current collective score in the game.
+    #[prost(string, tag = "5")]
+    pub score: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GameScores {
+    #[prost(message, repeated, tag = "1")]
+    pub team_scores: ::prost::alloc::vec::Vec<GameTeamScore>,
+    #[prost(message, repeated, tag = "2")]
+    pub party_scores: ::prost::alloc::vec::Vec<GamePartyScore>,
+}
+/// Bus event is a container for event bus events emitted by Vega
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BusEvent {
+    /// Unique event ID for the message
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// The batch or block of transactions that the events relate to
+    #[prost(string, tag = "2")]
+    pub block: ::prost::alloc::string::String,
+    /// The type of bus event. Must be one of the list below:
+    #[prost(enumeration = "BusEventType", tag = "3")]
+    pub r#type: i32,
+    /// Version of bus event
+    #[prost(uint32, tag = "4")]
+    pub version: u32,
+    #[prost(string, tag = "5")]
+    pub chain_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "6")]
+    pub tx_hash: ::prost::alloc::string::String,
+    #[prost(
+        oneof = "bus_event::Event",
+        tags = "101, 102, 103, 104, 105, 106, 107, 108, 109, 110, 111, 112, 113, 114, 115, 116, 117, 118, 119, 120, 121, 122, 123, 124, 125, 126, 127, 129, 130, 131, 132, 133, 134, 135, 136, 137, 138, 139, 140, 141, 142, 143, 144, 145, 146, 147, 148, 149, 150, 151, 152, 153, 154, 155, 156, 157, 158, 159, 160, 161, 162, 163, 164, 165, 166, 167, 168, 169, 170, 171, 172, 173, 174, 175, 176, 177, 178, 179, 180, 181, 182, 183, 184, 185, 186, 187, 188, 189, 190, 191, 192, 193, 1001, 2001"
+    )]
+    pub event: ::core::option::Option<bus_event::Event>,
+}
+/// Nested message and enum types in `BusEvent`.
+pub mod bus_event {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Event {
+        /// Time update events
+        #[prost(message, tag = "101")]
+        TimeUpdate(super::TimeUpdate),
+        /// Transfer responses update events
+        #[prost(message, tag = "102")]
+        LedgerMovements(super::LedgerMovements),
+        /// Position resolution events
+        #[prost(message, tag = "103")]
+        PositionResolution(super::PositionResolution),
+        /// Order events
+        #[prost(message, tag = "104")]
+        Order(super::super::super::Order),
+        /// Account events
+        #[prost(message, tag = "105")]
+        Account(super::super::super::Account),
+        /// Party events
+        #[prost(message, tag = "106")]
+        Party(super::super::super::Party),
+        /// Trade events
+        #[prost(message, tag = "107")]
+        Trade(super::super::super::Trade),
+        /// Margin level update events
+        #[prost(message, tag = "108")]
+        MarginLevels(super::super::super::MarginLevels),
+        /// Proposal events for governance
+        #[prost(message, tag = "109")]
+        Proposal(super::super::super::Proposal),
+        /// Vote events for governance
+        #[prost(message, tag = "110")]
+        Vote(super::super::super::Vote),
+        /// Market data events
+        #[prost(message, tag = "111")]
+        MarketData(super::super::super::MarketData),
+        /// Node signature events
+        #[prost(message, tag = "112")]
+        NodeSignature(super::super::super::commands::v1::NodeSignature),
+        /// Loss socialization events
+        #[prost(message, tag = "113")]
+        LossSocialization(super::LossSocialization),
+        /// Position settlement events
+        #[prost(message, tag = "114")]
+        SettlePosition(super::SettlePosition),
+        /// Position distressed events
+        #[prost(message, tag = "115")]
+        SettleDistressed(super::SettleDistressed),
+        /// Market created events
+        #[prost(message, tag = "116")]
+        MarketCreated(super::super::super::Market),
+        /// Asset events
+        #[prost(message, tag = "117")]
+        Asset(super::super::super::Asset),
+        /// Market tick events
+        #[prost(message, tag = "118")]
+        MarketTick(super::MarketTick),
+        /// Withdrawal events
+        #[prost(message, tag = "119")]
+        Withdrawal(super::super::super::Withdrawal),
+        /// Deposit events
+        #[prost(message, tag = "120")]
+        Deposit(super::super::super::Deposit),
+        /// Auction events
+        #[prost(message, tag = "121")]
+        Auction(super::AuctionEvent),
+        /// Risk factor events
+        #[prost(message, tag = "122")]
+        RiskFactor(super::super::super::RiskFactor),
+        /// Network parameter events
+        #[prost(message, tag = "123")]
+        NetworkParameter(super::super::super::NetworkParameter),
+        /// LiquidityProvision  events
+        #[prost(message, tag = "124")]
+        LiquidityProvision(super::super::super::LiquidityProvision),
+        /// Market created events
+        #[prost(message, tag = "125")]
+        MarketUpdated(super::super::super::Market),
+        /// OracleSpec events
+        #[prost(message, tag = "126")]
+        OracleSpec(super::super::super::OracleSpec),
+        /// OracleData events
+        #[prost(message, tag = "127")]
+        OracleData(super::super::super::OracleData),
+        /// Delegation balance events
+        #[prost(message, tag = "129")]
+        DelegationBalance(super::DelegationBalanceEvent),
+        /// Validator score calculated
+        #[prost(message, tag = "130")]
+        ValidatorScore(super::ValidatorScoreEvent),
+        /// Epoch update events
+        #[prost(message, tag = "131")]
+        EpochEvent(super::EpochEvent),
+        /// Validator update events
+        #[prost(message, tag = "132")]
+        ValidatorUpdate(super::ValidatorUpdate),
+        /// Staking event
+        #[prost(message, tag = "133")]
+        StakeLinking(super::StakeLinking),
+        /// Reward payout event
+        #[prost(message, tag = "134")]
+        RewardPayout(super::RewardPayoutEvent),
+        /// Checkpoint was created
+        #[prost(message, tag = "135")]
+        Checkpoint(super::CheckpointEvent),
+        /// Key rotation took place
+        #[prost(message, tag = "136")]
+        KeyRotation(super::KeyRotation),
+        /// State variable consensus state transition update
+        #[prost(message, tag = "137")]
+        StateVar(super::StateVar),
+        /// Network limits events
+        #[prost(message, tag = "138")]
+        NetworkLimits(super::super::super::NetworkLimits),
+        /// Transfer event
+        #[prost(message, tag = "139")]
+        Transfer(super::Transfer),
+        /// Ranking event
+        #[prost(message, tag = "140")]
+        RankingEvent(super::ValidatorRankingEvent),
+        /// ERC20 multi sig signer event
+        #[prost(message, tag = "141")]
+        Erc20MultisigSignerEvent(super::Erc20MultiSigSignerEvent),
+        /// ERC20 multi sig set threshold event
+        #[prost(message, tag = "142")]
+        Erc20MultisigSetThresholdEvent(super::Erc20MultiSigThresholdSetEvent),
+        /// ERC20 multi sig signer added
+        #[prost(message, tag = "143")]
+        Erc20MultisigSignerAdded(super::Erc20MultiSigSignerAdded),
+        /// ERC20 multi sig signer removed
+        #[prost(message, tag = "144")]
+        Erc20MultisigSignerRemoved(super::Erc20MultiSigSignerRemoved),
+        /// Position status for a party in a market
+        #[prost(message, tag = "145")]
+        PositionStateEvent(super::PositionStateEvent),
+        /// Ethereum key rotation took place
+        #[prost(message, tag = "146")]
+        EthereumKeyRotation(super::EthereumKeyRotation),
+        /// Protocol upgrade proposal updates
+        #[prost(message, tag = "147")]
+        ProtocolUpgradeEvent(super::ProtocolUpgradeEvent),
+        /// Core is starting to process a new block
+        #[prost(message, tag = "148")]
+        BeginBlock(super::BeginBlock),
+        /// Core finished processing a block
+        #[prost(message, tag = "149")]
+        EndBlock(super::EndBlock),
+        /// Core is starting a protocol upgrade
+        #[prost(message, tag = "150")]
+        ProtocolUpgradeStarted(super::ProtocolUpgradeStarted),
+        /// Settle market event for data-node to update positions for settled markets
+        #[prost(message, tag = "151")]
+        SettleMarket(super::SettleMarket),
+        /// Result of a transaction processed by the network
+        #[prost(message, tag = "152")]
+        TransactionResult(super::TransactionResult),
+        /// Core snapshot has been taken at the end of the block
+        #[prost(message, tag = "153")]
+        CoreSnapshotEvent(super::CoreSnapshotData),
+        /// Core snapshot has been taken at the end of the block
+        #[prost(message, tag = "154")]
+        ProtocolUpgradeDataNodeReady(super::ProtocolUpgradeDataNodeReady),
+        /// Parties that had their orders closed because they were distressed
+        #[prost(message, tag = "155")]
+        DistressedOrders(super::DistressedOrders),
+        /// Orders that expired for a given market
+        #[prost(message, tag = "156")]
+        ExpiredOrders(super::ExpiredOrders),
+        /// Open positions on the market that are/were distressed
+        #[prost(message, tag = "157")]
+        DistressedPositions(super::DistressedPositions),
+        /// A stop order event
+        #[prost(message, tag = "158")]
+        StopOrder(super::StopOrderEvent),
+        /// Start or end of a funding period.
+        #[prost(message, tag = "159")]
+        FundingPeriod(super::FundingPeriod),
+        /// Data point within a funding period.
+        #[prost(message, tag = "160")]
+        FundingPeriodDataPoint(super::FundingPeriodDataPoint),
+        /// Event notifying of the creation of a team.m
+        #[prost(message, tag = "161")]
+        TeamCreated(super::TeamCreated),
+        /// Event notifying of an update to a team.
+        #[prost(message, tag = "162")]
+        TeamUpdated(super::TeamUpdated),
+        /// Event notifying that a referee switched teams.
+        #[prost(message, tag = "163")]
+        RefereeSwitchedTeam(super::RefereeSwitchedTeam),
+        /// Event notifying that a referee joined a team.
+        #[prost(message, tag = "164")]
+        RefereeJoinedTeam(super::RefereeJoinedTeam),
+        /// Event notifying that a referral program has started.
+        #[prost(message, tag = "165")]
+        ReferralProgramStarted(super::ReferralProgramStarted),
+        /// Event notifying that a referral program has been updated.
+        #[prost(message, tag = "166")]
+        ReferralProgramUpdated(super::ReferralProgramUpdated),
+        /// Event notifying that a referral program has ended.
+        #[prost(message, tag = "167")]
+        ReferralProgramEnded(super::ReferralProgramEnded),
+        /// Event notifying a referral set has been created.
+        #[prost(message, tag = "168")]
+        ReferralSetCreated(super::ReferralSetCreated),
+        /// Event notifying a referee has joined a referral set.
+        #[prost(message, tag = "169")]
+        RefereeJoinedReferralSet(super::RefereeJoinedReferralSet),
+        /// Event notifying of an update to a party's activity streak
+        #[prost(message, tag = "170")]
+        PartyActivityStreak(super::PartyActivityStreak),
+        /// Event notifying that a volume discount program has started.
+        #[prost(message, tag = "171")]
+        VolumeDiscountProgramStarted(super::VolumeDiscountProgramStarted),
+        /// Event notifying that a volume discount program has been updated.
+        #[prost(message, tag = "172")]
+        VolumeDiscountProgramUpdated(super::VolumeDiscountProgramUpdated),
+        /// Event notifying that a volume discount program has ended.
+        #[prost(message, tag = "173")]
+        VolumeDiscountProgramEnded(super::VolumeDiscountProgramEnded),
+        /// Event notifying of an update to a referral set's statistics.
+        #[prost(message, tag = "174")]
+        ReferralSetStatsUpdated(super::ReferralSetStatsUpdated),
+        /// Event notifying of an update to the vesting statistics.
+        #[prost(message, tag = "175")]
+        VestingStatsUpdated(super::VestingStatsUpdated),
+        /// Event notifying of an update to the volume discount statistics.
+        #[prost(message, tag = "176")]
+        VolumeDiscountStatsUpdated(super::VolumeDiscountStatsUpdated),
+        /// Event notifying of an update the fees stats for a market.
+        #[prost(message, tag = "177")]
+        FeesStats(super::FeesStats),
+        /// Event notifying of funding payments at the end of a funding period.
+        #[prost(message, tag = "178")]
+        FundingPayments(super::FundingPayments),
+        /// Event notifying of an update to the liqudity fees stats for a market.
+        #[prost(message, tag = "179")]
+        PaidLiquidityFeesStats(super::PaidLiquidityFeesStats),
+        /// Event notifying of an update to the vesting and locked balances.
+        #[prost(message, tag = "180")]
+        VestingBalancesSummary(super::VestingBalancesSummary),
+        /// Event notifying of fees related to a transfer being paid.
+        #[prost(message, tag = "181")]
+        TransferFees(super::TransferFees),
+        /// Event notifying of a party's available discounts for transfer fees, per asset.
+        #[prost(message, tag = "182")]
+        TransferFeesDiscount(super::TransferFeesDiscount),
+        /// Event notifying of a party's margin mode update on a market.
+        #[prost(message, tag = "183")]
+        PartyMarginModeUpdated(super::PartyMarginModeUpdated),
+        /// Event notifying of a party's profile update.
+        #[prost(message, tag = "184")]
+        PartyProfileUpdated(super::PartyProfileUpdated),
+        /// Event notifying of an update of teams' statistics.
+        #[prost(message, tag = "185")]
+        TeamsStatsUpdated(super::TeamsStatsUpdated),
+        /// Event notifying an update of the time weighted notional position for a party in an asset
+        #[prost(message, tag = "186")]
+        TimeWeightedNotionalPositionUpdated(super::TimeWeightedNotionalPositionUpdated),
+        /// Event notifying of multiple orders being cancelled for a given party, on a given market.
+        #[prost(message, tag = "187")]
+        CancelledOrders(super::CancelledOrders),
+        /// Event notifying on near realtime game scores for parties and teams.
+        #[prost(message, tag = "188")]
+        GameScores(super::GameScores),
+        /// Event notifying of AMM updates.
+        #[prost(message, tag = "189")]
+        Amm(super::Amm),
+        /// Event notifying that a volume rebate program has started.
+        #[prost(message, tag = "190")]
+        VolumeRebateProgramStarted(super::VolumeRebateProgramStarted),
+        /// Event notifying that a volume rebate program has been updated.
+        #[prost(message, tag = "191")]
+        VolumeRebateProgramUpdated(super::VolumeRebateProgramUpdated),
+        /// Event notifying that a volume rebate program has ended.
+        #[prost(message, tag = "192")]
+        VolumeRebateProgramEnded(super::VolumeRebateProgramEnded),
+        /// Event notifying of an update to the volume rebate statistics.
+        #[prost(message, tag = "193")]
+        VolumeRebateStatsUpdated(super::VolumeRebateStatsUpdated),
+        /// Market tick events
+        #[prost(message, tag = "1001")]
+        Market(super::MarketEvent),
+        /// Transaction error events, not included in the ALL event type
+        #[prost(message, tag = "2001")]
+        TxErrEvent(super::TxErrorEvent),
+    }
+}
+/// Stats of all parties eligible for volume rebate.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VolumeRebateStatsUpdated {
+    /// Epoch at which the volume rebate statistics are updated.
+    #[prost(uint64, tag = "1")]
+    pub at_epoch: u64,
+    /// All parties' stats.
+    #[prost(message, repeated, tag = "2")]
+    pub stats: ::prost::alloc::vec::Vec<PartyVolumeRebateStats>,
+}
+/// Volume rebate stats for a given party.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PartyVolumeRebateStats {
+    /// Party ID.
+    #[prost(string, tag = "1")]
+    pub party_id: ::prost::alloc::string::String,
+    /// Rebate factor applied to fees.
+    #[prost(string, tag = "2")]
+    pub additional_rebate: ::prost::alloc::string::String,
+    /// The party's maker volume fraction.
+    #[prost(string, tag = "3")]
+    pub maker_volume_fraction: ::prost::alloc::string::String,
+    /// The party's maker fee received over the window across all markets and assets, expressed in quantum.
+    #[prost(string, tag = "4")]
+    pub maker_fees_received: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VolumeRebateProgramStarted {
+    /// Volume rebate program that has started.
+    #[prost(message, optional, tag = "1")]
+    pub program: ::core::option::Option<super::super::VolumeRebateProgram>,
+    /// Time in Unix nanoseconds when the volume discount program started.
+    #[prost(int64, tag = "2")]
+    pub started_at: i64,
+    /// Epoch at which the volume discount program started.
+    #[prost(uint64, tag = "3")]
+    pub at_epoch: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VolumeRebateProgramUpdated {
+    /// The updated volume rebate program.
+    #[prost(message, optional, tag = "1")]
+    pub program: ::core::option::Option<super::super::VolumeRebateProgram>,
+    /// Time in Unix nanoseconds when the volume discount program was updated.
+    #[prost(int64, tag = "2")]
+    pub updated_at: i64,
+    /// Epoch at which the volume discount program was updated.
+    #[prost(uint64, tag = "3")]
+    pub at_epoch: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VolumeRebateProgramEnded {
+    /// Program update version.
+    #[prost(uint64, tag = "1")]
+    pub version: u64,
+    /// Unique ID of the volume discount program.
+    #[prost(string, tag = "2")]
+    pub id: ::prost::alloc::string::String,
+    /// Time in Unix nanoseconds when the referral program ended.
+    #[prost(int64, tag = "3")]
+    pub ended_at: i64,
+    /// Epoch at which the referral program ended.
+    #[prost(uint64, tag = "4")]
+    pub at_epoch: u64,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ProtocolUpgradeProposalStatus {
+    Unspecified = 0,
+    /// The proposal is pending
+    Pending = 1,
+    /// The proposal is approved
+    Approved = 2,
+    /// The proposal is rejected
+    Rejected = 3,
+}
+impl ProtocolUpgradeProposalStatus {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            ProtocolUpgradeProposalStatus::Unspecified => {
+                "PROTOCOL_UPGRADE_PROPOSAL_STATUS_UNSPECIFIED"
+            }
+            ProtocolUpgradeProposalStatus::Pending => {
+                "PROTOCOL_UPGRADE_PROPOSAL_STATUS_PENDING"
+            }
+            ProtocolUpgradeProposalStatus::Approved => {
+                "PROTOCOL_UPGRADE_PROPOSAL_STATUS_APPROVED"
+            }
+            ProtocolUpgradeProposalStatus::Rejected => {
+                "PROTOCOL_UPGRADE_PROPOSAL_STATUS_REJECTED"
+            }
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "PROTOCOL_UPGRADE_PROPOSAL_STATUS_UNSPECIFIED" => Some(Self::Unspecified),
+            "PROTOCOL_UPGRADE_PROPOSAL_STATUS_PENDING" => Some(Self::Pending),
+            "PROTOCOL_UPGRADE_PROPOSAL_STATUS_APPROVED" => Some(Self::Approved),
+            "PROTOCOL_UPGRADE_PROPOSAL_STATUS_REJECTED" => Some(Self::Rejected),
+            _ => None,
+        }
+    }
+}
+/// Bus event type is used to specify a type of event
+/// It has 2 styles of event:
+/// Single values (e.g. BUS_EVENT_TYPE_ORDER) where they represent one data item
+/// Group values (e.g. BUS_EVENT_TYPE_AUCTION) where they represent a group of data items
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum BusEventType {
+    /// Default value, always invalid
+    Unspecified = 0,
+    /// Events of ALL event types, used when filtering stream from event bus
+    All = 1,
+    /// Event for blockchain time updates
+    TimeUpdate = 2,
+    /// Event for when a transfer happens internally, contains the transfer information
+    LedgerMovements = 3,
+    /// Event indicating position resolution has occurred
+    PositionResolution = 4,
+    /// Event for order updates, both new and existing orders
+    Order = 5,
+    /// Event for account updates
+    Account = 6,
+    /// Event for party updates
+    Party = 7,
+    /// Event indicating a new trade has occurred
+    Trade = 8,
+    /// Event indicating margin levels have changed for a party
+    MarginLevels = 9,
+    /// Event for proposal updates (for governance)
+    Proposal = 10,
+    /// Event indicating a new vote has occurred (for governance)
+    Vote = 11,
+    /// Event for market data updates
+    MarketData = 12,
+    /// Event for a new signature for a Vega node
+    NodeSignature = 13,
+    /// Event indicating loss socialisation occurred for a party
+    LossSocialization = 14,
+    /// Event for when a position is being settled
+    SettlePosition = 15,
+    /// Event for when a position is distressed
+    SettleDistressed = 16,
+    /// Event indicating a new market was created
+    MarketCreated = 17,
+    /// Event for when an asset is added to Vega
+    Asset = 18,
+    /// Event indicating a market tick event
+    MarketTick = 19,
+    /// Event for when a withdrawal occurs
+    Withdrawal = 20,
+    /// Event for when a deposit occurs
+    Deposit = 21,
+    /// Event indicating a change in auction state, for example starting or ending an auction
+    Auction = 22,
+    /// Event indicating a risk factor has been updated
+    RiskFactor = 23,
+    /// Event indicating a network parameter has been added or updated
+    NetworkParameter = 24,
+    /// Event indicating a liquidity provision has been created or updated
+    LiquidityProvision = 25,
+    /// Event indicating a new market was created
+    MarketUpdated = 26,
+    /// Event indicating an oracle spec has been created or updated
+    OracleSpec = 27,
+    /// Event indicating that an oracle data has been broadcast
+    OracleData = 28,
+    /// Event indicating that an delegation balance of a party to a node for current epoch has changed
+    DelegationBalance = 29,
+    /// Event indicating the validator score for the given epoch
+    ValidatorScore = 30,
+    /// Event indicating the start or end of an epoch
+    EpochUpdate = 31,
+    /// Event indicating that validator node has been updated
+    ValidatorUpdate = 32,
+    /// Event indicating a new staking event have been processed by the network
+    StakeLinking = 33,
+    /// Event indicating the payout of a reward has been initiated
+    RewardPayoutEvent = 34,
+    /// Event indicating a new checkpoint was created
+    Checkpoint = 35,
+    /// Event indicating stream is starting
+    StreamStart = 36,
+    /// Event indicating key rotation took place
+    KeyRotation = 37,
+    /// Event indicating state transitions in state variable consensus
+    StateVar = 38,
+    /// Event indicating network limits set or updated
+    NetworkLimits = 39,
+    /// Event indicating a update for a transfer
+    Transfer = 40,
+    /// Event indicating the ranking of validator and their status in Vega
+    ValidatorRanking = 41,
+    /// Event indicating a new multi sig signer event have been processed
+    Erc20MultiSigSignerEvent = 42,
+    /// Event indicating the erc20 multi sig threshold have been updated
+    Erc20MultiSigSetThreshold = 43,
+    /// Event indicating a new signer has been added to the ERC-20 multisig
+    Erc20MultiSigSignerAdded = 44,
+    /// Event indicating a signer has been removed from the ERC-20 multisig
+    Erc20MultiSigSignerRemoved = 45,
+    /// Event indicating that a party's position has changed
+    PositionState = 46,
+    /// Event indicating Ethereum key rotation took place
+    EthereumKeyRotation = 47,
+    /// Event indicating protocol upgrade proposal updates
+    ProtocolUpgradeProposal = 48,
+    /// Event indicating the core is starting to process a new block
+    BeginBlock = 49,
+    /// Event indicating the core finished to process a block
+    EndBlock = 50,
+    /// Event indicating the core is starting a protocol upgrade
+    ProtocolUpgradeStarted = 51,
+    /// Event indicating the market has stopped and settled
+    SettleMarket = 52,
+    /// Event indicating the result of a transaction processed by the network
+    TransactionResult = 53,
+    /// Event indicating a snapshot was taken at this block height
+    SnapshotTaken = 54,
+    /// Event data node uses to notify that it is ready to upgrade
+    ProtocolUpgradeDataNodeReady = 55,
+    /// Event indicating parties had orders closed because they were distressed, but were not closed out.
+    DistressedOrdersClosed = 56,
+    /// Event indicating parties had orders closed because they were distressed, but were not closed out.
+    ExpiredOrders = 57,
+    /// Event indicating parties have become, or were, distressed but still have an active position.
+    DistressedPositions = 58,
+    /// Event indicating a spot liquidity provision has been created or updated.
+    SpotLiquidityProvision = 59,
+    /// Event indicating a stop order has been submitted or updated.
+    StopOrder = 60,
+    /// Event indicating the start or end of a funding period.
+    FundingPeriod = 61,
+    /// Event indicating a data point for a funding period has been received.
+    FundingPeriodDataPoint = 62,
+    /// Event indicating a team has been created.
+    TeamCreated = 63,
+    /// Event indicating a team has been updated.
+    TeamUpdated = 64,
+    /// Event indicating a referee switched team.
+    RefereeSwitchedTeam = 65,
+    /// Event indicating a referee joined a team.
+    RefereeJoinedTeam = 66,
+    /// Event indicating a referral program started.
+    ReferralProgramStarted = 67,
+    /// Event indicating a referral program has been updated.
+    ReferralProgramUpdated = 68,
+    /// Event indicating a referral program ended.
+    ReferralProgramEnded = 69,
+    /// Event indicating a set has been created.
+    ReferralSetCreated = 70,
+    /// Event indicating a referee joined a set.
+    RefereeJoinedReferralSet = 71,
+    /// Event indicating the updated activity streak for a party
+    PartyActivityStreak = 72,
+    /// Event indicating a volume discount program started.
+    VolumeDiscountProgramStarted = 73,
+    /// Event indicating a volume discount program has been updated.
+    VolumeDiscountProgramUpdated = 74,
+    /// Event indicating a volume discount program ended.
+    VolumeDiscountProgramEnded = 75,
+    /// Event indicating the updated statistics for a referral set.
+    ReferralSetStatsUpdated = 76,
+    /// Event indicating the updated statistics for the vesting program.
+    VestingStatsUpdated = 77,
+    /// Event indicating the updated statistics for the volume discount.
+    VolumeDiscountStatsUpdated = 78,
+    /// Event indicating the fees statistics per market at end of epoch
+    FeesStatsUpdated = 79,
+    /// Event indicating a funding period has ended and resulted in funding payment transfers.
+    FundingPayments = 80,
+    /// Event used to report the updated paid liquidity fee statistics for the market at the end of the epoch
+    PaidLiquidityFeesStatsUpdated = 81,
+    /// Event used to report the summary of vesting and locked balances at the end of the epoch
+    VestingSummary = 82,
+    /// Event used to link ledger entries to the transfer that triggered the fees being collected.
+    TransferFeesPaid = 83,
+    /// Event indicating that a party's available transfer fee discount has changed, per asset.
+    TransferFeesDiscountUpdated = 84,
+    /// Event indicating that a party updated their margin mode on a market.
+    PartyMarginModeUpdated = 85,
+    /// Event indicating that a party updated their profile.
+    PartyProfileUpdated = 86,
+    /// Event indicating that teams' statistics have been updated.
+    TeamsStatsUpdated = 87,
+    /// Event indicating time weighted notional position has been updated.
+    TimeWeightedNotionalPositionUpdated = 88,
+    /// Event containing the IDs of orders cancelled by a party on a market.
+    CancelledOrders = 89,
+    /// Event containing the near realtime game scores for parties and teams.
+    GameScores = 90,
+    /// Event use to notify for an AMM update.
+    Amm = 91,
+    /// Event indicating a volume rebate program started.
+    VolumeRebateProgramStarted = 92,
+    /// Event indicating a volume rebate program has been updated.
+    VolumeRebateProgramUpdated = 93,
+    /// Event indicating a volume rebate program ended.
+    VolumeRebateProgramEnded = 94,
+    /// Event indicating the updated statistics for the volume rebate.
+    VolumeRebateStatsUpdated = 95,
+    /// Event indicating a market related event, for example when a market opens
+    Market = 101,
+    /// Event used to report failed transactions back to a user, this is excluded from the ALL type
+    TxError = 201,
+}
+impl BusEventType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            BusEventType::Unspecified => "BUS_EVENT_TYPE_UNSPECIFIED",
+            BusEventType::All => "BUS_EVENT_TYPE_ALL",
+            BusEventType::TimeUpdate => "BUS_EVENT_TYPE_TIME_UPDATE",
+            BusEventType::LedgerMovements => "BUS_EVENT_TYPE_LEDGER_MOVEMENTS",
+            BusEventType::PositionResolution => "BUS_EVENT_TYPE_POSITION_RESOLUTION",
+            BusEventType::Order => "BUS_EVENT_TYPE_ORDER",
+            BusEventType::Account => "BUS_EVENT_TYPE_ACCOUNT",
+            BusEventType::Party => "BUS_EVENT_TYPE_PARTY",
+            BusEventType::Trade => "BUS_EVENT_TYPE_TRADE",
+            BusEventType::MarginLevels => "BUS_EVENT_TYPE_MARGIN_LEVELS",
+            BusEventType::Proposal => "BUS_EVENT_TYPE_PROPOSAL",
+            BusEventType::Vote => "BUS_EVENT_TYPE_VOTE",
+            BusEventType::MarketData => "BUS_EVENT_TYPE_MARKET_DATA",
+            BusEventType::NodeSignature => "BUS_EVENT_TYPE_NODE_SIGNATURE",
+            BusEventType::LossSocialization => "BUS_EVENT_TYPE_LOSS_SOCIALIZATION",
+            BusEventType::SettlePosition => "BUS_EVENT_TYPE_SETTLE_POSITION",
+            BusEventType::SettleDistressed => "BUS_EVENT_TYPE_SETTLE_DISTRESSED",
+            BusEventType::MarketCreated => "BUS_EVENT_TYPE_MARKET_CREATED",
+            BusEventType::Asset => "BUS_EVENT_TYPE_ASSET",
+            BusEventType::MarketTick => "BUS_EVENT_TYPE_MARKET_TICK",
+            BusEventType::Withdrawal => "BUS_EVENT_TYPE_WITHDRAWAL",
+            BusEventType::Deposit => "BUS_EVENT_TYPE_DEPOSIT",
+            BusEventType::Auction => "BUS_EVENT_TYPE_AUCTION",
+            BusEventType::RiskFactor => "BUS_EVENT_TYPE_RISK_FACTOR",
+            BusEventType::NetworkParameter => "BUS_EVENT_TYPE_NETWORK_PARAMETER",
+            BusEventType::LiquidityProvision => "BUS_EVENT_TYPE_LIQUIDITY_PROVISION",
+            BusEventType::MarketUpdated => "BUS_EVENT_TYPE_MARKET_UPDATED",
+            BusEventType::OracleSpec => "BUS_EVENT_TYPE_ORACLE_SPEC",
+            BusEventType::OracleData => "BUS_EVENT_TYPE_ORACLE_DATA",
+            BusEventType::DelegationBalance => "BUS_EVENT_TYPE_DELEGATION_BALANCE",
+            BusEventType::ValidatorScore => "BUS_EVENT_TYPE_VALIDATOR_SCORE",
+            BusEventType::EpochUpdate => "BUS_EVENT_TYPE_EPOCH_UPDATE",
+            BusEventType::ValidatorUpdate => "BUS_EVENT_TYPE_VALIDATOR_UPDATE",
+            BusEventType::StakeLinking => "BUS_EVENT_TYPE_STAKE_LINKING",
+            BusEventType::RewardPayoutEvent => "BUS_EVENT_TYPE_REWARD_PAYOUT_EVENT",
+            BusEventType::Checkpoint => "BUS_EVENT_TYPE_CHECKPOINT",
+            BusEventType::StreamStart => "BUS_EVENT_TYPE_STREAM_START",
+            BusEventType::KeyRotation => "BUS_EVENT_TYPE_KEY_ROTATION",
+            BusEventType::StateVar => "BUS_EVENT_TYPE_STATE_VAR",
+            BusEventType::NetworkLimits => "BUS_EVENT_TYPE_NETWORK_LIMITS",
+            BusEventType::Transfer => "BUS_EVENT_TYPE_TRANSFER",
+            BusEventType::ValidatorRanking => "BUS_EVENT_TYPE_VALIDATOR_RANKING",
+            BusEventType::Erc20MultiSigSignerEvent => {
+                "BUS_EVENT_TYPE_ERC20_MULTI_SIG_SIGNER_EVENT"
+            }
+            BusEventType::Erc20MultiSigSetThreshold => {
+                "BUS_EVENT_TYPE_ERC20_MULTI_SIG_SET_THRESHOLD"
+            }
+            BusEventType::Erc20MultiSigSignerAdded => {
+                "BUS_EVENT_TYPE_ERC20_MULTI_SIG_SIGNER_ADDED"
+            }
+            BusEventType::Erc20MultiSigSignerRemoved => {
+                "BUS_EVENT_TYPE_ERC20_MULTI_SIG_SIGNER_REMOVED"
+            }
+            BusEventType::PositionState => "BUS_EVENT_TYPE_POSITION_STATE",
+            BusEventType::EthereumKeyRotation => "BUS_EVENT_TYPE_ETHEREUM_KEY_ROTATION",
+            BusEventType::ProtocolUpgradeProposal => {
+                "BUS_EVENT_TYPE_PROTOCOL_UPGRADE_PROPOSAL"
+            }
+            BusEventType::BeginBlock => "BUS_EVENT_TYPE_BEGIN_BLOCK",
+            BusEventType::EndBlock => "BUS_EVENT_TYPE_END_BLOCK",
+            BusEventType::ProtocolUpgradeStarted => {
+                "BUS_EVENT_TYPE_PROTOCOL_UPGRADE_STARTED"
+            }
+            BusEventType::SettleMarket => "BUS_EVENT_TYPE_SETTLE_MARKET",
+            BusEventType::TransactionResult => "BUS_EVENT_TYPE_TRANSACTION_RESULT",
+            BusEventType::SnapshotTaken => "BUS_EVENT_TYPE_SNAPSHOT_TAKEN",
+            BusEventType::ProtocolUpgradeDataNodeReady => {
+                "BUS_EVENT_TYPE_PROTOCOL_UPGRADE_DATA_NODE_READY"
+            }
+            BusEventType::DistressedOrdersClosed => {
+                "BUS_EVENT_TYPE_DISTRESSED_ORDERS_CLOSED"
+            }
+            BusEventType::ExpiredOrders => "BUS_EVENT_TYPE_EXPIRED_ORDERS",
+            BusEventType::DistressedPositions => "BUS_EVENT_TYPE_DISTRESSED_POSITIONS",
+            BusEventType::SpotLiquidityProvision => {
+                "BUS_EVENT_TYPE_SPOT_LIQUIDITY_PROVISION"
+            }
+            BusEventType::StopOrder => "BUS_EVENT_TYPE_STOP_ORDER",
+            BusEventType::FundingPeriod => "BUS_EVENT_TYPE_FUNDING_PERIOD",
+            BusEventType::FundingPeriodDataPoint => {
+                "BUS_EVENT_TYPE_FUNDING_PERIOD_DATA_POINT"
+            }
+            BusEventType::TeamCreated => "BUS_EVENT_TYPE_TEAM_CREATED",
+            BusEventType::TeamUpdated => "BUS_EVENT_TYPE_TEAM_UPDATED",
+            BusEventType::RefereeSwitchedTeam => "BUS_EVENT_TYPE_REFEREE_SWITCHED_TEAM",
+            BusEventType::RefereeJoinedTeam => "BUS_EVENT_TYPE_REFEREE_JOINED_TEAM",
+            BusEventType::ReferralProgramStarted => {
+                "BUS_EVENT_TYPE_REFERRAL_PROGRAM_STARTED"
+            }
+            BusEventType::ReferralProgramUpdated => {
+                "BUS_EVENT_TYPE_REFERRAL_PROGRAM_UPDATED"
+            }
+            BusEventType::ReferralProgramEnded => "BUS_EVENT_TYPE_REFERRAL_PROGRAM_ENDED",
+            BusEventType::ReferralSetCreated => "BUS_EVENT_TYPE_REFERRAL_SET_CREATED",
+            BusEventType::RefereeJoinedReferralSet => {
+                "BUS_EVENT_TYPE_REFEREE_JOINED_REFERRAL_SET"
+            }
+            BusEventType::PartyActivityStreak => "BUS_EVENT_TYPE_PARTY_ACTIVITY_STREAK",
+            BusEventType::VolumeDiscountProgramStarted => {
+                "BUS_EVENT_TYPE_VOLUME_DISCOUNT_PROGRAM_STARTED"
+            }
+            BusEventType::VolumeDiscountProgramUpdated => {
+                "BUS_EVENT_TYPE_VOLUME_DISCOUNT_PROGRAM_UPDATED"
+            }
+            BusEventType::VolumeDiscountProgramEnded => {
+                "BUS_EVENT_TYPE_VOLUME_DISCOUNT_PROGRAM_ENDED"
+            }
+            BusEventType::ReferralSetStatsUpdated => {
+                "BUS_EVENT_TYPE_REFERRAL_SET_STATS_UPDATED"
+            }
+            BusEventType::VestingStatsUpdated => "BUS_EVENT_TYPE_VESTING_STATS_UPDATED",
+            BusEventType::VolumeDiscountStatsUpdated => {
+                "BUS_EVENT_TYPE_VOLUME_DISCOUNT_STATS_UPDATED"
+            }
+            BusEventType::FeesStatsUpdated => "BUS_EVENT_TYPE_FEES_STATS_UPDATED",
+            BusEventType::FundingPayments => "BUS_EVENT_TYPE_FUNDING_PAYMENTS",
+            BusEventType::PaidLiquidityFeesStatsUpdated => {
+                "BUS_EVENT_TYPE_PAID_LIQUIDITY_FEES_STATS_UPDATED"
+            }
+            BusEventType::VestingSummary => "BUS_EVENT_TYPE_VESTING_SUMMARY",
+            BusEventType::TransferFeesPaid => "BUS_EVENT_TYPE_TRANSFER_FEES_PAID",
+            BusEventType::TransferFeesDiscountUpdated => {
+                "BUS_EVENT_TYPE_TRANSFER_FEES_DISCOUNT_UPDATED"
+            }
+            BusEventType::PartyMarginModeUpdated => {
+                "BUS_EVENT_TYPE_PARTY_MARGIN_MODE_UPDATED"
+            }
+            BusEventType::PartyProfileUpdated => "BUS_EVENT_TYPE_PARTY_PROFILE_UPDATED",
+            BusEventType::TeamsStatsUpdated => "BUS_EVENT_TYPE_TEAMS_STATS_UPDATED",
+            BusEventType::TimeWeightedNotionalPositionUpdated => {
+                "BUS_EVENT_TYPE_TIME_WEIGHTED_NOTIONAL_POSITION_UPDATED"
+            }
+            BusEventType::CancelledOrders => "BUS_EVENT_TYPE_CANCELLED_ORDERS",
+            BusEventType::GameScores => "BUS_EVENT_TYPE_GAME_SCORES",
+            BusEventType::Amm => "BUS_EVENT_TYPE_AMM",
+            BusEventType::VolumeRebateProgramStarted => {
+                "BUS_EVENT_TYPE_VOLUME_REBATE_PROGRAM_STARTED"
+            }
+            BusEventType::VolumeRebateProgramUpdated => {
+                "BUS_EVENT_TYPE_VOLUME_REBATE_PROGRAM_UPDATED"
+            }
+            BusEventType::VolumeRebateProgramEnded => {
+                "BUS_EVENT_TYPE_VOLUME_REBATE_PROGRAM_ENDED"
+            }
+            BusEventType::VolumeRebateStatsUpdated => {
+                "BUS_EVENT_TYPE_VOLUME_REBATE_STATS_UPDATED"
+            }
+            BusEventType::Market => "BUS_EVENT_TYPE_MARKET",
+            BusEventType::TxError => "BUS_EVENT_TYPE_TX_ERROR",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "BUS_EVENT_TYPE_UNSPECIFIED" => Some(Self::Unspecified),
+            "BUS_EVENT_TYPE_ALL" => Some(Self::All),
+            "BUS_EVENT_TYPE_TIME_UPDATE" => Some(Self::TimeUpdate),
+            "BUS_EVENT_TYPE_LEDGER_MOVEMENTS" => Some(Self::LedgerMovements),
+            "BUS_EVENT_TYPE_POSITION_RESOLUTION" => Some(Self::PositionResolution),
+            "BUS_EVENT_TYPE_ORDER" => Some(Self::Order),
+            "BUS_EVENT_TYPE_ACCOUNT" => Some(Self::Account),
+            "BUS_EVENT_TYPE_PARTY" => Some(Self::Party),
+            "BUS_EVENT_TYPE_TRADE" => Some(Self::Trade),
+            "BUS_EVENT_TYPE_MARGIN_LEVELS" => Some(Self::MarginLevels),
+            "BUS_EVENT_TYPE_PROPOSAL" => Some(Self::Proposal),
+            "BUS_EVENT_TYPE_VOTE" => Some(Self::Vote),
+            "BUS_EVENT_TYPE_MARKET_DATA" => Some(Self::MarketData),
+            "BUS_EVENT_TYPE_NODE_SIGNATURE" => Some(Self::NodeSignature),
+            "BUS_EVENT_TYPE_LOSS_SOCIALIZATION" => Some(Self::LossSocialization),
+            "BUS_EVENT_TYPE_SETTLE_POSITION" => Some(Self::SettlePosition),
+            "BUS_EVENT_TYPE_SETTLE_DISTRESSED" => Some(Self::SettleDistressed),
+            "BUS_EVENT_TYPE_MARKET_CREATED" => Some(Self::MarketCreated),
+            "BUS_EVENT_TYPE_ASSET" => Some(Self::Asset),
+            "BUS_EVENT_TYPE_MARKET_TICK" => Some(Self::MarketTick),
+            "BUS_EVENT_TYPE_WITHDRAWAL" => Some(Self::Withdrawal),
+            "BUS_EVENT_TYPE_DEPOSIT" => Some(Self::Deposit),
+            "BUS_EVENT_TYPE_AUCTION" => Some(Self::Auction),
+            "BUS_EVENT_TYPE_RISK_FACTOR" => Some(Self::RiskFactor),
+            "BUS_EVENT_TYPE_NETWORK_PARAMETER" => Some(Self::NetworkParameter),
+            "BUS_EVENT_TYPE_LIQUIDITY_PROVISION" => Some(Self::LiquidityProvision),
+            "BUS_EVENT_TYPE_MARKET_UPDATED" => Some(Self::MarketUpdated),
+            "BUS_EVENT_TYPE_ORACLE_SPEC" => Some(Self::OracleSpec),
+            "BUS_EVENT_TYPE_ORACLE_DATA" => Some(Self::OracleData),
+            "BUS_EVENT_TYPE_DELEGATION_BALANCE" => Some(Self::DelegationBalance),
+            "BUS_EVENT_TYPE_VALIDATOR_SCORE" => Some(Self::ValidatorScore),
+            "BUS_EVENT_TYPE_EPOCH_UPDATE" => Some(Self::EpochUpdate),
+            "BUS_EVENT_TYPE_VALIDATOR_UPDATE" => Some(Self::ValidatorUpdate),
+            "BUS_EVENT_TYPE_STAKE_LINKING" => Some(Self::StakeLinking),
+            "BUS_EVENT_TYPE_REWARD_PAYOUT_EVENT" => Some(Self::RewardPayoutEvent),
+            "BUS_EVENT_TYPE_CHECKPOINT" => Some(Self::Checkpoint),
+            "BUS_EVENT_TYPE_STREAM_START" => Some(Self::StreamStart),
+            "BUS_EVENT_TYPE_KEY_ROTATION" => Some(Self::KeyRotation),
+            "BUS_EVENT_TYPE_STATE_VAR" => Some(Self::StateVar),
+            "BUS_EVENT_TYPE_NETWORK_LIMITS" => Some(Self::NetworkLimits),
+            "BUS_EVENT_TYPE_TRANSFER" => Some(Self::Transfer),
+            "BUS_EVENT_TYPE_VALIDATOR_RANKING" => Some(Self::ValidatorRanking),
+            "BUS_EVENT_TYPE_ERC20_MULTI_SIG_SIGNER_EVENT" => {
+                Some(Self::Erc20MultiSigSignerEvent)
+            }
+            "BUS_EVENT_TYPE_ERC20_MULTI_SIG_SET_THRESHOLD" => {
+                Some(Self::Erc20MultiSigSetThreshold)
+            }
+            "BUS_EVENT_TYPE_ERC20_MULTI_SIG_SIGNER_ADDED" => {
+                Some(Self::Erc20MultiSigSignerAdded)
+            }
+            "BUS_EVENT_TYPE_ERC20_MULTI_SIG_SIGNER_REMOVED" => {
+                Some(Self::Erc20MultiSigSignerRemoved)
+            }
+            "BUS_EVENT_TYPE_POSITION_STATE" => Some(Self::PositionState),
+            "BUS_EVENT_TYPE_ETHEREUM_KEY_ROTATION" => Some(Self::EthereumKeyRotation),
+            "BUS_EVENT_TYPE_PROTOCOL_UPGRADE_PROPOSAL" => {
+                Some(Self::ProtocolUpgradeProposal)
+            }
+            "BUS_EVENT_TYPE_BEGIN_BLOCK" => Some(Self::BeginBlock),
+            "BUS_EVENT_TYPE_END_BLOCK" => Some(Self::EndBlock),
+            "BUS_EVENT_TYPE_PROTOCOL_UPGRADE_STARTED" => {
+                Some(Self::ProtocolUpgradeStarted)
+            }
+            "BUS_EVENT_TYPE_SETTLE_MARKET" => Some(Self::SettleMarket),
+            "BUS_EVENT_TYPE_TRANSACTION_RESULT" => Some(Self::TransactionResult),
+            "BUS_EVENT_TYPE_SNAPSHOT_TAKEN" => Some(Self::SnapshotTaken),
+            "BUS_EVENT_TYPE_PROTOCOL_UPGRADE_DATA_NODE_READY" => {
+                Some(Self::ProtocolUpgradeDataNodeReady)
+            }
+            "BUS_EVENT_TYPE_DISTRESSED_ORDERS_CLOSED" => {
+                Some(Self::DistressedOrdersClosed)
+            }
+            "BUS_EVENT_TYPE_EXPIRED_ORDERS" => Some(Self::ExpiredOrders),
+            "BUS_EVENT_TYPE_DISTRESSED_POSITIONS" => Some(Self::DistressedPositions),
+            "BUS_EVENT_TYPE_SPOT_LIQUIDITY_PROVISION" => {
+                Some(Self::SpotLiquidityProvision)
+            }
+            "BUS_EVENT_TYPE_STOP_ORDER" => Some(Self::StopOrder),
+            "BUS_EVENT_TYPE_FUNDING_PERIOD" => Some(Self::FundingPeriod),
+            "BUS_EVENT_TYPE_FUNDING_PERIOD_DATA_POINT" => {
+                Some(Self::FundingPeriodDataPoint)
+            }
+            "BUS_EVENT_TYPE_TEAM_CREATED" => Some(Self::TeamCreated),
+            "BUS_EVENT_TYPE_TEAM_UPDATED" => Some(Self::TeamUpdated),
+            "BUS_EVENT_TYPE_REFEREE_SWITCHED_TEAM" => Some(Self::RefereeSwitchedTeam),
+            "BUS_EVENT_TYPE_REFEREE_JOINED_TEAM" => Some(Self::RefereeJoinedTeam),
+            "BUS_EVENT_TYPE_REFERRAL_PROGRAM_STARTED" => {
+                Some(Self::ReferralProgramStarted)
+            }
+            "BUS_EVENT_TYPE_REFERRAL_PROGRAM_UPDATED" => {
+                Some(Self::ReferralProgramUpdated)
+            }
+            "BUS_EVENT_TYPE_REFERRAL_PROGRAM_ENDED" => Some(Self::ReferralProgramEnded),
+            "BUS_EVENT_TYPE_REFERRAL_SET_CREATED" => Some(Self::ReferralSetCreated),
+            "BUS_EVENT_TYPE_REFEREE_JOINED_REFERRAL_SET" => {
+                Some(Self::RefereeJoinedReferralSet)
+            }
+            "BUS_EVENT_TYPE_PARTY_ACTIVITY_STREAK" => Some(Self::PartyActivityStreak),
+            "BUS_EVENT_TYPE_VOLUME_DISCOUNT_PROGRAM_STARTED" => {
+                Some(Self::VolumeDiscountProgramStarted)
+            }
+            "BUS_EVENT_TYPE_VOLUME_DISCOUNT_PROGRAM_UPDATED" => {
+                Some(Self::VolumeDiscountProgramUpdated)
+            }
+            "BUS_EVENT_TYPE_VOLUME_DISCOUNT_PROGRAM_ENDED" => {
+                Some(Self::VolumeDiscountProgramEnded)
+            }
+            "BUS_EVENT_TYPE_REFERRAL_SET_STATS_UPDATED" => {
+                Some(Self::ReferralSetStatsUpdated)
+            }
+            "BUS_EVENT_TYPE_VESTING_STATS_UPDATED" => Some(Self::VestingStatsUpdated),
+            "BUS_EVENT_TYPE_VOLUME_DISCOUNT_STATS_UPDATED" => {
+                Some(Self::VolumeDiscountStatsUpdated)
+            }
+            "BUS_EVENT_TYPE_FEES_STATS_UPDATED" => Some(Self::FeesStatsUpdated),
+            "BUS_EVENT_TYPE_FUNDING_PAYMENTS" => Some(Self::FundingPayments),
+            "BUS_EVENT_TYPE_PAID_LIQUIDITY_FEES_STATS_UPDATED" => {
+                Some(Self::PaidLiquidityFeesStatsUpdated)
+            }
+            "BUS_EVENT_TYPE_VESTING_SUMMARY" => Some(Self::VestingSummary),
+            "BUS_EVENT_TYPE_TRANSFER_FEES_PAID" => Some(Self::TransferFeesPaid),
+            "BUS_EVENT_TYPE_TRANSFER_FEES_DISCOUNT_UPDATED" => {
+                Some(Self::TransferFeesDiscountUpdated)
+            }
+            "BUS_EVENT_TYPE_PARTY_MARGIN_MODE_UPDATED" => {
+                Some(Self::PartyMarginModeUpdated)
+            }
+            "BUS_EVENT_TYPE_PARTY_PROFILE_UPDATED" => Some(Self::PartyProfileUpdated),
+            "BUS_EVENT_TYPE_TEAMS_STATS_UPDATED" => Some(Self::TeamsStatsUpdated),
+            "BUS_EVENT_TYPE_TIME_WEIGHTED_NOTIONAL_POSITION_UPDATED" => {
+                Some(Self::TimeWeightedNotionalPositionUpdated)
+            }
+            "BUS_EVENT_TYPE_CANCELLED_ORDERS" => Some(Self::CancelledOrders),
+            "BUS_EVENT_TYPE_GAME_SCORES" => Some(Self::GameScores),
+            "BUS_EVENT_TYPE_AMM" => Some(Self::Amm),
+            "BUS_EVENT_TYPE_VOLUME_REBATE_PROGRAM_STARTED" => {
+                Some(Self::VolumeRebateProgramStarted)
+            }
+            "BUS_EVENT_TYPE_VOLUME_REBATE_PROGRAM_UPDATED" => {
+                Some(Self::VolumeRebateProgramUpdated)
+            }
+            "BUS_EVENT_TYPE_VOLUME_REBATE_PROGRAM_ENDED" => {
+                Some(Self::VolumeRebateProgramEnded)
+            }
+            "BUS_EVENT_TYPE_VOLUME_REBATE_STATS_UPDATED" => {
+                Some(Self::VolumeRebateStatsUpdated)
+            }
+            "BUS_EVENT_TYPE_MARKET" => Some(Self::Market),
+            "BUS_EVENT_TYPE_TX_ERROR" => Some(Self::TxError),
+            _ => None,
+        }
+    }
+}
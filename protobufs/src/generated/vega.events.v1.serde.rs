@@ -0,0 +1,18753 @@
+impl serde::Serialize for Amm {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.id.is_empty() {
+            len += 1;
+        }
+        if !self.party_id.is_empty() {
+            len += 1;
+        }
+        if !self.market_id.is_empty() {
+            len += 1;
+        }
+        if !self.amm_party_id.is_empty() {
+            len += 1;
+        }
+        if !self.commitment.is_empty() {
+            len += 1;
+        }
+        if self.parameters.is_some() {
+            len += 1;
+        }
+        if self.status != 0 {
+            len += 1;
+        }
+        if self.status_reason != 0 {
+            len += 1;
+        }
+        if !self.proposed_fee.is_empty() {
+            len += 1;
+        }
+        if self.lower_curve.is_some() {
+            len += 1;
+        }
+        if self.upper_curve.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.AMM", len)?;
+        if !self.id.is_empty() {
+            struct_ser.serialize_field("id", &self.id)?;
+        }
+        if !self.party_id.is_empty() {
+            struct_ser.serialize_field("partyId", &self.party_id)?;
+        }
+        if !self.market_id.is_empty() {
+            struct_ser.serialize_field("marketId", &self.market_id)?;
+        }
+        if !self.amm_party_id.is_empty() {
+            struct_ser.serialize_field("ammPartyId", &self.amm_party_id)?;
+        }
+        if !self.commitment.is_empty() {
+            struct_ser.serialize_field("commitment", &self.commitment)?;
+        }
+        if let Some(v) = self.parameters.as_ref() {
+            struct_ser.serialize_field("parameters", v)?;
+        }
+        if self.status != 0 {
+            let v = amm::Status::try_from(self.status)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.status)))?;
+            struct_ser.serialize_field("status", &v)?;
+        }
+        if self.status_reason != 0 {
+            let v = amm::StatusReason::try_from(self.status_reason)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.status_reason)))?;
+            struct_ser.serialize_field("statusReason", &v)?;
+        }
+        if !self.proposed_fee.is_empty() {
+            struct_ser.serialize_field("proposedFee", &self.proposed_fee)?;
+        }
+        if let Some(v) = self.lower_curve.as_ref() {
+            struct_ser.serialize_field("lowerCurve", v)?;
+        }
+        if let Some(v) = self.upper_curve.as_ref() {
+            struct_ser.serialize_field("upperCurve", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Amm {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "id",
+            "party_id",
+            "partyId",
+            "market_id",
+            "marketId",
+            "amm_party_id",
+            "ammPartyId",
+            "commitment",
+            "parameters",
+            "status",
+            "status_reason",
+            "statusReason",
+            "proposed_fee",
+            "proposedFee",
+            "lower_curve",
+            "lowerCurve",
+            "upper_curve",
+            "upperCurve",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Id,
+            PartyId,
+            MarketId,
+            AmmPartyId,
+            Commitment,
+            Parameters,
+            Status,
+            StatusReason,
+            ProposedFee,
+            LowerCurve,
+            UpperCurve,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "id" => Ok(GeneratedField::Id),
+                            "partyId" | "party_id" => Ok(GeneratedField::PartyId),
+                            "marketId" | "market_id" => Ok(GeneratedField::MarketId),
+                            "ammPartyId" | "amm_party_id" => Ok(GeneratedField::AmmPartyId),
+                            "commitment" => Ok(GeneratedField::Commitment),
+                            "parameters" => Ok(GeneratedField::Parameters),
+                            "status" => Ok(GeneratedField::Status),
+                            "statusReason" | "status_reason" => Ok(GeneratedField::StatusReason),
+                            "proposedFee" | "proposed_fee" => Ok(GeneratedField::ProposedFee),
+                            "lowerCurve" | "lower_curve" => Ok(GeneratedField::LowerCurve),
+                            "upperCurve" | "upper_curve" => Ok(GeneratedField::UpperCurve),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Amm;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.AMM")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Amm, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut id__ = None;
+                let mut party_id__ = None;
+                let mut market_id__ = None;
+                let mut amm_party_id__ = None;
+                let mut commitment__ = None;
+                let mut parameters__ = None;
+                let mut status__ = None;
+                let mut status_reason__ = None;
+                let mut proposed_fee__ = None;
+                let mut lower_curve__ = None;
+                let mut upper_curve__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::PartyId => {
+                            if party_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("partyId"));
+                            }
+                            party_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::MarketId => {
+                            if market_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("marketId"));
+                            }
+                            market_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::AmmPartyId => {
+                            if amm_party_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ammPartyId"));
+                            }
+                            amm_party_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Commitment => {
+                            if commitment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("commitment"));
+                            }
+                            commitment__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Parameters => {
+                            if parameters__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("parameters"));
+                            }
+                            parameters__ = map_.next_value()?;
+                        }
+                        GeneratedField::Status => {
+                            if status__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("status"));
+                            }
+                            status__ = Some(map_.next_value::<amm::Status>()? as i32);
+                        }
+                        GeneratedField::StatusReason => {
+                            if status_reason__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("statusReason"));
+                            }
+                            status_reason__ = Some(map_.next_value::<amm::StatusReason>()? as i32);
+                        }
+                        GeneratedField::ProposedFee => {
+                            if proposed_fee__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposedFee"));
+                            }
+                            proposed_fee__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::LowerCurve => {
+                            if lower_curve__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lowerCurve"));
+                            }
+                            lower_curve__ = map_.next_value()?;
+                        }
+                        GeneratedField::UpperCurve => {
+                            if upper_curve__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("upperCurve"));
+                            }
+                            upper_curve__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(Amm {
+                    id: id__.unwrap_or_default(),
+                    party_id: party_id__.unwrap_or_default(),
+                    market_id: market_id__.unwrap_or_default(),
+                    amm_party_id: amm_party_id__.unwrap_or_default(),
+                    commitment: commitment__.unwrap_or_default(),
+                    parameters: parameters__,
+                    status: status__.unwrap_or_default(),
+                    status_reason: status_reason__.unwrap_or_default(),
+                    proposed_fee: proposed_fee__.unwrap_or_default(),
+                    lower_curve: lower_curve__,
+                    upper_curve: upper_curve__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.AMM", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for amm::ConcentratedLiquidityParameters {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.base.is_empty() {
+            len += 1;
+        }
+        if self.lower_bound.is_some() {
+            len += 1;
+        }
+        if self.upper_bound.is_some() {
+            len += 1;
+        }
+        if self.leverage_at_upper_bound.is_some() {
+            len += 1;
+        }
+        if self.leverage_at_lower_bound.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.AMM.ConcentratedLiquidityParameters", len)?;
+        if !self.base.is_empty() {
+            struct_ser.serialize_field("base", &self.base)?;
+        }
+        if let Some(v) = self.lower_bound.as_ref() {
+            struct_ser.serialize_field("lowerBound", v)?;
+        }
+        if let Some(v) = self.upper_bound.as_ref() {
+            struct_ser.serialize_field("upperBound", v)?;
+        }
+        if let Some(v) = self.leverage_at_upper_bound.as_ref() {
+            struct_ser.serialize_field("leverageAtUpperBound", v)?;
+        }
+        if let Some(v) = self.leverage_at_lower_bound.as_ref() {
+            struct_ser.serialize_field("leverageAtLowerBound", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for amm::ConcentratedLiquidityParameters {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "base",
+            "lower_bound",
+            "lowerBound",
+            "upper_bound",
+            "upperBound",
+            "leverage_at_upper_bound",
+            "leverageAtUpperBound",
+            "leverage_at_lower_bound",
+            "leverageAtLowerBound",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Base,
+            LowerBound,
+            UpperBound,
+            LeverageAtUpperBound,
+            LeverageAtLowerBound,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "base" => Ok(GeneratedField::Base),
+                            "lowerBound" | "lower_bound" => Ok(GeneratedField::LowerBound),
+                            "upperBound" | "upper_bound" => Ok(GeneratedField::UpperBound),
+                            "leverageAtUpperBound" | "leverage_at_upper_bound" => Ok(GeneratedField::LeverageAtUpperBound),
+                            "leverageAtLowerBound" | "leverage_at_lower_bound" => Ok(GeneratedField::LeverageAtLowerBound),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = amm::ConcentratedLiquidityParameters;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.AMM.ConcentratedLiquidityParameters")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<amm::ConcentratedLiquidityParameters, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut base__ = None;
+                let mut lower_bound__ = None;
+                let mut upper_bound__ = None;
+                let mut leverage_at_upper_bound__ = None;
+                let mut leverage_at_lower_bound__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Base => {
+                            if base__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("base"));
+                            }
+                            base__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::LowerBound => {
+                            if lower_bound__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lowerBound"));
+                            }
+                            lower_bound__ = map_.next_value()?;
+                        }
+                        GeneratedField::UpperBound => {
+                            if upper_bound__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("upperBound"));
+                            }
+                            upper_bound__ = map_.next_value()?;
+                        }
+                        GeneratedField::LeverageAtUpperBound => {
+                            if leverage_at_upper_bound__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("leverageAtUpperBound"));
+                            }
+                            leverage_at_upper_bound__ = map_.next_value()?;
+                        }
+                        GeneratedField::LeverageAtLowerBound => {
+                            if leverage_at_lower_bound__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("leverageAtLowerBound"));
+                            }
+                            leverage_at_lower_bound__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(amm::ConcentratedLiquidityParameters {
+                    base: base__.unwrap_or_default(),
+                    lower_bound: lower_bound__,
+                    upper_bound: upper_bound__,
+                    leverage_at_upper_bound: leverage_at_upper_bound__,
+                    leverage_at_lower_bound: leverage_at_lower_bound__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.AMM.ConcentratedLiquidityParameters", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for amm::Curve {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.virtual_liquidity.is_empty() {
+            len += 1;
+        }
+        if !self.theoretical_position.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.AMM.Curve", len)?;
+        if !self.virtual_liquidity.is_empty() {
+            struct_ser.serialize_field("virtualLiquidity", &self.virtual_liquidity)?;
+        }
+        if !self.theoretical_position.is_empty() {
+            struct_ser.serialize_field("theoreticalPosition", &self.theoretical_position)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for amm::Curve {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "virtual_liquidity",
+            "virtualLiquidity",
+            "theoretical_position",
+            "theoreticalPosition",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            VirtualLiquidity,
+            TheoreticalPosition,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "virtualLiquidity" | "virtual_liquidity" => Ok(GeneratedField::VirtualLiquidity),
+                            "theoreticalPosition" | "theoretical_position" => Ok(GeneratedField::TheoreticalPosition),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = amm::Curve;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.AMM.Curve")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<amm::Curve, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut virtual_liquidity__ = None;
+                let mut theoretical_position__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::VirtualLiquidity => {
+                            if virtual_liquidity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("virtualLiquidity"));
+                            }
+                            virtual_liquidity__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::TheoreticalPosition => {
+                            if theoretical_position__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("theoreticalPosition"));
+                            }
+                            theoretical_position__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(amm::Curve {
+                    virtual_liquidity: virtual_liquidity__.unwrap_or_default(),
+                    theoretical_position: theoretical_position__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.AMM.Curve", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for amm::Status {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "STATUS_UNSPECIFIED",
+            Self::Active => "STATUS_ACTIVE",
+            Self::Rejected => "STATUS_REJECTED",
+            Self::Cancelled => "STATUS_CANCELLED",
+            Self::Stopped => "STATUS_STOPPED",
+            Self::ReduceOnly => "STATUS_REDUCE_ONLY",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for amm::Status {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "STATUS_UNSPECIFIED",
+            "STATUS_ACTIVE",
+            "STATUS_REJECTED",
+            "STATUS_CANCELLED",
+            "STATUS_STOPPED",
+            "STATUS_REDUCE_ONLY",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = amm::Status;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "STATUS_UNSPECIFIED" => Ok(amm::Status::Unspecified),
+                    "STATUS_ACTIVE" => Ok(amm::Status::Active),
+                    "STATUS_REJECTED" => Ok(amm::Status::Rejected),
+                    "STATUS_CANCELLED" => Ok(amm::Status::Cancelled),
+                    "STATUS_STOPPED" => Ok(amm::Status::Stopped),
+                    "STATUS_REDUCE_ONLY" => Ok(amm::Status::ReduceOnly),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for amm::StatusReason {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "STATUS_REASON_UNSPECIFIED",
+            Self::CancelledByParty => "STATUS_REASON_CANCELLED_BY_PARTY",
+            Self::CannotFillCommitment => "STATUS_REASON_CANNOT_FILL_COMMITMENT",
+            Self::PartyAlreadyOwnsAmmForMarket => "STATUS_REASON_PARTY_ALREADY_OWNS_AMM_FOR_MARKET",
+            Self::PartyClosedOut => "STATUS_REASON_PARTY_CLOSED_OUT",
+            Self::MarketClosed => "STATUS_REASON_MARKET_CLOSED",
+            Self::CommitmentTooLow => "STATUS_REASON_COMMITMENT_TOO_LOW",
+            Self::CannotRebase => "STATUS_REASON_CANNOT_REBASE",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for amm::StatusReason {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "STATUS_REASON_UNSPECIFIED",
+            "STATUS_REASON_CANCELLED_BY_PARTY",
+            "STATUS_REASON_CANNOT_FILL_COMMITMENT",
+            "STATUS_REASON_PARTY_ALREADY_OWNS_AMM_FOR_MARKET",
+            "STATUS_REASON_PARTY_CLOSED_OUT",
+            "STATUS_REASON_MARKET_CLOSED",
+            "STATUS_REASON_COMMITMENT_TOO_LOW",
+            "STATUS_REASON_CANNOT_REBASE",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = amm::StatusReason;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "STATUS_REASON_UNSPECIFIED" => Ok(amm::StatusReason::Unspecified),
+                    "STATUS_REASON_CANCELLED_BY_PARTY" => Ok(amm::StatusReason::CancelledByParty),
+                    "STATUS_REASON_CANNOT_FILL_COMMITMENT" => Ok(amm::StatusReason::CannotFillCommitment),
+                    "STATUS_REASON_PARTY_ALREADY_OWNS_AMM_FOR_MARKET" => Ok(amm::StatusReason::PartyAlreadyOwnsAmmForMarket),
+                    "STATUS_REASON_PARTY_CLOSED_OUT" => Ok(amm::StatusReason::PartyClosedOut),
+                    "STATUS_REASON_MARKET_CLOSED" => Ok(amm::StatusReason::MarketClosed),
+                    "STATUS_REASON_COMMITMENT_TOO_LOW" => Ok(amm::StatusReason::CommitmentTooLow),
+                    "STATUS_REASON_CANNOT_REBASE" => Ok(amm::StatusReason::CannotRebase),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for AuctionEvent {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.market_id.is_empty() {
+            len += 1;
+        }
+        if self.opening_auction {
+            len += 1;
+        }
+        if self.leave {
+            len += 1;
+        }
+        if self.start != 0 {
+            len += 1;
+        }
+        if self.end != 0 {
+            len += 1;
+        }
+        if self.trigger != 0 {
+            len += 1;
+        }
+        if self.extension_trigger != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.AuctionEvent", len)?;
+        if !self.market_id.is_empty() {
+            struct_ser.serialize_field("marketId", &self.market_id)?;
+        }
+        if self.opening_auction {
+            struct_ser.serialize_field("openingAuction", &self.opening_auction)?;
+        }
+        if self.leave {
+            struct_ser.serialize_field("leave", &self.leave)?;
+        }
+        if self.start != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("start", ToString::to_string(&self.start).as_str())?;
+        }
+        if self.end != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("end", ToString::to_string(&self.end).as_str())?;
+        }
+        if self.trigger != 0 {
+            let v = super::super::AuctionTrigger::try_from(self.trigger)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.trigger)))?;
+            struct_ser.serialize_field("trigger", &v)?;
+        }
+        if self.extension_trigger != 0 {
+            let v = super::super::AuctionTrigger::try_from(self.extension_trigger)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.extension_trigger)))?;
+            struct_ser.serialize_field("extensionTrigger", &v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for AuctionEvent {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "market_id",
+            "marketId",
+            "opening_auction",
+            "openingAuction",
+            "leave",
+            "start",
+            "end",
+            "trigger",
+            "extension_trigger",
+            "extensionTrigger",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            MarketId,
+            OpeningAuction,
+            Leave,
+            Start,
+            End,
+            Trigger,
+            ExtensionTrigger,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "marketId" | "market_id" => Ok(GeneratedField::MarketId),
+                            "openingAuction" | "opening_auction" => Ok(GeneratedField::OpeningAuction),
+                            "leave" => Ok(GeneratedField::Leave),
+                            "start" => Ok(GeneratedField::Start),
+                            "end" => Ok(GeneratedField::End),
+                            "trigger" => Ok(GeneratedField::Trigger),
+                            "extensionTrigger" | "extension_trigger" => Ok(GeneratedField::ExtensionTrigger),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = AuctionEvent;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.AuctionEvent")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<AuctionEvent, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut market_id__ = None;
+                let mut opening_auction__ = None;
+                let mut leave__ = None;
+                let mut start__ = None;
+                let mut end__ = None;
+                let mut trigger__ = None;
+                let mut extension_trigger__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::MarketId => {
+                            if market_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("marketId"));
+                            }
+                            market_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::OpeningAuction => {
+                            if opening_auction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("openingAuction"));
+                            }
+                            opening_auction__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Leave => {
+                            if leave__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("leave"));
+                            }
+                            leave__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Start => {
+                            if start__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("start"));
+                            }
+                            start__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::End => {
+                            if end__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("end"));
+                            }
+                            end__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Trigger => {
+                            if trigger__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("trigger"));
+                            }
+                            trigger__ = Some(map_.next_value::<super::super::AuctionTrigger>()? as i32);
+                        }
+                        GeneratedField::ExtensionTrigger => {
+                            if extension_trigger__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("extensionTrigger"));
+                            }
+                            extension_trigger__ = Some(map_.next_value::<super::super::AuctionTrigger>()? as i32);
+                        }
+                    }
+                }
+                Ok(AuctionEvent {
+                    market_id: market_id__.unwrap_or_default(),
+                    opening_auction: opening_auction__.unwrap_or_default(),
+                    leave: leave__.unwrap_or_default(),
+                    start: start__.unwrap_or_default(),
+                    end: end__.unwrap_or_default(),
+                    trigger: trigger__.unwrap_or_default(),
+                    extension_trigger: extension_trigger__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.AuctionEvent", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for BeginBlock {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.height != 0 {
+            len += 1;
+        }
+        if self.timestamp != 0 {
+            len += 1;
+        }
+        if !self.hash.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.BeginBlock", len)?;
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        if self.timestamp != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("timestamp", ToString::to_string(&self.timestamp).as_str())?;
+        }
+        if !self.hash.is_empty() {
+            struct_ser.serialize_field("hash", &self.hash)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BeginBlock {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "height",
+            "timestamp",
+            "hash",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Height,
+            Timestamp,
+            Hash,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "height" => Ok(GeneratedField::Height),
+                            "timestamp" => Ok(GeneratedField::Timestamp),
+                            "hash" => Ok(GeneratedField::Hash),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BeginBlock;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.BeginBlock")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BeginBlock, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut height__ = None;
+                let mut timestamp__ = None;
+                let mut hash__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Timestamp => {
+                            if timestamp__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timestamp"));
+                            }
+                            timestamp__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Hash => {
+                            if hash__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("hash"));
+                            }
+                            hash__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(BeginBlock {
+                    height: height__.unwrap_or_default(),
+                    timestamp: timestamp__.unwrap_or_default(),
+                    hash: hash__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.BeginBlock", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for BusEvent {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.id.is_empty() {
+            len += 1;
+        }
+        if !self.block.is_empty() {
+            len += 1;
+        }
+        if self.r#type != 0 {
+            len += 1;
+        }
+        if self.version != 0 {
+            len += 1;
+        }
+        if !self.chain_id.is_empty() {
+            len += 1;
+        }
+        if !self.tx_hash.is_empty() {
+            len += 1;
+        }
+        if self.event.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.BusEvent", len)?;
+        if !self.id.is_empty() {
+            struct_ser.serialize_field("id", &self.id)?;
+        }
+        if !self.block.is_empty() {
+            struct_ser.serialize_field("block", &self.block)?;
+        }
+        if self.r#type != 0 {
+            let v = BusEventType::try_from(self.r#type)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.r#type)))?;
+            struct_ser.serialize_field("type", &v)?;
+        }
+        if self.version != 0 {
+            struct_ser.serialize_field("version", &self.version)?;
+        }
+        if !self.chain_id.is_empty() {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
+        }
+        if !self.tx_hash.is_empty() {
+            struct_ser.serialize_field("txHash", &self.tx_hash)?;
+        }
+        if let Some(v) = self.event.as_ref() {
+            match v {
+                bus_event::Event::TimeUpdate(v) => {
+                    struct_ser.serialize_field("timeUpdate", v)?;
+                }
+                bus_event::Event::LedgerMovements(v) => {
+                    struct_ser.serialize_field("ledgerMovements", v)?;
+                }
+                bus_event::Event::PositionResolution(v) => {
+                    struct_ser.serialize_field("positionResolution", v)?;
+                }
+                bus_event::Event::Order(v) => {
+                    struct_ser.serialize_field("order", v)?;
+                }
+                bus_event::Event::Account(v) => {
+                    struct_ser.serialize_field("account", v)?;
+                }
+                bus_event::Event::Party(v) => {
+                    struct_ser.serialize_field("party", v)?;
+                }
+                bus_event::Event::Trade(v) => {
+                    struct_ser.serialize_field("trade", v)?;
+                }
+                bus_event::Event::MarginLevels(v) => {
+                    struct_ser.serialize_field("marginLevels", v)?;
+                }
+                bus_event::Event::Proposal(v) => {
+                    struct_ser.serialize_field("proposal", v)?;
+                }
+                bus_event::Event::Vote(v) => {
+                    struct_ser.serialize_field("vote", v)?;
+                }
+                bus_event::Event::MarketData(v) => {
+                    struct_ser.serialize_field("marketData", v)?;
+                }
+                bus_event::Event::NodeSignature(v) => {
+                    struct_ser.serialize_field("nodeSignature", v)?;
+                }
+                bus_event::Event::LossSocialization(v) => {
+                    struct_ser.serialize_field("lossSocialization", v)?;
+                }
+                bus_event::Event::SettlePosition(v) => {
+                    struct_ser.serialize_field("settlePosition", v)?;
+                }
+                bus_event::Event::SettleDistressed(v) => {
+                    struct_ser.serialize_field("settleDistressed", v)?;
+                }
+                bus_event::Event::MarketCreated(v) => {
+                    struct_ser.serialize_field("marketCreated", v)?;
+                }
+                bus_event::Event::Asset(v) => {
+                    struct_ser.serialize_field("asset", v)?;
+                }
+                bus_event::Event::MarketTick(v) => {
+                    struct_ser.serialize_field("marketTick", v)?;
+                }
+                bus_event::Event::Withdrawal(v) => {
+                    struct_ser.serialize_field("withdrawal", v)?;
+                }
+                bus_event::Event::Deposit(v) => {
+                    struct_ser.serialize_field("deposit", v)?;
+                }
+                bus_event::Event::Auction(v) => {
+                    struct_ser.serialize_field("auction", v)?;
+                }
+                bus_event::Event::RiskFactor(v) => {
+                    struct_ser.serialize_field("riskFactor", v)?;
+                }
+                bus_event::Event::NetworkParameter(v) => {
+                    struct_ser.serialize_field("networkParameter", v)?;
+                }
+                bus_event::Event::LiquidityProvision(v) => {
+                    struct_ser.serialize_field("liquidityProvision", v)?;
+                }
+                bus_event::Event::MarketUpdated(v) => {
+                    struct_ser.serialize_field("marketUpdated", v)?;
+                }
+                bus_event::Event::OracleSpec(v) => {
+                    struct_ser.serialize_field("oracleSpec", v)?;
+                }
+                bus_event::Event::OracleData(v) => {
+                    struct_ser.serialize_field("oracleData", v)?;
+                }
+                bus_event::Event::DelegationBalance(v) => {
+                    struct_ser.serialize_field("delegationBalance", v)?;
+                }
+                bus_event::Event::ValidatorScore(v) => {
+                    struct_ser.serialize_field("validatorScore", v)?;
+                }
+                bus_event::Event::EpochEvent(v) => {
+                    struct_ser.serialize_field("epochEvent", v)?;
+                }
+                bus_event::Event::ValidatorUpdate(v) => {
+                    struct_ser.serialize_field("validatorUpdate", v)?;
+                }
+                bus_event::Event::StakeLinking(v) => {
+                    struct_ser.serialize_field("stakeLinking", v)?;
+                }
+                bus_event::Event::RewardPayout(v) => {
+                    struct_ser.serialize_field("rewardPayout", v)?;
+                }
+                bus_event::Event::Checkpoint(v) => {
+                    struct_ser.serialize_field("checkpoint", v)?;
+                }
+                bus_event::Event::KeyRotation(v) => {
+                    struct_ser.serialize_field("keyRotation", v)?;
+                }
+                bus_event::Event::StateVar(v) => {
+                    struct_ser.serialize_field("stateVar", v)?;
+                }
+                bus_event::Event::NetworkLimits(v) => {
+                    struct_ser.serialize_field("networkLimits", v)?;
+                }
+                bus_event::Event::Transfer(v) => {
+                    struct_ser.serialize_field("transfer", v)?;
+                }
+                bus_event::Event::RankingEvent(v) => {
+                    struct_ser.serialize_field("rankingEvent", v)?;
+                }
+                bus_event::Event::Erc20MultisigSignerEvent(v) => {
+                    struct_ser.serialize_field("erc20MultisigSignerEvent", v)?;
+                }
+                bus_event::Event::Erc20MultisigSetThresholdEvent(v) => {
+                    struct_ser.serialize_field("erc20MultisigSetThresholdEvent", v)?;
+                }
+                bus_event::Event::Erc20MultisigSignerAdded(v) => {
+                    struct_ser.serialize_field("erc20MultisigSignerAdded", v)?;
+                }
+                bus_event::Event::Erc20MultisigSignerRemoved(v) => {
+                    struct_ser.serialize_field("erc20MultisigSignerRemoved", v)?;
+                }
+                bus_event::Event::PositionStateEvent(v) => {
+                    struct_ser.serialize_field("positionStateEvent", v)?;
+                }
+                bus_event::Event::EthereumKeyRotation(v) => {
+                    struct_ser.serialize_field("ethereumKeyRotation", v)?;
+                }
+                bus_event::Event::ProtocolUpgradeEvent(v) => {
+                    struct_ser.serialize_field("protocolUpgradeEvent", v)?;
+                }
+                bus_event::Event::BeginBlock(v) => {
+                    struct_ser.serialize_field("beginBlock", v)?;
+                }
+                bus_event::Event::EndBlock(v) => {
+                    struct_ser.serialize_field("endBlock", v)?;
+                }
+                bus_event::Event::ProtocolUpgradeStarted(v) => {
+                    struct_ser.serialize_field("protocolUpgradeStarted", v)?;
+                }
+                bus_event::Event::SettleMarket(v) => {
+                    struct_ser.serialize_field("settleMarket", v)?;
+                }
+                bus_event::Event::TransactionResult(v) => {
+                    struct_ser.serialize_field("transactionResult", v)?;
+                }
+                bus_event::Event::CoreSnapshotEvent(v) => {
+                    struct_ser.serialize_field("coreSnapshotEvent", v)?;
+                }
+                bus_event::Event::ProtocolUpgradeDataNodeReady(v) => {
+                    struct_ser.serialize_field("protocolUpgradeDataNodeReady", v)?;
+                }
+                bus_event::Event::DistressedOrders(v) => {
+                    struct_ser.serialize_field("distressedOrders", v)?;
+                }
+                bus_event::Event::ExpiredOrders(v) => {
+                    struct_ser.serialize_field("expiredOrders", v)?;
+                }
+                bus_event::Event::DistressedPositions(v) => {
+                    struct_ser.serialize_field("distressedPositions", v)?;
+                }
+                bus_event::Event::StopOrder(v) => {
+                    struct_ser.serialize_field("stopOrder", v)?;
+                }
+                bus_event::Event::FundingPeriod(v) => {
+                    struct_ser.serialize_field("fundingPeriod", v)?;
+                }
+                bus_event::Event::FundingPeriodDataPoint(v) => {
+                    struct_ser.serialize_field("fundingPeriodDataPoint", v)?;
+                }
+                bus_event::Event::TeamCreated(v) => {
+                    struct_ser.serialize_field("teamCreated", v)?;
+                }
+                bus_event::Event::TeamUpdated(v) => {
+                    struct_ser.serialize_field("teamUpdated", v)?;
+                }
+                bus_event::Event::RefereeSwitchedTeam(v) => {
+                    struct_ser.serialize_field("refereeSwitchedTeam", v)?;
+                }
+                bus_event::Event::RefereeJoinedTeam(v) => {
+                    struct_ser.serialize_field("refereeJoinedTeam", v)?;
+                }
+                bus_event::Event::ReferralProgramStarted(v) => {
+                    struct_ser.serialize_field("referralProgramStarted", v)?;
+                }
+                bus_event::Event::ReferralProgramUpdated(v) => {
+                    struct_ser.serialize_field("referralProgramUpdated", v)?;
+                }
+                bus_event::Event::ReferralProgramEnded(v) => {
+                    struct_ser.serialize_field("referralProgramEnded", v)?;
+                }
+                bus_event::Event::ReferralSetCreated(v) => {
+                    struct_ser.serialize_field("referralSetCreated", v)?;
+                }
+                bus_event::Event::RefereeJoinedReferralSet(v) => {
+                    struct_ser.serialize_field("refereeJoinedReferralSet", v)?;
+                }
+                bus_event::Event::PartyActivityStreak(v) => {
+                    struct_ser.serialize_field("partyActivityStreak", v)?;
+                }
+                bus_event::Event::VolumeDiscountProgramStarted(v) => {
+                    struct_ser.serialize_field("volumeDiscountProgramStarted", v)?;
+                }
+                bus_event::Event::VolumeDiscountProgramUpdated(v) => {
+                    struct_ser.serialize_field("volumeDiscountProgramUpdated", v)?;
+                }
+                bus_event::Event::VolumeDiscountProgramEnded(v) => {
+                    struct_ser.serialize_field("volumeDiscountProgramEnded", v)?;
+                }
+                bus_event::Event::ReferralSetStatsUpdated(v) => {
+                    struct_ser.serialize_field("referralSetStatsUpdated", v)?;
+                }
+                bus_event::Event::VestingStatsUpdated(v) => {
+                    struct_ser.serialize_field("vestingStatsUpdated", v)?;
+                }
+                bus_event::Event::VolumeDiscountStatsUpdated(v) => {
+                    struct_ser.serialize_field("volumeDiscountStatsUpdated", v)?;
+                }
+                bus_event::Event::FeesStats(v) => {
+                    struct_ser.serialize_field("feesStats", v)?;
+                }
+                bus_event::Event::FundingPayments(v) => {
+                    struct_ser.serialize_field("fundingPayments", v)?;
+                }
+                bus_event::Event::PaidLiquidityFeesStats(v) => {
+                    struct_ser.serialize_field("paidLiquidityFeesStats", v)?;
+                }
+                bus_event::Event::VestingBalancesSummary(v) => {
+                    struct_ser.serialize_field("vestingBalancesSummary", v)?;
+                }
+                bus_event::Event::TransferFees(v) => {
+                    struct_ser.serialize_field("transferFees", v)?;
+                }
+                bus_event::Event::TransferFeesDiscount(v) => {
+                    struct_ser.serialize_field("transferFeesDiscount", v)?;
+                }
+                bus_event::Event::PartyMarginModeUpdated(v) => {
+                    struct_ser.serialize_field("partyMarginModeUpdated", v)?;
+                }
+                bus_event::Event::PartyProfileUpdated(v) => {
+                    struct_ser.serialize_field("partyProfileUpdated", v)?;
+                }
+                bus_event::Event::TeamsStatsUpdated(v) => {
+                    struct_ser.serialize_field("teamsStatsUpdated", v)?;
+                }
+                bus_event::Event::TimeWeightedNotionalPositionUpdated(v) => {
+                    struct_ser.serialize_field("timeWeightedNotionalPositionUpdated", v)?;
+                }
+                bus_event::Event::CancelledOrders(v) => {
+                    struct_ser.serialize_field("cancelledOrders", v)?;
+                }
+                bus_event::Event::GameScores(v) => {
+                    struct_ser.serialize_field("gameScores", v)?;
+                }
+                bus_event::Event::Amm(v) => {
+                    struct_ser.serialize_field("amm", v)?;
+                }
+                bus_event::Event::VolumeRebateProgramStarted(v) => {
+                    struct_ser.serialize_field("volumeRebateProgramStarted", v)?;
+                }
+                bus_event::Event::VolumeRebateProgramUpdated(v) => {
+                    struct_ser.serialize_field("volumeRebateProgramUpdated", v)?;
+                }
+                bus_event::Event::VolumeRebateProgramEnded(v) => {
+                    struct_ser.serialize_field("volumeRebateProgramEnded", v)?;
+                }
+                bus_event::Event::VolumeRebateStatsUpdated(v) => {
+                    struct_ser.serialize_field("volumeRebateStatsUpdated", v)?;
+                }
+                bus_event::Event::Market(v) => {
+                    struct_ser.serialize_field("market", v)?;
+                }
+                bus_event::Event::TxErrEvent(v) => {
+                    struct_ser.serialize_field("txErrEvent", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BusEvent {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "id",
+            "block",
+            "type",
+            "version",
+            "chain_id",
+            "chainId",
+            "tx_hash",
+            "txHash",
+            "time_update",
+            "timeUpdate",
+            "ledger_movements",
+            "ledgerMovements",
+            "position_resolution",
+            "positionResolution",
+            "order",
+            "account",
+            "party",
+            "trade",
+            "margin_levels",
+            "marginLevels",
+            "proposal",
+            "vote",
+            "market_data",
+            "marketData",
+            "node_signature",
+            "nodeSignature",
+            "loss_socialization",
+            "lossSocialization",
+            "settle_position",
+            "settlePosition",
+            "settle_distressed",
+            "settleDistressed",
+            "market_created",
+            "marketCreated",
+            "asset",
+            "market_tick",
+            "marketTick",
+            "withdrawal",
+            "deposit",
+            "auction",
+            "risk_factor",
+            "riskFactor",
+            "network_parameter",
+            "networkParameter",
+            "liquidity_provision",
+            "liquidityProvision",
+            "market_updated",
+            "marketUpdated",
+            "oracle_spec",
+            "oracleSpec",
+            "oracle_data",
+            "oracleData",
+            "delegation_balance",
+            "delegationBalance",
+            "validator_score",
+            "validatorScore",
+            "epoch_event",
+            "epochEvent",
+            "validator_update",
+            "validatorUpdate",
+            "stake_linking",
+            "stakeLinking",
+            "reward_payout",
+            "rewardPayout",
+            "checkpoint",
+            "key_rotation",
+            "keyRotation",
+            "state_var",
+            "stateVar",
+            "network_limits",
+            "networkLimits",
+            "transfer",
+            "ranking_event",
+            "rankingEvent",
+            "erc20_multisig_signer_event",
+            "erc20MultisigSignerEvent",
+            "erc20_multisig_set_threshold_event",
+            "erc20MultisigSetThresholdEvent",
+            "erc20_multisig_signer_added",
+            "erc20MultisigSignerAdded",
+            "erc20_multisig_signer_removed",
+            "erc20MultisigSignerRemoved",
+            "position_state_event",
+            "positionStateEvent",
+            "ethereum_key_rotation",
+            "ethereumKeyRotation",
+            "protocol_upgrade_event",
+            "protocolUpgradeEvent",
+            "begin_block",
+            "beginBlock",
+            "end_block",
+            "endBlock",
+            "protocol_upgrade_started",
+            "protocolUpgradeStarted",
+            "settle_market",
+            "settleMarket",
+            "transaction_result",
+            "transactionResult",
+            "core_snapshot_event",
+            "coreSnapshotEvent",
+            "protocol_upgrade_data_node_ready",
+            "protocolUpgradeDataNodeReady",
+            "distressed_orders",
+            "distressedOrders",
+            "expired_orders",
+            "expiredOrders",
+            "distressed_positions",
+            "distressedPositions",
+            "stop_order",
+            "stopOrder",
+            "funding_period",
+            "fundingPeriod",
+            "funding_period_data_point",
+            "fundingPeriodDataPoint",
+            "team_created",
+            "teamCreated",
+            "team_updated",
+            "teamUpdated",
+            "referee_switched_team",
+            "refereeSwitchedTeam",
+            "referee_joined_team",
+            "refereeJoinedTeam",
+            "referral_program_started",
+            "referralProgramStarted",
+            "referral_program_updated",
+            "referralProgramUpdated",
+            "referral_program_ended",
+            "referralProgramEnded",
+            "referral_set_created",
+            "referralSetCreated",
+            "referee_joined_referral_set",
+            "refereeJoinedReferralSet",
+            "party_activity_streak",
+            "partyActivityStreak",
+            "volume_discount_program_started",
+            "volumeDiscountProgramStarted",
+            "volume_discount_program_updated",
+            "volumeDiscountProgramUpdated",
+            "volume_discount_program_ended",
+            "volumeDiscountProgramEnded",
+            "referral_set_stats_updated",
+            "referralSetStatsUpdated",
+            "vesting_stats_updated",
+            "vestingStatsUpdated",
+            "volume_discount_stats_updated",
+            "volumeDiscountStatsUpdated",
+            "fees_stats",
+            "feesStats",
+            "funding_payments",
+            "fundingPayments",
+            "paid_liquidity_fees_stats",
+            "paidLiquidityFeesStats",
+            "vesting_balances_summary",
+            "vestingBalancesSummary",
+            "transfer_fees",
+            "transferFees",
+            "transfer_fees_discount",
+            "transferFeesDiscount",
+            "party_margin_mode_updated",
+            "partyMarginModeUpdated",
+            "party_profile_updated",
+            "partyProfileUpdated",
+            "teams_stats_updated",
+            "teamsStatsUpdated",
+            "time_weighted_notional_position_updated",
+            "timeWeightedNotionalPositionUpdated",
+            "cancelled_orders",
+            "cancelledOrders",
+            "game_scores",
+            "gameScores",
+            "amm",
+            "volume_rebate_program_started",
+            "volumeRebateProgramStarted",
+            "volume_rebate_program_updated",
+            "volumeRebateProgramUpdated",
+            "volume_rebate_program_ended",
+            "volumeRebateProgramEnded",
+            "volume_rebate_stats_updated",
+            "volumeRebateStatsUpdated",
+            "market",
+            "tx_err_event",
+            "txErrEvent",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Id,
+            Block,
+            Type,
+            Version,
+            ChainId,
+            TxHash,
+            TimeUpdate,
+            LedgerMovements,
+            PositionResolution,
+            Order,
+            Account,
+            Party,
+            Trade,
+            MarginLevels,
+            Proposal,
+            Vote,
+            MarketData,
+            NodeSignature,
+            LossSocialization,
+            SettlePosition,
+            SettleDistressed,
+            MarketCreated,
+            Asset,
+            MarketTick,
+            Withdrawal,
+            Deposit,
+            Auction,
+            RiskFactor,
+            NetworkParameter,
+            LiquidityProvision,
+            MarketUpdated,
+            OracleSpec,
+            OracleData,
+            DelegationBalance,
+            ValidatorScore,
+            EpochEvent,
+            ValidatorUpdate,
+            StakeLinking,
+            RewardPayout,
+            Checkpoint,
+            KeyRotation,
+            StateVar,
+            NetworkLimits,
+            Transfer,
+            RankingEvent,
+            Erc20MultisigSignerEvent,
+            Erc20MultisigSetThresholdEvent,
+            Erc20MultisigSignerAdded,
+            Erc20MultisigSignerRemoved,
+            PositionStateEvent,
+            EthereumKeyRotation,
+            ProtocolUpgradeEvent,
+            BeginBlock,
+            EndBlock,
+            ProtocolUpgradeStarted,
+            SettleMarket,
+            TransactionResult,
+            CoreSnapshotEvent,
+            ProtocolUpgradeDataNodeReady,
+            DistressedOrders,
+            ExpiredOrders,
+            DistressedPositions,
+            StopOrder,
+            FundingPeriod,
+            FundingPeriodDataPoint,
+            TeamCreated,
+            TeamUpdated,
+            RefereeSwitchedTeam,
+            RefereeJoinedTeam,
+            ReferralProgramStarted,
+            ReferralProgramUpdated,
+            ReferralProgramEnded,
+            ReferralSetCreated,
+            RefereeJoinedReferralSet,
+            PartyActivityStreak,
+            VolumeDiscountProgramStarted,
+            VolumeDiscountProgramUpdated,
+            VolumeDiscountProgramEnded,
+            ReferralSetStatsUpdated,
+            VestingStatsUpdated,
+            VolumeDiscountStatsUpdated,
+            FeesStats,
+            FundingPayments,
+            PaidLiquidityFeesStats,
+            VestingBalancesSummary,
+            TransferFees,
+            TransferFeesDiscount,
+            PartyMarginModeUpdated,
+            PartyProfileUpdated,
+            TeamsStatsUpdated,
+            TimeWeightedNotionalPositionUpdated,
+            CancelledOrders,
+            GameScores,
+            Amm,
+            VolumeRebateProgramStarted,
+            VolumeRebateProgramUpdated,
+            VolumeRebateProgramEnded,
+            VolumeRebateStatsUpdated,
+            Market,
+            TxErrEvent,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "id" => Ok(GeneratedField::Id),
+                            "block" => Ok(GeneratedField::Block),
+                            "type" => Ok(GeneratedField::Type),
+                            "version" => Ok(GeneratedField::Version),
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            "txHash" | "tx_hash" => Ok(GeneratedField::TxHash),
+                            "timeUpdate" | "time_update" => Ok(GeneratedField::TimeUpdate),
+                            "ledgerMovements" | "ledger_movements" => Ok(GeneratedField::LedgerMovements),
+                            "positionResolution" | "position_resolution" => Ok(GeneratedField::PositionResolution),
+                            "order" => Ok(GeneratedField::Order),
+                            "account" => Ok(GeneratedField::Account),
+                            "party" => Ok(GeneratedField::Party),
+                            "trade" => Ok(GeneratedField::Trade),
+                            "marginLevels" | "margin_levels" => Ok(GeneratedField::MarginLevels),
+                            "proposal" => Ok(GeneratedField::Proposal),
+                            "vote" => Ok(GeneratedField::Vote),
+                            "marketData" | "market_data" => Ok(GeneratedField::MarketData),
+                            "nodeSignature" | "node_signature" => Ok(GeneratedField::NodeSignature),
+                            "lossSocialization" | "loss_socialization" => Ok(GeneratedField::LossSocialization),
+                            "settlePosition" | "settle_position" => Ok(GeneratedField::SettlePosition),
+                            "settleDistressed" | "settle_distressed" => Ok(GeneratedField::SettleDistressed),
+                            "marketCreated" | "market_created" => Ok(GeneratedField::MarketCreated),
+                            "asset" => Ok(GeneratedField::Asset),
+                            "marketTick" | "market_tick" => Ok(GeneratedField::MarketTick),
+                            "withdrawal" => Ok(GeneratedField::Withdrawal),
+                            "deposit" => Ok(GeneratedField::Deposit),
+                            "auction" => Ok(GeneratedField::Auction),
+                            "riskFactor" | "risk_factor" => Ok(GeneratedField::RiskFactor),
+                            "networkParameter" | "network_parameter" => Ok(GeneratedField::NetworkParameter),
+                            "liquidityProvision" | "liquidity_provision" => Ok(GeneratedField::LiquidityProvision),
+                            "marketUpdated" | "market_updated" => Ok(GeneratedField::MarketUpdated),
+                            "oracleSpec" | "oracle_spec" => Ok(GeneratedField::OracleSpec),
+                            "oracleData" | "oracle_data" => Ok(GeneratedField::OracleData),
+                            "delegationBalance" | "delegation_balance" => Ok(GeneratedField::DelegationBalance),
+                            "validatorScore" | "validator_score" => Ok(GeneratedField::ValidatorScore),
+                            "epochEvent" | "epoch_event" => Ok(GeneratedField::EpochEvent),
+                            "validatorUpdate" | "validator_update" => Ok(GeneratedField::ValidatorUpdate),
+                            "stakeLinking" | "stake_linking" => Ok(GeneratedField::StakeLinking),
+                            "rewardPayout" | "reward_payout" => Ok(GeneratedField::RewardPayout),
+                            "checkpoint" => Ok(GeneratedField::Checkpoint),
+                            "keyRotation" | "key_rotation" => Ok(GeneratedField::KeyRotation),
+                            "stateVar" | "state_var" => Ok(GeneratedField::StateVar),
+                            "networkLimits" | "network_limits" => Ok(GeneratedField::NetworkLimits),
+                            "transfer" => Ok(GeneratedField::Transfer),
+                            "rankingEvent" | "ranking_event" => Ok(GeneratedField::RankingEvent),
+                            "erc20MultisigSignerEvent" | "erc20_multisig_signer_event" => Ok(GeneratedField::Erc20MultisigSignerEvent),
+                            "erc20MultisigSetThresholdEvent" | "erc20_multisig_set_threshold_event" => Ok(GeneratedField::Erc20MultisigSetThresholdEvent),
+                            "erc20MultisigSignerAdded" | "erc20_multisig_signer_added" => Ok(GeneratedField::Erc20MultisigSignerAdded),
+                            "erc20MultisigSignerRemoved" | "erc20_multisig_signer_removed" => Ok(GeneratedField::Erc20MultisigSignerRemoved),
+                            "positionStateEvent" | "position_state_event" => Ok(GeneratedField::PositionStateEvent),
+                            "ethereumKeyRotation" | "ethereum_key_rotation" => Ok(GeneratedField::EthereumKeyRotation),
+                            "protocolUpgradeEvent" | "protocol_upgrade_event" => Ok(GeneratedField::ProtocolUpgradeEvent),
+                            "beginBlock" | "begin_block" => Ok(GeneratedField::BeginBlock),
+                            "endBlock" | "end_block" => Ok(GeneratedField::EndBlock),
+                            "protocolUpgradeStarted" | "protocol_upgrade_started" => Ok(GeneratedField::ProtocolUpgradeStarted),
+                            "settleMarket" | "settle_market" => Ok(GeneratedField::SettleMarket),
+                            "transactionResult" | "transaction_result" => Ok(GeneratedField::TransactionResult),
+                            "coreSnapshotEvent" | "core_snapshot_event" => Ok(GeneratedField::CoreSnapshotEvent),
+                            "protocolUpgradeDataNodeReady" | "protocol_upgrade_data_node_ready" => Ok(GeneratedField::ProtocolUpgradeDataNodeReady),
+                            "distressedOrders" | "distressed_orders" => Ok(GeneratedField::DistressedOrders),
+                            "expiredOrders" | "expired_orders" => Ok(GeneratedField::ExpiredOrders),
+                            "distressedPositions" | "distressed_positions" => Ok(GeneratedField::DistressedPositions),
+                            "stopOrder" | "stop_order" => Ok(GeneratedField::StopOrder),
+                            "fundingPeriod" | "funding_period" => Ok(GeneratedField::FundingPeriod),
+                            "fundingPeriodDataPoint" | "funding_period_data_point" => Ok(GeneratedField::FundingPeriodDataPoint),
+                            "teamCreated" | "team_created" => Ok(GeneratedField::TeamCreated),
+                            "teamUpdated" | "team_updated" => Ok(GeneratedField::TeamUpdated),
+                            "refereeSwitchedTeam" | "referee_switched_team" => Ok(GeneratedField::RefereeSwitchedTeam),
+                            "refereeJoinedTeam" | "referee_joined_team" => Ok(GeneratedField::RefereeJoinedTeam),
+                            "referralProgramStarted" | "referral_program_started" => Ok(GeneratedField::ReferralProgramStarted),
+                            "referralProgramUpdated" | "referral_program_updated" => Ok(GeneratedField::ReferralProgramUpdated),
+                            "referralProgramEnded" | "referral_program_ended" => Ok(GeneratedField::ReferralProgramEnded),
+                            "referralSetCreated" | "referral_set_created" => Ok(GeneratedField::ReferralSetCreated),
+                            "refereeJoinedReferralSet" | "referee_joined_referral_set" => Ok(GeneratedField::RefereeJoinedReferralSet),
+                            "partyActivityStreak" | "party_activity_streak" => Ok(GeneratedField::PartyActivityStreak),
+                            "volumeDiscountProgramStarted" | "volume_discount_program_started" => Ok(GeneratedField::VolumeDiscountProgramStarted),
+                            "volumeDiscountProgramUpdated" | "volume_discount_program_updated" => Ok(GeneratedField::VolumeDiscountProgramUpdated),
+                            "volumeDiscountProgramEnded" | "volume_discount_program_ended" => Ok(GeneratedField::VolumeDiscountProgramEnded),
+                            "referralSetStatsUpdated" | "referral_set_stats_updated" => Ok(GeneratedField::ReferralSetStatsUpdated),
+                            "vestingStatsUpdated" | "vesting_stats_updated" => Ok(GeneratedField::VestingStatsUpdated),
+                            "volumeDiscountStatsUpdated" | "volume_discount_stats_updated" => Ok(GeneratedField::VolumeDiscountStatsUpdated),
+                            "feesStats" | "fees_stats" => Ok(GeneratedField::FeesStats),
+                            "fundingPayments" | "funding_payments" => Ok(GeneratedField::FundingPayments),
+                            "paidLiquidityFeesStats" | "paid_liquidity_fees_stats" => Ok(GeneratedField::PaidLiquidityFeesStats),
+                            "vestingBalancesSummary" | "vesting_balances_summary" => Ok(GeneratedField::VestingBalancesSummary),
+                            "transferFees" | "transfer_fees" => Ok(GeneratedField::TransferFees),
+                            "transferFeesDiscount" | "transfer_fees_discount" => Ok(GeneratedField::TransferFeesDiscount),
+                            "partyMarginModeUpdated" | "party_margin_mode_updated" => Ok(GeneratedField::PartyMarginModeUpdated),
+                            "partyProfileUpdated" | "party_profile_updated" => Ok(GeneratedField::PartyProfileUpdated),
+                            "teamsStatsUpdated" | "teams_stats_updated" => Ok(GeneratedField::TeamsStatsUpdated),
+                            "timeWeightedNotionalPositionUpdated" | "time_weighted_notional_position_updated" => Ok(GeneratedField::TimeWeightedNotionalPositionUpdated),
+                            "cancelledOrders" | "cancelled_orders" => Ok(GeneratedField::CancelledOrders),
+                            "gameScores" | "game_scores" => Ok(GeneratedField::GameScores),
+                            "amm" => Ok(GeneratedField::Amm),
+                            "volumeRebateProgramStarted" | "volume_rebate_program_started" => Ok(GeneratedField::VolumeRebateProgramStarted),
+                            "volumeRebateProgramUpdated" | "volume_rebate_program_updated" => Ok(GeneratedField::VolumeRebateProgramUpdated),
+                            "volumeRebateProgramEnded" | "volume_rebate_program_ended" => Ok(GeneratedField::VolumeRebateProgramEnded),
+                            "volumeRebateStatsUpdated" | "volume_rebate_stats_updated" => Ok(GeneratedField::VolumeRebateStatsUpdated),
+                            "market" => Ok(GeneratedField::Market),
+                            "txErrEvent" | "tx_err_event" => Ok(GeneratedField::TxErrEvent),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BusEvent;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.BusEvent")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BusEvent, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut id__ = None;
+                let mut block__ = None;
+                let mut r#type__ = None;
+                let mut version__ = None;
+                let mut chain_id__ = None;
+                let mut tx_hash__ = None;
+                let mut event__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Block => {
+                            if block__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("block"));
+                            }
+                            block__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Type => {
+                            if r#type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("type"));
+                            }
+                            r#type__ = Some(map_.next_value::<BusEventType>()? as i32);
+                        }
+                        GeneratedField::Version => {
+                            if version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("version"));
+                            }
+                            version__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
+                            }
+                            chain_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::TxHash => {
+                            if tx_hash__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("txHash"));
+                            }
+                            tx_hash__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::TimeUpdate => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timeUpdate"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::TimeUpdate)
+;
+                        }
+                        GeneratedField::LedgerMovements => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ledgerMovements"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::LedgerMovements)
+;
+                        }
+                        GeneratedField::PositionResolution => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionResolution"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::PositionResolution)
+;
+                        }
+                        GeneratedField::Order => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("order"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::Order)
+;
+                        }
+                        GeneratedField::Account => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("account"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::Account)
+;
+                        }
+                        GeneratedField::Party => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("party"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::Party)
+;
+                        }
+                        GeneratedField::Trade => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("trade"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::Trade)
+;
+                        }
+                        GeneratedField::MarginLevels => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("marginLevels"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::MarginLevels)
+;
+                        }
+                        GeneratedField::Proposal => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposal"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::Proposal)
+;
+                        }
+                        GeneratedField::Vote => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("vote"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::Vote)
+;
+                        }
+                        GeneratedField::MarketData => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("marketData"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::MarketData)
+;
+                        }
+                        GeneratedField::NodeSignature => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nodeSignature"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::NodeSignature)
+;
+                        }
+                        GeneratedField::LossSocialization => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lossSocialization"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::LossSocialization)
+;
+                        }
+                        GeneratedField::SettlePosition => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("settlePosition"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::SettlePosition)
+;
+                        }
+                        GeneratedField::SettleDistressed => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("settleDistressed"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::SettleDistressed)
+;
+                        }
+                        GeneratedField::MarketCreated => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("marketCreated"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::MarketCreated)
+;
+                        }
+                        GeneratedField::Asset => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("asset"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::Asset)
+;
+                        }
+                        GeneratedField::MarketTick => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("marketTick"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::MarketTick)
+;
+                        }
+                        GeneratedField::Withdrawal => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("withdrawal"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::Withdrawal)
+;
+                        }
+                        GeneratedField::Deposit => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("deposit"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::Deposit)
+;
+                        }
+                        GeneratedField::Auction => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("auction"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::Auction)
+;
+                        }
+                        GeneratedField::RiskFactor => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("riskFactor"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::RiskFactor)
+;
+                        }
+                        GeneratedField::NetworkParameter => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("networkParameter"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::NetworkParameter)
+;
+                        }
+                        GeneratedField::LiquidityProvision => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("liquidityProvision"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::LiquidityProvision)
+;
+                        }
+                        GeneratedField::MarketUpdated => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("marketUpdated"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::MarketUpdated)
+;
+                        }
+                        GeneratedField::OracleSpec => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("oracleSpec"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::OracleSpec)
+;
+                        }
+                        GeneratedField::OracleData => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("oracleData"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::OracleData)
+;
+                        }
+                        GeneratedField::DelegationBalance => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("delegationBalance"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::DelegationBalance)
+;
+                        }
+                        GeneratedField::ValidatorScore => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("validatorScore"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::ValidatorScore)
+;
+                        }
+                        GeneratedField::EpochEvent => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epochEvent"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::EpochEvent)
+;
+                        }
+                        GeneratedField::ValidatorUpdate => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("validatorUpdate"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::ValidatorUpdate)
+;
+                        }
+                        GeneratedField::StakeLinking => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stakeLinking"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::StakeLinking)
+;
+                        }
+                        GeneratedField::RewardPayout => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rewardPayout"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::RewardPayout)
+;
+                        }
+                        GeneratedField::Checkpoint => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("checkpoint"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::Checkpoint)
+;
+                        }
+                        GeneratedField::KeyRotation => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("keyRotation"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::KeyRotation)
+;
+                        }
+                        GeneratedField::StateVar => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stateVar"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::StateVar)
+;
+                        }
+                        GeneratedField::NetworkLimits => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("networkLimits"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::NetworkLimits)
+;
+                        }
+                        GeneratedField::Transfer => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("transfer"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::Transfer)
+;
+                        }
+                        GeneratedField::RankingEvent => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rankingEvent"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::RankingEvent)
+;
+                        }
+                        GeneratedField::Erc20MultisigSignerEvent => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("erc20MultisigSignerEvent"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::Erc20MultisigSignerEvent)
+;
+                        }
+                        GeneratedField::Erc20MultisigSetThresholdEvent => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("erc20MultisigSetThresholdEvent"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::Erc20MultisigSetThresholdEvent)
+;
+                        }
+                        GeneratedField::Erc20MultisigSignerAdded => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("erc20MultisigSignerAdded"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::Erc20MultisigSignerAdded)
+;
+                        }
+                        GeneratedField::Erc20MultisigSignerRemoved => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("erc20MultisigSignerRemoved"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::Erc20MultisigSignerRemoved)
+;
+                        }
+                        GeneratedField::PositionStateEvent => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionStateEvent"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::PositionStateEvent)
+;
+                        }
+                        GeneratedField::EthereumKeyRotation => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ethereumKeyRotation"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::EthereumKeyRotation)
+;
+                        }
+                        GeneratedField::ProtocolUpgradeEvent => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("protocolUpgradeEvent"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::ProtocolUpgradeEvent)
+;
+                        }
+                        GeneratedField::BeginBlock => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("beginBlock"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::BeginBlock)
+;
+                        }
+                        GeneratedField::EndBlock => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endBlock"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::EndBlock)
+;
+                        }
+                        GeneratedField::ProtocolUpgradeStarted => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("protocolUpgradeStarted"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::ProtocolUpgradeStarted)
+;
+                        }
+                        GeneratedField::SettleMarket => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("settleMarket"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::SettleMarket)
+;
+                        }
+                        GeneratedField::TransactionResult => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("transactionResult"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::TransactionResult)
+;
+                        }
+                        GeneratedField::CoreSnapshotEvent => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("coreSnapshotEvent"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::CoreSnapshotEvent)
+;
+                        }
+                        GeneratedField::ProtocolUpgradeDataNodeReady => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("protocolUpgradeDataNodeReady"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::ProtocolUpgradeDataNodeReady)
+;
+                        }
+                        GeneratedField::DistressedOrders => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("distressedOrders"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::DistressedOrders)
+;
+                        }
+                        GeneratedField::ExpiredOrders => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("expiredOrders"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::ExpiredOrders)
+;
+                        }
+                        GeneratedField::DistressedPositions => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("distressedPositions"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::DistressedPositions)
+;
+                        }
+                        GeneratedField::StopOrder => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stopOrder"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::StopOrder)
+;
+                        }
+                        GeneratedField::FundingPeriod => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fundingPeriod"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::FundingPeriod)
+;
+                        }
+                        GeneratedField::FundingPeriodDataPoint => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fundingPeriodDataPoint"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::FundingPeriodDataPoint)
+;
+                        }
+                        GeneratedField::TeamCreated => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("teamCreated"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::TeamCreated)
+;
+                        }
+                        GeneratedField::TeamUpdated => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("teamUpdated"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::TeamUpdated)
+;
+                        }
+                        GeneratedField::RefereeSwitchedTeam => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("refereeSwitchedTeam"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::RefereeSwitchedTeam)
+;
+                        }
+                        GeneratedField::RefereeJoinedTeam => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("refereeJoinedTeam"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::RefereeJoinedTeam)
+;
+                        }
+                        GeneratedField::ReferralProgramStarted => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("referralProgramStarted"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::ReferralProgramStarted)
+;
+                        }
+                        GeneratedField::ReferralProgramUpdated => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("referralProgramUpdated"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::ReferralProgramUpdated)
+;
+                        }
+                        GeneratedField::ReferralProgramEnded => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("referralProgramEnded"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::ReferralProgramEnded)
+;
+                        }
+                        GeneratedField::ReferralSetCreated => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("referralSetCreated"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::ReferralSetCreated)
+;
+                        }
+                        GeneratedField::RefereeJoinedReferralSet => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("refereeJoinedReferralSet"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::RefereeJoinedReferralSet)
+;
+                        }
+                        GeneratedField::PartyActivityStreak => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("partyActivityStreak"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::PartyActivityStreak)
+;
+                        }
+                        GeneratedField::VolumeDiscountProgramStarted => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("volumeDiscountProgramStarted"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::VolumeDiscountProgramStarted)
+;
+                        }
+                        GeneratedField::VolumeDiscountProgramUpdated => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("volumeDiscountProgramUpdated"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::VolumeDiscountProgramUpdated)
+;
+                        }
+                        GeneratedField::VolumeDiscountProgramEnded => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("volumeDiscountProgramEnded"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::VolumeDiscountProgramEnded)
+;
+                        }
+                        GeneratedField::ReferralSetStatsUpdated => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("referralSetStatsUpdated"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::ReferralSetStatsUpdated)
+;
+                        }
+                        GeneratedField::VestingStatsUpdated => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("vestingStatsUpdated"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::VestingStatsUpdated)
+;
+                        }
+                        GeneratedField::VolumeDiscountStatsUpdated => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("volumeDiscountStatsUpdated"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::VolumeDiscountStatsUpdated)
+;
+                        }
+                        GeneratedField::FeesStats => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feesStats"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::FeesStats)
+;
+                        }
+                        GeneratedField::FundingPayments => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fundingPayments"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::FundingPayments)
+;
+                        }
+                        GeneratedField::PaidLiquidityFeesStats => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("paidLiquidityFeesStats"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::PaidLiquidityFeesStats)
+;
+                        }
+                        GeneratedField::VestingBalancesSummary => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("vestingBalancesSummary"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::VestingBalancesSummary)
+;
+                        }
+                        GeneratedField::TransferFees => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("transferFees"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::TransferFees)
+;
+                        }
+                        GeneratedField::TransferFeesDiscount => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("transferFeesDiscount"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::TransferFeesDiscount)
+;
+                        }
+                        GeneratedField::PartyMarginModeUpdated => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("partyMarginModeUpdated"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::PartyMarginModeUpdated)
+;
+                        }
+                        GeneratedField::PartyProfileUpdated => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("partyProfileUpdated"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::PartyProfileUpdated)
+;
+                        }
+                        GeneratedField::TeamsStatsUpdated => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("teamsStatsUpdated"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::TeamsStatsUpdated)
+;
+                        }
+                        GeneratedField::TimeWeightedNotionalPositionUpdated => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timeWeightedNotionalPositionUpdated"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::TimeWeightedNotionalPositionUpdated)
+;
+                        }
+                        GeneratedField::CancelledOrders => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("cancelledOrders"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::CancelledOrders)
+;
+                        }
+                        GeneratedField::GameScores => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("gameScores"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::GameScores)
+;
+                        }
+                        GeneratedField::Amm => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amm"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::Amm)
+;
+                        }
+                        GeneratedField::VolumeRebateProgramStarted => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("volumeRebateProgramStarted"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::VolumeRebateProgramStarted)
+;
+                        }
+                        GeneratedField::VolumeRebateProgramUpdated => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("volumeRebateProgramUpdated"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::VolumeRebateProgramUpdated)
+;
+                        }
+                        GeneratedField::VolumeRebateProgramEnded => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("volumeRebateProgramEnded"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::VolumeRebateProgramEnded)
+;
+                        }
+                        GeneratedField::VolumeRebateStatsUpdated => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("volumeRebateStatsUpdated"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::VolumeRebateStatsUpdated)
+;
+                        }
+                        GeneratedField::Market => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("market"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::Market)
+;
+                        }
+                        GeneratedField::TxErrEvent => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("txErrEvent"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(bus_event::Event::TxErrEvent)
+;
+                        }
+                    }
+                }
+                Ok(BusEvent {
+                    id: id__.unwrap_or_default(),
+                    block: block__.unwrap_or_default(),
+                    r#type: r#type__.unwrap_or_default(),
+                    version: version__.unwrap_or_default(),
+                    chain_id: chain_id__.unwrap_or_default(),
+                    tx_hash: tx_hash__.unwrap_or_default(),
+                    event: event__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.BusEvent", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for BusEventType {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "BUS_EVENT_TYPE_UNSPECIFIED",
+            Self::All => "BUS_EVENT_TYPE_ALL",
+            Self::TimeUpdate => "BUS_EVENT_TYPE_TIME_UPDATE",
+            Self::LedgerMovements => "BUS_EVENT_TYPE_LEDGER_MOVEMENTS",
+            Self::PositionResolution => "BUS_EVENT_TYPE_POSITION_RESOLUTION",
+            Self::Order => "BUS_EVENT_TYPE_ORDER",
+            Self::Account => "BUS_EVENT_TYPE_ACCOUNT",
+            Self::Party => "BUS_EVENT_TYPE_PARTY",
+            Self::Trade => "BUS_EVENT_TYPE_TRADE",
+            Self::MarginLevels => "BUS_EVENT_TYPE_MARGIN_LEVELS",
+            Self::Proposal => "BUS_EVENT_TYPE_PROPOSAL",
+            Self::Vote => "BUS_EVENT_TYPE_VOTE",
+            Self::MarketData => "BUS_EVENT_TYPE_MARKET_DATA",
+            Self::NodeSignature => "BUS_EVENT_TYPE_NODE_SIGNATURE",
+            Self::LossSocialization => "BUS_EVENT_TYPE_LOSS_SOCIALIZATION",
+            Self::SettlePosition => "BUS_EVENT_TYPE_SETTLE_POSITION",
+            Self::SettleDistressed => "BUS_EVENT_TYPE_SETTLE_DISTRESSED",
+            Self::MarketCreated => "BUS_EVENT_TYPE_MARKET_CREATED",
+            Self::Asset => "BUS_EVENT_TYPE_ASSET",
+            Self::MarketTick => "BUS_EVENT_TYPE_MARKET_TICK",
+            Self::Withdrawal => "BUS_EVENT_TYPE_WITHDRAWAL",
+            Self::Deposit => "BUS_EVENT_TYPE_DEPOSIT",
+            Self::Auction => "BUS_EVENT_TYPE_AUCTION",
+            Self::RiskFactor => "BUS_EVENT_TYPE_RISK_FACTOR",
+            Self::NetworkParameter => "BUS_EVENT_TYPE_NETWORK_PARAMETER",
+            Self::LiquidityProvision => "BUS_EVENT_TYPE_LIQUIDITY_PROVISION",
+            Self::MarketUpdated => "BUS_EVENT_TYPE_MARKET_UPDATED",
+            Self::OracleSpec => "BUS_EVENT_TYPE_ORACLE_SPEC",
+            Self::OracleData => "BUS_EVENT_TYPE_ORACLE_DATA",
+            Self::DelegationBalance => "BUS_EVENT_TYPE_DELEGATION_BALANCE",
+            Self::ValidatorScore => "BUS_EVENT_TYPE_VALIDATOR_SCORE",
+            Self::EpochUpdate => "BUS_EVENT_TYPE_EPOCH_UPDATE",
+            Self::ValidatorUpdate => "BUS_EVENT_TYPE_VALIDATOR_UPDATE",
+            Self::StakeLinking => "BUS_EVENT_TYPE_STAKE_LINKING",
+            Self::RewardPayoutEvent => "BUS_EVENT_TYPE_REWARD_PAYOUT_EVENT",
+            Self::Checkpoint => "BUS_EVENT_TYPE_CHECKPOINT",
+            Self::StreamStart => "BUS_EVENT_TYPE_STREAM_START",
+            Self::KeyRotation => "BUS_EVENT_TYPE_KEY_ROTATION",
+            Self::StateVar => "BUS_EVENT_TYPE_STATE_VAR",
+            Self::NetworkLimits => "BUS_EVENT_TYPE_NETWORK_LIMITS",
+            Self::Transfer => "BUS_EVENT_TYPE_TRANSFER",
+            Self::ValidatorRanking => "BUS_EVENT_TYPE_VALIDATOR_RANKING",
+            Self::Erc20MultiSigSignerEvent => "BUS_EVENT_TYPE_ERC20_MULTI_SIG_SIGNER_EVENT",
+            Self::Erc20MultiSigSetThreshold => "BUS_EVENT_TYPE_ERC20_MULTI_SIG_SET_THRESHOLD",
+            Self::Erc20MultiSigSignerAdded => "BUS_EVENT_TYPE_ERC20_MULTI_SIG_SIGNER_ADDED",
+            Self::Erc20MultiSigSignerRemoved => "BUS_EVENT_TYPE_ERC20_MULTI_SIG_SIGNER_REMOVED",
+            Self::PositionState => "BUS_EVENT_TYPE_POSITION_STATE",
+            Self::EthereumKeyRotation => "BUS_EVENT_TYPE_ETHEREUM_KEY_ROTATION",
+            Self::ProtocolUpgradeProposal => "BUS_EVENT_TYPE_PROTOCOL_UPGRADE_PROPOSAL",
+            Self::BeginBlock => "BUS_EVENT_TYPE_BEGIN_BLOCK",
+            Self::EndBlock => "BUS_EVENT_TYPE_END_BLOCK",
+            Self::ProtocolUpgradeStarted => "BUS_EVENT_TYPE_PROTOCOL_UPGRADE_STARTED",
+            Self::SettleMarket => "BUS_EVENT_TYPE_SETTLE_MARKET",
+            Self::TransactionResult => "BUS_EVENT_TYPE_TRANSACTION_RESULT",
+            Self::SnapshotTaken => "BUS_EVENT_TYPE_SNAPSHOT_TAKEN",
+            Self::ProtocolUpgradeDataNodeReady => "BUS_EVENT_TYPE_PROTOCOL_UPGRADE_DATA_NODE_READY",
+            Self::DistressedOrdersClosed => "BUS_EVENT_TYPE_DISTRESSED_ORDERS_CLOSED",
+            Self::ExpiredOrders => "BUS_EVENT_TYPE_EXPIRED_ORDERS",
+            Self::DistressedPositions => "BUS_EVENT_TYPE_DISTRESSED_POSITIONS",
+            Self::SpotLiquidityProvision => "BUS_EVENT_TYPE_SPOT_LIQUIDITY_PROVISION",
+            Self::StopOrder => "BUS_EVENT_TYPE_STOP_ORDER",
+            Self::FundingPeriod => "BUS_EVENT_TYPE_FUNDING_PERIOD",
+            Self::FundingPeriodDataPoint => "BUS_EVENT_TYPE_FUNDING_PERIOD_DATA_POINT",
+            Self::TeamCreated => "BUS_EVENT_TYPE_TEAM_CREATED",
+            Self::TeamUpdated => "BUS_EVENT_TYPE_TEAM_UPDATED",
+            Self::RefereeSwitchedTeam => "BUS_EVENT_TYPE_REFEREE_SWITCHED_TEAM",
+            Self::RefereeJoinedTeam => "BUS_EVENT_TYPE_REFEREE_JOINED_TEAM",
+            Self::ReferralProgramStarted => "BUS_EVENT_TYPE_REFERRAL_PROGRAM_STARTED",
+            Self::ReferralProgramUpdated => "BUS_EVENT_TYPE_REFERRAL_PROGRAM_UPDATED",
+            Self::ReferralProgramEnded => "BUS_EVENT_TYPE_REFERRAL_PROGRAM_ENDED",
+            Self::ReferralSetCreated => "BUS_EVENT_TYPE_REFERRAL_SET_CREATED",
+            Self::RefereeJoinedReferralSet => "BUS_EVENT_TYPE_REFEREE_JOINED_REFERRAL_SET",
+            Self::PartyActivityStreak => "BUS_EVENT_TYPE_PARTY_ACTIVITY_STREAK",
+            Self::VolumeDiscountProgramStarted => "BUS_EVENT_TYPE_VOLUME_DISCOUNT_PROGRAM_STARTED",
+            Self::VolumeDiscountProgramUpdated => "BUS_EVENT_TYPE_VOLUME_DISCOUNT_PROGRAM_UPDATED",
+            Self::VolumeDiscountProgramEnded => "BUS_EVENT_TYPE_VOLUME_DISCOUNT_PROGRAM_ENDED",
+            Self::ReferralSetStatsUpdated => "BUS_EVENT_TYPE_REFERRAL_SET_STATS_UPDATED",
+            Self::VestingStatsUpdated => "BUS_EVENT_TYPE_VESTING_STATS_UPDATED",
+            Self::VolumeDiscountStatsUpdated => "BUS_EVENT_TYPE_VOLUME_DISCOUNT_STATS_UPDATED",
+            Self::FeesStatsUpdated => "BUS_EVENT_TYPE_FEES_STATS_UPDATED",
+            Self::FundingPayments => "BUS_EVENT_TYPE_FUNDING_PAYMENTS",
+            Self::PaidLiquidityFeesStatsUpdated => "BUS_EVENT_TYPE_PAID_LIQUIDITY_FEES_STATS_UPDATED",
+            Self::VestingSummary => "BUS_EVENT_TYPE_VESTING_SUMMARY",
+            Self::TransferFeesPaid => "BUS_EVENT_TYPE_TRANSFER_FEES_PAID",
+            Self::TransferFeesDiscountUpdated => "BUS_EVENT_TYPE_TRANSFER_FEES_DISCOUNT_UPDATED",
+            Self::PartyMarginModeUpdated => "BUS_EVENT_TYPE_PARTY_MARGIN_MODE_UPDATED",
+            Self::PartyProfileUpdated => "BUS_EVENT_TYPE_PARTY_PROFILE_UPDATED",
+            Self::TeamsStatsUpdated => "BUS_EVENT_TYPE_TEAMS_STATS_UPDATED",
+            Self::TimeWeightedNotionalPositionUpdated => "BUS_EVENT_TYPE_TIME_WEIGHTED_NOTIONAL_POSITION_UPDATED",
+            Self::CancelledOrders => "BUS_EVENT_TYPE_CANCELLED_ORDERS",
+            Self::GameScores => "BUS_EVENT_TYPE_GAME_SCORES",
+            Self::Amm => "BUS_EVENT_TYPE_AMM",
+            Self::VolumeRebateProgramStarted => "BUS_EVENT_TYPE_VOLUME_REBATE_PROGRAM_STARTED",
+            Self::VolumeRebateProgramUpdated => "BUS_EVENT_TYPE_VOLUME_REBATE_PROGRAM_UPDATED",
+            Self::VolumeRebateProgramEnded => "BUS_EVENT_TYPE_VOLUME_REBATE_PROGRAM_ENDED",
+            Self::VolumeRebateStatsUpdated => "BUS_EVENT_TYPE_VOLUME_REBATE_STATS_UPDATED",
+            Self::Market => "BUS_EVENT_TYPE_MARKET",
+            Self::TxError => "BUS_EVENT_TYPE_TX_ERROR",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for BusEventType {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "BUS_EVENT_TYPE_UNSPECIFIED",
+            "BUS_EVENT_TYPE_ALL",
+            "BUS_EVENT_TYPE_TIME_UPDATE",
+            "BUS_EVENT_TYPE_LEDGER_MOVEMENTS",
+            "BUS_EVENT_TYPE_POSITION_RESOLUTION",
+            "BUS_EVENT_TYPE_ORDER",
+            "BUS_EVENT_TYPE_ACCOUNT",
+            "BUS_EVENT_TYPE_PARTY",
+            "BUS_EVENT_TYPE_TRADE",
+            "BUS_EVENT_TYPE_MARGIN_LEVELS",
+            "BUS_EVENT_TYPE_PROPOSAL",
+            "BUS_EVENT_TYPE_VOTE",
+            "BUS_EVENT_TYPE_MARKET_DATA",
+            "BUS_EVENT_TYPE_NODE_SIGNATURE",
+            "BUS_EVENT_TYPE_LOSS_SOCIALIZATION",
+            "BUS_EVENT_TYPE_SETTLE_POSITION",
+            "BUS_EVENT_TYPE_SETTLE_DISTRESSED",
+            "BUS_EVENT_TYPE_MARKET_CREATED",
+            "BUS_EVENT_TYPE_ASSET",
+            "BUS_EVENT_TYPE_MARKET_TICK",
+            "BUS_EVENT_TYPE_WITHDRAWAL",
+            "BUS_EVENT_TYPE_DEPOSIT",
+            "BUS_EVENT_TYPE_AUCTION",
+            "BUS_EVENT_TYPE_RISK_FACTOR",
+            "BUS_EVENT_TYPE_NETWORK_PARAMETER",
+            "BUS_EVENT_TYPE_LIQUIDITY_PROVISION",
+            "BUS_EVENT_TYPE_MARKET_UPDATED",
+            "BUS_EVENT_TYPE_ORACLE_SPEC",
+            "BUS_EVENT_TYPE_ORACLE_DATA",
+            "BUS_EVENT_TYPE_DELEGATION_BALANCE",
+            "BUS_EVENT_TYPE_VALIDATOR_SCORE",
+            "BUS_EVENT_TYPE_EPOCH_UPDATE",
+            "BUS_EVENT_TYPE_VALIDATOR_UPDATE",
+            "BUS_EVENT_TYPE_STAKE_LINKING",
+            "BUS_EVENT_TYPE_REWARD_PAYOUT_EVENT",
+            "BUS_EVENT_TYPE_CHECKPOINT",
+            "BUS_EVENT_TYPE_STREAM_START",
+            "BUS_EVENT_TYPE_KEY_ROTATION",
+            "BUS_EVENT_TYPE_STATE_VAR",
+            "BUS_EVENT_TYPE_NETWORK_LIMITS",
+            "BUS_EVENT_TYPE_TRANSFER",
+            "BUS_EVENT_TYPE_VALIDATOR_RANKING",
+            "BUS_EVENT_TYPE_ERC20_MULTI_SIG_SIGNER_EVENT",
+            "BUS_EVENT_TYPE_ERC20_MULTI_SIG_SET_THRESHOLD",
+            "BUS_EVENT_TYPE_ERC20_MULTI_SIG_SIGNER_ADDED",
+            "BUS_EVENT_TYPE_ERC20_MULTI_SIG_SIGNER_REMOVED",
+            "BUS_EVENT_TYPE_POSITION_STATE",
+            "BUS_EVENT_TYPE_ETHEREUM_KEY_ROTATION",
+            "BUS_EVENT_TYPE_PROTOCOL_UPGRADE_PROPOSAL",
+            "BUS_EVENT_TYPE_BEGIN_BLOCK",
+            "BUS_EVENT_TYPE_END_BLOCK",
+            "BUS_EVENT_TYPE_PROTOCOL_UPGRADE_STARTED",
+            "BUS_EVENT_TYPE_SETTLE_MARKET",
+            "BUS_EVENT_TYPE_TRANSACTION_RESULT",
+            "BUS_EVENT_TYPE_SNAPSHOT_TAKEN",
+            "BUS_EVENT_TYPE_PROTOCOL_UPGRADE_DATA_NODE_READY",
+            "BUS_EVENT_TYPE_DISTRESSED_ORDERS_CLOSED",
+            "BUS_EVENT_TYPE_EXPIRED_ORDERS",
+            "BUS_EVENT_TYPE_DISTRESSED_POSITIONS",
+            "BUS_EVENT_TYPE_SPOT_LIQUIDITY_PROVISION",
+            "BUS_EVENT_TYPE_STOP_ORDER",
+            "BUS_EVENT_TYPE_FUNDING_PERIOD",
+            "BUS_EVENT_TYPE_FUNDING_PERIOD_DATA_POINT",
+            "BUS_EVENT_TYPE_TEAM_CREATED",
+            "BUS_EVENT_TYPE_TEAM_UPDATED",
+            "BUS_EVENT_TYPE_REFEREE_SWITCHED_TEAM",
+            "BUS_EVENT_TYPE_REFEREE_JOINED_TEAM",
+            "BUS_EVENT_TYPE_REFERRAL_PROGRAM_STARTED",
+            "BUS_EVENT_TYPE_REFERRAL_PROGRAM_UPDATED",
+            "BUS_EVENT_TYPE_REFERRAL_PROGRAM_ENDED",
+            "BUS_EVENT_TYPE_REFERRAL_SET_CREATED",
+            "BUS_EVENT_TYPE_REFEREE_JOINED_REFERRAL_SET",
+            "BUS_EVENT_TYPE_PARTY_ACTIVITY_STREAK",
+            "BUS_EVENT_TYPE_VOLUME_DISCOUNT_PROGRAM_STARTED",
+            "BUS_EVENT_TYPE_VOLUME_DISCOUNT_PROGRAM_UPDATED",
+            "BUS_EVENT_TYPE_VOLUME_DISCOUNT_PROGRAM_ENDED",
+            "BUS_EVENT_TYPE_REFERRAL_SET_STATS_UPDATED",
+            "BUS_EVENT_TYPE_VESTING_STATS_UPDATED",
+            "BUS_EVENT_TYPE_VOLUME_DISCOUNT_STATS_UPDATED",
+            "BUS_EVENT_TYPE_FEES_STATS_UPDATED",
+            "BUS_EVENT_TYPE_FUNDING_PAYMENTS",
+            "BUS_EVENT_TYPE_PAID_LIQUIDITY_FEES_STATS_UPDATED",
+            "BUS_EVENT_TYPE_VESTING_SUMMARY",
+            "BUS_EVENT_TYPE_TRANSFER_FEES_PAID",
+            "BUS_EVENT_TYPE_TRANSFER_FEES_DISCOUNT_UPDATED",
+            "BUS_EVENT_TYPE_PARTY_MARGIN_MODE_UPDATED",
+            "BUS_EVENT_TYPE_PARTY_PROFILE_UPDATED",
+            "BUS_EVENT_TYPE_TEAMS_STATS_UPDATED",
+            "BUS_EVENT_TYPE_TIME_WEIGHTED_NOTIONAL_POSITION_UPDATED",
+            "BUS_EVENT_TYPE_CANCELLED_ORDERS",
+            "BUS_EVENT_TYPE_GAME_SCORES",
+            "BUS_EVENT_TYPE_AMM",
+            "BUS_EVENT_TYPE_VOLUME_REBATE_PROGRAM_STARTED",
+            "BUS_EVENT_TYPE_VOLUME_REBATE_PROGRAM_UPDATED",
+            "BUS_EVENT_TYPE_VOLUME_REBATE_PROGRAM_ENDED",
+            "BUS_EVENT_TYPE_VOLUME_REBATE_STATS_UPDATED",
+            "BUS_EVENT_TYPE_MARKET",
+            "BUS_EVENT_TYPE_TX_ERROR",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BusEventType;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "BUS_EVENT_TYPE_UNSPECIFIED" => Ok(BusEventType::Unspecified),
+                    "BUS_EVENT_TYPE_ALL" => Ok(BusEventType::All),
+                    "BUS_EVENT_TYPE_TIME_UPDATE" => Ok(BusEventType::TimeUpdate),
+                    "BUS_EVENT_TYPE_LEDGER_MOVEMENTS" => Ok(BusEventType::LedgerMovements),
+                    "BUS_EVENT_TYPE_POSITION_RESOLUTION" => Ok(BusEventType::PositionResolution),
+                    "BUS_EVENT_TYPE_ORDER" => Ok(BusEventType::Order),
+                    "BUS_EVENT_TYPE_ACCOUNT" => Ok(BusEventType::Account),
+                    "BUS_EVENT_TYPE_PARTY" => Ok(BusEventType::Party),
+                    "BUS_EVENT_TYPE_TRADE" => Ok(BusEventType::Trade),
+                    "BUS_EVENT_TYPE_MARGIN_LEVELS" => Ok(BusEventType::MarginLevels),
+                    "BUS_EVENT_TYPE_PROPOSAL" => Ok(BusEventType::Proposal),
+                    "BUS_EVENT_TYPE_VOTE" => Ok(BusEventType::Vote),
+                    "BUS_EVENT_TYPE_MARKET_DATA" => Ok(BusEventType::MarketData),
+                    "BUS_EVENT_TYPE_NODE_SIGNATURE" => Ok(BusEventType::NodeSignature),
+                    "BUS_EVENT_TYPE_LOSS_SOCIALIZATION" => Ok(BusEventType::LossSocialization),
+                    "BUS_EVENT_TYPE_SETTLE_POSITION" => Ok(BusEventType::SettlePosition),
+                    "BUS_EVENT_TYPE_SETTLE_DISTRESSED" => Ok(BusEventType::SettleDistressed),
+                    "BUS_EVENT_TYPE_MARKET_CREATED" => Ok(BusEventType::MarketCreated),
+                    "BUS_EVENT_TYPE_ASSET" => Ok(BusEventType::Asset),
+                    "BUS_EVENT_TYPE_MARKET_TICK" => Ok(BusEventType::MarketTick),
+                    "BUS_EVENT_TYPE_WITHDRAWAL" => Ok(BusEventType::Withdrawal),
+                    "BUS_EVENT_TYPE_DEPOSIT" => Ok(BusEventType::Deposit),
+                    "BUS_EVENT_TYPE_AUCTION" => Ok(BusEventType::Auction),
+                    "BUS_EVENT_TYPE_RISK_FACTOR" => Ok(BusEventType::RiskFactor),
+                    "BUS_EVENT_TYPE_NETWORK_PARAMETER" => Ok(BusEventType::NetworkParameter),
+                    "BUS_EVENT_TYPE_LIQUIDITY_PROVISION" => Ok(BusEventType::LiquidityProvision),
+                    "BUS_EVENT_TYPE_MARKET_UPDATED" => Ok(BusEventType::MarketUpdated),
+                    "BUS_EVENT_TYPE_ORACLE_SPEC" => Ok(BusEventType::OracleSpec),
+                    "BUS_EVENT_TYPE_ORACLE_DATA" => Ok(BusEventType::OracleData),
+                    "BUS_EVENT_TYPE_DELEGATION_BALANCE" => Ok(BusEventType::DelegationBalance),
+                    "BUS_EVENT_TYPE_VALIDATOR_SCORE" => Ok(BusEventType::ValidatorScore),
+                    "BUS_EVENT_TYPE_EPOCH_UPDATE" => Ok(BusEventType::EpochUpdate),
+                    "BUS_EVENT_TYPE_VALIDATOR_UPDATE" => Ok(BusEventType::ValidatorUpdate),
+                    "BUS_EVENT_TYPE_STAKE_LINKING" => Ok(BusEventType::StakeLinking),
+                    "BUS_EVENT_TYPE_REWARD_PAYOUT_EVENT" => Ok(BusEventType::RewardPayoutEvent),
+                    "BUS_EVENT_TYPE_CHECKPOINT" => Ok(BusEventType::Checkpoint),
+                    "BUS_EVENT_TYPE_STREAM_START" => Ok(BusEventType::StreamStart),
+                    "BUS_EVENT_TYPE_KEY_ROTATION" => Ok(BusEventType::KeyRotation),
+                    "BUS_EVENT_TYPE_STATE_VAR" => Ok(BusEventType::StateVar),
+                    "BUS_EVENT_TYPE_NETWORK_LIMITS" => Ok(BusEventType::NetworkLimits),
+                    "BUS_EVENT_TYPE_TRANSFER" => Ok(BusEventType::Transfer),
+                    "BUS_EVENT_TYPE_VALIDATOR_RANKING" => Ok(BusEventType::ValidatorRanking),
+                    "BUS_EVENT_TYPE_ERC20_MULTI_SIG_SIGNER_EVENT" => Ok(BusEventType::Erc20MultiSigSignerEvent),
+                    "BUS_EVENT_TYPE_ERC20_MULTI_SIG_SET_THRESHOLD" => Ok(BusEventType::Erc20MultiSigSetThreshold),
+                    "BUS_EVENT_TYPE_ERC20_MULTI_SIG_SIGNER_ADDED" => Ok(BusEventType::Erc20MultiSigSignerAdded),
+                    "BUS_EVENT_TYPE_ERC20_MULTI_SIG_SIGNER_REMOVED" => Ok(BusEventType::Erc20MultiSigSignerRemoved),
+                    "BUS_EVENT_TYPE_POSITION_STATE" => Ok(BusEventType::PositionState),
+                    "BUS_EVENT_TYPE_ETHEREUM_KEY_ROTATION" => Ok(BusEventType::EthereumKeyRotation),
+                    "BUS_EVENT_TYPE_PROTOCOL_UPGRADE_PROPOSAL" => Ok(BusEventType::ProtocolUpgradeProposal),
+                    "BUS_EVENT_TYPE_BEGIN_BLOCK" => Ok(BusEventType::BeginBlock),
+                    "BUS_EVENT_TYPE_END_BLOCK" => Ok(BusEventType::EndBlock),
+                    "BUS_EVENT_TYPE_PROTOCOL_UPGRADE_STARTED" => Ok(BusEventType::ProtocolUpgradeStarted),
+                    "BUS_EVENT_TYPE_SETTLE_MARKET" => Ok(BusEventType::SettleMarket),
+                    "BUS_EVENT_TYPE_TRANSACTION_RESULT" => Ok(BusEventType::TransactionResult),
+                    "BUS_EVENT_TYPE_SNAPSHOT_TAKEN" => Ok(BusEventType::SnapshotTaken),
+                    "BUS_EVENT_TYPE_PROTOCOL_UPGRADE_DATA_NODE_READY" => Ok(BusEventType::ProtocolUpgradeDataNodeReady),
+                    "BUS_EVENT_TYPE_DISTRESSED_ORDERS_CLOSED" => Ok(BusEventType::DistressedOrdersClosed),
+                    "BUS_EVENT_TYPE_EXPIRED_ORDERS" => Ok(BusEventType::ExpiredOrders),
+                    "BUS_EVENT_TYPE_DISTRESSED_POSITIONS" => Ok(BusEventType::DistressedPositions),
+                    "BUS_EVENT_TYPE_SPOT_LIQUIDITY_PROVISION" => Ok(BusEventType::SpotLiquidityProvision),
+                    "BUS_EVENT_TYPE_STOP_ORDER" => Ok(BusEventType::StopOrder),
+                    "BUS_EVENT_TYPE_FUNDING_PERIOD" => Ok(BusEventType::FundingPeriod),
+                    "BUS_EVENT_TYPE_FUNDING_PERIOD_DATA_POINT" => Ok(BusEventType::FundingPeriodDataPoint),
+                    "BUS_EVENT_TYPE_TEAM_CREATED" => Ok(BusEventType::TeamCreated),
+                    "BUS_EVENT_TYPE_TEAM_UPDATED" => Ok(BusEventType::TeamUpdated),
+                    "BUS_EVENT_TYPE_REFEREE_SWITCHED_TEAM" => Ok(BusEventType::RefereeSwitchedTeam),
+                    "BUS_EVENT_TYPE_REFEREE_JOINED_TEAM" => Ok(BusEventType::RefereeJoinedTeam),
+                    "BUS_EVENT_TYPE_REFERRAL_PROGRAM_STARTED" => Ok(BusEventType::ReferralProgramStarted),
+                    "BUS_EVENT_TYPE_REFERRAL_PROGRAM_UPDATED" => Ok(BusEventType::ReferralProgramUpdated),
+                    "BUS_EVENT_TYPE_REFERRAL_PROGRAM_ENDED" => Ok(BusEventType::ReferralProgramEnded),
+                    "BUS_EVENT_TYPE_REFERRAL_SET_CREATED" => Ok(BusEventType::ReferralSetCreated),
+                    "BUS_EVENT_TYPE_REFEREE_JOINED_REFERRAL_SET" => Ok(BusEventType::RefereeJoinedReferralSet),
+                    "BUS_EVENT_TYPE_PARTY_ACTIVITY_STREAK" => Ok(BusEventType::PartyActivityStreak),
+                    "BUS_EVENT_TYPE_VOLUME_DISCOUNT_PROGRAM_STARTED" => Ok(BusEventType::VolumeDiscountProgramStarted),
+                    "BUS_EVENT_TYPE_VOLUME_DISCOUNT_PROGRAM_UPDATED" => Ok(BusEventType::VolumeDiscountProgramUpdated),
+                    "BUS_EVENT_TYPE_VOLUME_DISCOUNT_PROGRAM_ENDED" => Ok(BusEventType::VolumeDiscountProgramEnded),
+                    "BUS_EVENT_TYPE_REFERRAL_SET_STATS_UPDATED" => Ok(BusEventType::ReferralSetStatsUpdated),
+                    "BUS_EVENT_TYPE_VESTING_STATS_UPDATED" => Ok(BusEventType::VestingStatsUpdated),
+                    "BUS_EVENT_TYPE_VOLUME_DISCOUNT_STATS_UPDATED" => Ok(BusEventType::VolumeDiscountStatsUpdated),
+                    "BUS_EVENT_TYPE_FEES_STATS_UPDATED" => Ok(BusEventType::FeesStatsUpdated),
+                    "BUS_EVENT_TYPE_FUNDING_PAYMENTS" => Ok(BusEventType::FundingPayments),
+                    "BUS_EVENT_TYPE_PAID_LIQUIDITY_FEES_STATS_UPDATED" => Ok(BusEventType::PaidLiquidityFeesStatsUpdated),
+                    "BUS_EVENT_TYPE_VESTING_SUMMARY" => Ok(BusEventType::VestingSummary),
+                    "BUS_EVENT_TYPE_TRANSFER_FEES_PAID" => Ok(BusEventType::TransferFeesPaid),
+                    "BUS_EVENT_TYPE_TRANSFER_FEES_DISCOUNT_UPDATED" => Ok(BusEventType::TransferFeesDiscountUpdated),
+                    "BUS_EVENT_TYPE_PARTY_MARGIN_MODE_UPDATED" => Ok(BusEventType::PartyMarginModeUpdated),
+                    "BUS_EVENT_TYPE_PARTY_PROFILE_UPDATED" => Ok(BusEventType::PartyProfileUpdated),
+                    "BUS_EVENT_TYPE_TEAMS_STATS_UPDATED" => Ok(BusEventType::TeamsStatsUpdated),
+                    "BUS_EVENT_TYPE_TIME_WEIGHTED_NOTIONAL_POSITION_UPDATED" => Ok(BusEventType::TimeWeightedNotionalPositionUpdated),
+                    "BUS_EVENT_TYPE_CANCELLED_ORDERS" => Ok(BusEventType::CancelledOrders),
+                    "BUS_EVENT_TYPE_GAME_SCORES" => Ok(BusEventType::GameScores),
+                    "BUS_EVENT_TYPE_AMM" => Ok(BusEventType::Amm),
+                    "BUS_EVENT_TYPE_VOLUME_REBATE_PROGRAM_STARTED" => Ok(BusEventType::VolumeRebateProgramStarted),
+                    "BUS_EVENT_TYPE_VOLUME_REBATE_PROGRAM_UPDATED" => Ok(BusEventType::VolumeRebateProgramUpdated),
+                    "BUS_EVENT_TYPE_VOLUME_REBATE_PROGRAM_ENDED" => Ok(BusEventType::VolumeRebateProgramEnded),
+                    "BUS_EVENT_TYPE_VOLUME_REBATE_STATS_UPDATED" => Ok(BusEventType::VolumeRebateStatsUpdated),
+                    "BUS_EVENT_TYPE_MARKET" => Ok(BusEventType::Market),
+                    "BUS_EVENT_TYPE_TX_ERROR" => Ok(BusEventType::TxError),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for CancelledOrders {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.market_id.is_empty() {
+            len += 1;
+        }
+        if !self.party_id.is_empty() {
+            len += 1;
+        }
+        if !self.order_ids.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.CancelledOrders", len)?;
+        if !self.market_id.is_empty() {
+            struct_ser.serialize_field("marketId", &self.market_id)?;
+        }
+        if !self.party_id.is_empty() {
+            struct_ser.serialize_field("partyId", &self.party_id)?;
+        }
+        if !self.order_ids.is_empty() {
+            struct_ser.serialize_field("orderIds", &self.order_ids)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for CancelledOrders {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "market_id",
+            "marketId",
+            "party_id",
+            "partyId",
+            "order_ids",
+            "orderIds",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            MarketId,
+            PartyId,
+            OrderIds,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "marketId" | "market_id" => Ok(GeneratedField::MarketId),
+                            "partyId" | "party_id" => Ok(GeneratedField::PartyId),
+                            "orderIds" | "order_ids" => Ok(GeneratedField::OrderIds),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CancelledOrders;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.CancelledOrders")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CancelledOrders, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut market_id__ = None;
+                let mut party_id__ = None;
+                let mut order_ids__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::MarketId => {
+                            if market_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("marketId"));
+                            }
+                            market_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::PartyId => {
+                            if party_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("partyId"));
+                            }
+                            party_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::OrderIds => {
+                            if order_ids__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("orderIds"));
+                            }
+                            order_ids__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(CancelledOrders {
+                    market_id: market_id__.unwrap_or_default(),
+                    party_id: party_id__.unwrap_or_default(),
+                    order_ids: order_ids__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.CancelledOrders", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for CheckpointEvent {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.hash.is_empty() {
+            len += 1;
+        }
+        if !self.block_hash.is_empty() {
+            len += 1;
+        }
+        if self.block_height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.CheckpointEvent", len)?;
+        if !self.hash.is_empty() {
+            struct_ser.serialize_field("hash", &self.hash)?;
+        }
+        if !self.block_hash.is_empty() {
+            struct_ser.serialize_field("blockHash", &self.block_hash)?;
+        }
+        if self.block_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("blockHeight", ToString::to_string(&self.block_height).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for CheckpointEvent {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "hash",
+            "block_hash",
+            "blockHash",
+            "block_height",
+            "blockHeight",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Hash,
+            BlockHash,
+            BlockHeight,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "hash" => Ok(GeneratedField::Hash),
+                            "blockHash" | "block_hash" => Ok(GeneratedField::BlockHash),
+                            "blockHeight" | "block_height" => Ok(GeneratedField::BlockHeight),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CheckpointEvent;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.CheckpointEvent")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CheckpointEvent, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut hash__ = None;
+                let mut block_hash__ = None;
+                let mut block_height__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Hash => {
+                            if hash__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("hash"));
+                            }
+                            hash__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::BlockHash => {
+                            if block_hash__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("blockHash"));
+                            }
+                            block_hash__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::BlockHeight => {
+                            if block_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("blockHeight"));
+                            }
+                            block_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(CheckpointEvent {
+                    hash: hash__.unwrap_or_default(),
+                    block_hash: block_hash__.unwrap_or_default(),
+                    block_height: block_height__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.CheckpointEvent", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for CoreSnapshotData {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.block_height != 0 {
+            len += 1;
+        }
+        if !self.block_hash.is_empty() {
+            len += 1;
+        }
+        if !self.core_version.is_empty() {
+            len += 1;
+        }
+        if self.protocol_upgrade_block {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.CoreSnapshotData", len)?;
+        if self.block_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("blockHeight", ToString::to_string(&self.block_height).as_str())?;
+        }
+        if !self.block_hash.is_empty() {
+            struct_ser.serialize_field("blockHash", &self.block_hash)?;
+        }
+        if !self.core_version.is_empty() {
+            struct_ser.serialize_field("coreVersion", &self.core_version)?;
+        }
+        if self.protocol_upgrade_block {
+            struct_ser.serialize_field("protocolUpgradeBlock", &self.protocol_upgrade_block)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for CoreSnapshotData {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "block_height",
+            "blockHeight",
+            "block_hash",
+            "blockHash",
+            "core_version",
+            "coreVersion",
+            "protocol_upgrade_block",
+            "protocolUpgradeBlock",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            BlockHeight,
+            BlockHash,
+            CoreVersion,
+            ProtocolUpgradeBlock,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "blockHeight" | "block_height" => Ok(GeneratedField::BlockHeight),
+                            "blockHash" | "block_hash" => Ok(GeneratedField::BlockHash),
+                            "coreVersion" | "core_version" => Ok(GeneratedField::CoreVersion),
+                            "protocolUpgradeBlock" | "protocol_upgrade_block" => Ok(GeneratedField::ProtocolUpgradeBlock),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CoreSnapshotData;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.CoreSnapshotData")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CoreSnapshotData, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut block_height__ = None;
+                let mut block_hash__ = None;
+                let mut core_version__ = None;
+                let mut protocol_upgrade_block__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::BlockHeight => {
+                            if block_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("blockHeight"));
+                            }
+                            block_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::BlockHash => {
+                            if block_hash__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("blockHash"));
+                            }
+                            block_hash__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::CoreVersion => {
+                            if core_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("coreVersion"));
+                            }
+                            core_version__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ProtocolUpgradeBlock => {
+                            if protocol_upgrade_block__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("protocolUpgradeBlock"));
+                            }
+                            protocol_upgrade_block__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(CoreSnapshotData {
+                    block_height: block_height__.unwrap_or_default(),
+                    block_hash: block_hash__.unwrap_or_default(),
+                    core_version: core_version__.unwrap_or_default(),
+                    protocol_upgrade_block: protocol_upgrade_block__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.CoreSnapshotData", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for DelegationBalanceEvent {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.party.is_empty() {
+            len += 1;
+        }
+        if !self.node_id.is_empty() {
+            len += 1;
+        }
+        if !self.amount.is_empty() {
+            len += 1;
+        }
+        if !self.epoch_seq.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.DelegationBalanceEvent", len)?;
+        if !self.party.is_empty() {
+            struct_ser.serialize_field("party", &self.party)?;
+        }
+        if !self.node_id.is_empty() {
+            struct_ser.serialize_field("nodeId", &self.node_id)?;
+        }
+        if !self.amount.is_empty() {
+            struct_ser.serialize_field("amount", &self.amount)?;
+        }
+        if !self.epoch_seq.is_empty() {
+            struct_ser.serialize_field("epochSeq", &self.epoch_seq)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for DelegationBalanceEvent {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "party",
+            "node_id",
+            "nodeId",
+            "amount",
+            "epoch_seq",
+            "epochSeq",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Party,
+            NodeId,
+            Amount,
+            EpochSeq,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "party" => Ok(GeneratedField::Party),
+                            "nodeId" | "node_id" => Ok(GeneratedField::NodeId),
+                            "amount" => Ok(GeneratedField::Amount),
+                            "epochSeq" | "epoch_seq" => Ok(GeneratedField::EpochSeq),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = DelegationBalanceEvent;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.DelegationBalanceEvent")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DelegationBalanceEvent, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut party__ = None;
+                let mut node_id__ = None;
+                let mut amount__ = None;
+                let mut epoch_seq__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Party => {
+                            if party__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("party"));
+                            }
+                            party__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::NodeId => {
+                            if node_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nodeId"));
+                            }
+                            node_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Amount => {
+                            if amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amount"));
+                            }
+                            amount__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::EpochSeq => {
+                            if epoch_seq__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epochSeq"));
+                            }
+                            epoch_seq__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(DelegationBalanceEvent {
+                    party: party__.unwrap_or_default(),
+                    node_id: node_id__.unwrap_or_default(),
+                    amount: amount__.unwrap_or_default(),
+                    epoch_seq: epoch_seq__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.DelegationBalanceEvent", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for DistressedOrders {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.market_id.is_empty() {
+            len += 1;
+        }
+        if !self.parties.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.DistressedOrders", len)?;
+        if !self.market_id.is_empty() {
+            struct_ser.serialize_field("marketId", &self.market_id)?;
+        }
+        if !self.parties.is_empty() {
+            struct_ser.serialize_field("parties", &self.parties)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for DistressedOrders {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "market_id",
+            "marketId",
+            "parties",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            MarketId,
+            Parties,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "marketId" | "market_id" => Ok(GeneratedField::MarketId),
+                            "parties" => Ok(GeneratedField::Parties),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = DistressedOrders;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.DistressedOrders")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DistressedOrders, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut market_id__ = None;
+                let mut parties__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::MarketId => {
+                            if market_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("marketId"));
+                            }
+                            market_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Parties => {
+                            if parties__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("parties"));
+                            }
+                            parties__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(DistressedOrders {
+                    market_id: market_id__.unwrap_or_default(),
+                    parties: parties__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.DistressedOrders", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for DistressedPositions {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.market_id.is_empty() {
+            len += 1;
+        }
+        if !self.distressed_parties.is_empty() {
+            len += 1;
+        }
+        if !self.safe_parties.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.DistressedPositions", len)?;
+        if !self.market_id.is_empty() {
+            struct_ser.serialize_field("marketId", &self.market_id)?;
+        }
+        if !self.distressed_parties.is_empty() {
+            struct_ser.serialize_field("distressedParties", &self.distressed_parties)?;
+        }
+        if !self.safe_parties.is_empty() {
+            struct_ser.serialize_field("safeParties", &self.safe_parties)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for DistressedPositions {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "market_id",
+            "marketId",
+            "distressed_parties",
+            "distressedParties",
+            "safe_parties",
+            "safeParties",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            MarketId,
+            DistressedParties,
+            SafeParties,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "marketId" | "market_id" => Ok(GeneratedField::MarketId),
+                            "distressedParties" | "distressed_parties" => Ok(GeneratedField::DistressedParties),
+                            "safeParties" | "safe_parties" => Ok(GeneratedField::SafeParties),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = DistressedPositions;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.DistressedPositions")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DistressedPositions, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut market_id__ = None;
+                let mut distressed_parties__ = None;
+                let mut safe_parties__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::MarketId => {
+                            if market_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("marketId"));
+                            }
+                            market_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::DistressedParties => {
+                            if distressed_parties__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("distressedParties"));
+                            }
+                            distressed_parties__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::SafeParties => {
+                            if safe_parties__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("safeParties"));
+                            }
+                            safe_parties__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(DistressedPositions {
+                    market_id: market_id__.unwrap_or_default(),
+                    distressed_parties: distressed_parties__.unwrap_or_default(),
+                    safe_parties: safe_parties__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.DistressedPositions", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Erc20MultiSigSignerAdded {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.signature_id.is_empty() {
+            len += 1;
+        }
+        if !self.validator_id.is_empty() {
+            len += 1;
+        }
+        if self.timestamp != 0 {
+            len += 1;
+        }
+        if !self.new_signer.is_empty() {
+            len += 1;
+        }
+        if !self.submitter.is_empty() {
+            len += 1;
+        }
+        if !self.nonce.is_empty() {
+            len += 1;
+        }
+        if !self.epoch_seq.is_empty() {
+            len += 1;
+        }
+        if !self.chain_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.ERC20MultiSigSignerAdded", len)?;
+        if !self.signature_id.is_empty() {
+            struct_ser.serialize_field("signatureId", &self.signature_id)?;
+        }
+        if !self.validator_id.is_empty() {
+            struct_ser.serialize_field("validatorId", &self.validator_id)?;
+        }
+        if self.timestamp != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("timestamp", ToString::to_string(&self.timestamp).as_str())?;
+        }
+        if !self.new_signer.is_empty() {
+            struct_ser.serialize_field("newSigner", &self.new_signer)?;
+        }
+        if !self.submitter.is_empty() {
+            struct_ser.serialize_field("submitter", &self.submitter)?;
+        }
+        if !self.nonce.is_empty() {
+            struct_ser.serialize_field("nonce", &self.nonce)?;
+        }
+        if !self.epoch_seq.is_empty() {
+            struct_ser.serialize_field("epochSeq", &self.epoch_seq)?;
+        }
+        if !self.chain_id.is_empty() {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Erc20MultiSigSignerAdded {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "signature_id",
+            "signatureId",
+            "validator_id",
+            "validatorId",
+            "timestamp",
+            "new_signer",
+            "newSigner",
+            "submitter",
+            "nonce",
+            "epoch_seq",
+            "epochSeq",
+            "chain_id",
+            "chainId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            SignatureId,
+            ValidatorId,
+            Timestamp,
+            NewSigner,
+            Submitter,
+            Nonce,
+            EpochSeq,
+            ChainId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "signatureId" | "signature_id" => Ok(GeneratedField::SignatureId),
+                            "validatorId" | "validator_id" => Ok(GeneratedField::ValidatorId),
+                            "timestamp" => Ok(GeneratedField::Timestamp),
+                            "newSigner" | "new_signer" => Ok(GeneratedField::NewSigner),
+                            "submitter" => Ok(GeneratedField::Submitter),
+                            "nonce" => Ok(GeneratedField::Nonce),
+                            "epochSeq" | "epoch_seq" => Ok(GeneratedField::EpochSeq),
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Erc20MultiSigSignerAdded;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.ERC20MultiSigSignerAdded")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Erc20MultiSigSignerAdded, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut signature_id__ = None;
+                let mut validator_id__ = None;
+                let mut timestamp__ = None;
+                let mut new_signer__ = None;
+                let mut submitter__ = None;
+                let mut nonce__ = None;
+                let mut epoch_seq__ = None;
+                let mut chain_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::SignatureId => {
+                            if signature_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("signatureId"));
+                            }
+                            signature_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ValidatorId => {
+                            if validator_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("validatorId"));
+                            }
+                            validator_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Timestamp => {
+                            if timestamp__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timestamp"));
+                            }
+                            timestamp__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::NewSigner => {
+                            if new_signer__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newSigner"));
+                            }
+                            new_signer__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Submitter => {
+                            if submitter__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("submitter"));
+                            }
+                            submitter__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Nonce => {
+                            if nonce__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nonce"));
+                            }
+                            nonce__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::EpochSeq => {
+                            if epoch_seq__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epochSeq"));
+                            }
+                            epoch_seq__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
+                            }
+                            chain_id__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(Erc20MultiSigSignerAdded {
+                    signature_id: signature_id__.unwrap_or_default(),
+                    validator_id: validator_id__.unwrap_or_default(),
+                    timestamp: timestamp__.unwrap_or_default(),
+                    new_signer: new_signer__.unwrap_or_default(),
+                    submitter: submitter__.unwrap_or_default(),
+                    nonce: nonce__.unwrap_or_default(),
+                    epoch_seq: epoch_seq__.unwrap_or_default(),
+                    chain_id: chain_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.ERC20MultiSigSignerAdded", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Erc20MultiSigSignerEvent {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.id.is_empty() {
+            len += 1;
+        }
+        if self.r#type != 0 {
+            len += 1;
+        }
+        if !self.signer.is_empty() {
+            len += 1;
+        }
+        if !self.nonce.is_empty() {
+            len += 1;
+        }
+        if self.block_time != 0 {
+            len += 1;
+        }
+        if !self.tx_hash.is_empty() {
+            len += 1;
+        }
+        if self.log_index != 0 {
+            len += 1;
+        }
+        if self.block_number != 0 {
+            len += 1;
+        }
+        if !self.chain_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.ERC20MultiSigSignerEvent", len)?;
+        if !self.id.is_empty() {
+            struct_ser.serialize_field("id", &self.id)?;
+        }
+        if self.r#type != 0 {
+            let v = erc20_multi_sig_signer_event::Type::try_from(self.r#type)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.r#type)))?;
+            struct_ser.serialize_field("type", &v)?;
+        }
+        if !self.signer.is_empty() {
+            struct_ser.serialize_field("signer", &self.signer)?;
+        }
+        if !self.nonce.is_empty() {
+            struct_ser.serialize_field("nonce", &self.nonce)?;
+        }
+        if self.block_time != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("blockTime", ToString::to_string(&self.block_time).as_str())?;
+        }
+        if !self.tx_hash.is_empty() {
+            struct_ser.serialize_field("txHash", &self.tx_hash)?;
+        }
+        if self.log_index != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("logIndex", ToString::to_string(&self.log_index).as_str())?;
+        }
+        if self.block_number != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("blockNumber", ToString::to_string(&self.block_number).as_str())?;
+        }
+        if !self.chain_id.is_empty() {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Erc20MultiSigSignerEvent {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "id",
+            "type",
+            "signer",
+            "nonce",
+            "block_time",
+            "blockTime",
+            "tx_hash",
+            "txHash",
+            "log_index",
+            "logIndex",
+            "block_number",
+            "blockNumber",
+            "chain_id",
+            "chainId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Id,
+            Type,
+            Signer,
+            Nonce,
+            BlockTime,
+            TxHash,
+            LogIndex,
+            BlockNumber,
+            ChainId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "id" => Ok(GeneratedField::Id),
+                            "type" => Ok(GeneratedField::Type),
+                            "signer" => Ok(GeneratedField::Signer),
+                            "nonce" => Ok(GeneratedField::Nonce),
+                            "blockTime" | "block_time" => Ok(GeneratedField::BlockTime),
+                            "txHash" | "tx_hash" => Ok(GeneratedField::TxHash),
+                            "logIndex" | "log_index" => Ok(GeneratedField::LogIndex),
+                            "blockNumber" | "block_number" => Ok(GeneratedField::BlockNumber),
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Erc20MultiSigSignerEvent;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.ERC20MultiSigSignerEvent")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Erc20MultiSigSignerEvent, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut id__ = None;
+                let mut r#type__ = None;
+                let mut signer__ = None;
+                let mut nonce__ = None;
+                let mut block_time__ = None;
+                let mut tx_hash__ = None;
+                let mut log_index__ = None;
+                let mut block_number__ = None;
+                let mut chain_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Type => {
+                            if r#type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("type"));
+                            }
+                            r#type__ = Some(map_.next_value::<erc20_multi_sig_signer_event::Type>()? as i32);
+                        }
+                        GeneratedField::Signer => {
+                            if signer__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("signer"));
+                            }
+                            signer__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Nonce => {
+                            if nonce__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nonce"));
+                            }
+                            nonce__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::BlockTime => {
+                            if block_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("blockTime"));
+                            }
+                            block_time__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::TxHash => {
+                            if tx_hash__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("txHash"));
+                            }
+                            tx_hash__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::LogIndex => {
+                            if log_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("logIndex"));
+                            }
+                            log_index__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::BlockNumber => {
+                            if block_number__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("blockNumber"));
+                            }
+                            block_number__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
+                            }
+                            chain_id__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(Erc20MultiSigSignerEvent {
+                    id: id__.unwrap_or_default(),
+                    r#type: r#type__.unwrap_or_default(),
+                    signer: signer__.unwrap_or_default(),
+                    nonce: nonce__.unwrap_or_default(),
+                    block_time: block_time__.unwrap_or_default(),
+                    tx_hash: tx_hash__.unwrap_or_default(),
+                    log_index: log_index__.unwrap_or_default(),
+                    block_number: block_number__.unwrap_or_default(),
+                    chain_id: chain_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.ERC20MultiSigSignerEvent", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for erc20_multi_sig_signer_event::Type {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "TYPE_UNSPECIFIED",
+            Self::Added => "TYPE_ADDED",
+            Self::Removed => "TYPE_REMOVED",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for erc20_multi_sig_signer_event::Type {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "TYPE_UNSPECIFIED",
+            "TYPE_ADDED",
+            "TYPE_REMOVED",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = erc20_multi_sig_signer_event::Type;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "TYPE_UNSPECIFIED" => Ok(erc20_multi_sig_signer_event::Type::Unspecified),
+                    "TYPE_ADDED" => Ok(erc20_multi_sig_signer_event::Type::Added),
+                    "TYPE_REMOVED" => Ok(erc20_multi_sig_signer_event::Type::Removed),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Erc20MultiSigSignerRemoved {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.signature_submitters.is_empty() {
+            len += 1;
+        }
+        if !self.validator_id.is_empty() {
+            len += 1;
+        }
+        if self.timestamp != 0 {
+            len += 1;
+        }
+        if !self.old_signer.is_empty() {
+            len += 1;
+        }
+        if !self.nonce.is_empty() {
+            len += 1;
+        }
+        if !self.epoch_seq.is_empty() {
+            len += 1;
+        }
+        if !self.chain_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.ERC20MultiSigSignerRemoved", len)?;
+        if !self.signature_submitters.is_empty() {
+            struct_ser.serialize_field("signatureSubmitters", &self.signature_submitters)?;
+        }
+        if !self.validator_id.is_empty() {
+            struct_ser.serialize_field("validatorId", &self.validator_id)?;
+        }
+        if self.timestamp != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("timestamp", ToString::to_string(&self.timestamp).as_str())?;
+        }
+        if !self.old_signer.is_empty() {
+            struct_ser.serialize_field("oldSigner", &self.old_signer)?;
+        }
+        if !self.nonce.is_empty() {
+            struct_ser.serialize_field("nonce", &self.nonce)?;
+        }
+        if !self.epoch_seq.is_empty() {
+            struct_ser.serialize_field("epochSeq", &self.epoch_seq)?;
+        }
+        if !self.chain_id.is_empty() {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Erc20MultiSigSignerRemoved {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "signature_submitters",
+            "signatureSubmitters",
+            "validator_id",
+            "validatorId",
+            "timestamp",
+            "old_signer",
+            "oldSigner",
+            "nonce",
+            "epoch_seq",
+            "epochSeq",
+            "chain_id",
+            "chainId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            SignatureSubmitters,
+            ValidatorId,
+            Timestamp,
+            OldSigner,
+            Nonce,
+            EpochSeq,
+            ChainId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "signatureSubmitters" | "signature_submitters" => Ok(GeneratedField::SignatureSubmitters),
+                            "validatorId" | "validator_id" => Ok(GeneratedField::ValidatorId),
+                            "timestamp" => Ok(GeneratedField::Timestamp),
+                            "oldSigner" | "old_signer" => Ok(GeneratedField::OldSigner),
+                            "nonce" => Ok(GeneratedField::Nonce),
+                            "epochSeq" | "epoch_seq" => Ok(GeneratedField::EpochSeq),
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Erc20MultiSigSignerRemoved;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.ERC20MultiSigSignerRemoved")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Erc20MultiSigSignerRemoved, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut signature_submitters__ = None;
+                let mut validator_id__ = None;
+                let mut timestamp__ = None;
+                let mut old_signer__ = None;
+                let mut nonce__ = None;
+                let mut epoch_seq__ = None;
+                let mut chain_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::SignatureSubmitters => {
+                            if signature_submitters__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("signatureSubmitters"));
+                            }
+                            signature_submitters__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ValidatorId => {
+                            if validator_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("validatorId"));
+                            }
+                            validator_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Timestamp => {
+                            if timestamp__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timestamp"));
+                            }
+                            timestamp__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::OldSigner => {
+                            if old_signer__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("oldSigner"));
+                            }
+                            old_signer__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Nonce => {
+                            if nonce__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nonce"));
+                            }
+                            nonce__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::EpochSeq => {
+                            if epoch_seq__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epochSeq"));
+                            }
+                            epoch_seq__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
+                            }
+                            chain_id__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(Erc20MultiSigSignerRemoved {
+                    signature_submitters: signature_submitters__.unwrap_or_default(),
+                    validator_id: validator_id__.unwrap_or_default(),
+                    timestamp: timestamp__.unwrap_or_default(),
+                    old_signer: old_signer__.unwrap_or_default(),
+                    nonce: nonce__.unwrap_or_default(),
+                    epoch_seq: epoch_seq__.unwrap_or_default(),
+                    chain_id: chain_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.ERC20MultiSigSignerRemoved", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Erc20MultiSigSignerRemovedSubmitter {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.signature_id.is_empty() {
+            len += 1;
+        }
+        if !self.submitter.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.ERC20MultiSigSignerRemovedSubmitter", len)?;
+        if !self.signature_id.is_empty() {
+            struct_ser.serialize_field("signatureId", &self.signature_id)?;
+        }
+        if !self.submitter.is_empty() {
+            struct_ser.serialize_field("submitter", &self.submitter)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Erc20MultiSigSignerRemovedSubmitter {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "signature_id",
+            "signatureId",
+            "submitter",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            SignatureId,
+            Submitter,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "signatureId" | "signature_id" => Ok(GeneratedField::SignatureId),
+                            "submitter" => Ok(GeneratedField::Submitter),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Erc20MultiSigSignerRemovedSubmitter;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.ERC20MultiSigSignerRemovedSubmitter")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Erc20MultiSigSignerRemovedSubmitter, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut signature_id__ = None;
+                let mut submitter__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::SignatureId => {
+                            if signature_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("signatureId"));
+                            }
+                            signature_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Submitter => {
+                            if submitter__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("submitter"));
+                            }
+                            submitter__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(Erc20MultiSigSignerRemovedSubmitter {
+                    signature_id: signature_id__.unwrap_or_default(),
+                    submitter: submitter__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.ERC20MultiSigSignerRemovedSubmitter", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Erc20MultiSigThresholdSetEvent {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.id.is_empty() {
+            len += 1;
+        }
+        if self.new_threshold != 0 {
+            len += 1;
+        }
+        if !self.nonce.is_empty() {
+            len += 1;
+        }
+        if self.block_time != 0 {
+            len += 1;
+        }
+        if !self.tx_hash.is_empty() {
+            len += 1;
+        }
+        if self.log_index != 0 {
+            len += 1;
+        }
+        if self.block_number != 0 {
+            len += 1;
+        }
+        if !self.chain_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.ERC20MultiSigThresholdSetEvent", len)?;
+        if !self.id.is_empty() {
+            struct_ser.serialize_field("id", &self.id)?;
+        }
+        if self.new_threshold != 0 {
+            struct_ser.serialize_field("newThreshold", &self.new_threshold)?;
+        }
+        if !self.nonce.is_empty() {
+            struct_ser.serialize_field("nonce", &self.nonce)?;
+        }
+        if self.block_time != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("blockTime", ToString::to_string(&self.block_time).as_str())?;
+        }
+        if !self.tx_hash.is_empty() {
+            struct_ser.serialize_field("txHash", &self.tx_hash)?;
+        }
+        if self.log_index != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("logIndex", ToString::to_string(&self.log_index).as_str())?;
+        }
+        if self.block_number != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("blockNumber", ToString::to_string(&self.block_number).as_str())?;
+        }
+        if !self.chain_id.is_empty() {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Erc20MultiSigThresholdSetEvent {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "id",
+            "new_threshold",
+            "newThreshold",
+            "nonce",
+            "block_time",
+            "blockTime",
+            "tx_hash",
+            "txHash",
+            "log_index",
+            "logIndex",
+            "block_number",
+            "blockNumber",
+            "chain_id",
+            "chainId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Id,
+            NewThreshold,
+            Nonce,
+            BlockTime,
+            TxHash,
+            LogIndex,
+            BlockNumber,
+            ChainId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "id" => Ok(GeneratedField::Id),
+                            "newThreshold" | "new_threshold" => Ok(GeneratedField::NewThreshold),
+                            "nonce" => Ok(GeneratedField::Nonce),
+                            "blockTime" | "block_time" => Ok(GeneratedField::BlockTime),
+                            "txHash" | "tx_hash" => Ok(GeneratedField::TxHash),
+                            "logIndex" | "log_index" => Ok(GeneratedField::LogIndex),
+                            "blockNumber" | "block_number" => Ok(GeneratedField::BlockNumber),
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Erc20MultiSigThresholdSetEvent;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.ERC20MultiSigThresholdSetEvent")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Erc20MultiSigThresholdSetEvent, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut id__ = None;
+                let mut new_threshold__ = None;
+                let mut nonce__ = None;
+                let mut block_time__ = None;
+                let mut tx_hash__ = None;
+                let mut log_index__ = None;
+                let mut block_number__ = None;
+                let mut chain_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::NewThreshold => {
+                            if new_threshold__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newThreshold"));
+                            }
+                            new_threshold__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Nonce => {
+                            if nonce__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nonce"));
+                            }
+                            nonce__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::BlockTime => {
+                            if block_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("blockTime"));
+                            }
+                            block_time__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::TxHash => {
+                            if tx_hash__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("txHash"));
+                            }
+                            tx_hash__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::LogIndex => {
+                            if log_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("logIndex"));
+                            }
+                            log_index__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::BlockNumber => {
+                            if block_number__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("blockNumber"));
+                            }
+                            block_number__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
+                            }
+                            chain_id__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(Erc20MultiSigThresholdSetEvent {
+                    id: id__.unwrap_or_default(),
+                    new_threshold: new_threshold__.unwrap_or_default(),
+                    nonce: nonce__.unwrap_or_default(),
+                    block_time: block_time__.unwrap_or_default(),
+                    tx_hash: tx_hash__.unwrap_or_default(),
+                    log_index: log_index__.unwrap_or_default(),
+                    block_number: block_number__.unwrap_or_default(),
+                    chain_id: chain_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.ERC20MultiSigThresholdSetEvent", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EndBlock {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.EndBlock", len)?;
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EndBlock {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "height",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Height,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "height" => Ok(GeneratedField::Height),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EndBlock;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.EndBlock")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EndBlock, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut height__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(EndBlock {
+                    height: height__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.EndBlock", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EpochEvent {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.seq != 0 {
+            len += 1;
+        }
+        if self.action != 0 {
+            len += 1;
+        }
+        if self.start_time != 0 {
+            len += 1;
+        }
+        if self.expire_time != 0 {
+            len += 1;
+        }
+        if self.end_time != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.EpochEvent", len)?;
+        if self.seq != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("seq", ToString::to_string(&self.seq).as_str())?;
+        }
+        if self.action != 0 {
+            let v = super::super::EpochAction::try_from(self.action)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.action)))?;
+            struct_ser.serialize_field("action", &v)?;
+        }
+        if self.start_time != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("startTime", ToString::to_string(&self.start_time).as_str())?;
+        }
+        if self.expire_time != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("expireTime", ToString::to_string(&self.expire_time).as_str())?;
+        }
+        if self.end_time != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("endTime", ToString::to_string(&self.end_time).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EpochEvent {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "seq",
+            "action",
+            "start_time",
+            "startTime",
+            "expire_time",
+            "expireTime",
+            "end_time",
+            "endTime",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Seq,
+            Action,
+            StartTime,
+            ExpireTime,
+            EndTime,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "seq" => Ok(GeneratedField::Seq),
+                            "action" => Ok(GeneratedField::Action),
+                            "startTime" | "start_time" => Ok(GeneratedField::StartTime),
+                            "expireTime" | "expire_time" => Ok(GeneratedField::ExpireTime),
+                            "endTime" | "end_time" => Ok(GeneratedField::EndTime),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EpochEvent;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.EpochEvent")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EpochEvent, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut seq__ = None;
+                let mut action__ = None;
+                let mut start_time__ = None;
+                let mut expire_time__ = None;
+                let mut end_time__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Seq => {
+                            if seq__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("seq"));
+                            }
+                            seq__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Action => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("action"));
+                            }
+                            action__ = Some(map_.next_value::<super::super::EpochAction>()? as i32);
+                        }
+                        GeneratedField::StartTime => {
+                            if start_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startTime"));
+                            }
+                            start_time__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ExpireTime => {
+                            if expire_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("expireTime"));
+                            }
+                            expire_time__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::EndTime => {
+                            if end_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endTime"));
+                            }
+                            end_time__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(EpochEvent {
+                    seq: seq__.unwrap_or_default(),
+                    action: action__.unwrap_or_default(),
+                    start_time: start_time__.unwrap_or_default(),
+                    expire_time: expire_time__.unwrap_or_default(),
+                    end_time: end_time__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.EpochEvent", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EthereumKeyRotation {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.node_id.is_empty() {
+            len += 1;
+        }
+        if !self.old_address.is_empty() {
+            len += 1;
+        }
+        if !self.new_address.is_empty() {
+            len += 1;
+        }
+        if self.block_height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.EthereumKeyRotation", len)?;
+        if !self.node_id.is_empty() {
+            struct_ser.serialize_field("nodeId", &self.node_id)?;
+        }
+        if !self.old_address.is_empty() {
+            struct_ser.serialize_field("oldAddress", &self.old_address)?;
+        }
+        if !self.new_address.is_empty() {
+            struct_ser.serialize_field("newAddress", &self.new_address)?;
+        }
+        if self.block_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("blockHeight", ToString::to_string(&self.block_height).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EthereumKeyRotation {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "node_id",
+            "nodeId",
+            "old_address",
+            "oldAddress",
+            "new_address",
+            "newAddress",
+            "block_height",
+            "blockHeight",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            NodeId,
+            OldAddress,
+            NewAddress,
+            BlockHeight,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "nodeId" | "node_id" => Ok(GeneratedField::NodeId),
+                            "oldAddress" | "old_address" => Ok(GeneratedField::OldAddress),
+                            "newAddress" | "new_address" => Ok(GeneratedField::NewAddress),
+                            "blockHeight" | "block_height" => Ok(GeneratedField::BlockHeight),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EthereumKeyRotation;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.EthereumKeyRotation")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EthereumKeyRotation, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut node_id__ = None;
+                let mut old_address__ = None;
+                let mut new_address__ = None;
+                let mut block_height__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::NodeId => {
+                            if node_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nodeId"));
+                            }
+                            node_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::OldAddress => {
+                            if old_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("oldAddress"));
+                            }
+                            old_address__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::NewAddress => {
+                            if new_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newAddress"));
+                            }
+                            new_address__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::BlockHeight => {
+                            if block_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("blockHeight"));
+                            }
+                            block_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(EthereumKeyRotation {
+                    node_id: node_id__.unwrap_or_default(),
+                    old_address: old_address__.unwrap_or_default(),
+                    new_address: new_address__.unwrap_or_default(),
+                    block_height: block_height__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.EthereumKeyRotation", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ExpiredOrders {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.market_id.is_empty() {
+            len += 1;
+        }
+        if !self.order_ids.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.ExpiredOrders", len)?;
+        if !self.market_id.is_empty() {
+            struct_ser.serialize_field("marketId", &self.market_id)?;
+        }
+        if !self.order_ids.is_empty() {
+            struct_ser.serialize_field("orderIds", &self.order_ids)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ExpiredOrders {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "market_id",
+            "marketId",
+            "order_ids",
+            "orderIds",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            MarketId,
+            OrderIds,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "marketId" | "market_id" => Ok(GeneratedField::MarketId),
+                            "orderIds" | "order_ids" => Ok(GeneratedField::OrderIds),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ExpiredOrders;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.ExpiredOrders")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ExpiredOrders, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut market_id__ = None;
+                let mut order_ids__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::MarketId => {
+                            if market_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("marketId"));
+                            }
+                            market_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::OrderIds => {
+                            if order_ids__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("orderIds"));
+                            }
+                            order_ids__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(ExpiredOrders {
+                    market_id: market_id__.unwrap_or_default(),
+                    order_ids: order_ids__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.ExpiredOrders", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for FeesStats {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.market.is_empty() {
+            len += 1;
+        }
+        if !self.asset.is_empty() {
+            len += 1;
+        }
+        if self.epoch_seq != 0 {
+            len += 1;
+        }
+        if !self.total_rewards_received.is_empty() {
+            len += 1;
+        }
+        if !self.referrer_rewards_generated.is_empty() {
+            len += 1;
+        }
+        if !self.referees_discount_applied.is_empty() {
+            len += 1;
+        }
+        if !self.volume_discount_applied.is_empty() {
+            len += 1;
+        }
+        if !self.total_maker_fees_received.is_empty() {
+            len += 1;
+        }
+        if !self.maker_fees_generated.is_empty() {
+            len += 1;
+        }
+        if !self.total_fees_paid_and_received.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.FeesStats", len)?;
+        if !self.market.is_empty() {
+            struct_ser.serialize_field("market", &self.market)?;
+        }
+        if !self.asset.is_empty() {
+            struct_ser.serialize_field("asset", &self.asset)?;
+        }
+        if self.epoch_seq != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("epochSeq", ToString::to_string(&self.epoch_seq).as_str())?;
+        }
+        if !self.total_rewards_received.is_empty() {
+            struct_ser.serialize_field("totalRewardsReceived", &self.total_rewards_received)?;
+        }
+        if !self.referrer_rewards_generated.is_empty() {
+            struct_ser.serialize_field("referrerRewardsGenerated", &self.referrer_rewards_generated)?;
+        }
+        if !self.referees_discount_applied.is_empty() {
+            struct_ser.serialize_field("refereesDiscountApplied", &self.referees_discount_applied)?;
+        }
+        if !self.volume_discount_applied.is_empty() {
+            struct_ser.serialize_field("volumeDiscountApplied", &self.volume_discount_applied)?;
+        }
+        if !self.total_maker_fees_received.is_empty() {
+            struct_ser.serialize_field("totalMakerFeesReceived", &self.total_maker_fees_received)?;
+        }
+        if !self.maker_fees_generated.is_empty() {
+            struct_ser.serialize_field("makerFeesGenerated", &self.maker_fees_generated)?;
+        }
+        if !self.total_fees_paid_and_received.is_empty() {
+            struct_ser.serialize_field("totalFeesPaidAndReceived", &self.total_fees_paid_and_received)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for FeesStats {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "market",
+            "asset",
+            "epoch_seq",
+            "epochSeq",
+            "total_rewards_received",
+            "totalRewardsReceived",
+            "referrer_rewards_generated",
+            "referrerRewardsGenerated",
+            "referees_discount_applied",
+            "refereesDiscountApplied",
+            "volume_discount_applied",
+            "volumeDiscountApplied",
+            "total_maker_fees_received",
+            "totalMakerFeesReceived",
+            "maker_fees_generated",
+            "makerFeesGenerated",
+            "total_fees_paid_and_received",
+            "totalFeesPaidAndReceived",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Market,
+            Asset,
+            EpochSeq,
+            TotalRewardsReceived,
+            ReferrerRewardsGenerated,
+            RefereesDiscountApplied,
+            VolumeDiscountApplied,
+            TotalMakerFeesReceived,
+            MakerFeesGenerated,
+            TotalFeesPaidAndReceived,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "market" => Ok(GeneratedField::Market),
+                            "asset" => Ok(GeneratedField::Asset),
+                            "epochSeq" | "epoch_seq" => Ok(GeneratedField::EpochSeq),
+                            "totalRewardsReceived" | "total_rewards_received" => Ok(GeneratedField::TotalRewardsReceived),
+                            "referrerRewardsGenerated" | "referrer_rewards_generated" => Ok(GeneratedField::ReferrerRewardsGenerated),
+                            "refereesDiscountApplied" | "referees_discount_applied" => Ok(GeneratedField::RefereesDiscountApplied),
+                            "volumeDiscountApplied" | "volume_discount_applied" => Ok(GeneratedField::VolumeDiscountApplied),
+                            "totalMakerFeesReceived" | "total_maker_fees_received" => Ok(GeneratedField::TotalMakerFeesReceived),
+                            "makerFeesGenerated" | "maker_fees_generated" => Ok(GeneratedField::MakerFeesGenerated),
+                            "totalFeesPaidAndReceived" | "total_fees_paid_and_received" => Ok(GeneratedField::TotalFeesPaidAndReceived),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = FeesStats;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.FeesStats")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<FeesStats, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut market__ = None;
+                let mut asset__ = None;
+                let mut epoch_seq__ = None;
+                let mut total_rewards_received__ = None;
+                let mut referrer_rewards_generated__ = None;
+                let mut referees_discount_applied__ = None;
+                let mut volume_discount_applied__ = None;
+                let mut total_maker_fees_received__ = None;
+                let mut maker_fees_generated__ = None;
+                let mut total_fees_paid_and_received__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Market => {
+                            if market__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("market"));
+                            }
+                            market__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Asset => {
+                            if asset__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("asset"));
+                            }
+                            asset__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::EpochSeq => {
+                            if epoch_seq__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epochSeq"));
+                            }
+                            epoch_seq__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::TotalRewardsReceived => {
+                            if total_rewards_received__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("totalRewardsReceived"));
+                            }
+                            total_rewards_received__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ReferrerRewardsGenerated => {
+                            if referrer_rewards_generated__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("referrerRewardsGenerated"));
+                            }
+                            referrer_rewards_generated__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::RefereesDiscountApplied => {
+                            if referees_discount_applied__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("refereesDiscountApplied"));
+                            }
+                            referees_discount_applied__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::VolumeDiscountApplied => {
+                            if volume_discount_applied__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("volumeDiscountApplied"));
+                            }
+                            volume_discount_applied__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::TotalMakerFeesReceived => {
+                            if total_maker_fees_received__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("totalMakerFeesReceived"));
+                            }
+                            total_maker_fees_received__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::MakerFeesGenerated => {
+                            if maker_fees_generated__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("makerFeesGenerated"));
+                            }
+                            maker_fees_generated__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::TotalFeesPaidAndReceived => {
+                            if total_fees_paid_and_received__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("totalFeesPaidAndReceived"));
+                            }
+                            total_fees_paid_and_received__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(FeesStats {
+                    market: market__.unwrap_or_default(),
+                    asset: asset__.unwrap_or_default(),
+                    epoch_seq: epoch_seq__.unwrap_or_default(),
+                    total_rewards_received: total_rewards_received__.unwrap_or_default(),
+                    referrer_rewards_generated: referrer_rewards_generated__.unwrap_or_default(),
+                    referees_discount_applied: referees_discount_applied__.unwrap_or_default(),
+                    volume_discount_applied: volume_discount_applied__.unwrap_or_default(),
+                    total_maker_fees_received: total_maker_fees_received__.unwrap_or_default(),
+                    maker_fees_generated: maker_fees_generated__.unwrap_or_default(),
+                    total_fees_paid_and_received: total_fees_paid_and_received__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.FeesStats", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for FundingPayment {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.party_id.is_empty() {
+            len += 1;
+        }
+        if !self.amount.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.FundingPayment", len)?;
+        if !self.party_id.is_empty() {
+            struct_ser.serialize_field("partyId", &self.party_id)?;
+        }
+        if !self.amount.is_empty() {
+            struct_ser.serialize_field("amount", &self.amount)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for FundingPayment {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "party_id",
+            "partyId",
+            "amount",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PartyId,
+            Amount,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "partyId" | "party_id" => Ok(GeneratedField::PartyId),
+                            "amount" => Ok(GeneratedField::Amount),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = FundingPayment;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.FundingPayment")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<FundingPayment, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut party_id__ = None;
+                let mut amount__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PartyId => {
+                            if party_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("partyId"));
+                            }
+                            party_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Amount => {
+                            if amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amount"));
+                            }
+                            amount__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(FundingPayment {
+                    party_id: party_id__.unwrap_or_default(),
+                    amount: amount__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.FundingPayment", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for FundingPayments {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.market_id.is_empty() {
+            len += 1;
+        }
+        if self.seq != 0 {
+            len += 1;
+        }
+        if !self.payments.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.FundingPayments", len)?;
+        if !self.market_id.is_empty() {
+            struct_ser.serialize_field("marketId", &self.market_id)?;
+        }
+        if self.seq != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("seq", ToString::to_string(&self.seq).as_str())?;
+        }
+        if !self.payments.is_empty() {
+            struct_ser.serialize_field("payments", &self.payments)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for FundingPayments {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "market_id",
+            "marketId",
+            "seq",
+            "payments",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            MarketId,
+            Seq,
+            Payments,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "marketId" | "market_id" => Ok(GeneratedField::MarketId),
+                            "seq" => Ok(GeneratedField::Seq),
+                            "payments" => Ok(GeneratedField::Payments),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = FundingPayments;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.FundingPayments")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<FundingPayments, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut market_id__ = None;
+                let mut seq__ = None;
+                let mut payments__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::MarketId => {
+                            if market_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("marketId"));
+                            }
+                            market_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Seq => {
+                            if seq__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("seq"));
+                            }
+                            seq__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Payments => {
+                            if payments__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("payments"));
+                            }
+                            payments__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(FundingPayments {
+                    market_id: market_id__.unwrap_or_default(),
+                    seq: seq__.unwrap_or_default(),
+                    payments: payments__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.FundingPayments", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for FundingPeriod {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.market_id.is_empty() {
+            len += 1;
+        }
+        if self.seq != 0 {
+            len += 1;
+        }
+        if self.start != 0 {
+            len += 1;
+        }
+        if self.end.is_some() {
+            len += 1;
+        }
+        if self.funding_payment.is_some() {
+            len += 1;
+        }
+        if self.funding_rate.is_some() {
+            len += 1;
+        }
+        if self.internal_twap.is_some() {
+            len += 1;
+        }
+        if self.external_twap.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.FundingPeriod", len)?;
+        if !self.market_id.is_empty() {
+            struct_ser.serialize_field("marketId", &self.market_id)?;
+        }
+        if self.seq != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("seq", ToString::to_string(&self.seq).as_str())?;
+        }
+        if self.start != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("start", ToString::to_string(&self.start).as_str())?;
+        }
+        if let Some(v) = self.end.as_ref() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("end", ToString::to_string(&v).as_str())?;
+        }
+        if let Some(v) = self.funding_payment.as_ref() {
+            struct_ser.serialize_field("fundingPayment", v)?;
+        }
+        if let Some(v) = self.funding_rate.as_ref() {
+            struct_ser.serialize_field("fundingRate", v)?;
+        }
+        if let Some(v) = self.internal_twap.as_ref() {
+            struct_ser.serialize_field("internalTwap", v)?;
+        }
+        if let Some(v) = self.external_twap.as_ref() {
+            struct_ser.serialize_field("externalTwap", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for FundingPeriod {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "market_id",
+            "marketId",
+            "seq",
+            "start",
+            "end",
+            "funding_payment",
+            "fundingPayment",
+            "funding_rate",
+            "fundingRate",
+            "internal_twap",
+            "internalTwap",
+            "external_twap",
+            "externalTwap",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            MarketId,
+            Seq,
+            Start,
+            End,
+            FundingPayment,
+            FundingRate,
+            InternalTwap,
+            ExternalTwap,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "marketId" | "market_id" => Ok(GeneratedField::MarketId),
+                            "seq" => Ok(GeneratedField::Seq),
+                            "start" => Ok(GeneratedField::Start),
+                            "end" => Ok(GeneratedField::End),
+                            "fundingPayment" | "funding_payment" => Ok(GeneratedField::FundingPayment),
+                            "fundingRate" | "funding_rate" => Ok(GeneratedField::FundingRate),
+                            "internalTwap" | "internal_twap" => Ok(GeneratedField::InternalTwap),
+                            "externalTwap" | "external_twap" => Ok(GeneratedField::ExternalTwap),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = FundingPeriod;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.FundingPeriod")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<FundingPeriod, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut market_id__ = None;
+                let mut seq__ = None;
+                let mut start__ = None;
+                let mut end__ = None;
+                let mut funding_payment__ = None;
+                let mut funding_rate__ = None;
+                let mut internal_twap__ = None;
+                let mut external_twap__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::MarketId => {
+                            if market_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("marketId"));
+                            }
+                            market_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Seq => {
+                            if seq__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("seq"));
+                            }
+                            seq__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Start => {
+                            if start__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("start"));
+                            }
+                            start__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::End => {
+                            if end__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("end"));
+                            }
+                            end__ = 
+                                map_.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                        GeneratedField::FundingPayment => {
+                            if funding_payment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fundingPayment"));
+                            }
+                            funding_payment__ = map_.next_value()?;
+                        }
+                        GeneratedField::FundingRate => {
+                            if funding_rate__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fundingRate"));
+                            }
+                            funding_rate__ = map_.next_value()?;
+                        }
+                        GeneratedField::InternalTwap => {
+                            if internal_twap__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("internalTwap"));
+                            }
+                            internal_twap__ = map_.next_value()?;
+                        }
+                        GeneratedField::ExternalTwap => {
+                            if external_twap__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("externalTwap"));
+                            }
+                            external_twap__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(FundingPeriod {
+                    market_id: market_id__.unwrap_or_default(),
+                    seq: seq__.unwrap_or_default(),
+                    start: start__.unwrap_or_default(),
+                    end: end__,
+                    funding_payment: funding_payment__,
+                    funding_rate: funding_rate__,
+                    internal_twap: internal_twap__,
+                    external_twap: external_twap__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.FundingPeriod", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for FundingPeriodDataPoint {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.market_id.is_empty() {
+            len += 1;
+        }
+        if self.seq != 0 {
+            len += 1;
+        }
+        if self.data_point_type != 0 {
+            len += 1;
+        }
+        if !self.price.is_empty() {
+            len += 1;
+        }
+        if self.timestamp != 0 {
+            len += 1;
+        }
+        if !self.twap.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.FundingPeriodDataPoint", len)?;
+        if !self.market_id.is_empty() {
+            struct_ser.serialize_field("marketId", &self.market_id)?;
+        }
+        if self.seq != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("seq", ToString::to_string(&self.seq).as_str())?;
+        }
+        if self.data_point_type != 0 {
+            let v = funding_period_data_point::Source::try_from(self.data_point_type)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.data_point_type)))?;
+            struct_ser.serialize_field("dataPointType", &v)?;
+        }
+        if !self.price.is_empty() {
+            struct_ser.serialize_field("price", &self.price)?;
+        }
+        if self.timestamp != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("timestamp", ToString::to_string(&self.timestamp).as_str())?;
+        }
+        if !self.twap.is_empty() {
+            struct_ser.serialize_field("twap", &self.twap)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for FundingPeriodDataPoint {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "market_id",
+            "marketId",
+            "seq",
+            "data_point_type",
+            "dataPointType",
+            "price",
+            "timestamp",
+            "twap",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            MarketId,
+            Seq,
+            DataPointType,
+            Price,
+            Timestamp,
+            Twap,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "marketId" | "market_id" => Ok(GeneratedField::MarketId),
+                            "seq" => Ok(GeneratedField::Seq),
+                            "dataPointType" | "data_point_type" => Ok(GeneratedField::DataPointType),
+                            "price" => Ok(GeneratedField::Price),
+                            "timestamp" => Ok(GeneratedField::Timestamp),
+                            "twap" => Ok(GeneratedField::Twap),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = FundingPeriodDataPoint;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.FundingPeriodDataPoint")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<FundingPeriodDataPoint, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut market_id__ = None;
+                let mut seq__ = None;
+                let mut data_point_type__ = None;
+                let mut price__ = None;
+                let mut timestamp__ = None;
+                let mut twap__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::MarketId => {
+                            if market_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("marketId"));
+                            }
+                            market_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Seq => {
+                            if seq__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("seq"));
+                            }
+                            seq__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::DataPointType => {
+                            if data_point_type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("dataPointType"));
+                            }
+                            data_point_type__ = Some(map_.next_value::<funding_period_data_point::Source>()? as i32);
+                        }
+                        GeneratedField::Price => {
+                            if price__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("price"));
+                            }
+                            price__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Timestamp => {
+                            if timestamp__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timestamp"));
+                            }
+                            timestamp__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Twap => {
+                            if twap__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("twap"));
+                            }
+                            twap__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(FundingPeriodDataPoint {
+                    market_id: market_id__.unwrap_or_default(),
+                    seq: seq__.unwrap_or_default(),
+                    data_point_type: data_point_type__.unwrap_or_default(),
+                    price: price__.unwrap_or_default(),
+                    timestamp: timestamp__.unwrap_or_default(),
+                    twap: twap__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.FundingPeriodDataPoint", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for funding_period_data_point::Source {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "SOURCE_UNSPECIFIED",
+            Self::External => "SOURCE_EXTERNAL",
+            Self::Internal => "SOURCE_INTERNAL",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for funding_period_data_point::Source {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "SOURCE_UNSPECIFIED",
+            "SOURCE_EXTERNAL",
+            "SOURCE_INTERNAL",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = funding_period_data_point::Source;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "SOURCE_UNSPECIFIED" => Ok(funding_period_data_point::Source::Unspecified),
+                    "SOURCE_EXTERNAL" => Ok(funding_period_data_point::Source::External),
+                    "SOURCE_INTERNAL" => Ok(funding_period_data_point::Source::Internal),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for GamePartyScore {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.game_id.is_empty() {
+            len += 1;
+        }
+        if !self.party.is_empty() {
+            len += 1;
+        }
+        if self.team_id.is_some() {
+            len += 1;
+        }
+        if self.epoch != 0 {
+            len += 1;
+        }
+        if self.time != 0 {
+            len += 1;
+        }
+        if !self.score.is_empty() {
+            len += 1;
+        }
+        if !self.staking_balance.is_empty() {
+            len += 1;
+        }
+        if !self.open_volume.is_empty() {
+            len += 1;
+        }
+        if !self.total_fees_paid.is_empty() {
+            len += 1;
+        }
+        if self.is_eligible {
+            len += 1;
+        }
+        if self.rank.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.GamePartyScore", len)?;
+        if !self.game_id.is_empty() {
+            struct_ser.serialize_field("gameId", &self.game_id)?;
+        }
+        if !self.party.is_empty() {
+            struct_ser.serialize_field("party", &self.party)?;
+        }
+        if let Some(v) = self.team_id.as_ref() {
+            struct_ser.serialize_field("teamId", v)?;
+        }
+        if self.epoch != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("epoch", ToString::to_string(&self.epoch).as_str())?;
+        }
+        if self.time != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("time", ToString::to_string(&self.time).as_str())?;
+        }
+        if !self.score.is_empty() {
+            struct_ser.serialize_field("score", &self.score)?;
+        }
+        if !self.staking_balance.is_empty() {
+            struct_ser.serialize_field("stakingBalance", &self.staking_balance)?;
+        }
+        if !self.open_volume.is_empty() {
+            struct_ser.serialize_field("openVolume", &self.open_volume)?;
+        }
+        if !self.total_fees_paid.is_empty() {
+            struct_ser.serialize_field("totalFeesPaid", &self.total_fees_paid)?;
+        }
+        if self.is_eligible {
+            struct_ser.serialize_field("isEligible", &self.is_eligible)?;
+        }
+        if let Some(v) = self.rank.as_ref() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("rank", ToString::to_string(&v).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GamePartyScore {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "game_id",
+            "gameId",
+            "party",
+            "team_id",
+            "teamId",
+            "epoch",
+            "time",
+            "score",
+            "staking_balance",
+            "stakingBalance",
+            "open_volume",
+            "openVolume",
+            "total_fees_paid",
+            "totalFeesPaid",
+            "is_eligible",
+            "isEligible",
+            "rank",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            GameId,
+            Party,
+            TeamId,
+            Epoch,
+            Time,
+            Score,
+            StakingBalance,
+            OpenVolume,
+            TotalFeesPaid,
+            IsEligible,
+            Rank,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "gameId" | "game_id" => Ok(GeneratedField::GameId),
+                            "party" => Ok(GeneratedField::Party),
+                            "teamId" | "team_id" => Ok(GeneratedField::TeamId),
+                            "epoch" => Ok(GeneratedField::Epoch),
+                            "time" => Ok(GeneratedField::Time),
+                            "score" => Ok(GeneratedField::Score),
+                            "stakingBalance" | "staking_balance" => Ok(GeneratedField::StakingBalance),
+                            "openVolume" | "open_volume" => Ok(GeneratedField::OpenVolume),
+                            "totalFeesPaid" | "total_fees_paid" => Ok(GeneratedField::TotalFeesPaid),
+                            "isEligible" | "is_eligible" => Ok(GeneratedField::IsEligible),
+                            "rank" => Ok(GeneratedField::Rank),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GamePartyScore;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.GamePartyScore")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GamePartyScore, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut game_id__ = None;
+                let mut party__ = None;
+                let mut team_id__ = None;
+                let mut epoch__ = None;
+                let mut time__ = None;
+                let mut score__ = None;
+                let mut staking_balance__ = None;
+                let mut open_volume__ = None;
+                let mut total_fees_paid__ = None;
+                let mut is_eligible__ = None;
+                let mut rank__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::GameId => {
+                            if game_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("gameId"));
+                            }
+                            game_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Party => {
+                            if party__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("party"));
+                            }
+                            party__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::TeamId => {
+                            if team_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("teamId"));
+                            }
+                            team_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::Epoch => {
+                            if epoch__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epoch"));
+                            }
+                            epoch__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Time => {
+                            if time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("time"));
+                            }
+                            time__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Score => {
+                            if score__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("score"));
+                            }
+                            score__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::StakingBalance => {
+                            if staking_balance__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stakingBalance"));
+                            }
+                            staking_balance__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::OpenVolume => {
+                            if open_volume__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("openVolume"));
+                            }
+                            open_volume__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::TotalFeesPaid => {
+                            if total_fees_paid__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("totalFeesPaid"));
+                            }
+                            total_fees_paid__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::IsEligible => {
+                            if is_eligible__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("isEligible"));
+                            }
+                            is_eligible__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Rank => {
+                            if rank__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rank"));
+                            }
+                            rank__ = 
+                                map_.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(GamePartyScore {
+                    game_id: game_id__.unwrap_or_default(),
+                    party: party__.unwrap_or_default(),
+                    team_id: team_id__,
+                    epoch: epoch__.unwrap_or_default(),
+                    time: time__.unwrap_or_default(),
+                    score: score__.unwrap_or_default(),
+                    staking_balance: staking_balance__.unwrap_or_default(),
+                    open_volume: open_volume__.unwrap_or_default(),
+                    total_fees_paid: total_fees_paid__.unwrap_or_default(),
+                    is_eligible: is_eligible__.unwrap_or_default(),
+                    rank: rank__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.GamePartyScore", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for GameScores {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.team_scores.is_empty() {
+            len += 1;
+        }
+        if !self.party_scores.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.GameScores", len)?;
+        if !self.team_scores.is_empty() {
+            struct_ser.serialize_field("teamScores", &self.team_scores)?;
+        }
+        if !self.party_scores.is_empty() {
+            struct_ser.serialize_field("partyScores", &self.party_scores)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GameScores {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "team_scores",
+            "teamScores",
+            "party_scores",
+            "partyScores",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TeamScores,
+            PartyScores,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "teamScores" | "team_scores" => Ok(GeneratedField::TeamScores),
+                            "partyScores" | "party_scores" => Ok(GeneratedField::PartyScores),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GameScores;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.GameScores")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GameScores, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut team_scores__ = None;
+                let mut party_scores__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TeamScores => {
+                            if team_scores__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("teamScores"));
+                            }
+                            team_scores__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::PartyScores => {
+                            if party_scores__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("partyScores"));
+                            }
+                            party_scores__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(GameScores {
+                    team_scores: team_scores__.unwrap_or_default(),
+                    party_scores: party_scores__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.GameScores", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for GameTeamScore {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.game_id.is_empty() {
+            len += 1;
+        }
+        if !self.team_id.is_empty() {
+            len += 1;
+        }
+        if self.epoch != 0 {
+            len += 1;
+        }
+        if self.time != 0 {
+            len += 1;
+        }
+        if !self.score.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.GameTeamScore", len)?;
+        if !self.game_id.is_empty() {
+            struct_ser.serialize_field("gameId", &self.game_id)?;
+        }
+        if !self.team_id.is_empty() {
+            struct_ser.serialize_field("teamId", &self.team_id)?;
+        }
+        if self.epoch != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("epoch", ToString::to_string(&self.epoch).as_str())?;
+        }
+        if self.time != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("time", ToString::to_string(&self.time).as_str())?;
+        }
+        if !self.score.is_empty() {
+            struct_ser.serialize_field("score", &self.score)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GameTeamScore {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "game_id",
+            "gameId",
+            "team_id",
+            "teamId",
+            "epoch",
+            "time",
+            "score",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            GameId,
+            TeamId,
+            Epoch,
+            Time,
+            Score,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "gameId" | "game_id" => Ok(GeneratedField::GameId),
+                            "teamId" | "team_id" => Ok(GeneratedField::TeamId),
+                            "epoch" => Ok(GeneratedField::Epoch),
+                            "time" => Ok(GeneratedField::Time),
+                            "score" => Ok(GeneratedField::Score),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GameTeamScore;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.GameTeamScore")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GameTeamScore, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut game_id__ = None;
+                let mut team_id__ = None;
+                let mut epoch__ = None;
+                let mut time__ = None;
+                let mut score__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::GameId => {
+                            if game_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("gameId"));
+                            }
+                            game_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::TeamId => {
+                            if team_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("teamId"));
+                            }
+                            team_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Epoch => {
+                            if epoch__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epoch"));
+                            }
+                            epoch__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Time => {
+                            if time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("time"));
+                            }
+                            time__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Score => {
+                            if score__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("score"));
+                            }
+                            score__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(GameTeamScore {
+                    game_id: game_id__.unwrap_or_default(),
+                    team_id: team_id__.unwrap_or_default(),
+                    epoch: epoch__.unwrap_or_default(),
+                    time: time__.unwrap_or_default(),
+                    score: score__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.GameTeamScore", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for KeyRotation {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.node_id.is_empty() {
+            len += 1;
+        }
+        if !self.old_pub_key.is_empty() {
+            len += 1;
+        }
+        if !self.new_pub_key.is_empty() {
+            len += 1;
+        }
+        if self.block_height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.KeyRotation", len)?;
+        if !self.node_id.is_empty() {
+            struct_ser.serialize_field("nodeId", &self.node_id)?;
+        }
+        if !self.old_pub_key.is_empty() {
+            struct_ser.serialize_field("oldPubKey", &self.old_pub_key)?;
+        }
+        if !self.new_pub_key.is_empty() {
+            struct_ser.serialize_field("newPubKey", &self.new_pub_key)?;
+        }
+        if self.block_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("blockHeight", ToString::to_string(&self.block_height).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for KeyRotation {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "node_id",
+            "nodeId",
+            "old_pub_key",
+            "oldPubKey",
+            "new_pub_key",
+            "newPubKey",
+            "block_height",
+            "blockHeight",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            NodeId,
+            OldPubKey,
+            NewPubKey,
+            BlockHeight,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "nodeId" | "node_id" => Ok(GeneratedField::NodeId),
+                            "oldPubKey" | "old_pub_key" => Ok(GeneratedField::OldPubKey),
+                            "newPubKey" | "new_pub_key" => Ok(GeneratedField::NewPubKey),
+                            "blockHeight" | "block_height" => Ok(GeneratedField::BlockHeight),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = KeyRotation;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.KeyRotation")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<KeyRotation, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut node_id__ = None;
+                let mut old_pub_key__ = None;
+                let mut new_pub_key__ = None;
+                let mut block_height__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::NodeId => {
+                            if node_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nodeId"));
+                            }
+                            node_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::OldPubKey => {
+                            if old_pub_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("oldPubKey"));
+                            }
+                            old_pub_key__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::NewPubKey => {
+                            if new_pub_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newPubKey"));
+                            }
+                            new_pub_key__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::BlockHeight => {
+                            if block_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("blockHeight"));
+                            }
+                            block_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(KeyRotation {
+                    node_id: node_id__.unwrap_or_default(),
+                    old_pub_key: old_pub_key__.unwrap_or_default(),
+                    new_pub_key: new_pub_key__.unwrap_or_default(),
+                    block_height: block_height__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.KeyRotation", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for LedgerMovements {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.ledger_movements.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.LedgerMovements", len)?;
+        if !self.ledger_movements.is_empty() {
+            struct_ser.serialize_field("ledgerMovements", &self.ledger_movements)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for LedgerMovements {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "ledger_movements",
+            "ledgerMovements",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            LedgerMovements,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "ledgerMovements" | "ledger_movements" => Ok(GeneratedField::LedgerMovements),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = LedgerMovements;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.LedgerMovements")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LedgerMovements, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut ledger_movements__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::LedgerMovements => {
+                            if ledger_movements__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ledgerMovements"));
+                            }
+                            ledger_movements__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(LedgerMovements {
+                    ledger_movements: ledger_movements__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.LedgerMovements", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for LossSocialization {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.market_id.is_empty() {
+            len += 1;
+        }
+        if !self.party_id.is_empty() {
+            len += 1;
+        }
+        if !self.amount.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.LossSocialization", len)?;
+        if !self.market_id.is_empty() {
+            struct_ser.serialize_field("marketId", &self.market_id)?;
+        }
+        if !self.party_id.is_empty() {
+            struct_ser.serialize_field("partyId", &self.party_id)?;
+        }
+        if !self.amount.is_empty() {
+            struct_ser.serialize_field("amount", &self.amount)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for LossSocialization {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "market_id",
+            "marketId",
+            "party_id",
+            "partyId",
+            "amount",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            MarketId,
+            PartyId,
+            Amount,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "marketId" | "market_id" => Ok(GeneratedField::MarketId),
+                            "partyId" | "party_id" => Ok(GeneratedField::PartyId),
+                            "amount" => Ok(GeneratedField::Amount),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = LossSocialization;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.LossSocialization")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LossSocialization, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut market_id__ = None;
+                let mut party_id__ = None;
+                let mut amount__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::MarketId => {
+                            if market_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("marketId"));
+                            }
+                            market_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::PartyId => {
+                            if party_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("partyId"));
+                            }
+                            party_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Amount => {
+                            if amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amount"));
+                            }
+                            amount__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(LossSocialization {
+                    market_id: market_id__.unwrap_or_default(),
+                    party_id: party_id__.unwrap_or_default(),
+                    amount: amount__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.LossSocialization", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for MakerFeesGenerated {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.taker.is_empty() {
+            len += 1;
+        }
+        if !self.maker_fees_paid.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.MakerFeesGenerated", len)?;
+        if !self.taker.is_empty() {
+            struct_ser.serialize_field("taker", &self.taker)?;
+        }
+        if !self.maker_fees_paid.is_empty() {
+            struct_ser.serialize_field("makerFeesPaid", &self.maker_fees_paid)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for MakerFeesGenerated {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "taker",
+            "maker_fees_paid",
+            "makerFeesPaid",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Taker,
+            MakerFeesPaid,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "taker" => Ok(GeneratedField::Taker),
+                            "makerFeesPaid" | "maker_fees_paid" => Ok(GeneratedField::MakerFeesPaid),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = MakerFeesGenerated;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.MakerFeesGenerated")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<MakerFeesGenerated, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut taker__ = None;
+                let mut maker_fees_paid__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Taker => {
+                            if taker__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("taker"));
+                            }
+                            taker__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::MakerFeesPaid => {
+                            if maker_fees_paid__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("makerFeesPaid"));
+                            }
+                            maker_fees_paid__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(MakerFeesGenerated {
+                    taker: taker__.unwrap_or_default(),
+                    maker_fees_paid: maker_fees_paid__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.MakerFeesGenerated", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for MarketEvent {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.market_id.is_empty() {
+            len += 1;
+        }
+        if !self.payload.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.MarketEvent", len)?;
+        if !self.market_id.is_empty() {
+            struct_ser.serialize_field("marketId", &self.market_id)?;
+        }
+        if !self.payload.is_empty() {
+            struct_ser.serialize_field("payload", &self.payload)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for MarketEvent {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "market_id",
+            "marketId",
+            "payload",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            MarketId,
+            Payload,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "marketId" | "market_id" => Ok(GeneratedField::MarketId),
+                            "payload" => Ok(GeneratedField::Payload),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = MarketEvent;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.MarketEvent")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<MarketEvent, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut market_id__ = None;
+                let mut payload__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::MarketId => {
+                            if market_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("marketId"));
+                            }
+                            market_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Payload => {
+                            if payload__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("payload"));
+                            }
+                            payload__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(MarketEvent {
+                    market_id: market_id__.unwrap_or_default(),
+                    payload: payload__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.MarketEvent", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for MarketTick {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.id.is_empty() {
+            len += 1;
+        }
+        if self.time != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.MarketTick", len)?;
+        if !self.id.is_empty() {
+            struct_ser.serialize_field("id", &self.id)?;
+        }
+        if self.time != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("time", ToString::to_string(&self.time).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for MarketTick {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "id",
+            "time",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Id,
+            Time,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "id" => Ok(GeneratedField::Id),
+                            "time" => Ok(GeneratedField::Time),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = MarketTick;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.MarketTick")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<MarketTick, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut id__ = None;
+                let mut time__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Time => {
+                            if time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("time"));
+                            }
+                            time__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(MarketTick {
+                    id: id__.unwrap_or_default(),
+                    time: time__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.MarketTick", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for OneOffGovernanceTransfer {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.deliver_on != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.OneOffGovernanceTransfer", len)?;
+        if self.deliver_on != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("deliverOn", ToString::to_string(&self.deliver_on).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for OneOffGovernanceTransfer {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "deliver_on",
+            "deliverOn",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            DeliverOn,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "deliverOn" | "deliver_on" => Ok(GeneratedField::DeliverOn),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = OneOffGovernanceTransfer;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.OneOffGovernanceTransfer")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<OneOffGovernanceTransfer, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut deliver_on__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::DeliverOn => {
+                            if deliver_on__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("deliverOn"));
+                            }
+                            deliver_on__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(OneOffGovernanceTransfer {
+                    deliver_on: deliver_on__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.OneOffGovernanceTransfer", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for OneOffTransfer {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.deliver_on != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.OneOffTransfer", len)?;
+        if self.deliver_on != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("deliverOn", ToString::to_string(&self.deliver_on).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for OneOffTransfer {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "deliver_on",
+            "deliverOn",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            DeliverOn,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "deliverOn" | "deliver_on" => Ok(GeneratedField::DeliverOn),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = OneOffTransfer;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.OneOffTransfer")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<OneOffTransfer, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut deliver_on__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::DeliverOn => {
+                            if deliver_on__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("deliverOn"));
+                            }
+                            deliver_on__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(OneOffTransfer {
+                    deliver_on: deliver_on__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.OneOffTransfer", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PaidLiquidityFeesStats {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.market.is_empty() {
+            len += 1;
+        }
+        if !self.asset.is_empty() {
+            len += 1;
+        }
+        if self.epoch_seq != 0 {
+            len += 1;
+        }
+        if !self.total_fees_paid.is_empty() {
+            len += 1;
+        }
+        if !self.fees_paid_per_party.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.PaidLiquidityFeesStats", len)?;
+        if !self.market.is_empty() {
+            struct_ser.serialize_field("market", &self.market)?;
+        }
+        if !self.asset.is_empty() {
+            struct_ser.serialize_field("asset", &self.asset)?;
+        }
+        if self.epoch_seq != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("epochSeq", ToString::to_string(&self.epoch_seq).as_str())?;
+        }
+        if !self.total_fees_paid.is_empty() {
+            struct_ser.serialize_field("totalFeesPaid", &self.total_fees_paid)?;
+        }
+        if !self.fees_paid_per_party.is_empty() {
+            struct_ser.serialize_field("feesPaidPerParty", &self.fees_paid_per_party)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PaidLiquidityFeesStats {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "market",
+            "asset",
+            "epoch_seq",
+            "epochSeq",
+            "total_fees_paid",
+            "totalFeesPaid",
+            "fees_paid_per_party",
+            "feesPaidPerParty",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Market,
+            Asset,
+            EpochSeq,
+            TotalFeesPaid,
+            FeesPaidPerParty,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "market" => Ok(GeneratedField::Market),
+                            "asset" => Ok(GeneratedField::Asset),
+                            "epochSeq" | "epoch_seq" => Ok(GeneratedField::EpochSeq),
+                            "totalFeesPaid" | "total_fees_paid" => Ok(GeneratedField::TotalFeesPaid),
+                            "feesPaidPerParty" | "fees_paid_per_party" => Ok(GeneratedField::FeesPaidPerParty),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PaidLiquidityFeesStats;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.PaidLiquidityFeesStats")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PaidLiquidityFeesStats, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut market__ = None;
+                let mut asset__ = None;
+                let mut epoch_seq__ = None;
+                let mut total_fees_paid__ = None;
+                let mut fees_paid_per_party__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Market => {
+                            if market__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("market"));
+                            }
+                            market__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Asset => {
+                            if asset__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("asset"));
+                            }
+                            asset__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::EpochSeq => {
+                            if epoch_seq__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epochSeq"));
+                            }
+                            epoch_seq__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::TotalFeesPaid => {
+                            if total_fees_paid__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("totalFeesPaid"));
+                            }
+                            total_fees_paid__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::FeesPaidPerParty => {
+                            if fees_paid_per_party__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feesPaidPerParty"));
+                            }
+                            fees_paid_per_party__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(PaidLiquidityFeesStats {
+                    market: market__.unwrap_or_default(),
+                    asset: asset__.unwrap_or_default(),
+                    epoch_seq: epoch_seq__.unwrap_or_default(),
+                    total_fees_paid: total_fees_paid__.unwrap_or_default(),
+                    fees_paid_per_party: fees_paid_per_party__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.PaidLiquidityFeesStats", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PartyActivityStreak {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.party.is_empty() {
+            len += 1;
+        }
+        if self.active_for != 0 {
+            len += 1;
+        }
+        if self.inactive_for != 0 {
+            len += 1;
+        }
+        if self.is_active {
+            len += 1;
+        }
+        if !self.reward_distribution_activity_multiplier.is_empty() {
+            len += 1;
+        }
+        if !self.reward_vesting_activity_multiplier.is_empty() {
+            len += 1;
+        }
+        if self.epoch != 0 {
+            len += 1;
+        }
+        if !self.traded_volume.is_empty() {
+            len += 1;
+        }
+        if !self.open_volume.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.PartyActivityStreak", len)?;
+        if !self.party.is_empty() {
+            struct_ser.serialize_field("party", &self.party)?;
+        }
+        if self.active_for != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("activeFor", ToString::to_string(&self.active_for).as_str())?;
+        }
+        if self.inactive_for != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("inactiveFor", ToString::to_string(&self.inactive_for).as_str())?;
+        }
+        if self.is_active {
+            struct_ser.serialize_field("isActive", &self.is_active)?;
+        }
+        if !self.reward_distribution_activity_multiplier.is_empty() {
+            struct_ser.serialize_field("rewardDistributionActivityMultiplier", &self.reward_distribution_activity_multiplier)?;
+        }
+        if !self.reward_vesting_activity_multiplier.is_empty() {
+            struct_ser.serialize_field("rewardVestingActivityMultiplier", &self.reward_vesting_activity_multiplier)?;
+        }
+        if self.epoch != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("epoch", ToString::to_string(&self.epoch).as_str())?;
+        }
+        if !self.traded_volume.is_empty() {
+            struct_ser.serialize_field("tradedVolume", &self.traded_volume)?;
+        }
+        if !self.open_volume.is_empty() {
+            struct_ser.serialize_field("openVolume", &self.open_volume)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PartyActivityStreak {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "party",
+            "active_for",
+            "activeFor",
+            "inactive_for",
+            "inactiveFor",
+            "is_active",
+            "isActive",
+            "reward_distribution_activity_multiplier",
+            "rewardDistributionActivityMultiplier",
+            "reward_vesting_activity_multiplier",
+            "rewardVestingActivityMultiplier",
+            "epoch",
+            "traded_volume",
+            "tradedVolume",
+            "open_volume",
+            "openVolume",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Party,
+            ActiveFor,
+            InactiveFor,
+            IsActive,
+            RewardDistributionActivityMultiplier,
+            RewardVestingActivityMultiplier,
+            Epoch,
+            TradedVolume,
+            OpenVolume,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "party" => Ok(GeneratedField::Party),
+                            "activeFor" | "active_for" => Ok(GeneratedField::ActiveFor),
+                            "inactiveFor" | "inactive_for" => Ok(GeneratedField::InactiveFor),
+                            "isActive" | "is_active" => Ok(GeneratedField::IsActive),
+                            "rewardDistributionActivityMultiplier" | "reward_distribution_activity_multiplier" => Ok(GeneratedField::RewardDistributionActivityMultiplier),
+                            "rewardVestingActivityMultiplier" | "reward_vesting_activity_multiplier" => Ok(GeneratedField::RewardVestingActivityMultiplier),
+                            "epoch" => Ok(GeneratedField::Epoch),
+                            "tradedVolume" | "traded_volume" => Ok(GeneratedField::TradedVolume),
+                            "openVolume" | "open_volume" => Ok(GeneratedField::OpenVolume),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PartyActivityStreak;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.PartyActivityStreak")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PartyActivityStreak, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut party__ = None;
+                let mut active_for__ = None;
+                let mut inactive_for__ = None;
+                let mut is_active__ = None;
+                let mut reward_distribution_activity_multiplier__ = None;
+                let mut reward_vesting_activity_multiplier__ = None;
+                let mut epoch__ = None;
+                let mut traded_volume__ = None;
+                let mut open_volume__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Party => {
+                            if party__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("party"));
+                            }
+                            party__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ActiveFor => {
+                            if active_for__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("activeFor"));
+                            }
+                            active_for__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::InactiveFor => {
+                            if inactive_for__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("inactiveFor"));
+                            }
+                            inactive_for__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::IsActive => {
+                            if is_active__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("isActive"));
+                            }
+                            is_active__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::RewardDistributionActivityMultiplier => {
+                            if reward_distribution_activity_multiplier__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rewardDistributionActivityMultiplier"));
+                            }
+                            reward_distribution_activity_multiplier__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::RewardVestingActivityMultiplier => {
+                            if reward_vesting_activity_multiplier__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rewardVestingActivityMultiplier"));
+                            }
+                            reward_vesting_activity_multiplier__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Epoch => {
+                            if epoch__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epoch"));
+                            }
+                            epoch__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::TradedVolume => {
+                            if traded_volume__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradedVolume"));
+                            }
+                            traded_volume__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::OpenVolume => {
+                            if open_volume__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("openVolume"));
+                            }
+                            open_volume__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(PartyActivityStreak {
+                    party: party__.unwrap_or_default(),
+                    active_for: active_for__.unwrap_or_default(),
+                    inactive_for: inactive_for__.unwrap_or_default(),
+                    is_active: is_active__.unwrap_or_default(),
+                    reward_distribution_activity_multiplier: reward_distribution_activity_multiplier__.unwrap_or_default(),
+                    reward_vesting_activity_multiplier: reward_vesting_activity_multiplier__.unwrap_or_default(),
+                    epoch: epoch__.unwrap_or_default(),
+                    traded_volume: traded_volume__.unwrap_or_default(),
+                    open_volume: open_volume__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.PartyActivityStreak", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PartyAmount {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.party.is_empty() {
+            len += 1;
+        }
+        if !self.amount.is_empty() {
+            len += 1;
+        }
+        if !self.quantum_amount.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.PartyAmount", len)?;
+        if !self.party.is_empty() {
+            struct_ser.serialize_field("party", &self.party)?;
+        }
+        if !self.amount.is_empty() {
+            struct_ser.serialize_field("amount", &self.amount)?;
+        }
+        if !self.quantum_amount.is_empty() {
+            struct_ser.serialize_field("quantumAmount", &self.quantum_amount)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PartyAmount {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "party",
+            "amount",
+            "quantum_amount",
+            "quantumAmount",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Party,
+            Amount,
+            QuantumAmount,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "party" => Ok(GeneratedField::Party),
+                            "amount" => Ok(GeneratedField::Amount),
+                            "quantumAmount" | "quantum_amount" => Ok(GeneratedField::QuantumAmount),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PartyAmount;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.PartyAmount")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PartyAmount, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut party__ = None;
+                let mut amount__ = None;
+                let mut quantum_amount__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Party => {
+                            if party__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("party"));
+                            }
+                            party__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Amount => {
+                            if amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amount"));
+                            }
+                            amount__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::QuantumAmount => {
+                            if quantum_amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("quantumAmount"));
+                            }
+                            quantum_amount__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(PartyAmount {
+                    party: party__.unwrap_or_default(),
+                    amount: amount__.unwrap_or_default(),
+                    quantum_amount: quantum_amount__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.PartyAmount", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PartyLockedBalance {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.asset.is_empty() {
+            len += 1;
+        }
+        if self.until_epoch != 0 {
+            len += 1;
+        }
+        if !self.balance.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.PartyLockedBalance", len)?;
+        if !self.asset.is_empty() {
+            struct_ser.serialize_field("asset", &self.asset)?;
+        }
+        if self.until_epoch != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("untilEpoch", ToString::to_string(&self.until_epoch).as_str())?;
+        }
+        if !self.balance.is_empty() {
+            struct_ser.serialize_field("balance", &self.balance)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PartyLockedBalance {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "asset",
+            "until_epoch",
+            "untilEpoch",
+            "balance",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Asset,
+            UntilEpoch,
+            Balance,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "asset" => Ok(GeneratedField::Asset),
+                            "untilEpoch" | "until_epoch" => Ok(GeneratedField::UntilEpoch),
+                            "balance" => Ok(GeneratedField::Balance),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PartyLockedBalance;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.PartyLockedBalance")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PartyLockedBalance, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut asset__ = None;
+                let mut until_epoch__ = None;
+                let mut balance__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Asset => {
+                            if asset__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("asset"));
+                            }
+                            asset__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::UntilEpoch => {
+                            if until_epoch__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("untilEpoch"));
+                            }
+                            until_epoch__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Balance => {
+                            if balance__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("balance"));
+                            }
+                            balance__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(PartyLockedBalance {
+                    asset: asset__.unwrap_or_default(),
+                    until_epoch: until_epoch__.unwrap_or_default(),
+                    balance: balance__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.PartyLockedBalance", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PartyMarginModeUpdated {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.market_id.is_empty() {
+            len += 1;
+        }
+        if !self.party_id.is_empty() {
+            len += 1;
+        }
+        if self.margin_mode != 0 {
+            len += 1;
+        }
+        if self.margin_factor.is_some() {
+            len += 1;
+        }
+        if self.min_theoretical_margin_factor.is_some() {
+            len += 1;
+        }
+        if self.max_theoretical_leverage.is_some() {
+            len += 1;
+        }
+        if self.at_epoch != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.PartyMarginModeUpdated", len)?;
+        if !self.market_id.is_empty() {
+            struct_ser.serialize_field("marketId", &self.market_id)?;
+        }
+        if !self.party_id.is_empty() {
+            struct_ser.serialize_field("partyId", &self.party_id)?;
+        }
+        if self.margin_mode != 0 {
+            let v = super::super::MarginMode::try_from(self.margin_mode)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.margin_mode)))?;
+            struct_ser.serialize_field("marginMode", &v)?;
+        }
+        if let Some(v) = self.margin_factor.as_ref() {
+            struct_ser.serialize_field("marginFactor", v)?;
+        }
+        if let Some(v) = self.min_theoretical_margin_factor.as_ref() {
+            struct_ser.serialize_field("minTheoreticalMarginFactor", v)?;
+        }
+        if let Some(v) = self.max_theoretical_leverage.as_ref() {
+            struct_ser.serialize_field("maxTheoreticalLeverage", v)?;
+        }
+        if self.at_epoch != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("atEpoch", ToString::to_string(&self.at_epoch).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PartyMarginModeUpdated {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "market_id",
+            "marketId",
+            "party_id",
+            "partyId",
+            "margin_mode",
+            "marginMode",
+            "margin_factor",
+            "marginFactor",
+            "min_theoretical_margin_factor",
+            "minTheoreticalMarginFactor",
+            "max_theoretical_leverage",
+            "maxTheoreticalLeverage",
+            "at_epoch",
+            "atEpoch",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            MarketId,
+            PartyId,
+            MarginMode,
+            MarginFactor,
+            MinTheoreticalMarginFactor,
+            MaxTheoreticalLeverage,
+            AtEpoch,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "marketId" | "market_id" => Ok(GeneratedField::MarketId),
+                            "partyId" | "party_id" => Ok(GeneratedField::PartyId),
+                            "marginMode" | "margin_mode" => Ok(GeneratedField::MarginMode),
+                            "marginFactor" | "margin_factor" => Ok(GeneratedField::MarginFactor),
+                            "minTheoreticalMarginFactor" | "min_theoretical_margin_factor" => Ok(GeneratedField::MinTheoreticalMarginFactor),
+                            "maxTheoreticalLeverage" | "max_theoretical_leverage" => Ok(GeneratedField::MaxTheoreticalLeverage),
+                            "atEpoch" | "at_epoch" => Ok(GeneratedField::AtEpoch),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PartyMarginModeUpdated;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.PartyMarginModeUpdated")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PartyMarginModeUpdated, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut market_id__ = None;
+                let mut party_id__ = None;
+                let mut margin_mode__ = None;
+                let mut margin_factor__ = None;
+                let mut min_theoretical_margin_factor__ = None;
+                let mut max_theoretical_leverage__ = None;
+                let mut at_epoch__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::MarketId => {
+                            if market_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("marketId"));
+                            }
+                            market_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::PartyId => {
+                            if party_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("partyId"));
+                            }
+                            party_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::MarginMode => {
+                            if margin_mode__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("marginMode"));
+                            }
+                            margin_mode__ = Some(map_.next_value::<super::super::MarginMode>()? as i32);
+                        }
+                        GeneratedField::MarginFactor => {
+                            if margin_factor__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("marginFactor"));
+                            }
+                            margin_factor__ = map_.next_value()?;
+                        }
+                        GeneratedField::MinTheoreticalMarginFactor => {
+                            if min_theoretical_margin_factor__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("minTheoreticalMarginFactor"));
+                            }
+                            min_theoretical_margin_factor__ = map_.next_value()?;
+                        }
+                        GeneratedField::MaxTheoreticalLeverage => {
+                            if max_theoretical_leverage__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxTheoreticalLeverage"));
+                            }
+                            max_theoretical_leverage__ = map_.next_value()?;
+                        }
+                        GeneratedField::AtEpoch => {
+                            if at_epoch__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("atEpoch"));
+                            }
+                            at_epoch__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(PartyMarginModeUpdated {
+                    market_id: market_id__.unwrap_or_default(),
+                    party_id: party_id__.unwrap_or_default(),
+                    margin_mode: margin_mode__.unwrap_or_default(),
+                    margin_factor: margin_factor__,
+                    min_theoretical_margin_factor: min_theoretical_margin_factor__,
+                    max_theoretical_leverage: max_theoretical_leverage__,
+                    at_epoch: at_epoch__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.PartyMarginModeUpdated", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PartyProfileUpdated {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.updated_profile.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.PartyProfileUpdated", len)?;
+        if let Some(v) = self.updated_profile.as_ref() {
+            struct_ser.serialize_field("updatedProfile", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PartyProfileUpdated {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "updated_profile",
+            "updatedProfile",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            UpdatedProfile,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "updatedProfile" | "updated_profile" => Ok(GeneratedField::UpdatedProfile),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PartyProfileUpdated;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.PartyProfileUpdated")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PartyProfileUpdated, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut updated_profile__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::UpdatedProfile => {
+                            if updated_profile__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("updatedProfile"));
+                            }
+                            updated_profile__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(PartyProfileUpdated {
+                    updated_profile: updated_profile__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.PartyProfileUpdated", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PartyVestingBalance {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.asset.is_empty() {
+            len += 1;
+        }
+        if !self.balance.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.PartyVestingBalance", len)?;
+        if !self.asset.is_empty() {
+            struct_ser.serialize_field("asset", &self.asset)?;
+        }
+        if !self.balance.is_empty() {
+            struct_ser.serialize_field("balance", &self.balance)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PartyVestingBalance {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "asset",
+            "balance",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Asset,
+            Balance,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "asset" => Ok(GeneratedField::Asset),
+                            "balance" => Ok(GeneratedField::Balance),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PartyVestingBalance;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.PartyVestingBalance")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PartyVestingBalance, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut asset__ = None;
+                let mut balance__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Asset => {
+                            if asset__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("asset"));
+                            }
+                            asset__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Balance => {
+                            if balance__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("balance"));
+                            }
+                            balance__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(PartyVestingBalance {
+                    asset: asset__.unwrap_or_default(),
+                    balance: balance__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.PartyVestingBalance", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PartyVestingStats {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.party_id.is_empty() {
+            len += 1;
+        }
+        if !self.reward_bonus_multiplier.is_empty() {
+            len += 1;
+        }
+        if !self.quantum_balance.is_empty() {
+            len += 1;
+        }
+        if !self.summed_reward_bonus_multiplier.is_empty() {
+            len += 1;
+        }
+        if !self.summed_quantum_balance.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.PartyVestingStats", len)?;
+        if !self.party_id.is_empty() {
+            struct_ser.serialize_field("partyId", &self.party_id)?;
+        }
+        if !self.reward_bonus_multiplier.is_empty() {
+            struct_ser.serialize_field("rewardBonusMultiplier", &self.reward_bonus_multiplier)?;
+        }
+        if !self.quantum_balance.is_empty() {
+            struct_ser.serialize_field("quantumBalance", &self.quantum_balance)?;
+        }
+        if !self.summed_reward_bonus_multiplier.is_empty() {
+            struct_ser.serialize_field("summedRewardBonusMultiplier", &self.summed_reward_bonus_multiplier)?;
+        }
+        if !self.summed_quantum_balance.is_empty() {
+            struct_ser.serialize_field("summedQuantumBalance", &self.summed_quantum_balance)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PartyVestingStats {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "party_id",
+            "partyId",
+            "reward_bonus_multiplier",
+            "rewardBonusMultiplier",
+            "quantum_balance",
+            "quantumBalance",
+            "summed_reward_bonus_multiplier",
+            "summedRewardBonusMultiplier",
+            "summed_quantum_balance",
+            "summedQuantumBalance",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PartyId,
+            RewardBonusMultiplier,
+            QuantumBalance,
+            SummedRewardBonusMultiplier,
+            SummedQuantumBalance,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "partyId" | "party_id" => Ok(GeneratedField::PartyId),
+                            "rewardBonusMultiplier" | "reward_bonus_multiplier" => Ok(GeneratedField::RewardBonusMultiplier),
+                            "quantumBalance" | "quantum_balance" => Ok(GeneratedField::QuantumBalance),
+                            "summedRewardBonusMultiplier" | "summed_reward_bonus_multiplier" => Ok(GeneratedField::SummedRewardBonusMultiplier),
+                            "summedQuantumBalance" | "summed_quantum_balance" => Ok(GeneratedField::SummedQuantumBalance),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PartyVestingStats;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.PartyVestingStats")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PartyVestingStats, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut party_id__ = None;
+                let mut reward_bonus_multiplier__ = None;
+                let mut quantum_balance__ = None;
+                let mut summed_reward_bonus_multiplier__ = None;
+                let mut summed_quantum_balance__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PartyId => {
+                            if party_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("partyId"));
+                            }
+                            party_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::RewardBonusMultiplier => {
+                            if reward_bonus_multiplier__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rewardBonusMultiplier"));
+                            }
+                            reward_bonus_multiplier__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::QuantumBalance => {
+                            if quantum_balance__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("quantumBalance"));
+                            }
+                            quantum_balance__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::SummedRewardBonusMultiplier => {
+                            if summed_reward_bonus_multiplier__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("summedRewardBonusMultiplier"));
+                            }
+                            summed_reward_bonus_multiplier__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::SummedQuantumBalance => {
+                            if summed_quantum_balance__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("summedQuantumBalance"));
+                            }
+                            summed_quantum_balance__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(PartyVestingStats {
+                    party_id: party_id__.unwrap_or_default(),
+                    reward_bonus_multiplier: reward_bonus_multiplier__.unwrap_or_default(),
+                    quantum_balance: quantum_balance__.unwrap_or_default(),
+                    summed_reward_bonus_multiplier: summed_reward_bonus_multiplier__.unwrap_or_default(),
+                    summed_quantum_balance: summed_quantum_balance__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.PartyVestingStats", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PartyVestingSummary {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.party.is_empty() {
+            len += 1;
+        }
+        if !self.party_locked_balances.is_empty() {
+            len += 1;
+        }
+        if !self.party_vesting_balances.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.PartyVestingSummary", len)?;
+        if !self.party.is_empty() {
+            struct_ser.serialize_field("party", &self.party)?;
+        }
+        if !self.party_locked_balances.is_empty() {
+            struct_ser.serialize_field("partyLockedBalances", &self.party_locked_balances)?;
+        }
+        if !self.party_vesting_balances.is_empty() {
+            struct_ser.serialize_field("partyVestingBalances", &self.party_vesting_balances)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PartyVestingSummary {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "party",
+            "party_locked_balances",
+            "partyLockedBalances",
+            "party_vesting_balances",
+            "partyVestingBalances",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Party,
+            PartyLockedBalances,
+            PartyVestingBalances,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "party" => Ok(GeneratedField::Party),
+                            "partyLockedBalances" | "party_locked_balances" => Ok(GeneratedField::PartyLockedBalances),
+                            "partyVestingBalances" | "party_vesting_balances" => Ok(GeneratedField::PartyVestingBalances),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PartyVestingSummary;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.PartyVestingSummary")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PartyVestingSummary, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut party__ = None;
+                let mut party_locked_balances__ = None;
+                let mut party_vesting_balances__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Party => {
+                            if party__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("party"));
+                            }
+                            party__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::PartyLockedBalances => {
+                            if party_locked_balances__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("partyLockedBalances"));
+                            }
+                            party_locked_balances__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::PartyVestingBalances => {
+                            if party_vesting_balances__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("partyVestingBalances"));
+                            }
+                            party_vesting_balances__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(PartyVestingSummary {
+                    party: party__.unwrap_or_default(),
+                    party_locked_balances: party_locked_balances__.unwrap_or_default(),
+                    party_vesting_balances: party_vesting_balances__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.PartyVestingSummary", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PartyVolumeDiscountStats {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.party_id.is_empty() {
+            len += 1;
+        }
+        if !self.discount_factor.is_empty() {
+            len += 1;
+        }
+        if !self.running_volume.is_empty() {
+            len += 1;
+        }
+        if self.discount_factors.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.PartyVolumeDiscountStats", len)?;
+        if !self.party_id.is_empty() {
+            struct_ser.serialize_field("partyId", &self.party_id)?;
+        }
+        if !self.discount_factor.is_empty() {
+            struct_ser.serialize_field("discountFactor", &self.discount_factor)?;
+        }
+        if !self.running_volume.is_empty() {
+            struct_ser.serialize_field("runningVolume", &self.running_volume)?;
+        }
+        if let Some(v) = self.discount_factors.as_ref() {
+            struct_ser.serialize_field("discountFactors", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PartyVolumeDiscountStats {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "party_id",
+            "partyId",
+            "discount_factor",
+            "discountFactor",
+            "running_volume",
+            "runningVolume",
+            "discount_factors",
+            "discountFactors",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PartyId,
+            DiscountFactor,
+            RunningVolume,
+            DiscountFactors,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "partyId" | "party_id" => Ok(GeneratedField::PartyId),
+                            "discountFactor" | "discount_factor" => Ok(GeneratedField::DiscountFactor),
+                            "runningVolume" | "running_volume" => Ok(GeneratedField::RunningVolume),
+                            "discountFactors" | "discount_factors" => Ok(GeneratedField::DiscountFactors),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PartyVolumeDiscountStats;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.PartyVolumeDiscountStats")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PartyVolumeDiscountStats, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut party_id__ = None;
+                let mut discount_factor__ = None;
+                let mut running_volume__ = None;
+                let mut discount_factors__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PartyId => {
+                            if party_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("partyId"));
+                            }
+                            party_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::DiscountFactor => {
+                            if discount_factor__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("discountFactor"));
+                            }
+                            discount_factor__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::RunningVolume => {
+                            if running_volume__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("runningVolume"));
+                            }
+                            running_volume__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::DiscountFactors => {
+                            if discount_factors__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("discountFactors"));
+                            }
+                            discount_factors__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(PartyVolumeDiscountStats {
+                    party_id: party_id__.unwrap_or_default(),
+                    discount_factor: discount_factor__.unwrap_or_default(),
+                    running_volume: running_volume__.unwrap_or_default(),
+                    discount_factors: discount_factors__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.PartyVolumeDiscountStats", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PartyVolumeRebateStats {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.party_id.is_empty() {
+            len += 1;
+        }
+        if !self.additional_rebate.is_empty() {
+            len += 1;
+        }
+        if !self.maker_volume_fraction.is_empty() {
+            len += 1;
+        }
+        if !self.maker_fees_received.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.PartyVolumeRebateStats", len)?;
+        if !self.party_id.is_empty() {
+            struct_ser.serialize_field("partyId", &self.party_id)?;
+        }
+        if !self.additional_rebate.is_empty() {
+            struct_ser.serialize_field("additionalRebate", &self.additional_rebate)?;
+        }
+        if !self.maker_volume_fraction.is_empty() {
+            struct_ser.serialize_field("makerVolumeFraction", &self.maker_volume_fraction)?;
+        }
+        if !self.maker_fees_received.is_empty() {
+            struct_ser.serialize_field("makerFeesReceived", &self.maker_fees_received)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PartyVolumeRebateStats {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "party_id",
+            "partyId",
+            "additional_rebate",
+            "additionalRebate",
+            "maker_volume_fraction",
+            "makerVolumeFraction",
+            "maker_fees_received",
+            "makerFeesReceived",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PartyId,
+            AdditionalRebate,
+            MakerVolumeFraction,
+            MakerFeesReceived,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "partyId" | "party_id" => Ok(GeneratedField::PartyId),
+                            "additionalRebate" | "additional_rebate" => Ok(GeneratedField::AdditionalRebate),
+                            "makerVolumeFraction" | "maker_volume_fraction" => Ok(GeneratedField::MakerVolumeFraction),
+                            "makerFeesReceived" | "maker_fees_received" => Ok(GeneratedField::MakerFeesReceived),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PartyVolumeRebateStats;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.PartyVolumeRebateStats")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PartyVolumeRebateStats, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut party_id__ = None;
+                let mut additional_rebate__ = None;
+                let mut maker_volume_fraction__ = None;
+                let mut maker_fees_received__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PartyId => {
+                            if party_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("partyId"));
+                            }
+                            party_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::AdditionalRebate => {
+                            if additional_rebate__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("additionalRebate"));
+                            }
+                            additional_rebate__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::MakerVolumeFraction => {
+                            if maker_volume_fraction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("makerVolumeFraction"));
+                            }
+                            maker_volume_fraction__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::MakerFeesReceived => {
+                            if maker_fees_received__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("makerFeesReceived"));
+                            }
+                            maker_fees_received__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(PartyVolumeRebateStats {
+                    party_id: party_id__.unwrap_or_default(),
+                    additional_rebate: additional_rebate__.unwrap_or_default(),
+                    maker_volume_fraction: maker_volume_fraction__.unwrap_or_default(),
+                    maker_fees_received: maker_fees_received__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.PartyVolumeRebateStats", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PositionResolution {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.market_id.is_empty() {
+            len += 1;
+        }
+        if self.distressed != 0 {
+            len += 1;
+        }
+        if self.closed != 0 {
+            len += 1;
+        }
+        if !self.mark_price.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.PositionResolution", len)?;
+        if !self.market_id.is_empty() {
+            struct_ser.serialize_field("marketId", &self.market_id)?;
+        }
+        if self.distressed != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("distressed", ToString::to_string(&self.distressed).as_str())?;
+        }
+        if self.closed != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("closed", ToString::to_string(&self.closed).as_str())?;
+        }
+        if !self.mark_price.is_empty() {
+            struct_ser.serialize_field("markPrice", &self.mark_price)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PositionResolution {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "market_id",
+            "marketId",
+            "distressed",
+            "closed",
+            "mark_price",
+            "markPrice",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            MarketId,
+            Distressed,
+            Closed,
+            MarkPrice,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "marketId" | "market_id" => Ok(GeneratedField::MarketId),
+                            "distressed" => Ok(GeneratedField::Distressed),
+                            "closed" => Ok(GeneratedField::Closed),
+                            "markPrice" | "mark_price" => Ok(GeneratedField::MarkPrice),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PositionResolution;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.PositionResolution")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionResolution, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut market_id__ = None;
+                let mut distressed__ = None;
+                let mut closed__ = None;
+                let mut mark_price__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::MarketId => {
+                            if market_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("marketId"));
+                            }
+                            market_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Distressed => {
+                            if distressed__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("distressed"));
+                            }
+                            distressed__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Closed => {
+                            if closed__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("closed"));
+                            }
+                            closed__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::MarkPrice => {
+                            if mark_price__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("markPrice"));
+                            }
+                            mark_price__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(PositionResolution {
+                    market_id: market_id__.unwrap_or_default(),
+                    distressed: distressed__.unwrap_or_default(),
+                    closed: closed__.unwrap_or_default(),
+                    mark_price: mark_price__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.PositionResolution", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PositionStateEvent {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.party_id.is_empty() {
+            len += 1;
+        }
+        if !self.market_id.is_empty() {
+            len += 1;
+        }
+        if self.size != 0 {
+            len += 1;
+        }
+        if self.potential_buys != 0 {
+            len += 1;
+        }
+        if self.potential_sells != 0 {
+            len += 1;
+        }
+        if !self.vw_buy_price.is_empty() {
+            len += 1;
+        }
+        if !self.vw_sell_price.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.PositionStateEvent", len)?;
+        if !self.party_id.is_empty() {
+            struct_ser.serialize_field("partyId", &self.party_id)?;
+        }
+        if !self.market_id.is_empty() {
+            struct_ser.serialize_field("marketId", &self.market_id)?;
+        }
+        if self.size != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("size", ToString::to_string(&self.size).as_str())?;
+        }
+        if self.potential_buys != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("potentialBuys", ToString::to_string(&self.potential_buys).as_str())?;
+        }
+        if self.potential_sells != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("potentialSells", ToString::to_string(&self.potential_sells).as_str())?;
+        }
+        if !self.vw_buy_price.is_empty() {
+            struct_ser.serialize_field("vwBuyPrice", &self.vw_buy_price)?;
+        }
+        if !self.vw_sell_price.is_empty() {
+            struct_ser.serialize_field("vwSellPrice", &self.vw_sell_price)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PositionStateEvent {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "party_id",
+            "partyId",
+            "market_id",
+            "marketId",
+            "size",
+            "potential_buys",
+            "potentialBuys",
+            "potential_sells",
+            "potentialSells",
+            "vw_buy_price",
+            "vwBuyPrice",
+            "vw_sell_price",
+            "vwSellPrice",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PartyId,
+            MarketId,
+            Size,
+            PotentialBuys,
+            PotentialSells,
+            VwBuyPrice,
+            VwSellPrice,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "partyId" | "party_id" => Ok(GeneratedField::PartyId),
+                            "marketId" | "market_id" => Ok(GeneratedField::MarketId),
+                            "size" => Ok(GeneratedField::Size),
+                            "potentialBuys" | "potential_buys" => Ok(GeneratedField::PotentialBuys),
+                            "potentialSells" | "potential_sells" => Ok(GeneratedField::PotentialSells),
+                            "vwBuyPrice" | "vw_buy_price" => Ok(GeneratedField::VwBuyPrice),
+                            "vwSellPrice" | "vw_sell_price" => Ok(GeneratedField::VwSellPrice),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PositionStateEvent;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.PositionStateEvent")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionStateEvent, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut party_id__ = None;
+                let mut market_id__ = None;
+                let mut size__ = None;
+                let mut potential_buys__ = None;
+                let mut potential_sells__ = None;
+                let mut vw_buy_price__ = None;
+                let mut vw_sell_price__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PartyId => {
+                            if party_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("partyId"));
+                            }
+                            party_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::MarketId => {
+                            if market_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("marketId"));
+                            }
+                            market_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Size => {
+                            if size__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("size"));
+                            }
+                            size__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::PotentialBuys => {
+                            if potential_buys__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("potentialBuys"));
+                            }
+                            potential_buys__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::PotentialSells => {
+                            if potential_sells__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("potentialSells"));
+                            }
+                            potential_sells__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::VwBuyPrice => {
+                            if vw_buy_price__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("vwBuyPrice"));
+                            }
+                            vw_buy_price__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::VwSellPrice => {
+                            if vw_sell_price__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("vwSellPrice"));
+                            }
+                            vw_sell_price__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(PositionStateEvent {
+                    party_id: party_id__.unwrap_or_default(),
+                    market_id: market_id__.unwrap_or_default(),
+                    size: size__.unwrap_or_default(),
+                    potential_buys: potential_buys__.unwrap_or_default(),
+                    potential_sells: potential_sells__.unwrap_or_default(),
+                    vw_buy_price: vw_buy_price__.unwrap_or_default(),
+                    vw_sell_price: vw_sell_price__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.PositionStateEvent", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ProtocolUpgradeDataNodeReady {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.last_block_height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.ProtocolUpgradeDataNodeReady", len)?;
+        if self.last_block_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("lastBlockHeight", ToString::to_string(&self.last_block_height).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ProtocolUpgradeDataNodeReady {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "last_block_height",
+            "lastBlockHeight",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            LastBlockHeight,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "lastBlockHeight" | "last_block_height" => Ok(GeneratedField::LastBlockHeight),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ProtocolUpgradeDataNodeReady;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.ProtocolUpgradeDataNodeReady")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ProtocolUpgradeDataNodeReady, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut last_block_height__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::LastBlockHeight => {
+                            if last_block_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastBlockHeight"));
+                            }
+                            last_block_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(ProtocolUpgradeDataNodeReady {
+                    last_block_height: last_block_height__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.ProtocolUpgradeDataNodeReady", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ProtocolUpgradeEvent {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.upgrade_block_height != 0 {
+            len += 1;
+        }
+        if !self.vega_release_tag.is_empty() {
+            len += 1;
+        }
+        if !self.approvers.is_empty() {
+            len += 1;
+        }
+        if self.status != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.ProtocolUpgradeEvent", len)?;
+        if self.upgrade_block_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("upgradeBlockHeight", ToString::to_string(&self.upgrade_block_height).as_str())?;
+        }
+        if !self.vega_release_tag.is_empty() {
+            struct_ser.serialize_field("vegaReleaseTag", &self.vega_release_tag)?;
+        }
+        if !self.approvers.is_empty() {
+            struct_ser.serialize_field("approvers", &self.approvers)?;
+        }
+        if self.status != 0 {
+            let v = ProtocolUpgradeProposalStatus::try_from(self.status)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.status)))?;
+            struct_ser.serialize_field("status", &v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ProtocolUpgradeEvent {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "upgrade_block_height",
+            "upgradeBlockHeight",
+            "vega_release_tag",
+            "vegaReleaseTag",
+            "approvers",
+            "status",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            UpgradeBlockHeight,
+            VegaReleaseTag,
+            Approvers,
+            Status,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "upgradeBlockHeight" | "upgrade_block_height" => Ok(GeneratedField::UpgradeBlockHeight),
+                            "vegaReleaseTag" | "vega_release_tag" => Ok(GeneratedField::VegaReleaseTag),
+                            "approvers" => Ok(GeneratedField::Approvers),
+                            "status" => Ok(GeneratedField::Status),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ProtocolUpgradeEvent;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.ProtocolUpgradeEvent")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ProtocolUpgradeEvent, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut upgrade_block_height__ = None;
+                let mut vega_release_tag__ = None;
+                let mut approvers__ = None;
+                let mut status__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::UpgradeBlockHeight => {
+                            if upgrade_block_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("upgradeBlockHeight"));
+                            }
+                            upgrade_block_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::VegaReleaseTag => {
+                            if vega_release_tag__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("vegaReleaseTag"));
+                            }
+                            vega_release_tag__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Approvers => {
+                            if approvers__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("approvers"));
+                            }
+                            approvers__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Status => {
+                            if status__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("status"));
+                            }
+                            status__ = Some(map_.next_value::<ProtocolUpgradeProposalStatus>()? as i32);
+                        }
+                    }
+                }
+                Ok(ProtocolUpgradeEvent {
+                    upgrade_block_height: upgrade_block_height__.unwrap_or_default(),
+                    vega_release_tag: vega_release_tag__.unwrap_or_default(),
+                    approvers: approvers__.unwrap_or_default(),
+                    status: status__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.ProtocolUpgradeEvent", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ProtocolUpgradeProposalStatus {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "PROTOCOL_UPGRADE_PROPOSAL_STATUS_UNSPECIFIED",
+            Self::Pending => "PROTOCOL_UPGRADE_PROPOSAL_STATUS_PENDING",
+            Self::Approved => "PROTOCOL_UPGRADE_PROPOSAL_STATUS_APPROVED",
+            Self::Rejected => "PROTOCOL_UPGRADE_PROPOSAL_STATUS_REJECTED",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for ProtocolUpgradeProposalStatus {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "PROTOCOL_UPGRADE_PROPOSAL_STATUS_UNSPECIFIED",
+            "PROTOCOL_UPGRADE_PROPOSAL_STATUS_PENDING",
+            "PROTOCOL_UPGRADE_PROPOSAL_STATUS_APPROVED",
+            "PROTOCOL_UPGRADE_PROPOSAL_STATUS_REJECTED",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ProtocolUpgradeProposalStatus;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "PROTOCOL_UPGRADE_PROPOSAL_STATUS_UNSPECIFIED" => Ok(ProtocolUpgradeProposalStatus::Unspecified),
+                    "PROTOCOL_UPGRADE_PROPOSAL_STATUS_PENDING" => Ok(ProtocolUpgradeProposalStatus::Pending),
+                    "PROTOCOL_UPGRADE_PROPOSAL_STATUS_APPROVED" => Ok(ProtocolUpgradeProposalStatus::Approved),
+                    "PROTOCOL_UPGRADE_PROPOSAL_STATUS_REJECTED" => Ok(ProtocolUpgradeProposalStatus::Rejected),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ProtocolUpgradeStarted {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.last_block_height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.ProtocolUpgradeStarted", len)?;
+        if self.last_block_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("lastBlockHeight", ToString::to_string(&self.last_block_height).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ProtocolUpgradeStarted {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "last_block_height",
+            "lastBlockHeight",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            LastBlockHeight,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "lastBlockHeight" | "last_block_height" => Ok(GeneratedField::LastBlockHeight),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ProtocolUpgradeStarted;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.ProtocolUpgradeStarted")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ProtocolUpgradeStarted, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut last_block_height__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::LastBlockHeight => {
+                            if last_block_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastBlockHeight"));
+                            }
+                            last_block_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(ProtocolUpgradeStarted {
+                    last_block_height: last_block_height__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.ProtocolUpgradeStarted", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for RecurringGovernanceTransfer {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.start_epoch != 0 {
+            len += 1;
+        }
+        if self.end_epoch.is_some() {
+            len += 1;
+        }
+        if self.dispatch_strategy.is_some() {
+            len += 1;
+        }
+        if !self.factor.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.RecurringGovernanceTransfer", len)?;
+        if self.start_epoch != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("startEpoch", ToString::to_string(&self.start_epoch).as_str())?;
+        }
+        if let Some(v) = self.end_epoch.as_ref() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("endEpoch", ToString::to_string(&v).as_str())?;
+        }
+        if let Some(v) = self.dispatch_strategy.as_ref() {
+            struct_ser.serialize_field("dispatchStrategy", v)?;
+        }
+        if !self.factor.is_empty() {
+            struct_ser.serialize_field("factor", &self.factor)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RecurringGovernanceTransfer {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "start_epoch",
+            "startEpoch",
+            "end_epoch",
+            "endEpoch",
+            "dispatch_strategy",
+            "dispatchStrategy",
+            "factor",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            StartEpoch,
+            EndEpoch,
+            DispatchStrategy,
+            Factor,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "startEpoch" | "start_epoch" => Ok(GeneratedField::StartEpoch),
+                            "endEpoch" | "end_epoch" => Ok(GeneratedField::EndEpoch),
+                            "dispatchStrategy" | "dispatch_strategy" => Ok(GeneratedField::DispatchStrategy),
+                            "factor" => Ok(GeneratedField::Factor),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = RecurringGovernanceTransfer;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.RecurringGovernanceTransfer")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RecurringGovernanceTransfer, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut start_epoch__ = None;
+                let mut end_epoch__ = None;
+                let mut dispatch_strategy__ = None;
+                let mut factor__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::StartEpoch => {
+                            if start_epoch__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startEpoch"));
+                            }
+                            start_epoch__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::EndEpoch => {
+                            if end_epoch__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endEpoch"));
+                            }
+                            end_epoch__ = 
+                                map_.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                        GeneratedField::DispatchStrategy => {
+                            if dispatch_strategy__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("dispatchStrategy"));
+                            }
+                            dispatch_strategy__ = map_.next_value()?;
+                        }
+                        GeneratedField::Factor => {
+                            if factor__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("factor"));
+                            }
+                            factor__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(RecurringGovernanceTransfer {
+                    start_epoch: start_epoch__.unwrap_or_default(),
+                    end_epoch: end_epoch__,
+                    dispatch_strategy: dispatch_strategy__,
+                    factor: factor__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.RecurringGovernanceTransfer", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for RecurringTransfer {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.start_epoch != 0 {
+            len += 1;
+        }
+        if self.end_epoch.is_some() {
+            len += 1;
+        }
+        if !self.factor.is_empty() {
+            len += 1;
+        }
+        if self.dispatch_strategy.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.RecurringTransfer", len)?;
+        if self.start_epoch != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("startEpoch", ToString::to_string(&self.start_epoch).as_str())?;
+        }
+        if let Some(v) = self.end_epoch.as_ref() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("endEpoch", ToString::to_string(&v).as_str())?;
+        }
+        if !self.factor.is_empty() {
+            struct_ser.serialize_field("factor", &self.factor)?;
+        }
+        if let Some(v) = self.dispatch_strategy.as_ref() {
+            struct_ser.serialize_field("dispatchStrategy", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RecurringTransfer {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "start_epoch",
+            "startEpoch",
+            "end_epoch",
+            "endEpoch",
+            "factor",
+            "dispatch_strategy",
+            "dispatchStrategy",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            StartEpoch,
+            EndEpoch,
+            Factor,
+            DispatchStrategy,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "startEpoch" | "start_epoch" => Ok(GeneratedField::StartEpoch),
+                            "endEpoch" | "end_epoch" => Ok(GeneratedField::EndEpoch),
+                            "factor" => Ok(GeneratedField::Factor),
+                            "dispatchStrategy" | "dispatch_strategy" => Ok(GeneratedField::DispatchStrategy),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = RecurringTransfer;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.RecurringTransfer")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RecurringTransfer, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut start_epoch__ = None;
+                let mut end_epoch__ = None;
+                let mut factor__ = None;
+                let mut dispatch_strategy__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::StartEpoch => {
+                            if start_epoch__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startEpoch"));
+                            }
+                            start_epoch__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::EndEpoch => {
+                            if end_epoch__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endEpoch"));
+                            }
+                            end_epoch__ = 
+                                map_.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                        GeneratedField::Factor => {
+                            if factor__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("factor"));
+                            }
+                            factor__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::DispatchStrategy => {
+                            if dispatch_strategy__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("dispatchStrategy"));
+                            }
+                            dispatch_strategy__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(RecurringTransfer {
+                    start_epoch: start_epoch__.unwrap_or_default(),
+                    end_epoch: end_epoch__,
+                    factor: factor__.unwrap_or_default(),
+                    dispatch_strategy: dispatch_strategy__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.RecurringTransfer", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for RefereeJoinedReferralSet {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.set_id.is_empty() {
+            len += 1;
+        }
+        if !self.referee.is_empty() {
+            len += 1;
+        }
+        if self.joined_at != 0 {
+            len += 1;
+        }
+        if self.at_epoch != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.RefereeJoinedReferralSet", len)?;
+        if !self.set_id.is_empty() {
+            struct_ser.serialize_field("setId", &self.set_id)?;
+        }
+        if !self.referee.is_empty() {
+            struct_ser.serialize_field("referee", &self.referee)?;
+        }
+        if self.joined_at != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("joinedAt", ToString::to_string(&self.joined_at).as_str())?;
+        }
+        if self.at_epoch != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("atEpoch", ToString::to_string(&self.at_epoch).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RefereeJoinedReferralSet {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "set_id",
+            "setId",
+            "referee",
+            "joined_at",
+            "joinedAt",
+            "at_epoch",
+            "atEpoch",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            SetId,
+            Referee,
+            JoinedAt,
+            AtEpoch,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "setId" | "set_id" => Ok(GeneratedField::SetId),
+                            "referee" => Ok(GeneratedField::Referee),
+                            "joinedAt" | "joined_at" => Ok(GeneratedField::JoinedAt),
+                            "atEpoch" | "at_epoch" => Ok(GeneratedField::AtEpoch),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = RefereeJoinedReferralSet;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.RefereeJoinedReferralSet")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RefereeJoinedReferralSet, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut set_id__ = None;
+                let mut referee__ = None;
+                let mut joined_at__ = None;
+                let mut at_epoch__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::SetId => {
+                            if set_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("setId"));
+                            }
+                            set_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Referee => {
+                            if referee__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("referee"));
+                            }
+                            referee__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::JoinedAt => {
+                            if joined_at__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("joinedAt"));
+                            }
+                            joined_at__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::AtEpoch => {
+                            if at_epoch__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("atEpoch"));
+                            }
+                            at_epoch__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(RefereeJoinedReferralSet {
+                    set_id: set_id__.unwrap_or_default(),
+                    referee: referee__.unwrap_or_default(),
+                    joined_at: joined_at__.unwrap_or_default(),
+                    at_epoch: at_epoch__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.RefereeJoinedReferralSet", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for RefereeJoinedTeam {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.team_id.is_empty() {
+            len += 1;
+        }
+        if !self.referee.is_empty() {
+            len += 1;
+        }
+        if self.joined_at != 0 {
+            len += 1;
+        }
+        if self.at_epoch != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.RefereeJoinedTeam", len)?;
+        if !self.team_id.is_empty() {
+            struct_ser.serialize_field("teamId", &self.team_id)?;
+        }
+        if !self.referee.is_empty() {
+            struct_ser.serialize_field("referee", &self.referee)?;
+        }
+        if self.joined_at != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("joinedAt", ToString::to_string(&self.joined_at).as_str())?;
+        }
+        if self.at_epoch != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("atEpoch", ToString::to_string(&self.at_epoch).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RefereeJoinedTeam {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "team_id",
+            "teamId",
+            "referee",
+            "joined_at",
+            "joinedAt",
+            "at_epoch",
+            "atEpoch",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TeamId,
+            Referee,
+            JoinedAt,
+            AtEpoch,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "teamId" | "team_id" => Ok(GeneratedField::TeamId),
+                            "referee" => Ok(GeneratedField::Referee),
+                            "joinedAt" | "joined_at" => Ok(GeneratedField::JoinedAt),
+                            "atEpoch" | "at_epoch" => Ok(GeneratedField::AtEpoch),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = RefereeJoinedTeam;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.RefereeJoinedTeam")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RefereeJoinedTeam, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut team_id__ = None;
+                let mut referee__ = None;
+                let mut joined_at__ = None;
+                let mut at_epoch__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TeamId => {
+                            if team_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("teamId"));
+                            }
+                            team_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Referee => {
+                            if referee__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("referee"));
+                            }
+                            referee__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::JoinedAt => {
+                            if joined_at__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("joinedAt"));
+                            }
+                            joined_at__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::AtEpoch => {
+                            if at_epoch__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("atEpoch"));
+                            }
+                            at_epoch__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(RefereeJoinedTeam {
+                    team_id: team_id__.unwrap_or_default(),
+                    referee: referee__.unwrap_or_default(),
+                    joined_at: joined_at__.unwrap_or_default(),
+                    at_epoch: at_epoch__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.RefereeJoinedTeam", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for RefereeStats {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.party_id.is_empty() {
+            len += 1;
+        }
+        if !self.discount_factor.is_empty() {
+            len += 1;
+        }
+        if !self.epoch_notional_taker_volume.is_empty() {
+            len += 1;
+        }
+        if self.discount_factors.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.RefereeStats", len)?;
+        if !self.party_id.is_empty() {
+            struct_ser.serialize_field("partyId", &self.party_id)?;
+        }
+        if !self.discount_factor.is_empty() {
+            struct_ser.serialize_field("discountFactor", &self.discount_factor)?;
+        }
+        if !self.epoch_notional_taker_volume.is_empty() {
+            struct_ser.serialize_field("epochNotionalTakerVolume", &self.epoch_notional_taker_volume)?;
+        }
+        if let Some(v) = self.discount_factors.as_ref() {
+            struct_ser.serialize_field("discountFactors", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RefereeStats {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "party_id",
+            "partyId",
+            "discount_factor",
+            "discountFactor",
+            "epoch_notional_taker_volume",
+            "epochNotionalTakerVolume",
+            "discount_factors",
+            "discountFactors",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PartyId,
+            DiscountFactor,
+            EpochNotionalTakerVolume,
+            DiscountFactors,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "partyId" | "party_id" => Ok(GeneratedField::PartyId),
+                            "discountFactor" | "discount_factor" => Ok(GeneratedField::DiscountFactor),
+                            "epochNotionalTakerVolume" | "epoch_notional_taker_volume" => Ok(GeneratedField::EpochNotionalTakerVolume),
+                            "discountFactors" | "discount_factors" => Ok(GeneratedField::DiscountFactors),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = RefereeStats;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.RefereeStats")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RefereeStats, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut party_id__ = None;
+                let mut discount_factor__ = None;
+                let mut epoch_notional_taker_volume__ = None;
+                let mut discount_factors__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PartyId => {
+                            if party_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("partyId"));
+                            }
+                            party_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::DiscountFactor => {
+                            if discount_factor__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("discountFactor"));
+                            }
+                            discount_factor__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::EpochNotionalTakerVolume => {
+                            if epoch_notional_taker_volume__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epochNotionalTakerVolume"));
+                            }
+                            epoch_notional_taker_volume__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::DiscountFactors => {
+                            if discount_factors__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("discountFactors"));
+                            }
+                            discount_factors__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(RefereeStats {
+                    party_id: party_id__.unwrap_or_default(),
+                    discount_factor: discount_factor__.unwrap_or_default(),
+                    epoch_notional_taker_volume: epoch_notional_taker_volume__.unwrap_or_default(),
+                    discount_factors: discount_factors__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.RefereeStats", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for RefereeSwitchedTeam {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.from_team_id.is_empty() {
+            len += 1;
+        }
+        if !self.to_team_id.is_empty() {
+            len += 1;
+        }
+        if !self.referee.is_empty() {
+            len += 1;
+        }
+        if self.switched_at != 0 {
+            len += 1;
+        }
+        if self.at_epoch != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.RefereeSwitchedTeam", len)?;
+        if !self.from_team_id.is_empty() {
+            struct_ser.serialize_field("fromTeamId", &self.from_team_id)?;
+        }
+        if !self.to_team_id.is_empty() {
+            struct_ser.serialize_field("toTeamId", &self.to_team_id)?;
+        }
+        if !self.referee.is_empty() {
+            struct_ser.serialize_field("referee", &self.referee)?;
+        }
+        if self.switched_at != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("switchedAt", ToString::to_string(&self.switched_at).as_str())?;
+        }
+        if self.at_epoch != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("atEpoch", ToString::to_string(&self.at_epoch).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RefereeSwitchedTeam {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "from_team_id",
+            "fromTeamId",
+            "to_team_id",
+            "toTeamId",
+            "referee",
+            "switched_at",
+            "switchedAt",
+            "at_epoch",
+            "atEpoch",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            FromTeamId,
+            ToTeamId,
+            Referee,
+            SwitchedAt,
+            AtEpoch,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "fromTeamId" | "from_team_id" => Ok(GeneratedField::FromTeamId),
+                            "toTeamId" | "to_team_id" => Ok(GeneratedField::ToTeamId),
+                            "referee" => Ok(GeneratedField::Referee),
+                            "switchedAt" | "switched_at" => Ok(GeneratedField::SwitchedAt),
+                            "atEpoch" | "at_epoch" => Ok(GeneratedField::AtEpoch),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = RefereeSwitchedTeam;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.RefereeSwitchedTeam")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RefereeSwitchedTeam, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut from_team_id__ = None;
+                let mut to_team_id__ = None;
+                let mut referee__ = None;
+                let mut switched_at__ = None;
+                let mut at_epoch__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::FromTeamId => {
+                            if from_team_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fromTeamId"));
+                            }
+                            from_team_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ToTeamId => {
+                            if to_team_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("toTeamId"));
+                            }
+                            to_team_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Referee => {
+                            if referee__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("referee"));
+                            }
+                            referee__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::SwitchedAt => {
+                            if switched_at__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("switchedAt"));
+                            }
+                            switched_at__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::AtEpoch => {
+                            if at_epoch__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("atEpoch"));
+                            }
+                            at_epoch__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(RefereeSwitchedTeam {
+                    from_team_id: from_team_id__.unwrap_or_default(),
+                    to_team_id: to_team_id__.unwrap_or_default(),
+                    referee: referee__.unwrap_or_default(),
+                    switched_at: switched_at__.unwrap_or_default(),
+                    at_epoch: at_epoch__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.RefereeSwitchedTeam", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ReferralProgramEnded {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.version != 0 {
+            len += 1;
+        }
+        if !self.id.is_empty() {
+            len += 1;
+        }
+        if self.ended_at != 0 {
+            len += 1;
+        }
+        if self.at_epoch != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.ReferralProgramEnded", len)?;
+        if self.version != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("version", ToString::to_string(&self.version).as_str())?;
+        }
+        if !self.id.is_empty() {
+            struct_ser.serialize_field("id", &self.id)?;
+        }
+        if self.ended_at != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("endedAt", ToString::to_string(&self.ended_at).as_str())?;
+        }
+        if self.at_epoch != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("atEpoch", ToString::to_string(&self.at_epoch).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ReferralProgramEnded {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "version",
+            "id",
+            "ended_at",
+            "endedAt",
+            "at_epoch",
+            "atEpoch",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Version,
+            Id,
+            EndedAt,
+            AtEpoch,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "version" => Ok(GeneratedField::Version),
+                            "id" => Ok(GeneratedField::Id),
+                            "endedAt" | "ended_at" => Ok(GeneratedField::EndedAt),
+                            "atEpoch" | "at_epoch" => Ok(GeneratedField::AtEpoch),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ReferralProgramEnded;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.ReferralProgramEnded")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ReferralProgramEnded, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut version__ = None;
+                let mut id__ = None;
+                let mut ended_at__ = None;
+                let mut at_epoch__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Version => {
+                            if version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("version"));
+                            }
+                            version__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::EndedAt => {
+                            if ended_at__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endedAt"));
+                            }
+                            ended_at__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::AtEpoch => {
+                            if at_epoch__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("atEpoch"));
+                            }
+                            at_epoch__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(ReferralProgramEnded {
+                    version: version__.unwrap_or_default(),
+                    id: id__.unwrap_or_default(),
+                    ended_at: ended_at__.unwrap_or_default(),
+                    at_epoch: at_epoch__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.ReferralProgramEnded", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ReferralProgramStarted {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.program.is_some() {
+            len += 1;
+        }
+        if self.started_at != 0 {
+            len += 1;
+        }
+        if self.at_epoch != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.ReferralProgramStarted", len)?;
+        if let Some(v) = self.program.as_ref() {
+            struct_ser.serialize_field("program", v)?;
+        }
+        if self.started_at != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("startedAt", ToString::to_string(&self.started_at).as_str())?;
+        }
+        if self.at_epoch != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("atEpoch", ToString::to_string(&self.at_epoch).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ReferralProgramStarted {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "program",
+            "started_at",
+            "startedAt",
+            "at_epoch",
+            "atEpoch",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Program,
+            StartedAt,
+            AtEpoch,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "program" => Ok(GeneratedField::Program),
+                            "startedAt" | "started_at" => Ok(GeneratedField::StartedAt),
+                            "atEpoch" | "at_epoch" => Ok(GeneratedField::AtEpoch),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ReferralProgramStarted;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.ReferralProgramStarted")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ReferralProgramStarted, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut program__ = None;
+                let mut started_at__ = None;
+                let mut at_epoch__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Program => {
+                            if program__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("program"));
+                            }
+                            program__ = map_.next_value()?;
+                        }
+                        GeneratedField::StartedAt => {
+                            if started_at__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startedAt"));
+                            }
+                            started_at__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::AtEpoch => {
+                            if at_epoch__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("atEpoch"));
+                            }
+                            at_epoch__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(ReferralProgramStarted {
+                    program: program__,
+                    started_at: started_at__.unwrap_or_default(),
+                    at_epoch: at_epoch__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.ReferralProgramStarted", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ReferralProgramUpdated {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.program.is_some() {
+            len += 1;
+        }
+        if self.updated_at != 0 {
+            len += 1;
+        }
+        if self.at_epoch != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.ReferralProgramUpdated", len)?;
+        if let Some(v) = self.program.as_ref() {
+            struct_ser.serialize_field("program", v)?;
+        }
+        if self.updated_at != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("updatedAt", ToString::to_string(&self.updated_at).as_str())?;
+        }
+        if self.at_epoch != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("atEpoch", ToString::to_string(&self.at_epoch).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ReferralProgramUpdated {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "program",
+            "updated_at",
+            "updatedAt",
+            "at_epoch",
+            "atEpoch",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Program,
+            UpdatedAt,
+            AtEpoch,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "program" => Ok(GeneratedField::Program),
+                            "updatedAt" | "updated_at" => Ok(GeneratedField::UpdatedAt),
+                            "atEpoch" | "at_epoch" => Ok(GeneratedField::AtEpoch),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ReferralProgramUpdated;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.ReferralProgramUpdated")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ReferralProgramUpdated, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut program__ = None;
+                let mut updated_at__ = None;
+                let mut at_epoch__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Program => {
+                            if program__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("program"));
+                            }
+                            program__ = map_.next_value()?;
+                        }
+                        GeneratedField::UpdatedAt => {
+                            if updated_at__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("updatedAt"));
+                            }
+                            updated_at__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::AtEpoch => {
+                            if at_epoch__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("atEpoch"));
+                            }
+                            at_epoch__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(ReferralProgramUpdated {
+                    program: program__,
+                    updated_at: updated_at__.unwrap_or_default(),
+                    at_epoch: at_epoch__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.ReferralProgramUpdated", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ReferralSetCreated {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.set_id.is_empty() {
+            len += 1;
+        }
+        if !self.referrer.is_empty() {
+            len += 1;
+        }
+        if self.created_at != 0 {
+            len += 1;
+        }
+        if self.updated_at != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.ReferralSetCreated", len)?;
+        if !self.set_id.is_empty() {
+            struct_ser.serialize_field("setId", &self.set_id)?;
+        }
+        if !self.referrer.is_empty() {
+            struct_ser.serialize_field("referrer", &self.referrer)?;
+        }
+        if self.created_at != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("createdAt", ToString::to_string(&self.created_at).as_str())?;
+        }
+        if self.updated_at != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("updatedAt", ToString::to_string(&self.updated_at).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ReferralSetCreated {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "set_id",
+            "setId",
+            "referrer",
+            "created_at",
+            "createdAt",
+            "updated_at",
+            "updatedAt",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            SetId,
+            Referrer,
+            CreatedAt,
+            UpdatedAt,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "setId" | "set_id" => Ok(GeneratedField::SetId),
+                            "referrer" => Ok(GeneratedField::Referrer),
+                            "createdAt" | "created_at" => Ok(GeneratedField::CreatedAt),
+                            "updatedAt" | "updated_at" => Ok(GeneratedField::UpdatedAt),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ReferralSetCreated;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.ReferralSetCreated")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ReferralSetCreated, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut set_id__ = None;
+                let mut referrer__ = None;
+                let mut created_at__ = None;
+                let mut updated_at__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::SetId => {
+                            if set_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("setId"));
+                            }
+                            set_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Referrer => {
+                            if referrer__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("referrer"));
+                            }
+                            referrer__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::CreatedAt => {
+                            if created_at__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("createdAt"));
+                            }
+                            created_at__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::UpdatedAt => {
+                            if updated_at__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("updatedAt"));
+                            }
+                            updated_at__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(ReferralSetCreated {
+                    set_id: set_id__.unwrap_or_default(),
+                    referrer: referrer__.unwrap_or_default(),
+                    created_at: created_at__.unwrap_or_default(),
+                    updated_at: updated_at__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.ReferralSetCreated", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ReferralSetStatsUpdated {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.set_id.is_empty() {
+            len += 1;
+        }
+        if self.at_epoch != 0 {
+            len += 1;
+        }
+        if !self.referral_set_running_notional_taker_volume.is_empty() {
+            len += 1;
+        }
+        if !self.referees_stats.is_empty() {
+            len += 1;
+        }
+        if !self.reward_factor.is_empty() {
+            len += 1;
+        }
+        if !self.rewards_multiplier.is_empty() {
+            len += 1;
+        }
+        if !self.rewards_factor_multiplier.is_empty() {
+            len += 1;
+        }
+        if self.was_eligible {
+            len += 1;
+        }
+        if !self.referrer_taker_volume.is_empty() {
+            len += 1;
+        }
+        if self.reward_factors.is_some() {
+            len += 1;
+        }
+        if self.reward_factors_multiplier.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.ReferralSetStatsUpdated", len)?;
+        if !self.set_id.is_empty() {
+            struct_ser.serialize_field("setId", &self.set_id)?;
+        }
+        if self.at_epoch != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("atEpoch", ToString::to_string(&self.at_epoch).as_str())?;
+        }
+        if !self.referral_set_running_notional_taker_volume.is_empty() {
+            struct_ser.serialize_field("referralSetRunningNotionalTakerVolume", &self.referral_set_running_notional_taker_volume)?;
+        }
+        if !self.referees_stats.is_empty() {
+            struct_ser.serialize_field("refereesStats", &self.referees_stats)?;
+        }
+        if !self.reward_factor.is_empty() {
+            struct_ser.serialize_field("rewardFactor", &self.reward_factor)?;
+        }
+        if !self.rewards_multiplier.is_empty() {
+            struct_ser.serialize_field("rewardsMultiplier", &self.rewards_multiplier)?;
+        }
+        if !self.rewards_factor_multiplier.is_empty() {
+            struct_ser.serialize_field("rewardsFactorMultiplier", &self.rewards_factor_multiplier)?;
+        }
+        if self.was_eligible {
+            struct_ser.serialize_field("wasEligible", &self.was_eligible)?;
+        }
+        if !self.referrer_taker_volume.is_empty() {
+            struct_ser.serialize_field("referrerTakerVolume", &self.referrer_taker_volume)?;
+        }
+        if let Some(v) = self.reward_factors.as_ref() {
+            struct_ser.serialize_field("rewardFactors", v)?;
+        }
+        if let Some(v) = self.reward_factors_multiplier.as_ref() {
+            struct_ser.serialize_field("rewardFactorsMultiplier", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ReferralSetStatsUpdated {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "set_id",
+            "setId",
+            "at_epoch",
+            "atEpoch",
+            "referral_set_running_notional_taker_volume",
+            "referralSetRunningNotionalTakerVolume",
+            "referees_stats",
+            "refereesStats",
+            "reward_factor",
+            "rewardFactor",
+            "rewards_multiplier",
+            "rewardsMultiplier",
+            "rewards_factor_multiplier",
+            "rewardsFactorMultiplier",
+            "was_eligible",
+            "wasEligible",
+            "referrer_taker_volume",
+            "referrerTakerVolume",
+            "reward_factors",
+            "rewardFactors",
+            "reward_factors_multiplier",
+            "rewardFactorsMultiplier",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            SetId,
+            AtEpoch,
+            ReferralSetRunningNotionalTakerVolume,
+            RefereesStats,
+            RewardFactor,
+            RewardsMultiplier,
+            RewardsFactorMultiplier,
+            WasEligible,
+            ReferrerTakerVolume,
+            RewardFactors,
+            RewardFactorsMultiplier,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "setId" | "set_id" => Ok(GeneratedField::SetId),
+                            "atEpoch" | "at_epoch" => Ok(GeneratedField::AtEpoch),
+                            "referralSetRunningNotionalTakerVolume" | "referral_set_running_notional_taker_volume" => Ok(GeneratedField::ReferralSetRunningNotionalTakerVolume),
+                            "refereesStats" | "referees_stats" => Ok(GeneratedField::RefereesStats),
+                            "rewardFactor" | "reward_factor" => Ok(GeneratedField::RewardFactor),
+                            "rewardsMultiplier" | "rewards_multiplier" => Ok(GeneratedField::RewardsMultiplier),
+                            "rewardsFactorMultiplier" | "rewards_factor_multiplier" => Ok(GeneratedField::RewardsFactorMultiplier),
+                            "wasEligible" | "was_eligible" => Ok(GeneratedField::WasEligible),
+                            "referrerTakerVolume" | "referrer_taker_volume" => Ok(GeneratedField::ReferrerTakerVolume),
+                            "rewardFactors" | "reward_factors" => Ok(GeneratedField::RewardFactors),
+                            "rewardFactorsMultiplier" | "reward_factors_multiplier" => Ok(GeneratedField::RewardFactorsMultiplier),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ReferralSetStatsUpdated;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.ReferralSetStatsUpdated")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ReferralSetStatsUpdated, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut set_id__ = None;
+                let mut at_epoch__ = None;
+                let mut referral_set_running_notional_taker_volume__ = None;
+                let mut referees_stats__ = None;
+                let mut reward_factor__ = None;
+                let mut rewards_multiplier__ = None;
+                let mut rewards_factor_multiplier__ = None;
+                let mut was_eligible__ = None;
+                let mut referrer_taker_volume__ = None;
+                let mut reward_factors__ = None;
+                let mut reward_factors_multiplier__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::SetId => {
+                            if set_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("setId"));
+                            }
+                            set_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::AtEpoch => {
+                            if at_epoch__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("atEpoch"));
+                            }
+                            at_epoch__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ReferralSetRunningNotionalTakerVolume => {
+                            if referral_set_running_notional_taker_volume__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("referralSetRunningNotionalTakerVolume"));
+                            }
+                            referral_set_running_notional_taker_volume__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::RefereesStats => {
+                            if referees_stats__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("refereesStats"));
+                            }
+                            referees_stats__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::RewardFactor => {
+                            if reward_factor__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rewardFactor"));
+                            }
+                            reward_factor__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::RewardsMultiplier => {
+                            if rewards_multiplier__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rewardsMultiplier"));
+                            }
+                            rewards_multiplier__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::RewardsFactorMultiplier => {
+                            if rewards_factor_multiplier__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rewardsFactorMultiplier"));
+                            }
+                            rewards_factor_multiplier__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::WasEligible => {
+                            if was_eligible__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("wasEligible"));
+                            }
+                            was_eligible__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ReferrerTakerVolume => {
+                            if referrer_taker_volume__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("referrerTakerVolume"));
+                            }
+                            referrer_taker_volume__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::RewardFactors => {
+                            if reward_factors__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rewardFactors"));
+                            }
+                            reward_factors__ = map_.next_value()?;
+                        }
+                        GeneratedField::RewardFactorsMultiplier => {
+                            if reward_factors_multiplier__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rewardFactorsMultiplier"));
+                            }
+                            reward_factors_multiplier__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(ReferralSetStatsUpdated {
+                    set_id: set_id__.unwrap_or_default(),
+                    at_epoch: at_epoch__.unwrap_or_default(),
+                    referral_set_running_notional_taker_volume: referral_set_running_notional_taker_volume__.unwrap_or_default(),
+                    referees_stats: referees_stats__.unwrap_or_default(),
+                    reward_factor: reward_factor__.unwrap_or_default(),
+                    rewards_multiplier: rewards_multiplier__.unwrap_or_default(),
+                    rewards_factor_multiplier: rewards_factor_multiplier__.unwrap_or_default(),
+                    was_eligible: was_eligible__.unwrap_or_default(),
+                    referrer_taker_volume: referrer_taker_volume__.unwrap_or_default(),
+                    reward_factors: reward_factors__,
+                    reward_factors_multiplier: reward_factors_multiplier__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.ReferralSetStatsUpdated", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ReferrerRewardsGenerated {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.referrer.is_empty() {
+            len += 1;
+        }
+        if !self.generated_reward.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.ReferrerRewardsGenerated", len)?;
+        if !self.referrer.is_empty() {
+            struct_ser.serialize_field("referrer", &self.referrer)?;
+        }
+        if !self.generated_reward.is_empty() {
+            struct_ser.serialize_field("generatedReward", &self.generated_reward)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ReferrerRewardsGenerated {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "referrer",
+            "generated_reward",
+            "generatedReward",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Referrer,
+            GeneratedReward,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "referrer" => Ok(GeneratedField::Referrer),
+                            "generatedReward" | "generated_reward" => Ok(GeneratedField::GeneratedReward),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ReferrerRewardsGenerated;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.ReferrerRewardsGenerated")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ReferrerRewardsGenerated, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut referrer__ = None;
+                let mut generated_reward__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Referrer => {
+                            if referrer__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("referrer"));
+                            }
+                            referrer__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::GeneratedReward => {
+                            if generated_reward__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("generatedReward"));
+                            }
+                            generated_reward__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(ReferrerRewardsGenerated {
+                    referrer: referrer__.unwrap_or_default(),
+                    generated_reward: generated_reward__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.ReferrerRewardsGenerated", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for RewardPayoutEvent {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.party.is_empty() {
+            len += 1;
+        }
+        if !self.epoch_seq.is_empty() {
+            len += 1;
+        }
+        if !self.asset.is_empty() {
+            len += 1;
+        }
+        if !self.amount.is_empty() {
+            len += 1;
+        }
+        if !self.percent_of_total_reward.is_empty() {
+            len += 1;
+        }
+        if self.timestamp != 0 {
+            len += 1;
+        }
+        if !self.reward_type.is_empty() {
+            len += 1;
+        }
+        if !self.locked_until_epoch.is_empty() {
+            len += 1;
+        }
+        if !self.quantum_amount.is_empty() {
+            len += 1;
+        }
+        if self.game_id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.RewardPayoutEvent", len)?;
+        if !self.party.is_empty() {
+            struct_ser.serialize_field("party", &self.party)?;
+        }
+        if !self.epoch_seq.is_empty() {
+            struct_ser.serialize_field("epochSeq", &self.epoch_seq)?;
+        }
+        if !self.asset.is_empty() {
+            struct_ser.serialize_field("asset", &self.asset)?;
+        }
+        if !self.amount.is_empty() {
+            struct_ser.serialize_field("amount", &self.amount)?;
+        }
+        if !self.percent_of_total_reward.is_empty() {
+            struct_ser.serialize_field("percentOfTotalReward", &self.percent_of_total_reward)?;
+        }
+        if self.timestamp != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("timestamp", ToString::to_string(&self.timestamp).as_str())?;
+        }
+        if !self.reward_type.is_empty() {
+            struct_ser.serialize_field("rewardType", &self.reward_type)?;
+        }
+        if !self.locked_until_epoch.is_empty() {
+            struct_ser.serialize_field("lockedUntilEpoch", &self.locked_until_epoch)?;
+        }
+        if !self.quantum_amount.is_empty() {
+            struct_ser.serialize_field("quantumAmount", &self.quantum_amount)?;
+        }
+        if let Some(v) = self.game_id.as_ref() {
+            struct_ser.serialize_field("gameId", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RewardPayoutEvent {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "party",
+            "epoch_seq",
+            "epochSeq",
+            "asset",
+            "amount",
+            "percent_of_total_reward",
+            "percentOfTotalReward",
+            "timestamp",
+            "reward_type",
+            "rewardType",
+            "locked_until_epoch",
+            "lockedUntilEpoch",
+            "quantum_amount",
+            "quantumAmount",
+            "game_id",
+            "gameId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Party,
+            EpochSeq,
+            Asset,
+            Amount,
+            PercentOfTotalReward,
+            Timestamp,
+            RewardType,
+            LockedUntilEpoch,
+            QuantumAmount,
+            GameId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "party" => Ok(GeneratedField::Party),
+                            "epochSeq" | "epoch_seq" => Ok(GeneratedField::EpochSeq),
+                            "asset" => Ok(GeneratedField::Asset),
+                            "amount" => Ok(GeneratedField::Amount),
+                            "percentOfTotalReward" | "percent_of_total_reward" => Ok(GeneratedField::PercentOfTotalReward),
+                            "timestamp" => Ok(GeneratedField::Timestamp),
+                            "rewardType" | "reward_type" => Ok(GeneratedField::RewardType),
+                            "lockedUntilEpoch" | "locked_until_epoch" => Ok(GeneratedField::LockedUntilEpoch),
+                            "quantumAmount" | "quantum_amount" => Ok(GeneratedField::QuantumAmount),
+                            "gameId" | "game_id" => Ok(GeneratedField::GameId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = RewardPayoutEvent;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.RewardPayoutEvent")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RewardPayoutEvent, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut party__ = None;
+                let mut epoch_seq__ = None;
+                let mut asset__ = None;
+                let mut amount__ = None;
+                let mut percent_of_total_reward__ = None;
+                let mut timestamp__ = None;
+                let mut reward_type__ = None;
+                let mut locked_until_epoch__ = None;
+                let mut quantum_amount__ = None;
+                let mut game_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Party => {
+                            if party__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("party"));
+                            }
+                            party__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::EpochSeq => {
+                            if epoch_seq__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epochSeq"));
+                            }
+                            epoch_seq__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Asset => {
+                            if asset__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("asset"));
+                            }
+                            asset__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Amount => {
+                            if amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amount"));
+                            }
+                            amount__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::PercentOfTotalReward => {
+                            if percent_of_total_reward__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("percentOfTotalReward"));
+                            }
+                            percent_of_total_reward__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Timestamp => {
+                            if timestamp__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timestamp"));
+                            }
+                            timestamp__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::RewardType => {
+                            if reward_type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rewardType"));
+                            }
+                            reward_type__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::LockedUntilEpoch => {
+                            if locked_until_epoch__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lockedUntilEpoch"));
+                            }
+                            locked_until_epoch__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::QuantumAmount => {
+                            if quantum_amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("quantumAmount"));
+                            }
+                            quantum_amount__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::GameId => {
+                            if game_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("gameId"));
+                            }
+                            game_id__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(RewardPayoutEvent {
+                    party: party__.unwrap_or_default(),
+                    epoch_seq: epoch_seq__.unwrap_or_default(),
+                    asset: asset__.unwrap_or_default(),
+                    amount: amount__.unwrap_or_default(),
+                    percent_of_total_reward: percent_of_total_reward__.unwrap_or_default(),
+                    timestamp: timestamp__.unwrap_or_default(),
+                    reward_type: reward_type__.unwrap_or_default(),
+                    locked_until_epoch: locked_until_epoch__.unwrap_or_default(),
+                    quantum_amount: quantum_amount__.unwrap_or_default(),
+                    game_id: game_id__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.RewardPayoutEvent", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SettleDistressed {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.market_id.is_empty() {
+            len += 1;
+        }
+        if !self.party_id.is_empty() {
+            len += 1;
+        }
+        if !self.margin.is_empty() {
+            len += 1;
+        }
+        if !self.price.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.SettleDistressed", len)?;
+        if !self.market_id.is_empty() {
+            struct_ser.serialize_field("marketId", &self.market_id)?;
+        }
+        if !self.party_id.is_empty() {
+            struct_ser.serialize_field("partyId", &self.party_id)?;
+        }
+        if !self.margin.is_empty() {
+            struct_ser.serialize_field("margin", &self.margin)?;
+        }
+        if !self.price.is_empty() {
+            struct_ser.serialize_field("price", &self.price)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SettleDistressed {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "market_id",
+            "marketId",
+            "party_id",
+            "partyId",
+            "margin",
+            "price",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            MarketId,
+            PartyId,
+            Margin,
+            Price,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "marketId" | "market_id" => Ok(GeneratedField::MarketId),
+                            "partyId" | "party_id" => Ok(GeneratedField::PartyId),
+                            "margin" => Ok(GeneratedField::Margin),
+                            "price" => Ok(GeneratedField::Price),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SettleDistressed;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.SettleDistressed")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SettleDistressed, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut market_id__ = None;
+                let mut party_id__ = None;
+                let mut margin__ = None;
+                let mut price__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::MarketId => {
+                            if market_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("marketId"));
+                            }
+                            market_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::PartyId => {
+                            if party_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("partyId"));
+                            }
+                            party_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Margin => {
+                            if margin__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("margin"));
+                            }
+                            margin__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Price => {
+                            if price__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("price"));
+                            }
+                            price__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(SettleDistressed {
+                    market_id: market_id__.unwrap_or_default(),
+                    party_id: party_id__.unwrap_or_default(),
+                    margin: margin__.unwrap_or_default(),
+                    price: price__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.SettleDistressed", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SettleMarket {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.market_id.is_empty() {
+            len += 1;
+        }
+        if !self.price.is_empty() {
+            len += 1;
+        }
+        if !self.position_factor.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.SettleMarket", len)?;
+        if !self.market_id.is_empty() {
+            struct_ser.serialize_field("marketId", &self.market_id)?;
+        }
+        if !self.price.is_empty() {
+            struct_ser.serialize_field("price", &self.price)?;
+        }
+        if !self.position_factor.is_empty() {
+            struct_ser.serialize_field("positionFactor", &self.position_factor)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SettleMarket {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "market_id",
+            "marketId",
+            "price",
+            "position_factor",
+            "positionFactor",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            MarketId,
+            Price,
+            PositionFactor,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "marketId" | "market_id" => Ok(GeneratedField::MarketId),
+                            "price" => Ok(GeneratedField::Price),
+                            "positionFactor" | "position_factor" => Ok(GeneratedField::PositionFactor),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SettleMarket;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.SettleMarket")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SettleMarket, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut market_id__ = None;
+                let mut price__ = None;
+                let mut position_factor__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::MarketId => {
+                            if market_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("marketId"));
+                            }
+                            market_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Price => {
+                            if price__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("price"));
+                            }
+                            price__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::PositionFactor => {
+                            if position_factor__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionFactor"));
+                            }
+                            position_factor__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(SettleMarket {
+                    market_id: market_id__.unwrap_or_default(),
+                    price: price__.unwrap_or_default(),
+                    position_factor: position_factor__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.SettleMarket", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SettlePosition {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.market_id.is_empty() {
+            len += 1;
+        }
+        if !self.party_id.is_empty() {
+            len += 1;
+        }
+        if !self.price.is_empty() {
+            len += 1;
+        }
+        if !self.trade_settlements.is_empty() {
+            len += 1;
+        }
+        if !self.position_factor.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.SettlePosition", len)?;
+        if !self.market_id.is_empty() {
+            struct_ser.serialize_field("marketId", &self.market_id)?;
+        }
+        if !self.party_id.is_empty() {
+            struct_ser.serialize_field("partyId", &self.party_id)?;
+        }
+        if !self.price.is_empty() {
+            struct_ser.serialize_field("price", &self.price)?;
+        }
+        if !self.trade_settlements.is_empty() {
+            struct_ser.serialize_field("tradeSettlements", &self.trade_settlements)?;
+        }
+        if !self.position_factor.is_empty() {
+            struct_ser.serialize_field("positionFactor", &self.position_factor)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SettlePosition {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "market_id",
+            "marketId",
+            "party_id",
+            "partyId",
+            "price",
+            "trade_settlements",
+            "tradeSettlements",
+            "position_factor",
+            "positionFactor",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            MarketId,
+            PartyId,
+            Price,
+            TradeSettlements,
+            PositionFactor,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "marketId" | "market_id" => Ok(GeneratedField::MarketId),
+                            "partyId" | "party_id" => Ok(GeneratedField::PartyId),
+                            "price" => Ok(GeneratedField::Price),
+                            "tradeSettlements" | "trade_settlements" => Ok(GeneratedField::TradeSettlements),
+                            "positionFactor" | "position_factor" => Ok(GeneratedField::PositionFactor),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SettlePosition;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.SettlePosition")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SettlePosition, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut market_id__ = None;
+                let mut party_id__ = None;
+                let mut price__ = None;
+                let mut trade_settlements__ = None;
+                let mut position_factor__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::MarketId => {
+                            if market_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("marketId"));
+                            }
+                            market_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::PartyId => {
+                            if party_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("partyId"));
+                            }
+                            party_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Price => {
+                            if price__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("price"));
+                            }
+                            price__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::TradeSettlements => {
+                            if trade_settlements__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradeSettlements"));
+                            }
+                            trade_settlements__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::PositionFactor => {
+                            if position_factor__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionFactor"));
+                            }
+                            position_factor__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(SettlePosition {
+                    market_id: market_id__.unwrap_or_default(),
+                    party_id: party_id__.unwrap_or_default(),
+                    price: price__.unwrap_or_default(),
+                    trade_settlements: trade_settlements__.unwrap_or_default(),
+                    position_factor: position_factor__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.SettlePosition", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for StakeLinking {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.id.is_empty() {
+            len += 1;
+        }
+        if self.r#type != 0 {
+            len += 1;
+        }
+        if self.ts != 0 {
+            len += 1;
+        }
+        if !self.party.is_empty() {
+            len += 1;
+        }
+        if !self.amount.is_empty() {
+            len += 1;
+        }
+        if self.status != 0 {
+            len += 1;
+        }
+        if self.finalized_at != 0 {
+            len += 1;
+        }
+        if !self.tx_hash.is_empty() {
+            len += 1;
+        }
+        if self.block_height != 0 {
+            len += 1;
+        }
+        if self.block_time != 0 {
+            len += 1;
+        }
+        if self.log_index != 0 {
+            len += 1;
+        }
+        if !self.ethereum_address.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.StakeLinking", len)?;
+        if !self.id.is_empty() {
+            struct_ser.serialize_field("id", &self.id)?;
+        }
+        if self.r#type != 0 {
+            let v = stake_linking::Type::try_from(self.r#type)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.r#type)))?;
+            struct_ser.serialize_field("type", &v)?;
+        }
+        if self.ts != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("ts", ToString::to_string(&self.ts).as_str())?;
+        }
+        if !self.party.is_empty() {
+            struct_ser.serialize_field("party", &self.party)?;
+        }
+        if !self.amount.is_empty() {
+            struct_ser.serialize_field("amount", &self.amount)?;
+        }
+        if self.status != 0 {
+            let v = stake_linking::Status::try_from(self.status)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.status)))?;
+            struct_ser.serialize_field("status", &v)?;
+        }
+        if self.finalized_at != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("finalizedAt", ToString::to_string(&self.finalized_at).as_str())?;
+        }
+        if !self.tx_hash.is_empty() {
+            struct_ser.serialize_field("txHash", &self.tx_hash)?;
+        }
+        if self.block_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("blockHeight", ToString::to_string(&self.block_height).as_str())?;
+        }
+        if self.block_time != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("blockTime", ToString::to_string(&self.block_time).as_str())?;
+        }
+        if self.log_index != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("logIndex", ToString::to_string(&self.log_index).as_str())?;
+        }
+        if !self.ethereum_address.is_empty() {
+            struct_ser.serialize_field("ethereumAddress", &self.ethereum_address)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for StakeLinking {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "id",
+            "type",
+            "ts",
+            "party",
+            "amount",
+            "status",
+            "finalized_at",
+            "finalizedAt",
+            "tx_hash",
+            "txHash",
+            "block_height",
+            "blockHeight",
+            "block_time",
+            "blockTime",
+            "log_index",
+            "logIndex",
+            "ethereum_address",
+            "ethereumAddress",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Id,
+            Type,
+            Ts,
+            Party,
+            Amount,
+            Status,
+            FinalizedAt,
+            TxHash,
+            BlockHeight,
+            BlockTime,
+            LogIndex,
+            EthereumAddress,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "id" => Ok(GeneratedField::Id),
+                            "type" => Ok(GeneratedField::Type),
+                            "ts" => Ok(GeneratedField::Ts),
+                            "party" => Ok(GeneratedField::Party),
+                            "amount" => Ok(GeneratedField::Amount),
+                            "status" => Ok(GeneratedField::Status),
+                            "finalizedAt" | "finalized_at" => Ok(GeneratedField::FinalizedAt),
+                            "txHash" | "tx_hash" => Ok(GeneratedField::TxHash),
+                            "blockHeight" | "block_height" => Ok(GeneratedField::BlockHeight),
+                            "blockTime" | "block_time" => Ok(GeneratedField::BlockTime),
+                            "logIndex" | "log_index" => Ok(GeneratedField::LogIndex),
+                            "ethereumAddress" | "ethereum_address" => Ok(GeneratedField::EthereumAddress),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = StakeLinking;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.StakeLinking")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<StakeLinking, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut id__ = None;
+                let mut r#type__ = None;
+                let mut ts__ = None;
+                let mut party__ = None;
+                let mut amount__ = None;
+                let mut status__ = None;
+                let mut finalized_at__ = None;
+                let mut tx_hash__ = None;
+                let mut block_height__ = None;
+                let mut block_time__ = None;
+                let mut log_index__ = None;
+                let mut ethereum_address__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Type => {
+                            if r#type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("type"));
+                            }
+                            r#type__ = Some(map_.next_value::<stake_linking::Type>()? as i32);
+                        }
+                        GeneratedField::Ts => {
+                            if ts__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ts"));
+                            }
+                            ts__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Party => {
+                            if party__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("party"));
+                            }
+                            party__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Amount => {
+                            if amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amount"));
+                            }
+                            amount__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Status => {
+                            if status__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("status"));
+                            }
+                            status__ = Some(map_.next_value::<stake_linking::Status>()? as i32);
+                        }
+                        GeneratedField::FinalizedAt => {
+                            if finalized_at__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("finalizedAt"));
+                            }
+                            finalized_at__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::TxHash => {
+                            if tx_hash__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("txHash"));
+                            }
+                            tx_hash__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::BlockHeight => {
+                            if block_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("blockHeight"));
+                            }
+                            block_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::BlockTime => {
+                            if block_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("blockTime"));
+                            }
+                            block_time__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::LogIndex => {
+                            if log_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("logIndex"));
+                            }
+                            log_index__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::EthereumAddress => {
+                            if ethereum_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ethereumAddress"));
+                            }
+                            ethereum_address__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(StakeLinking {
+                    id: id__.unwrap_or_default(),
+                    r#type: r#type__.unwrap_or_default(),
+                    ts: ts__.unwrap_or_default(),
+                    party: party__.unwrap_or_default(),
+                    amount: amount__.unwrap_or_default(),
+                    status: status__.unwrap_or_default(),
+                    finalized_at: finalized_at__.unwrap_or_default(),
+                    tx_hash: tx_hash__.unwrap_or_default(),
+                    block_height: block_height__.unwrap_or_default(),
+                    block_time: block_time__.unwrap_or_default(),
+                    log_index: log_index__.unwrap_or_default(),
+                    ethereum_address: ethereum_address__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.StakeLinking", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for stake_linking::Status {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "STATUS_UNSPECIFIED",
+            Self::Pending => "STATUS_PENDING",
+            Self::Accepted => "STATUS_ACCEPTED",
+            Self::Rejected => "STATUS_REJECTED",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for stake_linking::Status {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "STATUS_UNSPECIFIED",
+            "STATUS_PENDING",
+            "STATUS_ACCEPTED",
+            "STATUS_REJECTED",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = stake_linking::Status;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "STATUS_UNSPECIFIED" => Ok(stake_linking::Status::Unspecified),
+                    "STATUS_PENDING" => Ok(stake_linking::Status::Pending),
+                    "STATUS_ACCEPTED" => Ok(stake_linking::Status::Accepted),
+                    "STATUS_REJECTED" => Ok(stake_linking::Status::Rejected),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for stake_linking::Type {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "TYPE_UNSPECIFIED",
+            Self::Link => "TYPE_LINK",
+            Self::Unlink => "TYPE_UNLINK",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for stake_linking::Type {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "TYPE_UNSPECIFIED",
+            "TYPE_LINK",
+            "TYPE_UNLINK",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = stake_linking::Type;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "TYPE_UNSPECIFIED" => Ok(stake_linking::Type::Unspecified),
+                    "TYPE_LINK" => Ok(stake_linking::Type::Link),
+                    "TYPE_UNLINK" => Ok(stake_linking::Type::Unlink),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for StateVar {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.id.is_empty() {
+            len += 1;
+        }
+        if !self.event_id.is_empty() {
+            len += 1;
+        }
+        if !self.state.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.StateVar", len)?;
+        if !self.id.is_empty() {
+            struct_ser.serialize_field("id", &self.id)?;
+        }
+        if !self.event_id.is_empty() {
+            struct_ser.serialize_field("eventId", &self.event_id)?;
+        }
+        if !self.state.is_empty() {
+            struct_ser.serialize_field("state", &self.state)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for StateVar {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "id",
+            "event_id",
+            "eventId",
+            "state",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Id,
+            EventId,
+            State,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "id" => Ok(GeneratedField::Id),
+                            "eventId" | "event_id" => Ok(GeneratedField::EventId),
+                            "state" => Ok(GeneratedField::State),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = StateVar;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.StateVar")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<StateVar, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut id__ = None;
+                let mut event_id__ = None;
+                let mut state__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::EventId => {
+                            if event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("eventId"));
+                            }
+                            event_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::State => {
+                            if state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("state"));
+                            }
+                            state__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(StateVar {
+                    id: id__.unwrap_or_default(),
+                    event_id: event_id__.unwrap_or_default(),
+                    state: state__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.StateVar", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for StopOrderEvent {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.submission.is_some() {
+            len += 1;
+        }
+        if self.stop_order.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.StopOrderEvent", len)?;
+        if let Some(v) = self.submission.as_ref() {
+            struct_ser.serialize_field("submission", v)?;
+        }
+        if let Some(v) = self.stop_order.as_ref() {
+            struct_ser.serialize_field("stopOrder", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for StopOrderEvent {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "submission",
+            "stop_order",
+            "stopOrder",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Submission,
+            StopOrder,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "submission" => Ok(GeneratedField::Submission),
+                            "stopOrder" | "stop_order" => Ok(GeneratedField::StopOrder),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = StopOrderEvent;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.StopOrderEvent")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<StopOrderEvent, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut submission__ = None;
+                let mut stop_order__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Submission => {
+                            if submission__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("submission"));
+                            }
+                            submission__ = map_.next_value()?;
+                        }
+                        GeneratedField::StopOrder => {
+                            if stop_order__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stopOrder"));
+                            }
+                            stop_order__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(StopOrderEvent {
+                    submission: submission__,
+                    stop_order: stop_order__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.StopOrderEvent", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for StreamStartEvent {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.chain_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.StreamStartEvent", len)?;
+        if !self.chain_id.is_empty() {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for StreamStartEvent {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "chain_id",
+            "chainId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ChainId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = StreamStartEvent;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.StreamStartEvent")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<StreamStartEvent, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut chain_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
+                            }
+                            chain_id__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(StreamStartEvent {
+                    chain_id: chain_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.StreamStartEvent", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TeamCreated {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.team_id.is_empty() {
+            len += 1;
+        }
+        if !self.referrer.is_empty() {
+            len += 1;
+        }
+        if !self.name.is_empty() {
+            len += 1;
+        }
+        if self.team_url.is_some() {
+            len += 1;
+        }
+        if self.avatar_url.is_some() {
+            len += 1;
+        }
+        if self.created_at != 0 {
+            len += 1;
+        }
+        if self.closed {
+            len += 1;
+        }
+        if self.at_epoch != 0 {
+            len += 1;
+        }
+        if !self.allow_list.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.TeamCreated", len)?;
+        if !self.team_id.is_empty() {
+            struct_ser.serialize_field("teamId", &self.team_id)?;
+        }
+        if !self.referrer.is_empty() {
+            struct_ser.serialize_field("referrer", &self.referrer)?;
+        }
+        if !self.name.is_empty() {
+            struct_ser.serialize_field("name", &self.name)?;
+        }
+        if let Some(v) = self.team_url.as_ref() {
+            struct_ser.serialize_field("teamUrl", v)?;
+        }
+        if let Some(v) = self.avatar_url.as_ref() {
+            struct_ser.serialize_field("avatarUrl", v)?;
+        }
+        if self.created_at != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("createdAt", ToString::to_string(&self.created_at).as_str())?;
+        }
+        if self.closed {
+            struct_ser.serialize_field("closed", &self.closed)?;
+        }
+        if self.at_epoch != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("atEpoch", ToString::to_string(&self.at_epoch).as_str())?;
+        }
+        if !self.allow_list.is_empty() {
+            struct_ser.serialize_field("allowList", &self.allow_list)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TeamCreated {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "team_id",
+            "teamId",
+            "referrer",
+            "name",
+            "team_url",
+            "teamUrl",
+            "avatar_url",
+            "avatarUrl",
+            "created_at",
+            "createdAt",
+            "closed",
+            "at_epoch",
+            "atEpoch",
+            "allow_list",
+            "allowList",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TeamId,
+            Referrer,
+            Name,
+            TeamUrl,
+            AvatarUrl,
+            CreatedAt,
+            Closed,
+            AtEpoch,
+            AllowList,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "teamId" | "team_id" => Ok(GeneratedField::TeamId),
+                            "referrer" => Ok(GeneratedField::Referrer),
+                            "name" => Ok(GeneratedField::Name),
+                            "teamUrl" | "team_url" => Ok(GeneratedField::TeamUrl),
+                            "avatarUrl" | "avatar_url" => Ok(GeneratedField::AvatarUrl),
+                            "createdAt" | "created_at" => Ok(GeneratedField::CreatedAt),
+                            "closed" => Ok(GeneratedField::Closed),
+                            "atEpoch" | "at_epoch" => Ok(GeneratedField::AtEpoch),
+                            "allowList" | "allow_list" => Ok(GeneratedField::AllowList),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TeamCreated;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.TeamCreated")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TeamCreated, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut team_id__ = None;
+                let mut referrer__ = None;
+                let mut name__ = None;
+                let mut team_url__ = None;
+                let mut avatar_url__ = None;
+                let mut created_at__ = None;
+                let mut closed__ = None;
+                let mut at_epoch__ = None;
+                let mut allow_list__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TeamId => {
+                            if team_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("teamId"));
+                            }
+                            team_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Referrer => {
+                            if referrer__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("referrer"));
+                            }
+                            referrer__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Name => {
+                            if name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("name"));
+                            }
+                            name__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::TeamUrl => {
+                            if team_url__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("teamUrl"));
+                            }
+                            team_url__ = map_.next_value()?;
+                        }
+                        GeneratedField::AvatarUrl => {
+                            if avatar_url__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("avatarUrl"));
+                            }
+                            avatar_url__ = map_.next_value()?;
+                        }
+                        GeneratedField::CreatedAt => {
+                            if created_at__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("createdAt"));
+                            }
+                            created_at__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Closed => {
+                            if closed__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("closed"));
+                            }
+                            closed__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::AtEpoch => {
+                            if at_epoch__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("atEpoch"));
+                            }
+                            at_epoch__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::AllowList => {
+                            if allow_list__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("allowList"));
+                            }
+                            allow_list__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(TeamCreated {
+                    team_id: team_id__.unwrap_or_default(),
+                    referrer: referrer__.unwrap_or_default(),
+                    name: name__.unwrap_or_default(),
+                    team_url: team_url__,
+                    avatar_url: avatar_url__,
+                    created_at: created_at__.unwrap_or_default(),
+                    closed: closed__.unwrap_or_default(),
+                    at_epoch: at_epoch__.unwrap_or_default(),
+                    allow_list: allow_list__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.TeamCreated", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TeamMemberStats {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.party_id.is_empty() {
+            len += 1;
+        }
+        if !self.notional_volume.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.TeamMemberStats", len)?;
+        if !self.party_id.is_empty() {
+            struct_ser.serialize_field("partyId", &self.party_id)?;
+        }
+        if !self.notional_volume.is_empty() {
+            struct_ser.serialize_field("notionalVolume", &self.notional_volume)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TeamMemberStats {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "party_id",
+            "partyId",
+            "notional_volume",
+            "notionalVolume",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PartyId,
+            NotionalVolume,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "partyId" | "party_id" => Ok(GeneratedField::PartyId),
+                            "notionalVolume" | "notional_volume" => Ok(GeneratedField::NotionalVolume),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TeamMemberStats;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.TeamMemberStats")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TeamMemberStats, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut party_id__ = None;
+                let mut notional_volume__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PartyId => {
+                            if party_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("partyId"));
+                            }
+                            party_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::NotionalVolume => {
+                            if notional_volume__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("notionalVolume"));
+                            }
+                            notional_volume__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(TeamMemberStats {
+                    party_id: party_id__.unwrap_or_default(),
+                    notional_volume: notional_volume__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.TeamMemberStats", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TeamStats {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.team_id.is_empty() {
+            len += 1;
+        }
+        if !self.members_stats.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.TeamStats", len)?;
+        if !self.team_id.is_empty() {
+            struct_ser.serialize_field("teamId", &self.team_id)?;
+        }
+        if !self.members_stats.is_empty() {
+            struct_ser.serialize_field("membersStats", &self.members_stats)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TeamStats {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "team_id",
+            "teamId",
+            "members_stats",
+            "membersStats",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TeamId,
+            MembersStats,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "teamId" | "team_id" => Ok(GeneratedField::TeamId),
+                            "membersStats" | "members_stats" => Ok(GeneratedField::MembersStats),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TeamStats;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.TeamStats")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TeamStats, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut team_id__ = None;
+                let mut members_stats__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TeamId => {
+                            if team_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("teamId"));
+                            }
+                            team_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::MembersStats => {
+                            if members_stats__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("membersStats"));
+                            }
+                            members_stats__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(TeamStats {
+                    team_id: team_id__.unwrap_or_default(),
+                    members_stats: members_stats__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.TeamStats", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TeamUpdated {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.team_id.is_empty() {
+            len += 1;
+        }
+        if !self.name.is_empty() {
+            len += 1;
+        }
+        if self.team_url.is_some() {
+            len += 1;
+        }
+        if self.avatar_url.is_some() {
+            len += 1;
+        }
+        if self.closed {
+            len += 1;
+        }
+        if !self.allow_list.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.TeamUpdated", len)?;
+        if !self.team_id.is_empty() {
+            struct_ser.serialize_field("teamId", &self.team_id)?;
+        }
+        if !self.name.is_empty() {
+            struct_ser.serialize_field("name", &self.name)?;
+        }
+        if let Some(v) = self.team_url.as_ref() {
+            struct_ser.serialize_field("teamUrl", v)?;
+        }
+        if let Some(v) = self.avatar_url.as_ref() {
+            struct_ser.serialize_field("avatarUrl", v)?;
+        }
+        if self.closed {
+            struct_ser.serialize_field("closed", &self.closed)?;
+        }
+        if !self.allow_list.is_empty() {
+            struct_ser.serialize_field("allowList", &self.allow_list)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TeamUpdated {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "team_id",
+            "teamId",
+            "name",
+            "team_url",
+            "teamUrl",
+            "avatar_url",
+            "avatarUrl",
+            "closed",
+            "allow_list",
+            "allowList",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TeamId,
+            Name,
+            TeamUrl,
+            AvatarUrl,
+            Closed,
+            AllowList,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "teamId" | "team_id" => Ok(GeneratedField::TeamId),
+                            "name" => Ok(GeneratedField::Name),
+                            "teamUrl" | "team_url" => Ok(GeneratedField::TeamUrl),
+                            "avatarUrl" | "avatar_url" => Ok(GeneratedField::AvatarUrl),
+                            "closed" => Ok(GeneratedField::Closed),
+                            "allowList" | "allow_list" => Ok(GeneratedField::AllowList),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TeamUpdated;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.TeamUpdated")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TeamUpdated, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut team_id__ = None;
+                let mut name__ = None;
+                let mut team_url__ = None;
+                let mut avatar_url__ = None;
+                let mut closed__ = None;
+                let mut allow_list__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TeamId => {
+                            if team_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("teamId"));
+                            }
+                            team_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Name => {
+                            if name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("name"));
+                            }
+                            name__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::TeamUrl => {
+                            if team_url__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("teamUrl"));
+                            }
+                            team_url__ = map_.next_value()?;
+                        }
+                        GeneratedField::AvatarUrl => {
+                            if avatar_url__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("avatarUrl"));
+                            }
+                            avatar_url__ = map_.next_value()?;
+                        }
+                        GeneratedField::Closed => {
+                            if closed__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("closed"));
+                            }
+                            closed__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::AllowList => {
+                            if allow_list__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("allowList"));
+                            }
+                            allow_list__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(TeamUpdated {
+                    team_id: team_id__.unwrap_or_default(),
+                    name: name__.unwrap_or_default(),
+                    team_url: team_url__,
+                    avatar_url: avatar_url__,
+                    closed: closed__.unwrap_or_default(),
+                    allow_list: allow_list__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.TeamUpdated", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TeamsStatsUpdated {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.at_epoch != 0 {
+            len += 1;
+        }
+        if !self.stats.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.TeamsStatsUpdated", len)?;
+        if self.at_epoch != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("atEpoch", ToString::to_string(&self.at_epoch).as_str())?;
+        }
+        if !self.stats.is_empty() {
+            struct_ser.serialize_field("stats", &self.stats)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TeamsStatsUpdated {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "at_epoch",
+            "atEpoch",
+            "stats",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            AtEpoch,
+            Stats,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "atEpoch" | "at_epoch" => Ok(GeneratedField::AtEpoch),
+                            "stats" => Ok(GeneratedField::Stats),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TeamsStatsUpdated;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.TeamsStatsUpdated")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TeamsStatsUpdated, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut at_epoch__ = None;
+                let mut stats__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::AtEpoch => {
+                            if at_epoch__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("atEpoch"));
+                            }
+                            at_epoch__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Stats => {
+                            if stats__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stats"));
+                            }
+                            stats__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(TeamsStatsUpdated {
+                    at_epoch: at_epoch__.unwrap_or_default(),
+                    stats: stats__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.TeamsStatsUpdated", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TimeUpdate {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.timestamp != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.TimeUpdate", len)?;
+        if self.timestamp != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("timestamp", ToString::to_string(&self.timestamp).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TimeUpdate {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "timestamp",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Timestamp,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "timestamp" => Ok(GeneratedField::Timestamp),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TimeUpdate;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.TimeUpdate")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TimeUpdate, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut timestamp__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Timestamp => {
+                            if timestamp__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timestamp"));
+                            }
+                            timestamp__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(TimeUpdate {
+                    timestamp: timestamp__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.TimeUpdate", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TimeWeightedNotionalPositionUpdated {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.epoch_seq != 0 {
+            len += 1;
+        }
+        if !self.asset.is_empty() {
+            len += 1;
+        }
+        if !self.party.is_empty() {
+            len += 1;
+        }
+        if !self.game_id.is_empty() {
+            len += 1;
+        }
+        if !self.time_weighted_notional_position.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.TimeWeightedNotionalPositionUpdated", len)?;
+        if self.epoch_seq != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("epochSeq", ToString::to_string(&self.epoch_seq).as_str())?;
+        }
+        if !self.asset.is_empty() {
+            struct_ser.serialize_field("asset", &self.asset)?;
+        }
+        if !self.party.is_empty() {
+            struct_ser.serialize_field("party", &self.party)?;
+        }
+        if !self.game_id.is_empty() {
+            struct_ser.serialize_field("gameId", &self.game_id)?;
+        }
+        if !self.time_weighted_notional_position.is_empty() {
+            struct_ser.serialize_field("timeWeightedNotionalPosition", &self.time_weighted_notional_position)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TimeWeightedNotionalPositionUpdated {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "epoch_seq",
+            "epochSeq",
+            "asset",
+            "party",
+            "game_id",
+            "gameId",
+            "time_weighted_notional_position",
+            "timeWeightedNotionalPosition",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            EpochSeq,
+            Asset,
+            Party,
+            GameId,
+            TimeWeightedNotionalPosition,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "epochSeq" | "epoch_seq" => Ok(GeneratedField::EpochSeq),
+                            "asset" => Ok(GeneratedField::Asset),
+                            "party" => Ok(GeneratedField::Party),
+                            "gameId" | "game_id" => Ok(GeneratedField::GameId),
+                            "timeWeightedNotionalPosition" | "time_weighted_notional_position" => Ok(GeneratedField::TimeWeightedNotionalPosition),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TimeWeightedNotionalPositionUpdated;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.TimeWeightedNotionalPositionUpdated")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TimeWeightedNotionalPositionUpdated, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut epoch_seq__ = None;
+                let mut asset__ = None;
+                let mut party__ = None;
+                let mut game_id__ = None;
+                let mut time_weighted_notional_position__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::EpochSeq => {
+                            if epoch_seq__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epochSeq"));
+                            }
+                            epoch_seq__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Asset => {
+                            if asset__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("asset"));
+                            }
+                            asset__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Party => {
+                            if party__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("party"));
+                            }
+                            party__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::GameId => {
+                            if game_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("gameId"));
+                            }
+                            game_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::TimeWeightedNotionalPosition => {
+                            if time_weighted_notional_position__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timeWeightedNotionalPosition"));
+                            }
+                            time_weighted_notional_position__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(TimeWeightedNotionalPositionUpdated {
+                    epoch_seq: epoch_seq__.unwrap_or_default(),
+                    asset: asset__.unwrap_or_default(),
+                    party: party__.unwrap_or_default(),
+                    game_id: game_id__.unwrap_or_default(),
+                    time_weighted_notional_position: time_weighted_notional_position__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.TimeWeightedNotionalPositionUpdated", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TradeSettlement {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.size != 0 {
+            len += 1;
+        }
+        if !self.price.is_empty() {
+            len += 1;
+        }
+        if !self.market_price.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.TradeSettlement", len)?;
+        if self.size != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("size", ToString::to_string(&self.size).as_str())?;
+        }
+        if !self.price.is_empty() {
+            struct_ser.serialize_field("price", &self.price)?;
+        }
+        if !self.market_price.is_empty() {
+            struct_ser.serialize_field("marketPrice", &self.market_price)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TradeSettlement {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "size",
+            "price",
+            "market_price",
+            "marketPrice",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Size,
+            Price,
+            MarketPrice,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "size" => Ok(GeneratedField::Size),
+                            "price" => Ok(GeneratedField::Price),
+                            "marketPrice" | "market_price" => Ok(GeneratedField::MarketPrice),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TradeSettlement;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.TradeSettlement")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TradeSettlement, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut size__ = None;
+                let mut price__ = None;
+                let mut market_price__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Size => {
+                            if size__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("size"));
+                            }
+                            size__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Price => {
+                            if price__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("price"));
+                            }
+                            price__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::MarketPrice => {
+                            if market_price__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("marketPrice"));
+                            }
+                            market_price__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(TradeSettlement {
+                    size: size__.unwrap_or_default(),
+                    price: price__.unwrap_or_default(),
+                    market_price: market_price__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.TradeSettlement", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TransactionResult {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.party_id.is_empty() {
+            len += 1;
+        }
+        if self.status {
+            len += 1;
+        }
+        if !self.hash.is_empty() {
+            len += 1;
+        }
+        if self.status_detail != 0 {
+            len += 1;
+        }
+        if self.transaction.is_some() {
+            len += 1;
+        }
+        if self.extra.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.TransactionResult", len)?;
+        if !self.party_id.is_empty() {
+            struct_ser.serialize_field("partyId", &self.party_id)?;
+        }
+        if self.status {
+            struct_ser.serialize_field("status", &self.status)?;
+        }
+        if !self.hash.is_empty() {
+            struct_ser.serialize_field("hash", &self.hash)?;
+        }
+        if self.status_detail != 0 {
+            let v = transaction_result::Status::try_from(self.status_detail)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.status_detail)))?;
+            struct_ser.serialize_field("statusDetail", &v)?;
+        }
+        if let Some(v) = self.transaction.as_ref() {
+            match v {
+                transaction_result::Transaction::OrderSubmission(v) => {
+                    struct_ser.serialize_field("orderSubmission", v)?;
+                }
+                transaction_result::Transaction::OrderAmendment(v) => {
+                    struct_ser.serialize_field("orderAmendment", v)?;
+                }
+                transaction_result::Transaction::OrderCancellation(v) => {
+                    struct_ser.serialize_field("orderCancellation", v)?;
+                }
+                transaction_result::Transaction::Proposal(v) => {
+                    struct_ser.serialize_field("proposal", v)?;
+                }
+                transaction_result::Transaction::VoteSubmission(v) => {
+                    struct_ser.serialize_field("voteSubmission", v)?;
+                }
+                transaction_result::Transaction::LiquidityProvisionSubmission(v) => {
+                    struct_ser.serialize_field("liquidityProvisionSubmission", v)?;
+                }
+                transaction_result::Transaction::WithdrawSubmission(v) => {
+                    struct_ser.serialize_field("withdrawSubmission", v)?;
+                }
+                transaction_result::Transaction::DelegateSubmission(v) => {
+                    struct_ser.serialize_field("delegateSubmission", v)?;
+                }
+                transaction_result::Transaction::UndelegateSubmission(v) => {
+                    struct_ser.serialize_field("undelegateSubmission", v)?;
+                }
+                transaction_result::Transaction::LiquidityProvisionCancellation(v) => {
+                    struct_ser.serialize_field("liquidityProvisionCancellation", v)?;
+                }
+                transaction_result::Transaction::LiquidityProvisionAmendment(v) => {
+                    struct_ser.serialize_field("liquidityProvisionAmendment", v)?;
+                }
+                transaction_result::Transaction::Transfer(v) => {
+                    struct_ser.serialize_field("transfer", v)?;
+                }
+                transaction_result::Transaction::CancelTransfer(v) => {
+                    struct_ser.serialize_field("cancelTransfer", v)?;
+                }
+                transaction_result::Transaction::AnnounceNode(v) => {
+                    struct_ser.serialize_field("announceNode", v)?;
+                }
+                transaction_result::Transaction::OracleDataSubmission(v) => {
+                    struct_ser.serialize_field("oracleDataSubmission", v)?;
+                }
+                transaction_result::Transaction::ProtocolUpgradeProposal(v) => {
+                    struct_ser.serialize_field("protocolUpgradeProposal", v)?;
+                }
+                transaction_result::Transaction::IssueSignatures(v) => {
+                    struct_ser.serialize_field("issueSignatures", v)?;
+                }
+                transaction_result::Transaction::BatchMarketInstructions(v) => {
+                    struct_ser.serialize_field("batchMarketInstructions", v)?;
+                }
+                transaction_result::Transaction::KeyRotateSubmission(v) => {
+                    struct_ser.serialize_field("keyRotateSubmission", v)?;
+                }
+                transaction_result::Transaction::EthereumKeyRotateSubmission(v) => {
+                    struct_ser.serialize_field("ethereumKeyRotateSubmission", v)?;
+                }
+                transaction_result::Transaction::StopOrdersSubmission(v) => {
+                    struct_ser.serialize_field("stopOrdersSubmission", v)?;
+                }
+                transaction_result::Transaction::StopOrdersCancellation(v) => {
+                    struct_ser.serialize_field("stopOrdersCancellation", v)?;
+                }
+                transaction_result::Transaction::CreateReferralSet(v) => {
+                    struct_ser.serialize_field("createReferralSet", v)?;
+                }
+                transaction_result::Transaction::UpdateReferralSet(v) => {
+                    struct_ser.serialize_field("updateReferralSet", v)?;
+                }
+                transaction_result::Transaction::ApplyReferralCode(v) => {
+                    struct_ser.serialize_field("applyReferralCode", v)?;
+                }
+                transaction_result::Transaction::UpdateMarginMode(v) => {
+                    struct_ser.serialize_field("updateMarginMode", v)?;
+                }
+                transaction_result::Transaction::JoinTeam(v) => {
+                    struct_ser.serialize_field("joinTeam", v)?;
+                }
+                transaction_result::Transaction::BatchProposal(v) => {
+                    struct_ser.serialize_field("batchProposal", v)?;
+                }
+                transaction_result::Transaction::UpdatePartyProfile(v) => {
+                    struct_ser.serialize_field("updatePartyProfile", v)?;
+                }
+                transaction_result::Transaction::SubmitAmm(v) => {
+                    struct_ser.serialize_field("submitAmm", v)?;
+                }
+                transaction_result::Transaction::AmendAmm(v) => {
+                    struct_ser.serialize_field("amendAmm", v)?;
+                }
+                transaction_result::Transaction::CancelAmm(v) => {
+                    struct_ser.serialize_field("cancelAmm", v)?;
+                }
+            }
+        }
+        if let Some(v) = self.extra.as_ref() {
+            match v {
+                transaction_result::Extra::Success(v) => {
+                    struct_ser.serialize_field("success", v)?;
+                }
+                transaction_result::Extra::Failure(v) => {
+                    struct_ser.serialize_field("failure", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TransactionResult {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "party_id",
+            "partyId",
+            "status",
+            "hash",
+            "status_detail",
+            "statusDetail",
+            "order_submission",
+            "orderSubmission",
+            "order_amendment",
+            "orderAmendment",
+            "order_cancellation",
+            "orderCancellation",
+            "proposal",
+            "vote_submission",
+            "voteSubmission",
+            "liquidity_provision_submission",
+            "liquidityProvisionSubmission",
+            "withdraw_submission",
+            "withdrawSubmission",
+            "delegate_submission",
+            "delegateSubmission",
+            "undelegate_submission",
+            "undelegateSubmission",
+            "liquidity_provision_cancellation",
+            "liquidityProvisionCancellation",
+            "liquidity_provision_amendment",
+            "liquidityProvisionAmendment",
+            "transfer",
+            "cancel_transfer",
+            "cancelTransfer",
+            "announce_node",
+            "announceNode",
+            "oracle_data_submission",
+            "oracleDataSubmission",
+            "protocol_upgrade_proposal",
+            "protocolUpgradeProposal",
+            "issue_signatures",
+            "issueSignatures",
+            "batch_market_instructions",
+            "batchMarketInstructions",
+            "key_rotate_submission",
+            "keyRotateSubmission",
+            "ethereum_key_rotate_submission",
+            "ethereumKeyRotateSubmission",
+            "stop_orders_submission",
+            "stopOrdersSubmission",
+            "stop_orders_cancellation",
+            "stopOrdersCancellation",
+            "create_referral_set",
+            "createReferralSet",
+            "update_referral_set",
+            "updateReferralSet",
+            "apply_referral_code",
+            "applyReferralCode",
+            "update_margin_mode",
+            "updateMarginMode",
+            "join_team",
+            "joinTeam",
+            "batch_proposal",
+            "batchProposal",
+            "update_party_profile",
+            "updatePartyProfile",
+            "submit_amm",
+            "submitAmm",
+            "amend_amm",
+            "amendAmm",
+            "cancel_amm",
+            "cancelAmm",
+            "success",
+            "failure",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PartyId,
+            Status,
+            Hash,
+            StatusDetail,
+            OrderSubmission,
+            OrderAmendment,
+            OrderCancellation,
+            Proposal,
+            VoteSubmission,
+            LiquidityProvisionSubmission,
+            WithdrawSubmission,
+            DelegateSubmission,
+            UndelegateSubmission,
+            LiquidityProvisionCancellation,
+            LiquidityProvisionAmendment,
+            Transfer,
+            CancelTransfer,
+            AnnounceNode,
+            OracleDataSubmission,
+            ProtocolUpgradeProposal,
+            IssueSignatures,
+            BatchMarketInstructions,
+            KeyRotateSubmission,
+            EthereumKeyRotateSubmission,
+            StopOrdersSubmission,
+            StopOrdersCancellation,
+            CreateReferralSet,
+            UpdateReferralSet,
+            ApplyReferralCode,
+            UpdateMarginMode,
+            JoinTeam,
+            BatchProposal,
+            UpdatePartyProfile,
+            SubmitAmm,
+            AmendAmm,
+            CancelAmm,
+            Success,
+            Failure,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "partyId" | "party_id" => Ok(GeneratedField::PartyId),
+                            "status" => Ok(GeneratedField::Status),
+                            "hash" => Ok(GeneratedField::Hash),
+                            "statusDetail" | "status_detail" => Ok(GeneratedField::StatusDetail),
+                            "orderSubmission" | "order_submission" => Ok(GeneratedField::OrderSubmission),
+                            "orderAmendment" | "order_amendment" => Ok(GeneratedField::OrderAmendment),
+                            "orderCancellation" | "order_cancellation" => Ok(GeneratedField::OrderCancellation),
+                            "proposal" => Ok(GeneratedField::Proposal),
+                            "voteSubmission" | "vote_submission" => Ok(GeneratedField::VoteSubmission),
+                            "liquidityProvisionSubmission" | "liquidity_provision_submission" => Ok(GeneratedField::LiquidityProvisionSubmission),
+                            "withdrawSubmission" | "withdraw_submission" => Ok(GeneratedField::WithdrawSubmission),
+                            "delegateSubmission" | "delegate_submission" => Ok(GeneratedField::DelegateSubmission),
+                            "undelegateSubmission" | "undelegate_submission" => Ok(GeneratedField::UndelegateSubmission),
+                            "liquidityProvisionCancellation" | "liquidity_provision_cancellation" => Ok(GeneratedField::LiquidityProvisionCancellation),
+                            "liquidityProvisionAmendment" | "liquidity_provision_amendment" => Ok(GeneratedField::LiquidityProvisionAmendment),
+                            "transfer" => Ok(GeneratedField::Transfer),
+                            "cancelTransfer" | "cancel_transfer" => Ok(GeneratedField::CancelTransfer),
+                            "announceNode" | "announce_node" => Ok(GeneratedField::AnnounceNode),
+                            "oracleDataSubmission" | "oracle_data_submission" => Ok(GeneratedField::OracleDataSubmission),
+                            "protocolUpgradeProposal" | "protocol_upgrade_proposal" => Ok(GeneratedField::ProtocolUpgradeProposal),
+                            "issueSignatures" | "issue_signatures" => Ok(GeneratedField::IssueSignatures),
+                            "batchMarketInstructions" | "batch_market_instructions" => Ok(GeneratedField::BatchMarketInstructions),
+                            "keyRotateSubmission" | "key_rotate_submission" => Ok(GeneratedField::KeyRotateSubmission),
+                            "ethereumKeyRotateSubmission" | "ethereum_key_rotate_submission" => Ok(GeneratedField::EthereumKeyRotateSubmission),
+                            "stopOrdersSubmission" | "stop_orders_submission" => Ok(GeneratedField::StopOrdersSubmission),
+                            "stopOrdersCancellation" | "stop_orders_cancellation" => Ok(GeneratedField::StopOrdersCancellation),
+                            "createReferralSet" | "create_referral_set" => Ok(GeneratedField::CreateReferralSet),
+                            "updateReferralSet" | "update_referral_set" => Ok(GeneratedField::UpdateReferralSet),
+                            "applyReferralCode" | "apply_referral_code" => Ok(GeneratedField::ApplyReferralCode),
+                            "updateMarginMode" | "update_margin_mode" => Ok(GeneratedField::UpdateMarginMode),
+                            "joinTeam" | "join_team" => Ok(GeneratedField::JoinTeam),
+                            "batchProposal" | "batch_proposal" => Ok(GeneratedField::BatchProposal),
+                            "updatePartyProfile" | "update_party_profile" => Ok(GeneratedField::UpdatePartyProfile),
+                            "submitAmm" | "submit_amm" => Ok(GeneratedField::SubmitAmm),
+                            "amendAmm" | "amend_amm" => Ok(GeneratedField::AmendAmm),
+                            "cancelAmm" | "cancel_amm" => Ok(GeneratedField::CancelAmm),
+                            "success" => Ok(GeneratedField::Success),
+                            "failure" => Ok(GeneratedField::Failure),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TransactionResult;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.TransactionResult")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TransactionResult, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut party_id__ = None;
+                let mut status__ = None;
+                let mut hash__ = None;
+                let mut status_detail__ = None;
+                let mut transaction__ = None;
+                let mut extra__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PartyId => {
+                            if party_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("partyId"));
+                            }
+                            party_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Status => {
+                            if status__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("status"));
+                            }
+                            status__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Hash => {
+                            if hash__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("hash"));
+                            }
+                            hash__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::StatusDetail => {
+                            if status_detail__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("statusDetail"));
+                            }
+                            status_detail__ = Some(map_.next_value::<transaction_result::Status>()? as i32);
+                        }
+                        GeneratedField::OrderSubmission => {
+                            if transaction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("orderSubmission"));
+                            }
+                            transaction__ = map_.next_value::<::std::option::Option<_>>()?.map(transaction_result::Transaction::OrderSubmission)
+;
+                        }
+                        GeneratedField::OrderAmendment => {
+                            if transaction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("orderAmendment"));
+                            }
+                            transaction__ = map_.next_value::<::std::option::Option<_>>()?.map(transaction_result::Transaction::OrderAmendment)
+;
+                        }
+                        GeneratedField::OrderCancellation => {
+                            if transaction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("orderCancellation"));
+                            }
+                            transaction__ = map_.next_value::<::std::option::Option<_>>()?.map(transaction_result::Transaction::OrderCancellation)
+;
+                        }
+                        GeneratedField::Proposal => {
+                            if transaction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposal"));
+                            }
+                            transaction__ = map_.next_value::<::std::option::Option<_>>()?.map(transaction_result::Transaction::Proposal)
+;
+                        }
+                        GeneratedField::VoteSubmission => {
+                            if transaction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("voteSubmission"));
+                            }
+                            transaction__ = map_.next_value::<::std::option::Option<_>>()?.map(transaction_result::Transaction::VoteSubmission)
+;
+                        }
+                        GeneratedField::LiquidityProvisionSubmission => {
+                            if transaction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("liquidityProvisionSubmission"));
+                            }
+                            transaction__ = map_.next_value::<::std::option::Option<_>>()?.map(transaction_result::Transaction::LiquidityProvisionSubmission)
+;
+                        }
+                        GeneratedField::WithdrawSubmission => {
+                            if transaction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("withdrawSubmission"));
+                            }
+                            transaction__ = map_.next_value::<::std::option::Option<_>>()?.map(transaction_result::Transaction::WithdrawSubmission)
+;
+                        }
+                        GeneratedField::DelegateSubmission => {
+                            if transaction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("delegateSubmission"));
+                            }
+                            transaction__ = map_.next_value::<::std::option::Option<_>>()?.map(transaction_result::Transaction::DelegateSubmission)
+;
+                        }
+                        GeneratedField::UndelegateSubmission => {
+                            if transaction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("undelegateSubmission"));
+                            }
+                            transaction__ = map_.next_value::<::std::option::Option<_>>()?.map(transaction_result::Transaction::UndelegateSubmission)
+;
+                        }
+                        GeneratedField::LiquidityProvisionCancellation => {
+                            if transaction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("liquidityProvisionCancellation"));
+                            }
+                            transaction__ = map_.next_value::<::std::option::Option<_>>()?.map(transaction_result::Transaction::LiquidityProvisionCancellation)
+;
+                        }
+                        GeneratedField::LiquidityProvisionAmendment => {
+                            if transaction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("liquidityProvisionAmendment"));
+                            }
+                            transaction__ = map_.next_value::<::std::option::Option<_>>()?.map(transaction_result::Transaction::LiquidityProvisionAmendment)
+;
+                        }
+                        GeneratedField::Transfer => {
+                            if transaction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("transfer"));
+                            }
+                            transaction__ = map_.next_value::<::std::option::Option<_>>()?.map(transaction_result::Transaction::Transfer)
+;
+                        }
+                        GeneratedField::CancelTransfer => {
+                            if transaction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("cancelTransfer"));
+                            }
+                            transaction__ = map_.next_value::<::std::option::Option<_>>()?.map(transaction_result::Transaction::CancelTransfer)
+;
+                        }
+                        GeneratedField::AnnounceNode => {
+                            if transaction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("announceNode"));
+                            }
+                            transaction__ = map_.next_value::<::std::option::Option<_>>()?.map(transaction_result::Transaction::AnnounceNode)
+;
+                        }
+                        GeneratedField::OracleDataSubmission => {
+                            if transaction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("oracleDataSubmission"));
+                            }
+                            transaction__ = map_.next_value::<::std::option::Option<_>>()?.map(transaction_result::Transaction::OracleDataSubmission)
+;
+                        }
+                        GeneratedField::ProtocolUpgradeProposal => {
+                            if transaction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("protocolUpgradeProposal"));
+                            }
+                            transaction__ = map_.next_value::<::std::option::Option<_>>()?.map(transaction_result::Transaction::ProtocolUpgradeProposal)
+;
+                        }
+                        GeneratedField::IssueSignatures => {
+                            if transaction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("issueSignatures"));
+                            }
+                            transaction__ = map_.next_value::<::std::option::Option<_>>()?.map(transaction_result::Transaction::IssueSignatures)
+;
+                        }
+                        GeneratedField::BatchMarketInstructions => {
+                            if transaction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("batchMarketInstructions"));
+                            }
+                            transaction__ = map_.next_value::<::std::option::Option<_>>()?.map(transaction_result::Transaction::BatchMarketInstructions)
+;
+                        }
+                        GeneratedField::KeyRotateSubmission => {
+                            if transaction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("keyRotateSubmission"));
+                            }
+                            transaction__ = map_.next_value::<::std::option::Option<_>>()?.map(transaction_result::Transaction::KeyRotateSubmission)
+;
+                        }
+                        GeneratedField::EthereumKeyRotateSubmission => {
+                            if transaction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ethereumKeyRotateSubmission"));
+                            }
+                            transaction__ = map_.next_value::<::std::option::Option<_>>()?.map(transaction_result::Transaction::EthereumKeyRotateSubmission)
+;
+                        }
+                        GeneratedField::StopOrdersSubmission => {
+                            if transaction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stopOrdersSubmission"));
+                            }
+                            transaction__ = map_.next_value::<::std::option::Option<_>>()?.map(transaction_result::Transaction::StopOrdersSubmission)
+;
+                        }
+                        GeneratedField::StopOrdersCancellation => {
+                            if transaction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stopOrdersCancellation"));
+                            }
+                            transaction__ = map_.next_value::<::std::option::Option<_>>()?.map(transaction_result::Transaction::StopOrdersCancellation)
+;
+                        }
+                        GeneratedField::CreateReferralSet => {
+                            if transaction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("createReferralSet"));
+                            }
+                            transaction__ = map_.next_value::<::std::option::Option<_>>()?.map(transaction_result::Transaction::CreateReferralSet)
+;
+                        }
+                        GeneratedField::UpdateReferralSet => {
+                            if transaction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("updateReferralSet"));
+                            }
+                            transaction__ = map_.next_value::<::std::option::Option<_>>()?.map(transaction_result::Transaction::UpdateReferralSet)
+;
+                        }
+                        GeneratedField::ApplyReferralCode => {
+                            if transaction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("applyReferralCode"));
+                            }
+                            transaction__ = map_.next_value::<::std::option::Option<_>>()?.map(transaction_result::Transaction::ApplyReferralCode)
+;
+                        }
+                        GeneratedField::UpdateMarginMode => {
+                            if transaction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("updateMarginMode"));
+                            }
+                            transaction__ = map_.next_value::<::std::option::Option<_>>()?.map(transaction_result::Transaction::UpdateMarginMode)
+;
+                        }
+                        GeneratedField::JoinTeam => {
+                            if transaction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("joinTeam"));
+                            }
+                            transaction__ = map_.next_value::<::std::option::Option<_>>()?.map(transaction_result::Transaction::JoinTeam)
+;
+                        }
+                        GeneratedField::BatchProposal => {
+                            if transaction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("batchProposal"));
+                            }
+                            transaction__ = map_.next_value::<::std::option::Option<_>>()?.map(transaction_result::Transaction::BatchProposal)
+;
+                        }
+                        GeneratedField::UpdatePartyProfile => {
+                            if transaction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("updatePartyProfile"));
+                            }
+                            transaction__ = map_.next_value::<::std::option::Option<_>>()?.map(transaction_result::Transaction::UpdatePartyProfile)
+;
+                        }
+                        GeneratedField::SubmitAmm => {
+                            if transaction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("submitAmm"));
+                            }
+                            transaction__ = map_.next_value::<::std::option::Option<_>>()?.map(transaction_result::Transaction::SubmitAmm)
+;
+                        }
+                        GeneratedField::AmendAmm => {
+                            if transaction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amendAmm"));
+                            }
+                            transaction__ = map_.next_value::<::std::option::Option<_>>()?.map(transaction_result::Transaction::AmendAmm)
+;
+                        }
+                        GeneratedField::CancelAmm => {
+                            if transaction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("cancelAmm"));
+                            }
+                            transaction__ = map_.next_value::<::std::option::Option<_>>()?.map(transaction_result::Transaction::CancelAmm)
+;
+                        }
+                        GeneratedField::Success => {
+                            if extra__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("success"));
+                            }
+                            extra__ = map_.next_value::<::std::option::Option<_>>()?.map(transaction_result::Extra::Success)
+;
+                        }
+                        GeneratedField::Failure => {
+                            if extra__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("failure"));
+                            }
+                            extra__ = map_.next_value::<::std::option::Option<_>>()?.map(transaction_result::Extra::Failure)
+;
+                        }
+                    }
+                }
+                Ok(TransactionResult {
+                    party_id: party_id__.unwrap_or_default(),
+                    status: status__.unwrap_or_default(),
+                    hash: hash__.unwrap_or_default(),
+                    status_detail: status_detail__.unwrap_or_default(),
+                    transaction: transaction__,
+                    extra: extra__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.TransactionResult", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for transaction_result::FailureDetails {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.error.is_empty() {
+            len += 1;
+        }
+        if !self.errors.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.TransactionResult.FailureDetails", len)?;
+        if !self.error.is_empty() {
+            struct_ser.serialize_field("error", &self.error)?;
+        }
+        if !self.errors.is_empty() {
+            struct_ser.serialize_field("errors", &self.errors)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for transaction_result::FailureDetails {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "error",
+            "errors",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Error,
+            Errors,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "error" => Ok(GeneratedField::Error),
+                            "errors" => Ok(GeneratedField::Errors),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = transaction_result::FailureDetails;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.TransactionResult.FailureDetails")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<transaction_result::FailureDetails, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut error__ = None;
+                let mut errors__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Error => {
+                            if error__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("error"));
+                            }
+                            error__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Errors => {
+                            if errors__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("errors"));
+                            }
+                            errors__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(transaction_result::FailureDetails {
+                    error: error__.unwrap_or_default(),
+                    errors: errors__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.TransactionResult.FailureDetails", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for transaction_result::KeyErrors {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.key.is_empty() {
+            len += 1;
+        }
+        if !self.errors.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.TransactionResult.KeyErrors", len)?;
+        if !self.key.is_empty() {
+            struct_ser.serialize_field("key", &self.key)?;
+        }
+        if !self.errors.is_empty() {
+            struct_ser.serialize_field("errors", &self.errors)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for transaction_result::KeyErrors {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "key",
+            "errors",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Key,
+            Errors,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "key" => Ok(GeneratedField::Key),
+                            "errors" => Ok(GeneratedField::Errors),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = transaction_result::KeyErrors;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.TransactionResult.KeyErrors")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<transaction_result::KeyErrors, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut key__ = None;
+                let mut errors__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Key => {
+                            if key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("key"));
+                            }
+                            key__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Errors => {
+                            if errors__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("errors"));
+                            }
+                            errors__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(transaction_result::KeyErrors {
+                    key: key__.unwrap_or_default(),
+                    errors: errors__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.TransactionResult.KeyErrors", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for transaction_result::Status {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "STATUS_UNSPECIFIED",
+            Self::Success => "STATUS_SUCCESS",
+            Self::PartialSuccess => "STATUS_PARTIAL_SUCCESS",
+            Self::Failure => "STATUS_FAILURE",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for transaction_result::Status {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "STATUS_UNSPECIFIED",
+            "STATUS_SUCCESS",
+            "STATUS_PARTIAL_SUCCESS",
+            "STATUS_FAILURE",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = transaction_result::Status;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "STATUS_UNSPECIFIED" => Ok(transaction_result::Status::Unspecified),
+                    "STATUS_SUCCESS" => Ok(transaction_result::Status::Success),
+                    "STATUS_PARTIAL_SUCCESS" => Ok(transaction_result::Status::PartialSuccess),
+                    "STATUS_FAILURE" => Ok(transaction_result::Status::Failure),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for transaction_result::SuccessDetails {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("vega.events.v1.TransactionResult.SuccessDetails", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for transaction_result::SuccessDetails {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                            Err(serde::de::Error::unknown_field(value, FIELDS))
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = transaction_result::SuccessDetails;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.TransactionResult.SuccessDetails")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<transaction_result::SuccessDetails, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(transaction_result::SuccessDetails {
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.TransactionResult.SuccessDetails", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Transfer {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.id.is_empty() {
+            len += 1;
+        }
+        if !self.from.is_empty() {
+            len += 1;
+        }
+        if self.from_account_type != 0 {
+            len += 1;
+        }
+        if !self.to.is_empty() {
+            len += 1;
+        }
+        if self.to_account_type != 0 {
+            len += 1;
+        }
+        if !self.asset.is_empty() {
+            len += 1;
+        }
+        if !self.amount.is_empty() {
+            len += 1;
+        }
+        if !self.reference.is_empty() {
+            len += 1;
+        }
+        if self.status != 0 {
+            len += 1;
+        }
+        if self.timestamp != 0 {
+            len += 1;
+        }
+        if self.reason.is_some() {
+            len += 1;
+        }
+        if self.game_id.is_some() {
+            len += 1;
+        }
+        if self.kind.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.Transfer", len)?;
+        if !self.id.is_empty() {
+            struct_ser.serialize_field("id", &self.id)?;
+        }
+        if !self.from.is_empty() {
+            struct_ser.serialize_field("from", &self.from)?;
+        }
+        if self.from_account_type != 0 {
+            let v = super::super::AccountType::try_from(self.from_account_type)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.from_account_type)))?;
+            struct_ser.serialize_field("fromAccountType", &v)?;
+        }
+        if !self.to.is_empty() {
+            struct_ser.serialize_field("to", &self.to)?;
+        }
+        if self.to_account_type != 0 {
+            let v = super::super::AccountType::try_from(self.to_account_type)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.to_account_type)))?;
+            struct_ser.serialize_field("toAccountType", &v)?;
+        }
+        if !self.asset.is_empty() {
+            struct_ser.serialize_field("asset", &self.asset)?;
+        }
+        if !self.amount.is_empty() {
+            struct_ser.serialize_field("amount", &self.amount)?;
+        }
+        if !self.reference.is_empty() {
+            struct_ser.serialize_field("reference", &self.reference)?;
+        }
+        if self.status != 0 {
+            let v = transfer::Status::try_from(self.status)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.status)))?;
+            struct_ser.serialize_field("status", &v)?;
+        }
+        if self.timestamp != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("timestamp", ToString::to_string(&self.timestamp).as_str())?;
+        }
+        if let Some(v) = self.reason.as_ref() {
+            struct_ser.serialize_field("reason", v)?;
+        }
+        if let Some(v) = self.game_id.as_ref() {
+            struct_ser.serialize_field("gameId", v)?;
+        }
+        if let Some(v) = self.kind.as_ref() {
+            match v {
+                transfer::Kind::OneOff(v) => {
+                    struct_ser.serialize_field("oneOff", v)?;
+                }
+                transfer::Kind::Recurring(v) => {
+                    struct_ser.serialize_field("recurring", v)?;
+                }
+                transfer::Kind::OneOffGovernance(v) => {
+                    struct_ser.serialize_field("oneOffGovernance", v)?;
+                }
+                transfer::Kind::RecurringGovernance(v) => {
+                    struct_ser.serialize_field("recurringGovernance", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Transfer {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "id",
+            "from",
+            "from_account_type",
+            "fromAccountType",
+            "to",
+            "to_account_type",
+            "toAccountType",
+            "asset",
+            "amount",
+            "reference",
+            "status",
+            "timestamp",
+            "reason",
+            "game_id",
+            "gameId",
+            "one_off",
+            "oneOff",
+            "recurring",
+            "one_off_governance",
+            "oneOffGovernance",
+            "recurring_governance",
+            "recurringGovernance",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Id,
+            From,
+            FromAccountType,
+            To,
+            ToAccountType,
+            Asset,
+            Amount,
+            Reference,
+            Status,
+            Timestamp,
+            Reason,
+            GameId,
+            OneOff,
+            Recurring,
+            OneOffGovernance,
+            RecurringGovernance,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "id" => Ok(GeneratedField::Id),
+                            "from" => Ok(GeneratedField::From),
+                            "fromAccountType" | "from_account_type" => Ok(GeneratedField::FromAccountType),
+                            "to" => Ok(GeneratedField::To),
+                            "toAccountType" | "to_account_type" => Ok(GeneratedField::ToAccountType),
+                            "asset" => Ok(GeneratedField::Asset),
+                            "amount" => Ok(GeneratedField::Amount),
+                            "reference" => Ok(GeneratedField::Reference),
+                            "status" => Ok(GeneratedField::Status),
+                            "timestamp" => Ok(GeneratedField::Timestamp),
+                            "reason" => Ok(GeneratedField::Reason),
+                            "gameId" | "game_id" => Ok(GeneratedField::GameId),
+                            "oneOff" | "one_off" => Ok(GeneratedField::OneOff),
+                            "recurring" => Ok(GeneratedField::Recurring),
+                            "oneOffGovernance" | "one_off_governance" => Ok(GeneratedField::OneOffGovernance),
+                            "recurringGovernance" | "recurring_governance" => Ok(GeneratedField::RecurringGovernance),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Transfer;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.Transfer")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Transfer, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut id__ = None;
+                let mut from__ = None;
+                let mut from_account_type__ = None;
+                let mut to__ = None;
+                let mut to_account_type__ = None;
+                let mut asset__ = None;
+                let mut amount__ = None;
+                let mut reference__ = None;
+                let mut status__ = None;
+                let mut timestamp__ = None;
+                let mut reason__ = None;
+                let mut game_id__ = None;
+                let mut kind__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::From => {
+                            if from__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("from"));
+                            }
+                            from__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::FromAccountType => {
+                            if from_account_type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fromAccountType"));
+                            }
+                            from_account_type__ = Some(map_.next_value::<super::super::AccountType>()? as i32);
+                        }
+                        GeneratedField::To => {
+                            if to__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("to"));
+                            }
+                            to__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ToAccountType => {
+                            if to_account_type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("toAccountType"));
+                            }
+                            to_account_type__ = Some(map_.next_value::<super::super::AccountType>()? as i32);
+                        }
+                        GeneratedField::Asset => {
+                            if asset__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("asset"));
+                            }
+                            asset__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Amount => {
+                            if amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amount"));
+                            }
+                            amount__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Reference => {
+                            if reference__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reference"));
+                            }
+                            reference__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Status => {
+                            if status__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("status"));
+                            }
+                            status__ = Some(map_.next_value::<transfer::Status>()? as i32);
+                        }
+                        GeneratedField::Timestamp => {
+                            if timestamp__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timestamp"));
+                            }
+                            timestamp__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Reason => {
+                            if reason__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reason"));
+                            }
+                            reason__ = map_.next_value()?;
+                        }
+                        GeneratedField::GameId => {
+                            if game_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("gameId"));
+                            }
+                            game_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::OneOff => {
+                            if kind__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("oneOff"));
+                            }
+                            kind__ = map_.next_value::<::std::option::Option<_>>()?.map(transfer::Kind::OneOff)
+;
+                        }
+                        GeneratedField::Recurring => {
+                            if kind__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("recurring"));
+                            }
+                            kind__ = map_.next_value::<::std::option::Option<_>>()?.map(transfer::Kind::Recurring)
+;
+                        }
+                        GeneratedField::OneOffGovernance => {
+                            if kind__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("oneOffGovernance"));
+                            }
+                            kind__ = map_.next_value::<::std::option::Option<_>>()?.map(transfer::Kind::OneOffGovernance)
+;
+                        }
+                        GeneratedField::RecurringGovernance => {
+                            if kind__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("recurringGovernance"));
+                            }
+                            kind__ = map_.next_value::<::std::option::Option<_>>()?.map(transfer::Kind::RecurringGovernance)
+;
+                        }
+                    }
+                }
+                Ok(Transfer {
+                    id: id__.unwrap_or_default(),
+                    from: from__.unwrap_or_default(),
+                    from_account_type: from_account_type__.unwrap_or_default(),
+                    to: to__.unwrap_or_default(),
+                    to_account_type: to_account_type__.unwrap_or_default(),
+                    asset: asset__.unwrap_or_default(),
+                    amount: amount__.unwrap_or_default(),
+                    reference: reference__.unwrap_or_default(),
+                    status: status__.unwrap_or_default(),
+                    timestamp: timestamp__.unwrap_or_default(),
+                    reason: reason__,
+                    game_id: game_id__,
+                    kind: kind__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.Transfer", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for transfer::Status {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "STATUS_UNSPECIFIED",
+            Self::Pending => "STATUS_PENDING",
+            Self::Done => "STATUS_DONE",
+            Self::Rejected => "STATUS_REJECTED",
+            Self::Stopped => "STATUS_STOPPED",
+            Self::Cancelled => "STATUS_CANCELLED",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for transfer::Status {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "STATUS_UNSPECIFIED",
+            "STATUS_PENDING",
+            "STATUS_DONE",
+            "STATUS_REJECTED",
+            "STATUS_STOPPED",
+            "STATUS_CANCELLED",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = transfer::Status;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "STATUS_UNSPECIFIED" => Ok(transfer::Status::Unspecified),
+                    "STATUS_PENDING" => Ok(transfer::Status::Pending),
+                    "STATUS_DONE" => Ok(transfer::Status::Done),
+                    "STATUS_REJECTED" => Ok(transfer::Status::Rejected),
+                    "STATUS_STOPPED" => Ok(transfer::Status::Stopped),
+                    "STATUS_CANCELLED" => Ok(transfer::Status::Cancelled),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TransferFees {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.transfer_id.is_empty() {
+            len += 1;
+        }
+        if !self.amount.is_empty() {
+            len += 1;
+        }
+        if self.epoch != 0 {
+            len += 1;
+        }
+        if !self.discount_applied.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.TransferFees", len)?;
+        if !self.transfer_id.is_empty() {
+            struct_ser.serialize_field("transferId", &self.transfer_id)?;
+        }
+        if !self.amount.is_empty() {
+            struct_ser.serialize_field("amount", &self.amount)?;
+        }
+        if self.epoch != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("epoch", ToString::to_string(&self.epoch).as_str())?;
+        }
+        if !self.discount_applied.is_empty() {
+            struct_ser.serialize_field("discountApplied", &self.discount_applied)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TransferFees {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "transfer_id",
+            "transferId",
+            "amount",
+            "epoch",
+            "discount_applied",
+            "discountApplied",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TransferId,
+            Amount,
+            Epoch,
+            DiscountApplied,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "transferId" | "transfer_id" => Ok(GeneratedField::TransferId),
+                            "amount" => Ok(GeneratedField::Amount),
+                            "epoch" => Ok(GeneratedField::Epoch),
+                            "discountApplied" | "discount_applied" => Ok(GeneratedField::DiscountApplied),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TransferFees;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.TransferFees")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TransferFees, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut transfer_id__ = None;
+                let mut amount__ = None;
+                let mut epoch__ = None;
+                let mut discount_applied__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TransferId => {
+                            if transfer_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("transferId"));
+                            }
+                            transfer_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Amount => {
+                            if amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amount"));
+                            }
+                            amount__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Epoch => {
+                            if epoch__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epoch"));
+                            }
+                            epoch__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::DiscountApplied => {
+                            if discount_applied__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("discountApplied"));
+                            }
+                            discount_applied__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(TransferFees {
+                    transfer_id: transfer_id__.unwrap_or_default(),
+                    amount: amount__.unwrap_or_default(),
+                    epoch: epoch__.unwrap_or_default(),
+                    discount_applied: discount_applied__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.TransferFees", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TransferFeesDiscount {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.party.is_empty() {
+            len += 1;
+        }
+        if !self.asset.is_empty() {
+            len += 1;
+        }
+        if !self.amount.is_empty() {
+            len += 1;
+        }
+        if self.epoch != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.TransferFeesDiscount", len)?;
+        if !self.party.is_empty() {
+            struct_ser.serialize_field("party", &self.party)?;
+        }
+        if !self.asset.is_empty() {
+            struct_ser.serialize_field("asset", &self.asset)?;
+        }
+        if !self.amount.is_empty() {
+            struct_ser.serialize_field("amount", &self.amount)?;
+        }
+        if self.epoch != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("epoch", ToString::to_string(&self.epoch).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TransferFeesDiscount {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "party",
+            "asset",
+            "amount",
+            "epoch",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Party,
+            Asset,
+            Amount,
+            Epoch,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "party" => Ok(GeneratedField::Party),
+                            "asset" => Ok(GeneratedField::Asset),
+                            "amount" => Ok(GeneratedField::Amount),
+                            "epoch" => Ok(GeneratedField::Epoch),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TransferFeesDiscount;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.TransferFeesDiscount")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TransferFeesDiscount, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut party__ = None;
+                let mut asset__ = None;
+                let mut amount__ = None;
+                let mut epoch__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Party => {
+                            if party__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("party"));
+                            }
+                            party__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Asset => {
+                            if asset__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("asset"));
+                            }
+                            asset__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Amount => {
+                            if amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amount"));
+                            }
+                            amount__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Epoch => {
+                            if epoch__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epoch"));
+                            }
+                            epoch__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(TransferFeesDiscount {
+                    party: party__.unwrap_or_default(),
+                    asset: asset__.unwrap_or_default(),
+                    amount: amount__.unwrap_or_default(),
+                    epoch: epoch__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.TransferFeesDiscount", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TxErrorEvent {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.party_id.is_empty() {
+            len += 1;
+        }
+        if !self.err_msg.is_empty() {
+            len += 1;
+        }
+        if self.transaction.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.TxErrorEvent", len)?;
+        if !self.party_id.is_empty() {
+            struct_ser.serialize_field("partyId", &self.party_id)?;
+        }
+        if !self.err_msg.is_empty() {
+            struct_ser.serialize_field("errMsg", &self.err_msg)?;
+        }
+        if let Some(v) = self.transaction.as_ref() {
+            match v {
+                tx_error_event::Transaction::OrderSubmission(v) => {
+                    struct_ser.serialize_field("orderSubmission", v)?;
+                }
+                tx_error_event::Transaction::OrderAmendment(v) => {
+                    struct_ser.serialize_field("orderAmendment", v)?;
+                }
+                tx_error_event::Transaction::OrderCancellation(v) => {
+                    struct_ser.serialize_field("orderCancellation", v)?;
+                }
+                tx_error_event::Transaction::Proposal(v) => {
+                    struct_ser.serialize_field("proposal", v)?;
+                }
+                tx_error_event::Transaction::VoteSubmission(v) => {
+                    struct_ser.serialize_field("voteSubmission", v)?;
+                }
+                tx_error_event::Transaction::LiquidityProvisionSubmission(v) => {
+                    struct_ser.serialize_field("liquidityProvisionSubmission", v)?;
+                }
+                tx_error_event::Transaction::WithdrawSubmission(v) => {
+                    struct_ser.serialize_field("withdrawSubmission", v)?;
+                }
+                tx_error_event::Transaction::DelegateSubmission(v) => {
+                    struct_ser.serialize_field("delegateSubmission", v)?;
+                }
+                tx_error_event::Transaction::UndelegateSubmission(v) => {
+                    struct_ser.serialize_field("undelegateSubmission", v)?;
+                }
+                tx_error_event::Transaction::LiquidityProvisionCancellation(v) => {
+                    struct_ser.serialize_field("liquidityProvisionCancellation", v)?;
+                }
+                tx_error_event::Transaction::LiquidityProvisionAmendment(v) => {
+                    struct_ser.serialize_field("liquidityProvisionAmendment", v)?;
+                }
+                tx_error_event::Transaction::Transfer(v) => {
+                    struct_ser.serialize_field("transfer", v)?;
+                }
+                tx_error_event::Transaction::CancelTransfer(v) => {
+                    struct_ser.serialize_field("cancelTransfer", v)?;
+                }
+                tx_error_event::Transaction::AnnounceNode(v) => {
+                    struct_ser.serialize_field("announceNode", v)?;
+                }
+                tx_error_event::Transaction::OracleDataSubmission(v) => {
+                    struct_ser.serialize_field("oracleDataSubmission", v)?;
+                }
+                tx_error_event::Transaction::ProtocolUpgradeProposal(v) => {
+                    struct_ser.serialize_field("protocolUpgradeProposal", v)?;
+                }
+                tx_error_event::Transaction::IssueSignatures(v) => {
+                    struct_ser.serialize_field("issueSignatures", v)?;
+                }
+                tx_error_event::Transaction::BatchMarketInstructions(v) => {
+                    struct_ser.serialize_field("batchMarketInstructions", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TxErrorEvent {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "party_id",
+            "partyId",
+            "err_msg",
+            "errMsg",
+            "order_submission",
+            "orderSubmission",
+            "order_amendment",
+            "orderAmendment",
+            "order_cancellation",
+            "orderCancellation",
+            "proposal",
+            "vote_submission",
+            "voteSubmission",
+            "liquidity_provision_submission",
+            "liquidityProvisionSubmission",
+            "withdraw_submission",
+            "withdrawSubmission",
+            "delegate_submission",
+            "delegateSubmission",
+            "undelegate_submission",
+            "undelegateSubmission",
+            "liquidity_provision_cancellation",
+            "liquidityProvisionCancellation",
+            "liquidity_provision_amendment",
+            "liquidityProvisionAmendment",
+            "transfer",
+            "cancel_transfer",
+            "cancelTransfer",
+            "announce_node",
+            "announceNode",
+            "oracle_data_submission",
+            "oracleDataSubmission",
+            "protocol_upgrade_proposal",
+            "protocolUpgradeProposal",
+            "issue_signatures",
+            "issueSignatures",
+            "batch_market_instructions",
+            "batchMarketInstructions",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PartyId,
+            ErrMsg,
+            OrderSubmission,
+            OrderAmendment,
+            OrderCancellation,
+            Proposal,
+            VoteSubmission,
+            LiquidityProvisionSubmission,
+            WithdrawSubmission,
+            DelegateSubmission,
+            UndelegateSubmission,
+            LiquidityProvisionCancellation,
+            LiquidityProvisionAmendment,
+            Transfer,
+            CancelTransfer,
+            AnnounceNode,
+            OracleDataSubmission,
+            ProtocolUpgradeProposal,
+            IssueSignatures,
+            BatchMarketInstructions,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "partyId" | "party_id" => Ok(GeneratedField::PartyId),
+                            "errMsg" | "err_msg" => Ok(GeneratedField::ErrMsg),
+                            "orderSubmission" | "order_submission" => Ok(GeneratedField::OrderSubmission),
+                            "orderAmendment" | "order_amendment" => Ok(GeneratedField::OrderAmendment),
+                            "orderCancellation" | "order_cancellation" => Ok(GeneratedField::OrderCancellation),
+                            "proposal" => Ok(GeneratedField::Proposal),
+                            "voteSubmission" | "vote_submission" => Ok(GeneratedField::VoteSubmission),
+                            "liquidityProvisionSubmission" | "liquidity_provision_submission" => Ok(GeneratedField::LiquidityProvisionSubmission),
+                            "withdrawSubmission" | "withdraw_submission" => Ok(GeneratedField::WithdrawSubmission),
+                            "delegateSubmission" | "delegate_submission" => Ok(GeneratedField::DelegateSubmission),
+                            "undelegateSubmission" | "undelegate_submission" => Ok(GeneratedField::UndelegateSubmission),
+                            "liquidityProvisionCancellation" | "liquidity_provision_cancellation" => Ok(GeneratedField::LiquidityProvisionCancellation),
+                            "liquidityProvisionAmendment" | "liquidity_provision_amendment" => Ok(GeneratedField::LiquidityProvisionAmendment),
+                            "transfer" => Ok(GeneratedField::Transfer),
+                            "cancelTransfer" | "cancel_transfer" => Ok(GeneratedField::CancelTransfer),
+                            "announceNode" | "announce_node" => Ok(GeneratedField::AnnounceNode),
+                            "oracleDataSubmission" | "oracle_data_submission" => Ok(GeneratedField::OracleDataSubmission),
+                            "protocolUpgradeProposal" | "protocol_upgrade_proposal" => Ok(GeneratedField::ProtocolUpgradeProposal),
+                            "issueSignatures" | "issue_signatures" => Ok(GeneratedField::IssueSignatures),
+                            "batchMarketInstructions" | "batch_market_instructions" => Ok(GeneratedField::BatchMarketInstructions),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TxErrorEvent;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.TxErrorEvent")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TxErrorEvent, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut party_id__ = None;
+                let mut err_msg__ = None;
+                let mut transaction__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PartyId => {
+                            if party_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("partyId"));
+                            }
+                            party_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ErrMsg => {
+                            if err_msg__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("errMsg"));
+                            }
+                            err_msg__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::OrderSubmission => {
+                            if transaction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("orderSubmission"));
+                            }
+                            transaction__ = map_.next_value::<::std::option::Option<_>>()?.map(tx_error_event::Transaction::OrderSubmission)
+;
+                        }
+                        GeneratedField::OrderAmendment => {
+                            if transaction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("orderAmendment"));
+                            }
+                            transaction__ = map_.next_value::<::std::option::Option<_>>()?.map(tx_error_event::Transaction::OrderAmendment)
+;
+                        }
+                        GeneratedField::OrderCancellation => {
+                            if transaction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("orderCancellation"));
+                            }
+                            transaction__ = map_.next_value::<::std::option::Option<_>>()?.map(tx_error_event::Transaction::OrderCancellation)
+;
+                        }
+                        GeneratedField::Proposal => {
+                            if transaction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposal"));
+                            }
+                            transaction__ = map_.next_value::<::std::option::Option<_>>()?.map(tx_error_event::Transaction::Proposal)
+;
+                        }
+                        GeneratedField::VoteSubmission => {
+                            if transaction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("voteSubmission"));
+                            }
+                            transaction__ = map_.next_value::<::std::option::Option<_>>()?.map(tx_error_event::Transaction::VoteSubmission)
+;
+                        }
+                        GeneratedField::LiquidityProvisionSubmission => {
+                            if transaction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("liquidityProvisionSubmission"));
+                            }
+                            transaction__ = map_.next_value::<::std::option::Option<_>>()?.map(tx_error_event::Transaction::LiquidityProvisionSubmission)
+;
+                        }
+                        GeneratedField::WithdrawSubmission => {
+                            if transaction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("withdrawSubmission"));
+                            }
+                            transaction__ = map_.next_value::<::std::option::Option<_>>()?.map(tx_error_event::Transaction::WithdrawSubmission)
+;
+                        }
+                        GeneratedField::DelegateSubmission => {
+                            if transaction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("delegateSubmission"));
+                            }
+                            transaction__ = map_.next_value::<::std::option::Option<_>>()?.map(tx_error_event::Transaction::DelegateSubmission)
+;
+                        }
+                        GeneratedField::UndelegateSubmission => {
+                            if transaction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("undelegateSubmission"));
+                            }
+                            transaction__ = map_.next_value::<::std::option::Option<_>>()?.map(tx_error_event::Transaction::UndelegateSubmission)
+;
+                        }
+                        GeneratedField::LiquidityProvisionCancellation => {
+                            if transaction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("liquidityProvisionCancellation"));
+                            }
+                            transaction__ = map_.next_value::<::std::option::Option<_>>()?.map(tx_error_event::Transaction::LiquidityProvisionCancellation)
+;
+                        }
+                        GeneratedField::LiquidityProvisionAmendment => {
+                            if transaction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("liquidityProvisionAmendment"));
+                            }
+                            transaction__ = map_.next_value::<::std::option::Option<_>>()?.map(tx_error_event::Transaction::LiquidityProvisionAmendment)
+;
+                        }
+                        GeneratedField::Transfer => {
+                            if transaction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("transfer"));
+                            }
+                            transaction__ = map_.next_value::<::std::option::Option<_>>()?.map(tx_error_event::Transaction::Transfer)
+;
+                        }
+                        GeneratedField::CancelTransfer => {
+                            if transaction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("cancelTransfer"));
+                            }
+                            transaction__ = map_.next_value::<::std::option::Option<_>>()?.map(tx_error_event::Transaction::CancelTransfer)
+;
+                        }
+                        GeneratedField::AnnounceNode => {
+                            if transaction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("announceNode"));
+                            }
+                            transaction__ = map_.next_value::<::std::option::Option<_>>()?.map(tx_error_event::Transaction::AnnounceNode)
+;
+                        }
+                        GeneratedField::OracleDataSubmission => {
+                            if transaction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("oracleDataSubmission"));
+                            }
+                            transaction__ = map_.next_value::<::std::option::Option<_>>()?.map(tx_error_event::Transaction::OracleDataSubmission)
+;
+                        }
+                        GeneratedField::ProtocolUpgradeProposal => {
+                            if transaction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("protocolUpgradeProposal"));
+                            }
+                            transaction__ = map_.next_value::<::std::option::Option<_>>()?.map(tx_error_event::Transaction::ProtocolUpgradeProposal)
+;
+                        }
+                        GeneratedField::IssueSignatures => {
+                            if transaction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("issueSignatures"));
+                            }
+                            transaction__ = map_.next_value::<::std::option::Option<_>>()?.map(tx_error_event::Transaction::IssueSignatures)
+;
+                        }
+                        GeneratedField::BatchMarketInstructions => {
+                            if transaction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("batchMarketInstructions"));
+                            }
+                            transaction__ = map_.next_value::<::std::option::Option<_>>()?.map(tx_error_event::Transaction::BatchMarketInstructions)
+;
+                        }
+                    }
+                }
+                Ok(TxErrorEvent {
+                    party_id: party_id__.unwrap_or_default(),
+                    err_msg: err_msg__.unwrap_or_default(),
+                    transaction: transaction__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.TxErrorEvent", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ValidatorRankingEvent {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.node_id.is_empty() {
+            len += 1;
+        }
+        if !self.stake_score.is_empty() {
+            len += 1;
+        }
+        if !self.performance_score.is_empty() {
+            len += 1;
+        }
+        if !self.ranking_score.is_empty() {
+            len += 1;
+        }
+        if !self.previous_status.is_empty() {
+            len += 1;
+        }
+        if !self.next_status.is_empty() {
+            len += 1;
+        }
+        if !self.epoch_seq.is_empty() {
+            len += 1;
+        }
+        if self.tm_voting_power != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.ValidatorRankingEvent", len)?;
+        if !self.node_id.is_empty() {
+            struct_ser.serialize_field("nodeId", &self.node_id)?;
+        }
+        if !self.stake_score.is_empty() {
+            struct_ser.serialize_field("stakeScore", &self.stake_score)?;
+        }
+        if !self.performance_score.is_empty() {
+            struct_ser.serialize_field("performanceScore", &self.performance_score)?;
+        }
+        if !self.ranking_score.is_empty() {
+            struct_ser.serialize_field("rankingScore", &self.ranking_score)?;
+        }
+        if !self.previous_status.is_empty() {
+            struct_ser.serialize_field("previousStatus", &self.previous_status)?;
+        }
+        if !self.next_status.is_empty() {
+            struct_ser.serialize_field("nextStatus", &self.next_status)?;
+        }
+        if !self.epoch_seq.is_empty() {
+            struct_ser.serialize_field("epochSeq", &self.epoch_seq)?;
+        }
+        if self.tm_voting_power != 0 {
+            struct_ser.serialize_field("tmVotingPower", &self.tm_voting_power)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValidatorRankingEvent {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "node_id",
+            "nodeId",
+            "stake_score",
+            "stakeScore",
+            "performance_score",
+            "performanceScore",
+            "ranking_score",
+            "rankingScore",
+            "previous_status",
+            "previousStatus",
+            "next_status",
+            "nextStatus",
+            "epoch_seq",
+            "epochSeq",
+            "tm_voting_power",
+            "tmVotingPower",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            NodeId,
+            StakeScore,
+            PerformanceScore,
+            RankingScore,
+            PreviousStatus,
+            NextStatus,
+            EpochSeq,
+            TmVotingPower,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "nodeId" | "node_id" => Ok(GeneratedField::NodeId),
+                            "stakeScore" | "stake_score" => Ok(GeneratedField::StakeScore),
+                            "performanceScore" | "performance_score" => Ok(GeneratedField::PerformanceScore),
+                            "rankingScore" | "ranking_score" => Ok(GeneratedField::RankingScore),
+                            "previousStatus" | "previous_status" => Ok(GeneratedField::PreviousStatus),
+                            "nextStatus" | "next_status" => Ok(GeneratedField::NextStatus),
+                            "epochSeq" | "epoch_seq" => Ok(GeneratedField::EpochSeq),
+                            "tmVotingPower" | "tm_voting_power" => Ok(GeneratedField::TmVotingPower),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ValidatorRankingEvent;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.ValidatorRankingEvent")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorRankingEvent, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut node_id__ = None;
+                let mut stake_score__ = None;
+                let mut performance_score__ = None;
+                let mut ranking_score__ = None;
+                let mut previous_status__ = None;
+                let mut next_status__ = None;
+                let mut epoch_seq__ = None;
+                let mut tm_voting_power__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::NodeId => {
+                            if node_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nodeId"));
+                            }
+                            node_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::StakeScore => {
+                            if stake_score__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stakeScore"));
+                            }
+                            stake_score__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::PerformanceScore => {
+                            if performance_score__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("performanceScore"));
+                            }
+                            performance_score__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::RankingScore => {
+                            if ranking_score__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rankingScore"));
+                            }
+                            ranking_score__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::PreviousStatus => {
+                            if previous_status__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("previousStatus"));
+                            }
+                            previous_status__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::NextStatus => {
+                            if next_status__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nextStatus"));
+                            }
+                            next_status__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::EpochSeq => {
+                            if epoch_seq__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epochSeq"));
+                            }
+                            epoch_seq__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::TmVotingPower => {
+                            if tm_voting_power__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tmVotingPower"));
+                            }
+                            tm_voting_power__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(ValidatorRankingEvent {
+                    node_id: node_id__.unwrap_or_default(),
+                    stake_score: stake_score__.unwrap_or_default(),
+                    performance_score: performance_score__.unwrap_or_default(),
+                    ranking_score: ranking_score__.unwrap_or_default(),
+                    previous_status: previous_status__.unwrap_or_default(),
+                    next_status: next_status__.unwrap_or_default(),
+                    epoch_seq: epoch_seq__.unwrap_or_default(),
+                    tm_voting_power: tm_voting_power__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.ValidatorRankingEvent", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ValidatorScoreEvent {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.node_id.is_empty() {
+            len += 1;
+        }
+        if !self.epoch_seq.is_empty() {
+            len += 1;
+        }
+        if !self.validator_score.is_empty() {
+            len += 1;
+        }
+        if !self.normalised_score.is_empty() {
+            len += 1;
+        }
+        if !self.validator_performance.is_empty() {
+            len += 1;
+        }
+        if !self.raw_validator_score.is_empty() {
+            len += 1;
+        }
+        if !self.validator_status.is_empty() {
+            len += 1;
+        }
+        if !self.multisig_score.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.ValidatorScoreEvent", len)?;
+        if !self.node_id.is_empty() {
+            struct_ser.serialize_field("nodeId", &self.node_id)?;
+        }
+        if !self.epoch_seq.is_empty() {
+            struct_ser.serialize_field("epochSeq", &self.epoch_seq)?;
+        }
+        if !self.validator_score.is_empty() {
+            struct_ser.serialize_field("validatorScore", &self.validator_score)?;
+        }
+        if !self.normalised_score.is_empty() {
+            struct_ser.serialize_field("normalisedScore", &self.normalised_score)?;
+        }
+        if !self.validator_performance.is_empty() {
+            struct_ser.serialize_field("validatorPerformance", &self.validator_performance)?;
+        }
+        if !self.raw_validator_score.is_empty() {
+            struct_ser.serialize_field("rawValidatorScore", &self.raw_validator_score)?;
+        }
+        if !self.validator_status.is_empty() {
+            struct_ser.serialize_field("validatorStatus", &self.validator_status)?;
+        }
+        if !self.multisig_score.is_empty() {
+            struct_ser.serialize_field("multisigScore", &self.multisig_score)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValidatorScoreEvent {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "node_id",
+            "nodeId",
+            "epoch_seq",
+            "epochSeq",
+            "validator_score",
+            "validatorScore",
+            "normalised_score",
+            "normalisedScore",
+            "validator_performance",
+            "validatorPerformance",
+            "raw_validator_score",
+            "rawValidatorScore",
+            "validator_status",
+            "validatorStatus",
+            "multisig_score",
+            "multisigScore",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            NodeId,
+            EpochSeq,
+            ValidatorScore,
+            NormalisedScore,
+            ValidatorPerformance,
+            RawValidatorScore,
+            ValidatorStatus,
+            MultisigScore,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "nodeId" | "node_id" => Ok(GeneratedField::NodeId),
+                            "epochSeq" | "epoch_seq" => Ok(GeneratedField::EpochSeq),
+                            "validatorScore" | "validator_score" => Ok(GeneratedField::ValidatorScore),
+                            "normalisedScore" | "normalised_score" => Ok(GeneratedField::NormalisedScore),
+                            "validatorPerformance" | "validator_performance" => Ok(GeneratedField::ValidatorPerformance),
+                            "rawValidatorScore" | "raw_validator_score" => Ok(GeneratedField::RawValidatorScore),
+                            "validatorStatus" | "validator_status" => Ok(GeneratedField::ValidatorStatus),
+                            "multisigScore" | "multisig_score" => Ok(GeneratedField::MultisigScore),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ValidatorScoreEvent;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.ValidatorScoreEvent")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorScoreEvent, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut node_id__ = None;
+                let mut epoch_seq__ = None;
+                let mut validator_score__ = None;
+                let mut normalised_score__ = None;
+                let mut validator_performance__ = None;
+                let mut raw_validator_score__ = None;
+                let mut validator_status__ = None;
+                let mut multisig_score__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::NodeId => {
+                            if node_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nodeId"));
+                            }
+                            node_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::EpochSeq => {
+                            if epoch_seq__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epochSeq"));
+                            }
+                            epoch_seq__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ValidatorScore => {
+                            if validator_score__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("validatorScore"));
+                            }
+                            validator_score__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::NormalisedScore => {
+                            if normalised_score__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("normalisedScore"));
+                            }
+                            normalised_score__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ValidatorPerformance => {
+                            if validator_performance__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("validatorPerformance"));
+                            }
+                            validator_performance__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::RawValidatorScore => {
+                            if raw_validator_score__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rawValidatorScore"));
+                            }
+                            raw_validator_score__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ValidatorStatus => {
+                            if validator_status__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("validatorStatus"));
+                            }
+                            validator_status__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::MultisigScore => {
+                            if multisig_score__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("multisigScore"));
+                            }
+                            multisig_score__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(ValidatorScoreEvent {
+                    node_id: node_id__.unwrap_or_default(),
+                    epoch_seq: epoch_seq__.unwrap_or_default(),
+                    validator_score: validator_score__.unwrap_or_default(),
+                    normalised_score: normalised_score__.unwrap_or_default(),
+                    validator_performance: validator_performance__.unwrap_or_default(),
+                    raw_validator_score: raw_validator_score__.unwrap_or_default(),
+                    validator_status: validator_status__.unwrap_or_default(),
+                    multisig_score: multisig_score__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.ValidatorScoreEvent", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ValidatorUpdate {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.node_id.is_empty() {
+            len += 1;
+        }
+        if !self.vega_pub_key.is_empty() {
+            len += 1;
+        }
+        if !self.ethereum_address.is_empty() {
+            len += 1;
+        }
+        if !self.tm_pub_key.is_empty() {
+            len += 1;
+        }
+        if !self.info_url.is_empty() {
+            len += 1;
+        }
+        if !self.country.is_empty() {
+            len += 1;
+        }
+        if !self.name.is_empty() {
+            len += 1;
+        }
+        if !self.avatar_url.is_empty() {
+            len += 1;
+        }
+        if self.vega_pub_key_index != 0 {
+            len += 1;
+        }
+        if self.added {
+            len += 1;
+        }
+        if self.from_epoch != 0 {
+            len += 1;
+        }
+        if !self.submitter_address.is_empty() {
+            len += 1;
+        }
+        if self.epoch_seq != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.ValidatorUpdate", len)?;
+        if !self.node_id.is_empty() {
+            struct_ser.serialize_field("nodeId", &self.node_id)?;
+        }
+        if !self.vega_pub_key.is_empty() {
+            struct_ser.serialize_field("vegaPubKey", &self.vega_pub_key)?;
+        }
+        if !self.ethereum_address.is_empty() {
+            struct_ser.serialize_field("ethereumAddress", &self.ethereum_address)?;
+        }
+        if !self.tm_pub_key.is_empty() {
+            struct_ser.serialize_field("tmPubKey", &self.tm_pub_key)?;
+        }
+        if !self.info_url.is_empty() {
+            struct_ser.serialize_field("infoUrl", &self.info_url)?;
+        }
+        if !self.country.is_empty() {
+            struct_ser.serialize_field("country", &self.country)?;
+        }
+        if !self.name.is_empty() {
+            struct_ser.serialize_field("name", &self.name)?;
+        }
+        if !self.avatar_url.is_empty() {
+            struct_ser.serialize_field("avatarUrl", &self.avatar_url)?;
+        }
+        if self.vega_pub_key_index != 0 {
+            struct_ser.serialize_field("vegaPubKeyIndex", &self.vega_pub_key_index)?;
+        }
+        if self.added {
+            struct_ser.serialize_field("added", &self.added)?;
+        }
+        if self.from_epoch != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("fromEpoch", ToString::to_string(&self.from_epoch).as_str())?;
+        }
+        if !self.submitter_address.is_empty() {
+            struct_ser.serialize_field("submitterAddress", &self.submitter_address)?;
+        }
+        if self.epoch_seq != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("epochSeq", ToString::to_string(&self.epoch_seq).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValidatorUpdate {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "node_id",
+            "nodeId",
+            "vega_pub_key",
+            "vegaPubKey",
+            "ethereum_address",
+            "ethereumAddress",
+            "tm_pub_key",
+            "tmPubKey",
+            "info_url",
+            "infoUrl",
+            "country",
+            "name",
+            "avatar_url",
+            "avatarUrl",
+            "vega_pub_key_index",
+            "vegaPubKeyIndex",
+            "added",
+            "from_epoch",
+            "fromEpoch",
+            "submitter_address",
+            "submitterAddress",
+            "epoch_seq",
+            "epochSeq",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            NodeId,
+            VegaPubKey,
+            EthereumAddress,
+            TmPubKey,
+            InfoUrl,
+            Country,
+            Name,
+            AvatarUrl,
+            VegaPubKeyIndex,
+            Added,
+            FromEpoch,
+            SubmitterAddress,
+            EpochSeq,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "nodeId" | "node_id" => Ok(GeneratedField::NodeId),
+                            "vegaPubKey" | "vega_pub_key" => Ok(GeneratedField::VegaPubKey),
+                            "ethereumAddress" | "ethereum_address" => Ok(GeneratedField::EthereumAddress),
+                            "tmPubKey" | "tm_pub_key" => Ok(GeneratedField::TmPubKey),
+                            "infoUrl" | "info_url" => Ok(GeneratedField::InfoUrl),
+                            "country" => Ok(GeneratedField::Country),
+                            "name" => Ok(GeneratedField::Name),
+                            "avatarUrl" | "avatar_url" => Ok(GeneratedField::AvatarUrl),
+                            "vegaPubKeyIndex" | "vega_pub_key_index" => Ok(GeneratedField::VegaPubKeyIndex),
+                            "added" => Ok(GeneratedField::Added),
+                            "fromEpoch" | "from_epoch" => Ok(GeneratedField::FromEpoch),
+                            "submitterAddress" | "submitter_address" => Ok(GeneratedField::SubmitterAddress),
+                            "epochSeq" | "epoch_seq" => Ok(GeneratedField::EpochSeq),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ValidatorUpdate;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.ValidatorUpdate")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorUpdate, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut node_id__ = None;
+                let mut vega_pub_key__ = None;
+                let mut ethereum_address__ = None;
+                let mut tm_pub_key__ = None;
+                let mut info_url__ = None;
+                let mut country__ = None;
+                let mut name__ = None;
+                let mut avatar_url__ = None;
+                let mut vega_pub_key_index__ = None;
+                let mut added__ = None;
+                let mut from_epoch__ = None;
+                let mut submitter_address__ = None;
+                let mut epoch_seq__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::NodeId => {
+                            if node_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nodeId"));
+                            }
+                            node_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::VegaPubKey => {
+                            if vega_pub_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("vegaPubKey"));
+                            }
+                            vega_pub_key__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::EthereumAddress => {
+                            if ethereum_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ethereumAddress"));
+                            }
+                            ethereum_address__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::TmPubKey => {
+                            if tm_pub_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tmPubKey"));
+                            }
+                            tm_pub_key__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::InfoUrl => {
+                            if info_url__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("infoUrl"));
+                            }
+                            info_url__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Country => {
+                            if country__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("country"));
+                            }
+                            country__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Name => {
+                            if name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("name"));
+                            }
+                            name__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::AvatarUrl => {
+                            if avatar_url__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("avatarUrl"));
+                            }
+                            avatar_url__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::VegaPubKeyIndex => {
+                            if vega_pub_key_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("vegaPubKeyIndex"));
+                            }
+                            vega_pub_key_index__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Added => {
+                            if added__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("added"));
+                            }
+                            added__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::FromEpoch => {
+                            if from_epoch__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fromEpoch"));
+                            }
+                            from_epoch__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::SubmitterAddress => {
+                            if submitter_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("submitterAddress"));
+                            }
+                            submitter_address__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::EpochSeq => {
+                            if epoch_seq__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epochSeq"));
+                            }
+                            epoch_seq__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(ValidatorUpdate {
+                    node_id: node_id__.unwrap_or_default(),
+                    vega_pub_key: vega_pub_key__.unwrap_or_default(),
+                    ethereum_address: ethereum_address__.unwrap_or_default(),
+                    tm_pub_key: tm_pub_key__.unwrap_or_default(),
+                    info_url: info_url__.unwrap_or_default(),
+                    country: country__.unwrap_or_default(),
+                    name: name__.unwrap_or_default(),
+                    avatar_url: avatar_url__.unwrap_or_default(),
+                    vega_pub_key_index: vega_pub_key_index__.unwrap_or_default(),
+                    added: added__.unwrap_or_default(),
+                    from_epoch: from_epoch__.unwrap_or_default(),
+                    submitter_address: submitter_address__.unwrap_or_default(),
+                    epoch_seq: epoch_seq__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.ValidatorUpdate", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for VestingBalancesSummary {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.epoch_seq != 0 {
+            len += 1;
+        }
+        if !self.parties_vesting_summary.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.VestingBalancesSummary", len)?;
+        if self.epoch_seq != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("epochSeq", ToString::to_string(&self.epoch_seq).as_str())?;
+        }
+        if !self.parties_vesting_summary.is_empty() {
+            struct_ser.serialize_field("partiesVestingSummary", &self.parties_vesting_summary)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for VestingBalancesSummary {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "epoch_seq",
+            "epochSeq",
+            "parties_vesting_summary",
+            "partiesVestingSummary",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            EpochSeq,
+            PartiesVestingSummary,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "epochSeq" | "epoch_seq" => Ok(GeneratedField::EpochSeq),
+                            "partiesVestingSummary" | "parties_vesting_summary" => Ok(GeneratedField::PartiesVestingSummary),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = VestingBalancesSummary;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.VestingBalancesSummary")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<VestingBalancesSummary, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut epoch_seq__ = None;
+                let mut parties_vesting_summary__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::EpochSeq => {
+                            if epoch_seq__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epochSeq"));
+                            }
+                            epoch_seq__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::PartiesVestingSummary => {
+                            if parties_vesting_summary__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("partiesVestingSummary"));
+                            }
+                            parties_vesting_summary__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(VestingBalancesSummary {
+                    epoch_seq: epoch_seq__.unwrap_or_default(),
+                    parties_vesting_summary: parties_vesting_summary__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.VestingBalancesSummary", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for VestingStatsUpdated {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.at_epoch != 0 {
+            len += 1;
+        }
+        if !self.stats.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.VestingStatsUpdated", len)?;
+        if self.at_epoch != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("atEpoch", ToString::to_string(&self.at_epoch).as_str())?;
+        }
+        if !self.stats.is_empty() {
+            struct_ser.serialize_field("stats", &self.stats)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for VestingStatsUpdated {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "at_epoch",
+            "atEpoch",
+            "stats",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            AtEpoch,
+            Stats,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "atEpoch" | "at_epoch" => Ok(GeneratedField::AtEpoch),
+                            "stats" => Ok(GeneratedField::Stats),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = VestingStatsUpdated;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.VestingStatsUpdated")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<VestingStatsUpdated, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut at_epoch__ = None;
+                let mut stats__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::AtEpoch => {
+                            if at_epoch__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("atEpoch"));
+                            }
+                            at_epoch__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Stats => {
+                            if stats__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stats"));
+                            }
+                            stats__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(VestingStatsUpdated {
+                    at_epoch: at_epoch__.unwrap_or_default(),
+                    stats: stats__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.VestingStatsUpdated", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for VolumeDiscountProgramEnded {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.version != 0 {
+            len += 1;
+        }
+        if !self.id.is_empty() {
+            len += 1;
+        }
+        if self.ended_at != 0 {
+            len += 1;
+        }
+        if self.at_epoch != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.VolumeDiscountProgramEnded", len)?;
+        if self.version != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("version", ToString::to_string(&self.version).as_str())?;
+        }
+        if !self.id.is_empty() {
+            struct_ser.serialize_field("id", &self.id)?;
+        }
+        if self.ended_at != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("endedAt", ToString::to_string(&self.ended_at).as_str())?;
+        }
+        if self.at_epoch != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("atEpoch", ToString::to_string(&self.at_epoch).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for VolumeDiscountProgramEnded {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "version",
+            "id",
+            "ended_at",
+            "endedAt",
+            "at_epoch",
+            "atEpoch",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Version,
+            Id,
+            EndedAt,
+            AtEpoch,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "version" => Ok(GeneratedField::Version),
+                            "id" => Ok(GeneratedField::Id),
+                            "endedAt" | "ended_at" => Ok(GeneratedField::EndedAt),
+                            "atEpoch" | "at_epoch" => Ok(GeneratedField::AtEpoch),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = VolumeDiscountProgramEnded;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.VolumeDiscountProgramEnded")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<VolumeDiscountProgramEnded, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut version__ = None;
+                let mut id__ = None;
+                let mut ended_at__ = None;
+                let mut at_epoch__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Version => {
+                            if version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("version"));
+                            }
+                            version__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::EndedAt => {
+                            if ended_at__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endedAt"));
+                            }
+                            ended_at__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::AtEpoch => {
+                            if at_epoch__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("atEpoch"));
+                            }
+                            at_epoch__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(VolumeDiscountProgramEnded {
+                    version: version__.unwrap_or_default(),
+                    id: id__.unwrap_or_default(),
+                    ended_at: ended_at__.unwrap_or_default(),
+                    at_epoch: at_epoch__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.VolumeDiscountProgramEnded", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for VolumeDiscountProgramStarted {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.program.is_some() {
+            len += 1;
+        }
+        if self.started_at != 0 {
+            len += 1;
+        }
+        if self.at_epoch != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.VolumeDiscountProgramStarted", len)?;
+        if let Some(v) = self.program.as_ref() {
+            struct_ser.serialize_field("program", v)?;
+        }
+        if self.started_at != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("startedAt", ToString::to_string(&self.started_at).as_str())?;
+        }
+        if self.at_epoch != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("atEpoch", ToString::to_string(&self.at_epoch).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for VolumeDiscountProgramStarted {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "program",
+            "started_at",
+            "startedAt",
+            "at_epoch",
+            "atEpoch",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Program,
+            StartedAt,
+            AtEpoch,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "program" => Ok(GeneratedField::Program),
+                            "startedAt" | "started_at" => Ok(GeneratedField::StartedAt),
+                            "atEpoch" | "at_epoch" => Ok(GeneratedField::AtEpoch),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = VolumeDiscountProgramStarted;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.VolumeDiscountProgramStarted")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<VolumeDiscountProgramStarted, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut program__ = None;
+                let mut started_at__ = None;
+                let mut at_epoch__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Program => {
+                            if program__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("program"));
+                            }
+                            program__ = map_.next_value()?;
+                        }
+                        GeneratedField::StartedAt => {
+                            if started_at__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startedAt"));
+                            }
+                            started_at__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::AtEpoch => {
+                            if at_epoch__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("atEpoch"));
+                            }
+                            at_epoch__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(VolumeDiscountProgramStarted {
+                    program: program__,
+                    started_at: started_at__.unwrap_or_default(),
+                    at_epoch: at_epoch__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.VolumeDiscountProgramStarted", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for VolumeDiscountProgramUpdated {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.program.is_some() {
+            len += 1;
+        }
+        if self.updated_at != 0 {
+            len += 1;
+        }
+        if self.at_epoch != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.VolumeDiscountProgramUpdated", len)?;
+        if let Some(v) = self.program.as_ref() {
+            struct_ser.serialize_field("program", v)?;
+        }
+        if self.updated_at != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("updatedAt", ToString::to_string(&self.updated_at).as_str())?;
+        }
+        if self.at_epoch != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("atEpoch", ToString::to_string(&self.at_epoch).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for VolumeDiscountProgramUpdated {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "program",
+            "updated_at",
+            "updatedAt",
+            "at_epoch",
+            "atEpoch",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Program,
+            UpdatedAt,
+            AtEpoch,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "program" => Ok(GeneratedField::Program),
+                            "updatedAt" | "updated_at" => Ok(GeneratedField::UpdatedAt),
+                            "atEpoch" | "at_epoch" => Ok(GeneratedField::AtEpoch),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = VolumeDiscountProgramUpdated;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.VolumeDiscountProgramUpdated")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<VolumeDiscountProgramUpdated, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut program__ = None;
+                let mut updated_at__ = None;
+                let mut at_epoch__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Program => {
+                            if program__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("program"));
+                            }
+                            program__ = map_.next_value()?;
+                        }
+                        GeneratedField::UpdatedAt => {
+                            if updated_at__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("updatedAt"));
+                            }
+                            updated_at__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::AtEpoch => {
+                            if at_epoch__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("atEpoch"));
+                            }
+                            at_epoch__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(VolumeDiscountProgramUpdated {
+                    program: program__,
+                    updated_at: updated_at__.unwrap_or_default(),
+                    at_epoch: at_epoch__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.VolumeDiscountProgramUpdated", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for VolumeDiscountStatsUpdated {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.at_epoch != 0 {
+            len += 1;
+        }
+        if !self.stats.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.VolumeDiscountStatsUpdated", len)?;
+        if self.at_epoch != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("atEpoch", ToString::to_string(&self.at_epoch).as_str())?;
+        }
+        if !self.stats.is_empty() {
+            struct_ser.serialize_field("stats", &self.stats)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for VolumeDiscountStatsUpdated {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "at_epoch",
+            "atEpoch",
+            "stats",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            AtEpoch,
+            Stats,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "atEpoch" | "at_epoch" => Ok(GeneratedField::AtEpoch),
+                            "stats" => Ok(GeneratedField::Stats),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = VolumeDiscountStatsUpdated;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.VolumeDiscountStatsUpdated")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<VolumeDiscountStatsUpdated, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut at_epoch__ = None;
+                let mut stats__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::AtEpoch => {
+                            if at_epoch__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("atEpoch"));
+                            }
+                            at_epoch__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Stats => {
+                            if stats__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stats"));
+                            }
+                            stats__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(VolumeDiscountStatsUpdated {
+                    at_epoch: at_epoch__.unwrap_or_default(),
+                    stats: stats__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.VolumeDiscountStatsUpdated", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for VolumeRebateProgramEnded {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.version != 0 {
+            len += 1;
+        }
+        if !self.id.is_empty() {
+            len += 1;
+        }
+        if self.ended_at != 0 {
+            len += 1;
+        }
+        if self.at_epoch != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.VolumeRebateProgramEnded", len)?;
+        if self.version != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("version", ToString::to_string(&self.version).as_str())?;
+        }
+        if !self.id.is_empty() {
+            struct_ser.serialize_field("id", &self.id)?;
+        }
+        if self.ended_at != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("endedAt", ToString::to_string(&self.ended_at).as_str())?;
+        }
+        if self.at_epoch != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("atEpoch", ToString::to_string(&self.at_epoch).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for VolumeRebateProgramEnded {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "version",
+            "id",
+            "ended_at",
+            "endedAt",
+            "at_epoch",
+            "atEpoch",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Version,
+            Id,
+            EndedAt,
+            AtEpoch,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "version" => Ok(GeneratedField::Version),
+                            "id" => Ok(GeneratedField::Id),
+                            "endedAt" | "ended_at" => Ok(GeneratedField::EndedAt),
+                            "atEpoch" | "at_epoch" => Ok(GeneratedField::AtEpoch),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = VolumeRebateProgramEnded;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.VolumeRebateProgramEnded")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<VolumeRebateProgramEnded, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut version__ = None;
+                let mut id__ = None;
+                let mut ended_at__ = None;
+                let mut at_epoch__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Version => {
+                            if version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("version"));
+                            }
+                            version__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::EndedAt => {
+                            if ended_at__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endedAt"));
+                            }
+                            ended_at__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::AtEpoch => {
+                            if at_epoch__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("atEpoch"));
+                            }
+                            at_epoch__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(VolumeRebateProgramEnded {
+                    version: version__.unwrap_or_default(),
+                    id: id__.unwrap_or_default(),
+                    ended_at: ended_at__.unwrap_or_default(),
+                    at_epoch: at_epoch__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.VolumeRebateProgramEnded", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for VolumeRebateProgramStarted {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.program.is_some() {
+            len += 1;
+        }
+        if self.started_at != 0 {
+            len += 1;
+        }
+        if self.at_epoch != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.VolumeRebateProgramStarted", len)?;
+        if let Some(v) = self.program.as_ref() {
+            struct_ser.serialize_field("program", v)?;
+        }
+        if self.started_at != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("startedAt", ToString::to_string(&self.started_at).as_str())?;
+        }
+        if self.at_epoch != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("atEpoch", ToString::to_string(&self.at_epoch).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for VolumeRebateProgramStarted {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "program",
+            "started_at",
+            "startedAt",
+            "at_epoch",
+            "atEpoch",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Program,
+            StartedAt,
+            AtEpoch,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "program" => Ok(GeneratedField::Program),
+                            "startedAt" | "started_at" => Ok(GeneratedField::StartedAt),
+                            "atEpoch" | "at_epoch" => Ok(GeneratedField::AtEpoch),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = VolumeRebateProgramStarted;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.VolumeRebateProgramStarted")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<VolumeRebateProgramStarted, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut program__ = None;
+                let mut started_at__ = None;
+                let mut at_epoch__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Program => {
+                            if program__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("program"));
+                            }
+                            program__ = map_.next_value()?;
+                        }
+                        GeneratedField::StartedAt => {
+                            if started_at__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startedAt"));
+                            }
+                            started_at__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::AtEpoch => {
+                            if at_epoch__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("atEpoch"));
+                            }
+                            at_epoch__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(VolumeRebateProgramStarted {
+                    program: program__,
+                    started_at: started_at__.unwrap_or_default(),
+                    at_epoch: at_epoch__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.VolumeRebateProgramStarted", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for VolumeRebateProgramUpdated {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.program.is_some() {
+            len += 1;
+        }
+        if self.updated_at != 0 {
+            len += 1;
+        }
+        if self.at_epoch != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.VolumeRebateProgramUpdated", len)?;
+        if let Some(v) = self.program.as_ref() {
+            struct_ser.serialize_field("program", v)?;
+        }
+        if self.updated_at != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("updatedAt", ToString::to_string(&self.updated_at).as_str())?;
+        }
+        if self.at_epoch != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("atEpoch", ToString::to_string(&self.at_epoch).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for VolumeRebateProgramUpdated {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "program",
+            "updated_at",
+            "updatedAt",
+            "at_epoch",
+            "atEpoch",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Program,
+            UpdatedAt,
+            AtEpoch,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "program" => Ok(GeneratedField::Program),
+                            "updatedAt" | "updated_at" => Ok(GeneratedField::UpdatedAt),
+                            "atEpoch" | "at_epoch" => Ok(GeneratedField::AtEpoch),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = VolumeRebateProgramUpdated;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.VolumeRebateProgramUpdated")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<VolumeRebateProgramUpdated, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut program__ = None;
+                let mut updated_at__ = None;
+                let mut at_epoch__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Program => {
+                            if program__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("program"));
+                            }
+                            program__ = map_.next_value()?;
+                        }
+                        GeneratedField::UpdatedAt => {
+                            if updated_at__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("updatedAt"));
+                            }
+                            updated_at__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::AtEpoch => {
+                            if at_epoch__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("atEpoch"));
+                            }
+                            at_epoch__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(VolumeRebateProgramUpdated {
+                    program: program__,
+                    updated_at: updated_at__.unwrap_or_default(),
+                    at_epoch: at_epoch__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.VolumeRebateProgramUpdated", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for VolumeRebateStatsUpdated {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.at_epoch != 0 {
+            len += 1;
+        }
+        if !self.stats.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.events.v1.VolumeRebateStatsUpdated", len)?;
+        if self.at_epoch != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("atEpoch", ToString::to_string(&self.at_epoch).as_str())?;
+        }
+        if !self.stats.is_empty() {
+            struct_ser.serialize_field("stats", &self.stats)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for VolumeRebateStatsUpdated {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "at_epoch",
+            "atEpoch",
+            "stats",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            AtEpoch,
+            Stats,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "atEpoch" | "at_epoch" => Ok(GeneratedField::AtEpoch),
+                            "stats" => Ok(GeneratedField::Stats),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = VolumeRebateStatsUpdated;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.events.v1.VolumeRebateStatsUpdated")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<VolumeRebateStatsUpdated, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut at_epoch__ = None;
+                let mut stats__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::AtEpoch => {
+                            if at_epoch__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("atEpoch"));
+                            }
+                            at_epoch__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Stats => {
+                            if stats__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stats"));
+                            }
+                            stats__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(VolumeRebateStatsUpdated {
+                    at_epoch: at_epoch__.unwrap_or_default(),
+                    stats: stats__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.events.v1.VolumeRebateStatsUpdated", FIELDS, GeneratedVisitor)
+    }
+}
@@ -0,0 +1,7319 @@
+// This file is @generated by prost-build.
+/// Heartbeat represents that the ERC20 bridge is being watched even though no events happened.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Erc20Heartbeat {
+    /// Contract address that is being listened to
+    #[prost(string, tag = "1")]
+    pub contract_address: ::prost::alloc::string::String,
+    /// Last Ethereum block height that was checked for events
+    #[prost(uint64, tag = "2")]
+    pub block_height: u64,
+    /// Ethereum block time in Unix seconds.
+    #[prost(uint64, tag = "3")]
+    pub block_time: u64,
+    /// Source chain for this chain event.
+    #[prost(string, tag = "6")]
+    pub source_chain_id: ::prost::alloc::string::String,
+}
+/// Result of calling an arbitrary Ethereum contract method
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EthContractCallEvent {
+    /// ID of the data source spec that triggered this contract call.
+    #[prost(string, tag = "1")]
+    pub spec_id: ::prost::alloc::string::String,
+    /// Ethereum block height.
+    #[prost(uint64, tag = "2")]
+    pub block_height: u64,
+    /// Ethereum block time in Unix seconds.
+    #[prost(uint64, tag = "3")]
+    pub block_time: u64,
+    /// Result of contract call, packed according to the ABI stored in the associated data source spec.
+    #[prost(bytes = "vec", tag = "4")]
+    pub result: ::prost::alloc::vec::Vec<u8>,
+    /// Error message if the call failed.
+    #[prost(string, optional, tag = "5")]
+    pub error: ::core::option::Option<::prost::alloc::string::String>,
+    /// Source chain for this chain event.
+    #[prost(uint64, optional, tag = "6")]
+    pub source_chain_id: ::core::option::Option<u64>,
+    /// If true the event does not correspond to a contract call and is only a notification to core of the last checked block height.
+    #[prost(bool, tag = "7")]
+    pub heartbeat: bool,
+}
+/// Deposit for a Vega built-in asset
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BuiltinAssetDeposit {
+    /// Vega network internal asset ID.
+    #[prost(string, tag = "1")]
+    pub vega_asset_id: ::prost::alloc::string::String,
+    /// Vega party ID i.e. public key.
+    #[prost(string, tag = "2")]
+    pub party_id: ::prost::alloc::string::String,
+    /// Amount to be deposited. This field is an unsigned integer scaled to the asset's decimal places.
+    #[prost(string, tag = "3")]
+    pub amount: ::prost::alloc::string::String,
+}
+/// Withdrawal for a Vega built-in asset
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BuiltinAssetWithdrawal {
+    /// Vega network internal asset ID.
+    #[prost(string, tag = "1")]
+    pub vega_asset_id: ::prost::alloc::string::String,
+    /// Vega network party ID i.e. public key.
+    #[prost(string, tag = "2")]
+    pub party_id: ::prost::alloc::string::String,
+    /// The amount to be withdrawn. This field is an unsigned integer scaled to the asset's decimal places.
+    #[prost(string, tag = "3")]
+    pub amount: ::prost::alloc::string::String,
+}
+/// Event related to a Vega built-in asset
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BuiltinAssetEvent {
+    #[prost(oneof = "builtin_asset_event::Action", tags = "1001, 1002")]
+    pub action: ::core::option::Option<builtin_asset_event::Action>,
+}
+/// Nested message and enum types in `BuiltinAssetEvent`.
+pub mod builtin_asset_event {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Action {
+        /// Built-in asset deposit.
+        #[prost(message, tag = "1001")]
+        Deposit(super::BuiltinAssetDeposit),
+        /// Built-in asset withdrawal.
+        #[prost(message, tag = "1002")]
+        Withdrawal(super::BuiltinAssetWithdrawal),
+    }
+}
+/// Asset allow-listing for an ERC20 token
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Erc20AssetList {
+    /// Vega network internal asset ID.
+    #[prost(string, tag = "1")]
+    pub vega_asset_id: ::prost::alloc::string::String,
+    /// Ethereum address of the asset.
+    #[prost(string, tag = "2")]
+    pub asset_source: ::prost::alloc::string::String,
+}
+/// Asset deny-listing for an ERC20 token
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Erc20AssetDelist {
+    /// Vega network internal asset ID.
+    #[prost(string, tag = "1")]
+    pub vega_asset_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Erc20AssetLimitsUpdated {
+    /// Vega network internal asset ID.
+    #[prost(string, tag = "1")]
+    pub vega_asset_id: ::prost::alloc::string::String,
+    /// Ethereum wallet that initiated the deposit.
+    #[prost(string, tag = "2")]
+    pub source_ethereum_address: ::prost::alloc::string::String,
+    /// Updated lifetime limits.
+    #[prost(string, tag = "3")]
+    pub lifetime_limits: ::prost::alloc::string::String,
+    /// Updated withdrawal threshold.
+    #[prost(string, tag = "4")]
+    pub withdraw_threshold: ::prost::alloc::string::String,
+}
+/// Asset deposit for an ERC20 token
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Erc20Deposit {
+    /// Vega network internal asset ID.
+    #[prost(string, tag = "1")]
+    pub vega_asset_id: ::prost::alloc::string::String,
+    /// Ethereum wallet that initiated the deposit.
+    #[prost(string, tag = "2")]
+    pub source_ethereum_address: ::prost::alloc::string::String,
+    /// Vega party ID i.e. public key that is the target of the deposit.
+    #[prost(string, tag = "3")]
+    pub target_party_id: ::prost::alloc::string::String,
+    /// Amount to be deposited.
+    #[prost(string, tag = "4")]
+    pub amount: ::prost::alloc::string::String,
+}
+/// Asset withdrawal for an ERC20 token
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Erc20Withdrawal {
+    /// Vega network internal asset ID.
+    #[prost(string, tag = "1")]
+    pub vega_asset_id: ::prost::alloc::string::String,
+    /// Target Ethereum wallet address.
+    #[prost(string, tag = "2")]
+    pub target_ethereum_address: ::prost::alloc::string::String,
+    /// Reference nonce used for the transaction.
+    #[prost(string, tag = "3")]
+    pub reference_nonce: ::prost::alloc::string::String,
+}
+/// Event related to an ERC20 token
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Erc20Event {
+    /// Index of the log in the transaction.
+    #[prost(uint64, tag = "1")]
+    pub index: u64,
+    /// Block in which the transaction was added.
+    #[prost(uint64, tag = "2")]
+    pub block: u64,
+    /// ID of the source chain for this event.
+    #[prost(string, tag = "3")]
+    pub chain_id: ::prost::alloc::string::String,
+    /// Action undertaken as a result of the event.
+    #[prost(
+        oneof = "erc20_event::Action",
+        tags = "1001, 1002, 1003, 1004, 1005, 1006, 1007"
+    )]
+    pub action: ::core::option::Option<erc20_event::Action>,
+}
+/// Nested message and enum types in `ERC20Event`.
+pub mod erc20_event {
+    /// Action undertaken as a result of the event.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Action {
+        /// List an ERC20 asset.
+        #[prost(message, tag = "1001")]
+        AssetList(super::Erc20AssetList),
+        /// De-list an ERC20 asset.
+        #[prost(message, tag = "1002")]
+        AssetDelist(super::Erc20AssetDelist),
+        /// Deposit ERC20 asset.
+        #[prost(message, tag = "1003")]
+        Deposit(super::Erc20Deposit),
+        /// Withdraw ERC20 asset.
+        #[prost(message, tag = "1004")]
+        Withdrawal(super::Erc20Withdrawal),
+        /// Update an ERC20 asset.
+        #[prost(message, tag = "1005")]
+        AssetLimitsUpdated(super::Erc20AssetLimitsUpdated),
+        /// Bridge operations has been stopped.
+        #[prost(bool, tag = "1006")]
+        BridgeStopped(bool),
+        /// Bridge operations has been resumed.
+        #[prost(bool, tag = "1007")]
+        BridgeResumed(bool),
+    }
+}
+/// New signer added to the ERC20 bridge
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Erc20SignerAdded {
+    /// Ethereum address of the new signer
+    #[prost(string, tag = "1")]
+    pub new_signer: ::prost::alloc::string::String,
+    /// Nonce created by the Vega network used for this new signer
+    #[prost(string, tag = "2")]
+    pub nonce: ::prost::alloc::string::String,
+    /// Time at which the block was produced
+    /// will be used to inform the core at what time
+    /// the stake was made unavailable.
+    #[prost(int64, tag = "3")]
+    pub block_time: i64,
+}
+/// Signer removed from the ERC20 bridge
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Erc20SignerRemoved {
+    /// Ethereum address of the old signer
+    #[prost(string, tag = "1")]
+    pub old_signer: ::prost::alloc::string::String,
+    /// Nonce created by the Vega network used for this old signer
+    #[prost(string, tag = "2")]
+    pub nonce: ::prost::alloc::string::String,
+    /// Time at which the block was produced.
+    /// Will be used to inform the core at what time
+    /// the stake was made unavailable.
+    #[prost(int64, tag = "3")]
+    pub block_time: i64,
+}
+/// Threshold has been updated on the multisig control
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Erc20ThresholdSet {
+    /// New threshold value to set
+    #[prost(uint32, tag = "1")]
+    pub new_threshold: u32,
+    /// Nonce created by the Vega network
+    #[prost(string, tag = "2")]
+    pub nonce: ::prost::alloc::string::String,
+    /// Time at which the block was produced.
+    /// Will be used to inform the core at what time
+    /// the stake was made unavailable.
+    #[prost(int64, tag = "3")]
+    pub block_time: i64,
+}
+/// Event related to the ERC20 MultiSig
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Erc20MultiSigEvent {
+    /// Index of the log in the transaction
+    #[prost(uint64, tag = "1")]
+    pub index: u64,
+    /// Block in which the transaction was added
+    #[prost(uint64, tag = "2")]
+    pub block: u64,
+    /// ID of the source chain for this event.
+    #[prost(string, tag = "3")]
+    pub chain_id: ::prost::alloc::string::String,
+    /// Action undertaken as a result of the event.
+    #[prost(oneof = "erc20_multi_sig_event::Action", tags = "1001, 1002, 1003")]
+    pub action: ::core::option::Option<erc20_multi_sig_event::Action>,
+}
+/// Nested message and enum types in `ERC20MultiSigEvent`.
+pub mod erc20_multi_sig_event {
+    /// Action undertaken as a result of the event.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Action {
+        /// Add a signer to the erc20 bridge
+        #[prost(message, tag = "1001")]
+        SignerAdded(super::Erc20SignerAdded),
+        /// Remove a signer from the erc20 bridge
+        #[prost(message, tag = "1002")]
+        SignerRemoved(super::Erc20SignerRemoved),
+        /// Threshold set
+        #[prost(message, tag = "1003")]
+        ThresholdSet(super::Erc20ThresholdSet),
+    }
+}
+/// Event related to staking on the Vega network.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StakingEvent {
+    /// Index of the log in the transaction.
+    #[prost(uint64, tag = "1")]
+    pub index: u64,
+    /// Block in which the transaction was added.
+    #[prost(uint64, tag = "2")]
+    pub block: u64,
+    /// Action undertaken by the event.
+    #[prost(oneof = "staking_event::Action", tags = "1001, 1002, 1003")]
+    pub action: ::core::option::Option<staking_event::Action>,
+}
+/// Nested message and enum types in `StakingEvent`.
+pub mod staking_event {
+    /// Action undertaken by the event.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Action {
+        #[prost(message, tag = "1001")]
+        StakeDeposited(super::StakeDeposited),
+        #[prost(message, tag = "1002")]
+        StakeRemoved(super::StakeRemoved),
+        #[prost(message, tag = "1003")]
+        TotalSupply(super::StakeTotalSupply),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StakeDeposited {
+    /// Ethereum Address of the user depositing stake (hex encode with 0x prefix)
+    #[prost(string, tag = "1")]
+    pub ethereum_address: ::prost::alloc::string::String,
+    /// Hex encoded public key of the party receiving the stake deposit.
+    #[prost(string, tag = "2")]
+    pub vega_public_key: ::prost::alloc::string::String,
+    /// Amount deposited as an unsigned base 10 integer scaled to the asset's decimal places.
+    #[prost(string, tag = "3")]
+    pub amount: ::prost::alloc::string::String,
+    /// Time at which the block was produced.
+    /// Will be used to inform the core at what time
+    /// the stake started to be available.
+    #[prost(int64, tag = "4")]
+    pub block_time: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StakeRemoved {
+    /// Ethereum address of the user removing stake. This should be hex encoded with 0x prefix.
+    #[prost(string, tag = "1")]
+    pub ethereum_address: ::prost::alloc::string::String,
+    /// Hex encoded public key of the party from which to remove stake.
+    #[prost(string, tag = "2")]
+    pub vega_public_key: ::prost::alloc::string::String,
+    /// Amount removed as a base 10 unsigned integer scaled to the asset's decimal places.
+    #[prost(string, tag = "3")]
+    pub amount: ::prost::alloc::string::String,
+    /// The time at which the block was produced
+    /// will be used to inform the core at what time
+    /// the stake was made unavailable.
+    #[prost(int64, tag = "4")]
+    pub block_time: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StakeTotalSupply {
+    /// Address of the staking asset
+    #[prost(string, tag = "1")]
+    pub token_address: ::prost::alloc::string::String,
+    /// Total supply observed for the token as an unsigned based 10 integer scaled to the asset's decimal places.
+    #[prost(string, tag = "2")]
+    pub total_supply: ::prost::alloc::string::String,
+}
+/// Represents the top level object that handles data sources.
+/// Data source definition can be external or internal, with whatever
+/// number of data sources are defined for each type in the child objects below.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DataSourceDefinition {
+    #[prost(oneof = "data_source_definition::SourceType", tags = "1, 2")]
+    pub source_type: ::core::option::Option<data_source_definition::SourceType>,
+}
+/// Nested message and enum types in `DataSourceDefinition`.
+pub mod data_source_definition {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum SourceType {
+        #[prost(message, tag = "1")]
+        Internal(super::DataSourceDefinitionInternal),
+        #[prost(message, tag = "2")]
+        External(super::DataSourceDefinitionExternal),
+    }
+}
+/// Describes which property of the data source data is to be
+/// used for price source.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SpecBindingForCompositePrice {
+    /// The property name of price.
+    #[prost(string, tag = "1")]
+    pub price_source_property: ::prost::alloc::string::String,
+}
+/// Internal data source used for emitting timestamps.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DataSourceSpecConfigurationTime {
+    /// Conditions that the timestamps should meet in order to be considered.
+    #[prost(message, repeated, tag = "1")]
+    pub conditions: ::prost::alloc::vec::Vec<data::v1::Condition>,
+}
+/// Internal data source used for emitting timestamps automatically using predefined intervals and conditions.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DataSourceSpecConfigurationTimeTrigger {
+    /// Conditions that the timestamps need to meet in order to be considered.
+    #[prost(message, repeated, tag = "1")]
+    pub conditions: ::prost::alloc::vec::Vec<data::v1::Condition>,
+    /// An internal time trigger
+    #[prost(message, repeated, tag = "2")]
+    pub triggers: ::prost::alloc::vec::Vec<data::v1::InternalTimeTrigger>,
+}
+/// Top level object used for all internal data sources.
+/// It contains one of any of the defined source type variants.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DataSourceDefinitionInternal {
+    /// Types of internal data sources
+    #[prost(oneof = "data_source_definition_internal::SourceType", tags = "1, 2")]
+    pub source_type: ::core::option::Option<data_source_definition_internal::SourceType>,
+}
+/// Nested message and enum types in `DataSourceDefinitionInternal`.
+pub mod data_source_definition_internal {
+    /// Types of internal data sources
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum SourceType {
+        #[prost(message, tag = "1")]
+        Time(super::DataSourceSpecConfigurationTime),
+        #[prost(message, tag = "2")]
+        TimeTrigger(super::DataSourceSpecConfigurationTimeTrigger),
+    }
+}
+/// DataSourceDefinitionExternal is the top level object used for all external
+/// data sources. It contains one of any of the defined `SourceType` variants.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DataSourceDefinitionExternal {
+    /// Types of External data sources
+    #[prost(oneof = "data_source_definition_external::SourceType", tags = "1, 2")]
+    pub source_type: ::core::option::Option<data_source_definition_external::SourceType>,
+}
+/// Nested message and enum types in `DataSourceDefinitionExternal`.
+pub mod data_source_definition_external {
+    /// Types of External data sources
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum SourceType {
+        #[prost(message, tag = "1")]
+        Oracle(super::DataSourceSpecConfiguration),
+        /// Contains the data specification that is received from Ethereum sources.
+        #[prost(message, tag = "2")]
+        EthOracle(super::EthCallSpec),
+    }
+}
+/// All types of external data sources use the same configuration set for meeting
+/// requirements in order for the data to be useful for Vega - valid signatures
+/// and matching filters.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DataSourceSpecConfiguration {
+    /// Signers is the list of authorized signatures that signed the data for this
+    /// source. All the signatures in the data source data should be contained in
+    /// this external source. All the signatures in the data should be contained in
+    /// this list.
+    #[prost(message, repeated, tag = "1")]
+    pub signers: ::prost::alloc::vec::Vec<data::v1::Signer>,
+    /// Filters describes which source data are considered of interest or not for
+    /// the product (or the risk model).
+    #[prost(message, repeated, tag = "2")]
+    pub filters: ::prost::alloc::vec::Vec<data::v1::Filter>,
+}
+/// Specifies a data source that derives its content from calling a read method
+/// on an Ethereum contract.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EthCallSpec {
+    /// Ethereum address of the contract to call.
+    #[prost(string, tag = "1")]
+    pub address: ::prost::alloc::string::String,
+    /// The ABI of that contract.
+    #[prost(string, tag = "2")]
+    pub abi: ::prost::alloc::string::String,
+    /// Name of the method on the contract to call.
+    #[prost(string, tag = "3")]
+    pub method: ::prost::alloc::string::String,
+    /// List of arguments to pass to method call.
+    /// Protobuf 'Value' wraps an arbitrary JSON type that is mapped to an Ethereum
+    /// type according to the ABI.
+    #[prost(message, repeated, tag = "4")]
+    pub args: ::prost::alloc::vec::Vec<crate::google::protobuf::Value>,
+    /// Conditions for determining when to call the contract method.
+    #[prost(message, optional, tag = "5")]
+    pub trigger: ::core::option::Option<EthCallTrigger>,
+    /// Number of confirmations required before the query is considered verified
+    #[prost(uint64, tag = "6")]
+    pub required_confirmations: u64,
+    /// Filters the data returned from the contract method
+    #[prost(message, repeated, tag = "7")]
+    pub filters: ::prost::alloc::vec::Vec<data::v1::Filter>,
+    /// Normalisers are used to convert the data returned from the contract method
+    /// into a standard format. The key of the map is the name of the property,
+    /// which identifies the specific piece of data to other parts of the data
+    /// sourcing framework, for example filters. The value is a JSONPath expression
+    /// for expressing where in the contract call result the required data is
+    /// located, for example $\[0\] indicates the first result. $\[1\].price would look
+    /// in the second result returned from the contract for a structure with a key
+    /// called 'price' and use that if it exists.
+    #[prost(message, repeated, tag = "8")]
+    pub normalisers: ::prost::alloc::vec::Vec<Normaliser>,
+    /// The ID of the EVM based chain which is to be used to source the oracle data.
+    #[prost(uint64, tag = "9")]
+    pub source_chain_id: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Normaliser {
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub expression: ::prost::alloc::string::String,
+}
+/// Determines when the contract method should be called.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EthCallTrigger {
+    #[prost(oneof = "eth_call_trigger::Trigger", tags = "1")]
+    pub trigger: ::core::option::Option<eth_call_trigger::Trigger>,
+}
+/// Nested message and enum types in `EthCallTrigger`.
+pub mod eth_call_trigger {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Trigger {
+        #[prost(message, tag = "1")]
+        TimeTrigger(super::EthTimeTrigger),
+    }
+}
+/// Trigger for an Ethereum call based on the Ethereum block timestamp. Can be
+/// one-off or repeating.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EthTimeTrigger {
+    /// Trigger when the Ethereum time is greater or equal to this time, in Unix
+    /// seconds.
+    #[prost(uint64, optional, tag = "1")]
+    pub initial: ::core::option::Option<u64>,
+    /// Repeat the call every n seconds after the initial call. If no time for
+    /// initial call was specified, begin repeating immediately.
+    #[prost(uint64, optional, tag = "2")]
+    pub every: ::core::option::Option<u64>,
+    /// If repeating, stop once Ethereum time is greater than this time, in Unix
+    /// seconds. If not set, then repeat indefinitely.
+    #[prost(uint64, optional, tag = "3")]
+    pub until: ::core::option::Option<u64>,
+}
+/// Data source spec describes the data source base that a product or a risk
+/// model wants to get from the data source engine. This message contains
+/// additional information used by the API.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DataSourceSpec {
+    /// Hash generated from the DataSpec data.
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Creation date and time
+    #[prost(int64, tag = "2")]
+    pub created_at: i64,
+    /// Last Updated timestamp
+    #[prost(int64, tag = "3")]
+    pub updated_at: i64,
+    #[prost(message, optional, tag = "4")]
+    pub data: ::core::option::Option<DataSourceDefinition>,
+    /// Status describes the status of the data source spec
+    #[prost(enumeration = "data_source_spec::Status", tag = "5")]
+    pub status: i32,
+}
+/// Nested message and enum types in `DataSourceSpec`.
+pub mod data_source_spec {
+    /// Status describe the status of the data source spec
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Status {
+        /// Default value.
+        Unspecified = 0,
+        /// STATUS_ACTIVE describes an active data source spec.
+        Active = 1,
+        /// STATUS_DEACTIVATED describes a data source spec that is not listening to
+        /// data anymore.
+        Deactivated = 2,
+    }
+    impl Status {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Status::Unspecified => "STATUS_UNSPECIFIED",
+                Status::Active => "STATUS_ACTIVE",
+                Status::Deactivated => "STATUS_DEACTIVATED",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "STATUS_UNSPECIFIED" => Some(Self::Unspecified),
+                "STATUS_ACTIVE" => Some(Self::Active),
+                "STATUS_DEACTIVATED" => Some(Self::Deactivated),
+                _ => None,
+            }
+        }
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExternalDataSourceSpec {
+    #[prost(message, optional, tag = "1")]
+    pub spec: ::core::option::Option<DataSourceSpec>,
+}
+/// Auction duration is used to configure 3 auction periods:
+/// 1. `duration > 0`, `volume == 0`:
+///    The auction will last for at least N seconds
+/// 2. `duration == 0`, `volume > 0`:
+///    The auction will end once the given volume will match at uncrossing
+/// 3. `duration > 0`, `volume > 0`:
+///    The auction will take at least N seconds, but can end sooner if the market can trade a certain volume
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AuctionDuration {
+    /// Duration of the auction in seconds.
+    #[prost(int64, tag = "1")]
+    pub duration: i64,
+    /// Target uncrossing trading volume.
+    #[prost(uint64, tag = "2")]
+    pub volume: u64,
+}
+/// Spot product definition
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Spot {
+    /// Asset ID of the underlying base asset for the spot product.
+    #[prost(string, tag = "1")]
+    pub base_asset: ::prost::alloc::string::String,
+    /// Asset ID of the underlying quote asset for the spot product.
+    #[prost(string, tag = "2")]
+    pub quote_asset: ::prost::alloc::string::String,
+}
+/// Future product definition
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Future {
+    /// Underlying asset for the future.
+    #[prost(string, tag = "1")]
+    pub settlement_asset: ::prost::alloc::string::String,
+    /// Quote name of the instrument.
+    #[prost(string, tag = "2")]
+    pub quote_name: ::prost::alloc::string::String,
+    /// Data source specification that describes the settlement data source filter.
+    #[prost(message, optional, tag = "3")]
+    pub data_source_spec_for_settlement_data: ::core::option::Option<DataSourceSpec>,
+    /// Data source specification that describes the trading termination data source filter.
+    #[prost(message, optional, tag = "4")]
+    pub data_source_spec_for_trading_termination: ::core::option::Option<DataSourceSpec>,
+    /// Binding between the data spec and the data source.
+    #[prost(message, optional, tag = "5")]
+    pub data_source_spec_binding: ::core::option::Option<DataSourceSpecToFutureBinding>,
+    /// If set, this product represents a capped future market.
+    #[prost(message, optional, tag = "6")]
+    pub cap: ::core::option::Option<FutureCap>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FutureCap {
+    /// Set the maximum price for orders, and settlement data in market decimals.
+    #[prost(string, tag = "1")]
+    pub max_price: ::prost::alloc::string::String,
+    /// If set to true, the settlement price must either be zero, or equal to the max price.
+    #[prost(bool, optional, tag = "2")]
+    pub binary_settlement: ::core::option::Option<bool>,
+    /// If set to true, positions must be fully collateralised so there is no default risk for any party.
+    #[prost(bool, optional, tag = "3")]
+    pub fully_collateralised: ::core::option::Option<bool>,
+}
+/// Perpetual product definition
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Perpetual {
+    /// Underlying asset for the perpetual.
+    #[prost(string, tag = "1")]
+    pub settlement_asset: ::prost::alloc::string::String,
+    /// Quote name of the instrument.
+    #[prost(string, tag = "2")]
+    pub quote_name: ::prost::alloc::string::String,
+    /// Controls how much the upcoming funding payment liability contributes to party's margin, in the range \[0, 1\].
+    #[prost(string, tag = "3")]
+    pub margin_funding_factor: ::prost::alloc::string::String,
+    /// Continuously compounded interest rate used in funding rate calculation, in the range \[-1, 1\].
+    #[prost(string, tag = "4")]
+    pub interest_rate: ::prost::alloc::string::String,
+    /// Lower bound for the clamp function used as part of the funding rate calculation, in the range \[-1, 1\].
+    #[prost(string, tag = "5")]
+    pub clamp_lower_bound: ::prost::alloc::string::String,
+    /// Upper bound for the clamp function used as part of the funding rate calculation, in the range \[-1, 1\].
+    #[prost(string, tag = "6")]
+    pub clamp_upper_bound: ::prost::alloc::string::String,
+    /// Data source spec describing the data source for settlement schedule.
+    #[prost(message, optional, tag = "7")]
+    pub data_source_spec_for_settlement_schedule: ::core::option::Option<DataSourceSpec>,
+    /// Data source spec describing the data source for settlement.
+    #[prost(message, optional, tag = "8")]
+    pub data_source_spec_for_settlement_data: ::core::option::Option<DataSourceSpec>,
+    /// Binding between the data source spec and the settlement data.
+    #[prost(message, optional, tag = "9")]
+    pub data_source_spec_binding: ::core::option::Option<
+        DataSourceSpecToPerpetualBinding,
+    >,
+    /// Factor applied to funding-rates. This scales the impact that spot price deviations have on funding payments.
+    #[prost(string, optional, tag = "10")]
+    pub funding_rate_scaling_factor: ::core::option::Option<
+        ::prost::alloc::string::String,
+    >,
+    /// Lower bound for the funding-rate such that the funding-rate will never be lower than this value.
+    #[prost(string, optional, tag = "11")]
+    pub funding_rate_lower_bound: ::core::option::Option<::prost::alloc::string::String>,
+    /// Upper bound for the funding-rate such that the funding-rate will never be higher than this value.
+    #[prost(string, optional, tag = "12")]
+    pub funding_rate_upper_bound: ::core::option::Option<::prost::alloc::string::String>,
+    /// Optional configuration for the internal composite price used in funding payment calculation.
+    #[prost(message, optional, tag = "13")]
+    pub internal_composite_price_config: ::core::option::Option<
+        CompositePriceConfiguration,
+    >,
+}
+/// DataSourceSpecToFutureBinding describes which property of the data source data is to be
+/// used as settlement data and which to use as the trading terminated trigger
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DataSourceSpecToFutureBinding {
+    /// Name of the property in the source data that should be used as settlement data.
+    /// If it is set to "prices.BTC.value", then the Future will use the value of
+    /// this property as settlement data.
+    #[prost(string, tag = "1")]
+    pub settlement_data_property: ::prost::alloc::string::String,
+    /// Name of the property in the data source data that signals termination of trading.
+    #[prost(string, tag = "2")]
+    pub trading_termination_property: ::prost::alloc::string::String,
+}
+/// Describes which properties of the data source data is to be
+/// used for settlement.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DataSourceSpecToPerpetualBinding {
+    /// Name of the property in the source data that should be used for settlement data.
+    /// If it is set to "prices.BTC.value" for example, then the perpetual market will use the value of
+    /// this property to get settlement data.
+    #[prost(string, tag = "1")]
+    pub settlement_data_property: ::prost::alloc::string::String,
+    /// Name of the property in the source data that should be used to determine the perpetual's settlement schedule.
+    #[prost(string, tag = "2")]
+    pub settlement_schedule_property: ::prost::alloc::string::String,
+}
+/// Instrument metadata definition
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InstrumentMetadata {
+    /// List of 0 or more tags.
+    #[prost(string, repeated, tag = "1")]
+    pub tags: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// Instrument definition
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Instrument {
+    /// Unique instrument ID.
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Code for the instrument.
+    #[prost(string, tag = "2")]
+    pub code: ::prost::alloc::string::String,
+    /// Name of the instrument.
+    #[prost(string, tag = "3")]
+    pub name: ::prost::alloc::string::String,
+    /// Collection of instrument meta-data.
+    #[prost(message, optional, tag = "4")]
+    pub metadata: ::core::option::Option<InstrumentMetadata>,
+    /// Product the instrument is composed of.
+    #[prost(oneof = "instrument::Product", tags = "100, 101, 102")]
+    pub product: ::core::option::Option<instrument::Product>,
+}
+/// Nested message and enum types in `Instrument`.
+pub mod instrument {
+    /// Product the instrument is composed of.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Product {
+        /// Future.
+        #[prost(message, tag = "100")]
+        Future(super::Future),
+        /// Spot.
+        #[prost(message, tag = "101")]
+        Spot(super::Spot),
+        /// Perpetual.
+        #[prost(message, tag = "102")]
+        Perpetual(super::Perpetual),
+    }
+}
+/// Risk model for log normal
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LogNormalRiskModel {
+    /// Risk Aversion Parameter.
+    #[prost(double, tag = "1")]
+    pub risk_aversion_parameter: f64,
+    /// Tau parameter of the risk model, projection horizon measured as a year fraction used in the expected shortfall
+    /// calculation to obtain the maintenance margin, must be a strictly non-negative real number.
+    #[prost(double, tag = "2")]
+    pub tau: f64,
+    /// Risk model parameters for log normal.
+    #[prost(message, optional, tag = "3")]
+    pub params: ::core::option::Option<LogNormalModelParams>,
+    /// And optional override for the risk factor calculated by the risk model.
+    #[prost(message, optional, tag = "4")]
+    pub risk_factor_override: ::core::option::Option<RiskFactorOverride>,
+}
+/// Risk factor override to control stable leverage
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RiskFactorOverride {
+    /// Short Risk factor value.
+    #[prost(string, tag = "1")]
+    pub short: ::prost::alloc::string::String,
+    /// Long Risk factor value.
+    #[prost(string, tag = "2")]
+    pub long: ::prost::alloc::string::String,
+}
+/// Risk model parameters for log normal
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LogNormalModelParams {
+    /// Mu parameter, annualised growth rate of the underlying asset.
+    #[prost(double, tag = "1")]
+    pub mu: f64,
+    /// R parameter, annualised growth rate of the risk-free asset, used for discounting of future cash flows, can be any real number.
+    #[prost(double, tag = "2")]
+    pub r: f64,
+    /// Sigma parameter, annualised volatility of the underlying asset, must be a strictly non-negative real number.
+    #[prost(double, tag = "3")]
+    pub sigma: f64,
+}
+/// Risk model for simple modelling
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SimpleRiskModel {
+    /// Risk model params for simple modelling.
+    #[prost(message, optional, tag = "1")]
+    pub params: ::core::option::Option<SimpleModelParams>,
+}
+/// Risk model parameters for simple modelling
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SimpleModelParams {
+    /// Pre-defined risk factor value for long.
+    #[prost(double, tag = "1")]
+    pub factor_long: f64,
+    /// Pre-defined risk factor value for short.
+    #[prost(double, tag = "2")]
+    pub factor_short: f64,
+    /// Pre-defined maximum price move up that the model considers as valid.
+    #[prost(double, tag = "3")]
+    pub max_move_up: f64,
+    /// Pre-defined minimum price move down that the model considers as valid.
+    #[prost(double, tag = "4")]
+    pub min_move_down: f64,
+    /// Pre-defined constant probability of trading.
+    #[prost(double, tag = "5")]
+    pub probability_of_trading: f64,
+}
+/// Scaling Factors (for use in margin calculation)
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ScalingFactors {
+    /// Collateral search level. If collateral dips below this value,
+    /// the system will search for collateral to release.
+    #[prost(double, tag = "1")]
+    pub search_level: f64,
+    /// Initial margin level. This is the minimum amount of collateral
+    /// required to open a position in a market that requires margin.
+    #[prost(double, tag = "2")]
+    pub initial_margin: f64,
+    /// Collateral release level. If a trader has collateral above this level,
+    /// the system will release collateral to a trader's general collateral account
+    /// for the asset.
+    #[prost(double, tag = "3")]
+    pub collateral_release: f64,
+}
+/// Margin Calculator definition
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MarginCalculator {
+    /// Scaling factors for margin calculation.
+    #[prost(message, optional, tag = "1")]
+    pub scaling_factors: ::core::option::Option<ScalingFactors>,
+    /// If set to true, positions must be fully collateralised so there is no default risk for any party (capped futures).
+    #[prost(bool, optional, tag = "2")]
+    pub fully_collateralised: ::core::option::Option<bool>,
+}
+/// Tradable Instrument definition
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TradableInstrument {
+    /// Details for the underlying instrument.
+    #[prost(message, optional, tag = "1")]
+    pub instrument: ::core::option::Option<Instrument>,
+    /// Margin calculator for the instrument.
+    #[prost(message, optional, tag = "2")]
+    pub margin_calculator: ::core::option::Option<MarginCalculator>,
+    /// Risk model for use by the instrument.
+    #[prost(oneof = "tradable_instrument::RiskModel", tags = "100, 101")]
+    pub risk_model: ::core::option::Option<tradable_instrument::RiskModel>,
+}
+/// Nested message and enum types in `TradableInstrument`.
+pub mod tradable_instrument {
+    /// Risk model for use by the instrument.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum RiskModel {
+        /// Log normal.
+        #[prost(message, tag = "100")]
+        LogNormalRiskModel(super::LogNormalRiskModel),
+        /// Simple.
+        #[prost(message, tag = "101")]
+        SimpleRiskModel(super::SimpleRiskModel),
+    }
+}
+/// Fee factors definition
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FeeFactors {
+    /// Market maker fee charged network wide.
+    #[prost(string, tag = "1")]
+    pub maker_fee: ::prost::alloc::string::String,
+    /// Infrastructure fee charged network wide for staking and governance.
+    #[prost(string, tag = "2")]
+    pub infrastructure_fee: ::prost::alloc::string::String,
+    /// Liquidity fee applied per market for market making.
+    #[prost(string, tag = "3")]
+    pub liquidity_fee: ::prost::alloc::string::String,
+    /// Fees sent to network treasury for later use based on governance actions (network wide).
+    #[prost(string, tag = "4")]
+    pub treasury_fee: ::prost::alloc::string::String,
+    /// Fees used to purchase governance tokens via regular auctions (network wide).
+    #[prost(string, tag = "5")]
+    pub buy_back_fee: ::prost::alloc::string::String,
+}
+/// Fees definition
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Fees {
+    /// Fee factors.
+    #[prost(message, optional, tag = "1")]
+    pub factors: ::core::option::Option<FeeFactors>,
+    /// Liquidity fee settings for the market describing how the fee was calculated.
+    #[prost(message, optional, tag = "2")]
+    pub liquidity_fee_settings: ::core::option::Option<LiquidityFeeSettings>,
+}
+/// PriceMonitoringTrigger holds together price projection horizon τ, probability level p, and auction extension duration
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PriceMonitoringTrigger {
+    /// Price monitoring projection horizon τ in seconds.
+    #[prost(int64, tag = "1")]
+    pub horizon: i64,
+    /// Price monitoring probability level p.
+    #[prost(string, tag = "2")]
+    pub probability: ::prost::alloc::string::String,
+    /// Price monitoring auction extension duration in seconds should the price
+    /// breach its theoretical level over the specified horizon at the specified
+    /// probability level.
+    #[prost(int64, tag = "3")]
+    pub auction_extension: i64,
+}
+/// PriceMonitoringParameters contains a collection of triggers to be used for a given market
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PriceMonitoringParameters {
+    #[prost(message, repeated, tag = "1")]
+    pub triggers: ::prost::alloc::vec::Vec<PriceMonitoringTrigger>,
+}
+/// PriceMonitoringSettings contains the settings for price monitoring
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PriceMonitoringSettings {
+    /// Specifies price monitoring parameters to be used for price monitoring purposes.
+    #[prost(message, optional, tag = "1")]
+    pub parameters: ::core::option::Option<PriceMonitoringParameters>,
+}
+/// LiquidityMonitoringParameters contains settings used for liquidity monitoring
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidityMonitoringParameters {
+    /// Specifies parameters related to target stake calculation.
+    #[prost(message, optional, tag = "1")]
+    pub target_stake_parameters: ::core::option::Option<TargetStakeParameters>,
+    /// Specifies the triggering ratio for entering liquidity auction.
+    #[prost(string, tag = "2")]
+    pub triggering_ratio: ::prost::alloc::string::String,
+    /// Specifies by how many seconds an auction should be extended if leaving the auction were to trigger a liquidity auction.
+    #[prost(int64, tag = "3")]
+    pub auction_extension: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquiditySlaParameters {
+    #[prost(string, tag = "1")]
+    pub price_range: ::prost::alloc::string::String,
+    /// Specifies the minimum fraction of time LPs must spend "on the book" providing their committed liquidity.
+    #[prost(string, tag = "2")]
+    pub commitment_min_time_fraction: ::prost::alloc::string::String,
+    /// Specifies the number of liquidity epochs over which past performance will continue to affect rewards.
+    #[prost(uint64, tag = "4")]
+    pub performance_hysteresis_epochs: u64,
+    /// Specifies the maximum fraction of their accrued fees an LP that meets the SLA implied by market.liquidity.commitmentMinTimeFraction will lose to liquidity providers
+    /// that achieved a higher SLA performance than them.
+    #[prost(string, tag = "5")]
+    pub sla_competition_factor: ::prost::alloc::string::String,
+}
+/// Market settings that describe how the liquidity fee is calculated.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidityFeeSettings {
+    /// Method used to calculate the market's liquidity fee.
+    #[prost(enumeration = "liquidity_fee_settings::Method", tag = "1")]
+    pub method: i32,
+    /// Constant liquidity fee used when using the constant fee method.
+    #[prost(string, optional, tag = "2")]
+    pub fee_constant: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// Nested message and enum types in `LiquidityFeeSettings`.
+pub mod liquidity_fee_settings {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Method {
+        Unspecified = 0,
+        /// Fee is the smallest value of all bids, such that liquidity providers with nominated fees less than or equal to this value still have sufficient commitment to fulfil the market's target stake.
+        MarginalCost = 1,
+        /// Fee is the weighted average of all liquidity providers' nominated fees, weighted by their committment.
+        WeightedAverage = 2,
+        /// Fee is set by the market to a constant value irrespective of any liquidity provider's nominated fee.
+        Constant = 3,
+    }
+    impl Method {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Method::Unspecified => "METHOD_UNSPECIFIED",
+                Method::MarginalCost => "METHOD_MARGINAL_COST",
+                Method::WeightedAverage => "METHOD_WEIGHTED_AVERAGE",
+                Method::Constant => "METHOD_CONSTANT",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "METHOD_UNSPECIFIED" => Some(Self::Unspecified),
+                "METHOD_MARGINAL_COST" => Some(Self::MarginalCost),
+                "METHOD_WEIGHTED_AVERAGE" => Some(Self::WeightedAverage),
+                "METHOD_CONSTANT" => Some(Self::Constant),
+                _ => None,
+            }
+        }
+    }
+}
+/// TargetStakeParameters contains parameters used in target stake calculation
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TargetStakeParameters {
+    /// Specifies length of time window expressed in seconds for target stake calculation.
+    #[prost(int64, tag = "1")]
+    pub time_window: i64,
+    /// Specifies scaling factors used in target stake calculation.
+    #[prost(double, tag = "2")]
+    pub scaling_factor: f64,
+}
+/// Market definition
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Market {
+    /// Unique ID for the market.
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Tradable instrument configuration.
+    #[prost(message, optional, tag = "2")]
+    pub tradable_instrument: ::core::option::Option<TradableInstrument>,
+    /// Number of decimal places that a price must be shifted by in order to get a
+    /// correct price denominated in the currency of the market, for example:
+    /// `realPrice = price / 10^decimalPlaces`. On spot markets, also called 'size decimal places'.
+    #[prost(uint64, tag = "3")]
+    pub decimal_places: u64,
+    /// Fees configuration that apply to the market.
+    #[prost(message, optional, tag = "4")]
+    pub fees: ::core::option::Option<Fees>,
+    /// Auction duration specifies how long the opening auction will run (minimum
+    /// duration and optionally a minimum traded volume).
+    #[prost(message, optional, tag = "5")]
+    pub opening_auction: ::core::option::Option<AuctionDuration>,
+    /// PriceMonitoringSettings for the market.
+    #[prost(message, optional, tag = "6")]
+    pub price_monitoring_settings: ::core::option::Option<PriceMonitoringSettings>,
+    /// LiquidityMonitoringParameters for the market.
+    #[prost(message, optional, tag = "7")]
+    pub liquidity_monitoring_parameters: ::core::option::Option<
+        LiquidityMonitoringParameters,
+    >,
+    /// Current mode of execution of the market.
+    #[prost(enumeration = "market::TradingMode", tag = "8")]
+    pub trading_mode: i32,
+    /// Current state of the market.
+    #[prost(enumeration = "market::State", tag = "9")]
+    pub state: i32,
+    /// Timestamps for when the market state changes.
+    #[prost(message, optional, tag = "10")]
+    pub market_timestamps: ::core::option::Option<MarketTimestamps>,
+    /// The number of decimal places for a position.
+    /// On spot markets, used for order size, also known as 'size decimal places'.
+    #[prost(int64, tag = "11")]
+    pub position_decimal_places: i64,
+    /// Percentage move up and down from the mid price which specifies the range of
+    /// price levels over which automated liquidity provisions will be deployed.
+    #[prost(string, tag = "12")]
+    pub lp_price_range: ::prost::alloc::string::String,
+    /// Linear slippage factor is used to cap the slippage component of maintenance margin - it is applied to the slippage volume.
+    #[prost(string, tag = "13")]
+    pub linear_slippage_factor: ::prost::alloc::string::String,
+    /// Quadratic slippage factor is used to cap the slippage component of maintenance margin - it is applied to the square of the slippage volume.
+    #[prost(string, tag = "14")]
+    pub quadratic_slippage_factor: ::prost::alloc::string::String,
+    /// ID of the market this market succeeds
+    #[prost(string, optional, tag = "15")]
+    pub parent_market_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// The fraction of the parent market's insurance pool that this market inherits; range 0 through 1.
+    #[prost(string, optional, tag = "16")]
+    pub insurance_pool_fraction: ::core::option::Option<::prost::alloc::string::String>,
+    /// ID of the market that succeeds this market if it exists. This will be populated by the system when the successor market is enabled.
+    #[prost(string, optional, tag = "17")]
+    pub successor_market_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Liquidity SLA parameters for the market.
+    #[prost(message, optional, tag = "18")]
+    pub liquidity_sla_params: ::core::option::Option<LiquiditySlaParameters>,
+    /// Liquidation strategy used by this market.
+    #[prost(message, optional, tag = "19")]
+    pub liquidation_strategy: ::core::option::Option<LiquidationStrategy>,
+    /// Mark price calculation configuration.
+    #[prost(message, optional, tag = "20")]
+    pub mark_price_configuration: ::core::option::Option<CompositePriceConfiguration>,
+    /// The market tick size defines the minimum change in quote price for the market
+    #[prost(string, tag = "21")]
+    pub tick_size: ::prost::alloc::string::String,
+    /// If enabled aggressive orders sent to the market will be delayed by the configured number of blocks
+    #[prost(bool, tag = "22")]
+    pub enable_transaction_reordering: bool,
+}
+/// Nested message and enum types in `Market`.
+pub mod market {
+    /// Current state of the market
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum State {
+        /// Default value, invalid
+        Unspecified = 0,
+        /// Governance proposal valid and accepted
+        Proposed = 1,
+        /// Outcome of governance votes is to reject the market
+        Rejected = 2,
+        /// Governance vote passes/wins
+        Pending = 3,
+        /// Market triggers cancellation condition or governance
+        /// votes to close before market becomes Active
+        Cancelled = 4,
+        /// Enactment date reached and usual auction exit checks pass
+        Active = 5,
+        /// Price monitoring or liquidity monitoring trigger
+        Suspended = 6,
+        /// Governance vote to close (Not currently implemented)
+        Closed = 7,
+        /// Defined by the product (i.e. from a product parameter,
+        /// specified in market definition, giving close date/time)
+        TradingTerminated = 8,
+        /// Settlement triggered and completed as defined by product
+        Settled = 9,
+        /// Market has been suspended via governance
+        SuspendedViaGovernance = 10,
+    }
+    impl State {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                State::Unspecified => "STATE_UNSPECIFIED",
+                State::Proposed => "STATE_PROPOSED",
+                State::Rejected => "STATE_REJECTED",
+                State::Pending => "STATE_PENDING",
+                State::Cancelled => "STATE_CANCELLED",
+                State::Active => "STATE_ACTIVE",
+                State::Suspended => "STATE_SUSPENDED",
+                State::Closed => "STATE_CLOSED",
+                State::TradingTerminated => "STATE_TRADING_TERMINATED",
+                State::Settled => "STATE_SETTLED",
+                State::SuspendedViaGovernance => "STATE_SUSPENDED_VIA_GOVERNANCE",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "STATE_UNSPECIFIED" => Some(Self::Unspecified),
+                "STATE_PROPOSED" => Some(Self::Proposed),
+                "STATE_REJECTED" => Some(Self::Rejected),
+                "STATE_PENDING" => Some(Self::Pending),
+                "STATE_CANCELLED" => Some(Self::Cancelled),
+                "STATE_ACTIVE" => Some(Self::Active),
+                "STATE_SUSPENDED" => Some(Self::Suspended),
+                "STATE_CLOSED" => Some(Self::Closed),
+                "STATE_TRADING_TERMINATED" => Some(Self::TradingTerminated),
+                "STATE_SETTLED" => Some(Self::Settled),
+                "STATE_SUSPENDED_VIA_GOVERNANCE" => Some(Self::SuspendedViaGovernance),
+                _ => None,
+            }
+        }
+    }
+    /// Trading mode the market is currently running, also referred to as 'market state'
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum TradingMode {
+        /// Default value, this is invalid
+        Unspecified = 0,
+        /// Normal trading
+        Continuous = 1,
+        /// Auction trading (FBA)
+        BatchAuction = 2,
+        /// Opening auction
+        OpeningAuction = 3,
+        /// Auction triggered by monitoring
+        MonitoringAuction = 4,
+        /// No trading is allowed
+        NoTrading = 5,
+        /// Special auction mode triggered via governance
+        SuspendedViaGovernance = 6,
+        /// Auction triggered globally by long block
+        LongBlockAuction = 7,
+    }
+    impl TradingMode {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                TradingMode::Unspecified => "TRADING_MODE_UNSPECIFIED",
+                TradingMode::Continuous => "TRADING_MODE_CONTINUOUS",
+                TradingMode::BatchAuction => "TRADING_MODE_BATCH_AUCTION",
+                TradingMode::OpeningAuction => "TRADING_MODE_OPENING_AUCTION",
+                TradingMode::MonitoringAuction => "TRADING_MODE_MONITORING_AUCTION",
+                TradingMode::NoTrading => "TRADING_MODE_NO_TRADING",
+                TradingMode::SuspendedViaGovernance => {
+                    "TRADING_MODE_SUSPENDED_VIA_GOVERNANCE"
+                }
+                TradingMode::LongBlockAuction => "TRADING_MODE_LONG_BLOCK_AUCTION",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "TRADING_MODE_UNSPECIFIED" => Some(Self::Unspecified),
+                "TRADING_MODE_CONTINUOUS" => Some(Self::Continuous),
+                "TRADING_MODE_BATCH_AUCTION" => Some(Self::BatchAuction),
+                "TRADING_MODE_OPENING_AUCTION" => Some(Self::OpeningAuction),
+                "TRADING_MODE_MONITORING_AUCTION" => Some(Self::MonitoringAuction),
+                "TRADING_MODE_NO_TRADING" => Some(Self::NoTrading),
+                "TRADING_MODE_SUSPENDED_VIA_GOVERNANCE" => {
+                    Some(Self::SuspendedViaGovernance)
+                }
+                "TRADING_MODE_LONG_BLOCK_AUCTION" => Some(Self::LongBlockAuction),
+                _ => None,
+            }
+        }
+    }
+}
+/// Time stamps for important times about creating, enacting etc the market
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MarketTimestamps {
+    /// Time when the market is first proposed.
+    #[prost(int64, tag = "1")]
+    pub proposed: i64,
+    /// Time when the market has been voted in and began its opening auction.
+    #[prost(int64, tag = "2")]
+    pub pending: i64,
+    /// Time when the market has left the opening auction and is ready to accept trades.
+    #[prost(int64, tag = "3")]
+    pub open: i64,
+    /// Time when the market closed.
+    #[prost(int64, tag = "4")]
+    pub close: i64,
+}
+/// Liquidation strategy used when the network holds a position resulting from position resolution.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidationStrategy {
+    /// Interval, in seconds, at which the network will attempt to close its position.
+    #[prost(int64, tag = "1")]
+    pub disposal_time_step: i64,
+    /// Fraction of the open position the market will try to close in a single attempt; range 0 through 1.
+    #[prost(string, tag = "2")]
+    pub disposal_fraction: ::prost::alloc::string::String,
+    /// Size of the position that the network will try to close in a single attempt.
+    #[prost(uint64, tag = "3")]
+    pub full_disposal_size: u64,
+    /// Max fraction of the total volume of the orderbook, within liquidity bounds, that the network can use to close its position; range 0 through 1.
+    #[prost(string, tag = "4")]
+    pub max_fraction_consumed: ::prost::alloc::string::String,
+    /// Decimal > 0 specifying the range range above and below the mid price within which the network will trade to dispose of its position.
+    /// The value can be > 1. For example, if set to 1.5, the minimum price will be 0, ie max(0, mid_price * (1 - 1.5)), and the maximum price will be mid_price * (1 + 1.5).
+    #[prost(string, tag = "5")]
+    pub disposal_slippage_range: ::prost::alloc::string::String,
+}
+/// Mark price configuration parameters.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CompositePriceConfiguration {
+    /// Decay weight used for calculation of mark price.
+    #[prost(string, tag = "1")]
+    pub decay_weight: ::prost::alloc::string::String,
+    /// Decay power used for the calculation of mark price.
+    #[prost(uint64, tag = "2")]
+    pub decay_power: u64,
+    /// Cash amount, in asset decimals, used for the calculation of the mark price from the order book.
+    #[prost(string, tag = "3")]
+    pub cash_amount: ::prost::alloc::string::String,
+    /// Weights for each composite price data source.
+    #[prost(string, repeated, tag = "4")]
+    pub source_weights: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// For how long a price source is considered valid. One entry for each data source
+    /// such that the first is for the trade based mark price, the second is for the book based price
+    /// the third is for the first oracle, followed by more oracle data source staleness tolerance.
+    #[prost(string, repeated, tag = "5")]
+    pub source_staleness_tolerance: ::prost::alloc::vec::Vec<
+        ::prost::alloc::string::String,
+    >,
+    /// Which method is used for the calculation of the composite price for the market.
+    #[prost(enumeration = "CompositePriceType", tag = "6")]
+    pub composite_price_type: i32,
+    /// Additional price sources to be used for internal composite price calculation.
+    #[prost(message, repeated, tag = "7")]
+    pub data_sources_spec: ::prost::alloc::vec::Vec<DataSourceDefinition>,
+    /// List of each price source and its corresponding binding
+    #[prost(message, repeated, tag = "8")]
+    pub data_sources_spec_binding: ::prost::alloc::vec::Vec<
+        SpecBindingForCompositePrice,
+    >,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum CompositePriceType {
+    Unspecified = 0,
+    /// Composite price is calculated as a weighted average of the underlying mark prices.
+    Weighted = 1,
+    /// Composite price is calculated as a median of the underlying mark prices.
+    Median = 2,
+    /// Composite price is calculated as the last trade price.
+    LastTrade = 3,
+}
+impl CompositePriceType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            CompositePriceType::Unspecified => "COMPOSITE_PRICE_TYPE_UNSPECIFIED",
+            CompositePriceType::Weighted => "COMPOSITE_PRICE_TYPE_WEIGHTED",
+            CompositePriceType::Median => "COMPOSITE_PRICE_TYPE_MEDIAN",
+            CompositePriceType::LastTrade => "COMPOSITE_PRICE_TYPE_LAST_TRADE",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "COMPOSITE_PRICE_TYPE_UNSPECIFIED" => Some(Self::Unspecified),
+            "COMPOSITE_PRICE_TYPE_WEIGHTED" => Some(Self::Weighted),
+            "COMPOSITE_PRICE_TYPE_MEDIAN" => Some(Self::Median),
+            "COMPOSITE_PRICE_TYPE_LAST_TRADE" => Some(Self::LastTrade),
+            _ => None,
+        }
+    }
+}
+/// Holds metadata associated to a party.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PartyProfile {
+    /// Party ID associated to the profile.
+    #[prost(string, tag = "1")]
+    pub party_id: ::prost::alloc::string::String,
+    /// Alias given to the party.
+    #[prost(string, tag = "2")]
+    pub alias: ::prost::alloc::string::String,
+    /// Metadata to associate to a party, in a key/value format where the key
+    /// describes the type of metadata in the value field.
+    #[prost(message, repeated, tag = "3")]
+    pub metadata: ::prost::alloc::vec::Vec<Metadata>,
+    /// Derived keys for the party.
+    #[prost(string, repeated, tag = "4")]
+    pub derived_keys: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// Generic structure holding a key/value pair.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Metadata {
+    /// Key of the metadata.
+    #[prost(string, tag = "1")]
+    pub key: ::prost::alloc::string::String,
+    /// Value of the metadata.
+    #[prost(string, tag = "2")]
+    pub value: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StopOrder {
+    /// ID of this stop order
+    /// also the ID of the associated order if it is ever triggered
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// The ID of the 'other' part of the OCO if 2 stop orders were submitted at once
+    #[prost(string, optional, tag = "2")]
+    pub oco_link_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Optional expiry timestamp.
+    #[prost(int64, optional, tag = "3")]
+    pub expires_at: ::core::option::Option<i64>,
+    /// Strategy to adopt if the expiry time is reached.
+    #[prost(enumeration = "stop_order::ExpiryStrategy", optional, tag = "4")]
+    pub expiry_strategy: ::core::option::Option<i32>,
+    /// Trigger direction for this stop order.
+    #[prost(enumeration = "stop_order::TriggerDirection", tag = "5")]
+    pub trigger_direction: i32,
+    /// Status of the stop order.
+    #[prost(enumeration = "stop_order::Status", tag = "6")]
+    pub status: i32,
+    /// Creation time of the stop order.
+    #[prost(int64, tag = "7")]
+    pub created_at: i64,
+    /// Last update of this stop order.
+    #[prost(int64, optional, tag = "8")]
+    pub updated_at: ::core::option::Option<i64>,
+    /// ID of the order created once the trigger is hit.
+    #[prost(string, tag = "9")]
+    pub order_id: ::prost::alloc::string::String,
+    /// ID of the party that submitted this stop order.
+    #[prost(string, tag = "10")]
+    pub party_id: ::prost::alloc::string::String,
+    /// ID of the market the stop order is submitted to.
+    #[prost(string, tag = "11")]
+    pub market_id: ::prost::alloc::string::String,
+    /// An optional reason for why a stop order was rejected
+    #[prost(enumeration = "stop_order::RejectionReason", optional, tag = "12")]
+    pub rejection_reason: ::core::option::Option<i32>,
+    /// Size override setting
+    #[prost(enumeration = "stop_order::SizeOverrideSetting", tag = "13")]
+    pub size_override_setting: i32,
+    /// Size override value
+    #[prost(message, optional, tag = "14")]
+    pub size_override_value: ::core::option::Option<stop_order::SizeOverrideValue>,
+    /// Trigger that will need to be breached for the order
+    /// to be submitted to the book.
+    #[prost(oneof = "stop_order::Trigger", tags = "100, 101")]
+    pub trigger: ::core::option::Option<stop_order::Trigger>,
+}
+/// Nested message and enum types in `StopOrder`.
+pub mod stop_order {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct SizeOverrideValue {
+        /// Scaling percentage of the current position’s size
+        #[prost(string, tag = "1")]
+        pub percentage: ::prost::alloc::string::String,
+    }
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum SizeOverrideSetting {
+        /// Never valid
+        Unspecified = 0,
+        /// No override, the size within the contained normal order submission will be used
+        None = 1,
+        /// Use the total position of the trader
+        Position = 2,
+    }
+    impl SizeOverrideSetting {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                SizeOverrideSetting::Unspecified => "SIZE_OVERRIDE_SETTING_UNSPECIFIED",
+                SizeOverrideSetting::None => "SIZE_OVERRIDE_SETTING_NONE",
+                SizeOverrideSetting::Position => "SIZE_OVERRIDE_SETTING_POSITION",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "SIZE_OVERRIDE_SETTING_UNSPECIFIED" => Some(Self::Unspecified),
+                "SIZE_OVERRIDE_SETTING_NONE" => Some(Self::None),
+                "SIZE_OVERRIDE_SETTING_POSITION" => Some(Self::Position),
+                _ => None,
+            }
+        }
+    }
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum ExpiryStrategy {
+        /// Never valid
+        Unspecified = 0,
+        /// Stop order should be cancelled if the expiry time is reached.
+        Cancels = 1,
+        /// Order should be submitted if the expiry time is reached.
+        Submit = 2,
+    }
+    impl ExpiryStrategy {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                ExpiryStrategy::Unspecified => "EXPIRY_STRATEGY_UNSPECIFIED",
+                ExpiryStrategy::Cancels => "EXPIRY_STRATEGY_CANCELS",
+                ExpiryStrategy::Submit => "EXPIRY_STRATEGY_SUBMIT",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "EXPIRY_STRATEGY_UNSPECIFIED" => Some(Self::Unspecified),
+                "EXPIRY_STRATEGY_CANCELS" => Some(Self::Cancels),
+                "EXPIRY_STRATEGY_SUBMIT" => Some(Self::Submit),
+                _ => None,
+            }
+        }
+    }
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum TriggerDirection {
+        /// Never valid
+        Unspecified = 0,
+        /// Stop order is triggered once the price rises above a certain level
+        RisesAbove = 1,
+        /// Stop order is triggered once the price falls below a certain level
+        FallsBelow = 2,
+    }
+    impl TriggerDirection {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                TriggerDirection::Unspecified => "TRIGGER_DIRECTION_UNSPECIFIED",
+                TriggerDirection::RisesAbove => "TRIGGER_DIRECTION_RISES_ABOVE",
+                TriggerDirection::FallsBelow => "TRIGGER_DIRECTION_FALLS_BELOW",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "TRIGGER_DIRECTION_UNSPECIFIED" => Some(Self::Unspecified),
+                "TRIGGER_DIRECTION_RISES_ABOVE" => Some(Self::RisesAbove),
+                "TRIGGER_DIRECTION_FALLS_BELOW" => Some(Self::FallsBelow),
+                _ => None,
+            }
+        }
+    }
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Status {
+        /// Never valid
+        Unspecified = 0,
+        /// Pending to be executed once the trigger is breached
+        Pending = 1,
+        /// Cancelled by the user
+        Cancelled = 2,
+        /// Stopped by the network, e.g: OCO on the other side has been triggered
+        Stopped = 3,
+        /// Stop order has been triggered and generated an order
+        Triggered = 4,
+        /// Stop order has expired
+        Expired = 5,
+        /// Stop order was rejected at submission
+        Rejected = 6,
+    }
+    impl Status {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Status::Unspecified => "STATUS_UNSPECIFIED",
+                Status::Pending => "STATUS_PENDING",
+                Status::Cancelled => "STATUS_CANCELLED",
+                Status::Stopped => "STATUS_STOPPED",
+                Status::Triggered => "STATUS_TRIGGERED",
+                Status::Expired => "STATUS_EXPIRED",
+                Status::Rejected => "STATUS_REJECTED",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "STATUS_UNSPECIFIED" => Some(Self::Unspecified),
+                "STATUS_PENDING" => Some(Self::Pending),
+                "STATUS_CANCELLED" => Some(Self::Cancelled),
+                "STATUS_STOPPED" => Some(Self::Stopped),
+                "STATUS_TRIGGERED" => Some(Self::Triggered),
+                "STATUS_EXPIRED" => Some(Self::Expired),
+                "STATUS_REJECTED" => Some(Self::Rejected),
+                _ => None,
+            }
+        }
+    }
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum RejectionReason {
+        /// Never valid
+        Unspecified = 0,
+        /// Trading is not allowed yet
+        TradingNotAllowed = 1,
+        /// Expiry of the stop order is in the past
+        ExpiryInThePast = 2,
+        /// Stop orders submission must be reduce only
+        MustBeReduceOnly = 3,
+        /// Party has reached the maximum stop orders allowed for this market
+        MaxStopOrdersPerPartyReached = 4,
+        /// Stop orders are not allowed if there is no open position
+        StopOrderNotAllowedWithoutAPosition = 5,
+        /// This stop order does not close the position
+        StopOrderNotClosingThePosition = 6,
+        /// The percentage value for the linked stop order is invalid
+        StopOrderLinkedPercentageInvalid = 7,
+        /// Stop orders are not allowed during the opening auction
+        StopOrderNotAllowedDuringOpeningAuction = 8,
+        /// Stop OCO orders cannot have the same expiry timestamp
+        StopOrderCannotMatchOcoExpiryTimes = 9,
+        /// Stop orders with a position size override are not supported for spot markets
+        StopOrderSizeOverrideUnsupportedForSpot = 10,
+    }
+    impl RejectionReason {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                RejectionReason::Unspecified => "REJECTION_REASON_UNSPECIFIED",
+                RejectionReason::TradingNotAllowed => {
+                    "REJECTION_REASON_TRADING_NOT_ALLOWED"
+                }
+                RejectionReason::ExpiryInThePast => "REJECTION_REASON_EXPIRY_IN_THE_PAST",
+                RejectionReason::MustBeReduceOnly => {
+                    "REJECTION_REASON_MUST_BE_REDUCE_ONLY"
+                }
+                RejectionReason::MaxStopOrdersPerPartyReached => {
+                    "REJECTION_REASON_MAX_STOP_ORDERS_PER_PARTY_REACHED"
+                }
+                RejectionReason::StopOrderNotAllowedWithoutAPosition => {
+                    "REJECTION_REASON_STOP_ORDER_NOT_ALLOWED_WITHOUT_A_POSITION"
+                }
+                RejectionReason::StopOrderNotClosingThePosition => {
+                    "REJECTION_REASON_STOP_ORDER_NOT_CLOSING_THE_POSITION"
+                }
+                RejectionReason::StopOrderLinkedPercentageInvalid => {
+                    "REJECTION_REASON_STOP_ORDER_LINKED_PERCENTAGE_INVALID"
+                }
+                RejectionReason::StopOrderNotAllowedDuringOpeningAuction => {
+                    "REJECTION_REASON_STOP_ORDER_NOT_ALLOWED_DURING_OPENING_AUCTION"
+                }
+                RejectionReason::StopOrderCannotMatchOcoExpiryTimes => {
+                    "REJECTION_REASON_STOP_ORDER_CANNOT_MATCH_OCO_EXPIRY_TIMES"
+                }
+                RejectionReason::StopOrderSizeOverrideUnsupportedForSpot => {
+                    "REJECTION_REASON_STOP_ORDER_SIZE_OVERRIDE_UNSUPPORTED_FOR_SPOT"
+                }
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "REJECTION_REASON_UNSPECIFIED" => Some(Self::Unspecified),
+                "REJECTION_REASON_TRADING_NOT_ALLOWED" => Some(Self::TradingNotAllowed),
+                "REJECTION_REASON_EXPIRY_IN_THE_PAST" => Some(Self::ExpiryInThePast),
+                "REJECTION_REASON_MUST_BE_REDUCE_ONLY" => Some(Self::MustBeReduceOnly),
+                "REJECTION_REASON_MAX_STOP_ORDERS_PER_PARTY_REACHED" => {
+                    Some(Self::MaxStopOrdersPerPartyReached)
+                }
+                "REJECTION_REASON_STOP_ORDER_NOT_ALLOWED_WITHOUT_A_POSITION" => {
+                    Some(Self::StopOrderNotAllowedWithoutAPosition)
+                }
+                "REJECTION_REASON_STOP_ORDER_NOT_CLOSING_THE_POSITION" => {
+                    Some(Self::StopOrderNotClosingThePosition)
+                }
+                "REJECTION_REASON_STOP_ORDER_LINKED_PERCENTAGE_INVALID" => {
+                    Some(Self::StopOrderLinkedPercentageInvalid)
+                }
+                "REJECTION_REASON_STOP_ORDER_NOT_ALLOWED_DURING_OPENING_AUCTION" => {
+                    Some(Self::StopOrderNotAllowedDuringOpeningAuction)
+                }
+                "REJECTION_REASON_STOP_ORDER_CANNOT_MATCH_OCO_EXPIRY_TIMES" => {
+                    Some(Self::StopOrderCannotMatchOcoExpiryTimes)
+                }
+                "REJECTION_REASON_STOP_ORDER_SIZE_OVERRIDE_UNSUPPORTED_FOR_SPOT" => {
+                    Some(Self::StopOrderSizeOverrideUnsupportedForSpot)
+                }
+                _ => None,
+            }
+        }
+    }
+    /// Trigger that will need to be breached for the order
+    /// to be submitted to the book.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Trigger {
+        /// Fixed price at which the order will be submitted.
+        #[prost(string, tag = "100")]
+        Price(::prost::alloc::string::String),
+        /// Trailing percentage at which the order will be submitted.
+        /// This should be expressed as a decimal value between 0 and 1, e.g. 0.01 for 1%
+        #[prost(string, tag = "101")]
+        TrailingPercentOffset(::prost::alloc::string::String),
+    }
+}
+/// Party represents an entity who wishes to trade on or query a Vega network
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Party {
+    /// Unique ID for the party, typically represented by a public key.
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Alias given to the party.
+    #[prost(string, tag = "2")]
+    pub alias: ::prost::alloc::string::String,
+    /// Metadata to associate to a party, in a key/value format where the key
+    /// describes the type of metadata in the value field.
+    #[prost(message, repeated, tag = "3")]
+    pub metadata: ::prost::alloc::vec::Vec<Metadata>,
+}
+/// Risk factors are used to calculate the current risk associated with orders trading on a given market
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RiskFactor {
+    /// Market ID that relates to this risk factor.
+    #[prost(string, tag = "1")]
+    pub market: ::prost::alloc::string::String,
+    /// Short Risk factor value.
+    #[prost(string, tag = "2")]
+    pub short: ::prost::alloc::string::String,
+    /// Long Risk factor value.
+    #[prost(string, tag = "3")]
+    pub long: ::prost::alloc::string::String,
+}
+/// Pegged orders are limit orders where the price is specified in the form REFERENCE +/- OFFSET
+/// They can be used for any limit order that is valid during continuous trading
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PeggedOrder {
+    /// Price point the order is linked to.
+    #[prost(enumeration = "PeggedReference", tag = "1")]
+    pub reference: i32,
+    /// Offset from the price reference.
+    #[prost(string, tag = "2")]
+    pub offset: ::prost::alloc::string::String,
+}
+/// Details of an iceberg order
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IcebergOrder {
+    /// Size of the order that will be made visible if the iceberg order is replenished after trading.
+    #[prost(uint64, tag = "1")]
+    pub peak_size: u64,
+    /// If the visible size of the order falls below this value, it will be replenished back to the peak size using the reserved amount.
+    #[prost(uint64, tag = "2")]
+    pub minimum_visible_size: u64,
+    /// Size of the order that is reserved and used to restore the iceberg's peak when it is refreshed.
+    #[prost(uint64, tag = "3")]
+    pub reserved_remaining: u64,
+}
+/// Orders can be submitted, amended and cancelled on Vega in an attempt to make trades with other parties
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Order {
+    /// Unique ID generated for the order.
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Market ID for the order.
+    #[prost(string, tag = "2")]
+    pub market_id: ::prost::alloc::string::String,
+    /// Party ID for the order.
+    #[prost(string, tag = "3")]
+    pub party_id: ::prost::alloc::string::String,
+    /// Side for the order, e.g. SIDE_BUY or SIDE_SELL.
+    #[prost(enumeration = "Side", tag = "4")]
+    pub side: i32,
+    /// Price for the order, the price is an integer, for example `123456` is a correctly
+    /// formatted price of `1.23456` assuming market configured to 5 decimal places.
+    #[prost(string, tag = "5")]
+    pub price: ::prost::alloc::string::String,
+    /// Size for the order, for example, in a futures market the size equals the number of contracts.
+    #[prost(uint64, tag = "6")]
+    pub size: u64,
+    /// Size remaining, when this reaches 0 then the order is fully filled and status becomes STATUS_FILLED.
+    #[prost(uint64, tag = "7")]
+    pub remaining: u64,
+    /// Time in force indicates how long an order will remain active before it is executed or expires.
+    /// - See OrderTimeInForce
+    #[prost(enumeration = "order::TimeInForce", tag = "8")]
+    pub time_in_force: i32,
+    /// Type for the order.
+    #[prost(enumeration = "order::Type", tag = "9")]
+    pub r#type: i32,
+    /// Timestamp for when the order was created at, in nanoseconds.
+    #[prost(int64, tag = "10")]
+    pub created_at: i64,
+    /// Current status of the order.
+    #[prost(enumeration = "order::Status", tag = "11")]
+    pub status: i32,
+    /// Timestamp in Unix nanoseconds for when the order will expire.
+    #[prost(int64, tag = "12")]
+    pub expires_at: i64,
+    /// Reference given for the order.
+    #[prost(string, tag = "13")]
+    pub reference: ::prost::alloc::string::String,
+    /// Futher details for why an order with status `STATUS_REJECTED` was rejected.
+    #[prost(enumeration = "OrderError", optional, tag = "14")]
+    pub reason: ::core::option::Option<i32>,
+    /// Timestamp in Unix nanoseconds for when the order was last updated.
+    #[prost(int64, tag = "15")]
+    pub updated_at: i64,
+    /// Version for the order, initial value is version 1 and is incremented after each successful amend.
+    #[prost(uint64, tag = "16")]
+    pub version: u64,
+    /// Batch ID for the order, used internally for orders submitted during auctions
+    /// to keep track of the auction batch this order falls under. Required for fees calculation.
+    #[prost(uint64, tag = "17")]
+    pub batch_id: u64,
+    /// Pegged order details, used only if the order represents a pegged order.
+    #[prost(message, optional, tag = "18")]
+    pub pegged_order: ::core::option::Option<PeggedOrder>,
+    /// Set if the order was created as part of a liquidity provision, will be empty if not.
+    #[prost(string, tag = "19")]
+    pub liquidity_provision_id: ::prost::alloc::string::String,
+    /// Only valid for Limit orders. Cannot be True at the same time as Reduce-Only.
+    #[prost(bool, tag = "20")]
+    pub post_only: bool,
+    /// Only valid for Non-Persistent orders. Cannot be True at the same time as Post-Only.
+    /// If set, order will only be executed if the outcome of the trade moves the trader's position closer to 0.
+    #[prost(bool, tag = "21")]
+    pub reduce_only: bool,
+    /// Details of an iceberg order
+    #[prost(message, optional, tag = "22")]
+    pub iceberg_order: ::core::option::Option<IcebergOrder>,
+}
+/// Nested message and enum types in `Order`.
+pub mod order {
+    /// Time In Force for an order
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum TimeInForce {
+        /// Default value for TimeInForce, can be valid for an amend
+        Unspecified = 0,
+        /// Good until cancelled, the order trades any amount and as much as possible
+        /// and remains on the book until it either trades completely or is cancelled
+        Gtc = 1,
+        /// Good until specified time, this order type trades any amount and as much as possible
+        /// and remains on the book until it either trades completely, is cancelled, or expires at a set time
+        /// NOTE: this may in future be multiple types or have sub types for orders that provide different ways of specifying expiry
+        Gtt = 2,
+        /// Immediate or cancel, the order trades any amount and as much as possible
+        /// but does not remain on the book (whether it trades or not)
+        Ioc = 3,
+        /// Fill or kill, the order either trades completely i.e. remainingSize == 0 after adding,
+        /// or not at all, and does not remain on the book if it doesn't trade
+        Fok = 4,
+        /// Good for auction, this order is only accepted during an auction period
+        Gfa = 5,
+        /// Good for normal, this order is only accepted during normal trading (that can be continuous trading or frequent batched auctions)
+        Gfn = 6,
+    }
+    impl TimeInForce {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                TimeInForce::Unspecified => "TIME_IN_FORCE_UNSPECIFIED",
+                TimeInForce::Gtc => "TIME_IN_FORCE_GTC",
+                TimeInForce::Gtt => "TIME_IN_FORCE_GTT",
+                TimeInForce::Ioc => "TIME_IN_FORCE_IOC",
+                TimeInForce::Fok => "TIME_IN_FORCE_FOK",
+                TimeInForce::Gfa => "TIME_IN_FORCE_GFA",
+                TimeInForce::Gfn => "TIME_IN_FORCE_GFN",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "TIME_IN_FORCE_UNSPECIFIED" => Some(Self::Unspecified),
+                "TIME_IN_FORCE_GTC" => Some(Self::Gtc),
+                "TIME_IN_FORCE_GTT" => Some(Self::Gtt),
+                "TIME_IN_FORCE_IOC" => Some(Self::Ioc),
+                "TIME_IN_FORCE_FOK" => Some(Self::Fok),
+                "TIME_IN_FORCE_GFA" => Some(Self::Gfa),
+                "TIME_IN_FORCE_GFN" => Some(Self::Gfn),
+                _ => None,
+            }
+        }
+    }
+    /// Type values for an order
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Type {
+        /// Default value, always invalid
+        Unspecified = 0,
+        /// Used for Limit orders
+        Limit = 1,
+        /// Used for Market orders
+        Market = 2,
+        /// Used for orders where the initiating party is the network (with distressed parties)
+        Network = 3,
+    }
+    impl Type {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Type::Unspecified => "TYPE_UNSPECIFIED",
+                Type::Limit => "TYPE_LIMIT",
+                Type::Market => "TYPE_MARKET",
+                Type::Network => "TYPE_NETWORK",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "TYPE_UNSPECIFIED" => Some(Self::Unspecified),
+                "TYPE_LIMIT" => Some(Self::Limit),
+                "TYPE_MARKET" => Some(Self::Market),
+                "TYPE_NETWORK" => Some(Self::Network),
+                _ => None,
+            }
+        }
+    }
+    /// Status values for an order
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Status {
+        /// Default value, always invalid
+        Unspecified = 0,
+        /// Used for active unfilled or partially filled orders
+        Active = 1,
+        /// Used for expired GTT orders
+        Expired = 2,
+        /// Used for orders cancelled by the party that created the order
+        Cancelled = 3,
+        /// Used for unfilled FOK or IOC orders, and for orders that were stopped by the network
+        Stopped = 4,
+        /// Used for closed fully filled orders
+        Filled = 5,
+        /// Used for orders when not enough collateral was available to fill the margin requirements
+        Rejected = 6,
+        /// Used for closed partially filled IOC orders
+        PartiallyFilled = 7,
+        /// Order has been removed from the order book and has been parked,
+        /// this applies to pegged orders and liquidity orders (orders created from a liquidity provision shape)
+        Parked = 8,
+    }
+    impl Status {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Status::Unspecified => "STATUS_UNSPECIFIED",
+                Status::Active => "STATUS_ACTIVE",
+                Status::Expired => "STATUS_EXPIRED",
+                Status::Cancelled => "STATUS_CANCELLED",
+                Status::Stopped => "STATUS_STOPPED",
+                Status::Filled => "STATUS_FILLED",
+                Status::Rejected => "STATUS_REJECTED",
+                Status::PartiallyFilled => "STATUS_PARTIALLY_FILLED",
+                Status::Parked => "STATUS_PARKED",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "STATUS_UNSPECIFIED" => Some(Self::Unspecified),
+                "STATUS_ACTIVE" => Some(Self::Active),
+                "STATUS_EXPIRED" => Some(Self::Expired),
+                "STATUS_CANCELLED" => Some(Self::Cancelled),
+                "STATUS_STOPPED" => Some(Self::Stopped),
+                "STATUS_FILLED" => Some(Self::Filled),
+                "STATUS_REJECTED" => Some(Self::Rejected),
+                "STATUS_PARTIALLY_FILLED" => Some(Self::PartiallyFilled),
+                "STATUS_PARKED" => Some(Self::Parked),
+                _ => None,
+            }
+        }
+    }
+}
+/// Used when cancelling an order
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OrderCancellationConfirmation {
+    /// Order that was cancelled.
+    #[prost(message, optional, tag = "1")]
+    pub order: ::core::option::Option<Order>,
+}
+/// Used when confirming an order
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OrderConfirmation {
+    /// Order that was confirmed.
+    #[prost(message, optional, tag = "1")]
+    pub order: ::core::option::Option<Order>,
+    /// 0 or more trades that were emitted.
+    #[prost(message, repeated, tag = "2")]
+    pub trades: ::prost::alloc::vec::Vec<Trade>,
+    /// 0 or more passive orders that were affected.
+    #[prost(message, repeated, tag = "3")]
+    pub passive_orders_affected: ::prost::alloc::vec::Vec<Order>,
+}
+/// AuctionIndicativeState is used to emit an event with the indicative price/volume per market during an auction
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AuctionIndicativeState {
+    /// Market ID for which this state relates to.
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+    /// Indicative uncrossing price is the price at which all trades would occur if the auction uncrossed now.
+    #[prost(string, tag = "2")]
+    pub indicative_price: ::prost::alloc::string::String,
+    /// Indicative uncrossing volume is the volume available at the indicative crossing price if the auction uncrossed now.
+    #[prost(uint64, tag = "3")]
+    pub indicative_volume: u64,
+    /// Timestamp at which the auction started.
+    #[prost(int64, tag = "4")]
+    pub auction_start: i64,
+    /// Timestamp at which the auction is meant to stop.
+    #[prost(int64, tag = "5")]
+    pub auction_end: i64,
+}
+/// A trade occurs when an aggressive order crosses one or more passive orders on the order book for a market on Vega
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Trade {
+    /// Unique ID for the trade.
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Market ID on which the trade occurred.
+    #[prost(string, tag = "2")]
+    pub market_id: ::prost::alloc::string::String,
+    /// Price for the trade, the price is an integer, for example `123456` is a correctly
+    /// formatted price of `1.23456` assuming market configured to 5 decimal places.
+    #[prost(string, tag = "3")]
+    pub price: ::prost::alloc::string::String,
+    /// Size filled for the trade.
+    #[prost(uint64, tag = "4")]
+    pub size: u64,
+    /// Unique party ID for the buyer.
+    #[prost(string, tag = "5")]
+    pub buyer: ::prost::alloc::string::String,
+    /// Unique party ID for the seller.
+    #[prost(string, tag = "6")]
+    pub seller: ::prost::alloc::string::String,
+    /// Direction of the aggressive party e.g. SIDE_BUY or SIDE_SELL.
+    #[prost(enumeration = "Side", tag = "7")]
+    pub aggressor: i32,
+    /// Identifier of the order from the buy side.
+    #[prost(string, tag = "8")]
+    pub buy_order: ::prost::alloc::string::String,
+    /// Identifier of the order from the sell side.
+    #[prost(string, tag = "9")]
+    pub sell_order: ::prost::alloc::string::String,
+    /// Timestamp in Unix nanoseconds for when the trade occurred.
+    #[prost(int64, tag = "10")]
+    pub timestamp: i64,
+    /// Type for the trade.
+    #[prost(enumeration = "trade::Type", tag = "11")]
+    pub r#type: i32,
+    /// Fee amount charged to the buyer party for the trade.
+    #[prost(message, optional, tag = "12")]
+    pub buyer_fee: ::core::option::Option<Fee>,
+    /// Fee amount charged to the seller party for the trade.
+    #[prost(message, optional, tag = "13")]
+    pub seller_fee: ::core::option::Option<Fee>,
+    /// Auction batch number that the buy side order was placed in.
+    #[prost(uint64, tag = "14")]
+    pub buyer_auction_batch: u64,
+    /// Auction batch number that the sell side order was placed in.
+    #[prost(uint64, tag = "15")]
+    pub seller_auction_batch: u64,
+    /// Price for the trade using asset decimals, as opposed to market decimals used
+    /// in the price field. This is only used in trade events for position updates.
+    #[prost(string, tag = "16")]
+    pub asset_price: ::prost::alloc::string::String,
+}
+/// Nested message and enum types in `Trade`.
+pub mod trade {
+    /// Type values for a trade
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Type {
+        /// Default value, always invalid
+        Unspecified = 0,
+        /// Normal trading between two parties
+        Default = 1,
+        /// Trading initiated by the network with another party on the book,
+        /// which helps to zero-out the positions of one or more distressed parties
+        NetworkCloseOutGood = 2,
+        /// Trading initiated by the network with another party off the book,
+        /// with a distressed party in order to zero-out the position of the party
+        NetworkCloseOutBad = 3,
+    }
+    impl Type {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Type::Unspecified => "TYPE_UNSPECIFIED",
+                Type::Default => "TYPE_DEFAULT",
+                Type::NetworkCloseOutGood => "TYPE_NETWORK_CLOSE_OUT_GOOD",
+                Type::NetworkCloseOutBad => "TYPE_NETWORK_CLOSE_OUT_BAD",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "TYPE_UNSPECIFIED" => Some(Self::Unspecified),
+                "TYPE_DEFAULT" => Some(Self::Default),
+                "TYPE_NETWORK_CLOSE_OUT_GOOD" => Some(Self::NetworkCloseOutGood),
+                "TYPE_NETWORK_CLOSE_OUT_BAD" => Some(Self::NetworkCloseOutBad),
+                _ => None,
+            }
+        }
+    }
+}
+/// Represents any fees paid by a party, resulting from a trade
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Fee {
+    /// Fee amount paid to the non-aggressive party of the trade. This field is an unsigned integer scaled to the asset's decimal places.
+    #[prost(string, tag = "1")]
+    pub maker_fee: ::prost::alloc::string::String,
+    /// Fee amount paid for maintaining the Vega infrastructure. This field is an unsigned integer scaled using the asset's decimal places.
+    #[prost(string, tag = "2")]
+    pub infrastructure_fee: ::prost::alloc::string::String,
+    /// Fee amount paid to market makers. This field is an unsigned integer scaled to the asset's decimal places.
+    #[prost(string, tag = "3")]
+    pub liquidity_fee: ::prost::alloc::string::String,
+    /// Volume discounts.
+    /// Discount on maker fee based on the taker volume.
+    #[prost(string, tag = "4")]
+    pub maker_fee_volume_discount: ::prost::alloc::string::String,
+    /// Discount on infrastructure fee based on the taker volume.
+    #[prost(string, tag = "5")]
+    pub infrastructure_fee_volume_discount: ::prost::alloc::string::String,
+    /// Discount on liquidity fee basedo on taker volume.
+    #[prost(string, tag = "6")]
+    pub liquidity_fee_volume_discount: ::prost::alloc::string::String,
+    /// Referrer discounts.
+    /// Discount on maker fee for eligible referrer.
+    #[prost(string, tag = "7")]
+    pub maker_fee_referrer_discount: ::prost::alloc::string::String,
+    /// Discount on infrastructure fee for eligible referrer.
+    #[prost(string, tag = "8")]
+    pub infrastructure_fee_referrer_discount: ::prost::alloc::string::String,
+    /// Discount on liquidity fee for eligible referrer.
+    #[prost(string, tag = "9")]
+    pub liquidity_fee_referrer_discount: ::prost::alloc::string::String,
+    /// Fee amount sent to network treasury for later use based on governance actions (network wide).
+    #[prost(string, tag = "10")]
+    pub treasury_fee: ::prost::alloc::string::String,
+    /// Fee amount used to purchase governance tokens via regular auctions (network wide).
+    #[prost(string, tag = "11")]
+    pub buy_back_fee: ::prost::alloc::string::String,
+    /// Fee paid by the taker to the maker if the maker is eligible.
+    #[prost(string, tag = "12")]
+    pub high_volume_maker_fee: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TradeSet {
+    /// Set of one or more trades.
+    #[prost(message, repeated, tag = "1")]
+    pub trades: ::prost::alloc::vec::Vec<Trade>,
+}
+/// Represents the high, low, open, and closing prices for an interval of trading,
+/// referred to commonly as a candlestick or candle
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Candle {
+    /// Timestamp in Unix nanoseconds for the point in time when the candle was initially created/opened.
+    #[prost(int64, tag = "1")]
+    pub timestamp: i64,
+    /// ISO-8601 datetime with nanosecond precision for when the candle was last updated.
+    #[prost(string, tag = "2")]
+    pub datetime: ::prost::alloc::string::String,
+    /// Highest price for trading during the candle interval. This field is an unsigned integer scaled to the market's decimal places.
+    #[prost(string, tag = "3")]
+    pub high: ::prost::alloc::string::String,
+    /// Lowest price for trading during the candle interval. This field is an unsigned integer scaled to the market's decimal places.
+    #[prost(string, tag = "4")]
+    pub low: ::prost::alloc::string::String,
+    /// Open trade price. This field is an unsigned integer scaled to the market's decimal places.
+    #[prost(string, tag = "5")]
+    pub open: ::prost::alloc::string::String,
+    /// Closing trade price. This field is an unsigned integer scaled to the market's decimal places.
+    #[prost(string, tag = "6")]
+    pub close: ::prost::alloc::string::String,
+    /// Total trading volume during the candle interval.
+    #[prost(uint64, tag = "7")]
+    pub volume: u64,
+    /// Time interval for the candle.
+    #[prost(enumeration = "Interval", tag = "8")]
+    pub interval: i32,
+    /// Total notional value traded during the candle interval.
+    #[prost(uint64, tag = "9")]
+    pub notional: u64,
+}
+/// Represents a price level from market depth or order book data
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PriceLevel {
+    /// Price for the price level, the price is an integer, for example `123456` is a correctly
+    /// formatted price of `1.23456` assuming market configured to 5 decimal places. This field
+    /// is an unsigned integer passed as a string and needs to be scaled using the market's decimal places.
+    #[prost(string, tag = "1")]
+    pub price: ::prost::alloc::string::String,
+    /// Number of orders at the price level.
+    #[prost(uint64, tag = "2")]
+    pub number_of_orders: u64,
+    /// Volume at the price level.
+    #[prost(uint64, tag = "3")]
+    pub volume: u64,
+    /// Volume of AMM's at the price level.
+    #[prost(uint64, tag = "4")]
+    pub amm_volume: u64,
+    /// Estimated AMM volume at the price level.
+    #[prost(uint64, tag = "5")]
+    pub amm_volume_estimated: u64,
+}
+/// Represents market depth or order book data for the specified market on Vega
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MarketDepth {
+    /// Market ID for which the depth levels apply.
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+    /// Collection of price levels for the buy side of the book.
+    #[prost(message, repeated, tag = "2")]
+    pub buy: ::prost::alloc::vec::Vec<PriceLevel>,
+    /// Collection of price levels for the sell side of the book.
+    #[prost(message, repeated, tag = "3")]
+    pub sell: ::prost::alloc::vec::Vec<PriceLevel>,
+    /// Sequence number for the market depth data returned.
+    #[prost(uint64, tag = "4")]
+    pub sequence_number: u64,
+}
+/// Represents the changed market depth since the last update
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MarketDepthUpdate {
+    /// Market ID for which the market depth updates are for.
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+    /// Collection of updated price levels for the buy side of the book.
+    #[prost(message, repeated, tag = "2")]
+    pub buy: ::prost::alloc::vec::Vec<PriceLevel>,
+    /// Collection of updated price levels for the sell side of the book.
+    #[prost(message, repeated, tag = "3")]
+    pub sell: ::prost::alloc::vec::Vec<PriceLevel>,
+    /// Sequence number for the market depth update data returned. It is increasing but not monotonic.
+    #[prost(uint64, tag = "4")]
+    pub sequence_number: u64,
+    /// Sequence number of the previous market depth update, for checking there are no gaps.
+    #[prost(uint64, tag = "5")]
+    pub previous_sequence_number: u64,
+}
+/// Represents position data for a party on the specified market on Vega
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Position {
+    /// Market ID in which the position is held.
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+    /// Party ID holding the position.
+    #[prost(string, tag = "2")]
+    pub party_id: ::prost::alloc::string::String,
+    /// Open volume for the position, value is signed +ve for long and -ve for short.
+    #[prost(int64, tag = "3")]
+    pub open_volume: i64,
+    /// Realised profit and loss for the position, value is signed +ve for long and -ve for short.
+    /// This field is a signed integer scaled to the market's decimal places.
+    #[prost(string, tag = "4")]
+    pub realised_pnl: ::prost::alloc::string::String,
+    /// Unrealised profit and loss for the position, value is signed +ve for long and -ve for short.
+    /// This field is a signed integer scaled to the market's decimal places.
+    #[prost(string, tag = "5")]
+    pub unrealised_pnl: ::prost::alloc::string::String,
+    /// Average entry price for the position, the price is an integer, for example `123456` is a correctly
+    /// formatted price of `1.23456` assuming market configured to 5 decimal places.
+    #[prost(string, tag = "6")]
+    pub average_entry_price: ::prost::alloc::string::String,
+    /// Timestamp for the latest time the position was updated.
+    #[prost(int64, tag = "7")]
+    pub updated_at: i64,
+    /// Sum of profit that could not be paid due to loss socialisation.
+    #[prost(string, tag = "8")]
+    pub loss_socialisation_amount: ::prost::alloc::string::String,
+    /// Position status, indicating whether the party was distressed and had orders cancelled or was closed out.
+    #[prost(enumeration = "PositionStatus", tag = "9")]
+    pub position_status: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PositionTrade {
+    /// Volume for the position trade, value is signed +ve for long and -ve for short.
+    #[prost(int64, tag = "1")]
+    pub volume: i64,
+    /// Price for the position trade, the price is an integer, for example `123456` is a correctly
+    /// formatted price of `1.23456` assuming market configured to 5 decimal places.
+    #[prost(string, tag = "2")]
+    pub price: ::prost::alloc::string::String,
+}
+/// Deposit on to the Vega network
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Deposit {
+    /// Unique ID for the deposit.
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Status of the deposit.
+    #[prost(enumeration = "deposit::Status", tag = "2")]
+    pub status: i32,
+    /// Party ID of the user initiating the deposit.
+    #[prost(string, tag = "3")]
+    pub party_id: ::prost::alloc::string::String,
+    /// Vega asset targeted by this deposit.
+    #[prost(string, tag = "4")]
+    pub asset: ::prost::alloc::string::String,
+    /// Amount to be deposited. This field is an unsigned integer scaled to the asset's decimal places.
+    #[prost(string, tag = "5")]
+    pub amount: ::prost::alloc::string::String,
+    /// Hash of the transaction from the foreign chain.
+    #[prost(string, tag = "6")]
+    pub tx_hash: ::prost::alloc::string::String,
+    /// Timestamp for when the Vega account was updated with the deposit.
+    #[prost(int64, tag = "7")]
+    pub credited_timestamp: i64,
+    /// Timestamp for when the deposit was created on the Vega network.
+    #[prost(int64, tag = "8")]
+    pub created_timestamp: i64,
+}
+/// Nested message and enum types in `Deposit`.
+pub mod deposit {
+    /// Status of the deposit
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Status {
+        /// Default value, always invalid
+        Unspecified = 0,
+        /// Deposit is being processed by the network
+        Open = 1,
+        /// Deposit has been cancelled or failed to be verified by the network
+        Cancelled = 2,
+        /// Deposit has been finalised and accounts have been updated
+        Finalized = 3,
+        /// Deposit has been rejected as a duplicate transaction.
+        DuplicateRejected = 4,
+    }
+    impl Status {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Status::Unspecified => "STATUS_UNSPECIFIED",
+                Status::Open => "STATUS_OPEN",
+                Status::Cancelled => "STATUS_CANCELLED",
+                Status::Finalized => "STATUS_FINALIZED",
+                Status::DuplicateRejected => "STATUS_DUPLICATE_REJECTED",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "STATUS_UNSPECIFIED" => Some(Self::Unspecified),
+                "STATUS_OPEN" => Some(Self::Open),
+                "STATUS_CANCELLED" => Some(Self::Cancelled),
+                "STATUS_FINALIZED" => Some(Self::Finalized),
+                "STATUS_DUPLICATE_REJECTED" => Some(Self::DuplicateRejected),
+                _ => None,
+            }
+        }
+    }
+}
+/// Withdrawal from the Vega network
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Withdrawal {
+    /// Unique ID for the withdrawal.
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Unique party ID of the user initiating the withdrawal.
+    #[prost(string, tag = "2")]
+    pub party_id: ::prost::alloc::string::String,
+    /// Amount to be withdrawn. This field is an unsigned integer scaled to the asset's decimal places.
+    #[prost(string, tag = "3")]
+    pub amount: ::prost::alloc::string::String,
+    /// Asset to withdraw funds from.
+    #[prost(string, tag = "4")]
+    pub asset: ::prost::alloc::string::String,
+    /// Status of the withdrawal.
+    #[prost(enumeration = "withdrawal::Status", tag = "5")]
+    pub status: i32,
+    /// Reference which is used by the foreign chain
+    /// to refer to this withdrawal.
+    #[prost(string, tag = "6")]
+    pub r#ref: ::prost::alloc::string::String,
+    /// Hash of the foreign chain for this transaction.
+    #[prost(string, tag = "8")]
+    pub tx_hash: ::prost::alloc::string::String,
+    /// Timestamp for when the network started to process this withdrawal.
+    #[prost(int64, tag = "9")]
+    pub created_timestamp: i64,
+    /// Timestamp for when the withdrawal was finalised by the network.
+    #[prost(int64, tag = "10")]
+    pub withdrawn_timestamp: i64,
+    /// Foreign chain specifics.
+    #[prost(message, optional, tag = "11")]
+    pub ext: ::core::option::Option<WithdrawExt>,
+}
+/// Nested message and enum types in `Withdrawal`.
+pub mod withdrawal {
+    /// Status of the withdrawal
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Status {
+        /// Default value, always invalid
+        Unspecified = 0,
+        /// Withdrawal is open and being processed by the network
+        Open = 1,
+        /// Withdrawal have been cancelled
+        Rejected = 2,
+        /// Withdrawal went through and is fully finalised, the funds are removed from the
+        /// Vega network and are unlocked on the foreign chain bridge, for example, on the Ethereum network
+        Finalized = 3,
+    }
+    impl Status {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Status::Unspecified => "STATUS_UNSPECIFIED",
+                Status::Open => "STATUS_OPEN",
+                Status::Rejected => "STATUS_REJECTED",
+                Status::Finalized => "STATUS_FINALIZED",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "STATUS_UNSPECIFIED" => Some(Self::Unspecified),
+                "STATUS_OPEN" => Some(Self::Open),
+                "STATUS_REJECTED" => Some(Self::Rejected),
+                "STATUS_FINALIZED" => Some(Self::Finalized),
+                _ => None,
+            }
+        }
+    }
+}
+/// Withdrawal external details
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WithdrawExt {
+    /// Foreign chain specifics.
+    #[prost(oneof = "withdraw_ext::Ext", tags = "1")]
+    pub ext: ::core::option::Option<withdraw_ext::Ext>,
+}
+/// Nested message and enum types in `WithdrawExt`.
+pub mod withdraw_ext {
+    /// Foreign chain specifics.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Ext {
+        /// ERC20 withdrawal details.
+        #[prost(message, tag = "1")]
+        Erc20(super::Erc20WithdrawExt),
+    }
+}
+/// Extension of data required for the withdraw submissions
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Erc20WithdrawExt {
+    /// Address into which the bridge will release the funds.
+    #[prost(string, tag = "1")]
+    pub receiver_address: ::prost::alloc::string::String,
+}
+/// Represents an account for an asset on Vega for a particular owner or party
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Account {
+    /// Unique account ID, used internally by Vega.
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Party that the account belongs to, special values include `network`, which represents the Vega network and is
+    /// most commonly seen during liquidation of distressed trading positions.
+    #[prost(string, tag = "2")]
+    pub owner: ::prost::alloc::string::String,
+    /// Balance of the asset, the balance is an integer, for example `123456` is a correctly
+    /// formatted price of `1.23456` assuming market configured to 5 decimal places
+    /// and importantly balances cannot be negative.
+    #[prost(string, tag = "3")]
+    pub balance: ::prost::alloc::string::String,
+    /// Asset ID for the account.
+    #[prost(string, tag = "4")]
+    pub asset: ::prost::alloc::string::String,
+    /// Market ID for the account, if `AccountType.ACCOUNT_TYPE_GENERAL` this will be empty.
+    #[prost(string, tag = "5")]
+    pub market_id: ::prost::alloc::string::String,
+    /// Account type related to this account.
+    #[prost(enumeration = "AccountType", tag = "6")]
+    pub r#type: i32,
+}
+/// Asset value information used within a transfer
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FinancialAmount {
+    /// Unsigned integer amount of asset scaled to the asset's decimal places.
+    #[prost(string, tag = "1")]
+    pub amount: ::prost::alloc::string::String,
+    /// Asset ID the amount applies to.
+    #[prost(string, tag = "2")]
+    pub asset: ::prost::alloc::string::String,
+}
+/// Represents a financial transfer within Vega
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Transfer {
+    /// Party ID for the owner of the transfer.
+    #[prost(string, tag = "1")]
+    pub owner: ::prost::alloc::string::String,
+    /// Financial amount of an asset to transfer.
+    #[prost(message, optional, tag = "2")]
+    pub amount: ::core::option::Option<FinancialAmount>,
+    /// Type of transfer, gives the reason for the transfer.
+    #[prost(enumeration = "TransferType", tag = "3")]
+    pub r#type: i32,
+    /// Minimum amount. This field is an unsigned integer scaled to the asset's decimal places.
+    #[prost(string, tag = "4")]
+    pub min_amount: ::prost::alloc::string::String,
+    /// Market ID the transfer is for
+    #[prost(string, tag = "5")]
+    pub market_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DispatchStrategy {
+    /// Asset to use for metric.
+    #[prost(string, tag = "1")]
+    pub asset_for_metric: ::prost::alloc::string::String,
+    /// Metric to apply.
+    #[prost(enumeration = "DispatchMetric", tag = "2")]
+    pub metric: i32,
+    /// Optional markets in scope.
+    #[prost(string, repeated, tag = "3")]
+    pub markets: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Mandatory enum that defines the entities within scope.
+    #[prost(enumeration = "EntityScope", tag = "4")]
+    pub entity_scope: i32,
+    /// Optional enum if the entity scope defined is for individuals, which determines the subset of individuals that are eligible to be rewarded.
+    #[prost(enumeration = "IndividualScope", tag = "5")]
+    pub individual_scope: i32,
+    /// Optional list applicable if the reward type has a scope of teams, which allows the funder to define a list of team IDs that are eligible to be rewarded from this transfer
+    #[prost(string, repeated, tag = "6")]
+    pub team_scope: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// The proportion of the top performers in the team for a given metric to be averaged for the metric calculation if the scope is team
+    #[prost(string, tag = "7")]
+    pub n_top_performers: ::prost::alloc::string::String,
+    /// Minimum number of governance (e.g. VEGA) tokens staked for a party to be considered eligible. Defaults to 0
+    #[prost(string, tag = "8")]
+    pub staking_requirement: ::prost::alloc::string::String,
+    /// Minimum notional time-weighted averaged position required for a party to be considered eligible. Defaults to 0
+    #[prost(string, tag = "9")]
+    pub notional_time_weighted_average_position_requirement: ::prost::alloc::string::String,
+    /// Number of epochs to evaluate the metric on
+    #[prost(uint64, tag = "10")]
+    pub window_length: u64,
+    /// Number of epochs after distribution to delay vesting of rewards by
+    #[prost(uint64, tag = "11")]
+    pub lock_period: u64,
+    /// Controls how the reward is distributed between qualifying parties
+    #[prost(enumeration = "DistributionStrategy", tag = "12")]
+    pub distribution_strategy: i32,
+    /// Ordered list, using start rank, defining the rank bands and share ratio for each band. Mandatory for the rank and rank lottery distribution strategies.
+    #[prost(message, repeated, tag = "13")]
+    pub rank_table: ::prost::alloc::vec::Vec<Rank>,
+    /// If set, the actual amount of rewards transferred to each public key during distribution for this transfer will be `min(calculated_reward_in_quantum, cap_reward_fee_multiple × fees_paid_this_epoch_in_quantum).
+    #[prost(string, optional, tag = "14")]
+    pub cap_reward_fee_multiple: ::core::option::Option<::prost::alloc::string::String>,
+    /// Number of epochs between transfers, i.e. when 4, funds will be transferred every 4 epochs with the first transfer occurring 4 epochs after the transaction is processed.
+    #[prost(int32, optional, tag = "15")]
+    pub transfer_interval: ::core::option::Option<i32>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Rank {
+    #[prost(uint32, tag = "1")]
+    pub start_rank: u32,
+    #[prost(uint32, tag = "2")]
+    pub share_ratio: u32,
+}
+/// Represents a request to transfer from one set of accounts to another
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransferRequest {
+    /// One or more accounts to transfer from.
+    #[prost(message, repeated, tag = "1")]
+    pub from_account: ::prost::alloc::vec::Vec<Account>,
+    /// One or more accounts to transfer to.
+    #[prost(message, repeated, tag = "2")]
+    pub to_account: ::prost::alloc::vec::Vec<Account>,
+    /// Amount to transfer for the asset. This field is an unsigned integer scaled to the asset's decimal places.
+    #[prost(string, tag = "3")]
+    pub amount: ::prost::alloc::string::String,
+    /// Minimum amount that needs to be transferred for the transfer request. If this minimum isn't reached, it will error.
+    /// This field is an unsigned integer scaled to the asset's decimal places.
+    #[prost(string, tag = "4")]
+    pub min_amount: ::prost::alloc::string::String,
+    /// Asset ID of the asset being transferred.
+    #[prost(string, tag = "5")]
+    pub asset: ::prost::alloc::string::String,
+    /// Type of the request for transfer.
+    #[prost(enumeration = "TransferType", tag = "7")]
+    pub r#type: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AccountDetails {
+    /// Asset ID of the asset for this account.
+    #[prost(string, tag = "1")]
+    pub asset_id: ::prost::alloc::string::String,
+    /// Type of the account.
+    #[prost(enumeration = "AccountType", tag = "2")]
+    pub r#type: i32,
+    /// Not specified if network account.
+    #[prost(string, optional, tag = "3")]
+    pub owner: ::core::option::Option<::prost::alloc::string::String>,
+    /// Not specified if account is not related to a market.
+    #[prost(string, optional, tag = "4")]
+    pub market_id: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// Represents a ledger entry on Vega
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LedgerEntry {
+    /// One or more accounts to transfer from.
+    #[prost(message, optional, tag = "1")]
+    pub from_account: ::core::option::Option<AccountDetails>,
+    /// One or more accounts to transfer to.
+    #[prost(message, optional, tag = "2")]
+    pub to_account: ::core::option::Option<AccountDetails>,
+    /// Amount to transfer. This field is an unsigned integer scaled to the asset's decimal places.
+    #[prost(string, tag = "3")]
+    pub amount: ::prost::alloc::string::String,
+    /// Transfer type for this entry.
+    #[prost(enumeration = "TransferType", tag = "4")]
+    pub r#type: i32,
+    /// Timestamp in nanoseconds of when the ledger entry was created.
+    #[prost(int64, tag = "5")]
+    pub timestamp: i64,
+    /// Sender account balance after the transfer. This field is an unsigned integer scaled to the asset's decimal places.
+    #[prost(string, tag = "6")]
+    pub from_account_balance: ::prost::alloc::string::String,
+    /// Receiver account balance after the transfer. This field is an unsigned integer scaled to the asset's decimal places.
+    #[prost(string, tag = "7")]
+    pub to_account_balance: ::prost::alloc::string::String,
+    /// Transfer ID the ledger entry relates to.
+    #[prost(string, optional, tag = "8")]
+    pub transfer_id: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// Represents the balance for an account during a transfer
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PostTransferBalance {
+    /// Account relating to the transfer.
+    #[prost(message, optional, tag = "1")]
+    pub account: ::core::option::Option<AccountDetails>,
+    /// Balance relating to the transfer. This field is an unsigned integer scaled to the asset's decimal places.
+    #[prost(string, tag = "2")]
+    pub balance: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LedgerMovement {
+    /// All the entries for these ledger movements.
+    #[prost(message, repeated, tag = "1")]
+    pub entries: ::prost::alloc::vec::Vec<LedgerEntry>,
+    /// Resulting balances once the ledger movement are applied.
+    #[prost(message, repeated, tag = "2")]
+    pub balances: ::prost::alloc::vec::Vec<PostTransferBalance>,
+}
+/// Represents the margin levels for a party on a market at a given time
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MarginLevels {
+    /// Maintenance margin value. This field is an unsigned integer scaled to the asset's decimal places.
+    #[prost(string, tag = "1")]
+    pub maintenance_margin: ::prost::alloc::string::String,
+    /// Margin search level value. This field is an unsigned integer scaled to the asset's decimal places.
+    #[prost(string, tag = "2")]
+    pub search_level: ::prost::alloc::string::String,
+    /// Initial margin value. This field is an unsigned integer scaled to the asset's decimal places.
+    #[prost(string, tag = "3")]
+    pub initial_margin: ::prost::alloc::string::String,
+    /// Collateral release level value. This field is an unsigned integer scaled to the asset's decimal places.
+    #[prost(string, tag = "4")]
+    pub collateral_release_level: ::prost::alloc::string::String,
+    /// Party ID for whom the margin levels apply.
+    #[prost(string, tag = "5")]
+    pub party_id: ::prost::alloc::string::String,
+    /// Market ID for which the margin levels apply.
+    #[prost(string, tag = "6")]
+    pub market_id: ::prost::alloc::string::String,
+    /// Asset ID for which the margin levels apply.
+    #[prost(string, tag = "7")]
+    pub asset: ::prost::alloc::string::String,
+    /// Timestamp in Unix nanoseconds for when the ledger entry was created.
+    #[prost(int64, tag = "8")]
+    pub timestamp: i64,
+    /// Margin required to cover orders in isolated margin mode.
+    #[prost(string, tag = "9")]
+    pub order_margin: ::prost::alloc::string::String,
+    /// Margin mode for the party, cross margin or isolated margin.
+    #[prost(enumeration = "MarginMode", tag = "10")]
+    pub margin_mode: i32,
+    /// Margin factor, relevant only for isolated margin, 0 otherwise.
+    #[prost(string, tag = "11")]
+    pub margin_factor: ::prost::alloc::string::String,
+}
+/// Represents market data specific to a perpetual market.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PerpetualData {
+    /// Current funding payment for the in-progress funding period.
+    #[prost(string, tag = "1")]
+    pub funding_payment: ::prost::alloc::string::String,
+    /// Current funding rate for the in-progress funding period.
+    #[prost(string, tag = "2")]
+    pub funding_rate: ::prost::alloc::string::String,
+    /// Time-weighted-average the internal data-points for the in-progress funding period.
+    #[prost(string, tag = "3")]
+    pub internal_twap: ::prost::alloc::string::String,
+    /// Time-weighted-average the external data points for the in-progress funding period.
+    #[prost(string, tag = "4")]
+    pub external_twap: ::prost::alloc::string::String,
+    /// Funding period sequence number
+    #[prost(uint64, tag = "5")]
+    pub seq_num: u64,
+    /// Funding period start time
+    #[prost(int64, tag = "6")]
+    pub start_time: i64,
+    /// The internal composite price used for perpetual markets.
+    #[prost(string, tag = "7")]
+    pub internal_composite_price: ::prost::alloc::string::String,
+    /// The next time the internal composite price is calculated for the perpetual market, in Unix nanoseconds.
+    #[prost(int64, tag = "8")]
+    pub next_internal_composite_price_calc: i64,
+    /// The method used for calculating the internal composite price, for perpetual markets only.
+    #[prost(enumeration = "CompositePriceType", tag = "9")]
+    pub internal_composite_price_type: i32,
+    /// Last seen value of the settlement oracle.
+    #[prost(string, tag = "10")]
+    pub underlying_index_price: ::prost::alloc::string::String,
+    /// State of the internal composite price.
+    #[prost(message, optional, tag = "11")]
+    pub internal_composite_price_state: ::core::option::Option<CompositePriceState>,
+}
+/// Represents market data specific to a particular product type.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProductData {
+    #[prost(oneof = "product_data::Data", tags = "31")]
+    pub data: ::core::option::Option<product_data::Data>,
+}
+/// Nested message and enum types in `ProductData`.
+pub mod product_data {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Data {
+        #[prost(message, tag = "31")]
+        PerpetualData(super::PerpetualData),
+    }
+}
+/// Represents data generated by a market when open
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MarketData {
+    /// Mark price, as an unsigned integer, for example `123456` is a correctly
+    /// formatted price of `1.23456` assuming market configured to 5 decimal places.
+    #[prost(string, tag = "1")]
+    pub mark_price: ::prost::alloc::string::String,
+    /// Highest price level on an order book for buy orders, as an unsigned integer, for example `123456` is a correctly
+    /// formatted price of `1.23456` assuming market configured to 5 decimal places.
+    #[prost(string, tag = "2")]
+    pub best_bid_price: ::prost::alloc::string::String,
+    /// Aggregated volume being bid at the best bid price, as an integer, for example `123456` is a correctly
+    /// formatted price of `1.23456` assuming market is configured to 5 decimal places.
+    #[prost(uint64, tag = "3")]
+    pub best_bid_volume: u64,
+    /// Lowest price level on an order book for offer orders. This field is an unsigned integer scaled to the market's decimal places.
+    #[prost(string, tag = "4")]
+    pub best_offer_price: ::prost::alloc::string::String,
+    /// Aggregated volume being offered at the best offer price, as an integer, for example `123456` is a correctly
+    /// formatted price of `1.23456` assuming market is configured to 5 decimal places.
+    #[prost(uint64, tag = "5")]
+    pub best_offer_volume: u64,
+    /// Highest price on the order book for buy orders not including pegged orders.
+    /// This field is an unsigned integer scaled to the market's decimal places.
+    #[prost(string, tag = "6")]
+    pub best_static_bid_price: ::prost::alloc::string::String,
+    /// Total volume at the best static bid price excluding pegged orders.
+    #[prost(uint64, tag = "7")]
+    pub best_static_bid_volume: u64,
+    /// Lowest price on the order book for sell orders not including pegged orders.
+    /// This field is an unsigned integer scaled to the market's decimal places.
+    #[prost(string, tag = "8")]
+    pub best_static_offer_price: ::prost::alloc::string::String,
+    /// Total volume at the best static offer price, excluding pegged orders.
+    #[prost(uint64, tag = "9")]
+    pub best_static_offer_volume: u64,
+    /// Arithmetic average of the best bid price and best offer price, as an integer, for example `123456` is a correctly
+    /// formatted price of `1.23456` assuming market configured to 5 decimal places.
+    #[prost(string, tag = "10")]
+    pub mid_price: ::prost::alloc::string::String,
+    /// Arithmetic average of the best static bid price and best static offer price.
+    /// This field is an unsigned integer scaled to the market's decimal places.
+    #[prost(string, tag = "11")]
+    pub static_mid_price: ::prost::alloc::string::String,
+    /// Market ID for the data
+    #[prost(string, tag = "12")]
+    pub market: ::prost::alloc::string::String,
+    /// Timestamp in Unix nanoseconds at which this mark price was relevant.
+    #[prost(int64, tag = "13")]
+    pub timestamp: i64,
+    /// Sum of the size of all positions greater than zero on the market.
+    #[prost(uint64, tag = "14")]
+    pub open_interest: u64,
+    /// Time in seconds until the end of the auction (zero if currently not in auction period).
+    #[prost(int64, tag = "15")]
+    pub auction_end: i64,
+    /// Time until next auction, or start time of the current auction if market is in auction period.
+    #[prost(int64, tag = "16")]
+    pub auction_start: i64,
+    /// Indicative price (zero if not in auction). This field is an unsigned scaled to the market's decimal places.
+    #[prost(string, tag = "17")]
+    pub indicative_price: ::prost::alloc::string::String,
+    /// Indicative volume (zero if not in auction).
+    #[prost(uint64, tag = "18")]
+    pub indicative_volume: u64,
+    /// Current trading mode for the market.
+    #[prost(enumeration = "market::TradingMode", tag = "19")]
+    pub market_trading_mode: i32,
+    /// When a market is in an auction trading mode, this field indicates what triggered the auction.
+    #[prost(enumeration = "AuctionTrigger", tag = "20")]
+    pub trigger: i32,
+    /// When a market auction is extended, this field indicates what caused the extension.
+    #[prost(enumeration = "AuctionTrigger", tag = "21")]
+    pub extension_trigger: i32,
+    /// Targeted stake for the given market. This field is an unsigned integer scaled to the settlement asset's decimal places.
+    #[prost(string, tag = "22")]
+    pub target_stake: ::prost::alloc::string::String,
+    /// Available stake for the given market. This field is an unsigned integer scaled to the settlement asset's decimal places.
+    #[prost(string, tag = "23")]
+    pub supplied_stake: ::prost::alloc::string::String,
+    /// One or more price monitoring bounds for the current timestamp.
+    #[prost(message, repeated, tag = "24")]
+    pub price_monitoring_bounds: ::prost::alloc::vec::Vec<PriceMonitoringBounds>,
+    /// Market value proxy.
+    #[prost(string, tag = "25")]
+    pub market_value_proxy: ::prost::alloc::string::String,
+    /// Equity-like share of liquidity fee for each liquidity provider.
+    #[prost(message, repeated, tag = "26")]
+    pub liquidity_provider_fee_share: ::prost::alloc::vec::Vec<
+        LiquidityProviderFeeShare,
+    >,
+    /// Current state of the market.
+    #[prost(enumeration = "market::State", tag = "27")]
+    pub market_state: i32,
+    /// Time in Unix nanoseconds when the next mark-to-market calculation will occur.
+    #[prost(int64, tag = "28")]
+    pub next_mark_to_market: i64,
+    /// Last traded price of the market. This field is an unsigned integer scaled to the market's decimal places.
+    #[prost(string, tag = "29")]
+    pub last_traded_price: ::prost::alloc::string::String,
+    /// Market growth at the last market time window.
+    #[prost(string, tag = "30")]
+    pub market_growth: ::prost::alloc::string::String,
+    /// Data related to the particular product type of the market.
+    #[prost(message, optional, tag = "31")]
+    pub product_data: ::core::option::Option<ProductData>,
+    /// SLA performance for each liquidity provider.
+    #[prost(message, repeated, tag = "32")]
+    pub liquidity_provider_sla: ::prost::alloc::vec::Vec<LiquidityProviderSla>,
+    /// Time in Unix nanoseconds when the market will next submit a trade to reduce its position.
+    #[prost(int64, tag = "33")]
+    pub next_network_closeout: i64,
+    /// The method used for calculating the mark price.
+    #[prost(enumeration = "CompositePriceType", tag = "34")]
+    pub mark_price_type: i32,
+    /// State of the internal composite price.
+    #[prost(message, optional, tag = "35")]
+    pub mark_price_state: ::core::option::Option<CompositePriceState>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CompositePriceSource {
+    /// Source of the price.
+    #[prost(string, tag = "1")]
+    pub price_source: ::prost::alloc::string::String,
+    /// Current value of the composite source price.
+    #[prost(string, tag = "2")]
+    pub price: ::prost::alloc::string::String,
+    /// Timestamp in Unix nanoseconds when the price source was last updated.
+    #[prost(int64, tag = "3")]
+    pub last_updated: i64,
+}
+/// Underlying state of the composite price..
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CompositePriceState {
+    #[prost(message, repeated, tag = "1")]
+    pub price_sources: ::prost::alloc::vec::Vec<CompositePriceSource>,
+}
+/// Equity-like share of liquidity fee for each liquidity provider
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidityProviderFeeShare {
+    /// Liquidity provider party ID.
+    #[prost(string, tag = "1")]
+    pub party: ::prost::alloc::string::String,
+    /// Share own by this liquidity provider.
+    #[prost(string, tag = "2")]
+    pub equity_like_share: ::prost::alloc::string::String,
+    /// Average entry valuation of the liquidity provider for the market.
+    #[prost(string, tag = "3")]
+    pub average_entry_valuation: ::prost::alloc::string::String,
+    /// Average liquidity score.
+    #[prost(string, tag = "4")]
+    pub average_score: ::prost::alloc::string::String,
+    /// The virtual stake of this liquidity provider.
+    #[prost(string, tag = "5")]
+    pub virtual_stake: ::prost::alloc::string::String,
+}
+/// SLA performance for each liquidity provider
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidityProviderSla {
+    /// Liquidity provider party ID.
+    #[prost(string, tag = "1")]
+    pub party: ::prost::alloc::string::String,
+    /// Indicates how often LP meets the commitment during the current epoch.
+    #[prost(string, tag = "2")]
+    pub current_epoch_fraction_of_time_on_book: ::prost::alloc::string::String,
+    /// Indicates how often LP met the commitment in the previous epoch.
+    #[prost(string, tag = "3")]
+    pub last_epoch_fraction_of_time_on_book: ::prost::alloc::string::String,
+    /// Indicates the fee penalty amount applied in the previous epoch.
+    #[prost(string, tag = "4")]
+    pub last_epoch_fee_penalty: ::prost::alloc::string::String,
+    /// Shows the bond penalties from past epochs.
+    #[prost(string, tag = "5")]
+    pub last_epoch_bond_penalty: ::prost::alloc::string::String,
+    /// Determines how the fee penalties from past epochs affect future fee revenue.
+    #[prost(string, repeated, tag = "6")]
+    pub hysteresis_period_fee_penalties: ::prost::alloc::vec::Vec<
+        ::prost::alloc::string::String,
+    >,
+    /// Represents the total amount of funds LP must supply. The amount to be supplied is in the market’s
+    /// settlement currency, spread on both buy and sell sides of the order book within a defined range.
+    #[prost(string, tag = "7")]
+    pub required_liquidity: ::prost::alloc::string::String,
+    /// Notional volume of orders within the range provided on the buy side of the book.
+    #[prost(string, tag = "8")]
+    pub notional_volume_buys: ::prost::alloc::string::String,
+    /// Notional volume of orders within the range provided on the sell side of the book.
+    #[prost(string, tag = "9")]
+    pub notional_volume_sells: ::prost::alloc::string::String,
+}
+/// Represents a list of valid (at the current timestamp) price ranges per associated trigger
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PriceMonitoringBounds {
+    /// Minimum price that isn't currently breaching the specified price monitoring trigger.
+    /// This field is an unsigned integer scaled to the market's decimal places.
+    #[prost(string, tag = "1")]
+    pub min_valid_price: ::prost::alloc::string::String,
+    /// Maximum price that isn't currently breaching the specified price monitoring trigger.
+    /// This field is an unsigned integer scaled to the market's decimal places.
+    #[prost(string, tag = "2")]
+    pub max_valid_price: ::prost::alloc::string::String,
+    /// Price monitoring trigger associated with the bounds.
+    #[prost(message, optional, tag = "3")]
+    pub trigger: ::core::option::Option<PriceMonitoringTrigger>,
+    /// Reference price used to calculate the valid price range. This field is an unsigned integer scaled to the market's decimal places.
+    #[prost(string, tag = "4")]
+    pub reference_price: ::prost::alloc::string::String,
+    /// Has this bound been triggered yet or is it still active.
+    #[prost(bool, tag = "5")]
+    pub active: bool,
+}
+/// Represents Vega domain specific error information over gRPC/Protobuf
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ErrorDetail {
+    /// Vega API domain specific unique error code, useful for client side mappings, e.g. 10004.
+    #[prost(int32, tag = "1")]
+    pub code: i32,
+    /// Message that describes the error in more detail, should describe the problem encountered.
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+    /// Any inner error information that could add more context, or be helpful for error reporting.
+    #[prost(string, tag = "3")]
+    pub inner: ::prost::alloc::string::String,
+}
+/// Represents a network parameter on Vega
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NetworkParameter {
+    /// Unique key of the network parameter.
+    #[prost(string, tag = "1")]
+    pub key: ::prost::alloc::string::String,
+    /// Value for the network parameter.
+    #[prost(string, tag = "2")]
+    pub value: ::prost::alloc::string::String,
+}
+/// Network limits, defined in the genesis file
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NetworkLimits {
+    /// Are market proposals allowed at this point in time.
+    #[prost(bool, tag = "1")]
+    pub can_propose_market: bool,
+    /// Are asset proposals allowed at this point in time.
+    #[prost(bool, tag = "2")]
+    pub can_propose_asset: bool,
+    /// Are market proposals enabled on this chain.
+    #[prost(bool, tag = "4")]
+    pub propose_market_enabled: bool,
+    /// Are asset proposals enabled on this chain.
+    #[prost(bool, tag = "5")]
+    pub propose_asset_enabled: bool,
+    /// True once the genesis file is loaded.
+    #[prost(bool, tag = "7")]
+    pub genesis_loaded: bool,
+    /// Timestamp in Unix nanoseconds at which market proposals will be enabled (0 indicates not set).
+    #[prost(int64, tag = "8")]
+    pub propose_market_enabled_from: i64,
+    /// Timestamp in Unix nanoseconds at which asset proposals will be enabled (0 indicates not set).
+    #[prost(int64, tag = "9")]
+    pub propose_asset_enabled_from: i64,
+    /// Are spot market proposals allowed at this point in time.
+    #[prost(bool, tag = "10")]
+    pub can_propose_spot_market: bool,
+    /// Are perpetual market proposals allowed at this point in time.
+    #[prost(bool, tag = "11")]
+    pub can_propose_perpetual_market: bool,
+    /// Can parties use AMM related transactions.
+    #[prost(bool, tag = "12")]
+    pub can_use_amm: bool,
+}
+/// Represents a liquidity order
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidityOrder {
+    /// Pegged reference point for the order.
+    #[prost(enumeration = "PeggedReference", tag = "1")]
+    pub reference: i32,
+    /// Relative proportion of the commitment to be allocated at a price level.
+    #[prost(uint32, tag = "2")]
+    pub proportion: u32,
+    /// Offset/amount of units away for the order. This field is an unsigned integer scaled using the market's decimal places.
+    #[prost(string, tag = "3")]
+    pub offset: ::prost::alloc::string::String,
+}
+/// Pair of a liquidity order and the ID of the generated order
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidityOrderReference {
+    /// Unique ID of the pegged order generated to fulfil this liquidity order.
+    #[prost(string, tag = "1")]
+    pub order_id: ::prost::alloc::string::String,
+    /// Liquidity order from the original submission.
+    #[prost(message, optional, tag = "2")]
+    pub liquidity_order: ::core::option::Option<LiquidityOrder>,
+}
+/// Liquidity provider commitment
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidityProvision {
+    /// Unique ID for the liquidity provision.
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Unique party ID for the creator of the provision.
+    #[prost(string, tag = "2")]
+    pub party_id: ::prost::alloc::string::String,
+    /// Timestamp in Unix nanoseconds for when the liquidity provision was created.
+    #[prost(int64, tag = "3")]
+    pub created_at: i64,
+    /// Timestamp in Unix nanoseconds for when the liquidity provision was updated.
+    #[prost(int64, tag = "4")]
+    pub updated_at: i64,
+    /// Market ID for the liquidity provision.
+    #[prost(string, tag = "5")]
+    pub market_id: ::prost::alloc::string::String,
+    /// Specified as a unitless number that represents the amount of settlement asset of the market.
+    /// This field is an unsigned integer scaled to the asset's decimal places.
+    #[prost(string, tag = "6")]
+    pub commitment_amount: ::prost::alloc::string::String,
+    /// Nominated liquidity fee factor, which is an input to the calculation of taker fees on the market, as per setting fees and rewarding liquidity providers.
+    #[prost(string, tag = "7")]
+    pub fee: ::prost::alloc::string::String,
+    /// Set of liquidity sell orders to meet the liquidity provision obligation.
+    #[prost(message, repeated, tag = "8")]
+    pub sells: ::prost::alloc::vec::Vec<LiquidityOrderReference>,
+    /// Set of liquidity buy orders to meet the liquidity provision obligation.
+    #[prost(message, repeated, tag = "9")]
+    pub buys: ::prost::alloc::vec::Vec<LiquidityOrderReference>,
+    /// Version of this liquidity provision.
+    #[prost(uint64, tag = "10")]
+    pub version: u64,
+    /// Status of this liquidity provision.
+    #[prost(enumeration = "liquidity_provision::Status", tag = "11")]
+    pub status: i32,
+    /// Reference shared between this liquidity provision and all its orders.
+    #[prost(string, tag = "12")]
+    pub reference: ::prost::alloc::string::String,
+}
+/// Nested message and enum types in `LiquidityProvision`.
+pub mod liquidity_provision {
+    /// Status of a liquidity provision.
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Status {
+        /// Always invalid
+        Unspecified = 0,
+        /// Liquidity provision is active
+        Active = 1,
+        /// Liquidity provision was stopped by the network
+        Stopped = 2,
+        /// Liquidity provision was cancelled by the liquidity provider
+        Cancelled = 3,
+        /// Liquidity provision was invalid and got rejected
+        Rejected = 4,
+        /// Liquidity provision is valid and accepted by network, but orders aren't deployed
+        Undeployed = 5,
+        /// Liquidity provision is valid and accepted by network
+        /// but has never been deployed. If when it's possible to deploy the orders for the first time
+        /// margin check fails, then they will be cancelled without any penalties.
+        Pending = 6,
+    }
+    impl Status {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Status::Unspecified => "STATUS_UNSPECIFIED",
+                Status::Active => "STATUS_ACTIVE",
+                Status::Stopped => "STATUS_STOPPED",
+                Status::Cancelled => "STATUS_CANCELLED",
+                Status::Rejected => "STATUS_REJECTED",
+                Status::Undeployed => "STATUS_UNDEPLOYED",
+                Status::Pending => "STATUS_PENDING",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "STATUS_UNSPECIFIED" => Some(Self::Unspecified),
+                "STATUS_ACTIVE" => Some(Self::Active),
+                "STATUS_STOPPED" => Some(Self::Stopped),
+                "STATUS_CANCELLED" => Some(Self::Cancelled),
+                "STATUS_REJECTED" => Some(Self::Rejected),
+                "STATUS_UNDEPLOYED" => Some(Self::Undeployed),
+                "STATUS_PENDING" => Some(Self::Pending),
+                _ => None,
+            }
+        }
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EthereumL2Config {
+    /// Network ID of this Ethereum layer 2 network.
+    #[prost(string, tag = "1")]
+    pub network_id: ::prost::alloc::string::String,
+    /// Chain ID of this Ethereum layer 2 network.
+    #[prost(string, tag = "2")]
+    pub chain_id: ::prost::alloc::string::String,
+    /// Number of block confirmations to wait to consider an Ethereum transaction trusted.
+    /// An Ethereum block is trusted when there are at least "n" blocks confirmed by the
+    /// network, "n" being the number of `confirmations` required. If `confirmations` was set to `3`,
+    /// and the current block to be forged, or mined, on the L2 is block 14, block
+    /// 10 would be considered as trusted, but not block 11.
+    #[prost(uint32, tag = "3")]
+    pub confirmations: u32,
+    /// Display name of this network
+    #[prost(string, tag = "4")]
+    pub name: ::prost::alloc::string::String,
+    /// Polling interval for the internal engine, needs to be adapted
+    /// to the source chain depending on its block production rate.
+    #[prost(uint64, tag = "5")]
+    pub block_interval: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EthereumL2Configs {
+    #[prost(message, repeated, tag = "1")]
+    pub configs: ::prost::alloc::vec::Vec<EthereumL2Config>,
+}
+/// Ethereum configuration details.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EthereumConfig {
+    /// Network ID of this Ethereum network.
+    #[prost(string, tag = "1")]
+    pub network_id: ::prost::alloc::string::String,
+    /// Chain ID of this Ethereum network.
+    #[prost(string, tag = "2")]
+    pub chain_id: ::prost::alloc::string::String,
+    /// Contract configuration of the collateral bridge contract for this Ethereum network.
+    #[prost(message, optional, tag = "3")]
+    pub collateral_bridge_contract: ::core::option::Option<EthereumContractConfig>,
+    /// Number of block confirmations to wait to consider an Ethereum transaction trusted.
+    /// An Ethereum block is trusted when there are at least "n" blocks confirmed by the
+    /// network, "n" being the number of `confirmations` required. If `confirmations` was set to `3`,
+    /// and the current block to be forged (or mined) on Ethereum is block 14, block
+    /// 10 would be considered as trusted, but not block 11.
+    #[prost(uint32, tag = "4")]
+    pub confirmations: u32,
+    /// Contract configuration of the stacking bridge contract for this Ethereum network.
+    #[prost(message, optional, tag = "5")]
+    pub staking_bridge_contract: ::core::option::Option<EthereumContractConfig>,
+    /// Contract configuration of the token vesting contract for this Ethereum network.
+    #[prost(message, optional, tag = "6")]
+    pub token_vesting_contract: ::core::option::Option<EthereumContractConfig>,
+    /// Contract configuration of the multisig control contract for this Ethereum network.
+    #[prost(message, optional, tag = "7")]
+    pub multisig_control_contract: ::core::option::Option<EthereumContractConfig>,
+    /// Approximate block time of the EVM chain as a duration e.g. 12s, 250ms.
+    #[prost(string, tag = "8")]
+    pub block_time: ::prost::alloc::string::String,
+}
+/// EVM Chain configuration details.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EvmBridgeConfig {
+    /// Network ID of this EVM compatible network.
+    #[prost(string, tag = "1")]
+    pub network_id: ::prost::alloc::string::String,
+    /// Chain ID of this EVM compatible network.
+    #[prost(string, tag = "2")]
+    pub chain_id: ::prost::alloc::string::String,
+    /// Contract configuration of the collateral bridge contract for this EVM compatible network.
+    #[prost(message, optional, tag = "3")]
+    pub collateral_bridge_contract: ::core::option::Option<EthereumContractConfig>,
+    /// Number of block confirmations to wait to consider an EVM compatible chain transaction trusted.
+    /// An EVM compatible chain block is trusted when there are at least "n" blocks confirmed by the
+    /// network, "n" being the number of `confirmations` required. If `confirmations` was set to `3`,
+    /// and the current block to be forged (or mined) on the EVM compatible chain is block 14, block
+    /// 10 would be considered as trusted, but not block 11.
+    #[prost(uint32, tag = "4")]
+    pub confirmations: u32,
+    /// Contract configuration of the multisig control contract for this EVM compatible network.
+    #[prost(message, optional, tag = "5")]
+    pub multisig_control_contract: ::core::option::Option<EthereumContractConfig>,
+    /// Approximate block time of the EVM chain as a duration e.g. 12s, 250ms.
+    #[prost(string, tag = "6")]
+    pub block_time: ::prost::alloc::string::String,
+    /// Display name of this network.
+    #[prost(string, tag = "7")]
+    pub name: ::prost::alloc::string::String,
+}
+/// A list of EVM bridge configurations
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EvmBridgeConfigs {
+    /// The EVM configurations
+    #[prost(message, repeated, tag = "1")]
+    pub configs: ::prost::alloc::vec::Vec<EvmBridgeConfig>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EthereumContractConfig {
+    /// Address of the contract for this EVM compatible network. The address should start with "0x".
+    #[prost(string, tag = "1")]
+    pub address: ::prost::alloc::string::String,
+    /// Block height at which the stacking contract has been deployed for this Ethereum network.
+    #[prost(uint64, tag = "6")]
+    pub deployment_block_height: u64,
+}
+/// Describes in both human readable and block time when an epoch spans
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EpochTimestamps {
+    /// Timestamp in Unix nanoseconds for when epoch started.
+    #[prost(int64, tag = "1")]
+    pub start_time: i64,
+    /// Timestamp in Unix nanoseconds for the epoch's expiry.
+    #[prost(int64, tag = "2")]
+    pub expiry_time: i64,
+    /// Timestamp in Unix nanoseconds for when the epoch ended, empty if not ended.
+    #[prost(int64, tag = "3")]
+    pub end_time: i64,
+    /// Height of first block in the epoch.
+    #[prost(uint64, tag = "4")]
+    pub first_block: u64,
+    /// Height of last block in the epoch, empty if not ended.
+    #[prost(uint64, tag = "5")]
+    pub last_block: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Epoch {
+    /// Sequence is used as epoch ID.
+    #[prost(uint64, tag = "1")]
+    pub seq: u64,
+    /// Timestamps for start/end etc.
+    #[prost(message, optional, tag = "2")]
+    pub timestamps: ::core::option::Option<EpochTimestamps>,
+    /// Validators that participated in this epoch.
+    #[prost(message, repeated, tag = "3")]
+    pub validators: ::prost::alloc::vec::Vec<Node>,
+    /// List of all delegations in epoch.
+    #[prost(message, repeated, tag = "4")]
+    pub delegations: ::prost::alloc::vec::Vec<Delegation>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EpochParticipation {
+    #[prost(message, optional, tag = "1")]
+    pub epoch: ::core::option::Option<Epoch>,
+    #[prost(uint64, tag = "2")]
+    pub offline: u64,
+    #[prost(uint64, tag = "3")]
+    pub online: u64,
+    #[prost(double, tag = "4")]
+    pub total_rewards: f64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EpochData {
+    /// Total number of epochs since node was created.
+    #[prost(int32, tag = "1")]
+    pub total: i32,
+    /// Total number of offline epochs since node was created.
+    #[prost(int32, tag = "2")]
+    pub offline: i32,
+    /// Total number of online epochs since node was created.
+    #[prost(int32, tag = "3")]
+    pub online: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RankingScore {
+    /// Stake based score - no anti-whaling.
+    #[prost(string, tag = "1")]
+    pub stake_score: ::prost::alloc::string::String,
+    /// Performance based score.
+    #[prost(string, tag = "2")]
+    pub performance_score: ::prost::alloc::string::String,
+    /// Status of the validator in the previous epoch.
+    #[prost(enumeration = "ValidatorNodeStatus", tag = "3")]
+    pub previous_status: i32,
+    /// Status of the validator in the current epoch.
+    #[prost(enumeration = "ValidatorNodeStatus", tag = "4")]
+    pub status: i32,
+    /// Tendermint voting power of the validator.
+    #[prost(uint32, tag = "5")]
+    pub voting_power: u32,
+    /// Final score.
+    #[prost(string, tag = "6")]
+    pub ranking_score: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RewardScore {
+    /// Stake based score - with anti-whaling.
+    #[prost(string, tag = "1")]
+    pub raw_validator_score: ::prost::alloc::string::String,
+    /// Performance based score.
+    #[prost(string, tag = "2")]
+    pub performance_score: ::prost::alloc::string::String,
+    /// Multisig score.
+    #[prost(string, tag = "3")]
+    pub multisig_score: ::prost::alloc::string::String,
+    /// Un-normalised score.
+    #[prost(string, tag = "4")]
+    pub validator_score: ::prost::alloc::string::String,
+    /// Normalised validator score for rewards.
+    #[prost(string, tag = "5")]
+    pub normalised_score: ::prost::alloc::string::String,
+    /// Status of the validator for reward.
+    #[prost(enumeration = "ValidatorNodeStatus", tag = "6")]
+    pub validator_status: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Node {
+    /// Node ID i.e. the node's wallet ID.
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Public key of the node operator.
+    #[prost(string, tag = "2")]
+    pub pub_key: ::prost::alloc::string::String,
+    /// Public key of Tendermint.
+    #[prost(string, tag = "3")]
+    pub tm_pub_key: ::prost::alloc::string::String,
+    /// Ethereum public key of the node.
+    #[prost(string, tag = "4")]
+    pub ethereum_address: ::prost::alloc::string::String,
+    /// URL where users can find out more information on the node.
+    #[prost(string, tag = "5")]
+    pub info_url: ::prost::alloc::string::String,
+    /// Country code for the location of the node.
+    #[prost(string, tag = "6")]
+    pub location: ::prost::alloc::string::String,
+    /// Amount the node operator has put up themselves. This field is an unsigned integer scaled to the asset's decimal places.
+    #[prost(string, tag = "7")]
+    pub staked_by_operator: ::prost::alloc::string::String,
+    /// Amount of stake that has been delegated by token holders. This field is an unsigned integer scaled to the asset's decimal places.
+    #[prost(string, tag = "8")]
+    pub staked_by_delegates: ::prost::alloc::string::String,
+    /// Total amount staked on node. This field is an unsigned integer scaled to the asset's decimal places.
+    #[prost(string, tag = "9")]
+    pub staked_total: ::prost::alloc::string::String,
+    /// Max amount of (wanted) stake. This field is an unsigned integer scaled to the asset's decimal places.
+    #[prost(string, tag = "10")]
+    pub max_intended_stake: ::prost::alloc::string::String,
+    /// Amount of stake on the next epoch. This field is an unsigned integer scaled to the asset's decimal places.
+    #[prost(string, tag = "11")]
+    pub pending_stake: ::prost::alloc::string::String,
+    /// Information about epoch.
+    #[prost(message, optional, tag = "12")]
+    pub epoch_data: ::core::option::Option<EpochData>,
+    /// Node status.
+    #[prost(enumeration = "NodeStatus", tag = "13")]
+    pub status: i32,
+    /// Node's delegations.
+    #[prost(message, repeated, tag = "14")]
+    pub delegations: ::prost::alloc::vec::Vec<Delegation>,
+    /// Node reward score.
+    #[prost(message, optional, tag = "15")]
+    pub reward_score: ::core::option::Option<RewardScore>,
+    /// Node ranking information.
+    #[prost(message, optional, tag = "16")]
+    pub ranking_score: ::core::option::Option<RankingScore>,
+    /// Node name.
+    #[prost(string, tag = "17")]
+    pub name: ::prost::alloc::string::String,
+    /// Avatar url.
+    #[prost(string, tag = "18")]
+    pub avatar_url: ::prost::alloc::string::String,
+}
+/// Details on the collection of nodes for a particular validator status
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NodeSet {
+    /// Total number of nodes in the node set.
+    #[prost(uint32, tag = "1")]
+    pub total: u32,
+    /// Number of nodes in the node set that had a performance score of 0 at the end of the last epoch.
+    #[prost(uint32, tag = "2")]
+    pub inactive: u32,
+    /// IDs of nodes that were promoted into this node set at the start of the epoch.
+    #[prost(string, repeated, tag = "3")]
+    pub promoted: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// IDs of nodes that were demoted into this node set at the start of the epoch.
+    #[prost(string, repeated, tag = "4")]
+    pub demoted: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Total number of nodes allowed in the node set.
+    #[prost(uint32, optional, tag = "5")]
+    pub maximum: ::core::option::Option<u32>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NodeData {
+    /// Total staked amount across all nodes. This field is an unsigned integer scaled to the asset's decimal places.
+    #[prost(string, tag = "1")]
+    pub staked_total: ::prost::alloc::string::String,
+    /// Total number of nodes across all node sets.
+    #[prost(uint32, tag = "2")]
+    pub total_nodes: u32,
+    /// Total number of nodes that had a performance score of 0 at the end of the last epoch.
+    #[prost(uint32, tag = "3")]
+    pub inactive_nodes: u32,
+    /// Details on the set of consensus nodes in the network.
+    #[prost(message, optional, tag = "4")]
+    pub tendermint_nodes: ::core::option::Option<NodeSet>,
+    /// Details on the set of ersatz (standby) nodes in the network.
+    #[prost(message, optional, tag = "5")]
+    pub ersatz_nodes: ::core::option::Option<NodeSet>,
+    /// Details on the set of pending nodes in the network.
+    #[prost(message, optional, tag = "6")]
+    pub pending_nodes: ::core::option::Option<NodeSet>,
+    /// Total uptime for all epochs across all nodes.
+    #[prost(float, tag = "7")]
+    pub uptime: f32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Delegation {
+    /// Party which is delegating.
+    #[prost(string, tag = "1")]
+    pub party: ::prost::alloc::string::String,
+    /// Node ID to delegate to.
+    #[prost(string, tag = "2")]
+    pub node_id: ::prost::alloc::string::String,
+    /// Amount delegated. This field is an unsigned integer scaled to the asset's decimal places.
+    #[prost(string, tag = "3")]
+    pub amount: ::prost::alloc::string::String,
+    /// Epoch of delegation.
+    #[prost(string, tag = "4")]
+    pub epoch_seq: ::prost::alloc::string::String,
+}
+/// Details for a single reward payment
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Reward {
+    /// Asset ID in which the reward is being paid.
+    #[prost(string, tag = "1")]
+    pub asset_id: ::prost::alloc::string::String,
+    /// Party ID to whom the reward is being paid.
+    #[prost(string, tag = "2")]
+    pub party_id: ::prost::alloc::string::String,
+    /// Epoch in which the reward is being paid.
+    #[prost(uint64, tag = "3")]
+    pub epoch: u64,
+    /// Amount paid as a reward. This field is an unsigned integer scaled to the asset's decimal places.
+    #[prost(string, tag = "4")]
+    pub amount: ::prost::alloc::string::String,
+    /// Percentage of total rewards paid in the epoch.
+    #[prost(string, tag = "5")]
+    pub percentage_of_total: ::prost::alloc::string::String,
+    /// Timestamp at which the reward was paid as Unix nano time.
+    #[prost(int64, tag = "6")]
+    pub received_at: i64,
+    /// Market ID in which the reward is being paid.
+    #[prost(string, tag = "7")]
+    pub market_id: ::prost::alloc::string::String,
+    /// Type of reward being paid.
+    #[prost(string, tag = "8")]
+    pub reward_type: ::prost::alloc::string::String,
+    /// The epoch when the reward is being released.
+    #[prost(uint64, tag = "9")]
+    pub locked_until_epoch: u64,
+    /// Amount paid as a reward, expressed in asset's quantum unit.
+    #[prost(string, tag = "10")]
+    pub quantum_amount: ::prost::alloc::string::String,
+    /// ID of the game the reward payment was made for if the payment was made for participation in a game.
+    #[prost(string, optional, tag = "11")]
+    pub game_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// ID of the team the party is a member of, if the party is a member of a participating team,
+    /// and the reward payment was made for participation in a game.
+    /// This field is currently only populated by the rewards API.
+    #[prost(string, optional, tag = "12")]
+    pub team_id: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// Details for rewards for a single asset
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RewardSummary {
+    /// Asset ID in which the reward is being paid.
+    #[prost(string, tag = "1")]
+    pub asset_id: ::prost::alloc::string::String,
+    /// Party ID to whom the reward is being paid.
+    #[prost(string, tag = "2")]
+    pub party_id: ::prost::alloc::string::String,
+    /// Total amount of rewards paid in the asset. This field is an unsigned integer scaled to the asset's decimal places.
+    #[prost(string, tag = "3")]
+    pub amount: ::prost::alloc::string::String,
+}
+/// Details for rewards for a combination of asset, market, and reward type in a given epoch
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EpochRewardSummary {
+    /// Epoch in which the reward is being paid.
+    #[prost(uint64, tag = "1")]
+    pub epoch: u64,
+    /// Asset ID in which the reward is being paid.
+    #[prost(string, tag = "2")]
+    pub asset_id: ::prost::alloc::string::String,
+    /// Market ID in which the reward is being paid.
+    #[prost(string, tag = "3")]
+    pub market_id: ::prost::alloc::string::String,
+    /// Type of reward being paid.
+    #[prost(string, tag = "4")]
+    pub reward_type: ::prost::alloc::string::String,
+    /// Amount distributed. This field is an unsigned integer scaled to the asset's decimal places.
+    #[prost(string, tag = "5")]
+    pub amount: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StateValueProposal {
+    /// State variable ID.
+    #[prost(string, tag = "1")]
+    pub state_var_id: ::prost::alloc::string::String,
+    /// Event ID.
+    #[prost(string, tag = "2")]
+    pub event_id: ::prost::alloc::string::String,
+    /// Key value tolerance triplets.
+    #[prost(message, repeated, tag = "3")]
+    pub kvb: ::prost::alloc::vec::Vec<KeyValueBundle>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct KeyValueBundle {
+    #[prost(string, tag = "1")]
+    pub key: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub tolerance: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub value: ::core::option::Option<StateVarValue>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StateVarValue {
+    #[prost(oneof = "state_var_value::Value", tags = "1, 2, 3")]
+    pub value: ::core::option::Option<state_var_value::Value>,
+}
+/// Nested message and enum types in `StateVarValue`.
+pub mod state_var_value {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Value {
+        #[prost(message, tag = "1")]
+        ScalarVal(super::ScalarValue),
+        #[prost(message, tag = "2")]
+        VectorVal(super::VectorValue),
+        #[prost(message, tag = "3")]
+        MatrixVal(super::MatrixValue),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ScalarValue {
+    #[prost(string, tag = "1")]
+    pub value: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VectorValue {
+    #[prost(string, repeated, tag = "1")]
+    pub value: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MatrixValue {
+    #[prost(message, repeated, tag = "1")]
+    pub value: ::prost::alloc::vec::Vec<VectorValue>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReferralProgram {
+    /// Incremental version of the program. It is incremented after each program
+    /// update.
+    #[prost(uint64, tag = "1")]
+    pub version: u64,
+    /// Unique ID generated from the proposal that created this program.
+    #[prost(string, tag = "2")]
+    pub id: ::prost::alloc::string::String,
+    /// Defined benefit tiers ordered by increasing discounts.
+    #[prost(message, repeated, tag = "3")]
+    pub benefit_tiers: ::prost::alloc::vec::Vec<BenefitTier>,
+    /// Timestamp in Unix nanoseconds, after which when the current epoch ends,
+    /// the program will end and benefits will be disabled.
+    #[prost(int64, tag = "4")]
+    pub end_of_program_timestamp: i64,
+    /// Number of epochs over which the referral set's running volume is evaluated.
+    #[prost(uint64, tag = "5")]
+    pub window_length: u64,
+    /// Defined benefit tiers ordered by increasing reward multiplier. Determines the level of
+    /// benefit a party can expect based on their staking.
+    #[prost(message, repeated, tag = "6")]
+    pub staking_tiers: ::prost::alloc::vec::Vec<StakingTier>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VolumeBenefitTier {
+    /// Required running notional taker volume in quantum units for parties
+    /// to access this tier.
+    #[prost(string, tag = "1")]
+    pub minimum_running_notional_taker_volume: ::prost::alloc::string::String,
+    /// deprecated
+    #[prost(string, tag = "2")]
+    pub volume_discount_factor: ::prost::alloc::string::String,
+    /// Proportion of the taker fees to be discounted.
+    #[prost(message, optional, tag = "3")]
+    pub volume_discount_factors: ::core::option::Option<DiscountFactors>,
+    /// The tier number. It's set by the core, and used in the party fee stats API.
+    #[prost(uint64, optional, tag = "4")]
+    pub tier_number: ::core::option::Option<u64>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BenefitTier {
+    /// Required running notional taker volume in quantum units for parties
+    /// to access this tier.
+    #[prost(string, tag = "1")]
+    pub minimum_running_notional_taker_volume: ::prost::alloc::string::String,
+    /// Required number of epochs a party must have been in a referral set to
+    /// access this tier.
+    #[prost(string, tag = "2")]
+    pub minimum_epochs: ::prost::alloc::string::String,
+    /// deprecated
+    #[prost(string, tag = "3")]
+    pub referral_reward_factor: ::prost::alloc::string::String,
+    /// deprecated
+    #[prost(string, tag = "4")]
+    pub referral_discount_factor: ::prost::alloc::string::String,
+    /// Proportion of the referee's fees to be rewarded to the referrer.
+    #[prost(message, optional, tag = "5")]
+    pub referral_reward_factors: ::core::option::Option<RewardFactors>,
+    /// Referral discount factors for the various fees.
+    #[prost(message, optional, tag = "6")]
+    pub referral_discount_factors: ::core::option::Option<DiscountFactors>,
+    /// The tier number. It's set by the core, and used in the party fee stats API.
+    #[prost(uint64, optional, tag = "7")]
+    pub tier_number: ::core::option::Option<u64>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RewardFactors {
+    /// Proportion of the referee's infrastructure fees to be rewarded to the referrer.
+    #[prost(string, tag = "1")]
+    pub infrastructure_reward_factor: ::prost::alloc::string::String,
+    /// Proportion of the referee's liquidity fees to be rewarded to the referrer.
+    #[prost(string, tag = "2")]
+    pub liquidity_reward_factor: ::prost::alloc::string::String,
+    /// Proportion of the maker fees to be rewarded.
+    #[prost(string, tag = "3")]
+    pub maker_reward_factor: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DiscountFactors {
+    /// Proportion of the referee's infrastructure fee to be discounted.
+    #[prost(string, tag = "1")]
+    pub infrastructure_discount_factor: ::prost::alloc::string::String,
+    /// Proportion of the referee's liquidity fee to be discounted.
+    #[prost(string, tag = "2")]
+    pub liquidity_discount_factor: ::prost::alloc::string::String,
+    /// Proportion of the referee's maker fee to be discounted.
+    #[prost(string, tag = "3")]
+    pub maker_discount_factor: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VestingBenefitTiers {
+    #[prost(message, repeated, tag = "1")]
+    pub tiers: ::prost::alloc::vec::Vec<VestingBenefitTier>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VestingBenefitTier {
+    #[prost(string, tag = "1")]
+    pub minimum_quantum_balance: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub reward_multiplier: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StakingTier {
+    /// Required number of governance tokens ($VEGA) a referrer must have staked to
+    /// receive the multiplier.
+    #[prost(string, tag = "1")]
+    pub minimum_staked_tokens: ::prost::alloc::string::String,
+    /// Multiplier applied to the referral reward factor when calculating referral
+    /// rewards due to the referrer.
+    #[prost(string, tag = "2")]
+    pub referral_reward_multiplier: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VolumeDiscountProgram {
+    /// Incremental version of the program. It is incremented after each program
+    /// update.
+    #[prost(uint64, tag = "1")]
+    pub version: u64,
+    /// Unique ID generated from the proposal that created this program.
+    #[prost(string, tag = "2")]
+    pub id: ::prost::alloc::string::String,
+    /// Defined benefit tiers ordered by increasing discounts.
+    #[prost(message, repeated, tag = "3")]
+    pub benefit_tiers: ::prost::alloc::vec::Vec<VolumeBenefitTier>,
+    /// Timestamp in Unix seconds, after which when the current epoch
+    /// ends, the program will end and benefits will be disabled.
+    #[prost(int64, tag = "4")]
+    pub end_of_program_timestamp: i64,
+    /// Number of epochs over which a referral set's running volume is evaluated.
+    #[prost(uint64, tag = "5")]
+    pub window_length: u64,
+}
+/// A list of activity streak benefit tiers
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ActivityStreakBenefitTiers {
+    /// Defined benefit tiers ordered by increasing reward multipliers.
+    #[prost(message, repeated, tag = "1")]
+    pub tiers: ::prost::alloc::vec::Vec<ActivityStreakBenefitTier>,
+}
+/// An activity streak benefit tier
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ActivityStreakBenefitTier {
+    /// Number of epochs a party must be active to receive the multiplier.
+    #[prost(uint64, tag = "1")]
+    pub minimum_activity_streak: u64,
+    /// Reward multiplier applicable to this tier.
+    #[prost(string, tag = "2")]
+    pub reward_multiplier: ::prost::alloc::string::String,
+    /// Vesting bonus applicable to this tier.
+    #[prost(string, tag = "3")]
+    pub vesting_multiplier: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LongBlockAuction {
+    /// Threshold for a long block.
+    #[prost(string, tag = "1")]
+    pub threshold: ::prost::alloc::string::String,
+    /// Auction duration for the given threshold.
+    #[prost(string, tag = "2")]
+    pub duration: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LongBlockAuctionDurationTable {
+    /// Slice of thresholds and durations for corresponding auctions.
+    #[prost(message, repeated, tag = "1")]
+    pub threshold_and_duration: ::prost::alloc::vec::Vec<LongBlockAuction>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VolumeRebateBenefitTier {
+    /// Fraction of a party's maker volume required for a party to access this tier.
+    #[prost(string, tag = "1")]
+    pub minimum_party_maker_volume_fraction: ::prost::alloc::string::String,
+    /// Additional rebate factor, based on the 'trade value for fee purposes', that a party at this tier will receive when they are the maker side of a trade.
+    #[prost(string, tag = "2")]
+    pub additional_maker_rebate: ::prost::alloc::string::String,
+    /// The tier number. It's set by the core, and used in the party fee stats API.
+    #[prost(uint64, optional, tag = "3")]
+    pub tier_number: ::core::option::Option<u64>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VolumeRebateProgram {
+    /// Incremental version of the program. It is incremented after each program
+    /// update.
+    #[prost(uint64, tag = "1")]
+    pub version: u64,
+    /// Unique ID generated from the proposal that created this program.
+    #[prost(string, tag = "2")]
+    pub id: ::prost::alloc::string::String,
+    /// Defined benefit tiers ordered by increasing rebates.
+    #[prost(message, repeated, tag = "3")]
+    pub benefit_tiers: ::prost::alloc::vec::Vec<VolumeRebateBenefitTier>,
+    /// Timestamp in Unix seconds, after which when the current epoch
+    /// ends, the program will end and benefits will be disabled.
+    #[prost(int64, tag = "4")]
+    pub end_of_program_timestamp: i64,
+    /// Number of epochs over which a referral set's running volume is evaluated.
+    #[prost(uint64, tag = "5")]
+    pub window_length: u64,
+}
+/// Side relates to the direction of an order, to Buy, or Sell
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum Side {
+    /// Default value, always invalid
+    Unspecified = 0,
+    /// Buy order
+    Buy = 1,
+    /// Sell order
+    Sell = 2,
+}
+impl Side {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Side::Unspecified => "SIDE_UNSPECIFIED",
+            Side::Buy => "SIDE_BUY",
+            Side::Sell => "SIDE_SELL",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SIDE_UNSPECIFIED" => Some(Self::Unspecified),
+            "SIDE_BUY" => Some(Self::Buy),
+            "SIDE_SELL" => Some(Self::Sell),
+            _ => None,
+        }
+    }
+}
+/// Represents a set of time intervals that are used when querying for candle-stick data
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum Interval {
+    /// Default value, always invalid
+    Unspecified = 0,
+    /// Block interval is not a fixed amount of time, rather it is used to indicate grouping of events that occur in a single block. It is usually about a second.
+    Block = -1,
+    /// 1 minute.
+    I1m = 60,
+    /// 5 minutes.
+    I5m = 300,
+    /// 15 minutes.
+    I15m = 900,
+    /// 30 minutes.
+    I30m = 1800,
+    /// 1 hour.
+    I1h = 3600,
+    /// 4 hours.
+    I4h = 14400,
+    /// 6 hours.
+    I6h = 21600,
+    /// 8 hours.
+    I8h = 28800,
+    /// 12 hours.
+    I12h = 43200,
+    /// 1 day.
+    I1d = 86400,
+    /// 7 days.
+    I7d = 604800,
+}
+impl Interval {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Interval::Unspecified => "INTERVAL_UNSPECIFIED",
+            Interval::Block => "INTERVAL_BLOCK",
+            Interval::I1m => "INTERVAL_I1M",
+            Interval::I5m => "INTERVAL_I5M",
+            Interval::I15m => "INTERVAL_I15M",
+            Interval::I30m => "INTERVAL_I30M",
+            Interval::I1h => "INTERVAL_I1H",
+            Interval::I4h => "INTERVAL_I4H",
+            Interval::I6h => "INTERVAL_I6H",
+            Interval::I8h => "INTERVAL_I8H",
+            Interval::I12h => "INTERVAL_I12H",
+            Interval::I1d => "INTERVAL_I1D",
+            Interval::I7d => "INTERVAL_I7D",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "INTERVAL_UNSPECIFIED" => Some(Self::Unspecified),
+            "INTERVAL_BLOCK" => Some(Self::Block),
+            "INTERVAL_I1M" => Some(Self::I1m),
+            "INTERVAL_I5M" => Some(Self::I5m),
+            "INTERVAL_I15M" => Some(Self::I15m),
+            "INTERVAL_I30M" => Some(Self::I30m),
+            "INTERVAL_I1H" => Some(Self::I1h),
+            "INTERVAL_I4H" => Some(Self::I4h),
+            "INTERVAL_I6H" => Some(Self::I6h),
+            "INTERVAL_I8H" => Some(Self::I8h),
+            "INTERVAL_I12H" => Some(Self::I12h),
+            "INTERVAL_I1D" => Some(Self::I1d),
+            "INTERVAL_I7D" => Some(Self::I7d),
+            _ => None,
+        }
+    }
+}
+/// Represents the status of a position
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum PositionStatus {
+    Unspecified = 0,
+    OrdersClosed = 1,
+    ClosedOut = 2,
+    Distressed = 4,
+}
+impl PositionStatus {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            PositionStatus::Unspecified => "POSITION_STATUS_UNSPECIFIED",
+            PositionStatus::OrdersClosed => "POSITION_STATUS_ORDERS_CLOSED",
+            PositionStatus::ClosedOut => "POSITION_STATUS_CLOSED_OUT",
+            PositionStatus::Distressed => "POSITION_STATUS_DISTRESSED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "POSITION_STATUS_UNSPECIFIED" => Some(Self::Unspecified),
+            "POSITION_STATUS_ORDERS_CLOSED" => Some(Self::OrdersClosed),
+            "POSITION_STATUS_CLOSED_OUT" => Some(Self::ClosedOut),
+            "POSITION_STATUS_DISTRESSED" => Some(Self::Distressed),
+            _ => None,
+        }
+    }
+}
+/// Auction triggers indicate what condition triggered an auction (if market is in auction mode)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum AuctionTrigger {
+    /// Default value for AuctionTrigger, no auction triggered
+    Unspecified = 0,
+    /// Batch auction
+    Batch = 1,
+    /// Opening auction
+    Opening = 2,
+    /// Price monitoring trigger
+    Price = 3,
+    /// Deprecated
+    Liquidity = 4,
+    /// Liquidity auction due to not enough committed liquidity
+    LiquidityTargetNotMet = 5,
+    /// Deprecated
+    UnableToDeployLpOrders = 6,
+    /// Market is suspended and put into auction via governance
+    GovernanceSuspension = 7,
+    /// Market is suspended in response to a long block
+    LongBlock = 8,
+}
+impl AuctionTrigger {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            AuctionTrigger::Unspecified => "AUCTION_TRIGGER_UNSPECIFIED",
+            AuctionTrigger::Batch => "AUCTION_TRIGGER_BATCH",
+            AuctionTrigger::Opening => "AUCTION_TRIGGER_OPENING",
+            AuctionTrigger::Price => "AUCTION_TRIGGER_PRICE",
+            AuctionTrigger::Liquidity => "AUCTION_TRIGGER_LIQUIDITY",
+            AuctionTrigger::LiquidityTargetNotMet => {
+                "AUCTION_TRIGGER_LIQUIDITY_TARGET_NOT_MET"
+            }
+            AuctionTrigger::UnableToDeployLpOrders => {
+                "AUCTION_TRIGGER_UNABLE_TO_DEPLOY_LP_ORDERS"
+            }
+            AuctionTrigger::GovernanceSuspension => {
+                "AUCTION_TRIGGER_GOVERNANCE_SUSPENSION"
+            }
+            AuctionTrigger::LongBlock => "AUCTION_TRIGGER_LONG_BLOCK",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "AUCTION_TRIGGER_UNSPECIFIED" => Some(Self::Unspecified),
+            "AUCTION_TRIGGER_BATCH" => Some(Self::Batch),
+            "AUCTION_TRIGGER_OPENING" => Some(Self::Opening),
+            "AUCTION_TRIGGER_PRICE" => Some(Self::Price),
+            "AUCTION_TRIGGER_LIQUIDITY" => Some(Self::Liquidity),
+            "AUCTION_TRIGGER_LIQUIDITY_TARGET_NOT_MET" => {
+                Some(Self::LiquidityTargetNotMet)
+            }
+            "AUCTION_TRIGGER_UNABLE_TO_DEPLOY_LP_ORDERS" => {
+                Some(Self::UnableToDeployLpOrders)
+            }
+            "AUCTION_TRIGGER_GOVERNANCE_SUSPENSION" => Some(Self::GovernanceSuspension),
+            "AUCTION_TRIGGER_LONG_BLOCK" => Some(Self::LongBlock),
+            _ => None,
+        }
+    }
+}
+/// Pegged reference defines which price point a pegged order is linked to - meaning
+/// the price for a pegged order is calculated from the value of the reference price point
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum PeggedReference {
+    /// Default value for PeggedReference, no reference given
+    Unspecified = 0,
+    /// Mid price reference
+    Mid = 1,
+    /// Best bid price reference
+    BestBid = 2,
+    /// Best ask price reference
+    BestAsk = 3,
+}
+impl PeggedReference {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            PeggedReference::Unspecified => "PEGGED_REFERENCE_UNSPECIFIED",
+            PeggedReference::Mid => "PEGGED_REFERENCE_MID",
+            PeggedReference::BestBid => "PEGGED_REFERENCE_BEST_BID",
+            PeggedReference::BestAsk => "PEGGED_REFERENCE_BEST_ASK",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "PEGGED_REFERENCE_UNSPECIFIED" => Some(Self::Unspecified),
+            "PEGGED_REFERENCE_MID" => Some(Self::Mid),
+            "PEGGED_REFERENCE_BEST_BID" => Some(Self::BestBid),
+            "PEGGED_REFERENCE_BEST_ASK" => Some(Self::BestAsk),
+            _ => None,
+        }
+    }
+}
+/// OrderError codes are returned in the Order.reason field - If there is an issue
+/// with an order during its life-cycle, it will be marked with `status.ORDER_STATUS_REJECTED`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum OrderError {
+    /// Default value, no error reported
+    Unspecified = 0,
+    /// Order was submitted for a market that does not exist
+    InvalidMarketId = 1,
+    /// Order was submitted with an invalid ID
+    InvalidOrderId = 2,
+    /// Order was amended with a sequence number that was not previous version + 1
+    OutOfSequence = 3,
+    /// Order was amended with an invalid remaining size (e.g. remaining greater than total size)
+    InvalidRemainingSize = 4,
+    /// Node was unable to get Vega (blockchain) time
+    TimeFailure = 5,
+    /// Failed to remove an order from the book
+    RemovalFailure = 6,
+    /// Order with `TimeInForce.TIME_IN_FORCE_GTT` was submitted or amended
+    /// with an expiration that was badly formatted or otherwise invalid
+    InvalidExpirationDatetime = 7,
+    /// Order was submitted or amended with an invalid reference field
+    InvalidOrderReference = 8,
+    /// Order amend was submitted for an order field that cannot not be amended (e.g. order ID)
+    EditNotAllowed = 9,
+    /// Amend failure because amend details do not match original order
+    AmendFailure = 10,
+    /// Order not found in an order book or store
+    NotFound = 11,
+    /// Order was submitted with an invalid or missing party ID
+    InvalidPartyId = 12,
+    /// Order was submitted for a market that has closed
+    MarketClosed = 13,
+    /// Order was submitted, but the party did not have enough collateral to cover the order
+    MarginCheckFailed = 14,
+    /// Order was submitted, but the party did not have an account for this asset
+    MissingGeneralAccount = 15,
+    /// Unspecified internal error
+    InternalError = 16,
+    /// Order was submitted with an invalid or missing size (e.g. 0)
+    InvalidSize = 17,
+    /// Order was submitted with an invalid persistence for its type
+    InvalidPersistence = 18,
+    /// Order was submitted with an invalid type field
+    InvalidType = 19,
+    /// Order was stopped as it would have traded with another order submitted from the same party
+    SelfTrading = 20,
+    /// Order was submitted, but the party did not have enough collateral to cover the fees for the order
+    InsufficientFundsToPayFees = 21,
+    /// Order was submitted with an incorrect or invalid market type
+    IncorrectMarketType = 22,
+    /// Order was submitted with invalid time in force
+    InvalidTimeInForce = 23,
+    /// Good For Normal order has reached the market when it is in auction mode
+    CannotSendGfnOrderDuringAnAuction = 24,
+    /// Good For Auction order has reached the market when it is in continuous trading mode
+    CannotSendGfaOrderDuringContinuousTrading = 25,
+    /// Attempt to amend order to GTT without ExpiryAt
+    CannotAmendToGttWithoutExpiryat = 26,
+    /// Attempt to amend ExpiryAt to a value before CreatedAt
+    ExpiryatBeforeCreatedat = 27,
+    /// Attempt to amend to GTC without an ExpiryAt value
+    CannotHaveGtcAndExpiryat = 28,
+    /// Amending to FOK or IOC is invalid
+    CannotAmendToFokOrIoc = 29,
+    /// Amending to GFA or GFN is invalid
+    CannotAmendToGfaOrGfn = 30,
+    /// Amending from GFA or GFN is invalid
+    CannotAmendFromGfaOrGfn = 31,
+    /// IOC orders are not allowed during auction
+    CannotSendIocOrderDuringAuction = 32,
+    /// FOK orders are not allowed during auction
+    CannotSendFokOrderDuringAuction = 33,
+    /// Pegged orders must be LIMIT orders
+    MustBeLimitOrder = 34,
+    /// Pegged orders can only have TIF GTC or GTT
+    MustBeGttOrGtc = 35,
+    /// Pegged order must have a reference price
+    WithoutReferencePrice = 36,
+    /// Buy pegged order cannot reference best ask price
+    BuyCannotReferenceBestAskPrice = 37,
+    /// Pegged order offset must be >= 0
+    OffsetMustBeGreaterOrEqualToZero = 40,
+    /// Sell pegged order cannot reference best bid price
+    SellCannotReferenceBestBidPrice = 41,
+    /// Pegged order offset must be > zero
+    OffsetMustBeGreaterThanZero = 42,
+    /// Party has an insufficient balance, or does not have
+    /// a general account to submit the order (no deposits made
+    /// for the required asset)
+    InsufficientAssetBalance = 43,
+    /// Cannot amend details of a non pegged details
+    CannotAmendPeggedOrderDetailsOnNonPeggedOrder = 44,
+    /// Could not re-price a pegged order because a market price is unavailable
+    UnableToRepricePeggedOrder = 45,
+    /// It is not possible to amend the price of an existing pegged order
+    UnableToAmendPriceOnPeggedOrder = 46,
+    /// FOK, IOC, or GFN order was rejected because it resulted in trades outside the price bounds
+    NonPersistentOrderOutOfPriceBounds = 47,
+    /// Unable to submit pegged order, temporarily too many pegged orders across all markets
+    TooManyPeggedOrders = 48,
+    /// Post order would trade
+    PostOnlyOrderWouldTrade = 49,
+    /// Post order would trade
+    ReduceOnlyOrderWouldNotReducePosition = 50,
+    /// Isolated margin check failed
+    IsolatedMarginCheckFailed = 51,
+    /// In isolated margin pegged orders are rejected
+    PeggedOrdersNotAllowedInIsolatedMarginMode = 52,
+    /// Order price does not respect market's required tick size
+    PriceNotInTickSize = 53,
+    /// Order price exceeds the max price of the capped future market
+    ///
+    /// Note: If adding an enum value, add a matching entry in:
+    ///        - proto/errors.go (func Error)
+    ///        - gateway/graphql/schema.graphql (enum RejectionReason)
+    ///        - gateway/graphql/helpers_enum.go
+    PriceMustBeLessThanOrEqualToMaxPrice = 54,
+}
+impl OrderError {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            OrderError::Unspecified => "ORDER_ERROR_UNSPECIFIED",
+            OrderError::InvalidMarketId => "ORDER_ERROR_INVALID_MARKET_ID",
+            OrderError::InvalidOrderId => "ORDER_ERROR_INVALID_ORDER_ID",
+            OrderError::OutOfSequence => "ORDER_ERROR_OUT_OF_SEQUENCE",
+            OrderError::InvalidRemainingSize => "ORDER_ERROR_INVALID_REMAINING_SIZE",
+            OrderError::TimeFailure => "ORDER_ERROR_TIME_FAILURE",
+            OrderError::RemovalFailure => "ORDER_ERROR_REMOVAL_FAILURE",
+            OrderError::InvalidExpirationDatetime => {
+                "ORDER_ERROR_INVALID_EXPIRATION_DATETIME"
+            }
+            OrderError::InvalidOrderReference => "ORDER_ERROR_INVALID_ORDER_REFERENCE",
+            OrderError::EditNotAllowed => "ORDER_ERROR_EDIT_NOT_ALLOWED",
+            OrderError::AmendFailure => "ORDER_ERROR_AMEND_FAILURE",
+            OrderError::NotFound => "ORDER_ERROR_NOT_FOUND",
+            OrderError::InvalidPartyId => "ORDER_ERROR_INVALID_PARTY_ID",
+            OrderError::MarketClosed => "ORDER_ERROR_MARKET_CLOSED",
+            OrderError::MarginCheckFailed => "ORDER_ERROR_MARGIN_CHECK_FAILED",
+            OrderError::MissingGeneralAccount => "ORDER_ERROR_MISSING_GENERAL_ACCOUNT",
+            OrderError::InternalError => "ORDER_ERROR_INTERNAL_ERROR",
+            OrderError::InvalidSize => "ORDER_ERROR_INVALID_SIZE",
+            OrderError::InvalidPersistence => "ORDER_ERROR_INVALID_PERSISTENCE",
+            OrderError::InvalidType => "ORDER_ERROR_INVALID_TYPE",
+            OrderError::SelfTrading => "ORDER_ERROR_SELF_TRADING",
+            OrderError::InsufficientFundsToPayFees => {
+                "ORDER_ERROR_INSUFFICIENT_FUNDS_TO_PAY_FEES"
+            }
+            OrderError::IncorrectMarketType => "ORDER_ERROR_INCORRECT_MARKET_TYPE",
+            OrderError::InvalidTimeInForce => "ORDER_ERROR_INVALID_TIME_IN_FORCE",
+            OrderError::CannotSendGfnOrderDuringAnAuction => {
+                "ORDER_ERROR_CANNOT_SEND_GFN_ORDER_DURING_AN_AUCTION"
+            }
+            OrderError::CannotSendGfaOrderDuringContinuousTrading => {
+                "ORDER_ERROR_CANNOT_SEND_GFA_ORDER_DURING_CONTINUOUS_TRADING"
+            }
+            OrderError::CannotAmendToGttWithoutExpiryat => {
+                "ORDER_ERROR_CANNOT_AMEND_TO_GTT_WITHOUT_EXPIRYAT"
+            }
+            OrderError::ExpiryatBeforeCreatedat => {
+                "ORDER_ERROR_EXPIRYAT_BEFORE_CREATEDAT"
+            }
+            OrderError::CannotHaveGtcAndExpiryat => {
+                "ORDER_ERROR_CANNOT_HAVE_GTC_AND_EXPIRYAT"
+            }
+            OrderError::CannotAmendToFokOrIoc => "ORDER_ERROR_CANNOT_AMEND_TO_FOK_OR_IOC",
+            OrderError::CannotAmendToGfaOrGfn => "ORDER_ERROR_CANNOT_AMEND_TO_GFA_OR_GFN",
+            OrderError::CannotAmendFromGfaOrGfn => {
+                "ORDER_ERROR_CANNOT_AMEND_FROM_GFA_OR_GFN"
+            }
+            OrderError::CannotSendIocOrderDuringAuction => {
+                "ORDER_ERROR_CANNOT_SEND_IOC_ORDER_DURING_AUCTION"
+            }
+            OrderError::CannotSendFokOrderDuringAuction => {
+                "ORDER_ERROR_CANNOT_SEND_FOK_ORDER_DURING_AUCTION"
+            }
+            OrderError::MustBeLimitOrder => "ORDER_ERROR_MUST_BE_LIMIT_ORDER",
+            OrderError::MustBeGttOrGtc => "ORDER_ERROR_MUST_BE_GTT_OR_GTC",
+            OrderError::WithoutReferencePrice => "ORDER_ERROR_WITHOUT_REFERENCE_PRICE",
+            OrderError::BuyCannotReferenceBestAskPrice => {
+                "ORDER_ERROR_BUY_CANNOT_REFERENCE_BEST_ASK_PRICE"
+            }
+            OrderError::OffsetMustBeGreaterOrEqualToZero => {
+                "ORDER_ERROR_OFFSET_MUST_BE_GREATER_OR_EQUAL_TO_ZERO"
+            }
+            OrderError::SellCannotReferenceBestBidPrice => {
+                "ORDER_ERROR_SELL_CANNOT_REFERENCE_BEST_BID_PRICE"
+            }
+            OrderError::OffsetMustBeGreaterThanZero => {
+                "ORDER_ERROR_OFFSET_MUST_BE_GREATER_THAN_ZERO"
+            }
+            OrderError::InsufficientAssetBalance => {
+                "ORDER_ERROR_INSUFFICIENT_ASSET_BALANCE"
+            }
+            OrderError::CannotAmendPeggedOrderDetailsOnNonPeggedOrder => {
+                "ORDER_ERROR_CANNOT_AMEND_PEGGED_ORDER_DETAILS_ON_NON_PEGGED_ORDER"
+            }
+            OrderError::UnableToRepricePeggedOrder => {
+                "ORDER_ERROR_UNABLE_TO_REPRICE_PEGGED_ORDER"
+            }
+            OrderError::UnableToAmendPriceOnPeggedOrder => {
+                "ORDER_ERROR_UNABLE_TO_AMEND_PRICE_ON_PEGGED_ORDER"
+            }
+            OrderError::NonPersistentOrderOutOfPriceBounds => {
+                "ORDER_ERROR_NON_PERSISTENT_ORDER_OUT_OF_PRICE_BOUNDS"
+            }
+            OrderError::TooManyPeggedOrders => "ORDER_ERROR_TOO_MANY_PEGGED_ORDERS",
+            OrderError::PostOnlyOrderWouldTrade => {
+                "ORDER_ERROR_POST_ONLY_ORDER_WOULD_TRADE"
+            }
+            OrderError::ReduceOnlyOrderWouldNotReducePosition => {
+                "ORDER_ERROR_REDUCE_ONLY_ORDER_WOULD_NOT_REDUCE_POSITION"
+            }
+            OrderError::IsolatedMarginCheckFailed => {
+                "ORDER_ERROR_ISOLATED_MARGIN_CHECK_FAILED"
+            }
+            OrderError::PeggedOrdersNotAllowedInIsolatedMarginMode => {
+                "ORDER_ERROR_PEGGED_ORDERS_NOT_ALLOWED_IN_ISOLATED_MARGIN_MODE"
+            }
+            OrderError::PriceNotInTickSize => "ORDER_ERROR_PRICE_NOT_IN_TICK_SIZE",
+            OrderError::PriceMustBeLessThanOrEqualToMaxPrice => {
+                "ORDER_ERROR_PRICE_MUST_BE_LESS_THAN_OR_EQUAL_TO_MAX_PRICE"
+            }
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "ORDER_ERROR_UNSPECIFIED" => Some(Self::Unspecified),
+            "ORDER_ERROR_INVALID_MARKET_ID" => Some(Self::InvalidMarketId),
+            "ORDER_ERROR_INVALID_ORDER_ID" => Some(Self::InvalidOrderId),
+            "ORDER_ERROR_OUT_OF_SEQUENCE" => Some(Self::OutOfSequence),
+            "ORDER_ERROR_INVALID_REMAINING_SIZE" => Some(Self::InvalidRemainingSize),
+            "ORDER_ERROR_TIME_FAILURE" => Some(Self::TimeFailure),
+            "ORDER_ERROR_REMOVAL_FAILURE" => Some(Self::RemovalFailure),
+            "ORDER_ERROR_INVALID_EXPIRATION_DATETIME" => {
+                Some(Self::InvalidExpirationDatetime)
+            }
+            "ORDER_ERROR_INVALID_ORDER_REFERENCE" => Some(Self::InvalidOrderReference),
+            "ORDER_ERROR_EDIT_NOT_ALLOWED" => Some(Self::EditNotAllowed),
+            "ORDER_ERROR_AMEND_FAILURE" => Some(Self::AmendFailure),
+            "ORDER_ERROR_NOT_FOUND" => Some(Self::NotFound),
+            "ORDER_ERROR_INVALID_PARTY_ID" => Some(Self::InvalidPartyId),
+            "ORDER_ERROR_MARKET_CLOSED" => Some(Self::MarketClosed),
+            "ORDER_ERROR_MARGIN_CHECK_FAILED" => Some(Self::MarginCheckFailed),
+            "ORDER_ERROR_MISSING_GENERAL_ACCOUNT" => Some(Self::MissingGeneralAccount),
+            "ORDER_ERROR_INTERNAL_ERROR" => Some(Self::InternalError),
+            "ORDER_ERROR_INVALID_SIZE" => Some(Self::InvalidSize),
+            "ORDER_ERROR_INVALID_PERSISTENCE" => Some(Self::InvalidPersistence),
+            "ORDER_ERROR_INVALID_TYPE" => Some(Self::InvalidType),
+            "ORDER_ERROR_SELF_TRADING" => Some(Self::SelfTrading),
+            "ORDER_ERROR_INSUFFICIENT_FUNDS_TO_PAY_FEES" => {
+                Some(Self::InsufficientFundsToPayFees)
+            }
+            "ORDER_ERROR_INCORRECT_MARKET_TYPE" => Some(Self::IncorrectMarketType),
+            "ORDER_ERROR_INVALID_TIME_IN_FORCE" => Some(Self::InvalidTimeInForce),
+            "ORDER_ERROR_CANNOT_SEND_GFN_ORDER_DURING_AN_AUCTION" => {
+                Some(Self::CannotSendGfnOrderDuringAnAuction)
+            }
+            "ORDER_ERROR_CANNOT_SEND_GFA_ORDER_DURING_CONTINUOUS_TRADING" => {
+                Some(Self::CannotSendGfaOrderDuringContinuousTrading)
+            }
+            "ORDER_ERROR_CANNOT_AMEND_TO_GTT_WITHOUT_EXPIRYAT" => {
+                Some(Self::CannotAmendToGttWithoutExpiryat)
+            }
+            "ORDER_ERROR_EXPIRYAT_BEFORE_CREATEDAT" => {
+                Some(Self::ExpiryatBeforeCreatedat)
+            }
+            "ORDER_ERROR_CANNOT_HAVE_GTC_AND_EXPIRYAT" => {
+                Some(Self::CannotHaveGtcAndExpiryat)
+            }
+            "ORDER_ERROR_CANNOT_AMEND_TO_FOK_OR_IOC" => Some(Self::CannotAmendToFokOrIoc),
+            "ORDER_ERROR_CANNOT_AMEND_TO_GFA_OR_GFN" => Some(Self::CannotAmendToGfaOrGfn),
+            "ORDER_ERROR_CANNOT_AMEND_FROM_GFA_OR_GFN" => {
+                Some(Self::CannotAmendFromGfaOrGfn)
+            }
+            "ORDER_ERROR_CANNOT_SEND_IOC_ORDER_DURING_AUCTION" => {
+                Some(Self::CannotSendIocOrderDuringAuction)
+            }
+            "ORDER_ERROR_CANNOT_SEND_FOK_ORDER_DURING_AUCTION" => {
+                Some(Self::CannotSendFokOrderDuringAuction)
+            }
+            "ORDER_ERROR_MUST_BE_LIMIT_ORDER" => Some(Self::MustBeLimitOrder),
+            "ORDER_ERROR_MUST_BE_GTT_OR_GTC" => Some(Self::MustBeGttOrGtc),
+            "ORDER_ERROR_WITHOUT_REFERENCE_PRICE" => Some(Self::WithoutReferencePrice),
+            "ORDER_ERROR_BUY_CANNOT_REFERENCE_BEST_ASK_PRICE" => {
+                Some(Self::BuyCannotReferenceBestAskPrice)
+            }
+            "ORDER_ERROR_OFFSET_MUST_BE_GREATER_OR_EQUAL_TO_ZERO" => {
+                Some(Self::OffsetMustBeGreaterOrEqualToZero)
+            }
+            "ORDER_ERROR_SELL_CANNOT_REFERENCE_BEST_BID_PRICE" => {
+                Some(Self::SellCannotReferenceBestBidPrice)
+            }
+            "ORDER_ERROR_OFFSET_MUST_BE_GREATER_THAN_ZERO" => {
+                Some(Self::OffsetMustBeGreaterThanZero)
+            }
+            "ORDER_ERROR_INSUFFICIENT_ASSET_BALANCE" => {
+                Some(Self::InsufficientAssetBalance)
+            }
+            "ORDER_ERROR_CANNOT_AMEND_PEGGED_ORDER_DETAILS_ON_NON_PEGGED_ORDER" => {
+                Some(Self::CannotAmendPeggedOrderDetailsOnNonPeggedOrder)
+            }
+            "ORDER_ERROR_UNABLE_TO_REPRICE_PEGGED_ORDER" => {
+                Some(Self::UnableToRepricePeggedOrder)
+            }
+            "ORDER_ERROR_UNABLE_TO_AMEND_PRICE_ON_PEGGED_ORDER" => {
+                Some(Self::UnableToAmendPriceOnPeggedOrder)
+            }
+            "ORDER_ERROR_NON_PERSISTENT_ORDER_OUT_OF_PRICE_BOUNDS" => {
+                Some(Self::NonPersistentOrderOutOfPriceBounds)
+            }
+            "ORDER_ERROR_TOO_MANY_PEGGED_ORDERS" => Some(Self::TooManyPeggedOrders),
+            "ORDER_ERROR_POST_ONLY_ORDER_WOULD_TRADE" => {
+                Some(Self::PostOnlyOrderWouldTrade)
+            }
+            "ORDER_ERROR_REDUCE_ONLY_ORDER_WOULD_NOT_REDUCE_POSITION" => {
+                Some(Self::ReduceOnlyOrderWouldNotReducePosition)
+            }
+            "ORDER_ERROR_ISOLATED_MARGIN_CHECK_FAILED" => {
+                Some(Self::IsolatedMarginCheckFailed)
+            }
+            "ORDER_ERROR_PEGGED_ORDERS_NOT_ALLOWED_IN_ISOLATED_MARGIN_MODE" => {
+                Some(Self::PeggedOrdersNotAllowedInIsolatedMarginMode)
+            }
+            "ORDER_ERROR_PRICE_NOT_IN_TICK_SIZE" => Some(Self::PriceNotInTickSize),
+            "ORDER_ERROR_PRICE_MUST_BE_LESS_THAN_OR_EQUAL_TO_MAX_PRICE" => {
+                Some(Self::PriceMustBeLessThanOrEqualToMaxPrice)
+            }
+            _ => None,
+        }
+    }
+}
+/// Vega blockchain status as reported by the node the caller is connected to
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ChainStatus {
+    /// Default value, always invalid
+    Unspecified = 0,
+    /// Blockchain is disconnected
+    Disconnected = 1,
+    /// Blockchain is replaying historic transactions
+    Replaying = 2,
+    /// Blockchain is connected and receiving transactions
+    Connected = 3,
+}
+impl ChainStatus {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            ChainStatus::Unspecified => "CHAIN_STATUS_UNSPECIFIED",
+            ChainStatus::Disconnected => "CHAIN_STATUS_DISCONNECTED",
+            ChainStatus::Replaying => "CHAIN_STATUS_REPLAYING",
+            ChainStatus::Connected => "CHAIN_STATUS_CONNECTED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "CHAIN_STATUS_UNSPECIFIED" => Some(Self::Unspecified),
+            "CHAIN_STATUS_DISCONNECTED" => Some(Self::Disconnected),
+            "CHAIN_STATUS_REPLAYING" => Some(Self::Replaying),
+            "CHAIN_STATUS_CONNECTED" => Some(Self::Connected),
+            _ => None,
+        }
+    }
+}
+/// Various collateral/account types as used by Vega
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum AccountType {
+    /// Default value
+    Unspecified = 0,
+    /// Insurance pool accounts contain insurance pool funds for a market
+    Insurance = 1,
+    /// Settlement accounts exist only during settlement or mark-to-market
+    Settlement = 2,
+    /// Margin accounts contain funds set aside for the margin needed to support a party's open positions.
+    /// Each party will have a margin account for each market they have traded in.
+    /// Required initial margin is allocated to each market from user's general account.
+    /// Collateral in the margin account can't be withdrawn or used as margin on another market until
+    /// it is released back to the general account.
+    /// Vega protocol uses an internal accounting system to segregate funds held as
+    /// margin from other funds to ensure they are never lost or 'double spent'
+    ///
+    /// Margin account funds will vary as margin requirements on positions change
+    Margin = 3,
+    /// General accounts contain the collateral for a party that is not otherwise allocated. A party will
+    /// have multiple general accounts, one for each asset they want
+    /// to trade with
+    ///
+    /// General accounts are where funds are initially deposited or withdrawn from,
+    /// it is also the account where funds are taken to fulfil fees and initial margin requirements
+    General = 4,
+    /// Infrastructure accounts contain fees earned by providing infrastructure on Vega
+    FeesInfrastructure = 5,
+    /// Liquidity accounts contain fees earned by providing liquidity on Vega markets
+    FeesLiquidity = 6,
+    /// This account is created to hold fees earned by placing orders that sit on the book
+    /// and are then matched with an incoming order to create a trade - These fees reward parties
+    /// who provide the best priced liquidity that actually allows trading to take place
+    FeesMaker = 7,
+    /// This account is created to maintain liquidity providers funds commitments
+    Bond = 9,
+    /// External account represents an external source (deposit/withdrawal)
+    External = 10,
+    /// Global insurance account for the asset
+    GlobalInsurance = 11,
+    /// Global reward account for the asset
+    GlobalReward = 12,
+    /// Per asset account used to store pending transfers (if any)
+    PendingTransfers = 13,
+    /// Per asset reward account for fees paid to makers
+    RewardMakerPaidFees = 14,
+    /// Per asset reward account for fees received by makers
+    RewardMakerReceivedFees = 15,
+    /// Per asset reward account for fees received by liquidity providers
+    RewardLpReceivedFees = 16,
+    /// Per asset reward account for market proposers when the market goes above some trading threshold
+    RewardMarketProposers = 17,
+    /// Per asset account for holding in-flight unfilled orders' funds
+    Holding = 18,
+    /// Network controlled liquidity provider's account, per market, to hold accrued liquidity fees.
+    LpLiquidityFees = 19,
+    /// Network controlled liquidity fees bonus distribution account, per market.
+    LiquidityFeesBonusDistribution = 20,
+    /// Network controlled treasury
+    NetworkTreasury = 21,
+    /// Account holding user's rewards for the vesting period
+    VestingRewards = 22,
+    /// Account holding user's rewards after the vesting period
+    VestedRewards = 23,
+    /// Per asset market reward account given for relative return
+    RewardRelativeReturn = 25,
+    /// Per asset market reward account given for return volatility
+    RewardReturnVolatility = 26,
+    /// Per asset market reward account given to validators by their ranking
+    RewardValidatorRanking = 27,
+    /// Per asset account for pending fee referral reward payouts
+    PendingFeeReferralReward = 28,
+    /// Per asset market account for party in isolated margin mode
+    OrderMargin = 29,
+    /// Per asset market reward account for realised return
+    RewardRealisedReturn = 30,
+    /// Per asset account for paid buy-back fees
+    BuyBackFees = 31,
+    /// Per asset market reward account given for average notional
+    RewardAverageNotional = 32,
+    /// Reward account for the eligible entities metric.
+    RewardEligibleEntities = 33,
+}
+impl AccountType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            AccountType::Unspecified => "ACCOUNT_TYPE_UNSPECIFIED",
+            AccountType::Insurance => "ACCOUNT_TYPE_INSURANCE",
+            AccountType::Settlement => "ACCOUNT_TYPE_SETTLEMENT",
+            AccountType::Margin => "ACCOUNT_TYPE_MARGIN",
+            AccountType::General => "ACCOUNT_TYPE_GENERAL",
+            AccountType::FeesInfrastructure => "ACCOUNT_TYPE_FEES_INFRASTRUCTURE",
+            AccountType::FeesLiquidity => "ACCOUNT_TYPE_FEES_LIQUIDITY",
+            AccountType::FeesMaker => "ACCOUNT_TYPE_FEES_MAKER",
+            AccountType::Bond => "ACCOUNT_TYPE_BOND",
+            AccountType::External => "ACCOUNT_TYPE_EXTERNAL",
+            AccountType::GlobalInsurance => "ACCOUNT_TYPE_GLOBAL_INSURANCE",
+            AccountType::GlobalReward => "ACCOUNT_TYPE_GLOBAL_REWARD",
+            AccountType::PendingTransfers => "ACCOUNT_TYPE_PENDING_TRANSFERS",
+            AccountType::RewardMakerPaidFees => "ACCOUNT_TYPE_REWARD_MAKER_PAID_FEES",
+            AccountType::RewardMakerReceivedFees => {
+                "ACCOUNT_TYPE_REWARD_MAKER_RECEIVED_FEES"
+            }
+            AccountType::RewardLpReceivedFees => "ACCOUNT_TYPE_REWARD_LP_RECEIVED_FEES",
+            AccountType::RewardMarketProposers => "ACCOUNT_TYPE_REWARD_MARKET_PROPOSERS",
+            AccountType::Holding => "ACCOUNT_TYPE_HOLDING",
+            AccountType::LpLiquidityFees => "ACCOUNT_TYPE_LP_LIQUIDITY_FEES",
+            AccountType::LiquidityFeesBonusDistribution => {
+                "ACCOUNT_TYPE_LIQUIDITY_FEES_BONUS_DISTRIBUTION"
+            }
+            AccountType::NetworkTreasury => "ACCOUNT_TYPE_NETWORK_TREASURY",
+            AccountType::VestingRewards => "ACCOUNT_TYPE_VESTING_REWARDS",
+            AccountType::VestedRewards => "ACCOUNT_TYPE_VESTED_REWARDS",
+            AccountType::RewardRelativeReturn => "ACCOUNT_TYPE_REWARD_RELATIVE_RETURN",
+            AccountType::RewardReturnVolatility => {
+                "ACCOUNT_TYPE_REWARD_RETURN_VOLATILITY"
+            }
+            AccountType::RewardValidatorRanking => {
+                "ACCOUNT_TYPE_REWARD_VALIDATOR_RANKING"
+            }
+            AccountType::PendingFeeReferralReward => {
+                "ACCOUNT_TYPE_PENDING_FEE_REFERRAL_REWARD"
+            }
+            AccountType::OrderMargin => "ACCOUNT_TYPE_ORDER_MARGIN",
+            AccountType::RewardRealisedReturn => "ACCOUNT_TYPE_REWARD_REALISED_RETURN",
+            AccountType::BuyBackFees => "ACCOUNT_TYPE_BUY_BACK_FEES",
+            AccountType::RewardAverageNotional => "ACCOUNT_TYPE_REWARD_AVERAGE_NOTIONAL",
+            AccountType::RewardEligibleEntities => {
+                "ACCOUNT_TYPE_REWARD_ELIGIBLE_ENTITIES"
+            }
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "ACCOUNT_TYPE_UNSPECIFIED" => Some(Self::Unspecified),
+            "ACCOUNT_TYPE_INSURANCE" => Some(Self::Insurance),
+            "ACCOUNT_TYPE_SETTLEMENT" => Some(Self::Settlement),
+            "ACCOUNT_TYPE_MARGIN" => Some(Self::Margin),
+            "ACCOUNT_TYPE_GENERAL" => Some(Self::General),
+            "ACCOUNT_TYPE_FEES_INFRASTRUCTURE" => Some(Self::FeesInfrastructure),
+            "ACCOUNT_TYPE_FEES_LIQUIDITY" => Some(Self::FeesLiquidity),
+            "ACCOUNT_TYPE_FEES_MAKER" => Some(Self::FeesMaker),
+            "ACCOUNT_TYPE_BOND" => Some(Self::Bond),
+            "ACCOUNT_TYPE_EXTERNAL" => Some(Self::External),
+            "ACCOUNT_TYPE_GLOBAL_INSURANCE" => Some(Self::GlobalInsurance),
+            "ACCOUNT_TYPE_GLOBAL_REWARD" => Some(Self::GlobalReward),
+            "ACCOUNT_TYPE_PENDING_TRANSFERS" => Some(Self::PendingTransfers),
+            "ACCOUNT_TYPE_REWARD_MAKER_PAID_FEES" => Some(Self::RewardMakerPaidFees),
+            "ACCOUNT_TYPE_REWARD_MAKER_RECEIVED_FEES" => {
+                Some(Self::RewardMakerReceivedFees)
+            }
+            "ACCOUNT_TYPE_REWARD_LP_RECEIVED_FEES" => Some(Self::RewardLpReceivedFees),
+            "ACCOUNT_TYPE_REWARD_MARKET_PROPOSERS" => Some(Self::RewardMarketProposers),
+            "ACCOUNT_TYPE_HOLDING" => Some(Self::Holding),
+            "ACCOUNT_TYPE_LP_LIQUIDITY_FEES" => Some(Self::LpLiquidityFees),
+            "ACCOUNT_TYPE_LIQUIDITY_FEES_BONUS_DISTRIBUTION" => {
+                Some(Self::LiquidityFeesBonusDistribution)
+            }
+            "ACCOUNT_TYPE_NETWORK_TREASURY" => Some(Self::NetworkTreasury),
+            "ACCOUNT_TYPE_VESTING_REWARDS" => Some(Self::VestingRewards),
+            "ACCOUNT_TYPE_VESTED_REWARDS" => Some(Self::VestedRewards),
+            "ACCOUNT_TYPE_REWARD_RELATIVE_RETURN" => Some(Self::RewardRelativeReturn),
+            "ACCOUNT_TYPE_REWARD_RETURN_VOLATILITY" => Some(Self::RewardReturnVolatility),
+            "ACCOUNT_TYPE_REWARD_VALIDATOR_RANKING" => Some(Self::RewardValidatorRanking),
+            "ACCOUNT_TYPE_PENDING_FEE_REFERRAL_REWARD" => {
+                Some(Self::PendingFeeReferralReward)
+            }
+            "ACCOUNT_TYPE_ORDER_MARGIN" => Some(Self::OrderMargin),
+            "ACCOUNT_TYPE_REWARD_REALISED_RETURN" => Some(Self::RewardRealisedReturn),
+            "ACCOUNT_TYPE_BUY_BACK_FEES" => Some(Self::BuyBackFees),
+            "ACCOUNT_TYPE_REWARD_AVERAGE_NOTIONAL" => Some(Self::RewardAverageNotional),
+            "ACCOUNT_TYPE_REWARD_ELIGIBLE_ENTITIES" => Some(Self::RewardEligibleEntities),
+            _ => None,
+        }
+    }
+}
+/// Transfers can occur between parties on Vega, these are the types that indicate why a transfer took place
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum TransferType {
+    /// Default value, always invalid
+    Unspecified = 0,
+    /// Funds deducted after final settlement loss
+    Loss = 1,
+    /// Funds added to general account after final settlement gain
+    Win = 2,
+    /// Funds deducted from margin account after mark to market loss
+    MtmLoss = 4,
+    /// Funds added to margin account after mark to market gain
+    MtmWin = 5,
+    /// Funds transferred from general account to meet margin requirement
+    MarginLow = 6,
+    /// Excess margin amount returned to general account
+    MarginHigh = 7,
+    /// Margin confiscated from margin account to fulfil closeout
+    MarginConfiscated = 8,
+    /// Maker fee paid from general account
+    MakerFeePay = 9,
+    /// Maker fee received into general account
+    MakerFeeReceive = 10,
+    /// Infrastructure fee paid from general account
+    InfrastructureFeePay = 11,
+    /// Infrastructure fee received into general account
+    InfrastructureFeeDistribute = 12,
+    /// Liquidity fee paid from general account
+    LiquidityFeePay = 13,
+    /// Liquidity fee received into general account
+    LiquidityFeeDistribute = 14,
+    /// Bond account funded from general account to meet required bond amount
+    BondLow = 15,
+    /// Bond returned to general account after liquidity commitment was reduced
+    BondHigh = 16,
+    /// Funds withdrawn from general account
+    Withdraw = 18,
+    /// Funds deposited to general account
+    Deposit = 19,
+    /// Bond account penalised when liquidity commitment not met
+    BondSlashing = 20,
+    /// Reward payout received
+    RewardPayout = 21,
+    /// Internal Vega network instruction for the collateral engine to move funds from a user's general account into the pending transfers pool
+    TransferFundsSend = 22,
+    /// Internal Vega network instruction for the collateral engine to move funds from the pending transfers pool account into the destination account
+    TransferFundsDistribute = 23,
+    /// Market-related accounts emptied because market has closed
+    ClearAccount = 24,
+    /// Balances restored after network restart
+    CheckpointBalanceRestore = 25,
+    /// Spot trade delivery
+    Spot = 26,
+    /// An internal instruction to transfer a quantity corresponding to an active spot order from a general account into a party holding account.
+    HoldingLock = 27,
+    /// An internal instruction to transfer an excess quantity corresponding to an active spot order from a holding account into a party general account.
+    HoldingRelease = 28,
+    /// Insurance pool fraction transfer from parent to successor market.
+    SuccessorInsuranceFraction = 29,
+    /// Allocates liquidity fee earnings to each liquidity provider's network controlled liquidity fee account.
+    LiquidityFeeAllocate = 30,
+    /// Distributes net fee earnings from liquidity provider's fee account to their general account.
+    LiquidityFeeNetDistribute = 31,
+    /// Applies SLA penalty by moving funds from party's bond account to market's insurance pool.
+    SlaPenaltyBondApply = 32,
+    /// Applies SLA penalty by moving funds from the liquidity provider's fee account to market insurance pool.
+    SlaPenaltyLpFeeApply = 33,
+    /// Collects penalties from the liquidity provider's fee account before the fee revenue is paid, and transfers it to the market's bonus distribution account.
+    LiquidityFeeUnpaidCollect = 34,
+    /// Distributes performance bonus from market bonus to liquidity provider's general account.
+    SlaPerformanceBonusDistribute = 35,
+    /// Funds deducted from margin account after a perpetuals funding loss.
+    PerpetualsFundingLoss = 36,
+    /// Funds added to margin account after a perpetuals funding gain.
+    PerpetualsFundingWin = 37,
+    /// Funds moved from the vesting account to the vested account once the vesting period is reached.
+    RewardsVested = 38,
+    /// Fee referrer reward paid from general account.
+    FeeReferrerRewardPay = 39,
+    /// Fee referrer reward received into general account of the referrer.
+    FeeReferrerRewardDistribute = 44,
+    /// Funds transferred from general account to meet order margin requirement in isolated margin mode.
+    OrderMarginLow = 45,
+    /// Excess order margin amount returned to general account.
+    OrderMarginHigh = 46,
+    /// Transfer from order margin account to margin account due to increase of position.
+    IsolatedMarginLow = 47,
+    /// Transfer from excess order margin account to general account.
+    IsolatedMarginHigh = 48,
+    /// Transfer from a party's general account to their AMM's general account.
+    AmmLow = 49,
+    /// Transfer from an AMM's general account to their owner's general account.
+    AmmHigh = 50,
+    /// Transfer releasing an AMM's general account upon closure.
+    AmmRelease = 51,
+    /// Treasury fee paid from party's general account.
+    TreasuryFeePay = 52,
+    /// Buy-back fee paid into network buy-back account.
+    BuyBackFeePay = 53,
+    /// High-volume maker fee paid from general account
+    HighMakerFeeRebatePay = 54,
+    /// Maker fee received into general account
+    HighMakerFeeRebateReceive = 55,
+}
+impl TransferType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            TransferType::Unspecified => "TRANSFER_TYPE_UNSPECIFIED",
+            TransferType::Loss => "TRANSFER_TYPE_LOSS",
+            TransferType::Win => "TRANSFER_TYPE_WIN",
+            TransferType::MtmLoss => "TRANSFER_TYPE_MTM_LOSS",
+            TransferType::MtmWin => "TRANSFER_TYPE_MTM_WIN",
+            TransferType::MarginLow => "TRANSFER_TYPE_MARGIN_LOW",
+            TransferType::MarginHigh => "TRANSFER_TYPE_MARGIN_HIGH",
+            TransferType::MarginConfiscated => "TRANSFER_TYPE_MARGIN_CONFISCATED",
+            TransferType::MakerFeePay => "TRANSFER_TYPE_MAKER_FEE_PAY",
+            TransferType::MakerFeeReceive => "TRANSFER_TYPE_MAKER_FEE_RECEIVE",
+            TransferType::InfrastructureFeePay => "TRANSFER_TYPE_INFRASTRUCTURE_FEE_PAY",
+            TransferType::InfrastructureFeeDistribute => {
+                "TRANSFER_TYPE_INFRASTRUCTURE_FEE_DISTRIBUTE"
+            }
+            TransferType::LiquidityFeePay => "TRANSFER_TYPE_LIQUIDITY_FEE_PAY",
+            TransferType::LiquidityFeeDistribute => {
+                "TRANSFER_TYPE_LIQUIDITY_FEE_DISTRIBUTE"
+            }
+            TransferType::BondLow => "TRANSFER_TYPE_BOND_LOW",
+            TransferType::BondHigh => "TRANSFER_TYPE_BOND_HIGH",
+            TransferType::Withdraw => "TRANSFER_TYPE_WITHDRAW",
+            TransferType::Deposit => "TRANSFER_TYPE_DEPOSIT",
+            TransferType::BondSlashing => "TRANSFER_TYPE_BOND_SLASHING",
+            TransferType::RewardPayout => "TRANSFER_TYPE_REWARD_PAYOUT",
+            TransferType::TransferFundsSend => "TRANSFER_TYPE_TRANSFER_FUNDS_SEND",
+            TransferType::TransferFundsDistribute => {
+                "TRANSFER_TYPE_TRANSFER_FUNDS_DISTRIBUTE"
+            }
+            TransferType::ClearAccount => "TRANSFER_TYPE_CLEAR_ACCOUNT",
+            TransferType::CheckpointBalanceRestore => {
+                "TRANSFER_TYPE_CHECKPOINT_BALANCE_RESTORE"
+            }
+            TransferType::Spot => "TRANSFER_TYPE_SPOT",
+            TransferType::HoldingLock => "TRANSFER_TYPE_HOLDING_LOCK",
+            TransferType::HoldingRelease => "TRANSFER_TYPE_HOLDING_RELEASE",
+            TransferType::SuccessorInsuranceFraction => {
+                "TRANSFER_TYPE_SUCCESSOR_INSURANCE_FRACTION"
+            }
+            TransferType::LiquidityFeeAllocate => "TRANSFER_TYPE_LIQUIDITY_FEE_ALLOCATE",
+            TransferType::LiquidityFeeNetDistribute => {
+                "TRANSFER_TYPE_LIQUIDITY_FEE_NET_DISTRIBUTE"
+            }
+            TransferType::SlaPenaltyBondApply => "TRANSFER_TYPE_SLA_PENALTY_BOND_APPLY",
+            TransferType::SlaPenaltyLpFeeApply => {
+                "TRANSFER_TYPE_SLA_PENALTY_LP_FEE_APPLY"
+            }
+            TransferType::LiquidityFeeUnpaidCollect => {
+                "TRANSFER_TYPE_LIQUIDITY_FEE_UNPAID_COLLECT"
+            }
+            TransferType::SlaPerformanceBonusDistribute => {
+                "TRANSFER_TYPE_SLA_PERFORMANCE_BONUS_DISTRIBUTE"
+            }
+            TransferType::PerpetualsFundingLoss => {
+                "TRANSFER_TYPE_PERPETUALS_FUNDING_LOSS"
+            }
+            TransferType::PerpetualsFundingWin => "TRANSFER_TYPE_PERPETUALS_FUNDING_WIN",
+            TransferType::RewardsVested => "TRANSFER_TYPE_REWARDS_VESTED",
+            TransferType::FeeReferrerRewardPay => "TRANSFER_TYPE_FEE_REFERRER_REWARD_PAY",
+            TransferType::FeeReferrerRewardDistribute => {
+                "TRANSFER_TYPE_FEE_REFERRER_REWARD_DISTRIBUTE"
+            }
+            TransferType::OrderMarginLow => "TRANSFER_TYPE_ORDER_MARGIN_LOW",
+            TransferType::OrderMarginHigh => "TRANSFER_TYPE_ORDER_MARGIN_HIGH",
+            TransferType::IsolatedMarginLow => "TRANSFER_TYPE_ISOLATED_MARGIN_LOW",
+            TransferType::IsolatedMarginHigh => "TRANSFER_TYPE_ISOLATED_MARGIN_HIGH",
+            TransferType::AmmLow => "TRANSFER_TYPE_AMM_LOW",
+            TransferType::AmmHigh => "TRANSFER_TYPE_AMM_HIGH",
+            TransferType::AmmRelease => "TRANSFER_TYPE_AMM_RELEASE",
+            TransferType::TreasuryFeePay => "TRANSFER_TYPE_TREASURY_FEE_PAY",
+            TransferType::BuyBackFeePay => "TRANSFER_TYPE_BUY_BACK_FEE_PAY",
+            TransferType::HighMakerFeeRebatePay => {
+                "TRANSFER_TYPE_HIGH_MAKER_FEE_REBATE_PAY"
+            }
+            TransferType::HighMakerFeeRebateReceive => {
+                "TRANSFER_TYPE_HIGH_MAKER_FEE_REBATE_RECEIVE"
+            }
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "TRANSFER_TYPE_UNSPECIFIED" => Some(Self::Unspecified),
+            "TRANSFER_TYPE_LOSS" => Some(Self::Loss),
+            "TRANSFER_TYPE_WIN" => Some(Self::Win),
+            "TRANSFER_TYPE_MTM_LOSS" => Some(Self::MtmLoss),
+            "TRANSFER_TYPE_MTM_WIN" => Some(Self::MtmWin),
+            "TRANSFER_TYPE_MARGIN_LOW" => Some(Self::MarginLow),
+            "TRANSFER_TYPE_MARGIN_HIGH" => Some(Self::MarginHigh),
+            "TRANSFER_TYPE_MARGIN_CONFISCATED" => Some(Self::MarginConfiscated),
+            "TRANSFER_TYPE_MAKER_FEE_PAY" => Some(Self::MakerFeePay),
+            "TRANSFER_TYPE_MAKER_FEE_RECEIVE" => Some(Self::MakerFeeReceive),
+            "TRANSFER_TYPE_INFRASTRUCTURE_FEE_PAY" => Some(Self::InfrastructureFeePay),
+            "TRANSFER_TYPE_INFRASTRUCTURE_FEE_DISTRIBUTE" => {
+                Some(Self::InfrastructureFeeDistribute)
+            }
+            "TRANSFER_TYPE_LIQUIDITY_FEE_PAY" => Some(Self::LiquidityFeePay),
+            "TRANSFER_TYPE_LIQUIDITY_FEE_DISTRIBUTE" => {
+                Some(Self::LiquidityFeeDistribute)
+            }
+            "TRANSFER_TYPE_BOND_LOW" => Some(Self::BondLow),
+            "TRANSFER_TYPE_BOND_HIGH" => Some(Self::BondHigh),
+            "TRANSFER_TYPE_WITHDRAW" => Some(Self::Withdraw),
+            "TRANSFER_TYPE_DEPOSIT" => Some(Self::Deposit),
+            "TRANSFER_TYPE_BOND_SLASHING" => Some(Self::BondSlashing),
+            "TRANSFER_TYPE_REWARD_PAYOUT" => Some(Self::RewardPayout),
+            "TRANSFER_TYPE_TRANSFER_FUNDS_SEND" => Some(Self::TransferFundsSend),
+            "TRANSFER_TYPE_TRANSFER_FUNDS_DISTRIBUTE" => {
+                Some(Self::TransferFundsDistribute)
+            }
+            "TRANSFER_TYPE_CLEAR_ACCOUNT" => Some(Self::ClearAccount),
+            "TRANSFER_TYPE_CHECKPOINT_BALANCE_RESTORE" => {
+                Some(Self::CheckpointBalanceRestore)
+            }
+            "TRANSFER_TYPE_SPOT" => Some(Self::Spot),
+            "TRANSFER_TYPE_HOLDING_LOCK" => Some(Self::HoldingLock),
+            "TRANSFER_TYPE_HOLDING_RELEASE" => Some(Self::HoldingRelease),
+            "TRANSFER_TYPE_SUCCESSOR_INSURANCE_FRACTION" => {
+                Some(Self::SuccessorInsuranceFraction)
+            }
+            "TRANSFER_TYPE_LIQUIDITY_FEE_ALLOCATE" => Some(Self::LiquidityFeeAllocate),
+            "TRANSFER_TYPE_LIQUIDITY_FEE_NET_DISTRIBUTE" => {
+                Some(Self::LiquidityFeeNetDistribute)
+            }
+            "TRANSFER_TYPE_SLA_PENALTY_BOND_APPLY" => Some(Self::SlaPenaltyBondApply),
+            "TRANSFER_TYPE_SLA_PENALTY_LP_FEE_APPLY" => Some(Self::SlaPenaltyLpFeeApply),
+            "TRANSFER_TYPE_LIQUIDITY_FEE_UNPAID_COLLECT" => {
+                Some(Self::LiquidityFeeUnpaidCollect)
+            }
+            "TRANSFER_TYPE_SLA_PERFORMANCE_BONUS_DISTRIBUTE" => {
+                Some(Self::SlaPerformanceBonusDistribute)
+            }
+            "TRANSFER_TYPE_PERPETUALS_FUNDING_LOSS" => Some(Self::PerpetualsFundingLoss),
+            "TRANSFER_TYPE_PERPETUALS_FUNDING_WIN" => Some(Self::PerpetualsFundingWin),
+            "TRANSFER_TYPE_REWARDS_VESTED" => Some(Self::RewardsVested),
+            "TRANSFER_TYPE_FEE_REFERRER_REWARD_PAY" => Some(Self::FeeReferrerRewardPay),
+            "TRANSFER_TYPE_FEE_REFERRER_REWARD_DISTRIBUTE" => {
+                Some(Self::FeeReferrerRewardDistribute)
+            }
+            "TRANSFER_TYPE_ORDER_MARGIN_LOW" => Some(Self::OrderMarginLow),
+            "TRANSFER_TYPE_ORDER_MARGIN_HIGH" => Some(Self::OrderMarginHigh),
+            "TRANSFER_TYPE_ISOLATED_MARGIN_LOW" => Some(Self::IsolatedMarginLow),
+            "TRANSFER_TYPE_ISOLATED_MARGIN_HIGH" => Some(Self::IsolatedMarginHigh),
+            "TRANSFER_TYPE_AMM_LOW" => Some(Self::AmmLow),
+            "TRANSFER_TYPE_AMM_HIGH" => Some(Self::AmmHigh),
+            "TRANSFER_TYPE_AMM_RELEASE" => Some(Self::AmmRelease),
+            "TRANSFER_TYPE_TREASURY_FEE_PAY" => Some(Self::TreasuryFeePay),
+            "TRANSFER_TYPE_BUY_BACK_FEE_PAY" => Some(Self::BuyBackFeePay),
+            "TRANSFER_TYPE_HIGH_MAKER_FEE_REBATE_PAY" => {
+                Some(Self::HighMakerFeeRebatePay)
+            }
+            "TRANSFER_TYPE_HIGH_MAKER_FEE_REBATE_RECEIVE" => {
+                Some(Self::HighMakerFeeRebateReceive)
+            }
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum DispatchMetric {
+    Unspecified = 0,
+    /// Dispatch metric that uses the total maker fees paid in the market
+    MakerFeesPaid = 1,
+    /// Dispatch metric that uses the total maker fees received in the market
+    MakerFeesReceived = 2,
+    /// Dispatch metric that uses the total LP fees received in the market
+    LpFeesReceived = 3,
+    /// Dispatch metric that uses total value of the market if above the required threshold and not paid given proposer bonus yet
+    MarketValue = 4,
+    /// Dispatch metric that uses the relative PNL of the party in the market
+    RelativeReturn = 6,
+    /// Dispatch metric that uses return volatility of the party in the market
+    ReturnVolatility = 7,
+    /// Dispatch metric that uses the validator ranking of the validator as metric
+    ValidatorRanking = 8,
+    /// Dispatch metric that uses the realised return of the party in a market
+    RealisedReturn = 9,
+    /// Dispatch metric that uses the time weighted average notional
+    AverageNotional = 10,
+    /// Dispatch metric that uses the eligibility criteria of entities
+    EligibleEntities = 11,
+}
+impl DispatchMetric {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            DispatchMetric::Unspecified => "DISPATCH_METRIC_UNSPECIFIED",
+            DispatchMetric::MakerFeesPaid => "DISPATCH_METRIC_MAKER_FEES_PAID",
+            DispatchMetric::MakerFeesReceived => "DISPATCH_METRIC_MAKER_FEES_RECEIVED",
+            DispatchMetric::LpFeesReceived => "DISPATCH_METRIC_LP_FEES_RECEIVED",
+            DispatchMetric::MarketValue => "DISPATCH_METRIC_MARKET_VALUE",
+            DispatchMetric::RelativeReturn => "DISPATCH_METRIC_RELATIVE_RETURN",
+            DispatchMetric::ReturnVolatility => "DISPATCH_METRIC_RETURN_VOLATILITY",
+            DispatchMetric::ValidatorRanking => "DISPATCH_METRIC_VALIDATOR_RANKING",
+            DispatchMetric::RealisedReturn => "DISPATCH_METRIC_REALISED_RETURN",
+            DispatchMetric::AverageNotional => "DISPATCH_METRIC_AVERAGE_NOTIONAL",
+            DispatchMetric::EligibleEntities => "DISPATCH_METRIC_ELIGIBLE_ENTITIES",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "DISPATCH_METRIC_UNSPECIFIED" => Some(Self::Unspecified),
+            "DISPATCH_METRIC_MAKER_FEES_PAID" => Some(Self::MakerFeesPaid),
+            "DISPATCH_METRIC_MAKER_FEES_RECEIVED" => Some(Self::MakerFeesReceived),
+            "DISPATCH_METRIC_LP_FEES_RECEIVED" => Some(Self::LpFeesReceived),
+            "DISPATCH_METRIC_MARKET_VALUE" => Some(Self::MarketValue),
+            "DISPATCH_METRIC_RELATIVE_RETURN" => Some(Self::RelativeReturn),
+            "DISPATCH_METRIC_RETURN_VOLATILITY" => Some(Self::ReturnVolatility),
+            "DISPATCH_METRIC_VALIDATOR_RANKING" => Some(Self::ValidatorRanking),
+            "DISPATCH_METRIC_REALISED_RETURN" => Some(Self::RealisedReturn),
+            "DISPATCH_METRIC_AVERAGE_NOTIONAL" => Some(Self::AverageNotional),
+            "DISPATCH_METRIC_ELIGIBLE_ENTITIES" => Some(Self::EligibleEntities),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum EntityScope {
+    Unspecified = 0,
+    /// Rewards must be distributed directly to eligible parties.
+    Individuals = 1,
+    /// Rewards must be distributed to directly eligible teams, and then amongst team members
+    Teams = 2,
+}
+impl EntityScope {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            EntityScope::Unspecified => "ENTITY_SCOPE_UNSPECIFIED",
+            EntityScope::Individuals => "ENTITY_SCOPE_INDIVIDUALS",
+            EntityScope::Teams => "ENTITY_SCOPE_TEAMS",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "ENTITY_SCOPE_UNSPECIFIED" => Some(Self::Unspecified),
+            "ENTITY_SCOPE_INDIVIDUALS" => Some(Self::Individuals),
+            "ENTITY_SCOPE_TEAMS" => Some(Self::Teams),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum IndividualScope {
+    Unspecified = 0,
+    /// All parties on the network are within the scope of this reward.
+    All = 1,
+    /// All parties that are part of a team are within the scope of this reward.
+    InTeam = 2,
+    /// All parties that are not part of a team are within the scope of this reward.
+    NotInTeam = 3,
+    /// All keys representing AMMs are within the scope of this reward.
+    Amm = 4,
+}
+impl IndividualScope {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            IndividualScope::Unspecified => "INDIVIDUAL_SCOPE_UNSPECIFIED",
+            IndividualScope::All => "INDIVIDUAL_SCOPE_ALL",
+            IndividualScope::InTeam => "INDIVIDUAL_SCOPE_IN_TEAM",
+            IndividualScope::NotInTeam => "INDIVIDUAL_SCOPE_NOT_IN_TEAM",
+            IndividualScope::Amm => "INDIVIDUAL_SCOPE_AMM",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "INDIVIDUAL_SCOPE_UNSPECIFIED" => Some(Self::Unspecified),
+            "INDIVIDUAL_SCOPE_ALL" => Some(Self::All),
+            "INDIVIDUAL_SCOPE_IN_TEAM" => Some(Self::InTeam),
+            "INDIVIDUAL_SCOPE_NOT_IN_TEAM" => Some(Self::NotInTeam),
+            "INDIVIDUAL_SCOPE_AMM" => Some(Self::Amm),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum DistributionStrategy {
+    Unspecified = 0,
+    /// Rewards funded using the pro-rata strategy should be distributed pro-rata by each entity's reward metric, scaled by any active multipliers that party has.
+    ProRata = 1,
+    /// Rewards funded using the party rank.
+    Rank = 2,
+    /// Rewards funded using the ranked lottery.
+    RankLottery = 3,
+}
+impl DistributionStrategy {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            DistributionStrategy::Unspecified => "DISTRIBUTION_STRATEGY_UNSPECIFIED",
+            DistributionStrategy::ProRata => "DISTRIBUTION_STRATEGY_PRO_RATA",
+            DistributionStrategy::Rank => "DISTRIBUTION_STRATEGY_RANK",
+            DistributionStrategy::RankLottery => "DISTRIBUTION_STRATEGY_RANK_LOTTERY",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "DISTRIBUTION_STRATEGY_UNSPECIFIED" => Some(Self::Unspecified),
+            "DISTRIBUTION_STRATEGY_PRO_RATA" => Some(Self::ProRata),
+            "DISTRIBUTION_STRATEGY_RANK" => Some(Self::Rank),
+            "DISTRIBUTION_STRATEGY_RANK_LOTTERY" => Some(Self::RankLottery),
+            _ => None,
+        }
+    }
+}
+/// Node status type
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum NodeStatus {
+    Unspecified = 0,
+    /// Node is a validating node
+    Validator = 1,
+    /// Node is a non-validating node
+    NonValidator = 2,
+}
+impl NodeStatus {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            NodeStatus::Unspecified => "NODE_STATUS_UNSPECIFIED",
+            NodeStatus::Validator => "NODE_STATUS_VALIDATOR",
+            NodeStatus::NonValidator => "NODE_STATUS_NON_VALIDATOR",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "NODE_STATUS_UNSPECIFIED" => Some(Self::Unspecified),
+            "NODE_STATUS_VALIDATOR" => Some(Self::Validator),
+            "NODE_STATUS_NON_VALIDATOR" => Some(Self::NonValidator),
+            _ => None,
+        }
+    }
+}
+/// What epoch action has occurred
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum EpochAction {
+    Unspecified = 0,
+    /// Epoch update is for a new epoch.
+    Start = 1,
+    /// Epoch update is for the end of an epoch.
+    End = 2,
+}
+impl EpochAction {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            EpochAction::Unspecified => "EPOCH_ACTION_UNSPECIFIED",
+            EpochAction::Start => "EPOCH_ACTION_START",
+            EpochAction::End => "EPOCH_ACTION_END",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "EPOCH_ACTION_UNSPECIFIED" => Some(Self::Unspecified),
+            "EPOCH_ACTION_START" => Some(Self::Start),
+            "EPOCH_ACTION_END" => Some(Self::End),
+            _ => None,
+        }
+    }
+}
+/// Validation status of the node
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ValidatorNodeStatus {
+    Unspecified = 0,
+    /// Node is a tendermint validator
+    Tendermint = 1,
+    /// Node is an ersatz validator
+    Ersatz = 2,
+    /// Node is a pending validator
+    Pending = 3,
+}
+impl ValidatorNodeStatus {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            ValidatorNodeStatus::Unspecified => "VALIDATOR_NODE_STATUS_UNSPECIFIED",
+            ValidatorNodeStatus::Tendermint => "VALIDATOR_NODE_STATUS_TENDERMINT",
+            ValidatorNodeStatus::Ersatz => "VALIDATOR_NODE_STATUS_ERSATZ",
+            ValidatorNodeStatus::Pending => "VALIDATOR_NODE_STATUS_PENDING",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "VALIDATOR_NODE_STATUS_UNSPECIFIED" => Some(Self::Unspecified),
+            "VALIDATOR_NODE_STATUS_TENDERMINT" => Some(Self::Tendermint),
+            "VALIDATOR_NODE_STATUS_ERSATZ" => Some(Self::Ersatz),
+            "VALIDATOR_NODE_STATUS_PENDING" => Some(Self::Pending),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum MarginMode {
+    /// Never valid.
+    Unspecified = 0,
+    /// Cross margin mode - margin is dynamically acquired and released as a position is marked to market
+    CrossMargin = 1,
+    /// Isolated margin mode - margin for any newly opened position volume is transferred to the margin account when the trade is executed
+    IsolatedMargin = 2,
+}
+impl MarginMode {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            MarginMode::Unspecified => "MARGIN_MODE_UNSPECIFIED",
+            MarginMode::CrossMargin => "MARGIN_MODE_CROSS_MARGIN",
+            MarginMode::IsolatedMargin => "MARGIN_MODE_ISOLATED_MARGIN",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "MARGIN_MODE_UNSPECIFIED" => Some(Self::Unspecified),
+            "MARGIN_MODE_CROSS_MARGIN" => Some(Self::CrossMargin),
+            "MARGIN_MODE_ISOLATED_MARGIN" => Some(Self::IsolatedMargin),
+            _ => None,
+        }
+    }
+}
+/// Vega representation of an external asset
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Asset {
+    /// Internal identifier of the asset.
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Definition of the external source for this asset.
+    #[prost(message, optional, tag = "2")]
+    pub details: ::core::option::Option<AssetDetails>,
+    /// Status of the asset.
+    #[prost(enumeration = "asset::Status", tag = "3")]
+    pub status: i32,
+}
+/// Nested message and enum types in `Asset`.
+pub mod asset {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Status {
+        /// Default value, always invalid
+        Unspecified = 0,
+        /// Asset is proposed and under vote
+        Proposed = 1,
+        /// Asset has been rejected from governance
+        Rejected = 2,
+        /// Asset is pending listing from the bridge
+        PendingListing = 3,
+        /// Asset is fully usable in the network
+        Enabled = 4,
+    }
+    impl Status {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Status::Unspecified => "STATUS_UNSPECIFIED",
+                Status::Proposed => "STATUS_PROPOSED",
+                Status::Rejected => "STATUS_REJECTED",
+                Status::PendingListing => "STATUS_PENDING_LISTING",
+                Status::Enabled => "STATUS_ENABLED",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "STATUS_UNSPECIFIED" => Some(Self::Unspecified),
+                "STATUS_PROPOSED" => Some(Self::Proposed),
+                "STATUS_REJECTED" => Some(Self::Rejected),
+                "STATUS_PENDING_LISTING" => Some(Self::PendingListing),
+                "STATUS_ENABLED" => Some(Self::Enabled),
+                _ => None,
+            }
+        }
+    }
+}
+/// Vega representation of an external asset
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AssetDetails {
+    /// Name of the asset (e.g: Great British Pound).
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// Symbol of the asset (e.g: GBP).
+    #[prost(string, tag = "2")]
+    pub symbol: ::prost::alloc::string::String,
+    /// Number of decimal / precision handled by this asset.
+    #[prost(uint64, tag = "4")]
+    pub decimals: u64,
+    /// Minimum economically meaningful amount in the asset.
+    #[prost(string, tag = "5")]
+    pub quantum: ::prost::alloc::string::String,
+    /// Source of the asset
+    #[prost(oneof = "asset_details::Source", tags = "101, 102")]
+    pub source: ::core::option::Option<asset_details::Source>,
+}
+/// Nested message and enum types in `AssetDetails`.
+pub mod asset_details {
+    /// Source of the asset
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Source {
+        /// Vega built-in asset.
+        #[prost(message, tag = "101")]
+        BuiltinAsset(super::BuiltinAsset),
+        /// Ethereum ERC20 asset.
+        #[prost(message, tag = "102")]
+        Erc20(super::Erc20),
+    }
+}
+/// Vega internal asset
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BuiltinAsset {
+    /// Maximum amount that can be requested by a party through the built-in asset faucet at a time.
+    #[prost(string, tag = "1")]
+    pub max_faucet_amount_mint: ::prost::alloc::string::String,
+}
+/// ERC20 token based asset, living on the ethereum network
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Erc20 {
+    /// Address of the contract for the token, on the ethereum network.
+    #[prost(string, tag = "1")]
+    pub contract_address: ::prost::alloc::string::String,
+    /// Lifetime limits deposit per address
+    /// note: this is a temporary measure that can be changed by governance.
+    #[prost(string, tag = "2")]
+    pub lifetime_limit: ::prost::alloc::string::String,
+    /// Maximum you can withdraw instantly. All withdrawals over the threshold will be delayed by the withdrawal delay.
+    /// There’s no limit on the size of a withdrawal
+    /// note: this is a temporary measure that can be changed by governance.
+    #[prost(string, tag = "3")]
+    pub withdraw_threshold: ::prost::alloc::string::String,
+    /// Chain ID the asset originated from.
+    #[prost(string, tag = "4")]
+    pub chain_id: ::prost::alloc::string::String,
+}
+/// Changes to apply on an existing asset.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AssetDetailsUpdate {
+    /// Minimum economically meaningful amount in the asset.
+    #[prost(string, tag = "5")]
+    pub quantum: ::prost::alloc::string::String,
+    /// Source of the asset update
+    #[prost(oneof = "asset_details_update::Source", tags = "101")]
+    pub source: ::core::option::Option<asset_details_update::Source>,
+}
+/// Nested message and enum types in `AssetDetailsUpdate`.
+pub mod asset_details_update {
+    /// Source of the asset update
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Source {
+        /// Ethereum ERC20 asset update.
+        #[prost(message, tag = "101")]
+        Erc20(super::Erc20Update),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Erc20Update {
+    /// Lifetime limits deposit per address.
+    /// This will be interpreted against the asset decimals.
+    /// note: this is a temporary measure that can be changed by governance.
+    #[prost(string, tag = "1")]
+    pub lifetime_limit: ::prost::alloc::string::String,
+    /// Maximum you can withdraw instantly. All withdrawals over the threshold will be delayed by the withdrawal delay.
+    /// There’s no limit on the size of a withdrawal
+    /// note: this is a temporary measure that can be changed by governance.
+    #[prost(string, tag = "2")]
+    pub withdraw_threshold: ::prost::alloc::string::String,
+}
+/// Spot product configuration
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SpotProduct {
+    /// Base asset ID.
+    #[prost(string, tag = "1")]
+    pub base_asset: ::prost::alloc::string::String,
+    /// Quote asset ID.
+    #[prost(string, tag = "2")]
+    pub quote_asset: ::prost::alloc::string::String,
+}
+/// Future product configuration
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FutureProduct {
+    /// Asset ID for the product's settlement asset.
+    #[prost(string, tag = "1")]
+    pub settlement_asset: ::prost::alloc::string::String,
+    /// Product quote name.
+    #[prost(string, tag = "2")]
+    pub quote_name: ::prost::alloc::string::String,
+    /// Data source spec describing the data source for settlement.
+    #[prost(message, optional, tag = "3")]
+    pub data_source_spec_for_settlement_data: ::core::option::Option<
+        DataSourceDefinition,
+    >,
+    /// The external data source spec describing the data source of trading termination.
+    #[prost(message, optional, tag = "4")]
+    pub data_source_spec_for_trading_termination: ::core::option::Option<
+        DataSourceDefinition,
+    >,
+    /// Binding between the data source spec and the settlement data.
+    #[prost(message, optional, tag = "5")]
+    pub data_source_spec_binding: ::core::option::Option<DataSourceSpecToFutureBinding>,
+    /// If set, this product creates a capped future market.
+    #[prost(message, optional, tag = "6")]
+    pub cap: ::core::option::Option<FutureCap>,
+}
+/// Perpetual product configuration
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PerpetualProduct {
+    /// Asset ID for the product's settlement asset.
+    #[prost(string, tag = "1")]
+    pub settlement_asset: ::prost::alloc::string::String,
+    /// Product quote name.
+    #[prost(string, tag = "2")]
+    pub quote_name: ::prost::alloc::string::String,
+    /// Controls how much the upcoming funding payment liability contributes to party's margin, in the range \[0, 1\].
+    #[prost(string, tag = "3")]
+    pub margin_funding_factor: ::prost::alloc::string::String,
+    /// Continuously compounded interest rate used in funding rate calculation, in the range \[-1, 1\].
+    #[prost(string, tag = "4")]
+    pub interest_rate: ::prost::alloc::string::String,
+    /// Lower bound for the clamp function used as part of the funding rate calculation, in the range \[-1, 1\].
+    #[prost(string, tag = "5")]
+    pub clamp_lower_bound: ::prost::alloc::string::String,
+    /// Upper bound for the clamp function used as part of the funding rate calculation, in the range \[-1, 1\].
+    #[prost(string, tag = "6")]
+    pub clamp_upper_bound: ::prost::alloc::string::String,
+    /// Data source spec describing the data source for settlement schedule.
+    #[prost(message, optional, tag = "7")]
+    pub data_source_spec_for_settlement_schedule: ::core::option::Option<
+        DataSourceDefinition,
+    >,
+    /// Data source spec describing the data source for settlement.
+    #[prost(message, optional, tag = "8")]
+    pub data_source_spec_for_settlement_data: ::core::option::Option<
+        DataSourceDefinition,
+    >,
+    /// Binding between the data source spec and the settlement data.
+    #[prost(message, optional, tag = "9")]
+    pub data_source_spec_binding: ::core::option::Option<
+        DataSourceSpecToPerpetualBinding,
+    >,
+    /// Factor applied to funding-rates. This scales the impact that spot price deviations have on funding payments.
+    #[prost(string, optional, tag = "10")]
+    pub funding_rate_scaling_factor: ::core::option::Option<
+        ::prost::alloc::string::String,
+    >,
+    /// Lower bound for the funding-rate such that the funding-rate will never be lower than this value.
+    #[prost(string, optional, tag = "11")]
+    pub funding_rate_lower_bound: ::core::option::Option<::prost::alloc::string::String>,
+    /// Upper bound for the funding-rate such that the funding-rate will never be higher than this value.
+    #[prost(string, optional, tag = "12")]
+    pub funding_rate_upper_bound: ::core::option::Option<::prost::alloc::string::String>,
+    /// Composite price configuration to drive the calculation of the internal composite price used for funding payments. If undefined the default mark price of the market is used.
+    #[prost(message, optional, tag = "13")]
+    pub internal_composite_price_configuration: ::core::option::Option<
+        CompositePriceConfiguration,
+    >,
+}
+/// Instrument configuration
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InstrumentConfiguration {
+    /// Instrument name.
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// Instrument code, human-readable shortcode used to describe the instrument.
+    #[prost(string, tag = "2")]
+    pub code: ::prost::alloc::string::String,
+    /// Product specification.
+    #[prost(oneof = "instrument_configuration::Product", tags = "100, 101, 102")]
+    pub product: ::core::option::Option<instrument_configuration::Product>,
+}
+/// Nested message and enum types in `InstrumentConfiguration`.
+pub mod instrument_configuration {
+    /// Product specification.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Product {
+        /// Future.
+        #[prost(message, tag = "100")]
+        Future(super::FutureProduct),
+        /// Spot.
+        #[prost(message, tag = "101")]
+        Spot(super::SpotProduct),
+        /// Perpetual.
+        #[prost(message, tag = "102")]
+        Perpetual(super::PerpetualProduct),
+    }
+}
+/// Configuration for a new spot market on Vega
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NewSpotMarketConfiguration {
+    /// New spot market instrument configuration.
+    #[prost(message, optional, tag = "1")]
+    pub instrument: ::core::option::Option<InstrumentConfiguration>,
+    /// Decimal places used for the new spot market, sets the smallest price increment on the book.
+    #[prost(uint64, tag = "2")]
+    pub price_decimal_places: u64,
+    /// Optional new spot market metadata, tags.
+    #[prost(string, repeated, tag = "3")]
+    pub metadata: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Price monitoring parameters.
+    #[prost(message, optional, tag = "4")]
+    pub price_monitoring_parameters: ::core::option::Option<PriceMonitoringParameters>,
+    /// Specifies parameters related to target stake calculation.
+    #[prost(message, optional, tag = "5")]
+    pub target_stake_parameters: ::core::option::Option<TargetStakeParameters>,
+    /// Decimal places for order sizes, sets what size the smallest order / position on the spot market can be.
+    #[prost(int64, tag = "6")]
+    pub size_decimal_places: i64,
+    /// Specifies the liquidity provision SLA parameters.
+    #[prost(message, optional, tag = "7")]
+    pub sla_params: ::core::option::Option<LiquiditySlaParameters>,
+    /// Specifies how the liquidity fee for the market will be calculated.
+    #[prost(message, optional, tag = "8")]
+    pub liquidity_fee_settings: ::core::option::Option<LiquidityFeeSettings>,
+    /// The market tick size defines the minimum change in quote price for the market
+    #[prost(string, tag = "9")]
+    pub tick_size: ::prost::alloc::string::String,
+    /// If enabled aggressive orders sent to the market will be delayed by the configured number of blocks
+    #[prost(bool, tag = "10")]
+    pub enable_transaction_reordering: bool,
+    /// New spot market risk model parameters.
+    #[prost(oneof = "new_spot_market_configuration::RiskParameters", tags = "100, 101")]
+    pub risk_parameters: ::core::option::Option<
+        new_spot_market_configuration::RiskParameters,
+    >,
+}
+/// Nested message and enum types in `NewSpotMarketConfiguration`.
+pub mod new_spot_market_configuration {
+    /// New spot market risk model parameters.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum RiskParameters {
+        /// Simple risk model parameters, valid only if MODEL_SIMPLE is selected.
+        #[prost(message, tag = "100")]
+        Simple(super::SimpleModelParams),
+        /// Log normal risk model parameters, valid only if MODEL_LOG_NORMAL is selected.
+        #[prost(message, tag = "101")]
+        LogNormal(super::LogNormalRiskModel),
+    }
+}
+/// Configuration for a new futures market on Vega
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NewMarketConfiguration {
+    /// New futures market instrument configuration.
+    #[prost(message, optional, tag = "1")]
+    pub instrument: ::core::option::Option<InstrumentConfiguration>,
+    /// Decimal places used for the new futures market, sets the smallest price increment on the book.
+    #[prost(uint64, tag = "2")]
+    pub decimal_places: u64,
+    /// Optional new futures market metadata, tags.
+    #[prost(string, repeated, tag = "3")]
+    pub metadata: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Price monitoring parameters.
+    #[prost(message, optional, tag = "4")]
+    pub price_monitoring_parameters: ::core::option::Option<PriceMonitoringParameters>,
+    /// Liquidity monitoring parameters.
+    #[prost(message, optional, tag = "5")]
+    pub liquidity_monitoring_parameters: ::core::option::Option<
+        LiquidityMonitoringParameters,
+    >,
+    /// Decimal places for order sizes, sets what size the smallest order / position on the futures market can be.
+    #[prost(int64, tag = "6")]
+    pub position_decimal_places: i64,
+    /// DEPRECATED: Use liquidity SLA parameters instead.
+    /// Percentage move up and down from the mid price which specifies the range of
+    /// price levels over which automated liquidity provisions will be deployed.
+    #[prost(string, optional, tag = "8")]
+    pub lp_price_range: ::core::option::Option<::prost::alloc::string::String>,
+    /// Linear slippage factor is used to cap the slippage component of maintenance margin - it is applied to the slippage volume.
+    #[prost(string, tag = "9")]
+    pub linear_slippage_factor: ::prost::alloc::string::String,
+    /// Quadratic slippage factor is used to cap the slippage component of maintenance margin - it is applied to the square of the slippage volume.
+    #[deprecated]
+    #[prost(string, tag = "10")]
+    pub quadratic_slippage_factor: ::prost::alloc::string::String,
+    /// Successor configuration. If this proposal is meant to succeed a given market, then this should be set.
+    #[prost(message, optional, tag = "11")]
+    pub successor: ::core::option::Option<SuccessorConfiguration>,
+    /// Liquidity SLA parameters
+    #[prost(message, optional, tag = "12")]
+    pub liquidity_sla_parameters: ::core::option::Option<LiquiditySlaParameters>,
+    /// Specifies how the liquidity fee for the market will be calculated.
+    #[prost(message, optional, tag = "13")]
+    pub liquidity_fee_settings: ::core::option::Option<LiquidityFeeSettings>,
+    /// Liquidation strategy for this market.
+    #[prost(message, optional, tag = "14")]
+    pub liquidation_strategy: ::core::option::Option<LiquidationStrategy>,
+    /// Mark price configuration.
+    #[prost(message, optional, tag = "15")]
+    pub mark_price_configuration: ::core::option::Option<CompositePriceConfiguration>,
+    /// The market tick size defines the minimum change in quote price for the market
+    #[prost(string, tag = "16")]
+    pub tick_size: ::prost::alloc::string::String,
+    /// If enabled aggressive orders sent to the market will be delayed by the configured number of blocks
+    #[prost(bool, tag = "17")]
+    pub enable_transaction_reordering: bool,
+    /// New futures market risk model parameters.
+    #[prost(oneof = "new_market_configuration::RiskParameters", tags = "100, 101")]
+    pub risk_parameters: ::core::option::Option<
+        new_market_configuration::RiskParameters,
+    >,
+}
+/// Nested message and enum types in `NewMarketConfiguration`.
+pub mod new_market_configuration {
+    /// New futures market risk model parameters.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum RiskParameters {
+        /// Simple risk model parameters, valid only if MODEL_SIMPLE is selected.
+        #[prost(message, tag = "100")]
+        Simple(super::SimpleModelParams),
+        /// Log normal risk model parameters, valid only if MODEL_LOG_NORMAL is selected.
+        #[prost(message, tag = "101")]
+        LogNormal(super::LogNormalRiskModel),
+    }
+}
+/// New spot market on Vega
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NewSpotMarket {
+    /// Configuration of the new spot market.
+    #[prost(message, optional, tag = "1")]
+    pub changes: ::core::option::Option<NewSpotMarketConfiguration>,
+}
+/// Configuration required to turn a new market proposal in to a successor market proposal.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SuccessorConfiguration {
+    /// ID of the market that the successor should take over from.
+    #[prost(string, tag = "1")]
+    pub parent_market_id: ::prost::alloc::string::String,
+    /// A decimal value between or equal to 0 and 1, specifying the fraction of the insurance pool balance that is carried over from the parent market to the successor.
+    #[prost(string, tag = "2")]
+    pub insurance_pool_fraction: ::prost::alloc::string::String,
+}
+/// New market on Vega
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NewMarket {
+    /// Configuration of the new market.
+    #[prost(message, optional, tag = "1")]
+    pub changes: ::core::option::Option<NewMarketConfiguration>,
+}
+/// Update an existing market on Vega
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateMarket {
+    /// Market ID the update is for.
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+    /// Updated configuration of the futures market.
+    #[prost(message, optional, tag = "2")]
+    pub changes: ::core::option::Option<UpdateMarketConfiguration>,
+}
+/// Update an existing spot market on Vega
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateSpotMarket {
+    /// Market ID the update is for.
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+    /// Updated configuration of the spot market.
+    #[prost(message, optional, tag = "2")]
+    pub changes: ::core::option::Option<UpdateSpotMarketConfiguration>,
+}
+/// Configuration to update a futures market on Vega
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateMarketConfiguration {
+    /// Updated futures market instrument configuration.
+    #[prost(message, optional, tag = "1")]
+    pub instrument: ::core::option::Option<UpdateInstrumentConfiguration>,
+    /// Optional futures market metadata, tags.
+    #[prost(string, repeated, tag = "2")]
+    pub metadata: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Price monitoring parameters.
+    #[prost(message, optional, tag = "3")]
+    pub price_monitoring_parameters: ::core::option::Option<PriceMonitoringParameters>,
+    /// Liquidity monitoring parameters.
+    #[prost(message, optional, tag = "4")]
+    pub liquidity_monitoring_parameters: ::core::option::Option<
+        LiquidityMonitoringParameters,
+    >,
+    /// DEPRECATED: Use liquidity SLA parameters instead.
+    /// Percentage move up and down from the mid price which specifies the range of
+    /// price levels over which automated liquidity provisions will be deployed.
+    #[prost(string, optional, tag = "5")]
+    pub lp_price_range: ::core::option::Option<::prost::alloc::string::String>,
+    /// Linear slippage factor is used to cap the slippage component of maintenance margin - it is applied to the slippage volume.
+    #[prost(string, tag = "6")]
+    pub linear_slippage_factor: ::prost::alloc::string::String,
+    /// Quadratic slippage factor is used to cap the slippage component of maintenance margin - it is applied to the square of the slippage volume.
+    #[deprecated]
+    #[prost(string, tag = "7")]
+    pub quadratic_slippage_factor: ::prost::alloc::string::String,
+    /// Liquidity SLA parameters
+    #[prost(message, optional, tag = "8")]
+    pub liquidity_sla_parameters: ::core::option::Option<LiquiditySlaParameters>,
+    /// Specifies how the liquidity fee for the market will be calculated.
+    #[prost(message, optional, tag = "9")]
+    pub liquidity_fee_settings: ::core::option::Option<LiquidityFeeSettings>,
+    /// Liquidation strategy parameters
+    #[prost(message, optional, tag = "10")]
+    pub liquidation_strategy: ::core::option::Option<LiquidationStrategy>,
+    /// Mark price configuration.
+    #[prost(message, optional, tag = "11")]
+    pub mark_price_configuration: ::core::option::Option<CompositePriceConfiguration>,
+    /// The market tick size defines the minimum change in quote price for the market
+    #[prost(string, tag = "12")]
+    pub tick_size: ::prost::alloc::string::String,
+    /// If enabled aggressive orders sent to the market will be delayed by the configured number of blocks
+    #[prost(bool, tag = "13")]
+    pub enable_transaction_reordering: bool,
+    /// Updated futures market risk model parameters.
+    #[prost(oneof = "update_market_configuration::RiskParameters", tags = "100, 101")]
+    pub risk_parameters: ::core::option::Option<
+        update_market_configuration::RiskParameters,
+    >,
+}
+/// Nested message and enum types in `UpdateMarketConfiguration`.
+pub mod update_market_configuration {
+    /// Updated futures market risk model parameters.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum RiskParameters {
+        /// Simple risk model parameters, valid only if MODEL_SIMPLE is selected.
+        #[prost(message, tag = "100")]
+        Simple(super::SimpleModelParams),
+        /// Log normal risk model parameters, valid only if MODEL_LOG_NORMAL is selected.
+        #[prost(message, tag = "101")]
+        LogNormal(super::LogNormalRiskModel),
+    }
+}
+/// Configuration to update a spot market on Vega
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateSpotMarketConfiguration {
+    /// Optional spot market metadata, tags.
+    #[prost(string, repeated, tag = "1")]
+    pub metadata: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Price monitoring parameters.
+    #[prost(message, optional, tag = "2")]
+    pub price_monitoring_parameters: ::core::option::Option<PriceMonitoringParameters>,
+    /// Specifies parameters related to target stake calculation.
+    #[prost(message, optional, tag = "3")]
+    pub target_stake_parameters: ::core::option::Option<TargetStakeParameters>,
+    /// Specifies the liquidity provision SLA parameters.
+    #[prost(message, optional, tag = "4")]
+    pub sla_params: ::core::option::Option<LiquiditySlaParameters>,
+    /// Specifies how the liquidity fee for the market will be calculated.
+    #[prost(message, optional, tag = "5")]
+    pub liquidity_fee_settings: ::core::option::Option<LiquidityFeeSettings>,
+    /// The market tick size defines the minimum change in quote price for the market
+    #[prost(string, tag = "6")]
+    pub tick_size: ::prost::alloc::string::String,
+    /// Specifies the name and code of the spot instrument.
+    #[prost(message, optional, tag = "7")]
+    pub instrument: ::core::option::Option<UpdateSpotInstrumentConfiguration>,
+    /// If enabled aggressive orders sent to the market will be delayed by the configured number of blocks
+    #[prost(bool, tag = "8")]
+    pub enable_transaction_reordering: bool,
+    /// Update spot market risk model parameters.
+    #[prost(
+        oneof = "update_spot_market_configuration::RiskParameters",
+        tags = "100, 101"
+    )]
+    pub risk_parameters: ::core::option::Option<
+        update_spot_market_configuration::RiskParameters,
+    >,
+}
+/// Nested message and enum types in `UpdateSpotMarketConfiguration`.
+pub mod update_spot_market_configuration {
+    /// Update spot market risk model parameters.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum RiskParameters {
+        /// Simple risk model parameters, valid only if MODEL_SIMPLE is selected.
+        #[prost(message, tag = "100")]
+        Simple(super::SimpleModelParams),
+        /// Log normal risk model parameters, valid only if MODEL_LOG_NORMAL is selected.
+        #[prost(message, tag = "101")]
+        LogNormal(super::LogNormalRiskModel),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateSpotInstrumentConfiguration {
+    /// Instrument code, human-readable shortcode used to describe the instrument.
+    #[prost(string, tag = "1")]
+    pub code: ::prost::alloc::string::String,
+    /// Instrument name
+    #[prost(string, tag = "2")]
+    pub name: ::prost::alloc::string::String,
+}
+/// Instrument configuration
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateInstrumentConfiguration {
+    /// Instrument code, human-readable shortcode used to describe the instrument.
+    #[prost(string, tag = "1")]
+    pub code: ::prost::alloc::string::String,
+    /// Instrument name
+    #[prost(string, tag = "2")]
+    pub name: ::prost::alloc::string::String,
+    /// Product specification.
+    #[prost(oneof = "update_instrument_configuration::Product", tags = "100, 101")]
+    pub product: ::core::option::Option<update_instrument_configuration::Product>,
+}
+/// Nested message and enum types in `UpdateInstrumentConfiguration`.
+pub mod update_instrument_configuration {
+    /// Product specification.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Product {
+        /// Future.
+        #[prost(message, tag = "100")]
+        Future(super::UpdateFutureProduct),
+        /// Perpetual.
+        #[prost(message, tag = "101")]
+        Perpetual(super::UpdatePerpetualProduct),
+    }
+}
+/// Future product configuration
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateFutureProduct {
+    /// Human-readable name/abbreviation of the quote name.
+    #[prost(string, tag = "1")]
+    pub quote_name: ::prost::alloc::string::String,
+    /// The data source spec describing the data of settlement data.
+    #[prost(message, optional, tag = "2")]
+    pub data_source_spec_for_settlement_data: ::core::option::Option<
+        DataSourceDefinition,
+    >,
+    /// The data source spec describing the data source for trading termination.
+    #[prost(message, optional, tag = "3")]
+    pub data_source_spec_for_trading_termination: ::core::option::Option<
+        DataSourceDefinition,
+    >,
+    /// The binding between the data source spec and the settlement data.
+    #[prost(message, optional, tag = "4")]
+    pub data_source_spec_binding: ::core::option::Option<DataSourceSpecToFutureBinding>,
+}
+/// Perpetual product configuration
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdatePerpetualProduct {
+    /// Human-readable name/abbreviation of the quote name.
+    #[prost(string, tag = "1")]
+    pub quote_name: ::prost::alloc::string::String,
+    /// Controls how much the upcoming funding payment liability contributes to party's margin, in the range \[0, 1\].
+    #[prost(string, tag = "2")]
+    pub margin_funding_factor: ::prost::alloc::string::String,
+    /// Continuously compounded interest rate used in funding rate calculation, in the range \[-1, 1\].
+    #[prost(string, tag = "3")]
+    pub interest_rate: ::prost::alloc::string::String,
+    /// Lower bound for the clamp function used as part of the funding rate calculation, in the range \[-1, 1\].
+    #[prost(string, tag = "4")]
+    pub clamp_lower_bound: ::prost::alloc::string::String,
+    /// Upper bound for the clamp function used as part of the funding rate calculation, in the range \[-1, 1\].
+    #[prost(string, tag = "5")]
+    pub clamp_upper_bound: ::prost::alloc::string::String,
+    /// Data source spec describing the data source for settlement schedule.
+    #[prost(message, optional, tag = "6")]
+    pub data_source_spec_for_settlement_schedule: ::core::option::Option<
+        DataSourceDefinition,
+    >,
+    /// Data source spec describing the data source for settlement.
+    #[prost(message, optional, tag = "7")]
+    pub data_source_spec_for_settlement_data: ::core::option::Option<
+        DataSourceDefinition,
+    >,
+    /// Binding between the data source spec and the settlement data.
+    #[prost(message, optional, tag = "8")]
+    pub data_source_spec_binding: ::core::option::Option<
+        DataSourceSpecToPerpetualBinding,
+    >,
+    /// Factor applied to funding-rates. This scales the impact that spot price deviations have on funding payments.
+    #[prost(string, optional, tag = "9")]
+    pub funding_rate_scaling_factor: ::core::option::Option<
+        ::prost::alloc::string::String,
+    >,
+    /// Lower bound for the funding-rate such that the funding-rate will never be lower than this value.
+    #[prost(string, optional, tag = "10")]
+    pub funding_rate_lower_bound: ::core::option::Option<::prost::alloc::string::String>,
+    /// Upper bound for the funding-rate such that the funding-rate will never be higher than this value.
+    #[prost(string, optional, tag = "11")]
+    pub funding_rate_upper_bound: ::core::option::Option<::prost::alloc::string::String>,
+    /// Configuration for the internal composite price used in funding payment calculation.
+    #[prost(message, optional, tag = "13")]
+    pub internal_composite_price_configuration: ::core::option::Option<
+        CompositePriceConfiguration,
+    >,
+}
+/// Update network configuration on Vega
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateNetworkParameter {
+    /// The network parameter to update.
+    #[prost(message, optional, tag = "1")]
+    pub changes: ::core::option::Option<NetworkParameter>,
+}
+/// New asset on Vega
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NewAsset {
+    /// Configuration of the new asset.
+    #[prost(message, optional, tag = "1")]
+    pub changes: ::core::option::Option<AssetDetails>,
+}
+/// Update an existing asset on Vega
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateAsset {
+    /// Asset ID the update is for.
+    #[prost(string, tag = "1")]
+    pub asset_id: ::prost::alloc::string::String,
+    /// Changes to apply on an existing asset.
+    #[prost(message, optional, tag = "2")]
+    pub changes: ::core::option::Option<AssetDetailsUpdate>,
+}
+/// Freeform proposal
+/// This message is just used as a placeholder to sort out the nature of the
+/// proposal once parsed.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NewFreeform {}
+/// Terms for a governance proposal on Vega
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProposalTerms {
+    /// Timestamp as Unix time in seconds when voting closes for this proposal,
+    /// constrained by `minClose` and `maxClose` network parameters.
+    #[prost(int64, tag = "1")]
+    pub closing_timestamp: i64,
+    /// Timestamp as Unix time in seconds when proposal gets enacted if passed,
+    /// constrained by `minEnact` and `maxEnact` network parameters.
+    #[prost(int64, tag = "2")]
+    pub enactment_timestamp: i64,
+    /// Validation timestamp as Unix time in seconds.
+    #[prost(int64, tag = "3")]
+    pub validation_timestamp: i64,
+    /// Changes being proposed.
+    #[prost(
+        oneof = "proposal_terms::Change",
+        tags = "101, 102, 103, 104, 105, 106, 107, 108, 109, 110, 111, 112, 113, 114"
+    )]
+    pub change: ::core::option::Option<proposal_terms::Change>,
+}
+/// Nested message and enum types in `ProposalTerms`.
+pub mod proposal_terms {
+    /// Changes being proposed.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Change {
+        /// Proposal change for modifying an existing futures market on Vega.
+        #[prost(message, tag = "101")]
+        UpdateMarket(super::UpdateMarket),
+        /// Proposal change for creating new futures market on Vega.
+        #[prost(message, tag = "102")]
+        NewMarket(super::NewMarket),
+        /// Proposal change for updating Vega network parameters.
+        #[prost(message, tag = "103")]
+        UpdateNetworkParameter(super::UpdateNetworkParameter),
+        /// Proposal change for creating new assets on Vega.
+        #[prost(message, tag = "104")]
+        NewAsset(super::NewAsset),
+        /// Proposal change for a freeform request, which can be voted on but does not change the behaviour of the system,
+        /// and can be used to gauge community sentiment.
+        #[prost(message, tag = "105")]
+        NewFreeform(super::NewFreeform),
+        /// Proposal change for updating an asset.
+        #[prost(message, tag = "106")]
+        UpdateAsset(super::UpdateAsset),
+        /// Proposal change for creating new spot market on Vega.
+        #[prost(message, tag = "107")]
+        NewSpotMarket(super::NewSpotMarket),
+        /// Proposal change for modifying an existing spot market on Vega.
+        #[prost(message, tag = "108")]
+        UpdateSpotMarket(super::UpdateSpotMarket),
+        /// Proposal change for a governance transfer.
+        #[prost(message, tag = "109")]
+        NewTransfer(super::NewTransfer),
+        /// Cancel a governance transfer.
+        #[prost(message, tag = "110")]
+        CancelTransfer(super::CancelTransfer),
+        /// Proposal change for updating the state of a market.
+        #[prost(message, tag = "111")]
+        UpdateMarketState(super::UpdateMarketState),
+        /// Proposal change for updating the referral program.
+        #[prost(message, tag = "112")]
+        UpdateReferralProgram(super::UpdateReferralProgram),
+        /// Proposal change for updating the volume discount program.
+        #[prost(message, tag = "113")]
+        UpdateVolumeDiscountProgram(super::UpdateVolumeDiscountProgram),
+        /// Proposal change for updating the volume rebate program.
+        #[prost(message, tag = "114")]
+        UpdateVolumeRebateProgram(super::UpdateVolumeRebateProgram),
+    }
+}
+/// Terms change for a batch governance proposal
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BatchProposalTermsChange {
+    /// Timestamp as Unix time in seconds when proposal terms gets enacted if proposal passed the vote,
+    /// constrained by `minEnact` and `maxEnact` network parameters.
+    #[prost(int64, tag = "1")]
+    pub enactment_timestamp: i64,
+    /// Validation timestamp as Unix time in seconds.
+    #[prost(int64, tag = "2")]
+    pub validation_timestamp: i64,
+    /// Changes being proposed.
+    #[prost(
+        oneof = "batch_proposal_terms_change::Change",
+        tags = "101, 102, 103, 104, 105, 106, 107, 108, 109, 110, 111, 112, 113, 114"
+    )]
+    pub change: ::core::option::Option<batch_proposal_terms_change::Change>,
+}
+/// Nested message and enum types in `BatchProposalTermsChange`.
+pub mod batch_proposal_terms_change {
+    /// Changes being proposed.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Change {
+        /// Proposal change for modifying an existing futures market.
+        #[prost(message, tag = "101")]
+        UpdateMarket(super::UpdateMarket),
+        /// Proposal change for creating new futures market.
+        #[prost(message, tag = "102")]
+        NewMarket(super::NewMarket),
+        /// Proposal change for updating Vega network parameters.
+        #[prost(message, tag = "103")]
+        UpdateNetworkParameter(super::UpdateNetworkParameter),
+        /// Proposal change for a freeform request, which can be voted on but does not change the behaviour of the system,
+        /// and can be used to gauge community sentiment.
+        #[prost(message, tag = "104")]
+        NewFreeform(super::NewFreeform),
+        /// Proposal change for updating an asset.
+        #[prost(message, tag = "105")]
+        UpdateAsset(super::UpdateAsset),
+        /// Proposal change for creating new spot market.
+        #[prost(message, tag = "106")]
+        NewSpotMarket(super::NewSpotMarket),
+        /// Proposal change for modifying an existing spot market.
+        #[prost(message, tag = "107")]
+        UpdateSpotMarket(super::UpdateSpotMarket),
+        /// Proposal change for a governance initiated transfer.
+        #[prost(message, tag = "108")]
+        NewTransfer(super::NewTransfer),
+        /// Proposal change to cancel a governance initiated transfe.
+        #[prost(message, tag = "109")]
+        CancelTransfer(super::CancelTransfer),
+        /// Proposal change for updating the state of a market.
+        #[prost(message, tag = "110")]
+        UpdateMarketState(super::UpdateMarketState),
+        /// Proposal change for updating the referral program.
+        #[prost(message, tag = "111")]
+        UpdateReferralProgram(super::UpdateReferralProgram),
+        /// Proposal change for updating the volume discount program.
+        #[prost(message, tag = "112")]
+        UpdateVolumeDiscountProgram(super::UpdateVolumeDiscountProgram),
+        /// Proposal change for adding a new asset.
+        #[prost(message, tag = "113")]
+        NewAsset(super::NewAsset),
+        /// Proposal change for updating the volume rebate program.
+        #[prost(message, tag = "114")]
+        UpdateVolumeRebateProgram(super::UpdateVolumeRebateProgram),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProposalParameters {
+    /// Represents the minimum time before a proposal can be closed for voting.
+    #[prost(int64, tag = "1")]
+    pub min_close: i64,
+    /// Represents the maximum time that a proposal can be open for voting.
+    #[prost(int64, tag = "2")]
+    pub max_close: i64,
+    /// Represents the minimum time before an enacted proposal takes effect.
+    #[prost(int64, tag = "3")]
+    pub min_enact: i64,
+    /// Represents the maximum time before an enacted proposal must take effect.
+    #[prost(int64, tag = "4")]
+    pub max_enact: i64,
+    /// Specifies the required percentage of eligible participants that must partake in the proposal for it to be valid.
+    #[prost(string, tag = "5")]
+    pub required_participation: ::prost::alloc::string::String,
+    /// Specifies the required percentage of votes a proposal needs to be approved.
+    #[prost(string, tag = "6")]
+    pub required_majority: ::prost::alloc::string::String,
+    /// Specifies the minimum governance token balance a proposer must hold to initiate a proposal.
+    #[prost(string, tag = "7")]
+    pub min_proposer_balance: ::prost::alloc::string::String,
+    /// Specifies the minimum governance token balance a voter must hold to participate in voting.
+    #[prost(string, tag = "8")]
+    pub min_voter_balance: ::prost::alloc::string::String,
+    /// Specifies the required percentage of participation from liquidity providers for the proposal to be valid.
+    #[prost(string, tag = "9")]
+    pub required_participation_lp: ::prost::alloc::string::String,
+    /// Specifies the required majority percentage from liquidity providers for a proposal to be approved.
+    #[prost(string, tag = "10")]
+    pub required_majority_lp: ::prost::alloc::string::String,
+    /// Specifies the minimum share that is akin to equity, which a participant must hold for their vote to be eligible.
+    #[prost(string, tag = "11")]
+    pub min_equity_like_share: ::prost::alloc::string::String,
+}
+/// Terms for a batch governance proposal on Vega
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BatchProposalTerms {
+    /// Closing timestamp in Unix time; adheres to `minClose` and `maxClose` limits.
+    #[prost(int64, tag = "1")]
+    pub closing_timestamp: i64,
+    /// Specific parameters defining the proposal's characteristics used for validation.
+    #[prost(message, optional, tag = "2")]
+    pub proposal_params: ::core::option::Option<ProposalParameters>,
+    /// List of individual changes included in the batch proposal.
+    #[prost(message, repeated, tag = "3")]
+    pub changes: ::prost::alloc::vec::Vec<BatchProposalTermsChange>,
+}
+/// Rationale behind a proposal.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProposalRationale {
+    /// Description to show a short title / something in case the link goes offline.
+    /// This is to be between 0 and 20k unicode characters.
+    /// This is mandatory for all proposals.
+    #[prost(string, tag = "1")]
+    pub description: ::prost::alloc::string::String,
+    /// Title to be used to give a short description of the proposal in lists.
+    /// This is to be between 0 and 100 unicode characters.
+    /// This is mandatory for all proposals.
+    #[prost(string, tag = "4")]
+    pub title: ::prost::alloc::string::String,
+}
+/// Governance data
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GovernanceData {
+    /// Governance proposal that is being voted on.
+    #[prost(message, optional, tag = "1")]
+    pub proposal: ::core::option::Option<Proposal>,
+    /// All YES votes in favour of the proposal above.
+    #[prost(message, repeated, tag = "2")]
+    pub yes: ::prost::alloc::vec::Vec<Vote>,
+    /// All NO votes against the proposal above.
+    #[prost(message, repeated, tag = "3")]
+    pub no: ::prost::alloc::vec::Vec<Vote>,
+    /// All latest YES votes by party which is guaranteed to be unique,
+    /// where key (string) is the party ID i.e. public key and
+    /// value (Vote) is the vote cast by the given party.
+    #[prost(map = "string, message", tag = "4")]
+    pub yes_party: ::std::collections::HashMap<::prost::alloc::string::String, Vote>,
+    /// All latest NO votes by party which is guaranteed to be unique,
+    /// where key (string) is the party ID i.e. public key and
+    /// value (Vote) is the vote cast by the given party.
+    #[prost(map = "string, message", tag = "5")]
+    pub no_party: ::std::collections::HashMap<::prost::alloc::string::String, Vote>,
+    /// Type of proposal this data is for.
+    #[prost(enumeration = "governance_data::Type", tag = "6")]
+    pub proposal_type: i32,
+    /// If proposal type is batch, proposals will contain all the proposals that are make up the batch.
+    #[prost(message, repeated, tag = "7")]
+    pub proposals: ::prost::alloc::vec::Vec<Proposal>,
+}
+/// Nested message and enum types in `GovernanceData`.
+pub mod governance_data {
+    /// Proposal type
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Type {
+        /// Single proposal only. In case it has not been specified, for example in older proposals, then this is the default.
+        SingleOrUnspecified = 0,
+        /// Proposal contains a batch of proposals or a proposal that should be treated as part of a batch.
+        Batch = 1,
+    }
+    impl Type {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Type::SingleOrUnspecified => "TYPE_SINGLE_OR_UNSPECIFIED",
+                Type::Batch => "TYPE_BATCH",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "TYPE_SINGLE_OR_UNSPECIFIED" => Some(Self::SingleOrUnspecified),
+                "TYPE_BATCH" => Some(Self::Batch),
+                _ => None,
+            }
+        }
+    }
+}
+/// Governance proposal
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Proposal {
+    /// Unique proposal ID.
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Proposal reference.
+    #[prost(string, tag = "2")]
+    pub reference: ::prost::alloc::string::String,
+    /// Party ID i.e. public key of the party submitting the proposal.
+    #[prost(string, tag = "3")]
+    pub party_id: ::prost::alloc::string::String,
+    /// Current state of the proposal, i.e. open, passed, failed etc.
+    #[prost(enumeration = "proposal::State", tag = "4")]
+    pub state: i32,
+    /// Proposal timestamp for date and time as Unix time in nanoseconds when proposal was submitted to the network.
+    #[prost(int64, tag = "5")]
+    pub timestamp: i64,
+    /// Proposal configuration and the actual change that is meant to be executed when proposal is enacted.
+    /// Single proposal term.
+    #[prost(message, optional, tag = "6")]
+    pub terms: ::core::option::Option<ProposalTerms>,
+    /// Reason for the current state of the proposal, this may be set in case of REJECTED and FAILED statuses.
+    #[prost(enumeration = "ProposalError", optional, tag = "7")]
+    pub reason: ::core::option::Option<i32>,
+    /// Detailed error associated to the reason.
+    #[prost(string, optional, tag = "8")]
+    pub error_details: ::core::option::Option<::prost::alloc::string::String>,
+    /// Rationale behind a proposal.
+    #[prost(message, optional, tag = "9")]
+    pub rationale: ::core::option::Option<ProposalRationale>,
+    /// Required vote participation for this proposal.
+    #[prost(string, tag = "10")]
+    pub required_participation: ::prost::alloc::string::String,
+    /// Required majority for this proposal.
+    #[prost(string, tag = "11")]
+    pub required_majority: ::prost::alloc::string::String,
+    /// Required participation from liquidity providers, optional but is required for market update proposal.
+    #[prost(string, optional, tag = "12")]
+    pub required_liquidity_provider_participation: ::core::option::Option<
+        ::prost::alloc::string::String,
+    >,
+    /// Required majority from liquidity providers, optional but is required for market update proposal.
+    #[prost(string, optional, tag = "13")]
+    pub required_liquidity_provider_majority: ::core::option::Option<
+        ::prost::alloc::string::String,
+    >,
+    /// Batch proposal terms.
+    #[prost(message, optional, tag = "14")]
+    pub batch_terms: ::core::option::Option<BatchProposalTerms>,
+    /// ID of a batch proposal that this proposal is part of.
+    #[prost(string, optional, tag = "15")]
+    pub batch_id: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// Nested message and enum types in `Proposal`.
+pub mod proposal {
+    /// Proposal state transition:
+    /// Open ->
+    ///    - Passed -> Enacted.
+    ///    - Passed -> Failed.
+    ///    - Declined
+    /// Rejected
+    /// Proposal can enter Failed state from any other state
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum State {
+        /// Default value, always invalid
+        Unspecified = 0,
+        /// Proposal enactment has failed - even though proposal has passed, its execution could not be performed
+        Failed = 1,
+        /// Proposal is open for voting
+        Open = 2,
+        /// Proposal has gained enough support to be executed
+        Passed = 3,
+        /// Proposal wasn't accepted i.e. proposal terms failed validation due to wrong configuration or failed to meet network requirements.
+        Rejected = 4,
+        /// Proposal didn't get enough votes, e.g. either failed to gain required participation or majority level.
+        Declined = 5,
+        /// Proposal enacted.
+        Enacted = 6,
+        /// Waiting for node validation of the proposal
+        WaitingForNodeVote = 7,
+    }
+    impl State {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                State::Unspecified => "STATE_UNSPECIFIED",
+                State::Failed => "STATE_FAILED",
+                State::Open => "STATE_OPEN",
+                State::Passed => "STATE_PASSED",
+                State::Rejected => "STATE_REJECTED",
+                State::Declined => "STATE_DECLINED",
+                State::Enacted => "STATE_ENACTED",
+                State::WaitingForNodeVote => "STATE_WAITING_FOR_NODE_VOTE",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "STATE_UNSPECIFIED" => Some(Self::Unspecified),
+                "STATE_FAILED" => Some(Self::Failed),
+                "STATE_OPEN" => Some(Self::Open),
+                "STATE_PASSED" => Some(Self::Passed),
+                "STATE_REJECTED" => Some(Self::Rejected),
+                "STATE_DECLINED" => Some(Self::Declined),
+                "STATE_ENACTED" => Some(Self::Enacted),
+                "STATE_WAITING_FOR_NODE_VOTE" => Some(Self::WaitingForNodeVote),
+                _ => None,
+            }
+        }
+    }
+}
+/// Governance vote
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Vote {
+    /// Voter's party ID.
+    #[prost(string, tag = "1")]
+    pub party_id: ::prost::alloc::string::String,
+    /// Which way the party voted.
+    #[prost(enumeration = "vote::Value", tag = "2")]
+    pub value: i32,
+    /// Proposal ID being voted on.
+    #[prost(string, tag = "3")]
+    pub proposal_id: ::prost::alloc::string::String,
+    /// Timestamp in Unix nanoseconds when the vote was acknowledged by the network.
+    #[prost(int64, tag = "4")]
+    pub timestamp: i64,
+    /// Total number of governance token for the party that cast the vote.
+    #[prost(string, tag = "5")]
+    pub total_governance_token_balance: ::prost::alloc::string::String,
+    /// The weight of this vote based on the total number of governance tokens.
+    #[prost(string, tag = "6")]
+    pub total_governance_token_weight: ::prost::alloc::string::String,
+    /// The weight of the vote compared to the total amount of equity-like share on the market. It is only populated if the vote is for a single type proposal.
+    #[prost(string, tag = "7")]
+    pub total_equity_like_share_weight: ::prost::alloc::string::String,
+    /// The per market weight of the vote compared to the total amount of equity-like share on the market.. It is only populated if the vote is for a batch type proposal.
+    #[prost(message, repeated, tag = "8")]
+    pub els_per_market: ::prost::alloc::vec::Vec<VoteElsPair>,
+}
+/// Nested message and enum types in `Vote`.
+pub mod vote {
+    /// Vote value
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        /// Default value, always invalid
+        Unspecified = 0,
+        /// Vote against the proposal
+        No = 1,
+        /// Vote in favour of the proposal
+        Yes = 2,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Value::Unspecified => "VALUE_UNSPECIFIED",
+                Value::No => "VALUE_NO",
+                Value::Yes => "VALUE_YES",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "VALUE_UNSPECIFIED" => Some(Self::Unspecified),
+                "VALUE_NO" => Some(Self::No),
+                "VALUE_YES" => Some(Self::Yes),
+                _ => None,
+            }
+        }
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VoteElsPair {
+    /// The market ID.
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+    /// The equity-like share weight for this market.
+    #[prost(string, tag = "2")]
+    pub els: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateVolumeDiscountProgram {
+    /// Configuration for a change to update a volume discount program
+    #[prost(message, optional, tag = "1")]
+    pub changes: ::core::option::Option<VolumeDiscountProgramChanges>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VolumeDiscountProgramChanges {
+    /// Defined benefit tiers in increasing order. First element will give Tier 1,
+    /// second element will give Tier 2, and so on. Determines the level of
+    /// benefit a party can expect based on performance criteria.
+    #[prost(message, repeated, tag = "1")]
+    pub benefit_tiers: ::prost::alloc::vec::Vec<VolumeBenefitTier>,
+    /// Timestamp as Unix time in seconds, after which when the current epoch
+    /// ends, the program will end and benefits will be disabled.
+    #[prost(int64, tag = "2")]
+    pub end_of_program_timestamp: i64,
+    /// Number of epochs over which to evaluate a referral set's running volume.
+    #[prost(uint64, tag = "3")]
+    pub window_length: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateVolumeRebateProgram {
+    /// Configuration for a change to update a volume rebate program
+    #[prost(message, optional, tag = "1")]
+    pub changes: ::core::option::Option<VolumeRebateProgramChanges>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VolumeRebateProgramChanges {
+    /// Defined benefit tiers in increasing order. First element will give Tier 1,
+    /// second element will give Tier 2, and so on. Determines the level of
+    /// benefit a party can expect based on performance criteria.
+    #[prost(message, repeated, tag = "1")]
+    pub benefit_tiers: ::prost::alloc::vec::Vec<VolumeRebateBenefitTier>,
+    /// Timestamp as Unix time in seconds, after which when the current epoch
+    /// ends, the program will end and benefits will be disabled.
+    #[prost(int64, tag = "2")]
+    pub end_of_program_timestamp: i64,
+    /// Number of epochs over which to evaluate a referral set's running volume.
+    #[prost(uint64, tag = "3")]
+    pub window_length: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateReferralProgram {
+    /// Configuration for change to update a referral program.
+    #[prost(message, optional, tag = "1")]
+    pub changes: ::core::option::Option<ReferralProgramChanges>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReferralProgramChanges {
+    /// Defined benefit tiers in increasing order. First element will give Tier 1,
+    /// second element will give Tier 2, and so on. Determines the level of
+    /// benefit a party can expect based on performance criteria.
+    #[prost(message, repeated, tag = "1")]
+    pub benefit_tiers: ::prost::alloc::vec::Vec<BenefitTier>,
+    /// Timestamp as Unix time in seconds, after which when the current epoch
+    /// ends, the program will end and benefits will be disabled.
+    #[prost(int64, tag = "2")]
+    pub end_of_program_timestamp: i64,
+    /// Number of epochs over which to evaluate a referral set's running volume.
+    #[prost(uint64, tag = "3")]
+    pub window_length: u64,
+    /// Defined staking tiers in increasing order. First element will give Tier 1,
+    /// second element will give Tier 2, and so on. Determines the level of
+    /// benefit a party can expect based on their staking.
+    #[prost(message, repeated, tag = "4")]
+    pub staking_tiers: ::prost::alloc::vec::Vec<StakingTier>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateMarketState {
+    /// Configuration for governance-initiated change of a market's state
+    #[prost(message, optional, tag = "1")]
+    pub changes: ::core::option::Option<UpdateMarketStateConfiguration>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateMarketStateConfiguration {
+    /// ID of the market
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+    /// Type of the market update
+    #[prost(enumeration = "MarketStateUpdateType", tag = "2")]
+    pub update_type: i32,
+    /// Settlement price, relevant only for market termination for futures markets
+    #[prost(string, optional, tag = "3")]
+    pub price: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CancelTransfer {
+    /// Configuration for cancellation of a governance-initiated transfer
+    #[prost(message, optional, tag = "1")]
+    pub changes: ::core::option::Option<CancelTransferConfiguration>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CancelTransferConfiguration {
+    /// ID of the governance transfer proposal.
+    #[prost(string, tag = "1")]
+    pub transfer_id: ::prost::alloc::string::String,
+}
+/// New governance transfer
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NewTransfer {
+    /// Configuration for a new transfer.
+    #[prost(message, optional, tag = "1")]
+    pub changes: ::core::option::Option<NewTransferConfiguration>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NewTransferConfiguration {
+    /// Source account type, such as network treasury, market insurance pool
+    #[prost(enumeration = "AccountType", tag = "1")]
+    pub source_type: i32,
+    /// If network treasury, field is empty, otherwise uses the market ID
+    #[prost(string, tag = "2")]
+    pub source: ::prost::alloc::string::String,
+    /// "All or nothing" or "best effort":
+    /// All or nothing: Transfers the specified amount or does not transfer anything
+    /// Best effort: Transfers the specified amount or the max allowable amount if this is less than the specified amount
+    #[prost(enumeration = "GovernanceTransferType", tag = "3")]
+    pub transfer_type: i32,
+    /// Maximum amount to transfer
+    #[prost(string, tag = "4")]
+    pub amount: ::prost::alloc::string::String,
+    /// ID of asset to transfer
+    #[prost(string, tag = "5")]
+    pub asset: ::prost::alloc::string::String,
+    /// Maximum fraction of the source account's balance to transfer as a decimal - i.e. 0.1 = 10% of the balance
+    #[prost(string, tag = "6")]
+    pub fraction_of_balance: ::prost::alloc::string::String,
+    /// Specifies the account type to transfer to: reward pool, party, network insurance pool, market insurance pool
+    #[prost(enumeration = "AccountType", tag = "7")]
+    pub destination_type: i32,
+    /// Specifies the account to transfer to, depending on the account type:
+    /// Network treasury: leave empty
+    /// Party: party's public key
+    /// Market insurance pool: market ID
+    #[prost(string, tag = "8")]
+    pub destination: ::prost::alloc::string::String,
+    /// Specific details of the transfer.
+    #[prost(oneof = "new_transfer_configuration::Kind", tags = "101, 102")]
+    pub kind: ::core::option::Option<new_transfer_configuration::Kind>,
+}
+/// Nested message and enum types in `NewTransferConfiguration`.
+pub mod new_transfer_configuration {
+    /// Specific details of the transfer.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Kind {
+        #[prost(message, tag = "101")]
+        OneOff(super::OneOffTransfer),
+        #[prost(message, tag = "102")]
+        Recurring(super::RecurringTransfer),
+    }
+}
+/// Specific details for a one off transfer
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OneOffTransfer {
+    /// Timestamp in Unix nanoseconds for when the transfer should be delivered into the receiver's account.
+    #[prost(int64, tag = "1")]
+    pub deliver_on: i64,
+}
+/// Specific details for a recurring transfer
+///
+/// First epoch from which this transfer shall be paid.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RecurringTransfer {
+    #[prost(uint64, tag = "1")]
+    pub start_epoch: u64,
+    /// Last epoch at which this transfer shall be paid.
+    #[prost(uint64, optional, tag = "2")]
+    pub end_epoch: ::core::option::Option<u64>,
+    /// Optional parameter defining how a transfer is dispatched.
+    #[prost(message, optional, tag = "3")]
+    pub dispatch_strategy: ::core::option::Option<DispatchStrategy>,
+    /// Factor that the initial transfer amount is multiplied by for each epoch that it is executed.
+    /// For example if the initial transfer amount is 1000 and the factor is 0.5, then the amounts transferred per epoch will be 1000, 500, 250, 125, etc.
+    #[prost(string, tag = "4")]
+    pub factor: ::prost::alloc::string::String,
+}
+/// List of possible errors that can cause a proposal to be in state rejected or failed
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ProposalError {
+    /// Default value
+    Unspecified = 0,
+    /// Specified close time is too early based on network parameters
+    CloseTimeTooSoon = 1,
+    /// Specified close time is too late based on network parameters
+    CloseTimeTooLate = 2,
+    /// Specified enactment time is too early based on network parameters
+    EnactTimeTooSoon = 3,
+    /// Specified enactment time is too late based on network parameters
+    EnactTimeTooLate = 4,
+    /// Proposer for this proposal has insufficient tokens
+    InsufficientTokens = 5,
+    /// Instrument quote name and base name were the same
+    InvalidInstrumentSecurity = 6,
+    /// Proposal has no product
+    NoProduct = 7,
+    /// Specified product is not supported
+    UnsupportedProduct = 8,
+    /// Proposal has no trading mode
+    NoTradingMode = 11,
+    /// Proposal has an unsupported trading mode
+    UnsupportedTradingMode = 12,
+    /// Proposal failed node validation
+    NodeValidationFailed = 13,
+    /// Field is missing in a builtin asset source
+    MissingBuiltinAssetField = 14,
+    /// Contract address is missing in the ERC20 asset source
+    MissingErc20ContractAddress = 15,
+    /// Asset ID is invalid or does not exist on the Vega network
+    InvalidAsset = 16,
+    /// Proposal terms timestamps are not compatible (Validation < Closing < Enactment)
+    IncompatibleTimestamps = 17,
+    /// No risk parameters were specified
+    NoRiskParameters = 18,
+    /// Invalid key in update network parameter proposal
+    NetworkParameterInvalidKey = 19,
+    /// Invalid value in update network parameter proposal
+    NetworkParameterInvalidValue = 20,
+    /// Validation failed for network parameter proposal
+    NetworkParameterValidationFailed = 21,
+    /// Opening auction duration is less than the network minimum opening auction time
+    OpeningAuctionDurationTooSmall = 22,
+    /// Opening auction duration is more than the network minimum opening auction time
+    OpeningAuctionDurationTooLarge = 23,
+    /// Market proposal market could not be instantiated in execution
+    CouldNotInstantiateMarket = 25,
+    /// Market proposal market contained invalid product definition
+    InvalidFutureProduct = 26,
+    /// Market proposal has invalid risk parameter
+    InvalidRiskParameter = 30,
+    /// Proposal was declined because vote didn't reach the majority threshold required
+    MajorityThresholdNotReached = 31,
+    /// Proposal declined because the participation threshold was not reached
+    ParticipationThresholdNotReached = 32,
+    /// Asset proposal has invalid asset details
+    InvalidAssetDetails = 33,
+    /// Proposal is an unknown type
+    UnknownType = 34,
+    /// Proposal has an unknown risk parameter type
+    UnknownRiskParameterType = 35,
+    /// Validation failed for freeform proposal
+    InvalidFreeform = 36,
+    /// Party doesn't have enough equity-like share to propose an update on the market
+    /// targeted by the proposal
+    InsufficientEquityLikeShare = 37,
+    /// Market targeted by the proposal does not exist or is not eligible for modification
+    InvalidMarket = 38,
+    /// Market proposal decimal place is higher than the market settlement asset decimal places
+    TooManyMarketDecimalPlaces = 39,
+    /// Market proposal contains too many price monitoring triggers
+    TooManyPriceMonitoringTriggers = 40,
+    /// Market proposal contains too many price monitoring triggers
+    Erc20AddressAlreadyInUse = 41,
+    /// LP price range must be larger than 0
+    LpPriceRangeNonpositive = 42,
+    /// LP price range must not be larger than 100
+    LpPriceRangeTooLarge = 43,
+    /// Linear slippage factor is out of range, either negative or too large
+    LinearSlippageFactorOutOfRange = 44,
+    /// Quadratic slippage factor is out of range, either negative or too large
+    QuadraticSlippageFactorOutOfRange = 45,
+    /// Validation failed for spot proposal
+    InvalidSpot = 46,
+    /// Spot trading not enabled
+    SpotProductDisabled = 47,
+    /// Market proposal is invalid, either invalid insurance pool fraction, or it specifies a parent market that it can't succeed.
+    InvalidSuccessorMarket = 48,
+    /// Governance transfer proposal is invalid
+    GovernanceTransferProposalFailed = 49,
+    /// Governance transfer proposal failed
+    GovernanceTransferProposalInvalid = 50,
+    /// Proposal for cancelling transfer is invalid, check proposal ID
+    GovernanceCancelTransferProposalInvalid = 51,
+    /// Proposal for updating market state is invalid
+    InvalidMarketStateUpdate = 52,
+    /// Liquidity provision SLA parameters are invalid
+    InvalidSlaParams = 53,
+    /// Mandatory liquidity provision SLA parameters are missing
+    MissingSlaParams = 54,
+    /// Perpetual market proposal contained invalid product definition
+    InvalidPerpetualProduct = 55,
+    /// Referral program proposal is invalid
+    InvalidReferralProgram = 56,
+    /// Volume discount program proposal is invalid
+    InvalidVolumeDiscountProgram = 57,
+    /// One or more proposals in a batch has been rejected
+    ProposalInBatchRejected = 58,
+    /// One or more proposals in a batch has been declined
+    ProposalInBatchDeclined = 59,
+    /// Spot market decimal places cannot be greater than the base asset’s decimal places
+    InvalidSizeDecimalPlaces = 60,
+    /// Volume rebate program proposal is invalid
+    InvalidVolumeRebateProgram = 61,
+}
+impl ProposalError {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            ProposalError::Unspecified => "PROPOSAL_ERROR_UNSPECIFIED",
+            ProposalError::CloseTimeTooSoon => "PROPOSAL_ERROR_CLOSE_TIME_TOO_SOON",
+            ProposalError::CloseTimeTooLate => "PROPOSAL_ERROR_CLOSE_TIME_TOO_LATE",
+            ProposalError::EnactTimeTooSoon => "PROPOSAL_ERROR_ENACT_TIME_TOO_SOON",
+            ProposalError::EnactTimeTooLate => "PROPOSAL_ERROR_ENACT_TIME_TOO_LATE",
+            ProposalError::InsufficientTokens => "PROPOSAL_ERROR_INSUFFICIENT_TOKENS",
+            ProposalError::InvalidInstrumentSecurity => {
+                "PROPOSAL_ERROR_INVALID_INSTRUMENT_SECURITY"
+            }
+            ProposalError::NoProduct => "PROPOSAL_ERROR_NO_PRODUCT",
+            ProposalError::UnsupportedProduct => "PROPOSAL_ERROR_UNSUPPORTED_PRODUCT",
+            ProposalError::NoTradingMode => "PROPOSAL_ERROR_NO_TRADING_MODE",
+            ProposalError::UnsupportedTradingMode => {
+                "PROPOSAL_ERROR_UNSUPPORTED_TRADING_MODE"
+            }
+            ProposalError::NodeValidationFailed => {
+                "PROPOSAL_ERROR_NODE_VALIDATION_FAILED"
+            }
+            ProposalError::MissingBuiltinAssetField => {
+                "PROPOSAL_ERROR_MISSING_BUILTIN_ASSET_FIELD"
+            }
+            ProposalError::MissingErc20ContractAddress => {
+                "PROPOSAL_ERROR_MISSING_ERC20_CONTRACT_ADDRESS"
+            }
+            ProposalError::InvalidAsset => "PROPOSAL_ERROR_INVALID_ASSET",
+            ProposalError::IncompatibleTimestamps => {
+                "PROPOSAL_ERROR_INCOMPATIBLE_TIMESTAMPS"
+            }
+            ProposalError::NoRiskParameters => "PROPOSAL_ERROR_NO_RISK_PARAMETERS",
+            ProposalError::NetworkParameterInvalidKey => {
+                "PROPOSAL_ERROR_NETWORK_PARAMETER_INVALID_KEY"
+            }
+            ProposalError::NetworkParameterInvalidValue => {
+                "PROPOSAL_ERROR_NETWORK_PARAMETER_INVALID_VALUE"
+            }
+            ProposalError::NetworkParameterValidationFailed => {
+                "PROPOSAL_ERROR_NETWORK_PARAMETER_VALIDATION_FAILED"
+            }
+            ProposalError::OpeningAuctionDurationTooSmall => {
+                "PROPOSAL_ERROR_OPENING_AUCTION_DURATION_TOO_SMALL"
+            }
+            ProposalError::OpeningAuctionDurationTooLarge => {
+                "PROPOSAL_ERROR_OPENING_AUCTION_DURATION_TOO_LARGE"
+            }
+            ProposalError::CouldNotInstantiateMarket => {
+                "PROPOSAL_ERROR_COULD_NOT_INSTANTIATE_MARKET"
+            }
+            ProposalError::InvalidFutureProduct => {
+                "PROPOSAL_ERROR_INVALID_FUTURE_PRODUCT"
+            }
+            ProposalError::InvalidRiskParameter => {
+                "PROPOSAL_ERROR_INVALID_RISK_PARAMETER"
+            }
+            ProposalError::MajorityThresholdNotReached => {
+                "PROPOSAL_ERROR_MAJORITY_THRESHOLD_NOT_REACHED"
+            }
+            ProposalError::ParticipationThresholdNotReached => {
+                "PROPOSAL_ERROR_PARTICIPATION_THRESHOLD_NOT_REACHED"
+            }
+            ProposalError::InvalidAssetDetails => "PROPOSAL_ERROR_INVALID_ASSET_DETAILS",
+            ProposalError::UnknownType => "PROPOSAL_ERROR_UNKNOWN_TYPE",
+            ProposalError::UnknownRiskParameterType => {
+                "PROPOSAL_ERROR_UNKNOWN_RISK_PARAMETER_TYPE"
+            }
+            ProposalError::InvalidFreeform => "PROPOSAL_ERROR_INVALID_FREEFORM",
+            ProposalError::InsufficientEquityLikeShare => {
+                "PROPOSAL_ERROR_INSUFFICIENT_EQUITY_LIKE_SHARE"
+            }
+            ProposalError::InvalidMarket => "PROPOSAL_ERROR_INVALID_MARKET",
+            ProposalError::TooManyMarketDecimalPlaces => {
+                "PROPOSAL_ERROR_TOO_MANY_MARKET_DECIMAL_PLACES"
+            }
+            ProposalError::TooManyPriceMonitoringTriggers => {
+                "PROPOSAL_ERROR_TOO_MANY_PRICE_MONITORING_TRIGGERS"
+            }
+            ProposalError::Erc20AddressAlreadyInUse => {
+                "PROPOSAL_ERROR_ERC20_ADDRESS_ALREADY_IN_USE"
+            }
+            ProposalError::LpPriceRangeNonpositive => {
+                "PROPOSAL_ERROR_LP_PRICE_RANGE_NONPOSITIVE"
+            }
+            ProposalError::LpPriceRangeTooLarge => {
+                "PROPOSAL_ERROR_LP_PRICE_RANGE_TOO_LARGE"
+            }
+            ProposalError::LinearSlippageFactorOutOfRange => {
+                "PROPOSAL_ERROR_LINEAR_SLIPPAGE_FACTOR_OUT_OF_RANGE"
+            }
+            ProposalError::QuadraticSlippageFactorOutOfRange => {
+                "PROPOSAL_ERROR_QUADRATIC_SLIPPAGE_FACTOR_OUT_OF_RANGE"
+            }
+            ProposalError::InvalidSpot => "PROPOSAL_ERROR_INVALID_SPOT",
+            ProposalError::SpotProductDisabled => "PROPOSAL_ERROR_SPOT_PRODUCT_DISABLED",
+            ProposalError::InvalidSuccessorMarket => {
+                "PROPOSAL_ERROR_INVALID_SUCCESSOR_MARKET"
+            }
+            ProposalError::GovernanceTransferProposalFailed => {
+                "PROPOSAL_ERROR_GOVERNANCE_TRANSFER_PROPOSAL_FAILED"
+            }
+            ProposalError::GovernanceTransferProposalInvalid => {
+                "PROPOSAL_ERROR_GOVERNANCE_TRANSFER_PROPOSAL_INVALID"
+            }
+            ProposalError::GovernanceCancelTransferProposalInvalid => {
+                "PROPOSAL_ERROR_GOVERNANCE_CANCEL_TRANSFER_PROPOSAL_INVALID"
+            }
+            ProposalError::InvalidMarketStateUpdate => {
+                "PROPOSAL_ERROR_INVALID_MARKET_STATE_UPDATE"
+            }
+            ProposalError::InvalidSlaParams => "PROPOSAL_ERROR_INVALID_SLA_PARAMS",
+            ProposalError::MissingSlaParams => "PROPOSAL_ERROR_MISSING_SLA_PARAMS",
+            ProposalError::InvalidPerpetualProduct => {
+                "PROPOSAL_ERROR_INVALID_PERPETUAL_PRODUCT"
+            }
+            ProposalError::InvalidReferralProgram => {
+                "PROPOSAL_ERROR_INVALID_REFERRAL_PROGRAM"
+            }
+            ProposalError::InvalidVolumeDiscountProgram => {
+                "PROPOSAL_ERROR_INVALID_VOLUME_DISCOUNT_PROGRAM"
+            }
+            ProposalError::ProposalInBatchRejected => {
+                "PROPOSAL_ERROR_PROPOSAL_IN_BATCH_REJECTED"
+            }
+            ProposalError::ProposalInBatchDeclined => {
+                "PROPOSAL_ERROR_PROPOSAL_IN_BATCH_DECLINED"
+            }
+            ProposalError::InvalidSizeDecimalPlaces => {
+                "PROPOSAL_ERROR_INVALID_SIZE_DECIMAL_PLACES"
+            }
+            ProposalError::InvalidVolumeRebateProgram => {
+                "PROPOSAL_ERROR_INVALID_VOLUME_REBATE_PROGRAM"
+            }
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "PROPOSAL_ERROR_UNSPECIFIED" => Some(Self::Unspecified),
+            "PROPOSAL_ERROR_CLOSE_TIME_TOO_SOON" => Some(Self::CloseTimeTooSoon),
+            "PROPOSAL_ERROR_CLOSE_TIME_TOO_LATE" => Some(Self::CloseTimeTooLate),
+            "PROPOSAL_ERROR_ENACT_TIME_TOO_SOON" => Some(Self::EnactTimeTooSoon),
+            "PROPOSAL_ERROR_ENACT_TIME_TOO_LATE" => Some(Self::EnactTimeTooLate),
+            "PROPOSAL_ERROR_INSUFFICIENT_TOKENS" => Some(Self::InsufficientTokens),
+            "PROPOSAL_ERROR_INVALID_INSTRUMENT_SECURITY" => {
+                Some(Self::InvalidInstrumentSecurity)
+            }
+            "PROPOSAL_ERROR_NO_PRODUCT" => Some(Self::NoProduct),
+            "PROPOSAL_ERROR_UNSUPPORTED_PRODUCT" => Some(Self::UnsupportedProduct),
+            "PROPOSAL_ERROR_NO_TRADING_MODE" => Some(Self::NoTradingMode),
+            "PROPOSAL_ERROR_UNSUPPORTED_TRADING_MODE" => {
+                Some(Self::UnsupportedTradingMode)
+            }
+            "PROPOSAL_ERROR_NODE_VALIDATION_FAILED" => Some(Self::NodeValidationFailed),
+            "PROPOSAL_ERROR_MISSING_BUILTIN_ASSET_FIELD" => {
+                Some(Self::MissingBuiltinAssetField)
+            }
+            "PROPOSAL_ERROR_MISSING_ERC20_CONTRACT_ADDRESS" => {
+                Some(Self::MissingErc20ContractAddress)
+            }
+            "PROPOSAL_ERROR_INVALID_ASSET" => Some(Self::InvalidAsset),
+            "PROPOSAL_ERROR_INCOMPATIBLE_TIMESTAMPS" => {
+                Some(Self::IncompatibleTimestamps)
+            }
+            "PROPOSAL_ERROR_NO_RISK_PARAMETERS" => Some(Self::NoRiskParameters),
+            "PROPOSAL_ERROR_NETWORK_PARAMETER_INVALID_KEY" => {
+                Some(Self::NetworkParameterInvalidKey)
+            }
+            "PROPOSAL_ERROR_NETWORK_PARAMETER_INVALID_VALUE" => {
+                Some(Self::NetworkParameterInvalidValue)
+            }
+            "PROPOSAL_ERROR_NETWORK_PARAMETER_VALIDATION_FAILED" => {
+                Some(Self::NetworkParameterValidationFailed)
+            }
+            "PROPOSAL_ERROR_OPENING_AUCTION_DURATION_TOO_SMALL" => {
+                Some(Self::OpeningAuctionDurationTooSmall)
+            }
+            "PROPOSAL_ERROR_OPENING_AUCTION_DURATION_TOO_LARGE" => {
+                Some(Self::OpeningAuctionDurationTooLarge)
+            }
+            "PROPOSAL_ERROR_COULD_NOT_INSTANTIATE_MARKET" => {
+                Some(Self::CouldNotInstantiateMarket)
+            }
+            "PROPOSAL_ERROR_INVALID_FUTURE_PRODUCT" => Some(Self::InvalidFutureProduct),
+            "PROPOSAL_ERROR_INVALID_RISK_PARAMETER" => Some(Self::InvalidRiskParameter),
+            "PROPOSAL_ERROR_MAJORITY_THRESHOLD_NOT_REACHED" => {
+                Some(Self::MajorityThresholdNotReached)
+            }
+            "PROPOSAL_ERROR_PARTICIPATION_THRESHOLD_NOT_REACHED" => {
+                Some(Self::ParticipationThresholdNotReached)
+            }
+            "PROPOSAL_ERROR_INVALID_ASSET_DETAILS" => Some(Self::InvalidAssetDetails),
+            "PROPOSAL_ERROR_UNKNOWN_TYPE" => Some(Self::UnknownType),
+            "PROPOSAL_ERROR_UNKNOWN_RISK_PARAMETER_TYPE" => {
+                Some(Self::UnknownRiskParameterType)
+            }
+            "PROPOSAL_ERROR_INVALID_FREEFORM" => Some(Self::InvalidFreeform),
+            "PROPOSAL_ERROR_INSUFFICIENT_EQUITY_LIKE_SHARE" => {
+                Some(Self::InsufficientEquityLikeShare)
+            }
+            "PROPOSAL_ERROR_INVALID_MARKET" => Some(Self::InvalidMarket),
+            "PROPOSAL_ERROR_TOO_MANY_MARKET_DECIMAL_PLACES" => {
+                Some(Self::TooManyMarketDecimalPlaces)
+            }
+            "PROPOSAL_ERROR_TOO_MANY_PRICE_MONITORING_TRIGGERS" => {
+                Some(Self::TooManyPriceMonitoringTriggers)
+            }
+            "PROPOSAL_ERROR_ERC20_ADDRESS_ALREADY_IN_USE" => {
+                Some(Self::Erc20AddressAlreadyInUse)
+            }
+            "PROPOSAL_ERROR_LP_PRICE_RANGE_NONPOSITIVE" => {
+                Some(Self::LpPriceRangeNonpositive)
+            }
+            "PROPOSAL_ERROR_LP_PRICE_RANGE_TOO_LARGE" => Some(Self::LpPriceRangeTooLarge),
+            "PROPOSAL_ERROR_LINEAR_SLIPPAGE_FACTOR_OUT_OF_RANGE" => {
+                Some(Self::LinearSlippageFactorOutOfRange)
+            }
+            "PROPOSAL_ERROR_QUADRATIC_SLIPPAGE_FACTOR_OUT_OF_RANGE" => {
+                Some(Self::QuadraticSlippageFactorOutOfRange)
+            }
+            "PROPOSAL_ERROR_INVALID_SPOT" => Some(Self::InvalidSpot),
+            "PROPOSAL_ERROR_SPOT_PRODUCT_DISABLED" => Some(Self::SpotProductDisabled),
+            "PROPOSAL_ERROR_INVALID_SUCCESSOR_MARKET" => {
+                Some(Self::InvalidSuccessorMarket)
+            }
+            "PROPOSAL_ERROR_GOVERNANCE_TRANSFER_PROPOSAL_FAILED" => {
+                Some(Self::GovernanceTransferProposalFailed)
+            }
+            "PROPOSAL_ERROR_GOVERNANCE_TRANSFER_PROPOSAL_INVALID" => {
+                Some(Self::GovernanceTransferProposalInvalid)
+            }
+            "PROPOSAL_ERROR_GOVERNANCE_CANCEL_TRANSFER_PROPOSAL_INVALID" => {
+                Some(Self::GovernanceCancelTransferProposalInvalid)
+            }
+            "PROPOSAL_ERROR_INVALID_MARKET_STATE_UPDATE" => {
+                Some(Self::InvalidMarketStateUpdate)
+            }
+            "PROPOSAL_ERROR_INVALID_SLA_PARAMS" => Some(Self::InvalidSlaParams),
+            "PROPOSAL_ERROR_MISSING_SLA_PARAMS" => Some(Self::MissingSlaParams),
+            "PROPOSAL_ERROR_INVALID_PERPETUAL_PRODUCT" => {
+                Some(Self::InvalidPerpetualProduct)
+            }
+            "PROPOSAL_ERROR_INVALID_REFERRAL_PROGRAM" => {
+                Some(Self::InvalidReferralProgram)
+            }
+            "PROPOSAL_ERROR_INVALID_VOLUME_DISCOUNT_PROGRAM" => {
+                Some(Self::InvalidVolumeDiscountProgram)
+            }
+            "PROPOSAL_ERROR_PROPOSAL_IN_BATCH_REJECTED" => {
+                Some(Self::ProposalInBatchRejected)
+            }
+            "PROPOSAL_ERROR_PROPOSAL_IN_BATCH_DECLINED" => {
+                Some(Self::ProposalInBatchDeclined)
+            }
+            "PROPOSAL_ERROR_INVALID_SIZE_DECIMAL_PLACES" => {
+                Some(Self::InvalidSizeDecimalPlaces)
+            }
+            "PROPOSAL_ERROR_INVALID_VOLUME_REBATE_PROGRAM" => {
+                Some(Self::InvalidVolumeRebateProgram)
+            }
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum MarketStateUpdateType {
+    /// Default value, always invalid
+    Unspecified = 0,
+    /// Request to terminate a market via governance
+    Terminate = 1,
+    /// Request to suspend a market via governance
+    Suspend = 2,
+    /// Request to resume a market via governance
+    Resume = 3,
+}
+impl MarketStateUpdateType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            MarketStateUpdateType::Unspecified => "MARKET_STATE_UPDATE_TYPE_UNSPECIFIED",
+            MarketStateUpdateType::Terminate => "MARKET_STATE_UPDATE_TYPE_TERMINATE",
+            MarketStateUpdateType::Suspend => "MARKET_STATE_UPDATE_TYPE_SUSPEND",
+            MarketStateUpdateType::Resume => "MARKET_STATE_UPDATE_TYPE_RESUME",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "MARKET_STATE_UPDATE_TYPE_UNSPECIFIED" => Some(Self::Unspecified),
+            "MARKET_STATE_UPDATE_TYPE_TERMINATE" => Some(Self::Terminate),
+            "MARKET_STATE_UPDATE_TYPE_SUSPEND" => Some(Self::Suspend),
+            "MARKET_STATE_UPDATE_TYPE_RESUME" => Some(Self::Resume),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum GovernanceTransferType {
+    Unspecified = 0,
+    AllOrNothing = 1,
+    BestEffort = 2,
+}
+impl GovernanceTransferType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            GovernanceTransferType::Unspecified => "GOVERNANCE_TRANSFER_TYPE_UNSPECIFIED",
+            GovernanceTransferType::AllOrNothing => {
+                "GOVERNANCE_TRANSFER_TYPE_ALL_OR_NOTHING"
+            }
+            GovernanceTransferType::BestEffort => "GOVERNANCE_TRANSFER_TYPE_BEST_EFFORT",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "GOVERNANCE_TRANSFER_TYPE_UNSPECIFIED" => Some(Self::Unspecified),
+            "GOVERNANCE_TRANSFER_TYPE_ALL_OR_NOTHING" => Some(Self::AllOrNothing),
+            "GOVERNANCE_TRANSFER_TYPE_BEST_EFFORT" => Some(Self::BestEffort),
+            _ => None,
+        }
+    }
+}
+/// Oracle spec contains an embedded external data source spec.
+/// It will be generated by the compiler as: OracleSpec_ExternalDataSourceSpec_*
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OracleSpec {
+    #[prost(message, optional, tag = "1")]
+    pub external_data_source_spec: ::core::option::Option<ExternalDataSourceSpec>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OracleData {
+    #[prost(message, optional, tag = "1")]
+    pub external_data: ::core::option::Option<data::v1::ExternalData>,
+}
@@ -0,0 +1,2567 @@
+// This file is @generated by prost-build.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Snapshot {
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    #[prost(enumeration = "Format", tag = "2")]
+    pub format: i32,
+    #[prost(uint32, tag = "3")]
+    pub chunks: u32,
+    #[prost(bytes = "vec", tag = "4")]
+    pub hash: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "5")]
+    pub metadata: ::prost::alloc::vec::Vec<u8>,
+}
+/// NodeHash represents an exported node from the AVL tree and contains all the
+/// data necessary to reconstruct an identical copy of the AVL tree after it's
+/// been serialised
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NodeHash {
+    /// Node's full key, for example epoch.all
+    #[prost(string, tag = "3")]
+    pub key: ::prost::alloc::string::String,
+    /// Hash of the node's value
+    #[prost(string, tag = "4")]
+    pub hash: ::prost::alloc::string::String,
+    /// Height of the node in the tree
+    #[prost(int32, tag = "5")]
+    pub height: i32,
+    /// Version of that node i.e., how many times its value has changed during tree
+    /// updates
+    #[prost(int64, tag = "6")]
+    pub version: i64,
+    /// Whether or not the node contains a snapshot payload, only leaf nodes in the
+    /// AVL contain payloads
+    #[prost(bool, tag = "7")]
+    pub is_leaf: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Metadata {
+    #[prost(int64, tag = "1")]
+    pub version: i64,
+    #[prost(string, repeated, tag = "2")]
+    pub chunk_hashes: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(message, repeated, tag = "3")]
+    pub node_hashes: ::prost::alloc::vec::Vec<NodeHash>,
+    #[prost(string, tag = "4")]
+    pub protocol_version: ::prost::alloc::string::String,
+    #[prost(bool, tag = "5")]
+    pub protocol_upgrade: bool,
+}
+/// Chunk is simply an as-is chunk belonging to a snapshot
+/// not sure how usable this type would be
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Chunk {
+    #[prost(message, repeated, tag = "1")]
+    pub data: ::prost::alloc::vec::Vec<Payload>,
+    /// in case of multi-part data, this is chunk nr...
+    #[prost(int64, tag = "2")]
+    pub nr: i64,
+    /// ...of a total of N chunks
+    #[prost(int64, tag = "3")]
+    pub of: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Payload {
+    #[prost(
+        oneof = "payload::Data",
+        tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 23, 24, 25, 26, 27, 28, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 46, 47, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64, 65, 66, 67, 68, 69, 70, 71, 72, 73, 76, 77, 78, 79, 80, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90"
+    )]
+    pub data: ::core::option::Option<payload::Data>,
+}
+/// Nested message and enum types in `Payload`.
+pub mod payload {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Data {
+        #[prost(message, tag = "1")]
+        ActiveAssets(super::ActiveAssets),
+        #[prost(message, tag = "2")]
+        PendingAssets(super::PendingAssets),
+        #[prost(message, tag = "3")]
+        BankingWithdrawals(super::BankingWithdrawals),
+        #[prost(message, tag = "4")]
+        BankingDeposits(super::BankingDeposits),
+        #[prost(message, tag = "5")]
+        BankingSeen(super::BankingSeen),
+        #[prost(message, tag = "6")]
+        BankingAssetActions(super::BankingAssetActions),
+        #[prost(message, tag = "7")]
+        Checkpoint(super::Checkpoint),
+        #[prost(message, tag = "8")]
+        CollateralAccounts(super::CollateralAccounts),
+        #[prost(message, tag = "9")]
+        CollateralAssets(super::CollateralAssets),
+        #[prost(message, tag = "10")]
+        DelegationActive(super::DelegationActive),
+        #[prost(message, tag = "11")]
+        DelegationPending(super::DelegationPending),
+        #[prost(message, tag = "12")]
+        DelegationAuto(super::DelegationAuto),
+        #[prost(message, tag = "13")]
+        GovernanceActive(super::GovernanceActive),
+        #[prost(message, tag = "14")]
+        GovernanceEnacted(super::GovernanceEnacted),
+        #[prost(message, tag = "15")]
+        StakingAccounts(super::StakingAccounts),
+        #[prost(message, tag = "16")]
+        MatchingBook(super::MatchingBook),
+        #[prost(message, tag = "17")]
+        NetworkParameters(super::NetParams),
+        #[prost(message, tag = "18")]
+        ExecutionMarkets(super::ExecutionMarkets),
+        #[prost(message, tag = "19")]
+        MarketPositions(super::MarketPositions),
+        #[prost(message, tag = "20")]
+        AppState(super::AppState),
+        #[prost(message, tag = "21")]
+        Epoch(super::EpochState),
+        #[prost(message, tag = "23")]
+        RewardsPendingPayouts(super::RewardsPendingPayouts),
+        #[prost(message, tag = "24")]
+        GovernanceNode(super::GovernanceNode),
+        #[prost(message, tag = "25")]
+        LimitState(super::LimitState),
+        #[prost(message, tag = "26")]
+        VoteSpamPolicy(super::VoteSpamPolicy),
+        #[prost(message, tag = "27")]
+        SimpleSpamPolicy(super::SimpleSpamPolicy),
+        #[prost(message, tag = "28")]
+        Notary(super::Notary),
+        #[prost(message, tag = "31")]
+        EventForwarder(super::EventForwarder),
+        #[prost(message, tag = "32")]
+        StakeVerifierDeposited(super::StakeVerifierDeposited),
+        #[prost(message, tag = "33")]
+        StakeVerifierRemoved(super::StakeVerifierRemoved),
+        #[prost(message, tag = "34")]
+        Witness(super::Witness),
+        #[prost(message, tag = "35")]
+        DelegationLastReconciliationTime(super::DelegationLastReconciliationTime),
+        #[prost(message, tag = "36")]
+        Topology(super::Topology),
+        #[prost(message, tag = "37")]
+        OracleData(super::OracleDataBatch),
+        #[prost(message, tag = "38")]
+        LiquidityParameters(super::LiquidityParameters),
+        #[prost(message, tag = "39")]
+        LiquidityPendingProvisions(super::LiquidityPendingProvisions),
+        #[prost(message, tag = "40")]
+        LiquidityPartiesLiquidityOrders(super::LiquidityPartiesLiquidityOrders),
+        #[prost(message, tag = "41")]
+        LiquidityPartiesOrders(super::LiquidityPartiesOrders),
+        #[prost(message, tag = "42")]
+        LiquidityProvisions(super::LiquidityProvisions),
+        #[prost(message, tag = "43")]
+        LiquiditySupplied(super::LiquiditySupplied),
+        #[prost(message, tag = "44")]
+        LiquidityTarget(super::LiquidityTarget),
+        #[prost(message, tag = "46")]
+        FloatingPointConsensus(super::FloatingPointConsensus),
+        #[prost(message, tag = "47")]
+        MarketTracker(super::MarketTracker),
+        #[prost(message, tag = "49")]
+        BankingRecurringTransfers(super::BankingRecurringTransfers),
+        #[prost(message, tag = "50")]
+        BankingScheduledTransfers(super::BankingScheduledTransfers),
+        #[prost(message, tag = "51")]
+        Erc20MultisigTopologyVerified(super::Erc20MultiSigTopologyVerified),
+        #[prost(message, tag = "52")]
+        Erc20MultisigTopologyPending(super::Erc20MultiSigTopologyPending),
+        #[prost(message, tag = "53")]
+        ProofOfWork(super::ProofOfWork),
+        #[prost(message, tag = "54")]
+        PendingAssetUpdates(super::PendingAssetUpdates),
+        #[prost(message, tag = "55")]
+        ProtocolUpgradeProposals(super::ProtocolUpgradeProposals),
+        #[prost(message, tag = "56")]
+        BankingPrimaryBridgeState(super::BankingBridgeState),
+        #[prost(message, tag = "57")]
+        SettlementState(super::SettlementState),
+        #[prost(message, tag = "58")]
+        LiquidityScores(super::LiquidityScores),
+        #[prost(message, tag = "59")]
+        SpotLiquidityTarget(super::SpotLiquidityTarget),
+        #[prost(message, tag = "60")]
+        BankingRecurringGovernanceTransfers(super::BankingRecurringGovernanceTransfers),
+        #[prost(message, tag = "61")]
+        BankingScheduledGovernanceTransfers(super::BankingScheduledGovernanceTransfers),
+        #[prost(message, tag = "62")]
+        EthContractCallResults(super::EthContractCallResults),
+        #[prost(message, tag = "63")]
+        EthOracleVerifierLastBlock(super::EthOracleVerifierLastBlock),
+        #[prost(message, tag = "64")]
+        LiquidityV2Provisions(super::LiquidityV2Provisions),
+        #[prost(message, tag = "65")]
+        LiquidityV2PendingProvisions(super::LiquidityV2PendingProvisions),
+        #[prost(message, tag = "66")]
+        LiquidityV2Performances(super::LiquidityV2Performances),
+        #[prost(message, tag = "67")]
+        LiquidityV2Supplied(super::LiquidityV2Supplied),
+        #[prost(message, tag = "68")]
+        LiquidityV2Scores(super::LiquidityV2Scores),
+        #[prost(message, tag = "69")]
+        HoldingAccountTracker(super::HoldingAccountTracker),
+        #[prost(message, tag = "70")]
+        Teams(super::Teams),
+        #[prost(message, tag = "71")]
+        TeamSwitches(super::TeamSwitches),
+        #[prost(message, tag = "72")]
+        Vesting(super::Vesting),
+        #[prost(message, tag = "73")]
+        ReferralProgram(super::ReferralProgramData),
+        #[prost(message, tag = "76")]
+        ActivityStreak(super::ActivityStreak),
+        #[prost(message, tag = "77")]
+        VolumeDiscountProgram(super::VolumeDiscountProgram),
+        #[prost(message, tag = "78")]
+        LiquidityV2Parameters(super::LiquidityV2Parameters),
+        #[prost(message, tag = "79")]
+        LiquidityV2PaidFeesStats(super::LiquidityV2PaidFeesStats),
+        #[prost(message, tag = "80")]
+        Liquidation(super::Liquidation),
+        #[prost(message, tag = "81")]
+        BankingTransferFeeDiscounts(super::BankingTransferFeeDiscounts),
+        #[prost(message, tag = "82")]
+        GovernanceBatchActive(super::GovernanceBatchActive),
+        #[prost(message, tag = "83")]
+        Parties(super::Parties),
+        #[prost(message, tag = "84")]
+        L2EthOracles(super::L2EthOracles),
+        #[prost(message, tag = "85")]
+        EthOracleVerifierMisc(super::EthOracleVerifierMisc),
+        #[prost(message, tag = "86")]
+        BankingEvmBridgeStates(super::BankingEvmBridgeStates),
+        #[prost(message, tag = "87")]
+        EvmMultisigTopologies(super::EvmMultisigTopologies),
+        #[prost(message, tag = "88")]
+        TxCache(super::TxCache),
+        #[prost(message, tag = "89")]
+        EvmFwdHeartbeats(super::EvmFwdHeartbeats),
+        #[prost(message, tag = "90")]
+        VolumeRebateProgram(super::VolumeRebateProgram),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OrderHoldingQuantities {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub quantity: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub fee: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct HoldingAccountTracker {
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "2")]
+    pub order_holding: ::prost::alloc::vec::Vec<OrderHoldingQuantities>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TimestampedTotalStake {
+    #[prost(uint64, tag = "1")]
+    pub total_stake: u64,
+    #[prost(int64, tag = "2")]
+    pub time: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TimestampedOpenInterest {
+    #[prost(uint64, tag = "1")]
+    pub open_interest: u64,
+    #[prost(int64, tag = "2")]
+    pub time: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidityTarget {
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+    #[prost(int64, tag = "2")]
+    pub current_time: i64,
+    #[prost(int64, tag = "3")]
+    pub scheduled_truncate: i64,
+    #[prost(uint64, repeated, tag = "4")]
+    pub current_open_interests: ::prost::alloc::vec::Vec<u64>,
+    #[prost(message, repeated, tag = "5")]
+    pub previous_open_interests: ::prost::alloc::vec::Vec<TimestampedOpenInterest>,
+    #[prost(message, optional, tag = "6")]
+    pub max_open_interests: ::core::option::Option<TimestampedOpenInterest>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SpotLiquidityTarget {
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+    #[prost(int64, tag = "2")]
+    pub current_time: i64,
+    #[prost(int64, tag = "3")]
+    pub scheduled_truncate: i64,
+    #[prost(uint64, repeated, tag = "4")]
+    pub current_total_stake: ::prost::alloc::vec::Vec<u64>,
+    #[prost(message, repeated, tag = "5")]
+    pub previous_total_stake: ::prost::alloc::vec::Vec<TimestampedTotalStake>,
+    #[prost(message, optional, tag = "6")]
+    pub max_total_stake: ::core::option::Option<TimestampedTotalStake>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidityOffsetProbabilityPair {
+    #[prost(uint32, tag = "1")]
+    pub offset: u32,
+    #[prost(string, tag = "2")]
+    pub probability: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquiditySupplied {
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+    #[prost(bool, tag = "2")]
+    pub consensus_reached: bool,
+    #[prost(message, repeated, tag = "3")]
+    pub bid_cache: ::prost::alloc::vec::Vec<LiquidityOffsetProbabilityPair>,
+    #[prost(message, repeated, tag = "4")]
+    pub ask_cache: ::prost::alloc::vec::Vec<LiquidityOffsetProbabilityPair>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OracleDataBatch {
+    #[prost(message, repeated, tag = "1")]
+    pub oracle_data: ::prost::alloc::vec::Vec<OracleData>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OracleData {
+    #[prost(message, repeated, tag = "1")]
+    pub signers: ::prost::alloc::vec::Vec<super::super::data::v1::Signer>,
+    #[prost(message, repeated, tag = "2")]
+    pub data: ::prost::alloc::vec::Vec<OracleDataPair>,
+    #[prost(message, repeated, tag = "3")]
+    pub meta_data: ::prost::alloc::vec::Vec<super::super::data::v1::Property>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OracleDataPair {
+    #[prost(string, tag = "1")]
+    pub key: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub value: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Witness {
+    #[prost(message, repeated, tag = "1")]
+    pub resources: ::prost::alloc::vec::Vec<Resource>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Resource {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(int64, tag = "2")]
+    pub check_until: i64,
+    #[prost(string, repeated, tag = "3")]
+    pub votes: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(uint32, tag = "4")]
+    pub state: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EventForwarderBucket {
+    #[prost(int64, tag = "1")]
+    pub ts: i64,
+    #[prost(string, repeated, tag = "2")]
+    pub hashes: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EventForwarder {
+    /// this is deprecated, use the other one now
+    #[prost(string, repeated, tag = "1")]
+    pub acked_events: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(message, repeated, tag = "2")]
+    pub buckets: ::prost::alloc::vec::Vec<EventForwarderBucket>,
+    #[prost(string, tag = "3")]
+    pub chain_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CollateralAccounts {
+    #[prost(message, repeated, tag = "1")]
+    pub accounts: ::prost::alloc::vec::Vec<super::super::Account>,
+    #[prost(int64, tag = "2")]
+    pub next_balance_snapshot: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CollateralAssets {
+    #[prost(message, repeated, tag = "1")]
+    pub assets: ::prost::alloc::vec::Vec<super::super::Asset>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ActiveAssets {
+    #[prost(message, repeated, tag = "1")]
+    pub assets: ::prost::alloc::vec::Vec<super::super::Asset>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PendingAssets {
+    #[prost(message, repeated, tag = "1")]
+    pub assets: ::prost::alloc::vec::Vec<super::super::Asset>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PendingAssetUpdates {
+    #[prost(message, repeated, tag = "1")]
+    pub assets: ::prost::alloc::vec::Vec<super::super::Asset>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Withdrawal {
+    #[prost(string, tag = "1")]
+    pub r#ref: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub withdrawal: ::core::option::Option<super::super::Withdrawal>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Deposit {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub deposit: ::core::option::Option<super::super::Deposit>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TxRef {
+    #[prost(string, tag = "1")]
+    pub asset: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "2")]
+    pub block_nr: u64,
+    #[prost(string, tag = "3")]
+    pub hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "4")]
+    pub log_index: u64,
+    #[prost(string, tag = "5")]
+    pub chain_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BankingWithdrawals {
+    #[prost(message, repeated, tag = "1")]
+    pub withdrawals: ::prost::alloc::vec::Vec<Withdrawal>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BankingDeposits {
+    #[prost(message, repeated, tag = "1")]
+    pub deposit: ::prost::alloc::vec::Vec<Deposit>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BankingSeen {
+    #[prost(string, repeated, tag = "1")]
+    pub refs: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(uint64, tag = "2")]
+    pub last_seen_primary_eth_block: u64,
+    #[prost(uint64, tag = "3")]
+    pub last_seen_secondary_eth_block: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BankingAssetActions {
+    #[prost(message, repeated, tag = "1")]
+    pub asset_action: ::prost::alloc::vec::Vec<
+        super::super::checkpoint::v1::AssetAction,
+    >,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BankingRecurringTransfers {
+    #[prost(message, optional, tag = "1")]
+    pub recurring_transfers: ::core::option::Option<
+        super::super::checkpoint::v1::RecurringTransfers,
+    >,
+    #[prost(int64, tag = "2")]
+    pub next_metric_update: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BankingScheduledTransfers {
+    #[prost(message, repeated, tag = "1")]
+    pub transfers_at_time: ::prost::alloc::vec::Vec<
+        super::super::checkpoint::v1::ScheduledTransferAtTime,
+    >,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BankingRecurringGovernanceTransfers {
+    #[prost(message, repeated, tag = "1")]
+    pub recurring_transfers: ::prost::alloc::vec::Vec<
+        super::super::checkpoint::v1::GovernanceTransfer,
+    >,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BankingScheduledGovernanceTransfers {
+    #[prost(message, repeated, tag = "1")]
+    pub transfers_at_time: ::prost::alloc::vec::Vec<
+        super::super::checkpoint::v1::ScheduledGovernanceTransferAtTime,
+    >,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BankingBridgeState {
+    #[prost(message, optional, tag = "1")]
+    pub bridge_state: ::core::option::Option<super::super::checkpoint::v1::BridgeState>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BankingEvmBridgeStates {
+    #[prost(message, repeated, tag = "1")]
+    pub bridge_states: ::prost::alloc::vec::Vec<
+        super::super::checkpoint::v1::BridgeState,
+    >,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Checkpoint {
+    #[prost(int64, tag = "1")]
+    pub next_cp: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DelegationLastReconciliationTime {
+    #[prost(int64, tag = "1")]
+    pub last_reconciliation_time: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DelegationActive {
+    #[prost(message, repeated, tag = "1")]
+    pub delegations: ::prost::alloc::vec::Vec<super::super::Delegation>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DelegationPending {
+    #[prost(message, repeated, tag = "1")]
+    pub delegations: ::prost::alloc::vec::Vec<super::super::Delegation>,
+    #[prost(message, repeated, tag = "2")]
+    pub undelegation: ::prost::alloc::vec::Vec<super::super::Delegation>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DelegationAuto {
+    #[prost(string, repeated, tag = "1")]
+    pub parties: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProposalData {
+    #[prost(message, optional, tag = "1")]
+    pub proposal: ::core::option::Option<super::super::Proposal>,
+    #[prost(message, repeated, tag = "2")]
+    pub yes: ::prost::alloc::vec::Vec<super::super::Vote>,
+    #[prost(message, repeated, tag = "3")]
+    pub no: ::prost::alloc::vec::Vec<super::super::Vote>,
+    #[prost(message, repeated, tag = "4")]
+    pub invalid: ::prost::alloc::vec::Vec<super::super::Vote>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GovernanceEnacted {
+    #[prost(message, repeated, tag = "1")]
+    pub proposals: ::prost::alloc::vec::Vec<ProposalData>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GovernanceActive {
+    #[prost(message, repeated, tag = "1")]
+    pub proposals: ::prost::alloc::vec::Vec<ProposalData>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BatchProposalData {
+    #[prost(message, optional, tag = "1")]
+    pub batch_proposal: ::core::option::Option<ProposalData>,
+    #[prost(message, repeated, tag = "2")]
+    pub proposals: ::prost::alloc::vec::Vec<super::super::Proposal>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GovernanceBatchActive {
+    #[prost(message, repeated, tag = "1")]
+    pub batch_proposals: ::prost::alloc::vec::Vec<BatchProposalData>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GovernanceNode {
+    #[prost(message, repeated, tag = "1")]
+    pub proposals: ::prost::alloc::vec::Vec<super::super::Proposal>,
+    /// includes votes
+    #[prost(message, repeated, tag = "2")]
+    pub proposal_data: ::prost::alloc::vec::Vec<ProposalData>,
+    #[prost(message, repeated, tag = "3")]
+    pub batch_proposal_data: ::prost::alloc::vec::Vec<BatchProposalData>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StakingAccount {
+    #[prost(string, tag = "1")]
+    pub party: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub balance: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "3")]
+    pub events: ::prost::alloc::vec::Vec<super::super::events::v1::StakeLinking>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StakingAccounts {
+    #[prost(message, repeated, tag = "1")]
+    pub accounts: ::prost::alloc::vec::Vec<StakingAccount>,
+    #[prost(string, tag = "2")]
+    pub staking_asset_total_supply: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub pending_stake_total_supply: ::core::option::Option<
+        super::super::StakeTotalSupply,
+    >,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MatchingBook {
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "2")]
+    pub buy: ::prost::alloc::vec::Vec<super::super::Order>,
+    #[prost(message, repeated, tag = "3")]
+    pub sell: ::prost::alloc::vec::Vec<super::super::Order>,
+    #[prost(string, tag = "4")]
+    pub last_traded_price: ::prost::alloc::string::String,
+    #[prost(bool, tag = "5")]
+    pub auction: bool,
+    #[prost(uint64, tag = "6")]
+    pub batch_id: u64,
+    #[prost(string, repeated, tag = "7")]
+    pub pegged_order_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NetParams {
+    #[prost(message, repeated, tag = "1")]
+    pub params: ::prost::alloc::vec::Vec<super::super::NetworkParameter>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DecimalMap {
+    #[prost(int64, tag = "1")]
+    pub key: i64,
+    #[prost(string, tag = "2")]
+    pub val: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TimePrice {
+    #[prost(int64, tag = "1")]
+    pub time: i64,
+    #[prost(string, tag = "2")]
+    pub price: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PriceVolume {
+    #[prost(string, tag = "1")]
+    pub price: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "2")]
+    pub volume: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PriceRange {
+    #[prost(string, tag = "1")]
+    pub min: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub max: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub r#ref: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PriceBound {
+    #[prost(bool, tag = "1")]
+    pub active: bool,
+    #[prost(string, tag = "2")]
+    pub up_factor: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub down_factor: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "4")]
+    pub trigger: ::core::option::Option<super::super::PriceMonitoringTrigger>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PriceRangeCache {
+    #[prost(message, optional, tag = "1")]
+    pub bound: ::core::option::Option<PriceBound>,
+    #[prost(message, optional, tag = "2")]
+    pub range: ::core::option::Option<PriceRange>,
+    #[prost(uint64, tag = "3")]
+    pub bound_index: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CurrentPrice {
+    #[prost(string, tag = "1")]
+    pub price: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "2")]
+    pub volume: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PastPrice {
+    #[prost(int64, tag = "1")]
+    pub time: i64,
+    #[prost(string, tag = "2")]
+    pub volume_weighted_price: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PriceMonitor {
+    #[prost(bool, tag = "3")]
+    pub initialised: bool,
+    #[prost(message, repeated, tag = "4")]
+    pub fp_horizons: ::prost::alloc::vec::Vec<DecimalMap>,
+    #[prost(int64, tag = "5")]
+    pub now: i64,
+    #[prost(int64, tag = "6")]
+    pub update: i64,
+    #[prost(message, repeated, tag = "7")]
+    pub bounds: ::prost::alloc::vec::Vec<PriceBound>,
+    #[prost(int64, tag = "8")]
+    pub price_range_cache_time: i64,
+    #[prost(message, repeated, tag = "9")]
+    pub price_range_cache: ::prost::alloc::vec::Vec<PriceRangeCache>,
+    #[prost(int64, tag = "10")]
+    pub ref_price_cache_time: i64,
+    #[prost(message, repeated, tag = "11")]
+    pub ref_price_cache: ::prost::alloc::vec::Vec<DecimalMap>,
+    #[prost(message, repeated, tag = "12")]
+    pub prices_now: ::prost::alloc::vec::Vec<CurrentPrice>,
+    #[prost(message, repeated, tag = "13")]
+    pub prices_past: ::prost::alloc::vec::Vec<PastPrice>,
+    #[prost(bool, tag = "14")]
+    pub consensus_reached: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AuctionState {
+    #[prost(enumeration = "super::super::market::TradingMode", tag = "1")]
+    pub mode: i32,
+    #[prost(enumeration = "super::super::market::TradingMode", tag = "2")]
+    pub default_mode: i32,
+    #[prost(enumeration = "super::super::AuctionTrigger", tag = "3")]
+    pub trigger: i32,
+    #[prost(int64, tag = "4")]
+    pub begin: i64,
+    #[prost(message, optional, tag = "5")]
+    pub end: ::core::option::Option<super::super::AuctionDuration>,
+    #[prost(bool, tag = "6")]
+    pub start: bool,
+    #[prost(bool, tag = "7")]
+    pub stop: bool,
+    #[prost(enumeration = "super::super::AuctionTrigger", tag = "8")]
+    pub extension: i32,
+    #[prost(bool, tag = "9")]
+    pub extension_event_sent: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EquityShareLp {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub stake: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub share: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub avg: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub vshare: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EquityShare {
+    #[prost(string, tag = "1")]
+    pub mvp: ::prost::alloc::string::String,
+    #[prost(bool, tag = "2")]
+    pub opening_auction_ended: bool,
+    #[prost(message, repeated, tag = "3")]
+    pub lps: ::prost::alloc::vec::Vec<EquityShareLp>,
+    #[prost(string, tag = "4")]
+    pub r: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub p_mvp: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FeeSplitter {
+    #[prost(int64, tag = "1")]
+    pub time_window_start: i64,
+    #[prost(string, tag = "2")]
+    pub trade_value: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub avg: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "4")]
+    pub window: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SpotMarket {
+    #[prost(message, optional, tag = "1")]
+    pub market: ::core::option::Option<super::super::Market>,
+    #[prost(message, optional, tag = "2")]
+    pub price_monitor: ::core::option::Option<PriceMonitor>,
+    #[prost(message, optional, tag = "3")]
+    pub auction_state: ::core::option::Option<AuctionState>,
+    #[prost(message, optional, tag = "4")]
+    pub pegged_orders: ::core::option::Option<PeggedOrders>,
+    #[prost(message, repeated, tag = "5")]
+    pub expiring_orders: ::prost::alloc::vec::Vec<super::super::Order>,
+    #[prost(string, tag = "6")]
+    pub last_best_bid: ::prost::alloc::string::String,
+    #[prost(string, tag = "7")]
+    pub last_best_ask: ::prost::alloc::string::String,
+    #[prost(string, tag = "8")]
+    pub last_mid_bid: ::prost::alloc::string::String,
+    #[prost(string, tag = "9")]
+    pub last_mid_ask: ::prost::alloc::string::String,
+    #[prost(string, tag = "10")]
+    pub last_market_value_proxy: ::prost::alloc::string::String,
+    #[prost(int64, tag = "11")]
+    pub last_equity_share_distributed: i64,
+    #[prost(message, optional, tag = "12")]
+    pub equity_share: ::core::option::Option<EquityShare>,
+    #[prost(string, tag = "13")]
+    pub current_mark_price: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "14")]
+    pub fee_splitter: ::core::option::Option<FeeSplitter>,
+    #[prost(int64, tag = "15")]
+    pub next_mark_to_market: i64,
+    #[prost(string, tag = "16")]
+    pub last_traded_price: ::prost::alloc::string::String,
+    #[prost(string, repeated, tag = "17")]
+    pub parties: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(bool, tag = "18")]
+    pub closed: bool,
+    #[prost(message, optional, tag = "19")]
+    pub stop_orders: ::core::option::Option<StopOrders>,
+    #[prost(message, repeated, tag = "20")]
+    pub expiring_stop_orders: ::prost::alloc::vec::Vec<super::super::Order>,
+    #[prost(message, optional, tag = "21")]
+    pub fees_stats: ::core::option::Option<super::super::events::v1::FeesStats>,
+    #[prost(bool, tag = "22")]
+    pub has_traded: bool,
+    #[prost(message, optional, tag = "23")]
+    pub market_liquidity: ::core::option::Option<MarketLiquidity>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Market {
+    #[prost(message, optional, tag = "1")]
+    pub market: ::core::option::Option<super::super::Market>,
+    #[prost(message, optional, tag = "2")]
+    pub price_monitor: ::core::option::Option<PriceMonitor>,
+    #[prost(message, optional, tag = "3")]
+    pub auction_state: ::core::option::Option<AuctionState>,
+    #[prost(message, optional, tag = "4")]
+    pub pegged_orders: ::core::option::Option<PeggedOrders>,
+    #[prost(message, repeated, tag = "5")]
+    pub expiring_orders: ::prost::alloc::vec::Vec<super::super::Order>,
+    #[prost(string, tag = "6")]
+    pub last_best_bid: ::prost::alloc::string::String,
+    #[prost(string, tag = "7")]
+    pub last_best_ask: ::prost::alloc::string::String,
+    #[prost(string, tag = "8")]
+    pub last_mid_bid: ::prost::alloc::string::String,
+    #[prost(string, tag = "9")]
+    pub last_mid_ask: ::prost::alloc::string::String,
+    #[prost(string, tag = "10")]
+    pub last_market_value_proxy: ::prost::alloc::string::String,
+    #[prost(int64, tag = "11")]
+    pub last_equity_share_distributed: i64,
+    #[prost(message, optional, tag = "12")]
+    pub equity_share: ::core::option::Option<EquityShare>,
+    #[prost(string, tag = "13")]
+    pub current_mark_price: ::prost::alloc::string::String,
+    #[prost(string, tag = "14")]
+    pub risk_factor_short: ::prost::alloc::string::String,
+    #[prost(string, tag = "15")]
+    pub risk_factor_long: ::prost::alloc::string::String,
+    #[prost(bool, tag = "16")]
+    pub risk_factor_consensus_reached: bool,
+    #[prost(message, optional, tag = "17")]
+    pub fee_splitter: ::core::option::Option<FeeSplitter>,
+    #[prost(string, tag = "18")]
+    pub settlement_data: ::prost::alloc::string::String,
+    #[prost(int64, tag = "19")]
+    pub next_mark_to_market: i64,
+    #[prost(string, tag = "20")]
+    pub last_traded_price: ::prost::alloc::string::String,
+    #[prost(string, repeated, tag = "21")]
+    pub parties: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(bool, tag = "22")]
+    pub closed: bool,
+    #[prost(bool, tag = "23")]
+    pub succeeded: bool,
+    #[prost(message, optional, tag = "24")]
+    pub stop_orders: ::core::option::Option<StopOrders>,
+    #[prost(message, repeated, tag = "25")]
+    pub expiring_stop_orders: ::prost::alloc::vec::Vec<super::super::Order>,
+    #[prost(message, optional, tag = "26")]
+    pub product: ::core::option::Option<Product>,
+    #[prost(message, optional, tag = "27")]
+    pub fees_stats: ::core::option::Option<super::super::events::v1::FeesStats>,
+    #[prost(message, repeated, tag = "28")]
+    pub party_margin_factor: ::prost::alloc::vec::Vec<PartyMarginFactor>,
+    #[prost(message, optional, tag = "29")]
+    pub mark_price_calculator: ::core::option::Option<CompositePriceCalculator>,
+    #[prost(message, optional, tag = "30")]
+    pub internal_composite_price_calculator: ::core::option::Option<
+        CompositePriceCalculator,
+    >,
+    #[prost(int64, tag = "31")]
+    pub next_internal_composite_price_calc: i64,
+    #[prost(message, optional, tag = "32")]
+    pub market_liquidity: ::core::option::Option<MarketLiquidity>,
+    #[prost(message, optional, tag = "33")]
+    pub amm: ::core::option::Option<AmmState>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PartyMarginFactor {
+    #[prost(string, tag = "1")]
+    pub party: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub margin_factor: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AmmState {
+    #[prost(message, repeated, tag = "1")]
+    pub sqrter: ::prost::alloc::vec::Vec<StringMapEntry>,
+    #[prost(message, repeated, tag = "2")]
+    pub amm_party_ids: ::prost::alloc::vec::Vec<StringMapEntry>,
+    #[prost(message, repeated, tag = "3")]
+    pub pools: ::prost::alloc::vec::Vec<PoolMapEntry>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PoolMapEntry {
+    #[prost(string, tag = "1")]
+    pub party: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub pool: ::core::option::Option<pool_map_entry::Pool>,
+}
+/// Nested message and enum types in `PoolMapEntry`.
+pub mod pool_map_entry {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Curve {
+        #[prost(string, tag = "1")]
+        pub l: ::prost::alloc::string::String,
+        #[prost(string, tag = "2")]
+        pub high: ::prost::alloc::string::String,
+        #[prost(string, tag = "3")]
+        pub low: ::prost::alloc::string::String,
+        #[prost(string, tag = "4")]
+        pub pv: ::prost::alloc::string::String,
+        #[prost(bool, tag = "5")]
+        pub empty: bool,
+    }
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Pool {
+        #[prost(string, tag = "1")]
+        pub id: ::prost::alloc::string::String,
+        #[prost(string, tag = "2")]
+        pub amm_party_id: ::prost::alloc::string::String,
+        #[prost(string, tag = "3")]
+        pub commitment: ::prost::alloc::string::String,
+        #[prost(message, optional, tag = "4")]
+        pub parameters: ::core::option::Option<
+            super::super::super::events::v1::amm::ConcentratedLiquidityParameters,
+        >,
+        #[prost(string, tag = "5")]
+        pub asset: ::prost::alloc::string::String,
+        #[prost(string, tag = "6")]
+        pub market: ::prost::alloc::string::String,
+        #[prost(message, optional, tag = "7")]
+        pub lower: ::core::option::Option<Curve>,
+        #[prost(message, optional, tag = "8")]
+        pub upper: ::core::option::Option<Curve>,
+        #[prost(enumeration = "super::super::super::events::v1::amm::Status", tag = "9")]
+        pub status: i32,
+        #[prost(string, tag = "10")]
+        pub proposed_fee: ::prost::alloc::string::String,
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StringMapEntry {
+    #[prost(string, tag = "1")]
+    pub key: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub value: ::prost::alloc::string::String,
+}
+/// eventually support multiple products
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Product {
+    #[prost(oneof = "product::Type", tags = "1")]
+    pub r#type: ::core::option::Option<product::Type>,
+}
+/// Nested message and enum types in `Product`.
+pub mod product {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Type {
+        #[prost(message, tag = "1")]
+        Perps(super::Perps),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DataPoint {
+    #[prost(string, tag = "1")]
+    pub price: ::prost::alloc::string::String,
+    #[prost(int64, tag = "2")]
+    pub timestamp: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AuctionIntervals {
+    #[prost(int64, repeated, tag = "1")]
+    pub t: ::prost::alloc::vec::Vec<i64>,
+    #[prost(int64, tag = "2")]
+    pub auction_start: i64,
+    #[prost(int64, tag = "3")]
+    pub total: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TwapData {
+    #[prost(int64, tag = "1")]
+    pub start: i64,
+    #[prost(int64, tag = "2")]
+    pub end: i64,
+    #[prost(string, tag = "3")]
+    pub sum_product: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Perps {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "2")]
+    pub external_data_point: ::prost::alloc::vec::Vec<DataPoint>,
+    #[prost(message, repeated, tag = "3")]
+    pub internal_data_point: ::prost::alloc::vec::Vec<DataPoint>,
+    #[prost(uint64, tag = "4")]
+    pub seq: u64,
+    #[prost(int64, tag = "5")]
+    pub started_at: i64,
+    #[prost(message, optional, tag = "6")]
+    pub external_twap_data: ::core::option::Option<TwapData>,
+    #[prost(message, optional, tag = "7")]
+    pub internal_twap_data: ::core::option::Option<TwapData>,
+    #[prost(message, optional, tag = "8")]
+    pub auction_intervals: ::core::option::Option<AuctionIntervals>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OrdersAtPrice {
+    #[prost(string, tag = "1")]
+    pub price: ::prost::alloc::string::String,
+    #[prost(string, repeated, tag = "2")]
+    pub orders: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PricedStopOrders {
+    #[prost(message, repeated, tag = "1")]
+    pub falls_bellow: ::prost::alloc::vec::Vec<OrdersAtPrice>,
+    #[prost(message, repeated, tag = "2")]
+    pub rises_above: ::prost::alloc::vec::Vec<OrdersAtPrice>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TrailingStopOrders {
+    #[prost(string, tag = "1")]
+    pub last_seen_price: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "2")]
+    pub falls_bellow: ::prost::alloc::vec::Vec<OffsetsAtPrice>,
+    #[prost(message, repeated, tag = "3")]
+    pub rises_above: ::prost::alloc::vec::Vec<OffsetsAtPrice>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OrdersAtOffset {
+    #[prost(string, tag = "1")]
+    pub offset: ::prost::alloc::string::String,
+    #[prost(string, repeated, tag = "2")]
+    pub orders: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OffsetsAtPrice {
+    #[prost(string, tag = "1")]
+    pub price: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "2")]
+    pub offsets: ::prost::alloc::vec::Vec<OrdersAtOffset>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StopOrders {
+    #[prost(message, repeated, tag = "1")]
+    pub stop_orders: ::prost::alloc::vec::Vec<super::super::events::v1::StopOrderEvent>,
+    #[prost(message, optional, tag = "2")]
+    pub priced_stop_orders: ::core::option::Option<PricedStopOrders>,
+    #[prost(message, optional, tag = "3")]
+    pub trailing_stop_orders: ::core::option::Option<TrailingStopOrders>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PeggedOrders {
+    #[prost(message, repeated, tag = "2")]
+    pub parked_orders: ::prost::alloc::vec::Vec<super::super::Order>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SlaNetworkParams {
+    #[prost(string, tag = "1")]
+    pub bond_penalty_factor: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub early_exit_penalty: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub max_liquidity_fee: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub non_performance_bond_penalty_max: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub non_performance_bond_penalty_slope: ::prost::alloc::string::String,
+    #[prost(string, tag = "6")]
+    pub stake_to_ccy_volume: ::prost::alloc::string::String,
+    #[prost(int64, tag = "7")]
+    pub providers_fee_calculation_time_step: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExecutionMarkets {
+    #[prost(message, repeated, tag = "1")]
+    pub markets: ::prost::alloc::vec::Vec<Market>,
+    #[prost(message, repeated, tag = "2")]
+    pub spot_markets: ::prost::alloc::vec::Vec<SpotMarket>,
+    #[prost(message, repeated, tag = "3")]
+    pub settled_markets: ::prost::alloc::vec::Vec<
+        super::super::checkpoint::v1::MarketState,
+    >,
+    #[prost(message, repeated, tag = "4")]
+    pub successors: ::prost::alloc::vec::Vec<Successors>,
+    #[prost(string, repeated, tag = "5")]
+    pub market_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(message, optional, tag = "6")]
+    pub sla_network_params: ::core::option::Option<SlaNetworkParams>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Successors {
+    #[prost(string, tag = "1")]
+    pub parent_market: ::prost::alloc::string::String,
+    #[prost(string, repeated, tag = "2")]
+    pub successor_markets: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Position {
+    #[prost(string, tag = "1")]
+    pub party_id: ::prost::alloc::string::String,
+    #[prost(int64, tag = "2")]
+    pub size: i64,
+    #[prost(int64, tag = "3")]
+    pub buy: i64,
+    #[prost(int64, tag = "4")]
+    pub sell: i64,
+    #[prost(string, tag = "5")]
+    pub price: ::prost::alloc::string::String,
+    #[prost(string, tag = "6")]
+    pub buy_sum_product: ::prost::alloc::string::String,
+    #[prost(string, tag = "7")]
+    pub sell_sum_product: ::prost::alloc::string::String,
+    #[prost(bool, tag = "8")]
+    pub distressed: bool,
+    #[prost(bytes = "vec", tag = "9")]
+    pub average_entry_price: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MarketPositions {
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "2")]
+    pub positions: ::prost::alloc::vec::Vec<Position>,
+    #[prost(message, repeated, tag = "3")]
+    pub parties_records: ::prost::alloc::vec::Vec<PartyPositionStats>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PartyPositionStats {
+    #[prost(string, tag = "1")]
+    pub party: ::prost::alloc::string::String,
+    #[prost(uint64, optional, tag = "2")]
+    pub latest_open_interest: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag = "3")]
+    pub lowest_open_interest: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag = "4")]
+    pub traded_volume: ::core::option::Option<u64>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SettlementState {
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub last_mark_price: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "3")]
+    pub last_settled_positions: ::prost::alloc::vec::Vec<LastSettledPosition>,
+    #[prost(message, repeated, tag = "4")]
+    pub trades: ::prost::alloc::vec::Vec<SettlementTrade>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LastSettledPosition {
+    #[prost(string, tag = "1")]
+    pub party: ::prost::alloc::string::String,
+    #[prost(int64, tag = "2")]
+    pub settled_position: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SettlementTrade {
+    #[prost(string, tag = "1")]
+    pub party_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub price: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub market_price: ::prost::alloc::string::String,
+    #[prost(int64, tag = "4")]
+    pub size: i64,
+    #[prost(int64, tag = "5")]
+    pub new_size: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AppState {
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    #[prost(string, tag = "2")]
+    pub block: ::prost::alloc::string::String,
+    #[prost(int64, tag = "3")]
+    pub time: i64,
+    #[prost(string, tag = "4")]
+    pub chain_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub protocol_version: ::prost::alloc::string::String,
+    #[prost(bool, tag = "6")]
+    pub protocol_upgrade: bool,
+    #[prost(int64, tag = "7")]
+    pub prev_block_time: i64,
+}
+/// EpochState details
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EpochState {
+    /// Sequence number that increases by one each epoch
+    #[prost(uint64, tag = "1")]
+    pub seq: u64,
+    /// Time at which this epoch started
+    #[prost(int64, tag = "3")]
+    pub start_time: i64,
+    /// Time at which this epoch should end
+    #[prost(int64, tag = "4")]
+    pub expire_time: i64,
+    /// Ready to start a new epoch
+    #[prost(bool, tag = "6")]
+    pub ready_to_start_new_epoch: bool,
+    /// Ready to end epoch
+    #[prost(bool, tag = "7")]
+    pub ready_to_end_epoch: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RewardsPendingPayouts {
+    #[prost(message, repeated, tag = "1")]
+    pub scheduled_rewards_payout: ::prost::alloc::vec::Vec<ScheduledRewardsPayout>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ScheduledRewardsPayout {
+    #[prost(int64, tag = "1")]
+    pub payout_time: i64,
+    #[prost(message, repeated, tag = "2")]
+    pub rewards_payout: ::prost::alloc::vec::Vec<RewardsPayout>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RewardsPayout {
+    #[prost(string, tag = "1")]
+    pub from_account: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub asset: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "3")]
+    pub reward_party_amount: ::prost::alloc::vec::Vec<RewardsPartyAmount>,
+    #[prost(string, tag = "4")]
+    pub total_reward: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub epoch_seq: ::prost::alloc::string::String,
+    #[prost(int64, tag = "6")]
+    pub timestamp: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RewardsPartyAmount {
+    #[prost(string, tag = "1")]
+    pub party: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub amount: ::prost::alloc::string::String,
+}
+/// LimitState details
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LimitState {
+    /// Number of blocks into the bootstrapping phase
+    #[prost(uint32, tag = "1")]
+    pub block_count: u32,
+    /// Whether or not the limit on market proposals has been lifted
+    #[prost(bool, tag = "2")]
+    pub can_propose_market: bool,
+    /// Whether or not the limit on asset proposals has been lifted
+    #[prost(bool, tag = "3")]
+    pub can_propose_asset: bool,
+    /// Whether or not the genesis state is loaded
+    #[prost(bool, tag = "4")]
+    pub genesis_loaded: bool,
+    /// Fields derived from the genesis state
+    #[prost(bool, tag = "5")]
+    pub propose_market_enabled: bool,
+    #[prost(bool, tag = "6")]
+    pub propose_asset_enabled: bool,
+    #[prost(int64, tag = "7")]
+    pub propose_market_enabled_from: i64,
+    #[prost(int64, tag = "8")]
+    pub propose_asset_enabled_from: i64,
+    #[prost(bool, tag = "9")]
+    pub propose_spot_market_enabled: bool,
+    #[prost(bool, tag = "10")]
+    pub propose_perps_market_enabled: bool,
+    #[prost(bool, tag = "11")]
+    pub can_use_amm_enabled: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VoteSpamPolicy {
+    #[prost(message, repeated, tag = "1")]
+    pub party_to_vote: ::prost::alloc::vec::Vec<PartyProposalVoteCount>,
+    #[prost(message, repeated, tag = "2")]
+    pub banned_parties: ::prost::alloc::vec::Vec<BannedParty>,
+    #[prost(message, repeated, tag = "3")]
+    pub token_balance: ::prost::alloc::vec::Vec<PartyTokenBalance>,
+    #[prost(message, repeated, tag = "4")]
+    pub recent_blocks_reject_stats: ::prost::alloc::vec::Vec<BlockRejectStats>,
+    #[prost(uint64, tag = "5")]
+    pub current_block_index: u64,
+    #[prost(uint64, tag = "6")]
+    pub last_increase_block: u64,
+    #[prost(uint64, tag = "7")]
+    pub current_epoch_seq: u64,
+    #[prost(string, tag = "8")]
+    pub min_voting_tokens_factor: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PartyProposalVoteCount {
+    #[prost(string, tag = "1")]
+    pub party: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub proposal: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "3")]
+    pub count: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PartyTokenBalance {
+    #[prost(string, tag = "1")]
+    pub party: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub balance: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BlockRejectStats {
+    #[prost(uint64, tag = "1")]
+    pub rejected: u64,
+    #[prost(uint64, tag = "2")]
+    pub total: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SpamPartyTransactionCount {
+    #[prost(string, tag = "1")]
+    pub party: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "2")]
+    pub count: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SimpleSpamPolicy {
+    #[prost(string, tag = "1")]
+    pub policy_name: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "2")]
+    pub party_to_count: ::prost::alloc::vec::Vec<SpamPartyTransactionCount>,
+    #[prost(message, repeated, tag = "3")]
+    pub banned_parties: ::prost::alloc::vec::Vec<BannedParty>,
+    #[prost(message, repeated, tag = "4")]
+    pub token_balance: ::prost::alloc::vec::Vec<PartyTokenBalance>,
+    #[prost(uint64, tag = "5")]
+    pub current_epoch_seq: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NotarySigs {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(int32, tag = "2")]
+    pub kind: i32,
+    #[prost(string, tag = "3")]
+    pub node: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub sig: ::prost::alloc::string::String,
+    #[prost(bool, tag = "5")]
+    pub pending: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Notary {
+    #[prost(message, repeated, tag = "1")]
+    pub notary_sigs: ::prost::alloc::vec::Vec<NotarySigs>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StakeVerifierDeposited {
+    #[prost(message, repeated, tag = "1")]
+    pub pending_deposited: ::prost::alloc::vec::Vec<StakeVerifierPending>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StakeVerifierRemoved {
+    #[prost(message, repeated, tag = "1")]
+    pub pending_removed: ::prost::alloc::vec::Vec<StakeVerifierPending>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StakeVerifierPending {
+    #[prost(string, tag = "1")]
+    pub ethereum_address: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub vega_public_key: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub amount: ::prost::alloc::string::String,
+    #[prost(int64, tag = "4")]
+    pub block_time: i64,
+    #[prost(uint64, tag = "5")]
+    pub block_number: u64,
+    #[prost(uint64, tag = "6")]
+    pub log_index: u64,
+    #[prost(string, tag = "7")]
+    pub tx_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "8")]
+    pub id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct L2EthOracles {
+    #[prost(message, repeated, tag = "1")]
+    pub chain_id_eth_oracles: ::prost::alloc::vec::Vec<ChainIdEthOracles>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ChainIdEthOracles {
+    #[prost(string, tag = "1")]
+    pub source_chain_id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub last_block: ::core::option::Option<EthOracleVerifierLastBlock>,
+    #[prost(message, optional, tag = "3")]
+    pub call_results: ::core::option::Option<EthContractCallResults>,
+    #[prost(message, optional, tag = "4")]
+    pub misc: ::core::option::Option<EthOracleVerifierMisc>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EthOracleVerifierLastBlock {
+    #[prost(uint64, tag = "1")]
+    pub block_height: u64,
+    #[prost(uint64, tag = "2")]
+    pub block_time: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EthOracleVerifierMisc {
+    #[prost(message, repeated, tag = "1")]
+    pub buckets: ::prost::alloc::vec::Vec<EthVerifierBucket>,
+    #[prost(message, optional, tag = "2")]
+    pub patch_block: ::core::option::Option<EthOracleVerifierLastBlock>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EthContractCallResults {
+    #[prost(message, repeated, tag = "1")]
+    pub pending_contract_call_result: ::prost::alloc::vec::Vec<EthContractCallResult>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EthContractCallResult {
+    #[prost(uint64, tag = "1")]
+    pub block_height: u64,
+    #[prost(uint64, tag = "2")]
+    pub block_time: u64,
+    #[prost(string, tag = "3")]
+    pub spec_id: ::prost::alloc::string::String,
+    #[prost(bytes = "vec", tag = "4")]
+    pub result: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, optional, tag = "5")]
+    pub error: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint64, optional, tag = "6")]
+    pub chain_id: ::core::option::Option<u64>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EthVerifierBucket {
+    #[prost(int64, tag = "1")]
+    pub ts: i64,
+    #[prost(string, repeated, tag = "2")]
+    pub hashes: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PendingKeyRotation {
+    #[prost(uint64, tag = "1")]
+    pub block_height: u64,
+    #[prost(string, tag = "2")]
+    pub node_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub new_pub_key: ::prost::alloc::string::String,
+    #[prost(uint32, tag = "4")]
+    pub new_pub_key_index: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PendingEthereumKeyRotation {
+    #[prost(uint64, tag = "1")]
+    pub block_height: u64,
+    #[prost(string, tag = "2")]
+    pub node_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub new_address: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub submitter: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub old_address: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Topology {
+    #[prost(message, repeated, tag = "1")]
+    pub validator_data: ::prost::alloc::vec::Vec<ValidatorState>,
+    #[prost(string, repeated, tag = "2")]
+    pub chain_keys: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(message, repeated, tag = "3")]
+    pub pending_pub_key_rotations: ::prost::alloc::vec::Vec<PendingKeyRotation>,
+    #[prost(message, optional, tag = "4")]
+    pub validator_performance: ::core::option::Option<ValidatorPerformance>,
+    #[prost(message, repeated, tag = "5")]
+    pub pending_ethereum_key_rotations: ::prost::alloc::vec::Vec<
+        PendingEthereumKeyRotation,
+    >,
+    #[prost(message, optional, tag = "6")]
+    pub signatures: ::core::option::Option<ToplogySignatures>,
+    #[prost(message, repeated, tag = "7")]
+    pub unsolved_ethereum_key_rotations: ::prost::alloc::vec::Vec<
+        PendingEthereumKeyRotation,
+    >,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ToplogySignatures {
+    #[prost(message, repeated, tag = "1")]
+    pub pending_signatures: ::prost::alloc::vec::Vec<
+        PendingErc20MultisigControlSignature,
+    >,
+    #[prost(message, repeated, tag = "2")]
+    pub issued_signatures: ::prost::alloc::vec::Vec<IssuedErc20MultisigControlSignature>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PendingErc20MultisigControlSignature {
+    #[prost(string, tag = "1")]
+    pub node_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub ethereum_address: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub nonce: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "4")]
+    pub epoch_seq: u64,
+    #[prost(bool, tag = "5")]
+    pub added: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IssuedErc20MultisigControlSignature {
+    #[prost(string, tag = "1")]
+    pub resource_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub ethereum_address: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub submitter_address: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub chain_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidatorState {
+    #[prost(message, optional, tag = "1")]
+    pub validator_update: ::core::option::Option<
+        super::super::events::v1::ValidatorUpdate,
+    >,
+    #[prost(uint64, tag = "2")]
+    pub block_added: u64,
+    #[prost(int32, tag = "3")]
+    pub status: i32,
+    #[prost(uint64, tag = "4")]
+    pub status_change_block: u64,
+    #[prost(uint64, tag = "5")]
+    pub last_block_with_positive_ranking: u64,
+    #[prost(uint64, tag = "6")]
+    pub eth_events_forwarded: u64,
+    #[prost(message, optional, tag = "7")]
+    pub heartbeat_tracker: ::core::option::Option<HeartbeatTracker>,
+    #[prost(int64, tag = "8")]
+    pub validator_power: i64,
+    #[prost(message, optional, tag = "9")]
+    pub ranking_score: ::core::option::Option<super::super::RankingScore>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct HeartbeatTracker {
+    #[prost(string, tag = "1")]
+    pub expected_next_hash: ::prost::alloc::string::String,
+    #[prost(int64, tag = "2")]
+    pub expected_next_hash_since: i64,
+    #[prost(int32, tag = "3")]
+    pub block_index: i32,
+    #[prost(bool, repeated, tag = "4")]
+    pub block_sigs: ::prost::alloc::vec::Vec<bool>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PerformanceStats {
+    #[prost(string, tag = "1")]
+    pub validator_address: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "2")]
+    pub proposed: u64,
+    #[prost(uint64, tag = "3")]
+    pub elected: u64,
+    #[prost(uint64, tag = "4")]
+    pub voted: u64,
+    #[prost(int64, tag = "5")]
+    pub last_height_voted: i64,
+    #[prost(int64, tag = "6")]
+    pub last_height_proposed: i64,
+    #[prost(int64, tag = "7")]
+    pub last_height_elected: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidatorPerformance {
+    #[prost(message, repeated, tag = "1")]
+    pub validator_perf_stats: ::prost::alloc::vec::Vec<PerformanceStats>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidityParameters {
+    #[prost(string, tag = "1")]
+    pub max_fee: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub max_shape_size: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub stake_to_obligation_factor: ::prost::alloc::string::String,
+    /// Market ID these liquidity parameters apply to. Required for key generation
+    #[prost(string, tag = "4")]
+    pub market_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidityPendingProvisions {
+    #[prost(string, repeated, tag = "1")]
+    pub pending_provisions: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Market ID these liquidity provisions apply to. Required for key generation
+    #[prost(string, tag = "2")]
+    pub market_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidityPartiesLiquidityOrders {
+    #[prost(message, repeated, tag = "1")]
+    pub party_orders: ::prost::alloc::vec::Vec<PartyOrders>,
+    /// Market ID these liquidity orders apply to. Required for key generation
+    #[prost(string, tag = "2")]
+    pub market_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PartyOrders {
+    #[prost(string, tag = "1")]
+    pub party: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "2")]
+    pub orders: ::prost::alloc::vec::Vec<super::super::Order>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidityPartiesOrders {
+    #[prost(message, repeated, tag = "1")]
+    pub party_orders: ::prost::alloc::vec::Vec<PartyOrders>,
+    /// Market ID these orders apply to.
+    #[prost(string, tag = "2")]
+    pub market_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidityProvisions {
+    #[prost(message, repeated, tag = "1")]
+    pub liquidity_provisions: ::prost::alloc::vec::Vec<super::super::LiquidityProvision>,
+    /// Market ID these provisions apply to.
+    #[prost(string, tag = "2")]
+    pub market_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidityScores {
+    #[prost(int32, tag = "1")]
+    pub running_average_counter: i32,
+    #[prost(message, repeated, tag = "2")]
+    pub scores: ::prost::alloc::vec::Vec<LiquidityScore>,
+    #[prost(string, tag = "3")]
+    pub market_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidityScore {
+    #[prost(string, tag = "1")]
+    pub score: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub party_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidityV2Parameters {
+    /// Market ID these liquidity parameters apply to. Required for key generation
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+    /// market specific SLA parameters, will be different than the ones in the market
+    /// snapshot since they apply at the end of an epoch
+    #[prost(message, optional, tag = "2")]
+    pub market_sla_parameters: ::core::option::Option<
+        super::super::LiquiditySlaParameters,
+    >,
+    /// network wide SLA parameters, will be different than network paramters in the
+    /// snapshot since they apply at the end of an epoch
+    #[prost(string, tag = "3")]
+    pub stake_to_volume: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub bond_penalty_slope: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub bond_penalty_max: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidityV2PaidFeesStats {
+    /// Market ID these liquidity parameters apply to. Required for key generation
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub stats: ::core::option::Option<super::super::events::v1::PaidLiquidityFeesStats>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidityV2Provisions {
+    /// Market ID these liquidity parameters apply to. Required for key generation
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "2")]
+    pub liquidity_provisions: ::prost::alloc::vec::Vec<super::super::LiquidityProvision>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidityV2PendingProvisions {
+    /// Market ID these liquidity parameters apply to. Required for key generation
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "2")]
+    pub pending_liquidity_provisions: ::prost::alloc::vec::Vec<
+        super::super::LiquidityProvision,
+    >,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidityV2Performances {
+    /// Market ID these liquidity parameters apply to. Required for key generation
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+    #[prost(int64, tag = "2")]
+    pub epoch_start_time: i64,
+    #[prost(message, repeated, tag = "3")]
+    pub performance_per_party: ::prost::alloc::vec::Vec<LiquidityV2PerformancePerParty>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidityV2PerformancePerParty {
+    #[prost(string, tag = "1")]
+    pub party: ::prost::alloc::string::String,
+    #[prost(int64, tag = "2")]
+    pub elapsed_time_meeting_sla_during_epoch: i64,
+    #[prost(int64, tag = "3")]
+    pub commitment_start_time: i64,
+    #[prost(string, repeated, tag = "4")]
+    pub registered_penalties_per_epoch: ::prost::alloc::vec::Vec<
+        ::prost::alloc::string::String,
+    >,
+    #[prost(uint32, tag = "5")]
+    pub position_in_penalties_per_epoch: u32,
+    #[prost(string, tag = "6")]
+    pub last_epoch_fraction_of_time_on_book: ::prost::alloc::string::String,
+    #[prost(string, tag = "7")]
+    pub last_epoch_fee_penalty: ::prost::alloc::string::String,
+    #[prost(string, tag = "8")]
+    pub last_epoch_bond_penalty: ::prost::alloc::string::String,
+    #[prost(string, tag = "9")]
+    pub required_liquidity: ::prost::alloc::string::String,
+    #[prost(string, tag = "10")]
+    pub notional_volume_buys: ::prost::alloc::string::String,
+    #[prost(string, tag = "11")]
+    pub notional_volume_sells: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidityV2Scores {
+    /// Market ID these liquidity parameters apply to. Required for key generation
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+    #[prost(int32, tag = "2")]
+    pub running_average_counter: i32,
+    #[prost(message, repeated, tag = "3")]
+    pub scores: ::prost::alloc::vec::Vec<LiquidityScore>,
+    #[prost(int64, tag = "4")]
+    pub last_fee_distribution_time: i64,
+    #[prost(int64, tag = "5")]
+    pub fee_calculation_time_step: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidityV2Supplied {
+    /// Market ID these liquidity parameters apply to. Required for key generation
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+    #[prost(bool, tag = "2")]
+    pub consensus_reached: bool,
+    #[prost(message, repeated, tag = "3")]
+    pub bid_cache: ::prost::alloc::vec::Vec<LiquidityOffsetProbabilityPair>,
+    #[prost(message, repeated, tag = "4")]
+    pub ask_cache: ::prost::alloc::vec::Vec<LiquidityOffsetProbabilityPair>,
+}
+/// Snapshot payload for floating point consensus
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FloatingPointConsensus {
+    #[prost(message, repeated, tag = "1")]
+    pub next_time_trigger: ::prost::alloc::vec::Vec<NextTimeTrigger>,
+    #[prost(message, repeated, tag = "2")]
+    pub state_variables: ::prost::alloc::vec::Vec<StateVarInternalState>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StateVarInternalState {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(int32, tag = "2")]
+    pub state: i32,
+    #[prost(string, tag = "3")]
+    pub event_id: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "4")]
+    pub validators_results: ::prost::alloc::vec::Vec<FloatingPointValidatorResult>,
+    #[prost(int32, tag = "5")]
+    pub rounds_since_meaningful_update: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FloatingPointValidatorResult {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "2")]
+    pub bundle: ::prost::alloc::vec::Vec<super::super::KeyValueBundle>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NextTimeTrigger {
+    #[prost(string, tag = "1")]
+    pub asset: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub market: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(int64, tag = "4")]
+    pub next_trigger: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MarketTracker {
+    #[prost(message, repeated, tag = "1")]
+    pub market_activity: ::prost::alloc::vec::Vec<
+        super::super::checkpoint::v1::MarketActivityTracker,
+    >,
+    #[prost(message, repeated, tag = "2")]
+    pub taker_notional_volume: ::prost::alloc::vec::Vec<
+        super::super::checkpoint::v1::TakerNotionalVolume,
+    >,
+    #[prost(message, repeated, tag = "3")]
+    pub market_to_party_taker_notional_volume: ::prost::alloc::vec::Vec<
+        super::super::checkpoint::v1::MarketToPartyTakerNotionalVolume,
+    >,
+    #[prost(message, repeated, tag = "4")]
+    pub epoch_taker_fees: ::prost::alloc::vec::Vec<
+        super::super::checkpoint::v1::EpochPartyTakerFees,
+    >,
+    #[prost(message, repeated, tag = "5")]
+    pub game_eligibility_tracker: ::prost::alloc::vec::Vec<
+        super::super::checkpoint::v1::GameEligibilityTracker,
+    >,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SignerEventsPerAddress {
+    #[prost(string, tag = "1")]
+    pub address: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "2")]
+    pub events: ::prost::alloc::vec::Vec<
+        super::super::events::v1::Erc20MultiSigSignerEvent,
+    >,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Erc20MultiSigTopologyVerified {
+    #[prost(string, repeated, tag = "1")]
+    pub signers: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(message, repeated, tag = "2")]
+    pub events_per_address: ::prost::alloc::vec::Vec<SignerEventsPerAddress>,
+    #[prost(message, optional, tag = "3")]
+    pub threshold: ::core::option::Option<
+        super::super::events::v1::Erc20MultiSigThresholdSetEvent,
+    >,
+    #[prost(string, repeated, tag = "4")]
+    pub seen_events: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Erc20MultiSigTopologyPending {
+    #[prost(message, repeated, tag = "1")]
+    pub pending_signers: ::prost::alloc::vec::Vec<
+        super::super::events::v1::Erc20MultiSigSignerEvent,
+    >,
+    #[prost(message, repeated, tag = "2")]
+    pub pending_threshold_set: ::prost::alloc::vec::Vec<
+        super::super::events::v1::Erc20MultiSigThresholdSetEvent,
+    >,
+    #[prost(string, repeated, tag = "3")]
+    pub witnessed_signers: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(string, repeated, tag = "4")]
+    pub witnessed_threshold_sets: ::prost::alloc::vec::Vec<
+        ::prost::alloc::string::String,
+    >,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EvmMultisigTopology {
+    #[prost(string, tag = "1")]
+    pub chain_id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub verified: ::core::option::Option<Erc20MultiSigTopologyVerified>,
+    #[prost(message, optional, tag = "3")]
+    pub pending: ::core::option::Option<Erc20MultiSigTopologyPending>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EvmMultisigTopologies {
+    #[prost(message, repeated, tag = "1")]
+    pub evm_multisig_topology: ::prost::alloc::vec::Vec<EvmMultisigTopology>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProofOfWork {
+    #[prost(uint64, repeated, tag = "1")]
+    pub block_height: ::prost::alloc::vec::Vec<u64>,
+    #[prost(string, repeated, tag = "2")]
+    pub block_hash: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(message, repeated, tag = "4")]
+    pub tx_at_height: ::prost::alloc::vec::Vec<TransactionsAtHeight>,
+    #[prost(message, repeated, tag = "6")]
+    pub tid_at_height: ::prost::alloc::vec::Vec<TransactionsAtHeight>,
+    #[prost(message, repeated, tag = "7")]
+    pub banned: ::prost::alloc::vec::Vec<BannedParty>,
+    #[prost(message, repeated, tag = "8")]
+    pub pow_params: ::prost::alloc::vec::Vec<ProofOfWorkParams>,
+    #[prost(message, repeated, tag = "9")]
+    pub pow_state: ::prost::alloc::vec::Vec<ProofOfWorkState>,
+    #[prost(uint64, tag = "10")]
+    pub last_pruning_block: u64,
+    #[prost(message, repeated, tag = "11")]
+    pub nonce_refs_at_height: ::prost::alloc::vec::Vec<NonceRefsAtHeight>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BannedParty {
+    #[prost(string, tag = "1")]
+    pub party: ::prost::alloc::string::String,
+    #[prost(int64, tag = "2")]
+    pub until: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProofOfWorkParams {
+    #[prost(uint64, tag = "1")]
+    pub spam_pow_number_of_past_blocks: u64,
+    #[prost(uint32, tag = "2")]
+    pub spam_pow_difficulty: u32,
+    #[prost(string, tag = "3")]
+    pub spam_pow_hash_function: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "4")]
+    pub spam_pow_number_of_tx_per_block: u64,
+    #[prost(bool, tag = "5")]
+    pub spam_pow_increasing_difficulty: bool,
+    #[prost(uint64, tag = "6")]
+    pub from_block: u64,
+    #[prost(int64, tag = "7")]
+    pub until_block: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProofOfWorkState {
+    #[prost(message, repeated, tag = "1")]
+    pub pow_state: ::prost::alloc::vec::Vec<ProofOfWorkBlockState>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProofOfWorkBlockState {
+    #[prost(uint64, tag = "1")]
+    pub block_height: u64,
+    #[prost(message, repeated, tag = "2")]
+    pub party_state: ::prost::alloc::vec::Vec<ProofOfWorkPartyStateForBlock>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProofOfWorkPartyStateForBlock {
+    #[prost(string, tag = "1")]
+    pub party: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "2")]
+    pub seen_count: u64,
+    #[prost(uint64, tag = "3")]
+    pub observed_difficulty: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransactionsAtHeight {
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    #[prost(string, repeated, tag = "2")]
+    pub transactions: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NonceRef {
+    #[prost(string, tag = "1")]
+    pub party: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "2")]
+    pub nonce: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NonceRefsAtHeight {
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    #[prost(message, repeated, tag = "2")]
+    pub refs: ::prost::alloc::vec::Vec<NonceRef>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtocolUpgradeProposals {
+    #[prost(message, repeated, tag = "1")]
+    pub active_proposals: ::prost::alloc::vec::Vec<
+        super::super::events::v1::ProtocolUpgradeEvent,
+    >,
+    #[prost(message, optional, tag = "2")]
+    pub accepted_proposal: ::core::option::Option<AcceptedProtocolUpgradeProposal>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AcceptedProtocolUpgradeProposal {
+    /// Block height at which to perform the upgrade
+    #[prost(uint64, tag = "1")]
+    pub upgrade_block_height: u64,
+    /// Release tag for the vega binary
+    #[prost(string, tag = "2")]
+    pub vega_release_tag: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Teams {
+    #[prost(message, repeated, tag = "1")]
+    pub teams: ::prost::alloc::vec::Vec<Team>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Team {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub referrer: ::core::option::Option<Membership>,
+    #[prost(message, repeated, tag = "3")]
+    pub referees: ::prost::alloc::vec::Vec<Membership>,
+    #[prost(string, tag = "4")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub team_url: ::prost::alloc::string::String,
+    #[prost(string, tag = "6")]
+    pub avatar_url: ::prost::alloc::string::String,
+    #[prost(int64, tag = "7")]
+    pub created_at: i64,
+    #[prost(bool, tag = "8")]
+    pub closed: bool,
+    #[prost(string, repeated, tag = "9")]
+    pub allow_list: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Membership {
+    #[prost(string, tag = "1")]
+    pub party_id: ::prost::alloc::string::String,
+    #[prost(int64, tag = "2")]
+    pub joined_at: i64,
+    #[prost(uint64, tag = "3")]
+    pub started_at_epoch: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TeamSwitches {
+    #[prost(message, repeated, tag = "1")]
+    pub team_switches: ::prost::alloc::vec::Vec<TeamSwitch>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TeamSwitch {
+    #[prost(string, tag = "1")]
+    pub from_team_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub to_team_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub party_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Vesting {
+    #[prost(message, repeated, tag = "1")]
+    pub parties_reward: ::prost::alloc::vec::Vec<PartyReward>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PartyReward {
+    #[prost(string, tag = "1")]
+    pub party: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "2")]
+    pub asset_locked: ::prost::alloc::vec::Vec<AssetLocked>,
+    #[prost(message, repeated, tag = "3")]
+    pub in_vesting: ::prost::alloc::vec::Vec<InVesting>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReferralProgramData {
+    #[prost(message, repeated, tag = "1")]
+    pub factor_by_referee: ::prost::alloc::vec::Vec<FactorByReferee>,
+    #[prost(message, optional, tag = "2")]
+    pub current_program: ::core::option::Option<super::super::ReferralProgram>,
+    #[prost(message, optional, tag = "3")]
+    pub new_program: ::core::option::Option<super::super::ReferralProgram>,
+    #[prost(uint64, tag = "4")]
+    pub last_program_version: u64,
+    #[prost(bool, tag = "5")]
+    pub program_has_ended: bool,
+    #[prost(message, repeated, tag = "6")]
+    pub sets: ::prost::alloc::vec::Vec<ReferralSet>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReferralSet {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(int64, tag = "2")]
+    pub created_at: i64,
+    #[prost(int64, tag = "3")]
+    pub updated_at: i64,
+    #[prost(message, optional, tag = "4")]
+    pub referrer: ::core::option::Option<Membership>,
+    #[prost(message, repeated, tag = "5")]
+    pub referees: ::prost::alloc::vec::Vec<Membership>,
+    #[prost(message, repeated, tag = "6")]
+    pub running_volumes: ::prost::alloc::vec::Vec<RunningVolume>,
+    /// deprecated
+    #[prost(string, tag = "7")]
+    pub current_reward_factor: ::prost::alloc::string::String,
+    #[prost(string, tag = "8")]
+    pub current_rewards_multiplier: ::prost::alloc::string::String,
+    /// deprecated
+    #[prost(string, tag = "9")]
+    pub current_rewards_factor_multiplier: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "10")]
+    pub current_rewards_factors_multiplier: ::core::option::Option<
+        super::super::RewardFactors,
+    >,
+    #[prost(message, optional, tag = "11")]
+    pub current_reward_factors: ::core::option::Option<super::super::RewardFactors>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RunningVolume {
+    #[prost(uint64, tag = "1")]
+    pub epoch: u64,
+    #[prost(bytes = "vec", tag = "2")]
+    pub volume: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FactorByReferee {
+    #[prost(string, tag = "1")]
+    pub party: ::prost::alloc::string::String,
+    /// deprecated
+    #[prost(bytes = "vec", tag = "2")]
+    pub discount_factor: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "3")]
+    pub taker_volume: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag = "4")]
+    pub discount_factors: ::core::option::Option<super::super::DiscountFactors>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AssetLocked {
+    #[prost(string, tag = "1")]
+    pub asset: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "2")]
+    pub epoch_balances: ::prost::alloc::vec::Vec<EpochBalance>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EpochBalance {
+    #[prost(uint64, tag = "1")]
+    pub epoch: u64,
+    #[prost(string, tag = "2")]
+    pub balance: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InVesting {
+    #[prost(string, tag = "1")]
+    pub asset: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub balance: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ActivityStreak {
+    #[prost(message, repeated, tag = "1")]
+    pub parties_activity_streak: ::prost::alloc::vec::Vec<PartyActivityStreak>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PartyActivityStreak {
+    #[prost(string, tag = "1")]
+    pub party: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "2")]
+    pub active: u64,
+    #[prost(uint64, tag = "3")]
+    pub inactive: u64,
+    #[prost(bytes = "vec", tag = "4")]
+    pub reward_distribution_multiplier: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "5")]
+    pub reward_vesting_multiplier: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PartyRebateData {
+    #[prost(string, tag = "1")]
+    pub party: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub fraction: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub maker_fee_received: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VolumeRebateProgram {
+    #[prost(string, repeated, tag = "1")]
+    pub parties: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(message, repeated, tag = "2")]
+    pub party_rebate_data: ::prost::alloc::vec::Vec<PartyRebateData>,
+    #[prost(message, optional, tag = "3")]
+    pub current_program: ::core::option::Option<super::super::VolumeRebateProgram>,
+    #[prost(message, optional, tag = "4")]
+    pub new_program: ::core::option::Option<super::super::VolumeRebateProgram>,
+    #[prost(message, repeated, tag = "5")]
+    pub factors_by_party: ::prost::alloc::vec::Vec<VolumeRebateStats>,
+    #[prost(uint64, tag = "6")]
+    pub last_program_version: u64,
+    #[prost(bool, tag = "7")]
+    pub program_has_ended: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VolumeRebateStats {
+    #[prost(string, tag = "1")]
+    pub party: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub rebate_factor: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VolumeDiscountProgram {
+    #[prost(string, repeated, tag = "1")]
+    pub parties: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(message, repeated, tag = "2")]
+    pub epoch_party_volumes: ::prost::alloc::vec::Vec<EpochPartyVolumes>,
+    #[prost(uint64, tag = "3")]
+    pub epoch_data_index: u64,
+    #[prost(message, repeated, tag = "4")]
+    pub average_party_volume: ::prost::alloc::vec::Vec<PartyVolume>,
+    #[prost(message, optional, tag = "5")]
+    pub current_program: ::core::option::Option<super::super::VolumeDiscountProgram>,
+    #[prost(message, optional, tag = "6")]
+    pub new_program: ::core::option::Option<super::super::VolumeDiscountProgram>,
+    #[prost(message, repeated, tag = "7")]
+    pub factors_by_party: ::prost::alloc::vec::Vec<VolumeDiscountStats>,
+    #[prost(uint64, tag = "8")]
+    pub last_program_version: u64,
+    #[prost(bool, tag = "9")]
+    pub program_has_ended: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VolumeDiscountStats {
+    #[prost(string, tag = "1")]
+    pub party: ::prost::alloc::string::String,
+    /// deprecated
+    #[prost(string, tag = "2")]
+    pub discount_factor: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub discount_factors: ::core::option::Option<super::super::DiscountFactors>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EpochPartyVolumes {
+    #[prost(message, repeated, tag = "1")]
+    pub party_volume: ::prost::alloc::vec::Vec<PartyVolume>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PartyVolume {
+    #[prost(string, tag = "1")]
+    pub party: ::prost::alloc::string::String,
+    #[prost(bytes = "vec", tag = "2")]
+    pub volume: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Liquidation {
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+    #[prost(int64, tag = "2")]
+    pub network_pos: i64,
+    #[prost(int64, tag = "3")]
+    pub next_step: i64,
+    #[prost(message, optional, tag = "4")]
+    pub config: ::core::option::Option<super::super::LiquidationStrategy>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PartyAssetAmount {
+    #[prost(string, tag = "1")]
+    pub party: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub asset: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub amount: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BankingTransferFeeDiscounts {
+    #[prost(message, repeated, tag = "1")]
+    pub party_asset_discount: ::prost::alloc::vec::Vec<PartyAssetAmount>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CompositePriceCalculator {
+    #[prost(string, tag = "1")]
+    pub composite_price: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub price_configuration: ::core::option::Option<
+        super::super::CompositePriceConfiguration,
+    >,
+    #[prost(message, repeated, tag = "3")]
+    pub trades: ::prost::alloc::vec::Vec<super::super::Trade>,
+    #[prost(string, repeated, tag = "4")]
+    pub price_sources: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(int64, repeated, tag = "5")]
+    pub price_source_last_update: ::prost::alloc::vec::Vec<i64>,
+    #[prost(message, repeated, tag = "6")]
+    pub book_price_at_time: ::prost::alloc::vec::Vec<TimePrice>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Parties {
+    #[prost(message, repeated, tag = "1")]
+    pub profiles: ::prost::alloc::vec::Vec<PartyProfile>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PartyProfile {
+    #[prost(string, tag = "1")]
+    pub party_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub alias: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "3")]
+    pub metadata: ::prost::alloc::vec::Vec<super::super::Metadata>,
+    #[prost(string, repeated, tag = "4")]
+    pub derived_keys: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AmmValues {
+    #[prost(string, tag = "1")]
+    pub party: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub stake: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub score: ::prost::alloc::string::String,
+    #[prost(int64, tag = "4")]
+    pub tick: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MarketLiquidity {
+    #[prost(string, tag = "1")]
+    pub price_range: ::prost::alloc::string::String,
+    #[prost(int64, tag = "2")]
+    pub tick: i64,
+    #[prost(message, repeated, tag = "3")]
+    pub amm: ::prost::alloc::vec::Vec<AmmValues>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DelayedTx {
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    pub tx: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(uint64, tag = "2")]
+    pub height: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TxCache {
+    #[prost(message, repeated, tag = "1")]
+    pub txs: ::prost::alloc::vec::Vec<DelayedTx>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EvmFwdPendingHeartbeat {
+    #[prost(uint64, tag = "1")]
+    pub block_height: u64,
+    #[prost(uint64, tag = "2")]
+    pub block_time: u64,
+    #[prost(string, tag = "3")]
+    pub contract_address: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub chain_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EvmFwdLastSeen {
+    #[prost(string, tag = "1")]
+    pub chain_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub contract_address: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "3")]
+    pub block_height: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EvmFwdHeartbeats {
+    #[prost(message, repeated, tag = "1")]
+    pub pending_heartbeats: ::prost::alloc::vec::Vec<EvmFwdPendingHeartbeat>,
+    #[prost(message, repeated, tag = "2")]
+    pub last_seen: ::prost::alloc::vec::Vec<EvmFwdLastSeen>,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum Format {
+    /// Default value, always invalid
+    Unspecified = 0,
+    /// Standard proto encoding
+    Proto = 1,
+    /// Compressed proto, might come in handy
+    ProtoCompressed = 2,
+    /// Simple JSON serialisation, probably not going to be used
+    Json = 3,
+}
+impl Format {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Format::Unspecified => "FORMAT_UNSPECIFIED",
+            Format::Proto => "FORMAT_PROTO",
+            Format::ProtoCompressed => "FORMAT_PROTO_COMPRESSED",
+            Format::Json => "FORMAT_JSON",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "FORMAT_UNSPECIFIED" => Some(Self::Unspecified),
+            "FORMAT_PROTO" => Some(Self::Proto),
+            "FORMAT_PROTO_COMPRESSED" => Some(Self::ProtoCompressed),
+            "FORMAT_JSON" => Some(Self::Json),
+            _ => None,
+        }
+    }
+}
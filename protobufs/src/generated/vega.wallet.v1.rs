@@ -0,0 +1,114 @@
+// This file is @generated by prost-build.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SubmitTransactionRequest {
+    #[prost(string, tag = "1")]
+    pub pub_key: ::prost::alloc::string::String,
+    #[prost(bool, tag = "2")]
+    pub propagate: bool,
+    #[prost(
+        oneof = "submit_transaction_request::Command",
+        tags = "1001, 1002, 1003, 1004, 1005, 1006, 1007, 1008, 1009, 1010, 1011, 1012, 1013, 1014, 1015, 1016, 1017, 1018, 1019, 1020, 1021, 1022, 1023, 1024, 1025, 1026, 1027, 2002, 2003, 2004, 2005, 2006, 2007, 2008, 2009, 2010, 3001"
+    )]
+    pub command: ::core::option::Option<submit_transaction_request::Command>,
+}
+/// Nested message and enum types in `SubmitTransactionRequest`.
+pub mod submit_transaction_request {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Command {
+        /// User commands
+        #[prost(message, tag = "1001")]
+        OrderSubmission(super::super::super::commands::v1::OrderSubmission),
+        #[prost(message, tag = "1002")]
+        OrderCancellation(super::super::super::commands::v1::OrderCancellation),
+        #[prost(message, tag = "1003")]
+        OrderAmendment(super::super::super::commands::v1::OrderAmendment),
+        #[prost(message, tag = "1004")]
+        WithdrawSubmission(super::super::super::commands::v1::WithdrawSubmission),
+        #[prost(message, tag = "1005")]
+        ProposalSubmission(super::super::super::commands::v1::ProposalSubmission),
+        #[prost(message, tag = "1006")]
+        VoteSubmission(super::super::super::commands::v1::VoteSubmission),
+        #[prost(message, tag = "1007")]
+        LiquidityProvisionSubmission(
+            super::super::super::commands::v1::LiquidityProvisionSubmission,
+        ),
+        #[prost(message, tag = "1008")]
+        DelegateSubmission(super::super::super::commands::v1::DelegateSubmission),
+        #[prost(message, tag = "1009")]
+        UndelegateSubmission(super::super::super::commands::v1::UndelegateSubmission),
+        #[prost(message, tag = "1010")]
+        LiquidityProvisionCancellation(
+            super::super::super::commands::v1::LiquidityProvisionCancellation,
+        ),
+        #[prost(message, tag = "1011")]
+        LiquidityProvisionAmendment(
+            super::super::super::commands::v1::LiquidityProvisionAmendment,
+        ),
+        #[prost(message, tag = "1012")]
+        Transfer(super::super::super::commands::v1::Transfer),
+        #[prost(message, tag = "1013")]
+        CancelTransfer(super::super::super::commands::v1::CancelTransfer),
+        #[prost(message, tag = "1014")]
+        AnnounceNode(super::super::super::commands::v1::AnnounceNode),
+        #[prost(message, tag = "1015")]
+        BatchMarketInstructions(
+            super::super::super::commands::v1::BatchMarketInstructions,
+        ),
+        #[prost(message, tag = "1016")]
+        StopOrdersSubmission(super::super::super::commands::v1::StopOrdersSubmission),
+        #[prost(message, tag = "1017")]
+        StopOrdersCancellation(
+            super::super::super::commands::v1::StopOrdersCancellation,
+        ),
+        #[prost(message, tag = "1018")]
+        CreateReferralSet(super::super::super::commands::v1::CreateReferralSet),
+        #[prost(message, tag = "1019")]
+        UpdateReferralSet(super::super::super::commands::v1::UpdateReferralSet),
+        #[prost(message, tag = "1020")]
+        ApplyReferralCode(super::super::super::commands::v1::ApplyReferralCode),
+        #[prost(message, tag = "1021")]
+        UpdateMarginMode(super::super::super::commands::v1::UpdateMarginMode),
+        #[prost(message, tag = "1022")]
+        JoinTeam(super::super::super::commands::v1::JoinTeam),
+        #[prost(message, tag = "1023")]
+        BatchProposalSubmission(
+            super::super::super::commands::v1::BatchProposalSubmission,
+        ),
+        #[prost(message, tag = "1024")]
+        UpdatePartyProfile(super::super::super::commands::v1::UpdatePartyProfile),
+        #[prost(message, tag = "1025")]
+        SubmitAmm(super::super::super::commands::v1::SubmitAmm),
+        #[prost(message, tag = "1026")]
+        AmendAmm(super::super::super::commands::v1::AmendAmm),
+        #[prost(message, tag = "1027")]
+        CancelAmm(super::super::super::commands::v1::CancelAmm),
+        /// Validator commands
+        #[prost(message, tag = "2002")]
+        NodeVote(super::super::super::commands::v1::NodeVote),
+        #[prost(message, tag = "2003")]
+        NodeSignature(super::super::super::commands::v1::NodeSignature),
+        #[prost(message, tag = "2004")]
+        ChainEvent(super::super::super::commands::v1::ChainEvent),
+        #[prost(message, tag = "2005")]
+        KeyRotateSubmission(super::super::super::commands::v1::KeyRotateSubmission),
+        #[prost(message, tag = "2006")]
+        StateVariableProposal(super::super::super::commands::v1::StateVariableProposal),
+        #[prost(message, tag = "2007")]
+        ValidatorHeartbeat(super::super::super::commands::v1::ValidatorHeartbeat),
+        #[prost(message, tag = "2008")]
+        EthereumKeyRotateSubmission(
+            super::super::super::commands::v1::EthereumKeyRotateSubmission,
+        ),
+        #[prost(message, tag = "2009")]
+        ProtocolUpgradeProposal(
+            super::super::super::commands::v1::ProtocolUpgradeProposal,
+        ),
+        #[prost(message, tag = "2010")]
+        IssueSignatures(super::super::super::commands::v1::IssueSignatures),
+        /// Oracle commands
+        #[prost(message, tag = "3001")]
+        OracleDataSubmission(super::super::super::commands::v1::OracleDataSubmission),
+    }
+}
@@ -0,0 +1,635 @@
+impl serde::Serialize for SubmitTransactionRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.pub_key.is_empty() {
+            len += 1;
+        }
+        if self.propagate {
+            len += 1;
+        }
+        if self.command.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("vega.wallet.v1.SubmitTransactionRequest", len)?;
+        if !self.pub_key.is_empty() {
+            struct_ser.serialize_field("pubKey", &self.pub_key)?;
+        }
+        if self.propagate {
+            struct_ser.serialize_field("propagate", &self.propagate)?;
+        }
+        if let Some(v) = self.command.as_ref() {
+            match v {
+                submit_transaction_request::Command::OrderSubmission(v) => {
+                    struct_ser.serialize_field("orderSubmission", v)?;
+                }
+                submit_transaction_request::Command::OrderCancellation(v) => {
+                    struct_ser.serialize_field("orderCancellation", v)?;
+                }
+                submit_transaction_request::Command::OrderAmendment(v) => {
+                    struct_ser.serialize_field("orderAmendment", v)?;
+                }
+                submit_transaction_request::Command::WithdrawSubmission(v) => {
+                    struct_ser.serialize_field("withdrawSubmission", v)?;
+                }
+                submit_transaction_request::Command::ProposalSubmission(v) => {
+                    struct_ser.serialize_field("proposalSubmission", v)?;
+                }
+                submit_transaction_request::Command::VoteSubmission(v) => {
+                    struct_ser.serialize_field("voteSubmission", v)?;
+                }
+                submit_transaction_request::Command::LiquidityProvisionSubmission(v) => {
+                    struct_ser.serialize_field("liquidityProvisionSubmission", v)?;
+                }
+                submit_transaction_request::Command::DelegateSubmission(v) => {
+                    struct_ser.serialize_field("delegateSubmission", v)?;
+                }
+                submit_transaction_request::Command::UndelegateSubmission(v) => {
+                    struct_ser.serialize_field("undelegateSubmission", v)?;
+                }
+                submit_transaction_request::Command::LiquidityProvisionCancellation(v) => {
+                    struct_ser.serialize_field("liquidityProvisionCancellation", v)?;
+                }
+                submit_transaction_request::Command::LiquidityProvisionAmendment(v) => {
+                    struct_ser.serialize_field("liquidityProvisionAmendment", v)?;
+                }
+                submit_transaction_request::Command::Transfer(v) => {
+                    struct_ser.serialize_field("transfer", v)?;
+                }
+                submit_transaction_request::Command::CancelTransfer(v) => {
+                    struct_ser.serialize_field("cancelTransfer", v)?;
+                }
+                submit_transaction_request::Command::AnnounceNode(v) => {
+                    struct_ser.serialize_field("announceNode", v)?;
+                }
+                submit_transaction_request::Command::BatchMarketInstructions(v) => {
+                    struct_ser.serialize_field("batchMarketInstructions", v)?;
+                }
+                submit_transaction_request::Command::StopOrdersSubmission(v) => {
+                    struct_ser.serialize_field("stopOrdersSubmission", v)?;
+                }
+                submit_transaction_request::Command::StopOrdersCancellation(v) => {
+                    struct_ser.serialize_field("stopOrdersCancellation", v)?;
+                }
+                submit_transaction_request::Command::CreateReferralSet(v) => {
+                    struct_ser.serialize_field("createReferralSet", v)?;
+                }
+                submit_transaction_request::Command::UpdateReferralSet(v) => {
+                    struct_ser.serialize_field("updateReferralSet", v)?;
+                }
+                submit_transaction_request::Command::ApplyReferralCode(v) => {
+                    struct_ser.serialize_field("applyReferralCode", v)?;
+                }
+                submit_transaction_request::Command::UpdateMarginMode(v) => {
+                    struct_ser.serialize_field("updateMarginMode", v)?;
+                }
+                submit_transaction_request::Command::JoinTeam(v) => {
+                    struct_ser.serialize_field("joinTeam", v)?;
+                }
+                submit_transaction_request::Command::BatchProposalSubmission(v) => {
+                    struct_ser.serialize_field("batchProposalSubmission", v)?;
+                }
+                submit_transaction_request::Command::UpdatePartyProfile(v) => {
+                    struct_ser.serialize_field("updatePartyProfile", v)?;
+                }
+                submit_transaction_request::Command::SubmitAmm(v) => {
+                    struct_ser.serialize_field("submitAmm", v)?;
+                }
+                submit_transaction_request::Command::AmendAmm(v) => {
+                    struct_ser.serialize_field("amendAmm", v)?;
+                }
+                submit_transaction_request::Command::CancelAmm(v) => {
+                    struct_ser.serialize_field("cancelAmm", v)?;
+                }
+                submit_transaction_request::Command::NodeVote(v) => {
+                    struct_ser.serialize_field("nodeVote", v)?;
+                }
+                submit_transaction_request::Command::NodeSignature(v) => {
+                    struct_ser.serialize_field("nodeSignature", v)?;
+                }
+                submit_transaction_request::Command::ChainEvent(v) => {
+                    struct_ser.serialize_field("chainEvent", v)?;
+                }
+                submit_transaction_request::Command::KeyRotateSubmission(v) => {
+                    struct_ser.serialize_field("keyRotateSubmission", v)?;
+                }
+                submit_transaction_request::Command::StateVariableProposal(v) => {
+                    struct_ser.serialize_field("stateVariableProposal", v)?;
+                }
+                submit_transaction_request::Command::ValidatorHeartbeat(v) => {
+                    struct_ser.serialize_field("validatorHeartbeat", v)?;
+                }
+                submit_transaction_request::Command::EthereumKeyRotateSubmission(v) => {
+                    struct_ser.serialize_field("ethereumKeyRotateSubmission", v)?;
+                }
+                submit_transaction_request::Command::ProtocolUpgradeProposal(v) => {
+                    struct_ser.serialize_field("protocolUpgradeProposal", v)?;
+                }
+                submit_transaction_request::Command::IssueSignatures(v) => {
+                    struct_ser.serialize_field("issueSignatures", v)?;
+                }
+                submit_transaction_request::Command::OracleDataSubmission(v) => {
+                    struct_ser.serialize_field("oracleDataSubmission", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SubmitTransactionRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "pub_key",
+            "pubKey",
+            "propagate",
+            "order_submission",
+            "orderSubmission",
+            "order_cancellation",
+            "orderCancellation",
+            "order_amendment",
+            "orderAmendment",
+            "withdraw_submission",
+            "withdrawSubmission",
+            "proposal_submission",
+            "proposalSubmission",
+            "vote_submission",
+            "voteSubmission",
+            "liquidity_provision_submission",
+            "liquidityProvisionSubmission",
+            "delegate_submission",
+            "delegateSubmission",
+            "undelegate_submission",
+            "undelegateSubmission",
+            "liquidity_provision_cancellation",
+            "liquidityProvisionCancellation",
+            "liquidity_provision_amendment",
+            "liquidityProvisionAmendment",
+            "transfer",
+            "cancel_transfer",
+            "cancelTransfer",
+            "announce_node",
+            "announceNode",
+            "batch_market_instructions",
+            "batchMarketInstructions",
+            "stop_orders_submission",
+            "stopOrdersSubmission",
+            "stop_orders_cancellation",
+            "stopOrdersCancellation",
+            "create_referral_set",
+            "createReferralSet",
+            "update_referral_set",
+            "updateReferralSet",
+            "apply_referral_code",
+            "applyReferralCode",
+            "update_margin_mode",
+            "updateMarginMode",
+            "join_team",
+            "joinTeam",
+            "batch_proposal_submission",
+            "batchProposalSubmission",
+            "update_party_profile",
+            "updatePartyProfile",
+            "submit_amm",
+            "submitAmm",
+            "amend_amm",
+            "amendAmm",
+            "cancel_amm",
+            "cancelAmm",
+            "node_vote",
+            "nodeVote",
+            "node_signature",
+            "nodeSignature",
+            "chain_event",
+            "chainEvent",
+            "key_rotate_submission",
+            "keyRotateSubmission",
+            "state_variable_proposal",
+            "stateVariableProposal",
+            "validator_heartbeat",
+            "validatorHeartbeat",
+            "ethereum_key_rotate_submission",
+            "ethereumKeyRotateSubmission",
+            "protocol_upgrade_proposal",
+            "protocolUpgradeProposal",
+            "issue_signatures",
+            "issueSignatures",
+            "oracle_data_submission",
+            "oracleDataSubmission",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PubKey,
+            Propagate,
+            OrderSubmission,
+            OrderCancellation,
+            OrderAmendment,
+            WithdrawSubmission,
+            ProposalSubmission,
+            VoteSubmission,
+            LiquidityProvisionSubmission,
+            DelegateSubmission,
+            UndelegateSubmission,
+            LiquidityProvisionCancellation,
+            LiquidityProvisionAmendment,
+            Transfer,
+            CancelTransfer,
+            AnnounceNode,
+            BatchMarketInstructions,
+            StopOrdersSubmission,
+            StopOrdersCancellation,
+            CreateReferralSet,
+            UpdateReferralSet,
+            ApplyReferralCode,
+            UpdateMarginMode,
+            JoinTeam,
+            BatchProposalSubmission,
+            UpdatePartyProfile,
+            SubmitAmm,
+            AmendAmm,
+            CancelAmm,
+            NodeVote,
+            NodeSignature,
+            ChainEvent,
+            KeyRotateSubmission,
+            StateVariableProposal,
+            ValidatorHeartbeat,
+            EthereumKeyRotateSubmission,
+            ProtocolUpgradeProposal,
+            IssueSignatures,
+            OracleDataSubmission,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "pubKey" | "pub_key" => Ok(GeneratedField::PubKey),
+                            "propagate" => Ok(GeneratedField::Propagate),
+                            "orderSubmission" | "order_submission" => Ok(GeneratedField::OrderSubmission),
+                            "orderCancellation" | "order_cancellation" => Ok(GeneratedField::OrderCancellation),
+                            "orderAmendment" | "order_amendment" => Ok(GeneratedField::OrderAmendment),
+                            "withdrawSubmission" | "withdraw_submission" => Ok(GeneratedField::WithdrawSubmission),
+                            "proposalSubmission" | "proposal_submission" => Ok(GeneratedField::ProposalSubmission),
+                            "voteSubmission" | "vote_submission" => Ok(GeneratedField::VoteSubmission),
+                            "liquidityProvisionSubmission" | "liquidity_provision_submission" => Ok(GeneratedField::LiquidityProvisionSubmission),
+                            "delegateSubmission" | "delegate_submission" => Ok(GeneratedField::DelegateSubmission),
+                            "undelegateSubmission" | "undelegate_submission" => Ok(GeneratedField::UndelegateSubmission),
+                            "liquidityProvisionCancellation" | "liquidity_provision_cancellation" => Ok(GeneratedField::LiquidityProvisionCancellation),
+                            "liquidityProvisionAmendment" | "liquidity_provision_amendment" => Ok(GeneratedField::LiquidityProvisionAmendment),
+                            "transfer" => Ok(GeneratedField::Transfer),
+                            "cancelTransfer" | "cancel_transfer" => Ok(GeneratedField::CancelTransfer),
+                            "announceNode" | "announce_node" => Ok(GeneratedField::AnnounceNode),
+                            "batchMarketInstructions" | "batch_market_instructions" => Ok(GeneratedField::BatchMarketInstructions),
+                            "stopOrdersSubmission" | "stop_orders_submission" => Ok(GeneratedField::StopOrdersSubmission),
+                            "stopOrdersCancellation" | "stop_orders_cancellation" => Ok(GeneratedField::StopOrdersCancellation),
+                            "createReferralSet" | "create_referral_set" => Ok(GeneratedField::CreateReferralSet),
+                            "updateReferralSet" | "update_referral_set" => Ok(GeneratedField::UpdateReferralSet),
+                            "applyReferralCode" | "apply_referral_code" => Ok(GeneratedField::ApplyReferralCode),
+                            "updateMarginMode" | "update_margin_mode" => Ok(GeneratedField::UpdateMarginMode),
+                            "joinTeam" | "join_team" => Ok(GeneratedField::JoinTeam),
+                            "batchProposalSubmission" | "batch_proposal_submission" => Ok(GeneratedField::BatchProposalSubmission),
+                            "updatePartyProfile" | "update_party_profile" => Ok(GeneratedField::UpdatePartyProfile),
+                            "submitAmm" | "submit_amm" => Ok(GeneratedField::SubmitAmm),
+                            "amendAmm" | "amend_amm" => Ok(GeneratedField::AmendAmm),
+                            "cancelAmm" | "cancel_amm" => Ok(GeneratedField::CancelAmm),
+                            "nodeVote" | "node_vote" => Ok(GeneratedField::NodeVote),
+                            "nodeSignature" | "node_signature" => Ok(GeneratedField::NodeSignature),
+                            "chainEvent" | "chain_event" => Ok(GeneratedField::ChainEvent),
+                            "keyRotateSubmission" | "key_rotate_submission" => Ok(GeneratedField::KeyRotateSubmission),
+                            "stateVariableProposal" | "state_variable_proposal" => Ok(GeneratedField::StateVariableProposal),
+                            "validatorHeartbeat" | "validator_heartbeat" => Ok(GeneratedField::ValidatorHeartbeat),
+                            "ethereumKeyRotateSubmission" | "ethereum_key_rotate_submission" => Ok(GeneratedField::EthereumKeyRotateSubmission),
+                            "protocolUpgradeProposal" | "protocol_upgrade_proposal" => Ok(GeneratedField::ProtocolUpgradeProposal),
+                            "issueSignatures" | "issue_signatures" => Ok(GeneratedField::IssueSignatures),
+                            "oracleDataSubmission" | "oracle_data_submission" => Ok(GeneratedField::OracleDataSubmission),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SubmitTransactionRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct vega.wallet.v1.SubmitTransactionRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SubmitTransactionRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut pub_key__ = None;
+                let mut propagate__ = None;
+                let mut command__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PubKey => {
+                            if pub_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pubKey"));
+                            }
+                            pub_key__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Propagate => {
+                            if propagate__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("propagate"));
+                            }
+                            propagate__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::OrderSubmission => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("orderSubmission"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(submit_transaction_request::Command::OrderSubmission)
+;
+                        }
+                        GeneratedField::OrderCancellation => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("orderCancellation"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(submit_transaction_request::Command::OrderCancellation)
+;
+                        }
+                        GeneratedField::OrderAmendment => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("orderAmendment"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(submit_transaction_request::Command::OrderAmendment)
+;
+                        }
+                        GeneratedField::WithdrawSubmission => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("withdrawSubmission"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(submit_transaction_request::Command::WithdrawSubmission)
+;
+                        }
+                        GeneratedField::ProposalSubmission => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposalSubmission"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(submit_transaction_request::Command::ProposalSubmission)
+;
+                        }
+                        GeneratedField::VoteSubmission => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("voteSubmission"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(submit_transaction_request::Command::VoteSubmission)
+;
+                        }
+                        GeneratedField::LiquidityProvisionSubmission => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("liquidityProvisionSubmission"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(submit_transaction_request::Command::LiquidityProvisionSubmission)
+;
+                        }
+                        GeneratedField::DelegateSubmission => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("delegateSubmission"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(submit_transaction_request::Command::DelegateSubmission)
+;
+                        }
+                        GeneratedField::UndelegateSubmission => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("undelegateSubmission"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(submit_transaction_request::Command::UndelegateSubmission)
+;
+                        }
+                        GeneratedField::LiquidityProvisionCancellation => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("liquidityProvisionCancellation"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(submit_transaction_request::Command::LiquidityProvisionCancellation)
+;
+                        }
+                        GeneratedField::LiquidityProvisionAmendment => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("liquidityProvisionAmendment"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(submit_transaction_request::Command::LiquidityProvisionAmendment)
+;
+                        }
+                        GeneratedField::Transfer => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("transfer"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(submit_transaction_request::Command::Transfer)
+;
+                        }
+                        GeneratedField::CancelTransfer => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("cancelTransfer"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(submit_transaction_request::Command::CancelTransfer)
+;
+                        }
+                        GeneratedField::AnnounceNode => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("announceNode"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(submit_transaction_request::Command::AnnounceNode)
+;
+                        }
+                        GeneratedField::BatchMarketInstructions => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("batchMarketInstructions"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(submit_transaction_request::Command::BatchMarketInstructions)
+;
+                        }
+                        GeneratedField::StopOrdersSubmission => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stopOrdersSubmission"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(submit_transaction_request::Command::StopOrdersSubmission)
+;
+                        }
+                        GeneratedField::StopOrdersCancellation => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stopOrdersCancellation"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(submit_transaction_request::Command::StopOrdersCancellation)
+;
+                        }
+                        GeneratedField::CreateReferralSet => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("createReferralSet"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(submit_transaction_request::Command::CreateReferralSet)
+;
+                        }
+                        GeneratedField::UpdateReferralSet => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("updateReferralSet"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(submit_transaction_request::Command::UpdateReferralSet)
+;
+                        }
+                        GeneratedField::ApplyReferralCode => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("applyReferralCode"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(submit_transaction_request::Command::ApplyReferralCode)
+;
+                        }
+                        GeneratedField::UpdateMarginMode => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("updateMarginMode"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(submit_transaction_request::Command::UpdateMarginMode)
+;
+                        }
+                        GeneratedField::JoinTeam => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("joinTeam"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(submit_transaction_request::Command::JoinTeam)
+;
+                        }
+                        GeneratedField::BatchProposalSubmission => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("batchProposalSubmission"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(submit_transaction_request::Command::BatchProposalSubmission)
+;
+                        }
+                        GeneratedField::UpdatePartyProfile => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("updatePartyProfile"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(submit_transaction_request::Command::UpdatePartyProfile)
+;
+                        }
+                        GeneratedField::SubmitAmm => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("submitAmm"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(submit_transaction_request::Command::SubmitAmm)
+;
+                        }
+                        GeneratedField::AmendAmm => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amendAmm"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(submit_transaction_request::Command::AmendAmm)
+;
+                        }
+                        GeneratedField::CancelAmm => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("cancelAmm"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(submit_transaction_request::Command::CancelAmm)
+;
+                        }
+                        GeneratedField::NodeVote => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nodeVote"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(submit_transaction_request::Command::NodeVote)
+;
+                        }
+                        GeneratedField::NodeSignature => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nodeSignature"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(submit_transaction_request::Command::NodeSignature)
+;
+                        }
+                        GeneratedField::ChainEvent => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainEvent"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(submit_transaction_request::Command::ChainEvent)
+;
+                        }
+                        GeneratedField::KeyRotateSubmission => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("keyRotateSubmission"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(submit_transaction_request::Command::KeyRotateSubmission)
+;
+                        }
+                        GeneratedField::StateVariableProposal => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stateVariableProposal"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(submit_transaction_request::Command::StateVariableProposal)
+;
+                        }
+                        GeneratedField::ValidatorHeartbeat => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("validatorHeartbeat"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(submit_transaction_request::Command::ValidatorHeartbeat)
+;
+                        }
+                        GeneratedField::EthereumKeyRotateSubmission => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ethereumKeyRotateSubmission"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(submit_transaction_request::Command::EthereumKeyRotateSubmission)
+;
+                        }
+                        GeneratedField::ProtocolUpgradeProposal => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("protocolUpgradeProposal"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(submit_transaction_request::Command::ProtocolUpgradeProposal)
+;
+                        }
+                        GeneratedField::IssueSignatures => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("issueSignatures"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(submit_transaction_request::Command::IssueSignatures)
+;
+                        }
+                        GeneratedField::OracleDataSubmission => {
+                            if command__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("oracleDataSubmission"));
+                            }
+                            command__ = map_.next_value::<::std::option::Option<_>>()?.map(submit_transaction_request::Command::OracleDataSubmission)
+;
+                        }
+                    }
+                }
+                Ok(SubmitTransactionRequest {
+                    pub_key: pub_key__.unwrap_or_default(),
+                    propagate: propagate__.unwrap_or_default(),
+                    command: command__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("vega.wallet.v1.SubmitTransactionRequest", FIELDS, GeneratedVisitor)
+    }
+}
@@ -1,63 +1,179 @@
-pub mod vega {
+// the generated code is not under our control
+#![allow(
+    clippy::doc_lazy_continuation,
+    clippy::doc_overindented_list_items,
+    clippy::large_enum_variant
+)]
+
+/// Include the code generated for a protobuf package, committed under
+/// `src/generated` or compiled by the build script with `compile-protos`.
+macro_rules! include_proto {
+    ($package:literal) => {
+        include_generated!($package, ".rs");
+        #[cfg(feature = "serde")]
+        include_generated!($package, ".serde.rs");
+    };
+}
+
+macro_rules! include_generated {
+    ($package:literal, $ext:literal) => {
+        #[cfg(not(feature = "compile-protos"))]
+        include!(concat!("generated/", $package, $ext));
+        #[cfg(feature = "compile-protos")]
+        include!(concat!(env!("OUT_DIR"), "/", $package, $ext));
+    };
+}
 
-    include!(concat!(env!("OUT_DIR"), "/vega.rs"));
+/// The encoded `google.protobuf.FileDescriptorSet` of all the protobuf
+/// files, to decode messages dynamically or serve gRPC reflection.
+#[cfg(not(feature = "compile-protos"))]
+pub const FILE_DESCRIPTOR_SET: &[u8] = include_bytes!("generated/file_descriptor_set.bin");
+/// The encoded `google.protobuf.FileDescriptorSet` of the compiled
+/// protobuf files, to decode messages dynamically or serve gRPC reflection.
+#[cfg(feature = "compile-protos")]
+pub const FILE_DESCRIPTOR_SET: &[u8] =
+    include_bytes!(concat!(env!("OUT_DIR"), "/file_descriptor_set.bin"));
+
+pub mod vega {
+    include_proto!("vega");
 
     pub mod commands {
         pub mod v1 {
-            include!(concat!(env!("OUT_DIR"), "/vega.commands.v1.rs"));
+            include_proto!("vega.commands.v1");
         }
     }
+    #[cfg(feature = "events")]
     pub mod events {
         pub mod v1 {
-            include!(concat!(env!("OUT_DIR"), "/vega.events.v1.rs"));
+            include_proto!("vega.events.v1");
         }
     }
     pub mod wallet {
         pub mod v1 {
-            include!(concat!(env!("OUT_DIR"), "/vega.wallet.v1.rs"));
+            include_proto!("vega.wallet.v1");
         }
     }
+    #[cfg(feature = "snapshot")]
     pub mod snapshot {
         pub mod v1 {
-            include!(concat!(env!("OUT_DIR"), "/vega.snapshot.v1.rs"));
+            include_proto!("vega.snapshot.v1");
         }
     }
+    #[cfg(feature = "checkpoint")]
     pub mod checkpoint {
         pub mod v1 {
-            include!(concat!(env!("OUT_DIR"), "/vega.checkpoint.v1.rs"));
+            include_proto!("vega.checkpoint.v1");
         }
     }
+    #[cfg(feature = "core-api")]
     pub mod api {
         pub mod v1 {
-            include!(concat!(env!("OUT_DIR"), "/vega.api.v1.rs"));
+            include_proto!("vega.api.v1");
         }
     }
 
     pub mod data {
         pub mod v1 {
-            include!(concat!(env!("OUT_DIR"), "/vega.data.v1.rs"));
+            include_proto!("vega.data.v1");
         }
     }
 }
 
+#[cfg(feature = "data-node")]
 pub mod datanode {
     pub mod api {
         pub mod v2 {
-            include!(concat!(env!("OUT_DIR"), "/datanode.api.v2.rs"));
+            include_proto!("datanode.api.v2");
         }
     }
 }
 
+#[cfg(feature = "block-explorer")]
 pub mod blockexplorer {
     pub mod api {
         pub mod v1 {
-            include!(concat!(env!("OUT_DIR"), "/blockexplorer.api.v1.rs"));
+            include_proto!("blockexplorer.api.v1");
         }
     }
 }
 
 pub mod google {
+    #[cfg(any(
+        feature = "core-api",
+        feature = "data-node",
+        feature = "block-explorer"
+    ))]
     pub mod api {
-        include!(concat!(env!("OUT_DIR"), "/google.api.rs"));
+        include_generated!("google.api", ".rs");
+    }
+
+    /// The well known types, always from pbjson_types so the types of the
+    /// generated fields are the same whether `serde` is enabled or not.
+    pub mod protobuf {
+        pub use pbjson_types::*;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::vega::commands::v1::{input_data::Command, InputData, OrderSubmission};
+    use prost::Message;
+    use prost_reflect::{DescriptorPool, DynamicMessage, Value};
+
+    #[test]
+    fn file_descriptor_set_decodes_messages() {
+        let pool = DescriptorPool::decode(crate::FILE_DESCRIPTOR_SET).unwrap();
+        let desc = pool
+            .get_message_by_name("vega.commands.v1.InputData")
+            .unwrap();
+
+        let input = InputData {
+            nonce: 42,
+            block_height: 100,
+            command: Some(Command::OrderSubmission(OrderSubmission {
+                market_id: "market".to_string(),
+                ..Default::default()
+            })),
+        };
+        let msg = DynamicMessage::decode(desc, input.encode_to_vec().as_slice()).unwrap();
+
+        assert_eq!(*msg.get_field_by_name("nonce").unwrap(), Value::U64(42));
+        let order = msg.get_field_by_name("order_submission").unwrap();
+        let market_id = order.as_message().unwrap().get_field_by_name("market_id");
+        assert_eq!(*market_id.unwrap(), Value::String("market".to_string()));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn proto3_json_mapping() {
+        use crate::vega::{order::TimeInForce, Side};
+
+        let input = InputData {
+            nonce: 42,
+            block_height: 100,
+            command: Some(Command::OrderSubmission(OrderSubmission {
+                market_id: "market".to_string(),
+                size: 10,
+                side: Side::Buy as i32,
+                time_in_force: TimeInForce::Gtc as i32,
+                ..Default::default()
+            })),
+        };
+
+        let value = serde_json::to_value(&input).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "nonce": "42",
+                "blockHeight": "100",
+                "orderSubmission": {
+                    "marketId": "market",
+                    "size": "10",
+                    "side": "SIDE_BUY",
+                    "timeInForce": "TIME_IN_FORCE_GTC",
+                }
+            })
+        );
+        assert_eq!(serde_json::from_value::<InputData>(value).unwrap(), input);
     }
 }
@@ -0,0 +1,394 @@
+use crate::request::Request;
+use crate::response::KeysResponse;
+use crate::{rpc, Error};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+/// A client for the `admin.*` namespace of the wallet API,
+/// used to manage wallets, keys and networks without user interaction.
+pub struct AdminClient {
+    clt: reqwest::Client,
+    url: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Metadata {
+    pub key: String,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Algorithm {
+    pub name: String,
+    pub version: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KeyResponse {
+    pub public_key: String,
+    pub algorithm: Algorithm,
+    #[serde(default)]
+    pub metadata: Vec<Metadata>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WalletInfo {
+    pub name: String,
+    pub key_derivation_version: u32,
+    /// Only returned when a wallet is created
+    pub recovery_phrase: Option<String>,
+    pub file_path: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WalletResponse {
+    pub wallet: WalletInfo,
+    pub key: KeyResponse,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ListWalletsResponse {
+    pub wallets: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IsolateKeyResponse {
+    pub wallet: String,
+    pub file_path: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Network {
+    pub name: String,
+    pub file_path: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ListNetworksResponse {
+    pub networks: Vec<Network>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct WalletParams<'a> {
+    wallet: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    passphrase: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recovery_phrase: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    key_derivation_version: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct KeyParams<'a> {
+    wallet: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    public_key: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    metadata: Option<&'a [Metadata]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    isolated_wallet_passphrase: Option<&'a str>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct NetworkParams<'a> {
+    network: &'a str,
+}
+
+impl<'a> WalletParams<'a> {
+    fn new(wallet: &'a str) -> WalletParams<'a> {
+        return WalletParams {
+            wallet,
+            passphrase: None,
+            recovery_phrase: None,
+            key_derivation_version: None,
+        };
+    }
+}
+
+impl<'a> KeyParams<'a> {
+    fn new(wallet: &'a str) -> KeyParams<'a> {
+        return KeyParams {
+            wallet,
+            public_key: None,
+            metadata: None,
+            isolated_wallet_passphrase: None,
+        };
+    }
+}
+
+impl AdminClient {
+    /// Create a client for the admin JSON-RPC endpoint at `url`,
+    /// e.g: http://localhost:1789/api/v2/admin
+    pub fn new(url: &str) -> AdminClient {
+        return AdminClient {
+            clt: reqwest::Client::new(),
+            url: url.to_string(),
+        };
+    }
+
+    /// Create a new wallet, the recovery phrase is only returned by this call.
+    pub async fn create_wallet(
+        &self,
+        wallet: &str,
+        passphrase: &str,
+    ) -> Result<WalletResponse, Error> {
+        let params = WalletParams {
+            passphrase: Some(passphrase),
+            ..WalletParams::new(wallet)
+        };
+        return self.request("admin.create_wallet", Some(params)).await;
+    }
+
+    pub async fn import_wallet(
+        &self,
+        wallet: &str,
+        recovery_phrase: &str,
+        key_derivation_version: u32,
+        passphrase: &str,
+    ) -> Result<WalletResponse, Error> {
+        let params = WalletParams {
+            passphrase: Some(passphrase),
+            recovery_phrase: Some(recovery_phrase),
+            key_derivation_version: Some(key_derivation_version),
+            ..WalletParams::new(wallet)
+        };
+        return self.request("admin.import_wallet", Some(params)).await;
+    }
+
+    pub async fn list_wallets(&self) -> Result<ListWalletsResponse, Error> {
+        return self.request::<(), _>("admin.list_wallets", None).await;
+    }
+
+    /// Unlock a wallet, required before managing its keys.
+    pub async fn unlock_wallet(&self, wallet: &str, passphrase: &str) -> Result<(), Error> {
+        let params = WalletParams {
+            passphrase: Some(passphrase),
+            ..WalletParams::new(wallet)
+        };
+        return self.request("admin.unlock_wallet", Some(params)).await;
+    }
+
+    pub async fn remove_wallet(&self, wallet: &str) -> Result<(), Error> {
+        return self
+            .request("admin.remove_wallet", Some(WalletParams::new(wallet)))
+            .await;
+    }
+
+    /// Generate the next key of a wallet.
+    pub async fn generate_key(
+        &self,
+        wallet: &str,
+        metadata: &[Metadata],
+    ) -> Result<KeyResponse, Error> {
+        let params = KeyParams {
+            metadata: Some(metadata),
+            ..KeyParams::new(wallet)
+        };
+        return self.request("admin.generate_key", Some(params)).await;
+    }
+
+    pub async fn list_keys(&self, wallet: &str) -> Result<KeysResponse, Error> {
+        return self
+            .request("admin.list_keys", Some(KeyParams::new(wallet)))
+            .await;
+    }
+
+    /// Extract a key into a new wallet, protected by its own passphrase.
+    pub async fn isolate_key(
+        &self,
+        wallet: &str,
+        public_key: &str,
+        isolated_wallet_passphrase: &str,
+    ) -> Result<IsolateKeyResponse, Error> {
+        let params = KeyParams {
+            public_key: Some(public_key),
+            isolated_wallet_passphrase: Some(isolated_wallet_passphrase),
+            ..KeyParams::new(wallet)
+        };
+        return self.request("admin.isolate_key", Some(params)).await;
+    }
+
+    /// Mark a key as tainted, it can't be used to sign anymore.
+    pub async fn taint_key(&self, wallet: &str, public_key: &str) -> Result<(), Error> {
+        let params = KeyParams {
+            public_key: Some(public_key),
+            ..KeyParams::new(wallet)
+        };
+        return self.request("admin.taint_key", Some(params)).await;
+    }
+
+    pub async fn untaint_key(&self, wallet: &str, public_key: &str) -> Result<(), Error> {
+        let params = KeyParams {
+            public_key: Some(public_key),
+            ..KeyParams::new(wallet)
+        };
+        return self.request("admin.untaint_key", Some(params)).await;
+    }
+
+    pub async fn list_networks(&self) -> Result<ListNetworksResponse, Error> {
+        return self.request::<(), _>("admin.list_networks", None).await;
+    }
+
+    /// Start the wallet service connected to the given network.
+    pub async fn start_service(&self, network: &str) -> Result<(), Error> {
+        return self
+            .request("admin.start_service", Some(NetworkParams { network }))
+            .await;
+    }
+
+    pub async fn stop_service(&self, network: &str) -> Result<(), Error> {
+        return self
+            .request("admin.stop_service", Some(NetworkParams { network }))
+            .await;
+    }
+
+    async fn request<P, RES>(&self, method: &str, params: Option<P>) -> Result<RES, Error>
+    where
+        P: Serialize,
+        RES: DeserializeOwned,
+    {
+        let resp = self
+            .clt
+            .post(&self.url)
+            .json(&Request::new(method, params))
+            .send()
+            .await?;
+        return rpc(resp).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{MockWallet, Reply};
+    use crate::response::ErrorCode;
+    use serde_json::json;
+
+    const PUB_KEY: &str = "6545621b8a3f398db322a4acc68c1b59fd284ab010e157e5aa887a6f55d94eba";
+
+    fn key() -> serde_json::Value {
+        return json!({
+            "publicKey": PUB_KEY,
+            "algorithm": {"name": "vega/ed25519", "version": 1},
+            "metadata": [{"key": "name", "value": "Key 1"}],
+        });
+    }
+
+    async fn client() -> (MockWallet, AdminClient) {
+        let mock = MockWallet::start(|r| match r.method.as_str() {
+            "admin.create_wallet" | "admin.import_wallet" => Reply::ok(json!({
+                "wallet": {
+                    "name": r.params["wallet"],
+                    "keyDerivationVersion": 2,
+                    "recoveryPhrase": "swing ceiling chaos",
+                    "filePath": "/wallets/w1",
+                },
+                "key": key(),
+            })),
+            "admin.generate_key" => Reply::ok(key()),
+            "admin.isolate_key" => Reply::ok(json!({
+                "wallet": "w1.6545621b.isolated",
+                "filePath": "/wallets/w1.6545621b.isolated",
+            })),
+            "admin.list_networks" => Reply::ok(json!({"networks": [{"name": "fairground"}]})),
+            "admin.taint_key" => Reply::ok(json!(null)),
+            "admin.unlock_wallet" => Reply::err(2000, "Application error", "wrong passphrase"),
+            _ => Reply::err(-32601, "Method not found", "unknown method"),
+        })
+        .await;
+        let clt = AdminClient::new(&format!("{}/api/v2/admin", mock.url));
+        return (mock, clt);
+    }
+
+    #[tokio::test]
+    async fn test_create_wallet() {
+        let (mock, clt) = client().await;
+        let resp = clt.create_wallet("w1", "passphrase").await.unwrap();
+        assert_eq!(resp.wallet.name, "w1");
+        assert!(resp.wallet.recovery_phrase.is_some());
+        assert_eq!(resp.key.public_key, PUB_KEY);
+
+        let received = mock.received();
+        assert_eq!(received[0].path, "/api/v2/admin");
+        assert_eq!(received[0].method, "admin.create_wallet");
+        assert_eq!(
+            received[0].params,
+            json!({"wallet": "w1", "passphrase": "passphrase"})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_import_wallet() {
+        let (mock, clt) = client().await;
+        clt.import_wallet("w2", "swing ceiling chaos", 2, "passphrase")
+            .await
+            .unwrap();
+        assert_eq!(
+            mock.received()[0].params,
+            json!({
+                "wallet": "w2",
+                "passphrase": "passphrase",
+                "recoveryPhrase": "swing ceiling chaos",
+                "keyDerivationVersion": 2,
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_keys() {
+        let (mock, clt) = client().await;
+        let meta = vec![Metadata {
+            key: "name".into(),
+            value: "Key 1".into(),
+        }];
+        let resp = clt.generate_key("w1", &meta).await.unwrap();
+        assert_eq!(resp.metadata, meta);
+
+        let resp = clt.isolate_key("w1", PUB_KEY, "isolated").await.unwrap();
+        assert_eq!(resp.wallet, "w1.6545621b.isolated");
+        clt.taint_key("w1", PUB_KEY).await.unwrap();
+
+        let received = mock.received();
+        assert_eq!(
+            received[0].params,
+            json!({"wallet": "w1", "metadata": [{"key": "name", "value": "Key 1"}]})
+        );
+        assert_eq!(
+            received[1].params,
+            json!({"wallet": "w1", "publicKey": PUB_KEY, "isolatedWalletPassphrase": "isolated"})
+        );
+        assert_eq!(
+            received[2].params,
+            json!({"wallet": "w1", "publicKey": PUB_KEY})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_networks() {
+        let (_mock, clt) = client().await;
+        let resp = clt.list_networks().await.unwrap();
+        assert_eq!(resp.networks[0].name, "fairground");
+        assert_eq!(resp.networks[0].file_path, None);
+    }
+
+    #[tokio::test]
+    async fn test_error_codes() {
+        let (_mock, clt) = client().await;
+        match clt.unlock_wallet("w1", "wrong").await {
+            Err(Error::WalletError(e)) => assert_eq!(e.kind(), ErrorCode::ApplicationError),
+            _ => panic!("expected an application error"),
+        }
+        match clt.start_service("fairground").await {
+            Err(Error::WalletError(e)) => assert_eq!(e.kind(), ErrorCode::MethodNotFound),
+            _ => panic!("expected a method not found error"),
+        }
+        assert_eq!(ErrorCode::from(42), ErrorCode::Other(42));
+    }
+}
@@ -1,28 +1,66 @@
+//! The transaction commands a wallet can sign and send.
+//!
+//! The types mirror the user commands of `vega.commands.v1.InputData` and
+//! serialize to their proto3 JSON mapping, the format expected by the wallet:
+//! oneof members are flattened in their parent, enums use the names of
+//! their protobuf values and 64 bits integers are strings. The tests check
+//! every command against the file descriptor set of `vega_protobufs`, so
+//! any change to the schema shows up here.
+
 use serde::{Deserialize, Serialize};
 
-/// A batch of order instructions.
-/// This command accepts only the following batches of commands
-/// and will be processed in the following order:
-/// - OrderCancellation
-/// - OrderAmendment
-/// - OrderSubmission
-/// The total amount of commands in the batch across all three lists of
-/// instructions is restricted by the following network parameter:
-/// "spam.protection.max.batchSize"
+/// A command that submits an order to the Vega network for a given market.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct BatchMarketInstructions {
-    /// A list of order cancellations to be processed sequentially
-    pub cancellations: Vec<OrderCancellation>,
-    /// A list of order amendments to be processed sequentially
-    pub amendments: Vec<OrderAmendment>,
-    /// A list of order submissions to be processed sequentially
-    pub submissions: Vec<OrderSubmission>,
+pub struct OrderSubmission {
+    /// Market ID to submit the order to.
+    pub market_id: String,
+    /// Price for the order, the price is an integer, for example `123456` is a correctly
+    /// formatted price of `1.23456` assuming market configured to 5 decimal places,
+    /// required field for limit orders, however it is not required for market orders.
+    /// This field is an unsigned integer scaled to the market's decimal places.
+    pub price: String,
+    /// Size for the order, for example, in a futures market the size equals the number of units.
+    #[serde(with = "int64")]
+    pub size: u64,
+    /// Which side of the order book the order is for, e.g. buy or sell.
+    pub side: Side,
+    /// Time in force indicates how long an order will remain active before it is executed or expires..
+    pub time_in_force: TimeInForce,
+    /// Timestamp, in Unix nanoseconds, for when the order will expire. Can only be set when the order's time-in-force is GTT.
+    #[serde(with = "int64")]
+    pub expires_at: i64,
+    /// Type of the order.
+    pub r#type: OrderType,
+    /// Arbitrary optional reference for the order, to be used as a human-readable non-unique identifier for the order.
+    pub reference: String,
+    /// Pegged order details. If set, the order's price will be offset from a particular reference price of the order book at all times.
+    pub pegged_order: Option<PeggedOrder>,
+    /// If set, the order will only be executed if it would not trade on entry to the order book. Only valid for limit orders.
+    pub post_only: bool,
+    /// If set, the order will only be executed if the outcome of the trade moves the trader's position closer to 0.
+    /// Only valid for non-persistent orders.
+    pub reduce_only: bool,
+    /// Iceberg order details. If set, the order will exist on the order book in chunks.
+    pub iceberg_opts: Option<IcebergOpts>,
+}
+
+/// Side relates to the direction of an order, to Buy, or Sell
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Side {
+    /// Default value, always invalid
+    #[serde(rename = "SIDE_UNSPECIFIED")]
+    Unspecified = 0,
+    /// Buy order
+    #[serde(rename = "SIDE_BUY")]
+    Buy = 1,
+    /// Sell order
+    #[serde(rename = "SIDE_SELL")]
+    Sell = 2,
 }
 
 /// Time In Force for an order
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
 pub enum TimeInForce {
     /// Default value for TimeInForce, can be valid for an amend
     #[serde(rename = "TIME_IN_FORCE_UNSPECIFIED")]
@@ -40,8 +78,8 @@ pub enum TimeInForce {
     /// but does not remain on the book (whether it trades or not)
     #[serde(rename = "TIME_IN_FORCE_IOC")]
     Ioc = 3,
-    /// Fill or kill, The order either trades completely (remainingSize == 0 after adding)
-    /// or not at all, does not remain on the book if it doesn't trade
+    /// Fill or kill, the order either trades completely i.e. remainingSize == 0 after adding,
+    /// or not at all, and does not remain on the book if it doesn't trade
     #[serde(rename = "TIME_IN_FORCE_FOK")]
     Fok = 4,
     /// Good for auction, this order is only accepted during an auction period
@@ -54,7 +92,6 @@ pub enum TimeInForce {
 
 /// Type values for an order
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
 pub enum OrderType {
     /// Default value, always invalid
     #[serde(rename = "TYPE_UNSPECIFIED")]
@@ -70,25 +107,20 @@ pub enum OrderType {
     Network = 3,
 }
 
-/// A side relates to the direction of an order, to Buy, or Sell
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+/// Pegged orders are limit orders where the price is specified in the form REFERENCE +/- OFFSET
+/// They can be used for any limit order that is valid during continuous trading
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub enum Side {
-    /// Default value, always invalid
-    #[serde(rename = "SIDE_UNSPECIFIED")]
-    Unspecified = 0,
-    /// Buy order
-    #[serde(rename = "SIDE_BUY")]
-    Buy = 1,
-    /// Sell order
-    #[serde(rename = "SIDE_SELL")]
-    Sell = 2,
+pub struct PeggedOrder {
+    /// Price point the order is linked to.
+    pub reference: PeggedReference,
+    /// Offset from the price reference.
+    pub offset: String,
 }
 
-/// A pegged reference defines which price point a pegged order is linked to - meaning
+/// Pegged reference defines which price point a pegged order is linked to - meaning
 /// the price for a pegged order is calculated from the value of the reference price point
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
 pub enum PeggedReference {
     /// Default value for PeggedReference, no reference given
     #[serde(rename = "PEGGED_REFERENCE_UNSPECIFIED")]
@@ -104,260 +136,304 @@ pub enum PeggedReference {
     BestAsk = 3,
 }
 
-/// Pegged orders are limit orders where the price is specified in the form REFERENCE +/- OFFSET
-/// They can be used for any limit order that is valid during continuous trading
+/// Iceberg order options
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct PeggedOrder {
-    /// The price point the order is linked to
-    pub reference: PeggedReference,
-    /// Offset from the price reference
-    pub offset: String,
+pub struct IcebergOpts {
+    /// Size of the order that is made visible and can be traded with during the execution of a single order.
+    #[serde(with = "int64")]
+    pub peak_size: u64,
+    /// Minimum allowed remaining size of the order before it is replenished back to its peak size.
+    #[serde(with = "int64")]
+    pub minimum_visible_size: u64,
 }
 
-/// An order submission is a request to submit or create a new order on Vega
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct OrderSubmission {
-    /// Market identifier for the order, required field
-    pub market_id: String,
-    /// Price for the order, the price is an integer, for example `123456` is a correctly
-    /// formatted price of `1.23456` assuming market configured to 5 decimal places,
-    /// , required field for limit orders, however it is not required for market orders
-    pub price: String,
-    /// Size for the order, for example, in a futures market the size equals the number of units, cannot be negative
-    pub size: u64,
-    /// Side for the order, e.g. SIDE_BUY or SIDE_SELL, required field
-    /// - See `Side`
-    pub side: Side,
-    /// Time in force indicates how long an order will remain active before it is executed or expires, required field
-    /// - See `Order.TimeInForce`
-    pub time_in_force: TimeInForce,
-    /// Timestamp for when the order will expire, in nanoseconds since the epoch,
-    /// required field only for `Order.TimeInForce`.TIME_IN_FORCE_GTT`
-    /// - See `VegaTimeResponse`.`timestamp`
-    pub expires_at: i64,
-    /// Type for the order, required field - See `Order.Type`
-    pub r#type: OrderType,
-    /// Reference given for the order, this is typically used to retrieve an order submitted through consensus, currently
-    /// set internally by the node to return a unique reference identifier for the order submission
-    pub reference: String,
-    /// Used to specify the details for a pegged order
-    /// - See `PeggedOrder`
-    pub pegged_order: Option<PeggedOrder>,
-}
-/// An order cancellation is a request to cancel an existing order on Vega
+/// A command that instructs the network to cancel orders, active or partially filled, that were previously submitted by the sender of this transaction.
+/// It is not possible to cancel another party's order with this command.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct OrderCancellation {
-    /// Unique identifier for the order (set by the system after consensus), required field
+    /// Restrict cancellations to an order with the given ID. If set, then a market ID must also be provided.
     pub order_id: String,
-    /// Market identifier for the order, required field
+    /// Restrict cancellations to those submitted to the given market. If not set, all stop orders across all markets will be cancelled.
     pub market_id: String,
 }
-/// An order amendment is a request to amend or update an existing order on Vega
-///
-/// The `orderID`, `partyID` and `marketID` fields are used for looking up the order only and cannot be amended by this command
+
+/// A command that allows a party to update the details of an existing order.
+/// Any field that is left unset or as a default value indicates that this field on the original order will be left unchanged.
+/// It is not possible to change an order's type through this command.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct OrderAmendment {
-    /// Order identifier, this is required to find the order and will not be updated, required field
+    /// ID of the order to amend.
     pub order_id: String,
-    /// Market identifier, this is required to find the order and will not be updated
+    /// Market ID that the order was originally submitted to.
     pub market_id: String,
-    /// Amend the price for the order, if the Price value is set, otherwise price will remain unchanged - See \[`Price`\](#vega.Price)
+    /// New price for the order. This field is an unsigned integer scaled to the market's decimal places.
     pub price: Option<String>,
     /// Amend the size for the order by the delta specified:
     /// - To reduce the size from the current value set a negative integer value
     /// - To increase the size from the current value, set a positive integer value
     /// - To leave the size unchanged set a value of zero
+    ///
+    /// This field needs to be scaled using the market's position decimal places.
+    /// If specified, size must not be set.
+    #[serde(with = "int64")]
     pub size_delta: i64,
-    /// Amend the expiry time for the order, if the Timestamp value is set, otherwise expiry time will remain unchanged
-    /// - See \[`VegaTimeResponse`\](#api.VegaTimeResponse).`timestamp`
+    /// Timestamp, in Unix nanoseconds, for the new expiry time for the order.
+    #[serde(default, with = "int64::option")]
     pub expires_at: Option<i64>,
-    /// Amend the time in force for the order, set to TIME_IN_FORCE_UNSPECIFIED to remain unchanged
-    /// - See \[`TimeInForce`\](#api.VegaTimeResponse).`timestamp`
+    /// New time in force for the order.
     pub time_in_force: TimeInForce,
-    /// Amend the pegged order offset for the order
+    /// New pegged offset for the order.
+    /// This field is an unsigned integer scaled to the market's decimal places.
     pub pegged_offset: String,
-    /// Amend the pegged order reference for the order
-    /// - See \[`PeggedReference`\](#vega.PeggedReference)
-    pub pegged_reference: i32,
+    /// New pegged reference for the order.
+    pub pegged_reference: PeggedReference,
+    /// New size for the order.
+    /// Amending the size causes the size and remaining part of the order to be changed by the difference between the original and amended size.
+    /// - Specifying a size smaller than the current size leaves the order at its current order book position.
+    /// - Specifying a size larger than the current size removes and reinserts the order at the back of the price level.
+    /// - Specifying a size that results in the remaining part of the order being reduced to zero cancels the order.
+    ///
+    /// This field is an unsigned integer scaled to the market's decimal places.
+    /// If specified, size_delta must be set to 0.
+    #[serde(default, with = "int64::option")]
+    pub size: Option<u64>,
 }
 
-/// Represents a liquidity order
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+/// Command to instruct the network to process an asset withdrawal from the Vega network.
+/// The process is specific to the destination foreign chain, for example, a withdrawal to Ethereum will generate signatures
+/// that allow funds to be taken across the bridge.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct LiquidityOrder {
-    /// The pegged reference point for the order
-    pub reference: PeggedReference,
-    /// The relative proportion of the commitment to be allocated at a price level
-    pub proportion: u32,
-    /// The offset/amount of units away for the order
-    pub offset: String,
+pub struct WithdrawSubmission {
+    /// Amount to be withdrawn, as an unsigned integer scaled to the asset's decimal places.
+    pub amount: String,
+    /// Asset to be withdrawn.
+    pub asset: String,
+    /// Details specific to the foreign chain, such as the receiver address.
+    pub ext: Option<WithdrawExt>,
 }
 
-/// A liquidity provision submitted for a given market
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+/// Withdrawal external details
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct LiquidityProvisionSubmission {
-    /// Market identifier for the order, required field
-    pub market_id: String,
-    /// Specified as a unitless number that represents the amount of settlement asset of the market
-    pub commitment_amount: String,
-    /// Nominated liquidity fee factor, which is an input to the calculation of taker fees on the market, as per setting fees and rewarding liquidity providers
-    pub fee: String,
-    /// A set of liquidity sell orders to meet the liquidity provision obligation
-    pub sells: Vec<LiquidityOrder>,
-    /// A set of liquidity buy orders to meet the liquidity provision obligation
-    pub buys: Vec<LiquidityOrder>,
-    /// A reference to be added to every order created out of this liquidityProvisionSubmission
-    pub reference: String,
+pub enum WithdrawExt {
+    /// ERC20 withdrawal details.
+    Erc20(Erc20WithdrawExt),
 }
 
-/// Cancel a liquidity provision request
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+/// Extension of data required for the withdraw submissions
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct LiquidityProvisionCancellation {
-    /// Unique ID for the market with the liquidity provision to be cancelled
-    pub market_id: String,
+pub struct Erc20WithdrawExt {
+    /// Address into which the bridge will release the funds.
+    pub receiver_address: String,
 }
 
-/// Amend a liquidity provision request
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+/// Command that allows a token holder to submit a governance proposal that can be voted on by any other token holders, and eventually enacted on the Vega network.
+/// For example this command can be used to propose a new market.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct LiquidityProvisionAmendment {
-    /// Unique ID for the market with the liquidity provision to be amended
-    pub market_id: String,
-    /// an empty strings means no change
-    pub commitment_amount: String,
-    /// an empty strings means no change
-    pub fee: String,
-    /// empty slice means no change
-    pub sells: Vec<LiquidityOrder>,
-    /// empty slice means no change
-    pub buys: Vec<LiquidityOrder>,
-    /// empty string means no change
+pub struct ProposalSubmission {
+    /// Arbitrary human-readable reference identifying the proposal.
     pub reference: String,
+    /// Proposal terms containing the type and details of the proposal, as well as time spans for voting and enactment.
+    pub terms: Option<ProposalTerms>,
+    /// Rationale behind a proposal.
+    pub rationale: Option<ProposalRationale>,
 }
 
-/// An extension of data required for the withdraw submissions
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+/// Terms for a governance proposal on Vega
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct Erc20WithdrawExt {
-    /// The address into which the bridge will release the funds
-    pub receiver_address: String,
+pub struct ProposalTerms {
+    /// Timestamp as Unix time in seconds when voting closes for this proposal,
+    /// constrained by `minClose` and `maxClose` network parameters.
+    #[serde(with = "int64")]
+    pub closing_timestamp: i64,
+    /// Timestamp as Unix time in seconds when proposal gets enacted if passed,
+    /// constrained by `minEnact` and `maxEnact` network parameters.
+    #[serde(with = "int64")]
+    pub enactment_timestamp: i64,
+    /// Validation timestamp as Unix time in seconds.
+    #[serde(with = "int64")]
+    pub validation_timestamp: i64,
+    /// Changes being proposed.
+    #[serde(flatten)]
+    pub change: Option<ProposalChange>,
 }
 
-/// Foreign chain specifics
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+/// Changes being proposed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub enum WithdrawExt {
-    /// ERC20 withdrawal details
-    Erc20(Erc20WithdrawExt),
+pub enum ProposalChange {
+    /// Proposal change for modifying an existing futures market on Vega.
+    UpdateMarket(UpdateMarket),
+    /// Proposal change for creating new futures market on Vega.
+    NewMarket(NewMarket),
+    /// Proposal change for updating Vega network parameters.
+    UpdateNetworkParameter(UpdateNetworkParameter),
+    /// Proposal change for creating new assets on Vega.
+    NewAsset(NewAsset),
+    /// Proposal change for a freeform request, which can be voted on but does not change the behaviour of the system,
+    /// and can be used to gauge community sentiment.
+    NewFreeform(NewFreeform),
+    /// Proposal change for updating an asset.
+    UpdateAsset(UpdateAsset),
+    /// Proposal change for creating new spot market on Vega.
+    NewSpotMarket(NewSpotMarket),
+    /// Proposal change for modifying an existing spot market on Vega.
+    UpdateSpotMarket(UpdateSpotMarket),
+    /// Proposal change for a governance transfer.
+    NewTransfer(NewTransfer),
+    /// Cancel a governance transfer.
+    CancelTransfer(GovernanceCancelTransfer),
+    /// Proposal change for updating the state of a market.
+    UpdateMarketState(UpdateMarketState),
+    /// Proposal change for updating the referral program.
+    UpdateReferralProgram(UpdateReferralProgram),
+    /// Proposal change for updating the volume discount program.
+    UpdateVolumeDiscountProgram(UpdateVolumeDiscountProgram),
+    /// Proposal change for updating the volume rebate program.
+    UpdateVolumeRebateProgram(UpdateVolumeRebateProgram),
 }
 
-/// Represents the submission request to withdraw funds for a party on Vega
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+/// Update an existing market on Vega
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct WithdrawSubmission {
-    /// The amount to be withdrawn
-    pub amount: String,
-    /// The asset to be withdrawn
-    pub asset: String,
-    /// Foreign chain specifics
-    pub ext: Option<WithdrawExt>,
+pub struct UpdateMarket {
+    /// Market ID the update is for.
+    pub market_id: String,
+    /// Updated configuration of the futures market.
+    pub changes: Option<UpdateMarketConfiguration>,
 }
 
-/// The rationale behind a proposal.
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+/// Configuration to update a futures market on Vega
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct ProposalRationale {
-    /// Description to show a short title / something in case the link goes offline.
-    /// This is to be between 0 and 20k unicode characters.
-    /// This is mandatory for all proposals.
-    pub description: String,
-    /// Title to be used to give a short description of the proposal in lists.
-    /// This is to be between 0 and 100 unicode characters.
-    /// This is mandatory for all proposals.
-    pub title: String,
-}
-
-/// DataSourceSpecConfigurationTime is the internal data source used for emitting timestamps.
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct UpdateMarketConfiguration {
+    /// Updated futures market instrument configuration.
+    pub instrument: Option<UpdateInstrumentConfiguration>,
+    /// Optional futures market metadata, tags.
+    pub metadata: Vec<String>,
+    /// Price monitoring parameters.
+    pub price_monitoring_parameters: Option<PriceMonitoringParameters>,
+    /// Liquidity monitoring parameters.
+    pub liquidity_monitoring_parameters: Option<LiquidityMonitoringParameters>,
+    /// Updated futures market risk model parameters.
+    #[serde(flatten)]
+    pub risk_parameters: Option<RiskParameters>,
+    /// DEPRECATED: Use liquidity SLA parameters instead.
+    /// Percentage move up and down from the mid price which specifies the range of
+    /// price levels over which automated liquidity provisions will be deployed.
+    pub lp_price_range: Option<String>,
+    /// Linear slippage factor is used to cap the slippage component of maintenance margin - it is applied to the slippage volume.
+    pub linear_slippage_factor: String,
+    /// Quadratic slippage factor is used to cap the slippage component of maintenance margin - it is applied to the square of the slippage volume.
+    pub quadratic_slippage_factor: String,
+    /// Liquidity SLA parameters
+    pub liquidity_sla_parameters: Option<LiquiditySlaParameters>,
+    /// Specifies how the liquidity fee for the market will be calculated.
+    pub liquidity_fee_settings: Option<LiquidityFeeSettings>,
+    /// Liquidation strategy parameters
+    pub liquidation_strategy: Option<LiquidationStrategy>,
+    /// Mark price configuration.
+    pub mark_price_configuration: Option<CompositePriceConfiguration>,
+    /// The market tick size defines the minimum change in quote price for the market
+    pub tick_size: String,
+    /// If enabled aggressive orders sent to the market will be delayed by the configured number of blocks
+    pub enable_transaction_reordering: bool,
+}
+
+/// Updated futures market risk model parameters.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct DataSourceSpecConfigurationTime {
-    /// Conditions that the timestamps should meet in order to be considered.
-    pub conditions: Vec<DataCondition>,
+pub enum RiskParameters {
+    /// Simple risk model parameters, valid only if MODEL_SIMPLE is selected.
+    Simple(SimpleModelParams),
+    /// Log normal risk model parameters, valid only if MODEL_LOG_NORMAL is selected.
+    LogNormal(LogNormalRiskModel),
 }
 
-/// DataSourceDefinitionInternal is the top level object used for all internal data sources.
-/// It contains one of any of the defined `SourceType` variants.
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+/// Instrument configuration
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct DataSourceDefinitionInternal {
-    /// Types of internal data sources
-    pub source_type: Option<InternalSourceType>,
+pub struct UpdateInstrumentConfiguration {
+    /// Instrument code, human-readable shortcode used to describe the instrument.
+    pub code: String,
+    /// Instrument name
+    pub name: String,
+    /// Product specification.
+    #[serde(flatten)]
+    pub product: Option<UpdateProduct>,
 }
 
-/// Types of internal data sources
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+/// Product specification.
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub enum InternalSourceType {
-    Time(DataSourceSpecConfigurationTime),
+pub enum UpdateProduct {
+    /// Future.
+    Future(UpdateFutureProduct),
+    /// Perpetual.
+    Perpetual(UpdatePerpetualProduct),
 }
 
-/// DataSourceDefinitionExternal is the top level object used for all external data sources.
-/// It contains one of any of the defined `SourceType` variants.
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+/// Future product configuration
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct DataSourceDefinitionExternal {
-    /// Types of External data sources
-    pub source_type: Option<ExternalSourceType>,
+pub struct UpdateFutureProduct {
+    /// Human-readable name/abbreviation of the quote name.
+    pub quote_name: String,
+    /// The data source spec describing the data of settlement data.
+    pub data_source_spec_for_settlement_data: Option<DataSourceDefinition>,
+    /// The data source spec describing the data source for trading termination.
+    pub data_source_spec_for_trading_termination: Option<DataSourceDefinition>,
+    /// The binding between the data source spec and the settlement data.
+    pub data_source_spec_binding: Option<DataSourceSpecToFutureBinding>,
 }
 
-/// Types of External data sources
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+/// Represents the top level object that handles data sources.
+/// Data source definition can be external or internal, with whatever
+/// number of data sources are defined for each type in the child objects below.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub enum ExternalSourceType {
-    Oracle(DataSourceSpecConfiguration),
+pub enum DataSourceDefinition {
+    Internal(DataSourceDefinitionInternal),
+    External(DataSourceDefinitionExternal),
 }
 
-/// Filter describes the conditions under which a data source data is considered of
-/// interest or not.
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+/// Top level object used for all internal data sources.
+/// It contains one of any of the defined source type variants.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct DataFilter {
-    /// key is the data source data property key targeted by the filter.
-    pub key: Option<DataPropertyKey>,
-    /// conditions are the conditions that should be matched by the data to be
-    /// considered of interest.
-    pub conditions: Vec<DataCondition>,
+pub enum DataSourceDefinitionInternal {
+    Time(DataSourceSpecConfigurationTime),
+    TimeTrigger(DataSourceSpecConfigurationTimeTrigger),
 }
 
-/// PropertyKey describes the property key contained in an data source data.
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+/// Internal data source used for emitting timestamps.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct DataPropertyKey {
-    /// name is the name of the property.
-    pub name: String,
-    /// type is the type of the property.
-    pub r#type: PropertyKeyType,
+pub struct DataSourceSpecConfigurationTime {
+    /// Conditions that the timestamps should meet in order to be considered.
+    pub conditions: Vec<DataCondition>,
 }
 
 /// Condition describes the condition that must be validated by the network
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DataCondition {
-    /// comparator is the type of comparison to make on the value.
+    /// Type of comparison to make on the value.
     pub operator: DataConditionOperator,
-    /// value is used by the comparator.
+    /// Value to be compared with by the operator.
     pub value: String,
 }
 
-/// Comparator describes the type of comparison.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+/// Operator describes the type of comparison.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum DataConditionOperator {
     /// The default value
     #[serde(rename = "OPERATOR_UNSPECIFIED")]
@@ -381,743 +457,2055 @@ pub enum DataConditionOperator {
     LessThanOrEqual = 5,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+/// Internal data source used for emitting timestamps automatically using predefined intervals and conditions.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-/// Type describes the type of properties that are supported by the data source
-/// engine.
-pub enum PropertyKeyType {
-    /// The default value.
-    #[serde(rename = "TYPE_UNSPECIFIED")]
-    Unspecified = 0,
-    /// Any type.
-    #[serde(rename = "TYPE_EMPTY")]
-    Empty = 1,
-    /// Integer type.
-    #[serde(rename = "TYPE_INTEGER")]
-    Integer = 2,
-    /// String type.
-    #[serde(rename = "TYPE_STRING")]
-    String = 3,
-    /// Boolean type.
-    #[serde(rename = "TYPE_BOOLEAN")]
-    Boolean = 4,
-    /// Any floating point decimal type.
-    #[serde(rename = "TYPE_DECIMAL")]
-    Decimal = 5,
-    /// Timestamp date type.
-    #[serde(rename = "TYPE_TIMESTAMP")]
-    Timestamp = 6,
+pub struct DataSourceSpecConfigurationTimeTrigger {
+    /// Conditions that the timestamps need to meet in order to be considered.
+    pub conditions: Vec<DataCondition>,
+    /// An internal time trigger
+    pub triggers: Vec<InternalTimeTrigger>,
 }
 
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+/// Trigger for an internal time data source.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct EthAddress {
-    pub address: String,
+pub struct InternalTimeTrigger {
+    /// Trigger when the vega time is greater or equal to this time, in Unix seconds.
+    #[serde(default, with = "int64::option")]
+    pub initial: Option<i64>,
+    /// Repeat the trigger every n seconds after the initial. If no time for
+    /// initial was specified, begin repeating immediately.
+    #[serde(with = "int64")]
+    pub every: i64,
 }
 
-/// PubKey is the public key that signed this data.
-/// Different public keys coming from different sources will be further separated.
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+/// DataSourceDefinitionExternal is the top level object used for all external
+/// data sources. It contains one of any of the defined `SourceType` variants.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct PubKey {
-    pub key: String,
+pub enum DataSourceDefinitionExternal {
+    Oracle(DataSourceSpecConfiguration),
+    /// Contains the data specification that is received from Ethereum sources.
+    EthOracle(EthCallSpec),
 }
 
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+/// All types of external data sources use the same configuration set for meeting
+/// requirements in order for the data to be useful for Vega - valid signatures
+/// and matching filters.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct DataSigner {
-    pub signer: Option<DataSignerOneOff>,
+pub struct DataSourceSpecConfiguration {
+    /// Signers is the list of authorized signatures that signed the data for this
+    /// source. All the signatures in the data source data should be contained in
+    /// this external source. All the signatures in the data should be contained in
+    /// this list.
+    pub signers: Vec<DataSigner>,
+    /// Filters describes which source data are considered of interest or not for
+    /// the product (or the risk model).
+    pub filters: Vec<DataFilter>,
 }
 
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub enum DataSignerOneOff {
-    /// pubKeys is the list of authorized public keys that signed the data for this
+pub enum DataSigner {
+    /// List of authorized public keys that signed the data for this
     /// source. All the public keys in the data should be contained in these
     /// public keys.
     PubKey(PubKey),
-    /// in case of an open oracle - Ethereum address will be submitted
+    /// In case of an open oracle - Ethereum address will be submitted.
     EthAddress(EthAddress),
 }
 
-/// All types of external data sources use the same configuration set for meeting requirements
-/// in order for the data to be useful for Vega - valid signatures and matching filters.
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+/// PubKey is the public key that signed this data.
+/// Different public keys coming from different sources will be further separated.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct DataSourceSpecConfiguration {
-    /// signers is the list of authorized signatures that signed the data for this
-    /// source. All the signatures in the data source data should be contained in this
-    /// external source. All the signatures in the data should be contained in this list.
-    pub signers: Vec<DataSigner>,
-    /// filters describes which source data are considered of interest or not for
-    /// the product (or the risk model).
-    pub filters: Vec<DataFilter>,
+pub struct PubKey {
+    pub key: String,
 }
 
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub enum SourceType {
-    Internal(DataSourceDefinitionInternal),
-    External(DataSourceDefinitionExternal),
+pub struct EthAddress {
+    pub address: String,
 }
 
-/// DataSourceDefinition represents the top level object that deals with data sources.
-/// DataSourceDefinition can be external or internal, with whatever number of data sources are defined
-/// for each type in the child objects below.
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+/// Filter describes the conditions under which a data source data is considered of
+/// interest or not.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct DataSourceDefinition {
-    pub source_type: Option<SourceType>,
-}
+pub struct DataFilter {
+    /// Data source's data property key targeted by the filter.
+    pub key: Option<DataPropertyKey>,
+    /// Conditions that should be matched by the data to be
+    /// considered of interest.
+    pub conditions: Vec<DataCondition>,
+}
 
-/// Future product configuration
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+/// PropertyKey describes the property key contained in data source data.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct FutureProduct {
-    /// Asset ID for the product's settlement asset
-    pub settlement_asset: String,
-    /// Product quote name
+pub struct DataPropertyKey {
+    /// Name of the property.
+    pub name: String,
+    /// Data type of the property.
+    pub r#type: PropertyKeyType,
+    /// Optional decimal place to be be applied on the provided value
+    /// valid only for PropertyType of type DECIMAL and INTEGER
+    #[serde(default, with = "int64::option")]
+    pub number_decimal_places: Option<u64>,
+}
+
+/// Type describes the data type of properties that are supported by the data source
+/// engine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum PropertyKeyType {
+    /// The default value.
+    #[serde(rename = "TYPE_UNSPECIFIED")]
+    Unspecified = 0,
+    /// Any type.
+    #[serde(rename = "TYPE_EMPTY")]
+    Empty = 1,
+    /// Integer type.
+    #[serde(rename = "TYPE_INTEGER")]
+    Integer = 2,
+    /// String type.
+    #[serde(rename = "TYPE_STRING")]
+    String = 3,
+    /// Boolean type.
+    #[serde(rename = "TYPE_BOOLEAN")]
+    Boolean = 4,
+    /// Any floating point decimal type.
+    #[serde(rename = "TYPE_DECIMAL")]
+    Decimal = 5,
+    /// Timestamp date type.
+    #[serde(rename = "TYPE_TIMESTAMP")]
+    Timestamp = 6,
+}
+
+/// Specifies a data source that derives its content from calling a read method
+/// on an Ethereum contract.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EthCallSpec {
+    /// Ethereum address of the contract to call.
+    pub address: String,
+    /// The ABI of that contract.
+    pub abi: String,
+    /// Name of the method on the contract to call.
+    pub method: String,
+    /// List of arguments to pass to method call.
+    /// Protobuf 'Value' wraps an arbitrary JSON type that is mapped to an Ethereum
+    /// type according to the ABI.
+    pub args: Vec<serde_json::Value>,
+    /// Conditions for determining when to call the contract method.
+    pub trigger: Option<EthCallTrigger>,
+    /// Number of confirmations required before the query is considered verified
+    #[serde(with = "int64")]
+    pub required_confirmations: u64,
+    /// Filters the data returned from the contract method
+    pub filters: Vec<DataFilter>,
+    /// Normalisers are used to convert the data returned from the contract method
+    /// into a standard format. The key of the map is the name of the property,
+    /// which identifies the specific piece of data to other parts of the data
+    /// sourcing framework, for example filters. The value is a JSONPath expression
+    /// for expressing where in the contract call result the required data is
+    /// located, for example $\[0\] indicates the first result. $\[1\].price would look
+    /// in the second result returned from the contract for a structure with a key
+    /// called 'price' and use that if it exists.
+    pub normalisers: Vec<Normaliser>,
+    /// The ID of the EVM based chain which is to be used to source the oracle data.
+    #[serde(with = "int64")]
+    pub source_chain_id: u64,
+}
+
+/// Determines when the contract method should be called.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum EthCallTrigger {
+    TimeTrigger(EthTimeTrigger),
+}
+
+/// Trigger for an Ethereum call based on the Ethereum block timestamp. Can be
+/// one-off or repeating.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EthTimeTrigger {
+    /// Trigger when the Ethereum time is greater or equal to this time, in Unix
+    /// seconds.
+    #[serde(default, with = "int64::option")]
+    pub initial: Option<u64>,
+    /// Repeat the call every n seconds after the initial call. If no time for
+    /// initial call was specified, begin repeating immediately.
+    #[serde(default, with = "int64::option")]
+    pub every: Option<u64>,
+    /// If repeating, stop once Ethereum time is greater than this time, in Unix
+    /// seconds. If not set, then repeat indefinitely.
+    #[serde(default, with = "int64::option")]
+    pub until: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Normaliser {
+    pub name: String,
+    pub expression: String,
+}
+
+/// DataSourceSpecToFutureBinding describes which property of the data source data is to be
+/// used as settlement data and which to use as the trading terminated trigger
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DataSourceSpecToFutureBinding {
+    /// Name of the property in the source data that should be used as settlement data.
+    /// If it is set to "prices.BTC.value", then the Future will use the value of
+    /// this property as settlement data.
+    pub settlement_data_property: String,
+    /// Name of the property in the data source data that signals termination of trading.
+    pub trading_termination_property: String,
+}
+
+/// Perpetual product configuration
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdatePerpetualProduct {
+    /// Human-readable name/abbreviation of the quote name.
     pub quote_name: String,
-    /// The data source spec describing the data source for settlement
+    /// Controls how much the upcoming funding payment liability contributes to party's margin, in the range \[0, 1\].
+    pub margin_funding_factor: String,
+    /// Continuously compounded interest rate used in funding rate calculation, in the range \[-1, 1\].
+    pub interest_rate: String,
+    /// Lower bound for the clamp function used as part of the funding rate calculation, in the range \[-1, 1\].
+    pub clamp_lower_bound: String,
+    /// Upper bound for the clamp function used as part of the funding rate calculation, in the range \[-1, 1\].
+    pub clamp_upper_bound: String,
+    /// Data source spec describing the data source for settlement schedule.
+    pub data_source_spec_for_settlement_schedule: Option<DataSourceDefinition>,
+    /// Data source spec describing the data source for settlement.
     pub data_source_spec_for_settlement_data: Option<DataSourceDefinition>,
-    /// The external data source spec describing the data source of trading termination
-    pub data_source_spec_for_trading_termination: Option<DataSourceDefinition>,
-    /// The binding between the data source spec and the settlement data
-    pub data_source_spec_binding: Option<DataSourceSpecToFutureBinding>,
-    /// The number of decimal places implied by the settlement data (such as price) emitted by the settlement data source
-    pub settlement_data_decimals: u32,
+    /// Binding between the data source spec and the settlement data.
+    pub data_source_spec_binding: Option<DataSourceSpecToPerpetualBinding>,
+    /// Factor applied to funding-rates. This scales the impact that spot price deviations have on funding payments.
+    pub funding_rate_scaling_factor: Option<String>,
+    /// Lower bound for the funding-rate such that the funding-rate will never be lower than this value.
+    pub funding_rate_lower_bound: Option<String>,
+    /// Upper bound for the funding-rate such that the funding-rate will never be higher than this value.
+    pub funding_rate_upper_bound: Option<String>,
+    /// Configuration for the internal composite price used in funding payment calculation.
+    pub internal_composite_price_configuration: Option<CompositePriceConfiguration>,
+}
+
+/// Describes which properties of the data source data is to be
+/// used for settlement.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DataSourceSpecToPerpetualBinding {
+    /// Name of the property in the source data that should be used for settlement data.
+    /// If it is set to "prices.BTC.value" for example, then the perpetual market will use the value of
+    /// this property to get settlement data.
+    pub settlement_data_property: String,
+    /// Name of the property in the source data that should be used to determine the perpetual's settlement schedule.
+    pub settlement_schedule_property: String,
 }
 
-/// Product specification
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+/// Mark price configuration parameters.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub enum Product {
-    /// Future
-    Future(FutureProduct),
+pub struct CompositePriceConfiguration {
+    /// Decay weight used for calculation of mark price.
+    pub decay_weight: String,
+    /// Decay power used for the calculation of mark price.
+    #[serde(with = "int64")]
+    pub decay_power: u64,
+    /// Cash amount, in asset decimals, used for the calculation of the mark price from the order book.
+    pub cash_amount: String,
+    /// Weights for each composite price data source.
+    pub source_weights: Vec<String>,
+    /// For how long a price source is considered valid. One entry for each data source
+    /// such that the first is for the trade based mark price, the second is for the book based price
+    /// the third is for the first oracle, followed by more oracle data source staleness tolerance.
+    pub source_staleness_tolerance: Vec<String>,
+    /// Which method is used for the calculation of the composite price for the market.
+    pub composite_price_type: CompositePriceType,
+    /// Additional price sources to be used for internal composite price calculation.
+    pub data_sources_spec: Vec<DataSourceDefinition>,
+    /// List of each price source and its corresponding binding
+    pub data_sources_spec_binding: Vec<SpecBindingForCompositePrice>,
 }
 
-/// Instrument configuration
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum CompositePriceType {
+    #[serde(rename = "COMPOSITE_PRICE_TYPE_UNSPECIFIED")]
+    Unspecified = 0,
+    /// Composite price is calculated as a weighted average of the underlying mark prices.
+    #[serde(rename = "COMPOSITE_PRICE_TYPE_WEIGHTED")]
+    Weighted = 1,
+    /// Composite price is calculated as a median of the underlying mark prices.
+    #[serde(rename = "COMPOSITE_PRICE_TYPE_MEDIAN")]
+    Median = 2,
+    /// Composite price is calculated as the last trade price.
+    #[serde(rename = "COMPOSITE_PRICE_TYPE_LAST_TRADE")]
+    LastTrade = 3,
+}
+
+/// Describes which property of the data source data is to be
+/// used for price source.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct InstrumentConfiguration {
-    /// Instrument name
-    pub name: String,
-    /// Instrument code, human-readable shortcode used to describe the instrument
-    pub code: String,
-    /// Product specification
-    pub product: Option<Product>,
+pub struct SpecBindingForCompositePrice {
+    /// The property name of price.
+    pub price_source_property: String,
+}
+
+/// PriceMonitoringParameters contains a collection of triggers to be used for a given market
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PriceMonitoringParameters {
+    pub triggers: Vec<PriceMonitoringTrigger>,
 }
 
 /// PriceMonitoringTrigger holds together price projection horizon τ, probability level p, and auction extension duration
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PriceMonitoringTrigger {
-    /// Price monitoring projection horizon τ in seconds
+    /// Price monitoring projection horizon τ in seconds.
+    #[serde(with = "int64")]
     pub horizon: i64,
-    /// Price monitoring probability level p
+    /// Price monitoring probability level p.
     pub probability: String,
     /// Price monitoring auction extension duration in seconds should the price
     /// breach its theoretical level over the specified horizon at the specified
-    /// probability level
+    /// probability level.
+    #[serde(with = "int64")]
     pub auction_extension: i64,
 }
 
-/// PriceMonitoringParameters contains a collection of triggers to be used for a given market
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct PriceMonitoringParameters {
-    pub triggers: Vec<PriceMonitoringTrigger>,
-}
-
 /// LiquidityMonitoringParameters contains settings used for liquidity monitoring
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct LiquidityMonitoringParameters {
-    /// Specifies parameters related to target stake calculation
+    /// Specifies parameters related to target stake calculation.
     pub target_stake_parameters: Option<TargetStakeParameters>,
-    /// Specifies the triggering ratio for entering liquidity auction
-    pub triggering_ratio: f64,
-    /// Specifies by how many seconds an auction should be extended if leaving the auction were to trigger a liquidity auction
+    /// Specifies the triggering ratio for entering liquidity auction.
+    pub triggering_ratio: String,
+    /// Specifies by how many seconds an auction should be extended if leaving the auction were to trigger a liquidity auction.
+    #[serde(with = "int64")]
     pub auction_extension: i64,
 }
+
 /// TargetStakeParameters contains parameters used in target stake calculation
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TargetStakeParameters {
-    /// Specifies length of time window expressed in seconds for target stake calculation
+    /// Specifies length of time window expressed in seconds for target stake calculation.
+    #[serde(with = "int64")]
     pub time_window: i64,
-    /// Specifies scaling factors used in target stake calculation
+    /// Specifies scaling factors used in target stake calculation.
     pub scaling_factor: f64,
 }
 
-/// Configuration for a new market on Vega
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+/// Risk model parameters for simple modelling
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct NewMarketConfiguration {
-    /// New market instrument configuration
-    pub instrument: Option<InstrumentConfiguration>,
-    /// Decimal places used for the new market, sets the smallest price increment on the book
-    pub decimal_places: u64,
-    /// Optional new market meta data, tags
-    pub metadata: Vec<String>,
-    /// Price monitoring parameters
-    pub price_monitoring_parameters: Option<PriceMonitoringParameters>,
-    /// Liquidity monitoring parameters
-    pub liquidity_monitoring_parameters: Option<LiquidityMonitoringParameters>,
-    /// Decimal places for order sizes, sets what size the smallest order / position on the market can be
-    pub position_decimal_places: i64,
-    /// Percentage move up and down from the mid price which specifies the range of
-    /// price levels over which automated liquidity provision orders will be deployed
-    pub lp_price_range: String,
-    /// New market risk model parameters
-    pub risk_parameters: Option<RiskParameters>,
+pub struct SimpleModelParams {
+    /// Pre-defined risk factor value for long.
+    pub factor_long: f64,
+    /// Pre-defined risk factor value for short.
+    pub factor_short: f64,
+    /// Pre-defined maximum price move up that the model considers as valid.
+    pub max_move_up: f64,
+    /// Pre-defined minimum price move down that the model considers as valid.
+    pub min_move_down: f64,
+    /// Pre-defined constant probability of trading.
+    pub probability_of_trading: f64,
 }
 
-/// New market on Vega
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+/// Risk model for log normal
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct NewMarket {
-    /// The configuration of the new market
-    pub changes: Option<NewMarketConfiguration>,
+pub struct LogNormalRiskModel {
+    /// Risk Aversion Parameter.
+    pub risk_aversion_parameter: f64,
+    /// Tau parameter of the risk model, projection horizon measured as a year fraction used in the expected shortfall
+    /// calculation to obtain the maintenance margin, must be a strictly non-negative real number.
+    pub tau: f64,
+    /// Risk model parameters for log normal.
+    pub params: Option<LogNormalModelParams>,
+    /// And optional override for the risk factor calculated by the risk model.
+    pub risk_factor_override: Option<RiskFactorOverride>,
 }
 
-/// Update an existing market on Vega
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+/// Risk model parameters for log normal
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct UpdateMarket {
-    /// The identifier of the market to update
-    pub market_id: String,
-    /// The updated configuration of the market
-    pub changes: Option<UpdateMarketConfiguration>,
+pub struct LogNormalModelParams {
+    /// Mu parameter, annualised growth rate of the underlying asset.
+    pub mu: f64,
+    /// R parameter, annualised growth rate of the risk-free asset, used for discounting of future cash flows, can be any real number.
+    pub r: f64,
+    /// Sigma parameter, annualised volatility of the underlying asset, must be a strictly non-negative real number.
+    pub sigma: f64,
 }
 
-/// Configuration to update a market on Vega
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+/// Risk factor override to control stable leverage
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct UpdateMarketConfiguration {
-    /// Updated market instrument configuration
-    pub instrument: Option<UpdateInstrumentConfiguration>,
-    /// Optional market metadata, tags
-    pub metadata: Vec<String>,
-    /// Price monitoring parameters
-    pub price_monitoring_parameters: Option<PriceMonitoringParameters>,
-    /// Liquidity monitoring parameters
-    pub liquidity_monitoring_parameters: Option<LiquidityMonitoringParameters>,
-    /// Percentage move up and down from the mid price which specifies the range of
-    /// price levels over which automated liquidity provision orders will be deployed
-    pub lp_price_range: String,
-    /// Updated market risk model parameters
-    pub risk_parameters: Option<RiskParameters>,
+pub struct RiskFactorOverride {
+    /// Short Risk factor value.
+    pub short: String,
+    /// Long Risk factor value.
+    pub long: String,
 }
 
-/// Risk model parameters for log normal
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct LogNormalModelParams {
-    /// Mu parameter, annualised growth rate of the underlying asset
-    pub mu: f64,
-    /// R parameter, annualised growth rate of the risk-free asset, used for discounting of future cash flows, can be any real number
-    pub r: f64,
-    /// Sigma parameter, annualised volatility of the underlying asset, must be a strictly non-negative real number
-    pub sigma: f64,
+pub struct LiquiditySlaParameters {
+    /// Deprecated "providers_fee_calculation_time_step"
+    pub price_range: String,
+    /// Specifies the minimum fraction of time LPs must spend "on the book" providing their committed liquidity.
+    pub commitment_min_time_fraction: String,
+    /// Specifies the number of liquidity epochs over which past performance will continue to affect rewards.
+    #[serde(with = "int64")]
+    pub performance_hysteresis_epochs: u64,
+    /// Specifies the maximum fraction of their accrued fees an LP that meets the SLA implied by market.liquidity.commitmentMinTimeFraction will lose to liquidity providers
+    /// that achieved a higher SLA performance than them.
+    pub sla_competition_factor: String,
 }
 
-/// Risk model for log normal
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+/// Market settings that describe how the liquidity fee is calculated.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct LogNormalRiskModel {
-    /// Risk Aversion Parameter
-    pub risk_aversion_parameter: f64,
-    /// Tau parameter of the risk model, projection horizon measured as a year fraction used in the expected shortfall calculation to obtain the maintenance margin, must be a strictly non-negative real number
-    pub tau: f64,
-    /// Risk model parameters for log normal
-    pub params: Option<LogNormalModelParams>,
+pub struct LiquidityFeeSettings {
+    /// Method used to calculate the market's liquidity fee.
+    pub method: LiquidityFeeSettingsMethod,
+    /// Constant liquidity fee used when using the constant fee method.
+    pub fee_constant: Option<String>,
 }
 
-/// Updated market risk model parameters
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum LiquidityFeeSettingsMethod {
+    #[serde(rename = "METHOD_UNSPECIFIED")]
+    Unspecified = 0,
+    /// Fee is the smallest value of all bids, such that liquidity providers with nominated fees less than or equal to this value still have sufficient commitment to fulfil the market's target stake.
+    #[serde(rename = "METHOD_MARGINAL_COST")]
+    MarginalCost = 1,
+    /// Fee is the weighted average of all liquidity providers' nominated fees, weighted by their committment.
+    #[serde(rename = "METHOD_WEIGHTED_AVERAGE")]
+    WeightedAverage = 2,
+    /// Fee is set by the market to a constant value irrespective of any liquidity provider's nominated fee.
+    #[serde(rename = "METHOD_CONSTANT")]
+    Constant = 3,
+}
+
+/// Liquidation strategy used when the network holds a position resulting from position resolution.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub enum RiskParameters {
-    /// Log normal risk model parameters, valid only if MODEL_LOG_NORMAL is selected
-    LogNormal(LogNormalRiskModel),
+pub struct LiquidationStrategy {
+    /// Interval, in seconds, at which the network will attempt to close its position.
+    #[serde(with = "int64")]
+    pub disposal_time_step: i64,
+    /// Fraction of the open position the market will try to close in a single attempt; range 0 through 1.
+    pub disposal_fraction: String,
+    /// Size of the position that the network will try to close in a single attempt.
+    #[serde(with = "int64")]
+    pub full_disposal_size: u64,
+    /// Max fraction of the total volume of the orderbook, within liquidity bounds, that the network can use to close its position; range 0 through 1.
+    pub max_fraction_consumed: String,
+    /// Decimal > 0 specifying the range range above and below the mid price within which the network will trade to dispose of its position.
+    /// The value can be > 1. For example, if set to 1.5, the minimum price will be 0, ie max(0, mid_price * (1 - 1.5)), and the maximum price will be mid_price * (1 + 1.5).
+    pub disposal_slippage_range: String,
+}
+
+/// New market on Vega
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewMarket {
+    /// Configuration of the new market.
+    pub changes: Option<NewMarketConfiguration>,
 }
 
-/// Product specification
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+/// Configuration for a new futures market on Vega
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub enum UpdateProduct {
-    /// Future
-    Future(UpdateFutureProduct),
+pub struct NewMarketConfiguration {
+    /// New futures market instrument configuration.
+    pub instrument: Option<InstrumentConfiguration>,
+    /// Decimal places used for the new futures market, sets the smallest price increment on the book.
+    #[serde(with = "int64")]
+    pub decimal_places: u64,
+    /// Optional new futures market metadata, tags.
+    pub metadata: Vec<String>,
+    /// Price monitoring parameters.
+    pub price_monitoring_parameters: Option<PriceMonitoringParameters>,
+    /// Liquidity monitoring parameters.
+    pub liquidity_monitoring_parameters: Option<LiquidityMonitoringParameters>,
+    /// New futures market risk model parameters.
+    #[serde(flatten)]
+    pub risk_parameters: Option<RiskParameters>,
+    /// Decimal places for order sizes, sets what size the smallest order / position on the futures market can be.
+    #[serde(with = "int64")]
+    pub position_decimal_places: i64,
+    /// DEPRECATED: Use liquidity SLA parameters instead.
+    /// Percentage move up and down from the mid price which specifies the range of
+    /// price levels over which automated liquidity provisions will be deployed.
+    pub lp_price_range: Option<String>,
+    /// Linear slippage factor is used to cap the slippage component of maintenance margin - it is applied to the slippage volume.
+    pub linear_slippage_factor: String,
+    /// Quadratic slippage factor is used to cap the slippage component of maintenance margin - it is applied to the square of the slippage volume.
+    pub quadratic_slippage_factor: String,
+    /// Successor configuration. If this proposal is meant to succeed a given market, then this should be set.
+    pub successor: Option<SuccessorConfiguration>,
+    /// Liquidity SLA parameters
+    pub liquidity_sla_parameters: Option<LiquiditySlaParameters>,
+    /// Specifies how the liquidity fee for the market will be calculated.
+    pub liquidity_fee_settings: Option<LiquidityFeeSettings>,
+    /// Liquidation strategy for this market.
+    pub liquidation_strategy: Option<LiquidationStrategy>,
+    /// Mark price configuration.
+    pub mark_price_configuration: Option<CompositePriceConfiguration>,
+    /// The market tick size defines the minimum change in quote price for the market
+    pub tick_size: String,
+    /// If enabled aggressive orders sent to the market will be delayed by the configured number of blocks
+    pub enable_transaction_reordering: bool,
 }
 
 /// Instrument configuration
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct UpdateInstrumentConfiguration {
-    /// Instrument code, human-readable shortcode used to describe the instrument
+pub struct InstrumentConfiguration {
+    /// Instrument name.
+    pub name: String,
+    /// Instrument code, human-readable shortcode used to describe the instrument.
     pub code: String,
-    /// Product specification
-    pub product: Option<UpdateProduct>,
+    /// Product specification.
+    #[serde(flatten)]
+    pub product: Option<Product>,
 }
 
-/// DataSourceSpecToFutureBinding describes which property of the data source data is to be
-/// used as settlement data and which to use as the trading terminated trigger
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+/// Product specification.
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct DataSourceSpecToFutureBinding {
-    /// settlement_data_property holds the name of the property in the source data
-    /// that should be used as settlement data.
-    /// If it is set to "prices.BTC.value", then the Future will use the value of
-    /// this property as settlement data.
-    pub settlement_data_property: String,
-    /// the name of the property in the data source data that signals termination of trading
-    pub trading_termination_property: String,
+pub enum Product {
+    /// Future.
+    Future(FutureProduct),
+    /// Spot.
+    Spot(SpotProduct),
+    /// Perpetual.
+    Perpetual(PerpetualProduct),
 }
 
 /// Future product configuration
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct UpdateFutureProduct {
-    /// Human-readable name/abbreviation of the quote name
+pub struct FutureProduct {
+    /// Asset ID for the product's settlement asset.
+    pub settlement_asset: String,
+    /// Product quote name.
     pub quote_name: String,
-    /// The data source spec describing the data of settlement data
+    /// Data source spec describing the data source for settlement.
     pub data_source_spec_for_settlement_data: Option<DataSourceDefinition>,
-    /// The data source spec describing the data source for trading termination
+    /// The external data source spec describing the data source of trading termination.
     pub data_source_spec_for_trading_termination: Option<DataSourceDefinition>,
-    /// The binding between the data source spec and the settlement data
+    /// Binding between the data source spec and the settlement data.
     pub data_source_spec_binding: Option<DataSourceSpecToFutureBinding>,
-    /// The number of decimal places implied by the settlement data (such as price) emitted by the settlement external data source
-    pub settlement_data_decimals: u32,
+    /// If set, this product creates a capped future market.
+    pub cap: Option<FutureCap>,
 }
 
-/// Represents a network parameter on Vega
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct NetworkParameter {
-    /// The unique key
-    pub key: String,
-    /// The value for the network parameter
-    pub value: String,
+pub struct FutureCap {
+    /// Set the maximum price for orders, and settlement data in market decimals.
+    pub max_price: String,
+    /// If set to true, the settlement price must either be zero, or equal to the max price.
+    pub binary_settlement: Option<bool>,
+    /// If set to true, positions must be fully collateralised so there is no default risk for any party.
+    pub fully_collateralised: Option<bool>,
+}
+
+/// Spot product configuration
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpotProduct {
+    /// Base asset ID.
+    pub base_asset: String,
+    /// Quote asset ID.
+    pub quote_asset: String,
+}
+
+/// Perpetual product configuration
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PerpetualProduct {
+    /// Asset ID for the product's settlement asset.
+    pub settlement_asset: String,
+    /// Product quote name.
+    pub quote_name: String,
+    /// Controls how much the upcoming funding payment liability contributes to party's margin, in the range \[0, 1\].
+    pub margin_funding_factor: String,
+    /// Continuously compounded interest rate used in funding rate calculation, in the range \[-1, 1\].
+    pub interest_rate: String,
+    /// Lower bound for the clamp function used as part of the funding rate calculation, in the range \[-1, 1\].
+    pub clamp_lower_bound: String,
+    /// Upper bound for the clamp function used as part of the funding rate calculation, in the range \[-1, 1\].
+    pub clamp_upper_bound: String,
+    /// Data source spec describing the data source for settlement schedule.
+    pub data_source_spec_for_settlement_schedule: Option<DataSourceDefinition>,
+    /// Data source spec describing the data source for settlement.
+    pub data_source_spec_for_settlement_data: Option<DataSourceDefinition>,
+    /// Binding between the data source spec and the settlement data.
+    pub data_source_spec_binding: Option<DataSourceSpecToPerpetualBinding>,
+    /// Factor applied to funding-rates. This scales the impact that spot price deviations have on funding payments.
+    pub funding_rate_scaling_factor: Option<String>,
+    /// Lower bound for the funding-rate such that the funding-rate will never be lower than this value.
+    pub funding_rate_lower_bound: Option<String>,
+    /// Upper bound for the funding-rate such that the funding-rate will never be higher than this value.
+    pub funding_rate_upper_bound: Option<String>,
+    /// Composite price configuration to drive the calculation of the internal composite price used for funding payments. If undefined the default mark price of the market is used.
+    pub internal_composite_price_configuration: Option<CompositePriceConfiguration>,
+}
+
+/// Configuration required to turn a new market proposal in to a successor market proposal.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessorConfiguration {
+    /// ID of the market that the successor should take over from.
+    pub parent_market_id: String,
+    /// A decimal value between or equal to 0 and 1, specifying the fraction of the insurance pool balance that is carried over from the parent market to the successor.
+    pub insurance_pool_fraction: String,
 }
 
 /// Update network configuration on Vega
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct UpdateNetworkParameter {
-    /// The network parameter to update
+    /// The network parameter to update.
     pub changes: Option<NetworkParameter>,
 }
 
-/// An ERC20 token based asset, living on the ethereum network
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+/// Represents a network parameter on Vega
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct Erc20Asset {
-    /// The address of the contract for the token, on the ethereum network
-    pub contract_address: String,
-    /// The lifetime limits deposit per address
-    /// note: this is a temporary measure that can be changed by governance
-    pub lifetime_limit: String,
-    /// The maximum you can withdraw instantly. All withdrawals over the threshold will be delayed by the withdrawal delay.
-    /// There’s no limit on the size of a withdrawal
-    /// note: this is a temporary measure that can be changed by governance
-    pub withdraw_threshold: String,
+pub struct NetworkParameter {
+    /// Unique key of the network parameter.
+    pub key: String,
+    /// Value for the network parameter.
+    pub value: String,
 }
 
-/// The source
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+/// New asset on Vega
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub enum AssetSource {
-    /// An Ethereum ERC20 asset
-    Erc20(Erc20Asset),
+pub struct NewAsset {
+    /// Configuration of the new asset.
+    pub changes: Option<AssetDetails>,
 }
 
-/// The Vega representation of an external asset
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+/// Vega representation of an external asset
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AssetDetails {
-    /// Name of the asset (e.g: Great British Pound)
+    /// Name of the asset (e.g: Great British Pound).
     pub name: String,
-    /// Symbol of the asset (e.g: GBP)
+    /// Symbol of the asset (e.g: GBP).
     pub symbol: String,
-    /// Number of decimal / precision handled by this asset
+    /// Number of decimal / precision handled by this asset.
+    #[serde(with = "int64")]
     pub decimals: u64,
-    /// The minimum economically meaningful amount in the asset
+    /// Minimum economically meaningful amount in the asset.
     pub quantum: String,
-    /// The source
+    /// Source of the asset
+    #[serde(flatten)]
     pub source: Option<AssetSource>,
 }
 
-/// New asset on Vega
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+/// Source of the asset
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct NewAsset {
-    /// The configuration of the new asset
-    pub changes: Option<AssetDetails>,
+pub enum AssetSource {
+    /// Vega built-in asset.
+    BuiltinAsset(BuiltinAsset),
+    /// Ethereum ERC20 asset.
+    Erc20(Erc20Asset),
 }
 
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+/// Vega internal asset
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct Erc20AssetUpdate {
-    /// The lifetime limits deposit per address.
-    /// This is will be interpreted against the asset decimals.
-    /// note: this is a temporary measure that can be changed by governance
+pub struct BuiltinAsset {
+    /// Maximum amount that can be requested by a party through the built-in asset faucet at a time.
+    pub max_faucet_amount_mint: String,
+}
+
+/// ERC20 token based asset, living on the ethereum network
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Erc20Asset {
+    /// Address of the contract for the token, on the ethereum network.
+    pub contract_address: String,
+    /// Lifetime limits deposit per address
+    /// note: this is a temporary measure that can be changed by governance.
     pub lifetime_limit: String,
-    /// The maximum you can withdraw instantly. All withdrawals over the threshold will be delayed by the withdrawal delay.
+    /// Maximum you can withdraw instantly. All withdrawals over the threshold will be delayed by the withdrawal delay.
     /// There’s no limit on the size of a withdrawal
-    /// note: this is a temporary measure that can be changed by governance
+    /// note: this is a temporary measure that can be changed by governance.
     pub withdraw_threshold: String,
+    /// Chain ID the asset originated from.
+    pub chain_id: String,
 }
 
-/// The source
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+/// Freeform proposal
+/// This message is just used as a placeholder to sort out the nature of the
+/// proposal once parsed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub enum AssetUpdateSource {
-    /// An Ethereum ERC20 asset
-    Erc20(Erc20AssetUpdate),
-}
+pub struct NewFreeform {}
 
-/// The changes to apply on an existing asset.
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+/// Update an existing asset on Vega
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateAsset {
+    /// Asset ID the update is for.
+    pub asset_id: String,
+    /// Changes to apply on an existing asset.
+    pub changes: Option<AssetDetailsUpdate>,
+}
+
+/// Changes to apply on an existing asset.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AssetDetailsUpdate {
-    /// The minimum economically meaningful amount in the asset
+    /// Minimum economically meaningful amount in the asset.
     pub quantum: String,
-    /// The source
+    /// Source of the asset update
+    #[serde(flatten)]
     pub source: Option<AssetUpdateSource>,
 }
 
-/// Update an existing asset on Vega
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+/// Source of the asset update
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct UpdateAsset {
-    /// The ID of the asset to be updated
-    pub asset_id: String,
-    /// The changes to apply on an existing asset
-    pub changes: Option<AssetDetailsUpdate>,
+pub enum AssetUpdateSource {
+    /// Ethereum ERC20 asset update.
+    Erc20(Erc20AssetUpdate),
 }
 
-/// Freeform proposal
-/// This message is just used as a placeholder to sort out the nature of the
-/// proposal once parsed.
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct NewFreeform {}
+pub struct Erc20AssetUpdate {
+    /// Lifetime limits deposit per address.
+    /// This will be interpreted against the asset decimals.
+    /// note: this is a temporary measure that can be changed by governance.
+    pub lifetime_limit: String,
+    /// Maximum you can withdraw instantly. All withdrawals over the threshold will be delayed by the withdrawal delay.
+    /// There’s no limit on the size of a withdrawal
+    /// note: this is a temporary measure that can be changed by governance.
+    pub withdraw_threshold: String,
+}
 
-/// Changes being proposed via governance
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+/// New spot market on Vega
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub enum ProposalChange {
-    /// Proposal change for modifying an existing market on Vega
-    UpdateMarket(UpdateMarket),
-    /// Proposal change for creating new market on Vega
-    NewMarket(NewMarket),
-    /// Proposal change for updating Vega network parameters
-    UpdateNetworkParameter(UpdateNetworkParameter),
-    /// Proposal change for creating new assets on Vega
-    NewAsset(NewAsset),
-    /// Proposal change for a freeform request, which can be voted on but does not change the behaviour of the system,
-    /// and can be used to gauge community sentiment
-    NewFreeform(NewFreeform),
-    /// Proposal change for updating an asset
-    UpdateAsset(UpdateAsset),
+pub struct NewSpotMarket {
+    /// Configuration of the new spot market.
+    pub changes: Option<NewSpotMarketConfiguration>,
 }
 
-/// Terms for a governance proposal on Vega
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+/// Configuration for a new spot market on Vega
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct ProposalTerms {
-    /// Timestamp (Unix time in seconds) when voting closes for this proposal,
-    /// constrained by `minClose` and `maxClose` network parameters
-    pub closing_timestamp: i64,
-    /// Timestamp (Unix time in seconds) when proposal gets enacted (if passed),
-    /// constrained by `minEnact` and `maxEnact` network parameters
-    pub enactment_timestamp: i64,
-    /// Validation timestamp (Unix time in seconds)
-    pub validation_timestamp: i64,
-    /// Changes being proposed
-    pub change: Option<ProposalChange>,
+pub struct NewSpotMarketConfiguration {
+    /// New spot market instrument configuration.
+    pub instrument: Option<InstrumentConfiguration>,
+    /// Decimal places used for the new spot market, sets the smallest price increment on the book.
+    #[serde(with = "int64")]
+    pub price_decimal_places: u64,
+    /// Optional new spot market metadata, tags.
+    pub metadata: Vec<String>,
+    /// Price monitoring parameters.
+    pub price_monitoring_parameters: Option<PriceMonitoringParameters>,
+    /// Specifies parameters related to target stake calculation.
+    pub target_stake_parameters: Option<TargetStakeParameters>,
+    /// New spot market risk model parameters.
+    #[serde(flatten)]
+    pub risk_parameters: Option<RiskParameters>,
+    /// Decimal places for order sizes, sets what size the smallest order / position on the spot market can be.
+    #[serde(with = "int64")]
+    pub size_decimal_places: i64,
+    /// Specifies the liquidity provision SLA parameters.
+    pub sla_params: Option<LiquiditySlaParameters>,
+    /// Specifies how the liquidity fee for the market will be calculated.
+    pub liquidity_fee_settings: Option<LiquidityFeeSettings>,
+    /// The market tick size defines the minimum change in quote price for the market
+    pub tick_size: String,
+    /// If enabled aggressive orders sent to the market will be delayed by the configured number of blocks
+    pub enable_transaction_reordering: bool,
+}
+
+/// Update an existing spot market on Vega
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateSpotMarket {
+    /// Market ID the update is for.
+    pub market_id: String,
+    /// Updated configuration of the spot market.
+    pub changes: Option<UpdateSpotMarketConfiguration>,
 }
 
-/// A command to submit a new proposal for the
-/// Vega network governance
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+/// Configuration to update a spot market on Vega
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct ProposalSubmission {
-    /// Proposal reference
-    pub reference: String,
-    /// Proposal configuration and the actual change that is meant to be executed when proposal is enacted
-    pub terms: Option<ProposalTerms>,
-    /// The rationale behind a proposal.
-    pub rationale: Option<ProposalRationale>,
+pub struct UpdateSpotMarketConfiguration {
+    /// Optional spot market metadata, tags.
+    pub metadata: Vec<String>,
+    /// Price monitoring parameters.
+    pub price_monitoring_parameters: Option<PriceMonitoringParameters>,
+    /// Specifies parameters related to target stake calculation.
+    pub target_stake_parameters: Option<TargetStakeParameters>,
+    /// Update spot market risk model parameters.
+    #[serde(flatten)]
+    pub risk_parameters: Option<RiskParameters>,
+    /// Specifies the liquidity provision SLA parameters.
+    pub sla_params: Option<LiquiditySlaParameters>,
+    /// Specifies how the liquidity fee for the market will be calculated.
+    pub liquidity_fee_settings: Option<LiquidityFeeSettings>,
+    /// The market tick size defines the minimum change in quote price for the market
+    pub tick_size: String,
+    /// Specifies the name and code of the spot instrument.
+    pub instrument: Option<UpdateSpotInstrumentConfiguration>,
+    /// If enabled aggressive orders sent to the market will be delayed by the configured number of blocks
+    pub enable_transaction_reordering: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateSpotInstrumentConfiguration {
+    /// Instrument code, human-readable shortcode used to describe the instrument.
+    pub code: String,
+    /// Instrument name
+    pub name: String,
+}
+
+/// New governance transfer
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewTransfer {
+    /// Configuration for a new transfer.
+    pub changes: Option<NewTransferConfiguration>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewTransferConfiguration {
+    /// Source account type, such as network treasury, market insurance pool
+    pub source_type: AccountType,
+    /// If network treasury, field is empty, otherwise uses the market ID
+    pub source: String,
+    /// "All or nothing" or "best effort":
+    /// All or nothing: Transfers the specified amount or does not transfer anything
+    /// Best effort: Transfers the specified amount or the max allowable amount if this is less than the specified amount
+    pub transfer_type: GovernanceTransferType,
+    /// Maximum amount to transfer
+    pub amount: String,
+    /// ID of asset to transfer
+    pub asset: String,
+    /// Maximum fraction of the source account's balance to transfer as a decimal - i.e. 0.1 = 10% of the balance
+    pub fraction_of_balance: String,
+    /// Specifies the account type to transfer to: reward pool, party, network insurance pool, market insurance pool
+    pub destination_type: AccountType,
+    /// Specifies the account to transfer to, depending on the account type:
+    /// Network treasury: leave empty
+    /// Party: party's public key
+    /// Market insurance pool: market ID
+    pub destination: String,
+    /// Specific details of the transfer.
+    #[serde(flatten)]
+    pub kind: Option<GovernanceTransferKind>,
+}
+
+/// Specific details of the transfer.
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum GovernanceTransferKind {
+    OneOff(GovernanceOneOffTransfer),
+    Recurring(GovernanceRecurringTransfer),
+}
+
+/// Various collateral/account types as used by Vega
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum AccountType {
+    /// Default value
+    #[serde(rename = "ACCOUNT_TYPE_UNSPECIFIED")]
+    Unspecified = 0,
+    /// Insurance pool accounts contain insurance pool funds for a market
+    #[serde(rename = "ACCOUNT_TYPE_INSURANCE")]
+    Insurance = 1,
+    /// Settlement accounts exist only during settlement or mark-to-market
+    #[serde(rename = "ACCOUNT_TYPE_SETTLEMENT")]
+    Settlement = 2,
+    /// Margin accounts contain funds set aside for the margin needed to support a party's open positions.
+    /// Each party will have a margin account for each market they have traded in.
+    /// Required initial margin is allocated to each market from user's general account.
+    /// Collateral in the margin account can't be withdrawn or used as margin on another market until
+    /// it is released back to the general account.
+    /// Vega protocol uses an internal accounting system to segregate funds held as
+    /// margin from other funds to ensure they are never lost or 'double spent'
+    ///
+    /// Margin account funds will vary as margin requirements on positions change
+    #[serde(rename = "ACCOUNT_TYPE_MARGIN")]
+    Margin = 3,
+    /// General accounts contain the collateral for a party that is not otherwise allocated. A party will
+    /// have multiple general accounts, one for each asset they want
+    /// to trade with
+    ///
+    /// General accounts are where funds are initially deposited or withdrawn from,
+    /// it is also the account where funds are taken to fulfil fees and initial margin requirements
+    #[serde(rename = "ACCOUNT_TYPE_GENERAL")]
+    General = 4,
+    /// Infrastructure accounts contain fees earned by providing infrastructure on Vega
+    #[serde(rename = "ACCOUNT_TYPE_FEES_INFRASTRUCTURE")]
+    FeesInfrastructure = 5,
+    /// Liquidity accounts contain fees earned by providing liquidity on Vega markets
+    #[serde(rename = "ACCOUNT_TYPE_FEES_LIQUIDITY")]
+    FeesLiquidity = 6,
+    /// This account is created to hold fees earned by placing orders that sit on the book
+    /// and are then matched with an incoming order to create a trade - These fees reward parties
+    /// who provide the best priced liquidity that actually allows trading to take place
+    #[serde(rename = "ACCOUNT_TYPE_FEES_MAKER")]
+    FeesMaker = 7,
+    /// This account is created to maintain liquidity providers funds commitments
+    #[serde(rename = "ACCOUNT_TYPE_BOND")]
+    Bond = 9,
+    /// External account represents an external source (deposit/withdrawal)
+    #[serde(rename = "ACCOUNT_TYPE_EXTERNAL")]
+    External = 10,
+    /// Global insurance account for the asset
+    #[serde(rename = "ACCOUNT_TYPE_GLOBAL_INSURANCE")]
+    GlobalInsurance = 11,
+    /// Global reward account for the asset
+    #[serde(rename = "ACCOUNT_TYPE_GLOBAL_REWARD")]
+    GlobalReward = 12,
+    /// Per asset account used to store pending transfers (if any)
+    #[serde(rename = "ACCOUNT_TYPE_PENDING_TRANSFERS")]
+    PendingTransfers = 13,
+    /// Per asset reward account for fees paid to makers
+    #[serde(rename = "ACCOUNT_TYPE_REWARD_MAKER_PAID_FEES")]
+    RewardMakerPaidFees = 14,
+    /// Per asset reward account for fees received by makers
+    #[serde(rename = "ACCOUNT_TYPE_REWARD_MAKER_RECEIVED_FEES")]
+    RewardMakerReceivedFees = 15,
+    /// Per asset reward account for fees received by liquidity providers
+    #[serde(rename = "ACCOUNT_TYPE_REWARD_LP_RECEIVED_FEES")]
+    RewardLpReceivedFees = 16,
+    /// Per asset reward account for market proposers when the market goes above some trading threshold
+    #[serde(rename = "ACCOUNT_TYPE_REWARD_MARKET_PROPOSERS")]
+    RewardMarketProposers = 17,
+    /// Per asset account for holding in-flight unfilled orders' funds
+    #[serde(rename = "ACCOUNT_TYPE_HOLDING")]
+    Holding = 18,
+    /// Network controlled liquidity provider's account, per market, to hold accrued liquidity fees.
+    #[serde(rename = "ACCOUNT_TYPE_LP_LIQUIDITY_FEES")]
+    LpLiquidityFees = 19,
+    /// Network controlled liquidity fees bonus distribution account, per market.
+    #[serde(rename = "ACCOUNT_TYPE_LIQUIDITY_FEES_BONUS_DISTRIBUTION")]
+    LiquidityFeesBonusDistribution = 20,
+    /// Network controlled treasury
+    #[serde(rename = "ACCOUNT_TYPE_NETWORK_TREASURY")]
+    NetworkTreasury = 21,
+    /// Account holding user's rewards for the vesting period
+    #[serde(rename = "ACCOUNT_TYPE_VESTING_REWARDS")]
+    VestingRewards = 22,
+    /// Account holding user's rewards after the vesting period
+    #[serde(rename = "ACCOUNT_TYPE_VESTED_REWARDS")]
+    VestedRewards = 23,
+    /// Per asset market reward account given for relative return
+    #[serde(rename = "ACCOUNT_TYPE_REWARD_RELATIVE_RETURN")]
+    RewardRelativeReturn = 25,
+    /// Per asset market reward account given for return volatility
+    #[serde(rename = "ACCOUNT_TYPE_REWARD_RETURN_VOLATILITY")]
+    RewardReturnVolatility = 26,
+    /// Per asset market reward account given to validators by their ranking
+    #[serde(rename = "ACCOUNT_TYPE_REWARD_VALIDATOR_RANKING")]
+    RewardValidatorRanking = 27,
+    /// Per asset account for pending fee referral reward payouts
+    #[serde(rename = "ACCOUNT_TYPE_PENDING_FEE_REFERRAL_REWARD")]
+    PendingFeeReferralReward = 28,
+    /// Per asset market account for party in isolated margin mode
+    #[serde(rename = "ACCOUNT_TYPE_ORDER_MARGIN")]
+    OrderMargin = 29,
+    /// Per asset market reward account for realised return
+    #[serde(rename = "ACCOUNT_TYPE_REWARD_REALISED_RETURN")]
+    RewardRealisedReturn = 30,
+    /// Per asset account for paid buy-back fees
+    #[serde(rename = "ACCOUNT_TYPE_BUY_BACK_FEES")]
+    BuyBackFees = 31,
+    /// Per asset market reward account given for average notional
+    #[serde(rename = "ACCOUNT_TYPE_REWARD_AVERAGE_NOTIONAL")]
+    RewardAverageNotional = 32,
+    /// Reward account for the eligible entities metric.
+    #[serde(rename = "ACCOUNT_TYPE_REWARD_ELIGIBLE_ENTITIES")]
+    RewardEligibleEntities = 33,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum GovernanceTransferType {
+    #[serde(rename = "GOVERNANCE_TRANSFER_TYPE_UNSPECIFIED")]
+    Unspecified = 0,
+    #[serde(rename = "GOVERNANCE_TRANSFER_TYPE_ALL_OR_NOTHING")]
+    AllOrNothing = 1,
+    #[serde(rename = "GOVERNANCE_TRANSFER_TYPE_BEST_EFFORT")]
+    BestEffort = 2,
+}
+
+/// Specific details for a one off transfer
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GovernanceOneOffTransfer {
+    /// Timestamp in Unix nanoseconds for when the transfer should be delivered into the receiver's account.
+    #[serde(with = "int64")]
+    pub deliver_on: i64,
+}
+
+/// Specific details for a recurring transfer
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GovernanceRecurringTransfer {
+    /// First epoch from which this transfer shall be paid.
+    #[serde(with = "int64")]
+    pub start_epoch: u64,
+    /// Last epoch at which this transfer shall be paid.
+    #[serde(default, with = "int64::option")]
+    pub end_epoch: Option<u64>,
+    /// Optional parameter defining how a transfer is dispatched.
+    pub dispatch_strategy: Option<DispatchStrategy>,
+    /// Factor that the initial transfer amount is multiplied by for each epoch that it is executed.
+    /// For example if the initial transfer amount is 1000 and the factor is 0.5, then the amounts transferred per epoch will be 1000, 500, 250, 125, etc.
+    pub factor: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DispatchStrategy {
+    /// Asset to use for metric.
+    pub asset_for_metric: String,
+    /// Metric to apply.
+    pub metric: DispatchMetric,
+    /// Optional markets in scope.
+    pub markets: Vec<String>,
+    /// Mandatory enum that defines the entities within scope.
+    pub entity_scope: EntityScope,
+    /// Optional enum if the entity scope defined is for individuals, which determines the subset of individuals that are eligible to be rewarded.
+    pub individual_scope: IndividualScope,
+    /// Optional list applicable if the reward type has a scope of teams, which allows the funder to define a list of team IDs that are eligible to be rewarded from this transfer
+    pub team_scope: Vec<String>,
+    /// The proportion of the top performers in the team for a given metric to be averaged for the metric calculation if the scope is team
+    pub n_top_performers: String,
+    /// Minimum number of governance (e.g. VEGA) tokens staked for a party to be considered eligible. Defaults to 0
+    pub staking_requirement: String,
+    /// Minimum notional time-weighted averaged position required for a party to be considered eligible. Defaults to 0
+    pub notional_time_weighted_average_position_requirement: String,
+    /// Number of epochs to evaluate the metric on
+    #[serde(with = "int64")]
+    pub window_length: u64,
+    /// Number of epochs after distribution to delay vesting of rewards by
+    #[serde(with = "int64")]
+    pub lock_period: u64,
+    /// Controls how the reward is distributed between qualifying parties
+    pub distribution_strategy: DistributionStrategy,
+    /// Ordered list, using start rank, defining the rank bands and share ratio for each band. Mandatory for the rank and rank lottery distribution strategies.
+    pub rank_table: Vec<Rank>,
+    /// If set, the actual amount of rewards transferred to each public key during distribution for this transfer will be `min(calculated_reward_in_quantum, cap_reward_fee_multiple × fees_paid_this_epoch_in_quantum).
+    pub cap_reward_fee_multiple: Option<String>,
+    /// Number of epochs between transfers, i.e. when 4, funds will be transferred every 4 epochs with the first transfer occurring 4 epochs after the transaction is processed.
+    pub transfer_interval: Option<i32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum DispatchMetric {
+    #[serde(rename = "DISPATCH_METRIC_UNSPECIFIED")]
+    Unspecified = 0,
+    /// Dispatch metric that uses the total maker fees paid in the market
+    #[serde(rename = "DISPATCH_METRIC_MAKER_FEES_PAID")]
+    MakerFeesPaid = 1,
+    /// Dispatch metric that uses the total maker fees received in the market
+    #[serde(rename = "DISPATCH_METRIC_MAKER_FEES_RECEIVED")]
+    MakerFeesReceived = 2,
+    /// Dispatch metric that uses the total LP fees received in the market
+    #[serde(rename = "DISPATCH_METRIC_LP_FEES_RECEIVED")]
+    LpFeesReceived = 3,
+    /// Dispatch metric that uses total value of the market if above the required threshold and not paid given proposer bonus yet
+    #[serde(rename = "DISPATCH_METRIC_MARKET_VALUE")]
+    MarketValue = 4,
+    /// Dispatch metric that uses the relative PNL of the party in the market
+    #[serde(rename = "DISPATCH_METRIC_RELATIVE_RETURN")]
+    RelativeReturn = 6,
+    /// Dispatch metric that uses return volatility of the party in the market
+    #[serde(rename = "DISPATCH_METRIC_RETURN_VOLATILITY")]
+    ReturnVolatility = 7,
+    /// Dispatch metric that uses the validator ranking of the validator as metric
+    #[serde(rename = "DISPATCH_METRIC_VALIDATOR_RANKING")]
+    ValidatorRanking = 8,
+    /// Dispatch metric that uses the realised return of the party in a market
+    #[serde(rename = "DISPATCH_METRIC_REALISED_RETURN")]
+    RealisedReturn = 9,
+    /// Dispatch metric that uses the time weighted average notional
+    #[serde(rename = "DISPATCH_METRIC_AVERAGE_NOTIONAL")]
+    AverageNotional = 10,
+    /// Dispatch metric that uses the eligibility criteria of entities
+    #[serde(rename = "DISPATCH_METRIC_ELIGIBLE_ENTITIES")]
+    EligibleEntities = 11,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum EntityScope {
+    #[serde(rename = "ENTITY_SCOPE_UNSPECIFIED")]
+    Unspecified = 0,
+    /// Rewards must be distributed directly to eligible parties.
+    #[serde(rename = "ENTITY_SCOPE_INDIVIDUALS")]
+    Individuals = 1,
+    /// Rewards must be distributed to directly eligible teams, and then amongst team members
+    #[serde(rename = "ENTITY_SCOPE_TEAMS")]
+    Teams = 2,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum IndividualScope {
+    #[serde(rename = "INDIVIDUAL_SCOPE_UNSPECIFIED")]
+    Unspecified = 0,
+    /// All parties on the network are within the scope of this reward.
+    #[serde(rename = "INDIVIDUAL_SCOPE_ALL")]
+    All = 1,
+    /// All parties that are part of a team are within the scope of this reward.
+    #[serde(rename = "INDIVIDUAL_SCOPE_IN_TEAM")]
+    InTeam = 2,
+    /// All parties that are not part of a team are within the scope of this reward.
+    #[serde(rename = "INDIVIDUAL_SCOPE_NOT_IN_TEAM")]
+    NotInTeam = 3,
+    /// All keys representing AMMs are within the scope of this reward.
+    #[serde(rename = "INDIVIDUAL_SCOPE_AMM")]
+    Amm = 4,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum DistributionStrategy {
+    #[serde(rename = "DISTRIBUTION_STRATEGY_UNSPECIFIED")]
+    Unspecified = 0,
+    /// Rewards funded using the pro-rata strategy should be distributed pro-rata by each entity's reward metric, scaled by any active multipliers that party has.
+    #[serde(rename = "DISTRIBUTION_STRATEGY_PRO_RATA")]
+    ProRata = 1,
+    /// Rewards funded using the party rank.
+    #[serde(rename = "DISTRIBUTION_STRATEGY_RANK")]
+    Rank = 2,
+    /// Rewards funded using the ranked lottery.
+    #[serde(rename = "DISTRIBUTION_STRATEGY_RANK_LOTTERY")]
+    RankLottery = 3,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Rank {
+    pub start_rank: u32,
+    pub share_ratio: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GovernanceCancelTransfer {
+    /// Configuration for cancellation of a governance-initiated transfer
+    pub changes: Option<CancelTransferConfiguration>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelTransferConfiguration {
+    /// ID of the governance transfer proposal.
+    pub transfer_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateMarketState {
+    /// Configuration for governance-initiated change of a market's state
+    pub changes: Option<UpdateMarketStateConfiguration>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateMarketStateConfiguration {
+    /// ID of the market
+    pub market_id: String,
+    /// Type of the market update
+    pub update_type: MarketStateUpdateType,
+    /// Settlement price, relevant only for market termination for futures markets
+    pub price: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum MarketStateUpdateType {
+    /// Default value, always invalid
+    #[serde(rename = "MARKET_STATE_UPDATE_TYPE_UNSPECIFIED")]
+    Unspecified = 0,
+    /// Request to terminate a market via governance
+    #[serde(rename = "MARKET_STATE_UPDATE_TYPE_TERMINATE")]
+    Terminate = 1,
+    /// Request to suspend a market via governance
+    #[serde(rename = "MARKET_STATE_UPDATE_TYPE_SUSPEND")]
+    Suspend = 2,
+    /// Request to resume a market via governance
+    #[serde(rename = "MARKET_STATE_UPDATE_TYPE_RESUME")]
+    Resume = 3,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateReferralProgram {
+    /// Configuration for change to update a referral program.
+    pub changes: Option<ReferralProgramChanges>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReferralProgramChanges {
+    /// Defined benefit tiers in increasing order. First element will give Tier 1,
+    /// second element will give Tier 2, and so on. Determines the level of
+    /// benefit a party can expect based on performance criteria.
+    pub benefit_tiers: Vec<BenefitTier>,
+    /// Timestamp as Unix time in seconds, after which when the current epoch
+    /// ends, the program will end and benefits will be disabled.
+    #[serde(with = "int64")]
+    pub end_of_program_timestamp: i64,
+    /// Number of epochs over which to evaluate a referral set's running volume.
+    #[serde(with = "int64")]
+    pub window_length: u64,
+    /// Defined staking tiers in increasing order. First element will give Tier 1,
+    /// second element will give Tier 2, and so on. Determines the level of
+    /// benefit a party can expect based on their staking.
+    pub staking_tiers: Vec<StakingTier>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BenefitTier {
+    /// Required running notional taker volume in quantum units for parties
+    /// to access this tier.
+    pub minimum_running_notional_taker_volume: String,
+    /// Required number of epochs a party must have been in a referral set to
+    /// access this tier.
+    pub minimum_epochs: String,
+    /// deprecated
+    pub referral_reward_factor: String,
+    /// deprecated
+    pub referral_discount_factor: String,
+    /// Proportion of the referee's fees to be rewarded to the referrer.
+    pub referral_reward_factors: Option<RewardFactors>,
+    /// Referral discount factors for the various fees.
+    pub referral_discount_factors: Option<DiscountFactors>,
+    /// The tier number. It's set by the core, and used in the party fee stats API.
+    #[serde(default, with = "int64::option")]
+    pub tier_number: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RewardFactors {
+    /// Proportion of the referee's infrastructure fees to be rewarded to the referrer.
+    pub infrastructure_reward_factor: String,
+    /// Proportion of the referee's liquidity fees to be rewarded to the referrer.
+    pub liquidity_reward_factor: String,
+    /// Proportion of the maker fees to be rewarded.
+    pub maker_reward_factor: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiscountFactors {
+    /// Proportion of the referee's infrastructure fee to be discounted.
+    pub infrastructure_discount_factor: String,
+    /// Proportion of the referee's liquidity fee to be discounted.
+    pub liquidity_discount_factor: String,
+    /// Proportion of the referee's maker fee to be discounted.
+    pub maker_discount_factor: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StakingTier {
+    /// Required number of governance tokens ($VEGA) a referrer must have staked to
+    /// receive the multiplier.
+    pub minimum_staked_tokens: String,
+    /// Multiplier applied to the referral reward factor when calculating referral
+    /// rewards due to the referrer.
+    pub referral_reward_multiplier: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateVolumeDiscountProgram {
+    /// Configuration for a change to update a volume discount program
+    pub changes: Option<VolumeDiscountProgramChanges>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VolumeDiscountProgramChanges {
+    /// Defined benefit tiers in increasing order. First element will give Tier 1,
+    /// second element will give Tier 2, and so on. Determines the level of
+    /// benefit a party can expect based on performance criteria.
+    pub benefit_tiers: Vec<VolumeBenefitTier>,
+    /// Timestamp as Unix time in seconds, after which when the current epoch
+    /// ends, the program will end and benefits will be disabled.
+    #[serde(with = "int64")]
+    pub end_of_program_timestamp: i64,
+    /// Number of epochs over which to evaluate a referral set's running volume.
+    #[serde(with = "int64")]
+    pub window_length: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VolumeBenefitTier {
+    /// Required running notional taker volume in quantum units for parties
+    /// to access this tier.
+    pub minimum_running_notional_taker_volume: String,
+    /// deprecated
+    pub volume_discount_factor: String,
+    /// Proportion of the taker fees to be discounted.
+    pub volume_discount_factors: Option<DiscountFactors>,
+    /// The tier number. It's set by the core, and used in the party fee stats API.
+    #[serde(default, with = "int64::option")]
+    pub tier_number: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateVolumeRebateProgram {
+    /// Configuration for a change to update a volume rebate program
+    pub changes: Option<VolumeRebateProgramChanges>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VolumeRebateProgramChanges {
+    /// Defined benefit tiers in increasing order. First element will give Tier 1,
+    /// second element will give Tier 2, and so on. Determines the level of
+    /// benefit a party can expect based on performance criteria.
+    pub benefit_tiers: Vec<VolumeRebateBenefitTier>,
+    /// Timestamp as Unix time in seconds, after which when the current epoch
+    /// ends, the program will end and benefits will be disabled.
+    #[serde(with = "int64")]
+    pub end_of_program_timestamp: i64,
+    /// Number of epochs over which to evaluate a referral set's running volume.
+    #[serde(with = "int64")]
+    pub window_length: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VolumeRebateBenefitTier {
+    /// Fraction of a party's maker volume required for a party to access this tier.
+    pub minimum_party_maker_volume_fraction: String,
+    /// Additional rebate factor, based on the 'trade value for fee purposes', that a party at this tier will receive when they are the maker side of a trade.
+    pub additional_maker_rebate: String,
+    /// The tier number. It's set by the core, and used in the party fee stats API.
+    #[serde(default, with = "int64::option")]
+    pub tier_number: Option<u64>,
+}
+
+/// Rationale behind a proposal.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProposalRationale {
+    /// Description to show a short title / something in case the link goes offline.
+    /// This is to be between 0 and 20k unicode characters.
+    /// This is mandatory for all proposals.
+    pub description: String,
+    /// Title to be used to give a short description of the proposal in lists.
+    /// This is to be between 0 and 100 unicode characters.
+    /// This is mandatory for all proposals.
+    pub title: String,
+}
+
+/// Command that allows a token holder to vote for or against an active governance proposal.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VoteSubmission {
+    /// Submit vote for the specified proposal ID.
+    pub proposal_id: String,
+    /// Actual value of the vote.
+    pub value: VoteValue,
 }
 
 /// Vote value
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum VoteValue {
     /// Default value, always invalid
     #[serde(rename = "VALUE_UNSPECIFIED")]
     Unspecified = 0,
-    /// A vote against the proposal
+    /// Vote against the proposal
     #[serde(rename = "VALUE_NO")]
     No = 1,
-    /// A vote in favour of the proposal
+    /// Vote in favour of the proposal
     #[serde(rename = "VALUE_YES")]
     Yes = 2,
 }
 
-/// A command to submit a new vote for a governance
-/// proposal.
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+/// A command that indicates to the network the party's intention to supply liquidity to the given market and become a liquidity provider.
+/// An active liquidity provider for a market will earn fees based on the trades that occur in the market.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct VoteSubmission {
-    /// The ID of the proposal to vote for.
-    pub proposal_id: String,
-    /// The actual value of the vote
-    pub value: VoteValue,
+pub struct LiquidityProvisionSubmission {
+    /// Market that the submitter wishes to provide liquidity for.
+    pub market_id: String,
+    /// Amount that the submitter will commit as liquidity to the market, specified as a unitless number in the settlement asset of the market.
+    /// This field is an unsigned integer scaled using the asset's decimal places.
+    pub commitment_amount: String,
+    /// Nominated liquidity fee factor, which is an input to the calculation of taker fees on the market, as per setting fees and rewarding liquidity providers.
+    pub fee: String,
+    /// Arbitrary reference to be added to every order created out of this liquidity provision submission.
+    pub reference: String,
 }
 
-/// A command to submit an instruction to delegate some stake to a node
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+/// Command to allow a token holder to delegate their tokens to a validator to help secure the network.
+/// A token holder delegating to a validator will earn rewards based on the amount they have delegated, and the performance of the chosen validator.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DelegateSubmission {
-    /// The ID for the node to delegate to
+    /// Node ID to delegate stake to.
     pub node_id: String,
-    /// The amount of stake to delegate
+    /// Amount of stake to delegate, as an unsigned integer scaled to the governance asset's decimal places.
     pub amount: String,
 }
 
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+/// Command to allow a token holder to instruct the network to remove their delegated stake from a given validator node.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct UndelegateSubmission {
+    /// Node ID to undelegate stake from.
     pub node_id: String,
-    /// optional, if not specified = ALL
+    /// Amount to undelegate, as an unsigned integer scaled to the governance asset's decimal places.
+    /// If not set, then all delegations to the given validator node will be removed.
     pub amount: String,
+    /// Method of delegation.
     pub method: UndelegateMethod,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum UndelegateMethod {
+    #[serde(rename = "METHOD_UNSPECIFIED")]
+    Unspecified = 0,
+    /// Undelegate straight away, losing all rewards for the current epoch.
+    #[serde(rename = "METHOD_NOW")]
+    Now = 1,
+    /// Undelegate at the end of an epoch, retaining all rewards for the current epoch.
+    #[serde(rename = "METHOD_AT_END_OF_EPOCH")]
+    AtEndOfEpoch = 2,
+}
+
+/// Command that allows a liquidity provider to inform the network that they will stop providing liquidity for a market.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LiquidityProvisionCancellation {
+    /// Market that the submitter will stop providing liquidity for.
+    pub market_id: String,
+}
+
+/// Command that allows a liquidity provider to update the details of their existing liquidity commitment.
+/// Any field that is left unset or as a default value indicates that this field on the original submission will be left unchanged.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LiquidityProvisionAmendment {
+    /// Market that the submitter wants to amend the liquidity commitment for.
+    pub market_id: String,
+    /// New commitment amount.
+    pub commitment_amount: String,
+    /// New nominated liquidity fee factor.
+    pub fee: String,
+    /// New arbitrary reference to be added to every order created out of this liquidity provision submission.
+    pub reference: String,
+}
+
+/// Command that allows a party to move assets from one account to another.
+/// A transfer can be set up as a single one-off transfer, or a recurring transfer that occurs once at the start of each epoch.
+/// Each transfer incurs a fee as specified by the network parameter `transfer.fee.factor`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Transfer {
+    /// Account type from which the funds of the party should be taken.
+    pub from_account_type: AccountType,
+    /// Public key of the destination account.
+    pub to: String,
+    /// Type of the destination account.
+    pub to_account_type: AccountType,
+    /// Asset ID of the asset to be transferred.
+    pub asset: String,
+    /// Amount to be taken from the source account, as an unsigned integer scaled to the asset's decimal places.
+    pub amount: String,
+    /// Reference to be attached to the transfer.
+    pub reference: String,
+    /// AMM key from which assets are to be transferred, if applicable.
+    /// The submitter of the transaction must be the owner of this AMM key.
+    /// If provided, the 'from_account_type' must be REWARDS_VESTED, and the asset in this account
+    /// must match the asset specified in the transfer.
+    pub from: Option<String>,
+    /// Specific details of the transfer.
+    #[serde(flatten)]
+    pub kind: Option<TransferKind>,
+}
+
+/// Specific details of the transfer.
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TransferKind {
+    /// Details of a one-off transfer that is executed once at a specified time.
+    OneOff(OneOffTransfer),
+    /// Details of a transfer that is executed once every epoch until stopped.
+    Recurring(RecurringTransfer),
+}
+
+/// Details for a one-off transfer.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OneOffTransfer {
+    /// Timestamp, in Unix nanoseconds, for when the transfer should be executed, i.e., assets transferred into the receiver's account.
+    #[serde(with = "int64")]
+    pub deliver_on: i64,
+}
+
+/// Details for a recurring transfer
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecurringTransfer {
+    /// First epoch from which this transfer shall be executed.
+    #[serde(with = "int64")]
+    pub start_epoch: u64,
+    /// Last epoch at which this transfer shall be executed.
+    #[serde(default, with = "int64::option")]
+    pub end_epoch: Option<u64>,
+    /// Factor that the initial transfer amount is multiplied by for each epoch that it is executed.
+    /// For example if the initial transfer amount is 1000 and the factor is 0.5, then the amounts transferred per epoch will be 1000, 500, 250, 125, etc.
+    pub factor: String,
+    /// Optional parameter defining how a transfer is dispatched.
+    pub dispatch_strategy: Option<DispatchStrategy>,
+}
+
+/// Command that can be used by the party that initiated a transfer to instruct the network to stop an active recurring transaction.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelTransfer {
+    /// Transfer ID of the transfer to cancel.
+    pub transfer_id: String,
+}
+
+/// A command that allows a new node operator to announce themselves to the network as a new validator.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnnounceNode {
+    /// Vega public key of the node being announced.
+    pub vega_pub_key: String,
+    /// Ethereum public key of the node being announced.
+    pub ethereum_address: String,
+    /// Public key for the blockchain, currently the node's CometBFT key.
+    pub chain_pub_key: String,
+    /// URL to the node operators homepage allowing stake holders to make an informed decision when delegating.
+    pub info_url: String,
+    /// Country code (ISO 3166-1 alpha-2) for the location of the node.
+    pub country: String,
+    /// Node ID of the validator, which is the node's public master key.
+    pub id: String,
+    /// Human-readable name of the node.
+    pub name: String,
+    /// URL to the node operator's avatar.
+    pub avatar_url: String,
+    /// Vega public key derivation index.
+    pub vega_pub_key_index: u32,
+    /// Epoch from which the node is expected to be ready to validate blocks.
+    #[serde(with = "int64")]
+    pub from_epoch: u64,
+    /// Signature from the node made using the ethereum wallet.
+    pub ethereum_signature: Option<Signature>,
+    /// Signature from the node made using the Vega wallet.
+    pub vega_signature: Option<Signature>,
+    /// Ethereum public key to use as a submitter to allow automatic signature generation.
+    pub submitter_address: String,
+}
+
+/// Signature definition that allows the network to authenticate external data.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Signature {
+    /// Hex encoded bytes of the signature.
+    pub value: String,
+    /// Algorithm used to create the signature.
+    pub algo: String,
+    /// Version of the algorithm used to create the signature.
+    pub version: u32,
+}
+
+/// A command that allows the submission of a batch market instruction which wraps up multiple market instructions into a single transaction.
+/// These instructions are then processed sequentially in the following order:
+/// - OrderCancellation
+/// - OrderAmendment
+/// - OrderSubmission
+/// - StopOrderSubmission
+///
+/// where the maximum allow of instructions in a batch is controlled by the network parameter "spam.protection.max.batchSize".
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchMarketInstructions {
+    /// List of order cancellations to be processed sequentially.
+    pub cancellations: Vec<OrderCancellation>,
+    /// List of order amendments to be processed sequentially.
+    pub amendments: Vec<OrderAmendment>,
+    /// List of order submissions to be processed sequentially.
+    pub submissions: Vec<OrderSubmission>,
+    /// List of stop order cancellations to be processed sequentially.
+    pub stop_orders_cancellation: Vec<StopOrdersCancellation>,
+    /// List of stop order submissions to be processed sequentially.
+    pub stop_orders_submission: Vec<StopOrdersSubmission>,
+    /// Update margin mode instruction
+    pub update_margin_mode: Vec<UpdateMarginMode>,
+}
+
+/// A command that instructs the network to cancel untriggered stop orders that were submitted by the sender of this transaction.
+/// If any cancelled stop order is part of an OCO, both stop orders will be cancelled.
+/// It is not possible to cancel another party's stop orders with this command.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StopOrdersCancellation {
+    /// Restrict cancellations to those submitted to the given market. If not set, all stop orders across all markets will be cancelled.
+    pub market_id: Option<String>,
+    /// Restrict cancellations to a stop order with the given ID. If set, then a market ID must also be provided.
+    pub stop_order_id: Option<String>,
+}
+
+/// A command that allows a party to submit a stop order for a given market.
+/// A stop order is a normal order that remains off the order book and is only submitted if a given trigger is breached from a particular direction.
+/// If both rises-above and falls-below are configured, then if one is triggered the other will be cancelled (OCO).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StopOrdersSubmission {
+    /// Stop order that will be triggered if the price rises above a given trigger price.
+    pub rises_above: Option<StopOrderSetup>,
+    /// Stop order that will be triggered if the price falls below a given trigger price.
+    pub falls_below: Option<StopOrderSetup>,
+}
+
+/// Price and expiry configuration for a stop order.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StopOrderSetup {
+    /// Order to be submitted once the trigger is breached.
+    pub order_submission: Option<OrderSubmission>,
+    /// Timestamp, in Unix nanoseconds, for when the stop order should expire. If not set the stop order will not expire.
+    #[serde(default, with = "int64::option")]
+    pub expires_at: Option<i64>,
+    /// Strategy to adopt if the expiry time is reached.
+    pub expiry_strategy: Option<StopOrderExpiryStrategy>,
+    /// Indicates if this order is linked to an order or position to derive the order size
+    pub size_override_setting: Option<StopOrderSizeOverrideSetting>,
+    /// If this order is linked to a position, provide an optional scaling factor
+    pub size_override_value: Option<StopOrderSizeOverrideValue>,
+    /// Trigger that will need to be breached for the order to be submitted to the book.
+    #[serde(flatten)]
+    pub trigger: Option<StopOrderTrigger>,
+}
+
+/// Trigger that will need to be breached for the order to be submitted to the book.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum StopOrderTrigger {
+    /// Order will be submitted if the last traded price on the market breaches the given price.
+    Price(String),
+    /// Order will be submitted if the last traded price has moved the given percent from the highest/lowest mark price since the stop order was submitted.
+    TrailingPercentOffset(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum StopOrderExpiryStrategy {
+    /// Never valid
+    #[serde(rename = "EXPIRY_STRATEGY_UNSPECIFIED")]
+    Unspecified = 0,
+    /// Stop order should be cancelled if the expiry time is reached.
+    #[serde(rename = "EXPIRY_STRATEGY_CANCELS")]
+    Cancels = 1,
+    /// Order should be submitted if the expiry time is reached.
+    #[serde(rename = "EXPIRY_STRATEGY_SUBMIT")]
+    Submit = 2,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum StopOrderSizeOverrideSetting {
+    /// Never valid
+    #[serde(rename = "SIZE_OVERRIDE_SETTING_UNSPECIFIED")]
+    Unspecified = 0,
+    /// No override, the size within the contained normal order submission will be used
+    #[serde(rename = "SIZE_OVERRIDE_SETTING_NONE")]
+    None = 1,
+    /// Use the total position of the trader
+    #[serde(rename = "SIZE_OVERRIDE_SETTING_POSITION")]
+    Position = 2,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StopOrderSizeOverrideValue {
+    /// Scaling percentage of the current position’s size
+    pub percentage: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateMarginMode {
+    /// Market to change margin mode for.
+    pub market_id: String,
+    /// Margin mode to use.
+    pub mode: MarginMode,
+    /// Margin factor to use for margin in isolated mode. It is a multiplier that defines how much margin needs to be set aside
+    pub margin_factor: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum MarginMode {
+    /// Never valid.
+    #[serde(rename = "MODE_UNSPECIFIED")]
+    Unspecified = 0,
+    /// Cross margin mode - margin is dynamically acquired and released as a position is marked to market
+    #[serde(rename = "MODE_CROSS_MARGIN")]
+    CrossMargin = 1,
+    /// Isolated margin mode - margin for any newly opened position volume is transferred to the margin account when the trade is executed
+    #[serde(rename = "MODE_ISOLATED_MARGIN")]
+    IsolatedMargin = 2,
+}
+
+/// Command that a party can use to instruct the network to create a new referral set on the network.
+/// The submitter of this command will become the referrer of the new set and cannot be the referrer or a referee of another set.
+/// A referrer can use the referral set ID as a referral code to attract others to the Vega network and have fees reduced for the referral set.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateReferralSet {
+    /// Whether or not the referral set should be considered a team that can participate in team games on the network.
+    pub is_team: bool,
+    /// Team details, if the referral set is to be considered a team.
+    pub team: Option<CreateReferralSetTeam>,
+    /// Should a referral set be created as well or only a team.
+    /// the default is false so the existing behaviour is kept if older versions of the protobufs are used.
+    pub do_not_create_referral_set: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateReferralSetTeam {
+    /// Name of the team.
+    pub name: String,
+    /// External link to the team's homepage.
+    pub team_url: Option<String>,
+    /// External link to an avatar for the team.
+    pub avatar_url: Option<String>,
+    /// Whether or not the team is closed to new party members.
+    pub closed: bool,
+    /// List of public keys that are allowed to join the team.
+    /// Only applicable to closed teams. Removing a party from the allow list does not remove
+    /// the party from the team.
+    pub allow_list: Vec<String>,
+}
+
+/// A command that allows the referrer of a referral set to update team details for a referral set.
+/// Any field that is left unset or has a default value indicates that this field on the original referral set will be left unchanged.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateReferralSet {
+    /// ID of the referral set to update.
+    pub id: String,
+    /// Whether or not the referral set should be considered a team that can participate in team games on the network.
+    pub is_team: bool,
+    /// Team details, if the referral set is to be considered a team.
+    pub team: Option<UpdateReferralSetTeam>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateReferralSetTeam {
+    /// New name of the team.
+    pub name: Option<String>,
+    /// New link to the team's homepage.
+    pub team_url: Option<String>,
+    /// New link to an avatar for the team.
+    pub avatar_url: Option<String>,
+    /// Whether or not the team is closed to new party members. When closed, only parties specified in the allow list can
+    /// join the team.
+    pub closed: Option<bool>,
+    /// List of public keys that are allowed to join the team.
+    /// Only applicable to closed teams. Removing a party from the allow list does not remove
+    /// the party from the team.
+    pub allow_list: Vec<String>,
+}
+
+/// Command that allows the submitter to join a referral set and earn a collective reduction in fees based on the activity of all members of that set.
+/// A party that joins a referral set is called a referee. A referee can only be a member of one referral set and cannot themselves be or become a referrer.
+/// To switch to another referral set, a subsequent command can be sent and the switch will take effect at the end of the epoch.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApplyReferralCode {
+    /// Referral code, normally the referral set ID, for the party to join.
+    pub id: String,
+    /// Should the key applying the referral code also join the team.
+    /// the default is false so the existing behaviour is kept if older versions of the protobufs are used.
+    pub do_not_join_team: bool,
+}
+
+/// Command that allows the submitter to join a team or change teams if they are already a member of a team.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JoinTeam {
+    /// ID of the team to join, this is the same as the referral code used to generate the team.
+    pub id: String,
+}
+
+/// Command that allows a token holder to submit a batch governance proposal that can be voted on by any other token holders, and eventually enacted on the Vega network.
+/// For example this command can be used to propose a new market and a network parameter change with it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchProposalSubmission {
+    /// Arbitrary human-readable reference identifying the proposal.
+    pub reference: String,
+    /// Proposal terms containing the type and details of the proposal, as well as time spans for voting and enactment.
+    pub terms: Option<BatchProposalSubmissionTerms>,
+    /// Rationale behind a proposal.
+    pub rationale: Option<ProposalRationale>,
+}
+
+/// Terms for a batch governance proposal submission
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub enum UndelegateMethod {
-    #[serde(rename = "METHOD_UNSPECIFIED")]
-    Unspecified = 0,
-    #[serde(rename = "METHOD_NOW")]
-    Now = 1,
-    #[serde(rename = "METHOD_AT_END_OF_EPOCH")]
-    AtEndOfEpoch = 2,
+pub struct BatchProposalSubmissionTerms {
+    /// Closing timestamp in Unix time; adheres to `minClose` and `maxClose` limits.
+    #[serde(with = "int64")]
+    pub closing_timestamp: i64,
+    /// List of individual changes included in the batch proposal.
+    pub changes: Vec<BatchProposalTermsChange>,
 }
 
-/// Various collateral/account types as used by Vega
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+/// Terms change for a batch governance proposal
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub enum AccountType {
-    /// Default value
-    #[serde(rename = "ACCOUNT_TYPE_UNSPECIFIED")]
-    Unspecified = 0,
-    /// Insurance pool accounts contain insurance pool funds for a market
-    #[serde(rename = "ACCOUNT_TYPE_INSURANCE")]
-    Insurance = 1,
-    /// Settlement accounts exist only during settlement or mark-to-market
-    #[serde(rename = "ACCOUNT_TYPE_SETTLEMENT")]
-    Settlement = 2,
-    /// Margin accounts contain funds set aside for the margin needed to support a party's open positions.
-    /// Each party will have a margin account for each market they have traded in.
-    /// The required initial margin is allocated to each market from your general account.
-    /// Collateral in the margin account can't be withdrawn or used as margin on another market until
-    /// it is released back to the general account.
-    /// The Vega protocol uses an internal accounting system to segregate funds held as
-    /// margin from other funds to ensure they are never lost or 'double spent'
-    ///
-    /// Margin account funds will vary as margin requirements on positions change
-    #[serde(rename = "ACCOUNT_TYPE_MARGIN")]
-    Margin = 3,
-    /// General accounts contain the collateral for a party that is not otherwise allocated. A party will
-    /// have multiple general accounts, one for each asset they want
-    /// to trade with
-    ///
-    /// General accounts are where funds are initially deposited or withdrawn from,
-    /// it is also the account where funds are taken to fulfil fees and initial margin requirements
-    #[serde(rename = "ACCOUNT_TYPE_GENERAL")]
-    General = 4,
-    /// Infrastructure accounts contain fees earned by providing infrastructure on Vega
-    #[serde(rename = "ACCOUNT_TYPE_FEES_INFRASTRUCTURE")]
-    FeesInfrastructure = 5,
-    /// Liquidity accounts contain fees earned by providing liquidity on Vega markets
-    #[serde(rename = "ACCOUNT_TYPE_FEES_LIQUIDITY")]
-    FeesLiquidity = 6,
-    /// This account is created to hold fees earned by placing orders that sit on the book
-    /// and are then matched with an incoming order to create a trade - These fees reward parties
-    /// who provide the best priced liquidity that actually allows trading to take place
-    #[serde(rename = "ACCOUNT_TYPE_FEES_MAKER")]
-    FeesMaker = 7,
-    /// This account is created to maintain liquidity providers funds commitments
-    #[serde(rename = "ACCOUNT_TYPE_BOND")]
-    Bond = 9,
-    /// External account represents an external source (deposit/withdrawal)
-    #[serde(rename = "ACCOUNT_TYPE_EXTERNAL")]
-    External = 10,
-    /// Global insurance account for the asset
-    #[serde(rename = "ACCOUNT_TYPE_GLOBAL_INSURANCE")]
-    GlobalInsurance = 11,
-    /// Global reward account for the asset
-    #[serde(rename = "ACCOUNT_TYPE_GLOBAL_REWARD")]
-    GlobalReward = 12,
-    /// Per asset account used to store pending transfers (if any)
-    #[serde(rename = "ACCOUNT_TYPE_PENDING_TRANSFERS")]
-    PendingTransfers = 13,
-    /// Per asset reward account for fees paid to makers
-    #[serde(rename = "ACCOUNT_TYPE_REWARD_MAKER_PAID_FEES")]
-    RewardMakerPaidFees = 14,
-    /// Per asset reward account for fees received by makers
-    #[serde(rename = "ACCOUNT_TYPE_REWARD_MAKER_RECEIVED_FEES")]
-    RewardMakerReceivedFees = 15,
-    /// Per asset reward account for fees received by liquidity providers
-    #[serde(rename = "ACCOUNT_TYPE_REWARD_LP_RECEIVED_FEES")]
-    RewardLpReceivedFees = 16,
-    /// Per asset reward account for market proposers when the market goes above some trading threshold
-    #[serde(rename = "ACCOUNT_TYPE_REWARD_MARKET_PROPOSERS")]
-    RewardMarketProposers = 17,
+pub struct BatchProposalTermsChange {
+    /// Timestamp as Unix time in seconds when proposal terms gets enacted if proposal passed the vote,
+    /// constrained by `minEnact` and `maxEnact` network parameters.
+    #[serde(with = "int64")]
+    pub enactment_timestamp: i64,
+    /// Validation timestamp as Unix time in seconds.
+    #[serde(with = "int64")]
+    pub validation_timestamp: i64,
+    /// Changes being proposed.
+    #[serde(flatten)]
+    pub change: Option<ProposalChange>,
 }
 
-/// A transfer initiated by a party
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+/// Command to associate metadata to a public key, known as a party ID.
+/// Partial update is not supported, meaning previous values must be included in
+/// the update, otherwise they are removed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct Transfer {
-    /// The account type from which the funds of the party
-    /// should be taken
-    pub from_account_type: AccountType,
-    /// The public key of the destination account
-    pub to: String,
-    /// The type of the destination account
-    pub to_account_type: AccountType,
-    /// The asset
-    pub asset: String,
-    /// The amount to be taken from the source account
-    pub amount: String,
-    /// The reference to be attached to the transfer
-    pub reference: String,
-    /// Specific details of the transfer
-    pub kind: Option<TransferKind>,
+pub struct UpdatePartyProfile {
+    /// Alias given to the party. It must be unique network-wide.
+    pub alias: String,
+    /// Freeform data to associate to the party.
+    /// Support a maximum of 10 entries.
+    pub metadata: Vec<Metadata>,
 }
 
-/// Specific details for a one off transfer
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+/// Generic structure holding a key/value pair.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct OneOffTransfer {
-    /// A unix timestamp in second. Time at which the
-    /// transfer should be delivered in the to account
-    pub deliver_on: i64,
+pub struct Metadata {
+    /// Key of the metadata.
+    pub key: String,
+    /// Value of the metadata.
+    pub value: String,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
-pub enum DispatchMetric {
-    #[serde(rename = "DISPATCH_METRIC_UNSPECIFIED")]
-    Unspecified = 0,
-    /// Dispatch metric that is using the total maker fees paid in the market
-    #[serde(rename = "DISPATCH_METRIC_MAKER_FEES_PAID")]
-    MakerFeesPaid = 1,
-    /// Dispatch metric that is using the total maker fees received in the market
-    #[serde(rename = "DISPATCH_METRIC_MAKER_FEES_RECEIVED")]
-    MakerFeesReceived = 2,
-    /// Dispatch metric that is using the total LP fees received in the market
-    #[serde(rename = "DISPATCH_LP_FEES_RECEIVED")]
-    LpFeesReceived = 3,
-    /// Dispatch metric that is using total value of the market if above the required threshold and not paid given proposer bonus yet
-    #[serde(rename = "DISPATCH_METRIC_MARKET_VALUE")]
-    MarketValue = 4,
+/// Command to create an automated market maker for a given market.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubmitAmm {
+    /// Market ID for which to create an AMM.
+    pub market_id: String,
+    /// Amount to be committed to the AMM.
+    pub commitment_amount: String,
+    /// Slippage tolerance used for rebasing the AMM if its base price crosses with existing order
+    pub slippage_tolerance: String,
+    /// Concentrated liquidity parameters defining the shape of the AMM's volume curves.
+    pub concentrated_liquidity_parameters: Option<SubmitAmmConcentratedLiquidityParameters>,
+    /// Nominated liquidity fee factor, which is an input to the calculation of taker fees on the market.
+    pub proposed_fee: String,
 }
 
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+/// Liquidity parameters that define the size and range of the AMM's tradeable volume.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct DispatchStrategy {
-    /// The asset to use for metric
-    pub asset_for_metric: String,
-    /// The metric to apply
-    pub metric: DispatchMetric,
-    /// Optional markets in scope
-    pub markets: Vec<String>,
+pub struct SubmitAmmConcentratedLiquidityParameters {
+    /// Price at which the AMM will stop quoting sell volume. If not supplied the AMM will never hold a short position.
+    pub upper_bound: Option<String>,
+    /// Price at which the AMM will stop quoting buy volume. If not supplied the AMM will never hold a long position.
+    pub lower_bound: Option<String>,
+    /// Price that the AMM will quote as its "fair price" when its position is zero.
+    pub base: String,
+    /// Leverage at upper bound. If not set the markets risk-factors will be used to calculate leverage.
+    pub leverage_at_upper_bound: Option<String>,
+    /// Leverage at lower bound. If not set the markets risk-factors will be used to calculate leverage.
+    pub leverage_at_lower_bound: Option<String>,
 }
 
-/// Specific details for a recurring transfer
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+/// Command to amend an existing automated market maker on a market.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct RecurringTransfer {
-    /// The first epoch from which this transfer shall be paid
-    pub start_epoch: u64,
-    /// The last epoch at which this transfer shall be paid
-    pub end_epoch: ::core::option::Option<u64>,
-    /// factor needs to be > 0
-    pub factor: String,
-    /// optional parameter defining how a transfer is dispatched
-    pub dispatch_strategy: Option<DispatchStrategy>,
+pub struct AmendAmm {
+    /// Market ID for the AMM to be amended.
+    pub market_id: String,
+    /// Amount to be committed to the AMM. If not supplied the commitment will remain unchanged.
+    pub commitment_amount: Option<String>,
+    /// Slippage tolerance for rebasing position when updating the AMM.
+    pub slippage_tolerance: String,
+    /// Concentrated liquidity parameters defining the shape of the AMM's volume curves. If not supplied the parameters will remain unchanged.
+    pub concentrated_liquidity_parameters: Option<AmendAmmConcentratedLiquidityParameters>,
+    /// Nominated liquidity fee factor, which is an input to the calculation of taker fees on the market. If not supplied the proposed fee will remain unchanged.
+    pub proposed_fee: Option<String>,
 }
 
-/// Specific details of the transfer
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+/// Liquidity parameters that define the size and range of the AMM's tradeable volume.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub enum TransferKind {
-    OneOff(OneOffTransfer),
-    Recurring(RecurringTransfer),
+pub struct AmendAmmConcentratedLiquidityParameters {
+    /// Price at which the AMM will stop quoting sell volume. If not supplied the AMM will never hold a short position.
+    pub upper_bound: Option<String>,
+    /// Price at which the AMM will stop quoting buy volume. If not supplied the AMM will never hold a long position.
+    pub lower_bound: Option<String>,
+    /// Price that the AMM will quote as its "fair price" when its position is zero.
+    pub base: String,
+    /// Leverage at upper bound. If not set the markets risk-factors will be used to calculate leverage.
+    pub leverage_at_upper_bound: Option<String>,
+    /// Leverage at lower bound. If not set the markets risk-factors will be used to calculate leverage.
+    pub leverage_at_lower_bound: Option<String>,
 }
 
-/// A request for cancelling a recurring transfer
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+/// Command to cancel an automated market maker for a given market.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct CancelTransfer {
-    /// The ID of the transfer to cancel
-    pub transfer_id: String,
+pub struct CancelAmm {
+    /// Market ID to cancel an AMM for.
+    pub market_id: String,
+    /// Method to use to cancel the AMM.
+    pub method: CancelAmmMethod,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum CancelAmmMethod {
+    #[serde(rename = "METHOD_UNSPECIFIED")]
+    Unspecified = 0,
+    /// Cancellation will be immediate and any open positions will be transferred to the network for liquidation.
+    #[serde(rename = "METHOD_IMMEDIATE")]
+    Immediate = 1,
+    /// AMM will only trade to reduce its position, and will be cancelled once its position reaches zero.
+    #[serde(rename = "METHOD_REDUCE_ONLY")]
+    ReduceOnly = 2,
 }
 
-/// Command to submit new Oracle data from third party providers
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+/// Command to submit new oracle data from third party providers
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct OracleDataSubmission {
-    /// The source from which the data is coming from. Must be base64 encoded.
-    /// Oracle data a type of external data source data.
+    /// Source from which the data is coming from.
     pub source: OracleSource,
-    /// The data provided by the data source
-    /// In the case of Open Oracle - it will be the entire object - it will contain messages, signatures and price data
+    /// Data provided by the data source.
+    #[serde(with = "base64_bytes")]
     pub payload: Vec<u8>,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+/// Supported oracle sources
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum OracleSource {
-    /// The default value
+    /// Default value
     #[serde(rename = "ORACLE_SOURCE_UNSPECIFIED")]
     Unspecified = 0,
-    /// Specifies that the payload will be base64 encoded JSON conforming to the Open Oracle standard
+    /// Specifies that the payload will be base64 encoded JSON conforming to the Open Oracle standard.
     #[serde(rename = "ORACLE_SOURCE_OPEN_ORACLE")]
     OpenOracle = 1,
-    /// Specifies that the payload will be base64 encoded JSON, but does not specify the shape of the data
+    /// Specifies that the payload will be base64 encoded JSON, but does not specify the shape of the data.
     #[serde(rename = "ORACLE_SOURCE_JSON")]
     Json = 2,
+    /// Specifies that the payload will be base64 encoded JSON conforming to the ETH standard.
+    #[serde(rename = "ORACLE_SOURCE_ETHEREUM")]
+    Ethereum = 3,
 }
 
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+/// A command the wallet can sign and send, serialized as the
+/// `command` field of `vega.commands.v1.InputData`.
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum Command {
-    BatchMarketInstructions(BatchMarketInstructions),
+    /// Command to submit an order.
     OrderSubmission(OrderSubmission),
+    /// Command to cancel an order.
     OrderCancellation(OrderCancellation),
+    /// Command to amend an order.
     OrderAmendment(OrderAmendment),
-    LiquidityProvisionSubmission(LiquidityProvisionSubmission),
-    LiquidityProvisionCancellation(LiquidityProvisionCancellation),
-    LiquidityProvisionAmendment(LiquidityProvisionAmendment),
+    /// Command to submit a withdrawal.
     WithdrawSubmission(WithdrawSubmission),
+    /// Command to submit a governance proposal.
     ProposalSubmission(ProposalSubmission),
+    /// Command to submit a vote on a governance proposal.
     VoteSubmission(VoteSubmission),
-    DelegateSubmisson(DelegateSubmission),
+    /// Command to submit a liquidity commitment.
+    LiquidityProvisionSubmission(LiquidityProvisionSubmission),
+    /// Command to delegate tokens to a validator.
+    DelegateSubmission(DelegateSubmission),
+    /// Command to remove tokens delegated to a validator.
     UndelegateSubmission(UndelegateSubmission),
+    /// Command to cancel a liquidity commitment.
+    LiquidityProvisionCancellation(LiquidityProvisionCancellation),
+    /// Command to amend a liquidity commitment.
+    LiquidityProvisionAmendment(LiquidityProvisionAmendment),
+    /// Command to submit a transfer.
     Transfer(Transfer),
+    /// Command to cancel a recurring transfer.
     CancelTransfer(CancelTransfer),
+    /// Command used by a node operator to announce its node as a pending validator.
+    AnnounceNode(AnnounceNode),
+    /// Command to submit a batch of order instructions.
+    BatchMarketInstructions(BatchMarketInstructions),
+    /// Command to submit a pair of stop orders.
+    StopOrdersSubmission(StopOrdersSubmission),
+    /// Command to cancel stop orders.
+    StopOrdersCancellation(StopOrdersCancellation),
+    /// Command to create a referral set.
+    CreateReferralSet(CreateReferralSet),
+    /// Command to update a referral set.
+    UpdateReferralSet(UpdateReferralSet),
+    /// Command to apply a referral code.
+    ApplyReferralCode(ApplyReferralCode),
+    /// Command to update the margin mode of a party in a market.
+    UpdateMarginMode(UpdateMarginMode),
+    /// Command to join a team.
+    JoinTeam(JoinTeam),
+    /// Command to submit a batch governance proposal.
+    BatchProposalSubmission(BatchProposalSubmission),
+    /// Command to update a party's profile.
+    UpdatePartyProfile(UpdatePartyProfile),
+    /// Command to submit an AMM pool to a market
+    SubmitAmm(SubmitAmm),
+    /// Command to amend an AMM pool on a market
+    AmendAmm(AmendAmm),
+    /// Command to cancel an AMM pool on a market
+    CancelAmm(CancelAmm),
+    /// Command to submit external oracle data.
     OracleDataSubmission(OracleDataSubmission),
 }
 
-impl From<BatchMarketInstructions> for Command {
-    fn from(cmd: BatchMarketInstructions) -> Self {
-        Command::BatchMarketInstructions(cmd)
-    }
-}
-
 impl From<OrderSubmission> for Command {
     fn from(cmd: OrderSubmission) -> Self {
         Command::OrderSubmission(cmd)
@@ -1136,12 +2524,42 @@ impl From<OrderAmendment> for Command {
     }
 }
 
+impl From<WithdrawSubmission> for Command {
+    fn from(cmd: WithdrawSubmission) -> Self {
+        Command::WithdrawSubmission(cmd)
+    }
+}
+
+impl From<ProposalSubmission> for Command {
+    fn from(cmd: ProposalSubmission) -> Self {
+        Command::ProposalSubmission(cmd)
+    }
+}
+
+impl From<VoteSubmission> for Command {
+    fn from(cmd: VoteSubmission) -> Self {
+        Command::VoteSubmission(cmd)
+    }
+}
+
 impl From<LiquidityProvisionSubmission> for Command {
     fn from(cmd: LiquidityProvisionSubmission) -> Self {
         Command::LiquidityProvisionSubmission(cmd)
     }
 }
 
+impl From<DelegateSubmission> for Command {
+    fn from(cmd: DelegateSubmission) -> Self {
+        Command::DelegateSubmission(cmd)
+    }
+}
+
+impl From<UndelegateSubmission> for Command {
+    fn from(cmd: UndelegateSubmission) -> Self {
+        Command::UndelegateSubmission(cmd)
+    }
+}
+
 impl From<LiquidityProvisionCancellation> for Command {
     fn from(cmd: LiquidityProvisionCancellation) -> Self {
         Command::LiquidityProvisionCancellation(cmd)
@@ -1154,33 +2572,99 @@ impl From<LiquidityProvisionAmendment> for Command {
     }
 }
 
-impl From<WithdrawSubmission> for Command {
-    fn from(cmd: WithdrawSubmission) -> Self {
-        Command::WithdrawSubmission(cmd)
+impl From<Transfer> for Command {
+    fn from(cmd: Transfer) -> Self {
+        Command::Transfer(cmd)
     }
 }
 
-impl From<ProposalSubmission> for Command {
-    fn from(cmd: ProposalSubmission) -> Self {
-        Command::ProposalSubmission(cmd)
+impl From<CancelTransfer> for Command {
+    fn from(cmd: CancelTransfer) -> Self {
+        Command::CancelTransfer(cmd)
     }
 }
 
-impl From<VoteSubmission> for Command {
-    fn from(cmd: VoteSubmission) -> Self {
-        Command::VoteSubmission(cmd)
+impl From<AnnounceNode> for Command {
+    fn from(cmd: AnnounceNode) -> Self {
+        Command::AnnounceNode(cmd)
     }
 }
 
-impl From<DelegateSubmission> for Command {
-    fn from(cmd: DelegateSubmission) -> Self {
-        Command::DelegateSubmisson(cmd)
+impl From<BatchMarketInstructions> for Command {
+    fn from(cmd: BatchMarketInstructions) -> Self {
+        Command::BatchMarketInstructions(cmd)
     }
 }
 
-impl From<UndelegateSubmission> for Command {
-    fn from(cmd: UndelegateSubmission) -> Self {
-        Command::UndelegateSubmission(cmd)
+impl From<StopOrdersSubmission> for Command {
+    fn from(cmd: StopOrdersSubmission) -> Self {
+        Command::StopOrdersSubmission(cmd)
+    }
+}
+
+impl From<StopOrdersCancellation> for Command {
+    fn from(cmd: StopOrdersCancellation) -> Self {
+        Command::StopOrdersCancellation(cmd)
+    }
+}
+
+impl From<CreateReferralSet> for Command {
+    fn from(cmd: CreateReferralSet) -> Self {
+        Command::CreateReferralSet(cmd)
+    }
+}
+
+impl From<UpdateReferralSet> for Command {
+    fn from(cmd: UpdateReferralSet) -> Self {
+        Command::UpdateReferralSet(cmd)
+    }
+}
+
+impl From<ApplyReferralCode> for Command {
+    fn from(cmd: ApplyReferralCode) -> Self {
+        Command::ApplyReferralCode(cmd)
+    }
+}
+
+impl From<UpdateMarginMode> for Command {
+    fn from(cmd: UpdateMarginMode) -> Self {
+        Command::UpdateMarginMode(cmd)
+    }
+}
+
+impl From<JoinTeam> for Command {
+    fn from(cmd: JoinTeam) -> Self {
+        Command::JoinTeam(cmd)
+    }
+}
+
+impl From<BatchProposalSubmission> for Command {
+    fn from(cmd: BatchProposalSubmission) -> Self {
+        Command::BatchProposalSubmission(cmd)
+    }
+}
+
+impl From<UpdatePartyProfile> for Command {
+    fn from(cmd: UpdatePartyProfile) -> Self {
+        Command::UpdatePartyProfile(cmd)
+    }
+}
+
+impl From<SubmitAmm> for Command {
+    fn from(cmd: SubmitAmm) -> Self {
+        Command::SubmitAmm(cmd)
+    }
+}
+
+impl From<AmendAmm> for Command {
+    fn from(cmd: AmendAmm) -> Self {
+        Command::AmendAmm(cmd)
+    }
+}
+
+impl From<CancelAmm> for Command {
+    fn from(cmd: CancelAmm) -> Self {
+        Command::CancelAmm(cmd)
     }
 }
 
@@ -1189,3 +2673,142 @@ impl From<OracleDataSubmission> for Command {
         Command::OracleDataSubmission(cmd)
     }
 }
+
+/// Serialize 64 bits integers as strings, as in proto3 JSON, numbers
+/// are accepted as well when deserializing.
+mod int64 {
+    use serde::{de, Deserialize, Deserializer, Serializer};
+    use std::fmt::Display;
+    use std::str::FromStr;
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Int64<T> {
+        Number(T),
+        String(String),
+    }
+
+    impl<T: FromStr> Int64<T>
+    where
+        T::Err: Display,
+    {
+        fn value<E: de::Error>(self) -> Result<T, E> {
+            return match self {
+                Int64::Number(n) => Ok(n),
+                Int64::String(s) => s.parse().map_err(de::Error::custom),
+            };
+        }
+    }
+
+    pub fn serialize<T: Display, S: Serializer>(n: &T, s: S) -> Result<S::Ok, S::Error> {
+        return s.collect_str(n);
+    }
+
+    pub fn deserialize<'de, T, D>(d: D) -> Result<T, D::Error>
+    where
+        T: FromStr + Deserialize<'de>,
+        T::Err: Display,
+        D: Deserializer<'de>,
+    {
+        return Int64::deserialize(d)?.value();
+    }
+
+    pub mod option {
+        use super::Int64;
+        use serde::{Deserialize, Deserializer, Serializer};
+        use std::fmt::Display;
+        use std::str::FromStr;
+
+        pub fn serialize<T: Display, S: Serializer>(
+            n: &Option<T>,
+            s: S,
+        ) -> Result<S::Ok, S::Error> {
+            return match n {
+                Some(n) => s.serialize_some(&n.to_string()),
+                None => s.serialize_none(),
+            };
+        }
+
+        pub fn deserialize<'de, T, D>(d: D) -> Result<Option<T>, D::Error>
+        where
+            T: FromStr + Deserialize<'de>,
+            T::Err: Display,
+            D: Deserializer<'de>,
+        {
+            return Option::<Int64<T>>::deserialize(d)?
+                .map(Int64::value)
+                .transpose();
+        }
+    }
+}
+
+/// Serialize bytes fields as base64 strings, as in proto3 JSON.
+mod base64_bytes {
+    use base64::Engine;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(bytes: &[u8], s: S) -> Result<S::Ok, S::Error> {
+        return s.serialize_str(&base64::engine::general_purpose::STANDARD.encode(bytes));
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Vec<u8>, D::Error> {
+        let s = String::deserialize(d)?;
+        return base64::engine::general_purpose::STANDARD
+            .decode(s)
+            .map_err(serde::de::Error::custom);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema;
+    use serde_json::json;
+
+    #[test]
+    fn commands_match_protobuf_definitions() {
+        let pool = schema::pool();
+        let input_data = pool
+            .get_message_by_name("vega.commands.v1.InputData")
+            .unwrap();
+        // user commands, the validator commands live in the 2000 range
+        let commands = input_data
+            .fields()
+            .filter(|f| schema::oneof(f).is_some_and(|o| o.name() == "command"))
+            .filter(|f| (1000..2000).contains(&f.number()) || f.number() == 3001)
+            .collect::<Vec<_>>();
+        assert!(commands.len() > 20);
+
+        for f in commands {
+            let message = f.kind().as_message().unwrap().clone();
+            for n in 0..schema::variants(&pool) {
+                let sample = json!({ f.json_name(): schema::sample(&message, n) });
+                let cmd: Command = serde_json::from_value(sample.clone())
+                    .unwrap_or_else(|e| panic!("{} sample {}: {}", message.full_name(), n, e));
+                assert_eq!(
+                    serde_json::to_value(&cmd).unwrap(),
+                    sample,
+                    "{}",
+                    message.full_name()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn oneof_members_are_flattened() {
+        let transfer = Transfer {
+            from_account_type: AccountType::General,
+            to: "party".to_string(),
+            to_account_type: AccountType::General,
+            asset: "asset".to_string(),
+            amount: "100".to_string(),
+            reference: "".to_string(),
+            from: None,
+            kind: Some(TransferKind::OneOff(OneOffTransfer { deliver_on: 0 })),
+        };
+        let value = serde_json::to_value(Command::from(transfer)).unwrap();
+        assert_eq!(value["transfer"]["oneOff"], json!({"deliverOn": "0"}));
+        assert_eq!(value["transfer"]["fromAccountType"], "ACCOUNT_TYPE_GENERAL");
+    }
+}
@@ -1,24 +1,49 @@
-use reqwest;
-use response::{SendResponse, SignResponse};
+#![allow(clippy::needless_return)]
+
+use response::{
+    ChainIdResponse, CheckResponse, PermissionsResponse, SendResponse, SignMessageResponse,
+    SignResponse,
+};
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 use std::error::Error as StdError;
 use std::fmt;
+use std::sync::RwLock;
 
+pub mod admin;
 pub mod commands;
+#[cfg(test)]
+mod mock;
 mod request;
 pub mod response;
+#[cfg(test)]
+mod schema;
+
+pub use admin::AdminClient;
+pub use request::SendingMode;
 
 pub struct WalletClient {
     clt: reqwest::Client,
     endpoints: Endpoints,
     pubkey: String,
+    sending_mode: SendingMode,
+    token: RwLock<Option<String>>,
+    /// Whether the token was obtained through `client.connect_wallet`,
+    /// and can be renewed by connecting again once expired
+    interactive: bool,
 }
 
 #[derive(Debug)]
 pub enum Error {
     ReqwestError(reqwest::Error),
     WalletError(response::WalletError),
+    MissingResult,
+    /// The wallet rejected the token, it may have expired
+    Unauthorized,
+    /// The wallet approved the connection without returning a token
+    MissingToken,
+    /// No key with this name in the wallet
+    KeyNotFound(String),
 }
 
 impl fmt::Display for Error {
@@ -50,21 +75,25 @@ impl Error {
                 "wallet error: code({}), message({}), data({})",
                 e.code, e.message, e.data
             ),
+            MissingResult => "wallet response has neither a result nor an error".into(),
+            Unauthorized => "the wallet token is invalid or expired".into(),
+            MissingToken => "the wallet did not return a token".into(),
+            KeyNotFound(name) => format!("no key named {} in the wallet", name),
         }
     }
 }
 
+const TOKEN_PREFIX: &str = "VWT ";
+
 struct Endpoints {
-    pub token_header: String,
     pub base_url: String,
     pub health: String,
     pub request: String,
 }
 
 impl Endpoints {
-    pub fn new(base_url: &str, token: &str) -> Endpoints {
+    pub fn new(base_url: &str) -> Endpoints {
         return Endpoints {
-            token_header: format!("VWT {}", token),
             base_url: base_url.to_string(),
             health: format!("{}/api/v2/health", base_url),
             request: format!("{}/api/v2/requests", base_url),
@@ -73,17 +102,30 @@ impl Endpoints {
 }
 
 trait WalletHeaders {
-    fn wallet_headers(self, endpoints: &Endpoints) -> reqwest::RequestBuilder;
+    fn wallet_headers(self, endpoints: &Endpoints, token: Option<&str>) -> reqwest::RequestBuilder;
 }
 
 impl WalletHeaders for reqwest::RequestBuilder {
-    fn wallet_headers(self, endpoints: &Endpoints) -> reqwest::RequestBuilder {
-        return self
-            .header("Origin", &endpoints.base_url)
-            .header("Authorization", &endpoints.token_header);
+    fn wallet_headers(self, endpoints: &Endpoints, token: Option<&str>) -> reqwest::RequestBuilder {
+        let rb = self.header("Origin", &endpoints.base_url);
+        return match token {
+            Some(t) => rb.header("Authorization", format!("{}{}", TOKEN_PREFIX, t)),
+            None => rb,
+        };
     }
 }
 
+// the token returned by the wallet in the Authorization header, if any
+fn response_token(resp: &reqwest::Response) -> Option<String> {
+    return resp
+        .headers()
+        .get(reqwest::header::AUTHORIZATION)
+        .and_then(|h| h.to_str().ok())
+        .and_then(|h| h.strip_prefix(TOKEN_PREFIX))
+        .filter(|t| !t.is_empty())
+        .map(|t| t.to_string());
+}
+
 impl WalletClient {
     pub async fn new(
         wallet_address: &str,
@@ -92,62 +134,518 @@ impl WalletClient {
     ) -> Result<WalletClient, Error> {
         let w = WalletClient {
             clt: reqwest::Client::new(),
-            endpoints: Endpoints::new(wallet_address, token),
+            endpoints: Endpoints::new(wallet_address),
             pubkey: pubkey.to_string(),
+            sending_mode: SendingMode::default(),
+            token: RwLock::new(Some(token.to_string())),
+            interactive: false,
         };
 
         w.check_health().await?;
         return Ok(w);
     }
 
+    /// Connect to the wallet without a pre-existing API token, the user
+    /// is prompted by the wallet to approve the connection, and the
+    /// session token is renewed the same way once it expires.
+    pub async fn connect(wallet_address: &str, pubkey: &str) -> Result<WalletClient, Error> {
+        let w = WalletClient {
+            clt: reqwest::Client::new(),
+            endpoints: Endpoints::new(wallet_address),
+            pubkey: pubkey.to_string(),
+            sending_mode: SendingMode::default(),
+            token: RwLock::new(None),
+            interactive: true,
+        };
+
+        w.check_health().await?;
+        w.connect_wallet().await?;
+        return Ok(w);
+    }
+
+    /// The token currently used to authenticate against the wallet.
+    pub fn token(&self) -> Option<String> {
+        return self.token.read().unwrap().clone();
+    }
+
     pub async fn check_health(&self) -> Result<(), Error> {
         let _ = self.clt.get(&self.endpoints.health).send().await?;
         return Ok(());
     }
 
+    /// Send a transaction signed by the default key.
     pub async fn send<C: Into<commands::Command>>(&self, cmd: C) -> Result<SendResponse, Error> {
+        return self.send_with(cmd, &self.pubkey, self.sending_mode).await;
+    }
+
+    /// Send a transaction signed by the given key, using the given sending mode.
+    pub async fn send_with<C: Into<commands::Command>>(
+        &self,
+        cmd: C,
+        pubkey: &str,
+        mode: SendingMode,
+    ) -> Result<SendResponse, Error> {
         return self
             .request::<_, response::SendResponse>(request::Request::new_send_transaction(
                 cmd.into(),
-                &self.pubkey,
+                pubkey,
+                mode,
             ))
             .await;
     }
 
     pub async fn sign<C: Into<commands::Command>>(&self, cmd: C) -> Result<SignResponse, Error> {
+        return self.sign_with(cmd, &self.pubkey).await;
+    }
+
+    /// Sign a transaction with the given key.
+    pub async fn sign_with<C: Into<commands::Command>>(
+        &self,
+        cmd: C,
+        pubkey: &str,
+    ) -> Result<SignResponse, Error> {
         return self
             .request::<_, response::SignResponse>(request::Request::new_sign_transaction(
                 cmd.into(),
-                &self.pubkey,
+                pubkey,
             ))
             .await;
     }
 
+    /// Check a transaction against the network without sending it.
+    pub async fn check<C: Into<commands::Command>>(&self, cmd: C) -> Result<CheckResponse, Error> {
+        return self.check_with(cmd, &self.pubkey).await;
+    }
+
+    /// Same as `check` with the given key.
+    pub async fn check_with<C: Into<commands::Command>>(
+        &self,
+        cmd: C,
+        pubkey: &str,
+    ) -> Result<CheckResponse, Error> {
+        return self
+            .request::<_, response::CheckResponse>(request::Request::new_check_transaction(
+                cmd.into(),
+                pubkey,
+            ))
+            .await;
+    }
+
+    /// Sign an arbitrary message, the signature can be verified
+    /// with `vega_crypto::PublicKey::verify`.
+    pub async fn sign_message(&self, message: &[u8]) -> Result<SignMessageResponse, Error> {
+        return self.sign_message_with(message, &self.pubkey).await;
+    }
+
+    /// Same as `sign_message` with the given key.
+    pub async fn sign_message_with(
+        &self,
+        message: &[u8],
+        pubkey: &str,
+    ) -> Result<SignMessageResponse, Error> {
+        return self
+            .request::<_, response::SignMessageResponse>(request::Request::new_sign_message(
+                message, pubkey,
+            ))
+            .await;
+    }
+
+    /// The public key used when none is given.
+    pub fn pubkey(&self) -> &str {
+        return &self.pubkey;
+    }
+
+    /// Change the public key used when none is given.
+    pub fn set_pubkey(&mut self, pubkey: &str) {
+        self.pubkey = pubkey.to_string();
+    }
+
+    /// Change the sending mode used by `send`, `TYPE_SYNC` by default.
+    pub fn set_sending_mode(&mut self, mode: SendingMode) {
+        self.sending_mode = mode;
+    }
+
+    /// Find a key of the wallet by its name.
+    pub async fn find_key(&self, name: &str) -> Result<response::Key, Error> {
+        return self
+            .list_keys()
+            .await?
+            .keys
+            .into_iter()
+            .find(|k| k.name == name)
+            .ok_or_else(|| Error::KeyNotFound(name.to_string()));
+    }
+
+    /// Use the key with the given name as the default key.
+    pub async fn select_key(&mut self, name: &str) -> Result<(), Error> {
+        self.pubkey = self.find_key(name).await?.public_key;
+        return Ok(());
+    }
+
     pub async fn list_keys(&self) -> Result<response::KeysResponse, Error> {
         return self
             .request::<_, response::KeysResponse>(request::Request::new_list_keys())
             .await;
     }
 
+    pub async fn get_chain_id(&self) -> Result<ChainIdResponse, Error> {
+        return self
+            .request::<_, response::ChainIdResponse>(request::Request::new_get_chain_id())
+            .await;
+    }
+
+    pub async fn get_permissions(&self) -> Result<PermissionsResponse, Error> {
+        return self
+            .request::<_, response::PermissionsResponse>(request::Request::new_get_permissions())
+            .await;
+    }
+
+    /// Ask the wallet to connect this application, the user
+    /// may be prompted to approve the connection. The session token
+    /// returned by the wallet replaces the current one.
+    pub async fn connect_wallet(&self) -> Result<(), Error> {
+        let resp = self
+            .clt
+            .post(&self.endpoints.request)
+            .json(&request::Request::new_connect_wallet())
+            .wallet_headers(&self.endpoints, None)
+            .send()
+            .await?;
+
+        let token = response_token(&resp);
+        rpc::<()>(resp).await?;
+        *self.token.write().unwrap() = Some(token.ok_or(Error::MissingToken)?);
+        return Ok(());
+    }
+
+    /// End the connection with the wallet, the token can't be used anymore.
+    pub async fn disconnect_wallet(&self) -> Result<(), Error> {
+        self.request::<_, ()>(request::Request::new_disconnect_wallet())
+            .await?;
+        *self.token.write().unwrap() = None;
+        return Ok(());
+    }
+
     async fn request<REQ, RES>(&self, req: REQ) -> Result<RES, Error>
     where
         REQ: Serialize,
         RES: DeserializeOwned,
     {
+        return match self.try_request(&req).await {
+            // the session expired, ask the user to approve a new one
+            Err(Error::Unauthorized) if self.interactive => {
+                self.connect_wallet().await?;
+                self.try_request(&req).await
+            }
+            res => res,
+        };
+    }
+
+    async fn try_request<REQ, RES>(&self, req: &REQ) -> Result<RES, Error>
+    where
+        REQ: Serialize,
+        RES: DeserializeOwned,
+    {
+        let token = self.token();
         let resp = self
             .clt
             .post(&self.endpoints.request)
-            .json(&req)
-            .wallet_headers(&self.endpoints)
+            .json(req)
+            .wallet_headers(&self.endpoints, token.as_deref())
             .send()
             .await?;
 
-        let resp_json = resp.json::<response::Response<RES>>().await?;
-
-        if let Some(e) = resp_json.error {
-            return Err(e.into());
+        if resp.status() == reqwest::StatusCode::UNAUTHORIZED {
+            return Err(Error::Unauthorized);
         }
+        // the wallet may rotate the token on any response
+        if let Some(t) = response_token(&resp) {
+            *self.token.write().unwrap() = Some(t);
+        }
+
+        return rpc(resp).await;
+    }
+}
+
+/// Unwrap the result or error of a JSON-RPC response.
+async fn rpc<RES: DeserializeOwned>(resp: reqwest::Response) -> Result<RES, Error> {
+    let resp_json = resp.json::<response::Response<RES>>().await?;
+
+    if let Some(e) = resp_json.error {
+        return Err(e.into());
+    }
+
+    return match resp_json.result {
+        Some(result) => Ok(result),
+        // some methods have no result, e.g: disconnect_wallet
+        None => RES::deserialize(serde_json::Value::Null).map_err(|_| Error::MissingResult),
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use commands::{VoteSubmission, VoteValue};
+    use mock::{MockWallet, Reply};
+    use serde_json::json;
+
+    const TOKEN: &str = "yf7loKt70Tgq4GXyoAcm68HUav5cwewbh9MYvvVDk4ARgyJD4CSl4cGtc6xmiJTA";
+    const PUB_KEY: &str = "6545621b8a3f398db322a4acc68c1b59fd284ab010e157e5aa887a6f55d94eba";
+    const OTHER_PUB_KEY: &str = "053a10c3e8aa92bcfae80b61845a23a4dfc88d94a31570e3c494da9f43b64ca0";
+
+    fn transaction() -> serde_json::Value {
+        return json!({
+            "input_data": "CNaH",
+            "signature": {"value": "ab", "algo": "vega/ed25519", "version": 1},
+            "From": {"PubKey": PUB_KEY},
+            "version": 3,
+            "pow": {"tid": "AB", "nonce": 42},
+        });
+    }
+
+    fn vote() -> VoteSubmission {
+        return VoteSubmission {
+            proposal_id: "p1".to_string(),
+            value: VoteValue::Yes,
+        };
+    }
+
+    async fn client() -> (MockWallet, WalletClient) {
+        let mock = MockWallet::start(|r| match r.method.as_str() {
+            "client.get_chain_id" => Reply::ok(json!({"chainID": "vega-testnet-0001"})),
+            "client.get_permissions" => Reply::ok(json!({"permissions": {"publicKeys": "read"}})),
+            "client.list_keys" => Reply::ok(json!({"keys": [
+                {"name": "Key 1", "publicKey": PUB_KEY},
+                {"name": "Key 2", "publicKey": OTHER_PUB_KEY},
+            ]})),
+            "client.sign_transaction" => Reply::ok(json!({"transaction": transaction()})),
+            "client.sign_message" => Reply::ok(json!({"signature": "AQID"})),
+            "client.check_transaction" | "client.send_transaction" => Reply::ok(json!({
+                "receivedAt": "2023-01-01T00:00:00Z",
+                "sentAt": "2023-01-01T00:00:01Z",
+                "transactionHash": "ABCD",
+                "transaction": transaction(),
+            })),
+            "client.connect_wallet" => {
+                Reply::ok(json!(null)).header("Authorization", "VWT session")
+            }
+            "client.disconnect_wallet" => Reply::ok(json!(null)),
+            _ => Reply::err(-32601, "Method not found", "unknown method"),
+        })
+        .await;
+        let clt = WalletClient::new(&mock.url, TOKEN, PUB_KEY).await.unwrap();
+        return (mock, clt);
+    }
+
+    #[tokio::test]
+    async fn test_get_chain_id() {
+        let (mock, clt) = client().await;
+        assert_eq!(
+            clt.get_chain_id().await.unwrap().chain_id,
+            "vega-testnet-0001"
+        );
 
-        return Ok(resp_json.result.unwrap());
+        let received = mock.received();
+        assert_eq!(received.len(), 1);
+        assert_eq!(received[0].path, "/api/v2/requests");
+        assert_eq!(
+            received[0].headers["authorization"],
+            format!("VWT {}", TOKEN)
+        );
+        assert_eq!(received[0].params, json!(null));
+    }
+
+    #[tokio::test]
+    async fn test_get_permissions() {
+        let (_mock, clt) = client().await;
+        let resp = clt.get_permissions().await.unwrap();
+        assert_eq!(resp.permissions.public_keys, response::AccessMode::Read);
+    }
+
+    #[tokio::test]
+    async fn test_list_keys() {
+        let (_mock, clt) = client().await;
+        let resp = clt.list_keys().await.unwrap();
+        assert_eq!(resp.keys.len(), 2);
+        assert_eq!(resp.keys[0].public_key, PUB_KEY);
+    }
+
+    #[tokio::test]
+    async fn test_select_key() {
+        let (mock, mut clt) = client().await;
+        assert_eq!(
+            clt.find_key("Key 2").await.unwrap().public_key,
+            OTHER_PUB_KEY
+        );
+        assert!(matches!(
+            clt.find_key("Key 3").await,
+            Err(Error::KeyNotFound(name)) if name == "Key 3"
+        ));
+
+        clt.select_key("Key 2").await.unwrap();
+        assert_eq!(clt.pubkey(), OTHER_PUB_KEY);
+        clt.sign(vote()).await.unwrap();
+        clt.sign_with(vote(), PUB_KEY).await.unwrap();
+
+        let received = mock.received();
+        assert_eq!(received[3].params["publicKey"], OTHER_PUB_KEY);
+        assert_eq!(received[4].params["publicKey"], PUB_KEY);
+    }
+
+    #[tokio::test]
+    async fn test_sending_mode() {
+        let (mock, mut clt) = client().await;
+        clt.send_with(vote(), OTHER_PUB_KEY, SendingMode::Commit)
+            .await
+            .unwrap();
+        clt.set_sending_mode(SendingMode::Async);
+        clt.send(vote()).await.unwrap();
+
+        let received = mock.received();
+        assert_eq!(received[0].params["sendingMode"], "TYPE_COMMIT");
+        assert_eq!(received[0].params["publicKey"], OTHER_PUB_KEY);
+        assert_eq!(received[1].params["sendingMode"], "TYPE_ASYNC");
+        assert_eq!(received[1].params["publicKey"], PUB_KEY);
+    }
+
+    #[tokio::test]
+    async fn test_sign_message() {
+        let (mock, clt) = client().await;
+        let resp = clt.sign_message(b"hello").await.unwrap();
+        assert_eq!(resp.decode().unwrap(), vec![1, 2, 3]);
+        assert_eq!(
+            mock.received()[0].params,
+            json!({"publicKey": PUB_KEY, "encodedMessage": "aGVsbG8="})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_check_transaction() {
+        let (mock, clt) = client().await;
+        let resp = clt.check(vote()).await.unwrap();
+        assert_eq!(resp.transaction.pow.nonce, 42);
+
+        let params = &mock.received()[0].params;
+        assert_eq!(params["publicKey"], PUB_KEY);
+        assert_eq!(params["transaction"]["voteSubmission"]["proposalId"], "p1");
+        assert!(params.get("sendingMode").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_send_transaction() {
+        let (mock, clt) = client().await;
+        let resp = clt.send(vote()).await.unwrap();
+        assert_eq!(resp.transaction_hash, "ABCD");
+        assert_eq!(mock.received()[0].params["sendingMode"], "TYPE_SYNC");
+    }
+
+    #[tokio::test]
+    async fn test_connect_disconnect_wallet() {
+        let (mock, clt) = client().await;
+        clt.connect_wallet().await.unwrap();
+        clt.disconnect_wallet().await.unwrap();
+        let methods = mock
+            .received()
+            .iter()
+            .map(|r| r.method.clone())
+            .collect::<Vec<String>>();
+        assert_eq!(
+            methods,
+            vec!["client.connect_wallet", "client.disconnect_wallet"]
+        );
+        assert_eq!(clt.token(), None);
+    }
+
+    #[tokio::test]
+    async fn test_connect() {
+        // every connection issues a new token, valid for 2 requests
+        let issued = std::sync::Arc::new(std::sync::Mutex::new((0, 0)));
+        let mock = MockWallet::start(move |r| {
+            let mut issued = issued.lock().unwrap();
+            if r.method == "client.connect_wallet" {
+                assert!(!r.headers.contains_key("authorization"));
+                *issued = (issued.0 + 1, 0);
+                return Reply::ok(json!(null))
+                    .header("Authorization", &format!("VWT token-{}", issued.0));
+            }
+            issued.1 += 1;
+            if r.headers["authorization"] != format!("VWT token-{}", issued.0) || issued.1 > 2 {
+                return Reply::err(-32001, "Unauthorized", "token expired").status(401);
+            }
+            return Reply::ok(json!({"chainID": "vega-testnet-0001"}));
+        })
+        .await;
+
+        let clt = WalletClient::connect(&mock.url, PUB_KEY).await.unwrap();
+        assert_eq!(clt.token().unwrap(), "token-1");
+        clt.get_chain_id().await.unwrap();
+        clt.get_chain_id().await.unwrap();
+        // the third request is rejected, the client connects again and retries
+        clt.get_chain_id().await.unwrap();
+        assert_eq!(clt.token().unwrap(), "token-2");
+
+        let methods = mock
+            .received()
+            .iter()
+            .map(|r| r.method.clone())
+            .collect::<Vec<String>>();
+        assert_eq!(
+            methods,
+            vec![
+                "client.connect_wallet",
+                "client.get_chain_id",
+                "client.get_chain_id",
+                "client.get_chain_id",
+                "client.connect_wallet",
+                "client.get_chain_id",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_expired_token() {
+        let mock = MockWallet::start(|r| match r.method.as_str() {
+            "client.connect_wallet" => Reply::ok(json!(null)),
+            _ => Reply::err(-32001, "Unauthorized", "token expired").status(401),
+        })
+        .await;
+
+        // a long-lived token is not renewed
+        let clt = WalletClient::new(&mock.url, TOKEN, PUB_KEY).await.unwrap();
+        assert!(matches!(clt.get_chain_id().await, Err(Error::Unauthorized)));
+        assert_eq!(mock.received().len(), 1);
+
+        // the wallet must return a token when connecting
+        assert!(matches!(
+            WalletClient::connect(&mock.url, PUB_KEY).await,
+            Err(Error::MissingToken)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_token_rotation() {
+        let mock = MockWallet::start(|_| {
+            Reply::ok(json!({"chainID": "vega-testnet-0001"}))
+                .header("Authorization", "VWT rotated")
+        })
+        .await;
+        let clt = WalletClient::new(&mock.url, TOKEN, PUB_KEY).await.unwrap();
+        clt.get_chain_id().await.unwrap();
+        assert_eq!(clt.token().unwrap(), "rotated");
+        clt.get_chain_id().await.unwrap();
+        assert_eq!(mock.received()[1].headers["authorization"], "VWT rotated");
+    }
+
+    #[tokio::test]
+    async fn test_wallet_error() {
+        let mock = MockWallet::start(|_| Reply::err(3000, "Access denied", "user rejected")).await;
+        let clt = WalletClient::new(&mock.url, TOKEN, PUB_KEY).await.unwrap();
+        match clt.get_chain_id().await {
+            Err(Error::WalletError(e)) => {
+                assert_eq!(e.code, 3000);
+                assert_eq!(e.data, "user rejected");
+            }
+            _ => panic!("expected a wallet error"),
+        }
     }
 }
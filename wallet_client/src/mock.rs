@@ -0,0 +1,161 @@
+//! A minimal HTTP server mocking the wallet API, for tests only.
+
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+/// A JSON-RPC request received by the mock wallet.
+#[derive(Debug, Clone)]
+pub struct Received {
+    pub path: String,
+    /// Header names are lowercase
+    pub headers: HashMap<String, String>,
+    pub method: String,
+    pub params: Value,
+}
+
+/// The reply of the mock wallet to a JSON-RPC request.
+pub struct Reply {
+    pub status: u16,
+    pub result: Result<Value, Value>,
+    pub headers: Vec<(String, String)>,
+}
+
+impl Reply {
+    pub fn ok(result: Value) -> Reply {
+        return Reply {
+            status: 200,
+            result: Ok(result),
+            headers: vec![],
+        };
+    }
+
+    pub fn err(code: i64, message: &str, data: &str) -> Reply {
+        return Reply {
+            status: 200,
+            result: Err(json!({"code": code, "message": message, "data": data})),
+            headers: vec![],
+        };
+    }
+
+    pub fn header(mut self, name: &str, value: &str) -> Reply {
+        self.headers.push((name.to_string(), value.to_string()));
+        return self;
+    }
+
+    pub fn status(mut self, status: u16) -> Reply {
+        self.status = status;
+        return self;
+    }
+}
+
+type Handler = dyn Fn(&Received) -> Reply + Send + Sync;
+
+pub struct MockWallet {
+    pub url: String,
+    received: Arc<Mutex<Vec<Received>>>,
+}
+
+impl MockWallet {
+    /// Start a mock wallet on a random port, every JSON-RPC request
+    /// is answered by the handler, health checks always succeed.
+    pub async fn start<F>(handler: F) -> MockWallet
+    where
+        F: Fn(&Received) -> Reply + Send + Sync + 'static,
+    {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let received = Arc::new(Mutex::new(vec![]));
+        let handler: Arc<Handler> = Arc::new(handler);
+
+        let r = received.clone();
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                tokio::spawn(serve(stream, handler.clone(), r.clone()));
+            }
+        });
+
+        return MockWallet { url, received };
+    }
+
+    /// All the JSON-RPC requests received so far.
+    pub fn received(&self) -> Vec<Received> {
+        return self.received.lock().unwrap().clone();
+    }
+}
+
+async fn serve(mut stream: TcpStream, handler: Arc<Handler>, received: Arc<Mutex<Vec<Received>>>) {
+    let mut buf = vec![];
+    let mut chunk = [0u8; 4096];
+    let head_len = loop {
+        let n = stream.read(&mut chunk).await.unwrap();
+        if n == 0 {
+            return;
+        }
+        buf.extend_from_slice(&chunk[..n]);
+        if let Some(i) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+            break i + 4;
+        }
+    };
+
+    let head = String::from_utf8_lossy(&buf[..head_len]).to_string();
+    let mut lines = head.lines();
+    let path = lines
+        .next()
+        .and_then(|l| l.split_whitespace().nth(1))
+        .unwrap_or("/")
+        .to_string();
+    let headers = lines
+        .filter_map(|l| l.split_once(':'))
+        .map(|(k, v)| (k.trim().to_lowercase(), v.trim().to_string()))
+        .collect::<HashMap<String, String>>();
+
+    let content_length = headers
+        .get("content-length")
+        .and_then(|l| l.parse::<usize>().ok())
+        .unwrap_or(0);
+    while buf.len() < head_len + content_length {
+        let n = stream.read(&mut chunk).await.unwrap();
+        if n == 0 {
+            return;
+        }
+        buf.extend_from_slice(&chunk[..n]);
+    }
+
+    let (status, body, extra_headers) = match content_length {
+        0 => (200, String::new(), vec![]),
+        _ => {
+            let req: Value = serde_json::from_slice(&buf[head_len..]).unwrap();
+            let r = Received {
+                path,
+                headers,
+                method: req["method"].as_str().unwrap_or_default().to_string(),
+                params: req["params"].clone(),
+            };
+            received.lock().unwrap().push(r.clone());
+            let reply = handler(&r);
+            let body = match reply.result {
+                Ok(result) => json!({"jsonrpc": "2.0", "id": req["id"], "result": result}),
+                Err(error) => json!({"jsonrpc": "2.0", "id": req["id"], "error": error}),
+            };
+            (reply.status, body.to_string(), reply.headers)
+        }
+    };
+
+    let mut resp = format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\n\
+         Content-Length: {}\r\nConnection: close\r\n",
+        status,
+        if status == 200 { "OK" } else { "Error" },
+        body.len()
+    );
+    for (k, v) in extra_headers.iter() {
+        resp.push_str(&format!("{}: {}\r\n", k, v));
+    }
+    resp.push_str("\r\n");
+    resp.push_str(&body);
+    let _ = stream.write_all(resp.as_bytes()).await;
+    let _ = stream.shutdown().await;
+}
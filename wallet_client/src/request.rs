@@ -1,60 +1,149 @@
 use crate::commands::Command;
-use rand;
 use serde::{Deserialize, Serialize};
 
+/// How long the wallet waits for the network before returning
+/// the result of `client.send_transaction`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SendingMode {
+    /// Wait for the transaction to be checked by the node
+    #[default]
+    #[serde(rename = "TYPE_SYNC")]
+    Sync,
+    /// Return as soon as the transaction is sent
+    #[serde(rename = "TYPE_ASYNC")]
+    Async,
+    /// Wait for the transaction to be included in a block
+    #[serde(rename = "TYPE_COMMIT")]
+    Commit,
+}
+
 #[derive(Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Params {
-    pub sending_mode: String,
+    pub sending_mode: SendingMode,
     pub transaction: Command,
     public_key: String,
 }
 
 impl Params {
-    pub fn new(cmd: Command, pubkey: &str) -> Params {
+    pub fn new(cmd: Command, pubkey: &str, mode: SendingMode) -> Params {
         return Params {
-            sending_mode: "TYPE_SYNC".to_string(),
+            sending_mode: mode,
             transaction: cmd,
             public_key: pubkey.to_string(),
         };
     }
 }
 
+/// Parameters of `client.check_transaction`, the transaction
+/// is only checked by the network, there is no sending mode.
 #[derive(Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct Request {
+pub struct CheckParams {
+    pub transaction: Command,
+    public_key: String,
+}
+
+impl CheckParams {
+    pub fn new(cmd: Command, pubkey: &str) -> CheckParams {
+        return CheckParams {
+            transaction: cmd,
+            public_key: pubkey.to_string(),
+        };
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignMessageParams {
+    public_key: String,
+    /// The base64 encoded message
+    pub encoded_message: String,
+}
+
+impl SignMessageParams {
+    pub fn new(message: &[u8], pubkey: &str) -> SignMessageParams {
+        use base64::Engine;
+        return SignMessageParams {
+            public_key: pubkey.to_string(),
+            encoded_message: base64::engine::general_purpose::STANDARD.encode(message),
+        };
+    }
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Request<P> {
     #[serde(rename = "jsonrpc")]
     pub version: String,
     pub method: String,
-    pub params: Option<Params>,
+    pub params: Option<P>,
     pub id: String,
 }
 
-impl Request {
-    pub fn new_send_transaction(cmd: Command, pubkey: &str) -> Request {
+impl<P> Request<P> {
+    pub(crate) fn new(method: &str, params: Option<P>) -> Request<P> {
         return Request {
             version: "2.0".to_string(),
-            method: "client.send_transaction".to_string(),
-            params: Some(Params::new(cmd, pubkey)),
+            method: method.to_string(),
+            params,
             id: rand::random::<u64>().to_string(),
         };
     }
+}
 
-    pub fn new_sign_transaction(cmd: Command, pubkey: &str) -> Request {
-        return Request {
-            version: "2.0".to_string(),
-            method: "client.sign_transaction".to_string(),
-            params: Some(Params::new(cmd, pubkey)),
-            id: rand::random::<u64>().to_string(),
-        };
+impl Request<Params> {
+    pub fn new_send_transaction(cmd: Command, pubkey: &str, mode: SendingMode) -> Request<Params> {
+        return Request::new(
+            "client.send_transaction",
+            Some(Params::new(cmd, pubkey, mode)),
+        );
     }
 
-    pub fn new_list_keys() -> Request {
-        return Request {
-            version: "2.0".to_string(),
-            method: "client.list_keys".to_string(),
-            params: None,
-            id: rand::random::<u64>().to_string(),
-        };
+    pub fn new_sign_transaction(cmd: Command, pubkey: &str) -> Request<Params> {
+        return Request::new(
+            "client.sign_transaction",
+            Some(Params::new(cmd, pubkey, SendingMode::default())),
+        );
+    }
+}
+
+impl Request<CheckParams> {
+    pub fn new_check_transaction(cmd: Command, pubkey: &str) -> Request<CheckParams> {
+        return Request::new(
+            "client.check_transaction",
+            Some(CheckParams::new(cmd, pubkey)),
+        );
+    }
+}
+
+impl Request<SignMessageParams> {
+    pub fn new_sign_message(message: &[u8], pubkey: &str) -> Request<SignMessageParams> {
+        return Request::new(
+            "client.sign_message",
+            Some(SignMessageParams::new(message, pubkey)),
+        );
+    }
+}
+
+impl Request<()> {
+    pub fn new_list_keys() -> Request<()> {
+        return Request::new("client.list_keys", None);
+    }
+
+    pub fn new_get_chain_id() -> Request<()> {
+        return Request::new("client.get_chain_id", None);
+    }
+
+    pub fn new_get_permissions() -> Request<()> {
+        return Request::new("client.get_permissions", None);
+    }
+
+    pub fn new_connect_wallet() -> Request<()> {
+        return Request::new("client.connect_wallet", None);
+    }
+
+    pub fn new_disconnect_wallet() -> Request<()> {
+        return Request::new("client.disconnect_wallet", None);
     }
 }
@@ -32,6 +32,49 @@ pub struct WalletError {
     pub data: String,
 }
 
+impl WalletError {
+    pub fn kind(&self) -> ErrorCode {
+        return ErrorCode::from(self.code);
+    }
+}
+
+/// The error codes returned by the wallet API.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorCode {
+    ParseError,
+    InvalidRequest,
+    MethodNotFound,
+    InvalidParams,
+    InternalError,
+    /// The request was interrupted, e.g: the wallet service was stopped
+    RequestInterrupted,
+    /// The wallet could not reach the network
+    NetworkError,
+    /// The wallet could not process the request
+    ApplicationError,
+    /// The user rejected the request
+    UserError,
+    Other(i64),
+}
+
+impl std::convert::From<i64> for ErrorCode {
+    fn from(code: i64) -> ErrorCode {
+        use ErrorCode::*;
+        return match code {
+            -32700 => ParseError,
+            -32600 => InvalidRequest,
+            -32601 => MethodNotFound,
+            -32602 => InvalidParams,
+            -32603 => InternalError,
+            -32001 => RequestInterrupted,
+            1000 => NetworkError,
+            2000 => ApplicationError,
+            3000 => UserError,
+            c => Other(c),
+        };
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SignResponse {
@@ -75,3 +118,51 @@ pub struct SendResponse {
     pub transaction_hash: String,
     pub transaction: Transaction,
 }
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckResponse {
+    pub received_at: DateTime<Utc>,
+    pub sent_at: DateTime<Utc>,
+    pub transaction: Transaction,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChainIdResponse {
+    #[serde(rename = "chainID")]
+    pub chain_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SignMessageResponse {
+    /// The base64 encoded signature
+    pub signature: String,
+}
+
+impl SignMessageResponse {
+    /// The raw signature bytes.
+    pub fn decode(&self) -> Result<Vec<u8>, base64::DecodeError> {
+        use base64::Engine;
+        return base64::engine::general_purpose::STANDARD.decode(&self.signature);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PermissionsResponse {
+    pub permissions: Permissions,
+}
+
+/// The permissions granted by the user to the application.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Permissions {
+    pub public_keys: AccessMode,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AccessMode {
+    None,
+    Read,
+}
@@ -0,0 +1,72 @@
+//! Proto3 JSON samples of the messages of the Vega API, built from
+//! the file descriptor set of `vega_protobufs`, for tests only.
+
+use prost_reflect::{DescriptorPool, FieldDescriptor, Kind, MessageDescriptor, OneofDescriptor};
+use serde_json::{json, Map, Value};
+
+pub fn pool() -> DescriptorPool {
+    return DescriptorPool::decode(vega_protobufs::FILE_DESCRIPTOR_SET).unwrap();
+}
+
+/// The oneof of a field, ignoring the oneofs generated for proto3 optional fields.
+pub fn oneof(field: &FieldDescriptor) -> Option<OneofDescriptor> {
+    if field.field_descriptor_proto().proto3_optional() {
+        return None;
+    }
+    return field.containing_oneof();
+}
+
+/// The number of samples needed for `sample` to go through
+/// every member of every oneof and every value of every enum.
+pub fn variants(pool: &DescriptorPool) -> usize {
+    let oneofs = pool
+        .all_messages()
+        .flat_map(|m| m.oneofs().map(|o| o.fields().len()).collect::<Vec<_>>());
+    let enums = pool.all_enums().map(|e| e.values().len());
+    return oneofs.chain(enums).max().unwrap_or(1);
+}
+
+/// A proto3 JSON sample of a message with every field set. The `n`th
+/// sample sets the `n`th member of each oneof and uses the `n`th value
+/// of each enum, wrapping around.
+pub fn sample(message: &MessageDescriptor, n: usize) -> Value {
+    let mut obj = Map::new();
+    for f in message.fields() {
+        if let Some(oneof) = oneof(&f) {
+            let members = oneof.fields().collect::<Vec<_>>();
+            if members[n % members.len()].number() != f.number() {
+                continue;
+            }
+        }
+        let v = match f.kind() {
+            Kind::Message(entry) if f.is_map() => {
+                json!({ "key": sample_value(&entry.map_entry_value_field(), n) })
+            }
+            _ if f.is_list() => json!([sample_value(&f, n)]),
+            _ => sample_value(&f, n),
+        };
+        obj.insert(f.json_name().to_string(), v);
+    }
+    return Value::Object(obj);
+}
+
+fn sample_value(field: &FieldDescriptor, n: usize) -> Value {
+    return match field.kind() {
+        Kind::Message(m) if m.full_name() == "google.protobuf.Value" => {
+            json!({ "value": [n, "sample"] })
+        }
+        Kind::Message(m) => sample(&m, n),
+        Kind::Enum(e) => {
+            let values = e.values().collect::<Vec<_>>();
+            json!(values[n % values.len()].name())
+        }
+        Kind::String => json!(format!("{}-{}", field.name(), n)),
+        Kind::Bool => json!(true),
+        Kind::Double | Kind::Float => json!(n as f64 + 0.5),
+        Kind::Bytes => json!("AQID"),
+        Kind::Int64 | Kind::Uint64 | Kind::Sint64 | Kind::Fixed64 | Kind::Sfixed64 => {
+            json!((n + 1).to_string())
+        }
+        _ => json!(n + 1),
+    };
+}